@@ -68,7 +68,9 @@ use bp_runtime::{
 	BasicOperatingMode, ChainId, OwnedBridgeModule, PreComputedSize, RangeInclusiveExt, Size,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{dispatch::PostDispatchInfo, ensure, fail, traits::Get, DefaultNoBound};
+use frame_support::{
+	dispatch::PostDispatchInfo, ensure, fail, traits::Get, weights::Weight, DefaultNoBound,
+};
 use sp_runtime::traits::UniqueSaturatedFrom;
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -136,6 +138,15 @@ pub mod pallet {
 		/// Transaction that is declaring more messages than this value, will be rejected. Even if
 		/// these messages are from different lanes.
 		type MaxUnconfirmedMessagesAtInboundLane: Get<MessageNonce>;
+		/// Maximal number of confirmed messages that may be pruned by a single delivery
+		/// transaction.
+		///
+		/// Delivery transactions are pruning confirmed messages of the active outbound lanes with
+		/// the same ids as lanes they are delivering messages to. The relayer is not charged for
+		/// that. Instead, the weight of pruning is credited to the delivery transaction, reducing
+		/// its fee. If it is zero, messages are only pruned in the `on_idle` hook.
+		#[pallet::constant]
+		type MaxMessagesToPruneAtOnce: Get<MessageNonce>;
 
 		/// Maximal encoded size of the outbound payload.
 		#[pallet::constant]
@@ -268,8 +279,15 @@ pub mod pallet {
 		///
 		/// The call may succeed, but some messages may not be delivered e.g. if they are not fit
 		/// into the unrewarded relayers vector.
+		///
+		/// The call also prunes up to `MaxMessagesToPruneAtOnce` confirmed messages of outbound
+		/// lanes with the same ids as lanes in the proof. The weight of pruning is credited to the
+		/// transaction, so the relayer pays less for the delivery.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(proof, *messages_count, *dispatch_weight))]
+		#[pallet::weight(
+			T::WeightInfo::receive_messages_proof_weight(proof, *messages_count, *dispatch_weight)
+				.saturating_add(max_pruning_weight::<T, I>())
+		)]
 		pub fn receive_messages_proof(
 			origin: OriginFor<T>,
 			relayer_id_at_bridged_chain: T::InboundRelayer,
@@ -322,7 +340,9 @@ pub mod pallet {
 			let mut valid_messages = 0;
 			let mut messages_received_status = Vec::with_capacity(messages.len());
 			let mut dispatch_weight_left = dispatch_weight;
+			let mut delivery_lanes = Vec::with_capacity(messages.len());
 			for (lane_id, lane_data) in messages {
+				delivery_lanes.push(lane_id);
 				let mut lane = inbound_lane::<T, I>(lane_id);
 
 				// subtract extra storage proof bytes from the actual PoV size - there may be
@@ -398,6 +418,18 @@ pub mod pallet {
 				messages_received_status.push(lane_messages_received_status);
 			}
 
+			// prune confirmed messages of the lanes we're delivering messages to. The relayer is
+			// not charged for the pruning - instead, its weight is credited to the transaction.
+			// The block still accounts both the work and the credit, so the block weight is
+			// correct
+			let (lanes_read_weight, pruning_weight) = prune_lanes_messages::<T, I>(&delivery_lanes);
+			let pruning_credit = pruning_weight.min(actual_weight);
+			actual_weight = actual_weight.saturating_sub(pruning_credit);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				lanes_read_weight.saturating_add(pruning_weight).saturating_add(pruning_credit),
+				DispatchClass::Normal,
+			);
+
 			// let's now deal with relayer payments
 			T::DeliveryPayments::pay_reward(
 				relayer_id_at_this_chain,
@@ -770,6 +802,53 @@ fn ensure_normal_operating_mode<T: Config<I>, I: 'static>() -> Result<(), Error<
 	Err(Error::<T, I>::NotOperatingNormally)
 }
 
+/// Returns the maximal weight that may be spent on pruning messages by a delivery transaction.
+fn max_pruning_weight<T: Config<I>, I: 'static>() -> Weight {
+	let max_messages_to_prune = T::MaxMessagesToPruneAtOnce::get();
+	if max_messages_to_prune == 0 {
+		return Weight::zero()
+	}
+
+	T::DbWeight::get().reads_writes(
+		T::ActiveOutboundLanes::get().len() as u64,
+		max_messages_to_prune.saturating_add(1),
+	)
+}
+
+/// Prunes confirmed messages of given lanes, but no more than `MaxMessagesToPruneAtOnce` in total.
+///
+/// Only active outbound lanes are pruned. Returns the weight of reading lane states and the weight
+/// of the pruning itself.
+fn prune_lanes_messages<T: Config<I>, I: 'static>(lanes: &[LaneId]) -> (Weight, Weight) {
+	let db_weight = T::DbWeight::get();
+	let max_messages_to_prune = T::MaxMessagesToPruneAtOnce::get();
+	let mut read_weight = Weight::zero();
+	let mut pruning_weight = Weight::zero();
+	if max_messages_to_prune == 0 {
+		return (read_weight, pruning_weight)
+	}
+
+	// pruning every message costs a single write, plus a single write for every lane update
+	let mut remaining_weight = db_weight.writes(max_messages_to_prune.saturating_add(1));
+	let active_lanes = T::ActiveOutboundLanes::get();
+	for lane_id in lanes {
+		if !remaining_weight.all_gte(db_weight.writes(2)) {
+			break
+		}
+		if !active_lanes.contains(lane_id) {
+			continue
+		}
+
+		read_weight += db_weight.reads(1);
+		let spent_weight =
+			outbound_lane::<T, I>(*lane_id).prune_messages(db_weight, remaining_weight);
+		pruning_weight += spent_weight;
+		remaining_weight = remaining_weight.saturating_sub(spent_weight);
+	}
+
+	(read_weight, pruning_weight)
+}
+
 /// Creates new inbound lane object, backed by runtime storage.
 fn inbound_lane<T: Config<I>, I: 'static>(
 	lane_id: LaneId,
@@ -1924,6 +2003,78 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn receive_messages_proof_prunes_confirmed_messages_and_credits_relayer() {
+		run_test(|| {
+			let max_messages_to_prune = crate::mock::MaxMessagesToPruneAtOnce::get();
+			let messages_to_confirm = max_messages_to_prune + 2;
+			for _ in 0..messages_to_confirm {
+				send_regular_message();
+			}
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok((
+					TEST_LANE_ID,
+					InboundLaneData {
+						last_confirmed_nonce: messages_to_confirm,
+						relayers: vec![unrewarded_relayer(1, messages_to_confirm, TEST_RELAYER_A)]
+							.into_iter()
+							.collect(),
+					},
+				))),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					messages_in_oldest_entry: messages_to_confirm,
+					total_messages: messages_to_confirm,
+					last_delivered_nonce: messages_to_confirm,
+				},
+			));
+
+			// delivery transaction prunes no more than `MaxMessagesToPruneAtOnce` messages
+			let proof: TestMessagesProof = Ok(vec![message(1, REGULAR_PAYLOAD)]).into();
+			let pre_dispatch_weight =
+				<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
+					&proof,
+					1,
+					REGULAR_PAYLOAD.declared_weight,
+				);
+			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof,
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap();
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				max_messages_to_prune + 1,
+			);
+
+			// and the weight of pruning is credited to the relayer
+			let pruning_weight = DbWeight::get().writes(max_messages_to_prune + 1);
+			assert_eq!(
+				post_dispatch_weight.ref_time(),
+				pre_dispatch_weight.ref_time() - pruning_weight.ref_time(),
+			);
+
+			// next delivery transaction prunes the rest
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(2, REGULAR_PAYLOAD)]).into(),
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			));
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				messages_to_confirm + 1,
+			);
+		});
+	}
+
 	#[test]
 	fn on_idle_callback_is_rotating_lanes_to_prune() {
 		run_test(|| {
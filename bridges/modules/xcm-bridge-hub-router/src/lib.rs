@@ -30,9 +30,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bp_xcm_bridge_hub_router::{
-	BridgeState, XcmChannelStatusProvider, MINIMAL_DELIVERY_FEE_FACTOR,
-};
+use bp_xcm_bridge_hub_router::{XcmChannelStatusProvider, MINIMAL_DELIVERY_FEE_FACTOR};
 use codec::Encode;
 use frame_support::traits::Get;
 use sp_core::H256;
@@ -40,6 +38,7 @@ use sp_runtime::{FixedPointNumber, FixedU128, Saturating};
 use xcm::prelude::*;
 use xcm_builder::{ExporterFor, SovereignPaidRemoteExporter};
 
+pub use bp_xcm_bridge_hub_router::BridgeState;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -355,6 +354,18 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 	}
 }
 
+sp_api::decl_runtime_apis! {
+	/// API for querying the state of the bridge, used by the XCM bridge hub router.
+	///
+	/// It allows wallets and front-ends to estimate the fee of messages sent over the bridge
+	/// without simulating the transfer.
+	pub trait XcmBridgeHubRouterApi {
+		/// Returns the current state of the bridge: the delivery fee factor, applied to all
+		/// messages sent over the bridge, and whether the bridge has reported congestion.
+		fn bridge_state() -> BridgeState;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
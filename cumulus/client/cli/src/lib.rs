@@ -309,6 +309,25 @@ pub struct RunCmd {
 	/// Will use the specified relay chain chainspec.
 	#[arg(long, conflicts_with_all = ["relay_chain_rpc_urls", "collator"])]
 	pub relay_chain_light_client: bool,
+
+	/// EXPERIMENTAL: On startup, recover the parachain blocks that were included on the relay
+	/// chain since the local best block from the relay chain's availability store.
+	///
+	/// This allows a collator that was restored from an old backup to catch up without any
+	/// parachain peers, as long as the missing blocks are still available on the relay chain. The
+	/// blocks are imported on top of the local database, the parent of the oldest missing block
+	/// needs to be present in it.
+	#[arg(long)]
+	pub recover_from_relay_chain: bool,
+
+	/// The maximum number of relay chain blocks to walk back when recovering from the relay chain.
+	#[arg(
+		long,
+		value_name = "BLOCKS",
+		default_value_t = 600,
+		requires = "recover_from_relay_chain"
+	)]
+	pub relay_chain_recovery_depth: u32,
 }
 
 impl RunCmd {
@@ -331,7 +350,12 @@ impl RunCmd {
 				_ => RelayChainMode::Embedded,
 			};
 
-		CollatorOptions { relay_chain_mode }
+		CollatorOptions {
+			relay_chain_mode,
+			recover_from_relay_chain: self
+				.recover_from_relay_chain
+				.then_some(self.relay_chain_recovery_depth),
+		}
 	}
 }
 
@@ -351,6 +375,9 @@ pub enum RelayChainMode {
 pub struct CollatorOptions {
 	/// How this collator retrieves relay chain information
 	pub relay_chain_mode: RelayChainMode,
+	/// Recover the blocks included on the relay chain since the local best block on startup,
+	/// walking back at most this many relay chain blocks.
+	pub recover_from_relay_chain: Option<u32>,
 }
 
 /// A non-redundant version of the `RunCmd` that sets the `validator` field when the
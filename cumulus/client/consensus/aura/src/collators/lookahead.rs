@@ -30,6 +30,10 @@
 //! building the block, as there is some buffer before it can get posted to the relay-chain.
 //! The main limitation is block propagation time - i.e. the new blocks created by an author
 //! must be propagated to the next author before their turn.
+//!
+//! When the runtime exposes [`BackoffParams`], this collator skips slots while the unincluded
+//! segment is close to its capacity or the relay chain is not including any parachain blocks, to
+//! avoid building and distributing collations which are unlikely to be backed.
 
 use codec::{Codec, Encode};
 use cumulus_client_collator::service::ServiceInterface as CollatorServiceInterface;
@@ -38,7 +42,7 @@ use cumulus_client_consensus_common::{
 	ParentSearchParams,
 };
 use cumulus_client_consensus_proposer::ProposerInterface;
-use cumulus_primitives_aura::AuraUnincludedSegmentApi;
+use cumulus_primitives_aura::{AuraUnincludedSegmentApi, BackoffParams};
 use cumulus_primitives_core::{
 	relay_chain::Hash as PHash, CollectCollationInfo, PersistedValidationData,
 };
@@ -55,7 +59,7 @@ use futures::{channel::oneshot, prelude::*};
 use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf};
 use sc_consensus::BlockImport;
 use sc_consensus_aura::standalone as aura_internal;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_application_crypto::AppPublic;
 use sp_blockchain::HeaderBackend;
 use sp_consensus::SyncOracle;
@@ -180,6 +184,10 @@ where
 			collator_util::Collator::<Block, P, _, _, _, _, _>::new(params)
 		};
 
+		// The most recently included block and the number of the relay parent at which it was
+		// first seen as included.
+		let mut last_inclusion = None;
+
 		while let Some(relay_parent_header) = import_notifications.next().await {
 			let relay_parent = relay_parent_header.hash();
 
@@ -266,6 +274,16 @@ where
 				Some(b) => b.hash,
 			};
 
+			let relay_parent_number = *relay_parent_header.number();
+			let relay_blocks_without_inclusion = match last_inclusion {
+				Some((hash, number)) if hash == included_block =>
+					relay_parent_number.saturating_sub(number),
+				_ => {
+					last_inclusion = Some((included_block, relay_parent_number));
+					0
+				},
+			};
+
 			let para_client = &*params.para_client;
 			let keystore = &params.keystore;
 			let can_build_upon = |block_hash| {
@@ -294,10 +312,32 @@ where
 			let mut parent_hash = initial_parent.hash;
 			let mut parent_header = initial_parent.header;
 			let overseer_handle = &mut params.overseer_handle;
+			let backoff_params = backoff_params::<Block, _>(para_client, parent_hash);
 
 			// This needs to change to support elastic scaling, but for continuously
 			// scheduled chains this ensures that the backlog will grow steadily.
 			for n_built in 0..2 {
+				if let Some(backoff_params) = &backoff_params {
+					let unincluded_segment_len = (initial_parent.depth + n_built) as u32;
+					let interval = backoff_interval(
+						backoff_params,
+						unincluded_segment_len,
+						relay_blocks_without_inclusion,
+					);
+
+					if is_backing_off::<Block, P>(interval, slot_now, &parent_header) {
+						tracing::debug!(
+							target: crate::LOG_TARGET,
+							?relay_parent,
+							unincluded_segment_len,
+							relay_blocks_without_inclusion,
+							skipped_slots = interval,
+							"Backing off from authoring"
+						);
+						break
+					}
+				}
+
 				let slot_claim = match can_build_upon(parent_hash).await {
 					None => break,
 					Some(c) => c,
@@ -430,6 +470,65 @@ where
 	Some(SlotClaim::unchecked::<P>(author_pub, slot, timestamp))
 }
 
+// Fetches the backoff parameters from the runtime, if it supports them.
+fn backoff_params<Block: BlockT, Client>(client: &Client, at: Block::Hash) -> Option<BackoffParams>
+where
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: AuraUnincludedSegmentApi<Block>,
+{
+	let runtime_api = client.runtime_api();
+	match runtime_api.api_version::<dyn AuraUnincludedSegmentApi<Block>>(at) {
+		Ok(Some(version)) if version >= 2 => runtime_api.backoff_params(at).ok(),
+		_ => None,
+	}
+}
+
+// The number of slots to skip after the slot of the parent block, growing by one with every block
+// or relay chain block exceeding the respective threshold.
+fn backoff_interval(
+	params: &BackoffParams,
+	unincluded_segment_len: u32,
+	relay_blocks_without_inclusion: u32,
+) -> u32 {
+	let excess = |value: u32, threshold: u32| {
+		if value >= threshold {
+			value.saturating_sub(threshold).saturating_add(1)
+		} else {
+			0
+		}
+	};
+
+	let segment_excess = excess(unincluded_segment_len, params.unincluded_segment_threshold);
+	let stall_excess = if params.inclusion_stall_threshold == 0 {
+		0
+	} else {
+		excess(relay_blocks_without_inclusion, params.inclusion_stall_threshold)
+	};
+
+	segment_excess.max(stall_excess).min(params.max_skipped_slots)
+}
+
+// Whether the given slot is within `interval` slots of the slot of the parent block.
+fn is_backing_off<Block: BlockT, P>(
+	interval: u32,
+	slot: Slot,
+	parent_header: &Block::Header,
+) -> bool
+where
+	P: Pair,
+	P::Signature: Codec,
+{
+	if interval == 0 {
+		return false
+	}
+
+	// The genesis block has slot 0, so it never causes any backoff in practice.
+	match aura_internal::find_pre_digest::<Block, P::Signature>(parent_header) {
+		Ok(parent_slot) => *slot <= (*parent_slot).saturating_add(interval.into()),
+		Err(_) => false,
+	}
+}
+
 /// Reads allowed ancestry length parameter from the relay chain storage at the given relay parent.
 ///
 /// Falls back to 0 in case of an error.
@@ -495,3 +594,85 @@ async fn is_para_scheduled(
 
 	cores.iter().any(|core| core.para_id() == Some(para_id))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_consensus_aura::{
+		digests::CompatibleDigestItem,
+		sr25519::{AuthorityPair, AuthoritySignature},
+	};
+	use sp_runtime::{generic, traits::BlakeTwo256, Digest, DigestItem, OpaqueExtrinsic};
+
+	type Header = generic::Header<u32, BlakeTwo256>;
+	type Block = generic::Block<Header, OpaqueExtrinsic>;
+
+	const PARAMS: BackoffParams = BackoffParams {
+		unincluded_segment_threshold: 2,
+		inclusion_stall_threshold: 3,
+		max_skipped_slots: 5,
+	};
+
+	fn header(number: u32, slot: Option<u64>) -> Header {
+		let logs = slot
+			.map(|slot| {
+				<DigestItem as CompatibleDigestItem<AuthoritySignature>>::aura_pre_digest(
+					slot.into(),
+				)
+			})
+			.into_iter()
+			.collect();
+		Header::new(
+			number,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Digest { logs },
+		)
+	}
+
+	#[test]
+	fn backoff_interval_grows_with_the_excess_over_the_thresholds() {
+		assert_eq!(backoff_interval(&PARAMS, 0, 0), 0);
+		assert_eq!(backoff_interval(&PARAMS, 1, 2), 0);
+
+		assert_eq!(backoff_interval(&PARAMS, 2, 0), 1);
+		assert_eq!(backoff_interval(&PARAMS, 4, 0), 3);
+		assert_eq!(backoff_interval(&PARAMS, 0, 3), 1);
+		assert_eq!(backoff_interval(&PARAMS, 0, 5), 3);
+
+		// The larger excess wins.
+		assert_eq!(backoff_interval(&PARAMS, 3, 5), 3);
+		assert_eq!(backoff_interval(&PARAMS, 5, 3), 4);
+
+		// Capped at the maximum number of skipped slots.
+		assert_eq!(backoff_interval(&PARAMS, 10, 0), 5);
+		assert_eq!(backoff_interval(&PARAMS, 0, u32::MAX), 5);
+	}
+
+	#[test]
+	fn backoff_interval_ignores_stalls_without_stall_threshold() {
+		let params = BackoffParams { inclusion_stall_threshold: 0, ..PARAMS };
+
+		assert_eq!(backoff_interval(&params, 0, 100), 0);
+		assert_eq!(backoff_interval(&params, 3, 100), 2);
+	}
+
+	#[test]
+	fn is_backing_off_skips_slots_after_the_parent() {
+		let parent = header(1, Some(10));
+
+		assert!(!is_backing_off::<Block, AuthorityPair>(0, 11.into(), &parent));
+		assert!(is_backing_off::<Block, AuthorityPair>(2, 11.into(), &parent));
+		assert!(is_backing_off::<Block, AuthorityPair>(2, 12.into(), &parent));
+		assert!(!is_backing_off::<Block, AuthorityPair>(2, 13.into(), &parent));
+	}
+
+	#[test]
+	fn is_backing_off_without_parent_slot() {
+		// Genesis.
+		assert!(!is_backing_off::<Block, AuthorityPair>(2, 100.into(), &header(0, None)));
+		// No pre-digest.
+		assert!(!is_backing_off::<Block, AuthorityPair>(2, 1.into(), &header(1, None)));
+	}
+}
@@ -24,8 +24,8 @@ use cumulus_primitives_core::{
 	CumulusDigestItem, InboundDownwardMessage, InboundHrmpMessage,
 };
 use cumulus_relay_chain_interface::{
-	CommittedCandidateReceipt, OccupiedCoreAssumption, OverseerHandle, PHeader, ParaId,
-	RelayChainInterface, RelayChainResult, SessionIndex, StorageValue, ValidatorId,
+	CandidateEvent, CommittedCandidateReceipt, OccupiedCoreAssumption, OverseerHandle, PHeader,
+	ParaId, RelayChainInterface, RelayChainResult, SessionIndex, StorageValue, ValidatorId,
 };
 use cumulus_test_client::{
 	runtime::{Block, Hash, Header},
@@ -144,6 +144,10 @@ impl RelayChainInterface for Relaychain {
 		unimplemented!("Not needed for test")
 	}
 
+	async fn candidate_events(&self, _: PHash) -> RelayChainResult<Vec<CandidateEvent>> {
+		unimplemented!("Not needed for test")
+	}
+
 	async fn session_index_for_child(&self, _: PHash) -> RelayChainResult<SessionIndex> {
 		Ok(0)
 	}
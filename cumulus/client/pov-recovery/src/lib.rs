@@ -45,6 +45,21 @@
 //!
 //! If we need to recover multiple PoV blocks (which should hopefully not happen in real life), we
 //! make sure that the blocks are imported in the correct order.
+//!
+//! # Recovery from the relay chain
+//!
+//! A node that was restored from an old backup can be started with relay chain recovery enabled.
+//! On startup we then walk the relay chain back from its finalized block, for at most a
+//! configured number of relay chain blocks, and note every candidate of our parachain that was
+//! included since our best block. The walk runs alongside the other recovery work. The noted
+//! candidates are recovered and imported in order, as described above. This allows a collator to
+//! catch up without any parachain peers, e.g. the only collator of a test network, as long as the
+//! PoVs of the missing blocks are still kept by the relay chain validators.
+//!
+//! The recovered blocks are imported as usual, so they are executed on top of the local state.
+//! There is no state reconstruction: the parent of the oldest recovered block needs to be in the
+//! local database. If our best block was included further back than the walk reaches, nothing is
+//! recovered and the node needs to sync from other nodes instead.
 
 use sc_client_api::{BlockBackend, BlockchainEvents, UsageProvider};
 use sc_consensus::import_queue::{ImportQueueService, IncomingBlock};
@@ -55,7 +70,8 @@ use polkadot_node_primitives::{PoV, POV_BOMB_LIMIT};
 use polkadot_node_subsystem::messages::AvailabilityRecoveryMessage;
 use polkadot_overseer::Handle as OverseerHandle;
 use polkadot_primitives::{
-	CandidateReceipt, CommittedCandidateReceipt, Id as ParaId, SessionIndex,
+	BlockId, CandidateEvent, CandidateReceipt, CommittedCandidateReceipt, Id as ParaId,
+	SessionIndex,
 };
 
 use cumulus_primitives_core::ParachainBlockData;
@@ -63,7 +79,8 @@ use cumulus_relay_chain_interface::{RelayChainInterface, RelayChainResult};
 
 use codec::Decode;
 use futures::{
-	channel::mpsc::Receiver, select, stream::FuturesUnordered, Future, FutureExt, Stream, StreamExt,
+	channel::mpsc::Receiver, future::OptionFuture, select, stream::FuturesUnordered, Future,
+	FutureExt, Stream, StreamExt,
 };
 use futures_timer::Delay;
 use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
@@ -78,6 +95,9 @@ use std::{
 mod active_candidate_recovery;
 use active_candidate_recovery::ActiveCandidateRecovery;
 
+#[cfg(test)]
+mod tests;
+
 const LOG_TARGET: &str = "cumulus-pov-recovery";
 
 /// Test-friendly wrapper trait for the overseer handle.
@@ -230,6 +250,9 @@ pub struct PoVRecovery<Block: BlockT, PC, RC> {
 	/// Blocks that we are retrying currently
 	candidates_in_retry: HashSet<Block::Hash>,
 	parachain_sync_service: Arc<dyn SyncOracle + Sync + Send>,
+	/// Recover the candidates included on the relay chain since our best block on startup,
+	/// walking back at most this many relay chain blocks.
+	recover_from_relay_chain: Option<u32>,
 }
 
 impl<Block: BlockT, PC, RCInterface> PoVRecovery<Block, PC, RCInterface>
@@ -247,6 +270,7 @@ where
 		para_id: ParaId,
 		recovery_chan_rx: Receiver<RecoveryRequest<Block>>,
 		parachain_sync_service: Arc<dyn SyncOracle + Sync + Send>,
+		recover_from_relay_chain: Option<u32>,
 	) -> Self {
 		Self {
 			candidates: HashMap::new(),
@@ -260,6 +284,7 @@ where
 			candidates_in_retry: HashSet::new(),
 			recovery_chan_rx,
 			parachain_sync_service,
+			recover_from_relay_chain,
 		}
 	}
 
@@ -308,6 +333,75 @@ where
 		self.recover(RecoveryRequest { hash, kind: RecoveryKind::Simple });
 	}
 
+	/// Note the candidates included on the relay chain since our best block and recover them.
+	fn handle_included_candidates(
+		&mut self,
+		included: RelayChainResult<Option<Vec<IncludedCandidate<Block>>>>,
+	) {
+		let included = match included {
+			Ok(Some(included)) => included,
+			Ok(None) => {
+				tracing::warn!(
+					target: LOG_TARGET,
+					"Reached the maximum relay chain recovery depth before reaching our best block, \
+					the missing blocks need to be synced from other nodes.",
+				);
+				return
+			},
+			Err(error) => {
+				tracing::error!(
+					target: LOG_TARGET,
+					?error,
+					"Unable to retrieve the candidates included on the relay chain.",
+				);
+				return
+			},
+		};
+
+		let Some((_, _, oldest)) = included.last() else {
+			tracing::info!(
+				target: LOG_TARGET,
+				"No candidates were included on the relay chain since our best block.",
+			);
+			return
+		};
+
+		// The blocks are executed on top of our local state, we can not import them without it.
+		let parent_hash = *oldest.parent_hash();
+		if !matches!(
+			self.parachain_client.block_status(parent_hash),
+			Ok(BlockStatus::InChainWithState)
+		) {
+			tracing::warn!(
+				target: LOG_TARGET,
+				?parent_hash,
+				"The parent of the oldest block included on the relay chain is not in our database, \
+				the missing blocks need to be synced from other nodes.",
+			);
+			return
+		}
+
+		tracing::info!(
+			target: LOG_TARGET,
+			count = included.len(),
+			"Recovering the blocks included on the relay chain since our best block.",
+		);
+
+		// The oldest candidate builds on our best block, which is not a candidate. So we recover
+		// each candidate on its own, oldest first, instead of recovering the full ancestry.
+		for (receipt, session_index, header) in included.into_iter().rev() {
+			let hash = header.hash();
+			self.candidates.entry(hash).or_insert(Candidate {
+				block_number: *header.number(),
+				receipt,
+				session_index,
+				parent_hash: *header.parent_hash(),
+				waiting_recovery: false,
+			});
+			self.recover(RecoveryRequest { hash, kind: RecoveryKind::Simple });
+		}
+	}
+
 	/// Block is no longer waiting for recovery
 	fn clear_waiting_recovery(&mut self, block_hash: &Block::Hash) {
 		if let Some(candidate) = self.candidates.get_mut(block_hash) {
@@ -552,6 +646,20 @@ where
 
 		futures::pin_mut!(pending_candidates);
 
+		let included_candidates: OptionFuture<_> = self
+			.recover_from_relay_chain
+			.map(|max_depth| {
+				included_candidates_since::<Block>(
+					self.relay_chain_interface.clone(),
+					self.para_id,
+					self.parachain_client.usage_info().chain.best_number,
+					max_depth,
+				)
+			})
+			.into();
+		let included_candidates = included_candidates.fuse();
+		futures::pin_mut!(included_candidates);
+
 		loop {
 			select! {
 				pending_candidate = pending_candidates.next() => {
@@ -586,6 +694,11 @@ where
 						return;
 					}
 				},
+				included = included_candidates => {
+					if let Some(included) = included {
+						self.handle_included_candidates(included);
+					}
+				},
 				next_to_recover = self.candidate_recovery_queue.next_recovery().fuse() => {
 						self.recover_candidate(next_to_recover).await;
 				},
@@ -649,3 +762,62 @@ async fn pending_candidates(
 	});
 	Ok(filtered_stream)
 }
+
+/// A candidate included on the relay chain, with the session it was backed in and its header.
+type IncludedCandidate<Block> = (CandidateReceipt, SessionIndex, <Block as BlockT>::Header);
+
+/// Returns the candidates of `para_id` that were included on the relay chain after the parachain
+/// block `best_number`, newest first.
+///
+/// The relay chain is walked back from its finalized block for at most `max_depth` blocks. Returns
+/// `None` if the inclusion of `best_number` was not reached by then, as the missing candidates
+/// could not be imported.
+async fn included_candidates_since<Block: BlockT>(
+	relay_chain_client: impl RelayChainInterface,
+	para_id: ParaId,
+	best_number: NumberFor<Block>,
+	max_depth: u32,
+) -> RelayChainResult<Option<Vec<IncludedCandidate<Block>>>> {
+	// Wait for the relay chain to finish syncing, we would miss the recent candidates otherwise.
+	let mut imported_blocks = relay_chain_client.import_notification_stream().await?;
+	while relay_chain_client.is_major_syncing().await? {
+		if imported_blocks.next().await.is_none() {
+			break
+		}
+	}
+
+	let mut included = Vec::new();
+	let mut hash = relay_chain_client.finalized_block_hash().await?;
+
+	for _ in 0..max_depth {
+		let Some(relay_header) = relay_chain_client.header(BlockId::Hash(hash)).await? else {
+			return Ok(None)
+		};
+
+		for event in relay_chain_client.candidate_events(hash).await? {
+			let CandidateEvent::CandidateIncluded(receipt, head_data, _, _) = event else {
+				continue
+			};
+			if receipt.descriptor.para_id != para_id {
+				continue
+			}
+
+			let header = Block::Header::decode(&mut &head_data.0[..])?;
+			if *header.number() <= best_number {
+				return Ok(Some(included))
+			}
+
+			// The candidate was backed in the parent of the block including it.
+			let session_index =
+				relay_chain_client.session_index_for_child(relay_header.parent_hash).await?;
+			included.push((receipt, session_index, header));
+		}
+
+		if relay_header.number == 0 {
+			return Ok(Some(included))
+		}
+		hash = relay_header.parent_hash;
+	}
+
+	Ok(None)
+}
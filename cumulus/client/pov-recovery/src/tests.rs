@@ -0,0 +1,243 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use codec::Encode;
+use cumulus_primitives_core::relay_chain::{
+	BlockNumber as RBlockNumber, CoreIndex, GroupIndex, Hash as PHash, HeadData, Header as PHeader,
+};
+use cumulus_relay_chain_interface::{
+	InboundDownwardMessage, InboundHrmpMessage, OccupiedCoreAssumption, PersistedValidationData,
+	StorageValue, ValidatorId,
+};
+use cumulus_test_service::runtime::{Block, Header};
+use futures::executor::block_on;
+use polkadot_primitives_test_helpers::dummy_candidate_receipt;
+use std::collections::BTreeMap;
+
+const PARA_ID: ParaId = ParaId::new(100);
+
+/// A relay chain that only knows its blocks and the candidates included in them.
+#[derive(Default)]
+struct Relaychain {
+	headers: HashMap<PHash, PHeader>,
+	events: HashMap<PHash, Vec<CandidateEvent>>,
+	finalized: PHash,
+}
+
+impl Relaychain {
+	/// Builds a relay chain of `length` blocks after genesis, where the block at the index of
+	/// each entry of `included` includes the parachain blocks of that entry.
+	fn new(length: RBlockNumber, included: &[(RBlockNumber, ParaId, u32)]) -> Self {
+		let mut relay_chain = Self::default();
+		let mut parent_hash = PHash::zero();
+
+		for number in 0..=length {
+			let header = PHeader::new(
+				number,
+				Default::default(),
+				Default::default(),
+				parent_hash,
+				Default::default(),
+			);
+			let hash = header.hash();
+
+			let events = included
+				.iter()
+				.filter(|(relay_number, _, _)| *relay_number == number)
+				.map(|(_, para_id, para_number)| {
+					let mut receipt = dummy_candidate_receipt(parent_hash);
+					receipt.descriptor.para_id = *para_id;
+					let head_data = HeadData(para_header(*para_number).encode());
+					CandidateEvent::CandidateIncluded(
+						receipt,
+						head_data,
+						CoreIndex(0),
+						GroupIndex(0),
+					)
+				})
+				.collect();
+
+			relay_chain.headers.insert(hash, header);
+			relay_chain.events.insert(hash, events);
+			relay_chain.finalized = hash;
+			parent_hash = hash;
+		}
+
+		relay_chain
+	}
+}
+
+fn para_header(number: u32) -> Header {
+	Header::new(
+		number,
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+	)
+}
+
+#[async_trait::async_trait]
+impl RelayChainInterface for Relaychain {
+	async fn validators(&self, _: PHash) -> RelayChainResult<Vec<ValidatorId>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn best_block_hash(&self) -> RelayChainResult<PHash> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn finalized_block_hash(&self) -> RelayChainResult<PHash> {
+		Ok(self.finalized)
+	}
+
+	async fn retrieve_dmq_contents(
+		&self,
+		_: ParaId,
+		_: PHash,
+	) -> RelayChainResult<Vec<InboundDownwardMessage>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn retrieve_all_inbound_hrmp_channel_contents(
+		&self,
+		_: ParaId,
+		_: PHash,
+	) -> RelayChainResult<BTreeMap<ParaId, Vec<InboundHrmpMessage>>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn persisted_validation_data(
+		&self,
+		_: PHash,
+		_: ParaId,
+		_: OccupiedCoreAssumption,
+	) -> RelayChainResult<Option<PersistedValidationData>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn candidate_pending_availability(
+		&self,
+		_: PHash,
+		_: ParaId,
+	) -> RelayChainResult<Option<CommittedCandidateReceipt>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn candidate_events(&self, hash: PHash) -> RelayChainResult<Vec<CandidateEvent>> {
+		Ok(self.events.get(&hash).cloned().unwrap_or_default())
+	}
+
+	async fn session_index_for_child(&self, _: PHash) -> RelayChainResult<SessionIndex> {
+		Ok(0)
+	}
+
+	async fn import_notification_stream(
+		&self,
+	) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+		Ok(Box::pin(futures::stream::pending()))
+	}
+
+	async fn finality_notification_stream(
+		&self,
+	) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn is_major_syncing(&self) -> RelayChainResult<bool> {
+		Ok(false)
+	}
+
+	fn overseer_handle(&self) -> RelayChainResult<OverseerHandle> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn get_storage_by_key(
+		&self,
+		_: PHash,
+		_: &[u8],
+	) -> RelayChainResult<Option<StorageValue>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn prove_read(
+		&self,
+		_: PHash,
+		_: &Vec<Vec<u8>>,
+	) -> RelayChainResult<sc_client_api::StorageProof> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn wait_for_block(&self, _: PHash) -> RelayChainResult<()> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn new_best_notification_stream(
+		&self,
+	) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+		unimplemented!("Not needed for test")
+	}
+
+	async fn header(&self, block_id: BlockId) -> RelayChainResult<Option<PHeader>> {
+		let BlockId::Hash(hash) = block_id else { unimplemented!("Not needed for test") };
+		Ok(self.headers.get(&hash).cloned())
+	}
+}
+
+fn included_numbers(relay_chain: Relaychain, best_number: u32, max_depth: u32) -> Option<Vec<u32>> {
+	let included =
+		block_on(included_candidates_since::<Block>(relay_chain, PARA_ID, best_number, max_depth))
+			.expect("The relay chain interface does not fail");
+
+	included.map(|included| included.into_iter().map(|(_, _, header)| header.number).collect())
+}
+
+#[test]
+fn included_candidates_are_returned_newest_first() {
+	let relay_chain = Relaychain::new(
+		10,
+		&[(2, PARA_ID, 1), (4, PARA_ID, 2), (6, PARA_ID, 3), (7, 200.into(), 3), (8, PARA_ID, 4)],
+	);
+
+	assert_eq!(included_numbers(relay_chain, 2, 100), Some(vec![4, 3]));
+}
+
+#[test]
+fn walk_reaches_relay_chain_genesis() {
+	let relay_chain = Relaychain::new(10, &[(2, PARA_ID, 1), (4, PARA_ID, 2), (6, 200.into(), 1)]);
+
+	assert_eq!(included_numbers(relay_chain, 0, 100), Some(vec![2, 1]));
+}
+
+#[test]
+fn walk_is_bounded_by_max_depth() {
+	let relay_chain = Relaychain::new(10, &[(2, PARA_ID, 1), (4, PARA_ID, 2), (8, PARA_ID, 3)]);
+
+	// Block 1 was included 8 blocks behind the finalized block.
+	assert_eq!(included_numbers(relay_chain, 1, 8), None);
+
+	let relay_chain = Relaychain::new(10, &[(2, PARA_ID, 1), (4, PARA_ID, 2), (8, PARA_ID, 3)]);
+	assert_eq!(included_numbers(relay_chain, 1, 9), Some(vec![3, 2]));
+}
+
+#[test]
+fn walk_stops_at_pruned_relay_chain_blocks() {
+	let mut relay_chain = Relaychain::new(10, &[(2, PARA_ID, 1), (8, PARA_ID, 2)]);
+	relay_chain.headers.retain(|_, header| header.number > 5);
+
+	assert_eq!(included_numbers(relay_chain, 1, 100), None);
+}
@@ -19,9 +19,9 @@ use std::{pin::Pin, sync::Arc, time::Duration};
 use async_trait::async_trait;
 use cumulus_primitives_core::{
 	relay_chain::{
-		runtime_api::ParachainHost, Block as PBlock, BlockId, CommittedCandidateReceipt,
-		Hash as PHash, Header as PHeader, InboundHrmpMessage, OccupiedCoreAssumption, SessionIndex,
-		ValidatorId,
+		runtime_api::ParachainHost, Block as PBlock, BlockId, CandidateEvent,
+		CommittedCandidateReceipt, Hash as PHash, Header as PHeader, InboundHrmpMessage,
+		OccupiedCoreAssumption, SessionIndex, ValidatorId,
 	},
 	InboundDownwardMessage, ParaId, PersistedValidationData,
 };
@@ -123,6 +123,10 @@ impl RelayChainInterface for RelayChainInProcessInterface {
 		Ok(self.full_client.runtime_api().candidate_pending_availability(hash, para_id)?)
 	}
 
+	async fn candidate_events(&self, hash: PHash) -> RelayChainResult<Vec<CandidateEvent>> {
+		Ok(self.full_client.runtime_api().candidate_events(hash)?)
+	}
+
 	async fn session_index_for_child(&self, hash: PHash) -> RelayChainResult<SessionIndex> {
 		Ok(self.full_client.runtime_api().session_index_for_child(hash)?)
 	}
@@ -29,8 +29,8 @@ use sp_api::ApiError;
 use cumulus_primitives_core::relay_chain::BlockId;
 pub use cumulus_primitives_core::{
 	relay_chain::{
-		CommittedCandidateReceipt, Hash as PHash, Header as PHeader, InboundHrmpMessage,
-		OccupiedCoreAssumption, SessionIndex, ValidatorId,
+		CandidateEvent, CommittedCandidateReceipt, Hash as PHash, Header as PHeader,
+		InboundHrmpMessage, OccupiedCoreAssumption, SessionIndex, ValidatorId,
 	},
 	InboundDownwardMessage, ParaId, PersistedValidationData,
 };
@@ -157,6 +157,10 @@ pub trait RelayChainInterface: Send + Sync {
 		para_id: ParaId,
 	) -> RelayChainResult<Option<CommittedCandidateReceipt>>;
 
+	/// Get the candidate events emitted in the given relay chain block, e.g. the candidates that
+	/// were backed or included in it.
+	async fn candidate_events(&self, block_id: PHash) -> RelayChainResult<Vec<CandidateEvent>>;
+
 	/// Returns the session index expected at a child of the block.
 	async fn session_index_for_child(&self, block_id: PHash) -> RelayChainResult<SessionIndex>;
 
@@ -236,6 +240,10 @@ where
 		(**self).candidate_pending_availability(block_id, para_id).await
 	}
 
+	async fn candidate_events(&self, block_id: PHash) -> RelayChainResult<Vec<CandidateEvent>> {
+		(**self).candidate_events(block_id).await
+	}
+
 	async fn session_index_for_child(&self, block_id: PHash) -> RelayChainResult<SessionIndex> {
 		(**self).session_index_for_child(block_id).await
 	}
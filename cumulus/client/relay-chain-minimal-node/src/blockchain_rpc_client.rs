@@ -369,6 +369,14 @@ impl RuntimeApiSubsystemClient for BlockChainRpcClient {
 	async fn node_features(&self, at: Hash) -> Result<NodeFeatures, ApiError> {
 		Ok(self.rpc_client.parachain_host_node_features(at).await?)
 	}
+
+	async fn para_async_backing_params(
+		&self,
+		at: Hash,
+		para_id: cumulus_primitives_core::ParaId,
+	) -> Result<AsyncBackingParams, ApiError> {
+		Ok(self.rpc_client.parachain_host_para_async_backing_params(at, para_id).await?)
+	}
 }
 
 #[async_trait::async_trait]
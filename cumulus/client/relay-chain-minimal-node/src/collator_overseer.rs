@@ -64,6 +64,8 @@ pub(crate) struct CollatorOverseerGenArgs<'a> {
 	pub collation_req_receiver_v1: IncomingRequestReceiver<v1::CollationFetchingRequest>,
 	/// Receiver for collation request protocol v2.
 	pub collation_req_receiver_v2: IncomingRequestReceiver<v2::CollationFetchingRequest>,
+	/// Receiver for collation chunk request protocol v2.
+	pub collation_chunk_req_receiver_v2: IncomingRequestReceiver<v2::CollationChunkFetchingRequest>,
 	/// Receiver for availability request protocol
 	pub available_data_req_receiver: IncomingRequestReceiver<AvailableDataFetchingRequest>,
 	/// Prometheus registry, commonly used for production systems, less so for test.
@@ -87,6 +89,7 @@ fn build_overseer(
 		authority_discovery_service,
 		collation_req_receiver_v1,
 		collation_req_receiver_v2,
+		collation_chunk_req_receiver_v2,
 		available_data_req_receiver,
 		registry,
 		spawner,
@@ -120,6 +123,7 @@ fn build_overseer(
 				collator_pair,
 				request_receiver_v1: collation_req_receiver_v1,
 				request_receiver_v2: collation_req_receiver_v2,
+				chunk_request_receiver_v2: collation_chunk_req_receiver_v2,
 				metrics: Metrics::register(registry)?,
 			};
 			CollatorProtocolSubsystem::new(side)
@@ -155,6 +159,7 @@ fn build_overseer(
 		.activation_external_listeners(Default::default())
 		.span_per_active_leaf(Default::default())
 		.active_leaves(Default::default())
+		.subsystem_load(Default::default())
 		.supports_parachains(runtime_client)
 		.metrics(Metrics::register(registry)?)
 		.spawner(spawner);
@@ -181,8 +181,12 @@ async fn new_minimal_relay_chain(
 	}
 
 	let request_protocol_names = ReqProtocolNames::new(genesis_hash, config.chain_spec.fork_id());
-	let (collation_req_receiver_v1, collation_req_receiver_v2, available_data_req_receiver) =
-		build_request_response_protocol_receivers(&request_protocol_names, &mut net_config);
+	let (
+		collation_req_receiver_v1,
+		collation_req_receiver_v2,
+		collation_chunk_req_receiver_v2,
+		available_data_req_receiver,
+	) = build_request_response_protocol_receivers(&request_protocol_names, &mut net_config);
 
 	let best_header = relay_chain_rpc_client
 		.chain_get_header(None)
@@ -212,6 +216,7 @@ async fn new_minimal_relay_chain(
 		authority_discovery_service,
 		collation_req_receiver_v1,
 		collation_req_receiver_v2,
+		collation_chunk_req_receiver_v2,
 		available_data_req_receiver,
 		registry: prometheus_registry,
 		spawner: task_manager.spawn_handle(),
@@ -234,6 +239,7 @@ fn build_request_response_protocol_receivers(
 ) -> (
 	IncomingRequestReceiver<v1::CollationFetchingRequest>,
 	IncomingRequestReceiver<v2::CollationFetchingRequest>,
+	IncomingRequestReceiver<v2::CollationChunkFetchingRequest>,
 	IncomingRequestReceiver<v1::AvailableDataFetchingRequest>,
 ) {
 	let (collation_req_receiver_v1, cfg) =
@@ -242,10 +248,18 @@ fn build_request_response_protocol_receivers(
 	let (collation_req_receiver_v2, cfg) =
 		IncomingRequest::get_config_receiver(request_protocol_names);
 	config.add_request_response_protocol(cfg);
+	let (collation_chunk_req_receiver_v2, cfg) =
+		IncomingRequest::get_config_receiver(request_protocol_names);
+	config.add_request_response_protocol(cfg);
 	let (available_data_req_receiver, cfg) =
 		IncomingRequest::get_config_receiver(request_protocol_names);
 	config.add_request_response_protocol(cfg);
 	let cfg = Protocol::ChunkFetchingV1.get_outbound_only_config(request_protocol_names);
 	config.add_request_response_protocol(cfg);
-	(collation_req_receiver_v1, collation_req_receiver_v2, available_data_req_receiver)
+	(
+		collation_req_receiver_v1,
+		collation_req_receiver_v2,
+		collation_chunk_req_receiver_v2,
+		available_data_req_receiver,
+	)
 }
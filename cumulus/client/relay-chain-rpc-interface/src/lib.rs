@@ -18,8 +18,8 @@ use async_trait::async_trait;
 use core::time::Duration;
 use cumulus_primitives_core::{
 	relay_chain::{
-		CommittedCandidateReceipt, Hash as RelayHash, Header as RelayHeader, InboundHrmpMessage,
-		OccupiedCoreAssumption, SessionIndex, ValidatorId,
+		CandidateEvent, CommittedCandidateReceipt, Hash as RelayHash, Header as RelayHeader,
+		InboundHrmpMessage, OccupiedCoreAssumption, SessionIndex, ValidatorId,
 	},
 	InboundDownwardMessage, ParaId, PersistedValidationData,
 };
@@ -120,6 +120,10 @@ impl RelayChainInterface for RelayChainRpcInterface {
 			.await
 	}
 
+	async fn candidate_events(&self, hash: RelayHash) -> RelayChainResult<Vec<CandidateEvent>> {
+		self.rpc_client.parachain_host_candidate_events(hash).await
+	}
+
 	async fn session_index_for_child(&self, hash: RelayHash) -> RelayChainResult<SessionIndex> {
 		self.rpc_client.parachain_host_session_index_for_child(hash).await
 	}
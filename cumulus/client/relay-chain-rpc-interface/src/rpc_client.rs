@@ -634,6 +634,20 @@ impl RelayChainRpcClient {
 			.await
 	}
 
+	#[allow(missing_docs)]
+	pub async fn parachain_host_para_async_backing_params(
+		&self,
+		at: RelayHash,
+		para_id: ParaId,
+	) -> Result<AsyncBackingParams, RelayChainError> {
+		self.call_remote_runtime_function(
+			"ParachainHost_para_async_backing_params",
+			at,
+			Some(para_id),
+		)
+		.await
+	}
+
 	fn send_register_message_to_worker(
 		&self,
 		message: RpcDispatcherMessage,
@@ -52,12 +52,21 @@ use sp_core::{traits::SpawnNamed, Decode};
 use sp_runtime::traits::{Block as BlockT, BlockIdTo, Header};
 use std::{sync::Arc, time::Duration};
 
+pub use cumulus_primitives_proof_size_hostfunction::storage_proof_size;
+
 // Given the sporadic nature of the explicit recovery operation and the
 // possibility to retry infinite times this value is more than enough.
 // In practice here we expect no more than one queued messages.
 const RECOVERY_CHAN_SIZE: usize = 8;
 const LOG_TARGET_SYNC: &str = "sync::cumulus";
 
+/// Host functions that should be used in parachain nodes.
+///
+/// Contains the standard substrate host functions, as well as the
+/// [`storage_proof_size`] host function used to reclaim unused proof size.
+pub type ParachainHostFunctions =
+	(storage_proof_size::HostFunctions, sp_io::SubstrateHostFunctions);
+
 /// A hint about how long the node should wait before attempting to recover missing block data
 /// from the data availability layer.
 pub enum DARecoveryProfile {
@@ -98,6 +107,9 @@ pub struct StartRelayChainTasksParams<'a, Block: BlockT, Client, RCInterface> {
 	pub relay_chain_slot_duration: Duration,
 	pub recovery_handle: Box<dyn RecoveryHandle>,
 	pub sync_service: Arc<SyncingService<Block>>,
+	/// Recover the blocks included on the relay chain since the local best block on startup,
+	/// walking back at most this many relay chain blocks.
+	pub recover_from_relay_chain: Option<u32>,
 }
 
 /// Parameters given to [`start_full_node`].
@@ -206,6 +218,7 @@ pub fn start_relay_chain_tasks<Block, Client, Backend, RCInterface>(
 		relay_chain_slot_duration,
 		recovery_handle,
 		sync_service,
+		recover_from_relay_chain,
 	}: StartRelayChainTasksParams<Block, Client, RCInterface>,
 ) -> sc_service::error::Result<()>
 where
@@ -268,6 +281,7 @@ where
 		para_id,
 		recovery_chan_rx,
 		sync_service,
+		recover_from_relay_chain,
 	);
 
 	task_manager
@@ -319,6 +333,7 @@ where
 		recovery_handle,
 		sync_service,
 		da_recovery_profile: DARecoveryProfile::FullNode,
+		recover_from_relay_chain: None,
 	})
 }
 
@@ -53,7 +53,7 @@ use sp_runtime::{
 		InvalidTransaction, TransactionLongevity, TransactionSource, TransactionValidity,
 		ValidTransaction,
 	},
-	BoundedSlice, DispatchError, FixedU128, RuntimeDebug, Saturating,
+	BoundedVec, DispatchError, FixedU128, RuntimeDebug, Saturating,
 };
 use sp_std::{cmp, collections::btree_map::BTreeMap, prelude::*};
 use xcm::latest::XcmHash;
@@ -186,6 +186,11 @@ where
 /// The max length of a DMP message.
 pub type MaxDmpMessageLenOf<T> = <<T as Config>::DmpQueue as HandleMessage>::MaxMessageLen;
 
+/// The maximum number of deferred downward messages.
+///
+/// Once reached, downward messages are enqueued regardless of the DMP weight limit.
+pub const MAX_DEFERRED_DOWNWARD_MESSAGES: u32 = 1024;
+
 pub mod ump_constants {
 	use super::FixedU128;
 
@@ -232,6 +237,9 @@ pub mod pallet {
 		type DmpQueue: HandleMessage;
 
 		/// The weight we reserve at the beginning of the block for processing DMP messages.
+		///
+		/// Can be overridden with [`Pallet::set_reserved_dmp_weight`], which also limits the
+		/// downward messages enqueued per block to the overridden weight.
 		type ReservedDmpWeight: Get<Weight>;
 
 		/// The message handler that will be invoked when messages are received via XCMP.
@@ -240,6 +248,8 @@ pub mod pallet {
 		type XcmpMessageHandler: XcmpMessageHandler;
 
 		/// The weight we reserve at the beginning of the block for processing XCMP messages.
+		///
+		/// Can be overridden with [`Pallet::set_reserved_xcmp_weight`].
 		type ReservedXcmpWeight: Get<Weight>;
 
 		/// Something that can check the associated relay parent block number.
@@ -382,7 +392,8 @@ pub mod pallet {
 
 			let maximum_channels = host_config
 				.hrmp_max_message_num_per_candidate
-				.min(<AnnouncedHrmpMessagesPerCandidate<T>>::take()) as usize;
+				.min(<AnnouncedHrmpMessagesPerCandidate<T>>::take())
+				as usize;
 
 			// Note: this internally calls the `GetChannelInfo` implementation for this
 			// pallet, which draws on the `RelevantMessagingState`. That in turn has
@@ -512,6 +523,24 @@ pub mod pallet {
 
 			weight
 		}
+
+		/// Enqueues downward messages which were deferred because they didn't fit into the DMP
+		/// weight limit, as far as the remaining weight of the block allows.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let read = T::DbWeight::get().reads(1);
+			if !remaining_weight.all_gte(read) {
+				return Weight::zero()
+			}
+
+			if DeferredDownwardMessageQueue::<T>::get().1 == 0 {
+				return read
+			}
+
+			read.saturating_add(Self::enqueue_downward_messages(
+				Vec::new(),
+				Some(remaining_weight.saturating_sub(read)),
+			))
+		}
 	}
 
 	#[pallet::call]
@@ -710,6 +739,44 @@ pub mod pallet {
 			AuthorizedUpgrade::<T>::kill();
 			Ok(Pays::No.into())
 		}
+
+		/// Set the weight reserved at the beginning of each block for enqueueing downward
+		/// messages, overriding [`Config::ReservedDmpWeight`]. `None` restores the configured
+		/// default.
+		///
+		/// While set, the downward messages which don't fit into this weight are deferred to
+		/// `on_idle` and the following blocks.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational))]
+		pub fn set_reserved_dmp_weight(
+			origin: OriginFor<T>,
+			weight: Option<Weight>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ReservedDmpWeightOverride::<T>::set(weight);
+
+			Self::deposit_event(Event::ReservedDmpWeightSet { weight });
+			Ok(())
+		}
+
+		/// Set the weight reserved at the beginning of each block for processing XCMP messages,
+		/// overriding [`Config::ReservedXcmpWeight`]. `None` restores the configured default.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational))]
+		pub fn set_reserved_xcmp_weight(
+			origin: OriginFor<T>,
+			weight: Option<Weight>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ReservedXcmpWeightOverride::<T>::set(weight);
+
+			Self::deposit_event(Event::ReservedXcmpWeightSet { weight });
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -729,6 +796,12 @@ pub mod pallet {
 		DownwardMessagesProcessed { weight_used: Weight, dmq_head: relay_chain::Hash },
 		/// An upward message was sent to the relay chain.
 		UpwardMessageSent { message_hash: Option<XcmHash> },
+		/// Some downward messages didn't fit into the reserved weight and have been deferred.
+		DownwardMessagesDeferred { count: u32 },
+		/// The weight reserved for processing DMP messages has been set.
+		ReservedDmpWeightSet { weight: Option<Weight> },
+		/// The weight reserved for processing XCMP messages has been set.
+		ReservedXcmpWeightSet { weight: Option<Weight> },
 	}
 
 	#[pallet::error]
@@ -928,6 +1001,21 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type ReservedDmpWeightOverride<T: Config> = StorageValue<_, Weight>;
 
+	/// Downward messages which were received but not yet enqueued, because they didn't fit into
+	/// the DMP weight limit, keyed by their position in the queue of deferred messages.
+	///
+	/// They are enqueued in `on_idle` and before any newly received messages in the following
+	/// blocks.
+	#[pallet::storage]
+	pub(super) type DeferredDownwardMessages<T: Config> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<u8, MaxDmpMessageLenOf<T>>>;
+
+	/// The position of the first deferred downward message and the number of deferred messages,
+	/// which is at most [`MAX_DEFERRED_DOWNWARD_MESSAGES`].
+	#[pallet::storage]
+	pub(super) type DeferredDownwardMessageQueue<T: Config> =
+		StorageValue<_, (u32, u32), ValueQuery>;
+
 	/// The next authorized upgrade, if there is one.
 	#[pallet::storage]
 	pub(super) type AuthorizedUpgrade<T: Config> = StorageValue<_, CodeUpgradeAuthorization<T>>;
@@ -1146,7 +1234,9 @@ impl<T: Config> Pallet<T> {
 	/// Enqueue all inbound downward messages relayed by the collator into the MQ pallet.
 	///
 	/// Checks if the sequence of the messages is valid, dispatches them and communicates the
-	/// number of processed messages to the collator via a storage update.
+	/// number of processed messages to the collator via a storage update. Messages deferred from
+	/// previous blocks are enqueued first, and messages which don't fit into the DMP weight limit
+	/// set through [`Pallet::set_reserved_dmp_weight`] are deferred.
 	///
 	/// # Panics
 	///
@@ -1159,7 +1249,6 @@ impl<T: Config> Pallet<T> {
 		let dm_count = downward_messages.len() as u32;
 		let mut dmq_head = <LastDmqMqcHead<T>>::get();
 
-		let weight_used = T::WeightInfo::enqueue_inbound_downward_messages(dm_count);
 		if dm_count != 0 {
 			Self::deposit_event(Event::DownwardMessagesReceived { count: dm_count });
 
@@ -1167,18 +1256,13 @@ impl<T: Config> Pallet<T> {
 			for m in &downward_messages {
 				dmq_head.extend_downward(m);
 			}
-			let bounded = downward_messages
-				.iter()
-				// Note: we are not using `.defensive()` here since that prints the whole value to
-				// console. In case that the message is too long, this clogs up the log quite badly.
-				.filter_map(|m| match BoundedSlice::try_from(&m.msg[..]) {
-					Ok(bounded) => Some(bounded),
-					Err(_) => {
-						defensive!("Inbound Downward message was too long; dropping");
-						None
-					},
-				});
-			T::DmpQueue::handle_messages(bounded);
+		}
+
+		let limit = <ReservedDmpWeightOverride<T>>::get();
+		let weight_used = Self::enqueue_downward_messages(downward_messages, limit)
+			.saturating_add(T::DbWeight::get().reads(1));
+
+		if dm_count != 0 {
 			<LastDmqMqcHead<T>>::put(&dmq_head);
 
 			Self::deposit_event(Event::DownwardMessagesProcessed {
@@ -1199,6 +1283,93 @@ impl<T: Config> Pallet<T> {
 		weight_used
 	}
 
+	/// Enqueue the deferred and the given downward messages into the MQ pallet in order, as far
+	/// as they fit into `limit`, and defer the remaining ones.
+	///
+	/// No more than [`MAX_DEFERRED_DOWNWARD_MESSAGES`] are deferred, the messages in front of them
+	/// are enqueued regardless of `limit`. Returns the weight used.
+	fn enqueue_downward_messages(
+		downward_messages: Vec<InboundDownwardMessage>,
+		limit: Option<Weight>,
+	) -> Weight {
+		// Reading and writing the queue of deferred messages.
+		let mut weight_used = T::DbWeight::get().reads_writes(1, 1);
+		let (mut head, mut deferred) = DeferredDownwardMessageQueue::<T>::get();
+		let pending = deferred.saturating_add(downward_messages.len() as u32);
+
+		let count = match limit {
+			None => pending,
+			Some(limit) => {
+				let limit = limit.saturating_sub(weight_used);
+				let fits = |n: u32| Self::enqueue_weight(n, n.min(deferred)).all_lte(limit);
+
+				// The weight is monotonic in the number of messages, so find the largest number
+				// that fits.
+				let (mut low, mut high) = (0, pending);
+				while low < high {
+					let mid = high - (high - low) / 2;
+					if fits(mid) {
+						low = mid;
+					} else {
+						high = mid - 1;
+					}
+				}
+				low.max(pending.saturating_sub(MAX_DEFERRED_DOWNWARD_MESSAGES))
+			},
+		};
+
+		let from_deferred = count.min(deferred);
+		let mut messages = Vec::with_capacity(count as usize);
+		for _ in 0..from_deferred {
+			messages.extend(DeferredDownwardMessages::<T>::take(head));
+			head = head.wrapping_add(1);
+		}
+		deferred -= from_deferred;
+
+		let mut downward_messages = downward_messages
+			.into_iter()
+			// Note: we are not using `.defensive()` here since that prints the whole value to
+			// console. In case that the message is too long, this clogs up the log quite badly.
+			.map(|m| match BoundedVec::try_from(m.msg) {
+				Ok(bounded) => Some(bounded),
+				Err(_) => {
+					defensive!("Inbound Downward message was too long; dropping");
+					None
+				},
+			});
+		messages
+			.extend(downward_messages.by_ref().take((count - from_deferred) as usize).flatten());
+		T::DmpQueue::handle_messages(messages.iter().map(|m| m.as_bounded_slice()));
+		weight_used.saturating_accrue(Self::enqueue_weight(count, from_deferred));
+
+		let mut newly_deferred = 0;
+		for message in downward_messages.flatten() {
+			DeferredDownwardMessages::<T>::insert(head.wrapping_add(deferred), message);
+			deferred += 1;
+			newly_deferred += 1;
+		}
+		if newly_deferred != 0 {
+			Self::deposit_event(Event::DownwardMessagesDeferred { count: newly_deferred });
+			weight_used.saturating_accrue(T::DbWeight::get().writes(newly_deferred as u64));
+		}
+
+		if deferred == 0 {
+			DeferredDownwardMessageQueue::<T>::kill();
+		} else {
+			DeferredDownwardMessageQueue::<T>::put((head, deferred));
+		}
+
+		weight_used
+	}
+
+	/// The weight of enqueueing `count` downward messages, the first `from_deferred` of which are
+	/// taken from the deferred messages.
+	fn enqueue_weight(count: u32, from_deferred: u32) -> Weight {
+		T::WeightInfo::enqueue_inbound_downward_messages(count).saturating_add(
+			T::DbWeight::get().reads_writes(from_deferred as u64, from_deferred as u64),
+		)
+	}
+
 	/// Process all inbound horizontal messages relayed by the collator.
 	///
 	/// This is similar to [`enqueue_inbound_downward_messages`], but works with multiple inbound
@@ -124,6 +124,8 @@ impl pallet_message_queue::Config for Test {
 	type QueuePausedQuery = ();
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
+	type MaxDeadLetters = sp_core::ConstU32<64>;
+	type DeadLetterOrigin = frame_system::EnsureRoot<u64>;
 	type ServiceWeight = MaxWeight;
 	type WeightInfo = ();
 }
@@ -20,7 +20,7 @@ use super::*;
 use crate::mock::*;
 
 use cumulus_primitives_core::{AbridgedHrmpChannel, InboundDownwardMessage, InboundHrmpMessage};
-use frame_support::{assert_ok, parameter_types, weights::Weight};
+use frame_support::{assert_ok, parameter_types, traits::OnIdle, weights::Weight};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use rand::Rng;
@@ -837,6 +837,87 @@ fn receive_dmp_after_pause() {
 		});
 }
 
+fn deferred_dmp() -> Vec<Vec<u8>> {
+	let (head, count) = DeferredDownwardMessageQueue::<Test>::get();
+	(0..count)
+		.map(|i| {
+			DeferredDownwardMessages::<Test>::get(head.wrapping_add(i))
+				.unwrap()
+				.into_inner()
+		})
+		.collect()
+}
+
+#[test]
+#[cfg(not(feature = "runtime-benchmarks"))]
+fn receive_dmp_defers_messages_over_reserved_weight() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, relay_block_num, sproof| match relay_block_num {
+			1 | 2 | 3 => {
+				sproof.dmq_mqc_head = Some(
+					MessageQueueChain::default()
+						.extend_downward(&mk_dmp(1))
+						.extend_downward(&mk_dmp(2))
+						.extend_downward(&mk_dmp(3))
+						.head(),
+				);
+			},
+			_ => unreachable!(),
+		})
+		.with_inherent_data(|_, relay_block_num, data| match relay_block_num {
+			1 => {
+				// Only reserve enough weight for a single message per block.
+				let weight = <() as WeightInfo>::enqueue_inbound_downward_messages(1)
+					.saturating_add(
+						<Test as frame_system::Config>::DbWeight::get().reads_writes(2, 2),
+					);
+				assert_ok!(ParachainSystem::set_reserved_dmp_weight(
+					RawOrigin::Root.into(),
+					Some(weight)
+				));
+				data.downward_messages.extend([mk_dmp(1), mk_dmp(2), mk_dmp(3)]);
+			},
+			2 | 3 => {
+				// no new messages
+			},
+			_ => unreachable!(),
+		})
+		.add(1, || {
+			HANDLED_DMP_MESSAGES.with(|m| {
+				let mut m = m.borrow_mut();
+				assert_eq!(&*m, &[mk_dmp(1).msg]);
+				m.clear();
+			});
+			assert_eq!(deferred_dmp(), vec![mk_dmp(2).msg, mk_dmp(3).msg]);
+			System::assert_has_event(RuntimeEvent::ParachainSystem(
+				crate::Event::DownwardMessagesDeferred { count: 2 },
+			));
+		})
+		.add(2, || {
+			// The deferred messages are carried over.
+			HANDLED_DMP_MESSAGES.with(|m| {
+				let mut m = m.borrow_mut();
+				assert_eq!(&*m, &[mk_dmp(2).msg]);
+				m.clear();
+			});
+			assert_eq!(deferred_dmp(), vec![mk_dmp(3).msg]);
+
+			// The remaining ones are enqueued if there is weight left in the block.
+			ParachainSystem::on_idle(2, Weight::zero());
+			assert_eq!(deferred_dmp(), vec![mk_dmp(3).msg]);
+			ParachainSystem::on_idle(2, Weight::MAX);
+			assert!(deferred_dmp().is_empty());
+			assert!(!DeferredDownwardMessageQueue::<Test>::exists());
+		})
+		.add(3, || {
+			HANDLED_DMP_MESSAGES.with(|m| {
+				let mut m = m.borrow_mut();
+				assert_eq!(&*m, &[mk_dmp(3).msg]);
+				m.clear();
+			});
+		});
+}
+
 // Sent up to 100 DMP messages per block over a period of 100 blocks.
 #[test]
 #[cfg(not(feature = "runtime-benchmarks"))]
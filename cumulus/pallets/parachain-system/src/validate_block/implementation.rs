@@ -16,11 +16,12 @@
 
 //! The actual implementation of the validate block functionality.
 
-use super::{trie_cache, MemoryOptimizedValidationParams};
+use super::{proof_size::SizeRecordingStorage, trie_cache, MemoryOptimizedValidationParams};
 use cumulus_primitives_core::{
 	relay_chain::Hash as RHash, ParachainBlockData, PersistedValidationData,
 };
 use cumulus_primitives_parachain_inherent::ParachainInherentData;
+use cumulus_primitives_proof_size_hostfunction::storage_proof_size;
 
 use polkadot_parachain_primitives::primitives::{
 	HeadData, RelayChainBlockNumber, ValidationResult,
@@ -34,15 +35,18 @@ use sp_externalities::{set_and_run_with_externalities, Externalities};
 use sp_io::KillStorageResult;
 use sp_runtime::traits::{Block as BlockT, Extrinsic, HashingFor, Header as HeaderT};
 use sp_std::prelude::*;
-use sp_trie::MemoryDB;
+use sp_trie::ProofSizeProvider;
 
-type TrieBackend<B> = sp_state_machine::TrieBackend<
-	MemoryDB<HashingFor<B>>,
+type TrieBackend<'a, B> = sp_state_machine::TrieBackend<
+	&'a SizeRecordingStorage<HashingFor<B>>,
 	HashingFor<B>,
 	trie_cache::CacheProvider<HashingFor<B>>,
 >;
 
-type Ext<'a, B> = sp_state_machine::Ext<'a, HashingFor<B>, TrieBackend<B>>;
+type Ext<'a, B> = sp_state_machine::Ext<'a, HashingFor<B>, TrieBackend<'a, B>>;
+
+// Provides the size of the storage proof read so far to `host_storage_proof_size`.
+environmental::environmental!(recorder: trait ProofSizeProvider);
 
 fn with_externalities<F: FnOnce(&mut dyn Externalities) -> R, R>(f: F) -> R {
 	sp_externalities::with_externalities(f).expect("Environmental externalities not set.")
@@ -120,11 +124,13 @@ where
 
 	sp_std::mem::drop(storage_proof);
 
+	let storage = SizeRecordingStorage::new(db);
+
 	let cache_provider = trie_cache::CacheProvider::new();
 	// We use the storage root of the `parent_head` to ensure that it is the correct root.
 	// This is already being done above while creating the in-memory db, but let's be paranoid!!
 	let backend = sp_state_machine::TrieBackendBuilder::new_with_cache(
-		db,
+		&storage,
 		*parent_header.state_root(),
 		cache_provider,
 	)
@@ -167,6 +173,7 @@ where
 			.replace_implementation(host_default_child_storage_next_key),
 		sp_io::offchain_index::host_set.replace_implementation(host_offchain_index_set),
 		sp_io::offchain_index::host_clear.replace_implementation(host_offchain_index_clear),
+		storage_proof_size::host_storage_proof_size.replace_implementation(host_storage_proof_size),
 	);
 
 	run_with_externalities::<B, _, _>(&backend, || {
@@ -190,6 +197,18 @@ where
 		}
 	});
 
+	// The proof size reported to the runtime must match the one seen by the block builder, which
+	// only recorded the nodes read while executing the block. So, we forget about the nodes read
+	// by `check_inherents` and use a fresh cache to make sure that every node read while executing
+	// the block is accounted for.
+	storage.reset();
+	let backend = sp_state_machine::TrieBackendBuilder::new_with_cache(
+		&storage,
+		*parent_header.state_root(),
+		trie_cache::CacheProvider::new(),
+	)
+	.build();
+
 	run_with_externalities::<B, _, _>(&backend, || {
 		let head_data = HeadData(block.header().encode());
 
@@ -267,13 +286,21 @@ fn validate_validation_data(
 
 /// Run the given closure with the externalities set.
 fn run_with_externalities<B: BlockT, R, F: FnOnce() -> R>(
-	backend: &TrieBackend<B>,
+	backend: &TrieBackend<'_, B>,
 	execute: F,
 ) -> R {
 	let mut overlay = sp_state_machine::OverlayedChanges::default();
 	let mut ext = Ext::<B>::new(&mut overlay, backend);
+	let mut proof_size_provider = *backend.backend_storage();
+
+	recorder::using(&mut proof_size_provider, || {
+		set_and_run_with_externalities(&mut ext, || execute())
+	})
+}
 
-	set_and_run_with_externalities(&mut ext, || execute())
+fn host_storage_proof_size() -> u64 {
+	recorder::with(|recorder| recorder.estimate_encoded_size() as u64)
+		.expect("Proof size provider is set by `run_with_externalities`; qed")
 }
 
 fn host_storage_read(key: &[u8], value_out: &mut [u8], value_offset: u32) -> Option<u32> {
@@ -22,6 +22,9 @@ pub mod implementation;
 #[cfg(test)]
 mod tests;
 
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+mod proof_size;
 #[cfg(not(feature = "std"))]
 #[doc(hidden)]
 mod trie_cache;
@@ -0,0 +1,80 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage used by `validate_block` that keeps track of the storage proof size.
+
+use codec::Encode;
+use sp_state_machine::{DefaultError, TrieBackendStorage};
+use sp_std::{
+	cell::{Cell, RefCell},
+	collections::btree_set::BTreeSet,
+};
+use sp_trie::{DBValue, MemoryDB, ProofSizeProvider};
+use trie_db::Hasher;
+
+/// Wrapper around the [`MemoryDB`] built from the storage proof of the block.
+///
+/// It sums up the encoded size of every distinct trie node read from the storage proof. This
+/// mirrors the proof size estimation of the recorder used while building the block, which is
+/// exposed to the runtime by the `storage_proof_size` host function.
+pub(crate) struct SizeRecordingStorage<H: Hasher> {
+	db: MemoryDB<H>,
+	seen_nodes: RefCell<BTreeSet<H::Out>>,
+	encoded_size: Cell<usize>,
+}
+
+impl<H: Hasher> SizeRecordingStorage<H> {
+	/// Constructs a new instance of [`SizeRecordingStorage`] that did not read any node yet.
+	pub fn new(db: MemoryDB<H>) -> Self {
+		Self { db, seen_nodes: Default::default(), encoded_size: Cell::new(0) }
+	}
+
+	/// Forget about all the nodes read so far.
+	pub fn reset(&self) {
+		self.seen_nodes.borrow_mut().clear();
+		self.encoded_size.set(0);
+	}
+}
+
+impl<H: Hasher> TrieBackendStorage<H> for SizeRecordingStorage<H> {
+	fn get(
+		&self,
+		key: &H::Out,
+		prefix: (&[u8], Option<u8>),
+	) -> Result<Option<DBValue>, DefaultError> {
+		let node = TrieBackendStorage::<H>::get(&self.db, key, prefix)?;
+
+		if let Some(ref node) = node {
+			if self.seen_nodes.borrow_mut().insert(*key) {
+				self.encoded_size
+					.set(self.encoded_size.get().saturating_add(node.encoded_size()));
+			}
+		}
+
+		Ok(node)
+	}
+}
+
+impl<H: Hasher> ProofSizeProvider for &SizeRecordingStorage<H> {
+	fn estimate_encoded_size(&self) -> usize {
+		self.encoded_size.get()
+	}
+}
+
+// This is safe here since we are single-threaded in WASM
+unsafe impl<H: Hasher> Send for SizeRecordingStorage<H> {}
+unsafe impl<H: Hasher> Sync for SizeRecordingStorage<H> {}
@@ -0,0 +1,216 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Weight Reclaim Pallet
+//!
+//! Allows parachain runtimes to reclaim the proof size over-estimated by the benchmarked weight of
+//! an extrinsic.
+//!
+//! ## Overview
+//!
+//! The `proof_size` of the benchmarked weight of an extrinsic is a worst case estimation. As the
+//! proof size of a parachain block is limited, the difference to the actually consumed proof size
+//! is wasted block space. The [`StorageWeightReclaim`] [`SignedExtension`] wraps the signed
+//! extensions of the runtime and measures the storage proof size consumed by the dispatch using the
+//! [`storage_proof_size`] host function. The difference to the benchmarked proof size is then
+//! credited back to the block weight tracked by `frame_system`. If the benchmarked proof size was
+//! too small, the difference is charged instead.
+//!
+//! ## Integration
+//!
+//! Wrap the signed extensions of the runtime in [`StorageWeightReclaim`], e.g.
+//! `StorageWeightReclaim<Runtime, (CheckNonce<Runtime>, CheckWeight<Runtime>, ..)>`. The encoding
+//! and the metadata of the wrapped extensions are left unchanged.
+//!
+//! The node needs to provide the [`storage_proof_size`] host function and record the storage
+//! proof while importing blocks, otherwise nothing is reclaimed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use cumulus_primitives_proof_size_hostfunction::{
+	storage_proof_size::storage_proof_size, PROOF_RECORDING_DISABLED,
+};
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, SignedExtensionMetadata,
+	},
+	transaction_validity::{TransactionValidity, TransactionValidityError},
+	DispatchResult,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+
+const LOG_TARGET: &str = "runtime::storage_reclaim";
+
+#[frame_support::pallet]
+pub mod pallet {
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+}
+
+/// A [`SignedExtension`] that reclaims the unused proof size of the dispatch of an extrinsic.
+///
+/// It wraps the signed extensions `S` of the runtime, which are applied as usual.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct StorageWeightReclaim<T: Config, S: SignedExtension>(pub S, PhantomData<T>);
+
+impl<T: Config, S: SignedExtension> sp_std::fmt::Debug for StorageWeightReclaim<T, S> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		f.debug_tuple("StorageWeightReclaim").field(&self.0).finish()
+	}
+}
+
+impl<T: Config, S: SignedExtension> StorageWeightReclaim<T, S> {
+	/// Wrap the given signed extensions.
+	pub fn new(s: S) -> Self {
+		Self(s, PhantomData)
+	}
+}
+
+impl<T: Config, S: SignedExtension> From<S> for StorageWeightReclaim<T, S> {
+	fn from(s: S) -> Self {
+		Self::new(s)
+	}
+}
+
+/// The current storage proof size, or `None` if proof recording is disabled.
+fn proof_size() -> Option<u64> {
+	let proof_size = storage_proof_size();
+	(proof_size != PROOF_RECORDING_DISABLED).then_some(proof_size)
+}
+
+impl<T: Config + Send + Sync, S: SignedExtension> SignedExtension for StorageWeightReclaim<T, S>
+where
+	S::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	type AccountId = S::AccountId;
+	type Call = S::Call;
+	type AdditionalSigned = S::AdditionalSigned;
+	/// The storage proof size before the dispatch and the `Pre` of the wrapped extensions.
+	type Pre = (Option<u64>, S::Pre);
+	const IDENTIFIER: &'static str = "StorageWeightReclaim";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		self.0.additional_signed()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		self.0.validate(who, call, info, len)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let pre = self.0.pre_dispatch(who, call, info, len)?;
+		Ok((proof_size(), pre))
+	}
+
+	fn validate_unsigned(
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		S::validate_unsigned(call, info, len)
+	}
+
+	fn pre_dispatch_unsigned(
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		S::pre_dispatch_unsigned(call, info, len)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (proof_size_before, pre) = match pre {
+			Some((proof_size_before, pre)) => (proof_size_before, Some(pre)),
+			None => (None, None),
+		};
+		// Measure before the wrapped extensions, their storage accesses are not part of the
+		// benchmarked weight of the dispatch.
+		let proof_size_after = proof_size();
+
+		S::post_dispatch(pre, info, post_info, len, result)?;
+
+		if let (Some(before), Some(after)) = (proof_size_before, proof_size_after) {
+			Pallet::<T>::reclaim_proof_size(info, post_info, after.saturating_sub(before));
+		}
+
+		Ok(())
+	}
+
+	fn metadata() -> Vec<SignedExtensionMetadata> {
+		S::metadata()
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Correct the proof size of the block weight by the difference between the benchmarked and the
+	/// `consumed` proof size of a dispatch.
+	fn reclaim_proof_size(info: &DispatchInfo, post_info: &PostDispatchInfo, consumed: u64) {
+		let benchmarked = post_info.calc_actual_weight(info).proof_size();
+		let difference = Weight::from_parts(0, benchmarked.abs_diff(consumed));
+
+		frame_system::BlockWeight::<T>::mutate(|current| {
+			if consumed > benchmarked {
+				log::error!(
+					target: LOG_TARGET,
+					"Benchmarked proof size smaller than consumed proof size. \
+					 benchmarked: {benchmarked} consumed: {consumed}",
+				);
+				current.accrue(difference, info.class)
+			} else {
+				log::trace!(
+					target: LOG_TARGET,
+					"Reclaiming unused proof size. benchmarked: {benchmarked} consumed: {consumed}",
+				);
+				current.reduce(difference, info.class)
+			}
+		});
+	}
+}
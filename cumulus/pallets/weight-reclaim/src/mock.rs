@@ -0,0 +1,96 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate as cumulus_pallet_weight_reclaim;
+
+use frame_support::{derive_impl, parameter_types};
+use sp_runtime::BuildStorage;
+use sp_trie::{proof_size_extension::ProofSizeExt, ProofSizeProvider};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+impl cumulus_pallet_weight_reclaim::Config for Test {}
+
+parameter_types! {
+	pub static ProofSize: usize = 0;
+	pub static PostDispatchCount: u32 = 0;
+}
+
+/// Provides the proof size set in [`ProofSize`].
+pub struct TestRecorder;
+
+impl ProofSizeProvider for TestRecorder {
+	fn estimate_encoded_size(&self) -> usize {
+		ProofSize::get()
+	}
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct DummyExtension;
+
+impl SignedExtension for DummyExtension {
+	type AccountId = u64;
+	type Call = RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "DummyExtension";
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn post_dispatch(
+		_pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Self::Call>,
+		_post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		_result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		PostDispatchCount::mutate(|c| *c += 1);
+		Ok(())
+	}
+}
+
+pub fn new_test_ext(proof_recording: bool) -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	if proof_recording {
+		ext.register_extension(ProofSizeExt::new(TestRecorder));
+	}
+	ext
+}
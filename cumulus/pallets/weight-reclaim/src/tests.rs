@@ -0,0 +1,118 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{
+	assert_ok,
+	dispatch::{DispatchClass, Pays},
+};
+
+type Reclaim = StorageWeightReclaim<Test, DummyExtension>;
+
+fn call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn info(proof_size: u64) -> DispatchInfo {
+	DispatchInfo { weight: Weight::from_parts(0, proof_size), ..Default::default() }
+}
+
+fn post_info(actual_proof_size: Option<u64>) -> PostDispatchInfo {
+	PostDispatchInfo {
+		actual_weight: actual_proof_size.map(|p| Weight::from_parts(0, p)),
+		pays_fee: Pays::Yes,
+	}
+}
+
+fn set_block_proof_size(proof_size: u64) {
+	frame_system::BlockWeight::<Test>::mutate(|current| {
+		current.set(Weight::from_parts(0, proof_size), DispatchClass::Normal)
+	});
+}
+
+fn block_proof_size() -> u64 {
+	frame_system::BlockWeight::<Test>::get().get(DispatchClass::Normal).proof_size()
+}
+
+/// Dispatch through the extension, consuming `consumed` proof size.
+fn dispatch(info: &DispatchInfo, post_info: &PostDispatchInfo, consumed: usize) {
+	ProofSize::set(1000);
+	let pre = Reclaim::new(DummyExtension).pre_dispatch(&1, &call(), info, 0).unwrap();
+	ProofSize::set(1000 + consumed);
+	assert_ok!(Reclaim::post_dispatch(Some(pre), info, post_info, 0, &Ok(())));
+}
+
+#[test]
+fn reclaims_unused_proof_size() {
+	new_test_ext(true).execute_with(|| {
+		set_block_proof_size(1000);
+
+		dispatch(&info(500), &post_info(None), 200);
+
+		assert_eq!(block_proof_size(), 700);
+	});
+}
+
+#[test]
+fn charges_under_estimated_proof_size() {
+	new_test_ext(true).execute_with(|| {
+		set_block_proof_size(1000);
+
+		dispatch(&info(100), &post_info(None), 300);
+
+		assert_eq!(block_proof_size(), 1200);
+	});
+}
+
+#[test]
+fn uses_actual_weight_of_dispatch() {
+	new_test_ext(true).execute_with(|| {
+		set_block_proof_size(1000);
+
+		dispatch(&info(500), &post_info(Some(300)), 200);
+
+		assert_eq!(block_proof_size(), 900);
+	});
+}
+
+#[test]
+fn does_nothing_without_proof_recording() {
+	new_test_ext(false).execute_with(|| {
+		set_block_proof_size(1000);
+
+		dispatch(&info(500), &post_info(None), 200);
+
+		assert_eq!(block_proof_size(), 1000);
+	});
+}
+
+#[test]
+fn wrapped_extension_is_applied() {
+	new_test_ext(true).execute_with(|| {
+		PostDispatchCount::set(0);
+
+		dispatch(&info(500), &post_info(None), 200);
+		assert_ok!(Reclaim::post_dispatch(None, &info(500), &post_info(None), 0, &Ok(())));
+
+		assert_eq!(PostDispatchCount::get(), 2);
+		assert_eq!(Reclaim::new(DummyExtension).encode(), DummyExtension.encode());
+		assert_eq!(
+			Reclaim::metadata().into_iter().map(|m| m.identifier).collect::<Vec<_>>(),
+			vec!["DummyExtension"],
+		);
+	});
+}
@@ -0,0 +1,193 @@
+//! Setup code for [`super::command`] which would otherwise bloat that module.
+//!
+//! Should only be used for benchmarking as it may break in other contexts.
+
+use crate::service::ParachainClient;
+
+use cumulus_primitives_core::ParaId;
+use cumulus_primitives_parachain_inherent::{
+	MockValidationDataInherentDataProvider, MockXcmConfig,
+};
+use parachain_template_runtime as runtime;
+use runtime::{opaque::Block, AccountId, Balance, Runtime};
+use sc_client_api::BlockBackend;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Encode, Get, Pair};
+use sp_inherents::CreateInherentDataProviders;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{generic::SignedPayload, OpaqueExtrinsic, SaturatedConversion};
+
+use std::{sync::Arc, time::Duration};
+
+/// Generates extrinsics for the `benchmark overhead` command.
+///
+/// Note: Should only be used for benchmarking.
+pub struct RemarkBuilder {
+	client: Arc<ParachainClient>,
+}
+
+impl RemarkBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<ParachainClient>) -> Self {
+		Self { client }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for RemarkBuilder {
+	fn pallet(&self) -> &str {
+		"system"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"remark"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = Sr25519Keyring::Bob.pair();
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			frame_system::Call::<Runtime>::remark { remark: vec![] }.into(),
+			nonce,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Generates `Balances::TransferKeepAlive` extrinsics for the benchmarks.
+///
+/// Note: Should only be used for benchmarking.
+pub struct TransferKeepAliveBuilder {
+	client: Arc<ParachainClient>,
+	dest: AccountId,
+	value: Balance,
+}
+
+impl TransferKeepAliveBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<ParachainClient>, dest: AccountId, value: Balance) -> Self {
+		Self { client, dest, value }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for TransferKeepAliveBuilder {
+	fn pallet(&self) -> &str {
+		"balances"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"transfer_keep_alive"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = Sr25519Keyring::Bob.pair();
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			pallet_balances::Call::<Runtime>::transfer_keep_alive {
+				dest: self.dest.clone().into(),
+				value: self.value,
+			}
+			.into(),
+			nonce,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Create a transaction using the given `call`.
+///
+/// Note: Should only be used for benchmarking.
+pub fn create_benchmark_extrinsic(
+	client: &ParachainClient,
+	sender: sp_core::sr25519::Pair,
+	call: runtime::RuntimeCall,
+	nonce: u32,
+) -> runtime::UncheckedExtrinsic {
+	let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+	let best_hash = client.chain_info().best_hash;
+	let best_block = client.chain_info().best_number;
+
+	let period = <Runtime as frame_system::Config>::BlockHashCount::get()
+		.checked_next_power_of_two()
+		.map(|c| c / 2)
+		.unwrap_or(2) as u64;
+	let extra: runtime::SignedExtra = runtime::SignedExtra::new((
+		frame_system::CheckNonZeroSender::<Runtime>::new(),
+		frame_system::CheckSpecVersion::<Runtime>::new(),
+		frame_system::CheckTxVersion::<Runtime>::new(),
+		frame_system::CheckGenesis::<Runtime>::new(),
+		frame_system::CheckEra::<Runtime>::from(sp_runtime::generic::Era::mortal(
+			period,
+			best_block.saturated_into(),
+		)),
+		frame_system::CheckNonce::<Runtime>::from(nonce),
+		frame_system::CheckWeight::<Runtime>::new(),
+		pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+	));
+
+	let raw_payload = SignedPayload::from_raw(
+		call.clone(),
+		extra.clone(),
+		(
+			(),
+			runtime::VERSION.spec_version,
+			runtime::VERSION.transaction_version,
+			genesis_hash,
+			best_hash,
+			(),
+			(),
+			(),
+		),
+	);
+	let signature = raw_payload.using_encoded(|e| sender.sign(e));
+
+	runtime::UncheckedExtrinsic::new_signed(
+		call,
+		sp_runtime::AccountId32::from(sender.public()).into(),
+		runtime::Signature::Sr25519(signature),
+		extra,
+	)
+}
+
+/// Creates the inherent data providers for the `benchmark overhead` and `benchmark extrinsic`
+/// commands.
+///
+/// Besides the timestamp, every parachain block requires the `set_validation_data` inherent. Its
+/// data is mocked on top of the parent block, as the benchmarks are not backed by a relay chain.
+///
+/// Note: Should only be used for benchmarking.
+pub fn inherent_benchmark_data_providers(
+	client: Arc<ParachainClient>,
+	para_id: ParaId,
+) -> impl CreateInherentDataProviders<Block, ()> {
+	move |parent, ()| {
+		let client = client.clone();
+		async move {
+			let parent_number = client.number(parent)?.ok_or("Parent block is unknown")?;
+			let timestamp =
+				sp_timestamp::InherentDataProvider::new(Duration::from_millis(0).into());
+			let validation_data = MockValidationDataInherentDataProvider {
+				current_para_block: parent_number + 1,
+				relay_offset: 1000,
+				relay_blocks_per_para_block: 2,
+				para_blocks_per_relay_epoch: 10,
+				relay_randomness_config: (),
+				xcm_config: MockXcmConfig::new::<Block, _, _>(
+					&*client,
+					parent,
+					para_id,
+					Default::default(),
+				),
+				raw_downward_messages: vec![],
+				raw_horizontal_messages: vec![],
+			};
+
+			Ok::<_, Box<dyn std::error::Error + Send + Sync>>((timestamp, validation_data))
+		}
+	}
+}
@@ -1,17 +1,19 @@
 use std::net::SocketAddr;
 
 use cumulus_primitives_core::ParaId;
-use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
+use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, SUBSTRATE_REFERENCE_HARDWARE};
 use log::info;
-use parachain_template_runtime::Block;
+use parachain_template_runtime::{Block, EXISTENTIAL_DEPOSIT};
 use sc_cli::{
 	ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams,
 	NetworkParams, Result, SharedParams, SubstrateCli,
 };
 use sc_service::config::{BasePath, PrometheusConfig};
+use sp_keyring::Sr25519Keyring;
 use sp_runtime::traits::AccountIdConversion;
 
 use crate::{
+	benchmarking::{inherent_benchmark_data_providers, RemarkBuilder, TransferKeepAliveBuilder},
 	chain_spec,
 	cli::{Cli, RelayChainCli, Subcommand},
 	service::new_partial,
@@ -208,6 +210,47 @@ pub fn run() -> Result<()> {
 					let storage = partials.backend.expose_storage();
 					cmd.run(config, partials.client.clone(), db, storage)
 				}),
+				BenchmarkCmd::Overhead(cmd) => runner.sync_run(|config| {
+					let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
+						.map(|e| e.para_id)
+						.ok_or("Could not find parachain ID in chain-spec.")?;
+					let partials = new_partial(&config)?;
+					let ext_builder = RemarkBuilder::new(partials.client.clone());
+					let inherent_data_providers =
+						inherent_benchmark_data_providers(partials.client.clone(), para_id.into());
+
+					cmd.run_with_inherent_data_providers(
+						config,
+						partials.client,
+						inherent_data_providers,
+						Vec::new(),
+						&ext_builder,
+					)
+				}),
+				BenchmarkCmd::Extrinsic(cmd) => runner.sync_run(|config| {
+					let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
+						.map(|e| e.para_id)
+						.ok_or("Could not find parachain ID in chain-spec.")?;
+					let partials = new_partial(&config)?;
+					// Register the *Remark* and *TKA* builders.
+					let ext_factory = ExtrinsicFactory(vec![
+						Box::new(RemarkBuilder::new(partials.client.clone())),
+						Box::new(TransferKeepAliveBuilder::new(
+							partials.client.clone(),
+							Sr25519Keyring::Alice.to_account_id(),
+							EXISTENTIAL_DEPOSIT,
+						)),
+					]);
+					let inherent_data_providers =
+						inherent_benchmark_data_providers(partials.client.clone(), para_id.into());
+
+					cmd.run_with_inherent_data_providers(
+						partials.client,
+						inherent_data_providers,
+						Vec::new(),
+						&ext_factory,
+					)
+				}),
 				BenchmarkCmd::Machine(cmd) =>
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone())),
 				// NOTE: this allows the Client to leniently implement
@@ -135,6 +135,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = PolkadotXcm;
 	type Aliasers = Nothing;
 }
 
@@ -178,6 +179,11 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type ForeignAssetRegistrationOrigin = frame_system::EnsureNever<MultiLocation>;
+	type ForeignAssetRegistrar = ();
+	type TeleportAccounting = ();
+	type MaxTransferReceipts = ();
+	type UnixTime = crate::Timestamp;
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
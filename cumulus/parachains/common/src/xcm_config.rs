@@ -16,12 +16,21 @@
 use crate::impls::AccountIdOf;
 use cumulus_primitives_core::{IsSystem, ParaId};
 use frame_support::{
-	traits::{fungibles::Inspect, tokens::ConversionToAssetBalance, Contains, ContainsPair},
+	traits::{
+		fungibles::Inspect, tokens::ConversionToAssetBalance, ConstU32, Contains, ContainsPair,
+		Everything,
+	},
 	weights::Weight,
 };
 use sp_runtime::traits::Get;
 use sp_std::marker::PhantomData;
 use xcm::latest::prelude::*;
+use xcm_builder::{
+	AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowLimitedUnpaidExecutionFrom,
+	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain,
+	DenyThenTry, TakeWeightCredit, TrailingSetTopicAsId, WithComputedOrigin,
+	XcmFeeManagerFromComponents, XcmFeeToAccount,
+};
 
 /// A `ChargeFeeInFungibles` implementation that converts the output of
 /// a given WeightToFee implementation an amount charged in
@@ -79,6 +88,8 @@ impl<Location: Get<MultiLocation>> ContainsPair<MultiAsset, MultiLocation>
 	}
 }
 
+/// Accepts the Relay Chain and the system parachains matched by `SystemParachainMatcher`, except
+/// for the local parachain itself.
 pub struct RelayOrOtherSystemParachains<
 	SystemParachainMatcher: Contains<MultiLocation>,
 	Runtime: parachain_info::Config,
@@ -100,6 +111,56 @@ impl<SystemParachainMatcher: Contains<MultiLocation>, Runtime: parachain_info::C
 	}
 }
 
+/// The fee manager shared by the system parachains: nothing is charged to the
+/// `WaivedLocations`, and the fees of everybody else are deposited to `FeeAccount` through the
+/// `AssetTransactor`.
+///
+/// `WaivedLocations` should only match the Relay Chain, its pallets and the system parachains
+/// that actually exist, e.g. with [`RelayOrOtherSystemParachains`] over an explicit list of
+/// para ids, rather than every para id in the system range.
+pub type SystemParachainFeeManager<WaivedLocations, AssetTransactor, AccountId, FeeAccount> =
+	XcmFeeManagerFromComponents<
+		WaivedLocations,
+		XcmFeeToAccount<AssetTransactor, AccountId, FeeAccount>,
+	>;
+
+/// The barrier shared by the system parachains.
+///
+/// Reserve transfers to the Relay Chain are denied. Otherwise, messages using local weight
+/// credit, expected responses, messages paying for their execution, unpaid messages from
+/// `UnpaidExecutionFrom`, unpaid messages within the weight limits granted by
+/// `LimitedUnpaidExecutionFrom` and version subscriptions from `SubscriptionsFrom` are allowed,
+/// the last four also through computed origins. Expected responses are the ones known to
+/// `ResponseHandler`.
+///
+/// `LimitedUnpaidExecutionFrom` is usually `pallet_xcm_unpaid_execution`, or `()` to grant no
+/// limited unpaid execution.
+pub type SystemParachainBarrier<
+	ResponseHandler,
+	UnpaidExecutionFrom,
+	LimitedUnpaidExecutionFrom,
+	SubscriptionsFrom,
+	UniversalLocation,
+> = TrailingSetTopicAsId<
+	DenyThenTry<
+		DenyReserveTransferToRelayChain,
+		(
+			TakeWeightCredit,
+			AllowKnownQueryResponses<ResponseHandler>,
+			WithComputedOrigin<
+				(
+					AllowTopLevelPaidExecutionFrom<Everything>,
+					AllowExplicitUnpaidExecutionFrom<UnpaidExecutionFrom>,
+					AllowLimitedUnpaidExecutionFrom<LimitedUnpaidExecutionFrom>,
+					AllowSubscriptionsFrom<SubscriptionsFrom>,
+				),
+				UniversalLocation,
+				ConstU32<8>,
+			>,
+		),
+	>,
+>;
+
 /// Accepts an asset if it is a concrete asset from the system (Relay Chain or system parachain).
 pub struct ConcreteAssetFromSystem<AssetLocation>(PhantomData<AssetLocation>);
 impl<AssetLocation: Get<MultiLocation>> ContainsPair<MultiAsset, MultiLocation>
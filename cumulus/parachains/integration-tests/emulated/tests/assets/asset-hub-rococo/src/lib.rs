@@ -34,8 +34,8 @@ pub use asset_test_utils::xcm_helpers;
 pub use emulated_integration_tests_common::{
 	test_parachain_is_trusted_teleporter,
 	xcm_emulator::{
-		assert_expected_events, bx, helpers::weight_within_threshold, Chain, Parachain as Para,
-		RelayChain as Relay, Test, TestArgs, TestContext, TestExt,
+		assert_expected_events, bx, helpers::weight_within_threshold, Chain, Network,
+		Parachain as Para, RelayChain as Relay, Test, TestArgs, TestContext, TestExt,
 	},
 	xcm_helpers::{xcm_transact_paid_execution, xcm_transact_unpaid_execution},
 	PROOF_SIZE_THRESHOLD, REF_TIME_THRESHOLD, XCM_V3,
@@ -50,7 +50,7 @@ pub use rococo_system_emulated_network::{
 	AssetHubRococoPara as AssetHubRococo, AssetHubRococoParaReceiver as AssetHubRococoReceiver,
 	AssetHubRococoParaSender as AssetHubRococoSender, BridgeHubRococoPara as BridgeHubRococo,
 	BridgeHubRococoParaReceiver as BridgeHubRococoReceiver, PenpalAPara as PenpalA,
-	PenpalAParaReceiver as PenpalAReceiver, PenpalAParaSender as PenpalASender,
+	PenpalAParaReceiver as PenpalAReceiver, PenpalAParaSender as PenpalASender, RococoMockNet,
 	RococoRelay as Rococo, RococoRelayReceiver as RococoReceiver,
 	RococoRelaySender as RococoSender,
 };
@@ -66,6 +66,12 @@ pub type SystemParaToRelayTest = Test<AssetHubRococo, Rococo>;
 pub type SystemParaToParaTest = Test<AssetHubRococo, PenpalA>;
 pub type ParaToSystemParaTest = Test<PenpalA, AssetHubRococo>;
 
+/// Resets the network to its state right after initialization, which is only built once and then
+/// restored from a snapshot shared by all tests.
+pub fn reset_network() {
+	RococoMockNet::restore_or_setup("initialized", || {});
+}
+
 /// Returns a `TestArgs` instance to be used for the Relay Chain across integration tests
 pub fn relay_test_args(
 	dest: MultiLocation,
@@ -16,5 +16,6 @@
 mod reserve_transfer;
 mod send;
 mod set_xcm_versions;
+mod snapshot;
 mod swap;
 mod teleport;
@@ -211,6 +211,8 @@ fn para_to_system_para_limited_reserve_transfer_assets(t: ParaToSystemParaTest)
 /// Reserve Transfers of native asset from Relay Chain to the System Parachain shouldn't work
 #[test]
 fn reserve_transfer_native_asset_from_relay_to_system_para_fails() {
+	reset_network();
+
 	let signed_origin = <Rococo as Chain>::RuntimeOrigin::signed(RococoSender::get().into());
 	let destination = Rococo::child_location_of(AssetHubRococo::para_id());
 	let beneficiary: MultiLocation =
@@ -243,6 +245,8 @@ fn reserve_transfer_native_asset_from_relay_to_system_para_fails() {
 /// Reserve Transfers of native asset from System Parachain to Relay Chain shouldn't work
 #[test]
 fn reserve_transfer_native_asset_from_system_para_to_relay_fails() {
+	reset_network();
+
 	// Init values for System Parachain
 	let signed_origin =
 		<AssetHubRococo as Chain>::RuntimeOrigin::signed(AssetHubRococoSender::get().into());
@@ -280,6 +284,8 @@ fn reserve_transfer_native_asset_from_system_para_to_relay_fails() {
 /// Reserve Transfers of native asset from Relay to Parachain should work
 #[test]
 fn reserve_transfer_native_asset_from_relay_to_para() {
+	reset_network();
+
 	// Init values for Relay
 	let destination = Rococo::child_location_of(PenpalA::para_id());
 	let beneficiary_id = PenpalAReceiver::get();
@@ -319,6 +325,8 @@ fn reserve_transfer_native_asset_from_relay_to_para() {
 /// Reserve Transfers of native asset from System Parachain to Parachain should work
 #[test]
 fn reserve_transfer_native_asset_from_system_para_to_para() {
+	reset_network();
+
 	// Init values for System Parachain
 	let destination = AssetHubRococo::sibling_location_of(PenpalA::para_id());
 	let beneficiary_id = PenpalAReceiver::get();
@@ -359,6 +367,8 @@ fn reserve_transfer_native_asset_from_system_para_to_para() {
 /// Reserve Transfers of native asset from Parachain to System Parachain should work
 #[test]
 fn reserve_transfer_native_asset_from_para_to_system_para() {
+	reset_network();
+
 	// Init values for Penpal Parachain
 	let destination = PenpalA::sibling_location_of(AssetHubRococo::para_id());
 	let beneficiary_id = AssetHubRococoReceiver::get();
@@ -406,6 +416,8 @@ fn reserve_transfer_native_asset_from_para_to_system_para() {
 /// work
 #[test]
 fn reserve_transfer_assets_from_system_para_to_para() {
+	reset_network();
+
 	// Force create asset on AssetHubRococo and PenpalA from Relay Chain
 	AssetHubRococo::force_create_and_mint_asset(
 		ASSET_ID,
@@ -19,6 +19,8 @@ use crate::*;
 /// when `OriginKind::Superuser`.
 #[test]
 fn send_transact_as_superuser_from_relay_to_system_para_works() {
+	reset_network();
+
 	AssetHubRococo::force_create_asset_from_relay_as_root(
 		ASSET_ID,
 		ASSET_MIN_BALANCE,
@@ -32,6 +34,8 @@ fn send_transact_as_superuser_from_relay_to_system_para_works() {
 /// in the System Parachain
 #[test]
 fn send_xcm_from_para_to_system_para_paying_fee_with_assets_works() {
+	reset_network();
+
 	let para_sovereign_account = AssetHubRococo::sovereign_account_id_of(
 		AssetHubRococo::sibling_location_of(PenpalA::para_id()),
 	);
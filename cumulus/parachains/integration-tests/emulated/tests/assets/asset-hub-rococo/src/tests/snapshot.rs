@@ -0,0 +1,71 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+
+fn sender_asset_balance() -> Balance {
+	AssetHubRococo::execute_with(|| {
+		<AssetHubRococo as AssetHubRococoPallet>::Assets::balance(
+			ASSET_ID,
+			AssetHubRococoSender::get(),
+		)
+	})
+}
+
+/// Changes made after a snapshot of the network are rolled back when it is restored.
+#[test]
+fn network_is_restored_from_snapshot() {
+	let sender = AssetHubRococoSender::get();
+	let minted = ASSET_MIN_BALANCE * 1000;
+
+	RococoMockNet::restore_or_setup("asset_minted", || {
+		AssetHubRococo::execute_with(|| {
+			assert_ok!(<AssetHubRococo as AssetHubRococoPallet>::Assets::create(
+				<AssetHubRococo as Chain>::RuntimeOrigin::signed(sender.clone()),
+				ASSET_ID.into(),
+				sender.clone().into(),
+				ASSET_MIN_BALANCE,
+			));
+			assert_ok!(<AssetHubRococo as AssetHubRococoPallet>::Assets::mint(
+				<AssetHubRococo as Chain>::RuntimeOrigin::signed(sender.clone()),
+				ASSET_ID.into(),
+				sender.clone().into(),
+				minted,
+			));
+		});
+	});
+	assert_eq!(sender_asset_balance(), minted);
+
+	AssetHubRococo::execute_with(|| {
+		assert_ok!(<AssetHubRococo as AssetHubRococoPallet>::Assets::transfer(
+			<AssetHubRococo as Chain>::RuntimeOrigin::signed(sender.clone()),
+			ASSET_ID.into(),
+			AssetHubRococoReceiver::get().into(),
+			ASSET_MIN_BALANCE,
+		));
+	});
+	assert_eq!(sender_asset_balance(), minted - ASSET_MIN_BALANCE);
+
+	assert!(RococoMockNet::restore("asset_minted"));
+	assert_eq!(sender_asset_balance(), minted);
+
+	// Unknown snapshots leave the network untouched.
+	assert!(!RococoMockNet::restore("unknown"));
+	assert_eq!(sender_asset_balance(), minted);
+
+	// The initial state does not contain the asset.
+	reset_network();
+	assert_eq!(sender_asset_balance(), 0);
+}
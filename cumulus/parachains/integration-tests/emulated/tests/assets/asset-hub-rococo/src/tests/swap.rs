@@ -20,6 +20,8 @@ use sp_runtime::{DispatchError, ModuleError};
 
 #[test]
 fn swap_locally_on_chain_using_local_assets() {
+	reset_network();
+
 	let asset_native = Box::new(asset_hub_rococo_runtime::xcm_config::TokenLocation::get());
 	let asset_one = Box::new(MultiLocation {
 		parents: 0,
@@ -118,6 +120,8 @@ fn swap_locally_on_chain_using_local_assets() {
 
 #[test]
 fn swap_locally_on_chain_using_foreign_assets() {
+	reset_network();
+
 	use frame_support::weights::WeightToFee;
 
 	let asset_native = Box::new(asset_hub_rococo_runtime::xcm_config::TokenLocation::get());
@@ -330,6 +334,8 @@ fn swap_locally_on_chain_using_foreign_assets() {
 
 #[test]
 fn cannot_create_pool_from_pool_assets() {
+	reset_network();
+
 	let asset_native = Box::new(asset_hub_rococo_runtime::xcm_config::TokenLocation::get());
 	let mut asset_one = asset_hub_rococo_runtime::xcm_config::PoolAssetsPalletLocation::get();
 	asset_one.append_with(GeneralIndex(ASSET_ID.into())).expect("pool assets");
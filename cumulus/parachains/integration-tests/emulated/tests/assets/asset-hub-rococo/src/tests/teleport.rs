@@ -155,6 +155,8 @@ fn system_para_teleport_assets(t: SystemParaToRelayTest) -> DispatchResult {
 /// Limited Teleport of native asset from Relay Chain to the System Parachain should work
 #[test]
 fn limited_teleport_native_assets_from_relay_to_system_para_works() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = ROCOCO_ED * 1000;
 	let dest = Rococo::child_location_of(AssetHubRococo::para_id());
@@ -194,6 +196,8 @@ fn limited_teleport_native_assets_from_relay_to_system_para_works() {
 /// should work when there is enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn limited_teleport_native_assets_back_from_system_para_to_relay_works() {
+	reset_network();
+
 	// Dependency - Relay Chain's `CheckAccount` should have enough balance
 	limited_teleport_native_assets_from_relay_to_system_para_works();
 
@@ -238,6 +242,8 @@ fn limited_teleport_native_assets_back_from_system_para_to_relay_works() {
 /// should't work when there is not enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn limited_teleport_native_assets_from_system_para_to_relay_fails() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = ASSET_HUB_ROCOCO_ED * 1000;
 	let destination = AssetHubRococo::parent_location().into();
@@ -278,6 +284,8 @@ fn limited_teleport_native_assets_from_system_para_to_relay_fails() {
 /// Teleport of native asset from Relay Chain to the System Parachain should work
 #[test]
 fn teleport_native_assets_from_relay_to_system_para_works() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = ROCOCO_ED * 1000;
 	let dest = Rococo::child_location_of(AssetHubRococo::para_id());
@@ -317,6 +325,8 @@ fn teleport_native_assets_from_relay_to_system_para_works() {
 /// should work when there is enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn teleport_native_assets_back_from_system_para_to_relay_works() {
+	reset_network();
+
 	// Dependency - Relay Chain's `CheckAccount` should have enough balance
 	teleport_native_assets_from_relay_to_system_para_works();
 
@@ -361,6 +371,8 @@ fn teleport_native_assets_back_from_system_para_to_relay_works() {
 /// shouldn't work when there is not enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn teleport_native_assets_from_system_para_to_relay_fails() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = ASSET_HUB_ROCOCO_ED * 1000;
 	let destination = AssetHubRococo::parent_location();
@@ -400,6 +412,8 @@ fn teleport_native_assets_from_system_para_to_relay_fails() {
 
 #[test]
 fn teleport_to_other_system_parachains_works() {
+	reset_network();
+
 	let amount = ASSET_HUB_ROCOCO_ED * 100;
 	let native_asset: MultiAssets = (Parent, amount).into();
 
@@ -36,8 +36,8 @@ pub use asset_test_utils::xcm_helpers;
 pub use emulated_integration_tests_common::{
 	test_parachain_is_trusted_teleporter,
 	xcm_emulator::{
-		assert_expected_events, bx, helpers::weight_within_threshold, Chain, Parachain as Para,
-		RelayChain as Relay, Test, TestArgs, TestContext, TestExt,
+		assert_expected_events, bx, helpers::weight_within_threshold, Chain, Network,
+		Parachain as Para, RelayChain as Relay, Test, TestArgs, TestContext, TestExt,
 	},
 	xcm_helpers::{xcm_transact_paid_execution, xcm_transact_unpaid_execution},
 	PROOF_SIZE_THRESHOLD, REF_TIME_THRESHOLD, XCM_V3,
@@ -51,7 +51,7 @@ pub use westend_system_emulated_network::{
 	westend_emulated_chain::{genesis::ED as WESTEND_ED, WestendRelayPallet as WestendPallet},
 	AssetHubWestendPara as AssetHubWestend, AssetHubWestendParaReceiver as AssetHubWestendReceiver,
 	AssetHubWestendParaSender as AssetHubWestendSender, PenpalAPara as PenpalA,
-	PenpalAParaReceiver as PenpalAReceiver, PenpalAParaSender as PenpalASender,
+	PenpalAParaReceiver as PenpalAReceiver, PenpalAParaSender as PenpalASender, WestendMockNet,
 	WestendRelay as Westend, WestendRelayReceiver as WestendReceiver,
 	WestendRelaySender as WestendSender,
 };
@@ -65,6 +65,12 @@ pub type RelayToSystemParaTest = Test<Westend, AssetHubWestend>;
 pub type SystemParaToRelayTest = Test<AssetHubWestend, Westend>;
 pub type SystemParaToParaTest = Test<AssetHubWestend, PenpalA>;
 
+/// Resets the network to its state right after initialization, which is only built once and then
+/// restored from a snapshot shared by all tests.
+pub fn reset_network() {
+	WestendMockNet::restore_or_setup("initialized", || {});
+}
+
 /// Returns a `TestArgs` instance to be used for the Relay Chain across integration tests
 pub fn relay_test_args(amount: Balance) -> TestArgs {
 	TestArgs {
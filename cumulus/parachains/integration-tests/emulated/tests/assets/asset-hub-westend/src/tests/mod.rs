@@ -16,6 +16,7 @@
 mod reserve_transfer;
 mod send;
 mod set_xcm_versions;
+mod snapshot;
 mod swap;
 mod teleport;
 mod treasury;
@@ -94,6 +94,8 @@ fn system_para_to_para_limited_reserve_transfer_assets(t: SystemParaToParaTest)
 /// Reserve Transfers of native asset from Relay Chain to the System Parachain shouldn't work
 #[test]
 fn reserve_transfer_native_asset_from_relay_to_system_para_fails() {
+	reset_network();
+
 	let signed_origin = <Westend as Chain>::RuntimeOrigin::signed(WestendSender::get().into());
 	let destination = Westend::child_location_of(AssetHubWestend::para_id());
 	let beneficiary: MultiLocation =
@@ -126,6 +128,8 @@ fn reserve_transfer_native_asset_from_relay_to_system_para_fails() {
 /// Reserve Transfers of native asset from System Parachain to Relay Chain shouldn't work
 #[test]
 fn reserve_transfer_native_asset_from_system_para_to_relay_fails() {
+	reset_network();
+
 	// Init values for System Parachain
 	let signed_origin =
 		<AssetHubWestend as Chain>::RuntimeOrigin::signed(AssetHubWestendSender::get().into());
@@ -162,6 +166,8 @@ fn reserve_transfer_native_asset_from_system_para_to_relay_fails() {
 /// Reserve Transfers of native asset from System Parachain to Parachain should work
 #[test]
 fn reserve_transfer_native_asset_from_system_para_to_para() {
+	reset_network();
+
 	// Init values for System Parachain
 	let destination = AssetHubWestend::sibling_location_of(PenpalA::para_id());
 	let beneficiary_id = PenpalAReceiver::get();
@@ -206,6 +212,8 @@ fn reserve_transfer_native_asset_from_system_para_to_para() {
 /// Reserve Transfers of a local asset from System Parachain to Parachain should work
 #[test]
 fn reserve_transfer_asset_from_system_para_to_para() {
+	reset_network();
+
 	// Force create asset from Relay Chain and mint assets for System Parachain's sender account
 	AssetHubWestend::force_create_and_mint_asset(
 		ASSET_ID,
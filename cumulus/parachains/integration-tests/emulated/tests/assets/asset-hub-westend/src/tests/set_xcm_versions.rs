@@ -17,6 +17,8 @@ use crate::*;
 
 #[test]
 fn relay_sets_system_para_xcm_supported_version() {
+	reset_network();
+
 	// Init tests variables
 	let sudo_origin = <Westend as Chain>::RuntimeOrigin::root();
 	let system_para_destination: MultiLocation =
@@ -46,6 +48,8 @@ fn relay_sets_system_para_xcm_supported_version() {
 
 #[test]
 fn system_para_sets_relay_xcm_supported_version() {
+	reset_network();
+
 	// Init test variables
 	let parent_location = AssetHubWestend::parent_location();
 	let force_xcm_version_call =
@@ -0,0 +1,71 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+
+fn sender_asset_balance() -> Balance {
+	AssetHubWestend::execute_with(|| {
+		<AssetHubWestend as AssetHubWestendPallet>::Assets::balance(
+			ASSET_ID,
+			AssetHubWestendSender::get(),
+		)
+	})
+}
+
+/// Changes made after a snapshot of the network are rolled back when it is restored.
+#[test]
+fn network_is_restored_from_snapshot() {
+	let sender = AssetHubWestendSender::get();
+	let minted = ASSET_MIN_BALANCE * 1000;
+
+	WestendMockNet::restore_or_setup("asset_minted", || {
+		AssetHubWestend::execute_with(|| {
+			assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::Assets::create(
+				<AssetHubWestend as Chain>::RuntimeOrigin::signed(sender.clone()),
+				ASSET_ID.into(),
+				sender.clone().into(),
+				ASSET_MIN_BALANCE,
+			));
+			assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::Assets::mint(
+				<AssetHubWestend as Chain>::RuntimeOrigin::signed(sender.clone()),
+				ASSET_ID.into(),
+				sender.clone().into(),
+				minted,
+			));
+		});
+	});
+	assert_eq!(sender_asset_balance(), minted);
+
+	AssetHubWestend::execute_with(|| {
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::Assets::transfer(
+			<AssetHubWestend as Chain>::RuntimeOrigin::signed(sender.clone()),
+			ASSET_ID.into(),
+			AssetHubWestendReceiver::get().into(),
+			ASSET_MIN_BALANCE,
+		));
+	});
+	assert_eq!(sender_asset_balance(), minted - ASSET_MIN_BALANCE);
+
+	assert!(WestendMockNet::restore("asset_minted"));
+	assert_eq!(sender_asset_balance(), minted);
+
+	// Unknown snapshots leave the network untouched.
+	assert!(!WestendMockNet::restore("unknown"));
+	assert_eq!(sender_asset_balance(), minted);
+
+	// The initial state does not contain the asset.
+	reset_network();
+	assert_eq!(sender_asset_balance(), 0);
+}
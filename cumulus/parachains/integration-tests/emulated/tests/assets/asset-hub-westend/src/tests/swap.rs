@@ -17,6 +17,8 @@ use crate::*;
 
 #[test]
 fn swap_locally_on_chain_using_local_assets() {
+	reset_network();
+
 	let asset_native = Box::new(asset_hub_westend_runtime::xcm_config::WestendLocation::get());
 	let asset_one = Box::new(MultiLocation {
 		parents: 0,
@@ -107,6 +109,8 @@ fn swap_locally_on_chain_using_local_assets() {
 
 #[test]
 fn swap_locally_on_chain_using_foreign_assets() {
+	reset_network();
+
 	use frame_support::weights::WeightToFee;
 
 	let asset_native = Box::new(asset_hub_westend_runtime::xcm_config::WestendLocation::get());
@@ -316,6 +320,8 @@ fn swap_locally_on_chain_using_foreign_assets() {
 
 #[test]
 fn cannot_create_pool_from_pool_assets() {
+	reset_network();
+
 	let asset_native = Box::new(asset_hub_westend_runtime::xcm_config::WestendLocation::get());
 	let mut asset_one = asset_hub_westend_runtime::xcm_config::PoolAssetsPalletLocation::get();
 	asset_one.append_with(GeneralIndex(ASSET_ID.into())).expect("pool assets");
@@ -155,6 +155,8 @@ fn system_para_teleport_assets(t: SystemParaToRelayTest) -> DispatchResult {
 /// Limited Teleport of native asset from Relay Chain to the System Parachain should work
 #[test]
 fn limited_teleport_native_assets_from_relay_to_system_para_works() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = WESTEND_ED * 1000;
 	let test_args = TestContext {
@@ -192,6 +194,8 @@ fn limited_teleport_native_assets_from_relay_to_system_para_works() {
 /// should work when there is enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn limited_teleport_native_assets_back_from_system_para_to_relay_works() {
+	reset_network();
+
 	// Dependency - Relay Chain's `CheckAccount` should have enough balance
 	limited_teleport_native_assets_from_relay_to_system_para_works();
 
@@ -236,6 +240,8 @@ fn limited_teleport_native_assets_back_from_system_para_to_relay_works() {
 /// should't work when there is not enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn limited_teleport_native_assets_from_system_para_to_relay_fails() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = ASSET_HUB_WESTEND_ED * 1000;
 	let destination = AssetHubWestend::parent_location().into();
@@ -276,6 +282,8 @@ fn limited_teleport_native_assets_from_system_para_to_relay_fails() {
 /// Teleport of native asset from Relay Chain to the System Parachain should work
 #[test]
 fn teleport_native_assets_from_relay_to_system_para_works() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = WESTEND_ED * 1000;
 	let test_args = TestContext {
@@ -313,6 +321,8 @@ fn teleport_native_assets_from_relay_to_system_para_works() {
 /// should work when there is enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn teleport_native_assets_back_from_system_para_to_relay_works() {
+	reset_network();
+
 	// Dependency - Relay Chain's `CheckAccount` should have enough balance
 	teleport_native_assets_from_relay_to_system_para_works();
 
@@ -357,6 +367,8 @@ fn teleport_native_assets_back_from_system_para_to_relay_works() {
 /// shouldn't work when there is not enough balance in Relay Chain's `CheckAccount`
 #[test]
 fn teleport_native_assets_from_system_para_to_relay_fails() {
+	reset_network();
+
 	// Init values for Relay Chain
 	let amount_to_send: Balance = ASSET_HUB_WESTEND_ED * 1000;
 	let destination = AssetHubWestend::parent_location();
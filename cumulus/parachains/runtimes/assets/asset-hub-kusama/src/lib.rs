@@ -193,6 +193,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -286,6 +287,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type CallbackHandle = ();
 	type AssetAccountDeposit = AssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+	type MaxRoleAccounts = frame_support::traits::ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -307,6 +309,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<10>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -398,6 +401,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type CallbackHandle = ();
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+	type MaxRoleAccounts = frame_support::traits::ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
 }
@@ -653,6 +657,8 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
+	type MaxDeadLetters = sp_core::ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
@@ -1079,6 +1085,14 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(&asset1, &asset2).ok()
 		}
+
+		fn pool_exists(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> bool {
+			AssetConversion::pool_exists(asset1, asset2)
+		}
+
+		fn find_path(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<Vec<Box<MultiLocation>>> {
+			AssetConversion::find_path(asset1, asset2).map(Into::into)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
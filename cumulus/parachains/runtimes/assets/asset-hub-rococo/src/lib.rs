@@ -188,6 +188,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -281,6 +282,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type CallbackHandle = ();
 	type AssetAccountDeposit = AssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+	type MaxRoleAccounts = frame_support::traits::ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -302,6 +304,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<10>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -393,6 +396,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type CallbackHandle = ();
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+	type MaxRoleAccounts = frame_support::traits::ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
 }
@@ -646,6 +650,8 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
+	type MaxDeadLetters = sp_core::ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
@@ -1113,6 +1119,14 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(&asset1, &asset2).ok()
 		}
+
+		fn pool_exists(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> bool {
+			AssetConversion::pool_exists(asset1, asset2)
+		}
+
+		fn find_path(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<Vec<Box<MultiLocation>>> {
+			AssetConversion::find_path(asset1, asset2).map(Into::into)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
@@ -1159,6 +1173,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm_bridge_hub_router::XcmBridgeHubRouterApi<Block> for Runtime {
+		fn bridge_state() -> pallet_xcm_bridge_hub_router::BridgeState {
+			ToWestendXcmRouter::bridge()
+		}
+	}
+
+	impl pallet_xcm::XcmTeleportAccountingApi<Block> for Runtime {
+		fn checked_issuance(asset: xcm::VersionedAssetId) -> Option<xcm_builder::CheckedIssuance> {
+			PolkadotXcm::checked_issuance(asset)
+		}
+	}
+
 	impl assets_common::runtime_api::FungiblesApi<
 		Block,
 		AccountId,
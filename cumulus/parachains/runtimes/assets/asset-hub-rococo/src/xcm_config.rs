@@ -20,6 +20,7 @@ use super::{
 	TransactionByteFee, TrustBackedAssetsInstance, WeightToFee, XcmpQueue,
 };
 use assets_common::{
+	foreign_creators::ForeignAssetsRegistrar,
 	local_and_foreign_assets::MatchesLocalAndForeignAssetsMultiLocation,
 	matching::{FromSiblingParachain, IsForeignConcreteAsset},
 };
@@ -33,7 +34,7 @@ use parachains_common::{
 	impls::ToStakingPot,
 	xcm_config::{
 		AssetFeeAsExistentialDepositMultiplier, ConcreteAssetFromSystem,
-		RelayOrOtherSystemParachains,
+		RelayOrOtherSystemParachains, SystemParachainBarrier, SystemParachainFeeManager,
 	},
 	TREASURY_PALLET_ID,
 };
@@ -43,16 +44,12 @@ use rococo_runtime_constants::system_parachain;
 use sp_runtime::traits::{AccountIdConversion, ConvertInto};
 use xcm::latest::prelude::*;
 use xcm_builder::{
-	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
-	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, CurrencyAdapter,
-	DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal, DescribeFamily,
-	EnsureXcmOrigin, FungiblesAdapter, GlobalConsensusParachainConvertsFor, HashedDescription,
-	IsConcrete, LocalMint, NetworkExportTableItem, NoChecking, ParentAsSuperuser, ParentIsPreset,
+	AccountId32Aliases, CurrencyAdapter, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin,
+	FungiblesAdapter, GlobalConsensusParachainConvertsFor, HashedDescription, IsConcrete,
+	LocalMint, NetworkExportTableItem, NoChecking, ParentAsSuperuser, ParentIsPreset,
 	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, StartsWith,
-	StartsWithExplicitGlobalConsensus, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
-	XcmFeeToAccount,
+	StartsWithExplicitGlobalConsensus, UsingComponents, WeightInfoBounds, WithUniqueTopic,
 };
 use xcm_executor::{traits::WithOriginFilter, XcmExecutor};
 
@@ -128,6 +125,8 @@ pub type FungiblesTransactor = FungiblesAdapter<
 	LocalMint<parachains_common::impls::NonZeroIssuance<AccountId, Assets>>,
 	// The account to use for tracking teleports.
 	CheckingAccount,
+	// We record the checked issuance of our teleports to check it in `try_state`.
+	PolkadotXcm,
 >;
 
 /// `AssetId/Balance` converter for `TrustBackedAssets`
@@ -180,6 +179,8 @@ pub type PoolFungiblesTransactor = FungiblesAdapter<
 	LocalMint<parachains_common::impls::NonZeroIssuance<AccountId, PoolAssets>>,
 	// The account to use for tracking teleports.
 	CheckingAccount,
+	// We record the checked issuance of our teleports to check it in `try_state`.
+	PolkadotXcm,
 >;
 
 /// Means for transacting assets on this chain.
@@ -464,34 +465,17 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 	}
 }
 
-pub type Barrier = TrailingSetTopicAsId<
-	DenyThenTry<
-		DenyReserveTransferToRelayChain,
-		(
-			TakeWeightCredit,
-			// Expected responses are OK.
-			AllowKnownQueryResponses<PolkadotXcm>,
-			// Allow XCMs with some computed origins to pass through.
-			WithComputedOrigin<
-				(
-					// If the message is one that immediately attempts to pay for execution, then
-					// allow it.
-					AllowTopLevelPaidExecutionFrom<Everything>,
-					// Parent, its pluralities (i.e. governance bodies), relay treasury pallet and
-					// BridgeHub get free execution.
-					AllowExplicitUnpaidExecutionFrom<(
-						ParentOrParentsPlurality,
-						Equals<RelayTreasuryLocation>,
-						Equals<bridging::SiblingBridgeHub>,
-					)>,
-					// Subscriptions for version tracking are OK.
-					AllowSubscriptionsFrom<ParentOrSiblings>,
-				),
-				UniversalLocation,
-				ConstU32<8>,
-			>,
-		),
-	>,
+/// Origins allowed to execute messages without paying for them. Parent, its pluralities (i.e.
+/// governance bodies), relay treasury pallet and BridgeHub get free execution.
+pub type UnpaidExecutionFrom =
+	(ParentOrParentsPlurality, Equals<RelayTreasuryLocation>, Equals<bridging::SiblingBridgeHub>);
+
+pub type Barrier = SystemParachainBarrier<
+	PolkadotXcm,
+	UnpaidExecutionFrom,
+	(),
+	ParentOrSiblings,
+	UniversalLocation,
 >;
 
 /// Multiplier used for dedicated `TakeFirstAssetTrader` with `Assets` instance.
@@ -596,14 +580,17 @@ impl xcm_executor::Config for XcmConfig {
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
 	type AssetLocker = ();
 	type AssetExchanger = ();
-	type FeeManager = XcmFeeManagerFromComponents<
+	type FeeManager = SystemParachainFeeManager<
 		WaivedLocations,
-		XcmFeeToAccount<Self::AssetTransactor, AccountId, TreasuryAccount>,
+		Self::AssetTransactor,
+		AccountId,
+		TreasuryAccount,
 	>;
 	type MessageExporter = ();
 	type UniversalAliases = (bridging::to_westend::UniversalAliases,);
 	type CallDispatcher = WithOriginFilter<SafeCallFilter>;
 	type SafeCallFilter = SafeCallFilter;
+	type XcmRecorder = PolkadotXcm;
 	type Aliasers = Nothing;
 }
 
@@ -631,6 +618,27 @@ pub type XcmRouter = WithUniqueTopic<(
 	ToWestendXcmRouter,
 )>;
 
+match_types! {
+	// The locations which may register their assets in `ForeignAssets` through
+	// `pallet_xcm::register_foreign_asset`, i.e. the sibling parachains. The `CreateOrigin` of
+	// `ForeignAssets` applies on top of this.
+	pub type ForeignAssetRegistrars: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: X1(Parachain(_)) }
+	};
+}
+
+parameter_types! {
+	/// The weight of registering a foreign asset pushed by the chain owning it, i.e. creating the
+	/// asset and setting its metadata.
+	pub ForeignAssetRegistrationWeight: frame_support::weights::Weight = {
+		use pallet_assets::WeightInfo;
+		type ForeignAssetsWeights = crate::weights::pallet_assets_foreign::WeightInfo<Runtime>;
+		let limit = crate::ForeignAssetsAssetsStringLimit::get();
+		ForeignAssetsWeights::create()
+			.saturating_add(ForeignAssetsWeights::set_metadata(limit, limit))
+	};
+}
+
 impl pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	// We want to disallow users sending (arbitrary) XCMs from this chain.
@@ -663,6 +671,16 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type ForeignAssetRegistrationOrigin = pallet_xcm::EnsureXcm<ForeignAssetRegistrars>;
+	type ForeignAssetRegistrar = ForeignAssetsRegistrar<
+		Runtime,
+		ForeignAssetsInstance,
+		ForeignCreatorsSovereignAccountOf,
+		ForeignAssetRegistrationWeight,
+	>;
+	type TeleportAccounting = (FungiblesTransactor, PoolFungiblesTransactor);
+	type MaxTransferReceipts = ();
+	type UnixTime = crate::Timestamp;
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
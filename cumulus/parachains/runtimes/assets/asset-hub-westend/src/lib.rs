@@ -174,6 +174,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -266,6 +267,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type CallbackHandle = ();
 	type AssetAccountDeposit = AssetAccountDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -287,6 +289,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<10>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -376,6 +379,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type CallbackHandle = ();
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
+	type MaxRoleAccounts = frame_support::traits::ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
 }
@@ -631,6 +635,8 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
+	type MaxDeadLetters = sp_core::ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
@@ -894,6 +900,7 @@ construct_runtime!(
 		// Bridge utilities.
 		ToRococoXcmRouter: pallet_xcm_bridge_hub_router::<Instance1>::{Pallet, Storage, Call} = 34,
 		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>} = 35,
+		XcmUnpaidExecution: pallet_xcm_unpaid_execution::{Pallet, Call, Storage, Event<T>} = 36,
 
 		// Handy utilities.
 		Utility: pallet_utility::{Pallet, Call, Event} = 40,
@@ -1083,6 +1090,7 @@ mod benches {
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[cumulus_pallet_dmp_queue, DmpQueue]
+		[pallet_xcm_unpaid_execution, XcmUnpaidExecution]
 		[pallet_xcm_bridge_hub_router, ToRococo]
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsiscsBenchmark::<Runtime>]
@@ -1186,6 +1194,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockAuthorApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			System::block_author()
+		}
+	}
+
 	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
@@ -1248,6 +1262,14 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(&asset1, &asset2).ok()
 		}
+
+		fn pool_exists(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> bool {
+			AssetConversion::pool_exists(asset1, asset2)
+		}
+
+		fn find_path(asset1: Box<MultiLocation>, asset2: Box<MultiLocation>) -> Option<Vec<Box<MultiLocation>>> {
+			AssetConversion::find_path(asset1, asset2).map(Into::into)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
@@ -1294,6 +1316,29 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm_bridge_hub_router::XcmBridgeHubRouterApi<Block> for Runtime {
+		fn bridge_state() -> pallet_xcm_bridge_hub_router::BridgeState {
+			ToRococoXcmRouter::bridge()
+		}
+	}
+
+	impl pallet_xcm::XcmTransferReceiptsApi<Block, BlockNumber> for Runtime {
+		fn transfer_receipts_of(
+			origin: xcm::VersionedMultiLocation,
+		) -> Vec<(u64, pallet_xcm::TransferReceipt<BlockNumber>)> {
+			origin
+				.try_into()
+				.map(|origin| PolkadotXcm::transfer_receipts_of(&origin))
+				.unwrap_or_default()
+		}
+	}
+
+	impl pallet_xcm::XcmTeleportAccountingApi<Block> for Runtime {
+		fn checked_issuance(asset: xcm::VersionedAssetId) -> Option<xcm_builder::CheckedIssuance> {
+			PolkadotXcm::checked_issuance(asset)
+		}
+	}
+
 	impl assets_common::runtime_api::FungiblesApi<
 		Block,
 		AccountId,
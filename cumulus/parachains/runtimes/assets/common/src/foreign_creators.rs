@@ -13,11 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use frame_support::traits::{
-	ContainsPair, EnsureOrigin, EnsureOriginWithArg, Everything, OriginTrait,
+use frame_support::{
+	pallet_prelude::DispatchResult,
+	traits::{
+		fungibles, ContainsPair, EnsureOrigin, EnsureOriginWithArg, Everything, Get, OriginTrait,
+	},
+	weights::Weight,
 };
 use pallet_xcm::{EnsureXcm, Origin as XcmOrigin};
+use sp_runtime::{traits::StaticLookup, ArithmeticError, DispatchError};
 use xcm::latest::MultiLocation;
+use xcm_builder::{ForeignAssetMetadata, RegisterForeignAsset};
 use xcm_executor::traits::ConvertLocation;
 
 /// `EnsureOriginWithArg` impl for `CreateOrigin` that allows only XCM origins that are locations
@@ -54,3 +60,58 @@ where
 		Ok(pallet_xcm::Origin::Xcm(*a).into())
 	}
 }
+
+/// [`RegisterForeignAsset`] implementation for a `pallet-assets` instance holding foreign assets.
+///
+/// The asset is created through the `create` call of the instance, dispatched with the XCM origin
+/// of the owning location. The owning location hence has to pass the `CreateOrigin` of the
+/// instance, e.g. [`ForeignCreators`], and the `AssetDeposit` is taken from its account. The
+/// metadata is then set by that account, which takes the metadata deposit from it. If the asset
+/// already exists, only its metadata is updated, which requires the account of the owning location
+/// to still own the asset.
+pub struct ForeignAssetsRegistrar<Runtime, Instance, AccountOf, RegisterWeight>(
+	sp_std::marker::PhantomData<(Runtime, Instance, AccountOf, RegisterWeight)>,
+);
+impl<
+		Runtime: pallet_assets::Config<Instance, AssetId = MultiLocation, AssetIdParameter = MultiLocation>,
+		Instance: 'static,
+		AccountOf: ConvertLocation<Runtime::AccountId>,
+		RegisterWeight: Get<Weight>,
+	> RegisterForeignAsset for ForeignAssetsRegistrar<Runtime, Instance, AccountOf, RegisterWeight>
+where
+	Runtime::RuntimeOrigin: From<XcmOrigin>,
+{
+	fn register(
+		owner: &MultiLocation,
+		asset: &MultiLocation,
+		metadata: ForeignAssetMetadata,
+	) -> DispatchResult {
+		let admin = AccountOf::convert_location(owner)
+			.ok_or(DispatchError::Other("owner location not convertible to an account"))?;
+
+		if !<pallet_assets::Pallet<Runtime, Instance> as fungibles::Inspect<_>>::asset_exists(
+			*asset,
+		) {
+			let min_balance =
+				metadata.min_balance.try_into().map_err(|_| ArithmeticError::Overflow)?;
+			pallet_assets::Pallet::<Runtime, Instance>::create(
+				XcmOrigin::Xcm(*owner).into(),
+				*asset,
+				Runtime::Lookup::unlookup(admin.clone()),
+				min_balance,
+			)?;
+		}
+
+		pallet_assets::Pallet::<Runtime, Instance>::set_metadata(
+			frame_system::RawOrigin::Signed(admin).into(),
+			*asset,
+			metadata.name,
+			metadata.symbol,
+			metadata.decimals,
+		)
+	}
+
+	fn register_weight() -> Weight {
+		RegisterWeight::get()
+	}
+}
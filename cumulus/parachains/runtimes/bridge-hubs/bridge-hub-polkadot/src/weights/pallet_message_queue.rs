@@ -176,4 +176,38 @@ impl<T: frame_system::Config> pallet_message_queue::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: MessageQueue DeadLetters (r:1 w:1)
+	/// Proof: MessageQueue DeadLetters (max_values: None, max_size: Some(65596), added: 68071, mode: MaxEncodedLen)
+	/// Storage: MessageQueue DeadLetterCount (r:1 w:1)
+	/// Proof: MessageQueue DeadLetterCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn discard_dead_letter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65667`
+		//  Estimated: `72578`
+		// Minimum execution time: 40_293_000 picoseconds.
+		Weight::from_parts(40_293_000, 0)
+			.saturating_add(Weight::from_parts(0, 72578))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: MessageQueue DeadLetters (r:1 w:1)
+	/// Proof: MessageQueue DeadLetters (max_values: None, max_size: Some(65596), added: 68071, mode: MaxEncodedLen)
+	/// Storage: MessageQueue DeadLetterCount (r:1 w:1)
+	/// Proof: MessageQueue DeadLetterCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: MessageQueue BookStateFor (r:3 w:3)
+	/// Proof: MessageQueue BookStateFor (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: MessageQueue ServiceHead (r:1 w:0)
+	/// Proof: MessageQueue ServiceHead (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	/// Storage: MessageQueue Pages (r:0 w:1)
+	/// Proof: MessageQueue Pages (max_values: None, max_size: Some(65585), added: 68060, mode: MaxEncodedLen)
+	fn requeue_dead_letter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65830`
+		//  Estimated: `72578`
+		// Minimum execution time: 79_267_000 picoseconds.
+		Weight::from_parts(79_267_000, 0)
+			.saturating_add(Weight::from_parts(0, 72578))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 }
@@ -19,7 +19,7 @@
 use bp_polkadot_core::Signature;
 use bridge_hub_rococo_runtime::{
 	bridge_common_config, bridge_to_westend_config,
-	xcm_config::{RelayNetwork, TokenLocation, XcmConfig},
+	xcm_config::{LocationToAccountId, RelayNetwork, TokenLocation, XcmConfig},
 	AllPalletsWithoutSystem, BridgeRejectObsoleteHeadersAndMessages, Executive, ExistentialDeposit,
 	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, SessionKeys, SignedExtra,
 	TransactionPayment, UncheckedExtrinsic,
@@ -282,6 +282,48 @@ mod bridge_hub_rococo_tests {
 		);
 	}
 
+	#[test]
+	pub fn message_round_trip_works() {
+		// with Westend
+		bridge_hub_test_utils::test_cases::message_round_trip_works::<
+			Runtime,
+			AllPalletsWithoutSystem,
+			XcmConfig,
+			ParachainSystem,
+			LocationToAccountId,
+			BridgeGrandpaWestendInstance,
+			BridgeParachainWestendInstance,
+			WithBridgeHubWestendMessagesInstance,
+			WithBridgeHubWestendMessageBridge,
+		>(
+			collator_session_keys(),
+			bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID,
+			bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
+			SIBLING_PARACHAIN_ID,
+			BridgeHubWestendChainId::get(),
+			Rococo,
+			XCM_LANE_FOR_ASSET_HUB_ROCOCO_TO_ASSET_HUB_WESTEND,
+			ExistentialDeposit::get(),
+			DeliveryRewardInBalance::get().into(),
+			(TokenLocation::get(), bp_bridge_hub_rococo::BridgeHubRococoBaseXcmFeeInRocs::get())
+				.into(),
+			|| ExportMessage {
+				network: Westend,
+				destination: X1(Parachain(1234)),
+				xcm: Xcm(vec![]),
+			},
+			executive_init_block,
+			construct_and_apply_extrinsic,
+			Box::new(|runtime_event_encoded: Vec<u8>| {
+				match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+					Ok(RuntimeEvent::BridgeWestendMessages(event)) => Some(event),
+					_ => None,
+				}
+			}),
+			|| (),
+		);
+	}
+
 	#[test]
 	pub fn can_calculate_weight_for_paid_export_message_with_reserve_transfer() {
 		let estimated = bridge_hub_test_utils::test_cases::can_calculate_weight_for_paid_export_message_with_reserve_transfer::<
@@ -20,7 +20,7 @@ use bp_polkadot_core::Signature;
 use bridge_common_config::{DeliveryRewardInBalance, RequiredStakeForStakeAndSlash};
 use bridge_hub_westend_runtime::{
 	bridge_common_config, bridge_to_rococo_config,
-	xcm_config::{RelayNetwork, WestendLocation, XcmConfig},
+	xcm_config::{LocationToAccountId, RelayNetwork, WestendLocation, XcmConfig},
 	AllPalletsWithoutSystem, BridgeRejectObsoleteHeadersAndMessages, Executive, ExistentialDeposit,
 	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, SessionKeys, SignedExtra,
 	TransactionPayment, UncheckedExtrinsic,
@@ -267,6 +267,43 @@ pub fn complex_relay_extrinsic_works() {
 	);
 }
 
+#[test]
+pub fn message_round_trip_works() {
+	bridge_hub_test_utils::test_cases::message_round_trip_works::<
+		Runtime,
+		AllPalletsWithoutSystem,
+		XcmConfig,
+		ParachainSystem,
+		LocationToAccountId,
+		BridgeGrandpaRococoInstance,
+		BridgeParachainRococoInstance,
+		WithBridgeHubRococoMessagesInstance,
+		WithBridgeHubRococoMessageBridge,
+	>(
+		collator_session_keys(),
+		bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
+		bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID,
+		SIBLING_PARACHAIN_ID,
+		BridgeHubRococoChainId::get(),
+		Westend,
+		XCM_LANE_FOR_ASSET_HUB_WESTEND_TO_ASSET_HUB_ROCOCO,
+		ExistentialDeposit::get(),
+		DeliveryRewardInBalance::get().into(),
+		(WestendLocation::get(), bp_bridge_hub_westend::BridgeHubWestendBaseXcmFeeInWnds::get())
+			.into(),
+		|| ExportMessage { network: Rococo, destination: X1(Parachain(4321)), xcm: Xcm(vec![]) },
+		executive_init_block,
+		construct_and_apply_extrinsic,
+		Box::new(|runtime_event_encoded: Vec<u8>| {
+			match RuntimeEvent::decode(&mut &runtime_event_encoded[..]) {
+				Ok(RuntimeEvent::BridgeRococoMessages(event)) => Some(event),
+				_ => None,
+			}
+		}),
+		|| (),
+	);
+}
+
 #[test]
 pub fn can_calculate_weight_for_paid_export_message_with_reserve_transfer() {
 	let estimated = bridge_hub_test_utils::test_cases::can_calculate_weight_for_paid_export_message_with_reserve_transfer::<
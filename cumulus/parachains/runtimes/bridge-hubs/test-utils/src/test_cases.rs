@@ -37,9 +37,10 @@ use bridge_runtime_common::{
 	},
 	messages_xcm_extension::{XcmAsPlainPayload, XcmBlobMessageDispatchResult},
 };
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::{
 	assert_ok,
+	dispatch::{DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo},
 	traits::{Get, OnFinalize, OnInitialize, OriginTrait, PalletInfoAccess},
 };
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
@@ -52,13 +53,13 @@ use parachains_runtimes_test_utils::{
 use sp_core::H256;
 use sp_keyring::AccountKeyring::*;
 use sp_runtime::{
-	traits::{Header as HeaderT, Zero},
+	traits::{Dispatchable, Header as HeaderT, Zero},
 	AccountId32,
 };
 use xcm::latest::prelude::*;
 use xcm_builder::DispatchBlobError;
 use xcm_executor::{
-	traits::{TransactAsset, WeightBounds},
+	traits::{ConvertLocation, TransactAsset, WeightBounds},
 	XcmExecutor,
 };
 
@@ -842,6 +843,485 @@ pub fn complex_relay_extrinsic_works<Runtime, AllPalletsWithoutSystem, XcmConfig
 		})
 }
 
+/// Test-case makes sure that `Runtime` handles a full message round-trip over the bridge,
+/// asserting fees, relayer rewards and weights at every hop it is involved in:
+///     1. sibling asset hub exports a paid message, which is added to the outbound lane with the
+///        payload expected by the bridged bridge hub (`AH-A -> BH-A`);
+///     2. relayer delivers a message from the bridged chain, which is dispatched to the sibling
+///        asset hub (`BH-B -> AH-B`, on the way back);
+///     3. relayer confirms delivery of the exported message (`BH-B -> BH-A`).
+///
+/// Rewards are checked to the exact amount: relayers submitting delivery and confirmation
+/// transactions are refunded the fee they paid (minus the weight which is not refunded for
+/// delivery), and the relayer which has delivered the exported message is paid
+/// `delivery_reward`. No other account is rewarded.
+///
+/// Running this test-case for both bridge hubs of a corridor covers the whole round-trip.
+pub fn message_round_trip_works<
+	Runtime,
+	AllPalletsWithoutSystem,
+	XcmConfig,
+	HrmpChannelOpener,
+	LocationToAccountId,
+	GPI,
+	PPI,
+	MPI,
+	MB,
+>(
+	collator_session_key: CollatorSessionKeys<Runtime>,
+	runtime_para_id: u32,
+	bridged_para_id: u32,
+	sibling_parachain_id: u32,
+	bridged_chain_id: bp_runtime::ChainId,
+	local_relay_chain_id: NetworkId,
+	lane_id: LaneId,
+	existential_deposit: BalanceOf<Runtime>,
+	delivery_reward: u128,
+	export_message_fee: MultiAsset,
+	export_message_instruction: fn() -> Instruction<XcmConfig::RuntimeCall>,
+	executive_init_block: fn(&HeaderFor<Runtime>),
+	construct_and_apply_extrinsic: fn(
+		sp_keyring::AccountKeyring,
+		pallet_utility::Call::<Runtime>
+	) -> sp_runtime::DispatchOutcome,
+	unwrap_pallet_bridge_messages_event: Box<
+		dyn Fn(Vec<u8>) -> Option<pallet_bridge_messages::Event<Runtime, MPI>>,
+	>,
+	prepare_configuration: impl Fn(),
+) where
+	Runtime: frame_system::Config
+	+ pallet_balances::Config
+	+ pallet_utility::Config
+	+ pallet_session::Config
+	+ pallet_transaction_payment::Config
+	+ pallet_xcm::Config
+	+ parachain_info::Config
+	+ pallet_collator_selection::Config
+	+ cumulus_pallet_parachain_system::Config
+	+ cumulus_pallet_xcmp_queue::Config
+	+ pallet_bridge_grandpa::Config<GPI>
+	+ pallet_bridge_parachains::Config<PPI>
+	+ pallet_bridge_messages::Config<MPI, InboundPayload = XcmAsPlainPayload, OutboundPayload = XcmAsPlainPayload>
+	+ pallet_bridge_relayers::Config,
+	AllPalletsWithoutSystem: OnInitialize<BlockNumberFor<Runtime>>
+		+ OnFinalize<BlockNumberFor<Runtime>>,
+	GPI: 'static,
+	PPI: 'static,
+	MPI: 'static,
+	MB: MessageBridge,
+	<Runtime as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<<Runtime as pallet_transaction_payment::Config>::OnChargeTransaction as pallet_transaction_payment::OnChargeTransaction<Runtime>>::Balance: Into<u128>,
+	<Runtime as pallet_bridge_relayers::Config>::Reward: Into<u128>,
+	<MB as MessageBridge>::BridgedChain: Send + Sync + 'static,
+	<MB as MessageBridge>::ThisChain: Send + Sync + 'static,
+	<<MB as MessageBridge>::ThisChain as bp_runtime::Chain>::AccountId: From<AccountId32>,
+	UnderlyingChainOf<MessageBridgedChain<MB>>: bp_runtime::Chain<Hash = ParaHash> + Parachain,
+	XcmConfig: xcm_executor::Config,
+	HrmpChannelOpener: frame_support::inherent::ProvideInherent<
+		Call = cumulus_pallet_parachain_system::Call<Runtime>,
+	>,
+	LocationToAccountId: ConvertLocation<AccountIdOf<Runtime>>,
+	ValidatorIdOf<Runtime>: From<AccountIdOf<Runtime>>,
+	BalanceOf<Runtime>: Into<u128>,
+	<<Runtime as pallet_bridge_messages::Config<MPI>>::SourceHeaderChain as SourceHeaderChain>::MessagesProof: From<FromBridgedChainMessagesProof<ParaHash>>,
+	<<Runtime as pallet_bridge_messages::Config<MPI>>::TargetHeaderChain as TargetHeaderChain<
+		XcmAsPlainPayload,
+		Runtime::AccountId,
+	>>::MessagesDeliveryProof: From<FromBridgedChainMessagesDeliveryProof<ParaHash>>,
+	<<Runtime as pallet_bridge_grandpa::Config<GPI>>::BridgedChain as bp_runtime::Chain>::Hash: From<ParaHash>,
+	ParaHash: From<<<Runtime as pallet_bridge_grandpa::Config<GPI>>::BridgedChain as bp_runtime::Chain>::Hash>,
+	<Runtime as frame_system::Config>::AccountId:
+	Into<<<Runtime as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId>,
+	AccountIdOf<Runtime>: From<sp_core::sr25519::Public>,
+	<Runtime as frame_system::Config>::AccountId: From<AccountId32>,
+	<Runtime as pallet_bridge_messages::Config<MPI>>::InboundRelayer: From<AccountId32>,
+	<Runtime as pallet_utility::Config>::RuntimeCall:
+	From<pallet_bridge_grandpa::Call<Runtime, GPI>>
+	+ From<pallet_bridge_parachains::Call<Runtime, PPI>>
+	+ From<pallet_bridge_messages::Call<Runtime, MPI>>
+{
+	assert_ne!(runtime_para_id, sibling_parachain_id);
+	let sibling_parachain_location = MultiLocation::new(1, Parachain(sibling_parachain_id));
+	let sibling_parachain_account =
+		LocationToAccountId::convert_location(&sibling_parachain_location)
+			.expect("sibling parachain has a sovereign account");
+	let export_message_fee_amount = match export_message_fee.fun {
+		Fungible(amount) => amount,
+		NonFungible(_) => panic!("export message fee must be fungible"),
+	};
+	let (export_network, export_destination, export_xcm) = match export_message_instruction() {
+		ExportMessage { network, destination, xcm } => (network, destination, xcm),
+		_ => panic!("export message instruction must be `ExportMessage`"),
+	};
+
+	// Relayer accounts at local/this BH, submitting delivery and confirmation transactions.
+	let relayer_at_target = Bob;
+	let relayer_id_on_target: AccountIdOf<Runtime> = relayer_at_target.public().into();
+	let confirmation_relayer_at_target = Eve;
+	let confirmation_relayer_id_on_target: AccountIdOf<Runtime> =
+		confirmation_relayer_at_target.public().into();
+	let relayer_initial_balance = existential_deposit * 100000u32.into();
+	// Relayer account at remote/bridged BH.
+	let relayer_at_source = Dave;
+	let relayer_id_on_source: AccountId32 = relayer_at_source.public().into();
+	// Relayer that has delivered our exported message to the bridged BH.
+	let delivery_relayer_at_source = Charlie;
+	let delivery_relayer_id_on_target: AccountId32 = delivery_relayer_at_source.public().into();
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(collator_session_key.collators())
+		.with_session_keys(collator_session_key.session_keys())
+		.with_safe_xcm_version(XCM_VERSION)
+		.with_para_id(runtime_para_id.into())
+		.with_balances(vec![
+			(sibling_parachain_account.clone(), existential_deposit),
+			(relayer_id_on_target.clone(), relayer_initial_balance),
+			(confirmation_relayer_id_on_target.clone(), relayer_initial_balance),
+		])
+		.with_tracing()
+		.build()
+		.execute_with(|| {
+			prepare_configuration();
+
+			let mut alice = [0u8; 32];
+			alice[0] = 1;
+
+			let included_head = RuntimeHelper::<Runtime, AllPalletsWithoutSystem>::run_to_block(
+				2,
+				AccountId::from(alice).into(),
+			);
+			let zero: BlockNumberFor<Runtime> = 0u32.into();
+			let genesis_hash = frame_system::Pallet::<Runtime>::block_hash(zero);
+			let mut header: HeaderFor<Runtime> = bp_test_utils::test_header(1u32.into());
+			header.set_parent_hash(genesis_hash);
+			executive_init_block(&header);
+
+			mock_open_hrmp_channel::<Runtime, HrmpChannelOpener>(
+				runtime_para_id.into(),
+				sibling_parachain_id.into(),
+				included_head,
+				&alice,
+			);
+
+			// start with bridged chain block#0
+			let init_data = test_data::initialization_data::<Runtime, GPI>(0);
+			pallet_bridge_grandpa::Pallet::<Runtime, GPI>::initialize(
+				RuntimeHelper::<Runtime>::root_origin(),
+				init_data,
+			)
+			.unwrap();
+
+			// 1. sibling asset hub exports a paid message over the bridge
+
+			// deposit fee to sibling parachain sovereign account
+			XcmConfig::AssetTransactor::deposit_asset(
+				&export_message_fee,
+				&sibling_parachain_location,
+				Some(&XcmContext::with_message_id([0; 32])),
+			)
+			.expect("deposited fee");
+			let sibling_balance_before: u128 =
+				pallet_balances::Pallet::<Runtime>::free_balance(&sibling_parachain_account).into();
+
+			let mut xcm = Xcm(vec![
+				WithdrawAsset(MultiAssets::from(vec![export_message_fee.clone()])),
+				BuyExecution { fees: export_message_fee, weight_limit: Unlimited },
+				export_message_instruction(),
+			]);
+			let estimated_weight = XcmConfig::Weigher::weight(&mut xcm).expect("weighable xcm");
+			let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
+			let outcome = XcmExecutor::<XcmConfig>::execute_xcm(
+				sibling_parachain_location,
+				xcm,
+				hash,
+				RuntimeHelper::<Runtime>::xcm_max_weight(XcmReceivedFrom::Sibling),
+			);
+			assert_ok!(outcome.clone().ensure_complete());
+			// verify export does not consume more than estimated
+			let used_weight = outcome.weight_used();
+			assert!(
+				used_weight.all_lte(estimated_weight),
+				"used weight: {:?}, estimated weight: {:?}",
+				used_weight,
+				estimated_weight,
+			);
+			// verify the whole fee has been paid by the sibling parachain
+			let sibling_balance_after: u128 =
+				pallet_balances::Pallet::<Runtime>::free_balance(&sibling_parachain_account).into();
+			assert_eq!(sibling_balance_after, sibling_balance_before - export_message_fee_amount);
+			// verify message is queued for delivery
+			assert_eq!(
+				pallet_bridge_messages::OutboundLanes::<Runtime, MPI>::get(lane_id),
+				OutboundLaneData {
+					oldest_unpruned_nonce: 1,
+					latest_received_nonce: 0,
+					latest_generated_nonce: 1,
+				},
+			);
+			assert!(<frame_system::Pallet<Runtime>>::events()
+				.into_iter()
+				.filter_map(|e| unwrap_pallet_bridge_messages_event(e.event.encode()))
+				.any(|e| matches!(e, pallet_bridge_messages::Event::MessageAccepted { .. })));
+			// verify the queued message is the one the bridged bridge hub expects to dispatch
+			let payload = pallet_bridge_messages::OutboundMessages::<Runtime, MPI>::get(
+				MessageKey { lane_id, nonce: 1 },
+			)
+			.expect("exported message is stored");
+			let xcm_builder::BridgeMessage { universal_dest, message } =
+				Decode::decode(&mut &payload[..]).expect("valid bridge message");
+			let universal_dest: InteriorMultiLocation =
+				universal_dest.try_into().expect("supported location version");
+			assert_eq!(
+				universal_dest,
+				export_destination
+					.pushed_front_with(GlobalConsensus(export_network))
+					.expect("destination is not too long"),
+			);
+			let message: Xcm<()> = message.try_into().expect("supported xcm version");
+			let mut expected_message = Xcm(vec![
+				UniversalOrigin(GlobalConsensus(local_relay_chain_id)),
+				DescendOrigin(X1(Parachain(sibling_parachain_id))),
+			]);
+			expected_message.0.extend(export_xcm.0);
+			assert_eq!(message, expected_message);
+
+			// 2. relayer delivers a message from the bridged chain to the sibling asset hub
+
+			let message_destination =
+				X2(GlobalConsensus(local_relay_chain_id), Parachain(sibling_parachain_id));
+			let message_nonce = 1;
+			let xcm = vec![xcm::latest::Instruction::<()>::ClearOrigin; 42];
+			let (
+				relay_chain_header,
+				grandpa_justification,
+				_,
+				parachain_heads,
+				para_heads_proof,
+				message_proof,
+			) = test_data::make_complex_relayer_delivery_proofs::<
+				<Runtime as pallet_bridge_grandpa::Config<GPI>>::BridgedChain,
+				MB,
+				(),
+			>(
+				lane_id,
+				xcm.clone().into(),
+				message_nonce,
+				message_destination,
+				5,
+				1,
+				bridged_para_id,
+			);
+			let batch = test_data::make_complex_relayer_delivery_batch::<Runtime, GPI, PPI, MPI>(
+				relay_chain_header,
+				grandpa_justification,
+				parachain_heads,
+				para_heads_proof,
+				message_proof,
+				relayer_id_on_source.clone(),
+			);
+			// verify dispatch weight declared by relayer covers the message dispatch
+			let payload = test_data::prepare_inbound_xcm(Xcm::<()>(xcm), message_destination);
+			let mut message = test_data::dispatch_message(
+				lane_id,
+				message_nonce,
+				Decode::decode(&mut &payload[..]).expect("valid message payload"),
+			);
+			let dispatch_weight =
+				<<Runtime as pallet_bridge_messages::Config<MPI>>::MessageDispatch>::dispatch_weight(
+					&mut message,
+				);
+			assert!(
+				dispatch_weight.all_lte(test_data::DELIVERY_DISPATCH_WEIGHT),
+				"dispatch weight: {:?}, declared: {:?}",
+				dispatch_weight,
+				test_data::DELIVERY_DISPATCH_WEIGHT,
+			);
+
+			let msg_proofs_rewards_account = RewardsAccountParams::new(
+				lane_id,
+				bridged_chain_id,
+				RewardsAccountOwner::ThisChain,
+			);
+			assert_eq!(
+				pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+					relayer_id_on_target.clone(),
+					msg_proofs_rewards_account
+				),
+				None,
+			);
+			// the refund doesn't cover the extra weight of slashing the relayer
+			let delivery_refund = apply_relayer_batch_and_compute_refund::<Runtime>(
+				construct_and_apply_extrinsic,
+				relayer_at_target,
+				batch,
+				<<Runtime as pallet_bridge_relayers::Config>::WeightInfo as pallet_bridge_relayers::WeightInfoExt>::extra_weight_of_successful_receive_messages_proof_call(),
+			);
+
+			// verify message is dispatched to the sibling asset hub, and the relayer at the
+			// bridged chain is recorded to be rewarded there
+			let inbound_lane = pallet_bridge_messages::InboundLanes::<Runtime, MPI>::get(lane_id);
+			assert_eq!(inbound_lane.last_delivered_nonce(), message_nonce);
+			assert_eq!(
+				inbound_lane.relayers.iter().map(|r| r.relayer.clone()).collect::<Vec<_>>(),
+				vec![relayer_id_on_source.into()],
+			);
+			assert!(RuntimeHelper::<cumulus_pallet_xcmp_queue::Pallet<Runtime>>::take_xcm(
+				sibling_parachain_id.into(),
+			)
+			.is_some());
+			// verify relayer is refunded for delivery
+			assert_eq!(
+				pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+					relayer_id_on_target.clone(),
+					msg_proofs_rewards_account
+				)
+				.map(Into::into),
+				Some(delivery_refund),
+			);
+			assert_eq!(pallet_bridge_relayers::RelayerRewards::<Runtime>::iter().count(), 1);
+
+			// 3. relayer confirms delivery of the exported message
+
+			let unrewarded_relayers = UnrewardedRelayersState {
+				unrewarded_relayer_entries: 1,
+				messages_in_oldest_entry: 1,
+				total_messages: 1,
+				last_delivered_nonce: 1,
+			};
+			let (
+				relay_chain_header,
+				grandpa_justification,
+				_,
+				parachain_heads,
+				para_heads_proof,
+				message_delivery_proof,
+			) = test_data::make_complex_relayer_confirmation_proofs::<
+				<Runtime as pallet_bridge_grandpa::Config<GPI>>::BridgedChain,
+				MB,
+				(),
+			>(
+				lane_id,
+				6,
+				2,
+				bridged_para_id,
+				delivery_relayer_id_on_target.clone(),
+				unrewarded_relayers.clone(),
+			);
+			let batch = test_data::make_complex_relayer_confirmation_batch::<Runtime, GPI, PPI, MPI>(
+				relay_chain_header,
+				grandpa_justification,
+				parachain_heads,
+				para_heads_proof,
+				message_delivery_proof,
+				unrewarded_relayers,
+			);
+			let confirmation_refund = apply_relayer_batch_and_compute_refund::<Runtime>(
+				construct_and_apply_extrinsic,
+				confirmation_relayer_at_target,
+				batch,
+				Weight::zero(),
+			);
+
+			// verify message is confirmed
+			assert_eq!(
+				pallet_bridge_messages::OutboundLanes::<Runtime, MPI>::get(lane_id)
+					.latest_received_nonce,
+				1,
+			);
+			assert!(<frame_system::Pallet<Runtime>>::events()
+				.into_iter()
+				.filter_map(|e| unwrap_pallet_bridge_messages_event(e.event.encode()))
+				.any(|e| matches!(e, pallet_bridge_messages::Event::MessagesDelivered { .. })));
+			// verify relayers are rewarded for delivery (at bridged chain) and confirmation
+			let confirmation_rewards_account = RewardsAccountParams::new(
+				lane_id,
+				bridged_chain_id,
+				RewardsAccountOwner::BridgedChain,
+			);
+			assert_eq!(
+				pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+					AccountIdOf::<Runtime>::from(delivery_relayer_id_on_target),
+					confirmation_rewards_account
+				)
+				.map(Into::into),
+				Some(delivery_reward),
+			);
+			assert_eq!(
+				pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+					confirmation_relayer_id_on_target,
+					confirmation_rewards_account
+				)
+				.map(Into::into),
+				Some(confirmation_refund),
+			);
+			// verify the delivery refund is untouched and nobody else is rewarded
+			assert_eq!(
+				pallet_bridge_relayers::RelayerRewards::<Runtime>::get(
+					relayer_id_on_target,
+					msg_proofs_rewards_account
+				)
+				.map(Into::into),
+				Some(delivery_refund),
+			);
+			assert_eq!(pallet_bridge_relayers::RelayerRewards::<Runtime>::iter().count(), 3);
+		})
+}
+
+/// Submits relayer `batch` on behalf of `relayer` and returns the refund that the relayer is
+/// expected to be rewarded with: the fee it has paid, without the fee for `unrefunded_weight`.
+fn apply_relayer_batch_and_compute_refund<Runtime>(
+	construct_and_apply_extrinsic: fn(
+		sp_keyring::AccountKeyring,
+		pallet_utility::Call::<Runtime>
+	) -> sp_runtime::DispatchOutcome,
+	relayer: sp_keyring::AccountKeyring,
+	batch: pallet_utility::Call<Runtime>,
+	unrefunded_weight: Weight,
+) -> u128
+where
+	Runtime: frame_system::Config
+		+ pallet_balances::Config
+		+ pallet_utility::Config
+		+ pallet_transaction_payment::Config,
+	<Runtime as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	<<Runtime as pallet_transaction_payment::Config>::OnChargeTransaction as pallet_transaction_payment::OnChargeTransaction<Runtime>>::Balance: Into<u128>,
+	AccountIdOf<Runtime>: From<sp_core::sr25519::Public>,
+	BalanceOf<Runtime>: Into<u128>,
+{
+	let relayer_id: AccountIdOf<Runtime> = relayer.public().into();
+	let info = batch.get_dispatch_info();
+	let base_extrinsic = <Runtime as frame_system::Config>::BlockWeights::get()
+		.get(info.class)
+		.base_extrinsic;
+	let balance_before: u128 = pallet_balances::Pallet::<Runtime>::free_balance(&relayer_id).into();
+	let block_weight_before = *frame_system::Pallet::<Runtime>::block_weight().get(info.class);
+
+	assert_ok!(construct_and_apply_extrinsic(relayer, batch));
+
+	// the block weight grows by the actual weight of the extrinsic
+	let actual_weight = frame_system::Pallet::<Runtime>::block_weight()
+		.get(info.class)
+		.saturating_sub(block_weight_before)
+		.saturating_sub(base_extrinsic);
+	let balance_after: u128 = pallet_balances::Pallet::<Runtime>::free_balance(&relayer_id).into();
+	let fee_paid = balance_before - balance_after;
+
+	// the length fee is the same for both weights, so it cancels out
+	let weight_fee = |weight: Weight| -> u128 {
+		pallet_transaction_payment::Pallet::<Runtime>::compute_actual_fee(
+			0,
+			&info,
+			&PostDispatchInfo { actual_weight: Some(weight), pays_fee: Pays::Yes },
+			Zero::zero(),
+		)
+		.into()
+	};
+	fee_paid - weight_fee(actual_weight) +
+		weight_fee(actual_weight.saturating_sub(unrefunded_weight))
+}
+
 /// Estimates XCM execution fee for paid `ExportMessage` processing.
 pub fn can_calculate_weight_for_paid_export_message_with_reserve_transfer<
 	Runtime,
@@ -1152,6 +1632,9 @@ pub mod test_data {
 	use xcm_builder::{HaulBlob, HaulBlobError, HaulBlobExporter};
 	use xcm_executor::traits::{validate_export, ExportXcm};
 
+	/// Dispatch weight declared by relayer in the message delivery batch.
+	pub const DELIVERY_DISPATCH_WEIGHT: Weight = Weight::from_parts(1000000000, 0);
+
 	pub fn prepare_inbound_xcm<InnerXcmRuntimeCall>(
 		xcm_message: Xcm<InnerXcmRuntimeCall>,
 		destination: InteriorMultiLocation,
@@ -1210,7 +1693,7 @@ pub mod test_data {
 			relayer_id_at_bridged_chain: relayer_id_at_bridged_chain.into(),
 			proof: message_proof.into(),
 			messages_count: 1,
-			dispatch_weight: Weight::from_parts(1000000000, 0),
+			dispatch_weight: DELIVERY_DISPATCH_WEIGHT,
 		};
 		pallet_utility::Call::<Runtime>::batch_all {
 			calls: vec![submit_grandpa.into(), submit_para_head.into(), submit_message.into()],
@@ -132,6 +132,14 @@ const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
 const BLOCK_PROCESSING_VELOCITY: u32 = 2;
 /// Relay chain slot duration, in milliseconds.
 const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
+/// Collators skip slots while the unincluded segment is about to be full or no block got
+/// included for a few relay chain blocks.
+const BACKOFF_PARAMS: cumulus_primitives_aura::BackoffParams =
+	cumulus_primitives_aura::BackoffParams {
+		unincluded_segment_threshold: UNINCLUDED_SEGMENT_CAPACITY - 1,
+		inclusion_stall_threshold: 4,
+		max_skipped_slots: 4,
+	};
 
 /// This determines the average expected block time that we are targeting.
 /// Blocks will be produced at a minimum duration defined by `SLOT_DURATION`.
@@ -192,6 +200,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 parameter_types! {
@@ -244,6 +253,8 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = (); // No XCMP queue pallet deployed.
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
+	type MaxDeadLetters = sp_core::ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
@@ -400,6 +411,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
 		fn can_build_upon(
 			included_hash: <Block as BlockT>::Hash,
@@ -407,6 +419,10 @@ impl_runtime_apis! {
 		) -> bool {
 			ConsensusHook::can_build_upon(included_hash, slot)
 		}
+
+		fn backoff_params() -> cumulus_primitives_aura::BackoffParams {
+			BACKOFF_PARAMS
+		}
 	}
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
@@ -83,6 +83,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = ();
 	type Aliasers = Nothing;
 }
 
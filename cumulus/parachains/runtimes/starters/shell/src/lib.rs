@@ -179,6 +179,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 parameter_types! {
@@ -230,6 +231,8 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = ();
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
+	type MaxDeadLetters = sp_core::ConstU32<64>;
+	type DeadLetterOrigin = frame_system::EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
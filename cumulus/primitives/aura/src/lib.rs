@@ -21,8 +21,29 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+
 pub use sp_consensus_aura::Slot;
 
+/// Parameters for collators to back off from authoring while their blocks are not getting
+/// included in the relay chain.
+///
+/// While backing off, collators skip slots to avoid building and distributing collations which
+/// are unlikely to ever be backed. The number of skipped slots between two blocks grows by one
+/// with every block the unincluded segment exceeds `unincluded_segment_threshold`, and with every
+/// relay chain block exceeding `inclusion_stall_threshold` without any para block being included.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub struct BackoffParams {
+	/// The length of the unincluded segment from which on collators back off.
+	pub unincluded_segment_threshold: u32,
+	/// The number of relay chain blocks without any para block being included from which on
+	/// collators back off. `0` disables this kind of backoff.
+	pub inclusion_stall_threshold: u32,
+	/// The maximum number of slots skipped between two blocks.
+	pub max_skipped_slots: u32,
+}
+
 sp_api::decl_runtime_apis! {
 	/// This runtime API is used to inform potential block authors whether they will
 	/// have the right to author at a slot, assuming they have claimed the slot.
@@ -46,5 +67,10 @@ sp_api::decl_runtime_apis! {
 		/// whose state we are querying against, this must always return `true` as long as the slot
 		/// is more recent than the included block itself.
 		fn can_build_upon(included_hash: Block::Hash, slot: Slot) -> bool;
+
+		/// The parameters collators should use to back off from authoring when blocks are not
+		/// getting included.
+		#[api_version(2)]
+		fn backoff_params() -> BackoffParams;
 	}
 }
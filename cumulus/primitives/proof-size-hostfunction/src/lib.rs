@@ -0,0 +1,74 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tools for reclaiming PoV weight in parachain runtimes.
+//!
+//! Provides the [`storage_proof_size`] host function, which returns the size of the storage proof
+//! recorded so far. The host reads it from the [`ProofSizeExt`] registered by the block builder or
+//! the block import, while `validate_block` provides its own implementation based on the nodes
+//! read from the storage proof of the candidate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_runtime_interface::runtime_interface;
+
+#[cfg(feature = "std")]
+use sp_externalities::ExternalitiesExt;
+#[cfg(feature = "std")]
+use sp_trie::proof_size_extension::ProofSizeExt;
+
+/// Value returned by [`storage_proof_size`] if proof recording is not enabled.
+pub const PROOF_RECORDING_DISABLED: u64 = u64::MAX;
+
+/// Interface that provides access to the current storage proof size.
+///
+/// Should return the current storage proof size if [`ProofSizeExt`] is registered. Otherwise, it
+/// returns [`PROOF_RECORDING_DISABLED`].
+#[runtime_interface]
+pub trait StorageProofSize {
+	/// Returns the current storage proof size.
+	fn storage_proof_size(&mut self) -> u64 {
+		self.extension::<ProofSizeExt>()
+			.map_or(PROOF_RECORDING_DISABLED, |e| e.storage_proof_size())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_trie::ProofSizeProvider;
+
+	struct TestProofSizeProvider(usize);
+
+	impl ProofSizeProvider for TestProofSizeProvider {
+		fn estimate_encoded_size(&self) -> usize {
+			self.0
+		}
+	}
+
+	#[test]
+	fn storage_proof_size_is_read_from_extension() {
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			assert_eq!(storage_proof_size::storage_proof_size(), PROOF_RECORDING_DISABLED);
+		});
+
+		ext.register_extension(ProofSizeExt::new(TestProofSizeProvider(42)));
+		ext.execute_with(|| {
+			assert_eq!(storage_proof_size::storage_proof_size(), 42);
+		});
+	}
+}
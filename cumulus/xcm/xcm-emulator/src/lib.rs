@@ -38,7 +38,11 @@ pub use frame_system::{Config as SystemConfig, Pallet as SystemPallet};
 pub use pallet_balances::AccountData;
 pub use pallet_message_queue;
 pub use sp_arithmetic::traits::Bounded;
-pub use sp_core::{blake2_256, parameter_types, sr25519, storage::Storage, Pair};
+pub use sp_core::{
+	blake2_256, parameter_types, sr25519,
+	storage::{StateVersion, Storage},
+	Pair, H256,
+};
 pub use sp_io::TestExternalities;
 pub use sp_runtime::BoundedSlice;
 pub use sp_std::{cell::RefCell, collections::vec_deque::VecDeque, fmt::Debug};
@@ -92,6 +96,85 @@ thread_local! {
 	pub static LAST_HEAD: RefCell<HashMap<String, HashMap<u32, HeadData>>> = RefCell::new(HashMap::new());
 }
 
+lazy_static! {
+	/// Snapshots of the externalities of emulated chains, shared by all test threads.
+	static ref EXT_SNAPSHOTS: Mutex<HashMap<String, ExtSnapshot>> = Mutex::new(HashMap::new());
+	/// Snapshots of the network wide state, stored next to the snapshots of the chains.
+	static ref NETWORK_SNAPSHOTS: Mutex<HashMap<String, NetworkSnapshot>> = Mutex::new(HashMap::new());
+}
+
+/// The storage of an emulated chain, in a format which is cheap to restore.
+///
+/// Restoring a snapshot does not require the storage root to be recomputed, which makes it a lot
+/// faster than building the genesis state of a chain from scratch.
+#[derive(Clone)]
+pub struct ExtSnapshot {
+	raw_storage: Vec<(Vec<u8>, (Vec<u8>, i32))>,
+	storage_root: H256,
+}
+
+impl ExtSnapshot {
+	/// Take a snapshot of `ext`, including all of its pending changes.
+	pub fn new(mut ext: TestExternalities) -> Self {
+		ext.commit_all().expect("no storage transaction is open between blocks; qed");
+		let (raw_storage, storage_root) = ext.into_raw_snapshot();
+		Self { raw_storage, storage_root }
+	}
+
+	/// Build new externalities from the snapshot.
+	pub fn restore(&self) -> TestExternalities {
+		TestExternalities::from_raw_snapshot(
+			self.raw_storage.clone(),
+			self.storage_root,
+			StateVersion::default(),
+		)
+	}
+}
+
+/// The network wide state which is not part of the storage of any chain.
+#[derive(Clone)]
+pub struct NetworkSnapshot {
+	pub last_head: HashMap<u32, HeadData>,
+	pub dmp_done: VecDeque<(u32, RelayBlockNumber, Vec<u8>)>,
+}
+
+/// The key of the snapshot `id` of the chain or network `T`.
+pub fn snapshot_key<T>(id: &str) -> String {
+	format!("{}::{}", type_name::<T>(), id)
+}
+
+/// Store a snapshot of `ext` under `key` and return externalities restored from it in its place.
+pub fn store_ext_snapshot(key: String, ext: TestExternalities) -> TestExternalities {
+	let snapshot = ExtSnapshot::new(ext);
+	let ext = snapshot.restore();
+	EXT_SNAPSHOTS.lock().unwrap().insert(key, snapshot);
+	ext
+}
+
+/// Externalities restored from the snapshot stored under `key`, if there is one.
+pub fn restore_ext_snapshot(key: &str) -> Option<TestExternalities> {
+	EXT_SNAPSHOTS.lock().unwrap().get(key).map(ExtSnapshot::restore)
+}
+
+/// Externalities restored from the snapshot stored under `key`, or built by `build` and stored
+/// under `key` if there is no such snapshot yet.
+pub fn ext_from_snapshot(
+	key: &str,
+	build: impl FnOnce() -> TestExternalities,
+) -> TestExternalities {
+	restore_ext_snapshot(key).unwrap_or_else(|| store_ext_snapshot(key.to_string(), build()))
+}
+
+/// Store the network wide state under `key`.
+pub fn store_network_snapshot(key: String, snapshot: NetworkSnapshot) {
+	NETWORK_SNAPSHOTS.lock().unwrap().insert(key, snapshot);
+}
+
+/// The network wide state stored under `key`, if there is one.
+pub fn network_snapshot(key: &str) -> Option<NetworkSnapshot> {
+	NETWORK_SNAPSHOTS.lock().unwrap().get(key).cloned()
+}
+
 pub trait CheckAssertion<Origin, Destination, Hops, Args>
 where
 	Origin: Chain + Clone,
@@ -127,6 +210,12 @@ pub trait TestExt {
 	fn move_ext_out(id: &'static str);
 	fn move_ext_in(id: &'static str);
 	fn reset_ext();
+	/// Store a snapshot of the current externalities under `id`, shared by all test threads.
+	fn snapshot_ext(id: &'static str);
+	/// Replace the current externalities by the snapshot stored under `id`.
+	///
+	/// Returns `false` if there is no such snapshot.
+	fn restore_ext(id: &'static str) -> bool;
 	fn execute_with<R>(execute: impl FnOnce() -> R) -> R;
 	fn ext_wrapper<R>(func: impl FnOnce() -> R) -> R;
 }
@@ -141,6 +230,10 @@ impl TestExt for () {
 	fn move_ext_out(_id: &'static str) {}
 	fn move_ext_in(_id: &'static str) {}
 	fn reset_ext() {}
+	fn snapshot_ext(_id: &'static str) {}
+	fn restore_ext(_id: &'static str) -> bool {
+		false
+	}
 	fn execute_with<R>(execute: impl FnOnce() -> R) -> R {
 		execute()
 	}
@@ -156,6 +249,26 @@ pub trait Network {
 	fn name() -> &'static str;
 	fn init();
 	fn reset();
+	/// Store a snapshot of all chains of the network under `id`.
+	///
+	/// Snapshots are shared by all test threads, so a state which is expensive to set up can be
+	/// reused by many tests. There must not be any unprocessed messages.
+	fn snapshot(id: &'static str);
+	/// Reset the network to the snapshot stored under `id`.
+	///
+	/// Returns `false` and leaves the network untouched if there is no such snapshot.
+	fn restore(id: &'static str) -> bool;
+	/// Reset the network to the snapshot stored under `id`, or create the snapshot by running
+	/// `setup` on a freshly reset network if it does not exist yet.
+	///
+	/// Meant to be called at the beginning of every test sharing the same `setup`.
+	fn restore_or_setup(id: &'static str, setup: impl FnOnce()) {
+		if !Self::restore(id) {
+			Self::reset();
+			setup();
+			Self::snapshot(id);
+		}
+	}
 	fn para_ids() -> Vec<u32>;
 	fn relay_block_number() -> u32;
 	fn set_relay_block_number(number: u32);
@@ -382,9 +495,7 @@ macro_rules! decl_test_relay_chains {
 				fn init() {
 					use $crate::TestExt;
 					// Initialize the thread local variable
-					$crate::paste::paste! {
-						[<LOCAL_EXT_ $name:upper>].with(|v| *v.borrow_mut() = Self::build_new_ext($genesis));
-					}
+					Self::reset_ext();
 				}
 			}
 
@@ -428,7 +539,10 @@ macro_rules! __impl_test_ext_for_relay_chain {
 	(@impl $name:ident, $network:ident, $genesis:expr, $on_init:expr, $api_version:ident, $local_ext:ident, $global_ext:ident) => {
 		thread_local! {
 			pub static $local_ext: $crate::RefCell<$crate::TestExternalities>
-				= $crate::RefCell::new($crate::TestExternalities::new($genesis));
+				= $crate::RefCell::new($crate::ext_from_snapshot(
+					concat!(module_path!(), "::", stringify!($name), "::raw_genesis"),
+					|| $crate::TestExternalities::new($genesis),
+				));
 		}
 
 		$crate::lazy_static! {
@@ -506,7 +620,29 @@ macro_rules! __impl_test_ext_for_relay_chain {
 			}
 
 			fn reset_ext() {
-				$local_ext.with(|v| *v.borrow_mut() = Self::build_new_ext($genesis));
+				let ext = $crate::ext_from_snapshot(
+					&$crate::snapshot_key::<Self>("genesis"),
+					|| Self::build_new_ext($genesis),
+				);
+				$local_ext.with(|v| *v.borrow_mut() = ext);
+			}
+
+			fn snapshot_ext(id: &'static str) {
+				let key = $crate::snapshot_key::<Self>(id);
+				$local_ext.with(|v| {
+					let ext = v.take();
+					*v.borrow_mut() = $crate::store_ext_snapshot(key, ext);
+				});
+			}
+
+			fn restore_ext(id: &'static str) -> bool {
+				match $crate::restore_ext_snapshot(&$crate::snapshot_key::<Self>(id)) {
+					Some(ext) => {
+						$local_ext.with(|v| *v.borrow_mut() = ext);
+						true
+					},
+					None => false,
+				}
 			}
 
 			fn execute_with<R>(execute: impl FnOnce() -> R) -> R {
@@ -622,9 +758,7 @@ macro_rules! decl_test_parachains {
 				fn init() {
 					use $crate::{Chain, HeadData, Network, Hooks, Encode, Parachain, TestExt};
 					// Initialize the thread local variable
-					$crate::paste::paste! {
-						[<LOCAL_EXT_ $name:upper>].with(|v| *v.borrow_mut() = Self::build_new_ext($genesis));
-					}
+					Self::reset_ext();
 					// Set the last block head for later use in the next block
 					Self::set_last_head();
 					// Initialize a new block
@@ -725,7 +859,10 @@ macro_rules! __impl_test_ext_for_parachain {
 	(@impl $name:ident, $network:ident, $genesis:expr, $on_init:expr, $local_ext:ident, $global_ext:ident) => {
 		thread_local! {
 			pub static $local_ext: $crate::RefCell<$crate::TestExternalities>
-				= $crate::RefCell::new($crate::TestExternalities::new($genesis));
+				= $crate::RefCell::new($crate::ext_from_snapshot(
+					concat!(module_path!(), "::", stringify!($name), "::raw_genesis"),
+					|| $crate::TestExternalities::new($genesis),
+				));
 		}
 
 		$crate::lazy_static! {
@@ -801,7 +938,29 @@ macro_rules! __impl_test_ext_for_parachain {
 			}
 
 			fn reset_ext() {
-				$local_ext.with(|v| *v.borrow_mut() = Self::build_new_ext($genesis));
+				let ext = $crate::ext_from_snapshot(
+					&$crate::snapshot_key::<Self>("genesis"),
+					|| Self::build_new_ext($genesis),
+				);
+				$local_ext.with(|v| *v.borrow_mut() = ext);
+			}
+
+			fn snapshot_ext(id: &'static str) {
+				let key = $crate::snapshot_key::<Self>(id);
+				$local_ext.with(|v| {
+					let ext = v.take();
+					*v.borrow_mut() = $crate::store_ext_snapshot(key, ext);
+				});
+			}
+
+			fn restore_ext(id: &'static str) -> bool {
+				match $crate::restore_ext_snapshot(&$crate::snapshot_key::<Self>(id)) {
+					Some(ext) => {
+						$local_ext.with(|v| *v.borrow_mut() = ext);
+						true
+					},
+					None => false,
+				}
 			}
 
 			fn execute_with<R>(execute: impl FnOnce() -> R) -> R {
@@ -928,6 +1087,46 @@ macro_rules! decl_test_networks {
 					$( <$parachain<Self>>::reset_ext(); )*
 				}
 
+				fn snapshot(id: &'static str) {
+					use $crate::{Network, TestExt};
+
+					Self::init();
+					assert!(
+						!Self::has_unprocessed_messages(),
+						"can not snapshot network `{}` with unprocessed messages",
+						Self::name(),
+					);
+
+					<$relay_chain<Self>>::snapshot_ext(id);
+					$( <$parachain<Self>>::snapshot_ext(id); )*
+
+					// Stored last, so that the snapshots of all chains exist once this one does.
+					let last_head = $crate::LAST_HEAD.with(|b| b.borrow().get(Self::name()).cloned().unwrap_or_default());
+					let dmp_done = $crate::DMP_DONE.with(|b| b.borrow().get(Self::name()).cloned().unwrap_or_default());
+					$crate::store_network_snapshot(
+						$crate::snapshot_key::<Self>(id),
+						$crate::NetworkSnapshot { last_head, dmp_done },
+					);
+				}
+
+				fn restore(id: &'static str) -> bool {
+					use $crate::{Network, TestExt};
+
+					let Some(snapshot) = $crate::network_snapshot(&$crate::snapshot_key::<Self>(id)) else {
+						return false
+					};
+
+					Self::reset();
+					Self::init();
+
+					$crate::LAST_HEAD.with(|b| b.borrow_mut().insert(Self::name().to_string(), snapshot.last_head));
+					$crate::DMP_DONE.with(|b| b.borrow_mut().insert(Self::name().to_string(), snapshot.dmp_done));
+
+					let restored = <$relay_chain<Self>>::restore_ext(id) $( && <$parachain<Self>>::restore_ext(id) )*;
+					assert!(restored, "snapshot `{}` of network `{}` is incomplete", id, Self::name());
+					true
+				}
+
 				fn init() {
 					// If Network has not been initialized yet, it gets initialized
 					if $crate::INITIALIZED.with(|b| b.borrow_mut().get(Self::name()).is_none()) {
@@ -1555,3 +1754,56 @@ pub mod helpers {
 		sp_runtime::MultiSigner::from(pubkey).into_account()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn genesis() -> TestExternalities {
+		TestExternalities::new(Storage {
+			top: [(b"genesis".to_vec(), b"value".to_vec())].into(),
+			children_default: Default::default(),
+		})
+	}
+
+	fn storage_value(ext: &mut TestExternalities, key: &[u8]) -> Option<Vec<u8>> {
+		ext.execute_with(|| sp_io::storage::get(key).map(|value| value.to_vec()))
+	}
+
+	#[test]
+	fn ext_snapshot_restores_storage_including_pending_changes() {
+		let mut ext = genesis();
+		ext.execute_with(|| sp_io::storage::set(b"pending", b"change"));
+		let expected_root = ext.execute_with(|| sp_io::storage::root(StateVersion::default()));
+
+		let snapshot = ExtSnapshot::new(ext);
+		let mut restored = snapshot.restore();
+		assert_eq!(storage_value(&mut restored, b"genesis"), Some(b"value".to_vec()));
+		assert_eq!(storage_value(&mut restored, b"pending"), Some(b"change".to_vec()));
+		assert_eq!(
+			restored.execute_with(|| sp_io::storage::root(StateVersion::default())),
+			expected_root
+		);
+
+		// Changes to restored externalities do not affect the snapshot.
+		restored.execute_with(|| sp_io::storage::clear(b"genesis"));
+		assert_eq!(storage_value(&mut snapshot.restore(), b"genesis"), Some(b"value".to_vec()));
+	}
+
+	#[test]
+	fn ext_from_snapshot_builds_once() {
+		let key = snapshot_key::<ExtSnapshot>("ext_from_snapshot_builds_once");
+		assert!(restore_ext_snapshot(&key).is_none());
+
+		let mut builds = 0;
+		for _ in 0..2 {
+			let mut ext = ext_from_snapshot(&key, || {
+				builds += 1;
+				genesis()
+			});
+			assert_eq!(storage_value(&mut ext, b"genesis"), Some(b"value".to_vec()));
+			ext.execute_with(|| sp_io::storage::set(b"genesis", b"changed"));
+		}
+		assert_eq!(builds, 1);
+	}
+}
@@ -96,6 +96,15 @@ pub const fn recovery_threshold(n_validators: usize) -> Result<usize, Error> {
 	Ok(needed + 1)
 }
 
+/// Obtain the threshold of systematic chunks that should be enough to recover the data.
+///
+/// The first `systematic_recovery_threshold` chunks contain the original data verbatim, so it can
+/// be recovered from them without decoding. If the regular `recovery_threshold` is a power of two,
+/// this is the same value, otherwise it is a lower power of two.
+pub fn systematic_recovery_threshold(n_validators: usize) -> Result<usize, Error> {
+	code_params(n_validators).map(|params| params.k())
+}
+
 fn code_params(n_validators: usize) -> Result<CodeParams, Error> {
 	// we need to be able to reconstruct from 1/3 - eps
 
@@ -204,6 +213,55 @@ where
 	Decode::decode(&mut &payload_bytes[..]).or_else(|_e| Err(Error::BadPayload))
 }
 
+/// Reconstruct the v1 available data from the set of systematic chunks.
+///
+/// Provide a vector containing the data of the first `systematic_recovery_threshold` chunks, in
+/// order of their index. If too few chunks are provided, recovery is not possible.
+pub fn reconstruct_from_systematic_v1(
+	n_validators: usize,
+	chunks: Vec<Vec<u8>>,
+) -> Result<AvailableData, Error> {
+	reconstruct_from_systematic(n_validators, chunks)
+}
+
+/// Reconstruct decodable data from the set of systematic chunks.
+///
+/// Provide a vector containing the data of the first `systematic_recovery_threshold` chunks, in
+/// order of their index. If too few chunks are provided, recovery is not possible.
+///
+/// This is much cheaper than `reconstruct`, as the systematic chunks only need to be
+/// concatenated instead of decoded.
+pub fn reconstruct_from_systematic<T: Decode>(
+	n_validators: usize,
+	chunks: Vec<Vec<u8>>,
+) -> Result<T, Error> {
+	let params = code_params(n_validators)?;
+	let k = params.k();
+
+	if chunks.len() < k {
+		return Err(Error::NotEnoughChunks)
+	}
+
+	let shard_len = chunks[0].len();
+	if shard_len % 2 != 0 {
+		return Err(Error::UnevenLength)
+	}
+	if shard_len == 0 || chunks.iter().take(k).any(|chunk| chunk.len() != shard_len) {
+		return Err(Error::NonUniformChunks)
+	}
+
+	let payload_bytes = params
+		.make_encoder()
+		.reconstruct_from_systematic(chunks.into_iter().take(k).map(WrappedShard::new).collect())
+		.map_err(|e| match e {
+			novelpoly::Error::NeedMoreShards { .. } => Error::NotEnoughChunks,
+			novelpoly::Error::InconsistentShardLengths { .. } => Error::NonUniformChunks,
+			_ => Error::UnknownReconstruction,
+		})?;
+
+	Decode::decode(&mut &payload_bytes[..]).or_else(|_e| Err(Error::BadPayload))
+}
+
 /// An iterator that yields merkle branches and chunk data for all chunks to
 /// be sent to other validators.
 pub struct Branches<'a, I> {
@@ -379,6 +437,28 @@ mod tests {
 		assert_eq!(reconstructed, available_data);
 	}
 
+	#[test]
+	fn systematic_round_trip_works() {
+		let pov = PoV { block_data: BlockData((0..255).collect()) };
+		let available_data = AvailableData { pov: pov.into(), validation_data: Default::default() };
+
+		for n_validators in [2, 10, 12, 100, 1000] {
+			let chunks = obtain_chunks(n_validators, &available_data).unwrap();
+			let threshold = systematic_recovery_threshold(n_validators).unwrap();
+			assert!(threshold <= recovery_threshold(n_validators).unwrap());
+
+			let reconstructed: AvailableData =
+				reconstruct_from_systematic(n_validators, chunks[..threshold].to_vec()).unwrap();
+			assert_eq!(reconstructed, available_data);
+
+			// one chunk short is not enough.
+			assert_eq!(
+				reconstruct_from_systematic_v1(n_validators, chunks[..threshold - 1].to_vec()),
+				Err(Error::NotEnoughChunks),
+			);
+		}
+	}
+
 	#[test]
 	fn reconstruct_does_not_panic_on_low_validator_count() {
 		let reconstructed = reconstruct_v1(1, [].iter().cloned());
@@ -1075,7 +1075,13 @@ async fn handle_actions<Context>(
 			Action::BecomeActive => {
 				*mode = Mode::Active;
 
-				let messages = distribution_messages_for_activation(overlayed_db, state)?;
+				let messages = distribution_messages_for_activation(
+					ctx.sender(),
+					overlayed_db,
+					state,
+					session_info_provider,
+				)
+				.await?;
 
 				ctx.send_messages(messages.into_iter()).await;
 			},
@@ -1131,10 +1137,15 @@ fn get_assignment_core_indices(
 	}
 }
 
-fn distribution_messages_for_activation(
+async fn distribution_messages_for_activation<Sender>(
+	sender: &mut Sender,
 	db: &OverlayedBackend<'_, impl Backend>,
 	state: &State,
-) -> SubsystemResult<Vec<ApprovalDistributionMessage>> {
+	session_info_provider: &mut RuntimeInfo,
+) -> SubsystemResult<Vec<ApprovalDistributionMessage>>
+where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
 	let all_blocks: Vec<Hash> = db.load_all_blocks()?;
 
 	let mut approval_meta = Vec::with_capacity(all_blocks.len());
@@ -1160,6 +1171,21 @@ fn distribution_messages_for_activation(
 				continue
 			},
 		};
+		let no_show_slots = match get_session_info(
+			session_info_provider,
+			sender,
+			block_entry.parent_hash(),
+			block_entry.session(),
+		)
+		.await
+		{
+			Some(session_info) => session_info.no_show_slots,
+			None => {
+				gum::warn!(target: LOG_TARGET, ?block_hash, "Missing session info");
+
+				continue
+			},
+		};
 
 		distribution_message_span.add_string_tag("block-hash", &block_hash.to_string());
 		distribution_message_span
@@ -1171,6 +1197,9 @@ fn distribution_messages_for_activation(
 			candidates: block_entry.candidates().iter().map(|(_, c_hash)| *c_hash).collect(),
 			slot: block_entry.slot(),
 			session: block_entry.session(),
+			no_show_duration: Duration::from_millis(
+				u64::from(no_show_slots).saturating_mul(state.slot_duration_millis),
+			),
 		});
 
 		for (i, (_, candidate_hash)) in block_entry.candidates().iter().enumerate() {
@@ -209,6 +209,8 @@ struct PerRelayParentState {
 	parent: Hash,
 	/// The `ParaId` assigned to the local validator at this relay parent.
 	assignment: Option<ParaId>,
+	/// The core assigned to the local validator at this relay parent.
+	core_index: Option<CoreIndex>,
 	/// The candidates that are backed by enough validators in their group, by hash.
 	backed: HashSet<CandidateHash>,
 	/// The table of candidates and statements under this relay-parent.
@@ -1029,6 +1031,7 @@ async fn construct_per_relay_parent_state<Context>(
 	let mut groups = HashMap::new();
 	let n_cores = cores.len();
 	let mut assignment = None;
+	let mut assigned_core = None;
 
 	for (idx, core) in cores.into_iter().enumerate() {
 		let core_para_id = match core {
@@ -1049,6 +1052,7 @@ async fn construct_per_relay_parent_state<Context>(
 		if let Some(g) = validator_groups.get(group_index.0 as usize) {
 			if validator.as_ref().map_or(false, |v| g.contains(&v.index())) {
 				assignment = Some(core_para_id);
+				assigned_core = Some(core_index);
 			}
 			groups.insert(core_para_id, g.clone());
 		}
@@ -1066,6 +1070,7 @@ async fn construct_per_relay_parent_state<Context>(
 		prospective_parachains_mode: mode,
 		parent,
 		assignment,
+		core_index: assigned_core,
 		backed: HashSet::new(),
 		table: Table::new(table_config),
 		table_context,
@@ -1297,7 +1302,17 @@ async fn handle_validated_candidate_command<Context>(
 						)
 						.await
 						{
-							SecondingAllowed::No => return Ok(()),
+							SecondingAllowed::No => {
+								gum::debug!(
+									target: LOG_TARGET,
+									relay_parent = ?candidate.descriptor().relay_parent,
+									?candidate_hash,
+									"Not allowed to second validated candidate",
+								);
+
+								metrics.on_candidate_rejected("seconding_not_allowed");
+								return Ok(())
+							},
 							SecondingAllowed::Yes(membership) => membership,
 						};
 
@@ -1325,6 +1340,7 @@ async fn handle_validated_candidate_command<Context>(
 								?candidate_hash,
 								"Attempted to second candidate but was rejected by prospective parachains",
 							);
+							metrics.on_candidate_rejected("seconded_rejected");
 
 							// Ensure the collator is reported.
 							ctx.send_message(CollatorProtocolMessage::Invalid(
@@ -1369,6 +1385,8 @@ async fn handle_validated_candidate_command<Context>(
 									.or_default();
 
 								for depth in depths {
+									metrics
+										.on_candidate_seconded_at_depth(rp_state.core_index, depth);
 									seconded_at_depth.insert(depth, candidate_hash);
 								}
 							}
@@ -1769,6 +1787,7 @@ async fn maybe_validate_and_import<Context>(
 	state: &mut State,
 	relay_parent: Hash,
 	statement: SignedFullStatementWithPVD,
+	metrics: &Metrics,
 ) -> Result<(), Error> {
 	let rp_state = match state.per_relay_parent.get_mut(&relay_parent) {
 		Some(r) => r,
@@ -1793,6 +1812,7 @@ async fn maybe_validate_and_import<Context>(
 			?relay_parent,
 			"Statement rejected by prospective parachains."
 		);
+		metrics.on_candidate_rejected("statement_rejected");
 
 		return Ok(())
 	}
@@ -1990,7 +2010,7 @@ async fn handle_statement_message<Context>(
 ) -> Result<(), Error> {
 	let _timer = metrics.time_process_statement();
 
-	match maybe_validate_and_import(ctx, state, relay_parent, statement).await {
+	match maybe_validate_and_import(ctx, state, relay_parent, statement, metrics).await {
 		Err(Error::ValidationFailed(_)) => Ok(()),
 		Err(e) => Err(e),
 		Ok(()) => Ok(()),
@@ -15,6 +15,7 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use polkadot_node_subsystem_util::metrics::{self, prometheus};
+use polkadot_primitives::CoreIndex;
 
 #[derive(Clone)]
 pub(crate) struct MetricsInner {
@@ -23,6 +24,8 @@ pub(crate) struct MetricsInner {
 	pub(crate) process_second: prometheus::Histogram,
 	pub(crate) process_statement: prometheus::Histogram,
 	pub(crate) get_backed_candidates: prometheus::Histogram,
+	pub(crate) candidate_rejections: prometheus::CounterVec<prometheus::U64>,
+	pub(crate) seconded_depth: prometheus::HistogramVec,
 }
 
 /// Candidate backing metrics.
@@ -42,6 +45,22 @@ impl Metrics {
 		}
 	}
 
+	/// Note that a candidate was rejected for the given reason.
+	pub fn on_candidate_rejected(&self, reason: &'static str) {
+		if let Some(metrics) = &self.0 {
+			metrics.candidate_rejections.with_label_values(&[reason]).inc();
+		}
+	}
+
+	/// Note that a candidate was seconded locally at the given depth of a fragment tree, on the
+	/// core assigned to the local validator.
+	pub fn on_candidate_seconded_at_depth(&self, core_index: Option<CoreIndex>, depth: usize) {
+		if let Some(metrics) = &self.0 {
+			let core = core_index.map_or_else(|| "none".to_string(), |c| c.0.to_string());
+			metrics.seconded_depth.with_label_values(&[&core]).observe(depth as f64);
+		}
+	}
+
 	/// Provide a timer for handling `CandidateBackingMessage:Second` which observes on drop.
 	pub fn time_process_second(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.process_second.start_timer())
@@ -101,6 +120,27 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			candidate_rejections: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_candidate_backing_candidate_rejections_total",
+						"Number of candidates rejected by prospective parachains, by reason",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			seconded_depth: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_candidate_backing_seconded_depth",
+						"Fragment tree depths at which candidates were seconded, by core",
+					)
+					.buckets(vec![0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0]),
+					&["core"],
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
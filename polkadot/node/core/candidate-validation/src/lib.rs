@@ -49,8 +49,8 @@ use polkadot_primitives::{
 		DEFAULT_LENIENT_PREPARATION_TIMEOUT, DEFAULT_PRECHECK_PREPARATION_TIMEOUT,
 	},
 	CandidateCommitments, CandidateDescriptor, CandidateReceipt, ExecutorParams, Hash,
-	OccupiedCoreAssumption, PersistedValidationData, PvfExecTimeoutKind, PvfPrepTimeoutKind,
-	ValidationCode, ValidationCodeHash,
+	OccupiedCoreAssumption, PersistedValidationData, PovDecompressionLimits, PvfExecTimeoutKind,
+	PvfPrepTimeoutKind, ValidationCode, ValidationCodeHash,
 };
 
 use parity_scale_codec::Encode;
@@ -597,16 +597,18 @@ async fn validate_candidate_exhaustive(
 	metrics.observe_code_size(raw_validation_code.len());
 
 	metrics.observe_pov_size(pov.block_data.0.len(), true);
-	let raw_block_data =
-		match sp_maybe_compressed_blob::decompress(&pov.block_data.0, POV_BOMB_LIMIT) {
-			Ok(block_data) => BlockData(block_data.to_vec()),
-			Err(e) => {
-				gum::info!(target: LOG_TARGET, ?para_id, err=?e, "Invalid candidate (PoV code)");
-
-				// If the PoV is invalid, the candidate certainly is.
-				return Ok(ValidationResult::Invalid(InvalidCandidate::PoVDecompressionFailure))
-			},
-		};
+	let raw_block_data = match decompress_pov(
+		&pov.block_data.0,
+		executor_params.pov_decompression_limits(para_id),
+	) {
+		Ok(block_data) => BlockData(block_data),
+		Err(e) => {
+			gum::info!(target: LOG_TARGET, ?para_id, err=?e, "Invalid candidate (PoV code)");
+
+			// If the PoV is invalid, the candidate certainly is.
+			return Ok(ValidationResult::Invalid(e))
+		},
+	};
 	metrics.observe_pov_size(raw_block_data.0.len(), false);
 
 	let params = ValidationParams {
@@ -837,6 +839,37 @@ impl ValidationBackend for ValidationHost {
 	}
 }
 
+/// Decompresses a PoV, enforcing the node's [`POV_BOMB_LIMIT`] as well as the decompression
+/// `limits` configured for the parachain, if any.
+///
+/// Both the size and the ratio limits bound the decompression itself, which is aborted as soon as
+/// the output exceeds them.
+fn decompress_pov(
+	compressed: &[u8],
+	limits: Option<PovDecompressionLimits>,
+) -> Result<Vec<u8>, InvalidCandidate> {
+	let bomb_limit = limits.map_or(POV_BOMB_LIMIT, |limits| {
+		(limits.max_size as usize)
+			.min(compressed.len().saturating_mul(limits.max_ratio as usize))
+			.min(POV_BOMB_LIMIT)
+	});
+
+	let raw = match sp_maybe_compressed_blob::decompress(compressed, bomb_limit) {
+		Ok(raw) => raw,
+		// Only the configured limits, which are lower than the node's, were exceeded.
+		Err(sp_maybe_compressed_blob::Error::PossibleBomb) if bomb_limit < POV_BOMB_LIMIT =>
+			return Err(InvalidCandidate::PoVDecompressionLimitExceeded),
+		Err(_) => return Err(InvalidCandidate::PoVDecompressionFailure),
+	};
+
+	// An uncompressed PoV is returned as is, so its size is checked here.
+	if limits.is_some() && raw.len() > bomb_limit {
+		return Err(InvalidCandidate::PoVDecompressionLimitExceeded)
+	}
+
+	Ok(raw.into_owned())
+}
+
 /// Does basic checks of a candidate. Provide the encoded PoV-block. Returns `Ok` if basic checks
 /// are passed, `Err` otherwise.
 fn perform_basic_checks(
@@ -22,7 +22,7 @@ use polkadot_node_core_pvf::PrepareError;
 use polkadot_node_subsystem::messages::AllMessages;
 use polkadot_node_subsystem_test_helpers as test_helpers;
 use polkadot_node_subsystem_util::reexports::SubsystemContext;
-use polkadot_primitives::{HeadData, Id as ParaId, UpwardMessage};
+use polkadot_primitives::{ExecutorParam, HeadData, Id as ParaId, UpwardMessage};
 use sp_core::testing::TaskExecutor;
 use sp_keyring::Sr25519Keyring;
 
@@ -1013,6 +1013,57 @@ fn pov_decompression_failure_is_invalid() {
 	assert_matches!(v, Ok(ValidationResult::Invalid(InvalidCandidate::PoVDecompressionFailure)));
 }
 
+#[test]
+fn pov_decompression_limits_are_enforced() {
+	let raw = vec![2u8; 10_000];
+	let compressed = sp_maybe_compressed_blob::compress(&raw, POV_BOMB_LIMIT).unwrap();
+	let limits = |max_size, max_ratio| Some(PovDecompressionLimits { max_size, max_ratio });
+
+	assert_eq!(decompress_pov(&compressed, None).unwrap(), raw);
+	assert_eq!(decompress_pov(&compressed, limits(10_000, u32::MAX)).unwrap(), raw);
+
+	// Decompressed size over the limit.
+	assert_matches!(
+		decompress_pov(&compressed, limits(9_999, u32::MAX)),
+		Err(InvalidCandidate::PoVDecompressionLimitExceeded)
+	);
+	// Compression ratio over the limit.
+	assert_matches!(
+		decompress_pov(&compressed, limits(10_000, 2)),
+		Err(InvalidCandidate::PoVDecompressionLimitExceeded)
+	);
+	// Uncompressed PoVs are subject to the size limit too.
+	assert_eq!(decompress_pov(&raw, limits(10_000, 1)).unwrap(), raw);
+	assert_matches!(
+		decompress_pov(&raw, limits(9_999, 1)),
+		Err(InvalidCandidate::PoVDecompressionLimitExceeded)
+	);
+	// Malformed PoVs are still reported as such.
+	let malformed = [&compressed[..8], &[0xff; 16][..]].concat();
+	assert_matches!(
+		decompress_pov(&malformed, limits(10_000, u32::MAX)),
+		Err(InvalidCandidate::PoVDecompressionFailure)
+	);
+
+	// Parachain specific limits override the default ones.
+	let executor_params = ExecutorParams::from(
+		&[
+			ExecutorParam::ParaPovDecompressionLimits(
+				ParaId::from(2_u32),
+				PovDecompressionLimits { max_size: 1, max_ratio: 1 },
+			),
+			ExecutorParam::DefaultPovDecompressionLimits(PovDecompressionLimits {
+				max_size: 2,
+				max_ratio: 2,
+			}),
+		][..],
+	);
+	assert!(executor_params.check_consistency().is_ok());
+	assert_eq!(executor_params.pov_decompression_limits(ParaId::from(1_u32)), limits(2, 2));
+	assert_eq!(executor_params.pov_decompression_limits(ParaId::from(2_u32)), limits(1, 1));
+	assert_eq!(ExecutorParams::default().pov_decompression_limits(ParaId::from(1_u32)), None);
+}
+
 struct MockPreCheckBackend {
 	result: Result<PrepareStats, PrepareError>,
 }
@@ -114,6 +114,29 @@ pub enum CandidateStorageInsertionError {
 	CandidateAlreadyKnown(CandidateHash),
 }
 
+/// Reasons for a candidate in storage not being part of a [`FragmentTree`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RejectionReason {
+	/// The relay-parent of the candidate is out of the scope of the tree.
+	RelayParentOutOfScope,
+	/// The candidate only builds on nodes which are already at the maximum depth of the tree.
+	DepthExceeded,
+	/// The candidate doesn't fit in the fragment chain: its parent head isn't part of the tree,
+	/// its relay-parent moves backwards or its outputs violate the constraints.
+	FragmentChainConflict,
+}
+
+impl RejectionReason {
+	/// The label of the reason, as used in metrics.
+	pub(crate) fn as_label(&self) -> &'static str {
+		match self {
+			Self::RelayParentOutOfScope => "relay_parent_out_of_scope",
+			Self::DepthExceeded => "depth_exceeded",
+			Self::FragmentChainConflict => "fragment_chain_conflict",
+		}
+	}
+}
+
 /// Stores candidates and information about them such as their relay-parents and their backing
 /// states.
 pub(crate) struct CandidateStorage {
@@ -609,6 +632,48 @@ impl FragmentTree {
 		self.populate_from_bases(storage, bases);
 	}
 
+	/// Explain why a candidate from storage is not part of the tree.
+	///
+	/// Returns `None` if the candidate is part of the tree or unknown to the storage.
+	pub(crate) fn rejection_reason(
+		&self,
+		hash: &CandidateHash,
+		storage: &CandidateStorage,
+	) -> Option<RejectionReason> {
+		if self.candidates.contains_key(hash) {
+			return None
+		}
+
+		let candidate_entry = storage.get(hash)?;
+		if self.scope.get_pending_availability(hash).is_none() &&
+			self.scope.ancestor_by_hash(&candidate_entry.relay_parent).is_none()
+		{
+			return Some(RejectionReason::RelayParentOutOfScope)
+		}
+
+		let candidate_parent = &candidate_entry.candidate.persisted_validation_data.parent_head;
+		if &self.scope.base_constraints.required_parent == candidate_parent {
+			return Some(RejectionReason::FragmentChainConflict)
+		}
+
+		let mut parent_depths = self
+			.nodes
+			.iter()
+			.filter(|n| {
+				n.cumulative_modifications.required_parent.as_ref() == Some(candidate_parent)
+			})
+			.map(|n| n.depth)
+			.peekable();
+
+		if parent_depths.peek().is_some() &&
+			parent_depths.all(|depth| depth >= self.scope.max_depth)
+		{
+			Some(RejectionReason::DepthExceeded)
+		} else {
+			Some(RejectionReason::FragmentChainConflict)
+		}
+	}
+
 	/// Returns `true` if the path from the root to the node's parent (inclusive)
 	/// only contains backed candidates, `false` otherwise.
 	fn path_contains_backed_only_candidates(
@@ -1282,6 +1347,102 @@ mod tests {
 		assert_eq!(tree.nodes[1].depth, 1);
 	}
 
+	#[test]
+	fn rejection_reasons() {
+		let mut storage = CandidateStorage::new();
+
+		let para_id = ParaId::from(5u32);
+		let relay_parent_a = Hash::repeat_byte(1);
+		let relay_parent_b = Hash::repeat_byte(2);
+
+		let (pvd_a, candidate_a) = make_committed_candidate(
+			para_id,
+			relay_parent_a,
+			0,
+			vec![0x0a].into(),
+			vec![0x0b].into(),
+			0,
+		);
+		let candidate_a_hash = candidate_a.hash();
+
+		// Builds on `a`, but the tree has a maximum depth of 0.
+		let (pvd_b, candidate_b) = make_committed_candidate(
+			para_id,
+			relay_parent_b,
+			1,
+			vec![0x0b].into(),
+			vec![0x0c].into(),
+			1,
+		);
+		let candidate_b_hash = candidate_b.hash();
+
+		// Has an unknown relay-parent.
+		let (pvd_c, candidate_c) = make_committed_candidate(
+			para_id,
+			Hash::repeat_byte(9),
+			1,
+			vec![0x0a].into(),
+			vec![0x0d].into(),
+			1,
+		);
+		let candidate_c_hash = candidate_c.hash();
+
+		// Has an unknown parent head.
+		let (pvd_d, candidate_d) = make_committed_candidate(
+			para_id,
+			relay_parent_b,
+			1,
+			vec![0x0f].into(),
+			vec![0x0e].into(),
+			1,
+		);
+		let candidate_d_hash = candidate_d.hash();
+
+		let base_constraints = make_constraints(0, vec![0], vec![0x0a].into());
+
+		let ancestors = vec![RelayChainBlockInfo {
+			number: pvd_a.relay_parent_number,
+			hash: relay_parent_a,
+			storage_root: pvd_a.relay_parent_storage_root,
+		}];
+
+		let relay_parent_b_info = RelayChainBlockInfo {
+			number: pvd_b.relay_parent_number,
+			hash: relay_parent_b,
+			storage_root: pvd_b.relay_parent_storage_root,
+		};
+
+		storage.add_candidate(candidate_a, pvd_a).unwrap();
+		storage.add_candidate(candidate_b, pvd_b).unwrap();
+		storage.add_candidate(candidate_c, pvd_c).unwrap();
+		storage.add_candidate(candidate_d, pvd_d).unwrap();
+		let scope = Scope::with_ancestors(
+			para_id,
+			relay_parent_b_info,
+			base_constraints,
+			Vec::new(),
+			0,
+			ancestors,
+		)
+		.unwrap();
+		let tree = FragmentTree::populate(scope, &storage);
+
+		assert_eq!(tree.candidates().collect::<Vec<_>>(), vec![candidate_a_hash]);
+		assert_eq!(tree.rejection_reason(&candidate_a_hash, &storage), None);
+		assert_eq!(
+			tree.rejection_reason(&candidate_b_hash, &storage),
+			Some(RejectionReason::DepthExceeded)
+		);
+		assert_eq!(
+			tree.rejection_reason(&candidate_c_hash, &storage),
+			Some(RejectionReason::RelayParentOutOfScope)
+		);
+		assert_eq!(
+			tree.rejection_reason(&candidate_d_hash, &storage),
+			Some(RejectionReason::FragmentChainConflict)
+		);
+	}
+
 	#[test]
 	fn children_of_root_are_contiguous() {
 		let mut storage = CandidateStorage::new();
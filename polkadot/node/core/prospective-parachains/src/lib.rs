@@ -34,6 +34,7 @@ use std::{
 use futures::{channel::oneshot, prelude::*};
 
 use polkadot_node_subsystem::{
+	errors::RuntimeApiError,
 	messages::{
 		ChainApiMessage, FragmentTreeMembership, HypotheticalCandidate,
 		HypotheticalFrontierRequest, IntroduceCandidateRequest, ProspectiveParachainsMessage,
@@ -47,15 +48,16 @@ use polkadot_node_subsystem_util::{
 	runtime::{prospective_parachains_mode, ProspectiveParachainsMode},
 };
 use polkadot_primitives::{
-	async_backing::CandidatePendingAvailability, BlockNumber, CandidateHash,
-	CommittedCandidateReceipt, CoreState, Hash, HeadData, Header, Id as ParaId,
-	PersistedValidationData,
+	async_backing::{AsyncBackingParams, CandidatePendingAvailability},
+	BlockNumber, CandidateHash, CommittedCandidateReceipt, CoreState, Hash, HeadData, Header,
+	Id as ParaId, PersistedValidationData,
 };
 
 use crate::{
 	error::{FatalError, FatalResult, JfyiError, JfyiErrorResult, Result},
 	fragment_tree::{
-		CandidateStorage, CandidateStorageInsertionError, FragmentTree, Scope as TreeScope,
+		CandidateStorage, CandidateStorageInsertionError, FragmentTree, RejectionReason,
+		Scope as TreeScope,
 	},
 };
 
@@ -141,7 +143,7 @@ async fn run_iteration<Context>(
 			FromOrchestra::Signal(OverseerSignal::BlockFinalized(..)) => {},
 			FromOrchestra::Communication { msg } => match msg {
 				ProspectiveParachainsMessage::IntroduceCandidate(request, tx) =>
-					handle_candidate_introduced(&mut *ctx, view, request, tx).await?,
+					handle_candidate_introduced(&mut *ctx, view, request, tx, metrics).await?,
 				ProspectiveParachainsMessage::CandidateSeconded(para, candidate_hash) =>
 					handle_candidate_seconded(view, para, candidate_hash),
 				ProspectiveParachainsMessage::CandidateBacked(para, candidate_hash) =>
@@ -249,6 +251,13 @@ async fn handle_active_leaves_update<Context>(
 				},
 			};
 
+			// The para may have its own asynchronous backing parameters. Runtimes which don't
+			// know about such overrides apply the global ones to every para.
+			let AsyncBackingParams { max_candidate_depth, allowed_ancestry_len: para_ancestry_len } =
+				fetch_para_async_backing_params(&mut *ctx, hash, para)
+					.await?
+					.unwrap_or(AsyncBackingParams { max_candidate_depth, allowed_ancestry_len });
+
 			let pending_availability = preprocess_candidates_pending_availability(
 				ctx,
 				&mut temp_header_cache,
@@ -286,7 +295,7 @@ async fn handle_active_leaves_update<Context>(
 				constraints,
 				compact_pending,
 				max_candidate_depth,
-				ancestry.iter().cloned(),
+				ancestry.iter().take(para_ancestry_len as usize).cloned(),
 			)
 			.expect("ancestors are provided in reverse order and correctly; qed");
 
@@ -410,6 +419,7 @@ async fn handle_candidate_introduced<Context>(
 	view: &mut View,
 	request: IntroduceCandidateRequest,
 	tx: oneshot::Sender<FragmentTreeMembership>,
+	metrics: &Metrics,
 ) -> JfyiErrorResult<()> {
 	let IntroduceCandidateRequest {
 		candidate_para: para,
@@ -428,6 +438,7 @@ async fn handle_candidate_introduced<Context>(
 				"Received seconded candidate for inactive para",
 			);
 
+			metrics.on_candidate_rejected("inactive_para");
 			let _ = tx.send(Vec::new());
 			return Ok(())
 		},
@@ -452,6 +463,7 @@ async fn handle_candidate_introduced<Context>(
 				"Received seconded candidate had mismatching validation data",
 			);
 
+			metrics.on_candidate_rejected("validation_data_mismatch");
 			let _ = tx.send(Vec::new());
 			return Ok(())
 		},
@@ -462,12 +474,43 @@ async fn handle_candidate_introduced<Context>(
 		if let Some(tree) = leaf_data.fragment_trees.get_mut(&para) {
 			tree.add_and_populate(candidate_hash, &*storage);
 			if let Some(depths) = tree.candidate(&candidate_hash) {
+				depths.iter().for_each(|depth| metrics.on_candidate_introduced(*depth));
 				membership.push((*relay_parent, depths));
 			}
 		}
 	}
 
 	if membership.is_empty() {
+		let mut reasons = Vec::new();
+		for (relay_parent, leaf_data) in &view.active_leaves {
+			if let Some(reason) = leaf_data
+				.fragment_trees
+				.get(&para)
+				.and_then(|tree| tree.rejection_reason(&candidate_hash, &*storage))
+			{
+				gum::debug!(
+					target: LOG_TARGET,
+					para_id = ?para,
+					?candidate_hash,
+					leaf = ?relay_parent,
+					?reason,
+					"Candidate rejected by fragment tree",
+				);
+				reasons.push(reason);
+			}
+		}
+
+		// Report the most specific reason: a candidate is typically out of scope of some leaves.
+		let reason = [
+			RejectionReason::DepthExceeded,
+			RejectionReason::FragmentChainConflict,
+			RejectionReason::RelayParentOutOfScope,
+		]
+		.into_iter()
+		.find(|r| reasons.contains(r))
+		.map_or("no_fragment_tree", |r| r.as_label());
+		metrics.on_candidate_rejected(reason);
+
 		storage.remove_candidate(&candidate_hash);
 	}
 
@@ -811,6 +854,28 @@ async fn fetch_backing_state<Context>(
 		.map(|s| (From::from(s.constraints), s.pending_availability)))
 }
 
+/// Fetch the asynchronous backing parameters of the given para, or `None` if the runtime
+/// doesn't support per-para parameters yet.
+#[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
+async fn fetch_para_async_backing_params<Context>(
+	ctx: &mut Context,
+	relay_parent: Hash,
+	para_id: ParaId,
+) -> JfyiErrorResult<Option<AsyncBackingParams>> {
+	let (tx, rx) = oneshot::channel();
+	ctx.send_message(RuntimeApiMessage::Request(
+		relay_parent,
+		RuntimeApiRequest::ParaAsyncBackingParams(para_id, tx),
+	))
+	.await;
+
+	match rx.await.map_err(JfyiError::RuntimeApiRequestCanceled)? {
+		Ok(params) => Ok(Some(params)),
+		Err(RuntimeApiError::NotSupported { .. }) => Ok(None),
+		Err(err) => Err(err.into()),
+	}
+}
+
 #[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
 async fn fetch_upcoming_paras<Context>(
 	ctx: &mut Context,
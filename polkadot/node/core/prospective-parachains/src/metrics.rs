@@ -19,6 +19,8 @@ use polkadot_node_subsystem_util::metrics::{self, prometheus};
 #[derive(Clone)]
 pub(crate) struct MetricsInner {
 	pub(crate) prune_view_candidate_storage: prometheus::Histogram,
+	pub(crate) candidate_rejections: prometheus::CounterVec<prometheus::U64>,
+	pub(crate) candidate_depth: prometheus::Histogram,
 }
 
 /// Candidate backing metrics.
@@ -34,6 +36,20 @@ impl Metrics {
 			.as_ref()
 			.map(|metrics| metrics.prune_view_candidate_storage.start_timer())
 	}
+
+	/// Note that an introduced candidate was rejected for the given reason.
+	pub fn on_candidate_rejected(&self, reason: &'static str) {
+		if let Some(metrics) = &self.0 {
+			metrics.candidate_rejections.with_label_values(&[reason]).inc();
+		}
+	}
+
+	/// Note the depth at which an introduced candidate was added to a fragment tree.
+	pub fn on_candidate_introduced(&self, depth: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.candidate_depth.observe(depth as f64);
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -46,6 +62,26 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			candidate_rejections: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_prospective_parachains_candidate_rejections_total",
+						"Number of introduced candidates rejected, by reason",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			candidate_depth: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_prospective_parachains_candidate_depth",
+						"Depths at which introduced candidates are added to fragment trees",
+					)
+					.buckets(vec![0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0]),
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
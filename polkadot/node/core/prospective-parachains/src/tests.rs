@@ -309,6 +309,15 @@ async fn handle_leaf_activation(
 			}
 		);
 
+		assert_matches!(
+			virtual_overseer.recv().await,
+			AllMessages::RuntimeApi(
+				RuntimeApiMessage::Request(parent, RuntimeApiRequest::ParaAsyncBackingParams(p_id, tx))
+			) if parent == *hash && p_id == para_id => {
+				tx.send(Ok(async_backing_params)).unwrap();
+			}
+		);
+
 		for pending in pending_availability {
 			send_block_header(
 				virtual_overseer,
@@ -134,6 +134,9 @@ pub enum InternalValidationError {
 	CouldNotCreateLink(String),
 	/// Could not find or open compiled artifact file.
 	CouldNotOpenFile(String),
+	/// The compiled artifact file does not match its checksum, e.g. due to disk corruption. The
+	/// artifact is removed, so that a retry prepares it again.
+	CorruptedArtifact(String),
 	/// Host could not clear the worker cache after a job.
 	CouldNotClearWorkerDir {
 		err: String,
@@ -161,6 +164,8 @@ impl fmt::Display for InternalValidationError {
 			),
 			CouldNotOpenFile(err) =>
 				write!(f, "validation: could not find or open compiled artifact file: {}", err),
+			CorruptedArtifact(err) =>
+				write!(f, "validation: compiled artifact file is corrupted: {}", err),
 			CouldNotClearWorkerDir { err, path } => write!(
 				f,
 				"validation: host could not clear the worker cache ({:?}) after a job: {}",
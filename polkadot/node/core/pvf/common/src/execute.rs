@@ -53,6 +53,9 @@ pub enum WorkerResponse {
 	/// Because malicious code can cause a job error, we must not treat it as an internal error. We
 	/// still retry it, because in the non-malicious case it is likely spurious.
 	JobError(String),
+	/// The compiled artifact does not match its checksum, e.g. due to disk corruption. The
+	/// artifact has to be removed and prepared again.
+	CorruptedArtifact(String),
 
 	/// Some internal error occurred.
 	InternalError(InternalValidationError),
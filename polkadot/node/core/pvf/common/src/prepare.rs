@@ -57,3 +57,61 @@ pub enum PrepareJobKind {
 	/// A prechecking job.
 	Prechecking,
 }
+
+/// The length of the checksum which is prepended to a compiled artifact when writing it to disk.
+pub const ARTIFACT_CHECKSUM_LEN: usize = blake3::OUT_LEN;
+
+/// Prepend the blake3 checksum of the compiled `artifact` to it, producing the bytes to be written
+/// to disk.
+pub fn artifact_with_checksum(artifact: &[u8]) -> Vec<u8> {
+	let mut bytes = Vec::with_capacity(ARTIFACT_CHECKSUM_LEN + artifact.len());
+	bytes.extend_from_slice(blake3::hash(artifact).as_bytes());
+	bytes.extend_from_slice(artifact);
+	bytes
+}
+
+/// Verify the checksum of artifact `bytes` read from disk, as written by
+/// [`artifact_with_checksum`], and strip it off.
+///
+/// Returns the compiled artifact, or an error describing why the artifact is corrupted, e.g. due
+/// to a partial write or disk bit-rot.
+pub fn verify_artifact_checksum(mut bytes: Vec<u8>) -> Result<Vec<u8>, String> {
+	if bytes.len() < ARTIFACT_CHECKSUM_LEN {
+		return Err(format!("artifact is too short to contain a checksum: {} bytes", bytes.len()))
+	}
+
+	let checksum = blake3::hash(&bytes[ARTIFACT_CHECKSUM_LEN..]);
+	if checksum.as_bytes()[..] != bytes[..ARTIFACT_CHECKSUM_LEN] {
+		return Err(format!("artifact checksum mismatch, computed {}", checksum.to_hex()))
+	}
+
+	bytes.drain(..ARTIFACT_CHECKSUM_LEN);
+	Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn artifact_checksum_roundtrip() {
+		let artifact = b"compiled artifact".to_vec();
+		let bytes = artifact_with_checksum(&artifact);
+		assert_eq!(bytes.len(), ARTIFACT_CHECKSUM_LEN + artifact.len());
+		assert_eq!(verify_artifact_checksum(bytes).unwrap(), artifact);
+	}
+
+	#[test]
+	fn corrupted_artifact_is_detected() {
+		let bytes = artifact_with_checksum(b"compiled artifact");
+
+		// Bit-rot in the artifact.
+		let mut corrupted = bytes.clone();
+		*corrupted.last_mut().unwrap() ^= 1;
+		assert!(verify_artifact_checksum(corrupted).is_err());
+
+		// A partial write.
+		assert!(verify_artifact_checksum(bytes[..bytes.len() - 1].to_vec()).is_err());
+		assert!(verify_artifact_checksum(bytes[..ARTIFACT_CHECKSUM_LEN - 1].to_vec()).is_err());
+	}
+}
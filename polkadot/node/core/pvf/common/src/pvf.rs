@@ -124,7 +124,7 @@ impl fmt::Debug for PvfPrepData {
 impl PartialEq for PvfPrepData {
 	fn eq(&self, other: &Self) -> bool {
 		self.code_hash == other.code_hash &&
-			self.executor_params.hash() == other.executor_params.hash()
+			self.executor_params.prep_hash() == other.executor_params.prep_hash()
 	}
 }
 
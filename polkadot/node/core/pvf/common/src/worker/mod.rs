@@ -18,9 +18,11 @@
 
 pub mod security;
 
-use crate::{SecurityStatus, LOG_TARGET};
+use crate::{framed_send_blocking, SecurityStatus, LOG_TARGET};
 use cpu_time::ProcessTime;
 use futures::never::Never;
+use parity_scale_codec::Encode;
+use security::AppliedPolicies;
 use std::{
 	any::Any,
 	fmt, io,
@@ -281,7 +283,7 @@ pub fn run_worker<F>(
 		let _ = std::fs::remove_file(&socket_path);
 		Ok(stream)
 	}();
-	let stream = match stream {
+	let mut stream = match stream {
 		Ok(s) => s,
 		Err(err) => {
 			gum::error!(
@@ -297,6 +299,8 @@ pub fn run_worker<F>(
 	};
 
 	// Enable some security features.
+	#[cfg_attr(not(target_os = "linux"), allow(unused_mut))]
+	let mut applied_policies = AppliedPolicies::default();
 	{
 		// Call based on whether we can change root. Error out if it should work but fails.
 		//
@@ -324,13 +328,16 @@ pub fn run_worker<F>(
 				return
 			}
 			worker_dir_path = std::path::Path::new("/").to_owned();
+			applied_policies.change_root = true;
 		}
 
 		#[cfg(target_os = "linux")]
 		if security_status.can_enable_landlock {
 			let landlock_status =
 				security::landlock::enable_for_worker(worker_kind, worker_pid, &worker_dir_path);
-			if !matches!(landlock_status, Ok(landlock::RulesetStatus::FullyEnforced)) {
+			if matches!(landlock_status, Ok(landlock::RulesetStatus::FullyEnforced)) {
+				applied_policies.landlock = Some(security::landlock::POLICY_VERSION);
+			} else {
 				// We previously were able to enable, so this should never happen.
 				gum::error!(
 					target: LOG_TARGET,
@@ -348,7 +355,9 @@ pub fn run_worker<F>(
 		if security_status.can_enable_seccomp {
 			let seccomp_status =
 				security::seccomp::enable_for_worker(worker_kind, worker_pid, &worker_dir_path);
-			if !matches!(seccomp_status, Ok(())) {
+			if matches!(seccomp_status, Ok(())) {
+				applied_policies.seccomp = Some(security::seccomp::POLICY_VERSION);
+			} else {
 				// We previously were able to enable, so this should never happen.
 				//
 				// TODO: Make this a real error in secure-mode. See:
@@ -377,6 +386,19 @@ pub fn run_worker<F>(
 		}
 	}
 
+	// Report the applied policies, so that the host can verify them.
+	if let Err(err) = framed_send_blocking(&mut stream, &applied_policies.encode()) {
+		gum::error!(
+			target: LOG_TARGET,
+			%worker_kind,
+			%worker_pid,
+			"could not report the applied sandbox policies: {}",
+			err
+		);
+		worker_shutdown_message(worker_kind, worker_pid, &err.to_string());
+		return
+	}
+
 	// Run the main worker loop.
 	let err = event_loop(stream, worker_dir_path)
 		// It's never `Ok` because it's `Ok(Never)`.
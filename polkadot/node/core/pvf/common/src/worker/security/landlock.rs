@@ -72,6 +72,11 @@ use std::path::{Path, PathBuf};
 /// supports it or if it introduces some new feature that is beneficial to security.
 pub const LANDLOCK_ABI: ABI = ABI::V1;
 
+/// The version of the landlock policy applied to workers.
+///
+/// Must be bumped whenever the handled access rights or the exceptions of workers change.
+pub const POLICY_VERSION: u32 = 1;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
 	#[error("Invalid exception path: {0:?}")]
@@ -26,8 +26,12 @@
 //!   - Change the root directory to a worker-specific temporary directory.
 //! - Restrict networking by blocking socket creation and io_uring.
 //! - Remove env vars
+//!
+//! The landlock and seccomp policies are versioned. After enabling them, workers report the
+//! versions they applied to the host, see [`AppliedPolicies`].
 
-use crate::{worker::WorkerKind, LOG_TARGET};
+use crate::{worker::WorkerKind, SecurityStatus, LOG_TARGET};
+use parity_scale_codec::{Decode, Encode};
 
 #[cfg(target_os = "linux")]
 pub mod landlock;
@@ -35,6 +39,46 @@ pub mod landlock;
 #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
 pub mod seccomp;
 
+/// The sandbox policies a worker applied to itself, reported to the host once they are enabled.
+///
+/// Policies are identified by their version, so that a change to a policy can be told apart in
+/// logs and metrics. `None` means that the policy was not applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+pub struct AppliedPolicies {
+	/// The version of the applied landlock policy.
+	pub landlock: Option<u32>,
+	/// The version of the applied seccomp policy.
+	pub seccomp: Option<u32>,
+	/// Whether the worker runs in its own user namespace, with the worker dir as its root.
+	pub change_root: bool,
+}
+
+impl AppliedPolicies {
+	/// The policies a worker is expected to apply, given the security features available on the
+	/// system.
+	pub fn expected(
+		#[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+		security_status: &SecurityStatus,
+	) -> Self {
+		#[cfg(target_os = "linux")]
+		let landlock = security_status.can_enable_landlock.then_some(landlock::POLICY_VERSION);
+		#[cfg(not(target_os = "linux"))]
+		let landlock = None;
+
+		#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+		let seccomp = security_status.can_enable_seccomp.then_some(seccomp::POLICY_VERSION);
+		#[cfg(not(all(target_os = "linux", target_arch = "x86_64")))]
+		let seccomp = None;
+
+		#[cfg(target_os = "linux")]
+		let change_root = security_status.can_unshare_user_namespace_and_change_root;
+		#[cfg(not(target_os = "linux"))]
+		let change_root = false;
+
+		Self { landlock, seccomp, change_root }
+	}
+}
+
 /// Unshare the user namespace and change root to be the artifact directory.
 ///
 /// NOTE: This should not be called in a multi-threaded context. `unshare(2)`:
@@ -99,6 +99,11 @@ pub enum Error {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The version of the seccomp policy applied to workers.
+///
+/// Must be bumped whenever the blocked syscalls or the action taken on them change.
+pub const POLICY_VERSION: u32 = 1;
+
 /// Try to enable seccomp for the given kind of worker.
 pub fn enable_for_worker(
 	worker_kind: WorkerKind,
@@ -39,6 +39,7 @@ use polkadot_node_core_pvf_common::{
 	error::InternalValidationError,
 	execute::{Handshake, JobError, JobResponse, JobResult, WorkerResponse},
 	framed_recv_blocking, framed_send_blocking,
+	prepare::verify_artifact_checksum,
 	worker::{
 		cpu_time_monitor_loop, run_worker, stringify_panic_payload,
 		thread::{self, WaitOutcome},
@@ -175,6 +176,23 @@ pub fn worker_entrypoint(
 					},
 				};
 
+				// Make sure the artifact was not corrupted on disk before loading it.
+				let compiled_artifact_blob = match verify_artifact_checksum(compiled_artifact_blob)
+				{
+					Ok(bytes) => bytes,
+					Err(err) => {
+						gum::warn!(
+							target: LOG_TARGET,
+							%worker_pid,
+							"worker: corrupted artifact {}: {}",
+							artifact_path.display(),
+							err,
+						);
+						send_response(&mut stream, WorkerResponse::CorruptedArtifact(err))?;
+						continue
+					},
+				};
+
 				let (pipe_reader, pipe_writer) = os_pipe::pipe()?;
 
 				let usage_before = match nix::sys::resource::getrusage(UsageWho::RUSAGE_CHILDREN) {
@@ -43,7 +43,7 @@ use polkadot_node_core_pvf_common::{
 	error::{PrepareError, PrepareResult},
 	executor_intf::create_runtime_from_artifact_bytes,
 	framed_recv_blocking, framed_send_blocking,
-	prepare::{MemoryStats, PrepareJobKind, PrepareStats},
+	prepare::{artifact_with_checksum, MemoryStats, PrepareJobKind, PrepareStats},
 	pvf::PvfPrepData,
 	worker::{
 		cpu_time_monitor_loop, run_worker, stringify_panic_payload,
@@ -576,8 +576,10 @@ fn handle_parent_process(
 						"worker: writing artifact to {}",
 						temp_artifact_dest.display(),
 					);
-					// Write to the temp file created by the host.
-					if let Err(err) = fs::write(&temp_artifact_dest, &response.artifact) {
+					// Write to the temp file created by the host. The artifact is prefixed by its
+					// checksum, which is verified by the execute worker when loading it.
+					let bytes = artifact_with_checksum(response.artifact.as_ref());
+					if let Err(err) = fs::write(&temp_artifact_dest, &bytes) {
 						return Err(PrepareError::IoErr(err.to_string()))
 					};
 
@@ -60,18 +60,23 @@ use always_assert::always;
 use polkadot_node_core_pvf_common::{error::PrepareError, prepare::PrepareStats, pvf::PvfPrepData};
 use polkadot_node_primitives::NODE_VERSION;
 use polkadot_parachain_primitives::primitives::ValidationCodeHash;
-use polkadot_primitives::ExecutorParamsHash;
+use polkadot_primitives::ExecutorParamsPrepHash;
 use std::{
 	collections::HashMap,
 	path::{Path, PathBuf},
 	time::{Duration, SystemTime},
 };
 
-/// Identifier of an artifact. Encodes a code hash of the PVF and a hash of executor parameter set.
+/// Identifier of an artifact. Encodes a code hash of the PVF and a hash of preparation-related
+/// executor parameter set.
+///
+/// Only the executor parameters affecting preparation are taken into account, so PVFs whose
+/// executor parameter sets differ only in execution-related parameters (e.g. execution timeouts)
+/// share the same artifact.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ArtifactId {
 	pub(crate) code_hash: ValidationCodeHash,
-	pub(crate) executor_params_hash: ExecutorParamsHash,
+	pub(crate) executor_params_prep_hash: ExecutorParamsPrepHash,
 }
 
 impl ArtifactId {
@@ -79,13 +84,16 @@ impl ArtifactId {
 	const NODE_VERSION_PREFIX: &'static str = "polkadot_v";
 
 	/// Creates a new artifact ID with the given hash.
-	pub fn new(code_hash: ValidationCodeHash, executor_params_hash: ExecutorParamsHash) -> Self {
-		Self { code_hash, executor_params_hash }
+	pub fn new(
+		code_hash: ValidationCodeHash,
+		executor_params_prep_hash: ExecutorParamsPrepHash,
+	) -> Self {
+		Self { code_hash, executor_params_prep_hash }
 	}
 
 	/// Returns an artifact ID that corresponds to the PVF with given executor params.
 	pub fn from_pvf_prep_data(pvf: &PvfPrepData) -> Self {
-		Self::new(pvf.code_hash(), pvf.executor_params().hash())
+		Self::new(pvf.code_hash(), pvf.executor_params().prep_hash())
 	}
 
 	/// Tries to recover the artifact id from the given file name.
@@ -99,13 +107,14 @@ impl ArtifactId {
 
 		// [ node version | code hash | param hash ]
 		let parts: Vec<&str> = file_name.split('_').collect();
-		let (_node_ver, code_hash_str, executor_params_hash_str) = (parts[0], parts[1], parts[2]);
+		let (_node_ver, code_hash_str, executor_params_prep_hash_str) =
+			(parts[0], parts[1], parts[2]);
 
 		let code_hash = Hash::from_str(code_hash_str).ok()?.into();
-		let executor_params_hash =
-			ExecutorParamsHash::from_hash(Hash::from_str(executor_params_hash_str).ok()?);
+		let executor_params_prep_hash =
+			ExecutorParamsPrepHash::from_hash(Hash::from_str(executor_params_prep_hash_str).ok()?);
 
-		Some(Self { code_hash, executor_params_hash })
+		Some(Self { code_hash, executor_params_prep_hash })
 	}
 
 	/// Returns the expected path to this artifact given the root of the cache.
@@ -116,7 +125,7 @@ impl ArtifactId {
 			Self::NODE_VERSION_PREFIX,
 			NODE_VERSION,
 			self.code_hash,
-			self.executor_params_hash
+			self.executor_params_prep_hash
 		);
 		cache_path.join(file_name)
 	}
@@ -239,6 +248,12 @@ impl Artifacts {
 			.is_none());
 	}
 
+	/// Remove the artifact with the given ID from the table, so that it is prepared again the next
+	/// time it is needed.
+	pub fn remove(&mut self, artifact_id: &ArtifactId) -> Option<ArtifactState> {
+		self.artifacts.remove(artifact_id)
+	}
+
 	/// Remove and retrieve the artifacts from the table that are older than the supplied
 	/// Time-To-Live.
 	pub fn prune(&mut self, artifact_ttl: Duration) -> Vec<ArtifactId> {
@@ -268,7 +283,7 @@ impl Artifacts {
 #[cfg(test)]
 mod tests {
 	use super::{ArtifactId, Artifacts, NODE_VERSION};
-	use polkadot_primitives::ExecutorParamsHash;
+	use polkadot_primitives::ExecutorParamsPrepHash;
 	use sp_core::H256;
 	use std::{path::Path, str::FromStr};
 
@@ -293,7 +308,7 @@ mod tests {
 					"0022800000000000000000000000000000000000000000000000000000000000"
 				]
 				.into(),
-				ExecutorParamsHash::from_hash(sp_core::H256(hex_literal::hex![
+				ExecutorParamsPrepHash::from_hash(sp_core::H256(hex_literal::hex![
 					"0033900000000000000000000000000000000000000000000000000000000000"
 				])),
 			)),
@@ -311,13 +326,55 @@ mod tests {
 		let params_hash = H256::from_str(params_hash).unwrap();
 
 		assert_eq!(
-			ArtifactId::new(code_hash.into(), ExecutorParamsHash::from_hash(params_hash))
+			ArtifactId::new(code_hash.into(), ExecutorParamsPrepHash::from_hash(params_hash))
 				.path(dir)
 				.to_str(),
 			Some(format!("/test/{}", file_name).as_str()),
 		);
 	}
 
+	#[test]
+	fn artifact_id_ignores_execution_only_params() {
+		use polkadot_node_core_pvf_common::{prepare::PrepareJobKind, pvf::PvfPrepData};
+		use polkadot_primitives::{
+			ExecutorParam, ExecutorParams, PvfExecTimeoutKind, PvfPrepTimeoutKind,
+		};
+		use std::time::Duration;
+
+		let pvf = |params: &[ExecutorParam]| {
+			PvfPrepData::from_code(
+				vec![1, 2, 3],
+				ExecutorParams::from(params),
+				Duration::from_secs(1),
+				PrepareJobKind::Compilation,
+			)
+		};
+
+		let base = ArtifactId::from_pvf_prep_data(&pvf(&[ExecutorParam::StackLogicalMax(1024)]));
+
+		// a different execution timeout results in the same artifact
+		assert_eq!(
+			ArtifactId::from_pvf_prep_data(&pvf(&[
+				ExecutorParam::StackLogicalMax(1024),
+				ExecutorParam::PvfExecTimeout(PvfExecTimeoutKind::Backing, 3000),
+			])),
+			base,
+		);
+
+		// while a change to a preparation-related param results in a different one
+		assert_ne!(
+			ArtifactId::from_pvf_prep_data(&pvf(&[ExecutorParam::StackLogicalMax(2048)])),
+			base,
+		);
+		assert_ne!(
+			ArtifactId::from_pvf_prep_data(&pvf(&[
+				ExecutorParam::StackLogicalMax(1024),
+				ExecutorParam::PvfPrepTimeout(PvfPrepTimeoutKind::Lenient, 3000),
+			])),
+			base,
+		);
+	}
+
 	#[tokio::test]
 	async fn artifacts_removes_cache_on_startup() {
 		let fake_cache_path = crate::worker_intf::tmppath("test-cache").await.unwrap();
@@ -17,10 +17,11 @@
 //! Execution part of the pipeline.
 //!
 //! The validation host [runs the queue][`start`] communicating with it by sending [`ToQueue`]
-//! messages. The queue will spawn workers in new processes. Those processes should jump to
+//! messages, and receiving [`FromQueue`] ones for artifacts which turned out to be corrupted. The
+//! queue will spawn workers in new processes. Those processes should jump to
 //! `polkadot_node_core_pvf_worker::execute_worker_entrypoint`.
 
 mod queue;
 mod worker_intf;
 
-pub use queue::{start, PendingExecutionRequest, ToQueue};
+pub use queue::{start, FromQueue, PendingExecutionRequest, ToQueue};
@@ -25,12 +25,12 @@ use crate::{
 	InvalidCandidate, ValidationError, LOG_TARGET,
 };
 use futures::{
-	channel::mpsc,
+	channel::{mpsc, oneshot},
 	future::BoxFuture,
 	stream::{FuturesUnordered, StreamExt as _},
 	Future, FutureExt,
 };
-use polkadot_node_core_pvf_common::SecurityStatus;
+use polkadot_node_core_pvf_common::{error::InternalValidationError, SecurityStatus};
 use polkadot_primitives::{ExecutorParams, ExecutorParamsHash};
 use slotmap::HopSlotMap;
 use std::{
@@ -54,6 +54,13 @@ pub enum ToQueue {
 	Enqueue { artifact: ArtifactPathId, pending_execution_request: PendingExecutionRequest },
 }
 
+#[derive(Debug)]
+pub enum FromQueue {
+	/// The artifact turned out to be corrupted and should be removed, so that it is prepared again
+	/// the next time it is needed. The host replies through `reply_to` once it is removed.
+	RemoveArtifact { artifact: ArtifactId, reply_to: oneshot::Sender<()> },
+}
+
 /// An execution request that should execute the PVF (known in the context) and send the results
 /// to the given result sender.
 #[derive(Debug)]
@@ -137,6 +144,8 @@ struct Queue {
 
 	/// The receiver that receives messages to the pool.
 	to_queue_rx: mpsc::Receiver<ToQueue>,
+	/// The sender of messages to the host.
+	from_queue_tx: mpsc::UnboundedSender<FromQueue>,
 
 	// Some variables related to the current session.
 	program_path: PathBuf,
@@ -149,6 +158,8 @@ struct Queue {
 	queue: VecDeque<ExecuteJob>,
 	workers: Workers,
 	mux: Mux,
+	/// The results which are held back until the host has removed a corrupted artifact.
+	held_results: FuturesUnordered<BoxFuture<'static, ()>>,
 }
 
 impl Queue {
@@ -161,6 +172,7 @@ impl Queue {
 		node_version: Option<String>,
 		security_status: SecurityStatus,
 		to_queue_rx: mpsc::Receiver<ToQueue>,
+		from_queue_tx: mpsc::UnboundedSender<FromQueue>,
 	) -> Self {
 		Self {
 			metrics,
@@ -170,8 +182,10 @@ impl Queue {
 			node_version,
 			security_status,
 			to_queue_rx,
+			from_queue_tx,
 			queue: VecDeque::new(),
 			mux: Mux::new(),
+			held_results: FuturesUnordered::new(),
 			workers: Workers {
 				running: HopSlotMap::with_capacity_and_key(10),
 				spawn_inflight: 0,
@@ -191,6 +205,7 @@ impl Queue {
 					}
 				}
 				ev = self.mux.select_next_some() => handle_mux(&mut self, ev).await,
+				() = self.held_results.select_next_some() => {},
 			}
 
 			purge_dead(&self.metrics, &mut self.workers).await;
@@ -312,7 +327,7 @@ fn handle_worker_spawned(
 	handle: WorkerHandle,
 	job: ExecuteJob,
 ) {
-	queue.metrics.execute_worker().on_spawned();
+	queue.metrics.execute_worker().on_spawned(&idle.applied_policies);
 	queue.workers.spawn_inflight -= 1;
 	let worker = queue.workers.running.insert(WorkerData {
 		idle: Some(idle),
@@ -334,6 +349,7 @@ fn handle_job_finish(
 	artifact_id: ArtifactId,
 	result_tx: ResultSender,
 ) {
+	let remove_artifact = matches!(outcome, Outcome::CorruptedArtifact { .. });
 	let (idle_worker, result, duration) = match outcome {
 		Outcome::Ok { result_descriptor, duration, idle_worker } => {
 			// TODO: propagate the soft timeout
@@ -346,6 +362,12 @@ fn handle_job_finish(
 			None,
 		),
 		Outcome::InternalError { err } => (None, Err(ValidationError::InternalError(err)), None),
+		// The artifact is removed below, so that the retry of the job prepares it again.
+		Outcome::CorruptedArtifact { err, idle_worker } => (
+			Some(idle_worker),
+			Err(ValidationError::InternalError(InternalValidationError::CorruptedArtifact(err))),
+			None,
+		),
 		// Either the worker or the job timed out. Kill the worker in either case. Treated as
 		// definitely-invalid, because if we timed out, there's no time left for a retry.
 		Outcome::HardTimeout =>
@@ -388,7 +410,24 @@ fn handle_job_finish(
 
 	// First we send the result. It may fail due to the other end of the channel being dropped,
 	// that's legitimate and we don't treat that as an error.
-	let _ = result_tx.send(result);
+	//
+	// If the artifact is corrupted, the result is only sent once the host has removed it. This
+	// way, a retry of the job can't be enqueued for the corrupted artifact again.
+	if remove_artifact {
+		let (reply_to, removed) = oneshot::channel();
+		let _ = queue
+			.from_queue_tx
+			.unbounded_send(FromQueue::RemoveArtifact { artifact: artifact_id, reply_to });
+		queue.held_results.push(
+			async move {
+				let _ = removed.await;
+				let _ = result_tx.send(result);
+			}
+			.boxed(),
+		);
+	} else {
+		let _ = result_tx.send(result);
+	}
 
 	// Then, we should deal with the worker:
 	//
@@ -497,10 +536,12 @@ fn assign(queue: &mut Queue, worker: Worker, job: ExecuteJob) {
 			qed.",
 	);
 	let execution_timer = queue.metrics.time_execution();
+	let metrics = queue.metrics.clone();
 	queue.mux.push(
 		async move {
 			let _timer = execution_timer;
 			let outcome = super::worker_intf::start_work(
+				&metrics,
 				idle,
 				job.artifact.clone(),
 				job.exec_timeout,
@@ -521,8 +562,9 @@ pub fn start(
 	spawn_timeout: Duration,
 	node_version: Option<String>,
 	security_status: SecurityStatus,
-) -> (mpsc::Sender<ToQueue>, impl Future<Output = ()>) {
+) -> (mpsc::Sender<ToQueue>, mpsc::UnboundedReceiver<FromQueue>, impl Future<Output = ()>) {
 	let (to_queue_tx, to_queue_rx) = mpsc::channel(20);
+	let (from_queue_tx, from_queue_rx) = mpsc::unbounded();
 	let run = Queue::new(
 		metrics,
 		program_path,
@@ -532,7 +574,8 @@ pub fn start(
 		node_version,
 		security_status,
 		to_queue_rx,
+		from_queue_tx,
 	)
 	.run();
-	(to_queue_tx, run)
+	(to_queue_tx, from_queue_rx, run)
 }
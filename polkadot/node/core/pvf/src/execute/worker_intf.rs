@@ -18,6 +18,7 @@
 
 use crate::{
 	artifacts::ArtifactPathId,
+	metrics::Metrics,
 	security,
 	worker_intf::{
 		clear_worker_dir_path, framed_recv, framed_send, spawn_with_program_path, IdleWorker,
@@ -101,6 +102,9 @@ pub enum Outcome {
 	///
 	/// Because malicious code can cause a job error, we must not treat it as an internal error.
 	JobError { err: String },
+	/// The compiled artifact does not match its checksum. It has to be removed and prepared again
+	/// before the job can be retried. The worker is ready for another job.
+	CorruptedArtifact { err: String, idle_worker: IdleWorker },
 
 	/// An internal error happened during the validation. Such an error is most likely related to
 	/// some transient glitch.
@@ -116,12 +120,13 @@ pub enum Outcome {
 /// NOTE: Not returning the idle worker token in `Outcome` will trigger the child process being
 /// killed, if it's still alive.
 pub async fn start_work(
+	metrics: &Metrics,
 	worker: IdleWorker,
 	artifact: ArtifactPathId,
 	execution_timeout: Duration,
 	validation_params: Vec<u8>,
 ) -> Outcome {
-	let IdleWorker { mut stream, pid, worker_dir } = worker;
+	let IdleWorker { mut stream, pid, worker_dir, applied_policies } = worker;
 
 	gum::debug!(
 		target: LOG_TARGET,
@@ -170,10 +175,12 @@ pub async fn start_work(
 						// auditing enabled, so we don't want attackers to abuse a non-deterministic
 						// outcome.
 						for syscall in security::check_seccomp_violations_for_worker(audit_log_file, pid).await {
+							metrics.execute_worker().on_sandbox_violation();
 							gum::error!(
 								target: LOG_TARGET,
 								worker_pid = %pid,
 								%syscall,
+								seccomp_policy = ?applied_policies.seccomp,
 								validation_code_hash = ?artifact.id.code_hash,
 								?artifact_path,
 								"A forbidden syscall was attempted! This is a violation of our seccomp security policy. Report an issue ASAP!"
@@ -186,10 +193,12 @@ pub async fn start_work(
 						// Check if any syscall violations occurred during the job. For now this is
 						// only informative, as we are not enforcing the seccomp policy yet.
 						for syscall in security::check_seccomp_violations_for_worker(audit_log_file, pid).await {
+							metrics.execute_worker().on_sandbox_violation();
 							gum::error!(
 								target: LOG_TARGET,
 								worker_pid = %pid,
 								%syscall,
+								seccomp_policy = ?applied_policies.seccomp,
 								validation_code_hash = ?artifact.id.code_hash,
 								?artifact_path,
 								"A forbidden syscall was attempted! This is a violation of our seccomp security policy. Report an issue ASAP!"
@@ -231,15 +240,19 @@ pub async fn start_work(
 			WorkerResponse::Ok { result_descriptor, duration } => Outcome::Ok {
 				result_descriptor,
 				duration,
-				idle_worker: IdleWorker { stream, pid, worker_dir },
+				idle_worker: IdleWorker { stream, pid, worker_dir, applied_policies },
 			},
 			WorkerResponse::InvalidCandidate(err) => Outcome::InvalidCandidate {
 				err,
-				idle_worker: IdleWorker { stream, pid, worker_dir },
+				idle_worker: IdleWorker { stream, pid, worker_dir, applied_policies },
 			},
 			WorkerResponse::JobTimedOut => Outcome::HardTimeout,
 			WorkerResponse::JobDied(err) => Outcome::JobDied { err },
 			WorkerResponse::JobError(err) => Outcome::JobError { err },
+			WorkerResponse::CorruptedArtifact(err) => Outcome::CorruptedArtifact {
+				err,
+				idle_worker: IdleWorker { stream, pid, worker_dir, applied_policies },
+			},
 
 			WorkerResponse::InternalError(err) => Outcome::InternalError { err },
 		}
@@ -235,7 +235,7 @@ pub async fn start(
 		from_prepare_pool,
 	);
 
-	let (to_execute_queue_tx, run_execute_queue) = execute::start(
+	let (to_execute_queue_tx, from_execute_queue_rx, run_execute_queue) = execute::start(
 		metrics,
 		config.execute_worker_program_path.to_owned(),
 		config.cache_path.clone(),
@@ -260,6 +260,7 @@ pub async fn start(
 			to_prepare_queue_tx,
 			from_prepare_queue_rx,
 			to_execute_queue_tx,
+			from_execute_queue_rx,
 			to_sweeper_tx,
 			awaiting_prepare: AwaitingPrepare::default(),
 		})
@@ -307,6 +308,7 @@ struct Inner {
 	from_prepare_queue_rx: mpsc::UnboundedReceiver<prepare::FromQueue>,
 
 	to_execute_queue_tx: mpsc::Sender<execute::ToQueue>,
+	from_execute_queue_rx: mpsc::UnboundedReceiver<execute::FromQueue>,
 	to_sweeper_tx: mpsc::Sender<PathBuf>,
 
 	awaiting_prepare: AwaitingPrepare,
@@ -325,6 +327,7 @@ async fn run(
 		from_prepare_queue_rx,
 		mut to_prepare_queue_tx,
 		mut to_execute_queue_tx,
+		from_execute_queue_rx,
 		mut to_sweeper_tx,
 		mut awaiting_prepare,
 	}: Inner,
@@ -350,6 +353,7 @@ async fn run(
 
 	let mut to_host_rx = to_host_rx.fuse();
 	let mut from_prepare_queue_rx = from_prepare_queue_rx.fuse();
+	let mut from_execute_queue_rx = from_execute_queue_rx.fuse();
 
 	loop {
 		// biased to make it behave deterministically for tests.
@@ -409,6 +413,13 @@ async fn run(
 					from_queue,
 				).await);
 			},
+			from_execute_queue = from_execute_queue_rx.next() => {
+				let from_queue = break_if_fatal!(from_execute_queue.ok_or(Fatal));
+
+				// Corrupted artifacts are removed, so that they are prepared again when the
+				// execution is retried.
+				handle_execute_queue_message(&cache_path, &mut artifacts, from_queue).await;
+			},
 		}
 	}
 }
@@ -838,6 +849,32 @@ async fn handle_cleanup_pulse(
 	Ok(())
 }
 
+async fn handle_execute_queue_message(
+	cache_path: &Path,
+	artifacts: &mut Artifacts,
+	from_queue: execute::FromQueue,
+) {
+	let execute::FromQueue::RemoveArtifact { artifact, reply_to } = from_queue;
+	gum::warn!(
+		target: LOG_TARGET,
+		validation_code_hash = ?artifact.code_hash,
+		"removing a corrupted artifact, it will be prepared again",
+	);
+	artifacts.remove(&artifact);
+	// The file is removed right away rather than by the sweeper, which could otherwise remove the
+	// artifact prepared again in its place.
+	let artifact_path = artifact.path(cache_path);
+	if let Err(err) = tokio::fs::remove_file(&artifact_path).await {
+		gum::warn!(
+			target: LOG_TARGET,
+			?err,
+			"failed to remove the corrupted artifact file {}",
+			artifact_path.display(),
+		);
+	}
+	let _ = reply_to.send(());
+}
+
 /// A simple task which sole purpose is to delete files thrown at it.
 async fn sweeper_task(mut sweeper_rx: mpsc::Receiver<PathBuf>) {
 	loop {
@@ -946,6 +983,7 @@ pub(crate) mod tests {
 		to_prepare_queue_rx: mpsc::Receiver<prepare::ToQueue>,
 		from_prepare_queue_tx: mpsc::UnboundedSender<prepare::FromQueue>,
 		to_execute_queue_rx: mpsc::Receiver<execute::ToQueue>,
+		from_execute_queue_tx: mpsc::UnboundedSender<execute::FromQueue>,
 		to_sweeper_rx: mpsc::Receiver<PathBuf>,
 
 		run: BoxFuture<'static, ()>,
@@ -959,6 +997,7 @@ pub(crate) mod tests {
 			let (to_prepare_queue_tx, to_prepare_queue_rx) = mpsc::channel(10);
 			let (from_prepare_queue_tx, from_prepare_queue_rx) = mpsc::unbounded();
 			let (to_execute_queue_tx, to_execute_queue_rx) = mpsc::channel(10);
+			let (from_execute_queue_tx, from_execute_queue_rx) = mpsc::unbounded();
 			let (to_sweeper_tx, to_sweeper_rx) = mpsc::channel(10);
 
 			let run = run(Inner {
@@ -970,6 +1009,7 @@ pub(crate) mod tests {
 				to_prepare_queue_tx,
 				from_prepare_queue_rx,
 				to_execute_queue_tx,
+				from_execute_queue_rx,
 				to_sweeper_tx,
 				awaiting_prepare: AwaitingPrepare::default(),
 			})
@@ -980,6 +1020,7 @@ pub(crate) mod tests {
 				to_prepare_queue_rx,
 				from_prepare_queue_tx,
 				to_execute_queue_rx,
+				from_execute_queue_tx,
 				to_sweeper_rx,
 				run,
 			}
@@ -1138,6 +1179,58 @@ pub(crate) mod tests {
 		test.poll_ensure_to_sweeper_is_empty().await;
 	}
 
+	#[tokio::test]
+	async fn corrupted_artifact_is_prepared_again() {
+		let mut builder = Builder::default();
+		builder.artifacts.insert_prepared(
+			artifact_id(1),
+			SystemTime::now(),
+			PrepareStats::default(),
+		);
+		let mut test = builder.build();
+		let mut host = test.host_handle();
+
+		let (result_tx, _result_rx) = oneshot::channel();
+		host.execute_pvf(
+			PvfPrepData::from_discriminator(1),
+			TEST_EXECUTION_TIMEOUT,
+			b"pvf1".to_vec(),
+			Priority::Normal,
+			result_tx,
+		)
+		.await
+		.unwrap();
+		assert_matches!(
+			test.poll_and_recv_to_execute_queue().await,
+			execute::ToQueue::Enqueue { .. }
+		);
+
+		// The execute queue reports the artifact as corrupted.
+		let (reply_to, removed) = oneshot::channel();
+		test.from_execute_queue_tx
+			.send(execute::FromQueue::RemoveArtifact { artifact: artifact_id(1), reply_to })
+			.await
+			.unwrap();
+		test.poll_and_recv_result(removed).await;
+
+		// The retry has to prepare the artifact again.
+		let (result_tx, _result_rx) = oneshot::channel();
+		host.execute_pvf(
+			PvfPrepData::from_discriminator(1),
+			TEST_EXECUTION_TIMEOUT,
+			b"pvf1".to_vec(),
+			Priority::Normal,
+			result_tx,
+		)
+		.await
+		.unwrap();
+		assert_matches!(
+			test.poll_and_recv_to_prepare_queue().await,
+			prepare::ToQueue::Enqueue { .. }
+		);
+		test.poll_ensure_to_execute_queue_is_empty().await;
+	}
+
 	#[tokio::test]
 	async fn execute_pvf_requests() {
 		let mut test = Builder::default().build();
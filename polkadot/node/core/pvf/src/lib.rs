@@ -114,6 +114,7 @@ pub use polkadot_node_core_pvf_common::{
 	error::{InternalValidationError, PrepareError},
 	prepare::{PrepareJobKind, PrepareStats},
 	pvf::PvfPrepData,
+	worker::security::AppliedPolicies,
 	SecurityStatus,
 };
 
@@ -16,7 +16,7 @@
 
 //! Prometheus metrics related to the validation host.
 
-use polkadot_node_core_pvf_common::prepare::MemoryStats;
+use polkadot_node_core_pvf_common::{prepare::MemoryStats, worker::security::AppliedPolicies};
 use polkadot_node_metrics::metrics::{self, prometheus};
 
 /// Validation host metrics.
@@ -106,6 +106,8 @@ struct MetricsInner {
 	worker_spawning: prometheus::CounterVec<prometheus::U64>,
 	worker_spawned: prometheus::CounterVec<prometheus::U64>,
 	worker_retired: prometheus::CounterVec<prometheus::U64>,
+	worker_sandbox_policy: prometheus::GaugeVec<prometheus::U64>,
+	worker_sandbox_violations: prometheus::CounterVec<prometheus::U64>,
 	prepare_enqueued: prometheus::Counter<prometheus::U64>,
 	prepare_concluded: prometheus::Counter<prometheus::U64>,
 	execute_enqueued: prometheus::Counter<prometheus::U64>,
@@ -157,6 +159,26 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			worker_sandbox_policy: prometheus::register(
+				prometheus::GaugeVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_worker_sandbox_policy",
+						"The version of each sandbox policy applied by the last spawned worker, 0 if not applied",
+					),
+					&["flavor", "sandbox"],
+				)?,
+				registry,
+			)?,
+			worker_sandbox_violations: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_worker_sandbox_violations",
+						"The total number of operations denied by the sandbox of workers, as found in the audit log",
+					),
+					&["flavor"],
+				)?,
+				registry,
+			)?,
 			prepare_enqueued: prometheus::register(
 				prometheus::Counter::new(
 					"polkadot_pvf_prepare_enqueued",
@@ -323,10 +345,32 @@ impl<'a> WorkerRelatedMetrics<'a> {
 		}
 	}
 
-	/// When the worker successfully spawned.
-	pub(crate) fn on_spawned(&self) {
+	/// When the worker successfully spawned, having applied the given sandbox policies.
+	pub(crate) fn on_spawned(&self, applied_policies: &AppliedPolicies) {
 		if let Some(metrics) = &self.metrics.0 {
 			metrics.worker_spawned.with_label_values(&[self.flavor.as_label()]).inc();
+
+			let AppliedPolicies { landlock, seccomp, change_root } = applied_policies;
+			for (sandbox, version) in [
+				("landlock", landlock.unwrap_or(0)),
+				("seccomp", seccomp.unwrap_or(0)),
+				("change_root", *change_root as u32),
+			] {
+				metrics
+					.worker_sandbox_policy
+					.with_label_values(&[self.flavor.as_label(), sandbox])
+					.set(version.into());
+			}
+		}
+	}
+
+	/// When an operation denied by the sandbox was attempted by the worker.
+	pub(crate) fn on_sandbox_violation(&self) {
+		if let Some(metrics) = &self.metrics.0 {
+			metrics
+				.worker_sandbox_violations
+				.with_label_values(&[self.flavor.as_label()])
+				.inc();
 		}
 	}
 
@@ -318,7 +318,7 @@ fn handle_mux(
 ) -> Result<(), Fatal> {
 	match event {
 		PoolEvent::Spawn(idle, handle) => {
-			metrics.prepare_worker().on_spawned();
+			metrics.prepare_worker().on_spawned(&idle.applied_policies);
 
 			let worker = spawned.insert(WorkerData { idle: Some(idle), handle });
 
@@ -30,6 +30,7 @@ use polkadot_node_core_pvf_common::{
 	error::{PrepareError, PrepareResult},
 	prepare::PrepareStats,
 	pvf::PvfPrepData,
+	worker::security::AppliedPolicies,
 	worker_dir, SecurityStatus,
 };
 
@@ -117,7 +118,7 @@ pub async fn start_work(
 	pvf: PvfPrepData,
 	artifact_path: PathBuf,
 ) -> Outcome {
-	let IdleWorker { stream, pid, worker_dir } = worker;
+	let IdleWorker { stream, pid, worker_dir, applied_policies } = worker;
 
 	gum::debug!(
 		target: LOG_TARGET,
@@ -131,7 +132,8 @@ pub async fn start_work(
 		worker_dir,
 		stream,
 		pid,
-		|tmp_artifact_file, mut stream, worker_dir| async move {
+		applied_policies,
+		|tmp_artifact_file, mut stream, worker_dir, applied_policies| async move {
 			let preparation_timeout = pvf.prep_timeout();
 			let audit_log_file = security::AuditLogFile::try_open_and_seek_to_end().await;
 
@@ -163,10 +165,12 @@ pub async fn start_work(
 					// Check if any syscall violations occurred during the job. For now this is only
 					// informative, as we are not enforcing the seccomp policy yet.
 					for syscall in security::check_seccomp_violations_for_worker(audit_log_file, pid).await {
+						metrics.prepare_worker().on_sandbox_violation();
 						gum::error!(
 							target: LOG_TARGET,
 							worker_pid = %pid,
 							%syscall,
+							seccomp_policy = ?applied_policies.seccomp,
 							?pvf,
 							"A forbidden syscall was attempted! This is a violation of our seccomp security policy. Report an issue ASAP!"
 						);
@@ -174,7 +178,7 @@ pub async fn start_work(
 
 					handle_response(
 						metrics,
-						IdleWorker { stream, pid, worker_dir },
+						IdleWorker { stream, pid, worker_dir, applied_policies },
 						prepare_result,
 						pid,
 						tmp_artifact_file,
@@ -288,11 +292,12 @@ async fn with_worker_dir_setup<F, Fut>(
 	worker_dir: WorkerDir,
 	stream: UnixStream,
 	pid: u32,
+	applied_policies: AppliedPolicies,
 	f: F,
 ) -> Outcome
 where
 	Fut: futures::Future<Output = Outcome>,
-	F: FnOnce(PathBuf, UnixStream, WorkerDir) -> Fut,
+	F: FnOnce(PathBuf, UnixStream, WorkerDir, AppliedPolicies) -> Fut,
 {
 	// Create the tmp file here so that the child doesn't need any file creation rights. This will
 	// be cleared at the end of this function.
@@ -306,13 +311,13 @@ where
 			err,
 		);
 		return Outcome::CreateTmpFileErr {
-			worker: IdleWorker { stream, pid, worker_dir },
+			worker: IdleWorker { stream, pid, worker_dir, applied_policies },
 			err: format!("{:?}", err),
 		}
 	};
 
 	let worker_dir_path = worker_dir.path.clone();
-	let outcome = f(tmp_file, stream, worker_dir).await;
+	let outcome = f(tmp_file, stream, worker_dir, applied_policies).await;
 
 	// Try to clear the worker dir.
 	if let Err(err) = clear_worker_dir_path(&worker_dir_path) {
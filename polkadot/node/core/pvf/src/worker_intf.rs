@@ -19,8 +19,9 @@
 use crate::LOG_TARGET;
 use futures::FutureExt as _;
 use futures_timer::Delay;
+use parity_scale_codec::Decode;
 use pin_project::pin_project;
-use polkadot_node_core_pvf_common::SecurityStatus;
+use polkadot_node_core_pvf_common::{worker::security::AppliedPolicies, SecurityStatus};
 use rand::Rng;
 use std::{
 	fmt, mem,
@@ -56,6 +57,9 @@ pub const JOB_TIMEOUT_WALL_CLOCK_FACTOR: u32 = 4;
 /// - `spawn_timeout`: The amount of time to wait for the child process to spawn.
 ///
 /// - `security_status`: contains the detected status of security features.
+///
+/// Once connected, the worker reports the sandbox policies it applied, which must match those
+/// expected from `security_status`. A mismatch is logged, but does not fail the spawn.
 #[doc(hidden)]
 pub async fn spawn_with_program_path(
 	debug_id: &'static str,
@@ -92,7 +96,7 @@ pub async fn spawn_with_program_path(
 				&extra_args,
 				&socket_path,
 				&worker_dir.path,
-				security_status,
+				security_status.clone(),
 			)
 			.map_err(|err| {
 				gum::warn!(
@@ -109,22 +113,57 @@ pub async fn spawn_with_program_path(
 			})?;
 
 			let worker_dir_path = worker_dir.path.clone();
+			let pid = handle.id();
+			let accept_and_recv_policies = async {
+				let (mut stream, _) = listener.accept().await.map_err(|err| {
+					gum::warn!(
+						target: LOG_TARGET,
+						%debug_id,
+						?program_path,
+						?extra_args,
+						?worker_dir_path,
+						?socket_path,
+						"cannot accept a worker: {:?}",
+						err,
+					);
+					SpawnErr::Accept
+				})?;
+				let applied_policies = recv_applied_policies(&mut stream).await.map_err(|err| {
+					gum::warn!(
+						target: LOG_TARGET,
+						%debug_id,
+						?program_path,
+						worker_pid = %pid,
+						"cannot receive the applied sandbox policies of a worker: {:?}",
+						err,
+					);
+					SpawnErr::PolicyReport
+				})?;
+				Ok::<_, SpawnErr>((stream, applied_policies))
+			};
 			futures::select! {
-				accept_result = listener.accept().fuse() => {
-					let (stream, _) = accept_result.map_err(|err| {
+				accept_result = accept_and_recv_policies.fuse() => {
+					let (stream, applied_policies) = accept_result?;
+					let expected_policies = AppliedPolicies::expected(&security_status);
+					if applied_policies != expected_policies {
 						gum::warn!(
 							target: LOG_TARGET,
 							%debug_id,
-							?program_path,
-							?extra_args,
-							?worker_dir_path,
-							?socket_path,
-							"cannot accept a worker: {:?}",
-							err,
+							worker_pid = %pid,
+							?applied_policies,
+							?expected_policies,
+							"worker did not apply the expected sandbox policies",
+						);
+					} else {
+						gum::debug!(
+							target: LOG_TARGET,
+							%debug_id,
+							worker_pid = %pid,
+							?applied_policies,
+							"worker applied sandbox policies",
 						);
-						SpawnErr::Accept
-					})?;
-					Ok((IdleWorker { stream, pid: handle.id(), worker_dir }, handle))
+					}
+					Ok((IdleWorker { stream, pid, worker_dir, applied_policies }, handle))
 				}
 				_ = Delay::new(spawn_timeout).fuse() => {
 					gum::warn!(
@@ -217,6 +256,9 @@ pub struct IdleWorker {
 	/// The temporary per-worker path. We clean up the worker dir between jobs and delete it when
 	/// the worker dies.
 	pub worker_dir: WorkerDir,
+
+	/// The sandbox policies the worker reported to have applied.
+	pub applied_policies: AppliedPolicies,
 }
 
 /// An error happened during spawning a worker process.
@@ -236,6 +278,8 @@ pub enum SpawnErr {
 	AcceptTimeout,
 	/// Failed to send handshake after successful spawning was signaled
 	Handshake,
+	/// Failed to receive the sandbox policies applied by the worker.
+	PolicyReport,
 }
 
 /// This is a representation of a potentially running worker. Drop it and the process will be
@@ -384,6 +428,16 @@ pub async fn framed_send(w: &mut (impl AsyncWrite + Unpin), buf: &[u8]) -> io::R
 }
 
 /// Read some data prefixed by its length from `r`.
+async fn recv_applied_policies(stream: &mut UnixStream) -> io::Result<AppliedPolicies> {
+	let report = framed_recv(stream).await?;
+	AppliedPolicies::decode(&mut &report[..]).map_err(|e| {
+		io::Error::new(
+			io::ErrorKind::Other,
+			format!("recv_applied_policies: decode error: {:?}", e),
+		)
+	})
+}
+
 pub async fn framed_recv(r: &mut (impl AsyncRead + Unpin)) -> io::Result<Vec<u8>> {
 	let mut len_buf = [0u8; mem::size_of::<usize>()];
 	r.read_exact(&mut len_buf).await?;
@@ -16,7 +16,7 @@
 
 use polkadot_node_core_pvf::{
 	testing::{build_workers_and_get_paths, spawn_with_program_path, SpawnErr},
-	SecurityStatus,
+	AppliedPolicies, SecurityStatus,
 };
 use std::{env, time::Duration};
 
@@ -59,7 +59,7 @@ async fn spawn_timeout() {
 async fn should_connect() {
 	let (prepare_worker_path, _) = build_workers_and_get_paths(false);
 
-	let _ = spawn_with_program_path(
+	let (idle, _handle) = spawn_with_program_path(
 		"integration-test",
 		prepare_worker_path,
 		&env::temp_dir(),
@@ -69,4 +69,7 @@ async fn should_connect() {
 	)
 	.await
 	.unwrap();
+
+	// No security features were requested, so none should have been applied.
+	assert_eq!(idle.applied_policies, AppliedPolicies::default());
 }
@@ -66,6 +66,7 @@ pub(crate) struct RequestResultCache {
 	minimum_backing_votes: LruMap<SessionIndex, u32>,
 	disabled_validators: LruMap<Hash, Vec<ValidatorIndex>>,
 	para_backing_state: LruMap<(Hash, ParaId), Option<async_backing::BackingState>>,
+	para_async_backing_params: LruMap<(Hash, ParaId), async_backing::AsyncBackingParams>,
 	async_backing_params: LruMap<Hash, async_backing::AsyncBackingParams>,
 	node_features: LruMap<SessionIndex, vstaging::NodeFeatures>,
 }
@@ -100,6 +101,7 @@ impl Default for RequestResultCache {
 			minimum_backing_votes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			disabled_validators: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			para_backing_state: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
+			para_async_backing_params: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			async_backing_params: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			node_features: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 		}
@@ -493,6 +495,21 @@ impl RequestResultCache {
 		self.para_backing_state.insert(key, value);
 	}
 
+	pub(crate) fn para_async_backing_params(
+		&mut self,
+		key: (Hash, ParaId),
+	) -> Option<&async_backing::AsyncBackingParams> {
+		self.para_async_backing_params.get(&key).map(|v| &*v)
+	}
+
+	pub(crate) fn cache_para_async_backing_params(
+		&mut self,
+		key: (Hash, ParaId),
+		value: async_backing::AsyncBackingParams,
+	) {
+		self.para_async_backing_params.insert(key, value);
+	}
+
 	pub(crate) fn async_backing_params(
 		&mut self,
 		key: &Hash,
@@ -558,4 +575,5 @@ pub(crate) enum RequestResult {
 	ParaBackingState(Hash, ParaId, Option<async_backing::BackingState>),
 	AsyncBackingParams(Hash, async_backing::AsyncBackingParams),
 	NodeFeatures(SessionIndex, vstaging::NodeFeatures),
+	ParaAsyncBackingParams(Hash, ParaId, async_backing::AsyncBackingParams),
 }
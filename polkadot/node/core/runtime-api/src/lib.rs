@@ -175,6 +175,9 @@ where
 				self.requests_cache.cache_async_backing_params(relay_parent, params),
 			NodeFeatures(session_index, params) =>
 				self.requests_cache.cache_node_features(session_index, params),
+			ParaAsyncBackingParams(relay_parent, para_id, params) => self
+				.requests_cache
+				.cache_para_async_backing_params((relay_parent, para_id), params),
 		}
 	}
 
@@ -306,6 +309,9 @@ where
 				.map(|sender| Request::ParaBackingState(para, sender)),
 			Request::AsyncBackingParams(sender) => query!(async_backing_params(), sender)
 				.map(|sender| Request::AsyncBackingParams(sender)),
+			Request::ParaAsyncBackingParams(para, sender) =>
+				query!(para_async_backing_params(para), sender)
+					.map(|sender| Request::ParaAsyncBackingParams(para, sender)),
 			Request::MinimumBackingVotes(index, sender) => {
 				if let Some(value) = self.requests_cache.minimum_backing_votes(index) {
 					self.metrics.on_cached_request();
@@ -612,5 +618,11 @@ where
 			sender,
 			result = (index)
 		),
+		Request::ParaAsyncBackingParams(para, sender) => query!(
+			ParaAsyncBackingParams,
+			para_async_backing_params(para),
+			ver = Request::PARA_ASYNC_BACKING_PARAMS_RUNTIME_REQUIREMENT,
+			sender
+		),
 	}
 }
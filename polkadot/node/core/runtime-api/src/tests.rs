@@ -276,6 +276,14 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	async fn disabled_validators(&self, _: Hash) -> Result<Vec<ValidatorIndex>, ApiError> {
 		todo!("Not required for tests")
 	}
+
+	async fn para_async_backing_params(
+		&self,
+		_: Hash,
+		_: ParaId,
+	) -> Result<async_backing::AsyncBackingParams, ApiError> {
+		todo!("Not required for tests")
+	}
 }
 
 #[test]
@@ -36,6 +36,8 @@ enum NemesisVariant {
 	BackGarbageCandidate(BackGarbageCandidateOptions),
 	/// Delayed disputing of ancestors that are perfectly fine.
 	DisputeAncestor(DisputeAncestorOptions),
+	/// Simulate faults while preparing PVFs.
+	PrepareFault(PrepareFaultOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -80,6 +82,11 @@ impl MalusCli {
 					finality_delay,
 				)?
 			},
+			NemesisVariant::PrepareFault(opts) => {
+				let PrepareFaultOptions { fault, percentage, cli } = opts;
+
+				polkadot_cli::run_node(cli, PrepareFaults { fault, percentage }, finality_delay)?
+			},
 		}
 		Ok(())
 	}
@@ -166,6 +173,28 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn fault_works_prepare_fault() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"prepare-fault",
+			"--fault",
+			"out-of-memory",
+			"--percentage",
+			"50",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::PrepareFault(run),
+			..
+		} => {
+			assert_eq!(run.fault, FakePrepareFault::OutOfMemory);
+			assert_eq!(run.percentage, 50);
+			assert!(run.cli.run.base.bob);
+		});
+	}
+
 	#[test]
 	#[should_panic]
 	fn validate_range_for_percentage() {
@@ -19,11 +19,13 @@
 mod back_garbage_candidate;
 mod common;
 mod dispute_valid_candidates;
+mod prepare_faults;
 mod suggest_garbage_candidate;
 
 pub(crate) use self::{
 	back_garbage_candidate::{BackGarbageCandidateOptions, BackGarbageCandidates},
 	dispute_valid_candidates::{DisputeAncestorOptions, DisputeValidCandidates},
+	prepare_faults::{FakePrepareFault, PrepareFaultOptions, PrepareFaults},
 	suggest_garbage_candidate::{SuggestGarbageCandidateOptions, SuggestGarbageCandidates},
 };
 pub(crate) use common::*;
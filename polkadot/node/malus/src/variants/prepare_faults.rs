@@ -0,0 +1,223 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that simulates faults while preparing PVFs.
+//!
+//! Depending on the configured fault, PVF pre-checking votes are cast and candidate validation
+//! responds as if preparing the PVF had failed. Corrupted artifacts make candidates appear
+//! invalid and therefore lead to disputes, while timeouts and running out of memory make the node
+//! fail to validate candidates.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_primitives::{InvalidCandidate, ValidationResult};
+use polkadot_node_subsystem::{
+	messages::{CandidateValidationMessage, PreCheckOutcome, ValidationFailed},
+	overseer, SpawnGlue,
+};
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use sp_core::traits::SpawnNamed;
+
+use crate::{interceptor::*, shared::MALUS};
+
+use rand::distributions::{Bernoulli, Distribution};
+use std::sync::Arc;
+
+/// The fault to simulate while preparing PVFs.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+#[value(rename_all = "kebab-case")]
+pub enum FakePrepareFault {
+	/// The prepared artifact is corrupted, so executing it fails.
+	ArtifactCorruption,
+	/// Preparation exceeds its time limit.
+	Timeout,
+	/// Preparation exceeds its memory limit.
+	OutOfMemory,
+}
+
+impl FakePrepareFault {
+	/// The pre-checking outcome if preparation fails with this fault before the artifact is
+	/// written.
+	fn precheck_outcome(&self) -> Option<PreCheckOutcome> {
+		match self {
+			// The artifact is only corrupted after it has been prepared successfully.
+			Self::ArtifactCorruption => None,
+			// A timeout can be caused by local load, so the node abstains from voting.
+			Self::Timeout => Some(PreCheckOutcome::Failed),
+			// Running out of memory is deterministic, so the node votes against the PVF.
+			Self::OutOfMemory => Some(PreCheckOutcome::Invalid),
+		}
+	}
+
+	/// The result of validating a candidate whose PVF failed to prepare with this fault.
+	fn validation_result(&self) -> Result<ValidationResult, ValidationFailed> {
+		match self {
+			Self::ArtifactCorruption => Ok(ValidationResult::Invalid(
+				InvalidCandidate::ExecutionError("Malus: corrupted artifact".into()),
+			)),
+			// A preparation error is never attributed to the candidate, so validation fails
+			// without a verdict, as it does in the candidate validation subsystem.
+			Self::Timeout => Err(ValidationFailed("Malus: preparation timed out".into())),
+			Self::OutOfMemory =>
+				Err(ValidationFailed("Malus: preparation ran out of memory".into())),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct PrepareFaultOptions {
+	/// The fault to simulate while preparing PVFs.
+	#[arg(long, value_enum, ignore_case = true, default_value_t = FakePrepareFault::ArtifactCorruption)]
+	pub fault: FakePrepareFault,
+
+	/// Determines the percentage of PVF preparations which are faulty. Value must be in the range
+	/// [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// An interceptor which responds to candidate validation requests as if preparing the PVF had
+/// failed with a preconfigured fault.
+#[derive(Clone, Debug)]
+pub struct ReplacePreparationResult {
+	fault: FakePrepareFault,
+	distribution: Bernoulli,
+}
+
+impl ReplacePreparationResult {
+	pub fn new(fault: FakePrepareFault, percentage: f64) -> Self {
+		let distribution = Bernoulli::new(percentage / 100.0)
+			.expect("Invalid probability! Percentage must be in range [0..=100].");
+		Self { fault, distribution }
+	}
+
+	fn behave_maliciously(&self) -> bool {
+		self.distribution.sample(&mut rand::thread_rng())
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for ReplacePreparationResult
+where
+	Sender: overseer::CandidateValidationSenderTrait + Clone + Send + 'static,
+{
+	type Message = CandidateValidationMessage;
+
+	fn intercept_incoming(
+		&self,
+		_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg:
+					CandidateValidationMessage::PreCheck {
+						validation_code_hash, response_sender, ..
+					},
+			} if self.fault.precheck_outcome().is_some() && self.behave_maliciously() => {
+				let outcome = self.fault.precheck_outcome().expect("checked above; qed");
+				gum::info!(
+					target: MALUS,
+					?validation_code_hash,
+					fault = ?self.fault,
+					"😈 Simulating faulty PVF preparation, pre-check outcome: {:?}.",
+					&outcome,
+				);
+
+				let _ = response_sender.send(outcome);
+				None
+			},
+			FromOrchestra::Communication {
+				msg:
+					CandidateValidationMessage::ValidateFromChainState {
+						candidate_receipt,
+						response_sender,
+						..
+					},
+			} |
+			FromOrchestra::Communication {
+				msg:
+					CandidateValidationMessage::ValidateFromExhaustive {
+						candidate_receipt,
+						response_sender,
+						..
+					},
+			} if self.behave_maliciously() => {
+				let result = self.fault.validation_result();
+				gum::info!(
+					target: MALUS,
+					para_id = ?candidate_receipt.descriptor.para_id,
+					candidate_hash = ?candidate_receipt.hash(),
+					fault = ?self.fault,
+					"😈 Simulating faulty PVF preparation, validation result: {:?}.",
+					&result,
+				);
+
+				let _ = response_sender.send(result);
+				None
+			},
+			msg => Some(msg),
+		}
+	}
+}
+
+pub(crate) struct PrepareFaults {
+	/// The fault to simulate.
+	pub fault: FakePrepareFault,
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for PrepareFaults {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		let validation_filter =
+			ReplacePreparationResult::new(self.fault, f64::from(self.percentage));
+
+		prepared_overseer_builder(args)?
+			.replace_candidate_validation(move |cv_subsystem| {
+				InterceptedSubsystem::new(cv_subsystem, validation_filter)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -46,7 +46,7 @@ use polkadot_node_subsystem::{
 		ApprovalCheckResult, ApprovalDistributionMessage, ApprovalVotingMessage,
 		AssignmentCheckResult, NetworkBridgeEvent, NetworkBridgeTxMessage,
 	},
-	overseer, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError,
+	overseer, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError, SubsystemLoad,
 };
 use polkadot_node_subsystem_util::reputation::{ReputationAggregator, REPUTATION_CHANGE_INTERVAL};
 use polkadot_primitives::{
@@ -55,7 +55,7 @@ use polkadot_primitives::{
 use rand::{CryptoRng, Rng, SeedableRng};
 use std::{
 	collections::{hash_map, BTreeMap, HashMap, HashSet, VecDeque},
-	time::Duration,
+	time::{Duration, Instant},
 };
 
 mod metrics;
@@ -80,9 +80,13 @@ const BENEFIT_VALID_MESSAGE_FIRST: Rep =
 // Maximum valid size for the `CandidateBitfield` in the assignment messages.
 const MAX_BITFIELD_SIZE: usize = 500;
 
+// The subsystem whose load defers the resending of messages to all peers.
+const NETWORK_BRIDGE_TX_SUBSYSTEM: &str = "network-bridge-tx-subsystem";
+
 /// The Approval Distribution subsystem.
 pub struct ApprovalDistribution {
 	metrics: Metrics,
+	subsystem_load: SubsystemLoad,
 }
 
 /// Contains recently finalized
@@ -128,6 +132,8 @@ struct ApprovalEntry {
 	validator_index: ValidatorIndex,
 	// Information required for gossiping to other peers using the grid topology.
 	routing_info: ApprovalRouting,
+	// When the assignment was first imported by us.
+	received_at: Instant,
 }
 
 #[derive(Debug)]
@@ -150,9 +156,26 @@ impl ApprovalEntry {
 			approvals: HashMap::with_capacity(candidates.len()),
 			candidates,
 			routing_info,
+			received_at: Instant::now(),
 		}
 	}
 
+	// Returns `true` if the assignment was imported at least `timeout` ago and we are still
+	// missing approvals for some of the claimed candidates.
+	pub fn is_no_show(&self, now: Instant, timeout: Duration) -> bool {
+		self.is_due(now, timeout) && self.approvals.len() < self.candidates.count_ones()
+	}
+
+	// Returns `true` if the assignment was imported at least `timeout` ago.
+	pub fn is_due(&self, now: Instant, timeout: Duration) -> bool {
+		now.saturating_duration_since(self.received_at) >= timeout
+	}
+
+	// Time elapsed since the assignment was imported.
+	pub fn elapsed(&self) -> Duration {
+		self.received_at.elapsed()
+	}
+
 	// Create a `MessageSubject` to reference the assignment.
 	pub fn create_assignment_knowledge(&self, block_hash: Hash) -> (MessageSubject, MessageKind) {
 		(
@@ -276,6 +299,15 @@ struct AggressionConfig {
 	/// How often to re-send messages to all targeted recipients.
 	/// This applies to all unfinalized blocks.
 	resend_unfinalized_period: Option<BlockNumber>,
+	/// The minimum number of assignments past the no-show duration of their block a block needs
+	/// before its no-show rate is taken into account.
+	min_no_show_samples: usize,
+	/// Escalate a block to aggression level 1 once this percentage of its due assignments are
+	/// no-shows.
+	l1_no_show_percent: Option<u8>,
+	/// Escalate a block to aggression level 2 once this percentage of its due assignments are
+	/// no-shows.
+	l2_no_show_percent: Option<u8>,
 }
 
 impl AggressionConfig {
@@ -289,6 +321,25 @@ impl AggressionConfig {
 			false
 		}
 	}
+
+	/// Returns the aggression level warranted by the observed no-shows of a block, where
+	/// `due` is the number of assignments older than the no-show duration of the block.
+	fn no_show_aggression_level(&self, due: usize, no_shows: usize) -> AggressionLevel {
+		if due == 0 || due < self.min_no_show_samples {
+			return AggressionLevel::L0
+		}
+
+		let percent = no_shows.saturating_mul(100) / due;
+		let reached = |threshold: Option<u8>| threshold.map_or(false, |t| percent >= t as usize);
+
+		if reached(self.l2_no_show_percent) {
+			AggressionLevel::L2
+		} else if reached(self.l1_no_show_percent) {
+			AggressionLevel::L1
+		} else {
+			AggressionLevel::L0
+		}
+	}
 }
 
 impl Default for AggressionConfig {
@@ -297,6 +348,31 @@ impl Default for AggressionConfig {
 			l1_threshold: Some(13),
 			l2_threshold: Some(28),
 			resend_unfinalized_period: Some(8),
+			// No-show based aggression is opt-in.
+			min_no_show_samples: 3,
+			l1_no_show_percent: None,
+			l2_no_show_percent: None,
+		}
+	}
+}
+
+/// The aggression level a block has been escalated to because of no-shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum AggressionLevel {
+	/// Normal grid topology routing.
+	L0,
+	/// Originators send their own messages to all peers.
+	L1,
+	/// Level 1 + everyone sends to all peers in the X and Y dimensions.
+	L2,
+}
+
+impl AggressionLevel {
+	fn as_label(&self) -> &'static str {
+		match self {
+			AggressionLevel::L0 => "l0",
+			AggressionLevel::L1 => "l1",
+			AggressionLevel::L2 => "l2",
 		}
 	}
 }
@@ -346,6 +422,9 @@ struct State {
 
 	/// Aggregated reputation change
 	reputation: ReputationAggregator,
+
+	/// The load of the other subsystems, used to defer non-critical work.
+	subsystem_load: SubsystemLoad,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -450,9 +529,31 @@ struct BlockEntry {
 	/// Approval entries for whole block. These also contain all approvals in the case of multiple
 	/// candidates being claimed by assignments.
 	approval_entries: HashMap<(ValidatorIndex, CandidateBitfield), ApprovalEntry>,
+	/// When we learned about the block.
+	imported_at: Instant,
+	/// How long approval-voting waits for the approval of an assignment before counting its
+	/// validator as a no-show.
+	no_show_duration: Duration,
+	/// The aggression level reached because of observed no-shows. It never decreases.
+	no_show_aggression: AggressionLevel,
 }
 
 impl BlockEntry {
+	// Returns the number of assignments imported at least `no_show_duration` ago and how many of
+	// them are still missing approvals, i.e. the assignments approval-voting counts as no-shows.
+	//
+	// Like approval-voting, the no-show window of an assignment starts when it is received, which
+	// is never before its tranche, as assignments are only gossiped once due.
+	pub fn no_show_stats(&self, now: Instant) -> (usize, usize) {
+		self.approval_entries.values().fold((0, 0), |(due, no_shows), entry| {
+			if entry.is_due(now, self.no_show_duration) {
+				(due + 1, no_shows + entry.is_no_show(now, self.no_show_duration) as usize)
+			} else {
+				(due, no_shows)
+			}
+		})
+	}
+
 	// Returns the peer which currently know this block.
 	pub fn known_by(&self) -> Vec<PeerId> {
 		self.known_by.keys().cloned().collect::<Vec<_>>()
@@ -657,6 +758,9 @@ impl State {
 						candidates,
 						session: meta.session,
 						approval_entries: HashMap::new(),
+						imported_at: Instant::now(),
+						no_show_duration: meta.no_show_duration,
+						no_show_aggression: AggressionLevel::L0,
 					});
 
 					self.topologies.inc_session_refs(meta.session);
@@ -1261,6 +1365,7 @@ impl State {
 		// Invariant: to our knowledge, none of the peers except for the `source` know about the
 		// assignment.
 		metrics.on_assignment_imported(&assignment.cert.kind);
+		metrics.on_assignment_propagation_latency(entry.imported_at.elapsed());
 
 		let topology = self.topologies.get_topology(entry.session);
 		let local = source == MessageSource::Local;
@@ -1572,6 +1677,7 @@ impl State {
 					return
 				}
 
+				metrics.on_approval_latency(approval_entry.elapsed());
 				approval_entry.routing_info().required_routing
 			},
 			None => {
@@ -1812,6 +1918,8 @@ impl State {
 	) {
 		let config = self.aggression_config.clone();
 
+		self.escalate_on_no_shows(ctx, metrics).await;
+
 		if !self.aggression_config.should_trigger_aggression(self.approval_checking_lag) {
 			gum::trace!(
 				target: LOG_TARGET,
@@ -1821,6 +1929,20 @@ impl State {
 			return
 		}
 
+		// Resending to all peers is a burst of messages, don't add it to an already overloaded
+		// network bridge. The aggression below is still applied, it only targets a few peers.
+		let resend = if resend == Resend::Yes &&
+			self.subsystem_load.is_overloaded(NETWORK_BRIDGE_TX_SUBSYSTEM)
+		{
+			gum::debug!(
+				target: LOG_TARGET,
+				"Network bridge is overloaded, deferring the resend of unfinalized messages",
+			);
+			Resend::No
+		} else {
+			resend
+		};
+
 		let max_age = self.blocks_by_number.iter().rev().next().map(|(num, _)| num);
 
 		let max_age = match max_age {
@@ -1911,6 +2033,88 @@ impl State {
 		.await;
 	}
 
+	// Escalates the aggression level of blocks whose approvals are not converging, judged by the
+	// share of assignments which are still missing approvals after the no-show duration of their
+	// block.
+	//
+	// Unlike the lag based aggression, this does not wait for finality to stall and applies to
+	// every block with too many no-shows. Once escalated, a block stays at its level until it is
+	// pruned, so assignments imported later are also routed with the wider topology.
+	async fn escalate_on_no_shows<Context>(&mut self, ctx: &mut Context, metrics: &Metrics) {
+		let config = &self.aggression_config;
+		if config.l1_no_show_percent.is_none() && config.l2_no_show_percent.is_none() {
+			return
+		}
+
+		let now = Instant::now();
+		let mut escalated = false;
+		for (block_hash, block_entry) in self.blocks.iter_mut() {
+			let (due, no_shows) = block_entry.no_show_stats(now);
+			let level = config.no_show_aggression_level(due, no_shows);
+
+			if level > block_entry.no_show_aggression {
+				gum::debug!(
+					target: LOG_TARGET,
+					?block_hash,
+					due,
+					no_shows,
+					?level,
+					"Escalating aggression because of no-shows",
+				);
+				metrics.on_aggression_escalated(level.as_label());
+				block_entry.no_show_aggression = level;
+			}
+
+			escalated |= block_entry.no_show_aggression > AggressionLevel::L0;
+		}
+
+		if !escalated {
+			return
+		}
+
+		adjust_required_routing_and_propagate(
+			ctx,
+			&mut self.blocks,
+			&self.topologies,
+			|block_entry| block_entry.no_show_aggression >= AggressionLevel::L1,
+			|required_routing, local, _| {
+				// Message originator sends to everyone.
+				if local &&
+					*required_routing != RequiredRouting::PendingTopology &&
+					*required_routing != RequiredRouting::All
+				{
+					metrics.on_aggression_l1();
+					RequiredRouting::All
+				} else {
+					*required_routing
+				}
+			},
+			&self.peer_views,
+		)
+		.await;
+
+		adjust_required_routing_and_propagate(
+			ctx,
+			&mut self.blocks,
+			&self.topologies,
+			|block_entry| block_entry.no_show_aggression >= AggressionLevel::L2,
+			|required_routing, local, _| {
+				// Everyone else sends to XY.
+				if !local &&
+					*required_routing != RequiredRouting::PendingTopology &&
+					*required_routing != RequiredRouting::GridXY
+				{
+					metrics.on_aggression_l2();
+					RequiredRouting::GridXY
+				} else {
+					*required_routing
+				}
+			},
+			&self.peer_views,
+		)
+		.await;
+	}
+
 	// Filter out invalid candidate index and certificate core bitfields.
 	// For each invalid assignment we also punish the peer.
 	async fn sanitize_v1_assignments(
@@ -2131,11 +2335,19 @@ async fn modify_reputation(
 impl ApprovalDistribution {
 	/// Create a new instance of the [`ApprovalDistribution`] subsystem.
 	pub fn new(metrics: Metrics) -> Self {
-		Self { metrics }
+		Self { metrics, subsystem_load: Default::default() }
+	}
+
+	/// Consult the given [`SubsystemLoad`] before resending messages to all peers.
+	///
+	/// It needs to be the instance handed to the overseer.
+	pub fn with_subsystem_load(mut self, subsystem_load: SubsystemLoad) -> Self {
+		self.subsystem_load = subsystem_load;
+		self
 	}
 
 	async fn run<Context>(self, ctx: Context) {
-		let mut state = State::default();
+		let mut state = State { subsystem_load: self.subsystem_load.clone(), ..Default::default() };
 
 		// According to the docs of `rand`, this is a ChaCha12 RNG in practice
 		// and will always be chosen for strong performance and security properties.
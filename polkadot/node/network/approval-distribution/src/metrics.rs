@@ -16,6 +16,7 @@
 
 use polkadot_node_metrics::metrics::{prometheus, Metrics as MetricsTrait};
 use polkadot_node_primitives::approval::v2::AssignmentCertKindV2;
+use std::time::Duration;
 
 /// Approval Distribution metrics.
 #[derive(Default, Clone)]
@@ -28,6 +29,9 @@ struct MetricsInner {
 	unified_with_peer_total: prometheus::Counter<prometheus::U64>,
 	aggression_l1_messages_total: prometheus::Counter<prometheus::U64>,
 	aggression_l2_messages_total: prometheus::Counter<prometheus::U64>,
+	aggression_escalations_total: prometheus::CounterVec<prometheus::U64>,
+	assignment_propagation_latency: prometheus::Histogram,
+	approval_latency: prometheus::Histogram,
 	time_unify_with_peer: prometheus::Histogram,
 	time_import_pending_now_known: prometheus::Histogram,
 	time_awaiting_approval_voting: prometheus::Histogram,
@@ -97,6 +101,24 @@ impl Metrics {
 			metrics.aggression_l2_messages_total.inc();
 		}
 	}
+
+	pub(crate) fn on_aggression_escalated(&self, level: &str) {
+		if let Some(metrics) = &self.0 {
+			metrics.aggression_escalations_total.with_label_values(&[level]).inc();
+		}
+	}
+
+	pub(crate) fn on_assignment_propagation_latency(&self, latency: Duration) {
+		if let Some(metrics) = &self.0 {
+			metrics.assignment_propagation_latency.observe(latency.as_secs_f64());
+		}
+	}
+
+	pub(crate) fn on_approval_latency(&self, latency: Duration) {
+		if let Some(metrics) = &self.0 {
+			metrics.approval_latency.observe(latency.as_secs_f64());
+		}
+	}
 }
 
 impl MetricsTrait for Metrics {
@@ -140,6 +162,36 @@ impl MetricsTrait for Metrics {
 				)?,
 				registry,
 			)?,
+			aggression_escalations_total: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_approval_distribution_aggression_escalations_total",
+						"Number of blocks escalated to a higher aggression level because of no-shows.",
+					),
+					&["level"],
+				)?,
+				registry,
+			)?,
+			assignment_propagation_latency: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_approval_distribution_assignment_propagation_latency",
+						"Time between learning about a block and importing each of its assignments.",
+					)
+					.buckets(vec![0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 6.0, 8.0, 12.0, 18.0, 24.0, 36.0]),
+				)?,
+				registry,
+			)?,
+			approval_latency: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_approval_distribution_approval_latency",
+						"Time between importing an assignment and importing the matching approval.",
+					)
+					.buckets(vec![0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 6.0, 8.0, 12.0, 18.0, 24.0, 36.0]),
+				)?,
+				registry,
+			)?,
 			time_unify_with_peer: prometheus::register(
 				prometheus::Histogram::with_opts(
 					prometheus::HistogramOpts::new(
@@ -88,6 +88,8 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 
 const TIMEOUT: Duration = Duration::from_millis(200);
 const REPUTATION_CHANGE_TEST_INTERVAL: Duration = Duration::from_millis(1);
+// Two relay chain slots of 6 seconds.
+const NO_SHOW_DURATION: Duration = Duration::from_secs(12);
 
 async fn overseer_send(overseer: &mut VirtualOverseer, msg: ApprovalDistributionMessage) {
 	gum::trace!(msg = ?msg, "Sending message");
@@ -402,6 +404,7 @@ fn try_import_the_same_assignment() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -486,6 +489,7 @@ fn try_import_the_same_assignment_v2() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -575,6 +579,7 @@ fn delay_reputation_change() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -638,6 +643,7 @@ fn spam_attack_results_in_negative_reputation_change() {
 			candidates: vec![Default::default(); candidates_count],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -721,6 +727,7 @@ fn peer_sending_us_the_same_we_just_sent_them_is_ok() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -802,6 +809,7 @@ fn import_approval_happy_path() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -907,6 +915,7 @@ fn import_approval_bad() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -985,6 +994,7 @@ fn update_our_view() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let meta_b = BlockApprovalMeta {
 			hash: hash_b,
@@ -993,6 +1003,7 @@ fn update_our_view() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let meta_c = BlockApprovalMeta {
 			hash: hash_c,
@@ -1001,6 +1012,7 @@ fn update_our_view() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta_a, meta_b, meta_c]);
@@ -1061,6 +1073,7 @@ fn update_peer_view() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let meta_b = BlockApprovalMeta {
 			hash: hash_b,
@@ -1069,6 +1082,7 @@ fn update_peer_view() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let meta_c = BlockApprovalMeta {
 			hash: hash_c,
@@ -1077,6 +1091,7 @@ fn update_peer_view() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta_a, meta_b, meta_c]);
@@ -1223,6 +1238,7 @@ fn import_remotely_then_locally() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -1311,6 +1327,7 @@ fn sends_assignments_even_when_state_is_approved() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -1396,6 +1413,7 @@ fn sends_assignments_even_when_state_is_approved_v2() {
 			candidates: vec![Default::default(); 4],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -1507,6 +1525,7 @@ fn race_condition_in_local_vs_remote_view_update() {
 			candidates: vec![Default::default(); candidates_count],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		// This will send a peer view that is ahead of our view
@@ -1597,6 +1616,7 @@ fn propagates_locally_generated_assignment_to_both_dimensions() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -1700,6 +1720,7 @@ fn propagates_assignments_along_unshared_dimension() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -1848,6 +1869,7 @@ fn propagates_to_required_after_connect() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -1976,6 +1998,7 @@ fn sends_to_more_peers_after_getting_topology() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -2131,6 +2154,7 @@ fn originator_aggression_l1() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -2207,6 +2231,7 @@ fn originator_aggression_l1() {
 					candidates: vec![],
 					slot: (level as u64).into(),
 					session: 1,
+					no_show_duration: NO_SHOW_DURATION,
 				};
 
 				let msg = ApprovalDistributionMessage::ApprovalCheckingLagUpdate(level + 1);
@@ -2265,6 +2290,152 @@ fn originator_aggression_l1() {
 	});
 }
 
+#[test]
+fn no_show_aggression_level_thresholds() {
+	// no-show based aggression is disabled by default.
+	assert_eq!(AggressionConfig::default().no_show_aggression_level(8, 8), AggressionLevel::L0);
+
+	let config = AggressionConfig {
+		min_no_show_samples: 4,
+		l1_no_show_percent: Some(25),
+		l2_no_show_percent: Some(50),
+		..Default::default()
+	};
+
+	// Not enough samples.
+	assert_eq!(config.no_show_aggression_level(0, 0), AggressionLevel::L0);
+	assert_eq!(config.no_show_aggression_level(3, 3), AggressionLevel::L0);
+
+	assert_eq!(config.no_show_aggression_level(10, 2), AggressionLevel::L0);
+	assert_eq!(config.no_show_aggression_level(8, 2), AggressionLevel::L1);
+	assert_eq!(config.no_show_aggression_level(8, 3), AggressionLevel::L1);
+	assert_eq!(config.no_show_aggression_level(8, 4), AggressionLevel::L2);
+	assert_eq!(config.no_show_aggression_level(8, 8), AggressionLevel::L2);
+
+	let config = AggressionConfig { l2_no_show_percent: None, ..config };
+	assert_eq!(config.no_show_aggression_level(8, 8), AggressionLevel::L1);
+
+	let config = AggressionConfig { l1_no_show_percent: None, ..config };
+	assert_eq!(config.no_show_aggression_level(8, 8), AggressionLevel::L0);
+}
+
+// test no-show driven aggression L1 on the originator, without any approval checking lag
+#[test]
+fn originator_no_show_aggression_l1() {
+	let parent_hash = Hash::repeat_byte(0xFF);
+	let hash = Hash::repeat_byte(0xAA);
+
+	let peers = make_peers_and_authority_ids(100);
+
+	let mut state = state_without_reputation_delay();
+	state.aggression_config = AggressionConfig {
+		l1_threshold: None,
+		l2_threshold: None,
+		resend_unfinalized_period: None,
+		min_no_show_samples: 1,
+		l1_no_show_percent: Some(50),
+		l2_no_show_percent: None,
+	};
+
+	let _ = test_harness(state, |mut virtual_overseer| async move {
+		let overseer = &mut virtual_overseer;
+
+		for (peer, _) in &peers {
+			setup_peer_with_view(overseer, peer, view![hash], ValidationVersion::V1).await;
+		}
+
+		// new block `hash` with 1 candidate, whose assignments are no-shows as soon as they are
+		// not approved.
+		let meta = BlockApprovalMeta {
+			hash,
+			parent_hash,
+			number: 1,
+			candidates: vec![Default::default(); 1],
+			slot: 1.into(),
+			session: 1,
+			no_show_duration: Duration::ZERO,
+		};
+
+		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
+		overseer_send(overseer, msg).await;
+
+		let validator_index = ValidatorIndex(0);
+		let candidate_index = 0u32;
+
+		// import an assignment locally, but never approve.
+		let cert = fake_assignment_cert(hash, validator_index);
+
+		setup_gossip_topology(
+			overseer,
+			make_gossip_topology(1, &peers, &[0, 10, 20, 30], &[50, 51, 52, 53]),
+		)
+		.await;
+
+		overseer_send(
+			overseer,
+			ApprovalDistributionMessage::DistributeAssignment(
+				cert.clone().into(),
+				candidate_index.into(),
+			),
+		)
+		.await;
+
+		let assignments = vec![(cert.clone(), candidate_index)];
+
+		let prev_sent_indices = assert_matches!(
+			overseer_recv(overseer).await,
+			AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::SendValidationMessage(
+				sent_peers,
+				Versioned::V1(protocol_v1::ValidationProtocol::ApprovalDistribution(
+					protocol_v1::ApprovalDistributionMessage::Assignments(_)
+				))
+			)) => {
+				sent_peers.into_iter()
+					.filter_map(|sp| peers.iter().position(|p| &p.0 == &sp))
+					.collect::<Vec<_>>()
+			}
+		);
+
+		// A new block re-evaluates the no-shows. The only assignment is past the no-show duration
+		// of its block without an approval, so the block is escalated.
+		let child_hash = BlakeTwo256::hash_of(&(hash, 2u32));
+		let meta = BlockApprovalMeta {
+			hash: child_hash,
+			parent_hash: hash,
+			number: 2,
+			candidates: vec![],
+			slot: 2.into(),
+			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
+		};
+		overseer_send(overseer, ApprovalDistributionMessage::NewBlocks(vec![meta])).await;
+
+		let unsent_indices =
+			(0..peers.len()).filter(|i| !prev_sent_indices.contains(&i)).collect::<Vec<_>>();
+
+		for _ in 0..unsent_indices.len() {
+			assert_matches!(
+				overseer_recv(overseer).await,
+				AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::SendValidationMessage(
+					sent_peers,
+					Versioned::V1(protocol_v1::ValidationProtocol::ApprovalDistribution(
+						protocol_v1::ApprovalDistributionMessage::Assignments(sent_assignments)
+					))
+				)) => {
+					assert_eq!(sent_peers.len(), 1);
+					assert_eq!(sent_assignments, assignments);
+
+					assert!(unsent_indices.iter()
+						.any(|i| &peers[*i].0 == &sent_peers[0]));
+				}
+			);
+		}
+
+		assert!(overseer.recv().timeout(TIMEOUT).await.is_none(), "no message should be sent");
+		virtual_overseer
+	});
+}
+
 // test aggression L1
 #[test]
 fn non_originator_aggression_l1() {
@@ -2293,6 +2464,7 @@ fn non_originator_aggression_l1() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -2353,6 +2525,7 @@ fn non_originator_aggression_l1() {
 					candidates: vec![],
 					slot: (level as u64).into(),
 					session: 1,
+					no_show_duration: NO_SHOW_DURATION,
 				};
 
 				let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -2398,6 +2571,7 @@ fn non_originator_aggression_l2() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -2462,6 +2636,7 @@ fn non_originator_aggression_l2() {
 					candidates: vec![],
 					slot: (level as u64).into(),
 					session: 1,
+					no_show_duration: NO_SHOW_DURATION,
 				};
 
 				let msg = ApprovalDistributionMessage::ApprovalCheckingLagUpdate(level + 1);
@@ -2491,6 +2666,7 @@ fn non_originator_aggression_l2() {
 					candidates: vec![],
 					slot: (level as u64).into(),
 					session: 1,
+					no_show_duration: NO_SHOW_DURATION,
 				};
 
 				let msg = ApprovalDistributionMessage::ApprovalCheckingLagUpdate(
@@ -2570,6 +2746,7 @@ fn resends_messages_periodically() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
@@ -2638,6 +2815,7 @@ fn resends_messages_periodically() {
 						candidates: vec![],
 						slot: (level as u64).into(),
 						session: 1,
+						no_show_duration: NO_SHOW_DURATION,
 					};
 
 					let msg = ApprovalDistributionMessage::ApprovalCheckingLagUpdate(2);
@@ -2703,6 +2881,7 @@ fn import_versioned_approval() {
 			candidates: vec![Default::default(); 1],
 			slot: 1.into(),
 			session: 1,
+			no_show_duration: NO_SHOW_DURATION,
 		};
 		let msg = ApprovalDistributionMessage::NewBlocks(vec![meta]);
 		overseer_send(overseer, msg).await;
@@ -31,6 +31,18 @@ pub const FAILED: &'static str = "failed";
 /// Label for chunks/PoVs that could not be served, because they were not available.
 pub const NOT_FOUND: &'static str = "not-found";
 
+/// Label for chunk requests sent to the validator we tried first.
+pub const PREFERRED: &'static str = "preferred";
+
+/// Label for chunk requests sent to further validators, after the preferred one failed.
+pub const FALLBACK: &'static str = "fallback";
+
+/// Label for systematic chunks.
+pub const SYSTEMATIC: &'static str = "systematic";
+
+/// Label for regular (non-systematic) chunks.
+pub const REGULAR: &'static str = "regular";
+
 /// Availability Distribution metrics.
 #[derive(Clone, Default)]
 pub struct Metrics(Option<MetricsInner>);
@@ -56,6 +68,12 @@ struct MetricsInner {
 	/// Number of times our first set of validators did not provide the needed chunk and we had to
 	/// query further validators.
 	retries: Counter<U64>,
+
+	/// Outcome of individual chunk requests, by source and by kind of chunk.
+	///
+	/// Allows for computing success rates of the validators we try first, compared to the ones we
+	/// fall back to.
+	chunk_requests: CounterVec<U64>,
 }
 
 impl Metrics {
@@ -98,6 +116,13 @@ impl Metrics {
 			metrics.retries.inc()
 		}
 	}
+
+	/// Increment counter on the outcome of a single chunk request.
+	pub fn on_chunk_request(&self, source: &'static str, chunk: &'static str, label: &'static str) {
+		if let Some(metrics) = &self.0 {
+			metrics.chunk_requests.with_label_values(&[source, chunk, label]).inc()
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -150,6 +175,16 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			chunk_requests: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_chunk_requests_total",
+						"Total number of chunk requests sent by this validator, by source, kind of chunk and outcome.",
+					),
+					&["source", "chunk", "success"]
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
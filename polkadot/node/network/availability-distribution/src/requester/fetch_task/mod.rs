@@ -40,8 +40,8 @@ use polkadot_primitives::{
 
 use crate::{
 	error::{FatalError, Result},
-	metrics::{Metrics, FAILED, SUCCEEDED},
-	requester::session_cache::{BadValidators, SessionInfo},
+	metrics::{Metrics, FAILED, FALLBACK, PREFERRED, REGULAR, SUCCEEDED, SYSTEMATIC},
+	requester::session_cache::{BadValidators, GoodValidator, SessionInfo},
 	LOG_TARGET,
 };
 
@@ -88,9 +88,10 @@ pub enum FromFetchTask {
 
 	/// Concluded with result.
 	///
-	/// In case of `None` everything was fine, in case of `Some`, some validators in the group
-	/// did not serve us our chunk as expected.
-	Concluded(Option<BadValidators>),
+	/// Contains the validator which served us our chunk. In case of `None` everything else was
+	/// fine as well, in case of `Some`, some validators in the group did not serve us our chunk as
+	/// expected.
+	Concluded(GoodValidator, Option<BadValidators>),
 
 	/// We were not able to fetch the desired chunk for the given `CandidateHash`.
 	Failed(CandidateHash),
@@ -114,6 +115,9 @@ struct RunningTask {
 	/// The request to send.
 	request: ChunkFetchingRequest,
 
+	/// Whether the requested chunk is a systematic one.
+	systematic: bool,
+
 	/// Root hash, for verifying the chunks validity.
 	erasure_root: Hash,
 
@@ -169,6 +173,7 @@ impl FetchTaskConfig {
 				candidate_hash: core.candidate_hash,
 				index: session_info.our_index,
 			},
+			systematic: session_info.our_chunk_is_systematic,
 			erasure_root: core.candidate_descriptor.erasure_root,
 			relay_parent: core.candidate_descriptor.relay_parent,
 			metrics,
@@ -257,17 +262,21 @@ impl RunningTask {
 	/// Try validators in backing group in order.
 	async fn run_inner(mut self) {
 		let mut bad_validators = Vec::new();
-		let mut succeeded = false;
+		let mut served_by = None;
 		let mut count: u32 = 0;
 		let mut span = self.span.child("run-fetch-chunk-task").with_relay_parent(self.relay_parent);
 		let mut network_error_freq = gum::Freq::new();
 		let mut canceled_freq = gum::Freq::new();
+		let chunk_kind = if self.systematic { SYSTEMATIC } else { REGULAR };
 		// Try validators in reverse order:
 		while let Some(validator) = self.group.pop() {
 			// Report retries:
-			if count > 0 {
+			let source = if count > 0 {
 				self.metrics.on_retry();
-			}
+				FALLBACK
+			} else {
+				PREFERRED
+			};
 			count += 1;
 			let _chunk_fetch_span = span
 				.child("fetch-chunk-request")
@@ -288,6 +297,7 @@ impl RunningTask {
 					return
 				},
 				Err(TaskError::PeerError) => {
+					self.metrics.on_chunk_request(source, chunk_kind, FAILED);
 					bad_validators.push(validator);
 					continue
 				},
@@ -311,6 +321,7 @@ impl RunningTask {
 						candidate_hash = ?self.request.candidate_hash,
 						"Validator did not have our chunk"
 					);
+					self.metrics.on_chunk_request(source, chunk_kind, FAILED);
 					bad_validators.push(validator);
 					continue
 				},
@@ -325,19 +336,21 @@ impl RunningTask {
 
 			// Data genuine?
 			if !self.validate_chunk(&validator, &chunk) {
+				self.metrics.on_chunk_request(source, chunk_kind, FAILED);
 				bad_validators.push(validator);
 				continue
 			}
+			self.metrics.on_chunk_request(source, chunk_kind, SUCCEEDED);
 
 			// Ok, let's store it and be happy:
 			self.store_chunk(chunk).await;
-			succeeded = true;
+			served_by = Some(validator);
 			break
 		}
 		span.add_int_tag("tries", count as _);
-		if succeeded {
+		if let Some(served_by) = served_by {
 			self.metrics.on_fetch(SUCCEEDED);
-			self.conclude(bad_validators).await;
+			self.conclude(served_by, bad_validators).await;
 		} else {
 			self.metrics.on_fetch(FAILED);
 			self.conclude_fail().await
@@ -474,7 +487,16 @@ impl RunningTask {
 	}
 
 	/// Tell subsystem we are done.
-	async fn conclude(&mut self, bad_validators: Vec<AuthorityDiscoveryId>) {
+	async fn conclude(
+		&mut self,
+		served_by: AuthorityDiscoveryId,
+		bad_validators: Vec<AuthorityDiscoveryId>,
+	) {
+		let good = GoodValidator {
+			session_index: self.session_index,
+			group_index: self.group_index,
+			validator: served_by,
+		};
 		let payload = if bad_validators.is_empty() {
 			None
 		} else {
@@ -484,7 +506,7 @@ impl RunningTask {
 				bad_validators,
 			})
 		};
-		if let Err(err) = self.sender.send(FromFetchTask::Concluded(payload)).await {
+		if let Err(err) = self.sender.send(FromFetchTask::Concluded(good, payload)).await {
 			gum::warn!(
 				target: LOG_TARGET,
 				err= ?err,
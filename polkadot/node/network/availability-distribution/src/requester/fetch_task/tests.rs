@@ -214,7 +214,15 @@ impl TestRun {
 						break,
 				);
 				match msg {
-					FromFetchTask::Concluded(_) => break,
+					FromFetchTask::Concluded(good, _) => {
+						// The validator reported as good must have served a valid chunk:
+						assert_matches::assert_matches!(
+							self.chunk_responses.get(&Recipient::Authority(good.validator)),
+							Some(ChunkFetchingResponse::Chunk(resp))
+								if self.valid_chunks.contains(&resp.chunk)
+						);
+						break
+					},
 					FromFetchTask::Failed(_) => break,
 					FromFetchTask::Message(msg) => end_ok = self.handle_message(msg).await,
 				}
@@ -287,6 +295,7 @@ fn get_test_running_task() -> (RunningTask, mpsc::Receiver<FromFetchTask>) {
 				candidate_hash: CandidateHash([43u8; 32].into()),
 				index: ValidatorIndex(0),
 			},
+			systematic: true,
 			erasure_root: Hash::repeat_byte(99),
 			relay_parent: Hash::repeat_byte(71),
 			sender: tx,
@@ -260,11 +260,15 @@ impl Stream for Requester {
 		loop {
 			match Pin::new(&mut self.rx).poll_next(ctx) {
 				Poll::Ready(Some(FromFetchTask::Message(m))) => return Poll::Ready(Some(m)),
-				Poll::Ready(Some(FromFetchTask::Concluded(Some(bad_boys)))) => {
-					self.session_cache.report_bad_log(bad_boys);
+				Poll::Ready(Some(FromFetchTask::Concluded(good, bad_boys))) => {
+					// Both reports update the scores of the validators and reorder the group, so
+					// the most reliable validators are tried first.
+					if let Some(bad_boys) = bad_boys {
+						self.session_cache.report_bad_log(bad_boys);
+					}
+					self.session_cache.report_good_log(good);
 					continue
 				},
-				Poll::Ready(Some(FromFetchTask::Concluded(None))) => continue,
 				Poll::Ready(Some(FromFetchTask::Failed(candidate_hash))) => {
 					// Make sure we retry on next block still pending availability.
 					self.fetches.remove(&candidate_hash);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use rand::{seq::SliceRandom, thread_rng};
 use schnellru::{ByLength, LruMap};
@@ -23,6 +23,8 @@ use polkadot_node_subsystem::overseer;
 use polkadot_node_subsystem_util::runtime::RuntimeInfo;
 use polkadot_primitives::{AuthorityDiscoveryId, GroupIndex, Hash, SessionIndex, ValidatorIndex};
 
+use polkadot_erasure_coding::systematic_recovery_threshold;
+
 use crate::{
 	error::{Error, Result},
 	LOG_TARGET,
@@ -62,6 +64,20 @@ pub struct SessionInfo {
 	///
 	/// `None`, if we are not in fact part of any group.
 	pub our_group: Option<GroupIndex>,
+
+	/// Whether our chunk is a systematic one.
+	///
+	/// Systematic chunks are the first `systematic_recovery_threshold` chunks, which together
+	/// contain the original data verbatim. Availability recovery fetches them first, as no
+	/// decoding is necessary, so it is important that they are present in the network as early as
+	/// possible.
+	pub our_chunk_is_systematic: bool,
+
+	/// How reliably validators served us chunks in this session.
+	///
+	/// Served chunks increase the score of a validator, failed requests decrease it. The groups
+	/// in `validator_groups` are kept sorted by this score.
+	validator_scores: HashMap<AuthorityDiscoveryId, i32>,
 }
 
 /// Report of bad validators.
@@ -77,6 +93,19 @@ pub struct BadValidators {
 	pub bad_validators: Vec<AuthorityDiscoveryId>,
 }
 
+/// Report of the validator which successfully served us our chunk.
+///
+/// Fetching tasks will report back the validator that delivered, so we can try it first on the
+/// next fetch for a candidate of the same group.
+pub struct GoodValidator {
+	/// The session index that was used.
+	pub session_index: SessionIndex,
+	/// The group the validator belongs to.
+	pub group_index: GroupIndex,
+	/// The validator that served the chunk.
+	pub validator: AuthorityDiscoveryId,
+}
+
 #[overseer::contextbounds(AvailabilityDistribution, prefix = self::overseer)]
 impl SessionCache {
 	/// Create a new `SessionCache`.
@@ -139,30 +168,49 @@ impl SessionCache {
 	/// Make sure we try unresponsive or misbehaving validators last.
 	///
 	/// We assume validators in a group are tried in reverse order, so the reported bad validators
-	/// will be put at the beginning of the group.
+	/// will be moved towards the beginning of the group.
 	pub fn report_bad(&mut self, report: BadValidators) -> Result<()> {
-		let available_sessions = self.session_info_cache.iter().map(|(k, _)| *k).collect();
-		let session = self.session_info_cache.get(&report.session_index).ok_or(
-			Error::NoSuchCachedSession {
-				available_sessions,
-				missing_session: report.session_index,
-			},
-		)?;
-		let group = session.validator_groups.get_mut(report.group_index.0 as usize).expect(
-			"A bad validator report must contain a valid group for the reported session. qed.",
-		);
+		let session = self.get_session_mut(report.session_index)?;
 		let bad_set = report.bad_validators.iter().collect::<HashSet<_>>();
+		for validator in bad_set {
+			let score = session.validator_scores.entry(validator.clone()).or_default();
+			*score = score.saturating_sub(1);
+		}
+		session.sort_group(report.group_index);
+		Ok(())
+	}
 
-		// Get rid of bad boys:
-		group.retain(|v| !bad_set.contains(v));
+	/// Variant of `report_good` that never fails, but just logs errors.
+	pub fn report_good_log(&mut self, report: GoodValidator) {
+		if let Err(err) = self.report_good(report) {
+			gum::warn!(
+				target: LOG_TARGET,
+				err = ?err,
+				"Reporting good validator failed with error"
+			);
+		}
+	}
 
-		// We are trying validators in reverse order, so bad ones should be first:
-		let mut new_group = report.bad_validators;
-		new_group.append(group);
-		*group = new_group;
+	/// Make sure we try validators which served us well first.
+	///
+	/// We assume validators in a group are tried in reverse order, so the reported good validator
+	/// will be moved towards the end of the group.
+	pub fn report_good(&mut self, report: GoodValidator) -> Result<()> {
+		let session = self.get_session_mut(report.session_index)?;
+		let score = session.validator_scores.entry(report.validator).or_default();
+		*score = score.saturating_add(1);
+		session.sort_group(report.group_index);
 		Ok(())
 	}
 
+	fn get_session_mut(&mut self, session_index: SessionIndex) -> Result<&mut SessionInfo> {
+		let available_sessions = self.session_info_cache.iter().map(|(k, _)| *k).collect();
+		self.session_info_cache.get(&session_index).ok_or(Error::NoSuchCachedSession {
+			available_sessions,
+			missing_session: session_index,
+		})
+	}
+
 	/// Query needed information from runtime.
 	///
 	/// We need to pass in the relay parent for our call to `request_session_info`. We should
@@ -208,9 +256,98 @@ impl SessionCache {
 				})
 				.collect();
 
-			let info = SessionInfo { validator_groups, our_index, session_index, our_group };
+			let our_chunk_is_systematic = systematic_recovery_threshold(discovery_keys.len())
+				.map_or(false, |threshold| (our_index.0 as usize) < threshold);
+
+			let info = SessionInfo {
+				validator_groups,
+				our_index,
+				session_index,
+				our_group,
+				our_chunk_is_systematic,
+				validator_scores: HashMap::new(),
+			};
 			return Ok(Some(info))
 		}
 		return Ok(None)
 	}
 }
+
+impl SessionInfo {
+	/// Sort the validators of a group by their score.
+	///
+	/// Validators are tried in reverse order, so the most reliable ones go last. The sort is
+	/// stable, so validators of equal score keep their randomized order.
+	fn sort_group(&mut self, group_index: GroupIndex) {
+		let scores = &self.validator_scores;
+		let group = self
+			.validator_groups
+			.get_mut(group_index.0 as usize)
+			.expect("A validator report must contain a valid group for the reported session. qed.");
+		group.sort_by_key(|v| scores.get(v).copied().unwrap_or_default());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_keyring::Sr25519Keyring;
+
+	fn session_cache(group: Vec<AuthorityDiscoveryId>) -> SessionCache {
+		let mut cache = SessionCache::new();
+		cache.session_info_cache.insert(
+			1,
+			SessionInfo {
+				session_index: 1,
+				validator_groups: vec![group],
+				our_index: ValidatorIndex(0),
+				our_group: None,
+				our_chunk_is_systematic: true,
+				validator_scores: HashMap::new(),
+			},
+		);
+		cache
+	}
+
+	fn group(cache: &mut SessionCache) -> Vec<AuthorityDiscoveryId> {
+		cache.session_info_cache.get(&1).unwrap().validator_groups[0].clone()
+	}
+
+	#[test]
+	fn validators_are_ordered_by_reliability() {
+		let [a, b, c, d] = [
+			Sr25519Keyring::Alice,
+			Sr25519Keyring::Bob,
+			Sr25519Keyring::Charlie,
+			Sr25519Keyring::Dave,
+		]
+		.map(|k| AuthorityDiscoveryId::from(k.public()));
+		let mut cache = session_cache(vec![a.clone(), b.clone(), c.clone(), d.clone()]);
+		let good = |validator: &AuthorityDiscoveryId| GoodValidator {
+			session_index: 1,
+			group_index: GroupIndex(0),
+			validator: validator.clone(),
+		};
+
+		// Validators are tried from the end, so `a` is tried first now.
+		cache.report_good(good(&a)).unwrap();
+		assert_eq!(group(&mut cache), vec![b.clone(), c.clone(), d.clone(), a.clone()]);
+
+		// `b` serving us does not demote `a`, which served us before.
+		cache.report_good(good(&b)).unwrap();
+		cache.report_good(good(&b)).unwrap();
+		assert_eq!(group(&mut cache), vec![c.clone(), d.clone(), a.clone(), b.clone()]);
+
+		cache
+			.report_bad(BadValidators {
+				session_index: 1,
+				group_index: GroupIndex(0),
+				bad_validators: vec![d.clone(), b.clone()],
+			})
+			.unwrap();
+		assert_eq!(group(&mut cache), vec![d, c, a, b]);
+
+		// Reports for unknown sessions fail.
+		assert!(cache.report_good(GoodValidator { session_index: 2, ..good(&a) }).is_err());
+	}
+}
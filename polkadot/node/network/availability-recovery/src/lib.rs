@@ -35,11 +35,15 @@ use futures::{
 	task::{Context, Poll},
 };
 use schnellru::{ByLength, LruMap};
-use task::{FetchChunks, FetchChunksParams, FetchFull, FetchFullParams};
+use task::{
+	FetchChunks, FetchChunksParams, FetchFull, FetchFullParams, FetchSystematicChunks,
+	FetchSystematicChunksParams,
+};
 
 use fatality::Nested;
 use polkadot_erasure_coding::{
-	branch_hash, branches, obtain_chunks_v1, recovery_threshold, Error as ErasureEncodingError,
+	branch_hash, branches, obtain_chunks_v1, recovery_threshold, systematic_recovery_threshold,
+	Error as ErasureEncodingError,
 };
 use task::{RecoveryParams, RecoveryStrategy, RecoveryTask};
 
@@ -90,6 +94,11 @@ pub enum RecoveryStrategyKind {
 	BackersFirstIfSizeLower(usize),
 	/// We always recover using validator chunks.
 	ChunksAlways,
+	/// We try the backing group first if PoV size is lower than specified, then the systematic
+	/// chunks, then fallback to regular validator chunks.
+	BackersFirstIfSizeLowerThenSystematicChunks(usize),
+	/// We always try the systematic chunks first, then fallback to regular validator chunks.
+	SystematicChunks,
 }
 
 /// The Availability Recovery Subsystem.
@@ -126,6 +135,13 @@ pub enum ErasureTask {
 		HashMap<ValidatorIndex, ErasureChunk>,
 		oneshot::Sender<Result<AvailableData, ErasureEncodingError>>,
 	),
+	/// Reconstructs `AvailableData` from the systematic chunks, in order of their index, given
+	/// `n_validators`.
+	ReconstructFromSystematic(
+		usize,
+		Vec<Vec<u8>>,
+		oneshot::Sender<Result<AvailableData, ErasureEncodingError>>,
+	),
 	/// Re-encode `AvailableData` into erasure chunks in order to verify the provided root hash of
 	/// the Merkle tree.
 	Reencode(usize, Hash, AvailableData, oneshot::Sender<Option<AvailableData>>),
@@ -442,14 +458,16 @@ async fn handle_recover<Context>(
 		Some(session_info) => {
 			let mut recovery_strategies: VecDeque<
 				Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>,
-			> = VecDeque::with_capacity(2);
+			> = VecDeque::with_capacity(3);
 
 			if let Some(backing_group) = backing_group {
 				if let Some(backing_validators) = session_info.validator_groups.get(backing_group) {
 					let mut small_pov_size = true;
 
-					if let RecoveryStrategyKind::BackersFirstIfSizeLower(small_pov_limit) =
-						recovery_strategy_kind
+					if let RecoveryStrategyKind::BackersFirstIfSizeLower(small_pov_limit) |
+					RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(
+						small_pov_limit,
+					) = recovery_strategy_kind
 					{
 						// Get our own chunk size to get an estimate of the PoV size.
 						let chunk_size: Result<Option<usize>, error::Error> =
@@ -476,17 +494,34 @@ async fn handle_recover<Context>(
 
 					match (&recovery_strategy_kind, small_pov_size) {
 						(RecoveryStrategyKind::BackersFirstAlways, _) |
-						(RecoveryStrategyKind::BackersFirstIfSizeLower(_), true) => recovery_strategies.push_back(
-							Box::new(FetchFull::new(FetchFullParams {
+						(RecoveryStrategyKind::BackersFirstIfSizeLower(_), true) |
+						(
+							RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_),
+							true,
+						) => recovery_strategies.push_back(Box::new(FetchFull::new(
+							FetchFullParams {
 								validators: backing_validators.to_vec(),
 								erasure_task_tx: erasure_task_tx.clone(),
-							})),
-						),
+							},
+						))),
 						_ => {},
 					};
 				}
 			}
 
+			if matches!(
+				recovery_strategy_kind,
+				RecoveryStrategyKind::SystematicChunks |
+					RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(_)
+			) {
+				recovery_strategies.push_back(Box::new(FetchSystematicChunks::new(
+					FetchSystematicChunksParams {
+						threshold: systematic_recovery_threshold(session_info.validators.len())?,
+						erasure_task_tx: erasure_task_tx.clone(),
+					},
+				)));
+			}
+
 			recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
 				n_validators: session_info.validators.len(),
 				erasure_task_tx,
@@ -603,6 +638,37 @@ impl AvailabilityRecoverySubsystem {
 		}
 	}
 
+	/// Create a new instance of `AvailabilityRecoverySubsystem` which requests the systematic
+	/// chunks first, falling back to regular chunks.
+	pub fn with_systematic_chunks(
+		req_receiver: IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
+		metrics: Metrics,
+	) -> Self {
+		Self {
+			recovery_strategy_kind: RecoveryStrategyKind::SystematicChunks,
+			bypass_availability_store: false,
+			post_recovery_check: PostRecoveryCheck::Reencode,
+			req_receiver,
+			metrics,
+		}
+	}
+
+	/// Create a new instance of `AvailabilityRecoverySubsystem` which requests the systematic
+	/// chunks if PoV is above a threshold, falling back to regular chunks.
+	pub fn with_systematic_chunks_if_pov_large(
+		req_receiver: IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
+		metrics: Metrics,
+	) -> Self {
+		Self {
+			recovery_strategy_kind:
+				RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(SMALL_POV_LIMIT),
+			bypass_availability_store: false,
+			post_recovery_check: PostRecoveryCheck::Reencode,
+			req_receiver,
+			metrics,
+		}
+	}
+
 	async fn run<Context>(self, mut ctx: Context) -> SubsystemResult<()> {
 		let mut state = State::default();
 		let Self {
@@ -828,6 +894,12 @@ async fn erasure_task_thread(
 					chunks.values().map(|c| (&c.chunk[..], c.index.0 as usize)),
 				));
 			},
+			Some(ErasureTask::ReconstructFromSystematic(n_validators, chunks, sender)) => {
+				let _ = sender.send(polkadot_erasure_coding::reconstruct_from_systematic_v1(
+					n_validators,
+					chunks,
+				));
+			},
 			Some(ErasureTask::Reencode(n_validators, root, available_data, sender)) => {
 				let metrics = metrics.clone();
 
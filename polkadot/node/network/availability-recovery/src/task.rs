@@ -123,6 +123,14 @@ impl State {
 		self.received_chunks.len()
 	}
 
+	/// Number of systematic chunks received so far, given the number of systematic chunks.
+	fn systematic_chunk_count(&self, systematic_threshold: usize) -> usize {
+		self.received_chunks
+			.keys()
+			.filter(|i| (i.0 as usize) < systematic_threshold)
+			.count()
+	}
+
 	/// Retrieve the local chunks held in the av-store (either 0 or 1).
 	async fn populate_from_av_store<Sender: overseer::AvailabilityRecoverySenderTrait>(
 		&mut self,
@@ -344,6 +352,51 @@ impl State {
 	}
 }
 
+/// Perform the `PostRecoveryCheck` of `common_params` on data recovered from chunks.
+///
+/// Returns `None` if the data does not pass the check.
+async fn check_recovered_data(
+	erasure_task_tx: &mut futures::channel::mpsc::Sender<ErasureTask>,
+	common_params: &RecoveryParams,
+	data: AvailableData,
+) -> Result<Option<AvailableData>, RecoveryError> {
+	Ok(match common_params.post_recovery_check {
+		PostRecoveryCheck::Reencode => {
+			// Send request to re-encode the chunks and check merkle root.
+			let (reencode_tx, reencode_rx) = oneshot::channel();
+			erasure_task_tx
+				.send(ErasureTask::Reencode(
+					common_params.n_validators,
+					common_params.erasure_root,
+					data,
+					reencode_tx,
+				))
+				.await
+				.map_err(|_| RecoveryError::ChannelClosed)?;
+
+			reencode_rx.await.map_err(|_| RecoveryError::ChannelClosed)?.or_else(|| {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					"Data recovery error - root mismatch",
+				);
+				None
+			})
+		},
+		PostRecoveryCheck::PovHash =>
+			(data.pov.hash() == common_params.pov_hash).then_some(data).or_else(|| {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					pov_hash = ?common_params.pov_hash,
+					"Data recovery error - PoV hash mismatch",
+				);
+				None
+			}),
+	})
+}
+
 /// A stateful reconstruction of availability data in reference to
 /// a candidate hash.
 pub struct RecoveryTask<Sender: overseer::AvailabilityRecoverySenderTrait> {
@@ -662,41 +715,8 @@ impl FetchChunks {
 
 		match available_data_response {
 			Ok(data) => {
-				let maybe_data = match common_params.post_recovery_check {
-					PostRecoveryCheck::Reencode => {
-						// Send request to re-encode the chunks and check merkle root.
-						let (reencode_tx, reencode_rx) = oneshot::channel();
-						self.erasure_task_tx
-							.send(ErasureTask::Reencode(
-								common_params.n_validators,
-								common_params.erasure_root,
-								data,
-								reencode_tx,
-							))
-							.await
-							.map_err(|_| RecoveryError::ChannelClosed)?;
-
-						reencode_rx.await.map_err(|_| RecoveryError::ChannelClosed)?.or_else(|| {
-							gum::trace!(
-								target: LOG_TARGET,
-								candidate_hash = ?common_params.candidate_hash,
-								erasure_root = ?common_params.erasure_root,
-								"Data recovery error - root mismatch",
-							);
-							None
-						})
-					},
-					PostRecoveryCheck::PovHash =>
-						(data.pov.hash() == common_params.pov_hash).then_some(data).or_else(|| {
-							gum::trace!(
-								target: LOG_TARGET,
-								candidate_hash = ?common_params.candidate_hash,
-								pov_hash = ?common_params.pov_hash,
-								"Data recovery error - PoV hash mismatch",
-							);
-							None
-						}),
-				};
+				let maybe_data =
+					check_recovered_data(&mut self.erasure_task_tx, common_params, data).await?;
 
 				if let Some(data) = maybe_data {
 					gum::trace!(
@@ -826,6 +846,195 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 	}
 }
 
+/// `RecoveryStrategy` that requests the systematic chunks from the validators holding them, in
+/// parallel.
+///
+/// The systematic chunks contain the original data verbatim, so the data can be recovered from
+/// them without the expensive decoding. Each systematic chunk is held by a single validator though,
+/// so this strategy gives up as soon as one of them can not be fetched. The chunks received until
+/// then are kept for the strategies which follow.
+pub struct FetchSystematicChunks {
+	/// Number of systematic chunks, which are the ones of the lowest indices.
+	threshold: usize,
+	/// Collection of in-flight requests.
+	requesting_chunks: FuturesUndead<Result<Option<ErasureChunk>, (ValidatorIndex, RequestError)>>,
+	/// A random shuffling of the validators holding systematic chunks, which indicates the order
+	/// in which we request the chunk from them.
+	validators: VecDeque<ValidatorIndex>,
+	/// Channel to the erasure task handler.
+	erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
+}
+
+/// Parameters specific to the `FetchSystematicChunks` strategy.
+pub struct FetchSystematicChunksParams {
+	/// Number of systematic chunks.
+	pub threshold: usize,
+	/// Channel to the erasure task handler.
+	pub erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
+}
+
+impl FetchSystematicChunks {
+	/// Instantiate a new strategy.
+	pub fn new(params: FetchSystematicChunksParams) -> Self {
+		let mut shuffling: Vec<_> = (0..params.threshold)
+			.map(|i| ValidatorIndex(i.try_into().expect("number of validators must fit in a u32")))
+			.collect();
+		shuffling.shuffle(&mut rand::thread_rng());
+
+		Self {
+			threshold: params.threshold,
+			requesting_chunks: FuturesUndead::new(),
+			validators: shuffling.into(),
+			erasure_task_tx: params.erasure_task_tx,
+		}
+	}
+
+	async fn attempt_systematic_recovery(
+		&mut self,
+		state: &mut State,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		let recovery_duration = common_params.metrics.time_erasure_recovery();
+
+		// The systematic chunks in order of their index. Other chunks are left in place, should
+		// the recovery fail.
+		let chunks = (0..self.threshold)
+			.filter_map(|i| {
+				state.received_chunks.get(&ValidatorIndex(i as u32)).map(|c| c.chunk.clone())
+			})
+			.collect();
+
+		let (available_data_tx, available_data_rx) = oneshot::channel();
+		self.erasure_task_tx
+			.send(ErasureTask::ReconstructFromSystematic(
+				common_params.n_validators,
+				chunks,
+				available_data_tx,
+			))
+			.await
+			.map_err(|_| RecoveryError::ChannelClosed)?;
+
+		let available_data_response =
+			available_data_rx.await.map_err(|_| RecoveryError::ChannelClosed)?;
+
+		match available_data_response {
+			Ok(data) => {
+				let maybe_data =
+					check_recovered_data(&mut self.erasure_task_tx, common_params, data).await?;
+
+				if let Some(data) = maybe_data {
+					gum::trace!(
+						target: LOG_TARGET,
+						candidate_hash = ?common_params.candidate_hash,
+						erasure_root = ?common_params.erasure_root,
+						"Data recovery from systematic chunks complete",
+					);
+
+					Ok(data)
+				} else {
+					recovery_duration.map(|rd| rd.stop_and_discard());
+
+					Err(RecoveryError::Invalid)
+				}
+			},
+			Err(err) => {
+				recovery_duration.map(|rd| rd.stop_and_discard());
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					?err,
+					"Systematic data recovery error",
+				);
+
+				Err(RecoveryError::Invalid)
+			},
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
+	for FetchSystematicChunks
+{
+	fn display_name(&self) -> &'static str {
+		"Fetch systematic chunks"
+	}
+
+	async fn run(
+		&mut self,
+		state: &mut State,
+		sender: &mut Sender,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		// First query the store for any chunks we've got.
+		if !common_params.bypass_availability_store {
+			let local_chunk_indices = state.populate_from_av_store(common_params, sender).await;
+			self.validators.retain(|i| !local_chunk_indices.contains(i));
+		}
+
+		// No need to query the validators that have the chunks we already received.
+		self.validators.retain(|i| !state.received_chunks.contains_key(i));
+
+		let mut error_count = 0;
+		loop {
+			let systematic_chunk_count = state.systematic_chunk_count(self.threshold);
+			if systematic_chunk_count >= self.threshold {
+				return self.attempt_systematic_recovery(state, common_params).await
+			}
+
+			// Every systematic chunk is needed, so a single failure is enough to give up.
+			if error_count > 0 ||
+				is_unavailable(
+					systematic_chunk_count,
+					self.requesting_chunks.total_len(),
+					self.validators.len(),
+					self.threshold,
+				) {
+				gum::debug!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					erasure_root = ?common_params.erasure_root,
+					received = %systematic_chunk_count,
+					systematic_threshold = %self.threshold,
+					"Data recovery from systematic chunks is not possible",
+				);
+
+				return Err(RecoveryError::Unavailable)
+			}
+
+			let desired_requests_count =
+				std::cmp::min(N_PARALLEL, self.threshold - systematic_chunk_count);
+			state
+				.launch_parallel_chunk_requests(
+					common_params,
+					sender,
+					desired_requests_count,
+					&mut self.validators,
+					&mut self.requesting_chunks,
+				)
+				.await;
+
+			// Only systematic chunks are requested, so any other received chunk is already
+			// accounted for here.
+			let threshold = self.threshold;
+			let other_chunk_count = state.chunk_count() - systematic_chunk_count;
+			let (_, errors) = state
+				.wait_for_chunks(
+					common_params,
+					&mut self.validators,
+					&mut self.requesting_chunks,
+					|_, _, chunk_count, _, error_count| {
+						error_count > 0 || chunk_count - other_chunk_count >= threshold
+					},
+				)
+				.await;
+
+			error_count += errors;
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -16,7 +16,7 @@
 
 //! Primitives for tracking collations-related data.
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use futures::{future::BoxFuture, stream::FuturesUnordered};
 
@@ -77,12 +77,25 @@ pub struct WaitingCollationFetches {
 	/// If set to `true`, any new request will be queued.
 	pub collation_fetch_active: bool,
 	/// The collation fetches waiting to be fulfilled.
-	pub req_queue: VecDeque<VersionedCollationRequest>,
+	pub req_queue: VecDeque<QueuedCollationRequest>,
 	/// All peers that are waiting or actively uploading.
 	///
 	/// We will not accept multiple requests from the same peer, otherwise our DoS protection of
 	/// moving on to the next peer after `MAX_UNSHARED_UPLOAD_TIME` would be pointless.
 	pub waiting_peers: HashSet<(PeerId, CandidateHash)>,
+	/// The indices of the chunks peers are waiting for or actively uploading.
+	///
+	/// Peers fetching in chunks may keep several chunk requests in flight, but only a bounded
+	/// number of distinct chunks at once.
+	pub waiting_chunks: HashMap<(PeerId, CandidateHash), HashSet<u32>>,
+}
+
+/// A request waiting in the queue of a relay parent.
+pub enum QueuedCollationRequest {
+	/// A request for a full collation.
+	Full(VersionedCollationRequest),
+	/// A request for a chunk of the `PoV` of a collation.
+	Chunk(IncomingRequest<protocol_v2::CollationChunkFetchingRequest>),
 }
 
 /// Backwards-compatible wrapper for incoming collations requests.
@@ -152,6 +165,8 @@ pub struct CollationSendResult {
 	pub candidate_hash: CandidateHash,
 	/// Peer id.
 	pub peer_id: PeerId,
+	/// The index of the sent chunk, if only a chunk of the collation was sent.
+	pub chunk_index: Option<u32>,
 	/// Whether the max unshared timeout was hit.
 	pub timed_out: bool,
 }
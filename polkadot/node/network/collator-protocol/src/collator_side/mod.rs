@@ -31,7 +31,8 @@ use polkadot_node_network_protocol::{
 	peer_set::{CollationVersion, PeerSet},
 	request_response::{
 		incoming::{self, OutgoingResponse},
-		v1 as request_v1, v2 as request_v2, IncomingRequestReceiver,
+		v1 as request_v1, v2 as request_v2, IncomingRequest, IncomingRequestReceiver,
+		COLLATION_CHUNK_SIZE,
 	},
 	v1 as protocol_v1, v2 as protocol_v2, OurView, PeerId, UnifiedReputationChange as Rep,
 	Versioned, View,
@@ -72,7 +73,7 @@ mod validators_buffer;
 
 use collation::{
 	ActiveCollationFetches, Collation, CollationSendResult, CollationStatus,
-	VersionedCollationRequest, WaitingCollationFetches,
+	QueuedCollationRequest, VersionedCollationRequest, WaitingCollationFetches,
 };
 use validators_buffer::{
 	ResetInterestTimeout, ValidatorGroupsBuffer, RESET_INTEREST_TIMEOUT, VALIDATORS_BUFFER_CAPACITY,
@@ -93,6 +94,12 @@ const COST_APPARENT_FLOOD: Rep =
 /// For considerations on this value, see: https://github.com/paritytech/polkadot/issues/4386
 const MAX_UNSHARED_UPLOAD_TIME: Duration = Duration::from_millis(150);
 
+/// The maximum number of distinct chunks of a collation a peer may request at once.
+///
+/// Requests for further chunks are treated as a flood, like a second request for the full
+/// collation.
+const MAX_CHUNK_REQUESTS_PER_PEER: usize = 8;
+
 /// Ensure that collator updates its connection requests to validators
 /// this long after the most recent leaf.
 ///
@@ -135,6 +142,17 @@ struct ValidatorGroup {
 }
 
 impl ValidatorGroup {
+	/// Returns `true` if the given peer is a validator of this group.
+	fn contains_peer(
+		&self,
+		peer_ids: &HashMap<PeerId, HashSet<AuthorityDiscoveryId>>,
+		peer: &PeerId,
+	) -> bool {
+		peer_ids.get(peer).map_or(false, |authority_ids| {
+			self.validators.iter().any(|v| authority_ids.contains(v))
+		})
+	}
+
 	/// Returns `true` if we should advertise our collation to the given peer.
 	fn should_advertise_to(
 		&self,
@@ -859,7 +877,13 @@ async fn send_collation(
 			let r = rx.timeout(MAX_UNSHARED_UPLOAD_TIME).await;
 			let timed_out = r.is_none();
 
-			CollationSendResult { relay_parent, candidate_hash, peer_id, timed_out }
+			CollationSendResult {
+				relay_parent,
+				candidate_hash,
+				peer_id,
+				chunk_index: None,
+				timed_out,
+			}
 		}
 		.boxed(),
 	);
@@ -867,6 +891,71 @@ async fn send_collation(
 	state.metrics.on_collation_sent();
 }
 
+/// Send a chunk of the `PoV` of a collation to a validator.
+///
+/// The chunk is encoded straight from the stored `PoV`. Like [`send_collation`], this occupies
+/// the upload slot of the relay parent until the chunk was sent or `MAX_UNSHARED_UPLOAD_TIME` has
+/// passed.
+fn send_collation_chunk(
+	active_collation_fetches: &mut ActiveCollationFetches,
+	metrics: &Metrics,
+	request: IncomingRequest<request_v2::CollationChunkFetchingRequest>,
+	collation: &Collation,
+) {
+	let (tx, rx) = oneshot::channel();
+
+	let IncomingRequest { peer: peer_id, payload, pending_response } = request;
+	let request_v2::CollationChunkFetchingRequest {
+		relay_parent, candidate_hash, chunk_index, ..
+	} = payload;
+
+	let block_data = &collation.pov.block_data.0;
+	let chunk = block_data.chunks(COLLATION_CHUNK_SIZE).nth(chunk_index as usize).unwrap_or(&[]);
+	let response = if chunk_index == 0 {
+		request_v2::CollationChunkFetchingResponseRef::FirstChunk {
+			receipt: &collation.receipt,
+			pov_size: block_data.len() as u32,
+			chunk,
+		}
+	} else {
+		request_v2::CollationChunkFetchingResponseRef::Chunk(chunk)
+	};
+	let response = OutgoingResponse {
+		result: Ok(response),
+		reputation_changes: Vec::new(),
+		sent_feedback: Some(tx),
+	};
+
+	if let Err(_) = pending_response.send_outgoing_response_as(response) {
+		gum::warn!(target: LOG_TARGET, "Sending collation chunk response failed");
+	}
+
+	active_collation_fetches.push(
+		async move {
+			let r = rx.timeout(MAX_UNSHARED_UPLOAD_TIME).await;
+			let timed_out = r.is_none();
+
+			CollationSendResult {
+				relay_parent,
+				candidate_hash,
+				peer_id,
+				chunk_index: Some(chunk_index),
+				timed_out,
+			}
+		}
+		.boxed(),
+	);
+
+	if chunk_index as usize + 1 == chunk_count(block_data.len()) {
+		metrics.on_collation_sent();
+	}
+}
+
+/// The number of chunks `PoV` block data of the given size is split into.
+fn chunk_count(block_data_len: usize) -> usize {
+	((block_data_len + COLLATION_CHUNK_SIZE - 1) / COLLATION_CHUNK_SIZE).max(1)
+}
+
 /// A networking messages switch.
 #[overseer::contextbounds(CollatorProtocol, prefix = self::overseer)]
 async fn handle_incoming_peer_message<Context>(
@@ -1063,7 +1152,7 @@ async fn handle_incoming_request<Context>(
 			}
 
 			if waiting.collation_fetch_active {
-				waiting.req_queue.push_back(req);
+				waiting.req_queue.push_back(QueuedCollationRequest::Full(req));
 			} else {
 				waiting.collation_fetch_active = true;
 				// Obtain a timer for sending collation
@@ -1090,6 +1179,121 @@ async fn handle_incoming_request<Context>(
 	Ok(())
 }
 
+/// Process an incoming network request for a chunk of a collation.
+///
+/// Chunk requests share the upload queue of the relay parent with requests for full collations,
+/// and are only served to validators of the group assigned to our para.
+#[overseer::contextbounds(CollatorProtocol, prefix = self::overseer)]
+async fn handle_incoming_chunk_request<Context>(
+	ctx: &mut Context,
+	state: &mut State,
+	req: std::result::Result<
+		IncomingRequest<request_v2::CollationChunkFetchingRequest>,
+		incoming::Error,
+	>,
+) -> Result<()> {
+	let req = req?;
+	let peer_id = req.peer;
+	let request_v2::CollationChunkFetchingRequest {
+		relay_parent,
+		para_id,
+		candidate_hash,
+		chunk_index,
+	} = req.payload;
+
+	if state.collating_on != Some(para_id) {
+		gum::warn!(
+			target: LOG_TARGET,
+			for_para_id = %para_id,
+			our_para_id = ?state.collating_on,
+			"received a `CollationChunkFetchingRequest` for unexpected para_id",
+		);
+
+		return Ok(())
+	}
+
+	let per_relay_parent = match state.per_relay_parent.get_mut(&relay_parent) {
+		Some(per_relay_parent) => per_relay_parent,
+		None => {
+			gum::debug!(
+				target: LOG_TARGET,
+				relay_parent = %relay_parent,
+				"received a `CollationChunkFetchingRequest` for a relay parent out of our view",
+			);
+
+			return Ok(())
+		},
+	};
+
+	if !per_relay_parent.validator_group.contains_peer(&state.peer_ids, &peer_id) {
+		gum::debug!(
+			target: LOG_TARGET,
+			relay_parent = %relay_parent,
+			?peer_id,
+			"received a `CollationChunkFetchingRequest` from a peer not in our validator group",
+		);
+		modify_reputation(&mut state.reputation, ctx.sender(), peer_id, COST_UNEXPECTED_MESSAGE)
+			.await;
+
+		return Ok(())
+	}
+
+	let collation = match per_relay_parent.collations.get_mut(&candidate_hash) {
+		Some(collation) => collation,
+		None => {
+			gum::debug!(
+				target: LOG_TARGET,
+				relay_parent = %relay_parent,
+				?candidate_hash,
+				"received a `CollationChunkFetchingRequest` for a collation we don't have stored",
+			);
+
+			return Ok(())
+		},
+	};
+
+	if chunk_index as usize >= chunk_count(collation.pov.block_data.0.len()) {
+		let response = OutgoingResponse {
+			result: Err(()),
+			reputation_changes: vec![COST_INVALID_REQUEST],
+			sent_feedback: None,
+		};
+		if let Err(_) = req.pending_response.send_outgoing_response(response) {
+			gum::debug!(target: LOG_TARGET, "Sending collation chunk response failed");
+		}
+
+		return Ok(())
+	}
+
+	let waiting = state.waiting_collation_fetches.entry(relay_parent).or_default();
+	let waiting_chunks = waiting.waiting_chunks.entry((peer_id, candidate_hash)).or_default();
+	if waiting_chunks.len() >= MAX_CHUNK_REQUESTS_PER_PEER || !waiting_chunks.insert(chunk_index) {
+		gum::debug!(
+			target: LOG_TARGET,
+			?chunk_index,
+			"Dropping incoming chunk request as peer has too many or the same request in flight."
+		);
+		modify_reputation(&mut state.reputation, ctx.sender(), peer_id, COST_APPARENT_FLOOD.into())
+			.await;
+
+		return Ok(())
+	}
+
+	if chunk_index == 0 {
+		collation.status.advance_to_requested();
+		state.metrics.on_collation_sent_requested();
+	}
+
+	if waiting.collation_fetch_active {
+		waiting.req_queue.push_back(QueuedCollationRequest::Chunk(req));
+	} else {
+		waiting.collation_fetch_active = true;
+		send_collation_chunk(&mut state.active_collation_fetches, &state.metrics, req, collation);
+	}
+
+	Ok(())
+}
+
 /// Peer's view has changed. Send advertisements for new relay parents
 /// if there're any.
 #[overseer::contextbounds(CollatorProtocol, prefix = self::overseer)]
@@ -1334,6 +1538,7 @@ pub(crate) async fn run<Context>(
 	collator_pair: CollatorPair,
 	req_v1_receiver: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 	req_v2_receiver: IncomingRequestReceiver<request_v2::CollationFetchingRequest>,
+	chunk_req_v2_receiver: IncomingRequestReceiver<request_v2::CollationChunkFetchingRequest>,
 	metrics: Metrics,
 ) -> std::result::Result<(), FatalError> {
 	run_inner(
@@ -1342,6 +1547,7 @@ pub(crate) async fn run<Context>(
 		collator_pair,
 		req_v1_receiver,
 		req_v2_receiver,
+		chunk_req_v2_receiver,
 		metrics,
 		ReputationAggregator::default(),
 		REPUTATION_CHANGE_INTERVAL,
@@ -1356,6 +1562,7 @@ async fn run_inner<Context>(
 	collator_pair: CollatorPair,
 	mut req_v1_receiver: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 	mut req_v2_receiver: IncomingRequestReceiver<request_v2::CollationFetchingRequest>,
+	mut chunk_req_v2_receiver: IncomingRequestReceiver<request_v2::CollationChunkFetchingRequest>,
 	metrics: Metrics,
 	reputation: ReputationAggregator,
 	reputation_interval: Duration,
@@ -1372,8 +1579,10 @@ async fn run_inner<Context>(
 		let reputation_changes = || vec![COST_INVALID_REQUEST];
 		let recv_req_v1 = req_v1_receiver.recv(reputation_changes).fuse();
 		let recv_req_v2 = req_v2_receiver.recv(reputation_changes).fuse();
+		let recv_chunk_req_v2 = chunk_req_v2_receiver.recv(reputation_changes).fuse();
 		pin_mut!(recv_req_v1);
 		pin_mut!(recv_req_v2);
+		pin_mut!(recv_chunk_req_v2);
 
 		let mut reconnect_timeout = &mut state.reconnect_timeout;
 		select! {
@@ -1396,7 +1605,7 @@ async fn run_inner<Context>(
 				FromOrchestra::Signal(BlockFinalized(..)) => {}
 				FromOrchestra::Signal(Conclude) => return Ok(()),
 			},
-			CollationSendResult { relay_parent, candidate_hash, peer_id, timed_out } =
+			CollationSendResult { relay_parent, candidate_hash, peer_id, chunk_index, timed_out } =
 				state.active_collation_fetches.select_next_some() => {
 				let next = if let Some(waiting) = state.waiting_collation_fetches.get_mut(&relay_parent) {
 					if timed_out {
@@ -1411,6 +1620,28 @@ async fn run_inner<Context>(
 						// more bandwidth, but if the collation is not received within the
 						// timeout, we simply start processing next request.
 						// The request it still alive, it should be kept in a waiting queue.
+					} else if let Some(chunk_index) = chunk_index {
+						let key = (peer_id, candidate_hash);
+						if let Some(waiting_chunks) = waiting.waiting_chunks.get_mut(&key) {
+							waiting_chunks.remove(&chunk_index);
+							if waiting_chunks.is_empty() {
+								waiting.waiting_chunks.remove(&key);
+							}
+						}
+
+						let is_last_chunk = state
+							.per_relay_parent
+							.get(&relay_parent)
+							.and_then(|per_relay_parent| per_relay_parent.collations.get(&candidate_hash))
+							.map_or(false, |collation| {
+								chunk_index as usize + 1 == chunk_count(collation.pov.block_data.0.len())
+							});
+						if is_last_chunk {
+							for authority_id in state.peer_ids.get(&peer_id).into_iter().flatten() {
+								// This peer has received the whole candidate. Not interested anymore.
+								state.validator_groups_buf.reset_validator_interest(candidate_hash, authority_id);
+							}
+						}
 					} else {
 						for authority_id in state.peer_ids.get(&peer_id).into_iter().flatten() {
 							// This peer has received the candidate. Not interested anymore.
@@ -1430,6 +1661,25 @@ async fn run_inner<Context>(
 					continue
 				};
 
+				let next = match next {
+					QueuedCollationRequest::Full(next) => next,
+					QueuedCollationRequest::Chunk(next) => {
+						let collation = state
+							.per_relay_parent
+							.get(&relay_parent)
+							.and_then(|per_relay_parent| per_relay_parent.collations.get(&next.payload.candidate_hash));
+						if let Some(collation) = collation {
+							send_collation_chunk(
+								&mut state.active_collation_fetches,
+								&state.metrics,
+								next,
+								collation,
+							);
+						}
+						continue
+					},
+				};
+
 				let next_collation = {
 					let per_relay_parent = match state.per_relay_parent.get(&relay_parent) {
 						Some(per_relay_parent) => per_relay_parent,
@@ -1493,6 +1743,12 @@ async fn run_inner<Context>(
 					"Handling incoming collation fetch request V2"
 				)?;
 			}
+			in_req = recv_chunk_req_v2 => {
+				log_error(
+					handle_incoming_chunk_request(&mut ctx, &mut state, in_req).await,
+					"Handling incoming collation chunk fetch request V2"
+				)?;
+			}
 		}
 	}
 }
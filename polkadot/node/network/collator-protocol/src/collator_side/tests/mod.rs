@@ -213,6 +213,7 @@ struct TestHarness {
 	virtual_overseer: VirtualOverseer,
 	req_v1_cfg: sc_network::config::RequestResponseConfig,
 	req_v2_cfg: sc_network::config::RequestResponseConfig,
+	chunk_req_v2_cfg: sc_network::config::RequestResponseConfig,
 }
 
 fn test_harness<T: Future<Output = TestHarness>>(
@@ -238,6 +239,8 @@ fn test_harness<T: Future<Output = TestHarness>>(
 		IncomingRequest::get_config_receiver(&req_protocol_names);
 	let (collation_req_v2_receiver, req_v2_cfg) =
 		IncomingRequest::get_config_receiver(&req_protocol_names);
+	let (collation_chunk_req_v2_receiver, chunk_req_v2_cfg) =
+		IncomingRequest::get_config_receiver(&req_protocol_names);
 	let subsystem = async {
 		run_inner(
 			context,
@@ -245,6 +248,7 @@ fn test_harness<T: Future<Output = TestHarness>>(
 			collator_pair,
 			collation_req_receiver,
 			collation_req_v2_receiver,
+			collation_chunk_req_v2_receiver,
 			Default::default(),
 			reputation,
 			REPUTATION_CHANGE_TEST_INTERVAL,
@@ -253,7 +257,7 @@ fn test_harness<T: Future<Output = TestHarness>>(
 		.unwrap();
 	};
 
-	let test_fut = test(TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg });
+	let test_fut = test(TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, chunk_req_v2_cfg });
 
 	futures::pin_mut!(test_fut);
 	futures::pin_mut!(subsystem);
@@ -789,7 +793,7 @@ fn advertise_and_send_collation() {
 				None,
 			)
 			.await;
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, ..test_harness }
 		},
 	);
 }
@@ -903,7 +907,7 @@ fn delay_reputation_change() {
 				);
 			}
 
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, ..test_harness }
 		},
 	);
 }
@@ -1505,7 +1509,7 @@ fn connect_to_buffered_groups() {
 				}
 			);
 
-			TestHarness { virtual_overseer, req_v1_cfg: req_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg: req_cfg, req_v2_cfg, ..test_harness }
 		},
 	);
 }
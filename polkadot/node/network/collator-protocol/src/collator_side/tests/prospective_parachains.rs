@@ -481,7 +481,149 @@ fn advertise_and_send_collation_by_hash() {
 				);
 			}
 
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, ..test_harness }
+		},
+	)
+}
+
+/// Tests that collator serves collations in chunks of their `PoV`.
+#[test]
+fn send_collation_in_chunks() {
+	let test_state = TestState::default();
+
+	let local_peer_id = test_state.local_peer_id;
+	let collator_pair = test_state.collator_pair.clone();
+
+	test_harness(
+		local_peer_id,
+		collator_pair,
+		ReputationAggregator::new(|_| true),
+		|test_harness| async move {
+			let mut virtual_overseer = test_harness.virtual_overseer;
+			let chunk_req_v2_cfg = test_harness.chunk_req_v2_cfg;
+
+			let head_a = Hash::from_low_u64_be(128);
+			let head_a_num: u32 = 64;
+
+			overseer_send(
+				&mut virtual_overseer,
+				CollatorProtocolMessage::CollateOn(test_state.para_id),
+			)
+			.await;
+			update_view(&mut virtual_overseer, &test_state, vec![(head_a, head_a_num)], 1).await;
+
+			let pov = PoV {
+				block_data: BlockData((0..2 * COLLATION_CHUNK_SIZE + 1).map(|i| i as u8).collect()),
+			};
+			let candidate = TestCandidateBuilder {
+				para_id: test_state.para_id,
+				relay_parent: head_a,
+				pov_hash: pov.hash(),
+				..Default::default()
+			}
+			.build();
+			distribute_collation_with_receipt(
+				&mut virtual_overseer,
+				&test_state,
+				head_a,
+				true,
+				candidate.clone(),
+				pov.clone(),
+				Hash::zero(),
+			)
+			.await;
+
+			let request_chunk = |peer, chunk_index| {
+				let (pending_response, rx) = oneshot::channel();
+				let request = RawIncomingRequest {
+					peer,
+					payload: request_v2::CollationChunkFetchingRequest {
+						relay_parent: head_a,
+						para_id: test_state.para_id,
+						candidate_hash: candidate.hash(),
+						chunk_index,
+					}
+					.encode(),
+					pending_response,
+				};
+				let queue = chunk_req_v2_cfg.inbound_queue.as_ref().unwrap().clone();
+				async move {
+					queue.send(request).await.unwrap();
+					rx
+				}
+			};
+
+			// Peers outside of our validator group are not served.
+			let stranger = PeerId::random();
+			let rx = request_chunk(stranger, 0).await;
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::NetworkBridgeTx(NetworkBridgeTxMessage::ReportPeer(
+					ReportPeerMessage::Single(peer, rep),
+				)) => {
+					assert_eq!(peer, stranger);
+					assert_eq!(rep, COST_UNEXPECTED_MESSAGE.into());
+				}
+			);
+			assert!(rx.await.is_err());
+
+			let peer = test_state.validator_peer_id[0];
+			let validator_id = test_state.current_group_validator_authority_ids()[0].clone();
+			connect_peer(&mut virtual_overseer, peer, CollationVersion::V2, Some(validator_id))
+				.await;
+			expect_declare_msg_v2(&mut virtual_overseer, &test_state, &peer).await;
+			send_peer_view_change(&mut virtual_overseer, &peer, vec![head_a]).await;
+			expect_advertise_collation_msg(
+				&mut virtual_overseer,
+				&peer,
+				head_a,
+				Some(vec![candidate.hash()]),
+			)
+			.await;
+
+			// All chunks are requested at once and served one after the other.
+			let mut responses = Vec::new();
+			for chunk_index in 0..3 {
+				responses.push(request_chunk(peer, chunk_index).await);
+			}
+
+			let mut block_data = Vec::new();
+			for (chunk_index, rx) in responses.into_iter().enumerate() {
+				let response = rx.await.unwrap();
+				response
+					.sent_feedback
+					.expect("Feedback channel is always set")
+					.send(())
+					.unwrap();
+				let response = request_v2::CollationChunkFetchingResponse::decode(
+					&mut response.result.expect("Chunk exists").as_ref(),
+				)
+				.expect("Decoding should work");
+
+				match response {
+					request_v2::CollationChunkFetchingResponse::FirstChunk {
+						receipt,
+						pov_size,
+						chunk,
+					} => {
+						assert_eq!(chunk_index, 0);
+						assert_eq!(receipt, candidate);
+						assert_eq!(pov_size as usize, pov.block_data.0.len());
+						block_data.extend(chunk);
+					},
+					request_v2::CollationChunkFetchingResponse::Chunk(chunk) => {
+						assert_ne!(chunk_index, 0);
+						block_data.extend(chunk);
+					},
+				}
+			}
+			assert_eq!(block_data, pov.block_data.0);
+
+			// There is no fourth chunk.
+			let response = request_chunk(peer, 3).await.await.unwrap();
+			assert!(response.result.is_err());
+
+			TestHarness { virtual_overseer, chunk_req_v2_cfg, ..test_harness }
 		},
 	)
 }
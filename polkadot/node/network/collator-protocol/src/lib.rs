@@ -64,6 +64,19 @@ impl Default for CollatorEvictionPolicy {
 	}
 }
 
+/// Configuration of fetching collations in chunks of their `PoV`.
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkedFetchingConfig {
+	/// How many chunk requests are kept in flight at once for a collation.
+	pub pipeline_depth: usize,
+}
+
+impl Default for ChunkedFetchingConfig {
+	fn default() -> Self {
+		ChunkedFetchingConfig { pipeline_depth: 4 }
+	}
+}
+
 /// What side of the collator protocol is being engaged
 pub enum ProtocolSide {
 	/// Validators operate on the relay chain.
@@ -72,6 +85,8 @@ pub enum ProtocolSide {
 		keystore: KeystorePtr,
 		/// An eviction policy for inactive peers or validators.
 		eviction_policy: CollatorEvictionPolicy,
+		/// Fetch collations from v2 collators in chunks of their `PoV`, if set.
+		chunked_fetching: Option<ChunkedFetchingConfig>,
 		/// Prometheus metrics for validators.
 		metrics: validator_side::Metrics,
 	},
@@ -85,6 +100,9 @@ pub enum ProtocolSide {
 		request_receiver_v1: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 		/// Receiver for v2 collation fetching requests.
 		request_receiver_v2: IncomingRequestReceiver<protocol_v2::CollationFetchingRequest>,
+		/// Receiver for v2 collation chunk fetching requests.
+		chunk_request_receiver_v2:
+			IncomingRequestReceiver<protocol_v2::CollationChunkFetchingRequest>,
 		/// Metrics.
 		metrics: collator_side::Metrics,
 	},
@@ -112,8 +130,8 @@ impl CollatorProtocolSubsystem {
 impl<Context> CollatorProtocolSubsystem {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
 		let future = match self.protocol_side {
-			ProtocolSide::Validator { keystore, eviction_policy, metrics } =>
-				validator_side::run(ctx, keystore, eviction_policy, metrics)
+			ProtocolSide::Validator { keystore, eviction_policy, chunked_fetching, metrics } =>
+				validator_side::run(ctx, keystore, eviction_policy, chunked_fetching, metrics)
 					.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
 					.boxed(),
 			ProtocolSide::Collator {
@@ -121,6 +139,7 @@ impl<Context> CollatorProtocolSubsystem {
 				collator_pair,
 				request_receiver_v1,
 				request_receiver_v2,
+				chunk_request_receiver_v2,
 				metrics,
 			} => collator_side::run(
 				ctx,
@@ -128,6 +147,7 @@ impl<Context> CollatorProtocolSubsystem {
 				collator_pair,
 				request_receiver_v1,
 				request_receiver_v2,
+				chunk_request_receiver_v2,
 				metrics,
 			)
 			.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
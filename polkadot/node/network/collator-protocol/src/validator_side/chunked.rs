@@ -0,0 +1,178 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fetching of collations in chunks of their `PoV`.
+
+use futures::{future::BoxFuture, stream::FuturesOrdered, FutureExt, StreamExt};
+
+use polkadot_node_network_protocol::{
+	request_response::{
+		outgoing::{Recipient, RequestError},
+		v1 as request_v1, v2 as request_v2, OutgoingRequest, OutgoingResult, Requests,
+		COLLATION_CHUNK_SIZE,
+	},
+	PeerId,
+};
+use polkadot_node_primitives::{BlockData, PoV, POV_BOMB_LIMIT};
+use polkadot_node_subsystem::{
+	messages::{IfDisconnected, NetworkBridgeTxMessage},
+	CollatorProtocolSenderTrait,
+};
+use polkadot_primitives::MAX_POV_SIZE;
+use sp_maybe_compressed_blob::StreamingDecompressor;
+
+use super::collation::CollationFetchError;
+use crate::LOG_TARGET;
+
+/// Fetch a collation in chunks of its `PoV`.
+///
+/// Up to `pipeline_depth` chunk requests are kept in flight at once. Chunks are checked in order
+/// as they arrive, while the remaining chunks are being fetched:
+/// - each chunk must have the size implied by the `PoV` size announced with the first chunk,
+/// - the `PoV` must decompress without exceeding the bomb limit, which is checked incrementally,
+///   without keeping the decompressed output around.
+///
+/// Once complete, the `PoV` must match the hash in the candidate receipt. A collator serving an
+/// invalid `PoV` is hence detected before it is handed on for seconding, and usually before the
+/// `PoV` was fetched in full.
+///
+/// If the collator fails to serve the first chunk, e.g. because it doesn't support fetching in
+/// chunks yet, the collation is fetched in full instead.
+pub(super) async fn fetch_collation_chunked<Sender: CollatorProtocolSenderTrait>(
+	mut sender: Sender,
+	peer_id: PeerId,
+	request: request_v2::CollationFetchingRequest,
+	pipeline_depth: usize,
+) -> Result<request_v1::CollationFetchingResponse, CollationFetchError> {
+	let chunk_request = move |chunk_index| request_v2::CollationChunkFetchingRequest {
+		relay_parent: request.relay_parent,
+		para_id: request.para_id,
+		candidate_hash: request.candidate_hash,
+		chunk_index,
+	};
+
+	let (receipt, pov_size, first_chunk) =
+		match request_chunk(&mut sender, peer_id, chunk_request(0)).await.await {
+			Ok(request_v2::CollationChunkFetchingResponse::FirstChunk {
+				receipt,
+				pov_size,
+				chunk,
+			}) => (receipt, pov_size as usize, chunk),
+			Ok(request_v2::CollationChunkFetchingResponse::Chunk(_)) =>
+				return Err(CollationFetchError::InvalidChunk("first chunk without receipt")),
+			Err(RequestError::NetworkError(err)) => {
+				gum::debug!(
+					target: LOG_TARGET,
+					?peer_id,
+					?err,
+					"Fetching collation in chunks failed, fetching it in full",
+				);
+
+				return fetch_collation_in_full(&mut sender, peer_id, request).await
+			},
+			Err(err) => return Err(err.into()),
+		};
+
+	if pov_size > MAX_POV_SIZE as usize {
+		return Err(CollationFetchError::InvalidChunk("oversized PoV"))
+	}
+	let chunk_count = ((pov_size + COLLATION_CHUNK_SIZE - 1) / COLLATION_CHUNK_SIZE).max(1);
+
+	let mut block_data = Vec::with_capacity(pov_size);
+	let mut decompressor = StreamingDecompressor::validating(POV_BOMB_LIMIT);
+	push_chunk(&mut block_data, &mut decompressor, pov_size, first_chunk)?;
+
+	let mut in_flight = FuturesOrdered::new();
+	let mut next_chunk = 1;
+	loop {
+		while next_chunk < chunk_count && in_flight.len() < pipeline_depth.max(1) {
+			let response = request_chunk(&mut sender, peer_id, chunk_request(next_chunk as u32));
+			in_flight.push_back(response.await);
+			next_chunk += 1;
+		}
+
+		match in_flight.next().await {
+			Some(Ok(request_v2::CollationChunkFetchingResponse::Chunk(chunk))) =>
+				push_chunk(&mut block_data, &mut decompressor, pov_size, chunk)?,
+			Some(Ok(request_v2::CollationChunkFetchingResponse::FirstChunk { .. })) =>
+				return Err(CollationFetchError::InvalidChunk("receipt in subsequent chunk")),
+			Some(Err(err)) => return Err(err.into()),
+			None => break,
+		}
+	}
+
+	let pov = PoV { block_data: BlockData(block_data) };
+	if pov.hash() != receipt.descriptor.pov_hash {
+		return Err(CollationFetchError::InvalidChunk("PoV hash mismatch"))
+	}
+
+	Ok(request_v1::CollationFetchingResponse::Collation(receipt, pov))
+}
+
+/// Append a chunk to the `PoV` block data fetched so far, checking that it decompresses.
+fn push_chunk(
+	block_data: &mut Vec<u8>,
+	decompressor: &mut StreamingDecompressor,
+	pov_size: usize,
+	chunk: Vec<u8>,
+) -> Result<(), CollationFetchError> {
+	// All chunks but the last one are full.
+	let expected_len = (pov_size - block_data.len()).min(COLLATION_CHUNK_SIZE);
+	if chunk.len() != expected_len {
+		return Err(CollationFetchError::InvalidChunk("chunk of unexpected size"))
+	}
+
+	decompressor
+		.push(&chunk)
+		.map_err(|_| CollationFetchError::InvalidChunk("chunk could not be decompressed"))?;
+	block_data.extend(chunk);
+
+	Ok(())
+}
+
+/// Send a request for a single chunk of a collation.
+async fn request_chunk<Sender: CollatorProtocolSenderTrait>(
+	sender: &mut Sender,
+	peer_id: PeerId,
+	request: request_v2::CollationChunkFetchingRequest,
+) -> BoxFuture<'static, OutgoingResult<request_v2::CollationChunkFetchingResponse>> {
+	let (req, response_recv) = OutgoingRequest::new(Recipient::Peer(peer_id), request);
+	sender
+		.send_message(NetworkBridgeTxMessage::SendRequests(
+			vec![Requests::CollationChunkFetchingV2(req)],
+			IfDisconnected::ImmediateError,
+		))
+		.await;
+
+	response_recv.boxed()
+}
+
+/// Fetch a collation in full, as done without chunked fetching.
+async fn fetch_collation_in_full<Sender: CollatorProtocolSenderTrait>(
+	sender: &mut Sender,
+	peer_id: PeerId,
+	request: request_v2::CollationFetchingRequest,
+) -> Result<request_v1::CollationFetchingResponse, CollationFetchError> {
+	let (req, response_recv) = OutgoingRequest::new(Recipient::Peer(peer_id), request);
+	sender
+		.send_message(NetworkBridgeTxMessage::SendRequests(
+			vec![Requests::CollationFetchingV2(req)],
+			IfDisconnected::ImmediateError,
+		))
+		.await;
+
+	response_recv.await.map_err(CollationFetchError::Request)
+}
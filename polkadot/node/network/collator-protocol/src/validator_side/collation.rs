@@ -32,7 +32,7 @@ use std::{collections::VecDeque, future::Future, pin::Pin, task::Poll};
 use futures::{future::BoxFuture, FutureExt};
 use polkadot_node_network_protocol::{
 	peer_set::CollationVersion,
-	request_response::{outgoing::RequestError, v1 as request_v1, OutgoingResult},
+	request_response::{outgoing::RequestError, v1 as request_v1},
 	PeerId,
 };
 use polkadot_node_primitives::PoV;
@@ -301,6 +301,8 @@ pub(super) enum CollationFetchError {
 	Cancelled,
 	#[error("{0}")]
 	Request(#[from] RequestError),
+	#[error("Invalid collation chunk: {0}")]
+	InvalidChunk(&'static str),
 }
 
 /// Future that concludes when the collator has responded to our collation fetch request
@@ -313,7 +315,10 @@ pub(super) struct CollationFetchRequest {
 	/// The network protocol version the collator is using.
 	pub collator_protocol_version: CollationVersion,
 	/// Responses from collator.
-	pub from_collator: BoxFuture<'static, OutgoingResult<request_v1::CollationFetchingResponse>>,
+	pub from_collator: BoxFuture<
+		'static,
+		std::result::Result<request_v1::CollationFetchingResponse, CollationFetchError>,
+	>,
 	/// Handle used for checking if this request was cancelled.
 	pub cancellation_token: CancellationToken,
 	/// A jaeger span corresponding to the lifetime of the request.
@@ -354,7 +359,7 @@ impl Future for CollationFetchRequest {
 					collator_id: self.collator_id.clone(),
 					pending_collation: self.pending_collation,
 				},
-				res.map_err(CollationFetchError::Request),
+				res,
 			)
 		});
 
@@ -63,6 +63,7 @@ use crate::error::{Error, FetchError, Result, SecondingError};
 
 use super::{modify_reputation, tick_stream, LOG_TARGET};
 
+mod chunked;
 mod collation;
 mod metrics;
 
@@ -417,6 +418,9 @@ struct State {
 	/// Cancellation handles for the collation fetch requests.
 	collation_requests_cancel_handles: HashMap<PendingCollation, CancellationToken>,
 
+	/// Whether to fetch collations from v2 collators in chunks of their `PoV`.
+	chunked_fetching: Option<crate::ChunkedFetchingConfig>,
+
 	/// Metrics.
 	metrics: Metrics,
 
@@ -705,15 +709,28 @@ async fn request_collation(
 				request_v1::CollationFetchingRequest { relay_parent, para_id },
 			);
 			let requests = Requests::CollationFetchingV1(req);
-			(requests, response_recv.boxed())
+			(Some(requests), response_recv.map(|r| r.map_err(CollationFetchError::Request)).boxed())
 		},
 		(CollationVersion::V2, Some(ProspectiveCandidate { candidate_hash, .. })) => {
-			let (req, response_recv) = OutgoingRequest::new(
-				Recipient::Peer(peer_id),
-				request_v2::CollationFetchingRequest { relay_parent, para_id, candidate_hash },
-			);
-			let requests = Requests::CollationFetchingV2(req);
-			(requests, response_recv.boxed())
+			let request =
+				request_v2::CollationFetchingRequest { relay_parent, para_id, candidate_hash };
+			if let Some(config) = state.chunked_fetching {
+				// Chunk requests are sent as the returned future progresses.
+				let response_recv = chunked::fetch_collation_chunked(
+					sender.clone(),
+					peer_id,
+					request,
+					config.pipeline_depth,
+				);
+				(None, response_recv.boxed())
+			} else {
+				let (req, response_recv) = OutgoingRequest::new(Recipient::Peer(peer_id), request);
+				let requests = Requests::CollationFetchingV2(req);
+				(
+					Some(requests),
+					response_recv.map(|r| r.map_err(CollationFetchError::Request)).boxed(),
+				)
+			}
 		},
 		_ => return Err(FetchError::ProtocolMismatch),
 	};
@@ -723,7 +740,7 @@ async fn request_collation(
 		pending_collation,
 		collator_id: collator_id.clone(),
 		collator_protocol_version: peer_protocol_version,
-		from_collator: response_recv.boxed(),
+		from_collator: response_recv,
 		cancellation_token: cancellation_token.clone(),
 		span: state
 			.span_per_relay_parent
@@ -753,12 +770,14 @@ async fn request_collation(
 		.fetching_from
 		.replace((collator_id, maybe_candidate_hash));
 
-	sender
-		.send_message(NetworkBridgeTxMessage::SendRequests(
-			vec![requests],
-			IfDisconnected::ImmediateError,
-		))
-		.await;
+	if let Some(requests) = requests {
+		sender
+			.send_message(NetworkBridgeTxMessage::SendRequests(
+				vec![requests],
+				IfDisconnected::ImmediateError,
+			))
+			.await;
+	}
 	Ok(())
 }
 
@@ -1592,12 +1611,14 @@ pub(crate) async fn run<Context>(
 	ctx: Context,
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
+	chunked_fetching: Option<crate::ChunkedFetchingConfig>,
 	metrics: Metrics,
 ) -> std::result::Result<(), crate::error::FatalError> {
 	run_inner(
 		ctx,
 		keystore,
 		eviction_policy,
+		chunked_fetching,
 		metrics,
 		ReputationAggregator::default(),
 		REPUTATION_CHANGE_INTERVAL,
@@ -1610,6 +1631,7 @@ async fn run_inner<Context>(
 	mut ctx: Context,
 	keystore: KeystorePtr,
 	eviction_policy: crate::CollatorEvictionPolicy,
+	chunked_fetching: Option<crate::ChunkedFetchingConfig>,
 	metrics: Metrics,
 	reputation: ReputationAggregator,
 	reputation_interval: Duration,
@@ -1617,7 +1639,7 @@ async fn run_inner<Context>(
 	let new_reputation_delay = || futures_timer::Delay::new(reputation_interval).fuse();
 	let mut reputation_delay = new_reputation_delay();
 
-	let mut state = State { metrics, reputation, ..Default::default() };
+	let mut state = State { metrics, reputation, chunked_fetching, ..Default::default() };
 
 	let next_inactivity_stream = tick_stream(ACTIVITY_POLL);
 	futures::pin_mut!(next_inactivity_stream);
@@ -1912,6 +1934,18 @@ async fn handle_collation_fetch_response(
 			);
 			return Err(None)
 		},
+		Err(CollationFetchError::InvalidChunk(err)) => {
+			gum::warn!(
+				target: LOG_TARGET,
+				hash = ?pending_collation.relay_parent,
+				para_id = ?pending_collation.para_id,
+				peer_id = ?pending_collation.peer_id,
+				err = ?err,
+				"Collator provided an invalid collation chunk"
+			);
+			state.metrics.on_request(Err(()));
+			return Err(Some((pending_collation.peer_id, COST_CORRUPTED_MESSAGE)))
+		},
 		Err(CollationFetchError::Request(req_error)) => Err(req_error),
 		Ok(resp) => Ok(resp),
 	};
@@ -167,6 +167,7 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 			inactive_collator: ACTIVITY_TIMEOUT,
 			undeclared: DECLARE_TIMEOUT,
 		},
+		None,
 		Metrics::default(),
 		reputation,
 		REPUTATION_CHANGE_TEST_INTERVAL,
@@ -131,6 +131,16 @@ where
 	) -> std::result::Result<(), ()> {
 		self.pending_response.send_outgoing_response(resp)
 	}
+
+	/// Send response of a type encoding the same as the response type.
+	///
+	/// Calls [`OutgoingResponseSender::send_outgoing_response_as`].
+	pub fn send_outgoing_response_as<Response: Encode>(
+		self,
+		resp: OutgoingResponse<Response>,
+	) -> std::result::Result<(), ()> {
+		self.pending_response.send_outgoing_response_as(resp)
+	}
 }
 
 /// Sender for sending back responses on an `IncomingRequest`.
@@ -169,6 +179,17 @@ where
 	pub fn send_outgoing_response(
 		self,
 		resp: OutgoingResponse<<Req as IsRequest>::Response>,
+	) -> std::result::Result<(), ()> {
+		self.send_outgoing_response_as(resp)
+	}
+
+	/// Send response of a type encoding the same as the response type.
+	///
+	/// This allows responding with data borrowed from elsewhere instead of copying it into an
+	/// owned response first. It is up to the caller to make sure the encoding matches.
+	pub fn send_outgoing_response_as<Response: Encode>(
+		self,
+		resp: OutgoingResponse<Response>,
 	) -> std::result::Result<(), ()> {
 		let OutgoingResponse { result, reputation_changes, sent_feedback } = resp;
 
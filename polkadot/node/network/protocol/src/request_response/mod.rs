@@ -67,6 +67,8 @@ pub enum Protocol {
 	CollationFetchingV1,
 	/// Protocol for fetching collations from collators when async backing is enabled.
 	CollationFetchingV2,
+	/// Protocol for fetching collations from collators in chunks of their `PoV`.
+	CollationChunkFetchingV2,
 	/// Protocol for fetching seconded PoVs from validators of the same group.
 	PoVFetchingV1,
 	/// Protocol for fetching available data.
@@ -142,6 +144,15 @@ pub const MAX_PARALLEL_ATTESTED_CANDIDATE_REQUESTS: u32 = 5;
 /// limit might have more severe effects.
 const POV_RESPONSE_SIZE: u64 = MAX_POV_SIZE as u64 + 10_000;
 
+/// Size of the chunks `PoV`s are split into by `CollationChunkFetchingV2`.
+pub const COLLATION_CHUNK_SIZE: usize = 512 * 1024;
+
+/// Response size limit for `CollationChunkFetchingV2`.
+///
+/// This is `COLLATION_CHUNK_SIZE` plus some additional space for protocol overhead and the
+/// candidate receipt sent along with the first chunk.
+const COLLATION_CHUNK_RESPONSE_SIZE: u64 = COLLATION_CHUNK_SIZE as u64 + 10_000;
+
 /// Maximum response sizes for `StatementFetchingV1`.
 ///
 /// This is `MAX_CODE_SIZE` plus some additional space for protocol overhead.
@@ -209,6 +220,15 @@ impl Protocol {
 					request_timeout: POV_REQUEST_TIMEOUT_CONNECTED,
 					inbound_queue: tx,
 				},
+			Protocol::CollationChunkFetchingV2 => RequestResponseConfig {
+				name,
+				fallback_names,
+				max_request_size: 1_000,
+				max_response_size: COLLATION_CHUNK_RESPONSE_SIZE,
+				// A single chunk is quick to transfer once the connection is open.
+				request_timeout: DEFAULT_REQUEST_TIMEOUT_CONNECTED,
+				inbound_queue: tx,
+			},
 			Protocol::PoVFetchingV1 => RequestResponseConfig {
 				name,
 				fallback_names,
@@ -276,6 +296,8 @@ impl Protocol {
 			Protocol::ChunkFetchingV1 => 100,
 			// 10 seems reasonable, considering group sizes of max 10 validators.
 			Protocol::CollationFetchingV1 | Protocol::CollationFetchingV2 => 10,
+			// Validators of a group keep several chunk requests in flight each.
+			Protocol::CollationChunkFetchingV2 => 50,
 			// 10 seems reasonable, considering group sizes of max 10 validators.
 			Protocol::PoVFetchingV1 => 10,
 			// Validators are constantly self-selecting to request available data which may lead
@@ -346,6 +368,7 @@ impl Protocol {
 			// Introduced after legacy names became legacy.
 			Protocol::AttestedCandidateV2 => None,
 			Protocol::CollationFetchingV2 => None,
+			Protocol::CollationChunkFetchingV2 => None,
 		}
 	}
 }
@@ -403,6 +426,7 @@ impl ReqProtocolNames {
 			Protocol::DisputeSendingV1 => "/send_dispute/1",
 
 			Protocol::CollationFetchingV2 => "/req_collation/2",
+			Protocol::CollationChunkFetchingV2 => "/req_collation_chunk/2",
 			Protocol::AttestedCandidateV2 => "/req_attested_candidate/2",
 		};
 
@@ -46,6 +46,8 @@ pub enum Requests {
 	/// Fetch a collation from a collator which previously announced it.
 	/// Compared to V1 it requires specifying which candidate is requested by its hash.
 	CollationFetchingV2(OutgoingRequest<v2::CollationFetchingRequest>),
+	/// Fetch a chunk of a collation from a collator which previously announced it.
+	CollationChunkFetchingV2(OutgoingRequest<v2::CollationChunkFetchingRequest>),
 }
 
 impl Requests {
@@ -55,6 +57,7 @@ impl Requests {
 			Self::ChunkFetchingV1(_) => Protocol::ChunkFetchingV1,
 			Self::CollationFetchingV1(_) => Protocol::CollationFetchingV1,
 			Self::CollationFetchingV2(_) => Protocol::CollationFetchingV2,
+			Self::CollationChunkFetchingV2(_) => Protocol::CollationChunkFetchingV2,
 			Self::PoVFetchingV1(_) => Protocol::PoVFetchingV1,
 			Self::AvailableDataFetchingV1(_) => Protocol::AvailableDataFetchingV1,
 			Self::StatementFetchingV1(_) => Protocol::StatementFetchingV1,
@@ -75,6 +78,7 @@ impl Requests {
 			Self::ChunkFetchingV1(r) => r.encode_request(),
 			Self::CollationFetchingV1(r) => r.encode_request(),
 			Self::CollationFetchingV2(r) => r.encode_request(),
+			Self::CollationChunkFetchingV2(r) => r.encode_request(),
 			Self::PoVFetchingV1(r) => r.encode_request(),
 			Self::AvailableDataFetchingV1(r) => r.encode_request(),
 			Self::StatementFetchingV1(r) => r.encode_request(),
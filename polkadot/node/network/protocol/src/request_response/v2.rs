@@ -19,8 +19,8 @@
 use parity_scale_codec::{Decode, Encode};
 
 use polkadot_primitives::{
-	CandidateHash, CommittedCandidateReceipt, Hash, Id as ParaId, PersistedValidationData,
-	UncheckedSignedStatement,
+	CandidateHash, CandidateReceipt, CommittedCandidateReceipt, Hash, Id as ParaId,
+	PersistedValidationData, UncheckedSignedStatement,
 };
 
 use super::{IsRequest, Protocol};
@@ -78,3 +78,63 @@ impl IsRequest for CollationFetchingRequest {
 	type Response = CollationFetchingResponse;
 	const PROTOCOL: Protocol = Protocol::CollationFetchingV2;
 }
+
+/// Request a chunk of the `PoV` of the advertised collation.
+///
+/// The `PoV` block data is split into chunks of `COLLATION_CHUNK_SIZE`, which allows validators
+/// to fetch several chunks at once and to start processing the `PoV` before it was received in
+/// full.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct CollationChunkFetchingRequest {
+	/// Relay parent collation is built on top of.
+	pub relay_parent: Hash,
+	/// The `ParaId` of the collation.
+	pub para_id: ParaId,
+	/// Candidate hash.
+	pub candidate_hash: CandidateHash,
+	/// Index of the requested chunk.
+	pub chunk_index: u32,
+}
+
+/// Responses as sent by collators to `CollationChunkFetchingRequest`s.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum CollationChunkFetchingResponse {
+	/// Deliver the first chunk of the collation, along with its receipt and the size of the `PoV`.
+	#[codec(index = 0)]
+	FirstChunk {
+		/// Receipt of the candidate.
+		receipt: CandidateReceipt,
+		/// The size of the `PoV` block data, which determines the number of chunks.
+		pov_size: u32,
+		/// The first chunk of the `PoV` block data.
+		chunk: Vec<u8>,
+	},
+	/// Deliver any other chunk of the collation.
+	#[codec(index = 1)]
+	Chunk(Vec<u8>),
+}
+
+/// Borrowing variant of [`CollationChunkFetchingResponse`], with the same encoding.
+///
+/// Lets collators respond with a chunk of a stored `PoV` without copying it first.
+#[derive(Debug, Encode)]
+pub enum CollationChunkFetchingResponseRef<'a> {
+	/// See [`CollationChunkFetchingResponse::FirstChunk`].
+	#[codec(index = 0)]
+	FirstChunk {
+		/// Receipt of the candidate.
+		receipt: &'a CandidateReceipt,
+		/// The size of the `PoV` block data, which determines the number of chunks.
+		pov_size: u32,
+		/// The first chunk of the `PoV` block data.
+		chunk: &'a [u8],
+	},
+	/// See [`CollationChunkFetchingResponse::Chunk`].
+	#[codec(index = 1)]
+	Chunk(&'a [u8]),
+}
+
+impl IsRequest for CollationChunkFetchingRequest {
+	type Response = CollationChunkFetchingResponse;
+	const PROTOCOL: Protocol = Protocol::CollationChunkFetchingV2;
+}
@@ -0,0 +1,149 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Per-subsystem load tracking, used as a backpressure signal.
+//!
+//! The metrics metronome periodically samples the bounded channel of every subsystem and
+//! records how many messages are waiting in it. Subsystems can consult the shared
+//! [`SubsystemLoad`] before generating non-critical work, e.g. speculative requests or
+//! gossip, and back off while the receiving subsystem is overloaded. The same instance needs to
+//! be handed to the overseer builder and to the consulting subsystems.
+//!
+//! A subsystem is overloaded once its queue depth reaches its threshold, which is three quarters
+//! of its message channel capacity by default.
+
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+/// The default number of queued messages above which a subsystem is considered overloaded.
+///
+/// This is three quarters of the default message channel capacity of the overseer.
+pub const DEFAULT_OVERLOAD_THRESHOLD: usize = 1536;
+
+/// The default thresholds of the subsystems with a larger message channel capacity than the
+/// default one, three quarters of their capacity each.
+///
+/// Keep in sync with the `message_capacity` of the subsystems in the overseer definition.
+const DEFAULT_SUBSYSTEM_OVERLOAD_THRESHOLDS: &[(&str, usize)] =
+	&[("approval-distribution-subsystem", 48_000), ("dispute-coordinator-subsystem", 24_000)];
+
+/// A snapshot of the load of a single subsystem.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LoadState {
+	/// Number of messages waiting in the bounded channel of the subsystem.
+	pub queue_depth: usize,
+	/// Whether the subsystem is overloaded and non-critical work towards it should be deferred.
+	pub overloaded: bool,
+}
+
+#[derive(Debug, Default)]
+struct Tracked {
+	state: LoadState,
+	blocked: usize,
+}
+
+#[derive(Debug)]
+struct Inner {
+	overload_threshold: usize,
+	subsystem_overload_thresholds: HashMap<&'static str, usize>,
+	subsystems: HashMap<&'static str, Tracked>,
+}
+
+/// Shared view on the load of all subsystems.
+///
+/// Cloning is cheap, all clones observe the same state.
+#[derive(Debug, Clone)]
+pub struct SubsystemLoad(Arc<RwLock<Inner>>);
+
+impl Default for SubsystemLoad {
+	fn default() -> Self {
+		DEFAULT_SUBSYSTEM_OVERLOAD_THRESHOLDS
+			.iter()
+			.fold(Self::new(DEFAULT_OVERLOAD_THRESHOLD), |load, (subsystem, threshold)| {
+				load.with_overload_threshold(subsystem, *threshold)
+			})
+	}
+}
+
+impl SubsystemLoad {
+	/// Create a new load tracker which considers a subsystem overloaded once at least
+	/// `overload_threshold` messages are waiting in its bounded channel.
+	pub fn new(overload_threshold: usize) -> Self {
+		Self(Arc::new(RwLock::new(Inner {
+			overload_threshold,
+			subsystem_overload_thresholds: HashMap::new(),
+			subsystems: HashMap::new(),
+		})))
+	}
+
+	/// Consider the subsystem with the given name overloaded once at least `overload_threshold`
+	/// messages are waiting in its bounded channel, instead of the default threshold.
+	///
+	/// Subsystems with a larger message channel capacity need a larger threshold.
+	pub fn with_overload_threshold(
+		self,
+		subsystem: &'static str,
+		overload_threshold: usize,
+	) -> Self {
+		self.0
+			.write()
+			.subsystem_overload_thresholds
+			.insert(subsystem, overload_threshold);
+		self
+	}
+
+	/// The last sampled load of the subsystem with the given name.
+	///
+	/// Subsystems which were not sampled yet are reported as idle.
+	pub fn get(&self, subsystem: &str) -> LoadState {
+		self.0.read().subsystems.get(subsystem).map(|t| t.state).unwrap_or_default()
+	}
+
+	/// Whether the subsystem with the given name is currently overloaded.
+	pub fn is_overloaded(&self, subsystem: &str) -> bool {
+		self.get(subsystem).overloaded
+	}
+
+	/// Record a new sample of the bounded channel meters of a subsystem.
+	///
+	/// A subsystem is overloaded if its queue depth reached the threshold, or if any sender
+	/// had to block on its full channel since the previous sample.
+	pub(crate) fn update(
+		&self,
+		subsystem: &'static str,
+		sent: usize,
+		received: usize,
+		blocked: usize,
+	) -> LoadState {
+		let mut inner = self.0.write();
+		let overload_threshold = inner
+			.subsystem_overload_thresholds
+			.get(subsystem)
+			.copied()
+			.unwrap_or(inner.overload_threshold);
+		let tracked = inner.subsystems.entry(subsystem).or_default();
+
+		let queue_depth = sent.saturating_sub(received);
+		let blocked_since_last = blocked > tracked.blocked;
+		tracked.blocked = blocked;
+		tracked.state = LoadState {
+			queue_depth,
+			overloaded: queue_depth >= overload_threshold || blocked_since_last,
+		};
+
+		tracked.state
+	}
+}
@@ -94,6 +94,9 @@ pub use polkadot_node_subsystem_types::{
 pub mod metrics;
 pub use self::metrics::Metrics as OverseerMetrics;
 
+pub mod backpressure;
+pub use self::backpressure::{LoadState, SubsystemLoad};
+
 /// A dummy subsystem, mostly useful for placeholders and tests.
 pub mod dummy;
 pub use self::dummy::DummySubsystem;
@@ -642,6 +645,11 @@ pub struct Overseer<SupportsParachains> {
 
 	/// Various Prometheus metrics.
 	pub metrics: OverseerMetrics,
+
+	/// The load of all subsystems, sampled by the metrics metronome.
+	///
+	/// Clones of it can be handed to subsystems, to consult before generating non-critical work.
+	pub subsystem_load: SubsystemLoad,
 }
 
 /// Spawn the metrics metronome task.
@@ -666,6 +674,7 @@ where
 		}
 	}
 	let subsystem_meters = overseer.map_subsystems(ExtractNameAndMeters);
+	let subsystem_load = overseer.subsystem_load.clone();
 
 	#[cfg(any(target_os = "linux", feature = "jemalloc-allocator"))]
 	let collect_memory_stats: Box<dyn Fn(&OverseerMetrics) + Send> =
@@ -702,13 +711,26 @@ where
 		// We combine the amount of messages from subsystems to the overseer
 		// as well as the amount of messages from external sources to the overseer
 		// into one `to_overseer` value.
-		metronome_metrics.channel_metrics_snapshot(
-			subsystem_meters
-				.iter()
-				.cloned()
-				.flatten()
-				.map(|(name, ref meters)| (name, meters.read())),
-		);
+		metronome_metrics.channel_metrics_snapshot(subsystem_meters.iter().cloned().flatten().map(
+			|(name, ref meters)| {
+				let readouts = meters.read();
+				let load = subsystem_load.update(
+					name,
+					readouts.bounded.sent,
+					readouts.bounded.received,
+					readouts.bounded.blocked,
+				);
+				if load.overloaded {
+					gum::debug!(
+						target: LOG_TARGET,
+						subsystem = name,
+						queue_depth = load.queue_depth,
+						"Subsystem is overloaded",
+					);
+				}
+				(name, readouts, load)
+			},
+		));
 
 		futures::future::ready(())
 	});
@@ -30,6 +30,8 @@ struct MetricsInner {
 	to_subsystem_bounded_sent: prometheus::GaugeVec<prometheus::U64>,
 	to_subsystem_bounded_received: prometheus::GaugeVec<prometheus::U64>,
 	to_subsystem_bounded_blocked: prometheus::GaugeVec<prometheus::U64>,
+	to_subsystem_bounded_queue_depth: prometheus::GaugeVec<prometheus::U64>,
+	to_subsystem_overloaded: prometheus::GaugeVec<prometheus::U64>,
 
 	to_subsystem_unbounded_tof: prometheus::HistogramVec,
 	to_subsystem_unbounded_sent: prometheus::GaugeVec<prometheus::U64>,
@@ -80,12 +82,11 @@ impl Metrics {
 
 	pub(crate) fn channel_metrics_snapshot(
 		&self,
-		collection: impl IntoIterator<Item = (&'static str, SubsystemMeterReadouts)>,
+		collection: impl IntoIterator<Item = (&'static str, SubsystemMeterReadouts, LoadState)>,
 	) {
 		if let Some(metrics) = &self.0 {
-			collection
-				.into_iter()
-				.for_each(|(name, readouts): (_, SubsystemMeterReadouts)| {
+			collection.into_iter().for_each(
+				|(name, readouts, load): (_, SubsystemMeterReadouts, LoadState)| {
 					metrics
 						.to_subsystem_bounded_sent
 						.with_label_values(&[name])
@@ -101,6 +102,16 @@ impl Metrics {
 						.with_label_values(&[name])
 						.set(readouts.bounded.blocked as u64);
 
+					metrics
+						.to_subsystem_bounded_queue_depth
+						.with_label_values(&[name])
+						.set(load.queue_depth as u64);
+
+					metrics
+						.to_subsystem_overloaded
+						.with_label_values(&[name])
+						.set(load.overloaded as u64);
+
 					metrics
 						.to_subsystem_unbounded_sent
 						.with_label_values(&[name])
@@ -131,7 +142,8 @@ impl Metrics {
 					for tof in readouts.unbounded.tof {
 						hist_unbounded.observe(tof.as_f64());
 					}
-				});
+				},
+			);
 		}
 	}
 }
@@ -204,6 +216,26 @@ impl MetricsTrait for Metrics {
 				)?,
 				registry,
 			)?,
+			to_subsystem_bounded_queue_depth: prometheus::register(
+				prometheus::GaugeVec::<prometheus::U64>::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_subsystem_bounded_queue_depth",
+						"Number of elements waiting in subsystems' bounded queues",
+					),
+					&["subsystem_name"],
+				)?,
+				registry,
+			)?,
+			to_subsystem_overloaded: prometheus::register(
+				prometheus::GaugeVec::<prometheus::U64>::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_subsystem_overloaded",
+						"Whether a subsystem signals backpressure because of its queue depth",
+					),
+					&["subsystem_name"],
+				)?,
+				registry,
+			)?,
 			to_subsystem_unbounded_tof: prometheus::register(
 				prometheus::HistogramVec::new(
 					prometheus::HistogramOpts::new(
@@ -35,6 +35,7 @@ use polkadot_primitives::{
 
 use crate::{
 	self as overseer,
+	backpressure::DEFAULT_OVERLOAD_THRESHOLD,
 	dummy::{dummy_overseer_builder, one_for_all_overseer_builder},
 	gen::Delay,
 	HeadSupportsParachains,
@@ -1221,3 +1222,55 @@ fn context_holds_onto_message_until_enough_signals_received() {
 
 	futures::executor::block_on(test_fut);
 }
+
+#[test]
+fn subsystem_load_signals_backpressure() {
+	let load = SubsystemLoad::new(10);
+	let observer = load.clone();
+
+	assert_eq!(observer.get("approval-voting"), LoadState::default());
+
+	// Below the threshold and no blocked senders.
+	assert_eq!(
+		load.update("approval-voting", 15, 10, 0),
+		LoadState { queue_depth: 5, overloaded: false }
+	);
+	assert!(!observer.is_overloaded("approval-voting"));
+
+	// The queue depth reached the threshold.
+	load.update("approval-voting", 30, 20, 0);
+	assert_eq!(observer.get("approval-voting"), LoadState { queue_depth: 10, overloaded: true });
+
+	// Senders blocked since the previous sample, even though the queue was drained since.
+	load.update("approval-voting", 40, 39, 2);
+	assert!(observer.is_overloaded("approval-voting"));
+
+	// No new blocked senders and a short queue clears the signal.
+	load.update("approval-voting", 40, 40, 2);
+	assert!(!observer.is_overloaded("approval-voting"));
+	assert!(!observer.is_overloaded("dispute-coordinator"));
+}
+
+#[test]
+fn subsystem_load_uses_per_subsystem_thresholds() {
+	let load = SubsystemLoad::new(10).with_overload_threshold("approval-distribution", 100);
+
+	// The default threshold applies to subsystems without their own.
+	load.update("approval-voting", 10, 0, 0);
+	assert!(load.is_overloaded("approval-voting"));
+
+	load.update("approval-distribution", 99, 0, 0);
+	assert!(!load.is_overloaded("approval-distribution"));
+	load.update("approval-distribution", 199, 99, 0);
+	assert!(load.is_overloaded("approval-distribution"));
+
+	// The subsystems with a large channel capacity are not overloaded by default at the default
+	// threshold.
+	let load = SubsystemLoad::default();
+	load.update("approval-distribution-subsystem", DEFAULT_OVERLOAD_THRESHOLD, 0, 0);
+	load.update("dispute-coordinator-subsystem", DEFAULT_OVERLOAD_THRESHOLD, 0, 0);
+	load.update("approval-voting-subsystem", DEFAULT_OVERLOAD_THRESHOLD, 0, 0);
+	assert!(!load.is_overloaded("approval-distribution-subsystem"));
+	assert!(!load.is_overloaded("dispute-coordinator-subsystem"));
+	assert!(load.is_overloaded("approval-voting-subsystem"));
+}
@@ -29,6 +29,7 @@ pub mod v1 {
 		ValidatorIndex, ValidatorSignature,
 	};
 	use sp_application_crypto::ByteArray;
+	use std::time::Duration;
 
 	/// Validators assigning to check a particular candidate are split up into tranches.
 	/// Earlier tranches of validators check first, with later tranches serving as backup.
@@ -133,6 +134,10 @@ pub mod v1 {
 		pub slot: Slot,
 		/// The session of the block.
 		pub session: SessionIndex,
+		/// How long approval-voting waits for the approval of an assignment for a candidate of
+		/// the block before counting its validator as a no-show, i.e. the `no_show_slots` of the
+		/// session.
+		pub no_show_duration: Duration,
 	}
 
 	/// Errors that can occur during the approvals protocol.
@@ -333,6 +333,9 @@ pub enum InvalidCandidate {
 	CodeTooLarge(u64),
 	/// PoV does not decompress correctly.
 	PoVDecompressionFailure,
+	/// PoV exceeds the decompressed size or compression ratio limits configured for the
+	/// parachain.
+	PoVDecompressionLimitExceeded,
 	/// Validation function returned invalid data.
 	BadReturn,
 	/// Invalid relay chain parent.
@@ -852,6 +852,9 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 	let (collation_req_v2_receiver, cfg) =
 		IncomingRequest::get_config_receiver(&req_protocol_names);
 	net_config.add_request_response_protocol(cfg);
+	let (collation_chunk_req_v2_receiver, cfg) =
+		IncomingRequest::get_config_receiver(&req_protocol_names);
+	net_config.add_request_response_protocol(cfg);
 	let (available_data_req_receiver, cfg) =
 		IncomingRequest::get_config_receiver(&req_protocol_names);
 	net_config.add_request_response_protocol(cfg);
@@ -1051,6 +1054,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					chunk_req_receiver,
 					collation_req_v1_receiver,
 					collation_req_v2_receiver,
+					collation_chunk_req_v2_receiver,
 					available_data_req_receiver,
 					statement_req_receiver,
 					candidate_req_v2_receiver,
@@ -1124,6 +1128,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		let overseer_handle =
 			overseer_handle.as_ref().ok_or(Error::AuthoritiesRequireRealOverseer)?.clone();
 		let slot_duration = babe_link.config().slot_duration();
+		let approval_checking_lag = select_chain.approval_checking_lag();
 		let babe_config = babe::BabeParams {
 			keystore: keystore_container.keystore(),
 			client: client.clone(),
@@ -1135,6 +1140,10 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 			create_inherent_data_providers: move |parent, ()| {
 				let client_clone = client_clone.clone();
 				let overseer_handle = overseer_handle.clone();
+				let finality_lag =
+					relay_chain_selection::ApprovalCheckingLagInherentDataProvider::new(
+						approval_checking_lag.as_ref(),
+					);
 
 				async move {
 					let parachain =
@@ -1152,7 +1161,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 							slot_duration,
 						);
 
-					Ok((slot, timestamp, parachain))
+					Ok((slot, timestamp, parachain, finality_lag))
 				}
 			},
 			force_authoring,
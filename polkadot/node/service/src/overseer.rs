@@ -38,7 +38,7 @@ pub use polkadot_overseer::{
 };
 use polkadot_overseer::{
 	metrics::Metrics as OverseerMetrics, InitializedOverseerBuilder, MetricsTrait, Overseer,
-	OverseerConnector, OverseerHandle, SpawnGlue,
+	OverseerConnector, OverseerHandle, SpawnGlue, SubsystemLoad,
 };
 
 use polkadot_primitives::runtime_api::ParachainHost;
@@ -105,6 +105,9 @@ where
 	pub collation_req_v1_receiver: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 	/// Collations request receiver for network protocol v2.
 	pub collation_req_v2_receiver: IncomingRequestReceiver<request_v2::CollationFetchingRequest>,
+	/// Collation chunks request receiver for network protocol v2.
+	pub collation_chunk_req_v2_receiver:
+		IncomingRequestReceiver<request_v2::CollationChunkFetchingRequest>,
 	/// Receiver for available data requests.
 	pub available_data_req_receiver:
 		IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
@@ -156,6 +159,7 @@ pub fn prepared_overseer_builder<Spawner, RuntimeClient>(
 		chunk_req_receiver,
 		collation_req_v1_receiver,
 		collation_req_v2_receiver,
+		collation_chunk_req_v2_receiver,
 		available_data_req_receiver,
 		statement_req_receiver,
 		candidate_req_v2_receiver,
@@ -228,6 +232,8 @@ where
 		offchain_transaction_pool_factory,
 	));
 
+	let subsystem_load = SubsystemLoad::default();
+
 	let builder = Overseer::builder()
 		.network_bridge_tx(NetworkBridgeTxSubsystem::new(
 			network_service.clone(),
@@ -248,7 +254,7 @@ where
 			IncomingRequestReceivers { pov_req_receiver, chunk_req_receiver },
 			Metrics::register(registry)?,
 		))
-		.availability_recovery(AvailabilityRecoverySubsystem::with_chunks_if_pov_large(
+		.availability_recovery(AvailabilityRecoverySubsystem::with_systematic_chunks_if_pov_large(
 			available_data_req_receiver,
 			Metrics::register(registry)?,
 		))
@@ -286,12 +292,15 @@ where
 					collator_pair,
 					request_receiver_v1: collation_req_v1_receiver,
 					request_receiver_v2: collation_req_v2_receiver,
+					chunk_request_receiver_v2: collation_chunk_req_v2_receiver,
 					metrics: Metrics::register(registry)?,
 				},
 				IsParachainNode::FullNode => ProtocolSide::None,
 				IsParachainNode::No => ProtocolSide::Validator {
 					keystore: keystore.clone(),
 					eviction_policy: Default::default(),
+					// Fetching in chunks is opt-in while collators serving chunks are rolled out.
+					chunked_fetching: None,
 					metrics: Metrics::register(registry)?,
 				},
 			};
@@ -310,7 +319,10 @@ where
 			Metrics::register(registry)?,
 			rand::rngs::StdRng::from_entropy(),
 		))
-		.approval_distribution(ApprovalDistributionSubsystem::new(Metrics::register(registry)?))
+		.approval_distribution(
+			ApprovalDistributionSubsystem::new(Metrics::register(registry)?)
+				.with_subsystem_load(subsystem_load.clone()),
+		)
 		.approval_voting(ApprovalVotingSubsystem::with_config(
 			approval_voting_config,
 			parachains_db.clone(),
@@ -340,6 +352,7 @@ where
 		.activation_external_listeners(Default::default())
 		.span_per_active_leaf(Default::default())
 		.active_leaves(Default::default())
+		.subsystem_load(subsystem_load)
 		.supports_parachains(runtime_api_client)
 		.metrics(metrics)
 		.spawner(spawner);
@@ -46,7 +46,10 @@ use polkadot_node_subsystem::messages::{
 use polkadot_node_subsystem_util::metrics::{self, prometheus};
 use polkadot_overseer::{AllMessages, Handle};
 use polkadot_primitives::{Block as PolkadotBlock, BlockNumber, Hash, Header as PolkadotHeader};
-use std::sync::Arc;
+use std::sync::{
+	atomic::{AtomicU32, Ordering},
+	Arc,
+};
 
 pub use service::SpawnTaskHandle;
 
@@ -132,6 +135,69 @@ where
 	}
 }
 
+/// The approval-checking finality lag most recently measured by chain selection.
+///
+/// Shared between chain selection, which measures the lag when determining the finality target,
+/// and block authoring, which reports it on-chain through
+/// [`ApprovalCheckingLagInherentDataProvider`].
+#[derive(Debug, Clone)]
+pub struct ApprovalCheckingLag(Arc<AtomicU32>);
+
+impl ApprovalCheckingLag {
+	// Marks the lag as not measured yet.
+	const UNKNOWN: BlockNumber = BlockNumber::MAX;
+
+	/// The most recently measured lag, if any was measured yet.
+	pub fn get(&self) -> Option<BlockNumber> {
+		Some(self.0.load(Ordering::Relaxed)).filter(|lag| *lag != Self::UNKNOWN)
+	}
+
+	pub(crate) fn set(&self, lag: BlockNumber) {
+		self.0.store(lag.min(Self::UNKNOWN - 1), Ordering::Relaxed);
+	}
+}
+
+impl Default for ApprovalCheckingLag {
+	fn default() -> Self {
+		Self(Arc::new(AtomicU32::new(Self::UNKNOWN)))
+	}
+}
+
+/// Provides the approval-checking finality lag as inherent data, so that the runtime can track it.
+///
+/// Nothing is provided as long as the lag has not been measured.
+pub struct ApprovalCheckingLagInherentDataProvider(Option<BlockNumber>);
+
+impl ApprovalCheckingLagInherentDataProvider {
+	/// Create a new instance, capturing the currently known lag.
+	pub fn new(lag: Option<&ApprovalCheckingLag>) -> Self {
+		Self(lag.and_then(ApprovalCheckingLag::get))
+	}
+}
+
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for ApprovalCheckingLagInherentDataProvider {
+	async fn provide_inherent_data(
+		&self,
+		dst_inherent_data: &mut sp_inherents::InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		match self.0 {
+			Some(lag) => dst_inherent_data
+				.put_data(polkadot_primitives::vstaging::FINALITY_LAG_INHERENT_IDENTIFIER, &lag),
+			None => Ok(()),
+		}
+	}
+
+	async fn try_handle_error(
+		&self,
+		_identifier: &sp_inherents::InherentIdentifier,
+		_error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		// Inherent isn't checked and can not return any error
+		None
+	}
+}
+
 /// A chain-selection implementation which provides safety for relay chains.
 pub struct SelectRelayChain<B: sc_client_api::Backend<PolkadotBlock>> {
 	longest_chain: sc_consensus::LongestChain<B, PolkadotBlock>,
@@ -187,6 +253,17 @@ where
 	pub fn as_longest_chain(&self) -> &sc_consensus::LongestChain<B, PolkadotBlock> {
 		&self.longest_chain
 	}
+
+	/// The approval-checking finality lag measured by this chain selection.
+	///
+	/// `None` when using the plain longest chain algorithm, which does not measure it.
+	pub fn approval_checking_lag(&self) -> Option<ApprovalCheckingLag> {
+		match self.selection {
+			IsDisputesAwareWithOverseer::Yes(ref selection) =>
+				Some(selection.approval_checking_lag.clone()),
+			IsDisputesAwareWithOverseer::No => None,
+		}
+	}
 }
 
 #[async_trait::async_trait]
@@ -230,6 +307,7 @@ pub struct SelectRelayChainInner<B, OH> {
 	overseer: OH,
 	metrics: Metrics,
 	spawn_handle: Option<SpawnTaskHandle>,
+	approval_checking_lag: ApprovalCheckingLag,
 }
 
 impl<B, OH> SelectRelayChainInner<B, OH>
@@ -245,7 +323,13 @@ where
 		metrics: Metrics,
 		spawn_handle: Option<SpawnTaskHandle>,
 	) -> Self {
-		SelectRelayChainInner { backend, overseer, metrics, spawn_handle }
+		SelectRelayChainInner {
+			backend,
+			overseer,
+			metrics,
+			spawn_handle,
+			approval_checking_lag: ApprovalCheckingLag::default(),
+		}
 	}
 
 	fn block_header(&self, hash: Hash) -> Result<PolkadotHeader, ConsensusError> {
@@ -284,6 +368,7 @@ where
 			overseer: self.overseer.clone(),
 			metrics: self.metrics.clone(),
 			spawn_handle: self.spawn_handle.clone(),
+			approval_checking_lag: self.approval_checking_lag.clone(),
 		}
 	}
 }
@@ -471,6 +556,7 @@ where
 
 		let lag = initial_leaf_number.saturating_sub(subchain_number);
 		self.metrics.note_approval_checking_finality_lag(lag);
+		self.approval_checking_lag.set(lag);
 
 		// Messages sent to `approval-distrbution` are known to have high `ToF`, we need to spawn a
 		// task for sending the message to not block here and delay finality.
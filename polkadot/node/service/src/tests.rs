@@ -765,3 +765,25 @@ fn chain_sel_5_best_is_target_hash() {
 fn chain_sel_6_approval_lag() {
 	run_specialized_test_w_harness(chain_6);
 }
+
+#[test]
+fn approval_checking_lag_is_provided_once_measured() {
+	use sp_inherents::InherentDataProvider;
+
+	let lag = ApprovalCheckingLag::default();
+	let provide = |lag: Option<&ApprovalCheckingLag>| {
+		futures::executor::block_on(
+			ApprovalCheckingLagInherentDataProvider::new(lag).create_inherent_data(),
+		)
+		.unwrap()
+		.get_data::<BlockNumber>(&polkadot_primitives::vstaging::FINALITY_LAG_INHERENT_IDENTIFIER)
+		.unwrap()
+	};
+
+	assert_eq!(provide(None), None);
+	assert_eq!(provide(Some(&lag)), None);
+
+	lag.clone().set(7);
+	assert_eq!(lag.get(), Some(7));
+	assert_eq!(provide(Some(&lag)), Some(7));
+}
@@ -720,6 +720,10 @@ pub enum RuntimeApiRequest {
 	AsyncBackingParams(RuntimeApiSender<async_backing::AsyncBackingParams>),
 	/// Get the node features.
 	NodeFeatures(SessionIndex, RuntimeApiSender<NodeFeatures>),
+	/// Get the asynchronous backing parameters of the given para, i.e. the global ones combined
+	/// with the para's override, if any.
+	/// `V13`
+	ParaAsyncBackingParams(ParaId, RuntimeApiSender<async_backing::AsyncBackingParams>),
 }
 
 impl RuntimeApiRequest {
@@ -751,6 +755,12 @@ impl RuntimeApiRequest {
 
 	/// `Node features`
 	pub const NODE_FEATURES_RUNTIME_REQUIREMENT: u32 = 9;
+
+	/// `ParaAsyncBackingParams`
+	pub const PARA_ASYNC_BACKING_PARAMS_RUNTIME_REQUIREMENT: u32 = 13;
+
+	/// `UnappliedSlashes` including slashes of the `AgainstValidNarrowly` kind.
+	pub const UNAPPLIED_SLASHES_V2_RUNTIME_REQUIREMENT: u32 = 15;
 }
 
 /// A message to the Runtime API subsystem.
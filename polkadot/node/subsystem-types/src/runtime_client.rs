@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::messages::RuntimeApiRequest;
 use async_trait::async_trait;
 use polkadot_primitives::{
 	async_backing, runtime_api::ParachainHost, slashing, vstaging, Block, BlockNumber,
@@ -265,6 +266,17 @@ pub trait RuntimeApiSubsystemClient {
 
 	/// Get the node features.
 	async fn node_features(&self, at: Hash) -> Result<vstaging::NodeFeatures, ApiError>;
+
+	// === v13 ===
+
+	/// Returns the asynchronous backing parameters of the given para, combining the global ones
+	/// with the para's override, if any.
+	/// This is a staging method! Do not use on production runtimes!
+	async fn para_async_backing_params(
+		&self,
+		at: Hash,
+		para_id: Id,
+	) -> Result<async_backing::AsyncBackingParams, ApiError>;
 }
 
 /// Default implementation of [`RuntimeApiSubsystemClient`] using the client.
@@ -465,7 +477,14 @@ where
 		&self,
 		at: Hash,
 	) -> Result<Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>, ApiError> {
-		self.client.runtime_api().unapplied_slashes(at)
+		let runtime_api = self.client.runtime_api();
+		let version = runtime_api.api_version::<dyn ParachainHost<Block>>(at)?.unwrap_or_default();
+
+		if version >= RuntimeApiRequest::UNAPPLIED_SLASHES_V2_RUNTIME_REQUIREMENT {
+			runtime_api.unapplied_slashes_v2(at)
+		} else {
+			runtime_api.unapplied_slashes(at)
+		}
 	}
 
 	async fn key_ownership_proof(
@@ -521,4 +540,12 @@ where
 	async fn disabled_validators(&self, at: Hash) -> Result<Vec<ValidatorIndex>, ApiError> {
 		self.client.runtime_api().disabled_validators(at)
 	}
+
+	async fn para_async_backing_params(
+		&self,
+		at: Hash,
+		para_id: Id,
+	) -> Result<async_backing::AsyncBackingParams, ApiError> {
+		self.client.runtime_api().para_async_backing_params(at, para_id)
+	}
 }
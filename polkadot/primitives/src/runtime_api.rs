@@ -115,7 +115,7 @@
 
 use crate::{
 	async_backing, slashing, vstaging, AsyncBackingParams, BlockNumber, CandidateCommitments,
-	CandidateEvent, CandidateHash, CommittedCandidateReceipt, CoreState, DisputeState,
+	CandidateEvent, CandidateHash, CommittedCandidateReceipt, CoreIndex, CoreState, DisputeState,
 	ExecutorParams, GroupRotationInfo, OccupiedCoreAssumption, PersistedValidationData,
 	PvfCheckStatement, ScrapedOnChainVotes, SessionIndex, SessionInfo, ValidatorId, ValidatorIndex,
 	ValidatorSignature,
@@ -224,6 +224,7 @@ sp_api::decl_runtime_apis! {
 		fn session_executor_params(session_index: SessionIndex) -> Option<ExecutorParams>;
 
 		/// Returns a list of validators that lost a past session dispute and need to be slashed.
+		/// Slashes of the `AgainstValidNarrowly` kind are omitted, see `unapplied_slashes_v2`.
 		/// NOTE: This function is only available since parachain host version 5.
 		fn unapplied_slashes() -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>;
 
@@ -271,5 +272,72 @@ sp_api::decl_runtime_apis! {
 		/// This is a staging method! Do not use on production runtimes!
 		#[api_version(9)]
 		fn node_features() -> vstaging::NodeFeatures;
+
+		/***** Added in v10 *****/
+
+		/// Returns whether the given para is currently allowed to upgrade its validation code, or
+		/// `None` if the para is not registered.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(10)]
+		fn para_upgrade_status(para_id: ppp::Id) -> Option<vstaging::UpgradeStatus<N>>;
+
+		/***** Added in v11 *****/
+
+		/// Returns the current delivery fee factor of the upward messages of the given para.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(11)]
+		fn upward_delivery_fee_factor(para_id: ppp::Id) -> sp_arithmetic::FixedU128;
+
+		/***** Added in v12 *****/
+
+		/// Returns the backing group assigned to every availability core at the next block.
+		///
+		/// The assignments are valid until the `until` block of each entry, or until the end of
+		/// the current session, whichever comes first.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(12)]
+		fn backing_groups() -> Vec<vstaging::CoreBackingGroup<N>>;
+
+		/// Returns the backing group assigned to the given core at the next block, followed by
+		/// the group assigned to it after the next group rotation. Empty if the core is unknown.
+		///
+		/// The second entry is only a prediction: it is invalidated if a new session starts before
+		/// the rotation happens.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(12)]
+		fn validator_groups_for_core(core: CoreIndex) -> Vec<vstaging::CoreBackingGroup<N>>;
+
+		/***** Added in v13 *****/
+
+		/// Returns the asynchronous backing parameters of the given para, combining the global
+		/// ones with the para's override, if any.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(13)]
+		fn para_async_backing_params(para_id: ppp::Id) -> AsyncBackingParams;
+
+		/***** Added in v14 *****/
+
+		/// Returns the inclusive range of sessions for which `SessionInfo` is stored, as
+		/// `(earliest, latest)`. Queries for sessions outside of it return `None`.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(14)]
+		fn stored_session_info_range() -> (SessionIndex, SessionIndex);
+
+		/***** Added in v15 *****/
+
+		/// Returns a list of validators that lost a past session dispute and need to be slashed,
+		/// including slashes of the `AgainstValidNarrowly` kind, which nodes built before this
+		/// version cannot decode.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(15)]
+		fn unapplied_slashes_v2() -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>;
+
+		/***** Added in v16 *****/
+
+		/// Returns the number of candidates of the para which were backed but never included,
+		/// because they timed out, were disputed or were pending availability at a session change.
+		/// This is a staging method! Do not use on production runtimes!
+		#[api_version(16)]
+		fn backed_not_included(para_id: ppp::Id) -> u32;
 	}
 }
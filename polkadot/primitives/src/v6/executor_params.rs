@@ -21,12 +21,18 @@
 //! by the first element of the vector). Decoding to a usable semantics structure is
 //! done in `polkadot-node-core-pvf`.
 
-use crate::{BlakeTwo256, HashT as _, PvfExecTimeoutKind, PvfPrepTimeoutKind};
+use crate::{BlakeTwo256, HashT as _, Id, PvfExecTimeoutKind, PvfPrepTimeoutKind};
 use parity_scale_codec::{Decode, Encode};
 use polkadot_core_primitives::Hash;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
-use sp_std::{collections::btree_map::BTreeMap, ops::Deref, time::Duration, vec, vec::Vec};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	ops::Deref,
+	time::Duration,
+	vec,
+	vec::Vec,
+};
 
 /// Default maximum number of wasm values allowed for the stack during execution of a PVF.
 pub const DEFAULT_LOGICAL_STACK_MAX: u32 = 65536;
@@ -65,7 +71,20 @@ const DEFAULT_BACKING_EXECUTION_TIMEOUT_MS: u64 =
 const DEFAULT_APPROVAL_EXECUTION_TIMEOUT_MS: u64 =
 	DEFAULT_APPROVAL_EXECUTION_TIMEOUT.as_millis() as u64;
 
+/// Limits on the decompression of the proof of validity of a candidate, protecting validators from
+/// decompression bombs.
+#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, Serialize, Deserialize)]
+pub struct PovDecompressionLimits {
+	/// Maximum size of the decompressed PoV, in bytes. Never exceeds the node's own bomb limit.
+	pub max_size: u32,
+	/// Maximum ratio between the decompressed and the compressed size of the PoV.
+	pub max_ratio: u32,
+}
+
 /// The different executor parameters for changing the execution environment semantics.
+///
+/// Every parameter either affects PVF preparation or only its execution. New parameters must be
+/// classified accordingly in [`ExecutorParams::prep_hash`].
 #[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, Serialize, Deserialize)]
 pub enum ExecutorParam {
 	/// Maximum number of memory pages (64KiB bytes per page) the executor can allocate.
@@ -108,6 +127,16 @@ pub enum ExecutorParam {
 	/// Enables WASM bulk memory proposal
 	#[codec(index = 7)]
 	WasmExtBulkMemory,
+	/// PoV decompression limits applying to the parachains without specific limits.
+	/// Both limits must be non-zero. Only accepted by the runtime once the
+	/// `PovDecompressionLimits` node feature is enabled.
+	#[codec(index = 8)]
+	DefaultPovDecompressionLimits(PovDecompressionLimits),
+	/// PoV decompression limits applying to the given parachain, overriding
+	/// `DefaultPovDecompressionLimits`. Both limits must be non-zero. Only accepted by the
+	/// runtime once the `PovDecompressionLimits` node feature is enabled.
+	#[codec(index = 9)]
+	ParaPovDecompressionLimits(Id, PovDecompressionLimits),
 }
 
 /// Possible inconsistencies of executor params.
@@ -152,6 +181,38 @@ impl sp_std::fmt::LowerHex for ExecutorParamsHash {
 	}
 }
 
+/// Unit type wrapper around [`type@Hash`] that represents a hash of preparation-related
+/// executor parameters.
+///
+/// This type is produced by [`ExecutorParams::prep_hash`].
+#[derive(Clone, Copy, Encode, Decode, Hash, Eq, PartialEq, PartialOrd, Ord, TypeInfo)]
+pub struct ExecutorParamsPrepHash(Hash);
+
+impl ExecutorParamsPrepHash {
+	/// Create a new executor parameter preparation hash from `H256` hash
+	pub fn from_hash(hash: Hash) -> Self {
+		Self(hash)
+	}
+}
+
+impl sp_std::fmt::Display for ExecutorParamsPrepHash {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl sp_std::fmt::Debug for ExecutorParamsPrepHash {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		write!(f, "{:?}", self.0)
+	}
+}
+
+impl sp_std::fmt::LowerHex for ExecutorParamsPrepHash {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		sp_std::fmt::LowerHex::fmt(&self.0, f)
+	}
+}
+
 /// # Deterministically serialized execution environment semantics
 /// Represents an arbitrary semantics of an arbitrary execution environment, so should be kept as
 /// abstract as possible.
@@ -173,6 +234,34 @@ impl ExecutorParams {
 		ExecutorParamsHash(BlakeTwo256::hash(&self.encode()))
 	}
 
+	/// Returns hash of the subset of execution environment parameters that affect PVF
+	/// preparation.
+	///
+	/// Parameters that are only used when executing a prepared artifact, such as execution
+	/// timeouts, are left out, so changing them does not invalidate prepared artifacts. For a set
+	/// without such parameters the result equals [`Self::hash`].
+	pub fn prep_hash(&self) -> ExecutorParamsPrepHash {
+		use ExecutorParam::*;
+
+		let prep_params = self
+			.0
+			.iter()
+			.filter(|param| match param {
+				MaxMemoryPages(_) |
+				StackLogicalMax(_) |
+				StackNativeMax(_) |
+				PrecheckingMaxMemory(_) |
+				PvfPrepTimeout(..) |
+				WasmExtBulkMemory => true,
+				PvfExecTimeout(..) |
+				DefaultPovDecompressionLimits(_) |
+				ParaPovDecompressionLimits(..) => false,
+			})
+			.collect::<Vec<_>>();
+
+		ExecutorParamsPrepHash(BlakeTwo256::hash(&prep_params.encode()))
+	}
+
 	/// Returns a PVF preparation timeout, if any
 	pub fn pvf_prep_timeout(&self, kind: PvfPrepTimeoutKind) -> Option<Duration> {
 		for param in &self.0 {
@@ -207,12 +296,27 @@ impl ExecutorParams {
 		None
 	}
 
+	/// Returns the PoV decompression limits applying to `para_id`, if any
+	pub fn pov_decompression_limits(&self, para_id: Id) -> Option<PovDecompressionLimits> {
+		let mut default = None;
+		for param in &self.0 {
+			match param {
+				ExecutorParam::ParaPovDecompressionLimits(id, limits) if *id == para_id =>
+					return Some(*limits),
+				ExecutorParam::DefaultPovDecompressionLimits(limits) => default = Some(*limits),
+				_ => {},
+			}
+		}
+		default
+	}
+
 	/// Check params coherence.
 	pub fn check_consistency(&self) -> Result<(), ExecutorParamError> {
 		use ExecutorParam::*;
 		use ExecutorParamError::*;
 
 		let mut seen = BTreeMap::<&str, u64>::new();
+		let mut seen_paras = BTreeSet::<Id>::new();
 
 		macro_rules! check {
 			($param:ident, $val:expr $(,)?) => {
@@ -250,6 +354,8 @@ impl ExecutorParams {
 					PvfExecTimeoutKind::Approval => "PvfExecTimeoutKind::Approval",
 				},
 				WasmExtBulkMemory => "WasmExtBulkMemory",
+				DefaultPovDecompressionLimits(_) => "DefaultPovDecompressionLimits",
+				ParaPovDecompressionLimits(..) => "ParaPovDecompressionLimits",
 			};
 
 			match *param {
@@ -284,6 +390,19 @@ impl ExecutorParams {
 				WasmExtBulkMemory => {
 					check!(param_ident, 1);
 				},
+
+				DefaultPovDecompressionLimits(limits) => {
+					check!(param_ident, 1, limits.max_size == 0 || limits.max_ratio == 0);
+				},
+
+				ParaPovDecompressionLimits(para_id, limits) => {
+					if !seen_paras.insert(para_id) {
+						return Err(DuplicatedParam(param_ident))
+					}
+					if limits.max_size == 0 || limits.max_ratio == 0 {
+						return Err(OutsideLimit(param_ident))
+					}
+				},
 			}
 		}
 
@@ -30,6 +30,11 @@ pub enum SlashingOffenceKind {
 	/// A minor offence when a validator disputed a valid block.
 	#[codec(index = 1)]
 	AgainstValid,
+	/// A minor offence when a validator disputed a valid block, but a significant
+	/// share of the validator set disputed it as well. This usually points at
+	/// time-related flakiness, e.g. slow hardware, rather than malice.
+	#[codec(index = 2)]
+	AgainstValidNarrowly,
 }
 
 /// Timeslots should uniquely identify offences and are used for the offence
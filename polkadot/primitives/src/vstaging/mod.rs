@@ -18,7 +18,85 @@
 
 // Put any primitives used by staging APIs functions here
 
+use crate::{
+	BlockNumber, CoreIndex, GroupIndex, UpgradeRestriction, ValidationCodeHash, ValidatorIndex,
+};
 use bitvec::vec::BitVec;
+use inherents::InherentIdentifier;
+use parity_scale_codec::{Decode, Encode};
+use primitives::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
 
 /// Bit indices in the `HostConfiguration.node_features` that correspond to different node features.
 pub type NodeFeatures = BitVec<u8, bitvec::order::Lsb0>;
+
+/// Module containing feature-specific bit indices into the `NodeFeatures` bitvec.
+pub mod node_features {
+	/// A feature index used to identify a bit into the node_features array stored
+	/// in the HostConfiguration.
+	#[repr(u8)]
+	pub enum FeatureIndex {
+		/// Tells if tranche0 assignments could be sent in a single certificate covering
+		/// multiple cores.
+		EnableAssignmentsV2 = 0,
+		/// Tells if the PoV decompression limits executor parameters may be set, i.e. whether all
+		/// validators are able to decode and enforce them.
+		PovDecompressionLimits = 1,
+		/// First unassigned feature bit.
+		/// Every time a new feature flag is assigned it should take this value
+		/// and this should be incremented.
+		FirstUnassigned = 2,
+	}
+}
+
+/// Unique identifier for the approval-checking finality lag inherent.
+pub const FINALITY_LAG_INHERENT_IDENTIFIER: InherentIdentifier = *b"finlag00";
+
+/// The status of a para with regards to upgrading its validation code.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct UpgradeStatus<N = BlockNumber> {
+	/// The upgrade restriction signalled to the para, if any.
+	pub restriction: Option<UpgradeRestriction>,
+	/// The relay chain block number at which the cooldown following the previous upgrade ends, if
+	/// the para is still in cooldown.
+	pub cooldown_until: Option<N>,
+	/// The upgrade which is currently in progress, if any.
+	pub pending_upgrade: Option<PendingUpgrade<N>>,
+}
+
+impl<N> UpgradeStatus<N> {
+	/// Whether the para is currently allowed to upgrade its validation code.
+	pub fn can_upgrade(&self) -> bool {
+		self.restriction.is_none() && self.pending_upgrade.is_none()
+	}
+}
+
+/// A validation code upgrade of a para which is in progress.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PendingUpgrade<N = BlockNumber> {
+	/// The hash of the new validation code.
+	pub code_hash: ValidationCodeHash,
+	/// The relay chain block number at which the upgrade is expected to be applied, or `None` if
+	/// the new validation code is still being pre-checked.
+	pub expected_at: Option<N>,
+}
+
+/// The backing group assigned to an availability core for a range of relay chain blocks.
+///
+/// The range ends at the next group rotation. Validator groups are only reshuffled at session
+/// boundaries, so an assignment is stable for the remainder of the session in which it was
+/// queried, unless the session ends before `until`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CoreBackingGroup<N = BlockNumber> {
+	/// The index of the availability core.
+	pub core: CoreIndex,
+	/// The index of the group responsible for backing candidates on the core.
+	pub group: GroupIndex,
+	/// The validators of the group.
+	pub validators: Vec<ValidatorIndex>,
+	/// The relay chain block number from which on the group is assigned to the core.
+	pub from: N,
+	/// The relay chain block number at which the group is rotated off the core.
+	pub until: N,
+}
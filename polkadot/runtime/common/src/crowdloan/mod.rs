@@ -877,6 +877,7 @@ mod tests {
 		traits::{AuctionStatus, OnSwap},
 	};
 	use ::test_helpers::{dummy_head_data, dummy_validation_code};
+	use frame_support::derive_impl;
 	use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup, TrailingZeroInput},
@@ -900,6 +901,7 @@ mod tests {
 
 	type BlockNumber = u64;
 
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 	impl frame_system::Config for Test {
 		type BaseCallFilter = frame_support::traits::Everything;
 		type BlockWeights = ();
@@ -87,6 +87,11 @@ pub mod pallet {
 		/// A handler for what to do when an identity is reaped.
 		type ReapIdentityHandler: OnReapIdentity<Self::AccountId>;
 
+		/// The origin that can update deposits, in addition to root. Expected to be
+		/// `EnsureSigned<AccountId>` on the People Chain, so that anyone can reconcile the deposits
+		/// of accounts migrated with an estimated remote deposit.
+		type DepositPoker: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for the extrinsics in the pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -130,19 +135,29 @@ pub mod pallet {
 			Ok(post)
 		}
 
-		/// Update the deposit of `who`. Meant to be called by the system with an XCM `Transact`
-		/// Instruction.
+		/// Update the deposit of `who` to the one required under the current pricing, releasing or
+		/// reserving the difference. Meant to be called by the system with an XCM `Transact`
+		/// Instruction, or by anyone through `DepositPoker`.
+		///
+		/// The call is free if any deposit was updated.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::poke_deposit())]
 		pub fn poke_deposit(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::DepositPoker::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+			let old_id_deposit = pallet_identity::Pallet::<T>::identity(&who)
+				.map(|registration| registration.deposit)
+				.unwrap_or_default();
+			let (old_subs_deposit, _) = pallet_identity::Pallet::<T>::subs_of(&who);
+
 			let (id_deposit, subs_deposit) = pallet_identity::Pallet::<T>::poke_deposit(&who)?;
 			Self::deposit_event(Event::DepositUpdated {
 				who,
 				identity: id_deposit,
 				subs: subs_deposit,
 			});
-			Ok(Pays::No.into())
+
+			let updated = id_deposit != old_id_deposit || subs_deposit != old_subs_deposit;
+			Ok(if updated { Pays::No } else { Pays::Yes }.into())
 		}
 	}
 }
@@ -228,7 +243,7 @@ mod benchmarks {
 			let registrar_lookup = T::Lookup::unlookup(registrar.clone());
 			let _ = <T as pallet_identity::Config>::Currency::make_free_balance_be(
 				&registrar,
-				<T as pallet_identity::Config>::Currency::minimum_balance(),
+				<<T as pallet_identity::Config>::Currency as Currency<T::AccountId>>::minimum_balance(),
 			);
 
 			// add registrar
@@ -31,7 +31,7 @@ use frame_support::{
 	PalletId,
 };
 use frame_support_test::TestRandomness;
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_identity::{self, legacy::IdentityInfo};
 use parity_scale_codec::Encode;
 use primitives::{
@@ -91,7 +91,7 @@ frame_support::construct_runtime!(
 		Slots: slots::{Pallet, Call, Storage, Event<T>},
 
 		// Migrators
-		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>},
+		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>, HoldReason},
 		IdentityMigrator: identity_migrator::{Pallet, Call, Event<T>},
 	}
 );
@@ -105,6 +105,7 @@ where
 }
 
 use crate::{auctions::Error as AuctionsError, crowdloan::Error as CrowdloanError};
+use frame_support::derive_impl;
 
 parameter_types! {
 	pub const BlockHashCount: u32 = 250;
@@ -114,6 +115,7 @@ parameter_types! {
 		);
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = BlockWeights;
@@ -188,7 +190,7 @@ impl pallet_balances::Config for Test {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type FreezeIdentifier = ();
-	type MaxHolds = ConstU32<0>;
+	type MaxHolds = ConstU32<1>;
 	type MaxFreezes = ConstU32<0>;
 }
 
@@ -256,6 +258,7 @@ impl slots::Config for Test {
 	type LeasePeriod = LeasePeriod;
 	type LeaseOffset = LeaseOffset;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type CoretimeAssigner = ();
 	type WeightInfo = crate::slots::TestWeightInfo;
 }
 
@@ -279,11 +282,15 @@ impl crowdloan::Config for Test {
 	type WeightInfo = crate::crowdloan::TestWeightInfo;
 }
 
+parameter_types! {
+	pub static IdentityBasicDeposit: Balance = 100;
+}
+
 impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type Slashed = ();
-	type BasicDeposit = ConstU32<100>;
+	type BasicDeposit = IdentityBasicDeposit;
 	type ByteDeposit = ConstU32<10>;
 	type SubAccountDeposit = ConstU32<100>;
 	type MaxSubAccounts = ConstU32<2>;
@@ -291,6 +298,8 @@ impl pallet_identity::Config for Test {
 	type MaxRegistrars = ConstU32<20>;
 	type RegistrarOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type JudgementRequestExpiry = ConstU32<100>;
 	type WeightInfo = ();
 }
 
@@ -298,6 +307,7 @@ impl identity_migrator::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ();
+	type DepositPoker = EnsureSigned<AccountId>;
 	type WeightInfo = crate::identity_migrator::TestWeightInfo;
 }
 
@@ -1725,3 +1735,50 @@ fn cant_bid_on_existing_lease_periods() {
 		));
 	});
 }
+
+#[test]
+fn identity_deposit_can_be_poked_by_anyone() {
+	use frame_support::{dispatch::Pays, traits::ReservableCurrency};
+	use pallet_identity::Data;
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account_id(1), 1_000_000);
+		let info = IdentityInfo {
+			additional: Default::default(),
+			display: Data::Raw(b"alice".to_vec().try_into().unwrap()),
+			legal: Data::None,
+			web: Data::None,
+			riot: Data::None,
+			email: Data::None,
+			pgp_fingerprint: None,
+			image: Data::None,
+			twitter: Data::None,
+		};
+		assert_ok!(Identity::set_identity(signed(1), Box::new(info)));
+		let deposit = Balances::reserved_balance(&account_id(1));
+
+		// The deposit is already correct, so the caller pays for the call.
+		let post = IdentityMigrator::poke_deposit(signed(2), account_id(1)).unwrap();
+		assert_eq!(post.pays_fee, Pays::Yes);
+		assert_eq!(Balances::reserved_balance(&account_id(1)), deposit);
+
+		// The pricing changes, the difference is released for free.
+		IdentityBasicDeposit::set(40);
+		let post = IdentityMigrator::poke_deposit(signed(2), account_id(1)).unwrap();
+		assert_eq!(post.pays_fee, Pays::No);
+		assert_eq!(Balances::reserved_balance(&account_id(1)), deposit - 60);
+		System::assert_last_event(
+			identity_migrator::Event::<Test>::DepositUpdated {
+				who: account_id(1),
+				identity: deposit - 60,
+				subs: 0,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			IdentityMigrator::poke_deposit(signed(2), account_id(3)),
+			pallet_identity::Error::<Test>::NoIdentity
+		);
+	});
+}
@@ -24,16 +24,21 @@
 
 pub mod migration;
 
-use crate::traits::{LeaseError, Leaser, Registrar};
+use crate::traits::{LeaseError, Leaser, LegacyLeaseAssigner, Registrar};
 use frame_support::{
 	pallet_prelude::*,
+	storage::StoragePrefixedMap,
 	traits::{Currency, ReservableCurrency},
 	weights::Weight,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
+use parity_scale_codec::Codec;
 use primitives::Id as ParaId;
-use sp_runtime::traits::{CheckedConversion, CheckedSub, Saturating, Zero};
+use sp_runtime::{
+	traits::{CheckedConversion, CheckedSub, Saturating, Zero},
+	SaturatedConversion,
+};
 use sp_std::prelude::*;
 
 type BalanceOf<T> =
@@ -45,6 +50,7 @@ pub trait WeightInfo {
 	fn manage_lease_period_start(c: u32, t: u32) -> Weight;
 	fn clear_all_leases() -> Weight;
 	fn trigger_onboard() -> Weight;
+	fn start_coretime_migration() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -61,6 +67,9 @@ impl WeightInfo for TestWeightInfo {
 	fn trigger_onboard() -> Weight {
 		Weight::zero()
 	}
+	fn start_coretime_migration() -> Weight {
+		Weight::zero()
+	}
 }
 
 #[frame_support::pallet]
@@ -93,6 +102,11 @@ pub mod pallet {
 		/// The origin which may forcibly create or clear leases. Root can always do this.
 		type ForceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Assigns coretime to lease holding parachains when their leases are migrated to
+		/// coretime. Use `()` if no coretime is available, in which case no lease can be migrated
+		/// and `start_coretime_migration` has no effect besides `LeaseMigrationFailed` events.
+		type CoretimeAssigner: LegacyLeaseAssigner<BlockNumberFor<Self>>;
+
 		/// Weight Information for the Extrinsics in the Pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -118,6 +132,11 @@ pub mod pallet {
 	pub type Leases<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, Vec<Option<(T::AccountId, BalanceOf<T>)>>, ValueQuery>;
 
+	/// The raw storage key of the last entry of [`Leases`] migrated to coretime, if a migration
+	/// of the leases to coretime is ongoing.
+	#[pallet::storage]
+	pub type CoretimeMigrationCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -134,6 +153,19 @@ pub mod pallet {
 			extra_reserved: BalanceOf<T>,
 			total_amount: BalanceOf<T>,
 		},
+		/// The migration of all leases to coretime has started.
+		CoretimeMigrationStarted,
+		/// The lease of a para has been migrated to coretime, which is assigned from block `begin`
+		/// until block `until`.
+		LeaseMigratedToCoretime {
+			para_id: ParaId,
+			begin: BlockNumberFor<T>,
+			until: BlockNumberFor<T>,
+		},
+		/// The lease of a para could not be migrated to coretime.
+		LeaseMigrationFailed { para_id: ParaId, error: DispatchError },
+		/// All leases have been migrated to coretime.
+		CoretimeMigrationCompleted,
 	}
 
 	#[pallet::error]
@@ -142,6 +174,8 @@ pub mod pallet {
 		ParaNotOnboarding,
 		/// There was an error with the lease.
 		LeaseError,
+		/// A migration of the leases to coretime is already ongoing.
+		CoretimeMigrationOngoing,
 	}
 
 	#[pallet::hooks]
@@ -157,6 +191,10 @@ pub mod pallet {
 			// We didn't return early above, so we didn't do anything.
 			Weight::zero()
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::migrate_leases_to_coretime(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -221,6 +259,23 @@ pub mod pallet {
 			};
 			Ok(())
 		}
+
+		/// Start migrating all current and future leases to coretime.
+		///
+		/// The leases are handed to `T::CoretimeAssigner` in batches during `on_idle`, as far as
+		/// the remaining block weight allows. The leases themselves and their deposits are left
+		/// untouched and keep being managed by this pallet until they end.
+		///
+		/// The dispatch origin for this call must match `T::ForceOrigin`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::start_coretime_migration())]
+		pub fn start_coretime_migration(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!CoretimeMigrationCursor::<T>::exists(), Error::<T>::CoretimeMigrationOngoing);
+			CoretimeMigrationCursor::<T>::put(Leases::<T>::final_prefix().to_vec());
+			Self::deposit_event(Event::<T>::CoretimeMigrationStarted);
+			Ok(())
+		}
 	}
 }
 
@@ -306,6 +361,84 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// The number of lease periods, including the current one, until the lease of `para` ends.
+	pub fn remaining_lease_periods(para: ParaId) -> LeasePeriodOf<T> {
+		Leases::<T>::decode_len(para).unwrap_or_default().saturated_into()
+	}
+
+	/// The remaining lease periods of all paras holding a current or future lease.
+	pub fn all_remaining_lease_periods() -> Vec<(ParaId, LeasePeriodOf<T>)> {
+		Leases::<T>::iter()
+			.map(|(para, leases)| (para, leases.len().saturated_into()))
+			.collect()
+	}
+
+	/// The first block of the given lease period.
+	fn lease_period_start(lease_period: LeasePeriodOf<T>) -> BlockNumberFor<T> {
+		lease_period
+			.saturating_mul(T::LeasePeriod::get())
+			.saturating_add(T::LeaseOffset::get())
+	}
+
+	/// Migrate the leases to coretime, continuing after the lease migrated last, until either all
+	/// leases are migrated or `weight_limit` is reached.
+	///
+	/// Returns the weight used.
+	fn migrate_leases_to_coretime(weight_limit: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let mut weight_used = db_weight.reads(1);
+		if weight_limit.any_lt(weight_used) {
+			return Weight::zero()
+		}
+		let Some(cursor) = CoretimeMigrationCursor::<T>::get() else { return weight_used };
+		let now = frame_system::Pallet::<T>::block_number();
+		let Some((current_lease_period, _)) = Self::lease_period_index(now) else {
+			return weight_used
+		};
+
+		// Updating or removing the cursor.
+		weight_used.saturating_accrue(db_weight.writes(1));
+		let lease_weight = db_weight
+			.reads(1)
+			.saturating_add(T::CoretimeAssigner::assign_legacy_lease_weight());
+		let mut leases = Leases::<T>::iter_from(cursor);
+		loop {
+			if weight_limit.any_lt(weight_used.saturating_add(lease_weight)) {
+				CoretimeMigrationCursor::<T>::put(leases.last_raw_key().to_vec());
+				return weight_used
+			}
+			weight_used.saturating_accrue(lease_weight);
+
+			let Some((para, lease_periods)) = leases.next() else {
+				CoretimeMigrationCursor::<T>::kill();
+				Self::deposit_event(Event::<T>::CoretimeMigrationCompleted);
+				return weight_used
+			};
+			// Leading `None`s denote lease periods before a future lease begins.
+			let Some(first) = lease_periods.iter().position(Option::is_some) else { continue };
+			let begin = if first == 0 {
+				now
+			} else {
+				Self::lease_period_start(
+					current_lease_period.saturating_add(first.saturated_into()),
+				)
+			};
+			let until = Self::lease_period_start(
+				current_lease_period.saturating_add(lease_periods.len().saturated_into()),
+			);
+
+			match T::CoretimeAssigner::assign_legacy_lease(para, begin, until) {
+				Ok(()) => Self::deposit_event(Event::<T>::LeaseMigratedToCoretime {
+					para_id: para,
+					begin,
+					until,
+				}),
+				Err(error) =>
+					Self::deposit_event(Event::<T>::LeaseMigrationFailed { para_id: para, error }),
+			}
+		}
+	}
+
 	// Return a vector of (user, balance) for all deposits for a parachain.
 	// Useful when trying to clean up a parachain leases, as this would tell
 	// you all the balances you need to unreserve.
@@ -498,6 +631,17 @@ impl<T: Config> Leaser<BlockNumberFor<T>> for Pallet<T> {
 	}
 }
 
+sp_api::decl_runtime_apis! {
+	/// API for querying the leases held by paras.
+	pub trait SlotsApi<LeasePeriod> where LeasePeriod: Codec {
+		/// The number of lease periods, including the current one, until the lease of `para` ends.
+		fn remaining_lease_periods(para: ParaId) -> LeasePeriod;
+
+		/// The remaining lease periods of all paras holding a current or future lease.
+		fn all_remaining_lease_periods() -> Vec<(ParaId, LeasePeriod)>;
+	}
+}
+
 /// tests for this pallet
 #[cfg(test)]
 mod tests {
@@ -505,13 +649,13 @@ mod tests {
 
 	use crate::{mock::TestRegistrar, slots};
 	use ::test_helpers::{dummy_head_data, dummy_validation_code};
-	use frame_support::{assert_noop, assert_ok, parameter_types};
+	use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types};
 	use frame_system::EnsureRoot;
 	use pallet_balances;
 	use primitives::BlockNumber;
 	use sp_core::H256;
 	use sp_runtime::{
-		traits::{BlakeTwo256, IdentityLookup},
+		traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 		BuildStorage,
 	};
 
@@ -529,6 +673,7 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u32 = 250;
 	}
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 	impl frame_system::Config for Test {
 		type BaseCallFilter = frame_support::traits::Everything;
 		type BlockWeights = ();
@@ -582,6 +727,27 @@ mod tests {
 		pub const ParaDeposit: u64 = 1;
 	}
 
+	parameter_types! {
+		pub static CoretimeAssignments: Vec<(ParaId, BlockNumber, BlockNumber)> = vec![];
+	}
+
+	pub struct TestCoretimeAssigner;
+	impl LegacyLeaseAssigner<BlockNumber> for TestCoretimeAssigner {
+		fn assign_legacy_lease(
+			para: ParaId,
+			begin: BlockNumber,
+			until: BlockNumber,
+		) -> DispatchResult {
+			ensure!(para != ParaId::from(3_u32), DispatchError::Other("no core available"));
+			CoretimeAssignments::mutate(|assignments| assignments.push((para, begin, until)));
+			Ok(())
+		}
+
+		fn assign_legacy_lease_weight() -> Weight {
+			Weight::from_parts(10, 0)
+		}
+	}
+
 	impl Config for Test {
 		type RuntimeEvent = RuntimeEvent;
 		type Currency = Balances;
@@ -589,6 +755,7 @@ mod tests {
 		type LeasePeriod = LeasePeriod;
 		type LeaseOffset = LeaseOffset;
 		type ForceOrigin = EnsureRoot<Self::AccountId>;
+		type CoretimeAssigner = TestCoretimeAssigner;
 		type WeightInfo = crate::slots::TestWeightInfo;
 	}
 
@@ -983,6 +1150,79 @@ mod tests {
 			assert_eq!(Slots::lease_period_index(2 * lpl + offset + 1), Some((2, false)));
 		});
 	}
+
+	fn register_and_lease(para: u32, period_begin: BlockNumber, period_count: BlockNumber) {
+		assert_ok!(TestRegistrar::<Test>::register(
+			1,
+			ParaId::from(para),
+			dummy_head_data(),
+			dummy_validation_code()
+		));
+		assert_ok!(Slots::lease_out(para.into(), &1, 1, period_begin, period_count));
+	}
+
+	#[test]
+	fn remaining_lease_periods_works() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+			register_and_lease(1, 0, 2);
+			register_and_lease(2, 2, 1);
+
+			assert_eq!(Slots::remaining_lease_periods(1.into()), 2);
+			assert_eq!(Slots::remaining_lease_periods(2.into()), 3);
+			assert_eq!(Slots::remaining_lease_periods(3.into()), 0);
+			let mut all = Slots::all_remaining_lease_periods();
+			all.sort();
+			assert_eq!(all, vec![(1.into(), 2), (2.into(), 3)]);
+
+			run_to_block(10);
+			assert_eq!(Slots::remaining_lease_periods(1.into()), 1);
+			assert_eq!(Slots::remaining_lease_periods(2.into()), 2);
+		});
+	}
+
+	#[test]
+	fn coretime_migration_works() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+			register_and_lease(1, 0, 2);
+			register_and_lease(2, 2, 1);
+			register_and_lease(3, 1, 1);
+
+			// Nothing happens before the migration is started.
+			Slots::on_idle(1, Weight::MAX);
+			assert!(CoretimeAssignments::get().is_empty());
+
+			assert_noop!(Slots::start_coretime_migration(RuntimeOrigin::signed(1)), BadOrigin);
+			assert_ok!(Slots::start_coretime_migration(RuntimeOrigin::root()));
+			assert_noop!(
+				Slots::start_coretime_migration(RuntimeOrigin::root()),
+				Error::<Test>::CoretimeMigrationOngoing
+			);
+
+			// Only two leases fit into the first batch.
+			assert_eq!(Slots::on_idle(1, Weight::from_parts(25, 0)), Weight::from_parts(20, 0));
+			assert!(CoretimeMigrationCursor::<Test>::exists());
+			assert_eq!(Slots::on_idle(1, Weight::from_parts(25, 0)), Weight::from_parts(20, 0));
+			assert!(!CoretimeMigrationCursor::<Test>::exists());
+
+			let mut assignments = CoretimeAssignments::get();
+			assignments.sort();
+			assert_eq!(assignments, vec![(1.into(), 1, 20), (2.into(), 20, 30)]);
+			System::assert_has_event(
+				Event::<Test>::LeaseMigrationFailed {
+					para_id: 3.into(),
+					error: DispatchError::Other("no core available"),
+				}
+				.into(),
+			);
+			System::assert_last_event(Event::<Test>::CoretimeMigrationCompleted.into());
+
+			// The leases are left untouched.
+			assert_eq!(Slots::remaining_lease_periods(1.into()), 2);
+			assert_eq!(Balances::reserved_balance(1), 3);
+		});
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1160,6 +1400,15 @@ mod benchmarking {
 			assert!(T::Registrar::is_parachain(para));
 		}
 
+		start_coretime_migration {
+			let origin =
+				T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		}: _<T::RuntimeOrigin>(origin)
+		verify {
+			assert!(CoretimeMigrationCursor::<T>::exists());
+			assert_last_event::<T>(Event::<T>::CoretimeMigrationStarted.into());
+		}
+
 		impl_benchmark_test_suite!(
 			Slots,
 			crate::integration_tests::new_test_ext(),
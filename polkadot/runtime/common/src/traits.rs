@@ -19,8 +19,10 @@
 use frame_support::{
 	dispatch::DispatchResult,
 	traits::{Currency, ReservableCurrency},
+	weights::Weight,
 };
 use primitives::{HeadData, Id as ParaId, ValidationCode};
+use sp_runtime::DispatchError;
 use sp_std::vec::*;
 
 /// Parachain registration API.
@@ -263,3 +265,24 @@ pub trait OnSwap {
 	/// such as leases, deposits held and thread/chain nature are swapped.
 	fn on_swap(one: ParaId, other: ParaId);
 }
+
+/// Assignment of coretime to lease holding parachains, used to migrate their leases away from the
+/// slots pallet.
+pub trait LegacyLeaseAssigner<BlockNumber> {
+	/// Assign coretime to `para` from block `begin` until its lease ends at block `until`.
+	fn assign_legacy_lease(para: ParaId, begin: BlockNumber, until: BlockNumber) -> DispatchResult;
+
+	/// The weight of a single call to [`Self::assign_legacy_lease`].
+	fn assign_legacy_lease_weight() -> Weight;
+}
+
+/// No coretime is available, so no lease can be assigned.
+impl<BlockNumber> LegacyLeaseAssigner<BlockNumber> for () {
+	fn assign_legacy_lease(_: ParaId, _: BlockNumber, _: BlockNumber) -> DispatchResult {
+		Err(DispatchError::Other("no coretime assigner configured"))
+	}
+
+	fn assign_legacy_lease_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -26,7 +26,8 @@ use polkadot_parachain_primitives::primitives::{
 	MAX_HORIZONTAL_MESSAGE_NUM, MAX_UPWARD_MESSAGE_NUM,
 };
 use primitives::{
-	vstaging::NodeFeatures, AsyncBackingParams, Balance, ExecutorParamError, ExecutorParams,
+	vstaging::{node_features::FeatureIndex, NodeFeatures},
+	AsyncBackingParams, Balance, ExecutorParam, ExecutorParamError, ExecutorParams, Id as ParaId,
 	SessionIndex, LEGACY_MIN_BACKING_VOTES, MAX_CODE_SIZE, MAX_HEAD_DATA_SIZE, MAX_POV_SIZE,
 	ON_DEMAND_DEFAULT_QUEUE_MAX_SIZE,
 };
@@ -263,6 +264,11 @@ pub struct HostConfiguration<BlockNumber> {
 	pub minimum_backing_votes: u32,
 	/// Node features enablement.
 	pub node_features: NodeFeatures,
+	/// The number of past sessions for which session info is kept.
+	///
+	/// Session info is always kept for at least [`dispute_period`](Self::dispute_period)
+	/// sessions, so lower values (e.g. 0) have no effect.
+	pub session_info_retention: SessionIndex,
 }
 
 impl<BlockNumber: Default + From<u32>> Default for HostConfiguration<BlockNumber> {
@@ -315,6 +321,7 @@ impl<BlockNumber: Default + From<u32>> Default for HostConfiguration<BlockNumber
 			on_demand_ttl: 5u32.into(),
 			minimum_backing_votes: LEGACY_MIN_BACKING_VOTES,
 			node_features: NodeFeatures::EMPTY,
+			session_info_retention: 0,
 		}
 	}
 }
@@ -355,6 +362,9 @@ pub enum InconsistentError<BlockNumber> {
 	ZeroMinimumBackingVotes,
 	/// `executor_params` are inconsistent.
 	InconsistentExecutorParams { inner: ExecutorParamError },
+	/// `executor_params` set PoV decompression limits while the `PovDecompressionLimits` node
+	/// feature is disabled.
+	PovDecompressionLimitsNotEnabled,
 }
 
 impl<BlockNumber> HostConfiguration<BlockNumber>
@@ -443,6 +453,21 @@ where
 			return Err(InconsistentExecutorParams { inner })
 		}
 
+		let pov_decompression_limits_enabled = self
+			.node_features
+			.get(FeatureIndex::PovDecompressionLimits as usize)
+			.map_or(false, |enabled| *enabled);
+		if !pov_decompression_limits_enabled &&
+			self.executor_params.iter().any(|param| {
+				matches!(
+					param,
+					ExecutorParam::DefaultPovDecompressionLimits(_) |
+						ExecutorParam::ParaPovDecompressionLimits(..)
+				)
+			}) {
+			return Err(PovDecompressionLimitsNotEnabled)
+		}
+
 		Ok(())
 	}
 
@@ -467,6 +492,7 @@ pub trait WeightInfo {
 	fn set_config_with_executor_params() -> Weight;
 	fn set_config_with_perbill() -> Weight;
 	fn set_node_feature() -> Weight;
+	fn set_async_backing_params_override() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -495,6 +521,9 @@ impl WeightInfo for TestWeightInfo {
 	fn set_node_feature() -> Weight {
 		Weight::MAX
 	}
+	fn set_async_backing_params_override() -> Weight {
+		Weight::MAX
+	}
 }
 
 #[frame_support::pallet]
@@ -515,7 +544,7 @@ pub mod pallet {
 	/// v7-v8:  <https://github.com/paritytech/polkadot/pull/6969>
 	/// v8-v9:  <https://github.com/paritytech/polkadot/pull/7577>
 	/// v9-v10: <https://github.com/paritytech/polkadot-sdk/pull/2177>
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(11);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -557,6 +586,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type BypassConsistencyCheck<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Per-para overrides of the asynchronous backing parameters of the active configuration.
+	///
+	/// The active configuration bounds the overrides: each value of an override is capped by the
+	/// corresponding value of the active configuration. See [`Pallet::async_backing_params_for`].
+	#[pallet::storage]
+	pub(crate) type AsyncBackingParamsOverrides<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, AsyncBackingParams>;
+
+	/// Pending changes of the [`AsyncBackingParamsOverrides`], ordered by the session at which
+	/// they are applied. `None` removes the override of the para.
+	///
+	/// Like configuration changes, they are only applied at a session boundary, when the
+	/// allowed relay-parents are cleared.
+	#[pallet::storage]
+	pub(crate) type PendingAsyncBackingParamsOverrides<T: Config> =
+		StorageValue<_, Vec<(SessionIndex, ParaId, Option<AsyncBackingParams>)>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -1220,6 +1266,58 @@ pub mod pallet {
 				config.node_features.set(index, value);
 			})
 		}
+
+		/// Set the number of past sessions for which session info is kept.
+		#[pallet::call_index(55)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_u32(),
+			DispatchClass::Operational
+		))]
+		pub fn set_session_info_retention(
+			origin: OriginFor<T>,
+			new: SessionIndex,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::schedule_config_update(|config| {
+				config.session_info_retention = new;
+			})
+		}
+
+		/// Set or remove the override of the asynchronous backing parameters of a para.
+		///
+		/// Takes effect at the same session as a configuration change made now. The values of the
+		/// override must not exceed the ones of the active configuration.
+		#[pallet::call_index(54)]
+		#[pallet::weight((
+			T::WeightInfo::set_async_backing_params_override(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_async_backing_params_override(
+			origin: OriginFor<T>,
+			para: ParaId,
+			new: Option<AsyncBackingParams>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if let Some(new) = new {
+				let limits = Self::config().async_backing_params;
+				ensure!(
+					new.max_candidate_depth <= limits.max_candidate_depth &&
+						new.allowed_ancestry_len <= limits.allowed_ancestry_len,
+					Error::<T>::InvalidNewValue,
+				);
+			}
+
+			let scheduled_session = Self::scheduled_session();
+			PendingAsyncBackingParamsOverrides::<T>::mutate(|pending| {
+				// A later change of the same para at the same session replaces the earlier one.
+				pending.retain(|(session, pending_para, _)| {
+					*session != scheduled_session || *pending_para != para
+				});
+				pending.push((scheduled_session, para, new));
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -1245,6 +1343,21 @@ pub struct SessionChangeOutcome<BlockNumber> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The asynchronous backing parameters of `para`.
+	///
+	/// These are the ones of the active configuration, unless overridden for `para`. Overrides are
+	/// capped by the active configuration, which may have been lowered since they were set.
+	pub fn async_backing_params_for(para: ParaId) -> AsyncBackingParams {
+		let limits = Self::config().async_backing_params;
+		match AsyncBackingParamsOverrides::<T>::get(para) {
+			Some(params) => AsyncBackingParams {
+				max_candidate_depth: params.max_candidate_depth.min(limits.max_candidate_depth),
+				allowed_ancestry_len: params.allowed_ancestry_len.min(limits.allowed_ancestry_len),
+			},
+			None => limits,
+		}
+	}
+
 	/// Called by the initializer to initialize the configuration pallet.
 	pub(crate) fn initializer_initialize(_now: BlockNumberFor<T>) -> Weight {
 		Weight::zero()
@@ -1261,6 +1374,8 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn initializer_on_new_session(
 		session_index: &SessionIndex,
 	) -> SessionChangeOutcome<BlockNumberFor<T>> {
+		Self::apply_async_backing_params_overrides(session_index);
+
 		let pending_configs = <PendingConfigs<T>>::get();
 		let prev_config = ActiveConfig::<T>::get();
 
@@ -1293,6 +1408,24 @@ impl<T: Config> Pallet<T> {
 		SessionChangeOutcome { prev_config, new_config }
 	}
 
+	/// Apply the pending changes of the asynchronous backing parameters overrides which are due
+	/// at `session_index`.
+	fn apply_async_backing_params_overrides(session_index: &SessionIndex) {
+		let pending = PendingAsyncBackingParamsOverrides::<T>::get();
+		if pending.is_empty() {
+			return
+		}
+
+		let (due, future) = pending
+			.into_iter()
+			.partition::<Vec<_>, _>(|&(apply_at_session, _, _)| apply_at_session <= *session_index);
+		for (_, para, params) in due {
+			AsyncBackingParamsOverrides::<T>::set(para, params);
+		}
+
+		PendingAsyncBackingParamsOverrides::<T>::put(future);
+	}
+
 	/// Return the session index that should be used for any future scheduled changes.
 	fn scheduled_session() -> SessionIndex {
 		shared::Pallet::<T>::scheduled_session()
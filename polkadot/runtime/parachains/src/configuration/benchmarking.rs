@@ -17,7 +17,9 @@
 use crate::configuration::*;
 use frame_benchmarking::{benchmarks, BenchmarkError, BenchmarkResult};
 use frame_system::RawOrigin;
-use primitives::{ExecutorParam, ExecutorParams, PvfExecTimeoutKind, PvfPrepTimeoutKind};
+use primitives::{
+	AsyncBackingParams, ExecutorParam, ExecutorParams, PvfExecTimeoutKind, PvfPrepTimeoutKind,
+};
 use sp_runtime::traits::One;
 
 benchmarks! {
@@ -51,6 +53,13 @@ benchmarks! {
 
 	set_node_feature{}: set_node_feature(RawOrigin::Root, 255, true)
 
+	set_async_backing_params_override {
+		let params = ActiveConfig::<T>::get().async_backing_params;
+	}: _(RawOrigin::Root, ParaId::from(1000), Some(params))
+	verify {
+		assert_eq!(PendingAsyncBackingParamsOverrides::<T>::get().len(), 1);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(Default::default()),
@@ -17,6 +17,7 @@
 //! A module that is responsible for migration of storage.
 
 pub mod v10;
+pub mod v11;
 pub mod v6;
 pub mod v7;
 pub mod v8;
@@ -15,9 +15,12 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::mock::{new_test_ext, Configuration, ParasShared, RuntimeOrigin, Test};
+use crate::mock::{
+	new_test_ext, Configuration, MockGenesisConfig, ParasShared, RuntimeOrigin, Test,
+};
 use bitvec::{bitvec, prelude::Lsb0};
 use frame_support::{assert_err, assert_noop, assert_ok};
+use primitives::PovDecompressionLimits;
 
 fn on_new_session(session_index: SessionIndex) -> (HostConfiguration<u32>, HostConfiguration<u32>) {
 	ParasShared::set_session_index(session_index);
@@ -269,6 +272,37 @@ fn consistency_bypass_works() {
 	});
 }
 
+#[test]
+fn pov_decompression_limits_require_node_feature() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let limits = PovDecompressionLimits { max_size: 1024, max_ratio: 10 };
+		let executor_params =
+			ExecutorParams::from(&[ExecutorParam::DefaultPovDecompressionLimits(limits)][..]);
+
+		assert_err!(
+			Configuration::set_executor_params(RuntimeOrigin::root(), executor_params.clone()),
+			Error::<Test>::InvalidNewValue
+		);
+
+		assert_ok!(Configuration::set_node_feature(
+			RuntimeOrigin::root(),
+			FeatureIndex::PovDecompressionLimits as u8,
+			true
+		));
+		assert_ok!(Configuration::set_executor_params(RuntimeOrigin::root(), executor_params));
+
+		// The feature can't be disabled while the limits are set.
+		assert_err!(
+			Configuration::set_node_feature(
+				RuntimeOrigin::root(),
+				FeatureIndex::PovDecompressionLimits as u8,
+				false
+			),
+			Error::<Test>::InvalidNewValue
+		);
+	});
+}
+
 #[test]
 fn setting_pending_config_members() {
 	new_test_ext(Default::default()).execute_with(|| {
@@ -320,6 +354,7 @@ fn setting_pending_config_members() {
 			on_demand_ttl: 5u32,
 			minimum_backing_votes: 5,
 			node_features: bitvec![u8, Lsb0; 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1],
+			session_info_retention: 300,
 		};
 
 		Configuration::set_validation_upgrade_cooldown(
@@ -481,6 +516,11 @@ fn setting_pending_config_members() {
 		Configuration::set_node_feature(RuntimeOrigin::root(), 10, true).unwrap();
 		Configuration::set_node_feature(RuntimeOrigin::root(), 10, false).unwrap();
 		Configuration::set_node_feature(RuntimeOrigin::root(), 11, true).unwrap();
+		Configuration::set_session_info_retention(
+			RuntimeOrigin::root(),
+			new_config.session_info_retention,
+		)
+		.unwrap();
 
 		assert_eq!(PendingConfigs::<Test>::get(), vec![(shared::SESSION_DELAY, new_config)],);
 	})
@@ -493,6 +533,94 @@ fn non_root_cannot_set_config() {
 	});
 }
 
+#[test]
+fn async_backing_params_overrides_are_bounded_by_active_config() {
+	let params = |max_candidate_depth, allowed_ancestry_len| AsyncBackingParams {
+		max_candidate_depth,
+		allowed_ancestry_len,
+	};
+	let genesis_config = MockGenesisConfig {
+		configuration: GenesisConfig {
+			config: HostConfiguration { async_backing_params: params(3, 2), ..Default::default() },
+		},
+		..Default::default()
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		let para = ParaId::from(100);
+		assert_eq!(Configuration::async_backing_params_for(para), params(3, 2));
+
+		assert_noop!(
+			Configuration::set_async_backing_params_override(
+				RuntimeOrigin::signed(1),
+				para,
+				Some(params(1, 1)),
+			),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			Configuration::set_async_backing_params_override(
+				RuntimeOrigin::root(),
+				para,
+				Some(params(4, 1)),
+			),
+			Error::<Test>::InvalidNewValue,
+		);
+
+		assert_ok!(Configuration::set_async_backing_params_override(
+			RuntimeOrigin::root(),
+			para,
+			Some(params(1, 2)),
+		));
+		// The override is applied at the same session as a configuration change.
+		on_new_session(1);
+		assert_eq!(Configuration::async_backing_params_for(para), params(3, 2));
+		on_new_session(2);
+		assert_eq!(Configuration::async_backing_params_for(para), params(1, 2));
+		assert!(PendingAsyncBackingParamsOverrides::<Test>::get().is_empty());
+		// Other paras are not affected.
+		assert_eq!(Configuration::async_backing_params_for(ParaId::from(101)), params(3, 2));
+
+		// Overrides are capped if the active configuration is lowered.
+		ActiveConfig::<Test>::mutate(|config| config.async_backing_params = params(3, 1));
+		assert_eq!(Configuration::async_backing_params_for(para), params(1, 1));
+
+		assert_ok!(Configuration::set_async_backing_params_override(
+			RuntimeOrigin::root(),
+			para,
+			None,
+		));
+		assert_eq!(Configuration::async_backing_params_for(para), params(1, 1));
+		on_new_session(3);
+		on_new_session(4);
+		assert_eq!(Configuration::async_backing_params_for(para), params(3, 1));
+	});
+}
+
+#[test]
+fn later_async_backing_params_override_replaces_pending_one() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let para = ParaId::from(100);
+		let params = AsyncBackingParams { max_candidate_depth: 0, allowed_ancestry_len: 0 };
+
+		assert_ok!(Configuration::set_async_backing_params_override(
+			RuntimeOrigin::root(),
+			para,
+			Some(params),
+		));
+		assert_ok!(Configuration::set_async_backing_params_override(
+			RuntimeOrigin::root(),
+			para,
+			None,
+		));
+		assert_eq!(PendingAsyncBackingParamsOverrides::<Test>::get(), vec![(2, para, None)]);
+
+		on_new_session(1);
+		on_new_session(2);
+		assert_eq!(AsyncBackingParamsOverrides::<Test>::get(para), None);
+	});
+}
+
 #[test]
 fn verify_externally_accessible() {
 	// This test verifies that the value can be accessed through the well known keys and the
@@ -18,7 +18,10 @@
 //!
 //! Once a dispute is concluded, we want to slash validators who were on the
 //! wrong side of the dispute. The slashing amount depends on whether the
-//! candidate was valid (none at the moment) or invalid (big). In addition to
+//! candidate was valid (small) or invalid (big) and is configured by the
+//! runtime. Validators losing a dispute about a valid candidate are slashed
+//! less if a significant share of the validator set voted with them, as this
+//! usually hints at slow hardware rather than at an attack. In addition to
 //! that, we might want to kick out the validators from the active set.
 //! Currently, we limit slashing to the backing group for invalid disputes.
 //!
@@ -61,7 +64,7 @@ use sp_runtime::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
 		TransactionValidityError, ValidTransaction,
 	},
-	KeyTypeId, Perbill,
+	KeyTypeId, PerThing, Perbill,
 };
 use sp_session::{GetSessionNumber, GetValidatorCount};
 use sp_staking::offence::{DisableStrategy, Kind, Offence, OffenceError, ReportOffence};
@@ -72,10 +75,6 @@ use sp_std::{
 
 const LOG_TARGET: &str = "runtime::parachains::slashing";
 
-// These are constants, but we want to make them configurable
-// via `HostConfiguration` in the future.
-const SLASH_FOR_INVALID: Perbill = Perbill::from_percent(100);
-const SLASH_AGAINST_VALID: Perbill = Perbill::zero();
 const DEFENSIVE_PROOF: &'static str = "disputes module should bail on old session";
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -139,7 +138,8 @@ where
 			SlashingOffenceKind::ForInvalid => DisableStrategy::Always,
 			// in the future we might change it based on number of disputes initiated:
 			// <https://github.com/paritytech/polkadot/issues/5946>
-			SlashingOffenceKind::AgainstValid => DisableStrategy::Never,
+			SlashingOffenceKind::AgainstValid | SlashingOffenceKind::AgainstValidNarrowly =>
+				DisableStrategy::Never,
 		}
 	}
 
@@ -155,16 +155,19 @@ impl<KeyOwnerIdentification> SlashingOffence<KeyOwnerIdentification> {
 		validator_set_count: ValidatorSetCount,
 		offenders: Vec<KeyOwnerIdentification>,
 		kind: SlashingOffenceKind,
+		slash_fraction: Perbill,
 	) -> Self {
 		let time_slot = DisputesTimeSlot::new(session_index, candidate_hash);
-		let slash_fraction = match kind {
-			SlashingOffenceKind::ForInvalid => SLASH_FOR_INVALID,
-			SlashingOffenceKind::AgainstValid => SLASH_AGAINST_VALID,
-		};
 		Self { time_slot, validator_set_count, offenders, slash_fraction, kind }
 	}
 }
 
+/// Whether `losers` out of `validator_set_count` validators make up at least the `margin`
+/// fraction of the validator set. A zero `margin` disables the check.
+fn is_narrow_margin(losers: u32, validator_set_count: ValidatorSetCount, margin: Perbill) -> bool {
+	!margin.is_zero() && losers >= margin.mul_ceil(validator_set_count)
+}
+
 /// This type implements `SlashingHandler`.
 pub struct SlashValidatorsForDisputes<C> {
 	_phantom: sp_std::marker::PhantomData<C>,
@@ -217,17 +220,18 @@ where
 		losers: impl IntoIterator<Item = ValidatorIndex>,
 		backers: impl IntoIterator<Item = ValidatorIndex>,
 	) {
-		// sanity check for the current implementation
-		if kind == SlashingOffenceKind::AgainstValid {
-			debug_assert!(false, "should only slash ForInvalid disputes");
-			return
-		}
 		let losers: BTreeSet<_> = losers.into_iter().collect();
 		if losers.is_empty() {
 			return
 		}
-		let backers: BTreeSet<_> = backers.into_iter().collect();
-		let to_punish: Vec<ValidatorIndex> = losers.intersection(&backers).cloned().collect();
+		let to_punish: Vec<ValidatorIndex> = match kind {
+			SlashingOffenceKind::ForInvalid => {
+				let backers: BTreeSet<_> = backers.into_iter().collect();
+				losers.intersection(&backers).cloned().collect()
+			},
+			SlashingOffenceKind::AgainstValid | SlashingOffenceKind::AgainstValidNarrowly =>
+				losers.into_iter().collect(),
+		};
 		if to_punish.is_empty() {
 			return
 		}
@@ -237,16 +241,28 @@ where
 			Some(info) => info,
 			None => return,
 		};
+		let validator_set_count = session_info.discovery_keys.len() as ValidatorSetCount;
+
+		let kind = match kind {
+			SlashingOffenceKind::AgainstValid =>
+				Pallet::<T>::against_valid_offence_kind(to_punish.len() as u32, validator_set_count),
+			kind => kind,
+		};
+		// Nothing to report if the runtime doesn't slash for this kind of offence.
+		let slash_fraction = Pallet::<T>::slash_fraction(kind);
+		if slash_fraction.is_zero() {
+			return
+		}
 
 		let maybe = Self::maybe_identify_validators(session_index, to_punish.iter().cloned());
 		if let Some(offenders) = maybe {
-			let validator_set_count = session_info.discovery_keys.len() as ValidatorSetCount;
 			let offence = SlashingOffence::new(
 				session_index,
 				candidate_hash,
 				validator_set_count,
 				offenders,
 				kind,
+				slash_fraction,
 			);
 			// This is the first time we report an offence for this dispute,
 			// so it is not a duplicate.
@@ -286,13 +302,15 @@ where
 	}
 
 	fn punish_against_valid(
-		_session_index: SessionIndex,
-		_candidate_hash: CandidateHash,
-		_losers: impl IntoIterator<Item = ValidatorIndex>,
-		_backers: impl IntoIterator<Item = ValidatorIndex>,
+		session_index: SessionIndex,
+		candidate_hash: CandidateHash,
+		losers: impl IntoIterator<Item = ValidatorIndex>,
+		backers: impl IntoIterator<Item = ValidatorIndex>,
 	) {
-		// do nothing for now
-		// NOTE: changing that requires modifying `do_punish` implementation
+		// `do_punish` downgrades the offence to `AgainstValidNarrowly` if the dispute was
+		// concluded by a narrow margin.
+		let kind = SlashingOffenceKind::AgainstValid;
+		Self::do_punish(session_index, candidate_hash, kind, losers, backers);
 	}
 
 	fn initializer_initialize(now: BlockNumberFor<T>) -> Weight {
@@ -363,6 +381,7 @@ impl<T: Config> HandleReports<T> for () {
 
 pub trait WeightInfo {
 	fn report_dispute_lost(validator_count: ValidatorSetCount) -> Weight;
+	fn report_dispute_lost_against_valid(validator_count: ValidatorSetCount) -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -370,6 +389,9 @@ impl WeightInfo for TestWeightInfo {
 	fn report_dispute_lost(_validator_count: ValidatorSetCount) -> Weight {
 		Weight::zero()
 	}
+	fn report_dispute_lost_against_valid(_validator_count: ValidatorSetCount) -> Weight {
+		Weight::zero()
+	}
 }
 
 pub use pallet::*;
@@ -405,6 +427,30 @@ pub mod pallet {
 		/// `ValidateUnsigned` in the runtime definition.
 		type HandleReports: HandleReports<Self>;
 
+		/// The fraction of the exposure slashed from validators who backed an
+		/// invalid candidate.
+		#[pallet::constant]
+		type SlashForInvalid: Get<Perbill>;
+
+		/// The fraction of the exposure slashed from validators who disputed a
+		/// valid candidate. If zero, such validators are not reported at all.
+		#[pallet::constant]
+		type SlashAgainstValid: Get<Perbill>;
+
+		/// The fraction of the exposure slashed from validators who disputed a
+		/// valid candidate that was confirmed valid by a narrow margin only, see
+		/// [`Config::NarrowMargin`]. Expected to be lower than
+		/// [`Config::SlashAgainstValid`]. If zero, such validators are not
+		/// reported at all.
+		#[pallet::constant]
+		type SlashAgainstValidNarrowly: Get<Perbill>;
+
+		/// If at least this fraction of the validator set lost a dispute about a
+		/// valid candidate, the dispute is considered to be lost narrowly, which
+		/// usually hints at slow hardware rather than an attack.
+		#[pallet::constant]
+		type NarrowMargin: Get<Perbill>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -452,9 +498,14 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::report_dispute_lost(
-			key_owner_proof.validator_count()
-		))]
+		#[pallet::weight(match dispute_proof.kind {
+			SlashingOffenceKind::ForInvalid =>
+				<T as Config>::WeightInfo::report_dispute_lost(key_owner_proof.validator_count()),
+			SlashingOffenceKind::AgainstValid | SlashingOffenceKind::AgainstValidNarrowly =>
+				<T as Config>::WeightInfo::report_dispute_lost_against_valid(
+					key_owner_proof.validator_count(),
+				),
+		})]
 		pub fn report_dispute_lost_unsigned(
 			origin: OriginFor<T>,
 			// box to decrease the size of the call
@@ -506,6 +557,7 @@ pub mod pallet {
 				validator_set_count,
 				vec![offender],
 				dispute_proof.kind,
+				Self::slash_fraction(dispute_proof.kind),
 			);
 
 			<T::HandleReports as HandleReports<T>>::report_offence(offence)
@@ -553,6 +605,28 @@ impl<T: Config> Pallet<T> {
 		let _ = <UnappliedSlashes<T>>::clear_prefix(old_session, REMOVE_LIMIT, None);
 	}
 
+	/// The fraction of the exposure slashed for an offence of the given `kind`.
+	pub fn slash_fraction(kind: SlashingOffenceKind) -> Perbill {
+		match kind {
+			SlashingOffenceKind::ForInvalid => T::SlashForInvalid::get(),
+			SlashingOffenceKind::AgainstValid => T::SlashAgainstValid::get(),
+			SlashingOffenceKind::AgainstValidNarrowly => T::SlashAgainstValidNarrowly::get(),
+		}
+	}
+
+	/// The kind of the offence committed by `losers` validators out of
+	/// `validator_set_count` who disputed a valid candidate.
+	pub fn against_valid_offence_kind(
+		losers: u32,
+		validator_set_count: ValidatorSetCount,
+	) -> SlashingOffenceKind {
+		if is_narrow_margin(losers, validator_set_count, T::NarrowMargin::get()) {
+			SlashingOffenceKind::AgainstValidNarrowly
+		} else {
+			SlashingOffenceKind::AgainstValid
+		}
+	}
+
 	pub(crate) fn unapplied_slashes() -> Vec<(SessionIndex, CandidateHash, PendingSlashes)> {
 		<UnappliedSlashes<T>>::iter().collect()
 	}
@@ -594,6 +668,7 @@ impl<T: Config> Pallet<T> {
 			let tag_prefix = match dispute_proof.kind {
 				SlashingOffenceKind::ForInvalid => "DisputeForInvalid",
 				SlashingOffenceKind::AgainstValid => "DisputeAgainstValid",
+				SlashingOffenceKind::AgainstValidNarrowly => "DisputeAgainstValidNarrowly",
 			};
 
 			ValidTransaction::with_tag_prefix(tag_prefix)
@@ -726,3 +801,63 @@ where
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parity_scale_codec::{Decode, Encode};
+
+	#[test]
+	fn narrow_margin_is_a_fraction_of_the_validator_set() {
+		let margin = Perbill::from_percent(20);
+
+		assert!(!is_narrow_margin(0, 10, margin));
+		assert!(!is_narrow_margin(1, 10, margin));
+		assert!(is_narrow_margin(2, 10, margin));
+		assert!(is_narrow_margin(10, 10, margin));
+		// the threshold is rounded up
+		assert!(!is_narrow_margin(1, 6, margin));
+		assert!(is_narrow_margin(2, 6, margin));
+	}
+
+	#[test]
+	fn zero_narrow_margin_is_never_reached() {
+		assert!(!is_narrow_margin(10, 10, Perbill::zero()));
+		assert!(!is_narrow_margin(0, 0, Perbill::zero()));
+	}
+
+	#[test]
+	fn offences_against_valid_candidates_never_disable() {
+		let offence = |kind| {
+			SlashingOffence::new(
+				1,
+				CandidateHash(Default::default()),
+				10,
+				vec![1u64],
+				kind,
+				Perbill::from_percent(1),
+			)
+		};
+
+		let for_invalid = offence(SlashingOffenceKind::ForInvalid);
+		assert_eq!(for_invalid.disable_strategy(), DisableStrategy::Always);
+
+		let against_valid = offence(SlashingOffenceKind::AgainstValid);
+		assert_eq!(against_valid.disable_strategy(), DisableStrategy::Never);
+
+		let narrowly = offence(SlashingOffenceKind::AgainstValidNarrowly);
+		assert_eq!(narrowly.disable_strategy(), DisableStrategy::Never);
+		assert_eq!(narrowly.slash_fraction(1), Perbill::from_percent(1));
+	}
+
+	#[test]
+	fn offence_kinds_keep_their_encoding() {
+		assert_eq!(SlashingOffenceKind::ForInvalid.encode(), vec![0]);
+		assert_eq!(SlashingOffenceKind::AgainstValid.encode(), vec![1]);
+		assert_eq!(SlashingOffenceKind::AgainstValidNarrowly.encode(), vec![2]);
+		assert_eq!(
+			SlashingOffenceKind::decode(&mut &[2u8][..]).unwrap(),
+			SlashingOffenceKind::AgainstValidNarrowly,
+		);
+	}
+}
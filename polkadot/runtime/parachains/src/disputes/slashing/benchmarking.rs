@@ -106,7 +106,11 @@ where
 	(session_index, key_owner_proof, validator_id)
 }
 
-fn setup_dispute<T>(session_index: SessionIndex, validator_id: ValidatorId) -> DisputeProof
+fn setup_dispute<T>(
+	session_index: SessionIndex,
+	validator_id: ValidatorId,
+	kind: SlashingOffenceKind,
+) -> DisputeProof
 where
 	T: Config,
 {
@@ -117,20 +121,34 @@ where
 	let losers = [validator_index].into_iter();
 	let backers = losers.clone();
 
-	T::SlashingHandler::punish_for_invalid(session_index, CANDIDATE_HASH, losers, backers);
+	match kind {
+		SlashingOffenceKind::ForInvalid => {
+			T::SlashingHandler::punish_for_invalid(session_index, CANDIDATE_HASH, losers, backers);
+		},
+		// The runtime might not slash validators disputing valid candidates, so the
+		// pending slash is inserted directly.
+		SlashingOffenceKind::AgainstValid | SlashingOffenceKind::AgainstValidNarrowly => {
+			let keys = [(validator_index, validator_id.clone())].into_iter().collect();
+			<UnappliedSlashes<T>>::insert(
+				session_index,
+				CANDIDATE_HASH,
+				PendingSlashes { keys, kind },
+			);
+		},
+	}
 
 	let unapplied = <UnappliedSlashes<T>>::get(session_index, CANDIDATE_HASH);
 	assert_eq!(unapplied.unwrap().keys.len(), 1);
 
-	dispute_proof(session_index, validator_id, validator_index)
+	dispute_proof(session_index, validator_id, validator_index, kind)
 }
 
 fn dispute_proof(
 	session_index: SessionIndex,
 	validator_id: ValidatorId,
 	validator_index: ValidatorIndex,
+	kind: SlashingOffenceKind,
 ) -> DisputeProof {
-	let kind = SlashingOffenceKind::ForInvalid;
 	let time_slot = DisputesTimeSlot::new(session_index, CANDIDATE_HASH);
 
 	DisputeProof { time_slot, kind, validator_index, validator_id }
@@ -148,7 +166,32 @@ benchmarks! {
 
 		let origin = RawOrigin::None.into();
 		let (session_index, key_owner_proof, validator_id) = setup_validator_set::<T>(n);
-		let dispute_proof = setup_dispute::<T>(session_index, validator_id);
+		let dispute_proof =
+			setup_dispute::<T>(session_index, validator_id, SlashingOffenceKind::ForInvalid);
+	}: {
+		let result = Pallet::<T>::report_dispute_lost_unsigned(
+			origin,
+			Box::new(dispute_proof),
+			key_owner_proof,
+		);
+		assert!(result.is_ok());
+	} verify {
+		let unapplied = <UnappliedSlashes<T>>::get(session_index, CANDIDATE_HASH);
+		assert!(unapplied.is_none());
+	}
+
+	// in this setup we have a single `AgainstValidNarrowly` dispute
+	// submitted for a past session, which never disables the offender
+	report_dispute_lost_against_valid {
+		let n in 4..<<T as super::Config>::BenchmarkingConfig as BenchmarkingConfiguration>::MAX_VALIDATORS;
+
+		let origin = RawOrigin::None.into();
+		let (session_index, key_owner_proof, validator_id) = setup_validator_set::<T>(n);
+		let dispute_proof = setup_dispute::<T>(
+			session_index,
+			validator_id,
+			SlashingOffenceKind::AgainstValidNarrowly,
+		);
 	}: {
 		let result = Pallet::<T>::report_dispute_lost_unsigned(
 			origin,
@@ -0,0 +1,215 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A pallet recording the approval-checking finality lag as observed by block authors.
+//!
+//! Block authors measure by how many blocks approval checking holds back finality and report
+//! this lag through an optional inherent. The lag is tracked on-chain, so that governance and
+//! automated safety mechanisms can react to finality stalls, e.g. by throttling functionality
+//! based on [`IsFinalityLagging`].
+//!
+//! A report is only as trustworthy as the block author who made it. Since every report overwrites
+//! the previous one, an author can only influence the state of the blocks it authors. Consumers
+//! should therefore require the lag to persist for a number of blocks before acting upon it.
+
+use frame_support::{inherent::MakeFatalError, pallet_prelude::*};
+use frame_system::pallet_prelude::*;
+use primitives::{vstaging::FINALITY_LAG_INHERENT_IDENTIFIER, BlockNumber};
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+const LOG_TARGET: &str = "runtime::finality-lag";
+
+pub trait WeightInfo {
+	fn note_finality_lag() -> Weight;
+}
+
+pub struct TestWeightInfo;
+impl WeightInfo for TestWeightInfo {
+	fn note_finality_lag() -> Weight {
+		Weight::MAX
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The lag, in blocks, above which finality is considered to be lagging.
+		#[pallet::constant]
+		type LagThreshold: Get<BlockNumber>;
+
+		/// The maximum lag which can be recorded. Block authors clamp their reports to it, blocks
+		/// reporting a larger lag are invalid.
+		#[pallet::constant]
+		type MaxReportedLag: Get<BlockNumber>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The reported lag exceeded the threshold. `[lag]`
+		FinalityLagging { lag: BlockNumber },
+		/// The reported lag is back within the threshold. `[lag, lagged_for]`
+		FinalityRecovered { lag: BlockNumber, lagged_for: BlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The finality lag inherent was included more than once in a block.
+		TooManyInherents,
+	}
+
+	/// The approval-checking finality lag most recently reported by a block author.
+	#[pallet::storage]
+	#[pallet::getter(fn approval_checking_lag)]
+	pub(super) type ApprovalCheckingLag<T: Config> = StorageValue<_, BlockNumber, ValueQuery>;
+
+	/// The block since which the reported lag has been exceeding `LagThreshold`, if it does.
+	#[pallet::storage]
+	#[pallet::getter(fn lagging_since)]
+	pub(super) type LaggingSince<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Whether the finality lag inherent was already included in the current block.
+	///
+	/// Only ever present during block execution.
+	#[pallet::storage]
+	pub(super) type Included<T: Config> = StorageValue<_, (), OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			T::DbWeight::get().writes(1) // in `on_finalize`.
+		}
+
+		fn on_finalize(_: BlockNumberFor<T>) {
+			Included::<T>::kill();
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		type Call = Call<T>;
+		type Error = MakeFatalError<()>;
+		const INHERENT_IDENTIFIER: InherentIdentifier = FINALITY_LAG_INHERENT_IDENTIFIER;
+
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let lag = match data.get_data::<BlockNumber>(&Self::INHERENT_IDENTIFIER) {
+				Ok(lag) => lag?,
+				Err(_) => {
+					log::warn!(target: LOG_TARGET, "Finality lag inherent data failed to decode");
+					return None
+				},
+			};
+
+			Some(Call::note_finality_lag { lag: lag.min(T::MaxReportedLag::get()) })
+		}
+
+		fn check_inherent(call: &Self::Call, _: &InherentData) -> Result<(), Self::Error> {
+			match call {
+				Call::note_finality_lag { lag } if *lag > T::MaxReportedLag::get() => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Finality lag inherent reports a lag of {} above the maximum of {}",
+						lag,
+						T::MaxReportedLag::get(),
+					);
+					Err(().into())
+				},
+				_ => Ok(()),
+			}
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::note_finality_lag { .. })
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Note the approval-checking finality lag observed by the block author.
+		#[pallet::call_index(0)]
+		#[pallet::weight((<T as Config>::WeightInfo::note_finality_lag(), DispatchClass::Mandatory))]
+		pub fn note_finality_lag(origin: OriginFor<T>, lag: BlockNumber) -> DispatchResult {
+			ensure_none(origin)?;
+
+			ensure!(!Included::<T>::exists(), Error::<T>::TooManyInherents);
+			Included::<T>::set(Some(()));
+
+			Self::note_lag(
+				lag.min(T::MaxReportedLag::get()),
+				frame_system::Pallet::<T>::block_number(),
+			);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// For how many blocks finality has been lagging, or `None` if it is not lagging.
+	pub fn lagging_for() -> Option<BlockNumberFor<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		LaggingSince::<T>::get().map(|since| now.saturating_sub(since))
+	}
+
+	fn note_lag(lag: BlockNumber, now: BlockNumberFor<T>) {
+		ApprovalCheckingLag::<T>::put(lag);
+
+		let lagging = lag > T::LagThreshold::get();
+		match (LaggingSince::<T>::get(), lagging) {
+			(None, true) => {
+				LaggingSince::<T>::put(now);
+				Self::deposit_event(Event::FinalityLagging { lag });
+			},
+			(Some(since), false) => {
+				LaggingSince::<T>::kill();
+				Self::deposit_event(Event::FinalityRecovered {
+					lag,
+					lagged_for: now.saturating_sub(since),
+				});
+			},
+			_ => {},
+		}
+	}
+}
+
+/// `Get<bool>` which is `true` once finality has been lagging for at least `Period` blocks.
+pub struct IsFinalityLagging<T, Period>(PhantomData<(T, Period)>);
+
+impl<T: Config, Period: Get<BlockNumberFor<T>>> Get<bool> for IsFinalityLagging<T, Period> {
+	fn get() -> bool {
+		Pallet::<T>::lagging_for().map_or(false, |lagging_for| lagging_for >= Period::get())
+	}
+}
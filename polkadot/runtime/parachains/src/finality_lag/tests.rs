@@ -0,0 +1,113 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::{new_test_ext, FinalityLag, MockGenesisConfig, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, traits::ConstU32};
+
+fn run_to_block(to: BlockNumber) {
+	while System::block_number() < to {
+		let b = System::block_number();
+		FinalityLag::on_finalize(b);
+		System::on_finalize(b);
+
+		System::on_initialize(b + 1);
+		System::set_block_number(b + 1);
+		FinalityLag::on_initialize(b + 1);
+	}
+}
+
+fn note_lag(lag: BlockNumber) {
+	assert_ok!(FinalityLag::note_finality_lag(RuntimeOrigin::none(), lag));
+}
+
+#[test]
+fn inherent_is_created_from_inherent_data() {
+	let mut data = InherentData::new();
+	assert!(FinalityLag::create_inherent(&data).is_none());
+
+	data.put_data(FINALITY_LAG_INHERENT_IDENTIFIER, &5u32).unwrap();
+	let call = FinalityLag::create_inherent(&data).unwrap();
+	assert_eq!(call, Call::<Test>::note_finality_lag { lag: 5 });
+	assert!(FinalityLag::is_inherent(&call));
+
+	// Clamped to the maximum.
+	data.replace_data(FINALITY_LAG_INHERENT_IDENTIFIER, &5000u32);
+	assert_eq!(
+		FinalityLag::create_inherent(&data).unwrap(),
+		Call::<Test>::note_finality_lag { lag: 1000 }
+	);
+}
+
+#[test]
+fn inherent_above_the_maximum_lag_is_rejected() {
+	let data = InherentData::new();
+	assert!(FinalityLag::check_inherent(&Call::note_finality_lag { lag: 1000 }, &data).is_ok());
+	assert!(FinalityLag::check_inherent(&Call::note_finality_lag { lag: 1001 }, &data).is_err());
+}
+
+#[test]
+fn only_one_inherent_per_block() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		run_to_block(1);
+		note_lag(1);
+		assert_noop!(
+			FinalityLag::note_finality_lag(RuntimeOrigin::none(), 2),
+			Error::<Test>::TooManyInherents
+		);
+
+		run_to_block(2);
+		note_lag(2);
+		assert_eq!(FinalityLag::approval_checking_lag(), 2);
+	});
+}
+
+#[test]
+fn lagging_is_tracked_across_blocks() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		run_to_block(1);
+		note_lag(10);
+		assert_eq!(FinalityLag::lagging_for(), None);
+
+		run_to_block(2);
+		note_lag(11);
+		assert_eq!(FinalityLag::lagging_since(), Some(2));
+		System::assert_last_event(Event::<Test>::FinalityLagging { lag: 11 }.into());
+
+		run_to_block(5);
+		note_lag(20);
+		assert_eq!(FinalityLag::lagging_for(), Some(3));
+		assert!(IsFinalityLagging::<Test, ConstU32<3>>::get());
+		assert!(!IsFinalityLagging::<Test, ConstU32<4>>::get());
+
+		run_to_block(6);
+		note_lag(3);
+		assert_eq!(FinalityLag::lagging_for(), None);
+		assert!(!IsFinalityLagging::<Test, ConstU32<0>>::get());
+		System::assert_last_event(
+			Event::<Test>::FinalityRecovered { lag: 3, lagged_for: 4 }.into(),
+		);
+	});
+}
+
+#[test]
+fn reported_lag_is_clamped() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		run_to_block(1);
+		note_lag(BlockNumber::MAX);
+		assert_eq!(FinalityLag::approval_checking_lag(), 1000);
+	});
+}
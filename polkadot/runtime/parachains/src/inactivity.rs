@@ -0,0 +1,300 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A pallet detecting inactive parachain validators from data which is already on-chain.
+//!
+//! Unlike `pallet-im-online`, which requires validators to submit heartbeats, this pallet
+//! considers a validator active in a session if it authored blocks or if its availability
+//! bitfields contributed to the availability of included candidates. At the beginning of every
+//! session, the validators of the previous session which reached neither of the configured
+//! thresholds are reported through an [`InactivityOffence`].
+//!
+//! Participation is noted through the [`inclusion::RewardValidators`] and
+//! [`pallet_authorship::EventHandler`] implementations of the pallet, which need to be wired up
+//! in the runtime.
+//!
+//! A session in which no candidate became available says nothing about the participation of
+//! individual validators, so nobody is reported for it.
+
+use crate::{
+	inclusion, session_info,
+	session_info::{AccountId, IdentificationTuple},
+	shared,
+};
+use frame_support::{pallet_prelude::*, traits::ValidatorSetWithIdentification};
+use frame_system::pallet_prelude::*;
+use primitives::{SessionIndex, ValidatorIndex};
+use sp_runtime::{traits::Convert, Perbill};
+use sp_staking::offence::{DisableStrategy, Kind, Offence, ReportOffence};
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+const LOG_TARGET: &str = "runtime::inactivity";
+
+pub trait WeightInfo {
+	fn note_author() -> Weight;
+	fn reward_bitfields(v: u32) -> Weight;
+	fn new_session(v: u32) -> Weight;
+}
+
+pub struct TestWeightInfo;
+impl WeightInfo for TestWeightInfo {
+	fn note_author() -> Weight {
+		Weight::zero()
+	}
+	fn reward_bitfields(_v: u32) -> Weight {
+		Weight::zero()
+	}
+	fn new_session(_v: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+type IdentificationOf<T> =
+	<<T as session_info::Config>::ValidatorSet as ValidatorSetWithIdentification<
+		<T as frame_system::Config>::AccountId,
+	>>::IdentificationOf;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + shared::Config + session_info::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The number of blocks a validator needs to author in a session to be considered active.
+		#[pallet::constant]
+		type MinAuthoredBlocks: Get<u32>;
+
+		/// The number of included candidates a validator needs to have voted available in a
+		/// session to be considered active.
+		#[pallet::constant]
+		type MinAvailabilityVotes: Get<u32>;
+
+		/// A type that gives us the ability to submit inactivity offence reports.
+		type ReportInactivity: ReportOffence<
+			Self::AccountId,
+			IdentificationTuple<Self>,
+			InactivityOffence<IdentificationTuple<Self>>,
+		>;
+
+		/// Weight information for the operations of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Validators were inactive during a session and have been reported.
+		SomeInactive { session_index: SessionIndex, inactive: Vec<AccountId<T>> },
+	}
+
+	/// The session for which participation is currently being tracked.
+	#[pallet::storage]
+	pub(super) type TrackedSession<T: Config> = StorageValue<_, SessionIndex, OptionQuery>;
+
+	/// The number of blocks authored by each validator in the tracked session.
+	#[pallet::storage]
+	#[pallet::getter(fn authored_blocks)]
+	pub(super) type AuthoredBlocks<T: Config> =
+		StorageMap<_, Twox64Concat, AccountId<T>, u32, ValueQuery>;
+
+	/// The number of included candidates each validator voted available in the tracked session,
+	/// keyed by the index of the validator in that session.
+	#[pallet::storage]
+	#[pallet::getter(fn availability_votes)]
+	pub(super) type AvailabilityVotes<T: Config> =
+		StorageMap<_, Twox64Concat, ValidatorIndex, u32, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			let session_index = shared::Pallet::<T>::session_index();
+			let tracked = TrackedSession::<T>::get();
+			if tracked == Some(session_index) {
+				return T::DbWeight::get().reads(2)
+			}
+
+			let validators = tracked.map_or(0, Self::end_session);
+			TrackedSession::<T>::put(session_index);
+
+			<T as Config>::WeightInfo::new_session(validators)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Take the participation noted for the given session and report the validators which were
+	/// inactive in it.
+	///
+	/// Returns the number of validators in that session.
+	fn end_session(session_index: SessionIndex) -> u32 {
+		let validators = session_info::Pallet::<T>::account_keys(session_index).unwrap_or_default();
+		let validator_set_count = validators.len() as u32;
+
+		// Taking the entries of every validator leaves both maps empty for the next session.
+		let participation: Vec<_> = validators
+			.into_iter()
+			.enumerate()
+			.map(|(index, validator)| {
+				let authored_blocks = AuthoredBlocks::<T>::take(&validator);
+				let availability_votes = AvailabilityVotes::<T>::take(ValidatorIndex(index as u32));
+				(validator, authored_blocks, availability_votes)
+			})
+			.collect();
+		// Only validators of the session participate, so this only removes anything if its
+		// session info is missing. Bounded by the size of the active validator set.
+		let limit =
+			validator_set_count.max(shared::Pallet::<T>::active_validator_keys().len() as u32);
+		let _ = AuthoredBlocks::<T>::clear(limit, None);
+		let _ = AvailabilityVotes::<T>::clear(limit, None);
+
+		if participation.iter().all(|(_, _, availability_votes)| *availability_votes == 0) {
+			log::debug!(
+				target: LOG_TARGET,
+				"No candidate became available in session {}, not reporting inactivity",
+				session_index,
+			);
+			return validator_set_count
+		}
+
+		let min_authored_blocks = T::MinAuthoredBlocks::get();
+		let min_availability_votes = T::MinAvailabilityVotes::get();
+		let inactive: Vec<_> = participation
+			.into_iter()
+			.filter(|(_, authored_blocks, availability_votes)| {
+				*authored_blocks < min_authored_blocks &&
+					*availability_votes < min_availability_votes
+			})
+			.map(|(validator, _, _)| validator)
+			.collect();
+
+		if inactive.is_empty() {
+			return validator_set_count
+		}
+
+		let offenders = inactive
+			.iter()
+			.filter_map(|id| {
+				IdentificationOf::<T>::convert(id.clone()).map(|full| (id.clone(), full))
+			})
+			.collect();
+
+		Self::deposit_event(Event::SomeInactive { session_index, inactive });
+
+		let offence = InactivityOffence { session_index, validator_set_count, offenders };
+		if let Err(e) = T::ReportInactivity::report_offence(Vec::new(), offence) {
+			log::warn!(
+				target: LOG_TARGET,
+				"Failed to report inactivity in session {}: {:?}",
+				session_index,
+				e,
+			);
+		}
+
+		validator_set_count
+	}
+}
+
+impl<T: Config> inclusion::RewardValidators for Pallet<T> {
+	fn reward_backing(_: impl IntoIterator<Item = ValidatorIndex>) {}
+
+	fn reward_bitfields(validators: impl IntoIterator<Item = ValidatorIndex>) {
+		let mut rewarded = 0u32;
+		for validator in validators {
+			AvailabilityVotes::<T>::mutate(validator, |votes| *votes = votes.saturating_add(1));
+			rewarded = rewarded.saturating_add(1);
+		}
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			<T as Config>::WeightInfo::reward_bitfields(rewarded),
+			DispatchClass::Mandatory,
+		);
+	}
+}
+
+impl<T: Config> pallet_authorship::EventHandler<AccountId<T>, BlockNumberFor<T>> for Pallet<T> {
+	fn note_author(author: AccountId<T>) {
+		AuthoredBlocks::<T>::mutate(author, |blocks| *blocks = blocks.saturating_add(1));
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			<T as Config>::WeightInfo::note_author(),
+			DispatchClass::Mandatory,
+		);
+	}
+}
+
+/// An offence that is filed if a validator neither authored blocks nor took part in availability
+/// distribution during a session.
+#[derive(RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq, Eq))]
+pub struct InactivityOffence<Offender> {
+	/// The session in which the validators were inactive.
+	pub session_index: SessionIndex,
+	/// The size of the validator set in that session.
+	pub validator_set_count: u32,
+	/// Validators that were inactive during the session.
+	pub offenders: Vec<Offender>,
+}
+
+impl<Offender: Clone> Offence<Offender> for InactivityOffence<Offender> {
+	const ID: Kind = *b"para:inactivity:";
+	type TimeSlot = SessionIndex;
+
+	fn offenders(&self) -> Vec<Offender> {
+		self.offenders.clone()
+	}
+
+	fn session_index(&self) -> SessionIndex {
+		self.session_index
+	}
+
+	fn validator_set_count(&self) -> u32 {
+		self.validator_set_count
+	}
+
+	fn time_slot(&self) -> Self::TimeSlot {
+		self.session_index
+	}
+
+	fn disable_strategy(&self) -> DisableStrategy {
+		DisableStrategy::Never
+	}
+
+	fn slash_fraction(&self, offenders: u32) -> Perbill {
+		// Same as for `pallet-im-online`: up to 10% of the validators can be inactive without
+		// being slashed, after that the slash climbs linearly up to 7%.
+		if let Some(threshold) = offenders.checked_sub(self.validator_set_count / 10 + 1) {
+			let x = Perbill::from_rational(3 * threshold, self.validator_set_count);
+			x.saturating_mul(Perbill::from_percent(7))
+		} else {
+			Perbill::default()
+		}
+	}
+}
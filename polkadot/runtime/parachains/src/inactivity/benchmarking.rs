@@ -0,0 +1,77 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use pallet_authorship::EventHandler;
+use sp_runtime::traits::TrailingZeroInput;
+
+const MAX_VALIDATORS: u32 = 1000;
+
+fn validator<T: Config>(index: u32) -> AccountId<T> {
+	let entropy = (b"inactivity", index).using_encoded(sp_io::hashing::blake2_256);
+	AccountId::<T>::decode(&mut TrailingZeroInput::new(&entropy[..]))
+		.expect("infinite input is valid for any account id; qed")
+}
+
+benchmarks! {
+	note_author {
+		let author = validator::<T>(0);
+		AuthoredBlocks::<T>::insert(&author, 1);
+	}: {
+		<Pallet<T> as EventHandler<_, _>>::note_author(author.clone());
+	}
+	verify {
+		assert_eq!(AuthoredBlocks::<T>::get(&author), 2);
+	}
+
+	reward_bitfields {
+		let v in 1 .. MAX_VALIDATORS;
+		let validators: Vec<_> = (0..v).map(ValidatorIndex).collect();
+	}: {
+		<Pallet<T> as inclusion::RewardValidators>::reward_bitfields(validators);
+	}
+	verify {
+		assert_eq!(AvailabilityVotes::<T>::get(ValidatorIndex(v - 1)), 1);
+	}
+
+	// Worst case: a candidate became available, but every other validator of the ended session
+	// was inactive and gets reported.
+	new_session {
+		let v in 1 .. MAX_VALIDATORS;
+		let validators: Vec<_> = (0..v).map(validator::<T>).collect();
+		for validator in &validators {
+			AuthoredBlocks::<T>::insert(validator, 0);
+		}
+		AvailabilityVotes::<T>::insert(ValidatorIndex(0), 1);
+		session_info::AccountKeys::<T>::insert(0, validators);
+		TrackedSession::<T>::put(0);
+		shared::Pallet::<T>::set_session_index(1);
+	}: {
+		Pallet::<T>::on_initialize(0u32.into());
+	}
+	verify {
+		assert_eq!(TrackedSession::<T>::get(), Some(1));
+		assert_eq!(AuthoredBlocks::<T>::iter_keys().count(), 0);
+		assert_eq!(AvailabilityVotes::<T>::iter_keys().count(), 0);
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(Default::default()),
+		crate::mock::Test
+	);
+}
@@ -0,0 +1,115 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+	inclusion::RewardValidators,
+	mock::{
+		availability_rewards, new_test_ext, Inactivity, InactivityOffences, MockGenesisConfig,
+		ParasShared, System, Test,
+	},
+};
+use pallet_authorship::EventHandler;
+
+fn start_session(session_index: SessionIndex, validators: Vec<u64>) {
+	ParasShared::set_session_index(session_index);
+	session_info::AccountKeys::<Test>::insert(session_index, validators);
+	Inactivity::on_initialize(System::block_number());
+}
+
+fn vote_available(validators: Vec<u32>) {
+	<Test as inclusion::Config>::RewardValidators::reward_bitfields(
+		validators.into_iter().map(ValidatorIndex),
+	);
+}
+
+#[test]
+fn inactive_validators_are_reported() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		System::set_block_number(1);
+		InactivityOffences::take();
+		start_session(0, vec![10, 11, 12, 13]);
+
+		Inactivity::note_author(10);
+		vote_available(vec![1, 2]);
+		vote_available(vec![1]);
+		assert_eq!(Inactivity::availability_votes(ValidatorIndex(1)), 2);
+		// Participation is forwarded to the other reward handler as well.
+		assert_eq!(availability_rewards().get(&ValidatorIndex(1)), Some(&2));
+
+		start_session(1, vec![10, 11, 12, 13]);
+
+		assert_eq!(
+			InactivityOffences::take(),
+			vec![InactivityOffence {
+				session_index: 0,
+				validator_set_count: 4,
+				offenders: vec![(12, ()), (13, ())],
+			}],
+		);
+		System::assert_last_event(
+			Event::<Test>::SomeInactive { session_index: 0, inactive: vec![12, 13] }.into(),
+		);
+
+		assert_eq!(TrackedSession::<Test>::get(), Some(1));
+		assert_eq!(Inactivity::authored_blocks(10), 0);
+		assert_eq!(Inactivity::availability_votes(ValidatorIndex(1)), 0);
+	});
+}
+
+#[test]
+fn nothing_is_reported_without_available_candidates() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		System::set_block_number(1);
+		InactivityOffences::take();
+		start_session(0, vec![10, 11, 12, 13]);
+
+		Inactivity::note_author(10);
+
+		start_session(1, vec![10, 11, 12, 13]);
+		assert!(InactivityOffences::take().is_empty());
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn slash_fraction_tolerates_some_inactivity() {
+	let offence = InactivityOffence::<u64> {
+		session_index: 0,
+		validator_set_count: 50,
+		offenders: Vec::new(),
+	};
+
+	assert_eq!(offence.slash_fraction(6), Perbill::zero());
+	assert_eq!(offence.slash_fraction(7), Perbill::from_rational(21u32, 5000));
+}
+
+#[test]
+fn participation_of_non_validators_is_cleared() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		System::set_block_number(1);
+		start_session(0, vec![10, 11]);
+
+		Inactivity::note_author(99);
+		vote_available(vec![0, 7]);
+
+		start_session(1, vec![10, 11]);
+		assert_eq!(Inactivity::authored_blocks(99), 0);
+		assert_eq!(Inactivity::availability_votes(ValidatorIndex(7)), 0);
+		assert_eq!(AuthoredBlocks::<Test>::iter_keys().count(), 0);
+		assert_eq!(AvailabilityVotes::<Test>::iter_keys().count(), 0);
+	});
+}
@@ -16,6 +16,7 @@
 
 use super::*;
 use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
 use pallet_message_queue as mq;
 
 benchmarks! {
@@ -33,6 +34,17 @@ benchmarks! {
 		Pallet::<T>::receive_upward_messages(para, vec![vec![0; max_len]; 1].as_slice());
 	}: { Pallet::<T>::receive_upward_messages(para, upward_messages.as_slice()) }
 
+	force_reset_upward_delivery_fee_factor {
+		let para = 42u32.into();
+		UpwardDeliveryFeeFactor::<T>::insert(
+			para,
+			(FixedU128::from_u32(2), frame_system::Pallet::<T>::block_number()),
+		);
+	}: _(RawOrigin::Root, para)
+	verify {
+		assert_eq!(Pallet::<T>::upward_delivery_fee_factor(para), FixedU128::from_u32(1));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(Default::default()),
@@ -18,6 +18,21 @@
 //!
 //! It is responsible for carrying candidates from being backable to being backed, and then from
 //! backed to included.
+//!
+//! Upward messages of included candidates are enqueued into the message queue. To protect the
+//! relay chain from UMP floods, every para has an exponential fee factor
+//! (`UpwardDeliveryFeeFactor`), which follows the same rules as the `DeliveryFeeFactor` of the
+//! [`dmp`] module: it is multiplied by `UpwardExponentialFeeBase` for every message enqueued while
+//! the para's queue holds more than `max_upward_queue_count / UpwardFeeThresholdFactor` messages
+//! and divided by it again whenever the queue changes below that threshold. It also decays by one
+//! such step every `UpwardFeeDecayPeriod` blocks it is not updated, so that a para which stopped
+//! sending messages is not throttled forever.
+//!
+//! The factor can be used to price upward messages and throttles the number of messages a para
+//! can still send: candidates sending more than the remaining queue capacity divided by the
+//! factor are rejected, and the throttled capacity is advertised to the para through
+//! `well_known_keys::relay_dispatch_queue_remaining_capacity`. A single message is always let
+//! through while the queue has room left, so a para is never fully disabled by a high factor.
 
 use crate::{
 	configuration::{self, HostConfiguration},
@@ -25,6 +40,7 @@ use crate::{
 	paras::{self, SetGoAhead},
 	scheduler::{self, AvailabilityTimeoutStatus},
 	shared::{self, AllowedRelayParentsTracker},
+	FeeTracker,
 };
 use bitvec::{order::Lsb0 as BitOrderLsb0, vec::BitVec};
 use frame_support::{
@@ -44,7 +60,10 @@ use primitives::{
 	ValidatorId, ValidatorIndex, ValidityAttestation,
 };
 use scale_info::TypeInfo;
-use sp_runtime::{traits::One, DispatchError, SaturatedConversion, Saturating};
+use sp_runtime::{
+	traits::{CheckedDiv, One},
+	DispatchError, FixedPointNumber, FixedU128, SaturatedConversion, Saturating,
+};
 #[cfg(feature = "std")]
 use sp_std::fmt;
 use sp_std::{
@@ -62,6 +81,7 @@ mod benchmarking;
 
 pub trait WeightInfo {
 	fn receive_upward_messages(i: u32) -> Weight;
+	fn force_reset_upward_delivery_fee_factor() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -69,12 +89,18 @@ impl WeightInfo for TestWeightInfo {
 	fn receive_upward_messages(_: u32) -> Weight {
 		Weight::MAX
 	}
+	fn force_reset_upward_delivery_fee_factor() -> Weight {
+		Weight::MAX
+	}
 }
 
 impl WeightInfo for () {
 	fn receive_upward_messages(_: u32) -> Weight {
 		Weight::zero()
 	}
+	fn force_reset_upward_delivery_fee_factor() -> Weight {
+		Weight::zero()
+	}
 }
 
 /// Maximum value that `config.max_upward_message_size` can be set to.
@@ -185,6 +211,20 @@ pub trait RewardValidators {
 	fn reward_bitfields(validators: impl IntoIterator<Item = ValidatorIndex>);
 }
 
+impl<A: RewardValidators, B: RewardValidators> RewardValidators for (A, B) {
+	fn reward_backing(validators: impl IntoIterator<Item = ValidatorIndex>) {
+		let validators: Vec<_> = validators.into_iter().collect();
+		A::reward_backing(validators.iter().copied());
+		B::reward_backing(validators);
+	}
+
+	fn reward_bitfields(validators: impl IntoIterator<Item = ValidatorIndex>) {
+		let validators: Vec<_> = validators.into_iter().collect();
+		A::reward_bitfields(validators.iter().copied());
+		B::reward_bitfields(validators);
+	}
+}
+
 /// Helper return type for `process_candidates`.
 #[derive(Encode, Decode, PartialEq, TypeInfo)]
 #[cfg_attr(test, derive(Debug))]
@@ -283,6 +323,25 @@ pub mod pallet {
 
 		/// Weight info for the calls of this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The upward delivery fee factor of a para grows while its queue holds more than
+		/// `max_upward_queue_count` divided by this number of messages.
+		#[pallet::constant]
+		type UpwardFeeThresholdFactor: Get<u32>;
+
+		/// The factor the upward delivery fee factor is multiplied by for every message above the
+		/// threshold, and divided by when it decreases.
+		#[pallet::constant]
+		type UpwardExponentialFeeBase: Get<FixedU128>;
+
+		/// Added to `UpwardExponentialFeeBase` for every KiB of a message above the threshold.
+		#[pallet::constant]
+		type UpwardMessageSizeFeeBase: Get<FixedU128>;
+
+		/// The number of blocks after which the upward delivery fee factor of a para decreases
+		/// once if it was not updated in the meantime.
+		#[pallet::constant]
+		type UpwardFeeDecayPeriod: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::event]
@@ -296,6 +355,8 @@ pub mod pallet {
 		CandidateTimedOut(CandidateReceipt<T::Hash>, HeadData, CoreIndex),
 		/// Some upward messages have been received and will be processed.
 		UpwardMessagesReceived { from: ParaId, count: u32 },
+		/// The upward delivery fee factor of a para was reset by root.
+		UpwardDeliveryFeeFactorReset { para: ParaId },
 	}
 
 	#[pallet::error]
@@ -384,8 +445,47 @@ pub mod pallet {
 	pub(crate) type PendingAvailabilityCommitments<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, CandidateCommitments>;
 
+	/// The number of candidates of each para which were backed but never included, because they
+	/// timed out, were disputed or were still pending availability at a session change.
+	#[pallet::storage]
+	pub type BackedNotIncluded<T: Config> = StorageMap<_, Twox64Concat, ParaId, u32, ValueQuery>;
+
+	/// Initialization value for the upward delivery fee factor.
+	#[pallet::type_value]
+	pub fn InitialUpwardFeeFactor() -> FixedU128 {
+		FixedU128::from_u32(1)
+	}
+
+	/// The factor to multiply the base upward delivery fee by, per para, and the block it was last
+	/// updated at.
+	///
+	/// Grows exponentially while the para's upward message queue is above the threshold and
+	/// decays over time, see the module docs. Absent while it is at its initial value.
+	#[pallet::storage]
+	pub(crate) type UpwardDeliveryFeeFactor<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, (FixedU128, BlockNumberFor<T>)>;
+
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Reset the upward delivery fee factor of a para to its initial value.
+		///
+		/// This lifts any throttling of the para's upward messages caused by the factor.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_reset_upward_delivery_fee_factor())]
+		pub fn force_reset_upward_delivery_fee_factor(
+			origin: OriginFor<T>,
+			para: ParaId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			UpwardDeliveryFeeFactor::<T>::remove(para);
+			let (count, size) = Self::relay_dispatch_queue_size(para);
+			Self::note_remaining_capacity(&configuration::Pallet::<T>::config(), para, count, size);
+
+			Self::deposit_event(Event::UpwardDeliveryFeeFactorReset { para });
+			Ok(())
+		}
+	}
 }
 
 const LOG_TARGET: &str = "runtime::inclusion";
@@ -423,6 +523,8 @@ pub(crate) enum UmpAcceptanceCheckErr {
 	TotalSizeExceeded { total_size: u64, limit: u64 },
 	/// A para-chain cannot send UMP messages while it is offboarding.
 	IsOffboarding,
+	/// More messages than permitted by the upward delivery fee factor were sent.
+	CapacityThrottled { sent: u32, permitted: u32 },
 }
 
 #[cfg(feature = "std")]
@@ -452,6 +554,11 @@ impl fmt::Debug for UmpAcceptanceCheckErr {
 			UmpAcceptanceCheckErr::IsOffboarding => {
 				write!(fmt, "upward message rejected because the para is off-boarding")
 			},
+			UmpAcceptanceCheckErr::CapacityThrottled { sent, permitted } => write!(
+				fmt,
+				"more upward messages than permitted by the delivery fee factor ({} > {})",
+				sent, permitted,
+			),
 		}
 	}
 }
@@ -473,9 +580,14 @@ impl<T: Config> Pallet<T> {
 		// unlike most drain methods, drained elements are not cleared on `Drop` of the iterator
 		// and require consumption.
 		for _ in <PendingAvailabilityCommitments<T>>::drain() {}
-		for _ in <PendingAvailability<T>>::drain() {}
+		for (para_id, _) in <PendingAvailability<T>>::drain() {
+			Self::note_backed_not_included(para_id);
+		}
 		for _ in <AvailabilityBitfields<T>>::drain() {}
 
+		for outgoing_para in outgoing_paras {
+			<BackedNotIncluded<T>>::remove(outgoing_para);
+		}
 		Self::cleanup_outgoing_ump_dispatch_queues(outgoing_paras);
 	}
 
@@ -487,6 +599,7 @@ impl<T: Config> Pallet<T> {
 
 	pub(crate) fn cleanup_outgoing_ump_dispatch_queue(para: ParaId) {
 		T::MessageQueue::sweep_queue(AggregateMessageOrigin::Ump(UmpQueueId::Para(para)));
+		UpwardDeliveryFeeFactor::<T>::remove(para);
 	}
 
 	/// Extract the freed cores based on cores that became available.
@@ -928,6 +1041,49 @@ impl<T: Config> Pallet<T> {
 		(fp.storage.count as u32, fp.storage.size as u32)
 	}
 
+	/// The number of messages `para` can still send with `count` messages in its queue.
+	///
+	/// This is the remaining capacity of the queue throttled by the para's
+	/// `UpwardDeliveryFeeFactor`, but never zero while the queue still has room left.
+	fn throttled_remaining_count(
+		config: &HostConfiguration<BlockNumberFor<T>>,
+		para: ParaId,
+		count: u32,
+	) -> u32 {
+		let remaining_count = config.max_upward_queue_count.saturating_sub(count);
+		Self::get_fee_factor(para)
+			.reciprocal()
+			.map_or(remaining_count, |r| r.saturating_mul_int(remaining_count))
+			.max(remaining_count.min(1))
+	}
+
+	/// Write the remaining capacity of the para's upward message queue into
+	/// `relay_dispatch_queue_remaining_capacity`.
+	///
+	/// The remaining message count is throttled, see [`Self::throttled_remaining_count`].
+	fn note_remaining_capacity(
+		config: &HostConfiguration<BlockNumberFor<T>>,
+		para: ParaId,
+		count: u32,
+		size: u32,
+	) {
+		let remaining_size = config.max_upward_queue_size.saturating_sub(size);
+		well_known_keys::relay_dispatch_queue_remaining_capacity(para)
+			.set((Self::throttled_remaining_count(config, para, count), remaining_size));
+	}
+
+	/// The queue length above which the upward delivery fee factor grows.
+	fn upward_fee_threshold(config: &HostConfiguration<BlockNumberFor<T>>) -> u32 {
+		config
+			.max_upward_queue_count
+			.saturating_div(T::UpwardFeeThresholdFactor::get().max(1))
+	}
+
+	/// The current upward delivery fee factor of `para`.
+	pub(crate) fn upward_delivery_fee_factor(para: ParaId) -> FixedU128 {
+		Self::get_fee_factor(para)
+	}
+
 	/// Check that all the upward messages sent by a candidate pass the acceptance criteria.
 	pub(crate) fn check_upward_messages(
 		config: &HostConfiguration<BlockNumberFor<T>>,
@@ -956,6 +1112,14 @@ impl<T: Config> Pallet<T> {
 			})
 		}
 
+		let permitted = Self::throttled_remaining_count(config, para, para_queue_count);
+		if additional_msgs > permitted {
+			return Err(UmpAcceptanceCheckErr::CapacityThrottled {
+				sent: additional_msgs,
+				permitted,
+			})
+		}
+
 		for (idx, msg) in upward_messages.into_iter().enumerate() {
 			let msg_size = msg.len() as u32;
 			if msg_size > config.max_upward_message_size {
@@ -1010,6 +1174,20 @@ impl<T: Config> Pallet<T> {
 			return Weight::zero()
 		}
 
+		// The factor is updated before enqueueing, such that the queue change handler already
+		// advertises the remaining capacity throttled by the new factor.
+		let config = <configuration::Pallet<T>>::config();
+		let threshold = Self::upward_fee_threshold(&config);
+		let (mut queue_count, _) = Self::relay_dispatch_queue_size(para);
+		for msg in messages.iter() {
+			queue_count.saturating_inc();
+			if queue_count > threshold {
+				let message_size_factor = FixedU128::from((msg.len() / 1024) as u128)
+					.saturating_mul(T::UpwardMessageSizeFeeBase::get());
+				Self::increase_fee_factor(para, message_size_factor);
+			}
+		}
+
 		T::MessageQueue::enqueue_messages(
 			messages.into_iter(),
 			AggregateMessageOrigin::Ump(UmpQueueId::Para(para)),
@@ -1041,6 +1219,7 @@ impl<T: Config> Pallet<T> {
 		for para_id in cleaned_up_ids {
 			let pending = <PendingAvailability<T>>::take(&para_id);
 			let commitments = <PendingAvailabilityCommitments<T>>::take(&para_id);
+			Self::note_backed_not_included(para_id);
 
 			if let (Some(pending), Some(commitments)) = (pending, commitments) {
 				// defensive: this should always be true.
@@ -1077,11 +1256,21 @@ impl<T: Config> Pallet<T> {
 		for para_id in cleaned_up_ids {
 			let _ = <PendingAvailability<T>>::take(&para_id);
 			let _ = <PendingAvailabilityCommitments<T>>::take(&para_id);
+			Self::note_backed_not_included(para_id);
 		}
 
 		cleaned_up_cores
 	}
 
+	/// Returns the number of candidates of the para which were backed but never included.
+	pub fn backed_not_included(para: ParaId) -> u32 {
+		<BackedNotIncluded<T>>::get(para)
+	}
+
+	fn note_backed_not_included(para: ParaId) {
+		<BackedNotIncluded<T>>::mutate(para, |count| *count = count.saturating_add(1));
+	}
+
 	/// Forcibly enact the candidate with the given ID as though it had been deemed available
 	/// by bitfields.
 	///
@@ -1160,10 +1349,58 @@ impl<T: Config> OnQueueChanged<AggregateMessageOrigin> for Pallet<T> {
 		well_known_keys::relay_dispatch_queue_size_typed(para).set((count, size));
 
 		let config = <configuration::Pallet<T>>::config();
-		let remaining_count = config.max_upward_queue_count.saturating_sub(count);
-		let remaining_size = config.max_upward_queue_size.saturating_sub(size);
-		well_known_keys::relay_dispatch_queue_remaining_capacity(para)
-			.set((remaining_count, remaining_size));
+		if count <= Self::upward_fee_threshold(&config) {
+			Self::decrease_fee_factor(para);
+		}
+
+		Self::note_remaining_capacity(&config, para, count, size);
+	}
+}
+
+impl<T: Config> FeeTracker for Pallet<T> {
+	type Id = ParaId;
+
+	fn get_fee_factor(id: Self::Id) -> FixedU128 {
+		let Some((factor, updated_at)) = UpwardDeliveryFeeFactor::<T>::get(id) else {
+			return InitialUpwardFeeFactor::get()
+		};
+		let elapsed = frame_system::Pallet::<T>::block_number().saturating_sub(updated_at);
+		let steps = elapsed / T::UpwardFeeDecayPeriod::get().max(One::one());
+		Self::divide_fee_factor(factor, steps.saturated_into())
+	}
+
+	fn increase_fee_factor(id: Self::Id, message_size_factor: FixedU128) -> FixedU128 {
+		let factor = Self::get_fee_factor(id)
+			.saturating_mul(T::UpwardExponentialFeeBase::get().saturating_add(message_size_factor));
+		Self::set_fee_factor(id, factor)
+	}
+
+	fn decrease_fee_factor(id: Self::Id) -> FixedU128 {
+		let factor = Self::divide_fee_factor(Self::get_fee_factor(id), 1);
+		Self::set_fee_factor(id, factor)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Divide `factor` by `UpwardExponentialFeeBase` `times` times, but not below its initial
+	/// value.
+	fn divide_fee_factor(factor: FixedU128, times: usize) -> FixedU128 {
+		let divisor = T::UpwardExponentialFeeBase::get().saturating_pow(times);
+		factor
+			.checked_div(&divisor)
+			.unwrap_or_else(InitialUpwardFeeFactor::get)
+			.max(InitialUpwardFeeFactor::get())
+	}
+
+	/// Record `factor` as the upward delivery fee factor of `para` at the current block.
+	fn set_fee_factor(para: ParaId, factor: FixedU128) -> FixedU128 {
+		if factor == InitialUpwardFeeFactor::get() {
+			UpwardDeliveryFeeFactor::<T>::remove(para);
+		} else {
+			let now = frame_system::Pallet::<T>::block_number();
+			UpwardDeliveryFeeFactor::<T>::insert(para, (factor, now));
+		}
+		factor
 	}
 }
 
@@ -1208,6 +1445,18 @@ impl<T: Config> CandidateCheckContext<T> {
 			}
 		};
 
+		// The allowed ancestry of the para may be overridden to less than the global one, which
+		// the allowed relay-parents are already bounded by. Overrides only change at a session
+		// boundary, when the allowed relay-parents are cleared, so candidates built on the
+		// ancestry allowed before are never rejected.
+		if let Some(params) = configuration::AsyncBackingParamsOverrides::<T>::get(para_id) {
+			ensure!(
+				allowed_relay_parents
+					.is_within_ancestry(relay_parent_number, params.allowed_ancestry_len),
+				Error::<T>::DisallowedRelayParent,
+			);
+		}
+
 		{
 			let persisted_validation_data = match crate::util::make_persisted_validation_data::<T>(
 				para_id,
@@ -429,6 +429,8 @@ fn collect_pending_cleans_up_pending() {
 		assert!(<PendingAvailability<Test>>::get(&chain_b).is_some());
 		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_none());
 		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_some());
+		assert_eq!(ParaInclusion::backed_not_included(chain_a), 1);
+		assert_eq!(ParaInclusion::backed_not_included(chain_b), 0);
 	});
 }
 
@@ -2087,6 +2089,8 @@ fn session_change_wipes() {
 		assert!(<PendingAvailability<Test>>::get(&chain_b).is_none());
 		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_a).is_none());
 		assert!(<PendingAvailabilityCommitments<Test>>::get(&chain_b).is_none());
+		assert_eq!(ParaInclusion::backed_not_included(chain_a), 1);
+		assert_eq!(ParaInclusion::backed_not_included(chain_b), 1);
 
 		assert!(<AvailabilityBitfields<Test>>::iter().collect::<Vec<_>>().is_empty());
 		assert!(<PendingAvailability<Test>>::iter().collect::<Vec<_>>().is_empty());
@@ -29,7 +29,9 @@ pub mod assigner_parachains;
 pub mod configuration;
 pub mod disputes;
 pub mod dmp;
+pub mod finality_lag;
 pub mod hrmp;
+pub mod inactivity;
 pub mod inclusion;
 pub mod initializer;
 pub mod metrics;
@@ -17,7 +17,8 @@
 //! Mocks for all the traits.
 
 use crate::{
-	assigner, assigner_on_demand, assigner_parachains, configuration, disputes, dmp, hrmp,
+	assigner, assigner_on_demand, assigner_parachains, configuration, disputes, dmp, finality_lag,
+	hrmp, inactivity,
 	inclusion::{self, AggregateMessageOrigin, UmpQueueId},
 	initializer, origin, paras,
 	paras::ParaKind,
@@ -25,7 +26,7 @@ use crate::{
 };
 
 use frame_support::{
-	assert_ok, parameter_types,
+	assert_ok, derive_impl, parameter_types,
 	traits::{
 		Currency, ProcessMessage, ProcessMessageError, ValidatorSet, ValidatorSetWithIdentification,
 	},
@@ -45,6 +46,7 @@ use sp_runtime::{
 	transaction_validity::TransactionPriority,
 	BuildStorage, FixedU128, Perbill, Permill,
 };
+use sp_staking::offence::{OffenceError, ReportOffence};
 use std::{cell::RefCell, collections::HashMap};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -71,6 +73,8 @@ frame_support::construct_runtime!(
 		ParachainsOrigin: origin,
 		SessionInfo: session_info,
 		Disputes: disputes,
+		FinalityLag: finality_lag,
+		Inactivity: inactivity,
 		Babe: pallet_babe,
 	}
 );
@@ -94,6 +98,7 @@ parameter_types! {
 
 pub type AccountId = u64;
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = BlockWeights;
@@ -322,6 +327,12 @@ impl pallet_message_queue::WeightInfo for TestMessageQueueWeight {
 	fn execute_overweight_page_updated() -> Weight {
 		Weight::zero()
 	}
+	fn discard_dead_letter() -> Weight {
+		Weight::zero()
+	}
+	fn requeue_dead_letter() -> Weight {
+		Weight::zero()
+	}
 }
 parameter_types! {
 	pub const MessageQueueServiceWeight: Weight = Weight::from_all(500);
@@ -338,6 +349,8 @@ impl pallet_message_queue::Config for Test {
 	type QueuePausedQuery = ();
 	type HeapSize = ConstU32<65536>;
 	type MaxStale = ConstU32<8>;
+	type MaxDeadLetters = ConstU32<64>;
+	type DeadLetterOrigin = frame_system::EnsureRoot<u64>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
@@ -359,12 +372,23 @@ impl assigner_on_demand::Config for Test {
 	type WeightInfo = crate::assigner_on_demand::TestWeightInfo;
 }
 
+parameter_types! {
+	pub const UpwardFeeThresholdFactor: u32 = 2;
+	pub const UpwardExponentialFeeBase: FixedU128 = FixedU128::from_rational(105, 100);
+	pub const UpwardMessageSizeFeeBase: FixedU128 = FixedU128::from_rational(1, 1000);
+	pub const UpwardFeeDecayPeriod: BlockNumber = 10;
+}
+
 impl crate::inclusion::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = Disputes;
-	type RewardValidators = TestRewardValidators;
+	type RewardValidators = (TestRewardValidators, Inactivity);
 	type MessageQueue = MessageQueue;
+	type UpwardFeeThresholdFactor = UpwardFeeThresholdFactor;
+	type UpwardExponentialFeeBase = UpwardExponentialFeeBase;
+	type UpwardMessageSizeFeeBase = UpwardMessageSizeFeeBase;
+	type UpwardFeeDecayPeriod = UpwardFeeDecayPeriod;
 }
 
 impl crate::paras_inherent::Config for Test {
@@ -407,6 +431,43 @@ impl crate::session_info::Config for Test {
 	type ValidatorSet = MockValidatorSet;
 }
 
+impl crate::finality_lag::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type LagThreshold = ConstU32<10>;
+	type MaxReportedLag = ConstU32<1000>;
+	type WeightInfo = crate::finality_lag::TestWeightInfo;
+}
+
+parameter_types! {
+	pub static InactivityOffences: Vec<inactivity::InactivityOffence<(AccountId, ())>> = vec![];
+}
+
+pub struct TestReportInactivity;
+
+impl ReportOffence<AccountId, (AccountId, ()), inactivity::InactivityOffence<(AccountId, ())>>
+	for TestReportInactivity
+{
+	fn report_offence(
+		_: Vec<AccountId>,
+		offence: inactivity::InactivityOffence<(AccountId, ())>,
+	) -> Result<(), OffenceError> {
+		InactivityOffences::mutate(|offences| offences.push(offence));
+		Ok(())
+	}
+
+	fn is_known_offence(_: &[(AccountId, ())], _: &SessionIndex) -> bool {
+		false
+	}
+}
+
+impl crate::inactivity::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MinAuthoredBlocks = ConstU32<1>;
+	type MinAvailabilityVotes = ConstU32<2>;
+	type ReportInactivity = TestReportInactivity;
+	type WeightInfo = crate::inactivity::TestWeightInfo;
+}
+
 thread_local! {
 	pub static DISCOVERY_AUTHORITIES: RefCell<Vec<AuthorityDiscoveryId>> = RefCell::new(Vec::new());
 }
@@ -118,6 +118,7 @@ use frame_support::{pallet_prelude::*, traits::EstimateNextSessionRotation, Defa
 use frame_system::pallet_prelude::*;
 use parity_scale_codec::{Decode, Encode};
 use primitives::{
+	vstaging::{PendingUpgrade, UpgradeStatus},
 	ConsensusLog, HeadData, Id as ParaId, PvfCheckStatement, SessionIndex, UpgradeGoAhead,
 	UpgradeRestriction, ValidationCode, ValidationCodeHash, ValidatorSignature,
 };
@@ -2134,6 +2135,22 @@ impl<T: Config> Pallet<T> {
 		FutureCodeHash::<T>::get(&id).is_none() && UpgradeRestrictionSignal::<T>::get(&id).is_none()
 	}
 
+	/// Returns the status of the para `id` with regards to upgrading its validation code.
+	pub(crate) fn upgrade_status(id: ParaId) -> UpgradeStatus<BlockNumberFor<T>> {
+		let cooldown_until = UpgradeCooldowns::<T>::get()
+			.into_iter()
+			.find_map(|(para, at)| (para == id).then_some(at));
+		let pending_upgrade = FutureCodeHash::<T>::get(&id).map(|code_hash| PendingUpgrade {
+			code_hash,
+			expected_at: FutureCodeUpgrades::<T>::get(&id),
+		});
+		UpgradeStatus {
+			restriction: UpgradeRestrictionSignal::<T>::get(&id),
+			cooldown_until,
+			pending_upgrade,
+		}
+	}
+
 	/// Return the session index that should be used for any future scheduled changes.
 	fn scheduled_session() -> SessionIndex {
 		shared::Pallet::<T>::scheduled_session()
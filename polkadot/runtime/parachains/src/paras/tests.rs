@@ -875,6 +875,106 @@ fn upgrade_restriction_elapsed_doesnt_mean_can_upgrade() {
 	});
 }
 
+#[test]
+fn upgrade_status_reports_pending_upgrade_and_cooldown() {
+	let validation_upgrade_delay = 7;
+	let validation_upgrade_cooldown = 30;
+
+	let paras = vec![(
+		0u32.into(),
+		ParaGenesisArgs {
+			para_kind: ParaKind::Parachain,
+			genesis_head: dummy_head_data(),
+			validation_code: vec![1, 2, 3].into(),
+		},
+	)];
+
+	let genesis_config = MockGenesisConfig {
+		paras: GenesisConfig { paras, ..Default::default() },
+		configuration: crate::configuration::GenesisConfig {
+			config: HostConfiguration {
+				validation_upgrade_delay,
+				validation_upgrade_cooldown,
+				..Default::default()
+			},
+		},
+		..Default::default()
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		let para_id = 0u32.into();
+		let new_code = ValidationCode(vec![4, 5, 6]);
+
+		// Wait for at least one session change to set active validators.
+		const EXPECTED_SESSION: SessionIndex = 1;
+		run_to_block(1, Some(vec![1]));
+
+		let status = Paras::upgrade_status(para_id);
+		assert_eq!(
+			status,
+			UpgradeStatus { restriction: None, cooldown_until: None, pending_upgrade: None }
+		);
+		assert!(status.can_upgrade());
+
+		Paras::schedule_code_upgrade(
+			para_id,
+			new_code.clone(),
+			0,
+			&Configuration::config(),
+			SetGoAhead::Yes,
+		);
+
+		// The new code is being pre-checked.
+		let status = Paras::upgrade_status(para_id);
+		assert_eq!(
+			status,
+			UpgradeStatus {
+				restriction: Some(UpgradeRestriction::Present),
+				cooldown_until: Some(validation_upgrade_cooldown),
+				pending_upgrade: Some(PendingUpgrade {
+					code_hash: new_code.hash(),
+					expected_at: None
+				}),
+			}
+		);
+		assert!(!status.can_upgrade());
+
+		// Include votes for super-majority.
+		submit_super_majority_pvf_votes(&new_code, EXPECTED_SESSION, true);
+		Paras::note_new_head(para_id, dummy_head_data(), 0);
+		assert_eq!(
+			Paras::upgrade_status(para_id).pending_upgrade,
+			Some(PendingUpgrade {
+				code_hash: new_code.hash(),
+				expected_at: Some(validation_upgrade_delay)
+			}),
+		);
+
+		// Once the upgrade is applied, the para is still in cooldown.
+		run_to_block(validation_upgrade_delay + 1, None);
+		Paras::note_new_head(para_id, dummy_head_data(), validation_upgrade_delay);
+		let status = Paras::upgrade_status(para_id);
+		assert_eq!(
+			status,
+			UpgradeStatus {
+				restriction: Some(UpgradeRestriction::Present),
+				cooldown_until: Some(validation_upgrade_cooldown),
+				pending_upgrade: None,
+			}
+		);
+		assert!(!status.can_upgrade());
+
+		run_to_block(validation_upgrade_cooldown + 1, None);
+		let status = Paras::upgrade_status(para_id);
+		assert_eq!(
+			status,
+			UpgradeStatus { restriction: None, cooldown_until: None, pending_upgrade: None }
+		);
+		assert!(status.can_upgrade());
+		assert!(Paras::can_upgrade_validation_code(para_id));
+	});
+}
+
 #[test]
 fn full_parachain_cleanup_storage() {
 	let code_retention_period = 20;
@@ -19,6 +19,7 @@
 //! the relay chain, but we do care about the size of the block, by putting the tx in the
 //! proof_size we can use the already existing weight limiting code to limit the used size as well.
 
+use frame_support::traits::Get;
 use parity_scale_codec::{Encode, WrapperTypeEncode};
 use primitives::{
 	CheckedMultiDisputeStatementSet, MultiDisputeStatementSet, UncheckedSignedAvailabilityBitfield,
@@ -158,6 +159,10 @@ pub fn backed_candidate_weight<T: frame_system::Config + Config>(
 		},
 		candidate,
 	)
+	// A backed candidate which is never included bumps its para's `BackedNotIncluded` counter
+	// once, when it times out, is disputed or is dropped at a session change. Those paths aren't
+	// weighed on their own, so the access is charged upfront.
+	.saturating_add(T::DbWeight::get().reads_writes(1, 1))
 }
 
 pub fn backed_candidates_weight<T: frame_system::Config + Config>(
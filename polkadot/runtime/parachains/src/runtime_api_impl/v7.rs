@@ -376,7 +376,13 @@ pub fn session_executor_params<T: session_info::Config>(
 /// Implementation of `unapplied_slashes` runtime API
 pub fn unapplied_slashes<T: disputes::slashing::Config>(
 ) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+	// `AgainstValidNarrowly` was added in v15 and cannot be decoded by older nodes.
 	<disputes::slashing::Pallet<T>>::unapplied_slashes()
+		.into_iter()
+		.filter(|(_, _, pending)| {
+			pending.kind != slashing::SlashingOffenceKind::AgainstValidNarrowly
+		})
+		.collect()
 }
 
 /// Implementation of `submit_report_dispute_lost` runtime API
@@ -402,6 +408,7 @@ pub fn backing_state<T: initializer::Config>(
 	para_id: ParaId,
 ) -> Option<BackingState<T::Hash, BlockNumberFor<T>>> {
 	let config = <configuration::Pallet<T>>::config();
+	let async_backing_params = <configuration::Pallet<T>>::async_backing_params_for(para_id);
 	// Async backing is only expected to be enabled with a tracker capacity of 1.
 	// Subsequent configuration update gets applied on new session, which always
 	// clears the buffer.
@@ -409,7 +416,7 @@ pub fn backing_state<T: initializer::Config>(
 	// Thus, minimum relay parent is ensured to have asynchronous backing enabled.
 	let now = <frame_system::Pallet<T>>::block_number();
 	let min_relay_parent_number = <shared::Pallet<T>>::allowed_relay_parents()
-		.hypothetical_earliest_block_number(now, config.async_backing_params.allowed_ancestry_len);
+		.hypothetical_earliest_block_number(now, async_backing_params.allowed_ancestry_len);
 
 	let required_parent = <paras::Pallet<T>>::para_head(para_id)?;
 	let validation_code_hash = <paras::Pallet<T>>::current_code_hash(para_id)?;
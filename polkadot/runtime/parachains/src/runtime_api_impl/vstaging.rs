@@ -16,8 +16,16 @@
 
 //! Put implementations of functions from staging APIs here.
 
-use crate::{configuration, initializer, shared};
-use primitives::{vstaging::NodeFeatures, ValidatorIndex};
+use crate::{
+	configuration, disputes, inclusion, initializer, paras, scheduler, session_info, shared,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use primitives::{
+	slashing,
+	vstaging::{CoreBackingGroup, NodeFeatures, UpgradeStatus},
+	AsyncBackingParams, CandidateHash, CoreIndex, Id as ParaId, SessionIndex, ValidatorIndex,
+};
+use sp_runtime::{traits::One, FixedU128};
 use sp_std::{collections::btree_map::BTreeMap, prelude::Vec};
 
 /// Implementation for `DisabledValidators`
@@ -47,3 +55,80 @@ where
 pub fn node_features<T: initializer::Config>() -> NodeFeatures {
 	<configuration::Pallet<T>>::config().node_features
 }
+
+/// Implementation for the `para_upgrade_status` function of the runtime API.
+pub fn para_upgrade_status<T: paras::Config>(
+	para_id: ParaId,
+) -> Option<UpgradeStatus<BlockNumberFor<T>>> {
+	<paras::Pallet<T>>::lifecycle(para_id).map(|_| <paras::Pallet<T>>::upgrade_status(para_id))
+}
+
+/// Implementation for the `upward_delivery_fee_factor` function of the runtime API.
+pub fn upward_delivery_fee_factor<T: inclusion::Config>(para_id: ParaId) -> FixedU128 {
+	<inclusion::Pallet<T>>::upward_delivery_fee_factor(para_id)
+}
+
+/// Implementation for the `backing_groups` function of the runtime API.
+pub fn backing_groups<T: initializer::Config>() -> Vec<CoreBackingGroup<BlockNumberFor<T>>> {
+	let now = <frame_system::Pallet<T>>::block_number() + One::one();
+	let groups = <scheduler::Pallet<T>>::validator_groups();
+	let n_cores = <scheduler::Pallet<T>>::availability_cores().len() as u32;
+
+	(0..n_cores)
+		.filter_map(|core| backing_group_at::<T>(&groups, CoreIndex(core), now))
+		.collect()
+}
+
+/// Implementation for the `validator_groups_for_core` function of the runtime API.
+pub fn validator_groups_for_core<T: initializer::Config>(
+	core: CoreIndex,
+) -> Vec<CoreBackingGroup<BlockNumberFor<T>>> {
+	let now = <frame_system::Pallet<T>>::block_number() + One::one();
+	let groups = <scheduler::Pallet<T>>::validator_groups();
+
+	let Some(current) = backing_group_at::<T>(&groups, core, now) else { return Vec::new() };
+	let next = backing_group_at::<T>(&groups, core, current.until);
+
+	sp_std::iter::once(current).chain(next).collect()
+}
+
+/// Implementation for the `para_async_backing_params` function of the runtime API.
+pub fn para_async_backing_params<T: configuration::Config>(para_id: ParaId) -> AsyncBackingParams {
+	<configuration::Pallet<T>>::async_backing_params_for(para_id)
+}
+
+/// Implementation for the `stored_session_info_range` function of the runtime API.
+pub fn stored_session_info_range<T: session_info::Config>() -> (SessionIndex, SessionIndex) {
+	<session_info::Pallet<T>>::stored_session_range()
+}
+
+/// Implementation for the `unapplied_slashes_v2` function of the runtime API.
+pub fn unapplied_slashes_v2<T: disputes::slashing::Config>(
+) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+	<disputes::slashing::Pallet<T>>::unapplied_slashes()
+}
+
+/// Implementation for the `backed_not_included` function of the runtime API.
+pub fn backed_not_included<T: inclusion::Config>(para_id: ParaId) -> u32 {
+	<inclusion::Pallet<T>>::backed_not_included(para_id)
+}
+
+// Groups are reshuffled on session changes only, so this is only accurate for blocks in the
+// current session.
+fn backing_group_at<T: initializer::Config>(
+	groups: &[Vec<ValidatorIndex>],
+	core: CoreIndex,
+	at: BlockNumberFor<T>,
+) -> Option<CoreBackingGroup<BlockNumberFor<T>>> {
+	let group = <scheduler::Pallet<T>>::group_assigned_to_core(core, at)?;
+	let validators = groups.get(group.0 as usize)?.clone();
+	let rotation_info = <scheduler::Pallet<T>>::group_rotation_info(at);
+
+	Some(CoreBackingGroup {
+		core,
+		group,
+		validators,
+		from: rotation_info.last_rotation_at(),
+		until: rotation_info.next_rotation_at(),
+	})
+}
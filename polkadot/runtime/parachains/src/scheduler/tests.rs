@@ -904,6 +904,73 @@ fn schedule_rotates_groups() {
 	});
 }
 
+#[test]
+fn backing_groups_runtime_api_follows_rotations() {
+	use crate::runtime_api_impl::vstaging::{backing_groups, validator_groups_for_core};
+	use primitives::vstaging::CoreBackingGroup;
+
+	let mut config = default_config();
+	config.on_demand_cores = 2;
+	let rotation_frequency = config.group_rotation_frequency;
+
+	let genesis_config = genesis_config(&config);
+
+	let backing_group = |core: u32, group: u32, from: BlockNumber| CoreBackingGroup {
+		core: CoreIndex(core),
+		group: GroupIndex(group),
+		validators: vec![ValidatorIndex(group * 2), ValidatorIndex(group * 2 + 1)],
+		from,
+		until: from + rotation_frequency,
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		// 4 validators for 2 cores.
+		run_to_block(1, |number| match number {
+			1 => Some(SessionChangeNotification {
+				new_config: config.clone(),
+				validators: vec![
+					ValidatorId::from(Sr25519Keyring::Alice.public()),
+					ValidatorId::from(Sr25519Keyring::Bob.public()),
+					ValidatorId::from(Sr25519Keyring::Charlie.public()),
+					ValidatorId::from(Sr25519Keyring::Dave.public()),
+				],
+				..Default::default()
+			}),
+			_ => None,
+		});
+		assert_eq!(Scheduler::session_start_block(), 1);
+
+		run_to_block(2, |_| None);
+
+		assert_eq!(Scheduler::availability_cores().len(), 2);
+		assert_eq!(backing_groups::<Test>(), vec![backing_group(0, 0, 1), backing_group(1, 1, 1)],);
+		assert_eq!(
+			validator_groups_for_core::<Test>(CoreIndex(0)),
+			vec![backing_group(0, 0, 1), backing_group(0, 1, 1 + rotation_frequency)],
+		);
+		assert!(validator_groups_for_core::<Test>(CoreIndex(2)).is_empty());
+
+		// The runtime API reports the assignments of the next block, which is the first one
+		// after the rotation.
+		run_to_block(rotation_frequency, |_| None);
+
+		assert_eq!(
+			backing_groups::<Test>(),
+			vec![
+				backing_group(0, 1, 1 + rotation_frequency),
+				backing_group(1, 0, 1 + rotation_frequency)
+			],
+		);
+		assert_eq!(
+			validator_groups_for_core::<Test>(CoreIndex(1)),
+			vec![
+				backing_group(1, 0, 1 + rotation_frequency),
+				backing_group(1, 1, 1 + 2 * rotation_frequency)
+			],
+		);
+	});
+}
+
 #[test]
 fn on_demand_claims_are_pruned_after_timing_out() {
 	let max_retries = 20;
@@ -39,6 +39,12 @@ pub mod migration;
 #[cfg(test)]
 mod tests;
 
+/// The maximum number of sessions pruned on a single session change.
+///
+/// Keeps session changes cheap when the retention is lowered by a lot: the excess sessions are
+/// then pruned over the following session changes instead.
+pub const MAX_PRUNED_SESSIONS_PER_SESSION_CHANGE: SessionIndex = 10;
+
 /// A type for representing the validator account id in a session.
 pub type AccountId<T> = <<T as Config>::ValidatorSet as ValidatorSet<
 	<T as frame_system::Config>::AccountId,
@@ -132,6 +138,8 @@ impl<T: Config> Pallet<T> {
 		let config = <configuration::Pallet<T>>::config();
 
 		let dispute_period = config.dispute_period;
+		// Session info is needed for disputes, so keep it for at least the dispute period.
+		let retention = core::cmp::max(config.session_info_retention, dispute_period);
 
 		let validators = notification.validators.clone().into();
 		let discovery_keys = <T as AuthorityDiscoveryConfig>::authorities();
@@ -149,9 +157,11 @@ impl<T: Config> Pallet<T> {
 		let new_session_index = notification.session_index;
 		let random_seed = notification.random_seed;
 		let old_earliest_stored_session = EarliestStoredSession::<T>::get();
-		let new_earliest_stored_session = new_session_index.saturating_sub(dispute_period);
-		let new_earliest_stored_session =
-			core::cmp::max(new_earliest_stored_session, old_earliest_stored_session);
+		let new_earliest_stored_session = new_session_index.saturating_sub(retention);
+		let new_earliest_stored_session = new_earliest_stored_session.clamp(
+			old_earliest_stored_session,
+			old_earliest_stored_session.saturating_add(MAX_PRUNED_SESSIONS_PER_SESSION_CHANGE),
+		);
 		// remove all entries from `Sessions` from the previous value up to the new value
 		// avoid a potentially heavy loop when introduced on a live chain
 		if old_earliest_stored_session != 0 || Sessions::<T>::get(0).is_some() {
@@ -162,7 +172,7 @@ impl<T: Config> Pallet<T> {
 				AccountKeys::<T>::remove(&idx);
 				SessionExecutorParams::<T>::remove(&idx);
 			}
-			// update `EarliestStoredSession` based on `config.dispute_period`
+			// update `EarliestStoredSession` based on the retention
 			EarliestStoredSession::<T>::set(new_earliest_stored_session);
 		} else {
 			// just introduced on a live chain
@@ -196,6 +206,11 @@ impl<T: Config> Pallet<T> {
 		SessionExecutorParams::<T>::insert(&new_session_index, config.executor_params);
 	}
 
+	/// The inclusive range of sessions for which session info is stored, as `(earliest, latest)`.
+	pub fn stored_session_range() -> (SessionIndex, SessionIndex) {
+		(EarliestStoredSession::<T>::get(), <shared::Pallet<T>>::session_index())
+	}
+
 	/// Called by the initializer to initialize the session info pallet.
 	pub(crate) fn initializer_initialize(_now: BlockNumberFor<T>) -> Weight {
 		Weight::zero()
@@ -143,6 +143,47 @@ fn session_pruning_is_based_on_dispute_period() {
 	})
 }
 
+#[test]
+fn session_pruning_follows_retention_and_is_bounded() {
+	new_test_ext(genesis_config()).execute_with(|| {
+		// Move to session 10, pruning based on the dispute period of 2
+		run_to_block(100, session_changes);
+		assert_eq!(SessionInfo::stored_session_range(), (8, 10));
+
+		// Retain more sessions than the dispute period
+		Configuration::set_session_info_retention(RuntimeOrigin::root(), 30).unwrap();
+
+		// Nothing is pruned until there are more than 30 stored sessions
+		run_to_block(380, session_changes);
+		assert_eq!(SessionInfo::stored_session_range(), (8, 38));
+		run_to_block(400, session_changes);
+		assert_eq!(SessionInfo::stored_session_range(), (10, 40));
+		assert!(Sessions::<Test>::get(9).is_none());
+		assert!(Sessions::<Test>::get(10).is_some());
+
+		// Fall back to the dispute period
+		Configuration::set_session_info_retention(RuntimeOrigin::root(), 0).unwrap();
+
+		// Once it kicks in, at most `MAX_PRUNED_SESSIONS_PER_SESSION_CHANGE` sessions are pruned
+		// per session change
+		run_to_block(410, session_changes);
+		assert_eq!(EarliestStoredSession::<Test>::get(), 11);
+		run_to_block(420, session_changes);
+		assert_eq!(
+			EarliestStoredSession::<Test>::get(),
+			11 + MAX_PRUNED_SESSIONS_PER_SESSION_CHANGE
+		);
+		assert!(Sessions::<Test>::get(20).is_none());
+		assert!(Sessions::<Test>::get(21).is_some());
+		run_to_block(440, session_changes);
+		assert_eq!(EarliestStoredSession::<Test>::get(), 41);
+
+		// until the pruning caught up with the dispute period
+		run_to_block(450, session_changes);
+		assert_eq!(SessionInfo::stored_session_range(), (43, 45));
+	})
+}
+
 #[test]
 fn session_info_is_based_on_config() {
 	new_test_ext(genesis_config()).execute_with(|| {
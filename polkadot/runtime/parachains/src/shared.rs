@@ -22,7 +22,7 @@
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::BlockNumberFor;
 use primitives::{SessionIndex, ValidatorId, ValidatorIndex};
-use sp_runtime::traits::AtLeast32BitUnsigned;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating};
 use sp_std::{collections::vec_deque::VecDeque, vec::Vec};
 
 use rand::{seq::SliceRandom, SeedableRng};
@@ -107,6 +107,12 @@ impl<Hash: PartialEq + Copy, BlockNumber: AtLeast32BitUnsigned + Copy>
 		Some((self.buffer[pos].1, number))
 	}
 
+	/// Whether the relay-parent with the given `number` is at most `max_ancestry_len` blocks older
+	/// than the most recent relay-parent.
+	pub(crate) fn is_within_ancestry(&self, number: BlockNumber, max_ancestry_len: u32) -> bool {
+		self.latest_number.saturating_sub(number) <= max_ancestry_len.into()
+	}
+
 	/// Returns block number of the earliest block the buffer would contain if
 	/// `now` is pushed into it.
 	pub(crate) fn hypothetical_earliest_block_number(
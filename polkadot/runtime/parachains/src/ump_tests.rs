@@ -33,7 +33,7 @@ use frame_support::{
 use primitives::{well_known_keys, Id as ParaId, UpwardMessage};
 use sp_core::twox_64;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::Bounded;
+use sp_runtime::{traits::Bounded, DispatchError, FixedU128};
 use sp_std::prelude::*;
 
 pub(super) struct GenesisConfigBuilder {
@@ -454,12 +454,166 @@ fn verify_relay_dispatch_queue_size_is_externally_accessible() {
 	});
 }
 
+#[test]
+fn upward_delivery_fee_factor_throttles_remaining_capacity() {
+	let para = 42.into();
+	let msg = 0u32.encode();
+	let config = GenesisConfigBuilder {
+		max_upward_queue_count: 8,
+		max_upward_queue_size: 1024,
+		..Default::default()
+	};
+
+	new_test_ext(config.build()).execute_with(|| {
+		// Up to the threshold of half the queue the factor does not change.
+		for _ in 0..4 {
+			queue_upward_msg(para, msg.clone());
+		}
+		assert_eq!(ParaInclusion::upward_delivery_fee_factor(para), FixedU128::from_u32(1));
+		assert_queue_remaining(para, 4, 1024 - 16);
+
+		// Every message above the threshold increases the factor exponentially.
+		queue_upward_msg(para, msg.clone());
+		queue_upward_msg(para, msg.clone());
+		assert_eq!(
+			ParaInclusion::upward_delivery_fee_factor(para),
+			FixedU128::from_rational(11025, 10000)
+		);
+		// The advertised count is throttled by the factor: 2 / 1.1025.
+		assert_queue_remaining(para, 1, 1024 - 24);
+		// A single message is still let through while the queue has room left.
+		queue_upward_msg(para, msg.clone());
+		queue_upward_msg(para, msg.clone());
+		assert_queue_remaining(para, 0, 1024 - 32);
+
+		// Draining the queue decreases the factor again.
+		MessageQueue::service_queues(Weight::max_value());
+		assert_eq!(Processed::take().len(), 8);
+		assert_eq!(
+			ParaInclusion::upward_delivery_fee_factor(para),
+			FixedU128::from_rational(1157625, 1000000)
+		);
+		assert_queue_remaining(para, 6, 1024);
+	});
+}
+
+#[test]
+fn upward_delivery_fee_factor_throttles_sent_messages() {
+	let para = 42.into();
+	let msg = 0u32.encode();
+	let config = GenesisConfigBuilder {
+		max_upward_queue_count: 8,
+		max_upward_queue_size: 1024,
+		..Default::default()
+	};
+
+	new_test_ext(config.build()).execute_with(|| {
+		for _ in 0..6 {
+			queue_upward_msg(para, msg.clone());
+		}
+
+		// 2 / 1.1025 messages are permitted.
+		assert_eq!(
+			ParaInclusion::check_upward_messages(
+				&Configuration::config(),
+				para,
+				&[msg.clone(), msg.clone()]
+			),
+			Err(UmpAcceptanceCheckErr::CapacityThrottled { sent: 2, permitted: 1 })
+		);
+		assert_ok!(try_queue_upward_msg(para, msg.clone()));
+	});
+}
+
+#[test]
+fn upward_delivery_fee_factor_decays() {
+	let para = 42.into();
+	let msg = 0u32.encode();
+	let config = GenesisConfigBuilder {
+		max_upward_queue_count: 8,
+		max_upward_queue_size: 1024,
+		..Default::default()
+	};
+
+	new_test_ext(config.build()).execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..6 {
+			queue_upward_msg(para, msg.clone());
+		}
+		assert_eq!(
+			ParaInclusion::upward_delivery_fee_factor(para),
+			FixedU128::from_rational(11025, 10000)
+		);
+
+		// Decays by one step every `UpwardFeeDecayPeriod` blocks.
+		System::set_block_number(10);
+		assert_eq!(
+			ParaInclusion::upward_delivery_fee_factor(para),
+			FixedU128::from_rational(11025, 10000)
+		);
+		System::set_block_number(11);
+		assert_eq!(
+			ParaInclusion::upward_delivery_fee_factor(para),
+			FixedU128::from_rational(105, 100)
+		);
+
+		// But never below the initial value.
+		System::set_block_number(31);
+		assert_eq!(ParaInclusion::upward_delivery_fee_factor(para), FixedU128::from_u32(1));
+		assert_ok!(ParaInclusion::check_upward_messages(
+			&Configuration::config(),
+			para,
+			&[msg.clone(), msg.clone()]
+		));
+
+		// The decayed factor is the one growing again.
+		queue_upward_msg(para, msg.clone());
+		assert_eq!(
+			ParaInclusion::upward_delivery_fee_factor(para),
+			FixedU128::from_rational(105, 100)
+		);
+	});
+}
+
+#[test]
+fn force_reset_upward_delivery_fee_factor_works() {
+	let para = 42.into();
+	let msg = 0u32.encode();
+	let config = GenesisConfigBuilder {
+		max_upward_queue_count: 8,
+		max_upward_queue_size: 1024,
+		..Default::default()
+	};
+
+	new_test_ext(config.build()).execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..6 {
+			queue_upward_msg(para, msg.clone());
+		}
+		assert!(ParaInclusion::upward_delivery_fee_factor(para) > FixedU128::from_u32(1));
+
+		assert_noop!(
+			ParaInclusion::force_reset_upward_delivery_fee_factor(RuntimeOrigin::signed(1), para),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(ParaInclusion::force_reset_upward_delivery_fee_factor(
+			RuntimeOrigin::root(),
+			para
+		));
+		assert_eq!(ParaInclusion::upward_delivery_fee_factor(para), FixedU128::from_u32(1));
+		assert_last_event(crate::inclusion::Event::UpwardDeliveryFeeFactorReset { para }.into());
+		// The advertised capacity is no longer throttled.
+		assert_queue_remaining(para, 2, 1024 - 24);
+	});
+}
+
 fn assert_queue_size(para: ParaId, count: u32, size: u32) {
 	#[allow(deprecated)]
-	let raw_queue_size = sp_io::storage::get(&well_known_keys::relay_dispatch_queue_size(para)).expect(
-		"enqueing a message should create the dispatch queue\
+	let raw_queue_size = sp_io::storage::get(&well_known_keys::relay_dispatch_queue_size(para))
+		.expect(
+			"enqueing a message should create the dispatch queue\
 				and it should be accessible via the well known keys",
-	);
+		);
 	let (c, s) = <(u32, u32)>::decode(&mut &raw_queue_size[..])
 		.expect("the dispatch queue size should be decodable into (u32, u32)");
 	assert_eq!((c, s), (count, size));
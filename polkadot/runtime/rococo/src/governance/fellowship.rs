@@ -299,6 +299,7 @@ impl pallet_referenda::Config<FellowshipReferendaInstance> for Runtime {
 		pallet_ranked_collective::EnsureMember<Runtime, FellowshipCollectiveInstance, 1>;
 	type CancelOrigin = FellowshipExperts;
 	type KillOrigin = FellowshipMasters;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
 	type Slash = Treasury;
 	type Votes = pallet_ranked_collective::Votes;
 	type Tally = pallet_ranked_collective::TallyOf<Runtime, FellowshipCollectiveInstance>;
@@ -24,7 +24,7 @@ use pallet_nis::WithMaximumOf;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::{
 	slashing, vstaging::NodeFeatures, AccountId, AccountIndex, Balance, BlockNumber,
-	CandidateEvent, CandidateHash, CommittedCandidateReceipt, CoreState, DisputeState,
+	CandidateEvent, CandidateHash, CommittedCandidateReceipt, CoreIndex, CoreState, DisputeState,
 	ExecutorParams, GroupRotationInfo, Hash, Id as ParaId, InboundDownwardMessage,
 	InboundHrmpMessage, Moment, Nonce, OccupiedCoreAssumption, PersistedValidationData,
 	ScrapedOnChainVotes, SessionInfo, Signature, ValidationCode, ValidationCodeHash, ValidatorId,
@@ -46,7 +46,8 @@ use runtime_parachains::{
 	assigner_parachains as parachains_assigner_parachains,
 	configuration as parachains_configuration, disputes as parachains_disputes,
 	disputes::slashing as parachains_slashing,
-	dmp as parachains_dmp, hrmp as parachains_hrmp, inclusion as parachains_inclusion,
+	dmp as parachains_dmp, finality_lag as parachains_finality_lag, hrmp as parachains_hrmp,
+	inclusion as parachains_inclusion,
 	inclusion::{AggregateMessageOrigin, UmpQueueId},
 	initializer as parachains_initializer, origin as parachains_origin, paras as parachains_paras,
 	paras_inherent as parachains_paras_inherent,
@@ -75,7 +76,7 @@ use frame_support::{
 	weights::{ConstantMultiplier, WeightMeter},
 	PalletId,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureNever, EnsureRoot};
 use pallet_grandpa::{fg_primitives, AuthorityId as GrandpaId};
 use pallet_identity::legacy::IdentityInfo;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
@@ -98,9 +99,9 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use xcm::{
 	latest::{InteriorMultiLocation, Junction, Junction::PalletInstance},
-	VersionedMultiLocation,
+	VersionedAssetId, VersionedMultiLocation,
 };
-use xcm_builder::PayOverXcm;
+use xcm_builder::{CheckedIssuance, PayOverXcm};
 
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -210,6 +211,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 parameter_types! {
@@ -323,7 +325,7 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ConstU32<1>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
-	type MaxHolds = ConstU32<2>;
+	type MaxHolds = ConstU32<3>;
 }
 
 parameter_types! {
@@ -494,19 +496,29 @@ impl pallet_bounties::Config for Runtime {
 parameter_types! {
 	pub const MaxActiveChildBountyCount: u32 = 100;
 	pub const ChildBountyValueMinimum: Balance = BountyValueMinimum::get() / 10;
+	pub const ChildBountyClosureBatchSize: u32 = 10;
 }
 
 impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type ChildBountyClosureBatchSize = ChildBountyClosureBatchSize;
 	type WeightInfo = weights::pallet_child_bounties::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxQueuedOffenceReports: u32 = 1_000;
+	pub OffencesMaxProcessingWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
+}
+
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = ();
+	type MaxQueuedReports = MaxQueuedOffenceReports;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = weights::pallet_offences::WeightInfo<Runtime>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -625,6 +637,7 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const JudgementRequestExpiry: BlockNumber = 14 * DAYS;
 }
 
 impl pallet_identity::Config for Runtime {
@@ -639,6 +652,8 @@ impl pallet_identity::Config for Runtime {
 	type Slashed = Treasury;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type JudgementRequestExpiry = JudgementRequestExpiry;
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -883,6 +898,18 @@ impl parachains_session_info::Config for Runtime {
 	type ValidatorSet = Historical;
 }
 
+parameter_types! {
+	pub const FinalityLagThreshold: BlockNumber = 50;
+	pub const MaxReportedFinalityLag: BlockNumber = 500;
+}
+
+impl parachains_finality_lag::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type LagThreshold = FinalityLagThreshold;
+	type MaxReportedLag = MaxReportedFinalityLag;
+	type WeightInfo = weights::runtime_parachains_finality_lag::WeightInfo<Runtime>;
+}
+
 /// Special `RewardValidators` that does nothing ;)
 pub struct RewardValidators;
 impl runtime_parachains::inclusion::RewardValidators for RewardValidators {
@@ -890,12 +917,23 @@ impl runtime_parachains::inclusion::RewardValidators for RewardValidators {
 	fn reward_bitfields(_: impl IntoIterator<Item = ValidatorIndex>) {}
 }
 
+parameter_types! {
+	pub const UpwardFeeThresholdFactor: u32 = 2;
+	pub const UpwardExponentialFeeBase: FixedU128 = FixedU128::from_rational(105, 100);
+	pub const UpwardMessageSizeFeeBase: FixedU128 = FixedU128::from_rational(1, 1000);
+	pub const UpwardFeeDecayPeriod: BlockNumber = 10;
+}
+
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
 	type RewardValidators = RewardValidators;
 	type MessageQueue = MessageQueue;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
+	type UpwardFeeThresholdFactor = UpwardFeeThresholdFactor;
+	type UpwardExponentialFeeBase = UpwardExponentialFeeBase;
+	type UpwardMessageSizeFeeBase = UpwardMessageSizeFeeBase;
+	type UpwardFeeDecayPeriod = UpwardFeeDecayPeriod;
 }
 
 parameter_types! {
@@ -949,6 +987,8 @@ impl pallet_message_queue::Config for Runtime {
 	type Size = u32;
 	type HeapSize = MessageQueueHeapSize;
 	type MaxStale = MessageQueueMaxStale;
+	type MaxDeadLetters = ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MessageProcessor = MessageProcessor;
@@ -1009,6 +1049,14 @@ impl parachains_disputes::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_disputes::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const SlashForInvalid: Perbill = Perbill::from_percent(100);
+	// Validators disputing valid candidates are not slashed (yet).
+	pub const SlashAgainstValid: Perbill = Perbill::zero();
+	pub const SlashAgainstValidNarrowly: Perbill = Perbill::zero();
+	pub const DisputeNarrowMargin: Perbill = Perbill::from_percent(20);
+}
+
 impl parachains_slashing::Config for Runtime {
 	type KeyOwnerProofSystem = Historical;
 	type KeyOwnerProof =
@@ -1022,6 +1070,10 @@ impl parachains_slashing::Config for Runtime {
 		Offences,
 		ReportLongevity,
 	>;
+	type SlashForInvalid = SlashForInvalid;
+	type SlashAgainstValid = SlashAgainstValid;
+	type SlashAgainstValidNarrowly = SlashAgainstValidNarrowly;
+	type NarrowMargin = DisputeNarrowMargin;
 	type WeightInfo = parachains_slashing::TestWeightInfo;
 	type BenchmarkingConfig = parachains_slashing::BenchConfig<200>;
 }
@@ -1051,6 +1103,9 @@ impl slots::Config for Runtime {
 	type LeasePeriod = LeasePeriod;
 	type LeaseOffset = ();
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, LeaseAdmin>;
+	// No coretime is available on this chain, so `start_coretime_migration` is inert: every lease
+	// fails to migrate with a `LeaseMigrationFailed` event and stays managed by this pallet.
+	type CoretimeAssigner = ();
 	type WeightInfo = weights::runtime_common_slots::WeightInfo<Runtime>;
 }
 
@@ -1099,6 +1154,7 @@ impl identity_migrator::Config for Runtime {
 	// To be changed to `EnsureSigned` once there is a People Chain to migrate to.
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type DepositPoker = EnsureNever<()>;
 	type WeightInfo = weights::runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -1331,7 +1387,7 @@ construct_runtime! {
 		Utility: pallet_utility::{Pallet, Call, Event} = 24,
 
 		// Less simple identity module.
-		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 25,
+		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>, HoldReason} = 25,
 
 		// Society module.
 		Society: pallet_society::{Pallet, Call, Storage, Event<T>} = 26,
@@ -1384,6 +1440,7 @@ construct_runtime! {
 		ParaAssignmentProvider: parachains_assigner::{Pallet, Storage} = 65,
 		OnDemandAssignmentProvider: parachains_assigner_on_demand::{Pallet, Call, Storage, Event<T>} = 66,
 		ParachainsAssignmentProvider: parachains_assigner_parachains::{Pallet} = 67,
+		FinalityLag: parachains_finality_lag::{Pallet, Call, Storage, Event<T>, Inherent} = 68,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar::{Pallet, Call, Storage, Event<T>, Config<T>} = 70,
@@ -1527,6 +1584,9 @@ pub mod migrations {
 
 		pallet_grandpa::migrations::MigrateV4ToV5<Runtime>,
 		parachains_configuration::migration::v10::MigrateToV10<Runtime>,
+		parachains_configuration::migration::v11::MigrateToV11<Runtime>,
+		pallet_identity::migration::v1::MigrateToV1<Runtime>,
+		pallet_nis::migration::v1::MigrateToV1<Runtime>,
 	);
 }
 
@@ -1583,6 +1643,7 @@ mod benches {
 		[runtime_parachains::configuration, Configuration]
 		[runtime_parachains::hrmp, Hrmp]
 		[runtime_parachains::disputes, ParasDisputes]
+		[runtime_parachains::finality_lag, FinalityLag]
 		[runtime_parachains::inclusion, ParaInclusion]
 		[runtime_parachains::initializer, Initializer]
 		[runtime_parachains::paras_inherent, ParaInherent]
@@ -1601,6 +1662,7 @@ mod benches {
 		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
+		[pallet_offences, Offences]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, FellowshipCollective]
@@ -1689,7 +1751,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(9)]
+	#[api_version(16)]
 	impl primitives::runtime_api::ParachainHost<Block, Hash, BlockNumber> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -1840,6 +1902,41 @@ sp_api::impl_runtime_apis! {
 		fn node_features() -> NodeFeatures {
 			parachains_staging_runtime_api_impl::node_features::<Runtime>()
 		}
+
+		fn para_upgrade_status(para_id: ParaId) -> Option<primitives::vstaging::UpgradeStatus> {
+			parachains_staging_runtime_api_impl::para_upgrade_status::<Runtime>(para_id)
+		}
+
+		fn upward_delivery_fee_factor(para_id: ParaId) -> sp_runtime::FixedU128 {
+			parachains_staging_runtime_api_impl::upward_delivery_fee_factor::<Runtime>(para_id)
+		}
+
+		fn backing_groups() -> Vec<primitives::vstaging::CoreBackingGroup<BlockNumber>> {
+			parachains_staging_runtime_api_impl::backing_groups::<Runtime>()
+		}
+
+		fn validator_groups_for_core(
+			core: CoreIndex,
+		) -> Vec<primitives::vstaging::CoreBackingGroup<BlockNumber>> {
+			parachains_staging_runtime_api_impl::validator_groups_for_core::<Runtime>(core)
+		}
+
+		fn para_async_backing_params(para_id: ParaId) -> primitives::AsyncBackingParams {
+			parachains_staging_runtime_api_impl::para_async_backing_params::<Runtime>(para_id)
+		}
+
+		fn stored_session_info_range() -> (SessionIndex, SessionIndex) {
+			parachains_staging_runtime_api_impl::stored_session_info_range::<Runtime>()
+		}
+
+		fn unapplied_slashes_v2(
+		) -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)> {
+			parachains_staging_runtime_api_impl::unapplied_slashes_v2::<Runtime>()
+		}
+
+		fn backed_not_included(para_id: ParaId) -> u32 {
+			parachains_staging_runtime_api_impl::backed_not_included::<Runtime>(para_id)
+		}
 	}
 
 	#[api_version(3)]
@@ -2036,6 +2133,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockAuthorApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			System::block_author()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2054,6 +2157,61 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm::XcmTransactApi<Block, RuntimeCall> for Runtime {
+		fn transact_weight(call: RuntimeCall) -> Weight {
+			XcmPallet::transact_weight(&call, xcm_config::TransactWeightMargin::get())
+		}
+	}
+
+	impl pallet_xcm::XcmTeleportAccountingApi<Block> for Runtime {
+		fn checked_issuance(asset: VersionedAssetId) -> Option<CheckedIssuance> {
+			XcmPallet::checked_issuance(asset)
+		}
+	}
+
+	impl slots::SlotsApi<Block, BlockNumber> for Runtime {
+		fn remaining_lease_periods(para: ParaId) -> BlockNumber {
+			Slots::remaining_lease_periods(para)
+		}
+
+		fn all_remaining_lease_periods() -> Vec<(ParaId, BlockNumber)> {
+			Slots::all_remaining_lease_periods()
+		}
+	}
+
+	impl pallet_scheduler::SchedulerApi<Block, BlockNumber, OriginCaller, Hash> for Runtime {
+		fn scheduled_tasks() -> Vec<pallet_scheduler::ScheduledTask<BlockNumber, OriginCaller, Hash>> {
+			Scheduler::scheduled_tasks()
+		}
+
+		fn scheduled_task(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<pallet_scheduler::ScheduledTask<BlockNumber, OriginCaller, Hash>> {
+			Scheduler::scheduled_task(id)
+		}
+	}
+
+	impl frame_system::runtime_upgrade::RuntimeUpgradeApi<Block> for Runtime {
+		fn dry_run_runtime_upgrade() -> frame_system::runtime_upgrade::RuntimeUpgradeReport {
+			Executive::dry_run_runtime_upgrade()
+		}
+	}
+
+	impl frame_system::storage_keys::StorageKeyApi<Block> for Runtime {
+		fn storage_key(
+			pallet_name: Vec<u8>,
+			storage_name: Vec<u8>,
+			keys: Vec<Vec<u8>>,
+		) -> Result<Vec<u8>, frame_system::storage_keys::StorageKeyError> {
+			frame_support::storage::keys::storage_key_from_metadata(
+				&Runtime::metadata_ir().pallets,
+				&pallet_name,
+				&storage_name,
+				&keys,
+			)
+		}
+	}
+
 	impl pallet_beefy_mmr::BeefyMmrApi<Block, Hash> for RuntimeApi {
 		fn authority_set_proof() -> beefy_primitives::mmr::BeefyAuthoritySet<Hash> {
 			MmrLeaf::authority_set_proof()
@@ -2267,6 +2425,26 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_conviction_voting_runtime_api::ConvictionVotingApi<Block, AccountId, Balance, u16> for Runtime {
+		fn voting_power(
+			who: AccountId,
+			conviction: pallet_conviction_voting::Conviction,
+		) -> Vec<(u16, Balance)> {
+			ConvictionVoting::api_voting_power(who, conviction)
+		}
+
+		fn delegations(
+			who: AccountId,
+			start_after: Option<(AccountId, u16)>,
+			max_records: u32,
+		) -> (
+			Vec<pallet_conviction_voting::ClassDelegations<AccountId, Balance, u16>>,
+			Option<(AccountId, u16)>,
+		) {
+			ConvictionVoting::api_delegations(who, start_after, max_records)
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn create_default_config() -> Vec<u8> {
 			create_default_config::<RuntimeGenesisConfig>()
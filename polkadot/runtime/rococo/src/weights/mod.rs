@@ -28,6 +28,7 @@ pub mod pallet_indices;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_nis;
+pub mod pallet_offences;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_ranked_collective;
@@ -52,6 +53,7 @@ pub mod runtime_common_slots;
 pub mod runtime_parachains_assigner_on_demand;
 pub mod runtime_parachains_configuration;
 pub mod runtime_parachains_disputes;
+pub mod runtime_parachains_finality_lag;
 pub mod runtime_parachains_hrmp;
 pub mod runtime_parachains_inclusion;
 pub mod runtime_parachains_initializer;
@@ -96,4 +96,12 @@ impl<T: frame_system::Config> pallet_balances::WeightInfo for WeightInfo<T> {
 	fn upgrade_accounts(_: u32) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	// Storage: Balances InactiveIssuance (r:1 w:0)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	fn force_adjust_total_issuance() -> Weight {
+		// Minimum execution time: 6_868 nanoseconds.
+		Weight::from_parts(7_120_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
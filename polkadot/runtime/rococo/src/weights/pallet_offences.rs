@@ -0,0 +1,89 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `pallet_offences`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-05-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bm5`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("rococo-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/polkadot
+// benchmark
+// pallet
+// --chain=rococo-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_offences
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./file_header.txt
+// --output=./runtime/rococo/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_offences`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_offences::WeightInfo for WeightInfo<T> {
+	/// Storage: Offences QueueBounds (r:1 w:0)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn service_queue_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 2_512_000 picoseconds.
+		Weight::from_parts(2_698_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: Offences QueueBounds (r:1 w:1)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Offences QueuedReports (r:1 w:1)
+	/// Proof Skipped: Offences QueuedReports (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Offences KindWeight (r:0 w:1)
+	/// Proof Skipped: Offences KindWeight (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System ExecutionPhase (r:1 w:0)
+	/// Proof: System ExecutionPhase (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	/// Storage: System EventCount (r:1 w:1)
+	/// Proof: System EventCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Events (r:1 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `o` is `[1, 100]`.
+	fn process_queued_report(o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `193 + o * (34 ±0)`
+		//  Estimated: `3658 + o * (34 ±0)`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_416_309, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			// Standard Error: 1_262
+			.saturating_add(Weight::from_parts(1_063_871, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 34).saturating_mul(o.into()))
+	}
+}
@@ -109,6 +109,17 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(2_375_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// Storage: `XcmPallet::VersionOverrides` (r:0 w:1)
+	/// Proof: `XcmPallet::VersionOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_xcm_version_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_333_000 picoseconds.
+		Weight::from_parts(7_566_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `XcmPallet::VersionNotifiers` (r:1 w:1)
 	/// Proof: `XcmPallet::VersionNotifiers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `XcmPallet::QueryCounter` (r:1 w:1)
@@ -290,4 +301,12 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn transfer_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_869_000 picoseconds.
+		Weight::from_parts(16_264_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
 }
@@ -129,4 +129,16 @@ impl<T: frame_system::Config> runtime_common::slots::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: Slots CoretimeMigrationCursor (r:1 w:1)
+	/// Proof Skipped: Slots CoretimeMigrationCursor (max_values: Some(1), max_size: None, mode: Measured)
+	fn start_coretime_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 7_012_000 picoseconds.
+		Weight::from_parts(7_358_000, 0)
+			.saturating_add(Weight::from_parts(0, 1485))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -170,4 +170,13 @@ impl<T: frame_system::Config> runtime_parachains::configuration::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Storage: `Configuration::PendingAsyncBackingParamsOverrides` (r:1 w:1)
+	/// Storage: `ParasShared::CurrentSessionIndex` (r:1 w:0)
+	///
+	/// Not benchmarked yet: uses the weight of `set_node_feature`, which has the same storage
+	/// accesses, until this file is regenerated.
+	fn set_async_backing_params_override() -> Weight {
+		Self::set_node_feature()
+	}
 }
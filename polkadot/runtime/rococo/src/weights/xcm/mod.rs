@@ -18,7 +18,7 @@ mod pallet_xcm_benchmarks_fungible;
 mod pallet_xcm_benchmarks_generic;
 
 use crate::Runtime;
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight};
 use sp_std::prelude::*;
 use xcm::{latest::prelude::*, DoubleEncoded};
 
@@ -280,6 +280,28 @@ impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for RococoXcmWeight<RuntimeCall> {
 	}
 }
 
+/// The surcharge per junction of a location an instruction routes to, see
+/// [`xcm_builder::WithNestingSurcharge`].
+///
+/// Not benchmarked yet: reuses the weight of `DescendOrigin`, which processes a location as well.
+pub struct NestingSurchargePerJunction;
+impl Get<Weight> for NestingSurchargePerJunction {
+	fn get() -> Weight {
+		XcmGeneric::<Runtime>::descend_origin()
+	}
+}
+
+/// The surcharge per asset an instruction deals with, see [`xcm_builder::WithNestingSurcharge`].
+///
+/// Not benchmarked yet: reuses the weight of `ExpectAsset`, which checks assets against the
+/// holding register.
+pub struct NestingSurchargePerAsset;
+impl Get<Weight> for NestingSurchargePerAsset {
+	fn get() -> Weight {
+		XcmGeneric::<Runtime>::expect_asset()
+	}
+}
+
 #[test]
 fn all_counted_has_a_sane_weight_upper_limit() {
 	let assets = MultiAssetFilter::Wild(AllCounted(4294967295));
@@ -35,6 +35,7 @@ use runtime_common::{
 	ToAuthor,
 };
 use sp_core::ConstU32;
+use sp_runtime::Percent;
 use xcm::latest::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
@@ -43,8 +44,8 @@ use xcm_builder::{
 	DescribeFamily, FixedWeightBounds, HashedDescription, IsChildSystemParachain, IsConcrete,
 	MintLocation, OriginToPluralityVoice, SignedAccountId32AsNative, SignedToAccountId32,
 	SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
-	XcmFeeToAccount,
+	WeightInfoBounds, WithComputedOrigin, WithNestingSurcharge, WithUniqueTopic,
+	XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 use xcm_executor::XcmExecutor;
 
@@ -53,6 +54,9 @@ parameter_types! {
 	pub const ThisNetwork: NetworkId = NetworkId::Rococo;
 	pub UniversalLocation: InteriorMultiLocation = ThisNetwork::get().into();
 	pub CheckAccount: AccountId = XcmPallet::check_account();
+	/// The margin added to the benchmarked weight of calls when building `Transact` instructions
+	/// which dispatch them on this chain.
+	pub const TransactWeightMargin: Percent = Percent::from_percent(10);
 	pub LocalCheckAccount: (AccountId, MintLocation) = (CheckAccount::get(), MintLocation::Local);
 	pub TreasuryAccount: AccountId = Treasury::account_id();
 }
@@ -81,6 +85,8 @@ pub type LocalAssetTransactor = XcmCurrencyAdapter<
 	AccountId,
 	// We track our teleports in/out to keep total issuance correct.
 	LocalCheckAccount,
+	// We record the checked issuance of our teleports to check it in `try_state`.
+	XcmPallet,
 >;
 
 /// The means that we convert an the XCM message origin location into a local dispatch origin.
@@ -177,10 +183,15 @@ impl xcm_executor::Config for XcmConfig {
 	type IsTeleporter = TrustedTeleporters;
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
-	type Weigher = WeightInfoBounds<
-		crate::weights::xcm::RococoXcmWeight<RuntimeCall>,
-		RuntimeCall,
-		MaxInstructions,
+	type Weigher = WithNestingSurcharge<
+		WeightInfoBounds<
+			crate::weights::xcm::RococoXcmWeight<RuntimeCall>,
+			RuntimeCall,
+			MaxInstructions,
+		>,
+		crate::weights::xcm::NestingSurchargePerJunction,
+		crate::weights::xcm::NestingSurchargePerAsset,
+		MaxAssetsIntoHolding,
 	>;
 	type Trader =
 		UsingComponents<WeightToFee, TokenLocation, AccountId, Balances, ToAuthor<Runtime>>;
@@ -200,6 +211,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = XcmPallet;
 	type Aliasers = Nothing;
 }
 
@@ -263,6 +275,11 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = ConstU32<8>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type ForeignAssetRegistrationOrigin = frame_system::EnsureNever<MultiLocation>;
+	type ForeignAssetRegistrar = ();
+	type TeleportAccounting = LocalAssetTransactor;
+	type MaxTransferReceipts = ();
+	type UnixTime = crate::Timestamp;
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
@@ -163,6 +163,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
@@ -433,10 +434,18 @@ impl frame_system::offchain::SigningTypes for Runtime {
 	type Signature = Signature;
 }
 
+parameter_types! {
+	pub const MaxQueuedOffenceReports: u32 = 1_000;
+	pub OffencesMaxProcessingWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
+}
+
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = MaxQueuedOffenceReports;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = ();
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -488,12 +497,23 @@ impl parachains_configuration::Config for Runtime {
 
 impl parachains_shared::Config for Runtime {}
 
+parameter_types! {
+	pub const UpwardFeeThresholdFactor: u32 = 2;
+	pub const UpwardExponentialFeeBase: sp_runtime::FixedU128 = sp_runtime::FixedU128::from_rational(105, 100);
+	pub const UpwardMessageSizeFeeBase: sp_runtime::FixedU128 = sp_runtime::FixedU128::from_rational(1, 1000);
+	pub const UpwardFeeDecayPeriod: BlockNumber = 10;
+}
+
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
 	type RewardValidators = RewardValidatorsWithEraPoints<Runtime>;
 	type MessageQueue = ();
 	type WeightInfo = ();
+	type UpwardFeeThresholdFactor = UpwardFeeThresholdFactor;
+	type UpwardExponentialFeeBase = UpwardExponentialFeeBase;
+	type UpwardMessageSizeFeeBase = UpwardMessageSizeFeeBase;
+	type UpwardFeeDecayPeriod = UpwardFeeDecayPeriod;
 }
 
 impl parachains_disputes::Config for Runtime {
@@ -503,6 +523,13 @@ impl parachains_disputes::Config for Runtime {
 	type WeightInfo = parachains_disputes::TestWeightInfo;
 }
 
+parameter_types! {
+	pub const SlashForInvalid: Perbill = Perbill::from_percent(100);
+	pub const SlashAgainstValid: Perbill = Perbill::from_percent(10);
+	pub const SlashAgainstValidNarrowly: Perbill = Perbill::from_percent(1);
+	pub const DisputeNarrowMargin: Perbill = Perbill::from_percent(20);
+}
+
 impl parachains_slashing::Config for Runtime {
 	type KeyOwnerProofSystem = Historical;
 	type KeyOwnerProof =
@@ -516,6 +543,10 @@ impl parachains_slashing::Config for Runtime {
 		Offences,
 		ReportLongevity,
 	>;
+	type SlashForInvalid = SlashForInvalid;
+	type SlashAgainstValid = SlashAgainstValid;
+	type SlashAgainstValidNarrowly = SlashAgainstValidNarrowly;
+	type NarrowMargin = DisputeNarrowMargin;
 	type WeightInfo = parachains_disputes::slashing::TestWeightInfo;
 	type BenchmarkingConfig = parachains_slashing::BenchConfig<1000>;
 }
@@ -38,7 +38,7 @@ use frame_support::{
 	weights::{ConstantMultiplier, WeightMeter},
 	PalletId,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureNever, EnsureRoot};
 use pallet_grandpa::{fg_primitives, AuthorityId as GrandpaId};
 use pallet_identity::legacy::IdentityInfo;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
@@ -47,7 +47,7 @@ use pallet_transaction_payment::{CurrencyAdapter, FeeDetails, RuntimeDispatchInf
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use primitives::{
 	slashing, vstaging::NodeFeatures, AccountId, AccountIndex, Balance, BlockNumber,
-	CandidateEvent, CandidateHash, CommittedCandidateReceipt, CoreState, DisputeState,
+	CandidateEvent, CandidateHash, CommittedCandidateReceipt, CoreIndex, CoreState, DisputeState,
 	ExecutorParams, GroupRotationInfo, Hash, Id as ParaId, InboundDownwardMessage,
 	InboundHrmpMessage, Moment, Nonce, OccupiedCoreAssumption, PersistedValidationData,
 	PvfCheckStatement, ScrapedOnChainVotes, SessionInfo, Signature, ValidationCode,
@@ -67,7 +67,8 @@ use runtime_parachains::{
 	assigner_parachains as parachains_assigner_parachains,
 	configuration as parachains_configuration, disputes as parachains_disputes,
 	disputes::slashing as parachains_slashing,
-	dmp as parachains_dmp, hrmp as parachains_hrmp, inclusion as parachains_inclusion,
+	dmp as parachains_dmp, finality_lag as parachains_finality_lag, hrmp as parachains_hrmp,
+	inactivity as parachains_inactivity, inclusion as parachains_inclusion,
 	inclusion::{AggregateMessageOrigin, UmpQueueId},
 	initializer as parachains_initializer, origin as parachains_origin, paras as parachains_paras,
 	paras_inherent as parachains_paras_inherent, reward_points as parachains_reward_points,
@@ -97,9 +98,9 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use xcm::{
 	latest::{InteriorMultiLocation, Junction, Junction::PalletInstance},
-	VersionedMultiLocation,
+	VersionedAssetId, VersionedMultiLocation,
 };
-use xcm_builder::PayOverXcm;
+use xcm_builder::{CheckedIssuance, PayOverXcm};
 
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -205,6 +206,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 parameter_types! {
@@ -311,7 +313,7 @@ impl pallet_balances::Config for Runtime {
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = ConstU32<1>;
-	type MaxHolds = ConstU32<1>;
+	type MaxHolds = ConstU32<2>;
 }
 
 parameter_types! {
@@ -417,7 +419,7 @@ impl pallet_timestamp::Config for Runtime {
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
-	type EventHandler = (Staking, ImOnline);
+	type EventHandler = (Staking, ImOnline, Inactivity);
 }
 
 parameter_types! {
@@ -602,6 +604,7 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type WeightInfo = weights::pallet_bags_list::WeightInfo<Runtime>;
 	type BagThresholds = BagThresholds;
 	type Score = sp_npos_elections::VoteWeight;
+	type MaxAutoRebagPerBlock = ConstU32<32>;
 }
 
 pallet_staking_reward_curve::build! {
@@ -732,10 +735,18 @@ impl pallet_treasury::Config for Runtime {
 	type BenchmarkHelper = runtime_common::impls::benchmarks::TreasuryArguments;
 }
 
+parameter_types! {
+	pub const MaxQueuedOffenceReports: u32 = 1_000;
+	pub OffencesMaxProcessingWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
+}
+
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = MaxQueuedOffenceReports;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = weights::pallet_offences::WeightInfo<Runtime>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -846,6 +857,7 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const JudgementRequestExpiry: BlockNumber = 14 * DAYS;
 }
 
 impl pallet_identity::Config for Runtime {
@@ -860,6 +872,8 @@ impl pallet_identity::Config for Runtime {
 	type MaxRegistrars = MaxRegistrars;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type JudgementRequestExpiry = JudgementRequestExpiry;
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -1099,12 +1113,49 @@ impl parachains_session_info::Config for Runtime {
 	type ValidatorSet = Historical;
 }
 
+parameter_types! {
+	pub const FinalityLagThreshold: BlockNumber = 50;
+	pub const MaxReportedFinalityLag: BlockNumber = 500;
+}
+
+impl parachains_finality_lag::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type LagThreshold = FinalityLagThreshold;
+	type MaxReportedLag = MaxReportedFinalityLag;
+	type WeightInfo = weights::runtime_parachains_finality_lag::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const InactivityMinAuthoredBlocks: u32 = 1;
+	pub const InactivityMinAvailabilityVotes: u32 = 1;
+}
+
+impl parachains_inactivity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MinAuthoredBlocks = InactivityMinAuthoredBlocks;
+	type MinAvailabilityVotes = InactivityMinAvailabilityVotes;
+	type ReportInactivity = Offences;
+	type WeightInfo = weights::runtime_parachains_inactivity::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const UpwardFeeThresholdFactor: u32 = 2;
+	pub const UpwardExponentialFeeBase: FixedU128 = FixedU128::from_rational(105, 100);
+	pub const UpwardMessageSizeFeeBase: FixedU128 = FixedU128::from_rational(1, 1000);
+	pub const UpwardFeeDecayPeriod: BlockNumber = 10;
+}
+
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
-	type RewardValidators = parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>;
+	type RewardValidators =
+		(parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>, Inactivity);
 	type MessageQueue = MessageQueue;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
+	type UpwardFeeThresholdFactor = UpwardFeeThresholdFactor;
+	type UpwardExponentialFeeBase = UpwardExponentialFeeBase;
+	type UpwardMessageSizeFeeBase = UpwardMessageSizeFeeBase;
+	type UpwardFeeDecayPeriod = UpwardFeeDecayPeriod;
 }
 
 parameter_types! {
@@ -1158,6 +1209,8 @@ impl pallet_message_queue::Config for Runtime {
 	type Size = u32;
 	type HeapSize = MessageQueueHeapSize;
 	type MaxStale = MessageQueueMaxStale;
+	type MaxDeadLetters = ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MessageProcessor = MessageProcessor;
@@ -1220,6 +1273,14 @@ impl parachains_disputes::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_disputes::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const SlashForInvalid: Perbill = Perbill::from_percent(100);
+	// Validators disputing valid candidates are not slashed (yet).
+	pub const SlashAgainstValid: Perbill = Perbill::zero();
+	pub const SlashAgainstValidNarrowly: Perbill = Perbill::zero();
+	pub const DisputeNarrowMargin: Perbill = Perbill::from_percent(20);
+}
+
 impl parachains_slashing::Config for Runtime {
 	type KeyOwnerProofSystem = Historical;
 	type KeyOwnerProof =
@@ -1233,6 +1294,10 @@ impl parachains_slashing::Config for Runtime {
 		Offences,
 		ReportLongevity,
 	>;
+	type SlashForInvalid = SlashForInvalid;
+	type SlashAgainstValid = SlashAgainstValid;
+	type SlashAgainstValidNarrowly = SlashAgainstValidNarrowly;
+	type NarrowMargin = DisputeNarrowMargin;
 	type WeightInfo = weights::runtime_parachains_disputes_slashing::WeightInfo<Runtime>;
 	type BenchmarkingConfig = parachains_slashing::BenchConfig<300>;
 }
@@ -1263,6 +1328,9 @@ impl slots::Config for Runtime {
 	type LeasePeriod = LeasePeriod;
 	type LeaseOffset = ();
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, LeaseAdmin>;
+	// No coretime is available on this chain, so `start_coretime_migration` is inert: every lease
+	// fails to migrate with a `LeaseMigrationFailed` event and stays managed by this pallet.
+	type CoretimeAssigner = ();
 	type WeightInfo = weights::runtime_common_slots::WeightInfo<Runtime>;
 }
 
@@ -1311,6 +1379,7 @@ impl identity_migrator::Config for Runtime {
 	// To be changed to `EnsureSigned` once there is a People Chain to migrate to.
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type DepositPoker = EnsureNever<()>;
 	type WeightInfo = weights::runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -1332,6 +1401,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type MaxMetadataLen = ConstU32<256>;
 	// we use the same number of allowed unlocking chunks as with staking.
 	type MaxUnbonding = <Self as pallet_staking::Config>::MaxUnlockingChunks;
+	type Delegation = sp_staking::NoDelegation<AccountId, Balance>;
 	type PalletId = PoolsPalletId;
 	type MaxPointsToBalance = MaxPointsToBalance;
 }
@@ -1397,7 +1467,7 @@ construct_runtime! {
 		Utility: pallet_utility::{Pallet, Call, Event} = 16,
 
 		// Less simple identity module.
-		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 17,
+		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>, HoldReason} = 17,
 
 		// Social recovery module.
 		Recovery: pallet_recovery::{Pallet, Call, Storage, Event<T>} = 18,
@@ -1424,7 +1494,7 @@ construct_runtime! {
 		ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 24,
 
 		// Provides a semi-sorted list of nominators for staking.
-		VoterList: pallet_bags_list::<Instance1>::{Pallet, Call, Storage, Event<T>} = 25,
+		VoterList: pallet_bags_list::<Instance1>::{Pallet, Call, Storage, Event<T>, Task} = 25,
 
 		// Nomination pools for staking.
 		NominationPools: pallet_nomination_pools::{Pallet, Call, Storage, Event<T>, Config<T>, FreezeReason} = 29,
@@ -1457,6 +1527,8 @@ construct_runtime! {
 		ParasDisputes: parachains_disputes::{Pallet, Call, Storage, Event<T>} = 53,
 		ParasSlashing: parachains_slashing::{Pallet, Call, Storage, ValidateUnsigned} = 54,
 		ParaAssignmentProvider: parachains_assigner_parachains::{Pallet, Storage} = 55,
+		FinalityLag: parachains_finality_lag::{Pallet, Call, Storage, Event<T>, Inherent} = 56,
+		Inactivity: parachains_inactivity::{Pallet, Storage, Event<T>} = 57,
 
 		// Parachain Onboarding Pallets. Start indices at 60 to leave room.
 		Registrar: paras_registrar::{Pallet, Call, Storage, Event<T>, Config<T>} = 60,
@@ -1538,6 +1610,8 @@ pub mod migrations {
 		pallet_nomination_pools::migration::versioned_migrations::V6ToV7<Runtime>,
 		pallet_grandpa::migrations::MigrateV4ToV5<Runtime>,
 		parachains_configuration::migration::v10::MigrateToV10<Runtime>,
+		parachains_configuration::migration::v11::MigrateToV11<Runtime>,
+		pallet_identity::migration::v1::MigrateToV1<Runtime>,
 	);
 }
 
@@ -1571,7 +1645,9 @@ mod benches {
 		[runtime_parachains::configuration, Configuration]
 		[runtime_parachains::disputes, ParasDisputes]
 		[runtime_parachains::disputes::slashing, ParasSlashing]
+		[runtime_parachains::finality_lag, FinalityLag]
 		[runtime_parachains::hrmp, Hrmp]
+		[runtime_parachains::inactivity, Inactivity]
 		[runtime_parachains::inclusion, ParaInclusion]
 		[runtime_parachains::initializer, Initializer]
 		[runtime_parachains::paras, Paras]
@@ -1589,7 +1665,8 @@ mod benches {
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
-		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_offences, Offences]
+		[pallet_offences_benchmarking, OffencesBench::<Runtime>]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_recovery, Recovery]
@@ -1679,7 +1756,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(9)]
+	#[api_version(13)]
 	impl primitives::runtime_api::ParachainHost<Block, Hash, BlockNumber> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -1830,6 +1907,28 @@ sp_api::impl_runtime_apis! {
 		fn node_features() -> NodeFeatures {
 			parachains_staging_runtime_api_impl::node_features::<Runtime>()
 		}
+
+		fn para_upgrade_status(para_id: ParaId) -> Option<primitives::vstaging::UpgradeStatus> {
+			parachains_staging_runtime_api_impl::para_upgrade_status::<Runtime>(para_id)
+		}
+
+		fn upward_delivery_fee_factor(para_id: ParaId) -> sp_runtime::FixedU128 {
+			parachains_staging_runtime_api_impl::upward_delivery_fee_factor::<Runtime>(para_id)
+		}
+
+		fn backing_groups() -> Vec<primitives::vstaging::CoreBackingGroup<BlockNumber>> {
+			parachains_staging_runtime_api_impl::backing_groups::<Runtime>()
+		}
+
+		fn validator_groups_for_core(
+			core: CoreIndex,
+		) -> Vec<primitives::vstaging::CoreBackingGroup<BlockNumber>> {
+			parachains_staging_runtime_api_impl::validator_groups_for_core::<Runtime>(core)
+		}
+
+		fn para_async_backing_params(para_id: ParaId) -> primitives::AsyncBackingParams {
+			parachains_staging_runtime_api_impl::para_async_backing_params::<Runtime>(para_id)
+		}
 	}
 
 	impl beefy_primitives::BeefyApi<Block, BeefyId> for Runtime {
@@ -2034,6 +2133,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockAuthorApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			System::block_author()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2069,6 +2174,72 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm::XcmTransactApi<Block, RuntimeCall> for Runtime {
+		fn transact_weight(call: RuntimeCall) -> Weight {
+			XcmPallet::transact_weight(&call, xcm_config::TransactWeightMargin::get())
+		}
+	}
+
+	impl pallet_xcm::XcmTeleportAccountingApi<Block> for Runtime {
+		fn checked_issuance(asset: VersionedAssetId) -> Option<CheckedIssuance> {
+			XcmPallet::checked_issuance(asset)
+		}
+	}
+
+	impl pallet_xcm::XcmTransferReceiptsApi<Block, BlockNumber> for Runtime {
+		fn transfer_receipts_of(
+			origin: VersionedMultiLocation,
+		) -> Vec<(u64, pallet_xcm::TransferReceipt<BlockNumber>)> {
+			origin
+				.try_into()
+				.map(|origin| XcmPallet::transfer_receipts_of(&origin))
+				.unwrap_or_default()
+		}
+	}
+
+	impl slots::SlotsApi<Block, BlockNumber> for Runtime {
+		fn remaining_lease_periods(para: ParaId) -> BlockNumber {
+			Slots::remaining_lease_periods(para)
+		}
+
+		fn all_remaining_lease_periods() -> Vec<(ParaId, BlockNumber)> {
+			Slots::all_remaining_lease_periods()
+		}
+	}
+
+	impl pallet_scheduler::SchedulerApi<Block, BlockNumber, OriginCaller, Hash> for Runtime {
+		fn scheduled_tasks() -> Vec<pallet_scheduler::ScheduledTask<BlockNumber, OriginCaller, Hash>> {
+			Scheduler::scheduled_tasks()
+		}
+
+		fn scheduled_task(
+			id: frame_support::traits::schedule::v3::TaskName,
+		) -> Option<pallet_scheduler::ScheduledTask<BlockNumber, OriginCaller, Hash>> {
+			Scheduler::scheduled_task(id)
+		}
+	}
+
+	impl frame_system::runtime_upgrade::RuntimeUpgradeApi<Block> for Runtime {
+		fn dry_run_runtime_upgrade() -> frame_system::runtime_upgrade::RuntimeUpgradeReport {
+			Executive::dry_run_runtime_upgrade()
+		}
+	}
+
+	impl frame_system::storage_keys::StorageKeyApi<Block> for Runtime {
+		fn storage_key(
+			pallet_name: Vec<u8>,
+			storage_name: Vec<u8>,
+			keys: Vec<Vec<u8>>,
+		) -> Result<Vec<u8>, frame_system::storage_keys::StorageKeyError> {
+			frame_support::storage::keys::storage_key_from_metadata(
+				&Runtime::metadata_ir().pallets,
+				&pallet_name,
+				&storage_name,
+				&keys,
+			)
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<
 		Block,
 		AccountId,
@@ -2087,6 +2258,27 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_conviction_voting_runtime_api::ConvictionVotingApi<Block, AccountId, Balance, u16> for Runtime {
+		fn voting_power(
+			who: AccountId,
+			conviction: pallet_conviction_voting::Conviction,
+		) -> Vec<(u16, Balance)> {
+			ConvictionVoting::api_voting_power(who, conviction)
+		}
+
+		fn delegations(
+			who: AccountId,
+			start_after: Option<(AccountId, u16)>,
+			max_records: u32,
+		) -> (
+			Vec<pallet_conviction_voting::ClassDelegations<AccountId, Balance, u16>>,
+			Option<(AccountId, u16)>,
+		) {
+			ConvictionVoting::api_delegations(who, start_after, max_records)
+		}
+	}
+
+	#[api_version(2)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2095,6 +2287,28 @@ sp_api::impl_runtime_apis! {
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page {
 			Staking::api_eras_stakers_page_count(era, account)
 		}
+
+		fn exposures(
+			era: sp_staking::EraIndex,
+			account: AccountId,
+			start_after: Option<AccountId>,
+			max_validators: u32,
+		) -> (Vec<(AccountId, sp_staking::Page, Balance)>, Option<AccountId>) {
+			Staking::api_exposures(era, account, start_after, max_validators)
+		}
+
+		fn pending_rewards(
+			era: sp_staking::EraIndex,
+			account: AccountId,
+			start_after: Option<AccountId>,
+			max_validators: u32,
+		) -> (Vec<(AccountId, sp_staking::Page)>, Option<AccountId>) {
+			Staking::api_pending_rewards(era, account, start_after, max_validators)
+		}
+
+		fn slashes(account: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_slashes(account)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -29,6 +29,7 @@ pub mod pallet_indices;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_nomination_pools;
+pub mod pallet_offences;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_referenda_fellowship_referenda;
@@ -52,7 +53,9 @@ pub mod runtime_common_slots;
 pub mod runtime_parachains_configuration;
 pub mod runtime_parachains_disputes;
 pub mod runtime_parachains_disputes_slashing;
+pub mod runtime_parachains_finality_lag;
 pub mod runtime_parachains_hrmp;
+pub mod runtime_parachains_inactivity;
 pub mod runtime_parachains_inclusion;
 pub mod runtime_parachains_initializer;
 pub mod runtime_parachains_paras;
@@ -338,4 +338,23 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: Identity IdentityOf (r:1 w:1)
+	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: Identity RequestExpiryOf (r:1 w:1)
+	/// Proof: Identity RequestExpiryOf (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(949), added: 3424, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 20]`.
+	fn refund_expired_request(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515 + r * (57 ±0) + x * (66 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(41_003_172, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 3_877
+			.saturating_add(Weight::from_parts(58_209, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
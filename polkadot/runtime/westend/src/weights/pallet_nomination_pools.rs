@@ -618,4 +618,19 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn migrate_pool_to_delegate_stake() -> Weight {
+		Self::join()
+	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn migrate_delegation() -> Weight {
+		Self::join()
+	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn apply_slash() -> Weight {
+		Self::join()
+	}
 }
@@ -50,173 +50,43 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_offences`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_offences::WeightInfo for WeightInfo<T> {
-	/// Storage: Offences ConcurrentReportsIndex (r:1 w:1)
-	/// Proof Skipped: Offences ConcurrentReportsIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Offences Reports (r:100 w:100)
-	/// Proof Skipped: Offences Reports (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Staking SlashRewardFraction (r:1 w:0)
-	/// Proof: Staking SlashRewardFraction (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Staking ActiveEra (r:1 w:0)
-	/// Proof: Staking ActiveEra (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
-	/// Storage: Staking ErasStartSessionIndex (r:1 w:0)
-	/// Proof: Staking ErasStartSessionIndex (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
-	/// Storage: Staking Invulnerables (r:1 w:0)
-	/// Proof Skipped: Staking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Staking ValidatorSlashInEra (r:100 w:100)
-	/// Proof: Staking ValidatorSlashInEra (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
-	/// Storage: Staking SlashingSpans (r:1700 w:1700)
-	/// Proof Skipped: Staking SlashingSpans (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Staking SpanSlash (r:1700 w:1700)
-	/// Proof: Staking SpanSlash (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
-	/// Storage: Staking Validators (r:100 w:100)
-	/// Proof: Staking Validators (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
-	/// Storage: Staking CounterForValidators (r:1 w:1)
-	/// Proof: Staking CounterForValidators (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: VoterList ListNodes (r:300 w:300)
-	/// Proof: VoterList ListNodes (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
-	/// Storage: VoterList CounterForListNodes (r:1 w:1)
-	/// Proof: VoterList CounterForListNodes (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Staking Nominators (r:100 w:0)
-	/// Proof: Staking Nominators (max_values: None, max_size: Some(558), added: 3033, mode: MaxEncodedLen)
-	/// Storage: Staking OffendingValidators (r:1 w:1)
-	/// Proof Skipped: Staking OffendingValidators (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Session Validators (r:1 w:0)
-	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Staking NominatorSlashInEra (r:1600 w:1600)
-	/// Proof: Staking NominatorSlashInEra (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: Staking UnappliedSlashes (r:1 w:1)
-	/// Proof Skipped: Staking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
-	/// Storage: VoterList ListBags (r:1 w:1)
-	/// Proof: VoterList ListBags (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
-	/// The range of component `r` is `[1, 100]`.
-	/// The range of component `o` is `[2, 100]`.
-	/// The range of component `n` is `[0, 16]`.
-	fn report_offence_im_online(_r: u32, o: u32, n: u32, ) -> Weight {
+	/// Storage: Offences QueueBounds (r:1 w:0)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn service_queue_base() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (3462 ±0) + o * (1042 ±0)`
-		//  Estimated: `88666 + n * (157019 ±38) + o * (26384 ±6)`
-		// Minimum execution time: 535_077_000 picoseconds.
-		Weight::from_parts(552_420_000, 0)
-			.saturating_add(Weight::from_parts(0, 88666))
-			// Standard Error: 4_786_756
-			.saturating_add(Weight::from_parts(434_857_612, 0).saturating_mul(o.into()))
-			// Standard Error: 29_086_480
-			.saturating_add(Weight::from_parts(365_733_267, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(124))
-			.saturating_add(T::DbWeight::get().reads((37_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().reads((187_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(117))
-			.saturating_add(T::DbWeight::get().writes((36_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().writes((187_u64).saturating_mul(n.into())))
-			.saturating_add(Weight::from_parts(0, 157019).saturating_mul(n.into()))
-			.saturating_add(Weight::from_parts(0, 26384).saturating_mul(o.into()))
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 2_512_000 picoseconds.
+		Weight::from_parts(2_698_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: Offences ConcurrentReportsIndex (r:1 w:1)
-	/// Proof Skipped: Offences ConcurrentReportsIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Offences Reports (r:1 w:1)
-	/// Proof Skipped: Offences Reports (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Staking SlashRewardFraction (r:1 w:0)
-	/// Proof: Staking SlashRewardFraction (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Staking ActiveEra (r:1 w:0)
-	/// Proof: Staking ActiveEra (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
-	/// Storage: Staking ErasStartSessionIndex (r:1 w:0)
-	/// Proof: Staking ErasStartSessionIndex (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
-	/// Storage: Staking Invulnerables (r:1 w:0)
-	/// Proof Skipped: Staking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Staking ValidatorSlashInEra (r:1 w:1)
-	/// Proof: Staking ValidatorSlashInEra (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
-	/// Storage: Staking SlashingSpans (r:17 w:17)
-	/// Proof Skipped: Staking SlashingSpans (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Staking SpanSlash (r:17 w:17)
-	/// Proof: Staking SpanSlash (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
-	/// Storage: Staking Validators (r:1 w:1)
-	/// Proof: Staking Validators (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
-	/// Storage: Staking CounterForValidators (r:1 w:1)
-	/// Proof: Staking CounterForValidators (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: VoterList ListNodes (r:3 w:3)
-	/// Proof: VoterList ListNodes (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
-	/// Storage: VoterList CounterForListNodes (r:1 w:1)
-	/// Proof: VoterList CounterForListNodes (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Staking Nominators (r:1 w:0)
-	/// Proof: Staking Nominators (max_values: None, max_size: Some(558), added: 3033, mode: MaxEncodedLen)
-	/// Storage: Staking OffendingValidators (r:1 w:1)
-	/// Proof Skipped: Staking OffendingValidators (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Session Validators (r:1 w:0)
-	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Staking NominatorSlashInEra (r:16 w:16)
-	/// Proof: Staking NominatorSlashInEra (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: Staking UnappliedSlashes (r:1 w:1)
-	/// Proof Skipped: Staking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
-	/// Storage: VoterList ListBags (r:1 w:1)
-	/// Proof: VoterList ListBags (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
-	/// The range of component `n` is `[0, 16]`.
-	fn report_offence_grandpa(n: u32, ) -> Weight {
+	/// Storage: Offences QueueBounds (r:1 w:1)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Offences QueuedReports (r:1 w:1)
+	/// Proof Skipped: Offences QueuedReports (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Offences KindWeight (r:0 w:1)
+	/// Proof Skipped: Offences KindWeight (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System ExecutionPhase (r:1 w:0)
+	/// Proof: System ExecutionPhase (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	/// Storage: System EventCount (r:1 w:1)
+	/// Proof: System EventCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Events (r:1 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `o` is `[1, 100]`.
+	fn process_queued_report(o: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1763 + n * (65 ±0)`
-		//  Estimated: `8149 + n * (2551 ±0)`
-		// Minimum execution time: 98_496_000 picoseconds.
-		Weight::from_parts(103_619_589, 0)
-			.saturating_add(Weight::from_parts(0, 8149))
-			// Standard Error: 46_011
-			.saturating_add(Weight::from_parts(12_464_366, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(19))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(13))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
-			.saturating_add(Weight::from_parts(0, 2551).saturating_mul(n.into()))
-	}
-	/// Storage: Offences ConcurrentReportsIndex (r:1 w:1)
-	/// Proof Skipped: Offences ConcurrentReportsIndex (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Offences Reports (r:1 w:1)
-	/// Proof Skipped: Offences Reports (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Staking SlashRewardFraction (r:1 w:0)
-	/// Proof: Staking SlashRewardFraction (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Staking ActiveEra (r:1 w:0)
-	/// Proof: Staking ActiveEra (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
-	/// Storage: Staking ErasStartSessionIndex (r:1 w:0)
-	/// Proof: Staking ErasStartSessionIndex (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
-	/// Storage: Staking Invulnerables (r:1 w:0)
-	/// Proof Skipped: Staking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Staking ValidatorSlashInEra (r:1 w:1)
-	/// Proof: Staking ValidatorSlashInEra (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
-	/// Storage: Staking SlashingSpans (r:17 w:17)
-	/// Proof Skipped: Staking SlashingSpans (max_values: None, max_size: None, mode: Measured)
-	/// Storage: Staking SpanSlash (r:17 w:17)
-	/// Proof: Staking SpanSlash (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
-	/// Storage: Staking Validators (r:1 w:1)
-	/// Proof: Staking Validators (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
-	/// Storage: Staking CounterForValidators (r:1 w:1)
-	/// Proof: Staking CounterForValidators (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: VoterList ListNodes (r:3 w:3)
-	/// Proof: VoterList ListNodes (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
-	/// Storage: VoterList CounterForListNodes (r:1 w:1)
-	/// Proof: VoterList CounterForListNodes (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: Staking Nominators (r:1 w:0)
-	/// Proof: Staking Nominators (max_values: None, max_size: Some(558), added: 3033, mode: MaxEncodedLen)
-	/// Storage: Staking OffendingValidators (r:1 w:1)
-	/// Proof Skipped: Staking OffendingValidators (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Session Validators (r:1 w:0)
-	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: Staking NominatorSlashInEra (r:16 w:16)
-	/// Proof: Staking NominatorSlashInEra (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: Staking UnappliedSlashes (r:1 w:1)
-	/// Proof Skipped: Staking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
-	/// Storage: VoterList ListBags (r:1 w:1)
-	/// Proof: VoterList ListBags (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
-	/// The range of component `n` is `[0, 16]`.
-	fn report_offence_babe(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1763 + n * (65 ±0)`
-		//  Estimated: `8149 + n * (2551 ±0)`
-		// Minimum execution time: 98_531_000 picoseconds.
-		Weight::from_parts(104_912_692, 0)
-			.saturating_add(Weight::from_parts(0, 8149))
-			// Standard Error: 40_911
-			.saturating_add(Weight::from_parts(12_219_649, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(19))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(13))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
-			.saturating_add(Weight::from_parts(0, 2551).saturating_mul(n.into()))
+		//  Measured:  `193 + o * (34 ±0)`
+		//  Estimated: `3658 + o * (34 ±0)`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_416_309, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			// Standard Error: 1_262
+			.saturating_add(Weight::from_parts(1_063_871, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 34).saturating_mul(o.into()))
 	}
 }
@@ -522,4 +522,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: FellowshipReferenda TrackDetailsFor (r:0 w:1)
+	/// Proof: FellowshipReferenda TrackDetailsFor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -113,6 +113,17 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(2_253_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// Storage: `XcmPallet::VersionOverrides` (r:0 w:1)
+	/// Proof: `XcmPallet::VersionOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_xcm_version_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_444_000 picoseconds.
+		Weight::from_parts(7_671_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `XcmPallet::VersionNotifiers` (r:1 w:1)
 	/// Proof: `XcmPallet::VersionNotifiers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `XcmPallet::QueryCounter` (r:1 w:1)
@@ -294,4 +305,12 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn transfer_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_007_000 picoseconds.
+		Weight::from_parts(17_471_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
 }
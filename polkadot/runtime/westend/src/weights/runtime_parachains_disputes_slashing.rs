@@ -98,4 +98,38 @@ impl<T: frame_system::Config> runtime_parachains::disputes::slashing::WeightInfo
 			.saturating_add(T::DbWeight::get().writes(9))
 			.saturating_add(Weight::from_parts(0, 192).saturating_mul(n.into()))
 	}
+	/// Storage: Session CurrentIndex (r:1 w:0)
+	/// Proof Skipped: Session CurrentIndex (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Historical HistoricalSessions (r:1 w:0)
+	/// Proof: Historical HistoricalSessions (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ParasSlashing UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: ParasSlashing UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Offences ConcurrentReportsIndex (r:1 w:1)
+	/// Proof Skipped: Offences ConcurrentReportsIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Offences Reports (r:1 w:1)
+	/// Proof Skipped: Offences Reports (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Staking SlashRewardFraction (r:1 w:0)
+	/// Proof: Staking SlashRewardFraction (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Staking ActiveEra (r:1 w:0)
+	/// Proof: Staking ActiveEra (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
+	/// Storage: Staking ErasStartSessionIndex (r:1 w:0)
+	/// Proof: Staking ErasStartSessionIndex (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Staking Invulnerables (r:1 w:0)
+	/// Proof Skipped: Staking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Staking ValidatorSlashInEra (r:1 w:1)
+	/// Proof: Staking ValidatorSlashInEra (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Staking SlashingSpans (r:1 w:1)
+	/// Proof Skipped: Staking SlashingSpans (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Staking SpanSlash (r:1 w:1)
+	/// Proof: Staking SpanSlash (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Staking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: Staking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[4, 300]`.
+	/// Not benchmarked yet: only the storage accesses of the benchmark are accounted for, until
+	/// this file is regenerated.
+	fn report_dispute_lost_against_valid(_n: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
 }
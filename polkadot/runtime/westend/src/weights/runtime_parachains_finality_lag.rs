@@ -0,0 +1,46 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `runtime_parachains::finality_lag`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `runtime_parachains::finality_lag`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> runtime_parachains::finality_lag::WeightInfo for WeightInfo<T> {
+	/// Storage: FinalityLag Included (r:1 w:1)
+	/// Proof Skipped: FinalityLag Included (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: FinalityLag LaggingSince (r:1 w:1)
+	/// Proof Skipped: FinalityLag LaggingSince (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: FinalityLag ApprovalCheckingLag (r:0 w:1)
+	/// Proof Skipped: FinalityLag ApprovalCheckingLag (max_values: Some(1), max_size: None, mode: Measured)
+	fn note_finality_lag() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 6_012_000 picoseconds.
+		Weight::from_parts(6_283_000, 0)
+			.saturating_add(Weight::from_parts(0, 1485))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,86 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `runtime_parachains::inactivity`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `runtime_parachains::inactivity`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> runtime_parachains::inactivity::WeightInfo for WeightInfo<T> {
+	/// Storage: Inactivity AuthoredBlocks (r:1 w:1)
+	/// Proof Skipped: Inactivity AuthoredBlocks (max_values: None, max_size: None, mode: Measured)
+	fn note_author() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `3544`
+		// Minimum execution time: 6_105_000 picoseconds.
+		Weight::from_parts(6_342_000, 0)
+			.saturating_add(Weight::from_parts(0, 3544))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Inactivity AvailabilityVotes (r:1000 w:1000)
+	/// Proof Skipped: Inactivity AvailabilityVotes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `v` is `[1, 1000]`.
+	fn reward_bitfields(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `990 + v * (2475 ±0)`
+		// Minimum execution time: 3_106_000 picoseconds.
+		Weight::from_parts(3_248_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 2_021
+			.saturating_add(Weight::from_parts(3_712_904, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(v.into()))
+	}
+	/// Storage: ParasShared CurrentSessionIndex (r:1 w:0)
+	/// Proof Skipped: ParasShared CurrentSessionIndex (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Inactivity TrackedSession (r:1 w:1)
+	/// Proof Skipped: Inactivity TrackedSession (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParaSessionInfo AccountKeys (r:1 w:0)
+	/// Proof Skipped: ParaSessionInfo AccountKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParasShared ActiveValidatorKeys (r:1 w:0)
+	/// Proof Skipped: ParasShared ActiveValidatorKeys (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Inactivity AuthoredBlocks (r:1000 w:1000)
+	/// Proof Skipped: Inactivity AuthoredBlocks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Inactivity AvailabilityVotes (r:1000 w:1000)
+	/// Proof Skipped: Inactivity AvailabilityVotes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `v` is `[1, 1000]`.
+	fn new_session(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1166 + v * (97 ±0)`
+		//  Estimated: `4631 + v * (2573 ±0)`
+		// Minimum execution time: 34_204_000 picoseconds.
+		Weight::from_parts(26_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 4631))
+			// Standard Error: 8_311
+			.saturating_add(Weight::from_parts(9_611_742, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(v.into()))
+	}
+}
@@ -72,4 +72,22 @@ impl<T: frame_system::Config> runtime_parachains::inclusion::WeightInfo for Weig
 			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 	}
+	/// Storage: ParaInclusion UpwardDeliveryFeeFactor (r:0 w:1)
+	/// Proof Skipped: ParaInclusion UpwardDeliveryFeeFactor (max_values: None, max_size: None, mode: Measured)
+	/// Storage: MessageQueue BookStateFor (r:1 w:0)
+	/// Proof: MessageQueue BookStateFor (max_values: None, max_size: Some(55), added: 2530, mode: MaxEncodedLen)
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: unknown `0x3a72656c61795f64697370617463685f71756575655f72656d61696e696e675f` (r:0 w:1)
+	/// Proof Skipped: unknown `0x3a72656c61795f64697370617463685f71756575655f72656d61696e696e675f` (r:0 w:1)
+	fn force_reset_upward_delivery_fee_factor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1523`
+		//  Estimated: `4988`
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_030_000, 0)
+			.saturating_add(Weight::from_parts(0, 4988))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -33,6 +33,7 @@ use runtime_common::{
 	ToAuthor,
 };
 use sp_core::ConstU32;
+use sp_runtime::Percent;
 use westend_runtime_constants::{
 	currency::CENTS, system_parachain::*, xcm::body::FELLOWSHIP_ADMIN_INDEX,
 };
@@ -53,6 +54,9 @@ parameter_types! {
 	pub const ThisNetwork: NetworkId = Westend;
 	pub const UniversalLocation: InteriorMultiLocation = X1(GlobalConsensus(ThisNetwork::get()));
 	pub CheckAccount: AccountId = XcmPallet::check_account();
+	/// The margin added to the benchmarked weight of calls when building `Transact` instructions
+	/// which dispatch them on this chain.
+	pub const TransactWeightMargin: Percent = Percent::from_percent(10);
 	pub LocalCheckAccount: (AccountId, MintLocation) = (CheckAccount::get(), MintLocation::Local);
 	pub TreasuryAccount: AccountId = Treasury::account_id();
 	/// The asset ID for the asset that we use to pay for message delivery fees.
@@ -81,6 +85,8 @@ pub type LocalAssetTransactor = XcmCurrencyAdapter<
 	AccountId,
 	// It's a native asset so we keep track of the teleports to maintain total issuance.
 	LocalCheckAccount,
+	// We record the checked issuance of our teleports to check it in `try_state`.
+	XcmPallet,
 >;
 
 type LocalOriginConverter = (
@@ -188,6 +194,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = XcmPallet;
 	type Aliasers = Nothing;
 }
 
@@ -259,6 +266,11 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = ConstU32<8>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type ForeignAssetRegistrationOrigin = frame_system::EnsureNever<MultiLocation>;
+	type ForeignAssetRegistrar = ();
+	type TeleportAccounting = LocalAssetTransactor;
+	type MaxTransferReceipts = ConstU32<1024>;
+	type UnixTime = crate::Timestamp;
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
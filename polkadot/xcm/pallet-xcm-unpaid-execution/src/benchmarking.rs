@@ -0,0 +1,88 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the XCM unpaid execution pallet.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+/// The location used in the benchmarks, the longest of those commonly granted unpaid execution.
+fn location() -> MultiLocation {
+	MultiLocation::new(1, X2(Parachain(1000), PalletInstance(50)))
+}
+
+fn allowance<T: Config>(
+	expires_at: BlockNumberFor<T>,
+) -> UnpaidExecutionAllowance<BlockNumberFor<T>> {
+	UnpaidExecutionAllowance { weight_limit: Weight::MAX, expires_at: Some(expires_at) }
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn allow_unpaid_execution() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// Replace an existing allowance.
+		let now = frame_system::Pallet::<T>::block_number();
+		UnpaidExecutionOrigins::<T>::insert(location(), allowance::<T>(now + 1u32.into()));
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(location().into()),
+			Weight::MAX,
+			Some(now + 2u32.into()),
+		);
+
+		assert_eq!(
+			UnpaidExecutionOrigins::<T>::get(location()),
+			Some(allowance::<T>(now + 2u32.into()))
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_unpaid_execution() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		UnpaidExecutionOrigins::<T>::insert(location(), allowance::<T>(now + 1u32.into()));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(location().into()));
+
+		assert!(!UnpaidExecutionOrigins::<T>::contains_key(location()));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired() {
+		let caller: T::AccountId = whitelisted_caller();
+		let now = frame_system::Pallet::<T>::block_number();
+		UnpaidExecutionOrigins::<T>::insert(location(), allowance::<T>(now));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(location().into()));
+
+		assert!(!UnpaidExecutionOrigins::<T>::contains_key(location()));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
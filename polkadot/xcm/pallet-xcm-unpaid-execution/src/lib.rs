@@ -0,0 +1,206 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pallet to manage the set of origins which are granted unpaid XCM execution.
+//!
+//! Each allowed origin has a weight ceiling and an optional expiry block. The pallet implements
+//! [`UnpaidExecutionLimit`], so it can be plugged into the
+//! `xcm_builder::AllowLimitedUnpaidExecutionFrom` barrier. This makes trust relationships between
+//! (system) chains explicit on-chain state, managed by `AdminOrigin`, rather than compile-time
+//! constants.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, weights::Weight};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_std::boxed::Box;
+use xcm::prelude::*;
+use xcm_executor::traits::UnpaidExecutionLimit;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The terms under which an origin is granted unpaid execution.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UnpaidExecutionAllowance<BlockNumber> {
+	/// The maximum weight of a single message executed without payment.
+	pub weight_limit: Weight,
+	/// The block from which on the allowance no longer applies, if any.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> UnpaidExecutionAllowance<BlockNumber> {
+	/// Whether the allowance has expired at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().map_or(false, |expires_at| now >= expires_at)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin allowed to grant and revoke unpaid execution.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The origins granted unpaid execution, keyed by their location in the latest XCM version.
+	///
+	/// Locations given in older versions are converted first, so every location has one key.
+	#[pallet::storage]
+	pub type UnpaidExecutionOrigins<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		UnpaidExecutionAllowance<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `location` was granted unpaid execution up to `weight_limit` per message.
+		UnpaidExecutionAllowed {
+			location: MultiLocation,
+			weight_limit: Weight,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// The unpaid execution of `location` was revoked.
+		UnpaidExecutionRevoked { location: MultiLocation },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The version of the `Versioned` value used is not able to be interpreted.
+		BadVersion,
+		/// The location is not granted unpaid execution.
+		NotAllowed,
+		/// The expiry block is not in the future.
+		AlreadyExpired,
+		/// The allowance of the location has not expired yet.
+		NotExpired,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Grant `location` unpaid execution of messages weighing at most `weight_limit`, until
+		/// block `expires_at` (exclusive) or indefinitely if `None`.
+		///
+		/// Replaces any existing allowance of `location`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::allow_unpaid_execution())]
+		pub fn allow_unpaid_execution(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			weight_limit: Weight,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+			let allowance = UnpaidExecutionAllowance { weight_limit, expires_at };
+			ensure!(
+				!allowance.is_expired(&frame_system::Pallet::<T>::block_number()),
+				Error::<T>::AlreadyExpired
+			);
+
+			UnpaidExecutionOrigins::<T>::insert(location, allowance);
+			Self::deposit_event(Event::UnpaidExecutionAllowed {
+				location,
+				weight_limit,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Revoke the unpaid execution of `location`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::revoke_unpaid_execution())]
+		pub fn revoke_unpaid_execution(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			UnpaidExecutionOrigins::<T>::take(location).ok_or(Error::<T>::NotAllowed)?;
+			Self::deposit_event(Event::UnpaidExecutionRevoked { location });
+			Ok(())
+		}
+
+		/// Remove the expired allowance of `location`.
+		///
+		/// Can be called by any signed origin.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_expired())]
+		pub fn remove_expired(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadVersion)?;
+
+			let allowance =
+				UnpaidExecutionOrigins::<T>::get(location).ok_or(Error::<T>::NotAllowed)?;
+			ensure!(
+				allowance.is_expired(&frame_system::Pallet::<T>::block_number()),
+				Error::<T>::NotExpired
+			);
+			UnpaidExecutionOrigins::<T>::remove(location);
+			Self::deposit_event(Event::UnpaidExecutionRevoked { location });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The allowance of `location`, if it is granted unpaid execution and has not expired.
+	pub fn allowance(
+		location: &MultiLocation,
+	) -> Option<UnpaidExecutionAllowance<BlockNumberFor<T>>> {
+		UnpaidExecutionOrigins::<T>::get(location)
+			.filter(|allowance| !allowance.is_expired(&frame_system::Pallet::<T>::block_number()))
+	}
+}
+
+impl<T: Config> UnpaidExecutionLimit for Pallet<T> {
+	fn weight_limit(origin: &MultiLocation) -> Option<Weight> {
+		Self::allowance(origin).map(|allowance| allowance.weight_limit)
+	}
+}
@@ -0,0 +1,190 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event, UnpaidExecutionAllowance, UnpaidExecutionOrigins};
+use frame_support::{assert_noop, assert_ok, traits::ProcessMessageError, weights::Weight};
+use sp_runtime::DispatchError::BadOrigin;
+use xcm::prelude::*;
+use xcm_builder::AllowLimitedUnpaidExecutionFrom;
+use xcm_executor::traits::{Properties, ShouldExecute, UnpaidExecutionLimit};
+
+fn sibling() -> MultiLocation {
+	(Parent, Parachain(1000)).into()
+}
+
+fn versioned(location: MultiLocation) -> Box<VersionedMultiLocation> {
+	Box::new(location.into())
+}
+
+#[test]
+fn allow_and_revoke_works() {
+	new_test_ext().execute_with(|| {
+		let limit = Weight::from_parts(1_000, 1_000);
+		assert_noop!(
+			XcmUnpaidExecution::allow_unpaid_execution(
+				RuntimeOrigin::signed(1),
+				versioned(sibling()),
+				limit,
+				None
+			),
+			BadOrigin
+		);
+		assert_eq!(XcmUnpaidExecution::weight_limit(&sibling()), None);
+
+		assert_ok!(XcmUnpaidExecution::allow_unpaid_execution(
+			RuntimeOrigin::root(),
+			versioned(sibling()),
+			limit,
+			None
+		));
+		System::assert_last_event(
+			Event::UnpaidExecutionAllowed {
+				location: sibling(),
+				weight_limit: limit,
+				expires_at: None,
+			}
+			.into(),
+		);
+		assert_eq!(XcmUnpaidExecution::weight_limit(&sibling()), Some(limit));
+		assert_eq!(XcmUnpaidExecution::weight_limit(&Parent.into()), None);
+
+		assert_ok!(XcmUnpaidExecution::revoke_unpaid_execution(
+			RuntimeOrigin::root(),
+			versioned(sibling())
+		));
+		System::assert_last_event(Event::UnpaidExecutionRevoked { location: sibling() }.into());
+		assert_eq!(XcmUnpaidExecution::weight_limit(&sibling()), None);
+		assert_noop!(
+			XcmUnpaidExecution::revoke_unpaid_execution(
+				RuntimeOrigin::root(),
+				versioned(sibling())
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn allowances_are_keyed_by_latest_location() {
+	new_test_ext().execute_with(|| {
+		let limit = Weight::from_parts(1_000, 1_000);
+		let v2_sibling = xcm::v2::MultiLocation::try_from(sibling()).unwrap();
+		assert_ok!(XcmUnpaidExecution::allow_unpaid_execution(
+			RuntimeOrigin::root(),
+			Box::new(VersionedMultiLocation::V2(v2_sibling)),
+			limit,
+			None
+		));
+		assert_eq!(
+			UnpaidExecutionOrigins::<Test>::get(sibling()),
+			Some(UnpaidExecutionAllowance { weight_limit: limit, expires_at: None })
+		);
+		assert_eq!(XcmUnpaidExecution::weight_limit(&sibling()), Some(limit));
+
+		// The allowance granted in an older version is revoked in the latest one.
+		assert_ok!(XcmUnpaidExecution::revoke_unpaid_execution(
+			RuntimeOrigin::root(),
+			versioned(sibling())
+		));
+		assert_eq!(UnpaidExecutionOrigins::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn allowance_expires() {
+	new_test_ext().execute_with(|| {
+		let limit = Weight::from_parts(1_000, 1_000);
+		assert_noop!(
+			XcmUnpaidExecution::allow_unpaid_execution(
+				RuntimeOrigin::root(),
+				versioned(sibling()),
+				limit,
+				Some(1)
+			),
+			Error::<Test>::AlreadyExpired
+		);
+		assert_ok!(XcmUnpaidExecution::allow_unpaid_execution(
+			RuntimeOrigin::root(),
+			versioned(sibling()),
+			limit,
+			Some(3)
+		));
+		assert_noop!(
+			XcmUnpaidExecution::remove_expired(RuntimeOrigin::signed(1), versioned(sibling())),
+			Error::<Test>::NotExpired
+		);
+
+		System::set_block_number(2);
+		assert_eq!(
+			XcmUnpaidExecution::allowance(&sibling()),
+			Some(UnpaidExecutionAllowance { weight_limit: limit, expires_at: Some(3) })
+		);
+
+		System::set_block_number(3);
+		assert_eq!(XcmUnpaidExecution::weight_limit(&sibling()), None);
+		assert_ok!(XcmUnpaidExecution::remove_expired(
+			RuntimeOrigin::signed(1),
+			versioned(sibling())
+		));
+		System::assert_last_event(Event::UnpaidExecutionRevoked { location: sibling() }.into());
+		assert_noop!(
+			XcmUnpaidExecution::remove_expired(RuntimeOrigin::signed(1), versioned(sibling())),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn barrier_respects_allowances() {
+	new_test_ext().execute_with(|| {
+		let mut message = Xcm::<()>(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			ClearOrigin,
+		]);
+		let mut props = Properties { weight_credit: Weight::zero(), message_id: None };
+		let mut should_execute = |max_weight| {
+			AllowLimitedUnpaidExecutionFrom::<XcmUnpaidExecution>::should_execute(
+				&sibling(),
+				message.inner_mut(),
+				max_weight,
+				&mut props,
+			)
+		};
+
+		assert_eq!(
+			should_execute(Weight::from_parts(10, 10)),
+			Err(ProcessMessageError::Unsupported)
+		);
+
+		assert_ok!(XcmUnpaidExecution::allow_unpaid_execution(
+			RuntimeOrigin::root(),
+			versioned(sibling()),
+			Weight::from_parts(10, 10),
+			Some(5)
+		));
+		assert_eq!(should_execute(Weight::from_parts(10, 10)), Ok(()));
+		assert_eq!(
+			should_execute(Weight::from_parts(11, 10)),
+			Err(ProcessMessageError::Overweight(Weight::from_parts(11, 10)))
+		);
+
+		System::set_block_number(5);
+		assert_eq!(
+			should_execute(Weight::from_parts(10, 10)),
+			Err(ProcessMessageError::Unsupported)
+		);
+	});
+}
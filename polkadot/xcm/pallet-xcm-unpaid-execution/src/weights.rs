@@ -0,0 +1,72 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_xcm_unpaid_execution
+//!
+//! Not benchmarked yet: only the storage accesses of the benchmarks in `benchmarking.rs` are
+//! accounted for, until this file is generated with the benchmark CLI.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_unpaid_execution.
+pub trait WeightInfo {
+	fn allow_unpaid_execution() -> Weight;
+	fn revoke_unpaid_execution() -> Weight;
+	fn remove_expired() -> Weight;
+}
+
+/// Weights for pallet_xcm_unpaid_execution using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: XcmUnpaidExecution UnpaidExecutionOrigins (r:0 w:1)
+	fn allow_unpaid_execution() -> Weight {
+		T::DbWeight::get().writes(1_u64)
+	}
+	/// Storage: XcmUnpaidExecution UnpaidExecutionOrigins (r:1 w:1)
+	fn revoke_unpaid_execution() -> Weight {
+		T::DbWeight::get().reads(1_u64)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmUnpaidExecution UnpaidExecutionOrigins (r:1 w:1)
+	fn remove_expired() -> Weight {
+		T::DbWeight::get().reads(1_u64)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: XcmUnpaidExecution UnpaidExecutionOrigins (r:0 w:1)
+	fn allow_unpaid_execution() -> Weight {
+		RocksDbWeight::get().writes(1_u64)
+	}
+	/// Storage: XcmUnpaidExecution UnpaidExecutionOrigins (r:1 w:1)
+	fn revoke_unpaid_execution() -> Weight {
+		RocksDbWeight::get().reads(1_u64)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XcmUnpaidExecution UnpaidExecutionOrigins (r:1 w:1)
+	fn remove_expired() -> Weight {
+		RocksDbWeight::get().reads(1_u64)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -27,13 +27,13 @@ mod tests;
 
 pub mod migration;
 
-use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
+use codec::{Codec, Decode, DecodeAll, Encode, EncodeLike, MaxEncodedLen};
 use frame_support::{
 	dispatch::GetDispatchInfo,
 	pallet_prelude::*,
 	traits::{
-		Contains, ContainsPair, Currency, Defensive, EnsureOrigin, Get, LockableCurrency,
-		OriginTrait, WithdrawReasons,
+		Contains, ContainsPair, Currency, EnsureOrigin, Get, LockableCurrency, OriginTrait,
+		UnixTime, WithdrawReasons,
 	},
 	PalletId,
 };
@@ -45,19 +45,24 @@ use sp_runtime::{
 		AccountIdConversion, BadOrigin, BlakeTwo256, BlockNumberProvider, Dispatchable, Hash,
 		Saturating, Zero,
 	},
-	RuntimeDebug,
+	DispatchError, Percent, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{boxed::Box, marker::PhantomData, prelude::*, result::Result, vec};
-use xcm::{latest::QueryResponseInfo, prelude::*};
+use xcm::{
+	latest::{MaxDispatchErrorLen, QueryResponseInfo},
+	prelude::*,
+};
 use xcm_builder::{
-	ExecuteController, ExecuteControllerWeightInfo, QueryController, QueryControllerWeightInfo,
-	SendController, SendControllerWeightInfo,
+	CheckTeleportAccounting, CheckedIssuance, ExecuteController, ExecuteControllerWeightInfo,
+	ForeignAssetMetadata, MintLocation, QueryController, QueryControllerWeightInfo,
+	RecordTeleportAccounting, RegisterForeignAsset, SendController, SendControllerWeightInfo,
 };
 use xcm_executor::{
 	traits::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
 		DropAssets, MatchesFungible, OnResponse, Properties, QueryHandler, QueryResponseStatus,
-		TransactAsset, TransferType, VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
+		RecordXcm, TransactAsset, TransferType, VersionChangeNotifier, WeightBounds,
+		XcmAssetTransfers,
 	},
 	Assets,
 };
@@ -69,6 +74,7 @@ pub trait WeightInfo {
 	fn execute() -> Weight;
 	fn force_xcm_version() -> Weight;
 	fn force_default_xcm_version() -> Weight;
+	fn force_xcm_version_override() -> Weight;
 	fn force_subscribe_version_notify() -> Weight;
 	fn force_unsubscribe_version_notify() -> Weight;
 	fn force_suspension() -> Weight;
@@ -81,6 +87,7 @@ pub trait WeightInfo {
 	fn migrate_and_notify_old_targets() -> Weight;
 	fn new_query() -> Weight;
 	fn take_response() -> Weight;
+	fn transfer_assets() -> Weight;
 }
 
 /// fallback implementation
@@ -110,6 +117,10 @@ impl WeightInfo for TestWeightInfo {
 		Weight::from_parts(100_000_000, 0)
 	}
 
+	fn force_xcm_version_override() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
 	fn force_subscribe_version_notify() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
@@ -157,6 +168,10 @@ impl WeightInfo for TestWeightInfo {
 	fn take_response() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
+
+	fn transfer_assets() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
 }
 
 #[frame_support::pallet]
@@ -273,6 +288,29 @@ pub mod pallet {
 		/// The ID type for local consumers of remote locks.
 		type RemoteLockConsumerIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
 
+		/// Required origin for registering foreign assets. If successful, it resolves to the
+		/// `MultiLocation` on whose behalf assets within it are registered.
+		type ForeignAssetRegistrationOrigin: EnsureOrigin<
+			<Self as SysConfig>::RuntimeOrigin,
+			Success = MultiLocation,
+		>;
+
+		/// The asset registry in which foreign assets are registered.
+		type ForeignAssetRegistrar: RegisterForeignAsset;
+
+		/// The asset transactors recording their checked issuance in this pallet, whose teleport
+		/// accounting is checked against their checking accounts by `try_state`.
+		type TeleportAccounting: CheckTeleportAccounting;
+
+		/// The maximum number of transfer receipts kept in storage per origin. Once reached,
+		/// recording a new receipt prunes the oldest one of the same origin. Set to `0` to disable
+		/// [`Pallet::transfer_assets_with_receipt`].
+		#[pallet::constant]
+		type MaxTransferReceipts: Get<u32>;
+
+		/// The time used to timestamp transfer receipts.
+		type UnixTime: UnixTime;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -291,19 +329,19 @@ pub mod pallet {
 			max_weight: Weight,
 		) -> Result<Outcome, DispatchError> {
 			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
-			let hash = message.using_encoded(sp_io::hashing::blake2_256);
+			let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
 			let message = (*message).try_into().map_err(|()| Error::<T>::BadVersion)?;
 			let value = (origin_location, message);
 			ensure!(T::XcmExecuteFilter::contains(&value), Error::<T>::Filtered);
 			let (origin_location, message) = value;
-			let outcome = T::XcmExecutor::execute_xcm_in_credit(
+			let (outcome, context) = T::XcmExecutor::prepare_and_execute_with_context(
 				origin_location,
 				message,
-				hash,
+				&mut hash,
 				max_weight,
 				max_weight,
 			);
-			Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
+			Self::deposit_attempted(&outcome, context);
 			Ok(outcome)
 		}
 	}
@@ -478,6 +516,18 @@ pub mod pallet {
 		FeesPaid { paying: MultiLocation, fees: MultiAssets },
 		/// Some assets have been claimed from an asset trap
 		AssetsClaimed { hash: H256, origin: MultiLocation, assets: VersionedMultiAssets },
+		/// Execution of an XCM message was attempted, but did not complete. Provides the index and
+		/// kind of the failing instruction and the error raised by the error handler, if any. It
+		/// is immediately followed by the corresponding `Attempted` event.
+		AttemptIncomplete { context: xcm::latest::ErrorContext },
+		/// The XCM version used for a location has been overridden, or the override was removed.
+		VersionOverrideChanged { location: MultiLocation, version: Option<XcmVersion> },
+		/// A foreign asset has been registered, or its metadata updated, by its owner.
+		ForeignAssetRegistered { owner: MultiLocation, asset: MultiLocation },
+		/// A receipt of an asset transfer has been recorded.
+		TransferReceiptRecorded { id: u64, origin: MultiLocation, topic: XcmHash },
+		/// A transfer receipt has been pruned to make room for newer ones.
+		TransferReceiptPruned { id: u64 },
 	}
 
 	#[pallet::origin]
@@ -549,6 +599,15 @@ pub mod pallet {
 		TooManyReserves,
 		/// Local XCM execution of asset transfer incomplete.
 		LocalExecutionIncomplete,
+		/// The default XCM version is lower than the version of a location we are subscribed to.
+		DefaultVersionTooLow,
+		/// The asset is not located within the location of the origin registering it.
+		AssetNotOwnedByOrigin,
+		/// Transfer receipts are disabled by `MaxTransferReceipts`.
+		TransferReceiptsDisabled,
+		/// Too many locations are subscribed to for the default XCM version to be checked against
+		/// their versions.
+		TooManySubscriptions,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -652,6 +711,38 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Receipts of transfers made through [`Pallet::transfer_assets_with_receipt`], by the
+	/// location which initiated them, oldest first.
+	///
+	/// At most `MaxTransferReceipts` receipts are kept per origin.
+	#[pallet::storage]
+	pub(super) type TransferReceipts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VersionedMultiLocation,
+		Vec<(u64, TransferReceipt<BlockNumberFor<T>>)>,
+		ValueQuery,
+	>;
+
+	/// The id of the next transfer receipt to be recorded.
+	#[pallet::storage]
+	pub(super) type NextTransferReceiptId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// XCM versions to use for particular locations, set by `AdminOrigin`.
+	///
+	/// Takes precedence over both the versions learned through notifications and
+	/// `SafeXcmVersion`.
+	#[pallet::storage]
+	pub(super) type VersionOverrides<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		XcmVersion,
+		Blake2_128Concat,
+		VersionedMultiLocation,
+		XcmVersion,
+		OptionQuery,
+	>;
+
 	/// All locations that we have requested version notifications from.
 	#[pallet::storage]
 	pub(super) type VersionNotifiers<T: Config> = StorageDoubleMap<
@@ -746,10 +837,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The checked issuance of the assets whose teleports are checked by the asset transactors
+	/// using this pallet as their `RecordTeleportAccounting`.
+	#[pallet::storage]
+	pub(super) type CheckedIssuances<T: Config> =
+		StorageMap<_, Blake2_128Concat, VersionedAssetId, CheckedIssuance, OptionQuery>;
+
 	/// Global suspension state of the XCM executor.
 	#[pallet::storage]
 	pub(super) type XcmExecutionSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Whether the XCM programs executed locally are recorded in [`RecordedXcm`].
+	///
+	/// Only relevant if this pallet is the `XcmRecorder` of the XCM executor. Meant to be enabled
+	/// by dry-run runtime APIs and tests only, as recording costs an extra read and write for every
+	/// executed program.
+	#[pallet::storage]
+	pub(crate) type ShouldRecordXcm<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The last XCM program executed locally while [`ShouldRecordXcm`] was enabled.
+	#[pallet::storage]
+	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -805,6 +914,11 @@ pub mod pallet {
 			}
 			weight_used
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			T::TeleportAccounting::check_teleport_accounting()
+		}
 	}
 
 	pub mod migrations {
@@ -1040,17 +1154,39 @@ pub mod pallet {
 		/// Set a safe XCM version (the version that XCM should be encoded with if the most recent
 		/// version a destination can accept is unknown).
 		///
+		/// The version must not be lower than the known version of any location we are subscribed
+		/// to, as those would fall back to it should their version be forgotten. At most
+		/// [`MAX_SUBSCRIPTIONS_CHECKED`] subscriptions are checked, setting a version fails if
+		/// there are more.
+		///
 		/// - `origin`: Must be an origin specified by AdminOrigin.
 		/// - `maybe_xcm_version`: The default XCM encoding version, or `None` to disable.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::force_default_xcm_version())]
+		#[pallet::weight(Pallet::<T>::force_default_xcm_version_weight(MAX_SUBSCRIPTIONS_CHECKED + 1))]
 		pub fn force_default_xcm_version(
 			origin: OriginFor<T>,
 			maybe_xcm_version: Option<XcmVersion>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			T::AdminOrigin::ensure_origin(origin)?;
+			let mut checked = 0;
+			if let Some(xcm_version) = maybe_xcm_version {
+				let subscriptions: Vec<_> = VersionNotifiers::<T>::iter_key_prefix(XCM_VERSION)
+					.take(MAX_SUBSCRIPTIONS_CHECKED as usize + 1)
+					.collect();
+				checked = subscriptions.len() as u32;
+				ensure!(checked <= MAX_SUBSCRIPTIONS_CHECKED, Error::<T>::TooManySubscriptions);
+
+				let required = subscriptions
+					.into_iter()
+					.filter_map(|location| SupportedVersion::<T>::get(XCM_VERSION, location))
+					.max();
+				ensure!(
+					required.map_or(true, |required| xcm_version >= required),
+					Error::<T>::DefaultVersionTooLow
+				);
+			}
 			SafeXcmVersion::<T>::set(maybe_xcm_version);
-			Ok(())
+			Ok(Some(Self::force_default_xcm_version_weight(checked)).into())
 		}
 
 		/// Ask a location to notify us regarding their XCM version and any changes to it.
@@ -1219,12 +1355,231 @@ pub mod pallet {
 			XcmExecutionSuspended::<T>::set(suspended);
 			Ok(())
 		}
+
+		/// Transfer some assets from the local chain to the destination chain through their local,
+		/// destination or remote reserve, or through teleports.
+		///
+		/// Fee payment on the destination side is made from the asset in the `assets` vector of
+		/// index `fee_asset_item` (hence referred to as `fees`), up to enough to pay for
+		/// `weight_limit` of weight. If more weight is needed than `weight_limit`, then the
+		/// operation will fail and the assets sent may be at risk.
+		///
+		/// The transfer type of each asset is determined by the executor for `dest`. All non-fee
+		/// assets must use the same transfer type, while `fees` may take a different one:
+		///
+		/// - `assets` and `fees` using the same transfer type are transferred together;
+		/// - otherwise `fees` are transferred separately, ahead of `assets`, through their own
+		///   local reserve, destination reserve or teleport. This allows e.g. teleporting `assets`
+		///   while paying for execution on `dest` with reserve-transferred `fees`.
+		///
+		/// `fees` using a different transfer type than `assets` are not supported if `assets` are
+		/// to be transferred through a remote reserve, since there is no guarantee that `fees`
+		/// would arrive at `dest` first.
+		///
+		/// - `origin`: Must be capable of withdrawing the `assets` and executing XCM.
+		/// - `dest`: Destination context for the assets. Will typically be `X2(Parent,
+		///   Parachain(..))` to send from parachain to parachain, or `X1(Parachain(..))` to send
+		///   from relay to parachain.
+		/// - `beneficiary`: A beneficiary location for the assets in the context of `dest`. Will
+		///   generally be an `AccountId32` value.
+		/// - `assets`: The assets to be withdrawn. This should include the assets used to pay the
+		///   fee on the `dest` side.
+		/// - `fee_asset_item`: The index into `assets` of the item which should be used to pay
+		///   fees.
+		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
+		#[pallet::call_index(11)]
+		#[pallet::weight(Pallet::<T>::transfer_assets_weight(&assets, &dest))]
+		pub fn transfer_assets(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			beneficiary: Box<VersionedMultiLocation>,
+			assets: Box<VersionedMultiAssets>,
+			fee_asset_item: u32,
+			weight_limit: WeightLimit,
+		) -> DispatchResult {
+			Self::do_transfer_assets(
+				origin,
+				dest,
+				beneficiary,
+				assets,
+				fee_asset_item,
+				weight_limit,
+			)
+		}
+
+		/// Override the version of XCM used to communicate with a particular location, regardless
+		/// of the version it is known to support and of the default version.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `location`: The location whose version is overridden.
+		/// - `maybe_xcm_version`: The version to use for `location`, or `None` to remove the
+		///   override.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::force_xcm_version_override())]
+		pub fn force_xcm_version_override(
+			origin: OriginFor<T>,
+			location: Box<MultiLocation>,
+			maybe_xcm_version: Option<XcmVersion>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let location = *location;
+			let key = LatestVersionedMultiLocation(&location);
+			match maybe_xcm_version {
+				Some(version) => VersionOverrides::<T>::insert(XCM_VERSION, key, version),
+				None => VersionOverrides::<T>::remove(XCM_VERSION, key),
+			}
+			Self::deposit_event(Event::VersionOverrideChanged {
+				location,
+				version: maybe_xcm_version,
+			});
+			Ok(())
+		}
+
+		/// Transfer some assets exactly like [`Pallet::transfer_assets`], and record a durable
+		/// receipt of the transfer on-chain.
+		///
+		/// The receipt contains the origin, `assets`, `dest`, `beneficiary`, `topic`, the block
+		/// and the time at which the transfer was initiated, and can be queried with
+		/// [`Pallet::transfer_receipt`] and [`Pallet::transfer_receipts_of`] without relying on
+		/// archive nodes. At most `MaxTransferReceipts` receipts are kept per origin; recording a
+		/// new one prunes the oldest ones of the same origin.
+		///
+		/// - `topic`: An identifier chosen by the caller to relate the receipt to the transfer in
+		///   their own records.
+		///
+		/// See [`Pallet::transfer_assets`] for the other parameters.
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			Pallet::<T>::transfer_assets_weight(&assets, &dest).saturating_add(
+				T::DbWeight::get().reads_writes(2, 2)
+			)
+		)]
+		pub fn transfer_assets_with_receipt(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			beneficiary: Box<VersionedMultiLocation>,
+			assets: Box<VersionedMultiAssets>,
+			fee_asset_item: u32,
+			weight_limit: WeightLimit,
+			topic: XcmHash,
+		) -> DispatchResult {
+			ensure!(T::MaxTransferReceipts::get() > 0, Error::<T>::TransferReceiptsDisabled);
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin.clone())?;
+			let receipt = TransferReceipt {
+				origin: origin_location.into(),
+				assets: (*assets).clone(),
+				destination: (*dest).clone(),
+				beneficiary: (*beneficiary).clone(),
+				topic,
+				recorded_at: frame_system::Pallet::<T>::block_number(),
+				requested_at: T::UnixTime::now().as_millis().saturated_into(),
+			};
+
+			Self::do_transfer_assets(
+				origin,
+				dest,
+				beneficiary,
+				assets,
+				fee_asset_item,
+				weight_limit,
+			)?;
+
+			let id = Self::record_transfer_receipt(&origin_location, receipt);
+			Self::deposit_event(Event::TransferReceiptRecorded {
+				id,
+				origin: origin_location,
+				topic,
+			});
+			Ok(())
+		}
+
+		/// Register a foreign asset, or update its metadata, on behalf of the location owning it.
+		///
+		/// This is meant to be dispatched by a `Transact` with `OriginKind::Xcm` sent by the owning
+		/// chain, see [`Pallet::register_foreign_asset_instruction`].
+		///
+		/// - `origin`: Must be an origin specified by ForeignAssetRegistrationOrigin.
+		/// - `asset`: The location of the asset, which must be within the location of `origin`.
+		/// - `metadata`: The metadata of the asset.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::ForeignAssetRegistrar::register_weight())]
+		pub fn register_foreign_asset(
+			origin: OriginFor<T>,
+			asset: Box<VersionedMultiLocation>,
+			metadata: ForeignAssetMetadata,
+		) -> DispatchResult {
+			let owner = T::ForeignAssetRegistrationOrigin::ensure_origin(origin)?;
+			let asset: MultiLocation = (*asset).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(asset.starts_with(&owner), Error::<T>::AssetNotOwnedByOrigin);
+
+			T::ForeignAssetRegistrar::register(&owner, &asset, metadata)?;
+			Self::deposit_event(Event::ForeignAssetRegistered { owner, asset });
+			Ok(())
+		}
 	}
 }
 
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
 const MAX_ASSETS_FOR_TRANSFER: usize = 2;
 
+/// The maximum number of subscriptions [`Pallet::force_default_xcm_version`] checks the new
+/// default version against.
+pub const MAX_SUBSCRIPTIONS_CHECKED: u32 = 128;
+
+/// A durable record of an asset transfer initiated through
+/// [`Pallet::transfer_assets_with_receipt`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TransferReceipt<BlockNumber> {
+	/// The location which initiated the transfer.
+	pub origin: VersionedMultiLocation,
+	/// The assets transferred, as given to the call.
+	pub assets: VersionedMultiAssets,
+	/// The destination of the transfer.
+	pub destination: VersionedMultiLocation,
+	/// The beneficiary of the transfer, in the context of `destination`.
+	pub beneficiary: VersionedMultiLocation,
+	/// The identifier given by the initiator.
+	pub topic: XcmHash,
+	/// The block in which the transfer was initiated.
+	pub recorded_at: BlockNumber,
+	/// The time at which the transfer was initiated, in milliseconds since the Unix epoch.
+	pub requested_at: u64,
+}
+
+/// A [`UnixTime`] which is always the Unix epoch, for runtimes which keep no transfer receipts.
+pub struct NoUnixTime;
+
+impl UnixTime for NoUnixTime {
+	fn now() -> core::time::Duration {
+		Default::default()
+	}
+}
+
+/// The status of a remote `Transact`, reassembled from the `MaybeErrorCode` reported back by
+/// `ReportTransactStatus`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum TransactStatus {
+	/// The call was dispatched successfully.
+	Success,
+	/// The call failed with the given error. Note that the indices within the error refer to the
+	/// pallets and errors of the remote chain.
+	Error(DispatchError),
+	/// The call failed, but its error could not be reassembled, e.g. because it was truncated to
+	/// `MaxDispatchErrorLen` bytes. Contains the raw encoded error.
+	UnknownError(BoundedVec<u8, MaxDispatchErrorLen>),
+}
+
+impl From<MaybeErrorCode> for TransactStatus {
+	fn from(code: MaybeErrorCode) -> Self {
+		match code {
+			MaybeErrorCode::Success => Self::Success,
+			MaybeErrorCode::Error(error) => DispatchError::decode_all(&mut &error[..])
+				.map_or_else(|_| Self::UnknownError(error), Self::Error),
+			MaybeErrorCode::TruncatedError(error) => Self::UnknownError(error),
+		}
+	}
+}
+
 impl<T: Config> QueryHandler for Pallet<T> {
 	type QueryId = u64;
 	type BlockNumber = BlockNumberFor<T>;
@@ -1258,6 +1613,23 @@ impl<T: Config> QueryHandler for Pallet<T> {
 		Ok(query_id)
 	}
 
+	/// To check the status of the query, use `fn query()` or `fn take_transact_status()` passing
+	/// the resultant `QueryId` value.
+	fn report_transact_status(
+		message: &mut Xcm<()>,
+		responder: impl Into<MultiLocation>,
+		timeout: Self::BlockNumber,
+	) -> Result<Self::QueryId, Self::Error> {
+		let responder = responder.into();
+		let destination = Self::UniversalLocation::get()
+			.invert_target(&responder)
+			.map_err(|()| XcmError::LocationNotInvertible)?;
+		let query_id = Self::new_query(responder, timeout, Here);
+		let response_info = QueryResponseInfo { destination, query_id, max_weight: Weight::zero() };
+		Self::add_to_appendix(message, ReportTransactStatus(response_info));
+		Ok(query_id)
+	}
+
 	/// Removes response when ready and emits [Event::ResponseTaken] event.
 	fn take_response(query_id: Self::QueryId) -> QueryResponseStatus<Self::BlockNumber> {
 		match Queries::<T>::get(query_id) {
@@ -1286,6 +1658,22 @@ impl<T: Config> QueryHandler for Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// The weight of `force_default_xcm_version` when `subscriptions` subscriptions were checked,
+	/// each costing a read of `VersionNotifiers` and one of `SupportedVersion`.
+	fn force_default_xcm_version_weight(subscriptions: u32) -> Weight {
+		T::WeightInfo::force_default_xcm_version()
+			.saturating_add(T::DbWeight::get().reads(2 * subscriptions as u64))
+	}
+
+	/// Deposit the `Attempted` event for the `outcome` of executing an XCM message, preceded by
+	/// the `context` of its error if the execution did not complete.
+	fn deposit_attempted(outcome: &Outcome, context: Option<xcm::latest::ErrorContext>) {
+		if let Some(context) = context {
+			Self::deposit_event(Event::AttemptIncomplete { context });
+		}
+		Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
+	}
+
 	/// Validate `assets` to be reserve-transferred and return their reserve location.
 	fn validate_assets_and_find_reserve(
 		assets: &[MultiAsset],
@@ -1313,6 +1701,73 @@ impl<T: Config> Pallet<T> {
 		reserve.ok_or(Error::<T>::Empty)
 	}
 
+	/// The weight of [`Pallet::transfer_assets`] for the given `assets` and `dest`.
+	fn transfer_assets_weight(
+		assets: &VersionedMultiAssets,
+		dest: &VersionedMultiLocation,
+	) -> Weight {
+		let maybe_assets: Result<MultiAssets, ()> = assets.clone().try_into();
+		let maybe_dest: Result<MultiLocation, ()> = dest.clone().try_into();
+		match (maybe_assets, maybe_dest) {
+			(Ok(assets), Ok(dest)) => {
+				// heaviest version of locally executed XCM program: equivalent in weight to
+				// withdrawing and burning fees, transferring the rest of the assets to SA,
+				// reanchoring them, extending XCM program, and sending onward XCM
+				let mut message = Xcm(vec![
+					SetFeesMode { jit_withdraw: true },
+					WithdrawAsset(assets.clone()),
+					BurnAsset(assets.clone()),
+					TransferReserveAsset { assets, dest, xcm: Xcm(vec![]) },
+				]);
+				T::Weigher::weight(&mut message)
+					.map_or(Weight::MAX, |w| T::WeightInfo::transfer_assets().saturating_add(w))
+			},
+			_ => Weight::MAX,
+		}
+	}
+
+	/// Store `receipt` of a transfer initiated by `origin` under a new id, pruning the oldest
+	/// receipts of `origin` beyond `MaxTransferReceipts`. Returns the id of the receipt.
+	fn record_transfer_receipt(
+		origin: &MultiLocation,
+		receipt: TransferReceipt<BlockNumberFor<T>>,
+	) -> u64 {
+		let id = NextTransferReceiptId::<T>::mutate(|next| {
+			let id = *next;
+			next.saturating_inc();
+			id
+		});
+
+		let max = T::MaxTransferReceipts::get() as usize;
+		TransferReceipts::<T>::mutate(VersionedMultiLocation::from(*origin), |receipts| {
+			// Prune as many as needed to catch up with a lowered `MaxTransferReceipts`.
+			let excess = (receipts.len() + 1).saturating_sub(max);
+			for (pruned, _) in receipts.drain(..excess.min(receipts.len())) {
+				Self::deposit_event(Event::TransferReceiptPruned { id: pruned });
+			}
+			receipts.push((id, receipt));
+		});
+		id
+	}
+
+	/// The transfer receipt recorded under `id` for a transfer initiated by `origin`, unless it
+	/// was pruned.
+	pub fn transfer_receipt(
+		origin: &MultiLocation,
+		id: u64,
+	) -> Option<TransferReceipt<BlockNumberFor<T>>> {
+		TransferReceipts::<T>::get(VersionedMultiLocation::from(*origin))
+			.into_iter()
+			.find_map(|(receipt_id, receipt)| (receipt_id == id).then_some(receipt))
+	}
+
+	/// All transfer receipts still kept for transfers initiated by `origin`, oldest first.
+	pub fn transfer_receipts_of(
+		origin: &MultiLocation,
+	) -> Vec<(u64, TransferReceipt<BlockNumberFor<T>>)> {
+		TransferReceipts::<T>::get(VersionedMultiLocation::from(*origin))
+	}
+
 	fn do_reserve_transfer_assets(
 		origin: OriginFor<T>,
 		dest: Box<VersionedMultiLocation>,
@@ -1370,19 +1825,14 @@ impl<T: Config> Pallet<T> {
 				!matches!(assets_transfer_type, TransferType::RemoteReserve(_)),
 				Error::<T>::InvalidAssetUnsupportedReserve
 			);
-			let fees = fees.clone();
-			let weight_limit = weight_limit.clone();
 			// build fees transfer instructions to be added to assets transfers XCM programs
-			separate_fees_instructions = Some(match fees_transfer_type {
-				TransferType::LocalReserve =>
-					Self::local_reserve_fees_instructions(dest, fees, weight_limit)?,
-				TransferType::DestinationReserve =>
-					Self::destination_reserve_fees_instructions(dest, fees, weight_limit)?,
-				TransferType::Teleport =>
-					Self::teleport_fees_instructions(origin_location, dest, fees, weight_limit)?,
-				TransferType::RemoteReserve(_) =>
-					return Err(Error::<T>::InvalidAssetUnsupportedReserve.into()),
-			});
+			separate_fees_instructions = Some(Self::separate_fees_instructions(
+				origin_location,
+				dest,
+				fees.clone(),
+				fees_transfer_type,
+				weight_limit.clone(),
+			)?);
 		};
 
 		Self::build_and_execute_xcm_transfer_type(
@@ -1434,6 +1884,147 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	fn do_transfer_assets(
+		origin: OriginFor<T>,
+		dest: Box<VersionedMultiLocation>,
+		beneficiary: Box<VersionedMultiLocation>,
+		assets: Box<VersionedMultiAssets>,
+		fee_asset_item: u32,
+		weight_limit: WeightLimit,
+	) -> DispatchResult {
+		let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+		let dest = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+		let beneficiary: MultiLocation =
+			(*beneficiary).try_into().map_err(|()| Error::<T>::BadVersion)?;
+		let assets: MultiAssets = (*assets).try_into().map_err(|()| Error::<T>::BadVersion)?;
+		log::trace!(
+			target: "xcm::pallet_xcm::do_transfer_assets",
+			"origin {:?}, dest {:?}, beneficiary {:?}, assets {:?}, fee-idx {:?}, weight_limit {:?}",
+			origin_location, dest, beneficiary, assets, fee_asset_item, weight_limit,
+		);
+
+		ensure!(assets.len() <= MAX_ASSETS_FOR_TRANSFER, Error::<T>::TooManyAssets);
+		let mut assets = assets.into_inner();
+		let fee_asset_item = fee_asset_item as usize;
+		let fees = assets.get(fee_asset_item).ok_or(Error::<T>::Empty)?.clone();
+		let (fees_transfer_type, assets_transfer_type) =
+			Self::find_fee_and_assets_transfer_types(&assets, fee_asset_item, &dest)?;
+
+		// check teleported and reserve-transferred assets against their respective filters
+		let (teleported, reserve_transferred): (Vec<_>, Vec<_>) =
+			assets.iter().cloned().enumerate().partition(|(idx, _)| {
+				let transfer_type = if *idx == fee_asset_item {
+					&fees_transfer_type
+				} else {
+					&assets_transfer_type
+				};
+				*transfer_type == TransferType::Teleport
+			});
+		let teleported: Vec<_> = teleported.into_iter().map(|(_, asset)| asset).collect();
+		if !teleported.is_empty() {
+			let value = (origin_location, teleported);
+			ensure!(T::XcmTeleportFilter::contains(&value), Error::<T>::Filtered);
+		}
+		let reserve_transferred: Vec<_> =
+			reserve_transferred.into_iter().map(|(_, asset)| asset).collect();
+		if !reserve_transferred.is_empty() {
+			let value = (origin_location, reserve_transferred);
+			ensure!(T::XcmReserveTransferFilter::contains(&value), Error::<T>::Filtered);
+		}
+
+		// local and remote XCM programs to potentially handle fees separately
+		let separate_fees_instructions = if fees_transfer_type == assets_transfer_type {
+			// no need for custom fees instructions, fees are batched with assets
+			None
+		} else {
+			// Disallow _remote reserves_ unless assets & fees have same remote reserve (covered by
+			// branch above). The reason for this is that we'd need to send XCMs to separate chains
+			// with no guarantee of delivery order on final destination; therefore we cannot
+			// guarantee to have fees in place on final destination chain to pay for assets
+			// transfer.
+			ensure!(
+				!matches!(assets_transfer_type, TransferType::RemoteReserve(_)),
+				Error::<T>::InvalidAssetUnsupportedReserve
+			);
+			// remove `fees` from `assets` and build separate fees transfer instructions to be
+			// added to assets transfers XCM programs
+			let fees = assets.remove(fee_asset_item);
+			Some(Self::separate_fees_instructions(
+				origin_location,
+				dest,
+				fees,
+				fees_transfer_type,
+				weight_limit.clone(),
+			)?)
+		};
+
+		Self::build_and_execute_xcm_transfer_type(
+			origin_location,
+			dest,
+			beneficiary,
+			assets,
+			assets_transfer_type,
+			fees,
+			separate_fees_instructions,
+			weight_limit,
+		)
+	}
+
+	/// Determine the transfer types of the fee asset at `fee_asset_item` and of the rest of
+	/// `assets`, which must all share the same transfer type.
+	///
+	/// If `fees` is the only asset, its transfer type is returned for both.
+	fn find_fee_and_assets_transfer_types(
+		assets: &[MultiAsset],
+		fee_asset_item: usize,
+		dest: &MultiLocation,
+	) -> Result<(TransferType, TransferType), Error<T>> {
+		let mut fees_transfer_type = None;
+		let mut assets_transfer_type = None;
+		for (idx, asset) in assets.iter().enumerate() {
+			if let Fungible(x) = asset.fun {
+				// If fungible asset, ensure non-zero amount.
+				ensure!(!x.is_zero(), Error::<T>::Empty);
+			}
+			let transfer_type =
+				T::XcmExecutor::determine_for(&asset, dest).map_err(Error::<T>::from)?;
+			if idx == fee_asset_item {
+				fees_transfer_type = Some(transfer_type);
+			} else if let Some(existing) = assets_transfer_type.as_ref() {
+				// Ensure transfer for multiple assets uses same transfer type (only fee may have
+				// different transfer type)
+				ensure!(existing == &transfer_type, Error::<T>::TooManyReserves);
+			} else {
+				// asset transfer type identified
+				assets_transfer_type = Some(transfer_type);
+			}
+		}
+		let fees_transfer_type = fees_transfer_type.ok_or(Error::<T>::Empty)?;
+		// single asset also used for fees
+		let assets_transfer_type = assets_transfer_type.unwrap_or(fees_transfer_type);
+		Ok((fees_transfer_type, assets_transfer_type))
+	}
+
+	/// Build the local and remote instructions transferring `fees` to `dest` separately from the
+	/// rest of the assets, and buying execution on `dest` with them.
+	fn separate_fees_instructions(
+		origin: MultiLocation,
+		dest: MultiLocation,
+		fees: MultiAsset,
+		fees_transfer_type: TransferType,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		match fees_transfer_type {
+			TransferType::LocalReserve =>
+				Self::local_reserve_fees_instructions(dest, fees, weight_limit),
+			TransferType::DestinationReserve =>
+				Self::destination_reserve_fees_instructions(dest, fees, weight_limit),
+			TransferType::Teleport =>
+				Self::teleport_fees_instructions(origin, dest, fees, weight_limit),
+			TransferType::RemoteReserve(_) => Err(Error::<T>::InvalidAssetUnsupportedReserve),
+		}
+	}
+
 	fn build_and_execute_xcm_transfer_type(
 		origin: MultiLocation,
 		dest: MultiLocation,
@@ -1484,17 +2075,29 @@ impl<T: Config> Pallet<T> {
 				)?,
 				None,
 			),
-			TransferType::Teleport => (
-				Self::teleport_assets_program(dest, beneficiary, assets, fees, weight_limit)?,
-				None,
-			),
+			TransferType::Teleport => match separate_fees_instructions {
+				None => (
+					Self::teleport_assets_program(dest, beneficiary, assets, fees, weight_limit)?,
+					None,
+				),
+				Some(separate_fees_instructions) => {
+					let (local, remote) = Self::teleport_transfer_programs(
+						dest,
+						beneficiary,
+						assets,
+						separate_fees_instructions,
+					)?;
+					(local, Some(remote))
+				},
+			},
 		};
 		let weight =
 			T::Weigher::weight(&mut local_xcm).map_err(|()| Error::<T>::UnweighableMessage)?;
-		let hash = local_xcm.using_encoded(sp_io::hashing::blake2_256);
-		let outcome =
-			T::XcmExecutor::execute_xcm_in_credit(origin, local_xcm, hash, weight, weight);
-		Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
+		let mut hash = local_xcm.using_encoded(sp_io::hashing::blake2_256);
+		let (outcome, context) = T::XcmExecutor::prepare_and_execute_with_context(
+			origin, local_xcm, &mut hash, weight, weight,
+		);
+		Self::deposit_attempted(&outcome, context);
 		if let Some(remote_xcm) = remote_xcm {
 			outcome.ensure_complete().map_err(|_| Error::<T>::LocalExecutionIncomplete)?;
 
@@ -1785,6 +2388,71 @@ impl<T: Config> Pallet<T> {
 		]))
 	}
 
+	// function assumes fees are handled separately through `separate_fees_instructions`
+	fn teleport_transfer_programs(
+		dest: MultiLocation,
+		beneficiary: MultiLocation,
+		assets: Vec<MultiAsset>,
+		separate_fees_instructions: (Xcm<<T as Config>::RuntimeCall>, Xcm<()>),
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		// max assets is `assets` + separately handled fee
+		let max_assets = assets.len() as u32 + 1;
+		let assets: MultiAssets = assets.into();
+		let context = T::UniversalLocation::get();
+		let mut reanchored_assets = assets.clone();
+		reanchored_assets
+			.reanchor(&dest, context)
+			.map_err(|_| Error::<T>::CannotReanchor)?;
+
+		// XcmContext irrelevant in teleports checks
+		let dummy_context =
+			XcmContext { origin: None, message_id: Default::default(), topic: None };
+		// We should check that the assets can actually be teleported out (for this to
+		// be in error, there would need to be an accounting violation by ourselves,
+		// so it's unlikely, but we don't want to allow that kind of bug to leak into
+		// a trusted chain.
+		for asset in assets.inner() {
+			<T::XcmExecutor as XcmAssetTransfers>::AssetTransactor::can_check_out(
+				&dest,
+				asset,
+				&dummy_context,
+			)
+			.map_err(|_| Error::<T>::CannotCheckOutTeleport)?;
+		}
+		for asset in assets.inner() {
+			<T::XcmExecutor as XcmAssetTransfers>::AssetTransactor::check_out(
+				&dest,
+				asset,
+				&dummy_context,
+			);
+		}
+
+		let (fees_local_xcm, fees_remote_xcm) = separate_fees_instructions;
+		// start off with local fees specific instructions
+		let mut local_execute_xcm = fees_local_xcm.into_inner();
+		// continue with rest of assets
+		local_execute_xcm.extend_from_slice(&[
+			// withdraw assets to be teleported
+			WithdrawAsset(assets.clone()),
+			// burn assets on local chain
+			BurnAsset(assets),
+		]);
+
+		// on destination chain, start off with custom fee instructions
+		let mut xcm_on_dest = fees_remote_xcm.into_inner();
+		// continue with rest of assets
+		xcm_on_dest.extend_from_slice(&[
+			// (dest) chain receive teleported assets burned on origin chain
+			ReceiveTeleportedAsset(reanchored_assets),
+			// following instructions are not exec'ed on behalf of origin chain anymore
+			ClearOrigin,
+			// deposit all remaining assets in holding to `beneficiary` location
+			DepositAsset { assets: Wild(AllCounted(max_assets)), beneficiary },
+		]);
+
+		Ok((Xcm(local_execute_xcm), Xcm(xcm_on_dest)))
+	}
+
 	/// Halve `fees` fungible amount.
 	pub(crate) fn halve_fees(fees: MultiAsset) -> Result<(MultiAsset, MultiAsset), Error<T>> {
 		match fees.fun {
@@ -1799,6 +2467,24 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Store the fungible `locks` held on `who` and update the lock on its balance accordingly.
+	///
+	/// Locks held by different unlockers overlap, so the balance is locked by the largest of them,
+	/// just like balance freezes with different identifiers.
+	fn set_locks(
+		who: &T::AccountId,
+		locks: BoundedVec<(BalanceOf<T>, VersionedMultiLocation), T::MaxLockers>,
+	) {
+		let locked = locks.iter().map(|(amount, _)| *amount).max().unwrap_or_else(Zero::zero);
+		if locks.is_empty() {
+			LockedFungibles::<T>::remove(who);
+			T::Currency::remove_lock(*b"py/xcmlk", who);
+		} else {
+			LockedFungibles::<T>::insert(who, locks);
+			T::Currency::set_lock(*b"py/xcmlk", who, locked, WithdrawReasons::all());
+		}
+	}
+
 	/// Will always make progress, and will do its best not to use much more than `weight_cutoff`
 	/// in doing so.
 	pub(crate) fn check_xcm_version_change(
@@ -1830,6 +2516,15 @@ impl<T: Config> Pallet<T> {
 						return (weight_used, Some(stage))
 					}
 				}
+				for (old_key, value) in VersionOverrides::<T>::drain_prefix(v) {
+					if let Ok(new_key) = old_key.into_latest() {
+						VersionOverrides::<T>::insert(XCM_VERSION, new_key, value);
+					}
+					weight_used.saturating_accrue(sv_migrate_weight);
+					if weight_used.any_gte(weight_cutoff) {
+						return (weight_used, Some(stage))
+					}
+				}
 			}
 			stage = MigrateVersionNotifiers;
 		}
@@ -2025,6 +2720,72 @@ impl<T: Config> Pallet<T> {
 		AccountIdConversion::<T::AccountId>::into_account_truncating(&ID)
 	}
 
+	/// The weight to use as `require_weight_at_most` of a `Transact` dispatching `call` on this
+	/// chain.
+	///
+	/// This is the benchmarked weight of `call` increased by `margin` (rounded up), so that minor
+	/// weight changes between building the message and executing it do not make the `Transact`
+	/// fail.
+	pub fn transact_weight(call: &<T as Config>::RuntimeCall, margin: Percent) -> Weight {
+		let weight = call.get_dispatch_info().weight;
+		weight.saturating_add(Weight::from_parts(
+			margin.mul_ceil(weight.ref_time()),
+			margin.mul_ceil(weight.proof_size()),
+		))
+	}
+
+	/// Build a `Transact` instruction dispatching `call` on this chain with `origin_kind`.
+	///
+	/// Its `require_weight_at_most` is computed by [`Self::transact_weight`] with the given
+	/// `margin`.
+	pub fn transact_instruction<Call>(
+		call: &<T as Config>::RuntimeCall,
+		origin_kind: OriginKind,
+		margin: Percent,
+	) -> Instruction<Call> {
+		Transact {
+			origin_kind,
+			require_weight_at_most: Self::transact_weight(call, margin),
+			call: call.encode().into(),
+		}
+	}
+
+	/// The checked issuance of `asset`, if any of its teleports was checked on this chain by an
+	/// asset transactor using this pallet as its `RecordTeleportAccounting`.
+	pub fn checked_issuance(asset: VersionedAssetId) -> Option<CheckedIssuance> {
+		let asset = AssetId::try_from(asset).ok()?;
+		CheckedIssuances::<T>::get(VersionedAssetId::from(asset))
+	}
+
+	/// Build a `Transact` instruction registering the local `asset` with `metadata` in the asset
+	/// registry of `dest`.
+	///
+	/// `dest_pallet_index` is the index of this pallet in the runtime of `dest`, and
+	/// `require_weight_at_most` the weight of the registration there. The instruction dispatches
+	/// [`Pallet::register_foreign_asset`] with `OriginKind::Xcm`, so `dest` must convert the
+	/// origin of the message with `XcmPassthrough`. The caller is responsible for prepending the
+	/// instructions paying for execution on `dest`, e.g. `UnpaidExecution` between system chains.
+	pub fn register_foreign_asset_instruction<Call>(
+		dest: &MultiLocation,
+		dest_pallet_index: u8,
+		asset: MultiLocation,
+		metadata: ForeignAssetMetadata,
+		require_weight_at_most: Weight,
+	) -> Result<Instruction<Call>, Error<T>> {
+		let asset = asset
+			.reanchored(dest, T::UniversalLocation::get())
+			.map_err(|_| Error::<T>::CannotReanchor)?;
+		let call = Call::<T>::register_foreign_asset {
+			asset: Box::new(VersionedMultiLocation::from(asset)),
+			metadata,
+		};
+		Ok(Transact {
+			origin_kind: OriginKind::Xcm,
+			require_weight_at_most,
+			call: (dest_pallet_index, call).encode().into(),
+		})
+	}
+
 	/// Create a new expectation of a query response with the querier being here.
 	fn do_new_query(
 		responder: impl Into<MultiLocation>,
@@ -2089,6 +2850,60 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Consume `message` and return another which is equivalent to it except that it reports
+	/// back the status of the last `Transact` it executes and dispatches `notify` on this chain.
+	///
+	/// Follows the same rules as [`Self::report_outcome_notify`], except that `notify` is given a
+	/// `Response::DispatchResult`, which can be reassembled into a [`TransactStatus`].
+	///
+	/// If the outcome of `message` should be reported as well, the report of the outcome must be
+	/// added first. The status report is then added to the same appendix.
+	pub fn report_transact_status_notify(
+		message: &mut Xcm<()>,
+		responder: impl Into<MultiLocation>,
+		notify: impl Into<<T as Config>::RuntimeCall>,
+		timeout: BlockNumberFor<T>,
+	) -> Result<(), XcmError> {
+		let responder = responder.into();
+		let destination = T::UniversalLocation::get()
+			.invert_target(&responder)
+			.map_err(|()| XcmError::LocationNotInvertible)?;
+		let notify: <T as Config>::RuntimeCall = notify.into();
+		let max_weight = notify.get_dispatch_info().weight;
+		let query_id = Self::new_notify_query(responder, notify, timeout, Here);
+		let response_info = QueryResponseInfo { destination, query_id, max_weight };
+		Self::add_to_appendix(message, ReportTransactStatus(response_info));
+		Ok(())
+	}
+
+	/// Removes the transact status reported for the query `query_id` once it is ready and emits
+	/// [Event::ResponseTaken] event.
+	///
+	/// Returns `None`, leaving the query untouched, if the response is not ready yet or is not a
+	/// transact status.
+	pub fn take_transact_status(query_id: QueryId) -> Option<TransactStatus> {
+		match Queries::<T>::get(query_id) {
+			Some(QueryStatus::Ready { response, .. }) => match Response::try_from(response) {
+				Ok(Response::DispatchResult(status)) => {
+					Queries::<T>::remove(query_id);
+					Self::deposit_event(Event::ResponseTaken { query_id });
+					Some(status.into())
+				},
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// Add `instruction` to the appendix set at the start of `message`, or set a new appendix
+	/// with it if there is none.
+	fn add_to_appendix(message: &mut Xcm<()>, instruction: Instruction<()>) {
+		match message.0.first_mut() {
+			Some(SetAppendix(appendix)) => appendix.0.push(instruction),
+			_ => message.0.insert(0, SetAppendix(Xcm(vec![instruction]))),
+		}
+	}
+
 	/// Attempt to create a new query ID and register it as a query that is yet to respond, and
 	/// which will call a dispatchable when a response happens.
 	pub fn new_notify_query(
@@ -2158,13 +2973,7 @@ impl<T: Config> xcm_executor::traits::Enact for LockTicket<T> {
 					.map_err(|(_balance, _location)| UnexpectedState)?;
 			},
 		}
-		LockedFungibles::<T>::insert(&self.sovereign_account, locks);
-		T::Currency::extend_lock(
-			*b"py/xcmlk",
-			&self.sovereign_account,
-			self.amount,
-			WithdrawReasons::all(),
-		);
+		Pallet::<T>::set_locks(&self.sovereign_account, locks);
 		Ok(())
 	}
 }
@@ -2172,6 +2981,7 @@ impl<T: Config> xcm_executor::traits::Enact for LockTicket<T> {
 pub struct UnlockTicket<T: Config> {
 	sovereign_account: T::AccountId,
 	amount: BalanceOf<T>,
+	item_index: usize,
 	unlocker: MultiLocation,
 }
 
@@ -2180,28 +2990,16 @@ impl<T: Config> xcm_executor::traits::Enact for UnlockTicket<T> {
 		use xcm_executor::traits::LockError::UnexpectedState;
 		let mut locks =
 			LockedFungibles::<T>::get(&self.sovereign_account).ok_or(UnexpectedState)?;
-		let mut maybe_remove_index = None;
-		let mut locked = BalanceOf::<T>::zero();
-		let mut found = false;
-		// We could just as well do with with an into_iter, filter_map and collect, however this way
-		// avoids making an allocation.
-		for (i, x) in locks.iter_mut().enumerate() {
-			if x.1.try_as::<_>().defensive() == Ok(&self.unlocker) {
-				x.0 = x.0.saturating_sub(self.amount);
-				if x.0.is_zero() {
-					maybe_remove_index = Some(i);
-				}
-				found = true;
-			}
-			locked = locked.max(x.0);
-		}
-		ensure!(found, UnexpectedState);
-		if let Some(remove_index) = maybe_remove_index {
-			locks.swap_remove(remove_index);
+		let index = self.item_index;
+		ensure!(locks.len() > index, UnexpectedState);
+		ensure!(locks[index].1.try_as::<_>() == Ok(&self.unlocker), UnexpectedState);
+		// only the lock held by `unlocker` is reduced, locks of other unlockers are unaffected
+		ensure!(locks[index].0 >= self.amount, UnexpectedState);
+		locks[index].0 = locks[index].0.saturating_sub(self.amount);
+		if locks[index].0.is_zero() {
+			locks.swap_remove(index);
 		}
-		LockedFungibles::<T>::insert(&self.sovereign_account, locks);
-		let reasons = WithdrawReasons::all();
-		T::Currency::set_lock(*b"py/xcmlk", &self.sovereign_account, locked, reasons);
+		Pallet::<T>::set_locks(&self.sovereign_account, locks);
 		Ok(())
 	}
 }
@@ -2262,8 +3060,8 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		let locks = LockedFungibles::<T>::get(&sovereign_account).unwrap_or_default();
 		let item_index =
 			locks.iter().position(|x| x.1.try_as::<_>() == Ok(&unlocker)).ok_or(NotLocked)?;
-		ensure!(locks[item_index].0 >= amount, NotLocked);
-		Ok(UnlockTicket { sovereign_account, amount, unlocker })
+		ensure!(locks[item_index].0 >= amount, NotEnoughLocked);
+		Ok(UnlockTicket { sovereign_account, amount, item_index, unlocker })
 	}
 
 	fn note_unlockable(
@@ -2329,7 +3127,8 @@ impl<T: Config> WrapVersion for Pallet<T> {
 		dest: &MultiLocation,
 		xcm: impl Into<VersionedXcm<RuntimeCall>>,
 	) -> Result<VersionedXcm<RuntimeCall>, ()> {
-		SupportedVersion::<T>::get(XCM_VERSION, LatestVersionedMultiLocation(dest))
+		VersionOverrides::<T>::get(XCM_VERSION, LatestVersionedMultiLocation(dest))
+			.or_else(|| SupportedVersion::<T>::get(XCM_VERSION, LatestVersionedMultiLocation(dest)))
 			.or_else(|| {
 				Self::note_unknown_version(dest);
 				SafeXcmVersion::<T>::get()
@@ -2625,6 +3424,89 @@ impl<T: Config> CheckSuspension for Pallet<T> {
 	}
 }
 
+impl<T: Config> RecordXcm for Pallet<T> {
+	fn should_record() -> bool {
+		ShouldRecordXcm::<T>::get()
+	}
+
+	fn set_record_xcm(enabled: bool) {
+		ShouldRecordXcm::<T>::put(enabled);
+	}
+
+	fn recorded_xcm() -> Option<Xcm<()>> {
+		RecordedXcm::<T>::get()
+	}
+
+	fn record(xcm: Xcm<()>) {
+		RecordedXcm::<T>::put(xcm);
+	}
+}
+
+impl<T: Config> RecordTeleportAccounting for Pallet<T> {
+	fn note_accrued(
+		id: &AssetId,
+		mint_location: MintLocation,
+		balance: impl FnOnce() -> u128,
+		amount: u128,
+	) {
+		CheckedIssuances::<T>::mutate(VersionedAssetId::from(*id), |issuance| {
+			let checked = issuance.as_ref().map_or_else(balance, |issuance| issuance.amount);
+			*issuance =
+				Some(CheckedIssuance { mint_location, amount: checked.saturating_add(amount) });
+		});
+	}
+
+	fn note_reduced(
+		id: &AssetId,
+		mint_location: MintLocation,
+		balance: impl FnOnce() -> u128,
+		amount: u128,
+	) {
+		CheckedIssuances::<T>::mutate(VersionedAssetId::from(*id), |issuance| {
+			let checked = issuance.as_ref().map_or_else(balance, |issuance| issuance.amount);
+			*issuance =
+				Some(CheckedIssuance { mint_location, amount: checked.saturating_sub(amount) });
+		});
+	}
+
+	fn checked_issuances() -> Vec<(AssetId, CheckedIssuance)> {
+		CheckedIssuances::<T>::iter()
+			.filter_map(|(id, issuance)| Some((AssetId::try_from(id).ok()?, issuance)))
+			.collect()
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for building `Transact` instructions which dispatch calls on this chain.
+	pub trait XcmTransactApi<Call> where Call: Codec {
+		/// The weight to use as `require_weight_at_most` of a `Transact` dispatching `call` on
+		/// this chain, including the margin configured by the runtime.
+		///
+		/// See [`Pallet::transact_weight`].
+		fn transact_weight(call: Call) -> Weight;
+	}
+
+	/// API for inspecting the accounting of the teleports checked by the asset transactors.
+	pub trait XcmTeleportAccountingApi {
+		/// The checked issuance of `asset`, if any of its teleports was checked on this chain.
+		///
+		/// See [`Pallet::checked_issuance`].
+		fn checked_issuance(asset: VersionedAssetId) -> Option<CheckedIssuance>;
+	}
+
+	/// API for querying the receipts of transfers made through
+	/// [`Pallet::transfer_assets_with_receipt`].
+	pub trait XcmTransferReceiptsApi<BlockNumber> where BlockNumber: Codec {
+		/// All transfer receipts still kept for transfers initiated by `origin`, with their ids,
+		/// oldest first. Empty if `origin` can not be converted to the latest version.
+		///
+		/// See [`Pallet::transfer_receipts_of`].
+		fn transfer_receipts_of(
+			origin: VersionedMultiLocation,
+		) -> Vec<(u64, TransferReceipt<BlockNumber>)>;
+	}
+}
+
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
 ///
 /// Returns `Ok` with the location of the XCM sender or an `Err` otherwise.
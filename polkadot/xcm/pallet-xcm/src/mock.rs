@@ -18,8 +18,8 @@ use codec::Encode;
 use frame_support::{
 	construct_runtime, match_types, parameter_types,
 	traits::{
-		AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Equals, Everything, EverythingBut,
-		Nothing,
+		AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, ContainsPair, Equals, Everything,
+		EverythingBut, Get, Nothing, UnixTime,
 	},
 	weights::Weight,
 };
@@ -27,25 +27,27 @@ use frame_system::EnsureRoot;
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use polkadot_runtime_parachains::origin;
 use sp_core::H256;
-use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
-pub use sp_std::{
-	cell::RefCell, collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData,
+use sp_runtime::{
+	traits::IdentityLookup, AccountId32, BuildStorage, DispatchError, DispatchResult,
 };
+pub use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData};
 use xcm::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, Case, ChildParachainAsNative, ChildParachainConvertsVia,
 	ChildSystemParachainAsSuperuser, CurrencyAdapter as XcmCurrencyAdapter, DescribeAllTerminal,
-	FixedRateOfFungible, FixedWeightBounds, FungiblesAdapter, HashedDescription, IsConcrete,
-	MatchedConvertedConcreteId, NoChecking, SignedAccountId32AsNative, SignedToAccountId32,
-	SovereignSignedViaLocation, TakeWeightCredit, XcmFeeManagerFromComponents, XcmFeeToAccount,
+	FixedRateOfFungible, FixedWeightBounds, ForeignAssetMetadata, FungiblesAdapter,
+	HashedDescription, IsConcrete, MatchedConvertedConcreteId, NoChecking, RegisterForeignAsset,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+	XcmFeeManagerFromComponents, XcmFeeToAccount,
 };
 use xcm_executor::{
-	traits::{Identity, JustTry},
+	traits::{ConvertLocation, Identity, JustTry},
 	XcmExecutor,
 };
 
 use crate::{self as pallet_xcm, TestWeightInfo};
+use frame_support::derive_impl;
 
 pub type AccountId = AccountId32;
 pub type Balance = u128;
@@ -61,6 +63,7 @@ pub mod pallet_test_notifier {
 	use xcm_executor::traits::QueryHandler;
 
 	#[pallet::pallet]
+	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -79,6 +82,10 @@ pub mod pallet_test_notifier {
 		ResponseReceived(MultiLocation, QueryId, Response),
 	}
 
+	/// Messages delivered by the test routers, in order.
+	#[pallet::storage]
+	pub type SentXcm<T> = StorageValue<_, Vec<(MultiLocation, Xcm<()>)>, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		UnexpectedId,
@@ -147,22 +154,15 @@ construct_runtime!(
 		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
 		ParasOrigin: origin::{Pallet, Origin},
 		XcmPallet: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>},
-		TestNotifier: pallet_test_notifier::{Pallet, Call, Event<T>},
+		TestNotifier: pallet_test_notifier::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
-thread_local! {
-	pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
-}
 pub(crate) fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
-	SENT_XCM.with(|q| (*q.borrow()).clone())
+	pallet_test_notifier::SentXcm::<Test>::get()
 }
 pub(crate) fn take_sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
-	SENT_XCM.with(|q| {
-		let mut r = Vec::new();
-		std::mem::swap(&mut r, &mut *q.borrow_mut());
-		r
-	})
+	pallet_test_notifier::SentXcm::<Test>::take()
 }
 /// Sender that never returns error.
 pub struct TestSendXcm;
@@ -177,7 +177,7 @@ impl SendXcm for TestSendXcm {
 	}
 	fn deliver(pair: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
 		let hash = fake_message_hash(&pair.1);
-		SENT_XCM.with(|q| q.borrow_mut().push(pair));
+		pallet_test_notifier::SentXcm::<Test>::append(pair);
 		Ok(hash)
 	}
 }
@@ -198,7 +198,7 @@ impl SendXcm for TestSendXcmErrX8 {
 	}
 	fn deliver(pair: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
 		let hash = fake_message_hash(&pair.1);
-		SENT_XCM.with(|q| q.borrow_mut().push(pair));
+		pallet_test_notifier::SentXcm::<Test>::append(pair);
 		Ok(hash)
 	}
 }
@@ -230,7 +230,7 @@ impl SendXcm for TestPaidForPara3000SendXcm {
 	}
 	fn deliver(pair: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
 		let hash = fake_message_hash(&pair.1);
-		SENT_XCM.with(|q| q.borrow_mut().push(pair));
+		pallet_test_notifier::SentXcm::<Test>::append(pair);
 		Ok(hash)
 	}
 }
@@ -239,6 +239,7 @@ parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
@@ -317,6 +318,7 @@ impl pallet_assets::Config for Test {
 	type CallbackHandle = ();
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<5>;
+	type MaxRoleAccounts = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = XcmBenchmarkHelper;
 }
@@ -459,6 +461,9 @@ match_types! {
 	pub type XcmFeesNotWaivedLocations: impl Contains<MultiLocation> = {
 		MultiLocation { parents: 0, interior: X1(Junction::AccountId32 {network: None, id: XCM_FEES_NOT_WAIVED_USER_ACCOUNT})}
 	};
+	pub type ChildParachains: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 0, interior: X1(Parachain(_)) }
+	};
 }
 
 pub type Barrier = (
@@ -490,7 +495,7 @@ impl xcm_executor::Config for XcmConfig {
 	type Trader = FixedRateOfFungible<CurrencyPerSecondPerByte, ()>;
 	type ResponseHandler = XcmPallet;
 	type AssetTrap = XcmPallet;
-	type AssetLocker = ();
+	type AssetLocker = XcmPallet;
 	type AssetExchanger = ();
 	type AssetClaims = XcmPallet;
 	type SubscriptionService = XcmPallet;
@@ -504,13 +509,76 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = XcmPallet;
 	type Aliasers = Nothing;
 }
 
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
 
+pub struct TrustedLockerCase<T>(PhantomData<T>);
+impl<T: Get<(MultiLocation, MultiAssetFilter)>> ContainsPair<MultiLocation, MultiAsset>
+	for TrustedLockerCase<T>
+{
+	fn contains(origin: &MultiLocation, asset: &MultiAsset) -> bool {
+		let (o, a) = T::get();
+		a.matches(asset) && &o == origin
+	}
+}
+
+parameter_types! {
+	pub TrustedLockerLocation: MultiLocation = Parachain(OTHER_PARA_ID).into();
+	pub NativeTokenForTrustedLocker: (MultiLocation, MultiAssetFilter) = (
+		TrustedLockerLocation::get(),
+		Wild(AllOf { id: Concrete(RelayLocation::get()), fun: WildFungible }),
+	);
+}
+
 parameter_types! {
 	pub static AdvertisedXcmVersion: pallet_xcm::XcmVersion = 3;
+	pub static MaxTransferReceipts: u32 = 2;
+	pub static TimestampMillis: u64 = 0;
+	pub const ForeignAssetRegistrationWeight: Weight = Weight::from_parts(1_000, 1_000);
+}
+
+pub struct MockUnixTime;
+impl UnixTime for MockUnixTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(TimestampMillis::get())
+	}
+}
+
+/// Registers foreign assets in `Assets` through its `create` call, taking the deposits from the
+/// sovereign account of the owner.
+pub struct AssetsRegistrar;
+impl RegisterForeignAsset for AssetsRegistrar {
+	fn register(
+		owner: &MultiLocation,
+		asset: &MultiLocation,
+		metadata: ForeignAssetMetadata,
+	) -> DispatchResult {
+		use frame_support::traits::fungibles::Inspect;
+
+		let admin = SovereignAccountOf::convert_location(owner).ok_or(DispatchError::BadOrigin)?;
+		if !Assets::asset_exists(*asset) {
+			Assets::create(
+				RuntimeOrigin::signed(admin.clone()),
+				*asset,
+				admin.clone(),
+				metadata.min_balance,
+			)?;
+		}
+		Assets::set_metadata(
+			RuntimeOrigin::signed(admin),
+			*asset,
+			metadata.name,
+			metadata.symbol,
+			metadata.decimals,
+		)
+	}
+
+	fn register_weight() -> Weight {
+		ForeignAssetRegistrationWeight::get()
+	}
 }
 
 pub struct XcmTeleportFiltered;
@@ -537,13 +605,18 @@ impl pallet_xcm::Config for Test {
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 	type AdvertisedXcmVersion = AdvertisedXcmVersion;
 	type AdminOrigin = EnsureRoot<AccountId>;
-	type TrustedLockers = ();
+	type TrustedLockers = TrustedLockerCase<NativeTokenForTrustedLocker>;
 	type SovereignAccountOf = AccountId32Aliases<(), AccountId32>;
 	type Currency = Balances;
 	type CurrencyMatcher = IsConcrete<RelayLocation>;
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type ForeignAssetRegistrationOrigin = pallet_xcm::EnsureXcm<ChildParachains>;
+	type ForeignAssetRegistrar = AssetsRegistrar;
+	type TeleportAccounting = ();
+	type MaxTransferReceipts = MaxTransferReceipts;
+	type UnixTime = MockUnixTime;
 	type WeightInfo = TestWeightInfo;
 }
 
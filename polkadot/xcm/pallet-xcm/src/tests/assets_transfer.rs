@@ -28,7 +28,7 @@ use frame_support::{
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::{traits::AccountIdConversion, DispatchError, ModuleError};
 use xcm::prelude::*;
-use xcm_executor::traits::ConvertLocation;
+use xcm_executor::traits::{ConvertLocation, TransferType};
 
 // Helper function to deduplicate testing different teleport types.
 fn do_test_and_verify_teleport_assets<Call: FnOnce()>(
@@ -1457,3 +1457,298 @@ fn reserve_transfer_assets_with_filtered_teleported_fee_disallowed() {
 		);
 	});
 }
+
+/// Test `transfer_assets` with teleportable asset and local fee reserve.
+///
+/// Transferring USDT (teleport-trusted) to USDT chain. Using native asset (local reserve) for
+/// fees.
+///
+/// ```nocompile
+///    Here (source)                               USDT_PARA_ID (destination)
+///    |  `fees` reserve                           `assets` (USDT) teleport-trust
+///    |
+///    |  1. execute `TransferAsset(fees)`, `WithdrawAsset(assets)`, `BurnAsset(assets)`
+///    |     \--> sends `ReserveAssetDeposited(fees), BuyExecution(fees),
+///    |                 ReceiveTeleportedAsset(assets), ClearOrigin, DepositAsset`
+///    \------------------------------------------>
+/// ```
+#[test]
+fn transfer_assets_with_teleportable_asset_and_local_fee_reserve_works() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	let beneficiary: MultiLocation =
+		Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+	new_test_ext_with_balances(balances).execute_with(|| {
+		// create sufficient foreign asset USDT (0 total issuance)
+		let usdt_initial_local_amount = 42;
+		let (usdt_chain, usdt_chain_sovereign_account, usdt_id_multilocation) =
+			set_up_foreign_asset(USDT_PARA_ID, None, usdt_initial_local_amount, true);
+
+		// transfer destination is USDT chain (teleport trust only for USDT)
+		let dest = usdt_chain;
+
+		let (assets, fee_index, fee_asset, xfer_asset) = into_multiassets_checked(
+			// native asset for fees - local reserve
+			(MultiLocation::here(), FEE_AMOUNT).into(),
+			// USDT to transfer (not used for fees) - teleported
+			(usdt_id_multilocation, SEND_AMOUNT).into(),
+		);
+
+		// reanchor according to test-case
+		let context = UniversalLocation::get();
+		let expected_fee = fee_asset.reanchored(&dest, context).unwrap();
+		let expected_asset = xfer_asset.reanchored(&dest, context).unwrap();
+
+		// balances checks before
+		assert_eq!(Assets::balance(usdt_id_multilocation, ALICE), usdt_initial_local_amount);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+
+		// do the transfer
+		assert_ok!(XcmPallet::transfer_assets(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(dest.into()),
+			Box::new(beneficiary.into()),
+			Box::new(assets.into()),
+			fee_index as u32,
+			Unlimited,
+		));
+		let weight = BaseXcmWeight::get() * 3;
+		let mut last_events = last_events(3).into_iter();
+		assert_eq!(
+			last_events.next().unwrap(),
+			RuntimeEvent::XcmPallet(crate::Event::Attempted { outcome: Outcome::Complete(weight) })
+		);
+		assert_eq!(
+			last_events.next().unwrap(),
+			RuntimeEvent::XcmPallet(crate::Event::FeesPaid {
+				paying: beneficiary,
+				fees: MultiAssets::new(),
+			})
+		);
+		assert!(matches!(
+			last_events.next().unwrap(),
+			RuntimeEvent::XcmPallet(crate::Event::Sent { .. })
+		));
+		// Alice used native asset for fees
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - FEE_AMOUNT);
+		// Sovereign account of dest parachain holds `FEE_AMOUNT` native asset in local reserve
+		assert_eq!(Balances::free_balance(usdt_chain_sovereign_account.clone()), FEE_AMOUNT);
+		// Alice spent USDT for transfer
+		assert_eq!(
+			Assets::balance(usdt_id_multilocation, ALICE),
+			usdt_initial_local_amount - SEND_AMOUNT
+		);
+		assert_eq!(Assets::balance(usdt_id_multilocation, usdt_chain_sovereign_account), 0);
+		// Verify total and active issuance have decreased (teleported)
+		let expected_usdt_issuance = usdt_initial_local_amount - SEND_AMOUNT;
+		assert_eq!(Assets::total_issuance(usdt_id_multilocation), expected_usdt_issuance);
+		assert_eq!(Assets::active_issuance(usdt_id_multilocation), expected_usdt_issuance);
+
+		// Verify sent XCM program
+		assert_eq!(
+			sent_xcm(),
+			vec![(
+				dest,
+				Xcm(vec![
+					// fees are reserve-transferred to destination chain
+					ReserveAssetDeposited(expected_fee.clone().into()),
+					buy_limited_execution(expected_fee, Unlimited),
+					// `assets` are teleported
+					ReceiveTeleportedAsset(expected_asset.into()),
+					ClearOrigin,
+					DepositAsset { assets: AllCounted(2).into(), beneficiary },
+				])
+			)]
+		);
+	});
+}
+
+/// Test `transfer_assets` with teleportable asset that is filtered - should fail.
+#[test]
+fn transfer_assets_with_filtered_teleported_asset_disallowed() {
+	let beneficiary: MultiLocation = AccountId32 { network: None, id: BOB.into() }.into();
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let (assets, fee_index, _, _) = into_multiassets_checked(
+			// native asset for fees - local reserve
+			(MultiLocation::here(), FEE_AMOUNT).into(),
+			// FilteredTeleportAsset to transfer - teleportable but filtered
+			FilteredTeleportAsset::get().into(),
+		);
+		let result = XcmPallet::transfer_assets(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(FilteredTeleportLocation::get().into()),
+			Box::new(beneficiary.into()),
+			Box::new(assets.into()),
+			fee_index as u32,
+			Unlimited,
+		);
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 4,
+				error: [2, 0, 0, 0],
+				message: Some("Filtered")
+			}))
+		);
+		// Alice native asset is still same
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+	});
+}
+
+/// Test that a fee asset sharing its id with a transferred asset, but having a different transfer
+/// type, is moved with its own transfer type instead of being batched with the transferred assets.
+#[test]
+fn transfer_assets_with_fee_matching_asset_id_and_different_transfer_type_works() {
+	let beneficiary: MultiLocation = AccountId32 { network: None, id: BOB.into() }.into();
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let origin: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		// system parachain is trusted to teleport native fungibles only
+		let dest = SystemParachainLocation::get();
+		let (assets, fee_index, fee_asset, xfer_asset) = into_multiassets_checked(
+			// native asset for fees - teleported
+			(MultiLocation::here(), FEE_AMOUNT).into(),
+			// native non-fungible with the same id to transfer - local reserve
+			(MultiLocation::here(), Index(1)).into(),
+		);
+		assert_eq!(fee_asset.id, xfer_asset.id);
+		let assets = assets.into_inner();
+
+		// fees and asset have the same id, but different transfer types
+		let (fees_transfer_type, assets_transfer_type) =
+			XcmPallet::find_fee_and_assets_transfer_types(&assets, fee_index, &dest).unwrap();
+		assert_eq!(fees_transfer_type, TransferType::Teleport);
+		assert_eq!(assets_transfer_type, TransferType::LocalReserve);
+
+		// fees are handled separately, using their own transfer type
+		let separate_fees_instructions = XcmPallet::separate_fees_instructions(
+			origin,
+			dest,
+			fee_asset.clone(),
+			fees_transfer_type,
+			Unlimited,
+		)
+		.unwrap();
+		let (local_xcm, remote_xcm) = XcmPallet::local_reserve_transfer_programs(
+			dest,
+			beneficiary,
+			vec![xfer_asset.clone()],
+			fee_asset.clone(),
+			Some(separate_fees_instructions),
+			Unlimited,
+		)
+		.unwrap();
+
+		let context = UniversalLocation::get();
+		let expected_fee = fee_asset.clone().reanchored(&dest, context).unwrap();
+		let expected_asset = xfer_asset.clone().reanchored(&dest, context).unwrap();
+		assert_eq!(
+			local_xcm,
+			Xcm(vec![
+				// fees are teleported
+				WithdrawAsset(fee_asset.clone().into()),
+				BurnAsset(fee_asset.into()),
+				// `assets` are reserve-transferred
+				TransferAsset { assets: xfer_asset.into(), beneficiary: dest },
+			])
+		);
+		assert_eq!(
+			remote_xcm,
+			Xcm(vec![
+				ReceiveTeleportedAsset(expected_fee.clone().into()),
+				buy_limited_execution(expected_fee, Unlimited),
+				ReserveAssetDeposited(expected_asset.into()),
+				ClearOrigin,
+				// both the fee leftover and the asset are deposited
+				DepositAsset { assets: AllCounted(2).into(), beneficiary },
+			])
+		);
+	});
+}
+
+/// Test `transfer_assets_with_receipt` records receipts of transfers per origin, prunes only the
+/// oldest ones of the same origin past `MaxTransferReceipts` and is disabled when no receipts may
+/// be kept.
+#[test]
+fn transfer_assets_with_receipt_records_and_prunes_receipts() {
+	let balances = vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)];
+	let beneficiary: MultiLocation = AccountId32 { network: None, id: BOB.into() }.into();
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let alice: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let bob: MultiLocation = AccountId32 { network: None, id: BOB.into() }.into();
+		let dest: VersionedMultiLocation = RelayLocation::get().into();
+		let assets: VersionedMultiAssets = (Here, SEND_AMOUNT).into();
+		let transfer = |who, topic| {
+			XcmPallet::transfer_assets_with_receipt(
+				RuntimeOrigin::signed(who),
+				Box::new(dest.clone()),
+				Box::new(beneficiary.into()),
+				Box::new(assets.clone()),
+				0,
+				Unlimited,
+				topic,
+			)
+		};
+
+		// when
+		crate::mock::TimestampMillis::set(1_000);
+		assert_ok!(transfer(ALICE, [1; 32]));
+
+		// then the transfer happened and its receipt is recorded
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+		assert_eq!(sent_xcm().len(), 1);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::TransferReceiptRecorded {
+				id: 0,
+				origin: alice,
+				topic: [1; 32],
+			})
+		);
+		assert_eq!(
+			XcmPallet::transfer_receipt(&alice, 0),
+			Some(crate::TransferReceipt {
+				origin: alice.into(),
+				assets: assets.clone(),
+				destination: dest.clone(),
+				beneficiary: beneficiary.into(),
+				topic: [1; 32],
+				recorded_at: 1,
+				requested_at: 1_000,
+			})
+		);
+		assert_eq!(XcmPallet::transfer_receipt(&bob, 0), None);
+
+		// when another origin records more receipts than are kept per origin
+		System::set_block_number(2);
+		crate::mock::TimestampMillis::set(2_000);
+		assert_ok!(transfer(BOB, [2; 32]));
+		assert_ok!(transfer(BOB, [3; 32]));
+		assert_ok!(transfer(BOB, [4; 32]));
+
+		// then only its own oldest receipt is pruned
+		assert!(System::events()
+			.iter()
+			.any(|r| r.event ==
+				RuntimeEvent::XcmPallet(crate::Event::TransferReceiptPruned { id: 1 })));
+		assert_eq!(XcmPallet::transfer_receipt(&bob, 1), None);
+		let bob_receipts = XcmPallet::transfer_receipts_of(&bob);
+		assert_eq!(bob_receipts.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![2, 3]);
+		assert_eq!(bob_receipts[1].1.topic, [4; 32]);
+		assert_eq!(bob_receipts[1].1.recorded_at, 2);
+		assert_eq!(bob_receipts[1].1.requested_at, 2_000);
+		assert!(XcmPallet::transfer_receipt(&alice, 0).is_some());
+
+		// when the limit is lowered, later records catch up with it
+		MaxTransferReceipts::set(1);
+		assert_ok!(transfer(BOB, [5; 32]));
+		assert_eq!(XcmPallet::transfer_receipts_of(&bob).len(), 1);
+		assert!(XcmPallet::transfer_receipt(&bob, 4).is_some());
+		assert!(XcmPallet::transfer_receipt(&alice, 0).is_some());
+
+		// when receipts are disabled, the transfer is rejected altogether
+		MaxTransferReceipts::set(0);
+		frame_support::assert_noop!(
+			transfer(ALICE, [6; 32]),
+			crate::Error::<Test>::TransferReceiptsDisabled
+		);
+	});
+}
@@ -20,20 +20,24 @@ mod assets_transfer;
 
 use crate::{
 	mock::*, AssetTraps, CurrentMigration, Error, LatestVersionedMultiLocation, Queries,
-	QueryStatus, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
-	VersionNotifyTargets,
+	QueryStatus, TransactStatus, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
+	VersionNotifyTargets, MAX_SUBSCRIPTIONS_CHECKED,
 };
+use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{Currency, Hooks},
 	weights::Weight,
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
-use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256, Hash},
+	DispatchError,
+};
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::AllowKnownQueryResponses;
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{Properties, QueryHandler, QueryResponseStatus, RecordXcm, ShouldExecute},
 	XcmExecutor,
 };
 
@@ -178,6 +182,81 @@ fn report_outcome_works() {
 	});
 }
 
+#[test]
+fn report_transact_status_works() {
+	let sender: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+	let mut message =
+		Xcm(vec![TransferAsset { assets: (Here, SEND_AMOUNT).into(), beneficiary: sender }]);
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let responder = Parachain(OTHER_PARA_ID).into_location();
+		let outcome_query = XcmPallet::report_outcome(&mut message, responder, 100).unwrap();
+		let status_query = XcmPallet::report_transact_status(&mut message, responder, 100).unwrap();
+		// Both reports are added to the same appendix.
+		assert_eq!(
+			message,
+			Xcm(vec![
+				SetAppendix(Xcm(vec![
+					ReportError(QueryResponseInfo {
+						destination: Parent.into(),
+						query_id: outcome_query,
+						max_weight: Weight::zero(),
+					}),
+					ReportTransactStatus(QueryResponseInfo {
+						destination: Parent.into(),
+						query_id: status_query,
+						max_weight: Weight::zero(),
+					}),
+				])),
+				TransferAsset { assets: (Here, SEND_AMOUNT).into(), beneficiary: sender },
+			])
+		);
+
+		let querier: MultiLocation = Here.into();
+		let status = MaybeErrorCode::from(DispatchError::BadOrigin.encode());
+		let message = Xcm(vec![QueryResponse {
+			query_id: status_query,
+			response: Response::DispatchResult(status.clone()),
+			max_weight: Weight::zero(),
+			querier: Some(querier),
+		}]);
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<XcmConfig>::execute_xcm(
+			Parachain(OTHER_PARA_ID),
+			message,
+			hash,
+			Weight::from_parts(1_000_000_000, 1_000_000_000),
+		);
+		assert_eq!(r, Outcome::Complete(Weight::from_parts(1_000, 1_000)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::ResponseReady {
+				query_id: status_query,
+				response: Response::DispatchResult(status),
+			})
+		);
+
+		// The outcome is still pending, so there is no transact status to take.
+		assert_eq!(XcmPallet::take_transact_status(outcome_query), None);
+		assert_eq!(
+			XcmPallet::take_transact_status(status_query),
+			Some(TransactStatus::Error(DispatchError::BadOrigin))
+		);
+		assert_eq!(XcmPallet::take_response(status_query), QueryResponseStatus::NotFound);
+	});
+}
+
+#[test]
+fn transact_status_is_reassembled() {
+	assert_eq!(TransactStatus::from(MaybeErrorCode::Success), TransactStatus::Success);
+	assert_eq!(
+		TransactStatus::from(MaybeErrorCode::from(DispatchError::Other("").encode())),
+		TransactStatus::Error(DispatchError::Other("")),
+	);
+	let truncated = MaybeErrorCode::from(vec![0u8; 200]);
+	assert!(matches!(truncated, MaybeErrorCode::TruncatedError(_)));
+	assert!(matches!(TransactStatus::from(truncated), TransactStatus::UnknownError(_)));
+}
+
 #[test]
 fn custom_querier_works() {
 	let balances = vec![
@@ -383,6 +462,82 @@ fn execute_withdraw_to_deposit_works() {
 	});
 }
 
+/// Test that the locally executed XCM is recorded only while recording is enabled.
+#[test]
+fn execute_records_xcm_when_enabled() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let weight = BaseXcmWeight::get() * 3;
+		let dest: MultiLocation = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+		let message: Xcm<RuntimeCall> = Xcm(vec![
+			WithdrawAsset((Here, SEND_AMOUNT).into()),
+			buy_execution((Here, SEND_AMOUNT)),
+			DepositAsset { assets: AllCounted(1).into(), beneficiary: dest },
+		]);
+		let execute = |message: Xcm<RuntimeCall>| {
+			XcmPallet::execute(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedXcm::from(message)),
+				weight,
+			)
+		};
+
+		// Recording is disabled by default.
+		assert_ok!(execute(message.clone()));
+		assert_eq!(XcmPallet::recorded_xcm(), None);
+
+		XcmPallet::set_record_xcm(true);
+		assert_ok!(execute(message.clone()));
+		assert_eq!(XcmPallet::recorded_xcm(), Some(message.into::<()>()));
+	});
+}
+
+/// A failing error handler is rolled back, both in storage and in the holding register.
+#[test]
+fn failing_error_handler_is_rolled_back() {
+	let balances = vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let weight = BaseXcmWeight::get() * 6;
+		let dest: MultiLocation = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+
+		assert_ok!(XcmPallet::execute(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(VersionedXcm::from(Xcm(vec![
+				WithdrawAsset((Here, SEND_AMOUNT).into()),
+				buy_execution((Here, SEND_AMOUNT)),
+				// Deposits the assets, but fails afterwards.
+				SetErrorHandler(Xcm(vec![
+					DepositAsset { assets: AllCounted(1).into(), beneficiary: dest },
+					Trap(1),
+				])),
+				Trap(0),
+			]))),
+			weight
+		));
+		// The deposit was undone and the assets are back in holding, from where they are trapped.
+		let source: MultiLocation =
+			Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let vma = VersionedMultiAssets::from(MultiAssets::from((Here, SEND_AMOUNT)));
+		let hash = BlakeTwo256::hash_of(&(source, vma.clone()));
+		assert_eq!(
+			last_events(2),
+			vec![
+				RuntimeEvent::XcmPallet(crate::Event::AssetsTrapped {
+					hash,
+					origin: source,
+					assets: vma
+				}),
+				RuntimeEvent::XcmPallet(crate::Event::Attempted {
+					outcome: Outcome::Incomplete(weight, XcmError::Trap(0))
+				}),
+			]
+		);
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+		assert_eq!(Balances::total_balance(&BOB), INITIAL_BALANCE);
+		assert_eq!(AssetTraps::<Test>::iter().collect::<Vec<_>>(), vec![(hash, 1u32)]);
+	});
+}
+
 /// Test drop/claim assets.
 #[test]
 fn trapped_assets_can_be_claimed() {
@@ -455,7 +610,25 @@ fn trapped_assets_can_be_claimed() {
 			weight
 		));
 		let outcome = Outcome::Incomplete(BaseXcmWeight::get(), XcmError::UnknownClaim);
-		assert_eq!(last_event(), RuntimeEvent::XcmPallet(crate::Event::Attempted { outcome }));
+		let context = ErrorContext {
+			cause: InstructionError {
+				index: 0,
+				instruction: Instruction::<()>::ClaimAsset {
+					assets: MultiAssets::new(),
+					ticket: Here.into(),
+				}
+				.kind(),
+				error: XcmError::UnknownClaim,
+			},
+			handler_error: None,
+		};
+		assert_eq!(
+			last_events(2),
+			vec![
+				RuntimeEvent::XcmPallet(crate::Event::AttemptIncomplete { context }),
+				RuntimeEvent::XcmPallet(crate::Event::Attempted { outcome }),
+			]
+		);
 	});
 }
 
@@ -778,6 +951,78 @@ fn subscriber_side_subscription_works() {
 	});
 }
 
+/// A version override is used regardless of the known version of the destination.
+#[test]
+fn version_override_takes_precedence() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let remote: MultiLocation = Parachain(1000).into();
+		let msg_v2 = xcm::v2::Xcm::<()>(vec![xcm::v2::Instruction::Trap(0)]);
+		let msg_v3 = xcm::v3::Xcm::<()>(vec![xcm::v3::Instruction::ClearTopic]);
+
+		assert_ok!(XcmPallet::force_xcm_version(RuntimeOrigin::root(), Box::new(remote), 3));
+		assert_eq!(
+			XcmPallet::wrap_version(&remote, msg_v3.clone()),
+			Ok(VersionedXcm::from(msg_v3.clone())),
+		);
+
+		assert_ok!(XcmPallet::force_xcm_version_override(
+			RuntimeOrigin::root(),
+			Box::new(remote),
+			Some(2),
+		));
+		assert_eq!(XcmPallet::wrap_version(&remote, msg_v3.clone()), Err(()));
+		assert_eq!(
+			XcmPallet::wrap_version(&remote, msg_v2.clone()),
+			Ok(VersionedXcm::from(msg_v2)),
+		);
+
+		assert_ok!(XcmPallet::force_xcm_version_override(
+			RuntimeOrigin::root(),
+			Box::new(remote),
+			None,
+		));
+		assert_eq!(
+			XcmPallet::wrap_version(&remote, msg_v3.clone()),
+			Ok(VersionedXcm::from(msg_v3)),
+		);
+	});
+}
+
+/// The default version cannot be lowered below the version of a location we are subscribed to.
+#[test]
+fn default_version_cannot_be_lower_than_subscribed_version() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let remote: MultiLocation = Parachain(1000).into();
+		VersionNotifiers::<Test>::insert(XCM_VERSION, LatestVersionedMultiLocation(&remote), 0);
+		assert_ok!(XcmPallet::force_xcm_version(RuntimeOrigin::root(), Box::new(remote), 3));
+
+		assert_noop!(
+			XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), Some(2)),
+			Error::<Test>::DefaultVersionTooLow,
+		);
+		assert_ok!(XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), Some(3)));
+		assert_ok!(XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), None));
+	});
+}
+
+/// The subscriptions checked when setting the default version are bounded.
+#[test]
+fn default_version_check_is_bounded() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		for id in 0..=MAX_SUBSCRIPTIONS_CHECKED {
+			let remote: MultiLocation = Parachain(id).into();
+			VersionNotifiers::<Test>::insert(XCM_VERSION, LatestVersionedMultiLocation(&remote), 0);
+		}
+
+		assert_noop!(
+			XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), Some(3)),
+			Error::<Test>::TooManySubscriptions,
+		);
+		// Disabling the default version needs no check.
+		assert_ok!(XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), None));
+	});
+}
+
 /// We should auto-subscribe when we don't know the remote's version.
 #[test]
 fn auto_subscription_works() {
@@ -949,3 +1194,382 @@ fn subscription_side_upgrades_work_with_multistage_notify() {
 		);
 	});
 }
+
+#[test]
+fn transact_weight_includes_margin() {
+	use codec::Encode;
+	use sp_runtime::Percent;
+
+	let call = RuntimeCall::TestNotifier(pallet_test_notifier::Call::notification_received {
+		query_id: 0,
+		response: Default::default(),
+	});
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		assert_eq!(
+			XcmPallet::transact_weight(&call, Percent::zero()),
+			Weight::from_parts(1_000_000, 1_000_000)
+		);
+		assert_eq!(
+			XcmPallet::transact_weight(&call, Percent::from_percent(10)),
+			Weight::from_parts(1_100_000, 1_100_000)
+		);
+		assert_eq!(
+			XcmPallet::transact_instruction::<()>(
+				&call,
+				OriginKind::SovereignAccount,
+				Percent::from_percent(10)
+			),
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: Weight::from_parts(1_100_000, 1_100_000),
+				call: call.encode().into(),
+			}
+		);
+	});
+}
+
+#[test]
+fn register_foreign_asset_works() {
+	use frame_support::traits::{fungibles::metadata::Inspect, PalletInfoAccess};
+	use xcm_builder::ForeignAssetMetadata;
+
+	let para = ParaId::from(2000);
+	let owner: MultiLocation = Parachain(2000).into();
+	let sovereign = para.into_account_truncating();
+	let metadata = ForeignAssetMetadata {
+		name: b"Token".to_vec(),
+		symbol: b"TKN".to_vec(),
+		decimals: 10,
+		min_balance: 1,
+	};
+	new_test_ext_with_balances(vec![(sovereign.clone(), INITIAL_BALANCE)]).execute_with(|| {
+		// This chain builds the registration of its local asset in the registry of a child chain.
+		let local_asset: MultiLocation = GeneralIndex(1).into();
+		let weight = Weight::from_parts(1_000, 1_000);
+		let Transact { origin_kind, require_weight_at_most, call } =
+			XcmPallet::register_foreign_asset_instruction::<()>(
+				&owner,
+				<XcmPallet as PalletInfoAccess>::index() as u8,
+				local_asset,
+				metadata.clone(),
+				weight,
+			)
+			.unwrap()
+		else {
+			panic!("expected a `Transact` instruction")
+		};
+		assert_eq!(origin_kind, OriginKind::Xcm);
+		assert_eq!(require_weight_at_most, weight);
+		let call = call.into::<RuntimeCall>().try_into().unwrap();
+		assert_eq!(
+			call,
+			RuntimeCall::XcmPallet(crate::Call::register_foreign_asset {
+				asset: Box::new(MultiLocation::new(1, X1(GeneralIndex(1))).into()),
+				metadata: metadata.clone(),
+			})
+		);
+
+		// Only the owner of an asset can register it, and only parachains can register assets.
+		let asset: MultiLocation = (Parachain(2000), GeneralIndex(1)).into();
+		let alice = AccountId32 { network: None, id: ALICE.into() };
+		assert_noop!(
+			XcmPallet::register_foreign_asset(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(asset.into()),
+				metadata.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmPallet::register_foreign_asset(
+				crate::Origin::Xcm(Parachain(2001).into()).into(),
+				Box::new(asset.into()),
+				metadata.clone()
+			),
+			Error::<Test>::AssetNotOwnedByOrigin
+		);
+		assert_noop!(
+			XcmPallet::register_foreign_asset(
+				crate::Origin::Xcm(alice.into()).into(),
+				Box::new(MultiLocation::from((alice, GeneralIndex(1))).into()),
+				metadata.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(XcmPallet::register_foreign_asset(
+			crate::Origin::Xcm(owner).into(),
+			Box::new(asset.into()),
+			metadata
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::ForeignAssetRegistered { owner, asset })
+		);
+		assert_eq!(Assets::symbol(asset), b"TKN".to_vec());
+		assert_eq!(Assets::decimals(asset), 10);
+		// The asset deposit and the metadata deposit are taken from the owner.
+		let metadata_deposit = 1 + (b"Token".len() + b"TKN".len()) as u128;
+		assert_eq!(Balances::reserved_balance(sovereign), 1 + metadata_deposit);
+	});
+}
+
+/// Test that locks of the same owner held by different unlockers overlap and are only reduced by
+/// the unlocker holding them.
+#[test]
+fn partial_locks_of_multiple_unlockers_work() {
+	use crate::LockedFungibles;
+	use xcm_executor::traits::{AssetLock, Enact, LockError};
+
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let owner: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let first: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		let second: MultiLocation = Parachain(FOREIGN_ASSET_RESERVE_PARA_ID).into();
+		let lock = |unlocker: MultiLocation, amount: u128| {
+			XcmPallet::prepare_lock(unlocker, (Here, amount).into(), owner)
+				.and_then(|ticket| ticket.enact())
+		};
+		let unlock = |unlocker: MultiLocation, amount: u128| {
+			XcmPallet::prepare_unlock(unlocker, (Here, amount).into(), owner)
+				.and_then(|ticket| ticket.enact())
+		};
+		let locked = || Balances::locks(&ALICE).iter().map(|l| l.amount).max().unwrap_or(0);
+
+		// overlapping locks: the largest one is in effect
+		assert_ok!(lock(first, 30));
+		assert_ok!(lock(second, 20));
+		assert_eq!(locked(), 30);
+		// locking again extends the lock of the same unlocker to the larger amount
+		assert_ok!(lock(second, 10));
+		assert_ok!(lock(second, 40));
+		assert_eq!(locked(), 40);
+		assert_eq!(LockedFungibles::<Test>::get(&ALICE).unwrap().len(), 2);
+
+		// unlocking more than locked by the unlocker fails
+		assert!(matches!(unlock(first, 31), Err(LockError::NotEnoughLocked)));
+		// partially unlocking only reduces the lock of the unlocker
+		assert_ok!(unlock(second, 25));
+		assert_eq!(locked(), 30);
+		assert_ok!(unlock(first, 10));
+		assert_eq!(locked(), 20);
+		assert_eq!(
+			LockedFungibles::<Test>::get(&ALICE).unwrap().into_inner(),
+			vec![(20, first.into()), (15, second.into())]
+		);
+
+		// fully unlocking removes the lock of the unlocker, and eventually the balance lock
+		assert_ok!(unlock(first, 20));
+		assert_eq!(locked(), 15);
+		assert!(matches!(unlock(first, 1), Err(LockError::NotLocked)));
+		assert_ok!(unlock(second, 15));
+		assert!(Balances::locks(&ALICE).is_empty());
+		assert_eq!(LockedFungibles::<Test>::get(&ALICE), None);
+	});
+}
+
+/// Test that a lock can't be released once the owner no longer owns the locked amount.
+#[test]
+fn unlocking_more_than_owned_fails() {
+	use xcm_executor::traits::{AssetLock, Enact, LockError};
+
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let owner: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let unlocker: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		assert_ok!(XcmPallet::prepare_lock(unlocker, (Here, 30u128).into(), owner)
+			.and_then(|ticket| ticket.enact()));
+
+		Balances::make_free_balance_be(&ALICE, 10);
+		assert!(matches!(
+			XcmPallet::prepare_unlock(unlocker, (Here, 20u128).into(), owner),
+			Err(LockError::AssetNotOwned)
+		));
+		assert_ok!(XcmPallet::prepare_unlock(unlocker, (Here, 10u128).into(), owner)
+			.and_then(|ticket| ticket.enact()));
+		assert_eq!(Balances::locks(&ALICE)[0].amount, 20);
+	});
+}
+
+/// Test that only trusted lockers can note unlockable assets and that noting again keeps the
+/// larger amount.
+#[test]
+fn note_unlockable_works() {
+	use crate::RemoteLockedFungibles;
+	use xcm_executor::traits::{AssetLock, LockError};
+
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let owner: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let locker: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		let untrusted: MultiLocation = Parachain(FOREIGN_ASSET_RESERVE_PARA_ID).into();
+		let key = (XCM_VERSION, ALICE, VersionedAssetId::from(AssetId::from(Here)));
+
+		assert!(matches!(
+			XcmPallet::note_unlockable(untrusted, (Here, 30u128).into(), owner),
+			Err(LockError::NotTrusted)
+		));
+		assert!(matches!(
+			XcmPallet::note_unlockable(locker, (Here, Index(1)).into(), owner),
+			Err(LockError::NotTrusted)
+		));
+		assert!(matches!(
+			XcmPallet::note_unlockable(locker, (Here, 30u128).into(), Parachain(1).into()),
+			Err(LockError::BadOwner)
+		));
+		assert!(RemoteLockedFungibles::<Test>::get(&key).is_none());
+
+		assert_ok!(XcmPallet::note_unlockable(locker, (Here, 30u128).into(), owner));
+		let record = RemoteLockedFungibles::<Test>::get(&key).unwrap();
+		assert_eq!(record.amount, 30);
+		assert_eq!(record.owner, owner.into());
+		assert_eq!(record.locker, locker.into());
+
+		// the network id of the owner is ignored
+		let owner_with_network: MultiLocation =
+			AccountId32 { network: Some(Polkadot), id: ALICE.into() }.into();
+		assert_ok!(XcmPallet::note_unlockable(locker, (Here, 20u128).into(), owner_with_network));
+		assert_eq!(RemoteLockedFungibles::<Test>::get(&key).unwrap().amount, 30);
+		assert_ok!(XcmPallet::note_unlockable(locker, (Here, 50u128).into(), owner));
+		assert_eq!(RemoteLockedFungibles::<Test>::get(&key).unwrap().amount, 50);
+	});
+}
+
+/// Test that reducing an unlockable asset only succeeds for the locker and owner of the record
+/// and removes the record once nothing is left.
+#[test]
+fn reduce_unlockable_works() {
+	use crate::RemoteLockedFungibles;
+	use xcm_executor::traits::{AssetLock, Enact, LockError};
+
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let owner: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let locker: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		let other: MultiLocation = Parachain(FOREIGN_ASSET_RESERVE_PARA_ID).into();
+		let key = (XCM_VERSION, ALICE, VersionedAssetId::from(AssetId::from(Here)));
+		let reduce = |locker: MultiLocation, amount: u128| {
+			XcmPallet::prepare_reduce_unlockable(locker, (Here, amount).into(), owner)
+				.and_then(|ticket| ticket.enact())
+		};
+
+		assert!(matches!(reduce(locker, 10), Err(LockError::NotLocked)));
+		assert_ok!(XcmPallet::note_unlockable(locker, (Here, 30u128).into(), owner));
+
+		assert!(matches!(reduce(other, 10), Err(LockError::WouldClobber)));
+		assert!(matches!(reduce(locker, 31), Err(LockError::NotEnoughLocked)));
+		assert!(matches!(
+			XcmPallet::prepare_reduce_unlockable(locker, (Here, Index(1)).into(), owner),
+			Err(LockError::Unimplemented)
+		));
+
+		assert_ok!(reduce(locker, 10));
+		assert_eq!(RemoteLockedFungibles::<Test>::get(&key).unwrap().amount, 20);
+		assert_ok!(reduce(locker, 20));
+		assert!(RemoteLockedFungibles::<Test>::get(&key).is_none());
+	});
+}
+
+/// Test that `LockAsset` locks the balance of the origin and notes the lock on the unlocker, and
+/// that only the unlocker can release it with `UnlockAsset`.
+#[test]
+fn lock_and_unlock_asset_instructions_work() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let owner: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let unlocker: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		let execute = |origin: MultiLocation, instruction: Instruction<RuntimeCall>| {
+			let message = Xcm(vec![instruction]);
+			let hash = fake_message_hash(&message);
+			XcmExecutor::<XcmConfig>::execute_xcm_in_credit(
+				origin,
+				message,
+				hash,
+				BaseXcmWeight::get(),
+				BaseXcmWeight::get(),
+			)
+		};
+
+		let r = execute(owner, LockAsset { asset: (Here, 30u128).into(), unlocker });
+		assert_eq!(r, Outcome::Complete(BaseXcmWeight::get()));
+		assert_eq!(Balances::locks(&ALICE)[0].amount, 30);
+		assert_eq!(
+			take_sent_xcm(),
+			vec![(
+				unlocker,
+				Xcm(vec![NoteUnlockable {
+					asset: (Parent, 30u128).into(),
+					owner: (Parent, AccountId32 { network: None, id: ALICE.into() }).into(),
+				}]),
+			)]
+		);
+
+		// only the unlocker can unlock
+		let other: MultiLocation = Parachain(FOREIGN_ASSET_RESERVE_PARA_ID).into();
+		let r = execute(other, UnlockAsset { asset: (Here, 30u128).into(), target: owner });
+		assert_eq!(r, Outcome::Incomplete(BaseXcmWeight::get(), XcmError::LockError));
+		assert_eq!(Balances::locks(&ALICE)[0].amount, 30);
+
+		let r = execute(unlocker, UnlockAsset { asset: (Here, 10u128).into(), target: owner });
+		assert_eq!(r, Outcome::Complete(BaseXcmWeight::get()));
+		assert_eq!(Balances::locks(&ALICE)[0].amount, 20);
+		let r = execute(unlocker, UnlockAsset { asset: (Here, 20u128).into(), target: owner });
+		assert_eq!(r, Outcome::Complete(BaseXcmWeight::get()));
+		assert!(Balances::locks(&ALICE).is_empty());
+	});
+}
+
+/// Test that `NoteUnlockable` is only accepted from trusted lockers and that `RequestUnlock`
+/// reduces the noted lock and asks the locker to unlock it.
+#[test]
+fn note_unlockable_and_request_unlock_instructions_work() {
+	use crate::RemoteLockedFungibles;
+
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let owner: MultiLocation = AccountId32 { network: None, id: ALICE.into() }.into();
+		let locker: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		let key = (XCM_VERSION, ALICE, VersionedAssetId::from(AssetId::from(Here)));
+		let execute = |origin: MultiLocation, instruction: Instruction<RuntimeCall>| {
+			let message = Xcm(vec![instruction]);
+			let hash = fake_message_hash(&message);
+			XcmExecutor::<XcmConfig>::execute_xcm_in_credit(
+				origin,
+				message,
+				hash,
+				BaseXcmWeight::get(),
+				BaseXcmWeight::get(),
+			)
+		};
+
+		// untrusted lockers are rejected
+		let untrusted: MultiLocation = Parachain(FOREIGN_ASSET_RESERVE_PARA_ID).into();
+		let r = execute(untrusted, NoteUnlockable { asset: (Here, 30u128).into(), owner });
+		assert_eq!(r, Outcome::Incomplete(BaseXcmWeight::get(), XcmError::LockError));
+		assert!(RemoteLockedFungibles::<Test>::get(&key).is_none());
+
+		let r = execute(locker, NoteUnlockable { asset: (Here, 30u128).into(), owner });
+		assert_eq!(r, Outcome::Complete(BaseXcmWeight::get()));
+		assert_eq!(RemoteLockedFungibles::<Test>::get(&key).unwrap().amount, 30);
+
+		// requesting to unlock more than noted fails and sends nothing
+		let r = execute(owner, RequestUnlock { asset: (Here, 31u128).into(), locker });
+		assert_eq!(r, Outcome::Incomplete(BaseXcmWeight::get(), XcmError::LockError));
+		assert!(take_sent_xcm().is_empty());
+
+		let r = execute(owner, RequestUnlock { asset: (Here, 10u128).into(), locker });
+		assert_eq!(r, Outcome::Complete(BaseXcmWeight::get()));
+		assert_eq!(RemoteLockedFungibles::<Test>::get(&key).unwrap().amount, 20);
+		assert_eq!(
+			take_sent_xcm(),
+			vec![(
+				locker,
+				Xcm(vec![UnlockAsset {
+					asset: (Parent, 10u128).into(),
+					target: (Parent, AccountId32 { network: None, id: ALICE.into() }).into(),
+				}]),
+			)]
+		);
+
+		let r = execute(owner, RequestUnlock { asset: (Here, 20u128).into(), locker });
+		assert_eq!(r, Outcome::Complete(BaseXcmWeight::get()));
+		assert!(RemoteLockedFungibles::<Test>::get(&key).is_none());
+	});
+}
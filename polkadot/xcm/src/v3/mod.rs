@@ -51,8 +51,8 @@ pub use multilocation::{
 	Ancestor, AncestorThen, InteriorMultiLocation, MultiLocation, Parent, ParentThen,
 };
 pub use traits::{
-	send_xcm, validate_send, Error, ExecuteXcm, Outcome, PreparedMessage, Result, SendError,
-	SendResult, SendXcm, Weight, XcmHash,
+	send_xcm, validate_send, Error, ErrorContext, ExecuteXcm, InstructionError, Outcome,
+	PreparedMessage, Result, SendError, SendResult, SendXcm, Weight, XcmHash,
 };
 // These parts of XCM v2 are unchanged in XCM v3, and are re-imported here.
 pub use super::v2::{GetWeight, OriginKind};
@@ -201,11 +201,11 @@ pub mod prelude {
 			send_xcm, validate_send, Ancestor, AncestorThen,
 			AssetId::{self, *},
 			AssetInstance::{self, *},
-			BodyId, BodyPart, Error as XcmError, ExecuteXcm,
+			BodyId, BodyPart, Error as XcmError, ErrorContext, ExecuteXcm,
 			Fungibility::{self, *},
 			GetWeight,
 			Instruction::*,
-			InteriorMultiLocation,
+			InstructionError, InteriorMultiLocation,
 			Junction::{self, *},
 			Junctions::{self, *},
 			MaybeErrorCode, MultiAsset,
@@ -234,7 +234,8 @@ pub mod prelude {
 parameter_types! {
 	pub MaxPalletNameLen: u32 = 48;
 	/// Maximum size of the encoded error code coming from a `Dispatch` result, used for
-	/// `MaybeErrorCode`. This is not (yet) enforced, so it's just an indication of expectation.
+	/// `MaybeErrorCode`. Longer error codes are truncated to this size when recorded by the
+	/// executor and reported as `MaybeErrorCode::TruncatedError`.
 	pub MaxDispatchErrorLen: u32 = 128;
 	pub MaxPalletsInfo: u32 = 64;
 }
@@ -1144,6 +1145,62 @@ impl<Call> Instruction<Call> {
 				UnpaidExecution { weight_limit, check_origin },
 		}
 	}
+
+	/// The kind of this instruction, i.e. its index within [`Instruction`] as used by its
+	/// encoding.
+	pub fn kind(&self) -> u8 {
+		use Instruction::*;
+		match self {
+			WithdrawAsset(..) => 0,
+			ReserveAssetDeposited(..) => 1,
+			ReceiveTeleportedAsset(..) => 2,
+			QueryResponse { .. } => 3,
+			TransferAsset { .. } => 4,
+			TransferReserveAsset { .. } => 5,
+			Transact { .. } => 6,
+			HrmpNewChannelOpenRequest { .. } => 7,
+			HrmpChannelAccepted { .. } => 8,
+			HrmpChannelClosing { .. } => 9,
+			ClearOrigin => 10,
+			DescendOrigin(..) => 11,
+			ReportError(..) => 12,
+			DepositAsset { .. } => 13,
+			DepositReserveAsset { .. } => 14,
+			ExchangeAsset { .. } => 15,
+			InitiateReserveWithdraw { .. } => 16,
+			InitiateTeleport { .. } => 17,
+			ReportHolding { .. } => 18,
+			BuyExecution { .. } => 19,
+			RefundSurplus => 20,
+			SetErrorHandler(..) => 21,
+			SetAppendix(..) => 22,
+			ClearError => 23,
+			ClaimAsset { .. } => 24,
+			Trap(..) => 25,
+			SubscribeVersion { .. } => 26,
+			UnsubscribeVersion => 27,
+			BurnAsset(..) => 28,
+			ExpectAsset(..) => 29,
+			ExpectOrigin(..) => 30,
+			ExpectError(..) => 31,
+			ExpectTransactStatus(..) => 32,
+			QueryPallet { .. } => 33,
+			ExpectPallet { .. } => 34,
+			ReportTransactStatus(..) => 35,
+			ClearTransactStatus => 36,
+			UniversalOrigin(..) => 37,
+			ExportMessage { .. } => 38,
+			LockAsset { .. } => 39,
+			UnlockAsset { .. } => 40,
+			NoteUnlockable { .. } => 41,
+			RequestUnlock { .. } => 42,
+			SetFeesMode { .. } => 43,
+			SetTopic(..) => 44,
+			ClearTopic => 45,
+			AliasOrigin(..) => 46,
+			UnpaidExecution { .. } => 47,
+		}
+	}
 }
 
 // TODO: Automate Generation
@@ -1470,6 +1527,24 @@ mod tests {
 		assert_eq!(new_xcm, xcm);
 	}
 
+	#[test]
+	fn instruction_kind_matches_encoding() {
+		let instructions: Vec<Instruction<()>> = vec![
+			WithdrawAsset((Here, 1u128).into()),
+			TransferAsset { assets: (Here, 1u128).into(), beneficiary: Here.into() },
+			ClearOrigin,
+			BuyExecution { fees: (Here, 1u128).into(), weight_limit: Unlimited },
+			SetErrorHandler(Xcm(vec![ClearError])),
+			Trap(1),
+			ExpectError(None),
+			SetTopic([0; 32]),
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		];
+		for instruction in instructions {
+			assert_eq!(instruction.kind(), instruction.encode()[0]);
+		}
+	}
+
 	#[test]
 	fn decoding_respects_limit() {
 		let max_xcm = Xcm::<()>(vec![ClearOrigin; MAX_INSTRUCTIONS_TO_DECODE as usize]);
@@ -299,6 +299,33 @@ impl Outcome {
 	}
 }
 
+/// An error raised by a single instruction of an XCM program.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[scale_info(replace_segment("staging_xcm", "xcm"))]
+pub struct InstructionError {
+	/// The index of the instruction within the program which was executed.
+	pub index: u32,
+	/// The kind of the instruction, see [`super::Instruction::kind`].
+	pub instruction: u8,
+	/// The error raised by the instruction.
+	pub error: Error,
+}
+
+/// The context of the error which left an XCM execution incomplete.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[scale_info(replace_segment("staging_xcm", "xcm"))]
+pub struct ErrorContext {
+	/// The error which left the execution incomplete.
+	///
+	/// This is the error reported by [`Outcome::Incomplete`].
+	pub cause: InstructionError,
+	/// The error raised by the error handler which was executed in response to `cause`, if it
+	/// failed itself.
+	///
+	/// The index is relative to the error handler program.
+	pub handler_error: Option<InstructionError>,
+}
+
 pub trait PreparedMessage {
 	fn weight_of(&self) -> Weight;
 }
@@ -331,6 +358,39 @@ pub trait ExecuteXcm<Call> {
 		Self::execute(origin, pre, id, weight_credit)
 	}
 
+	/// Execute a prepared message like [`Self::execute`], additionally returning the context of
+	/// the error which left the execution incomplete, if any.
+	///
+	/// The default implementation provides no context.
+	fn execute_with_context(
+		origin: impl Into<MultiLocation>,
+		pre: Self::Prepared,
+		id: &mut XcmHash,
+		weight_credit: Weight,
+	) -> (Outcome, Option<ErrorContext>) {
+		(Self::execute(origin, pre, id, weight_credit), None)
+	}
+
+	/// Prepare and execute some XCM `message` like [`Self::prepare_and_execute`], additionally
+	/// returning the context of the error which left the execution incomplete, if any.
+	fn prepare_and_execute_with_context(
+		origin: impl Into<MultiLocation>,
+		message: Xcm<Call>,
+		id: &mut XcmHash,
+		weight_limit: Weight,
+		weight_credit: Weight,
+	) -> (Outcome, Option<ErrorContext>) {
+		let pre = match Self::prepare(message) {
+			Ok(x) => x,
+			Err(_) => return (Outcome::Error(Error::WeightNotComputable), None),
+		};
+		let xcm_weight = pre.weight_of();
+		if xcm_weight.any_gt(weight_limit) {
+			return (Outcome::Error(Error::WeightLimitReached(xcm_weight)), None)
+		}
+		Self::execute_with_context(origin, pre, id, weight_credit)
+	}
+
 	/// Execute some XCM `message` with the message `hash` from `origin` using no more than
 	/// `weight_limit` weight.
 	///
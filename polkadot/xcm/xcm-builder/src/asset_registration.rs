@@ -0,0 +1,64 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Registration of foreign assets whose metadata is pushed by the chain owning them.
+
+use frame_support::{pallet_prelude::DispatchResult, weights::Weight};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::prelude::*;
+use xcm::latest::prelude::*;
+
+/// The metadata of an asset, as pushed by its owning chain to the asset registry of another chain.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ForeignAssetMetadata {
+	/// The user friendly name of the asset.
+	pub name: Vec<u8>,
+	/// The ticker symbol of the asset.
+	pub symbol: Vec<u8>,
+	/// The number of decimals of the asset.
+	pub decimals: u8,
+	/// The minimum balance of an account holding the asset.
+	pub min_balance: u128,
+}
+
+/// Registers a foreign asset, or updates its metadata, on behalf of the location owning it.
+pub trait RegisterForeignAsset {
+	/// Register `asset` owned by `owner` with `metadata`.
+	///
+	/// The caller must have already ensured that `asset` is located within `owner`. Implementations
+	/// are responsible for checking that `owner` may create assets in the registry and for taking
+	/// the deposits the registry requires from it.
+	fn register(
+		owner: &MultiLocation,
+		asset: &MultiLocation,
+		metadata: ForeignAssetMetadata,
+	) -> DispatchResult;
+
+	/// The weight of [`Self::register`].
+	fn register_weight() -> Weight;
+}
+
+impl RegisterForeignAsset for () {
+	fn register(_: &MultiLocation, _: &MultiLocation, _: ForeignAssetMetadata) -> DispatchResult {
+		Err(DispatchError::Other("no foreign asset registrar configured"))
+	}
+
+	fn register_weight() -> Weight {
+		Weight::zero()
+	}
+}
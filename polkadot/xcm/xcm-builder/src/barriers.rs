@@ -22,9 +22,11 @@ use frame_support::{
 	traits::{Contains, Get, ProcessMessageError},
 };
 use polkadot_parachain_primitives::primitives::IsSystem;
-use sp_std::{cell::Cell, marker::PhantomData, ops::ControlFlow, result::Result};
+use sp_std::{any::type_name, cell::Cell, marker::PhantomData, ops::ControlFlow, result::Result};
 use xcm::prelude::*;
-use xcm_executor::traits::{CheckSuspension, OnResponse, Properties, ShouldExecute};
+use xcm_executor::traits::{
+	CheckSuspension, OnResponse, Properties, ShouldExecute, UnpaidExecutionLimit,
+};
 
 /// Execution barrier that just takes `max_weight` from `properties.weight_credit`.
 ///
@@ -312,6 +314,39 @@ impl<T: Contains<MultiLocation>> ShouldExecute for AllowExplicitUnpaidExecutionF
 	}
 }
 
+/// Allows execution from any origin which is granted unpaid execution by `T` (i.e.
+/// `T::weight_limit(origin)` is `Some`), provided the message's `max_weight` does not exceed the
+/// limit granted to that origin.
+///
+/// Like [`AllowExplicitUnpaidExecutionFrom`], the message must begin with an `UnpaidExecution`
+/// instruction whose weight limit covers `max_weight`.
+///
+/// Use only for executions from trusted origin groups, e.g. origins whose unpaid execution is
+/// granted on-chain by governance.
+pub struct AllowLimitedUnpaidExecutionFrom<T>(PhantomData<T>);
+impl<T: UnpaidExecutionLimit> ShouldExecute for AllowLimitedUnpaidExecutionFrom<T> {
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<Call>],
+		max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		log::trace!(
+			target: "xcm::barriers",
+			"AllowLimitedUnpaidExecutionFrom origin: {:?}, instructions: {:?}, max_weight: {:?}, properties: {:?}",
+			origin, instructions, max_weight, _properties,
+		);
+		let limit = T::weight_limit(origin).ok_or(ProcessMessageError::Unsupported)?;
+		ensure!(limit.all_gte(max_weight), ProcessMessageError::Overweight(max_weight));
+		instructions.matcher().match_next_inst(|inst| match inst {
+			UnpaidExecution { weight_limit: Limited(m), .. } if m.all_gte(max_weight) => Ok(()),
+			UnpaidExecution { weight_limit: Unlimited, .. } => Ok(()),
+			_ => Err(ProcessMessageError::Overweight(max_weight)),
+		})?;
+		Ok(())
+	}
+}
+
 /// Allows a message only if it is from a system-level child parachain.
 pub struct IsChildSystemParachain<ParaId>(PhantomData<ParaId>);
 impl<ParaId: IsSystem + From<u32>> Contains<MultiLocation> for IsChildSystemParachain<ParaId> {
@@ -401,6 +436,139 @@ where
 	}
 }
 
+/// Barrier combinator which passes only if both `A` and `B` pass, evaluated in that order.
+///
+/// `B` sees the `properties` and `instructions` as left by `A` (e.g. after `TakeWeightCredit`
+/// consumed some credit). If either barrier rejects the message, both are restored to their
+/// original value so that a rejected conjunction never leaves partial side effects behind.
+pub struct And<A, B>(PhantomData<(A, B)>);
+impl<A: ShouldExecute, B: ShouldExecute> ShouldExecute for And<A, B> {
+	fn should_execute<RuntimeCall>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		let original = (properties.clone(), instructions.to_vec());
+		let result = A::should_execute(origin, instructions, max_weight, properties)
+			.map_err(|e| (type_name::<A>(), e))
+			.and_then(|()| {
+				B::should_execute(origin, instructions, max_weight, properties)
+					.map_err(|e| (type_name::<B>(), e))
+			});
+		result.map_err(|(barrier, error)| {
+			log::trace!(
+				target: "xcm::barriers",
+				"And rejected by {}: {:?}, origin: {:?}",
+				barrier, error, origin,
+			);
+			let (original_properties, original_instructions) = original;
+			*properties = original_properties;
+			instructions.clone_from_slice(&original_instructions);
+			error
+		})
+	}
+}
+
+/// Barrier combinator which passes if either `A` or `B` passes, evaluated in that order.
+///
+/// Each side is evaluated against its own copy of `properties` and `instructions`, and only the
+/// copies of the side which passed are written back. When both sides reject the message, the error
+/// of `B` is returned.
+pub struct Or<A, B>(PhantomData<(A, B)>);
+impl<A: ShouldExecute, B: ShouldExecute> ShouldExecute for Or<A, B> {
+	fn should_execute<RuntimeCall>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		let mut scratch = properties.clone();
+		let mut scratch_instructions = instructions.to_vec();
+		let a_error =
+			match A::should_execute(origin, &mut scratch_instructions, max_weight, &mut scratch) {
+				Ok(()) => {
+					*properties = scratch;
+					instructions.clone_from_slice(&scratch_instructions);
+					return Ok(())
+				},
+				Err(e) => e,
+			};
+		let mut scratch = properties.clone();
+		let mut scratch_instructions = instructions.to_vec();
+		match B::should_execute(origin, &mut scratch_instructions, max_weight, &mut scratch) {
+			Ok(()) => {
+				*properties = scratch;
+				instructions.clone_from_slice(&scratch_instructions);
+				Ok(())
+			},
+			Err(b_error) => {
+				log::trace!(
+					target: "xcm::barriers",
+					"Or rejected by {}: {:?} and by {}: {:?}, origin: {:?}",
+					type_name::<A>(), a_error,
+					type_name::<B>(), b_error,
+					origin,
+				);
+				Err(b_error)
+			},
+		}
+	}
+}
+
+/// Barrier combinator which passes if and only if `Inner` rejects the message.
+///
+/// `Inner` is evaluated against a copy of `properties` and `instructions`, which is always
+/// discarded. A message which `Inner` lets through is rejected with
+/// `ProcessMessageError::Unsupported`.
+pub struct Not<Inner>(PhantomData<Inner>);
+impl<Inner: ShouldExecute> ShouldExecute for Not<Inner> {
+	fn should_execute<RuntimeCall>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		let mut scratch = properties.clone();
+		let mut scratch_instructions = instructions.to_vec();
+		match Inner::should_execute(origin, &mut scratch_instructions, max_weight, &mut scratch) {
+			Ok(()) => {
+				log::trace!(
+					target: "xcm::barriers",
+					"Not rejected: {} passed, origin: {:?}",
+					type_name::<Inner>(), origin,
+				);
+				Err(ProcessMessageError::Unsupported)
+			},
+			Err(_) => Ok(()),
+		}
+	}
+}
+
+/// Transparent barrier wrapper which reports every rejection of `Inner` at `debug` level.
+///
+/// The report names the rejecting barrier, the error it returned and the message being
+/// processed, making it possible to find out in production which part of a composed barrier
+/// stopped a message. Does not alter the outcome of `Inner`.
+pub struct ReportRejection<Inner>(PhantomData<Inner>);
+impl<Inner: ShouldExecute> ShouldExecute for ReportRejection<Inner> {
+	fn should_execute<RuntimeCall>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		Inner::should_execute(origin, instructions, max_weight, properties).map_err(|error| {
+			log::debug!(
+				target: "xcm::barriers",
+				"barrier {} rejected message: error: {:?}, origin: {:?}, instructions: {:?}, max_weight: {:?}, properties: {:?}",
+				type_name::<Inner>(), error, origin, instructions, max_weight, properties,
+			);
+			error
+		})
+	}
+}
+
 // See issue <https://github.com/paritytech/polkadot/issues/5233>
 pub struct DenyReserveTransferToRelayChain;
 impl ShouldExecute for DenyReserveTransferToRelayChain {
@@ -17,10 +17,14 @@
 //! Adapters to work with `frame_support::traits::Currency` through XCM.
 
 use super::MintLocation;
+use crate::teleport_checking::{self, CheckTeleportAccounting, RecordTeleportAccounting};
 use frame_support::traits::{ExistenceRequirement::AllowDeath, Get, WithdrawReasons};
-use sp_runtime::traits::CheckedSub;
+use sp_runtime::{traits::CheckedSub, DispatchError, SaturatedConversion};
 use sp_std::{marker::PhantomData, result};
-use xcm::latest::{Error as XcmError, MultiAsset, MultiLocation, Result, XcmContext};
+use xcm::latest::{
+	AssetId, Error as XcmError, Fungibility::Fungible, MultiAsset, MultiLocation, Result,
+	XcmContext,
+};
 use xcm_executor::{
 	traits::{ConvertLocation, MatchesFungible, TransactAsset},
 	Assets,
@@ -85,9 +89,17 @@ impl From<Error> for XcmError {
 ///     CheckingAccount,
 /// >;
 /// ```
-pub struct CurrencyAdapter<Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount>(
-	PhantomData<(Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount)>,
-);
+///
+/// The checked issuance of the teleported asset is recorded by `Accounting`, which doesn't record
+/// anything by default.
+pub struct CurrencyAdapter<
+	Currency,
+	Matcher,
+	AccountIdConverter,
+	AccountId,
+	CheckedAccount,
+	Accounting = (),
+>(PhantomData<(Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount, Accounting)>);
 
 impl<
 		Currency: frame_support::traits::Currency<AccountId>,
@@ -95,7 +107,8 @@ impl<
 		AccountIdConverter: ConvertLocation<AccountId>,
 		AccountId: Clone, // can't get away without it since Currency is generic over it.
 		CheckedAccount: Get<Option<(AccountId, MintLocation)>>,
-	> CurrencyAdapter<Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount>
+		Accounting: RecordTeleportAccounting,
+	> CurrencyAdapter<Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount, Accounting>
 {
 	fn can_accrue_checked(_checked_account: AccountId, _amount: Currency::Balance) -> Result {
 		Ok(())
@@ -112,11 +125,33 @@ impl<
 		)
 		.map_err(|_| XcmError::NotWithdrawable)
 	}
-	fn accrue_checked(checked_account: AccountId, amount: Currency::Balance) {
+	fn accrue_checked(
+		checked_account: AccountId,
+		mint_location: MintLocation,
+		id: &AssetId,
+		amount: Currency::Balance,
+	) {
+		Accounting::note_accrued(
+			id,
+			mint_location,
+			|| Currency::free_balance(&checked_account).saturated_into(),
+			amount.saturated_into(),
+		);
 		Currency::deposit_creating(&checked_account, amount);
 		Currency::deactivate(amount);
 	}
-	fn reduce_checked(checked_account: AccountId, amount: Currency::Balance) {
+	fn reduce_checked(
+		checked_account: AccountId,
+		mint_location: MintLocation,
+		id: &AssetId,
+		amount: Currency::Balance,
+	) {
+		Accounting::note_reduced(
+			id,
+			mint_location,
+			|| Currency::free_balance(&checked_account).saturated_into(),
+			amount.saturated_into(),
+		);
 		let ok =
 			Currency::withdraw(&checked_account, amount, WithdrawReasons::TRANSFER, AllowDeath)
 				.is_ok();
@@ -136,8 +171,33 @@ impl<
 		AccountIdConverter: ConvertLocation<AccountId>,
 		AccountId: Clone, // can't get away without it since Currency is generic over it.
 		CheckedAccount: Get<Option<(AccountId, MintLocation)>>,
+		Accounting: RecordTeleportAccounting,
+	> CheckTeleportAccounting
+	for CurrencyAdapter<Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount, Accounting>
+{
+	fn check_teleport_accounting() -> result::Result<(), DispatchError> {
+		let Some((checked_account, _)) = CheckedAccount::get() else { return Ok(()) };
+		for (id, issuance) in Accounting::checked_issuances() {
+			let asset = MultiAsset { id, fun: Fungible(issuance.amount) };
+			if Matcher::matches_fungible(&asset).is_none() {
+				continue
+			}
+			let balance = Currency::free_balance(&checked_account).saturated_into();
+			teleport_checking::ensure_covered(&id, issuance, balance)?;
+		}
+		Ok(())
+	}
+}
+
+impl<
+		Currency: frame_support::traits::Currency<AccountId>,
+		Matcher: MatchesFungible<Currency::Balance>,
+		AccountIdConverter: ConvertLocation<AccountId>,
+		AccountId: Clone, // can't get away without it since Currency is generic over it.
+		CheckedAccount: Get<Option<(AccountId, MintLocation)>>,
+		Accounting: RecordTeleportAccounting,
 	> TransactAsset
-	for CurrencyAdapter<Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount>
+	for CurrencyAdapter<Currency, Matcher, AccountIdConverter, AccountId, CheckedAccount, Accounting>
 {
 	fn can_check_in(_origin: &MultiLocation, what: &MultiAsset, _context: &XcmContext) -> Result {
 		log::trace!(target: "xcm::currency_adapter", "can_check_in origin: {:?}, what: {:?}", _origin, what);
@@ -158,9 +218,9 @@ impl<
 		if let Some(amount) = Matcher::matches_fungible(what) {
 			match CheckedAccount::get() {
 				Some((checked_account, MintLocation::Local)) =>
-					Self::reduce_checked(checked_account, amount),
+					Self::reduce_checked(checked_account, MintLocation::Local, &what.id, amount),
 				Some((checked_account, MintLocation::NonLocal)) =>
-					Self::accrue_checked(checked_account, amount),
+					Self::accrue_checked(checked_account, MintLocation::NonLocal, &what.id, amount),
 				None => (),
 			}
 		}
@@ -183,9 +243,9 @@ impl<
 		if let Some(amount) = Matcher::matches_fungible(what) {
 			match CheckedAccount::get() {
 				Some((checked_account, MintLocation::Local)) =>
-					Self::accrue_checked(checked_account, amount),
+					Self::accrue_checked(checked_account, MintLocation::Local, &what.id, amount),
 				Some((checked_account, MintLocation::NonLocal)) =>
-					Self::reduce_checked(checked_account, amount),
+					Self::reduce_checked(checked_account, MintLocation::NonLocal, &what.id, amount),
 				None => (),
 			}
 		}
@@ -16,12 +16,16 @@
 
 //! Adapters to work with [`frame_support::traits::fungibles`] through XCM.
 
+use crate::teleport_checking::{self, CheckTeleportAccounting, RecordTeleportAccounting};
 use frame_support::traits::{
 	tokens::{
 		fungibles, Fortitude::Polite, Precision::Exact, Preservation::Preserve, Provenance::Minted,
 	},
 	Contains, Get,
 };
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, RuntimeDebug, SaturatedConversion};
 use sp_std::{marker::PhantomData, prelude::*, result};
 use xcm::latest::prelude::*;
 use xcm_executor::traits::{ConvertLocation, Error as MatchError, MatchesFungibles, TransactAsset};
@@ -61,7 +65,7 @@ impl<
 }
 
 /// The location which is allowed to mint a particular asset.
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum MintLocation {
 	/// This chain is allowed to mint the asset. When we track teleports of the asset we ensure
 	/// that no more of the asset returns back to the chain than has been sent out.
@@ -144,7 +148,18 @@ pub struct FungiblesMutateAdapter<
 	AccountId,
 	CheckAsset,
 	CheckingAccount,
->(PhantomData<(Assets, Matcher, AccountIdConverter, AccountId, CheckAsset, CheckingAccount)>);
+	Accounting = (),
+>(
+	PhantomData<(
+		Assets,
+		Matcher,
+		AccountIdConverter,
+		AccountId,
+		CheckAsset,
+		CheckingAccount,
+		Accounting,
+	)>,
+);
 
 impl<
 		Assets: fungibles::Mutate<AccountId>,
@@ -153,8 +168,17 @@ impl<
 		AccountId: Eq + Clone, /* can't get away without it since Currency is generic over it. */
 		CheckAsset: AssetChecking<Assets::AssetId>,
 		CheckingAccount: Get<AccountId>,
+		Accounting: RecordTeleportAccounting,
+	>
+	FungiblesMutateAdapter<
+		Assets,
+		Matcher,
+		AccountIdConverter,
+		AccountId,
+		CheckAsset,
+		CheckingAccount,
+		Accounting,
 	>
-	FungiblesMutateAdapter<Assets, Matcher, AccountIdConverter, AccountId, CheckAsset, CheckingAccount>
 {
 	fn can_accrue_checked(asset_id: Assets::AssetId, amount: Assets::Balance) -> XcmResult {
 		let checking_account = CheckingAccount::get();
@@ -169,13 +193,35 @@ impl<
 			.map_err(|_| XcmError::NotWithdrawable)
 			.map(|_| ())
 	}
-	fn accrue_checked(asset_id: Assets::AssetId, amount: Assets::Balance) {
+	fn accrue_checked(
+		id: &AssetId,
+		mint_location: MintLocation,
+		asset_id: Assets::AssetId,
+		amount: Assets::Balance,
+	) {
 		let checking_account = CheckingAccount::get();
+		Accounting::note_accrued(
+			id,
+			mint_location,
+			|| Assets::balance(asset_id.clone(), &checking_account).saturated_into(),
+			amount.saturated_into(),
+		);
 		let ok = Assets::mint_into(asset_id, &checking_account, amount).is_ok();
 		debug_assert!(ok, "`can_accrue_checked` must have returned `true` immediately prior; qed");
 	}
-	fn reduce_checked(asset_id: Assets::AssetId, amount: Assets::Balance) {
+	fn reduce_checked(
+		id: &AssetId,
+		mint_location: MintLocation,
+		asset_id: Assets::AssetId,
+		amount: Assets::Balance,
+	) {
 		let checking_account = CheckingAccount::get();
+		Accounting::note_reduced(
+			id,
+			mint_location,
+			|| Assets::balance(asset_id.clone(), &checking_account).saturated_into(),
+			amount.saturated_into(),
+		);
 		let ok = Assets::burn_from(asset_id, &checking_account, amount, Exact, Polite).is_ok();
 		debug_assert!(ok, "`can_reduce_checked` must have returned `true` immediately prior; qed");
 	}
@@ -188,6 +234,7 @@ impl<
 		AccountId: Eq + Clone, /* can't get away without it since Currency is generic over it. */
 		CheckAsset: AssetChecking<Assets::AssetId>,
 		CheckingAccount: Get<AccountId>,
+		Accounting: RecordTeleportAccounting,
 	> TransactAsset
 	for FungiblesMutateAdapter<
 		Assets,
@@ -196,6 +243,7 @@ impl<
 		AccountId,
 		CheckAsset,
 		CheckingAccount,
+		Accounting,
 	>
 {
 	fn can_check_in(
@@ -228,9 +276,11 @@ impl<
 		if let Ok((asset_id, amount)) = Matcher::matches_fungibles(what) {
 			match CheckAsset::asset_checking(&asset_id) {
 				// We track this asset's teleports to ensure no more come in than have gone out.
-				Some(MintLocation::Local) => Self::reduce_checked(asset_id, amount),
+				Some(MintLocation::Local) =>
+					Self::reduce_checked(&what.id, MintLocation::Local, asset_id, amount),
 				// We track this asset's teleports to ensure no more go out than have come in.
-				Some(MintLocation::NonLocal) => Self::accrue_checked(asset_id, amount),
+				Some(MintLocation::NonLocal) =>
+					Self::accrue_checked(&what.id, MintLocation::NonLocal, asset_id, amount),
 				_ => (),
 			}
 		}
@@ -266,9 +316,11 @@ impl<
 		if let Ok((asset_id, amount)) = Matcher::matches_fungibles(what) {
 			match CheckAsset::asset_checking(&asset_id) {
 				// We track this asset's teleports to ensure no more come in than have gone out.
-				Some(MintLocation::Local) => Self::accrue_checked(asset_id, amount),
+				Some(MintLocation::Local) =>
+					Self::accrue_checked(&what.id, MintLocation::Local, asset_id, amount),
 				// We track this asset's teleports to ensure no more go out than have come in.
-				Some(MintLocation::NonLocal) => Self::reduce_checked(asset_id, amount),
+				Some(MintLocation::NonLocal) =>
+					Self::reduce_checked(&what.id, MintLocation::NonLocal, asset_id, amount),
 				_ => (),
 			}
 		}
@@ -313,6 +365,42 @@ impl<
 	}
 }
 
+impl<
+		Assets: fungibles::Mutate<AccountId>,
+		Matcher: MatchesFungibles<Assets::AssetId, Assets::Balance>,
+		AccountIdConverter: ConvertLocation<AccountId>,
+		AccountId: Eq + Clone, /* can't get away without it since Currency is generic over it. */
+		CheckAsset: AssetChecking<Assets::AssetId>,
+		CheckingAccount: Get<AccountId>,
+		Accounting: RecordTeleportAccounting,
+	> CheckTeleportAccounting
+	for FungiblesMutateAdapter<
+		Assets,
+		Matcher,
+		AccountIdConverter,
+		AccountId,
+		CheckAsset,
+		CheckingAccount,
+		Accounting,
+	>
+{
+	fn check_teleport_accounting() -> Result<(), DispatchError> {
+		let checking_account = CheckingAccount::get();
+		for (id, issuance) in Accounting::checked_issuances() {
+			let Ok((asset_id, _)) = Matcher::matches_fungibles(&(id, issuance.amount).into())
+			else {
+				continue
+			};
+			if CheckAsset::asset_checking(&asset_id).is_none() {
+				continue
+			}
+			let balance = Assets::balance(asset_id, &checking_account).saturated_into();
+			teleport_checking::ensure_covered(&id, issuance, balance)?;
+		}
+		Ok(())
+	}
+}
+
 pub struct FungiblesAdapter<
 	Assets,
 	Matcher,
@@ -320,7 +408,18 @@ pub struct FungiblesAdapter<
 	AccountId,
 	CheckAsset,
 	CheckingAccount,
->(PhantomData<(Assets, Matcher, AccountIdConverter, AccountId, CheckAsset, CheckingAccount)>);
+	Accounting = (),
+>(
+	PhantomData<(
+		Assets,
+		Matcher,
+		AccountIdConverter,
+		AccountId,
+		CheckAsset,
+		CheckingAccount,
+		Accounting,
+	)>,
+);
 impl<
 		Assets: fungibles::Mutate<AccountId>,
 		Matcher: MatchesFungibles<Assets::AssetId, Assets::Balance>,
@@ -328,8 +427,17 @@ impl<
 		AccountId: Eq + Clone, /* can't get away without it since Currency is generic over it. */
 		CheckAsset: AssetChecking<Assets::AssetId>,
 		CheckingAccount: Get<AccountId>,
+		Accounting: RecordTeleportAccounting,
 	> TransactAsset
-	for FungiblesAdapter<Assets, Matcher, AccountIdConverter, AccountId, CheckAsset, CheckingAccount>
+	for FungiblesAdapter<
+		Assets,
+		Matcher,
+		AccountIdConverter,
+		AccountId,
+		CheckAsset,
+		CheckingAccount,
+		Accounting,
+	>
 {
 	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
 		FungiblesMutateAdapter::<
@@ -339,6 +447,7 @@ impl<
 			AccountId,
 			CheckAsset,
 			CheckingAccount,
+			Accounting,
 		>::can_check_in(origin, what, context)
 	}
 
@@ -350,6 +459,7 @@ impl<
 			AccountId,
 			CheckAsset,
 			CheckingAccount,
+			Accounting,
 		>::check_in(origin, what, context)
 	}
 
@@ -361,6 +471,7 @@ impl<
 			AccountId,
 			CheckAsset,
 			CheckingAccount,
+			Accounting,
 		>::can_check_out(dest, what, context)
 	}
 
@@ -372,6 +483,7 @@ impl<
 			AccountId,
 			CheckAsset,
 			CheckingAccount,
+			Accounting,
 		>::check_out(dest, what, context)
 	}
 
@@ -387,6 +499,7 @@ impl<
 			AccountId,
 			CheckAsset,
 			CheckingAccount,
+			Accounting,
 		>::deposit_asset(what, who, context)
 	}
 
@@ -402,6 +515,7 @@ impl<
 			AccountId,
 			CheckAsset,
 			CheckingAccount,
+			Accounting,
 		>::withdraw_asset(what, who, maybe_context)
 	}
 
@@ -416,3 +530,35 @@ impl<
 		)
 	}
 }
+
+impl<
+		Assets: fungibles::Mutate<AccountId>,
+		Matcher: MatchesFungibles<Assets::AssetId, Assets::Balance>,
+		AccountIdConverter: ConvertLocation<AccountId>,
+		AccountId: Eq + Clone, /* can't get away without it since Currency is generic over it. */
+		CheckAsset: AssetChecking<Assets::AssetId>,
+		CheckingAccount: Get<AccountId>,
+		Accounting: RecordTeleportAccounting,
+	> CheckTeleportAccounting
+	for FungiblesAdapter<
+		Assets,
+		Matcher,
+		AccountIdConverter,
+		AccountId,
+		CheckAsset,
+		CheckingAccount,
+		Accounting,
+	>
+{
+	fn check_teleport_accounting() -> Result<(), DispatchError> {
+		FungiblesMutateAdapter::<
+			Assets,
+			Matcher,
+			AccountIdConverter,
+			AccountId,
+			CheckAsset,
+			CheckingAccount,
+			Accounting,
+		>::check_teleport_accounting()
+	}
+}
@@ -46,6 +46,9 @@ pub use origin_conversion::{
 	SignedToAccountId32, SovereignSignedViaLocation,
 };
 
+mod asset_registration;
+pub use asset_registration::{ForeignAssetMetadata, RegisterForeignAsset};
+
 mod asset_conversion;
 pub use asset_conversion::{
 	AsPrefixedGeneralIndex, ConvertedAbstractId, ConvertedConcreteId, MatchedConvertedConcreteId,
@@ -55,10 +58,10 @@ pub use asset_conversion::{ConvertedAbstractAssetId, ConvertedConcreteAssetId};
 
 mod barriers;
 pub use barriers::{
-	AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, DenyReserveTransferToRelayChain,
-	DenyThenTry, IsChildSystemParachain, RespectSuspension, TakeWeightCredit, TrailingSetTopicAsId,
-	WithComputedOrigin,
+	AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowLimitedUnpaidExecutionFrom,
+	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, And,
+	DenyReserveTransferToRelayChain, DenyThenTry, IsChildSystemParachain, Not, Or, ReportRejection,
+	RespectSuspension, TakeWeightCredit, TrailingSetTopicAsId, WithComputedOrigin,
 };
 
 mod process_xcm_message;
@@ -83,9 +86,13 @@ pub use nonfungibles_adapter::{
 	NonFungiblesAdapter, NonFungiblesMutateAdapter, NonFungiblesTransferAdapter,
 };
 
+mod teleport_checking;
+pub use teleport_checking::{CheckTeleportAccounting, CheckedIssuance, RecordTeleportAccounting};
+
 mod weight;
 pub use weight::{
 	FixedRateOfFungible, FixedWeightBounds, TakeRevenue, UsingComponents, WeightInfoBounds,
+	WithNestingSurcharge,
 };
 
 mod matches_location;
@@ -0,0 +1,116 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Accounting of the teleports checked in and out by the asset transactors.
+//!
+//! Asset transactors tracking the teleports of an asset move the checked amounts into and out of
+//! a checking account. Next to this, they can record the amount they expect the checking account
+//! to hold, its *checked issuance*, through a [`RecordTeleportAccounting`] implementation, so that
+//! any funds leaving the checking account other than through a checked teleport can be caught by
+//! [`CheckTeleportAccounting`].
+//!
+//! The checked issuance of an asset is recorded from the first teleport checked after this
+//! accounting was introduced, starting from the balance the checking account held then.
+
+use crate::MintLocation;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::prelude::*;
+use xcm::latest::AssetId;
+
+/// The amount of an asset its checking account is expected to hold.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CheckedIssuance {
+	/// Where the asset is minted, i.e. in which direction its teleports are checked.
+	pub mint_location: MintLocation,
+	/// The net amount moved into the checking account by checked teleports.
+	pub amount: u128,
+}
+
+/// Records the checked issuance of the assets whose teleports are checked by an asset transactor.
+///
+/// Every checked teleport costs one read and one write of the record, which must be accounted
+/// for in the weight of the XCM instructions checking teleports in and out.
+pub trait RecordTeleportAccounting {
+	/// Note that `amount` of the asset `id` was moved into its checking account. `balance` gives
+	/// the balance the checking account held before and is only called if no checked issuance
+	/// was recorded for the asset yet.
+	fn note_accrued(
+		id: &AssetId,
+		mint_location: MintLocation,
+		balance: impl FnOnce() -> u128,
+		amount: u128,
+	);
+
+	/// Note that `amount` of the asset `id` was moved out of its checking account. `balance` gives
+	/// the balance the checking account held before and is only called if no checked issuance
+	/// was recorded for the asset yet.
+	fn note_reduced(
+		id: &AssetId,
+		mint_location: MintLocation,
+		balance: impl FnOnce() -> u128,
+		amount: u128,
+	);
+
+	/// The checked issuance of all assets any teleport of which was recorded.
+	fn checked_issuances() -> Vec<(AssetId, CheckedIssuance)>;
+}
+
+/// Doesn't record any checked issuance.
+impl RecordTeleportAccounting for () {
+	fn note_accrued(_: &AssetId, _: MintLocation, _: impl FnOnce() -> u128, _: u128) {}
+	fn note_reduced(_: &AssetId, _: MintLocation, _: impl FnOnce() -> u128, _: u128) {}
+	fn checked_issuances() -> Vec<(AssetId, CheckedIssuance)> {
+		Vec::new()
+	}
+}
+
+/// Checks the checked issuance of the assets whose teleports are tracked by an asset transactor
+/// against the balance of their checking account.
+pub trait CheckTeleportAccounting {
+	/// Ensure that the checking account of every asset handled by this transactor holds at least
+	/// the checked issuance of the asset.
+	///
+	/// The checking account may hold more, since anyone can transfer funds into it.
+	fn check_teleport_accounting() -> Result<(), DispatchError>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl CheckTeleportAccounting for Tuple {
+	fn check_teleport_accounting() -> Result<(), DispatchError> {
+		for_tuples!( #( Tuple::check_teleport_accounting()?; )* );
+		Ok(())
+	}
+}
+
+/// Ensure that the checking account of the asset `id`, holding `balance`, covers its checked
+/// `issuance`.
+pub(crate) fn ensure_covered(
+	id: &AssetId,
+	issuance: CheckedIssuance,
+	balance: u128,
+) -> Result<(), DispatchError> {
+	if balance >= issuance.amount {
+		return Ok(())
+	}
+	log::error!(
+		target: "xcm::teleport_checking",
+		"checking account of {:?} holds {} but its checked issuance is {:?}",
+		id, balance, issuance,
+	);
+	Err(DispatchError::Other("checking account balance is below the checked issuance"))
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use xcm_executor::traits::Properties;
+use xcm_executor::traits::{Properties, UnpaidExecutionLimit};
 
 use super::*;
 
@@ -206,6 +206,59 @@ fn allow_explicit_unpaid_should_work() {
 	assert_eq!(r, Ok(()));
 }
 
+#[test]
+fn allow_limited_unpaid_should_work() {
+	struct ParentUpTo20;
+	impl UnpaidExecutionLimit for ParentUpTo20 {
+		fn weight_limit(origin: &MultiLocation) -> Option<Weight> {
+			(*origin == Parent.into()).then(|| Weight::from_parts(20, 20))
+		}
+	}
+
+	let mut bad_message = Xcm::<()>(vec![
+		UnpaidExecution { weight_limit: Limited(Weight::from_parts(10, 10)), check_origin: None },
+		TransferAsset { assets: (Parent, 100).into(), beneficiary: Here.into() },
+	]);
+
+	let mut good_message = Xcm::<()>(vec![
+		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		TransferAsset { assets: (Parent, 100).into(), beneficiary: Here.into() },
+	]);
+
+	let r = AllowLimitedUnpaidExecutionFrom::<ParentUpTo20>::should_execute(
+		&Parachain(1).into(),
+		good_message.inner_mut(),
+		Weight::from_parts(20, 20),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+
+	let r = AllowLimitedUnpaidExecutionFrom::<ParentUpTo20>::should_execute(
+		&Parent.into(),
+		bad_message.inner_mut(),
+		Weight::from_parts(20, 20),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Overweight(Weight::from_parts(20, 20))));
+
+	// Above the limit granted to the origin.
+	let r = AllowLimitedUnpaidExecutionFrom::<ParentUpTo20>::should_execute(
+		&Parent.into(),
+		good_message.inner_mut(),
+		Weight::from_parts(30, 20),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Overweight(Weight::from_parts(30, 20))));
+
+	let r = AllowLimitedUnpaidExecutionFrom::<ParentUpTo20>::should_execute(
+		&Parent.into(),
+		good_message.inner_mut(),
+		Weight::from_parts(20, 20),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Ok(()));
+}
+
 #[test]
 fn allow_paid_should_work() {
 	AllowPaidFrom::set(vec![Parent.into()]);
@@ -309,3 +362,174 @@ fn suspension_should_work() {
 	);
 	assert_eq!(r, Ok(()));
 }
+
+#[test]
+fn is_child_system_parachain_should_work() {
+	use polkadot_parachain_primitives::primitives::Id as ParaId;
+	type ChildSystem = IsChildSystemParachain<ParaId>;
+
+	assert!(ChildSystem::contains(&Parachain(1000).into()));
+	assert!(!ChildSystem::contains(&Parachain(2000).into()));
+	assert!(!ChildSystem::contains(&(Parent, Parachain(1000)).into()));
+	assert!(!ChildSystem::contains(&Parent.into()));
+}
+
+#[test]
+fn and_barrier_should_work() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	type Barrier = And<TakeWeightCredit, AllowUnpaidExecutionFrom<IsInVec<AllowUnpaidFrom>>>;
+	let mut message =
+		Xcm::<()>(vec![TransferAsset { assets: (Parent, 100).into(), beneficiary: Here.into() }]);
+
+	let mut properties = props(Weight::from_parts(10, 10));
+	let r = Barrier::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut properties,
+	);
+	assert_eq!(r, Ok(()));
+	assert_eq!(properties.weight_credit, Weight::zero());
+
+	// the second barrier rejects, so the credit taken by the first one is given back.
+	let mut properties = props(Weight::from_parts(10, 10));
+	let r = Barrier::should_execute(
+		&Parachain(1).into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut properties,
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+	assert_eq!(properties, props(Weight::from_parts(10, 10)));
+}
+
+#[test]
+fn or_barrier_should_work() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	type Barrier = Or<TakeWeightCredit, AllowUnpaidExecutionFrom<IsInVec<AllowUnpaidFrom>>>;
+	let mut message =
+		Xcm::<()>(vec![TransferAsset { assets: (Parent, 100).into(), beneficiary: Here.into() }]);
+
+	let mut properties = props(Weight::from_parts(10, 10));
+	let r = Barrier::should_execute(
+		&Parachain(1).into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut properties,
+	);
+	assert_eq!(r, Ok(()));
+	assert_eq!(properties.weight_credit, Weight::zero());
+
+	let mut properties = props(Weight::zero());
+	let r = Barrier::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut properties,
+	);
+	assert_eq!(r, Ok(()));
+
+	// both reject: the error of the second barrier is reported.
+	let r = Barrier::should_execute(
+		&Parachain(1).into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+}
+
+#[test]
+fn not_barrier_should_work() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	type Barrier = Not<TakeWeightCredit>;
+	let mut message =
+		Xcm::<()>(vec![TransferAsset { assets: (Parent, 100).into(), beneficiary: Here.into() }]);
+
+	let mut properties = props(Weight::from_parts(10, 10));
+	let r = Barrier::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut properties,
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+	// the credit taken by the inner barrier is discarded.
+	assert_eq!(properties.weight_credit, Weight::from_parts(10, 10));
+
+	let r = Barrier::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Ok(()));
+
+	// composes with the other combinators into a deny-list.
+	type DenyParent = And<
+		Not<AllowUnpaidExecutionFrom<IsInVec<AllowUnpaidFrom>>>,
+		ReportRejection<TakeWeightCredit>,
+	>;
+	let r = DenyParent::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::from_parts(10, 10)),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+	let r = DenyParent::should_execute(
+		&Parachain(1).into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Overweight(Weight::from_parts(10, 10))));
+}
+
+#[test]
+fn combinators_restore_rewritten_instructions() {
+	AllowPaidFrom::set(vec![Parent.into()]);
+	AllowUnpaidFrom::set(vec![]);
+	type Paid = AllowTopLevelPaidExecutionFrom<IsInVec<AllowPaidFrom>>;
+	type Unpaid = AllowUnpaidExecutionFrom<IsInVec<AllowUnpaidFrom>>;
+	let fees: MultiAsset = (Parent, 1).into();
+	let original = Xcm::<()>(vec![
+		ReserveAssetDeposited((Parent, 100).into()),
+		BuyExecution { fees: fees.clone(), weight_limit: Unlimited },
+		DepositAsset { assets: AllCounted(1).into(), beneficiary: Here.into() },
+	]);
+	let mut limited = original.clone();
+	limited.0[1] = BuyExecution { fees, weight_limit: Limited(Weight::from_parts(10, 10)) };
+
+	// `Paid` limits the `BuyExecution`, which is reverted when `Unpaid` rejects the message.
+	let mut message = original.clone();
+	let r = And::<Paid, Unpaid>::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+	assert_eq!(message, original);
+
+	// The rewrite of the passing side is kept.
+	let r = Or::<Unpaid, Paid>::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Ok(()));
+	assert_eq!(message, limited);
+
+	// The rewrite of a negated barrier is discarded.
+	let mut message = original.clone();
+	let r = Not::<Paid>::should_execute(
+		&Parent.into(),
+		message.inner_mut(),
+		Weight::from_parts(10, 10),
+		&mut props(Weight::zero()),
+	);
+	assert_eq!(r, Err(ProcessMessageError::Unsupported));
+	assert_eq!(message, original);
+}
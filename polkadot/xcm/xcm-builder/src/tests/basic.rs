@@ -56,51 +56,209 @@ fn weigher_should_work() {
 
 #[test]
 fn code_registers_should_work() {
-	// we'll let them have message execution for free.
-	AllowUnpaidFrom::set(vec![Here.into()]);
-	// We own 1000 of our tokens.
-	add_asset(Here, (Here, 21u128));
-	let mut message = Xcm(vec![
-		// Set our error handler - this will fire only on the second message, when there's an error
-		SetErrorHandler(Xcm(vec![
+	// Error handlers are executed in a storage transaction.
+	new_test_ext().execute_with(|| {
+		// we'll let them have message execution for free.
+		AllowUnpaidFrom::set(vec![Here.into()]);
+		// We own 1000 of our tokens.
+		add_asset(Here, (Here, 21u128));
+		let mut message = Xcm(vec![
+			// Set our error handler - this will fire only on the second message, when there's an
+			// error
+			SetErrorHandler(Xcm(vec![
+				TransferAsset {
+					assets: (Here, 2u128).into(),
+					beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
+				},
+				// It was handled fine.
+				ClearError,
+			])),
+			// Set the appendix - this will always fire.
+			SetAppendix(Xcm(vec![TransferAsset {
+				assets: (Here, 4u128).into(),
+				beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
+			}])),
+			// First xfer always works ok
 			TransferAsset {
-				assets: (Here, 2u128).into(),
+				assets: (Here, 1u128).into(),
 				beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
 			},
-			// It was handled fine.
-			ClearError,
-		])),
-		// Set the appendix - this will always fire.
-		SetAppendix(Xcm(vec![TransferAsset {
-			assets: (Here, 4u128).into(),
-			beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
-		}])),
-		// First xfer always works ok
-		TransferAsset {
-			assets: (Here, 1u128).into(),
-			beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
-		},
-		// Second xfer results in error on the second message - our error handler will fire.
-		TransferAsset {
-			assets: (Here, 8u128).into(),
-			beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
-		},
-	]);
-	// Weight limit of 70 is needed.
-	let limit = <TestConfig as Config>::Weigher::weight(&mut message).unwrap();
-	assert_eq!(limit, Weight::from_parts(70, 70));
-
-	let hash = fake_message_hash(&message);
-
-	let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message.clone(), hash, limit);
-	assert_eq!(r, Outcome::Complete(Weight::from_parts(50, 50))); // We don't pay the 20 weight for the error handler.
-	assert_eq!(asset_list(AccountIndex64 { index: 3, network: None }), vec![(Here, 13u128).into()]);
-	assert_eq!(asset_list(Here), vec![(Here, 8u128).into()]);
-	assert_eq!(sent_xcm(), vec![]);
-
-	let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
-	assert_eq!(r, Outcome::Complete(Weight::from_parts(70, 70))); // We pay the full weight here.
-	assert_eq!(asset_list(AccountIndex64 { index: 3, network: None }), vec![(Here, 20u128).into()]);
-	assert_eq!(asset_list(Here), vec![(Here, 1u128).into()]);
-	assert_eq!(sent_xcm(), vec![]);
+			// Second xfer results in error on the second message - our error handler will fire.
+			TransferAsset {
+				assets: (Here, 8u128).into(),
+				beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
+			},
+		]);
+		// Weight limit of 70 is needed.
+		let limit = <TestConfig as Config>::Weigher::weight(&mut message).unwrap();
+		assert_eq!(limit, Weight::from_parts(70, 70));
+
+		let hash = fake_message_hash(&message);
+
+		let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message.clone(), hash, limit);
+		assert_eq!(r, Outcome::Complete(Weight::from_parts(50, 50))); // We don't pay the 20 weight for the error handler.
+		assert_eq!(
+			asset_list(AccountIndex64 { index: 3, network: None }),
+			vec![(Here, 13u128).into()]
+		);
+		assert_eq!(asset_list(Here), vec![(Here, 8u128).into()]);
+		assert_eq!(sent_xcm(), vec![]);
+
+		let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
+		assert_eq!(r, Outcome::Complete(Weight::from_parts(70, 70))); // We pay the full weight here.
+		assert_eq!(
+			asset_list(AccountIndex64 { index: 3, network: None }),
+			vec![(Here, 20u128).into()]
+		);
+		assert_eq!(asset_list(Here), vec![(Here, 1u128).into()]);
+		assert_eq!(sent_xcm(), vec![]);
+	});
+}
+
+#[test]
+fn error_handler_origin_changes_carry_over_to_appendix() {
+	// Error handlers are executed in a storage transaction.
+	new_test_ext().execute_with(|| {
+		AllowUnpaidFrom::set(vec![Here.into()]);
+		add_asset(Here, (Here, 10u128));
+		let mut message = Xcm(vec![
+			// The error handler succeeds, so clearing the origin affects the appendix.
+			SetErrorHandler(Xcm(vec![ClearOrigin, ClearError])),
+			SetAppendix(Xcm(vec![TransferAsset {
+				assets: (Here, 1u128).into(),
+				beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
+			}])),
+			Trap(0),
+		]);
+		let limit = <TestConfig as Config>::Weigher::weight(&mut message).unwrap();
+		assert_eq!(limit, Weight::from_parts(60, 60));
+
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
+		assert_eq!(r, Outcome::Incomplete(Weight::from_parts(60, 60), XcmError::BadOrigin));
+		assert_eq!(asset_list(AccountIndex64 { index: 3, network: None }), vec![]);
+		assert_eq!(asset_list(Here), vec![(Here, 10u128).into()]);
+	});
+}
+
+#[test]
+fn failing_error_handler_restores_origin() {
+	// Error handlers are executed in a storage transaction.
+	new_test_ext().execute_with(|| {
+		AllowUnpaidFrom::set(vec![Here.into()]);
+		add_asset(Here, (Here, 10u128));
+		let mut message = Xcm(vec![
+			// The error handler fails, so clearing the origin has no effect on the appendix.
+			SetErrorHandler(Xcm(vec![ClearOrigin, Trap(2)])),
+			SetAppendix(Xcm(vec![TransferAsset {
+				assets: (Here, 1u128).into(),
+				beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
+			}])),
+			Trap(1),
+		]);
+		let limit = <TestConfig as Config>::Weigher::weight(&mut message).unwrap();
+		assert_eq!(limit, Weight::from_parts(60, 60));
+
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
+		assert_eq!(r, Outcome::Incomplete(Weight::from_parts(60, 60), XcmError::Trap(1)));
+		assert_eq!(
+			asset_list(AccountIndex64 { index: 3, network: None }),
+			vec![(Here, 1u128).into()]
+		);
+		assert_eq!(asset_list(Here), vec![(Here, 9u128).into()]);
+	});
+}
+
+#[test]
+fn failing_error_handler_reports_original_error() {
+	// Error handlers are executed in a storage transaction.
+	new_test_ext().execute_with(|| {
+		AllowUnpaidFrom::set(vec![Here.into()]);
+		let mut message = Xcm(vec![
+			// The error handler clears the error, but then fails itself.
+			SetErrorHandler(Xcm(vec![ClearError, Trap(2)])),
+			Trap(1),
+		]);
+		let limit = <TestConfig as Config>::Weigher::weight(&mut message).unwrap();
+		assert_eq!(limit, Weight::from_parts(40, 40));
+
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
+		assert_eq!(r, Outcome::Incomplete(Weight::from_parts(40, 40), XcmError::Trap(1)));
+	});
+}
+
+#[test]
+fn error_context_reports_failing_instructions() {
+	// Error handlers are executed in a storage transaction.
+	new_test_ext().execute_with(|| {
+		AllowUnpaidFrom::set(vec![Here.into()]);
+		let trap = Instruction::<()>::Trap(0).kind();
+		let message = Xcm(vec![SetErrorHandler(Xcm(vec![ClearError, Trap(2)])), Trap(1)]);
+
+		let mut hash = fake_message_hash(&message);
+		let (r, context) = XcmExecutor::<TestConfig>::prepare_and_execute_with_context(
+			Here,
+			message,
+			&mut hash,
+			Weight::from_parts(40, 40),
+			Weight::zero(),
+		);
+		assert_eq!(r, Outcome::Incomplete(Weight::from_parts(40, 40), XcmError::Trap(1)));
+		assert_eq!(
+			context,
+			Some(ErrorContext {
+				cause: InstructionError { index: 1, instruction: trap, error: XcmError::Trap(1) },
+				handler_error: Some(InstructionError {
+					index: 1,
+					instruction: trap,
+					error: XcmError::Trap(2),
+				}),
+			})
+		);
+
+		// An error handler which clears the error leaves no context behind.
+		let message = Xcm(vec![SetErrorHandler(Xcm(vec![ClearError])), Trap(1)]);
+		let mut hash = fake_message_hash(&message);
+		let (r, context) = XcmExecutor::<TestConfig>::prepare_and_execute_with_context(
+			Here,
+			message,
+			&mut hash,
+			Weight::from_parts(30, 30),
+			Weight::zero(),
+		);
+		assert_eq!(r, Outcome::Complete(Weight::from_parts(30, 30)));
+		assert_eq!(context, None);
+	});
+}
+
+#[test]
+fn holding_is_carried_over_from_error_handler() {
+	// Error handlers are executed in a storage transaction.
+	new_test_ext().execute_with(|| {
+		AllowUnpaidFrom::set(vec![Here.into()]);
+		add_asset(Here, (Here, 10u128));
+		let mut message = Xcm(vec![
+			WithdrawAsset((Here, 10u128).into()),
+			// The error handler empties holding, so there is nothing left to be trapped.
+			SetErrorHandler(Xcm(vec![DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary: X1(AccountIndex64 { index: 3, network: None }).into(),
+			}])),
+			Trap(0),
+		]);
+		let limit = <TestConfig as Config>::Weigher::weight(&mut message).unwrap();
+		assert_eq!(limit, Weight::from_parts(40, 40));
+
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
+		assert_eq!(r, Outcome::Incomplete(Weight::from_parts(40, 40), XcmError::Trap(0)));
+		assert_eq!(
+			asset_list(AccountIndex64 { index: 3, network: None }),
+			vec![(Here, 10u128).into()]
+		);
+		assert_eq!(asset_list(Here), vec![]);
+		assert_eq!(TrappedAssets::get(), vec![]);
+	});
 }
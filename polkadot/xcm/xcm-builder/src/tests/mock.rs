@@ -743,6 +743,7 @@ impl Config for TestConfig {
 	type MessageExporter = TestMessageExporter;
 	type CallDispatcher = TestCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = ();
 	type Aliasers = AliasForeignAccountId32<SiblingPrefix>;
 }
 
@@ -753,3 +754,9 @@ pub fn fungible_multi_asset(location: MultiLocation, amount: u128) -> MultiAsset
 pub fn fake_message_hash<T>(message: &Xcm<T>) -> XcmHash {
 	message.using_encoded(sp_io::hashing::blake2_256)
 }
+
+/// Externalities for tests which run error handlers, as those are executed in a storage
+/// transaction.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	sp_io::TestExternalities::default()
+}
@@ -108,6 +108,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type WeightInfo = ();
 	type RemoveItemsLimit = RemoveItemsLimit;
+	type MaxRoleAccounts = ConstU32<5>;
 	type AssetIdParameter = AssetIdForAssets;
 	type CallbackHandle = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -219,6 +220,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
+	type XcmRecorder = XcmPallet;
 	type Aliasers = Nothing;
 }
 
@@ -268,6 +270,11 @@ impl pallet_xcm::Config for Test {
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type ForeignAssetRegistrationOrigin = frame_system::EnsureNever<MultiLocation>;
+	type ForeignAssetRegistrar = ();
+	type TeleportAccounting = ();
+	type MaxTransferReceipts = ();
+	type UnixTime = pallet_xcm::NoUnixTime;
 	type WeightInfo = pallet_xcm::TestWeightInfo;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
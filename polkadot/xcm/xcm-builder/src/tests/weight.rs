@@ -204,3 +204,80 @@ fn weight_trader_tuple_should_work() {
 	// and no refund
 	assert_eq!(traders.refund_weight(Weight::from_parts(2, 2), &ctx), None);
 }
+
+#[test]
+fn nesting_surcharge_is_added_per_junction_and_asset() {
+	parameter_types! {
+		pub const PerJunction: Weight = Weight::from_parts(100, 1);
+		pub const PerAsset: Weight = Weight::from_parts(1_000, 10);
+		pub const MaxAssets: u32 = 4;
+	}
+	type Weigher = WithNestingSurcharge<
+		FixedWeightBounds<UnitWeightCost, TestCall, MaxInstructions>,
+		PerJunction,
+		PerAsset,
+		MaxAssets,
+	>;
+
+	let assets: MultiAssets = vec![(Here, 1u128).into(), (Parent, 1u128).into()].into();
+	let shallow = Xcm::<TestCall>(vec![DepositAsset {
+		assets: assets.clone().into(),
+		beneficiary: Parachain(1).into(),
+	}]);
+	let mut nested = Xcm::<TestCall>(vec![
+		WithdrawAsset(assets.clone()),
+		SetAppendix(Xcm(vec![DepositAsset {
+			assets: AllCounted(3).into(),
+			beneficiary: (Parent, Parachain(1), AccountIndex64 { network: None, index: 1 }).into(),
+		}])),
+	]);
+
+	let mut message = shallow.clone();
+	assert_eq!(
+		Weigher::weight(&mut message),
+		Ok(UnitWeightCost::get() + Weight::from_parts(100 + 2 * 1_000, 1 + 2 * 10)),
+	);
+	assert_eq!(
+		Weigher::instr_weight(&shallow.0[0]),
+		Ok(UnitWeightCost::get() + Weight::from_parts(100 + 2 * 1_000, 1 + 2 * 10)),
+	);
+
+	// Two assets withdrawn, three assets deposited to a location with two junctions.
+	assert_eq!(
+		Weigher::weight(&mut nested),
+		Ok(UnitWeightCost::get().saturating_mul(3) +
+			Weight::from_parts(2 * 100 + 5 * 1_000, 2 + 5 * 10)),
+	);
+
+	// Instructions without locations or assets are not surcharged.
+	let mut message = Xcm::<TestCall>(vec![ClearOrigin]);
+	assert_eq!(Weigher::weight(&mut message), Ok(UnitWeightCost::get()));
+}
+
+#[test]
+fn nesting_surcharge_charges_wildcards_as_the_max_assets() {
+	parameter_types! {
+		pub const PerJunction: Weight = Weight::zero();
+		pub const PerAsset: Weight = Weight::from_parts(1_000, 10);
+		pub const MaxAssets: u32 = 4;
+	}
+	type Weigher = WithNestingSurcharge<
+		FixedWeightBounds<UnitWeightCost, TestCall, MaxInstructions>,
+		PerJunction,
+		PerAsset,
+		MaxAssets,
+	>;
+
+	let deposit =
+		|assets: MultiAssetFilter| DepositAsset::<TestCall> { assets, beneficiary: Here.into() };
+	let surcharge = |assets: u64| UnitWeightCost::get() + PerAsset::get().saturating_mul(assets);
+
+	// Uncounted wildcards may match up to `MaxAssets` assets.
+	assert_eq!(Weigher::instr_weight(&deposit(Wild(All))), Ok(surcharge(4)));
+	let all_of = Wild(AllOf { id: Concrete(Here.into()), fun: WildFungible });
+	assert_eq!(Weigher::instr_weight(&deposit(all_of)), Ok(surcharge(4)));
+
+	// Counted wildcards are charged by their count, but never above `MaxAssets`.
+	assert_eq!(Weigher::instr_weight(&deposit(Wild(AllCounted(2)))), Ok(surcharge(2)));
+	assert_eq!(Weigher::instr_weight(&deposit(Wild(AllCounted(u32::MAX)))), Ok(surcharge(4)));
+}
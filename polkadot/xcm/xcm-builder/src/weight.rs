@@ -113,6 +113,85 @@ where
 	}
 }
 
+/// Wraps the weigher `B`, adding a surcharge for the locations and assets an instruction deals
+/// with on top of the weight determined by `B`.
+///
+/// For each location an instruction routes to, like the `dest` of `InitiateTeleport` or the
+/// `beneficiary` of `DepositAsset`, `J` is charged per junction of its interior. For each asset
+/// an instruction deals with, `A` is charged. Wildcard filters may match up to `M` assets, which
+/// should be the `MaxAssetsIntoHolding` of the executor: uncounted ones are charged as `M`
+/// assets, counted ones by their count, capped at `M`.
+///
+/// This reflects the cost of routing to deeply nested locations and of converting many assets, so
+/// that cheap messages can't make the executor do a lot of such work.
+pub struct WithNestingSurcharge<B, J, A, M>(PhantomData<(B, J, A, M)>);
+impl<B: WeightBounds<C>, J: Get<Weight>, A: Get<Weight>, M: Get<u32>, C> WeightBounds<C>
+	for WithNestingSurcharge<B, J, A, M>
+{
+	fn weight(message: &mut Xcm<C>) -> Result<Weight, ()> {
+		let surcharge = Self::message_surcharge(message)?;
+		B::weight(message)?.checked_add(&surcharge).ok_or(())
+	}
+	fn instr_weight(instruction: &Instruction<C>) -> Result<Weight, ()> {
+		let surcharge = Self::instr_surcharge(instruction)?;
+		B::instr_weight(instruction)?.checked_add(&surcharge).ok_or(())
+	}
+}
+
+impl<B, J: Get<Weight>, A: Get<Weight>, M: Get<u32>, C> WithNestingSurcharge<B, J, A, M> {
+	fn message_surcharge(message: &Xcm<C>) -> Result<Weight, ()> {
+		message.0.iter().try_fold(Weight::zero(), |total, instruction| {
+			total.checked_add(&Self::instr_surcharge(instruction)?).ok_or(())
+		})
+	}
+	fn instr_surcharge(instruction: &Instruction<C>) -> Result<Weight, ()> {
+		let (junctions, assets) = match instruction {
+			WithdrawAsset(assets) |
+			ReserveAssetDeposited(assets) |
+			ReceiveTeleportedAsset(assets) |
+			BurnAsset(assets) |
+			ExpectAsset(assets) |
+			ClaimAsset { assets, .. } => (0, assets.len() as u32),
+			TransferAsset { assets, beneficiary: dest } |
+			TransferReserveAsset { assets, dest, .. } => (dest.interior().len(), assets.len() as u32),
+			DepositAsset { assets, beneficiary: dest } |
+			DepositReserveAsset { assets, dest, .. } |
+			InitiateReserveWithdraw { assets, reserve: dest, .. } |
+			InitiateTeleport { assets, dest, .. } => (dest.interior().len(), Self::filter_count(assets)),
+			ReportHolding { response_info, assets } =>
+				(response_info.destination.interior().len(), Self::filter_count(assets)),
+			ReportError(response_info) |
+			ReportTransactStatus(response_info) |
+			QueryPallet { response_info, .. } => (response_info.destination.interior().len(), 0),
+			ExchangeAsset { give, want, .. } =>
+				(0, Self::filter_count(give).saturating_add(want.len() as u32)),
+			LockAsset { unlocker: location, .. } |
+			UnlockAsset { target: location, .. } |
+			NoteUnlockable { owner: location, .. } |
+			RequestUnlock { locker: location, .. } => (location.interior().len(), 1),
+			BuyExecution { .. } => (0, 1),
+			ExportMessage { destination, .. } | DescendOrigin(destination) =>
+				(destination.len(), 0),
+			AliasOrigin(location) => (location.interior().len(), 0),
+			SetErrorHandler(xcm) | SetAppendix(xcm) => return Self::message_surcharge(xcm),
+			_ => (0, 0),
+		};
+		J::get()
+			.checked_mul(junctions as u64)
+			.and_then(|surcharge| surcharge.checked_add(&A::get().checked_mul(assets as u64)?))
+			.ok_or(())
+	}
+	fn filter_count(filter: &MultiAssetFilter) -> u32 {
+		match filter {
+			MultiAssetFilter::Definite(assets) => assets.len() as u32,
+			MultiAssetFilter::Wild(WildMultiAsset::AllCounted(count)) |
+			MultiAssetFilter::Wild(WildMultiAsset::AllOfCounted { count, .. }) => (*count).min(M::get()),
+			MultiAssetFilter::Wild(WildMultiAsset::All) |
+			MultiAssetFilter::Wild(WildMultiAsset::AllOf { .. }) => M::get(),
+		}
+	}
+}
+
 /// Function trait for handling some revenue. Similar to a negative imbalance (credit) handler, but
 /// for a `MultiAsset`. Sensible implementations will deposit the asset in some known treasury or
 /// block-author account.
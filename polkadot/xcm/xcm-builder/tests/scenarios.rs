@@ -18,7 +18,7 @@ mod mock;
 
 use mock::{
 	fake_message_hash, kusama_like_with_balances, AccountId, Balance, Balances, BaseXcmWeight,
-	System, XcmConfig, CENTS,
+	CheckAccount, LocalAssetTransactor, System, XcmConfig, XcmPallet, CENTS,
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::traits::AccountIdConversion;
@@ -252,6 +252,47 @@ fn teleport_to_asset_hub_works() {
 	});
 }
 
+/// Scenario:
+/// A parachain teleports funds from the relay chain to another parachain, after which the checking
+/// account is credited and then debited outside of any teleport.
+///
+/// Asserts that the checked issuance tracks the teleport, that funds sent to the checking account
+/// are tolerated and that funds leaving it are caught.
+#[test]
+fn teleport_accounting_is_checked() {
+	use frame_support::traits::{Currency, Get};
+	use xcm::VersionedAssetId;
+	use xcm_builder::{CheckTeleportAccounting, CheckedIssuance, MintLocation};
+
+	let para_acc: AccountId = ParaId::from(PARA_ID).into_account_truncating();
+	let balances = vec![(ALICE, INITIAL_BALANCE), (para_acc.clone(), INITIAL_BALANCE)];
+	kusama_like_with_balances(balances).execute_with(|| {
+		let amount = REGISTER_AMOUNT;
+		let weight = BaseXcmWeight::get() * 3;
+		let message = Xcm(vec![
+			WithdrawAsset((Here, amount).into()),
+			buy_execution(),
+			InitiateTeleport { assets: All.into(), dest: Parachain(3000).into(), xcm: Xcm(vec![]) },
+		]);
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<XcmConfig>::execute_xcm(Parachain(PARA_ID), message, hash, weight);
+		assert_eq!(r, Outcome::Complete(weight));
+
+		assert_eq!(
+			XcmPallet::checked_issuance(VersionedAssetId::from(Concrete(Here.into()))),
+			Some(CheckedIssuance { mint_location: MintLocation::Local, amount })
+		);
+		assert_eq!(LocalAssetTransactor::check_teleport_accounting(), Ok(()));
+
+		let (check_account, _) = CheckAccount::get();
+		let _ = Balances::deposit_creating(&check_account, amount);
+		assert_eq!(LocalAssetTransactor::check_teleport_accounting(), Ok(()));
+
+		let _ = Balances::slash(&check_account, amount + 1);
+		assert!(LocalAssetTransactor::check_teleport_accounting().is_err());
+	});
+}
+
 /// Scenario:
 /// A parachain wants to move KSM from Kusama to the parachain.
 /// It withdraws funds and then deposits them into the reserve account of the destination chain.
@@ -16,8 +16,8 @@
 
 use crate::traits::{
 	AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin, DropAssets, ExportXcm,
-	FeeManager, OnResponse, ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds,
-	WeightTrader,
+	FeeManager, OnResponse, RecordXcm, ShouldExecute, TransactAsset, VersionChangeNotifier,
+	WeightBounds, WeightTrader,
 };
 use frame_support::{
 	dispatch::{GetDispatchInfo, Parameter, PostDispatchInfo},
@@ -111,4 +111,10 @@ pub trait Config {
 	/// Use this type to explicitly whitelist calls that cannot undergo recursion. This is a
 	/// temporary measure until we properly account for proof size weights for XCM instructions.
 	type SafeCallFilter: Contains<Self::RuntimeCall>;
+
+	/// Records the locally executed XCM programs.
+	///
+	/// Use `()` to never record them. Recording is meant for dry-run runtime APIs and tests, and
+	/// should stay disabled otherwise.
+	type XcmRecorder: RecordXcm;
 }
@@ -19,11 +19,13 @@
 use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
+	storage::TransactionOutcome,
 	traits::{Contains, ContainsPair, Get, PalletsInfoAccess},
 };
 use parity_scale_codec::{Decode, Encode};
 use sp_core::defer;
 use sp_io::hashing::blake2_128;
+use sp_runtime::DispatchError;
 use sp_std::{marker::PhantomData, prelude::*};
 use sp_weights::Weight;
 use xcm::latest::prelude::*;
@@ -31,8 +33,9 @@ use xcm::latest::prelude::*;
 pub mod traits;
 use traits::{
 	validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin,
-	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, OnResponse, Properties, ShouldExecute,
-	TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader, XcmAssetTransfers,
+	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, OnResponse, Properties, RecordXcm,
+	ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
+	XcmAssetTransfers,
 };
 
 mod assets;
@@ -64,6 +67,9 @@ pub struct XcmExecutor<Config: config::Config> {
 	/// The most recent error result and instruction index into the fragment in which it occurred,
 	/// if any.
 	error: Option<(u32, XcmError)>,
+	/// The context of the most recent error which caused the error handler or the appendix to
+	/// run, including any error raised by the error handler itself.
+	error_context: Option<ErrorContext>,
 	/// The surplus weight, defined as the amount by which `max_weight` is
 	/// an over-estimate of the actual weight consumed. We do it this way to avoid needing the
 	/// execution engine to keep track of all instructions' weights (it only needs to care about
@@ -173,6 +179,40 @@ impl<Config: config::Config> XcmExecutor<Config> {
 	}
 }
 
+/// The registers of the executor which are saved before an error handler runs and restored if it
+/// fails.
+///
+/// Execution of a message is a small state machine:
+///
+/// ```text
+///                 Err                    Ok / Err
+///   program ---------------> error handler ---------> appendix
+///      |                                                 ^
+///      +------------------------ Ok ---------------------+
+/// ```
+///
+/// Each of the phases may register a new error handler and appendix, in which case the machine
+/// loops until there is nothing left to execute. An error handler that is not run, because its
+/// program succeeded, is dropped and its weight refunded.
+///
+/// The error handler runs atomically, so what follows it never sees the effects of a partially
+/// executed handler on storage or on the registers it relies on:
+///
+/// - A handler that succeeds keeps all of its effects. Changes it made to the origin, holding and
+///   error registers carry over to the appendix, like those of any other program, so it may for
+///   example `ClearError` or deposit the assets left in holding.
+/// - A handler that fails is undone. Its changes to storage are rolled back, and the origin,
+///   holding and error registers are restored to what the erroring program left behind. The outcome
+///   reports the error which caused the handler to run, rather than the failure of the handler. As
+///   the storage changes are rolled back along with the registers, assets which the handler moved
+///   out of holding are neither lost nor duplicated.
+struct ErrorHandlerSnapshot {
+	handler_weight: Weight,
+	origin: Option<MultiLocation>,
+	holding: Assets,
+	error: Option<(u32, XcmError)>,
+}
+
 pub struct WeighedMessage<Call>(Weight, Xcm<Call>);
 impl<C> PreparedMessage for WeighedMessage<C> {
 	fn weight_of(&self) -> Weight {
@@ -185,6 +225,9 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 	fn prepare(
 		mut message: Xcm<Config::RuntimeCall>,
 	) -> Result<Self::Prepared, Xcm<Config::RuntimeCall>> {
+		if Config::XcmRecorder::should_record() {
+			Config::XcmRecorder::record(message.clone().into());
+		}
 		match Config::Weigher::weight(&mut message) {
 			Ok(weight) => Ok(WeighedMessage(weight, message)),
 			Err(_) => Err(message),
@@ -192,10 +235,18 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 	}
 	fn execute(
 		origin: impl Into<MultiLocation>,
-		WeighedMessage(xcm_weight, mut message): WeighedMessage<Config::RuntimeCall>,
+		pre: WeighedMessage<Config::RuntimeCall>,
 		id: &mut XcmHash,
 		weight_credit: Weight,
 	) -> Outcome {
+		Self::execute_with_context(origin, pre, id, weight_credit).0
+	}
+	fn execute_with_context(
+		origin: impl Into<MultiLocation>,
+		WeighedMessage(xcm_weight, mut message): WeighedMessage<Config::RuntimeCall>,
+		id: &mut XcmHash,
+		weight_credit: Weight,
+	) -> (Outcome, Option<ErrorContext>) {
 		let origin = origin.into();
 		log::trace!(
 			target: "xcm::execute_xcm_in_credit",
@@ -219,27 +270,51 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 				message,
 				properties,
 			);
-			return Outcome::Error(XcmError::Barrier)
+			return (Outcome::Error(XcmError::Barrier), None)
 		}
 
 		*id = properties.message_id.unwrap_or(*id);
 
 		let mut vm = Self::new(origin, *id);
+		// Set while an error handler is being executed.
+		let mut snapshot = None;
 
 		while !message.0.is_empty() {
-			let result = vm.process(message);
+			let result = match &snapshot {
+				Some(ErrorHandlerSnapshot { handler_weight, .. }) =>
+					vm.process_atomically(message, *handler_weight),
+				None => vm.process(message),
+			};
 			log::trace!(target: "xcm::execute_xcm_in_credit", "result: {:?}", result);
-			message = if let Err(error) = result {
+			let failed = if let Err(error) = result {
 				vm.total_surplus.saturating_accrue(error.weight);
 				vm.error = Some((error.index, error.xcm_error));
-				vm.take_error_handler().or_else(|| vm.take_appendix())
+				vm.note_error_context(&error, snapshot.is_some());
+				true
+			} else {
+				false
+			};
+			if let Some(snapshot) = snapshot.take() {
+				if failed {
+					vm.restore_snapshot(snapshot);
+				}
+			}
+			message = if failed {
+				let handler_weight = vm.error_handler_weight;
+				let error_handler = vm.take_error_handler();
+				if error_handler.0.is_empty() {
+					vm.take_appendix()
+				} else {
+					snapshot = Some(vm.take_snapshot(handler_weight));
+					error_handler
+				}
 			} else {
 				vm.drop_error_handler();
 				vm.take_appendix()
 			}
 		}
 
-		vm.post_process(xcm_weight)
+		vm.post_process_with_context(xcm_weight)
 	}
 
 	fn charge_fees(origin: impl Into<MultiLocation>, fees: MultiAssets) -> XcmResult {
@@ -263,6 +338,7 @@ impl<Config: config::Config> XcmAssetTransfers for XcmExecutor<Config> {
 #[derive(Debug)]
 pub struct ExecutorError {
 	pub index: u32,
+	pub instruction: u8,
 	pub xcm_error: XcmError,
 	pub weight: Weight,
 }
@@ -290,6 +366,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			original_origin: origin,
 			trader: Config::Trader::new(),
 			error: None,
+			error_context: None,
 			total_surplus: Weight::zero(),
 			total_refunded: Weight::zero(),
 			error_handler: Xcm(vec![]),
@@ -320,6 +397,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		for (i, instr) in xcm.0.into_iter().enumerate() {
 			match &mut result {
 				r @ Ok(()) => {
+					let kind = instr.kind();
 					// Initialize the recursion count only the first time we hit this code in our
 					// potential recursive execution.
 					let inst_res = recursion_count::using_once(&mut 1, || {
@@ -347,6 +425,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 						log::trace!(target: "xcm::execute", "!!! ERROR: {:?}", e);
 						*r = Err(ExecutorError {
 							index: i as u32,
+							instruction: kind,
 							xcm_error: e,
 							weight: Weight::zero(),
 						});
@@ -364,7 +443,17 @@ impl<Config: config::Config> XcmExecutor<Config> {
 	/// Execute any final operations after having executed the XCM message.
 	/// This includes refunding surplus weight, trapping extra holding funds, and returning any
 	/// errors during execution.
-	pub fn post_process(mut self, xcm_weight: Weight) -> Outcome {
+	pub fn post_process(self, xcm_weight: Weight) -> Outcome {
+		self.post_process_with_context(xcm_weight).0
+	}
+
+	/// Execute any final operations after having executed the XCM message like
+	/// [`Self::post_process`], additionally returning the context of the error which left the
+	/// execution incomplete, if any.
+	pub fn post_process_with_context(
+		mut self,
+		xcm_weight: Weight,
+	) -> (Outcome, Option<ErrorContext>) {
 		// We silently drop any error from our attempt to refund the surplus as it's a charitable
 		// thing so best-effort is all we will do.
 		let _ = self.refund_surplus();
@@ -385,16 +474,32 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		};
 
 		match self.error {
-			None => Outcome::Complete(weight_used),
+			None => (Outcome::Complete(weight_used), None),
 			// TODO: #2841 #REALWEIGHT We should deduct the cost of any instructions following
 			// the error which didn't end up being executed.
 			Some((_i, e)) => {
-				log::trace!(target: "xcm::execute_xcm_in_credit", "Execution errored at {:?}: {:?} (original_origin: {:?})", _i, e, self.original_origin);
-				Outcome::Incomplete(weight_used, e)
+				log::trace!(target: "xcm::execute_xcm_in_credit", "Execution errored at {:?}: {:?} (original_origin: {:?}, context: {:?})", _i, e, self.original_origin, self.error_context);
+				(Outcome::Incomplete(weight_used, e), self.error_context)
 			},
 		}
 	}
 
+	/// Record the context of an `error` raised by the program which was just processed.
+	///
+	/// If `in_error_handler`, then the error was raised by the error handler and is recorded
+	/// alongside the error which caused the handler to run.
+	fn note_error_context(&mut self, error: &ExecutorError, in_error_handler: bool) {
+		let error = InstructionError {
+			index: error.index,
+			instruction: error.instruction,
+			error: error.xcm_error,
+		};
+		match self.error_context.as_mut() {
+			Some(context) if in_error_handler => context.handler_error = Some(error),
+			_ => self.error_context = Some(ErrorContext { cause: error, handler_error: None }),
+		}
+	}
+
 	fn origin_ref(&self) -> Option<&MultiLocation> {
 		self.context.origin.as_ref()
 	}
@@ -430,6 +535,56 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		self.error_handler_weight = Weight::zero();
 	}
 
+	/// Save the registers which must survive the execution of an error handler.
+	fn take_snapshot(&self, handler_weight: Weight) -> ErrorHandlerSnapshot {
+		ErrorHandlerSnapshot {
+			handler_weight,
+			origin: self.context.origin,
+			holding: self.holding.clone(),
+			error: self.error,
+		}
+	}
+
+	/// Restore the registers saved before an error handler was run, after it failed.
+	fn restore_snapshot(&mut self, snapshot: ErrorHandlerSnapshot) {
+		log::trace!(
+			target: "xcm::execute_xcm_in_credit",
+			"Error handler failed with {:?}, restoring original error {:?}",
+			self.error,
+			snapshot.error,
+		);
+		self.context.origin = snapshot.origin;
+		self.holding = snapshot.holding;
+		self.error = snapshot.error;
+	}
+
+	/// Process `xcm` of the given `weight` in a storage transaction, which is rolled back if it
+	/// fails.
+	fn process_atomically(
+		&mut self,
+		xcm: Xcm<Config::RuntimeCall>,
+		weight: Weight,
+	) -> Result<(), ExecutorError> {
+		let instruction = xcm.0.first().map_or(0, |instr| instr.kind());
+		frame_support::storage::with_transaction::<_, DispatchError, _>(|| {
+			let result = self.process(xcm);
+			if result.is_ok() {
+				TransactionOutcome::Commit(Ok(result))
+			} else {
+				TransactionOutcome::Rollback(Ok(result))
+			}
+		})
+		// Too many nested transactional layers, the program did not run at all.
+		.unwrap_or_else(|_| {
+			Err(ExecutorError {
+				index: 0,
+				instruction,
+				xcm_error: XcmError::ExceedsStackLimit,
+				weight,
+			})
+		})
+	}
+
 	/// Remove the registered appendix and return it.
 	fn take_appendix(&mut self) -> Xcm<Config::RuntimeCall> {
 		let mut r = Xcm::<Config::RuntimeCall>(vec![]);
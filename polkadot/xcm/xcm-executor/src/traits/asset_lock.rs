@@ -76,6 +76,10 @@ pub trait AssetLock {
 	/// Prepare to lock an asset. On success, a `Self::LockTicket` it returned, which can be used
 	/// to actually enact the lock.
 	///
+	/// Locks of the same `owner` held by different `unlocker`s overlap, i.e. the amount locked
+	/// overall is the largest of them. Locking again for the same `unlocker` extends its lock to
+	/// the larger of both amounts.
+	///
 	/// WARNING: Don't call this with an undropped instance of `Self::LockTicket` or
 	/// `Self::UnlockTicket`.
 	fn prepare_lock(
@@ -87,6 +91,9 @@ pub trait AssetLock {
 	/// Prepare to unlock an asset. On success, a `Self::UnlockTicket` it returned, which can be
 	/// used to actually enact the lock.
 	///
+	/// Only the lock held by `locker` is reduced, by the amount of `asset`. Any remainder stays
+	/// locked, as do the locks held by other unlockers.
+	///
 	/// WARNING: Don't call this with an undropped instance of `Self::LockTicket` or
 	/// `Self::UnlockTicket`.
 	fn prepare_unlock(
@@ -40,9 +40,11 @@ pub use token_matching::{
 mod on_response;
 pub use on_response::{OnResponse, QueryHandler, QueryResponseStatus, VersionChangeNotifier};
 mod should_execute;
-pub use should_execute::{CheckSuspension, Properties, ShouldExecute};
+pub use should_execute::{CheckSuspension, Properties, ShouldExecute, UnpaidExecutionLimit};
 mod transact_asset;
 pub use transact_asset::TransactAsset;
+mod record_xcm;
+pub use record_xcm::RecordXcm;
 mod weight;
 #[deprecated = "Use `sp_runtime::traits::` instead"]
 pub use sp_runtime::traits::{Identity, TryConvertInto as JustTry};
@@ -52,8 +54,9 @@ pub mod prelude {
 	pub use super::{
 		export_xcm, validate_export, AssetExchange, AssetLock, ClaimAssets, ConvertOrigin,
 		DropAssets, Enact, Error, ExportXcm, FeeManager, FeeReason, LockError, MatchesFungible,
-		MatchesFungibles, MatchesNonFungible, MatchesNonFungibles, OnResponse, ShouldExecute,
-		TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader, WithOriginFilter,
+		MatchesFungibles, MatchesNonFungible, MatchesNonFungibles, OnResponse, RecordXcm,
+		ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
+		WithOriginFilter,
 	};
 	#[allow(deprecated)]
 	pub use super::{Identity, JustTry};
@@ -161,6 +161,28 @@ pub trait QueryHandler {
 		timeout: Self::BlockNumber,
 	) -> result::Result<Self::QueryId, Self::Error>;
 
+	/// Consume `message` and return another which is equivalent to it except that it reports
+	/// back the status of the last `Transact` it executes.
+	///
+	/// - `message`: The message whose transact status should be reported.
+	/// - `responder`: The origin from which a response should be expected.
+	/// - `timeout`: The block number after which it is permissible to return `NotFound` from
+	///   `take_response`.
+	///
+	/// `report_transact_status` may return an error if the `responder` is not invertible.
+	///
+	/// The status is reported even if `message` fails before executing any `Transact`, in which
+	/// case it is `MaybeErrorCode::Success`. To tell these cases apart, call `report_outcome`
+	/// first: the status report is then added to the same appendix.
+	///
+	/// It is assumed that the querier of the response will be `Here`.
+	/// The response, a `Response::DispatchResult`, can be queried with `take_response`.
+	fn report_transact_status(
+		message: &mut Xcm<()>,
+		responder: impl Into<MultiLocation>,
+		timeout: Self::BlockNumber,
+	) -> result::Result<Self::QueryId, Self::Error>;
+
 	/// Attempt to remove and return the response of query with ID `query_id`.
 	fn take_response(id: Self::QueryId) -> QueryResponseStatus<Self::BlockNumber>;
 
@@ -198,6 +220,14 @@ impl QueryHandler for () {
 		Err(())
 	}
 
+	fn report_transact_status(
+		_message: &mut Xcm<()>,
+		_responder: impl Into<MultiLocation>,
+		_timeout: Self::BlockNumber,
+	) -> Result<Self::QueryId, Self::Error> {
+		Err(())
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn expect_response(_id: Self::QueryId, _response: crate::Response) {}
 }
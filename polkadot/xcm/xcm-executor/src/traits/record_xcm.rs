@@ -0,0 +1,48 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Trait for recording XCMs and a dummy implementation.
+
+use xcm::latest::Xcm;
+
+/// Record the XCM programs executed locally, e.g. so that dry-run runtime APIs and tests can
+/// inspect them.
+pub trait RecordXcm {
+	/// Whether locally executed XCMs should be recorded.
+	fn should_record() -> bool;
+	/// Enable or disable the recording of locally executed XCMs.
+	fn set_record_xcm(enabled: bool);
+	/// The last recorded XCM.
+	///
+	/// Returns `None` if no XCM was executed while recording was enabled.
+	fn recorded_xcm() -> Option<Xcm<()>>;
+	/// Record `xcm`, replacing the previously recorded one.
+	fn record(xcm: Xcm<()>);
+}
+
+impl RecordXcm for () {
+	fn should_record() -> bool {
+		false
+	}
+
+	fn set_record_xcm(_: bool) {}
+
+	fn recorded_xcm() -> Option<Xcm<()>> {
+		None
+	}
+
+	fn record(_: Xcm<()>) {}
+}
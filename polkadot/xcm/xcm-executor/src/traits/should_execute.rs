@@ -111,3 +111,24 @@ impl CheckSuspension for Tuple {
 		false
 	}
 }
+
+/// Trait to determine the maximum weight an origin may execute without paying for it.
+///
+/// Returns `None` if the origin is not allowed unpaid execution at all. Can be chained together in
+/// tuples, in which case the first element returning `Some` determines the limit.
+pub trait UnpaidExecutionLimit {
+	fn weight_limit(origin: &MultiLocation) -> Option<Weight>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl UnpaidExecutionLimit for Tuple {
+	fn weight_limit(origin: &MultiLocation) -> Option<Weight> {
+		for_tuples!( #(
+			if let Some(limit) = Tuple::weight_limit(origin) {
+				return Some(limit)
+			}
+		)* );
+
+		None
+	}
+}
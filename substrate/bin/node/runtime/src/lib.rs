@@ -65,7 +65,9 @@ use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_nfts::PalletFeatures;
 use pallet_nis::WithMaximumOf;
 use pallet_session::historical as pallet_session_historical;
-pub use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
+pub use pallet_transaction_payment::{
+	CurrencyAdapter, Multiplier, SplitTips, TargetedFeeAdjustment, TipSplit,
+};
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use pallet_tx_pause::RuntimeCallNameOf;
 use sp_api::impl_runtime_apis;
@@ -78,8 +80,8 @@ use sp_runtime::{
 	curve::PiecewiseLinear,
 	generic, impl_opaque_keys,
 	traits::{
-		self, AccountIdConversion, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, NumberFor,
-		OpaqueKeys, SaturatedConversion, StaticLookup,
+		self, AccountIdConversion, BlakeTwo256, Block as BlockT, Bounded, Checkable, ConvertInto,
+		NumberFor, OpaqueKeys, SaturatedConversion, StaticLookup,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
@@ -172,17 +174,11 @@ type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
 pub struct DealWithFees;
 impl OnUnbalanced<NegativeImbalance> for DealWithFees {
-	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
-		if let Some(fees) = fees_then_tips.next() {
-			// for fees, 80% to treasury, 20% to author
-			let mut split = fees.ration(80, 20);
-			if let Some(tips) = fees_then_tips.next() {
-				// for tips, if any, 80% to treasury, 20% to author (though this can be anything)
-				tips.ration_merge_into(80, 20, &mut split);
-			}
-			Treasury::on_unbalanced(split.0);
-			Author::on_unbalanced(split.1);
-		}
+	fn on_nonzero_unbalanced(fees: NegativeImbalance) {
+		// for fees, 80% to treasury, 20% to author
+		let split = fees.ration(80, 20);
+		Treasury::on_unbalanced(split.0);
+		Author::on_unbalanced(split.1);
 	}
 }
 
@@ -307,6 +303,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
@@ -526,10 +523,13 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = ConstU32<1>;
-	type MaxHolds = ConstU32<6>;
+	type MaxHolds = ConstU32<7>;
 }
 
 parameter_types! {
+	/// How tips are routed until a split is set with `TransactionPayment::set_tip_split`.
+	pub const DefaultTipSplit: TipSplit =
+		TipSplit { author: Percent::from_percent(20), treasury: Percent::from_percent(80) };
 	pub const TransactionByteFee: Balance = 10 * MILLICENTS;
 	pub const OperationalFeeMultiplier: u8 = 5;
 	pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
@@ -540,7 +540,10 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction = CurrencyAdapter<
+		Balances,
+		SplitTips<Runtime, DefaultTipSplit, DealWithFees, Author, Treasury>,
+	>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -571,6 +574,7 @@ impl pallet_asset_conversion_tx_payment::Config for Runtime {
 
 impl pallet_skip_feeless_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type SkippedExtensionWeight = ();
 }
 
 parameter_types! {
@@ -861,6 +865,7 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type BagThresholds = BagThresholds;
 	type Score = VoteWeight;
 	type WeightInfo = pallet_bags_list::weights::SubstrateWeight<Runtime>;
+	type MaxAutoRebagPerBlock = ConstU32<32>;
 }
 
 parameter_types! {
@@ -895,6 +900,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type PostUnbondingPoolsWindow = PostUnbondPoolsWindow;
 	type MaxMetadataLen = ConstU32<256>;
 	type MaxUnbonding = ConstU32<8>;
+	type Delegation = sp_staking::NoDelegation<AccountId, Balance>;
 	type PalletId = NominationPoolsPalletId;
 	type MaxPointsToBalance = MaxPointsToBalance;
 }
@@ -970,6 +976,7 @@ impl pallet_referenda::Config for Runtime {
 	type SubmitOrigin = EnsureSigned<AccountId>;
 	type CancelOrigin = EnsureRoot<AccountId>;
 	type KillOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
 	type Slash = ();
 	type Votes = pallet_conviction_voting::VotesOf<Runtime>;
 	type Tally = pallet_conviction_voting::TallyOf<Runtime>;
@@ -990,6 +997,7 @@ impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type SubmitOrigin = EnsureSigned<AccountId>;
 	type CancelOrigin = EnsureRoot<AccountId>;
 	type KillOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
 	type Slash = ();
 	type Votes = pallet_ranked_collective::Votes;
 	type Tally = pallet_ranked_collective::TallyOf<Runtime>;
@@ -1284,6 +1292,8 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = ();
 	type HeapSize = ConstU32<{ 64 * 1024 }>;
 	type MaxStale = ConstU32<128>;
+	type MaxDeadLetters = ConstU32<64>;
+	type DeadLetterOrigin = EnsureRoot<AccountId>;
 	type ServiceWeight = MessageQueueServiceWeight;
 }
 
@@ -1295,6 +1305,7 @@ impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<5>;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type ChildBountyClosureBatchSize = ConstU32<5>;
 	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1442,10 +1453,18 @@ impl pallet_im_online::Config for Runtime {
 	type MaxPeerInHeartbeats = MaxPeerInHeartbeats;
 }
 
+parameter_types! {
+	pub const MaxQueuedOffenceReports: u32 = 1_000;
+	pub OffencesMaxProcessingWeight: Weight = Perbill::from_percent(20) * RuntimeBlockWeights::get().max_block;
+}
+
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = MaxQueuedOffenceReports;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = pallet_offences::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -1476,6 +1495,7 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const JudgementRequestExpiry: BlockNumber = 14 * DAYS;
 }
 
 impl pallet_identity::Config for Runtime {
@@ -1490,6 +1510,8 @@ impl pallet_identity::Config for Runtime {
 	type Slashed = Treasury;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type RegistrarOrigin = EnsureRootOrHalfCouncil;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type JudgementRequestExpiry = JudgementRequestExpiry;
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1613,6 +1635,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type CallbackHandle = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1639,6 +1662,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<10>;
 	type CallbackHandle = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -2176,6 +2200,12 @@ pub type SignedExtra = (
 	>,
 );
 
+/// The [`frame_system::CheckEra`] extension among the [`SignedExtra`].
+fn check_era(extra: &SignedExtra) -> &frame_system::CheckEra<Runtime> {
+	let (_, _, _, _, check_era, _, _, _) = extra;
+	check_era
+}
+
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
@@ -2199,6 +2229,8 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_nis::migration::v1::MigrateToV1<Runtime>,
+	pallet_identity::migration::v1::MigrateToV1<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2250,7 +2282,8 @@ mod benches {
 		[pallet_mmr, Mmr]
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
-		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_offences, Offences]
+		[pallet_offences_benchmarking, OffencesBench::<Runtime>]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, RankedCollective]
@@ -2354,6 +2387,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
 		fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
 			Grandpa::grandpa_authorities()
@@ -2363,6 +2397,10 @@ impl_runtime_apis! {
 			Grandpa::current_set_id()
 		}
 
+		fn authority_set_state() -> sp_consensus_grandpa::AuthoritySetState<NumberFor<Block>> {
+			Grandpa::authority_set_state()
+		}
+
 		fn submit_report_equivocation_unsigned_extrinsic(
 			equivocation_proof: sp_consensus_grandpa::EquivocationProof<
 				<Block as BlockT>::Hash,
@@ -2404,6 +2442,39 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_bags_list_runtime_api::BagsListApi<Block, AccountId, VoteWeight> for Runtime {
+		fn most_out_of_place(limit: u32) -> Vec<(AccountId, VoteWeight, VoteWeight)> {
+			VoterList::most_out_of_place(limit)
+		}
+	}
+
+	impl pallet_conviction_voting_runtime_api::ConvictionVotingApi<Block, AccountId, Balance, u16> for Runtime {
+		fn voting_power(
+			who: AccountId,
+			conviction: pallet_conviction_voting::Conviction,
+		) -> Vec<(u16, Balance)> {
+			ConvictionVoting::api_voting_power(who, conviction)
+		}
+
+		fn delegations(
+			who: AccountId,
+			start_after: Option<(AccountId, u16)>,
+			max_records: u32,
+		) -> (
+			Vec<pallet_conviction_voting::ClassDelegations<AccountId, Balance, u16>>,
+			Option<(AccountId, u16)>,
+		) {
+			ConvictionVoting::api_delegations(who, start_after, max_records)
+		}
+	}
+
+	impl pallet_transaction_storage_runtime_api::TransactionStorageApi<Block, BlockNumber> for Runtime {
+		fn stored_until(content_hash: [u8; 32]) -> Option<BlockNumber> {
+			TransactionStorage::stored_until(content_hash)
+		}
+	}
+
+	#[api_version(2)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2412,6 +2483,28 @@ impl_runtime_apis! {
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page {
 			Staking::api_eras_stakers_page_count(era, account)
 		}
+
+		fn exposures(
+			era: sp_staking::EraIndex,
+			account: AccountId,
+			start_after: Option<AccountId>,
+			max_validators: u32,
+		) -> (Vec<(AccountId, sp_staking::Page, Balance)>, Option<AccountId>) {
+			Staking::api_exposures(era, account, start_after, max_validators)
+		}
+
+		fn pending_rewards(
+			era: sp_staking::EraIndex,
+			account: AccountId,
+			start_after: Option<AccountId>,
+			max_validators: u32,
+		) -> (Vec<(AccountId, sp_staking::Page)>, Option<AccountId>) {
+			Staking::api_pending_rewards(era, account, start_after, max_validators)
+		}
+
+		fn slashes(account: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::api_slashes(account)
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -2475,6 +2568,29 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockAuthorApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			System::block_author()
+		}
+	}
+
+	impl frame_system_rpc_runtime_api::MortalityApi<Block, BlockNumber, Moment> for Runtime {
+		fn mortality_window(
+			extrinsic: <Block as BlockT>::Extrinsic,
+		) -> Option<frame_system_rpc_runtime_api::MortalityWindow<BlockNumber, Moment>> {
+			// The signature covers the hash of the birth block, checking it makes sure the window
+			// is the one the extrinsic was signed for.
+			let checked = extrinsic.check(&frame_system::ChainContext::<Runtime>::default()).ok()?;
+			let (_, extra) = checked.signed?;
+			let (birth, death) = check_era(&extra).validity_window()?;
+			let expires_at = death.map(|death| {
+				let blocks_left = death.saturating_sub(System::block_number());
+				Timestamp::now().saturating_add(Moment::from(blocks_left) * MILLISECS_PER_BLOCK)
+			});
+			Some(frame_system_rpc_runtime_api::MortalityWindow { birth, death, expires_at })
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
@@ -2597,6 +2713,14 @@ impl_runtime_apis! {
 		fn get_reserves(asset1: NativeOrAssetId<u32>, asset2: NativeOrAssetId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(&asset1, &asset2).ok()
 		}
+
+		fn pool_exists(asset1: NativeOrAssetId<u32>, asset2: NativeOrAssetId<u32>) -> bool {
+			AssetConversion::pool_exists(asset1, asset2)
+		}
+
+		fn find_path(asset1: NativeOrAssetId<u32>, asset2: NativeOrAssetId<u32>) -> Option<Vec<NativeOrAssetId<u32>>> {
+			AssetConversion::find_path(asset1, asset2).map(Into::into)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
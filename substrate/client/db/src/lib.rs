@@ -1142,6 +1142,11 @@ impl<Block: BlockT> Backend<Block> {
 		Self::from_database(db as Arc<_>, canonicalization_delay, &db_config, needs_init)
 	}
 
+	/// Returns the shared trie cache, if the cache is enabled.
+	pub fn shared_trie_cache(&self) -> Option<&SharedTrieCache<HashingFor<Block>>> {
+		self.shared_trie_cache.as_ref()
+	}
+
 	/// Reset the shared trie cache.
 	pub fn reset_trie_cache(&self) {
 		if let Some(cache) = &self.shared_trie_cache {
@@ -21,7 +21,7 @@ use crate::{
 	client::{Client, ClientConfig},
 	config::{Configuration, KeystoreConfig, PrometheusConfig},
 	error::Error,
-	metrics::MetricsService,
+	metrics::{report_trie_cache_metrics, MetricsService},
 	start_rpc_servers, BuildGenesisBlock, GenesisBlockBuilder, RpcHandlers, SpawnTaskHandle,
 	TaskManager, TransactionPoolAdapter,
 };
@@ -135,6 +135,23 @@ pub fn new_full_parts<TBl, TRtApi, TExec>(
 	telemetry: Option<TelemetryHandle>,
 	executor: TExec,
 ) -> Result<TFullParts<TBl, TRtApi, TExec>, Error>
+where
+	TBl: BlockT,
+	TExec: CodeExecutor + RuntimeVersionOf + Clone,
+{
+	new_full_parts_record_import(config, telemetry, executor, false)
+}
+
+/// Create the initial parts of a full node with the default genesis block builder.
+///
+/// If `enable_import_proof_recording` is set, storage proofs are recorded while importing blocks.
+/// This is required by runtimes which query the storage proof size during block execution.
+pub fn new_full_parts_record_import<TBl, TRtApi, TExec>(
+	config: &Configuration,
+	telemetry: Option<TelemetryHandle>,
+	executor: TExec,
+	enable_import_proof_recording: bool,
+) -> Result<TFullParts<TBl, TRtApi, TExec>, Error>
 where
 	TBl: BlockT,
 	TExec: CodeExecutor + RuntimeVersionOf + Clone,
@@ -148,7 +165,14 @@ where
 		executor.clone(),
 	)?;
 
-	new_full_parts_with_genesis_builder(config, telemetry, executor, backend, genesis_block_builder)
+	new_full_parts_with_genesis_builder(
+		config,
+		telemetry,
+		executor,
+		backend,
+		genesis_block_builder,
+		enable_import_proof_recording,
+	)
 }
 
 /// Create the initial parts of a full node.
@@ -158,6 +182,7 @@ pub fn new_full_parts_with_genesis_builder<TBl, TRtApi, TExec, TBuildGenesisBloc
 	executor: TExec,
 	backend: Arc<TFullBackend<TBl>>,
 	genesis_block_builder: TBuildGenesisBlock,
+	enable_import_proof_recording: bool,
 ) -> Result<TFullParts<TBl, TRtApi, TExec>, Error>
 where
 	TBl: BlockT,
@@ -174,6 +199,20 @@ where
 		TaskManager::new(config.tokio_handle.clone(), registry)?
 	};
 
+	if let (Some(cache), Some(registry)) =
+		(backend.shared_trie_cache(), config.prometheus_registry())
+	{
+		task_manager.spawn_handle().spawn(
+			"trie-cache-metrics",
+			None,
+			report_trie_cache_metrics::<TBl>(
+				cache.clone(),
+				registry.clone(),
+				config.chain_spec.id().to_owned(),
+			),
+		);
+	}
+
 	let chain_spec = &config.chain_spec;
 	let fork_blocks = get_extension::<ForkBlocks<TBl>>(chain_spec.extensions())
 		.cloned()
@@ -225,6 +264,7 @@ where
 					SyncMode::LightState { .. } | SyncMode::Warp { .. }
 				),
 				wasm_runtime_substitutes,
+				enable_import_proof_recording,
 			},
 		)?;
 
@@ -20,7 +20,9 @@ use std::time::SystemTime;
 
 use crate::config::Configuration;
 use futures_timer::Delay;
-use prometheus_endpoint::{register, Gauge, GaugeVec, Opts, PrometheusError, Registry, U64};
+use prometheus_endpoint::{
+	register, CounterVec, Gauge, GaugeVec, Opts, PrometheusError, Registry, U64,
+};
 use sc_client_api::{ClientInfo, UsageProvider};
 use sc_network::{config::Role, NetworkStatus, NetworkStatusProvider};
 use sc_network_sync::{SyncStatus, SyncStatusProvider};
@@ -28,7 +30,8 @@ use sc_telemetry::{telemetry, TelemetryHandle, SUBSTRATE_INFO};
 use sc_transaction_pool_api::{MaintainedTransactionPool, PoolStatus};
 use sc_utils::metrics::register_globals;
 use sp_api::ProvideRuntimeApi;
-use sp_runtime::traits::{Block, NumberFor, SaturatedConversion, UniqueSaturatedInto};
+use sp_runtime::traits::{Block, HashingFor, NumberFor, SaturatedConversion, UniqueSaturatedInto};
+use sp_trie::cache::{SharedTrieCache, SharedTrieCacheStats};
 use std::{
 	sync::Arc,
 	time::{Duration, Instant},
@@ -296,3 +299,124 @@ impl MetricsService {
 		}
 	}
 }
+
+/// Prometheus metrics of a [`SharedTrieCache`], which may be shared by multiple consumers.
+struct TrieCacheMetrics {
+	hits: CounterVec<U64>,
+	fetch_attempts: CounterVec<U64>,
+	added: CounterVec<U64>,
+	evictions: CounterVec<U64>,
+	used_memory: Gauge<U64>,
+}
+
+impl TrieCacheMetrics {
+	/// Register the metrics, labeled with `chain` so that the caches of multiple nodes can report
+	/// to the same `registry`.
+	fn register(registry: &Registry, chain: &str) -> Result<Self, PrometheusError> {
+		let counter = |name: &str, help: &str, labels: &[&str]| {
+			register(
+				CounterVec::new(Opts::new(name, help).const_label("chain", chain), labels)?,
+				registry,
+			)
+		};
+
+		Ok(Self {
+			hits: counter(
+				"substrate_trie_cache_hits_total",
+				"Number of items found in the shared trie cache",
+				&["consumer", "cache"],
+			)?,
+			fetch_attempts: counter(
+				"substrate_trie_cache_fetch_attempts_total",
+				"Number of items looked up in the shared trie cache",
+				&["consumer", "cache"],
+			)?,
+			added: counter(
+				"substrate_trie_cache_added_total",
+				"Number of items added to the shared trie cache",
+				&["consumer", "cache"],
+			)?,
+			evictions: counter(
+				"substrate_trie_cache_evictions_total",
+				"Number of items evicted from the shared trie cache",
+				&["cache"],
+			)?,
+			used_memory: register(
+				Gauge::<U64>::with_opts(
+					Opts::new(
+						"substrate_trie_cache_used_memory_bytes",
+						"Memory used by the shared trie cache",
+					)
+					.const_label("chain", chain),
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	fn update(&self, stats: &SharedTrieCacheStats) {
+		// The statistics are running totals, so the counters only advance by what is new.
+		let advance = |counter: &CounterVec<U64>, labels: &[&str], total: u64| {
+			let counter = counter.with_label_values(labels);
+			counter.inc_by(total.saturating_sub(counter.get()));
+		};
+
+		self.used_memory.set(stats.used_memory_size as u64);
+		advance(&self.evictions, &["node"], stats.node_evictions);
+		advance(&self.evictions, &["value"], stats.value_evictions);
+
+		for (consumer, consumer_stats) in &stats.consumers {
+			for (cache, hits, fetch_attempts, added) in [
+				(
+					"node",
+					consumer_stats.node_hits,
+					consumer_stats.node_fetch_attempts,
+					consumer_stats.nodes_added,
+				),
+				(
+					"value",
+					consumer_stats.value_hits,
+					consumer_stats.value_fetch_attempts,
+					consumer_stats.values_added,
+				),
+			] {
+				advance(&self.hits, &[consumer, cache], hits);
+				advance(&self.fetch_attempts, &[consumer, cache], fetch_attempts);
+				advance(&self.added, &[consumer, cache], added);
+			}
+		}
+	}
+}
+
+/// Returns a never-ending `Future` that periodically reports the statistics of the shared trie
+/// `cache` of `chain` to Prometheus.
+///
+/// Returns immediately if the metrics can't be registered, e.g. because the cache of another
+/// node of the same chain already reports to `registry`.
+pub(crate) async fn report_trie_cache_metrics<TBl: Block>(
+	cache: SharedTrieCache<HashingFor<TBl>>,
+	registry: Registry,
+	chain: String,
+) {
+	let metrics = match TrieCacheMetrics::register(&registry, &chain) {
+		Ok(metrics) => metrics,
+		Err(err) => {
+			log::warn!(
+				target: "service",
+				"Not reporting the trie cache metrics of {}: {}",
+				chain,
+				err,
+			);
+			return
+		},
+	};
+
+	let mut timer = Delay::new(Duration::from_secs(0));
+	let timer_interval = Duration::from_secs(5);
+
+	loop {
+		(&mut timer).await;
+		metrics.update(&cache.stats());
+		timer.reset(timer_interval);
+	}
+}
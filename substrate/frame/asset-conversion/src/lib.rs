@@ -1016,49 +1016,80 @@ pub mod pallet {
 			Ok(amounts)
 		}
 
+		/// Returns `true` if a pool exists for the given asset pair.
+		pub fn pool_exists(asset1: T::MultiAssetId, asset2: T::MultiAssetId) -> bool {
+			Pools::<T>::contains_key(Self::get_pool_id(asset1, asset2))
+		}
+
+		/// Finds a swap path from `asset1` to `asset2`.
+		///
+		/// The direct pool is used if it exists. Otherwise the swap is routed through the native
+		/// asset, provided both legs exist and `MaxSwapPathLength` permits the extra hop.
+		pub fn find_path(
+			asset1: T::MultiAssetId,
+			asset2: T::MultiAssetId,
+		) -> Option<BoundedVec<T::MultiAssetId, T::MaxSwapPathLength>> {
+			if Self::pool_exists(asset1.clone(), asset2.clone()) {
+				return vec![asset1, asset2].try_into().ok()
+			}
+
+			if T::MultiAssetIdConverter::is_native(&asset1) ||
+				T::MultiAssetIdConverter::is_native(&asset2)
+			{
+				return None
+			}
+
+			let native = T::MultiAssetIdConverter::get_native();
+			if !Self::pool_exists(asset1.clone(), native.clone()) ||
+				!Self::pool_exists(native.clone(), asset2.clone())
+			{
+				return None
+			}
+
+			vec![asset1, native, asset2].try_into().ok()
+		}
+
 		/// Used by the RPC service to provide current prices.
+		///
+		/// If there is no pool for the given pair, the swap is routed through the native asset.
 		pub fn quote_price_exact_tokens_for_tokens(
 			asset1: T::MultiAssetId,
 			asset2: T::MultiAssetId,
 			amount: T::AssetBalance,
 			include_fee: bool,
 		) -> Option<T::AssetBalance> {
-			let pool_id = Self::get_pool_id(asset1.clone(), asset2.clone());
-			let pool_account = Self::get_pool_account(&pool_id);
+			let path = Self::find_path(asset1, asset2)?;
 
-			let balance1 = Self::get_balance(&pool_account, &asset1).ok()?;
-			let balance2 = Self::get_balance(&pool_account, &asset2).ok()?;
-			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
-				}
+			if include_fee {
+				Self::get_amounts_out(&amount, &path).ok()?.last().copied()
 			} else {
-				None
+				path.windows(2).try_fold(amount, |amount, assets_pair| {
+					let (reserve_in, reserve_out) =
+						Self::get_reserves(&assets_pair[0], &assets_pair[1]).ok()?;
+					Self::quote(&amount, &reserve_in, &reserve_out).ok()
+				})
 			}
 		}
 
 		/// Used by the RPC service to provide current prices.
+		///
+		/// If there is no pool for the given pair, the swap is routed through the native asset.
 		pub fn quote_price_tokens_for_exact_tokens(
 			asset1: T::MultiAssetId,
 			asset2: T::MultiAssetId,
 			amount: T::AssetBalance,
 			include_fee: bool,
 		) -> Option<T::AssetBalance> {
-			let pool_id = Self::get_pool_id(asset1.clone(), asset2.clone());
-			let pool_account = Self::get_pool_account(&pool_id);
+			let path = Self::find_path(asset1, asset2)?;
 
-			let balance1 = Self::get_balance(&pool_account, &asset1).ok()?;
-			let balance2 = Self::get_balance(&pool_account, &asset2).ok()?;
-			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
-				}
+			if include_fee {
+				Self::get_amounts_in(&amount, &path).ok()?.first().copied()
 			} else {
-				None
+				path.windows(2).rev().try_fold(amount, |amount, assets_pair| {
+					let (reserve_in, reserve_out) =
+						Self::get_reserves(&assets_pair[0], &assets_pair[1]).ok()?;
+					Self::quote(&amount, &reserve_out, &reserve_in).ok()
+				})
 			}
 		}
 
@@ -1285,6 +1316,7 @@ impl<T: Config> Swap<T::AccountId, T::HigherPrecisionBalance, T::MultiAssetId> f
 sp_api::decl_runtime_apis! {
 	/// This runtime api allows people to query the size of the liquidity pools
 	/// and quote prices for swaps.
+	#[api_version(2)]
 	pub trait AssetConversionApi<Balance, AssetBalance, AssetId> where
 		Balance: Codec + MaybeDisplay,
 		AssetBalance: frame_support::traits::tokens::Balance,
@@ -1292,18 +1324,30 @@ sp_api::decl_runtime_apis! {
 	{
 		/// Provides a quote for [`Pallet::swap_tokens_for_exact_tokens`].
 		///
+		/// If there is no pool for the given pair, the swap is routed through the native asset.
+		///
 		/// Note that the price may have changed by the time the transaction is executed.
 		/// (Use `amount_in_max` to control slippage.)
 		fn quote_price_tokens_for_exact_tokens(asset1: AssetId, asset2: AssetId, amount: AssetBalance, include_fee: bool) -> Option<Balance>;
 
 		/// Provides a quote for [`Pallet::swap_exact_tokens_for_tokens`].
 		///
+		/// If there is no pool for the given pair, the swap is routed through the native asset.
+		///
 		/// Note that the price may have changed by the time the transaction is executed.
 		/// (Use `amount_out_min` to control slippage.)
 		fn quote_price_exact_tokens_for_tokens(asset1: AssetId, asset2: AssetId, amount: AssetBalance, include_fee: bool) -> Option<Balance>;
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Returns `true` if a pool exists for the given asset pair.
+		#[api_version(2)]
+		fn pool_exists(asset1: AssetId, asset2: AssetId) -> bool;
+
+		/// Returns the path a swap between the given assets would be routed through, if any.
+		#[api_version(2)]
+		fn find_path(asset1: AssetId, asset2: AssetId) -> Option<Vec<AssetId>>;
 	}
 }
 
@@ -21,7 +21,7 @@ use super::*;
 use crate as pallet_asset_conversion;
 
 use frame_support::{
-	construct_runtime,
+	construct_runtime, derive_impl,
 	instances::{Instance1, Instance2},
 	ord_parameter_types, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
@@ -48,6 +48,7 @@ construct_runtime!(
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -95,6 +96,7 @@ impl pallet_assets::Config<Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<5>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -119,6 +121,7 @@ impl pallet_assets::Config<Instance2> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<5>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -855,6 +855,97 @@ fn quote_price_tokens_for_exact_tokens_matches_execution() {
 	});
 }
 
+#[test]
+fn quote_price_routes_through_native_asset_without_direct_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let user2 = 2;
+		let token_1 = NativeOrAssetId::Native;
+		let token_2 = NativeOrAssetId::Asset(2);
+		let token_3 = NativeOrAssetId::Asset(3);
+
+		create_tokens(user, vec![token_2, token_3]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_2));
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(user), token_1, token_3));
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1000));
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_2,
+			10000,
+			200,
+			1,
+			1,
+			user,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			token_1,
+			token_3,
+			10000,
+			500,
+			1,
+			1,
+			user,
+		));
+
+		assert!(AssetConversion::pool_exists(token_2, token_1));
+		assert!(!AssetConversion::pool_exists(token_2, token_3));
+		assert_eq!(
+			AssetConversion::find_path(token_2, token_3).map(Into::<Vec<_>>::into),
+			Some(vec![token_2, token_1, token_3])
+		);
+
+		// without fees the quote is the product of the spot prices of both hops
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(token_2, token_3, 10, false),
+			Some(25)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(token_2, token_3, 25, false),
+			Some(10)
+		);
+
+		// with fees the quote matches the execution of the routed swap
+		let amount = 10;
+		let quoted_price =
+			AssetConversion::quote_price_exact_tokens_for_tokens(token_2, token_3, amount, true)
+				.unwrap();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user2, amount));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user2),
+			bvec![token_2, token_1, token_3],
+			amount,
+			1,
+			user2,
+			false,
+		));
+		assert_eq!(balance(user2, token_3), quoted_price);
+
+		let quoted_amount_in =
+			AssetConversion::quote_price_tokens_for_exact_tokens(token_3, token_2, 5, true);
+		assert_eq!(
+			quoted_amount_in,
+			AssetConversion::get_amounts_in(&5, &bvec![token_3, token_1, token_2])
+				.ok()
+				.and_then(|amounts| amounts.first().copied())
+		);
+
+		// there is no route if one of the legs is missing
+		let token_4 = NativeOrAssetId::Asset(4);
+		create_tokens(user, vec![token_4]);
+		assert_eq!(AssetConversion::find_path(token_2, token_4), None);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(token_2, token_4, 10, true),
+			None
+		);
+	});
+}
+
 #[test]
 fn can_swap_with_native() {
 	new_test_ext().execute_with(|| {
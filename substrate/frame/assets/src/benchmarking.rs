@@ -125,6 +125,24 @@ fn add_approvals<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 	}
 }
 
+fn add_role_accounts<T: Config<I>, I: 'static>(
+	owner: T::AccountId,
+	role: AssetRole,
+	n: u32,
+) -> Vec<T::AccountId> {
+	let asset_id = default_asset_id::<T, I>();
+	let origin = SystemOrigin::Signed(owner);
+	(0..n)
+		.map(|i| {
+			let who: T::AccountId = account("role", i, SEED);
+			let who_lookup = T::Lookup::unlookup(who.clone());
+			assert!(Assets::<T, I>::grant_role(origin.clone().into(), asset_id, role, who_lookup)
+				.is_ok());
+			who
+		})
+		.collect()
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -219,8 +237,12 @@ benchmarks_instance_pallet! {
 
 	mint {
 		let (asset_id, caller, caller_lookup) = create_default_asset::<T, I>(true);
+		// Worst case: the sender is the last of the minters rather than the issuer.
+		let minter = add_role_accounts::<T, I>(caller.clone(), AssetRole::Minter, T::MaxRoleAccounts::get())
+			.pop()
+			.unwrap();
 		let amount = T::Balance::from(100u32);
-	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, amount)
+	}: _(SystemOrigin::Signed(minter), asset_id, caller_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Issued { asset_id: asset_id.into(), owner: caller, amount }.into());
 	}
@@ -228,7 +250,11 @@ benchmarks_instance_pallet! {
 	burn {
 		let amount = T::Balance::from(100u32);
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
-	}: _(SystemOrigin::Signed(caller.clone()), asset_id, caller_lookup, amount)
+		// Worst case: the sender is the last of the burners rather than the admin.
+		let burner = add_role_accounts::<T, I>(caller.clone(), AssetRole::Burner, T::MaxRoleAccounts::get())
+			.pop()
+			.unwrap();
+	}: _(SystemOrigin::Signed(burner), asset_id, caller_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Burned { asset_id: asset_id.into(), owner: caller, balance: amount }.into());
 	}
@@ -255,6 +281,16 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
 	}
 
+	transfer_all {
+		let mint_amount = T::Balance::from(200u32);
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, mint_amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, target_lookup, false)
+	verify {
+		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount: mint_amount }.into());
+	}
+
 	force_transfer {
 		let amount = T::Balance::from(100u32);
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
@@ -548,5 +584,26 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Blocked { asset_id: asset_id.into(), who: caller }.into());
 	}
 
+	grant_role {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		add_role_accounts::<T, I>(caller.clone(), AssetRole::Minter, T::MaxRoleAccounts::get() - 1);
+		let who: T::AccountId = account("grantee", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+	}: _(SystemOrigin::Signed(caller), asset_id, AssetRole::Minter, who_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::RoleGranted { asset_id: asset_id.into(), role: AssetRole::Minter, who }.into());
+	}
+
+	revoke_role {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let who = add_role_accounts::<T, I>(caller.clone(), AssetRole::Minter, T::MaxRoleAccounts::get())
+			.pop()
+			.unwrap();
+		let who_lookup = T::Lookup::unlookup(who.clone());
+	}: _(SystemOrigin::Signed(caller), asset_id, AssetRole::Minter, who_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::RoleRevoked { asset_id: asset_id.into(), role: AssetRole::Minter, who }.into());
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
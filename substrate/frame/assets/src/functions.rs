@@ -412,7 +412,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		Self::increase_balance(id.clone(), beneficiary, amount, |details| -> DispatchResult {
 			if let Some(check_issuer) = maybe_check_issuer {
-				ensure!(check_issuer == details.issuer, Error::<T, I>::NoPermission);
+				ensure!(
+					check_issuer == details.issuer ||
+						Minters::<T, I>::get(&id).contains(&check_issuer),
+					Error::<T, I>::NoPermission
+				);
 			}
 			debug_assert!(details.supply.checked_add(&amount).is_some(), "checked in prep; qed");
 
@@ -497,7 +501,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let actual = Self::decrease_balance(id.clone(), target, amount, f, |actual, details| {
 			// Check admin rights.
 			if let Some(check_admin) = maybe_check_admin {
-				ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+				ensure!(
+					check_admin == details.admin ||
+						Burners::<T, I>::get(&id).contains(&check_admin),
+					Error::<T, I>::NoPermission
+				);
 			}
 
 			debug_assert!(details.supply >= actual, "checked in prep; qed");
@@ -688,6 +696,46 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok((credit, source_died))
 	}
 
+	/// Reduces the asset `id` balance of `source` by `amount` and increases the balance of `dest`
+	/// accordingly, requiring `source` to stay alive only where this is needed.
+	///
+	/// This is the case if some of the balance of `source` is frozen, as the account cannot be
+	/// reaped then. Otherwise `source` may be reaped, if `amount` is all it has.
+	///
+	/// Returns the actual amount placed into `dest`.
+	pub(super) fn transfer_keep_alive_if_needed(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let keep_alive = T::Freezer::frozen_balance(id.clone(), source).is_some();
+		let f = TransferFlags { keep_alive, best_effort: false, burn_dust: false };
+		Self::do_transfer(id, source, dest, amount, None, f)
+	}
+
+	/// Transfers the entire reducible asset `id` balance of `source` to `dest`.
+	///
+	/// Frozen balances and the minimum balance of the asset are honored, such that no dust
+	/// calculation is needed on the caller's side. If `keep_alive` is `true`, `source` will retain
+	/// at least the minimum balance.
+	///
+	/// Returns the actual amount placed into `dest`.
+	pub(super) fn do_transfer_all(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let amount = Self::reducible_balance(id.clone(), source, keep_alive)?;
+		if keep_alive {
+			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
+			Self::do_transfer(id, source, dest, amount, None, f)
+		} else {
+			Self::transfer_keep_alive_if_needed(id, source, dest, amount)
+		}
+	}
+
 	/// Create a new asset without taking a deposit.
 	///
 	/// * `id`: The `AssetId` you want the new asset to have. Must not already be in use.
@@ -847,12 +895,76 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				&details.owner,
 				details.deposit.saturating_add(metadata.deposit),
 			);
+			Minters::<T, I>::remove(&id);
+			Burners::<T, I>::remove(&id);
 			Self::deposit_event(Event::Destroyed { asset_id: id });
 
 			Ok(())
 		})
 	}
 
+	/// Grants `who` the additional `role` for the asset `id`.
+	///
+	/// If `maybe_check_owner` is `Some`, it must be the owner of the asset.
+	pub(super) fn do_grant_role(
+		id: T::AssetId,
+		role: AssetRole,
+		who: T::AccountId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(check_owner == details.owner, Error::<T, I>::NoPermission);
+		}
+
+		let grant = |accounts: &mut BoundedVec<T::AccountId, T::MaxRoleAccounts>| {
+			ensure!(!accounts.contains(&who), Error::<T, I>::AlreadyHasRole);
+			accounts.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyRoleAccounts)?;
+			Ok::<_, DispatchError>(())
+		};
+		match role {
+			AssetRole::Minter => Minters::<T, I>::try_mutate(&id, grant)?,
+			AssetRole::Burner => Burners::<T, I>::try_mutate(&id, grant)?,
+		}
+
+		Self::deposit_event(Event::RoleGranted { asset_id: id, role, who });
+		Ok(())
+	}
+
+	/// Revokes the additional `role` of `who` for the asset `id`.
+	///
+	/// If `maybe_check_owner` is `Some`, it must be the owner of the asset.
+	pub(super) fn do_revoke_role(
+		id: T::AssetId,
+		role: AssetRole,
+		who: T::AccountId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(check_owner == details.owner, Error::<T, I>::NoPermission);
+		}
+
+		let revoke = |maybe_accounts: &mut Option<BoundedVec<T::AccountId, T::MaxRoleAccounts>>| {
+			let accounts = maybe_accounts.as_mut().ok_or(Error::<T, I>::MissingRole)?;
+			let index =
+				accounts.iter().position(|a| a == &who).ok_or(Error::<T, I>::MissingRole)?;
+			accounts.remove(index);
+			if accounts.is_empty() {
+				*maybe_accounts = None;
+			}
+			Ok::<_, DispatchError>(())
+		};
+		match role {
+			AssetRole::Minter => Minters::<T, I>::try_mutate_exists(&id, revoke)?,
+			AssetRole::Burner => Burners::<T, I>::try_mutate_exists(&id, revoke)?,
+		}
+
+		Self::deposit_event(Event::RoleRevoked { asset_id: id, role, who });
+		Ok(())
+	}
+
 	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
 	/// while reserving `T::ApprovalDeposit` from owner
 	///
@@ -47,6 +47,10 @@
 //!   a privileged operation.
 //! * **Fungible asset**: An asset whose units are interchangeable.
 //! * **Issuer**: An account ID uniquely privileged to be able to mint a particular class of assets.
+//! * **Minter**: An additional account ID granted by the Owner the right to mint a particular class
+//!   of assets, next to the Issuer.
+//! * **Burner**: An additional account ID granted by the Owner the right to burn a particular class
+//!   of assets, next to the Admin.
 //! * **Freezer**: An account ID uniquely privileged to be able to freeze an account from
 //!   transferring a particular class of assets.
 //! * **Freezing**: Removing the possibility of an unpermissioned transfer of an asset from a
@@ -79,6 +83,7 @@
 //! * `create`: Creates a new asset class, taking the required deposit.
 //! * `transfer`: Transfer sender's assets to another account.
 //! * `transfer_keep_alive`: Transfer sender's assets to another account, keeping the sender alive.
+//! * `transfer_all`: Transfer all of the sender's transferable assets to another account.
 //! * `approve_transfer`: Create or increase an delegated transfer.
 //! * `cancel_approval`: Rescind a previous approval.
 //! * `transfer_approved`: Transfer third-party's assets to another account.
@@ -98,14 +103,20 @@
 //! ### Privileged Functions
 //!
 //! * `destroy`: Destroys an entire asset class; called by the asset class's Owner.
-//! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer.
-//! * `burn`: Decreases the asset balance of an account; called by the asset class's Admin.
+//! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer or one
+//!   of its Minters.
+//! * `burn`: Decreases the asset balance of an account; called by the asset class's Admin or one of
+//!   its Burners.
 //! * `force_transfer`: Transfers between arbitrary accounts; called by the asset class's Admin.
 //! * `freeze`: Disallows further `transfer`s from an account; called by the asset class's Freezer.
 //! * `thaw`: Allows further `transfer`s to and from an account; called by the asset class's Admin.
 //! * `transfer_ownership`: Changes an asset class's Owner; called by the asset class's Owner.
 //! * `set_team`: Changes an asset class's Admin, Freezer and Issuer; called by the asset class's
 //!   Owner.
+//! * `grant_role`: Adds an account to an asset class's Minters or Burners; called by the asset
+//!   class's Owner.
+//! * `revoke_role`: Removes an account from an asset class's Minters or Burners; called by the
+//!   asset class's Owner.
 //! * `set_metadata`: Set the metadata of an asset class; called by the asset class's Owner.
 //! * `clear_metadata`: Remove the metadata of an asset class; called by the asset class's Owner.
 //! * `touch_other`: Create an asset account for specified account. Caller must place a deposit;
@@ -249,6 +260,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type RemoveItemsLimit: Get<u32>;
 
+		/// Max number of additional minters, and separately burners, of a single asset class.
+		#[pallet::constant]
+		type MaxRoleAccounts: Get<u32>;
+
 		/// Identifier for the class of asset.
 		type AssetId: Member + Parameter + Clone + MaybeSerializeDeserialize + MaxEncodedLen;
 
@@ -368,6 +383,26 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// Accounts allowed to mint an asset, in addition to its issuer.
+	pub(super) type Minters<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<T::AccountId, T::MaxRoleAccounts>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Accounts allowed to burn an asset, in addition to its admin.
+	pub(super) type Burners<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<T::AccountId, T::MaxRoleAccounts>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -524,6 +559,10 @@ pub mod pallet {
 		Touched { asset_id: T::AssetId, who: T::AccountId, depositor: T::AccountId },
 		/// Some account `who` was blocked.
 		Blocked { asset_id: T::AssetId, who: T::AccountId },
+		/// Some account `who` was granted the `role` by the asset owner.
+		RoleGranted { asset_id: T::AssetId, role: AssetRole, who: T::AccountId },
+		/// Some account `who` had the `role` revoked by the asset owner.
+		RoleRevoked { asset_id: T::AssetId, role: AssetRole, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -571,6 +610,12 @@ pub mod pallet {
 		NotFrozen,
 		/// Callback action resulted in error
 		CallbackFailed,
+		/// The account already has the given role.
+		AlreadyHasRole,
+		/// The account does not have the given role.
+		MissingRole,
+		/// The asset already has the maximum number of accounts with the given role.
+		TooManyRoleAccounts,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -759,7 +804,8 @@ pub mod pallet {
 
 		/// Mint assets of a particular class.
 		///
-		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		/// The origin must be Signed and the sender must be the Issuer or one of the Minters of the
+		/// asset `id`.
 		///
 		/// - `id`: The identifier of the asset to have some amount minted.
 		/// - `beneficiary`: The account to be credited with the minted assets.
@@ -785,7 +831,8 @@ pub mod pallet {
 
 		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
 		///
-		/// Origin must be Signed and the sender should be the Manager of the asset `id`.
+		/// Origin must be Signed and the sender should be the Manager or one of the Burners of the
+		/// asset `id`.
 		///
 		/// Bails with `NoAccount` if the `who` is already dead.
 		///
@@ -1636,6 +1683,85 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Blocked { asset_id: id, who });
 			Ok(())
 		}
+
+		/// Transfer the entire transferable balance from the caller asset account.
+		///
+		/// NOTE: This function only attempts to transfer _transferable_ balances. This means that
+		/// any held, frozen, or minimum balance (when `keep_alive` is `true`), will not be
+		/// transferred by this function. To ensure that this function results in a killed account,
+		/// you might need to prepare the account by removing any reference counters, storage
+		/// deposits, etc...
+		///
+		/// The dispatch origin of this call must be Signed.
+		///
+		/// - `id`: The identifier of the asset for the account holding a deposit.
+		/// - `dest`: The recipient of the transfer.
+		/// - `keep_alive`: A boolean to determine if the `transfer_all` operation should send all
+		///   of the funds the asset account has, causing the sender asset account to be killed
+		///   (false), or transfer everything except at least the minimum balance, which will
+		///   guarantee to keep the sender asset account alive (true).
+		#[pallet::call_index(32)]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			dest: AccountIdLookupOf<T>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let id: T::AssetId = id.into();
+
+			Self::do_transfer_all(id, &transactor, &dest, keep_alive).map(|_| ())
+		}
+
+		/// Grant an account an additional role for an asset, e.g. to let a bridge or an issuer's
+		/// hot key mint without sharing the Issuer's key.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `role`: The role to grant.
+		/// - `who`: The account to be granted the role.
+		///
+		/// Emits `RoleGranted`.
+		///
+		/// Weight: `O(MaxRoleAccounts)`
+		#[pallet::call_index(33)]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			role: AssetRole,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::do_grant_role(id, role, who, Some(origin))
+		}
+
+		/// Revoke an additional role of an account for an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `role`: The role to revoke.
+		/// - `who`: The account to have the role revoked.
+		///
+		/// Emits `RoleRevoked`.
+		///
+		/// Weight: `O(MaxRoleAccounts)`
+		#[pallet::call_index(34)]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			role: AssetRole,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::do_revoke_role(id, role, who, Some(origin))
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
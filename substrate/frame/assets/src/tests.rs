@@ -716,6 +716,62 @@ fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 	});
 }
 
+#[test]
+fn transfer_all_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+
+		// keep alive leaves the minimum balance behind.
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(1), 0, 3, true));
+		assert_eq!(Assets::balance(0, 1), 10);
+		assert_eq!(Assets::balance(0, 3), 90);
+		assert!(hooks().is_empty());
+
+		// otherwise the account is emptied and reaped.
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(2), 0, 3, false));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::balance(0, 3), 190);
+		assert!(!Account::<Test>::contains_key(0, 2));
+		assert_eq!(hooks(), vec![Hook::Died(0, 2)]);
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 2,
+			to: 3,
+			amount: 100,
+		}));
+	});
+}
+
+#[test]
+fn transfer_all_respects_frozen_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// freeze 50 of it.
+		set_frozen_balance(0, 1, 50);
+
+		// the account cannot be reaped, so the frozen and the minimum balance stay behind.
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(1), 0, 2, false));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::balance(0, 2), 40);
+		assert!(hooks().is_empty());
+
+		// nothing left to transfer is fine.
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(1), 0, 2, true));
+		assert_eq!(Assets::balance(0, 1), 60);
+
+		// frozen accounts cannot transfer at all.
+		assert_ok!(Assets::freeze(RuntimeOrigin::signed(1), 0, 1));
+		assert_noop!(
+			Assets::transfer_all(RuntimeOrigin::signed(1), 0, 2, false),
+			Error::<Test>::Frozen
+		);
+	});
+}
+
 #[test]
 fn transferring_frozen_user_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -827,6 +883,71 @@ fn origin_guards_should_work() {
 	});
 }
 
+#[test]
+fn minters_and_burners_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// only the owner can manage roles.
+		assert_noop!(
+			Assets::grant_role(RuntimeOrigin::signed(2), 0, AssetRole::Minter, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::grant_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RoleGranted {
+			asset_id: 0,
+			role: AssetRole::Minter,
+			who: 2,
+		}));
+		assert_noop!(
+			Assets::grant_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 2),
+			Error::<Test>::AlreadyHasRole
+		);
+
+		// a minter can mint, but not burn.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(2), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(2), 0, 1, 10), Error::<Test>::NoPermission);
+
+		// a burner can burn, but not mint.
+		assert_ok!(Assets::grant_role(RuntimeOrigin::signed(1), 0, AssetRole::Burner, 3));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(3), 0, 1, 10));
+		assert_eq!(Assets::balance(0, 1), 90);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(3), 0, 3, 10), Error::<Test>::NoPermission);
+
+		// the number of accounts per role is bounded.
+		assert_ok!(Assets::grant_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 3));
+		assert_noop!(
+			Assets::grant_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 4),
+			Error::<Test>::TooManyRoleAccounts
+		);
+
+		// revoked minters can no longer mint.
+		assert_ok!(Assets::revoke_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 2));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RoleRevoked {
+			asset_id: 0,
+			role: AssetRole::Minter,
+			who: 2,
+		}));
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(2), 0, 2, 50), Error::<Test>::NoPermission);
+		assert_noop!(
+			Assets::revoke_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 2),
+			Error::<Test>::MissingRole
+		);
+
+		// roles are cleaned up when the asset is destroyed.
+		assert_ok!(Assets::revoke_role(RuntimeOrigin::signed(1), 0, AssetRole::Minter, 3));
+		assert!(!Minters::<Test>::contains_key(0));
+		assert!(Burners::<Test>::contains_key(0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_approvals(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert!(!Burners::<Test>::contains_key(0));
+	});
+}
+
 #[test]
 fn transfer_owner_should_work() {
 	new_test_ext().execute_with(|| {
@@ -48,6 +48,16 @@ pub(super) enum AssetStatus {
 	Destroying,
 }
 
+/// An additional role which the owner of an asset can grant to any number of accounts, up to
+/// `MaxRoleAccounts`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum AssetRole {
+	/// Can mint tokens, like the issuer.
+	Minter,
+	/// Can burn tokens from any account, like the admin.
+	Burner,
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
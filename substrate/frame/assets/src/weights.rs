@@ -62,6 +62,7 @@ pub trait WeightInfo {
 	fn burn() -> Weight;
 	fn transfer() -> Weight;
 	fn transfer_keep_alive() -> Weight;
+	fn transfer_all() -> Weight;
 	fn force_transfer() -> Weight;
 	fn freeze() -> Weight;
 	fn thaw() -> Weight;
@@ -84,6 +85,8 @@ pub trait WeightInfo {
 	fn refund() -> Weight;
 	fn refund_other() -> Weight;
 	fn block() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
 }
 
 /// Weights for pallet_assets using the Substrate node and recommended hardware.
@@ -181,26 +184,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Minters (r:1 w:0)
+	/// Proof: Assets Minters (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3786`
 		// Minimum execution time: 26_653_000 picoseconds.
-		Weight::from_parts(27_260_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(27_260_000, 3786)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Burners (r:1 w:0)
+	/// Proof: Assets Burners (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3786`
 		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(34_474_000, 3786)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -525,6 +532,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `6208`
+		// Minimum execution time: 47_609_000 picoseconds.
+		Weight::from_parts(48_476_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Minters (r:1 w:1)
+	/// Proof: Assets Minters (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `3786`
+		// Minimum execution time: 17_367_000 picoseconds.
+		Weight::from_parts(18_011_000, 3786)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Minters (r:1 w:1)
+	/// Proof: Assets Minters (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `3786`
+		// Minimum execution time: 17_397_000 picoseconds.
+		Weight::from_parts(18_041_000, 3786)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -621,26 +669,30 @@ impl WeightInfo for () {
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Minters (r:1 w:0)
+	/// Proof: Assets Minters (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `3675`
+		//  Estimated: `3786`
 		// Minimum execution time: 26_653_000 picoseconds.
-		Weight::from_parts(27_260_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		Weight::from_parts(27_260_000, 3786)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Assets Burners (r:1 w:0)
+	/// Proof: Assets Burners (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `459`
-		//  Estimated: `3675`
+		//  Estimated: `3786`
 		// Minimum execution time: 33_625_000 picoseconds.
-		Weight::from_parts(34_474_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		Weight::from_parts(34_474_000, 3786)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Assets Asset (r:1 w:1)
@@ -965,4 +1017,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_all() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `6208`
+		// Minimum execution time: 47_609_000 picoseconds.
+		Weight::from_parts(48_476_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Minters (r:1 w:1)
+	/// Proof: Assets Minters (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `3786`
+		// Minimum execution time: 17_367_000 picoseconds.
+		Weight::from_parts(18_011_000, 3786)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Minters (r:1 w:1)
+	/// Proof: Assets Minters (max_values: None, max_size: Some(321), added: 2796, mode: MaxEncodedLen)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `3786`
+		// Minimum execution time: 17_397_000 picoseconds.
+		Weight::from_parts(18_041_000, 3786)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
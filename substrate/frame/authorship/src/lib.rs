@@ -17,7 +17,9 @@
 
 //! Authorship tracking for FRAME runtimes.
 //!
-//! This tracks the current author of the block.
+//! This tracks the current author of the block. The author is noted in
+//! [`frame_system::BlockAuthor`], where other pallets and the `BlockAuthorApi` runtime API can
+//! read it.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -60,16 +62,7 @@ pub mod pallet {
 
 			Weight::zero()
 		}
-
-		fn on_finalize(_: BlockNumberFor<T>) {
-			// ensure we never go to trie with these values.
-			<Author<T>>::kill();
-		}
 	}
-
-	#[pallet::storage]
-	/// Author of current block.
-	pub(super) type Author<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -78,15 +71,15 @@ impl<T: Config> Pallet<T> {
 	/// This is safe to invoke in `on_initialize` implementations, as well
 	/// as afterwards.
 	pub fn author() -> Option<T::AccountId> {
-		// Check the memorized storage value.
-		if let Some(author) = <Author<T>>::get() {
+		// Check the author noted in the system pallet.
+		if let Some(author) = <frame_system::Pallet<T>>::block_author() {
 			return Some(author)
 		}
 
 		let digest = <frame_system::Pallet<T>>::digest();
 		let pre_runtime_digests = digest.logs.iter().filter_map(|d| d.as_pre_runtime());
 		T::FindAuthor::find_author(pre_runtime_digests).map(|a| {
-			<Author<T>>::put(&a);
+			<frame_system::Pallet<T>>::note_block_author(a.clone());
 			a
 		})
 	}
@@ -173,6 +166,22 @@ mod tests {
 			System::initialize(&1, &Default::default(), header.digest());
 
 			assert_eq!(Authorship::author(), Some(author));
+			assert_eq!(System::block_author(), Some(author));
+		});
+	}
+
+	#[test]
+	fn author_is_reset_for_the_next_block() {
+		new_test_ext().execute_with(|| {
+			let mut header = seal_header(create_header(1, Default::default(), [1; 32].into()), 42);
+			header.digest_mut().pop(); // pop the seal off.
+			System::initialize(&1, &Default::default(), header.digest());
+			assert_eq!(Authorship::author(), Some(42));
+
+			// The next block has no author.
+			System::initialize(&2, &Default::default(), &Default::default());
+			assert_eq!(Authorship::author(), None);
+			assert_eq!(System::block_author(), None);
 		});
 	}
 }
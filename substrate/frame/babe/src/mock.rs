@@ -189,10 +189,18 @@ impl pallet_staking::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const OffencesMaxProcessingWeight: frame_support::weights::Weight =
+		frame_support::weights::Weight::MAX;
+}
+
 impl pallet_offences::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = ConstU32<100>;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -234,6 +242,7 @@ pub fn go_to_block(n: u64, s: u64) {
 	System::initialize(&n, &parent_hash, &pre_digest);
 
 	Babe::on_initialize(n);
+	Offences::on_initialize(n);
 	Session::on_initialize(n);
 	Staking::on_initialize(n);
 }
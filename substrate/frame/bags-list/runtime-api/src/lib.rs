@@ -0,0 +1,36 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for bags-list pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for querying the nodes of a bags-list which are out of place.
+	pub trait BagsListApi<AccountId, Score>
+		where
+			AccountId: Codec,
+			Score: Codec,
+	{
+		/// Returns up to `limit` nodes which are not in the bag of their current score, with their
+		/// stored and current score, the most drifted first.
+		fn most_out_of_place(limit: u32) -> Vec<(AccountId, Score, Score)>;
+	}
+}
@@ -49,6 +49,10 @@
 //! within a bag must be within the range of the bag. If not, the permissionless [`Pallet::rebag`]
 //! can be used to move any node to the right bag.
 //!
+//! Rebagging is also exposed as a task (see `#[pallet::tasks_experimental]`): anyone can run the
+//! [`Pallet::rebag_misplaced`] task of an out-of-place node through `frame_system::Call::do_task`,
+//! bounded to [`Config::MaxAutoRebagPerBlock`] nodes per block.
+//!
 //! Once a `rebag` happens, the order within a node is still not enforced. To move a node to the
 //! optimal position in a bag, the [`Pallet::put_in_front_of`] or [`Pallet::put_in_front_of_other`]
 //! can be used.
@@ -245,6 +249,11 @@ pub mod pallet {
 			+ TypeInfo
 			+ FullCodec
 			+ MaxEncodedLen;
+
+		/// The maximum number of nodes that can be rebagged through the
+		/// [`Pallet::rebag_misplaced`] task in a single block.
+		#[pallet::constant]
+		type MaxAutoRebagPerBlock: Get<u32>;
 	}
 
 	/// A single node, within some bag.
@@ -261,6 +270,12 @@ pub mod pallet {
 	pub(crate) type ListBags<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::Score, list::Bag<T, I>>;
 
+	/// The block in which the last [`Pallet::rebag_misplaced`] task ran, and the number of such
+	/// tasks that ran in that block.
+	#[pallet::storage]
+	pub(crate) type AutoRebagCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -268,6 +283,9 @@ pub mod pallet {
 		Rebagged { who: T::AccountId, from: T::Score, to: T::Score },
 		/// Updated the score of some account to the given amount.
 		ScoreUpdated { who: T::AccountId, new_score: T::Score },
+		/// An out-of-place account was rebagged by a task, its score having drifted from
+		/// `old_score` to `new_score` since it was last updated.
+		AutoRebagged { who: T::AccountId, old_score: T::Score, new_score: T::Score },
 	}
 
 	#[pallet::error]
@@ -275,6 +293,8 @@ pub mod pallet {
 	pub enum Error<T, I = ()> {
 		/// A error in the list interface implementation.
 		List(ListError),
+		/// The node is not in the list or is already in the bag of its current score.
+		NotMisplaced,
 	}
 
 	impl<T, I> From<ListError> for Error<T, I> {
@@ -348,6 +368,39 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::tasks_experimental]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Move the out-of-place `node` into the bag matching its current score, as reported by
+		/// `ScoreProvider`.
+		///
+		/// Valid as long as `node` is misplaced and fewer than [`Config::MaxAutoRebagPerBlock`]
+		/// nodes have been rebagged by this task in the current block.
+		#[pallet::task_list(Pallet::<T, I>::misplaced_nodes())]
+		#[pallet::task_condition(|node| {
+			Pallet::<T, I>::auto_rebag_count() < T::MaxAutoRebagPerBlock::get() &&
+				Pallet::<T, I>::score_drift(node).is_some()
+		})]
+		#[pallet::task_weight(
+			T::WeightInfo::rebag_non_terminal()
+				.max(T::WeightInfo::rebag_terminal())
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		#[pallet::task_index(0)]
+		pub fn rebag_misplaced(node: T::AccountId) -> DispatchResult {
+			let (old_score, new_score) =
+				Self::score_drift(&node).ok_or(Error::<T, I>::NotMisplaced)?;
+			let count = Self::auto_rebag_count();
+			AutoRebagCount::<T, I>::put((
+				frame_system::Pallet::<T>::block_number(),
+				count.saturating_add(1),
+			));
+
+			Self::do_rebag(&node, new_score).map_err::<Error<T, I>, _>(Into::into)?;
+			Self::deposit_event(Event::<T, I>::AutoRebagged { who: node, old_score, new_score });
+			Ok(())
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		fn integrity_test() {
@@ -390,6 +443,47 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(maybe_movement)
 	}
 
+	/// The number of nodes rebagged by the [`Pallet::rebag_misplaced`] task in the current block.
+	pub fn auto_rebag_count() -> u32 {
+		let (block, count) = AutoRebagCount::<T, I>::get();
+		if block == frame_system::Pallet::<T>::block_number() {
+			count
+		} else {
+			0
+		}
+	}
+
+	/// If `id` is in the list but not in the bag of its current score, returns its stored score
+	/// and its current score, as reported by `ScoreProvider`.
+	pub fn score_drift(id: &T::AccountId) -> Option<(T::Score, T::Score)> {
+		let node = list::Node::<T, I>::get(id)?;
+		let current_score = T::ScoreProvider::score(id);
+		node.is_misplaced(current_score).then(|| (node.score(), current_score))
+	}
+
+	/// All the nodes which are not in the bag of their current score.
+	///
+	/// Iterates over the whole list, so it should only be used off-chain.
+	pub fn misplaced_nodes() -> Vec<T::AccountId> {
+		ListNodes::<T, I>::iter_keys()
+			.filter(|id| Self::score_drift(id).is_some())
+			.collect()
+	}
+
+	/// The `limit` most out-of-place nodes, with their stored and current score, ordered by the
+	/// absolute difference between the two, greatest first.
+	///
+	/// Iterates over the whole list, so it should only be used off-chain.
+	pub fn most_out_of_place(limit: u32) -> Vec<(T::AccountId, T::Score, T::Score)> {
+		let mut drifted = ListNodes::<T, I>::iter_keys()
+			.filter_map(|id| Self::score_drift(&id).map(|(old, new)| (id, old, new)))
+			.collect::<Vec<_>>();
+		drifted
+			.sort_by_key(|(_, old, new)| sp_std::cmp::Reverse((*old).max(*new) - (*old).min(*new)));
+		drifted.truncate(limit as usize);
+		drifted
+	}
+
 	/// Equivalent to `ListBags::get`, but public. Useful for tests in outside of this crate.
 	#[cfg(feature = "std")]
 	pub fn list_bags_get(score: T::Score) -> Option<list::Bag<T, I>> {
@@ -56,6 +56,7 @@ impl frame_system::Config for Runtime {
 
 parameter_types! {
 	pub static BagThresholds: &'static [VoteWeight] = &[10, 20, 30, 40, 50, 60, 1_000, 2_000, 10_000];
+	pub static MaxAutoRebagPerBlock: u32 = 2;
 }
 
 impl bags_list::Config for Runtime {
@@ -64,6 +65,7 @@ impl bags_list::Config for Runtime {
 	type BagThresholds = BagThresholds;
 	type ScoreProvider = StakingMock;
 	type Score = VoteWeight;
+	type MaxAutoRebagPerBlock = MaxAutoRebagPerBlock;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -71,7 +73,7 @@ frame_support::construct_runtime!(
 	pub struct Runtime
 	{
 		System: frame_system::{Pallet, Call, Storage, Event<T>, Config<T>},
-		BagsList: bags_list::{Pallet, Call, Storage, Event<T>},
+		BagsList: bags_list::{Pallet, Call, Storage, Event<T>, Task},
 	}
 );
 
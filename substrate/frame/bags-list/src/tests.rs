@@ -15,7 +15,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::IntegrityTest};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok, assert_storage_noop,
+	traits::{IntegrityTest, Task as _},
+};
 
 use super::*;
 use frame_election_provider_support::{SortedListProvider, VoteWeight};
@@ -227,6 +231,65 @@ mod pallet {
 		});
 	}
 
+	#[test]
+	fn rebag_misplaced_task_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			System::set_block_number(1);
+			let task =
+				|node| -> RuntimeTask { crate::Task::<Runtime>::RebagMisplaced { node }.into() };
+
+			// given
+			assert!(BagsList::misplaced_nodes().is_empty());
+			assert!(!task(2).is_valid());
+
+			// when the scores of 2, 3 and 4 drift out of their bag
+			StakingMock::set_score_of(&2, 10);
+			StakingMock::set_score_of(&3, 20);
+			StakingMock::set_score_of(&4, 2_000);
+
+			// then they are listed as tasks, and the most drifted nodes are reported first
+			let mut misplaced = BagsList::misplaced_nodes();
+			misplaced.sort();
+			assert_eq!(misplaced, vec![2, 3, 4]);
+			let mut tasks = RuntimeTask::iter().collect::<Vec<_>>();
+			tasks.sort_by_key(|task| task.encode());
+			assert_eq!(tasks, vec![task(2), task(3), task(4)]);
+			assert_eq!(BagsList::most_out_of_place(2), vec![(4, 1_000, 2_000), (2, 1_000, 10)]);
+
+			// when running the tasks up to the per-block limit
+			assert_ok!(System::do_task(RuntimeOrigin::signed(0), task(4)));
+			System::assert_last_event(
+				Event::AutoRebagged { who: 4, old_score: 1_000, new_score: 2_000 }.into(),
+			);
+			assert_ok!(System::do_task(RuntimeOrigin::signed(0), task(2)));
+			assert_eq!(BagsList::auto_rebag_count(), 2);
+
+			// then the nodes are rebagged, but no more tasks can run in this block
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1, 2]), (1_000, vec![3]), (2_000, vec![4])]
+			);
+			assert!(!task(3).is_valid());
+			assert_noop!(
+				System::do_task(RuntimeOrigin::signed(0), task(3)),
+				frame_system::Error::<Runtime>::InvalidTask
+			);
+
+			// when moving to the next block
+			System::set_block_number(2);
+
+			// then the remaining task can run
+			assert_eq!(BagsList::auto_rebag_count(), 0);
+			assert_ok!(System::do_task(RuntimeOrigin::signed(0), task(3)));
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1, 2]), (20, vec![3]), (2_000, vec![4])]
+			);
+			assert!(BagsList::misplaced_nodes().is_empty());
+			assert_eq!(task(3).run(), Err(Error::<Runtime>::NotMisplaced.into()));
+		});
+	}
+
 	#[test]
 	#[should_panic = "thresholds must strictly increase, and have no duplicates"]
 	fn duplicate_in_bags_threshold_panics() {
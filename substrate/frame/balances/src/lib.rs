@@ -92,6 +92,8 @@
 //! - `transfer_allow_death` - Transfer some liquid free balance to another account.
 //! - `force_set_balance` - Set the balances of a given account. The origin of this call must be
 //!   root.
+//! - `force_adjust_total_issuance` - Increase or decrease the recorded total issuance, e.g. to
+//!   repair a drift caused by a historical bug. The origin of this call must be root.
 //!
 //! ## Usage
 //!
@@ -192,7 +194,8 @@ use sp_runtime::{
 };
 use sp_std::{cmp, fmt::Debug, mem, prelude::*, result};
 pub use types::{
-	AccountData, BalanceLock, DustCleaner, ExtraFlags, IdAmount, Reasons, ReserveData,
+	AccountData, AdjustmentDirection, AdjustmentReason, BalanceLock, DustCleaner, ExtraFlags,
+	IdAmount, Reasons, ReserveData, MAX_ADJUSTMENT_REASON_LEN,
 };
 pub use weights::WeightInfo;
 
@@ -386,6 +389,8 @@ pub mod pallet {
 		Frozen { who: T::AccountId, amount: T::Balance },
 		/// Some balance was thawed.
 		Thawed { who: T::AccountId, amount: T::Balance },
+		/// The total issuance was forcefully changed.
+		TotalIssuanceForced { old: T::Balance, new: T::Balance, reason: AdjustmentReason },
 	}
 
 	#[pallet::error]
@@ -410,6 +415,10 @@ pub mod pallet {
 		TooManyHolds,
 		/// Number of freezes exceed `MaxFreezes`.
 		TooManyFreezes,
+		/// The issuance cannot be modified since it is already deactivated.
+		IssuanceDeactivated,
+		/// The delta cannot be zero.
+		DeltaZero,
 	}
 
 	/// The total units issued in the system.
@@ -542,6 +551,39 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::genesis_check]
+	impl<T: Config<I>, I: 'static> CheckGenesisConfig for GenesisConfig<T, I> {
+		fn check(&self) -> Result<(), Vec<sp_runtime::RuntimeString>> {
+			let mut errors = Vec::new();
+
+			if self
+				.balances
+				.iter()
+				.any(|(_, balance)| *balance < <T as Config<I>>::ExistentialDeposit::get())
+			{
+				errors.push(
+					"the balance of any account should always be at least the existential deposit."
+						.into(),
+				);
+			}
+
+			let endowed_accounts = self
+				.balances
+				.iter()
+				.map(|(x, _)| x)
+				.collect::<sp_std::collections::btree_set::BTreeSet<_>>();
+			if endowed_accounts.len() != self.balances.len() {
+				errors.push("duplicate balances in genesis.".into());
+			}
+
+			if errors.is_empty() {
+				Ok(())
+			} else {
+				Err(errors)
+			}
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		#[cfg(not(feature = "insecure_zero_ed"))]
@@ -564,6 +606,11 @@ pub mod pallet {
 				T::MaxFreezes::get(), <T::RuntimeFreezeReason as VariantCount>::VARIANT_COUNT,
 			);
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -745,12 +792,89 @@ pub mod pallet {
 			Self::deposit_event(Event::BalanceSet { who, free: new_free });
 			Ok(())
 		}
+
+		/// Adjust the total issuance in a saturating way.
+		///
+		/// Can only be called by root and always needs a positive `delta`. The `reason` is
+		/// included in the emitted event, so that every correction of the total issuance can be
+		/// audited later on. The `try-runtime` checks of this pallet report the adjustment
+		/// needed to reconcile the total issuance with the balances of all accounts.
+		#[pallet::call_index(9)]
+		pub fn force_adjust_total_issuance(
+			origin: OriginFor<T>,
+			direction: AdjustmentDirection,
+			#[pallet::compact] delta: T::Balance,
+			reason: AdjustmentReason,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!delta.is_zero(), Error::<T, I>::DeltaZero);
+
+			let old = TotalIssuance::<T, I>::get();
+			let new = match direction {
+				AdjustmentDirection::Increase => old.saturating_add(delta),
+				AdjustmentDirection::Decrease => old.saturating_sub(delta),
+			};
+			ensure!(InactiveIssuance::<T, I>::get() <= new, Error::<T, I>::IssuanceDeactivated);
+			TotalIssuance::<T, I>::set(new);
+
+			Self::deposit_event(Event::TotalIssuanceForced { old, new, reason });
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		fn ed() -> T::Balance {
 			T::ExistentialDeposit::get()
 		}
+
+		/// The sum of the free and reserved balances of all accounts.
+		///
+		/// Iterates over every account known to the system pallet, hence it is only available to
+		/// the `try-runtime` checks and never part of block execution.
+		#[cfg(any(test, feature = "try-runtime"))]
+		pub(crate) fn total_balance_of_all_accounts() -> T::Balance {
+			frame_system::Account::<T>::iter_keys().fold(Zero::zero(), |total, who| {
+				total.saturating_add(T::AccountStore::get(&who).total())
+			})
+		}
+
+		/// The adjustment of the total issuance needed to match the balances of all accounts, or
+		/// `None` if they already match.
+		///
+		/// The result can be passed to [`Pallet::force_adjust_total_issuance`]. Iterates over every
+		/// account, like [`Pallet::total_balance_of_all_accounts`].
+		#[cfg(any(test, feature = "try-runtime"))]
+		pub(crate) fn total_issuance_drift() -> Option<(AdjustmentDirection, T::Balance)> {
+			let total_issuance = TotalIssuance::<T, I>::get();
+			let total_balance = Self::total_balance_of_all_accounts();
+
+			match total_balance.cmp(&total_issuance) {
+				cmp::Ordering::Equal => None,
+				cmp::Ordering::Greater =>
+					Some((AdjustmentDirection::Increase, total_balance - total_issuance)),
+				cmp::Ordering::Less =>
+					Some((AdjustmentDirection::Decrease, total_issuance - total_balance)),
+			}
+		}
+
+		/// Ensure the correctness of the state of this pallet.
+		///
+		/// Checks that the total issuance matches the sum of the balances of all accounts.
+		#[cfg(any(test, feature = "try-runtime"))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			match Self::total_issuance_drift() {
+				None => Ok(()),
+				Some((direction, delta)) => {
+					log::error!(
+						target: LOG_TARGET,
+						"total issuance drifted from the balances of all accounts, needs {:?} by {:?}",
+						direction,
+						delta,
+					);
+					Err("total issuance does not match the balances of all accounts".into())
+				},
+			}
+		}
 		/// Ensure the account `who` is using the new logic.
 		///
 		/// Returns `true` if the account did get upgraded, `false` if it didn't need upgrading.
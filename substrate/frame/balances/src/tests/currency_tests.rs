@@ -709,6 +709,21 @@ fn cannot_set_genesis_value_twice() {
 		.unwrap();
 }
 
+#[test]
+fn genesis_check_reports_every_error() {
+	use frame_support::traits::CheckGenesisConfig;
+
+	EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = 11);
+	assert_eq!(
+		crate::GenesisConfig::<Test> { balances: vec![(1, 10), (2, 20), (1, 15)] }.check(),
+		Err(vec![
+			"the balance of any account should always be at least the existential deposit.".into(),
+			"duplicate balances in genesis.".into(),
+		])
+	);
+	assert_eq!(crate::GenesisConfig::<Test> { balances: vec![(1, 11), (2, 20)] }.check(), Ok(()));
+}
+
 #[test]
 fn existential_deposit_respected_when_reserving() {
 	ExtBuilder::default().existential_deposit(100).build_and_execute_with(|| {
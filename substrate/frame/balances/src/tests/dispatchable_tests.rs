@@ -18,6 +18,7 @@
 //! Tests regarding the functionality of the dispatchables/extrinsics.
 
 use super::*;
+use crate::{AdjustmentDirection, AdjustmentReason, TotalIssuance};
 use frame_support::traits::tokens::Preservation::Expendable;
 use fungible::{hold::Mutate as HoldMutate, Inspect, Mutate};
 
@@ -222,3 +223,122 @@ fn upgrade_accounts_should_work() {
 			assert_eq!(System::consumers(&7), 0);
 		});
 }
+
+fn adjustment_reason(reason: &[u8]) -> AdjustmentReason {
+	reason.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn force_adjust_total_issuance_works() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1337, 64));
+		assert_eq!(Balances::total_issuance(), 64);
+
+		assert_ok!(Balances::force_adjust_total_issuance(
+			RawOrigin::Root.into(),
+			AdjustmentDirection::Increase,
+			32,
+			adjustment_reason(b"increase"),
+		));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::TotalIssuanceForced {
+			old: 64,
+			new: 96,
+			reason: adjustment_reason(b"increase"),
+		}));
+		assert_eq!(Balances::total_issuance(), 96);
+
+		assert_ok!(Balances::force_adjust_total_issuance(
+			RawOrigin::Root.into(),
+			AdjustmentDirection::Decrease,
+			64,
+			adjustment_reason(b"decrease"),
+		));
+		System::assert_last_event(RuntimeEvent::Balances(crate::Event::TotalIssuanceForced {
+			old: 96,
+			new: 32,
+			reason: adjustment_reason(b"decrease"),
+		}));
+		assert_eq!(Balances::total_issuance(), 32);
+
+		// Decreasing saturates at zero.
+		assert_ok!(Balances::force_adjust_total_issuance(
+			RawOrigin::Root.into(),
+			AdjustmentDirection::Decrease,
+			u64::MAX,
+			adjustment_reason(b""),
+		));
+		assert_eq!(Balances::total_issuance(), 0);
+	});
+}
+
+#[test]
+fn force_adjust_total_issuance_fails() {
+	ExtBuilder::default().build_and_execute_with(|| {
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1337, 64));
+
+		assert_noop!(
+			Balances::force_adjust_total_issuance(
+				Some(1337).into(),
+				AdjustmentDirection::Increase,
+				1,
+				adjustment_reason(b""),
+			),
+			BadOrigin,
+		);
+		assert_noop!(
+			Balances::force_adjust_total_issuance(
+				RawOrigin::Root.into(),
+				AdjustmentDirection::Increase,
+				0,
+				adjustment_reason(b""),
+			),
+			Error::<Test>::DeltaZero,
+		);
+
+		// The total issuance can not drop below the inactive issuance.
+		<Balances as fungible::Unbalanced<_>>::deactivate(32);
+		assert_noop!(
+			Balances::force_adjust_total_issuance(
+				RawOrigin::Root.into(),
+				AdjustmentDirection::Decrease,
+				33,
+				adjustment_reason(b""),
+			),
+			Error::<Test>::IssuanceDeactivated,
+		);
+		assert_ok!(Balances::force_adjust_total_issuance(
+			RawOrigin::Root.into(),
+			AdjustmentDirection::Decrease,
+			32,
+			adjustment_reason(b""),
+		));
+	});
+}
+
+#[test]
+fn total_issuance_drift_can_be_reconciled() {
+	ExtBuilder::default().monied(true).build_and_execute_with(|| {
+		assert_eq!(Balances::total_balance_of_all_accounts(), 110);
+		assert_eq!(Balances::total_issuance_drift(), None);
+		assert_ok!(Balances::do_try_state());
+
+		// Simulate a historical bug which minted funds without accounting for them.
+		TotalIssuance::<Test>::mutate(|issuance| *issuance -= 10);
+		assert_eq!(Balances::total_issuance_drift(), Some((AdjustmentDirection::Increase, 10)));
+		assert!(Balances::do_try_state().is_err());
+
+		let (direction, delta) = Balances::total_issuance_drift().unwrap();
+		assert_ok!(Balances::force_adjust_total_issuance(
+			RawOrigin::Root.into(),
+			direction,
+			delta,
+			adjustment_reason(b"reconcile"),
+		));
+		assert_eq!(Balances::total_issuance_drift(), None);
+		assert_ok!(Balances::do_try_state());
+
+		// And the other way around.
+		TotalIssuance::<Test>::mutate(|issuance| *issuance += 5);
+		assert_eq!(Balances::total_issuance_drift(), Some((AdjustmentDirection::Decrease, 5)));
+	});
+}
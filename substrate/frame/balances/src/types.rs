@@ -20,7 +20,10 @@
 use crate::{Config, CreditOf, Event, Pallet};
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::ops::BitOr;
-use frame_support::traits::{Imbalance, LockIdentifier, OnUnbalanced, WithdrawReasons};
+use frame_support::{
+	traits::{ConstU32, Imbalance, LockIdentifier, OnUnbalanced, WithdrawReasons},
+	BoundedVec,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{RuntimeDebug, Saturating};
 
@@ -152,3 +155,19 @@ impl<T: Config<I>, I: 'static> Drop for DustCleaner<T, I> {
 		}
 	}
 }
+
+/// The direction in which [`Pallet::force_adjust_total_issuance`] changes the total issuance.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum AdjustmentDirection {
+	/// Increase the total issuance by the given amount.
+	Increase,
+	/// Decrease the total issuance by the given amount.
+	Decrease,
+}
+
+/// The maximum length of the reason given for a forced adjustment of the total issuance.
+pub const MAX_ADJUSTMENT_REASON_LEN: u32 = 128;
+
+/// A human readable reason for a forced adjustment of the total issuance, e.g. a reference to the
+/// post-mortem describing how the issuance drifted.
+pub type AdjustmentReason = BoundedVec<u8, ConstU32<MAX_ADJUSTMENT_REASON_LEN>>;
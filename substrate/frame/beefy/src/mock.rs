@@ -207,10 +207,18 @@ impl pallet_staking::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const OffencesMaxProcessingWeight: frame_support::weights::Weight =
+		frame_support::weights::Weight::MAX;
+}
+
 impl pallet_offences::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = ConstU32<100>;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = ();
 }
 
 // Note, that we can't use `UintAuthorityId` here. Reason is that the implementation
@@ -297,6 +305,7 @@ pub fn start_session(session_index: SessionIndex) {
 		Timestamp::set_timestamp(System::block_number() * 6000);
 
 		System::on_initialize(System::block_number());
+		Offences::on_initialize(System::block_number());
 		Session::on_initialize(System::block_number());
 		Staking::on_initialize(System::block_number());
 		Beefy::on_initialize(System::block_number());
@@ -339,6 +339,7 @@ frame_benchmarking::benchmarks! {
 
 #[cfg(test)]
 mod mock {
+	use frame_support::derive_impl;
 	use sp_runtime::{testing::H256, BuildStorage};
 
 	type AccountId = u64;
@@ -354,6 +355,7 @@ mod mock {
 		}
 	);
 
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 	impl frame_system::Config for Test {
 		type BaseCallFilter = frame_support::traits::Everything;
 		type BlockWeights = ();
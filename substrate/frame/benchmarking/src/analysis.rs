@@ -26,6 +26,10 @@ pub struct Analysis {
 	pub names: Vec<String>,
 	pub value_dists: Option<Vec<(Vec<u32>, u128, u128)>>,
 	pub errors: Option<Vec<u128>>,
+	/// The coefficient of determination of the linear regression, if one was fitted.
+	///
+	/// A value close to `1.0` means that the model explains the measurements well.
+	pub r_squared: Option<f64>,
 	pub minimum: u128,
 	selector: BenchmarkSelector,
 }
@@ -194,6 +198,23 @@ fn linear_regression(
 	Some((min, params, errors[1..].to_vec()))
 }
 
+/// The coefficient of determination of the model `intercept + slopes * x` for the given data.
+fn r_squared(xs: &[f64], ys: &[f64], x_vars: usize, intercept: f64, slopes: &[f64]) -> f64 {
+	let mean = ys.iter().sum::<f64>() / ys.len() as f64;
+	let (mut ss_res, mut ss_tot) = (0f64, 0f64);
+	for (&y, xs) in ys.iter().zip(xs.chunks_exact(x_vars)) {
+		let predicted = intercept + xs.iter().zip(slopes).map(|(x, m)| x * m).sum::<f64>();
+		ss_res += (y - predicted).powi(2);
+		ss_tot += (y - mean).powi(2);
+	}
+
+	// Constant measurements are perfectly explained by the intercept alone.
+	if ss_tot == 0f64 {
+		return 1f64
+	}
+	1f64 - ss_res / ss_tot
+}
+
 impl Analysis {
 	// Useful for when there are no components, and we just need an median value of the benchmark
 	// results. Note: We choose the median value because it is more robust to outliers.
@@ -222,6 +243,7 @@ impl Analysis {
 			names: Vec::new(),
 			value_dists: None,
 			errors: None,
+			r_squared: None,
 			minimum: selector.get_minimum(&r),
 			selector,
 		})
@@ -322,6 +344,7 @@ impl Analysis {
 			names: results.into_iter().map(|x| x.0).collect::<Vec<_>>(),
 			value_dists: None,
 			errors: None,
+			r_squared: None,
 			minimum: selector.get_minimum(&r),
 			selector,
 		})
@@ -379,7 +402,9 @@ impl Analysis {
 			}
 		}
 
-		let (intercept, slopes, errors) = linear_regression(xs, ys, r[0].components.len())?;
+		let x_vars = r[0].components.len();
+		let (intercept, slopes, errors) = linear_regression(xs.clone(), ys.clone(), x_vars)?;
+		let r_squared = r_squared(&xs, &ys, x_vars, intercept, &slopes);
 
 		Some(Self {
 			base: selector.scale_and_cast_weight(intercept, true),
@@ -395,6 +420,7 @@ impl Analysis {
 					.map(|value| selector.scale_and_cast_weight(value, false))
 					.collect(),
 			),
+			r_squared: Some(r_squared),
 			minimum: selector.get_minimum(&r),
 			selector,
 		})
@@ -427,9 +453,10 @@ impl Analysis {
 		let names = median_slopes.names;
 		let value_dists = min_squares.value_dists;
 		let errors = min_squares.errors;
+		let r_squared = min_squares.r_squared;
 		let minimum = selector.get_minimum(&r);
 
-		Some(Self { base, slopes, names, value_dists, errors, selector, minimum })
+		Some(Self { base, slopes, names, value_dists, errors, r_squared, selector, minimum })
 	}
 }
 
@@ -495,6 +522,10 @@ impl std::fmt::Display for Analysis {
 			}
 		}
 
+		if let Some(r_squared) = self.r_squared {
+			writeln!(f, "R²: {:.4}", r_squared)?;
+		}
+
 		writeln!(f, "\nModel:")?;
 		writeln!(f, "Time ~= {:>8}", ms(self.selector.nanos_from_weight(self.base)))?;
 		for (&t, n) in self.slopes.iter().zip(self.names.iter()) {
@@ -725,6 +756,7 @@ mod tests {
 			Analysis::min_squares_iqr(&data, BenchmarkSelector::ExtrinsicTime).unwrap();
 		assert_eq!(extrinsic_time.base, 10_000_000_000);
 		assert_eq!(extrinsic_time.slopes, vec![1000000000, 100000000]);
+		assert!(extrinsic_time.r_squared.unwrap() > 0.9999);
 
 		let reads = Analysis::min_squares_iqr(&data, BenchmarkSelector::Reads).unwrap();
 		assert_eq!(reads.base, 2);
@@ -748,6 +780,8 @@ mod tests {
 			Analysis::min_squares_iqr(&data, BenchmarkSelector::ExtrinsicTime).unwrap();
 		assert_eq!(extrinsic_time.base, 3_000_000_000);
 		assert_eq!(extrinsic_time.slopes, vec![3_000_000_000]);
+		// The noise between the repetitions can't be explained by the model.
+		assert!((extrinsic_time.r_squared.unwrap() - 9.0 / 19.0).abs() < 0.0001);
 	}
 
 	#[test]
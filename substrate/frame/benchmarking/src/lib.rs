@@ -158,6 +158,17 @@ pub use v1::*;
 /// The underscore will be substituted with the name of the benchmark  (i.e. the name of the
 /// function in the benchmark function definition).
 ///
+/// The extrinsic is looked up in the `Call` enum of the pallet being benchmarked. To benchmark
+/// a scenario starting with an extrinsic of another pallet, e.g. one whose cost depends on the
+/// state of the benchmarked pallet, prefix the call with the path to the `Call` enum of that
+/// pallet. The runtime must implement the `Config` of that pallet, which is usually ensured by
+/// the `where` clause of the `benchmarks` module:
+///
+/// ```ignore
+/// #[extrinsic_call]
+/// pallet_balances::Call::<T>::transfer_allow_death(RawOrigin::Signed(caller), dest, amount);
+/// ```
+///
 /// In case of a `force_origin` where you want to elevate the privileges of the provided origin,
 /// this is the general syntax:
 /// ```ignore
@@ -458,12 +458,12 @@ pub mod pallet {
 			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
 				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
 
-				let slash_curator = |curator: &T::AccountId,
-				                     curator_deposit: &mut BalanceOf<T, I>| {
-					let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
-					T::OnSlash::on_unbalanced(imbalance);
-					*curator_deposit = Zero::zero();
-				};
+				let slash_curator =
+					|curator: &T::AccountId, curator_deposit: &mut BalanceOf<T, I>| {
+						let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
+						T::OnSlash::on_unbalanced(imbalance);
+						*curator_deposit = Zero::zero();
+					};
 
 				match bounty.status {
 					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {
@@ -697,77 +697,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::RejectOrigin::ensure_origin(origin)?;
 
-			Bounties::<T, I>::try_mutate_exists(
-				bounty_id,
-				|maybe_bounty| -> DispatchResultWithPostInfo {
-					let bounty = maybe_bounty.as_ref().ok_or(Error::<T, I>::InvalidIndex)?;
-
-					// Ensure no active child bounties before processing the call.
-					ensure!(
-						T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
-						Error::<T, I>::HasActiveChildBounty
-					);
-
-					match &bounty.status {
-						BountyStatus::Proposed => {
-							// The reject origin would like to cancel a proposed bounty.
-							BountyDescriptions::<T, I>::remove(bounty_id);
-							let value = bounty.bond;
-							let imbalance = T::Currency::slash_reserved(&bounty.proposer, value).0;
-							T::OnSlash::on_unbalanced(imbalance);
-							*maybe_bounty = None;
-
-							Self::deposit_event(Event::<T, I>::BountyRejected {
-								index: bounty_id,
-								bond: value,
-							});
-							// Return early, nothing else to do.
-							return Ok(
-								Some(<T as Config<I>>::WeightInfo::close_bounty_proposed()).into()
-							)
-						},
-						BountyStatus::Approved => {
-							// For weight reasons, we don't allow a council to cancel in this phase.
-							// We ask for them to wait until it is funded before they can cancel.
-							return Err(Error::<T, I>::UnexpectedStatus.into())
-						},
-						BountyStatus::Funded | BountyStatus::CuratorProposed { .. } => {
-							// Nothing extra to do besides the removal of the bounty below.
-						},
-						BountyStatus::Active { curator, .. } => {
-							// Cancelled by council, refund deposit of the working curator.
-							let err_amount =
-								T::Currency::unreserve(curator, bounty.curator_deposit);
-							debug_assert!(err_amount.is_zero());
-							// Then execute removal of the bounty below.
-						},
-						BountyStatus::PendingPayout { .. } => {
-							// Bounty is already pending payout. If council wants to cancel
-							// this bounty, it should mean the curator was acting maliciously.
-							// So the council should first unassign the curator, slashing their
-							// deposit.
-							return Err(Error::<T, I>::PendingPayout.into())
-						},
-					}
-
-					let bounty_account = Self::bounty_account_id(bounty_id);
-
-					BountyDescriptions::<T, I>::remove(bounty_id);
-
-					let balance = T::Currency::free_balance(&bounty_account);
-					let res = T::Currency::transfer(
-						&bounty_account,
-						&Self::account_id(),
-						balance,
-						AllowDeath,
-					); // should not fail
-					debug_assert!(res.is_ok());
-					*maybe_bounty = None;
-
-					Self::deposit_event(Event::<T, I>::BountyCanceled { index: bounty_id });
-					Ok(Some(<T as Config<I>>::WeightInfo::close_bounty_active()).into())
-				},
-			)
+			Self::impl_close_bounty(bounty_id)
 		}
 
 		/// Extend the expiry time of an active bounty.
@@ -840,6 +770,79 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		T::PalletId::get().into_sub_account_truncating(("bt", id))
 	}
 
+	/// Cancel a proposed, funded or active bounty as in [`Pallet::close_bounty`], once the origin
+	/// has been checked.
+	pub fn impl_close_bounty(bounty_id: BountyIndex) -> DispatchResultWithPostInfo {
+		Bounties::<T, I>::try_mutate_exists(
+			bounty_id,
+			|maybe_bounty| -> DispatchResultWithPostInfo {
+				let bounty = maybe_bounty.as_ref().ok_or(Error::<T, I>::InvalidIndex)?;
+
+				// Ensure no active child bounties before processing the call.
+				ensure!(
+					T::ChildBountyManager::child_bounties_count(bounty_id) == 0,
+					Error::<T, I>::HasActiveChildBounty
+				);
+
+				match &bounty.status {
+					BountyStatus::Proposed => {
+						// The reject origin would like to cancel a proposed bounty.
+						BountyDescriptions::<T, I>::remove(bounty_id);
+						let value = bounty.bond;
+						let imbalance = T::Currency::slash_reserved(&bounty.proposer, value).0;
+						T::OnSlash::on_unbalanced(imbalance);
+						*maybe_bounty = None;
+
+						Self::deposit_event(Event::<T, I>::BountyRejected {
+							index: bounty_id,
+							bond: value,
+						});
+						// Return early, nothing else to do.
+						return Ok(Some(<T as Config<I>>::WeightInfo::close_bounty_proposed()).into())
+					},
+					BountyStatus::Approved => {
+						// For weight reasons, we don't allow a council to cancel in this phase.
+						// We ask for them to wait until it is funded before they can cancel.
+						return Err(Error::<T, I>::UnexpectedStatus.into())
+					},
+					BountyStatus::Funded | BountyStatus::CuratorProposed { .. } => {
+						// Nothing extra to do besides the removal of the bounty below.
+					},
+					BountyStatus::Active { curator, .. } => {
+						// Cancelled by council, refund deposit of the working curator.
+						let err_amount = T::Currency::unreserve(curator, bounty.curator_deposit);
+						debug_assert!(err_amount.is_zero());
+						// Then execute removal of the bounty below.
+					},
+					BountyStatus::PendingPayout { .. } => {
+						// Bounty is already pending payout. If council wants to cancel
+						// this bounty, it should mean the curator was acting maliciously.
+						// So the council should first unassign the curator, slashing their
+						// deposit.
+						return Err(Error::<T, I>::PendingPayout.into())
+					},
+				}
+
+				let bounty_account = Self::bounty_account_id(bounty_id);
+
+				BountyDescriptions::<T, I>::remove(bounty_id);
+
+				let balance = T::Currency::free_balance(&bounty_account);
+				let res = T::Currency::transfer(
+					&bounty_account,
+					&Self::account_id(),
+					balance,
+					AllowDeath,
+				); // should not fail
+				debug_assert!(res.is_ok());
+				*maybe_bounty = None;
+
+				Self::deposit_event(Event::<T, I>::BountyCanceled { index: bounty_id });
+				Ok(Some(<T as Config<I>>::WeightInfo::close_bounty_active()).into())
+			},
+		)
+	}
+
 	fn create_bounty(
 		proposer: T::AccountId,
 		description: Vec<u8>,
@@ -38,6 +38,7 @@ use sp_runtime::{
 };
 
 use super::Event as BountiesEvent;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -59,6 +60,7 @@ parameter_types! {
 
 type Balance = u64;
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -294,6 +294,7 @@ mod benches {
 			.map_err(|_| BenchmarkError::Weightless)?;
 
 		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let record = Regions::<T>::get(&region).ok_or(BenchmarkError::Weightless)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), region, recipient.clone());
@@ -304,6 +305,8 @@ mod benches {
 				old_owner: caller,
 				owner: recipient,
 				duration: 3u32.into(),
+				end: record.end,
+				paid: record.paid,
 			}
 			.into(),
 		);
@@ -326,8 +329,10 @@ mod benches {
 		let region = Broker::<T>::do_purchase(caller.clone(), 10u32.into())
 			.map_err(|_| BenchmarkError::Weightless)?;
 
+		let record = Regions::<T>::get(&region).ok_or(BenchmarkError::Weightless)?;
+
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), region, 2);
+		_(RawOrigin::Signed(caller.clone()), region, 2);
 
 		assert_last_event::<T>(
 			Event::Partitioned {
@@ -336,6 +341,9 @@ mod benches {
 					RegionId { begin: 4, core, mask: CoreMask::complete() },
 					RegionId { begin: 6, core, mask: CoreMask::complete() },
 				),
+				owner: caller,
+				end: record.end,
+				paid: record.paid,
 			}
 			.into(),
 		);
@@ -358,8 +366,10 @@ mod benches {
 		let region = Broker::<T>::do_purchase(caller.clone(), 10u32.into())
 			.map_err(|_| BenchmarkError::Weightless)?;
 
+		let record = Regions::<T>::get(&region).ok_or(BenchmarkError::Weightless)?;
+
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), region, 0x00000_fffff_fffff_00000.into());
+		_(RawOrigin::Signed(caller.clone()), region, 0x00000_fffff_fffff_00000.into());
 
 		assert_last_event::<T>(
 			Event::Interlaced {
@@ -372,6 +382,9 @@ mod benches {
 						mask: CoreMask::complete() ^ 0x00000_fffff_fffff_00000.into(),
 					},
 				),
+				owner: caller,
+				end: record.end,
+				paid: record.paid,
 			}
 			.into(),
 		);
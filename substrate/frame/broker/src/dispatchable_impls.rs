@@ -185,6 +185,8 @@ impl<T: Config> Pallet<T> {
 			old_owner,
 			owner: region.owner,
 			duration,
+			end: region.end,
+			paid: region.paid,
 		});
 
 		Ok(())
@@ -204,12 +206,18 @@ impl<T: Config> Pallet<T> {
 		ensure!(pivot < region.end, Error::<T>::PivotTooLate);
 		ensure!(pivot > region_id.begin, Error::<T>::PivotTooEarly);
 
-		region.paid = None;
+		let paid = region.paid.take();
 		let new_region_ids = (region_id, RegionId { begin: pivot, ..region_id });
 
 		Regions::<T>::insert(&new_region_ids.0, &RegionRecord { end: pivot, ..region.clone() });
 		Regions::<T>::insert(&new_region_ids.1, &region);
-		Self::deposit_event(Event::Partitioned { old_region_id: region_id, new_region_ids });
+		Self::deposit_event(Event::Partitioned {
+			old_region_id: region_id,
+			new_region_ids,
+			owner: region.owner,
+			end: region.end,
+			paid,
+		});
 
 		Ok(new_region_ids)
 	}
@@ -235,7 +243,13 @@ impl<T: Config> Pallet<T> {
 		Regions::<T>::insert(&other, &region);
 
 		let new_region_ids = (one, other);
-		Self::deposit_event(Event::Interlaced { old_region_id: region_id, new_region_ids });
+		Self::deposit_event(Event::Interlaced {
+			old_region_id: region_id,
+			new_region_ids,
+			owner: region.owner,
+			end: region.end,
+			paid: region.paid,
+		});
 		Ok(new_region_ids)
 	}
 
@@ -215,6 +215,10 @@ pub mod pallet {
 			old_owner: T::AccountId,
 			/// The new owner of the Region.
 			owner: T::AccountId,
+			/// The end of the Region.
+			end: Timeslice,
+			/// The amount paid for the Region, or `None` if renewal is not allowed.
+			paid: Option<BalanceOf<T>>,
 		},
 		/// A Region has been split into two non-overlapping Regions.
 		Partitioned {
@@ -222,6 +226,13 @@ pub mod pallet {
 			old_region_id: RegionId,
 			/// The new Regions into which it became.
 			new_region_ids: (RegionId, RegionId),
+			/// The owner of the Regions.
+			owner: T::AccountId,
+			/// The end of the Region which was split.
+			end: Timeslice,
+			/// The amount paid for the Region which was split, if it could be renewed. The new
+			/// Regions can't be renewed.
+			paid: Option<BalanceOf<T>>,
 		},
 		/// A Region has been converted into two overlapping Regions each of lesser regularity.
 		Interlaced {
@@ -229,6 +240,13 @@ pub mod pallet {
 			old_region_id: RegionId,
 			/// The new Regions into which it became.
 			new_region_ids: (RegionId, RegionId),
+			/// The owner of the Regions.
+			owner: T::AccountId,
+			/// The end of the Regions.
+			end: Timeslice,
+			/// The amount paid for the Region which was interlaced, or `None` if renewal is not
+			/// allowed. The new Regions keep it.
+			paid: Option<BalanceOf<T>>,
 		},
 		/// A Region has been assigned to a particular task.
 		Assigned {
@@ -191,6 +191,17 @@ fn transfer_works() {
 		advance_to(2);
 		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
 		assert_ok!(<Broker as Transfer<_>>::transfer(&region.into(), &2));
+		System::assert_last_event(
+			Event::<Test>::Transferred {
+				region_id: region,
+				old_owner: 1,
+				owner: 2,
+				duration: 3,
+				end: 7,
+				paid: Some(100),
+			}
+			.into(),
+		);
 		assert_eq!(<Broker as NftInspect<_>>::owner(&region.into()), Some(2));
 		assert_noop!(Broker::do_assign(region, Some(1), 1001, Final), Error::<Test>::NotOwner);
 		assert_ok!(Broker::do_assign(region, Some(2), 1002, Final));
@@ -533,7 +544,27 @@ fn partition_works() {
 		advance_to(2);
 		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
 		let (region1, region) = Broker::do_partition(region, None, 1).unwrap();
+		System::assert_last_event(
+			Event::<Test>::Partitioned {
+				old_region_id: region1,
+				new_region_ids: (region1, region),
+				owner: 1,
+				end: 7,
+				paid: Some(100),
+			}
+			.into(),
+		);
 		let (region2, region3) = Broker::do_partition(region, None, 1).unwrap();
+		System::assert_last_event(
+			Event::<Test>::Partitioned {
+				old_region_id: region2,
+				new_region_ids: (region2, region3),
+				owner: 1,
+				end: 7,
+				paid: None,
+			}
+			.into(),
+		);
 		assert_ok!(Broker::do_assign(region1, None, 1001, Final));
 		assert_ok!(Broker::do_assign(region2, None, 1002, Final));
 		assert_ok!(Broker::do_assign(region3, None, 1003, Final));
@@ -581,6 +612,16 @@ fn interlace_works() {
 		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
 		let (region1, region) =
 			Broker::do_interlace(region, None, CoreMask::from_chunk(0, 30)).unwrap();
+		System::assert_last_event(
+			Event::<Test>::Interlaced {
+				old_region_id: RegionId { mask: CoreMask::complete(), ..region1 },
+				new_region_ids: (region1, region),
+				owner: 1,
+				end: 7,
+				paid: Some(100),
+			}
+			.into(),
+		);
 		let (region2, region3) =
 			Broker::do_interlace(region, None, CoreMask::from_chunk(30, 60)).unwrap();
 		assert_ok!(Broker::do_assign(region1, None, 1001, Final));
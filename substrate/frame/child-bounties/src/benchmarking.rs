@@ -312,5 +312,61 @@ benchmarks! {
 		}.into())
 	}
 
+	set_default_child_curator_fee {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let fee = Some(Permill::from_percent(10));
+	}: _(RawOrigin::Signed(bounty_setup.curator), bounty_setup.bounty_id, fee)
+	verify {
+		assert_last_event::<T>(Event::DefaultCuratorFeeSet {
+			index: bounty_setup.bounty_id,
+			fee,
+		}.into())
+	}
+
+	// Worst case scenario, all child bounties are active and the parent bounty is closed.
+	close_parent_bounty {
+		let n in 0 .. T::ChildBountyClosureBatchSize::get().min(T::MaxActiveChildBountyCount::get());
+		setup_pot_account::<T>();
+		let bounty_setup = activate_bounty::<T>(0, T::MaximumReasonLength::get())?;
+
+		// Fund the parent bounty and the child-bounty curator for all child bounties.
+		let parent_bounty_account = Bounties::<T>::bounty_account_id(bounty_setup.bounty_id);
+		let _ = T::Currency::make_free_balance_be(
+			&parent_bounty_account,
+			bounty_setup.child_bounty_value.saturating_mul((n + 1).into()),
+		);
+		let _ = T::Currency::make_free_balance_be(
+			&bounty_setup.child_curator,
+			bounty_setup.child_bounty_fee.saturating_mul((n + 1).into()),
+		);
+
+		for _ in 0 .. n {
+			ChildBounties::<T>::add_child_bounty(
+				RawOrigin::Signed(bounty_setup.curator.clone()).into(),
+				bounty_setup.bounty_id,
+				bounty_setup.child_bounty_value,
+				bounty_setup.reason.clone(),
+			)?;
+			let child_bounty_id = ChildBountyCount::<T>::get() - 1;
+			ChildBounties::<T>::propose_curator(
+				RawOrigin::Signed(bounty_setup.curator.clone()).into(),
+				bounty_setup.bounty_id,
+				child_bounty_id,
+				T::Lookup::unlookup(bounty_setup.child_curator.clone()),
+				bounty_setup.child_bounty_fee,
+			)?;
+			ChildBounties::<T>::accept_curator(
+				RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+				bounty_setup.bounty_id,
+				child_bounty_id,
+			)?;
+		}
+	}: _(RawOrigin::Root, bounty_setup.bounty_id)
+	verify {
+		assert_eq!(ChildBounties::<T>::parent_child_bounties(bounty_setup.bounty_id), 0);
+		assert!(Bounties::<T>::bounties(bounty_setup.bounty_id).is_none());
+	}
+
 	impl_benchmark_test_suite!(ChildBounties, crate::tests::new_test_ext(), crate::tests::Test)
 }
@@ -43,6 +43,12 @@
 //! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
 //! - `close_child_bounty` - Cancel the child bounty for a specific treasury amount and close the
 //!   bounty.
+//! - `set_default_child_curator_fee` - Configure the share of a child bounty's value proposed as
+//!   fee for its curator, when the parent bounty curator does not specify one.
+//! - `close_parent_bounty` - Close a parent bounty after settling all of its child bounties, in
+//!   batches of at most `ChildBountyClosureBatchSize` child bounties. Child bounties which do not
+//!   fit in the first batch are settled in `on_idle` of the following blocks, and the parent bounty
+//!   is closed once the last child bounty pending payout is claimed.
 
 // Most of the business logic in this pallet has been
 // originally contributed by "https://github.com/shamb0",
@@ -66,10 +72,10 @@ use frame_support::traits::{
 
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, CheckedSub, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Permill, RuntimeDebug,
 };
 
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, weights::WeightMeter};
 use frame_system::pallet_prelude::*;
 use pallet_bounties::BountyStatus;
 use scale_info::TypeInfo;
@@ -144,6 +150,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
 
+		/// Maximum number of child bounties settled at once by `close_parent_bounty`, or per
+		/// batch in `on_idle`.
+		#[pallet::constant]
+		type ChildBountyClosureBatchSize: Get<u32>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -159,6 +170,8 @@ pub mod pallet {
 		InsufficientBountyBalance,
 		/// Number of child bounties exceeds limit `MaxActiveChildBountyCount`.
 		TooManyChildBounties,
+		/// The parent bounty is being closed.
+		ChildBountiesClosing,
 	}
 
 	#[pallet::event]
@@ -177,6 +190,12 @@ pub mod pallet {
 		},
 		/// A child-bounty is cancelled.
 		Canceled { index: BountyIndex, child_index: BountyIndex },
+		/// The default curator fee of the child bounties of a parent bounty is set.
+		DefaultCuratorFeeSet { index: BountyIndex, fee: Option<Permill> },
+		/// All child bounties of a parent bounty being closed which could be closed have been
+		/// closed. `remaining` child bounties are pending payout, the parent bounty is closed
+		/// once they are claimed.
+		ChildBountiesClosed { index: BountyIndex, remaining: u32 },
 	}
 
 	/// Number of total child bounties.
@@ -215,6 +234,51 @@ pub mod pallet {
 	pub type ChildrenCuratorFees<T: Config> =
 		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
 
+	/// The share of a child-bounty's value proposed as curator fee, when the parent bounty
+	/// curator does not specify one.
+	#[pallet::storage]
+	#[pallet::getter(fn default_child_curator_fee)]
+	pub type DefaultChildCuratorFee<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, Permill, OptionQuery>;
+
+	/// Parent bounties being closed whose child bounties are still being visited.
+	///
+	/// Map of parent bounty index to the index of the last child-bounty visited.
+	#[pallet::storage]
+	#[pallet::getter(fn child_bounty_closures)]
+	pub type ChildBountyClosures<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, BountyIndex, OptionQuery>;
+
+	/// Parent bounties being closed whose remaining child bounties are pending payout.
+	///
+	/// The parent bounty is closed when the last of them is claimed.
+	#[pallet::storage]
+	pub type ParentBountiesAwaitingPayout<T: Config> =
+		StorageMap<_, Twox64Concat, BountyIndex, (), OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			let batch_weight = <T as Config>::WeightInfo::close_parent_bounty(
+				T::ChildBountyClosureBatchSize::get(),
+			);
+
+			while meter.try_consume(T::DbWeight::get().reads(1)).is_ok() &&
+				meter.can_consume(batch_weight)
+			{
+				let Some((parent_bounty_id, cursor)) = ChildBountyClosures::<T>::iter().next()
+				else {
+					break
+				};
+				Self::close_child_bounties_batch(parent_bounty_id, Some(cursor));
+				meter.consume(batch_weight);
+			}
+
+			meter.consumed()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Add a new child-bounty.
@@ -255,6 +319,7 @@ pub mod pallet {
 					T::MaxActiveChildBountyCount::get() as u32,
 				Error::<T>::TooManyChildBounties,
 			);
+			ensure!(!Self::is_closing(parent_bounty_id), Error::<T>::ChildBountiesClosing);
 
 			let (curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
 			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
@@ -307,6 +372,10 @@ pub mod pallet {
 		/// state of child-bounty is moved to "CuratorProposed" on successful
 		/// call completion.
 		///
+		/// If `fee` is zero and a default child curator fee is configured for
+		/// the parent bounty, the default share of the child-bounty value is
+		/// used instead.
+		///
 		/// - `parent_bounty_id`: Index of parent bounty.
 		/// - `child_bounty_id`: Index of child bounty.
 		/// - `curator`: Address of child-bounty curator.
@@ -340,6 +409,12 @@ pub mod pallet {
 						BountiesError::<T>::UnexpectedStatus,
 					);
 
+					// Fall back to the default fee configured by the parent curator.
+					let fee = match Self::default_child_curator_fee(parent_bounty_id) {
+						Some(share) if fee.is_zero() => share * child_bounty.value,
+						_ => fee,
+					};
+
 					// Ensure child-bounty curator fee is less than child-bounty value.
 					ensure!(fee < child_bounty.value, BountiesError::<T>::InvalidFee);
 
@@ -638,15 +713,20 @@ pub mod pallet {
 		/// call. And instance of child-bounty is removed from the state on
 		/// successful call completion.
 		///
+		/// If the parent bounty is being closed and this was its last child
+		/// bounty, the parent bounty is closed as well.
+		///
 		/// - `parent_bounty_id`: Index of parent bounty.
 		/// - `child_bounty_id`: Index of child bounty.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim_child_bounty())]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_child_bounty()
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Pallet::<T>::settle_parent_bounty_weight()))]
 		pub fn claim_child_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
 			#[pallet::compact] child_bounty_id: BountyIndex,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 
 			// Ensure child-bounty is in expected state.
@@ -725,7 +805,18 @@ pub mod pallet {
 						Err(BountiesError::<T>::UnexpectedStatus.into())
 					}
 				},
-			)
+			)?;
+
+			let weight = <T as Config>::WeightInfo::claim_child_bounty()
+				.saturating_add(T::DbWeight::get().reads(1));
+			if ParentBountiesAwaitingPayout::<T>::contains_key(parent_bounty_id) &&
+				Self::parent_child_bounties(parent_bounty_id).is_zero()
+			{
+				ParentBountiesAwaitingPayout::<T>::remove(parent_bounty_id);
+				Self::settle_parent_bounty(parent_bounty_id);
+				return Ok(Some(weight.saturating_add(Self::settle_parent_bounty_weight())).into())
+			}
+			Ok(Some(weight).into())
 		}
 
 		/// Cancel a proposed or active child-bounty. Child-bounty account funds
@@ -770,6 +861,76 @@ pub mod pallet {
 			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id)?;
 			Ok(())
 		}
+
+		/// Set the share of a child-bounty's value which is proposed as fee
+		/// for its curator, when `propose_curator` is called with a zero fee.
+		///
+		/// The dispatch origin for this call must be the curator of the parent
+		/// bounty, and the parent bounty must be in "active" state.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `fee`: Share of the child-bounty value, or `None` to remove the default.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_default_child_curator_fee())]
+		pub fn set_default_child_curator_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			fee: Option<Permill>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			let (curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+
+			DefaultChildCuratorFee::<T>::set(parent_bounty_id, fee);
+			Self::deposit_event(Event::<T>::DefaultCuratorFeeSet { index: parent_bounty_id, fee });
+			Ok(())
+		}
+
+		/// Close a parent bounty, settling all of its child bounties first.
+		///
+		/// The dispatch origin for this call must be `T::RejectOrigin`, as for
+		/// `close_bounty` of the bounties pallet.
+		///
+		/// Up to `T::ChildBountyClosureBatchSize` child bounties are closed by
+		/// this call. The remaining ones are closed in batches of the same size
+		/// in `on_idle` of the following blocks. While the closure is ongoing,
+		/// no child bounties can be added to the parent bounty.
+		///
+		/// Child bounties in "PendingPayout" state are left untouched, so that
+		/// they can still be claimed by their beneficiary. All other child
+		/// bounties are closed as in `close_child_bounty`.
+		///
+		/// `ChildBountiesClosed` is emitted once all child bounties are visited.
+		/// The parent bounty is then closed as in `close_bounty`, right away if
+		/// no child bounty is pending payout, or else once the last of them is
+		/// claimed.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::close_parent_bounty(
+			T::ChildBountyClosureBatchSize::get()
+		))]
+		pub fn close_parent_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			T::RejectOrigin::ensure_origin(origin)?;
+
+			let parent_bounty = pallet_bounties::Pallet::<T>::bounties(parent_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?;
+			ensure!(
+				!matches!(
+					parent_bounty.get_status(),
+					BountyStatus::Approved | BountyStatus::PendingPayout { .. }
+				),
+				BountiesError::<T>::UnexpectedStatus,
+			);
+			ensure!(!Self::is_closing(parent_bounty_id), Error::<T>::ChildBountiesClosing);
+
+			let visited = Self::close_child_bounties_batch(parent_bounty_id, None);
+			Ok(Some(<T as Config>::WeightInfo::close_parent_bounty(visited)).into())
+		}
 	}
 }
 
@@ -892,6 +1053,81 @@ impl<T: Config> Pallet<T> {
 			},
 		)
 	}
+
+	/// Whether the parent bounty `parent_bounty_id` is being closed.
+	fn is_closing(parent_bounty_id: BountyIndex) -> bool {
+		ChildBountyClosures::<T>::contains_key(parent_bounty_id) ||
+			ParentBountiesAwaitingPayout::<T>::contains_key(parent_bounty_id)
+	}
+
+	/// Close a batch of up to `T::ChildBountyClosureBatchSize` child bounties of
+	/// `parent_bounty_id`, starting after the child-bounty `cursor`.
+	///
+	/// The closure is recorded in `ChildBountyClosures` as long as child bounties
+	/// may remain to be visited. Once all are visited, the parent bounty is
+	/// closed, or recorded in `ParentBountiesAwaitingPayout` if some child
+	/// bounties are pending payout. Returns the number of visited child bounties.
+	fn close_child_bounties_batch(
+		parent_bounty_id: BountyIndex,
+		cursor: Option<BountyIndex>,
+	) -> u32 {
+		let batch_size = T::ChildBountyClosureBatchSize::get() as usize;
+		// Look one child-bounty ahead to know whether the closure is complete.
+		let mut batch: Vec<BountyIndex> = match cursor {
+			Some(last) => ChildBounties::<T>::iter_key_prefix_from(
+				parent_bounty_id,
+				ChildBounties::<T>::hashed_key_for(parent_bounty_id, last),
+			)
+			.take(batch_size.saturating_add(1))
+			.collect(),
+			None => ChildBounties::<T>::iter_key_prefix(parent_bounty_id)
+				.take(batch_size.saturating_add(1))
+				.collect(),
+		};
+		let complete = batch.len() <= batch_size;
+		batch.truncate(batch_size);
+
+		for child_bounty_id in batch.iter() {
+			// Fails only for child bounties pending payout, which are left to be claimed.
+			let _ = Self::impl_close_child_bounty(parent_bounty_id, *child_bounty_id);
+		}
+
+		match batch.last() {
+			Some(last) if !complete => {
+				ChildBountyClosures::<T>::insert(parent_bounty_id, last);
+			},
+			_ => {
+				ChildBountyClosures::<T>::remove(parent_bounty_id);
+				let remaining = Self::parent_child_bounties(parent_bounty_id);
+				Self::deposit_event(Event::<T>::ChildBountiesClosed {
+					index: parent_bounty_id,
+					remaining,
+				});
+				if remaining.is_zero() {
+					Self::settle_parent_bounty(parent_bounty_id);
+				} else {
+					ParentBountiesAwaitingPayout::<T>::insert(parent_bounty_id, ());
+				}
+			},
+		}
+
+		batch.len() as u32
+	}
+
+	/// Close the parent bounty `parent_bounty_id` once all of its child bounties are settled.
+	fn settle_parent_bounty(parent_bounty_id: BountyIndex) {
+		DefaultChildCuratorFee::<T>::remove(parent_bounty_id);
+		ChildrenCuratorFees::<T>::remove(parent_bounty_id);
+		// Fails only if the parent bounty was closed or awarded meanwhile, after its last child
+		// bounties were closed or claimed one by one, which leaves nothing to settle.
+		let _ = pallet_bounties::Pallet::<T>::impl_close_bounty(parent_bounty_id);
+	}
+
+	/// The weight of [`Pallet::settle_parent_bounty`].
+	fn settle_parent_bounty_weight() -> Weight {
+		<<T as pallet_bounties::Config>::WeightInfo as pallet_bounties::WeightInfo>::close_bounty_active()
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 // Implement ChildBountyManager to connect with the bounties pallet. This is
@@ -909,6 +1145,7 @@ impl<T: Config> pallet_bounties::ChildBountyManager<BalanceOf<T>> for Pallet<T>
 		// keeping it in state after that. Hence removing.
 		let children_fee_total = Self::children_curator_fees(bounty_id);
 		<ChildrenCuratorFees<T>>::remove(bounty_id);
+		<DefaultChildCuratorFee<T>>::remove(bounty_id);
 		children_fee_total
 	}
 }
@@ -26,7 +26,7 @@ use frame_support::{
 	assert_noop, assert_ok, parameter_types,
 	traits::{
 		tokens::{PayFromAccount, UnityAssetBalanceConversion},
-		ConstU32, ConstU64, OnInitialize,
+		ConstU32, ConstU64, OnIdle, OnInitialize,
 	},
 	weights::Weight,
 	PalletId,
@@ -39,6 +39,7 @@ use sp_runtime::{
 };
 
 use super::Event as ChildBountiesEvent;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type BountiesError = pallet_bounties::Error<Test>;
@@ -62,6 +63,7 @@ parameter_types! {
 
 type Balance = u64;
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -163,6 +165,7 @@ impl pallet_child_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<2>;
 	type ChildBountyValueMinimum = ConstU64<1>;
+	type ChildBountyClosureBatchSize = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -1214,6 +1217,192 @@ fn close_parent_with_child_bounty() {
 	});
 }
 
+#[test]
+fn close_parent_bounty_in_batches() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// Bounty curator initial balance.
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Three child-bounties, one of them active.
+		for _ in 0..3 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+		}
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 20);
+
+		// Only `RejectOrigin` can close the parent bounty.
+		assert_noop!(ChildBounties::close_parent_bounty(RuntimeOrigin::signed(4), 0), BadOrigin);
+
+		// The first batch is closed right away.
+		assert_ok!(ChildBounties::close_parent_bounty(RuntimeOrigin::root(), 0));
+		assert_eq!(ChildBounties::parent_child_bounties(0), 1);
+		assert!(ChildBounties::child_bounty_closures(0).is_some());
+
+		// No child-bounties can be added while closing.
+		assert_noop!(
+			ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 10, b"12345-p1".to_vec()),
+			Error::<Test>::ChildBountiesClosing
+		);
+		assert_noop!(
+			ChildBounties::close_parent_bounty(RuntimeOrigin::root(), 0),
+			Error::<Test>::ChildBountiesClosing
+		);
+
+		// Nothing happens without enough idle weight.
+		<ChildBounties as OnIdle<u64>>::on_idle(3, Weight::zero());
+		assert_eq!(ChildBounties::parent_child_bounties(0), 1);
+
+		// The remaining batch is closed in `on_idle`, and the parent bounty with it.
+		let treasury_balance = Balances::free_balance(Treasury::account_id());
+		<ChildBounties as OnIdle<u64>>::on_idle(3, Weight::MAX);
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+		assert_eq!(ChildBounties::child_bounty_closures(0), None);
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::ChildBounties(ChildBountiesEvent::ChildBountiesClosed {
+				index: 0,
+				remaining: 0
+			})));
+		assert_eq!(Bounties::bounties(0), None);
+
+		// Funds and deposits are returned.
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), treasury_balance + 50);
+		assert_eq!(Balances::free_balance(8), 101);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::reserved_balance(4), 0);
+	});
+}
+
+#[test]
+fn close_parent_bounty_awaits_pending_payouts() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// Bounty curator initial balance.
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Two child-bounties, one of them pending payout.
+		for _ in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+		}
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 2));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+
+		// Both fit in a single batch.
+		assert_ok!(ChildBounties::close_parent_bounty(RuntimeOrigin::root(), 0));
+		assert_eq!(ChildBounties::child_bounty_closures(0), None);
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::ChildBountiesClosed { index: 0, remaining: 1 }
+		);
+		assert!(ChildBounties::child_bounties(0, 0).is_some());
+		assert!(ChildBounties::child_bounties(0, 1).is_none());
+
+		// The parent bounty waits for the pending payout.
+		assert!(Bounties::bounties(0).is_some());
+		assert_noop!(
+			ChildBounties::add_child_bounty(RuntimeOrigin::signed(4), 0, 10, b"12345-p1".to_vec()),
+			Error::<Test>::ChildBountiesClosing
+		);
+
+		// Claiming the pending payout closes the parent bounty.
+		System::set_block_number(5);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(ChildBounties::parent_child_bounties(0), 0);
+		assert!(!ParentBountiesAwaitingPayout::<Test>::contains_key(0));
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Balances::free_balance(7), 8);
+	});
+}
+
+#[test]
+fn default_child_curator_fee_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Only the parent curator can set the default fee.
+		let fee = Some(Permill::from_percent(20));
+		assert_noop!(
+			ChildBounties::set_default_child_curator_fee(RuntimeOrigin::signed(8), 0, fee),
+			BountiesError::RequireCurator
+		);
+		assert_ok!(ChildBounties::set_default_child_curator_fee(RuntimeOrigin::signed(4), 0, fee));
+		assert_eq!(last_event(), ChildBountiesEvent::DefaultCuratorFeeSet { index: 0, fee });
+
+		for _ in 0..2 {
+			assert_ok!(ChildBounties::add_child_bounty(
+				RuntimeOrigin::signed(4),
+				0,
+				10,
+				b"12345-p1".to_vec()
+			));
+		}
+
+		// A zero fee falls back to the default share of the child-bounty value.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 0));
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().fee, 2);
+
+		// An explicit fee takes precedence.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 1, 8, 3));
+		assert_eq!(ChildBounties::child_bounties(0, 1).unwrap().fee, 3);
+		assert_eq!(ChildBounties::children_curator_fees(0), 5);
+
+		// The default fee can be removed again.
+		assert_ok!(ChildBounties::set_default_child_curator_fee(RuntimeOrigin::signed(4), 0, None));
+		assert_eq!(ChildBounties::default_child_curator_fee(0), None);
+	});
+}
+
 #[test]
 fn children_curator_fee_calculation_test() {
 	// Tests the calculation of subtracting child-bounty curator fee
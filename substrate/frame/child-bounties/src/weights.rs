@@ -60,6 +60,8 @@ pub trait WeightInfo {
 	fn claim_child_bounty() -> Weight;
 	fn close_child_bounty_added() -> Weight;
 	fn close_child_bounty_active() -> Weight;
+	fn set_default_child_curator_fee() -> Weight;
+	fn close_parent_bounty(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_child_bounties using the Substrate node and recommended hardware.
@@ -204,6 +206,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Storage: Bounties Bounties (r:1 w:0)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// Storage: ChildBounties DefaultChildCuratorFee (r:0 w:1)
+	/// Proof: ChildBounties DefaultChildCuratorFee (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	///
+	/// Not benchmarked yet: only the storage accesses of the benchmark are accounted for, until
+	/// this file is regenerated.
+	fn set_default_child_curator_fee() -> Weight {
+		Weight::from_parts(0, 3642)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Bounties Bounties (r:2 w:1)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBountyClosures (r:1 w:1)
+	/// Proof: ChildBounties ChildBountyClosures (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ParentBountiesAwaitingPayout (r:1 w:0)
+	/// Proof: ChildBounties ParentBountiesAwaitingPayout (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBounties (r:{n} w:{n})
+	/// Proof: ChildBounties ChildBounties (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: System Account (r:{n} w:{n})
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
+	/// Proof: ChildBounties ChildrenCuratorFees (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	/// Proof: ChildBounties ParentChildBounties (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ChildBounties DefaultChildCuratorFee (r:0 w:1)
+	/// Proof: ChildBounties DefaultChildCuratorFee (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Bounties BountyDescriptions (r:0 w:1)
+	/// Proof: Bounties BountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBountyDescriptions (r:0 w:{n})
+	/// Proof: ChildBounties ChildBountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 2]`.
+	///
+	/// Not benchmarked yet: only the storage accesses of the benchmark are accounted for, until
+	/// this file is regenerated.
+	fn close_parent_bounty(n: u32, ) -> Weight {
+		Weight::from_parts(0, 8799)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5223).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -347,4 +395,50 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	/// Storage: Bounties Bounties (r:1 w:0)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// Storage: ChildBounties DefaultChildCuratorFee (r:0 w:1)
+	/// Proof: ChildBounties DefaultChildCuratorFee (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	///
+	/// Not benchmarked yet: only the storage accesses of the benchmark are accounted for, until
+	/// this file is regenerated.
+	fn set_default_child_curator_fee() -> Weight {
+		Weight::from_parts(0, 3642)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Bounties Bounties (r:2 w:1)
+	/// Proof: Bounties Bounties (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBountyClosures (r:1 w:1)
+	/// Proof: ChildBounties ChildBountyClosures (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ParentBountiesAwaitingPayout (r:1 w:0)
+	/// Proof: ChildBounties ParentBountiesAwaitingPayout (max_values: None, max_size: Some(12), added: 2487, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBounties (r:{n} w:{n})
+	/// Proof: ChildBounties ChildBounties (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: System Account (r:{n} w:{n})
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildrenCuratorFees (r:1 w:1)
+	/// Proof: ChildBounties ChildrenCuratorFees (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ParentChildBounties (r:1 w:1)
+	/// Proof: ChildBounties ParentChildBounties (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ChildBounties DefaultChildCuratorFee (r:0 w:1)
+	/// Proof: ChildBounties DefaultChildCuratorFee (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Bounties BountyDescriptions (r:0 w:1)
+	/// Proof: Bounties BountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// Storage: ChildBounties ChildBountyDescriptions (r:0 w:{n})
+	/// Proof: ChildBounties ChildBountyDescriptions (max_values: None, max_size: Some(314), added: 2789, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 2]`.
+	///
+	/// Not benchmarked yet: only the storage accesses of the benchmark are accounted for, until
+	/// this file is regenerated.
+	fn close_parent_bounty(n: u32, ) -> Weight {
+		Weight::from_parts(0, 8799)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5223).saturating_mul(n.into()))
+	}
 }
@@ -0,0 +1,48 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for conviction-voting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_conviction_voting::{ClassDelegations, Conviction};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for querying the voting power and delegations of accounts.
+	pub trait ConvictionVotingApi<AccountId, Balance, Class>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+			Class: Codec,
+	{
+		/// Returns the votes `who` can bring to bear on each class of polls when voting with
+		/// `conviction`, including the post-conviction votes delegated to it.
+		fn voting_power(who: AccountId, conviction: Conviction) -> Vec<(Class, Balance)>;
+
+		/// Returns the incoming and outgoing delegations of `who` found in at most `max_records`
+		/// voting records, starting after the record of `start_after`, if any.
+		///
+		/// Also returns the record to continue after, or `None` once all records were scanned.
+		fn delegations(
+			who: AccountId,
+			start_after: Option<(AccountId, Class)>,
+			max_records: u32,
+		) -> (Vec<ClassDelegations<AccountId, Balance, Class>>, Option<(AccountId, Class)>);
+	}
+}
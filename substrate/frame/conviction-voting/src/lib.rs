@@ -40,7 +40,7 @@ use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
 	ArithmeticError, DispatchError, Perbill,
 };
-use sp_std::prelude::*;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 mod conviction;
 mod types;
@@ -50,7 +50,7 @@ pub mod weights;
 pub use self::{
 	conviction::Conviction,
 	pallet::*,
-	types::{Delegations, Tally, UnvoteScope},
+	types::{ClassDelegations, Delegations, Tally, UnvoteScope},
 	vote::{AccountVote, Casting, Delegating, Vote, Voting},
 	weights::WeightInfo,
 };
@@ -387,6 +387,72 @@ pub mod pallet {
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The votes `who` can bring to bear on each class of polls when voting with `conviction`,
+	/// including the post-conviction votes delegated to it.
+	///
+	/// The voting power is zero on the classes on which `who` delegates.
+	pub fn api_voting_power(
+		who: T::AccountId,
+		conviction: Conviction,
+	) -> Vec<(ClassOf<T, I>, BalanceOf<T, I>)> {
+		let own = conviction.votes(T::Currency::total_balance(&who)).votes;
+		T::Polls::classes()
+			.into_iter()
+			.map(|class| {
+				let votes = match VotingFor::<T, I>::get(&who, &class) {
+					Voting::Casting(Casting { delegations, .. }) =>
+						own.saturating_add(delegations.votes),
+					Voting::Delegating(_) => Zero::zero(),
+				};
+				(class, votes)
+			})
+			.collect()
+	}
+
+	/// The incoming and outgoing delegations of `who` found in at most `max_records` voting
+	/// records, starting after the record of `start_after`, if any.
+	///
+	/// Finding the incoming delegations requires scanning all voting records, so they are scanned
+	/// in pages. Also returns the record to continue after, or `None` once all records were
+	/// scanned. The delegations on one class of polls may be spread over several pages.
+	pub fn api_delegations(
+		who: T::AccountId,
+		start_after: Option<(T::AccountId, ClassOf<T, I>)>,
+		max_records: u32,
+	) -> (
+		Vec<ClassDelegations<T::AccountId, BalanceOf<T, I>, ClassOf<T, I>>>,
+		Option<(T::AccountId, ClassOf<T, I>)>,
+	) {
+		let mut records = match start_after {
+			Some((voter, class)) =>
+				VotingFor::<T, I>::iter_from(VotingFor::<T, I>::hashed_key_for(voter, class)),
+			None => VotingFor::<T, I>::iter(),
+		};
+		let mut last = None;
+		let mut delegations = BTreeMap::<ClassOf<T, I>, ClassDelegations<_, _, _>>::new();
+		for (delegator, class, voting) in records.by_ref().take(max_records as usize) {
+			last = Some((delegator.clone(), class.clone()));
+			if let Voting::Delegating(Delegating { balance, target, conviction, .. }) = voting {
+				if delegator != who && target != who {
+					continue
+				}
+				let entry = delegations.entry(class.clone()).or_insert_with(|| ClassDelegations {
+					class,
+					outgoing: None,
+					incoming: Vec::new(),
+				});
+				if delegator == who {
+					entry.outgoing = Some((target, balance, conviction));
+				} else {
+					entry.incoming.push((delegator, balance, conviction));
+				}
+			}
+		}
+
+		let next = if records.next().is_some() { last } else { None };
+		(delegations.into_values().collect(), next)
+	}
+
 	/// Actually enact a vote, if legit.
 	fn try_vote(
 		who: &T::AccountId,
@@ -31,6 +31,7 @@ use sp_runtime::{
 
 use super::*;
 use crate as pallet_conviction_voting;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -51,6 +52,7 @@ impl Contains<RuntimeCall> for BaseFilter {
 	}
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = BaseFilter;
 	type BlockWeights = ();
@@ -541,6 +543,67 @@ fn classwise_delegation_works() {
 	});
 }
 
+#[test]
+fn voting_power_and_delegations_api_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 0, 2, Conviction::Locked2x, 10));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1, 4, Conviction::Locked2x, 10));
+
+		// 20 own votes everywhere but on class 1, plus 5 + 20 delegated votes on class 0.
+		assert_eq!(
+			Voting::api_voting_power(2, Conviction::Locked1x),
+			vec![(0, 45), (1, 0), (2, 20)]
+		);
+		assert_eq!(Voting::api_voting_power(4, Conviction::None), vec![(0, 4), (1, 24), (2, 4)]);
+
+		let (mut delegations, next) = Voting::api_delegations(2, None, 10);
+		assert_eq!(next, None);
+		delegations[0].incoming.sort_by_key(|(who, ..)| *who);
+		assert_eq!(
+			delegations,
+			vec![
+				ClassDelegations {
+					class: 0,
+					outgoing: None,
+					incoming: vec![(1, 5, Conviction::Locked1x), (3, 10, Conviction::Locked2x)],
+				},
+				ClassDelegations {
+					class: 1,
+					outgoing: Some((4, 10, Conviction::Locked2x)),
+					incoming: vec![],
+				},
+			]
+		);
+		assert_eq!(Voting::api_delegations(5, None, 10), (vec![], None));
+
+		// The five voting records are scanned in three pages, which find the same delegations.
+		let mut pages = 0;
+		let mut incoming = vec![];
+		let mut outgoing = vec![];
+		let mut start_after = None;
+		loop {
+			let (delegations, next) = Voting::api_delegations(2, start_after, 2);
+			pages += 1;
+			for d in delegations {
+				incoming.extend(d.incoming.into_iter().map(|i| (d.class, i)));
+				outgoing.extend(d.outgoing.map(|o| (d.class, o)));
+			}
+			if next.is_none() {
+				break
+			}
+			start_after = next;
+		}
+		incoming.sort_by_key(|(_, (who, ..))| *who);
+		assert_eq!(pages, 3);
+		assert_eq!(
+			incoming,
+			vec![(0, (1, 5, Conviction::Locked1x)), (0, (3, 10, Conviction::Locked2x))]
+		);
+		assert_eq!(outgoing, vec![(1, (4, 10, Conviction::Locked2x))]);
+	});
+}
+
 #[test]
 fn redelegation_after_vote_ending_should_keep_lock() {
 	new_test_ext().execute_with(|| {
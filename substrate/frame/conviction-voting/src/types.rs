@@ -26,7 +26,7 @@ use sp_runtime::{
 	traits::{Saturating, Zero},
 	RuntimeDebug,
 };
-use sp_std::{fmt::Debug, marker::PhantomData};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 
 use super::*;
 use crate::{AccountVote, Conviction, Vote};
@@ -254,6 +254,18 @@ impl<Balance: Saturating> Saturating for Delegations<Balance> {
 	}
 }
 
+/// The delegations of an account on a class of polls.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ClassDelegations<AccountId, Balance, Class> {
+	/// The class of polls.
+	pub class: Class,
+	/// The account the voting power is delegated to, with the amount and conviction.
+	pub outgoing: Option<(AccountId, Balance, Conviction)>,
+	/// The accounts delegating their voting power to this account, with the amount and
+	/// conviction of each.
+	pub incoming: Vec<(AccountId, Balance, Conviction)>,
+}
+
 /// Whether an `unvote` operation is able to make actions that are not strictly always in the
 /// interest of an account.
 pub enum UnvoteScope {
@@ -35,6 +35,7 @@ use sp_std::cell::RefCell;
 
 use super::*;
 use crate as pallet_core_fellowship;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -50,6 +51,7 @@ parameter_types! {
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1_000_000, u64::max_value()));
 }
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = Everything;
 	type BlockWeights = ();
@@ -0,0 +1,607 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Delegated Staking Pallet
+//!
+//! Lets accounts delegate funds to an *agent* which stakes them, while the funds stay in the
+//! accounts of the *delegators*.
+//!
+//! ## Overview
+//!
+//! An agent is registered with [`Pallet::register_agent`], along with the account receiving its
+//! staking rewards. Delegated funds are held in the account of the delegator under
+//! [`HoldReason::StakingDelegation`], and the agent is bonded for all of them as a virtual staker
+//! of [`Config::CoreStaking`], i.e. without any funds of its own.
+//!
+//! Delegated funds can be released with [`Pallet::withdraw_delegation`] once the agent has
+//! unbonded them in the staking system. An agent is removed once all of its delegated funds are
+//! released and it is not staking anymore, or with [`Pallet::deregister_agent`] if nothing was
+//! ever delegated to it.
+//!
+//! ## Migration
+//!
+//! An account staking its own funds can be turned into an agent with [`Pallet::migrate_to_agent`].
+//! Its stake is then moved to, and delegated by, a keyless proxy delegator derived from
+//! [`Config::PalletId`], and can be handed over to the actual delegators with
+//! [`Pallet::migrate_delegation`].
+//!
+//! ## Slashing
+//!
+//! Slashes of an agent are reported by the staking system through [`OnStakingUpdate::on_slash`],
+//! so this pallet must be one of its event listeners. They are recorded as the pending slash of
+//! the agent, and applied to its delegators with [`Pallet::delegator_slash`] by the system
+//! managing the agent, which knows how the slash is shared among them. The slashed funds go to
+//! [`Config::OnSlash`], except for the share of the reporter, if any, given by
+//! [`StakingUnchecked::slash_reward_fraction`]. No delegation can be withdrawn from an agent with a
+//! pending slash.
+//!
+//! This pallet has no dispatchables; it is meant to be used through [`DelegationInterface`] by
+//! other pallets, e.g. nomination pools.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{
+		fungible::{
+			hold::{Balanced as FunHoldBalanced, Mutate as FunHoldMutate},
+			Balanced, Credit, Inspect as FunInspect, Mutate as FunMutate,
+		},
+		tokens::{Precision, Preservation},
+		DefensiveResult, Imbalance, OnUnbalanced,
+	},
+	PalletId,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	DispatchResult, RuntimeDebug,
+};
+use sp_staking::{
+	DelegationInterface, DelegationMigrator, EraIndex, OnStakingUpdate, StakingInterface,
+	StakingUnchecked,
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::delegated-staking";
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as FunInspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The types of the accounts derived by this pallet.
+#[derive(Encode, Decode)]
+enum AccountType {
+	/// The proxy delegator holding the funds of a migrated agent.
+	ProxyDelegator,
+}
+
+/// The delegation of a delegator.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Delegation<T: Config> {
+	/// The agent the funds are delegated to.
+	pub agent: T::AccountId,
+	/// The amount delegated, held in the account of the delegator.
+	pub amount: BalanceOf<T>,
+}
+
+/// The ledger of an agent.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct AgentLedger<T: Config> {
+	/// The account receiving the staking rewards of the agent.
+	pub payee: T::AccountId,
+	/// The sum of all the delegations to the agent.
+	pub total_delegated: BalanceOf<T>,
+	/// The amount the agent has been slashed by which is not yet applied to its delegators.
+	pub pending_slash: BalanceOf<T>,
+}
+
+impl<T: Config> AgentLedger<T> {
+	/// The funds backing the stake of the agent.
+	pub fn effective_balance(&self) -> BalanceOf<T> {
+		self.total_delegated.saturating_sub(self.pending_slash)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency delegated funds are held in.
+		type Currency: FunHoldMutate<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ FunMutate<Self::AccountId>
+			+ FunHoldBalanced<Self::AccountId>;
+
+		/// Handler for the funds slashed from delegators, e.g. the treasury, like the slash
+		/// handler of [`Config::CoreStaking`].
+		type OnSlash: OnUnbalanced<Credit<Self::AccountId, Self::Currency>>;
+
+		/// Overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The staking system the agents stake in, as virtual stakers.
+		type CoreStaking: StakingUnchecked<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
+
+		/// The id of this pallet, used to derive the proxy delegators of migrated agents.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+	}
+
+	/// A reason for this pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are delegated to an agent.
+		#[codec(index = 0)]
+		StakingDelegation,
+	}
+
+	/// The delegation of each delegator.
+	#[pallet::storage]
+	pub type Delegators<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, Delegation<T>, OptionQuery>;
+
+	/// The ledger of each registered agent.
+	#[pallet::storage]
+	pub type Agents<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, AgentLedger<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An agent was registered.
+		AgentRegistered { agent: T::AccountId, payee: T::AccountId },
+		/// An agent was removed.
+		AgentRemoved { agent: T::AccountId },
+		/// Funds were delegated to an agent.
+		Delegated { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Delegated funds were released to their delegator.
+		Released { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// A delegator was slashed for a slash of its agent.
+		Slashed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// A staker was migrated to an agent, its stake now delegated by its proxy delegator.
+		AgentMigrated { agent: T::AccountId, payee: T::AccountId, amount: BalanceOf<T> },
+		/// Funds of the proxy delegator of an agent were migrated to a delegator.
+		DelegationMigrated { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already an agent or a delegator.
+		AlreadyRegistered,
+		/// The account is already staking on its own.
+		AlreadyStaking,
+		/// The reward account of an agent can't be the agent itself.
+		InvalidRewardDestination,
+		/// The account is not a registered agent.
+		NotAgent,
+		/// The account has no delegation to the given agent.
+		NotDelegator,
+		/// A delegator can only delegate to a single agent.
+		InvalidDelegation,
+		/// The agent has a slash not yet applied to its delegators.
+		UnappliedSlash,
+		/// Not enough delegated or unbonded funds.
+		NotEnoughFunds,
+		/// The agent has no pending slash.
+		NothingToSlash,
+		/// The account is not staking, or not with funds of its own.
+		NotDirectStaker,
+		/// The agent still has delegated funds or is still staking.
+		AgentNotEmpty,
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Register `agent`, forwarding its staking rewards to `payee`.
+		///
+		/// `agent` should be an account nobody holds the key of, e.g. derived from the id of a
+		/// pallet. It can't be a delegator or a staker already.
+		pub fn register_agent(agent: &T::AccountId, payee: &T::AccountId) -> DispatchResult {
+			ensure!(
+				!Agents::<T>::contains_key(agent) && !Delegators::<T>::contains_key(agent),
+				Error::<T>::AlreadyRegistered
+			);
+			ensure!(T::CoreStaking::status(agent).is_err(), Error::<T>::AlreadyStaking);
+			ensure!(agent != payee, Error::<T>::InvalidRewardDestination);
+
+			// the agent has no funds of its own, but must exist to be bonded.
+			frame_system::Pallet::<T>::inc_providers(agent);
+			Agents::<T>::insert(
+				agent,
+				AgentLedger {
+					payee: payee.clone(),
+					total_delegated: Zero::zero(),
+					pending_slash: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::AgentRegistered {
+				agent: agent.clone(),
+				payee: payee.clone(),
+			});
+			Ok(())
+		}
+
+		/// Remove `agent`, to which nothing is delegated and which is not staking.
+		///
+		/// Agents are removed once all of their delegated funds are withdrawn, so this is only
+		/// needed for agents nothing was ever delegated to.
+		pub fn deregister_agent(agent: &T::AccountId) -> DispatchResult {
+			let ledger = Agents::<T>::get(agent).ok_or(Error::<T>::NotAgent)?;
+			ensure!(
+				ledger.total_delegated.is_zero() && T::CoreStaking::status(agent).is_err(),
+				Error::<T>::AgentNotEmpty
+			);
+
+			Self::remove_agent(agent);
+			Ok(())
+		}
+
+		/// Turn `agent`, staking its own funds, into an agent forwarding its rewards to `payee`.
+		///
+		/// The total stake of `agent` is transferred to its [`Self::proxy_delegator`], and
+		/// delegated to `agent` from there.
+		pub fn migrate_to_agent(agent: &T::AccountId, payee: &T::AccountId) -> DispatchResult {
+			ensure!(
+				!Agents::<T>::contains_key(agent) && !Delegators::<T>::contains_key(agent),
+				Error::<T>::AlreadyRegistered
+			);
+			ensure!(
+				T::CoreStaking::status(agent).is_ok() && !T::CoreStaking::is_virtual_staker(agent),
+				Error::<T>::NotDirectStaker
+			);
+			ensure!(agent != payee, Error::<T>::InvalidRewardDestination);
+
+			let stake = T::CoreStaking::total_stake(agent)?;
+			T::CoreStaking::migrate_to_virtual_staker(agent, payee)?;
+
+			// the agent must outlive its funds, like the agents registered directly.
+			frame_system::Pallet::<T>::inc_providers(agent);
+			let proxy = Self::proxy_delegator(agent);
+			T::Currency::transfer(agent, &proxy, stake, Preservation::Expendable)?;
+			T::Currency::hold(&HoldReason::StakingDelegation.into(), &proxy, stake)?;
+
+			Delegators::<T>::insert(&proxy, Delegation { agent: agent.clone(), amount: stake });
+			Agents::<T>::insert(
+				agent,
+				AgentLedger {
+					payee: payee.clone(),
+					total_delegated: stake,
+					pending_slash: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::AgentMigrated {
+				agent: agent.clone(),
+				payee: payee.clone(),
+				amount: stake,
+			});
+			Ok(())
+		}
+
+		/// Move `amount` of the delegation of the proxy delegator of `agent` to `delegator`.
+		///
+		/// The funds are transferred to `delegator` and held there, so the stake of `agent` is
+		/// unchanged.
+		pub fn migrate_delegation(
+			agent: &T::AccountId,
+			delegator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(Agents::<T>::contains_key(agent), Error::<T>::NotAgent);
+			let proxy = Self::proxy_delegator(agent);
+			let mut proxy_delegation = Delegators::<T>::get(&proxy)
+				.filter(|d| &d.agent == agent)
+				.ok_or(Error::<T>::NotDelegator)?;
+			ensure!(proxy_delegation.amount >= amount, Error::<T>::NotEnoughFunds);
+
+			ensure!(
+				delegator != &proxy && !Agents::<T>::contains_key(delegator),
+				Error::<T>::InvalidDelegation
+			);
+			let mut delegation = match Delegators::<T>::get(delegator) {
+				Some(delegation) => {
+					ensure!(&delegation.agent == agent, Error::<T>::InvalidDelegation);
+					delegation
+				},
+				None => Delegation { agent: agent.clone(), amount: Zero::zero() },
+			};
+
+			let reason = HoldReason::StakingDelegation.into();
+			T::Currency::release(&reason, &proxy, amount, Precision::Exact)?;
+			T::Currency::transfer(&proxy, delegator, amount, Preservation::Expendable)?;
+			T::Currency::hold(&reason, delegator, amount)?;
+
+			proxy_delegation.amount.saturating_reduce(amount);
+			delegation.amount.saturating_accrue(amount);
+			if proxy_delegation.amount.is_zero() {
+				Delegators::<T>::remove(&proxy);
+			} else {
+				Delegators::<T>::insert(&proxy, proxy_delegation);
+			}
+			Delegators::<T>::insert(delegator, delegation);
+
+			Self::deposit_event(Event::<T>::DelegationMigrated {
+				agent: agent.clone(),
+				delegator: delegator.clone(),
+				amount,
+			});
+			Ok(())
+		}
+
+		/// The keyless account holding the funds of the migrated `agent` until they are handed
+		/// over to its delegators.
+		pub fn proxy_delegator(agent: &T::AccountId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating((AccountType::ProxyDelegator, agent))
+		}
+
+		/// Delegate `amount` of the funds of `delegator` to `agent`, and bond it.
+		pub fn delegate(
+			delegator: &T::AccountId,
+			agent: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let mut ledger = Agents::<T>::get(agent).ok_or(Error::<T>::NotAgent)?;
+			ensure!(!Agents::<T>::contains_key(delegator), Error::<T>::InvalidDelegation);
+			let mut delegation = match Delegators::<T>::get(delegator) {
+				Some(delegation) => {
+					ensure!(&delegation.agent == agent, Error::<T>::InvalidDelegation);
+					delegation
+				},
+				None => Delegation { agent: agent.clone(), amount: Zero::zero() },
+			};
+
+			T::Currency::hold(&HoldReason::StakingDelegation.into(), delegator, amount)?;
+
+			if T::CoreStaking::stake(agent).is_ok() {
+				T::CoreStaking::bond_extra(agent, amount)?;
+			} else {
+				T::CoreStaking::virtual_bond(agent, amount, &ledger.payee)?;
+			}
+
+			delegation.amount.saturating_accrue(amount);
+			ledger.total_delegated.saturating_accrue(amount);
+			Delegators::<T>::insert(delegator, delegation);
+			Agents::<T>::insert(agent, ledger);
+
+			Self::deposit_event(Event::<T>::Delegated {
+				agent: agent.clone(),
+				delegator: delegator.clone(),
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Release `amount` of the funds `delegator` delegated to `agent`.
+		///
+		/// The funds of `agent` unlocked in the staking system are withdrawn first, using
+		/// `num_slashing_spans`. Only funds not bonded by `agent` can be released.
+		pub fn withdraw_delegation(
+			delegator: &T::AccountId,
+			agent: &T::AccountId,
+			amount: BalanceOf<T>,
+			num_slashing_spans: u32,
+		) -> DispatchResult {
+			let mut ledger = Agents::<T>::get(agent).ok_or(Error::<T>::NotAgent)?;
+			let mut delegation = Delegators::<T>::get(delegator)
+				.filter(|d| &d.agent == agent)
+				.ok_or(Error::<T>::NotDelegator)?;
+			ensure!(ledger.pending_slash.is_zero(), Error::<T>::UnappliedSlash);
+			ensure!(delegation.amount >= amount, Error::<T>::NotEnoughFunds);
+
+			if T::CoreStaking::stake(agent).is_ok() {
+				T::CoreStaking::withdraw_unbonded(agent.clone(), num_slashing_spans)?;
+			}
+			let bonded = T::CoreStaking::total_stake(agent).unwrap_or_default();
+			ensure!(
+				ledger.total_delegated.saturating_sub(bonded) >= amount,
+				Error::<T>::NotEnoughFunds
+			);
+
+			T::Currency::release(
+				&HoldReason::StakingDelegation.into(),
+				delegator,
+				amount,
+				Precision::Exact,
+			)?;
+
+			delegation.amount.saturating_reduce(amount);
+			ledger.total_delegated.saturating_reduce(amount);
+			if delegation.amount.is_zero() {
+				Delegators::<T>::remove(delegator);
+			} else {
+				Delegators::<T>::insert(delegator, delegation);
+			}
+			if ledger.total_delegated.is_zero() && T::CoreStaking::status(agent).is_err() {
+				Self::remove_agent(agent);
+			} else {
+				Agents::<T>::insert(agent, ledger);
+			}
+
+			Self::deposit_event(Event::<T>::Released {
+				agent: agent.clone(),
+				delegator: delegator.clone(),
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Apply up to `value` of the pending slash of `agent` to the delegated funds of
+		/// `delegator`.
+		///
+		/// The slashed funds go to [`Config::OnSlash`], after paying `maybe_reporter`, if any, its
+		/// reward.
+		pub fn delegator_slash(
+			agent: &T::AccountId,
+			delegator: &T::AccountId,
+			value: BalanceOf<T>,
+			maybe_reporter: Option<T::AccountId>,
+		) -> DispatchResult {
+			let mut ledger = Agents::<T>::get(agent).ok_or(Error::<T>::NotAgent)?;
+			let mut delegation = Delegators::<T>::get(delegator)
+				.filter(|d| &d.agent == agent)
+				.ok_or(Error::<T>::NotDelegator)?;
+			ensure!(!ledger.pending_slash.is_zero(), Error::<T>::NothingToSlash);
+
+			let amount = value.min(ledger.pending_slash).min(delegation.amount);
+			let (mut credit, _missing) =
+				T::Currency::slash(&HoldReason::StakingDelegation.into(), delegator, amount);
+			let slashed = credit.peek();
+
+			if let Some(reporter) = maybe_reporter {
+				let reward = T::CoreStaking::slash_reward_fraction() * slashed;
+				let (reporter_reward, rest) = credit.split(reward);
+				credit = rest;
+				// a reward too small to create the account of the reporter is slashed as well.
+				if let Err(reporter_reward) = T::Currency::resolve(&reporter, reporter_reward) {
+					credit.subsume(reporter_reward);
+				}
+			}
+			T::OnSlash::on_unbalanced(credit);
+
+			delegation.amount.saturating_reduce(slashed);
+			ledger.total_delegated.saturating_reduce(slashed);
+			ledger.pending_slash.saturating_reduce(slashed);
+			if delegation.amount.is_zero() {
+				Delegators::<T>::remove(delegator);
+			} else {
+				Delegators::<T>::insert(delegator, delegation);
+			}
+			Agents::<T>::insert(agent, ledger);
+
+			Self::deposit_event(Event::<T>::Slashed {
+				agent: agent.clone(),
+				delegator: delegator.clone(),
+				amount: slashed,
+			});
+			Ok(())
+		}
+
+		/// Remove `agent`, dropping the provider added when it was registered or migrated.
+		fn remove_agent(agent: &T::AccountId) {
+			Agents::<T>::remove(agent);
+			let _ = frame_system::Pallet::<T>::dec_providers(agent).defensive();
+
+			Self::deposit_event(Event::<T>::AgentRemoved { agent: agent.clone() });
+		}
+	}
+}
+
+impl<T: Config> DelegationInterface for Pallet<T> {
+	type Balance = BalanceOf<T>;
+	type AccountId = T::AccountId;
+
+	fn agent_balance(agent: &Self::AccountId) -> Self::Balance {
+		Agents::<T>::get(agent).map(|l| l.effective_balance()).unwrap_or_default()
+	}
+
+	fn delegator_balance(delegator: &Self::AccountId) -> Self::Balance {
+		Delegators::<T>::get(delegator).map(|d| d.amount).unwrap_or_default()
+	}
+
+	fn delegate(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::delegate(delegator, agent, amount)
+	}
+
+	fn withdraw_delegation(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		amount: Self::Balance,
+		num_slashing_spans: u32,
+	) -> DispatchResult {
+		Self::withdraw_delegation(delegator, agent, amount, num_slashing_spans)
+	}
+
+	fn has_pending_slash(agent: &Self::AccountId) -> bool {
+		Agents::<T>::get(agent).map_or(false, |l| !l.pending_slash.is_zero())
+	}
+
+	fn delegator_slash(
+		agent: &Self::AccountId,
+		delegator: &Self::AccountId,
+		value: Self::Balance,
+		maybe_reporter: Option<Self::AccountId>,
+	) -> DispatchResult {
+		Self::delegator_slash(agent, delegator, value, maybe_reporter)
+	}
+}
+
+impl<T: Config> DelegationMigrator for Pallet<T> {
+	fn migrate_nominator_to_agent(
+		agent: &Self::AccountId,
+		payee: &Self::AccountId,
+	) -> DispatchResult {
+		Self::migrate_to_agent(agent, payee)
+	}
+
+	fn migrate_delegation(
+		agent: &Self::AccountId,
+		delegator: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::migrate_delegation(agent, delegator, amount)
+	}
+
+	fn pending_migration_balance(agent: &Self::AccountId) -> Self::Balance {
+		Delegators::<T>::get(Self::proxy_delegator(agent))
+			.filter(|d| &d.agent == agent)
+			.map(|d| d.amount)
+			.unwrap_or_default()
+	}
+
+	fn slash_pending_migration(agent: &Self::AccountId, value: Self::Balance) -> DispatchResult {
+		Self::delegator_slash(agent, &Self::proxy_delegator(agent), value, None)
+	}
+}
+
+impl<T: Config> OnStakingUpdate<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn on_slash(
+		who: &T::AccountId,
+		_slashed_active: BalanceOf<T>,
+		_slashed_unlocking: &BTreeMap<EraIndex, BalanceOf<T>>,
+		slashed_total: BalanceOf<T>,
+	) {
+		Agents::<T>::mutate(who, |maybe_ledger| {
+			if let Some(ledger) = maybe_ledger {
+				log::debug!(target: LOG_TARGET, "agent {:?} slashed by {:?}", who, slashed_total);
+				ledger.pending_slash.saturating_accrue(slashed_total);
+			}
+		});
+	}
+}
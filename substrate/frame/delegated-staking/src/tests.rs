@@ -0,0 +1,303 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use sp_runtime::{DispatchError, Perbill};
+
+#[test]
+fn register_agent_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			events_since_last_call(),
+			vec![Event::AgentRegistered { agent: AGENT, payee: PAYEE }]
+		);
+
+		// can't register twice.
+		assert_noop!(
+			DelegatedStaking::register_agent(&AGENT, &PAYEE),
+			Error::<Runtime>::AlreadyRegistered
+		);
+		// rewards can't go to the agent itself.
+		assert_noop!(
+			DelegatedStaking::register_agent(&300, &300),
+			Error::<Runtime>::InvalidRewardDestination
+		);
+		// a staker can't become an agent.
+		assert_ok!(<Staking as StakingInterface>::bond(&200, 100, &200));
+		assert_noop!(
+			DelegatedStaking::register_agent(&200, &PAYEE),
+			Error::<Runtime>::AlreadyStaking
+		);
+	});
+}
+
+#[test]
+fn delegate_bonds_agent_virtually() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		assert_ok!(DelegatedStaking::delegate(&201, &AGENT, 50));
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 10));
+
+		// funds stay with the delegators, on hold.
+		assert_eq!(held_balance(&200), 110);
+		assert_eq!(held_balance(&201), 50);
+		assert_eq!(Balances::balance(&AGENT), 0);
+
+		// the agent stakes all of them.
+		assert!(<Staking as StakingUnchecked>::is_virtual_staker(&AGENT));
+		assert_eq!(Staking::total_stake(&AGENT), Ok(160));
+		assert_eq!(DelegatedStaking::agent_balance(&AGENT), 160);
+		assert_eq!(DelegatedStaking::delegator_balance(&200), 110);
+
+		assert_eq!(
+			events_since_last_call(),
+			vec![
+				Event::AgentRegistered { agent: AGENT, payee: PAYEE },
+				Event::Delegated { agent: AGENT, delegator: 200, amount: 100 },
+				Event::Delegated { agent: AGENT, delegator: 201, amount: 50 },
+				Event::Delegated { agent: AGENT, delegator: 200, amount: 10 },
+			]
+		);
+	});
+}
+
+#[test]
+fn delegate_checks() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(DelegatedStaking::delegate(&200, &300, 100), Error::<Runtime>::NotAgent);
+		// can't delegate more than the free balance.
+		assert!(DelegatedStaking::delegate(&200, &AGENT, 2000).is_err());
+
+		// delegators have a single agent.
+		assert_ok!(DelegatedStaking::register_agent(&102, &PAYEE));
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		assert_noop!(
+			DelegatedStaking::delegate(&200, &102, 100),
+			Error::<Runtime>::InvalidDelegation
+		);
+		// agents can't delegate.
+		assert_noop!(
+			DelegatedStaking::delegate(&102, &AGENT, 100),
+			Error::<Runtime>::InvalidDelegation
+		);
+	});
+}
+
+#[test]
+fn withdraw_delegation_needs_unbonded_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		assert_ok!(DelegatedStaking::delegate(&201, &AGENT, 100));
+		assert_noop!(
+			DelegatedStaking::withdraw_delegation(&200, &AGENT, 50, 0),
+			Error::<Runtime>::NotEnoughFunds
+		);
+
+		// unbonded, but still unlocking.
+		assert_ok!(<Staking as StakingInterface>::unbond(&AGENT, 50));
+		assert_noop!(
+			DelegatedStaking::withdraw_delegation(&200, &AGENT, 50, 0),
+			Error::<Runtime>::NotEnoughFunds
+		);
+
+		pallet_staking::CurrentEra::<Runtime>::put(BondingDuration::get());
+		assert_noop!(
+			DelegatedStaking::withdraw_delegation(&202, &AGENT, 50, 0),
+			Error::<Runtime>::NotDelegator
+		);
+		assert_ok!(DelegatedStaking::withdraw_delegation(&200, &AGENT, 50, 0));
+
+		assert_eq!(held_balance(&200), 50);
+		assert_eq!(Balances::free_balance(&200), 950);
+		assert_eq!(Staking::total_stake(&AGENT), Ok(150));
+		assert_eq!(DelegatedStaking::agent_balance(&AGENT), 150);
+		// nothing more is unbonded.
+		assert_noop!(
+			DelegatedStaking::withdraw_delegation(&201, &AGENT, 1, 0),
+			Error::<Runtime>::NotEnoughFunds
+		);
+	});
+}
+
+#[test]
+fn agent_is_removed_once_fully_withdrawn() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(System::providers(&AGENT), 1);
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		assert_ok!(<Staking as StakingInterface>::unbond(&AGENT, 100));
+		pallet_staking::CurrentEra::<Runtime>::put(BondingDuration::get());
+
+		assert_ok!(DelegatedStaking::withdraw_delegation(&200, &AGENT, 100, 0));
+		assert!(Staking::status(&AGENT).is_err());
+		assert!(!Agents::<Runtime>::contains_key(&AGENT));
+		assert_eq!(System::providers(&AGENT), 0);
+		assert_eq!(held_balance(&200), 0);
+	});
+}
+
+#[test]
+fn slash_is_applied_to_delegators() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		assert_ok!(DelegatedStaking::delegate(&201, &AGENT, 100));
+		let issuance = Balances::total_issuance();
+
+		assert_noop!(
+			DelegatedStaking::delegator_slash(&AGENT, &200, 10, None),
+			Error::<Runtime>::NothingToSlash
+		);
+
+		// the staking system reports a slash of the agent.
+		<DelegatedStaking as OnStakingUpdate<_, _>>::on_slash(&AGENT, 40, &Default::default(), 40);
+		assert!(DelegatedStaking::has_pending_slash(&AGENT));
+		assert_eq!(DelegatedStaking::agent_balance(&AGENT), 160);
+
+		// nothing can be withdrawn until the slash is applied.
+		assert_noop!(
+			DelegatedStaking::withdraw_delegation(&200, &AGENT, 10, 0),
+			Error::<Runtime>::UnappliedSlash
+		);
+
+		assert_ok!(DelegatedStaking::delegator_slash(&AGENT, &200, 20, None));
+		// no more than the pending slash is applied.
+		assert_ok!(DelegatedStaking::delegator_slash(&AGENT, &201, 30, None));
+
+		assert!(!DelegatedStaking::has_pending_slash(&AGENT));
+		assert_eq!(held_balance(&200), 80);
+		assert_eq!(held_balance(&201), 80);
+		assert_eq!(DelegatedStaking::agent_balance(&AGENT), 160);
+		assert_eq!(Balances::total_issuance(), issuance - 40);
+		assert_eq!(SlashedFunds::get(), 40);
+		assert_eq!(
+			events_since_last_call().into_iter().rev().take(2).collect::<Vec<_>>(),
+			vec![
+				Event::Slashed { agent: AGENT, delegator: 201, amount: 20 },
+				Event::Slashed { agent: AGENT, delegator: 200, amount: 20 },
+			]
+		);
+	});
+}
+
+#[test]
+fn reporter_is_rewarded_from_slash() {
+	new_test_ext().execute_with(|| {
+		pallet_staking::SlashRewardFraction::<Runtime>::put(Perbill::from_percent(10));
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		let issuance = Balances::total_issuance();
+
+		<DelegatedStaking as OnStakingUpdate<_, _>>::on_slash(&AGENT, 40, &Default::default(), 40);
+		assert_ok!(DelegatedStaking::delegator_slash(&AGENT, &200, 40, Some(202)));
+
+		assert_eq!(held_balance(&200), 60);
+		assert_eq!(Balances::free_balance(&202), 1004);
+		assert_eq!(SlashedFunds::get(), 36);
+		assert_eq!(Balances::total_issuance(), issuance - 36);
+	});
+}
+
+#[test]
+fn agent_without_delegations_can_be_deregistered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DelegatedStaking::register_agent(&300, &PAYEE));
+		assert_eq!(System::providers(&300), 1);
+		assert_ok!(DelegatedStaking::delegate(&200, &AGENT, 100));
+		assert_noop!(DelegatedStaking::deregister_agent(&AGENT), Error::<Runtime>::AgentNotEmpty);
+
+		assert_ok!(DelegatedStaking::deregister_agent(&300));
+		assert!(!Agents::<Runtime>::contains_key(&300));
+		assert_eq!(System::providers(&300), 0);
+		assert_eq!(events_since_last_call().last(), Some(&Event::AgentRemoved { agent: 300 }));
+		assert_noop!(DelegatedStaking::deregister_agent(&300), Error::<Runtime>::NotAgent);
+	});
+}
+
+#[test]
+fn migrate_to_agent_works() {
+	new_test_ext().execute_with(|| {
+		// only direct stakers can be migrated.
+		assert_noop!(
+			DelegatedStaking::migrate_to_agent(&202, &PAYEE),
+			Error::<Runtime>::NotDirectStaker
+		);
+		assert_ok!(<Staking as StakingInterface>::bond(&202, 500, &202));
+		assert_noop!(
+			DelegatedStaking::migrate_to_agent(&202, &202),
+			Error::<Runtime>::InvalidRewardDestination
+		);
+
+		assert_ok!(DelegatedStaking::migrate_to_agent(&202, &PAYEE));
+		let proxy = DelegatedStaking::proxy_delegator(&202);
+
+		// the stake is held by the proxy delegator, and nothing is locked anymore.
+		assert!(<Staking as StakingUnchecked>::is_virtual_staker(&202));
+		assert!(Balances::locks(&202).is_empty());
+		assert_eq!(Balances::balance(&202), 500);
+		assert_eq!(held_balance(&proxy), 500);
+		assert_eq!(Staking::total_stake(&202), Ok(500));
+		assert_eq!(DelegatedStaking::agent_balance(&202), 500);
+		assert_eq!(DelegatedStaking::pending_migration_balance(&202), 500);
+
+		// the delegation is handed over to the delegators.
+		assert_ok!(DelegatedStaking::migrate_delegation(&202, &200, 100));
+		assert_eq!(held_balance(&proxy), 400);
+		assert_eq!(held_balance(&200), 100);
+		assert_eq!(DelegatedStaking::delegator_balance(&200), 100);
+		assert_eq!(DelegatedStaking::pending_migration_balance(&202), 400);
+		assert_eq!(DelegatedStaking::agent_balance(&202), 500);
+
+		assert_noop!(
+			DelegatedStaking::migrate_delegation(&202, &201, 401),
+			Error::<Runtime>::NotEnoughFunds
+		);
+		// a delegator can only delegate to a single agent.
+		assert_ok!(DelegatedStaking::delegate(&201, &AGENT, 10));
+		assert_noop!(
+			DelegatedStaking::migrate_delegation(&202, &201, 10),
+			Error::<Runtime>::InvalidDelegation
+		);
+		// agents registered directly have no proxy delegator.
+		assert_noop!(
+			DelegatedStaking::migrate_delegation(&AGENT, &200, 10),
+			Error::<Runtime>::NotDelegator
+		);
+
+		assert_ok!(DelegatedStaking::migrate_delegation(&202, &200, 400));
+		assert_eq!(DelegatedStaking::pending_migration_balance(&202), 0);
+		assert!(!Delegators::<Runtime>::contains_key(&proxy));
+		assert_eq!(
+			events_since_last_call().into_iter().rev().take(2).collect::<Vec<_>>(),
+			vec![
+				Event::DelegationMigrated { agent: 202, delegator: 200, amount: 400 },
+				Event::Delegated { agent: AGENT, delegator: 201, amount: 10 },
+			]
+		);
+	});
+}
+
+#[test]
+fn slash_of_other_stakers_is_ignored() {
+	new_test_ext().execute_with(|| {
+		<DelegatedStaking as OnStakingUpdate<_, _>>::on_slash(&200, 40, &Default::default(), 40);
+		assert!(!Agents::<Runtime>::contains_key(200));
+		assert_eq!(
+			DelegatedStaking::delegator_slash(&AGENT, &200, 10, None),
+			Err(DispatchError::from(Error::<Runtime>::NotDelegator))
+		);
+	});
+}
@@ -22,7 +22,9 @@ use crate::{
 	ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
 };
 use codec::Encode;
-use frame_election_provider_support::{NposSolution, NposSolver, PerThing128, VoteWeight};
+use frame_election_provider_support::{
+	miner, BalancingConfig, NposSolution, NposSolver, PerThing128, VoteWeight,
+};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -32,8 +34,8 @@ use frame_support::{
 use frame_system::{offchain::SubmitTransaction, pallet_prelude::BlockNumberFor};
 use scale_info::TypeInfo;
 use sp_npos_elections::{
-	assignment_ratio_to_staked_normalized, assignment_staked_to_ratio_normalized, ElectionResult,
-	ElectionScore, EvaluateSupport,
+	assignment_ratio_to_staked_normalized, assignment_staked_to_ratio_normalized, seq_phragmen,
+	ElectionResult, ElectionScore, EvaluateSupport,
 };
 use sp_runtime::{
 	offchain::storage::{MutateStorageError, StorageValueRef},
@@ -67,6 +69,9 @@ pub type Assignment<T> =
 /// particular runtime `T`.
 pub type IndexAssignmentOf<T> = frame_election_provider_support::IndexAssignmentOf<SolutionOf<T>>;
 
+/// The accuracy of the solution mined by a [`MinerConfig`].
+pub type MinerAccuracyOf<T> = <<T as MinerConfig>::Solution as NposSolution>::Accuracy;
+
 /// Error type of the pallet's [`crate::Config::Solver`].
 pub type SolverErrorOf<T> = <<T as Config>::Solver as NposSolver>::Error;
 /// Error type for operations related to the OCW npos solution miner.
@@ -108,25 +113,25 @@ impl From<FeasibilityError> for MinerError {
 	}
 }
 
-/// Reports the trimming result of a mined solution
-#[derive(Debug, Clone)]
-pub struct TrimmingStatus {
-	weight: usize,
-	length: usize,
-}
-
-impl TrimmingStatus {
-	pub fn is_trimmed(&self) -> bool {
-		self.weight > 0 || self.length > 0
-	}
+pub use frame_election_provider_support::miner::TrimmingStatus;
 
-	pub fn trimmed_weight(&self) -> usize {
-		self.weight
-	}
-
-	pub fn trimmed_length(&self) -> usize {
-		self.length
-	}
+/// The outcome of mining a solution without submitting it, see [`Miner::dry_run_with_snapshot`].
+#[derive(frame_support::DebugNoBound, frame_support::PartialEqNoBound)]
+pub struct MinerDryRun<T: MinerConfig> {
+	/// The mined solution.
+	pub solution: T::Solution,
+	/// The score of the solution.
+	pub score: ElectionScore,
+	/// The size of the snapshot the solution was mined from.
+	pub size: SolutionOrSnapshotSize,
+	/// The voters trimmed to fit the weight and length limits.
+	pub trimming: TrimmingStatus,
+	/// The encoded length of the solution.
+	pub length: u32,
+	/// The weight of the solution, as computed by [`MinerConfig::solution_weight`].
+	pub weight: Weight,
+	/// The result of the feasibility check of the solution against the snapshot.
+	pub feasibility: Result<(), FeasibilityError>,
 }
 
 /// Save a given call into OCW storage.
@@ -455,6 +460,78 @@ impl<T: MinerConfig> Miner<T> {
 			})
 	}
 
+	/// Same as [`Self::mine_solution_with_snapshot`], but mines with sequential phragmen followed
+	/// by the given number of `balancing` iterations.
+	///
+	/// This allows off-chain tooling to choose the balancing effort per run instead of through
+	/// the solver type.
+	pub fn mine_balanced_solution_with_snapshot(
+		voters: Vec<(T::AccountId, VoteWeight, BoundedVec<T::AccountId, T::MaxVotesPerVoter>)>,
+		targets: Vec<T::AccountId>,
+		desired_targets: u32,
+		balancing: Option<BalancingConfig>,
+	) -> Result<(SolutionOf<T>, ElectionScore, SolutionOrSnapshotSize, TrimmingStatus), MinerError>
+	{
+		seq_phragmen::<_, MinerAccuracyOf<T>>(
+			desired_targets as usize,
+			targets.clone(),
+			voters.clone(),
+			balancing,
+		)
+		.map_err(|e| {
+			log_no_system!(error, "solver error: {:?}", e);
+			MinerError::Solver
+		})
+		.and_then(|e| {
+			Self::prepare_election_result_with_snapshot::<MinerAccuracyOf<T>>(
+				e,
+				voters,
+				targets,
+				desired_targets,
+			)
+		})
+	}
+
+	/// Mine a solution with the solver `S` and report its score, size, trimming, weight and
+	/// feasibility, without submitting it anywhere.
+	///
+	/// `round` and `minimum_untrusted_score` are those the solution would be checked against on
+	/// chain.
+	pub fn dry_run_with_snapshot<S>(
+		voters: Vec<MinerVoterOf<T>>,
+		targets: Vec<T::AccountId>,
+		desired_targets: u32,
+		round: u32,
+		minimum_untrusted_score: Option<ElectionScore>,
+	) -> Result<MinerDryRun<T>, MinerError>
+	where
+		S: NposSolver<AccountId = T::AccountId>,
+	{
+		let (solution, score, size, trimming) = Self::mine_solution_with_snapshot::<S>(
+			voters.clone(),
+			targets.clone(),
+			desired_targets,
+		)?;
+		let length = solution.encoded_size().saturated_into();
+		let weight = T::solution_weight(
+			size.voters,
+			size.targets,
+			solution.voter_count().saturated_into(),
+			desired_targets,
+		);
+		let feasibility = Self::feasibility_check(
+			RawSolution { solution: solution.clone(), score, round },
+			ElectionCompute::Unsigned,
+			desired_targets,
+			RoundSnapshot { voters, targets },
+			round,
+			minimum_untrusted_score,
+		)
+		.map(|_| ());
+
+		Ok(MinerDryRun { solution, score, size, trimming, length, weight, feasibility })
+	}
+
 	/// Convert a raw solution from [`sp_npos_elections::ElectionResult`] to [`RawSolution`], which
 	/// is ready to be submitted to the chain.
 	///
@@ -493,22 +570,19 @@ impl<T: MinerConfig> Miner<T> {
 			sp_npos_elections::reduce(&mut staked);
 
 			// Sort the assignments by reversed voter stake. This ensures that we can efficiently
-			// truncate the list.
-			staked.sort_by_key(
-				|sp_npos_elections::StakedAssignment::<T::AccountId> { who, .. }| {
-					// though staked assignments are expressed in terms of absolute stake, we'd
-					// still need to iterate over all votes in order to actually compute the total
-					// stake. it should be faster to look it up from the cache.
-					let stake = cache
-						.get(who)
-						.map(|idx| {
-							let (_, stake, _) = voters[*idx];
-							stake
-						})
-						.unwrap_or_default();
-					sp_std::cmp::Reverse(stake)
-				},
-			);
+			// and deterministically truncate the list.
+			miner::sort_for_trimming(&mut staked, |who| {
+				// though staked assignments are expressed in terms of absolute stake, we'd
+				// still need to iterate over all votes in order to actually compute the total
+				// stake. it should be faster to look it up from the cache.
+				cache
+					.get(who)
+					.map(|idx| {
+						let (_, stake, _) = voters[*idx];
+						stake
+					})
+					.unwrap_or_default()
+			});
 
 			// convert back.
 			assignment_staked_to_ratio_normalized(staked)?
@@ -542,7 +616,7 @@ impl<T: MinerConfig> Miner<T> {
 		// re-calc score.
 		let score = solution.clone().score(stake_of, voter_at, target_at)?;
 
-		let is_trimmed = TrimmingStatus { weight: weight_trimmed, length: length_trimmed };
+		let is_trimmed = TrimmingStatus::new(weight_trimmed, length_trimmed);
 
 		Ok((solution, score, size, is_trimmed))
 	}
@@ -566,58 +640,16 @@ impl<T: MinerConfig> Miner<T> {
 		assignments: &mut Vec<IndexAssignmentOf<T>>,
 		encoded_size_of: impl Fn(&[IndexAssignmentOf<T>]) -> Result<usize, sp_npos_elections::Error>,
 	) -> Result<usize, MinerError> {
-		// Perform a binary search for the max subset of which can fit into the allowed
-		// length. Having discovered that, we can truncate efficiently.
-		let max_allowed_length: usize = max_allowed_length.saturated_into();
-		let mut high = assignments.len();
-		let mut low = 0;
-
-		// not much we can do if assignments are already empty.
-		if high == low {
-			return Ok(0)
-		}
-
-		while high - low > 1 {
-			let test = (high + low) / 2;
-			if encoded_size_of(&assignments[..test])? <= max_allowed_length {
-				low = test;
-			} else {
-				high = test;
-			}
-		}
-		let maximum_allowed_voters = if low < assignments.len() &&
-			encoded_size_of(&assignments[..low + 1])? <= max_allowed_length
-		{
-			low + 1
-		} else {
-			low
-		};
-
-		// ensure our post-conditions are correct
-		debug_assert!(
-			encoded_size_of(&assignments[..maximum_allowed_voters]).unwrap() <= max_allowed_length
-		);
-		debug_assert!(if maximum_allowed_voters < assignments.len() {
-			encoded_size_of(&assignments[..maximum_allowed_voters + 1]).unwrap() >
-				max_allowed_length
-		} else {
-			true
-		});
-
-		// NOTE: before this point, every access was immutable.
-		// after this point, we never error.
-		// check before edit.
-
-		let remove = assignments.len().saturating_sub(maximum_allowed_voters);
+		let assignments_len = assignments.len();
+		let remove = miner::trim_to_length(max_allowed_length, assignments, encoded_size_of)?;
 
 		log_no_system!(
 			debug,
 			"from {} assignments, truncating to {} for length, removing {}",
+			assignments_len,
 			assignments.len(),
-			maximum_allowed_voters,
 			remove
 		);
-		assignments.truncate(maximum_allowed_voters);
 
 		Ok(remove)
 	}
@@ -667,69 +699,9 @@ impl<T: MinerConfig> Miner<T> {
 		size: SolutionOrSnapshotSize,
 		max_weight: Weight,
 	) -> u32 {
-		if size.voters < 1 {
-			return size.voters
-		}
-
-		let max_voters = size.voters.max(1);
-		let mut voters = max_voters;
-
-		// helper closures.
-		let weight_with = |active_voters: u32| -> Weight {
+		miner::maximum_voters_for_weight(size.voters, max_weight, |active_voters| {
 			T::solution_weight(size.voters, size.targets, active_voters, desired_winners)
-		};
-
-		let next_voters = |current_weight: Weight, voters: u32, step: u32| -> Result<u32, ()> {
-			if current_weight.all_lt(max_weight) {
-				let next_voters = voters.checked_add(step);
-				match next_voters {
-					Some(voters) if voters < max_voters => Ok(voters),
-					_ => Err(()),
-				}
-			} else if current_weight.any_gt(max_weight) {
-				voters.checked_sub(step).ok_or(())
-			} else {
-				// If any of the constituent weights is equal to the max weight, we're at max
-				Ok(voters)
-			}
-		};
-
-		// First binary-search the right amount of voters
-		let mut step = voters / 2;
-		let mut current_weight = weight_with(voters);
-
-		while step > 0 {
-			match next_voters(current_weight, voters, step) {
-				// proceed with the binary search
-				Ok(next) if next != voters => {
-					voters = next;
-				},
-				// we are out of bounds, break out of the loop.
-				Err(()) => break,
-				// we found the right value - early exit the function.
-				Ok(next) => return next,
-			}
-			step /= 2;
-			current_weight = weight_with(voters);
-		}
-
-		// Time to finish. We might have reduced less than expected due to rounding error. Increase
-		// one last time if we have any room left, the reduce until we are sure we are below limit.
-		while voters < max_voters && weight_with(voters + 1).all_lt(max_weight) {
-			voters += 1;
-		}
-		while voters.checked_sub(1).is_some() && weight_with(voters).any_gt(max_weight) {
-			voters -= 1;
-		}
-
-		let final_decision = voters.min(size.voters);
-		debug_assert!(
-			weight_with(final_decision).all_lte(max_weight),
-			"weight_with({}) <= {}",
-			final_decision,
-			max_weight,
-		);
-		final_decision
+		})
 	}
 
 	/// Checks the feasibility of a solution.
@@ -1294,6 +1266,51 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn miner_dry_run_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_unsigned();
+			let RoundSnapshot { voters, targets } = MultiPhase::snapshot().unwrap();
+			let desired_targets = MultiPhase::desired_targets().unwrap();
+			let round = MultiPhase::round();
+			let dry_run = |round| {
+				Miner::<Runtime>::dry_run_with_snapshot::<<Runtime as Config>::Solver>(
+					voters.clone(),
+					targets.clone(),
+					desired_targets,
+					round,
+					None,
+				)
+				.unwrap()
+			};
+
+			// the dry run mines the same solution as the pallet, without submitting it.
+			let report = dry_run(round);
+			let (solution, witness, _) = MultiPhase::mine_solution().unwrap();
+			assert_eq!(report.solution, solution.solution);
+			assert_eq!(report.score, solution.score);
+			assert_eq!(report.size, witness);
+			assert_eq!(report.length as usize, solution.solution.encoded_size());
+			assert!(!report.trimming.is_trimmed());
+			assert_eq!(report.feasibility, Ok(()));
+			assert!(MultiPhase::queued_solution().is_none());
+
+			// the solution is checked against the given round.
+			assert_eq!(dry_run(round + 1).feasibility, Err(FeasibilityError::InvalidRound));
+
+			// balancing can be chosen per run.
+			let (balanced, score, _, _) = Miner::<Runtime>::mine_balanced_solution_with_snapshot(
+				voters.clone(),
+				targets.clone(),
+				desired_targets,
+				crate::mock::Balancing::get(),
+			)
+			.unwrap();
+			assert_eq!(balanced, report.solution);
+			assert_eq!(score, report.score);
+		})
+	}
+
 	#[test]
 	fn miner_trims_weight() {
 		ExtBuilder::default()
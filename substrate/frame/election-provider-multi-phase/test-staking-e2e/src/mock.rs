@@ -247,6 +247,7 @@ impl pallet_bags_list::Config for Runtime {
 	type ScoreProvider = Staking;
 	type BagThresholds = BagThresholds;
 	type Score = VoteWeight;
+	type MaxAutoRebagPerBlock = ConstU32<10>;
 }
 
 /// Upper limit on the number of NPOS nominations.
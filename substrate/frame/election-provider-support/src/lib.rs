@@ -174,6 +174,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod bounds;
+pub mod miner;
 pub mod onchain;
 pub mod traits;
 
@@ -0,0 +1,193 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Building blocks of an NPoS solution miner.
+//!
+//! These are independent of any election provider pallet, so that the off-chain worker of a
+//! pallet and node-side submission tooling trim and order solutions in exactly the same way.
+//!
+//! Trimming is deterministic: given the same assignments and limits, the same voters are removed
+//! regardless of the order in which the solver produced them.
+
+use crate::{Error, VoteWeight, Weight};
+use sp_npos_elections::StakedAssignment;
+use sp_std::{cmp::Reverse, prelude::*};
+
+/// Reports the trimming result of a mined solution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrimmingStatus {
+	weight: usize,
+	length: usize,
+}
+
+impl TrimmingStatus {
+	/// Create a new status from the number of voters removed for weight and length.
+	pub fn new(weight: usize, length: usize) -> Self {
+		Self { weight, length }
+	}
+
+	/// Whether any voter was removed.
+	pub fn is_trimmed(&self) -> bool {
+		self.weight > 0 || self.length > 0
+	}
+
+	/// The number of voters removed to fit the weight limit.
+	pub fn trimmed_weight(&self) -> usize {
+		self.weight
+	}
+
+	/// The number of voters removed to fit the length limit.
+	pub fn trimmed_length(&self) -> usize {
+		self.length
+	}
+}
+
+/// Sort `assignments` in the order in which voters are trimmed from the end.
+///
+/// Voters are sorted by descending stake, as given by `stake_of`, and voters with equal stake by
+/// ascending account id, so that the order does not depend on the solver.
+pub fn sort_for_trimming<AccountId: Ord>(
+	assignments: &mut [StakedAssignment<AccountId>],
+	stake_of: impl Fn(&AccountId) -> VoteWeight,
+) {
+	assignments.sort_by(|a, b| {
+		Reverse(stake_of(&a.who))
+			.cmp(&Reverse(stake_of(&b.who)))
+			.then_with(|| a.who.cmp(&b.who))
+	});
+}
+
+/// Truncate `assignments` to the longest prefix whose encoded size, as computed by
+/// `encoded_size_of`, does not exceed `max_allowed_length`.
+///
+/// Returns the number of removed assignments.
+pub fn trim_to_length<A>(
+	max_allowed_length: u32,
+	assignments: &mut Vec<A>,
+	encoded_size_of: impl Fn(&[A]) -> Result<usize, Error>,
+) -> Result<usize, Error> {
+	// Perform a binary search for the max subset of which can fit into the allowed
+	// length. Having discovered that, we can truncate efficiently.
+	let max_allowed_length = max_allowed_length as usize;
+	let mut high = assignments.len();
+	let mut low = 0;
+
+	// not much we can do if assignments are already empty.
+	if high == low {
+		return Ok(0)
+	}
+
+	while high - low > 1 {
+		let test = (high + low) / 2;
+		if encoded_size_of(&assignments[..test])? <= max_allowed_length {
+			low = test;
+		} else {
+			high = test;
+		}
+	}
+	let maximum_allowed_voters = if low < assignments.len() &&
+		encoded_size_of(&assignments[..low + 1])? <= max_allowed_length
+	{
+		low + 1
+	} else {
+		low
+	};
+
+	// ensure our post-conditions are correct
+	debug_assert!(
+		encoded_size_of(&assignments[..maximum_allowed_voters]).unwrap() <= max_allowed_length
+	);
+	debug_assert!(if maximum_allowed_voters < assignments.len() {
+		encoded_size_of(&assignments[..maximum_allowed_voters + 1]).unwrap() > max_allowed_length
+	} else {
+		true
+	});
+
+	let remove = assignments.len().saturating_sub(maximum_allowed_voters);
+	assignments.truncate(maximum_allowed_voters);
+
+	Ok(remove)
+}
+
+/// Find the maximum number of active voters, out of `voters`, for which `weight_with` does not
+/// exceed `max_weight`.
+///
+/// `weight_with` must be monotonically increasing in the number of active voters.
+pub fn maximum_voters_for_weight(
+	voters: u32,
+	max_weight: Weight,
+	weight_with: impl Fn(u32) -> Weight,
+) -> u32 {
+	if voters < 1 {
+		return voters
+	}
+
+	let max_voters = voters;
+	let mut voters = max_voters;
+
+	let next_voters = |current_weight: Weight, voters: u32, step: u32| -> Result<u32, ()> {
+		if current_weight.all_lt(max_weight) {
+			let next_voters = voters.checked_add(step);
+			match next_voters {
+				Some(voters) if voters < max_voters => Ok(voters),
+				_ => Err(()),
+			}
+		} else if current_weight.any_gt(max_weight) {
+			voters.checked_sub(step).ok_or(())
+		} else {
+			// If any of the constituent weights is equal to the max weight, we're at max
+			Ok(voters)
+		}
+	};
+
+	// First binary-search the right amount of voters
+	let mut step = voters / 2;
+	let mut current_weight = weight_with(voters);
+
+	while step > 0 {
+		match next_voters(current_weight, voters, step) {
+			// proceed with the binary search
+			Ok(next) if next != voters => {
+				voters = next;
+			},
+			// we are out of bounds, break out of the loop.
+			Err(()) => break,
+			// we found the right value - early exit the function.
+			Ok(next) => return next,
+		}
+		step /= 2;
+		current_weight = weight_with(voters);
+	}
+
+	// Time to finish. We might have reduced less than expected due to rounding error. Increase
+	// one last time if we have any room left, the reduce until we are sure we are below limit.
+	while voters < max_voters && weight_with(voters + 1).all_lt(max_weight) {
+		voters += 1;
+	}
+	while voters.checked_sub(1).is_some() && weight_with(voters).any_gt(max_weight) {
+		voters -= 1;
+	}
+
+	let final_decision = voters.min(max_voters);
+	debug_assert!(
+		weight_with(final_decision).all_lte(max_weight),
+		"weight_with({}) <= {}",
+		final_decision,
+		max_weight,
+	);
+	final_decision
+}
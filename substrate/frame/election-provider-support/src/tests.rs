@@ -452,3 +452,48 @@ fn index_assignments_generate_same_solution_as_plain_assignments() {
 
 	assert_eq!(solution, index_compact);
 }
+
+mod miner {
+	use crate::{miner::*, Weight};
+	use sp_npos_elections::StakedAssignment;
+
+	#[test]
+	fn sort_for_trimming_is_independent_of_input_order() {
+		let assignment = |who: u64| StakedAssignment { who, distribution: vec![(10, 1)] };
+		let stake_of = |who: &u64| if *who == 3 { 50 } else { 10 };
+
+		let mut forward: Vec<_> = (1..=4).map(assignment).collect();
+		let mut backward: Vec<_> = (1..=4).rev().map(assignment).collect();
+		sort_for_trimming(&mut forward, stake_of);
+		sort_for_trimming(&mut backward, stake_of);
+
+		let order = |a: &[StakedAssignment<u64>]| a.iter().map(|a| a.who).collect::<Vec<_>>();
+		assert_eq!(order(&forward), vec![3, 1, 2, 4]);
+		assert_eq!(order(&backward), order(&forward));
+	}
+
+	#[test]
+	fn trim_to_length_keeps_longest_fitting_prefix() {
+		let encoded_size_of = |a: &[u32]| Ok(a.len() * 4);
+
+		let mut assignments = vec![1u32, 2, 3, 4, 5];
+		assert_eq!(trim_to_length(13, &mut assignments, encoded_size_of), Ok(2));
+		assert_eq!(assignments, vec![1, 2, 3]);
+
+		assert_eq!(trim_to_length(100, &mut assignments, encoded_size_of), Ok(0));
+		assert_eq!(trim_to_length(0, &mut assignments, encoded_size_of), Ok(3));
+		assert!(assignments.is_empty());
+	}
+
+	#[test]
+	fn maximum_voters_for_weight_works() {
+		let weight_with = |voters: u32| Weight::from_parts(voters as u64 * 10, voters as u64 * 10);
+		let max = |w: u64| Weight::from_parts(w, w);
+
+		assert_eq!(maximum_voters_for_weight(0, max(100), weight_with), 0);
+		assert_eq!(maximum_voters_for_weight(20, max(95), weight_with), 9);
+		assert_eq!(maximum_voters_for_weight(20, max(100), weight_with), 10);
+		assert_eq!(maximum_voters_for_weight(5, max(100), weight_with), 5);
+		assert_eq!(maximum_voters_for_weight(5, Weight::zero(), weight_with), 0);
+	}
+}
@@ -32,6 +32,7 @@ use sp_runtime::{
 };
 // Reexport crate as its pallet name for construct_runtime.
 use crate as pallet_example_basic;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -45,6 +46,7 @@ frame_support::construct_runtime!(
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
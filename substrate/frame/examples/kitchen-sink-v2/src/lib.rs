@@ -0,0 +1,338 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Kitchensink V2 Example Pallet
+//!
+//! **This pallet serves as an example and is not meant to be used in production.**
+//!
+//! Where [`pallet_example_kitchensink`] catalogs the syntax of the FRAME macros one by one, this
+//! pallet shows how the newer FRAME subsystems fit together in a single, working pallet:
+//!
+//! - **Holds**: storing an item places a deposit on hold with [`HoldReason::ItemDeposit`], which is
+//!   released when the item is removed or expires.
+//! - **Freezes**: accounts can commit part of their balance, which is frozen with
+//!   [`FreezeReason::Commitment`] until released.
+//! - **Dynamic parameters**: [`Config::ItemDeposit`] is a plain [`Get`](frame_support::traits::Get)
+//!   and the mock runtime backs it with a `storage` parameter type of
+//!   [`frame_support::parameter_types`], so it can be changed without a runtime upgrade.
+//! - **Tasks**: expired items are removed by anyone through `frame_system::Call::do_task`, see
+//!   [`Pallet::expire_item`].
+//! - **Read-only queries**: [`Pallet::item_of`], [`Pallet::deposit_of`] and
+//!   [`Pallet::expired_items`] are the side-effect free functions a runtime would expose to
+//!   off-chain clients through a runtime API.
+//! - **Multi-block migrations**: the [`migrations::v1::LazyMigrationToV1`] migration converts the
+//!   storage of the previous version in bounded steps from `on_idle`, and blocks all calls of the
+//!   pallet while it is in progress.
+//!
+//! The tests of this crate exercise all of the above against a mock runtime and are the reference
+//! for how the pieces interact.
+//!
+//! [`pallet_example_kitchensink`]: ../pallet_example_kitchensink/index.html
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::traits::fungible;
+
+/// The balance type of this pallet.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{InspectHold, MutateFreeze, MutateHold},
+			tokens::Precision,
+		},
+		weights::WeightMeter,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+	use sp_std::prelude::*;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The overarching freeze reason.
+		type RuntimeFreezeReason: From<FreezeReason>;
+
+		/// The currency used for deposits and commitments.
+		type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ MutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason>;
+
+		/// The deposit held for each stored item.
+		///
+		/// Nothing in the pallet assumes this is constant: a runtime may back it with a storage
+		/// parameter and change it at any time. Items keep the deposit they were stored with.
+		#[pallet::constant]
+		type ItemDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum length of an item.
+		#[pallet::constant]
+		type MaxItemLen: Get<u32>;
+
+		/// The number of blocks after which an item can be removed by anyone.
+		#[pallet::constant]
+		type ItemLifetime: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of items migrated in a single block.
+		#[pallet::constant]
+		type MigrationStepLimit: Get<u32>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as the deposit of a stored item.
+		#[codec(index = 0)]
+		ItemDeposit,
+	}
+
+	/// A reason for the pallet freezing funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// The funds are committed by their owner.
+		#[codec(index = 0)]
+		Commitment,
+	}
+
+	/// An item stored by an account.
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Item<T: Config> {
+		/// The content of the item.
+		pub data: BoundedVec<u8, T::MaxItemLen>,
+		/// The deposit held for the item.
+		pub deposit: BalanceOf<T>,
+		/// The block from which on the item can be removed by anyone.
+		pub expires_at: BlockNumberFor<T>,
+	}
+
+	/// The item stored by each account.
+	#[pallet::storage]
+	pub type Items<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Item<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An item was stored and its deposit placed on hold.
+		ItemStored { who: T::AccountId, deposit: BalanceOf<T> },
+		/// An item was removed by its owner.
+		ItemRemoved { who: T::AccountId, released: BalanceOf<T> },
+		/// An expired item was removed by a task.
+		ItemExpired { who: T::AccountId, released: BalanceOf<T> },
+		/// Funds of an account were committed.
+		Committed { who: T::AccountId, amount: BalanceOf<T> },
+		/// The commitment of an account was released.
+		CommitmentReleased { who: T::AccountId },
+		/// The items of the previous storage version were migrated.
+		MigrationCompleted { migrated: u32 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account already stored an item.
+		AlreadyStored,
+		/// The account has no item.
+		NoItem,
+		/// The item has not expired yet.
+		NotExpired,
+		/// A multi-block migration of the pallet is in progress.
+		MigrationInProgress,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			migrations::v1::LazyMigrationToV1::<T>::step(&mut meter);
+			meter.consumed()
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MigrationStepLimit::get() > 0,
+				"a migration step must be able to make progress"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Store `data` for the sender, placing [`Config::ItemDeposit`] on hold.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 3))]
+		pub fn store(origin: OriginFor<T>, data: BoundedVec<u8, T::MaxItemLen>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+			ensure!(!Items::<T>::contains_key(&who), Error::<T>::AlreadyStored);
+
+			let deposit = T::ItemDeposit::get();
+			T::Currency::hold(&HoldReason::ItemDeposit.into(), &who, deposit)?;
+
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ItemLifetime::get());
+			Items::<T>::insert(&who, Item { data, deposit, expires_at });
+
+			Self::deposit_event(Event::<T>::ItemStored { who, deposit });
+			Ok(())
+		}
+
+		/// Remove the item of the sender and release its deposit.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 3))]
+		pub fn remove(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			let released = Self::do_remove(&who)?;
+			Self::deposit_event(Event::<T>::ItemRemoved { who, released });
+			Ok(())
+		}
+
+		/// Freeze `amount` of the balance of the sender, replacing any previous commitment.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn commit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			T::Currency::set_freeze(&FreezeReason::Commitment.into(), &who, amount)?;
+
+			Self::deposit_event(Event::<T>::Committed { who, amount });
+			Ok(())
+		}
+
+		/// Release the commitment of the sender.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn release_commitment(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_migrating()?;
+
+			T::Currency::thaw(&FreezeReason::Commitment.into(), &who)?;
+
+			Self::deposit_event(Event::<T>::CommitmentReleased { who });
+			Ok(())
+		}
+	}
+
+	#[pallet::tasks_experimental]
+	impl<T: Config> Pallet<T> {
+		/// Remove the expired item of `who` and release its deposit back to `who`.
+		///
+		/// Valid once the item is past [`Item::expires_at`] and no migration is in progress.
+		#[pallet::task_list(Pallet::<T>::expired_items().into_iter())]
+		#[pallet::task_condition(|who| {
+			!Pallet::<T>::migration_in_progress() && Pallet::<T>::is_expired(who)
+		})]
+		#[pallet::task_weight(T::DbWeight::get().reads_writes(3, 3))]
+		#[pallet::task_index(0)]
+		pub fn expire_item(who: T::AccountId) -> DispatchResult {
+			ensure!(Self::is_expired(&who), Error::<T>::NotExpired);
+
+			let released = Self::do_remove(&who)?;
+			Self::deposit_event(Event::<T>::ItemExpired { who, released });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The item stored by `who`, if any.
+		pub fn item_of(who: &T::AccountId) -> Option<Item<T>> {
+			Items::<T>::get(who)
+		}
+
+		/// The total deposit `who` has on hold with this pallet.
+		pub fn deposit_of(who: &T::AccountId) -> BalanceOf<T> {
+			T::Currency::balance_on_hold(&HoldReason::ItemDeposit.into(), who)
+		}
+
+		/// All accounts whose item has expired.
+		///
+		/// This iterates all items and is only meant for off-chain queries and task discovery.
+		pub fn expired_items() -> Vec<T::AccountId> {
+			let now = frame_system::Pallet::<T>::block_number();
+			Items::<T>::iter()
+				.filter(|(_, item)| item.expires_at <= now)
+				.map(|(who, _)| who)
+				.collect()
+		}
+
+		/// Whether the multi-block migration of the pallet is still in progress.
+		pub fn migration_in_progress() -> bool {
+			migrations::v1::LazyMigrationToV1::<T>::in_progress()
+		}
+
+		fn is_expired(who: &T::AccountId) -> bool {
+			Items::<T>::get(who)
+				.map_or(false, |item| item.expires_at <= frame_system::Pallet::<T>::block_number())
+		}
+
+		fn ensure_not_migrating() -> DispatchResult {
+			ensure!(!Self::migration_in_progress(), Error::<T>::MigrationInProgress);
+			Ok(())
+		}
+
+		fn do_remove(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+			let item = Items::<T>::take(who).ok_or(Error::<T>::NoItem)?;
+			if item.deposit.is_zero() {
+				return Ok(Zero::zero())
+			}
+
+			T::Currency::release(
+				&HoldReason::ItemDeposit.into(),
+				who,
+				item.deposit,
+				Precision::BestEffort,
+			)
+		}
+	}
+}
@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations of the pallet.
+
+use super::*;
+
+/// The log target.
+const LOG_TARGET: &str = "runtime::kitchen-sink-v2::migration";
+
+/// The storage layout of the pallet before any storage version was set.
+pub mod v0 {
+	use super::*;
+	use frame_support::{pallet_prelude::*, storage_alias};
+
+	/// Raw notes of each account, without deposit or expiry.
+	#[storage_alias]
+	pub type Notes<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<u8, <T as Config>::MaxItemLen>,
+	>;
+}
+
+/// Migration from [`v0`] to version 1 of the storage.
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		storage_alias,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+	use sp_runtime::traits::{Saturating, Zero};
+
+	/// The number of notes migrated so far.
+	#[storage_alias]
+	type MigratedCount<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
+
+	/// Converts the [`v0::Notes`] into [`Items`] over as many blocks as needed.
+	///
+	/// Every note becomes an item without deposit, expiring [`Config::ItemLifetime`] blocks after
+	/// it was migrated. At most [`Config::MigrationStepLimit`] notes are migrated per block, and
+	/// only as long as the given weight allows. The storage version is bumped once all notes are
+	/// migrated, which also unblocks the calls of the pallet.
+	///
+	/// Nothing needs to be scheduled for this migration: as long as the on-chain storage version
+	/// is `0`, [`Pallet`] calls [`Self::step`] from `on_idle`.
+	pub struct LazyMigrationToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> LazyMigrationToV1<T> {
+		/// Whether there is still something to migrate.
+		pub fn in_progress() -> bool {
+			Pallet::<T>::on_chain_storage_version() < 1
+		}
+
+		/// The weight of migrating a single note.
+		pub fn note_weight() -> Weight {
+			T::DbWeight::get().reads_writes(1, 3)
+		}
+
+		/// Migrate as many notes as `meter` and [`Config::MigrationStepLimit`] allow.
+		///
+		/// Returns the number of notes migrated.
+		pub fn step(meter: &mut WeightMeter) -> u32 {
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_err() || !Self::in_progress() {
+				return 0
+			}
+
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ItemLifetime::get());
+			let mut migrated = 0;
+			while migrated < T::MigrationStepLimit::get() &&
+				meter.try_consume(Self::note_weight()).is_ok()
+			{
+				let Some((who, data)) = v0::Notes::<T>::drain().next() else {
+					MigratedCount::<T>::mutate(|count| count.saturating_accrue(migrated));
+					Self::complete();
+					return migrated
+				};
+
+				Items::<T>::insert(who, Item { data, deposit: Zero::zero(), expires_at });
+				migrated += 1;
+			}
+
+			if migrated > 0 {
+				MigratedCount::<T>::mutate(|count| count.saturating_accrue(migrated));
+			}
+			migrated
+		}
+
+		fn complete() {
+			let migrated = MigratedCount::<T>::take();
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "migrated {} notes to v1", migrated);
+			Pallet::<T>::deposit_event(Event::<T>::MigrationCompleted { migrated });
+		}
+	}
+}
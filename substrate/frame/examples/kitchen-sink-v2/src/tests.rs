@@ -0,0 +1,267 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for pallet-example-kitchen-sink-v2.
+
+use crate::{migrations::v0, mock::*, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::Inspect,
+		tokens::{Fortitude, Preservation},
+		ConstU32, GetStorageVersion, Hooks, StorageVersion, Task,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_runtime::TokenError;
+
+fn data(bytes: &[u8]) -> BoundedVec<u8, ConstU32<8>> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+fn expire_task(who: AccountId) -> RuntimeTask {
+	crate::Task::<Test>::ExpireItem { who }.into()
+}
+
+fn transferable(who: AccountId) -> Balance {
+	Balances::reducible_balance(&who, Preservation::Expendable, Fortitude::Polite)
+}
+
+#[test]
+fn store_and_remove_hold_and_release_deposit() {
+	new_test_ext().execute_with(|| {
+		// when
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(1), data(b"hello")));
+
+		// then
+		System::assert_last_event(Event::<Test>::ItemStored { who: 1, deposit: 10 }.into());
+		assert_eq!(
+			KitchenSink::item_of(&1),
+			Some(Item { data: data(b"hello"), deposit: 10, expires_at: 11 })
+		);
+		assert_eq!(KitchenSink::deposit_of(&1), 10);
+		assert_eq!(Balances::balance(&1), 90);
+		assert_noop!(
+			KitchenSink::store(RuntimeOrigin::signed(1), data(b"again")),
+			Error::<Test>::AlreadyStored
+		);
+
+		// when
+		assert_ok!(KitchenSink::remove(RuntimeOrigin::signed(1)));
+
+		// then
+		System::assert_last_event(Event::<Test>::ItemRemoved { who: 1, released: 10 }.into());
+		assert_eq!(KitchenSink::item_of(&1), None);
+		assert_eq!(KitchenSink::deposit_of(&1), 0);
+		assert_eq!(Balances::balance(&1), 100);
+		assert_noop!(KitchenSink::remove(RuntimeOrigin::signed(1)), Error::<Test>::NoItem);
+	});
+}
+
+#[test]
+fn deposit_follows_dynamic_parameter() {
+	new_test_ext().execute_with(|| {
+		// given an account that cannot afford the default deposit
+		assert_noop!(
+			KitchenSink::store(RuntimeOrigin::signed(3), data(b"cheap")),
+			TokenError::FundsUnavailable
+		);
+
+		// when the parameter is lowered, without any runtime upgrade
+		ItemDeposit::set(&4);
+
+		// then new items use the new deposit
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(3), data(b"cheap")));
+		assert_eq!(KitchenSink::deposit_of(&3), 4);
+
+		// when the parameter is raised again
+		ItemDeposit::set(&20);
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(1), data(b"pricey")));
+
+		// then only new items are affected, and removal releases what was actually held
+		assert_eq!(KitchenSink::deposit_of(&1), 20);
+		assert_ok!(KitchenSink::remove(RuntimeOrigin::signed(3)));
+		System::assert_last_event(Event::<Test>::ItemRemoved { who: 3, released: 4 }.into());
+		assert_eq!(Balances::balance(&3), 5);
+	});
+}
+
+#[test]
+fn commitments_freeze_funds_and_overlap_with_holds() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(transferable(2), 99);
+
+		// when
+		assert_ok!(KitchenSink::commit(RuntimeOrigin::signed(2), 80));
+
+		// then
+		System::assert_last_event(Event::<Test>::Committed { who: 2, amount: 80 }.into());
+		assert_eq!(transferable(2), 20);
+		assert_noop!(
+			Balances::transfer_allow_death(RuntimeOrigin::signed(2), 1, 30),
+			TokenError::Frozen
+		);
+
+		// when a deposit is held, it counts towards the frozen amount
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(2), data(b"held")));
+
+		// then the transferable balance is unchanged
+		assert_eq!(KitchenSink::deposit_of(&2), 10);
+		assert_eq!(transferable(2), 20);
+
+		// when
+		assert_ok!(KitchenSink::release_commitment(RuntimeOrigin::signed(2)));
+
+		// then
+		System::assert_last_event(Event::<Test>::CommitmentReleased { who: 2 }.into());
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 1, 30));
+	});
+}
+
+#[test]
+fn expired_items_are_removed_by_tasks() {
+	new_test_ext().execute_with(|| {
+		// given
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(1), data(b"one")));
+		System::set_block_number(5);
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(2), data(b"two")));
+
+		// then nothing can be expired yet
+		assert!(KitchenSink::expired_items().is_empty());
+		assert_eq!(RuntimeTask::iter().count(), 0);
+		assert!(!expire_task(1).is_valid());
+		assert_noop!(
+			System::do_task(RuntimeOrigin::signed(3), expire_task(1)),
+			frame_system::Error::<Test>::InvalidTask
+		);
+
+		// when the first item expires
+		System::set_block_number(11);
+
+		// then it is listed as a task, which anyone can run
+		assert_eq!(KitchenSink::expired_items(), vec![1]);
+		assert_eq!(RuntimeTask::iter().collect::<Vec<_>>(), vec![expire_task(1)]);
+		assert_ok!(System::do_task(RuntimeOrigin::signed(3), expire_task(1)));
+		System::assert_has_event(Event::<Test>::ItemExpired { who: 1, released: 10 }.into());
+		assert_eq!(KitchenSink::item_of(&1), None);
+		assert_eq!(Balances::balance(&1), 100);
+
+		// and it cannot be run twice
+		assert!(!expire_task(1).is_valid());
+		assert_eq!(expire_task(1).run(), Err(Error::<Test>::NotExpired.into()));
+
+		// when the second item expires
+		System::set_block_number(15);
+		assert_ok!(System::do_task(RuntimeOrigin::signed(3), expire_task(2)));
+		assert_eq!(RuntimeTask::iter().count(), 0);
+	});
+}
+
+#[test]
+fn lazy_migration_spans_multiple_blocks() {
+	new_test_ext().execute_with(|| {
+		// given a chain still on the previous storage layout
+		StorageVersion::new(0).put::<KitchenSink>();
+		for who in 1..=5 {
+			v0::Notes::<Test>::insert(who, data(&[who as u8]));
+		}
+		assert!(KitchenSink::migration_in_progress());
+
+		// then the pallet is blocked
+		assert_noop!(
+			KitchenSink::store(RuntimeOrigin::signed(1), data(b"blocked")),
+			Error::<Test>::MigrationInProgress
+		);
+		assert_noop!(
+			KitchenSink::commit(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::MigrationInProgress
+		);
+
+		// when idle, at most `MigrationStepLimit` notes are migrated per block
+		KitchenSink::on_idle(1, Weight::MAX);
+		assert_eq!(Items::<Test>::iter().count(), 2);
+		assert_eq!(v0::Notes::<Test>::iter().count(), 3);
+
+		System::set_block_number(2);
+		KitchenSink::on_idle(2, Weight::MAX);
+		assert_eq!(Items::<Test>::iter().count(), 4);
+		assert!(KitchenSink::migration_in_progress());
+
+		// when the last note is migrated
+		System::set_block_number(3);
+		KitchenSink::on_idle(3, Weight::MAX);
+
+		// then the migration completes in the same block
+		assert!(!KitchenSink::migration_in_progress());
+		assert_eq!(KitchenSink::on_chain_storage_version(), 1);
+		System::assert_last_event(Event::<Test>::MigrationCompleted { migrated: 5 }.into());
+		assert_eq!(v0::Notes::<Test>::iter().count(), 0);
+		assert_eq!(
+			KitchenSink::item_of(&5),
+			Some(Item { data: data(&[5]), deposit: 0, expires_at: 13 })
+		);
+
+		// and the pallet is usable again, with migrated items expiring like any other
+		assert_noop!(
+			KitchenSink::store(RuntimeOrigin::signed(1), data(b"fresh")),
+			Error::<Test>::AlreadyStored
+		);
+		assert_ok!(KitchenSink::remove(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::<Test>::ItemRemoved { who: 1, released: 0 }.into());
+		assert_ok!(KitchenSink::store(RuntimeOrigin::signed(1), data(b"fresh")));
+
+		System::set_block_number(13);
+		assert_ok!(System::do_task(RuntimeOrigin::signed(3), expire_task(5)));
+		System::assert_has_event(Event::<Test>::ItemExpired { who: 5, released: 0 }.into());
+
+		// and further idle blocks do nothing
+		assert_eq!(
+			KitchenSink::on_idle(14, Weight::MAX),
+			<Test as frame_system::Config>::DbWeight::get().reads(1)
+		);
+	});
+}
+
+#[test]
+fn lazy_migration_respects_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		// given
+		StorageVersion::new(0).put::<KitchenSink>();
+		for who in 1..=3 {
+			v0::Notes::<Test>::insert(who, data(&[who as u8]));
+		}
+		MigrationStepLimit::set(10);
+
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let note_weight = migrations::v1::LazyMigrationToV1::<Test>::note_weight();
+
+		// when there is not even enough weight to check the storage version
+		assert_eq!(KitchenSink::on_idle(1, Weight::zero()), Weight::zero());
+
+		// then nothing is migrated
+		assert_eq!(v0::Notes::<Test>::iter().count(), 3);
+
+		// when there is only enough weight for a single note
+		let consumed = KitchenSink::on_idle(1, db.reads(1) + note_weight);
+
+		// then exactly one is migrated
+		assert_eq!(consumed, db.reads(1) + note_weight);
+		assert_eq!(v0::Notes::<Test>::iter().count(), 2);
+		assert!(KitchenSink::migration_in_progress());
+	});
+}
@@ -37,6 +37,9 @@
 //! - [`pallet_example_kitchensink`]: This pallet demonstrates a catalog of all FRAME macros in use
 //!   and their various syntax options.
 //!
+//! - [`pallet_example_kitchen_sink_v2`]: This pallet demonstrates tasks, holds, freezes, dynamic
+//!   parameters and multi-block migrations working together in one pallet.
+//!
 //! - [`pallet_example_split`]: A simple example of a FRAME pallet demonstrating the ability to
 //!   split sections across multiple files.
 //!
@@ -113,6 +113,16 @@
 //!
 //! pub type Executive = executive::Executive<Runtime, Block, Context, Runtime, AllPalletsWithSystem, CustomOnRuntimeUpgrade>;
 //! ```
+//!
+//! ### Isolated `on_initialize` hooks
+//!
+//! By setting the `IsolateOnInitialize` generic parameter to `ConstBool<true>`, the
+//! `on_initialize` hook of every pallet is executed in its own storage transaction. A hook which
+//! reports a failure, e.g. through a `defensive!` failure, has its changes rolled back and
+//! `frame_system::Event::OnInitializeRolledBack` is emitted instead of leaving the storage in a
+//! half-updated state. Panics still render the block invalid. See
+//! [`OnInitializeIsolated`](frame_support::traits::OnInitializeIsolated) for the implications on
+//! pallet invariants before enabling this.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -120,13 +130,15 @@ use codec::{Codec, Encode};
 use frame_support::{
 	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
 	pallet_prelude::InvalidTransaction,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
-		BeforeAllRuntimeMigrations, EnsureInherentsAreFirst, ExecuteBlock, OffchainWorker,
-		OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade,
+		BeforeAllRuntimeMigrations, ConstBool, EnsureInherentsAreFirst, ExecuteBlock, Get,
+		GetPalletsStorageVersions, OffchainWorker, OnFinalize, OnIdle, OnInitialize,
+		OnInitializeIsolated, OnRuntimeUpgrade,
 	},
 	weights::Weight,
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, runtime_upgrade::RuntimeUpgradeReport};
 use sp_runtime::{
 	generic::Digest,
 	traits::{
@@ -134,7 +146,7 @@ use sp_runtime::{
 		ValidateUnsigned, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	ApplyExtrinsicResult, DispatchError,
 };
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -167,6 +179,9 @@ pub type OriginOf<E, C> = <CallOf<E, C> as Dispatchable>::RuntimeOrigin;
 ///   used to call hooks e.g. `on_initialize`.
 /// - `OnRuntimeUpgrade`: Custom logic that should be called after a runtime upgrade. Modules are
 ///   already called by `AllPalletsWithSystem`. It will be called before all modules will be called.
+/// - `IsolateOnInitialize`: Whether the `on_initialize` hook of every pallet should be executed in
+///   its own storage transaction. See [`OnInitializeIsolated`] for the details and the implications
+///   on pallet invariants. Disabled by default.
 pub struct Executive<
 	System,
 	Block,
@@ -174,6 +189,7 @@ pub struct Executive<
 	UnsignedValidator,
 	AllPalletsWithSystem,
 	OnRuntimeUpgrade = (),
+	IsolateOnInitialize = ConstBool<false>,
 >(
 	PhantomData<(
 		System,
@@ -182,6 +198,7 @@ pub struct Executive<
 		UnsignedValidator,
 		AllPalletsWithSystem,
 		OnRuntimeUpgrade,
+		IsolateOnInitialize,
 	)>,
 );
 
@@ -196,12 +213,22 @@ impl<
 		AllPalletsWithSystem: OnRuntimeUpgrade
 			+ BeforeAllRuntimeMigrations
 			+ OnInitialize<BlockNumberFor<System>>
+			+ OnInitializeIsolated<BlockNumberFor<System>>
 			+ OnIdle<BlockNumberFor<System>>
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
+		IsolateOnInitialize: Get<bool>,
 	> ExecuteBlock<Block>
-	for Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+	for Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		IsolateOnInitialize,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -218,6 +245,7 @@ where
 			UnsignedValidator,
 			AllPalletsWithSystem,
 			COnRuntimeUpgrade,
+			IsolateOnInitialize,
 		>::execute_block(block);
 	}
 }
@@ -234,13 +262,24 @@ impl<
 		AllPalletsWithSystem: OnRuntimeUpgrade
 			+ BeforeAllRuntimeMigrations
 			+ OnInitialize<BlockNumberFor<System>>
+			+ OnInitializeIsolated<BlockNumberFor<System>>
 			+ OnIdle<BlockNumberFor<System>>
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>
 			+ TryState<BlockNumberFor<System>>
 			+ TryDecodeEntireStorage,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+		IsolateOnInitialize: Get<bool>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		IsolateOnInitialize,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -435,11 +474,93 @@ impl<
 		AllPalletsWithSystem: OnRuntimeUpgrade
 			+ BeforeAllRuntimeMigrations
 			+ OnInitialize<BlockNumberFor<System>>
+			+ OnInitializeIsolated<BlockNumberFor<System>>
+			+ OnIdle<BlockNumberFor<System>>
+			+ OnFinalize<BlockNumberFor<System>>
+			+ OffchainWorker<BlockNumberFor<System>>
+			+ GetPalletsStorageVersions,
+		COnRuntimeUpgrade: OnRuntimeUpgrade,
+		IsolateOnInitialize: Get<bool>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		IsolateOnInitialize,
+	>
+where
+	Block::Extrinsic: Checkable<Context> + Codec,
+	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
+	CallOf<Block::Extrinsic, Context>:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	OriginOf<Block::Extrinsic, Context>: From<Option<System::AccountId>>,
+	UnsignedValidator: ValidateUnsigned<Call = CallOf<Block::Extrinsic, Context>>,
+{
+	/// Dry-run all `OnRuntimeUpgrade` of this runtime against the current state.
+	///
+	/// Reports the pallets which still need to be migrated and the weight of the migrations in
+	/// the block enacting the upgrade and in each of the following blocks, as long as the
+	/// migrations defer work to them. The changes made by the migrations are reverted before
+	/// returning.
+	pub fn dry_run_runtime_upgrade() -> RuntimeUpgradeReport {
+		let pending_migrations =
+			<AllPalletsWithSystem as GetPalletsStorageVersions>::pallets_storage_versions()
+				.into_iter()
+				.filter(|pallet| pallet.is_behind())
+				.collect();
+
+		let (weight, deferred_weights) = with_transaction(|| {
+			let weight = Self::execute_on_runtime_upgrade();
+			let mut deferred_weights = Vec::new();
+			while <COnRuntimeUpgrade as OnRuntimeUpgrade>::runtime_upgrade_pending() {
+				deferred_weights
+					.push(<COnRuntimeUpgrade as OnRuntimeUpgrade>::continue_runtime_upgrade());
+			}
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>((weight, deferred_weights)))
+		})
+		.expect("The migrations are executed infallibly; qed");
+		let max_block = <System::BlockWeights as frame_support::traits::Get<_>>::get().max_block;
+
+		RuntimeUpgradeReport {
+			pending_migrations,
+			exceeds_max_block: weight.any_gt(max_block) ||
+				deferred_weights.iter().any(|weight| weight.any_gt(max_block)),
+			weight,
+			deferred_weights,
+		}
+	}
+}
+
+impl<
+		System: frame_system::Config + EnsureInherentsAreFirst<Block>,
+		Block: traits::Block<
+			Header = frame_system::pallet_prelude::HeaderFor<System>,
+			Hash = System::Hash,
+		>,
+		Context: Default,
+		UnsignedValidator,
+		AllPalletsWithSystem: OnRuntimeUpgrade
+			+ BeforeAllRuntimeMigrations
+			+ OnInitialize<BlockNumberFor<System>>
+			+ OnInitializeIsolated<BlockNumberFor<System>>
 			+ OnIdle<BlockNumberFor<System>>
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+		IsolateOnInitialize: Get<bool>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		IsolateOnInitialize,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -487,11 +608,12 @@ where
 		let mut weight = Weight::zero();
 		if Self::runtime_upgraded() {
 			weight = weight.saturating_add(Self::execute_on_runtime_upgrade());
+		} else {
+			weight = weight
+				.saturating_add(<COnRuntimeUpgrade as OnRuntimeUpgrade>::continue_runtime_upgrade());
 		}
 		<frame_system::Pallet<System>>::initialize(block_number, parent_hash, digest);
-		weight = weight.saturating_add(<AllPalletsWithSystem as OnInitialize<
-			BlockNumberFor<System>,
-		>>::on_initialize(*block_number));
+		weight = weight.saturating_add(Self::execute_on_initialize(*block_number));
 		weight = weight.saturating_add(
 			<System::BlockWeights as frame_support::traits::Get<_>>::get().base_block,
 		);
@@ -503,6 +625,33 @@ where
 		frame_system::Pallet::<System>::note_finished_initialize();
 	}
 
+	/// Execute the `on_initialize` hooks of all pallets, isolated from each other if enabled
+	/// through `IsolateOnInitialize`.
+	fn execute_on_initialize(block_number: BlockNumberFor<System>) -> Weight {
+		if !IsolateOnInitialize::get() {
+			return <AllPalletsWithSystem as OnInitialize<BlockNumberFor<System>>>::on_initialize(
+				block_number,
+			)
+		}
+
+		<AllPalletsWithSystem as OnInitializeIsolated<BlockNumberFor<System>>>::on_initialize_isolated(
+			block_number,
+			&mut |pallet_index, reason| {
+				log::error!(
+					target: LOG_TARGET,
+					"on_initialize of pallet {} failed and was rolled back: {}",
+					pallet_index,
+					reason,
+				);
+				<frame_system::Pallet<System>>::deposit_event(
+					frame_system::Event::<System>::OnInitializeRolledBack {
+						pallet_index: pallet_index as u32,
+					},
+				);
+			},
+		)
+	}
+
 	/// Returns if the runtime was upgraded since the last time this function was called.
 	fn runtime_upgraded() -> bool {
 		let last = frame_system::LastRuntimeUpgrade::<System>::get();
@@ -752,7 +901,7 @@ mod tests {
 
 	use frame_support::{
 		assert_err, parameter_types,
-		traits::{fungible, ConstU32, ConstU64, ConstU8, Currency},
+		traits::{fungible, ConstU32, ConstU64, ConstU8, Currency, PalletInfoAccess},
 		weights::{ConstantMultiplier, IdentityFee, RuntimeDbWeight, Weight, WeightToFee},
 	};
 	use frame_system::{ChainContext, LastRuntimeUpgradeInfo};
@@ -760,6 +909,10 @@ mod tests {
 	use pallet_transaction_payment::CurrencyAdapter;
 
 	const TEST_KEY: &[u8] = b":test:key:";
+	// When set, the `on_initialize` hook of the custom pallet writes to `ON_INITIALIZE_KEY` and
+	// then reports a failure.
+	const FAIL_ON_INITIALIZE_KEY: &[u8] = b":test:fail_on_initialize:";
+	const ON_INITIALIZE_KEY: &[u8] = b":test:on_initialize:";
 
 	#[frame_support::pallet(dev_mode)]
 	mod custom {
@@ -778,6 +931,10 @@ mod tests {
 			// one with block number arg and one without
 			fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 				println!("on_initialize({})", n);
+				if sp_io::storage::exists(super::FAIL_ON_INITIALIZE_KEY) {
+					sp_io::storage::set(super::ON_INITIALIZE_KEY, &n.encode());
+					frame_support::traits::note_on_initialize_failure("requested by test");
+				}
 				Weight::from_parts(175, 0)
 			}
 
@@ -931,6 +1088,7 @@ mod tests {
 		type SS58Prefix = ();
 		type OnSetCode = ();
 		type MaxConsumers = ConstU32<16>;
+		type MaxEventsPerPallet = ();
 	}
 
 	type Balance = u64;
@@ -1007,6 +1165,16 @@ mod tests {
 		CustomOnRuntimeUpgrade,
 	>;
 
+	type IsolatedExecutive = super::Executive<
+		Runtime,
+		Block<TestXt>,
+		ChainContext<Runtime>,
+		Runtime,
+		AllPalletsWithSystem,
+		CustomOnRuntimeUpgrade,
+		frame_support::traits::ConstBool<true>,
+	>;
+
 	fn extra(nonce: u64, fee: Balance) -> SignedExtra {
 		(
 			frame_system::CheckEra::from(Era::Immortal),
@@ -1351,6 +1519,57 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn failed_on_initialize_is_kept_without_isolation() {
+		new_test_ext(1).execute_with(|| {
+			sp_io::storage::set(FAIL_ON_INITIALIZE_KEY, &[]);
+
+			Executive::initialize_block(&Header::new_from_number(1));
+
+			assert_eq!(sp_io::storage::get(ON_INITIALIZE_KEY).unwrap(), 1u64.encode());
+			assert!(System::events().is_empty());
+		})
+	}
+
+	#[test]
+	fn failed_on_initialize_is_rolled_back_with_isolation() {
+		new_test_ext(1).execute_with(|| {
+			sp_io::storage::set(FAIL_ON_INITIALIZE_KEY, &[]);
+			// Events are not deposited in the genesis block.
+			System::set_block_number(1);
+
+			IsolatedExecutive::initialize_block(&Header::new_from_number(2));
+
+			assert!(sp_io::storage::get(ON_INITIALIZE_KEY).is_none());
+			System::assert_last_event(
+				frame_system::Event::<Runtime>::OnInitializeRolledBack {
+					pallet_index: <Custom as PalletInfoAccess>::index() as u32,
+				}
+				.into(),
+			);
+			// The changes of the other hooks are kept and the weight is still accounted for.
+			assert_eq!(System::block_number(), 2);
+			assert_eq!(
+				<frame_system::Pallet<Runtime>>::block_weight().total(),
+				Weight::from_parts(175 + 10, 0)
+			);
+		})
+	}
+
+	#[test]
+	fn isolated_on_initialize_without_failure_works() {
+		new_test_ext(1).execute_with(|| {
+			IsolatedExecutive::initialize_block(&Header::new_from_number(1));
+			IsolatedExecutive::finalize_block();
+
+			assert!(System::events().is_empty());
+			assert_eq!(
+				<frame_system::Pallet<Runtime>>::block_weight().total(),
+				Weight::from_parts(175 + 175 + 10, 0)
+			);
+		})
+	}
+
 	#[test]
 	fn runtime_upgraded_should_work() {
 		new_test_ext(1).execute_with(|| {
@@ -1517,6 +1736,28 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn dry_run_runtime_upgrade_works() {
+		new_test_ext(1).execute_with(|| {
+			// Pretend `Balances` was not yet migrated to its current storage version.
+			frame_support::traits::StorageVersion::new(0).put::<Balances>();
+
+			let report = Executive::dry_run_runtime_upgrade();
+			assert_eq!(
+				report.pending_migrations.iter().map(|p| p.name.clone()).collect::<Vec<_>>(),
+				vec![b"Balances".to_vec()],
+			);
+			assert!(report.deferred_weights.is_empty());
+			assert!(!report.exceeds_max_block);
+
+			// Nothing was changed by the dry-run.
+			assert!(sp_io::storage::get(TEST_KEY).is_none());
+			assert!(sp_io::storage::get(CUSTOM_ON_RUNTIME_KEY).is_none());
+
+			assert_eq!(report.weight, Executive::execute_on_runtime_upgrade());
+		});
+	}
+
 	#[test]
 	fn all_weights_are_recorded_correctly() {
 		new_test_ext(1).execute_with(|| {
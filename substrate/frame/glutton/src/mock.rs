@@ -19,7 +19,7 @@ use super::*;
 use crate as pallet_glutton;
 
 use frame_support::{
-	assert_ok,
+	assert_ok, derive_impl,
 	traits::{ConstU32, ConstU64},
 };
 use sp_core::H256;
@@ -38,6 +38,7 @@ frame_support::construct_runtime!(
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
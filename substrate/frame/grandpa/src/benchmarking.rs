@@ -69,6 +69,23 @@ benchmarks! {
 		assert!(Grandpa::<T>::stalled().is_some());
 	}
 
+	pause {
+		let delay = 1000u32.into();
+	}: _(RawOrigin::Root, delay)
+	verify {
+		assert!(matches!(Grandpa::<T>::state(), StoredState::PendingPause { .. }));
+	}
+
+	resume {
+		let delay = 1000u32.into();
+		let best_finalized_block_number = Some(1u32.into());
+		State::<T>::put(StoredState::Paused);
+	}: _(RawOrigin::Root, delay, best_finalized_block_number)
+	verify {
+		assert!(matches!(Grandpa::<T>::state(), StoredState::PendingResume { .. }));
+		assert!(Grandpa::<T>::pending_change().is_some());
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(vec![(1, 1), (2, 1), (3, 1)]),
@@ -54,4 +54,17 @@ impl crate::WeightInfo for () {
 		Weight::from_parts(3u64 * WEIGHT_REF_TIME_PER_MICROS, 0)
 			.saturating_add(DbWeight::get().writes(1))
 	}
+
+	fn pause() -> Weight {
+		Weight::from_parts(3u64 * WEIGHT_REF_TIME_PER_MICROS, 0)
+			.saturating_add(DbWeight::get().reads(1))
+			.saturating_add(DbWeight::get().writes(1))
+	}
+
+	fn resume() -> Weight {
+		// the forced change reads the authorities, pending and next forced change
+		Weight::from_parts(5u64 * WEIGHT_REF_TIME_PER_MICROS, 0)
+			.saturating_add(DbWeight::get().reads(4))
+			.saturating_add(DbWeight::get().writes(3))
+	}
 }
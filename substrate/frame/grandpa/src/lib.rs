@@ -44,7 +44,7 @@ use frame_support::{
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_consensus_grandpa::{
-	ConsensusLog, EquivocationProof, ScheduledChange, SetId, GRANDPA_ENGINE_ID,
+	AuthoritySetState, ConsensusLog, EquivocationProof, ScheduledChange, SetId, GRANDPA_ENGINE_ID,
 	RUNTIME_LOG_TARGET as LOG_TARGET,
 };
 use sp_runtime::{generic::DigestItem, traits::Zero, DispatchResult};
@@ -261,6 +261,51 @@ pub mod pallet {
 			Self::on_stalled(delay, best_finalized_block_number);
 			Ok(())
 		}
+
+		/// Pause the current GRANDPA authority set.
+		///
+		/// Signals the authorities to stop voting after the block `delay` blocks from now has
+		/// been finalized. This is meant to deliberately halt finality during incident response,
+		/// e.g. around complex migrations or bridge incidents. Fails if the authority set is not
+		/// currently live.
+		///
+		/// Only callable by root.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, delay: BlockNumberFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::schedule_pause(delay)?;
+			Self::deposit_event(Event::PauseScheduled);
+			Ok(())
+		}
+
+		/// Resume the currently paused GRANDPA authority set.
+		///
+		/// Signals the authorities to resume voting after authoring the block `delay` blocks from
+		/// now. If `best_finalized_block_number` is given, a forced authority set change to the
+		/// current authorities is also scheduled with the same delay, so that the voters restart
+		/// on top of the given block. This is subject to the same restrictions as any other
+		/// forced change, i.e. it fails if a change is already pending or the last forced change
+		/// happened too recently. Fails if the authority set is not currently paused.
+		///
+		/// Only callable by root.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::resume())]
+		pub fn resume(
+			origin: OriginFor<T>,
+			delay: BlockNumberFor<T>,
+			best_finalized_block_number: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::schedule_resume(delay)?;
+			if let Some(median) = best_finalized_block_number {
+				Self::schedule_change(Self::grandpa_authorities(), delay, Some(median))?;
+			}
+			Self::deposit_event(Event::ResumeScheduled);
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -272,6 +317,10 @@ pub mod pallet {
 		Paused,
 		/// Current authority set has been resumed.
 		Resumed,
+		/// A pause of the current authority set has been scheduled by governance.
+		PauseScheduled,
+		/// A resume of the current authority set has been scheduled by governance.
+		ResumeScheduled,
 	}
 
 	#[pallet::error]
@@ -379,6 +428,8 @@ pub mod pallet {
 pub trait WeightInfo {
 	fn report_equivocation(validator_count: u32, max_nominators_per_validator: u32) -> Weight;
 	fn note_stalled() -> Weight;
+	fn pause() -> Weight;
+	fn resume() -> Weight;
 }
 
 /// Bounded version of `AuthorityList`, `Limit` being the bound
@@ -435,6 +486,18 @@ impl<T: Config> Pallet<T> {
 		Authorities::<T>::get().into_inner()
 	}
 
+	/// The pause state of the current authority set, as exposed through the runtime API.
+	pub fn authority_set_state() -> AuthoritySetState<BlockNumberFor<T>> {
+		match <State<T>>::get() {
+			StoredState::Live => AuthoritySetState::Live,
+			StoredState::PendingPause { scheduled_at, delay } =>
+				AuthoritySetState::PendingPause { scheduled_at, delay },
+			StoredState::Paused => AuthoritySetState::Paused,
+			StoredState::PendingResume { scheduled_at, delay } =>
+				AuthoritySetState::PendingResume { scheduled_at, delay },
+		}
+	}
+
 	/// Schedule GRANDPA to pause starting in the given number of blocks.
 	/// Cannot be done when already paused.
 	pub fn schedule_pause(in_blocks: BlockNumberFor<T>) -> DispatchResult {
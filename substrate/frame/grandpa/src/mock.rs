@@ -27,7 +27,7 @@ use frame_election_provider_support::{
 	onchain, SequentialPhragmen,
 };
 use frame_support::{
-	parameter_types,
+	derive_impl, parameter_types,
 	traits::{ConstU128, ConstU32, ConstU64, KeyOwnerProofSystem, OnFinalize, OnInitialize},
 };
 use pallet_session::historical as pallet_session_historical;
@@ -66,6 +66,7 @@ impl_opaque_keys! {
 	}
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -211,10 +212,18 @@ impl pallet_staking::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const OffencesMaxProcessingWeight: frame_support::weights::Weight =
+		frame_support::weights::Weight::MAX;
+}
+
 impl pallet_offences::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = ConstU32<100>;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -321,6 +330,7 @@ pub fn start_session(session_index: SessionIndex) {
 		Timestamp::set_timestamp(System::block_number() * 6000);
 
 		System::on_initialize(System::block_number());
+		Offences::on_initialize(System::block_number());
 		Session::on_initialize(System::block_number());
 		Staking::on_initialize(System::block_number());
 		Grandpa::on_initialize(System::block_number());
@@ -293,6 +293,108 @@ fn schedule_resume_only_when_paused() {
 	});
 }
 
+#[test]
+fn root_can_pause_and_resume_authority_set() {
+	new_test_ext(vec![(1, 1), (2, 1), (3, 1)]).execute_with(|| {
+		initialize_block(1, Default::default());
+
+		// only root can pause the authority set
+		assert_noop!(
+			Grandpa::pause(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		// and it must be paused before being resumed
+		assert_noop!(Grandpa::resume(RuntimeOrigin::root(), 1, None), Error::<Test>::ResumeFailed);
+
+		assert_ok!(Grandpa::pause(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::PauseScheduled.into());
+		assert_eq!(
+			Grandpa::authority_set_state(),
+			AuthoritySetState::PendingPause { scheduled_at: 1, delay: 1 },
+		);
+
+		// the pause signal is deposited in the block that scheduled it
+		Grandpa::on_finalize(1);
+		let header = System::finalize();
+		assert_eq!(header.digest, Digest { logs: vec![grandpa_log(ConsensusLog::Pause(1))] });
+
+		initialize_block(2, header.hash());
+		Grandpa::on_finalize(2);
+		let header = System::finalize();
+		assert_eq!(Grandpa::authority_set_state(), AuthoritySetState::Paused);
+
+		initialize_block(3, header.hash());
+		assert_noop!(Grandpa::pause(RuntimeOrigin::root(), 1), Error::<Test>::PauseFailed);
+
+		assert_ok!(Grandpa::resume(RuntimeOrigin::root(), 0, None));
+		System::assert_last_event(Event::ResumeScheduled.into());
+		Grandpa::on_finalize(3);
+		let _ = System::finalize();
+
+		assert_eq!(Grandpa::authority_set_state(), AuthoritySetState::Live);
+		assert!(Grandpa::pending_change().is_none());
+	});
+}
+
+#[test]
+fn resume_can_force_authority_set_change() {
+	new_test_ext(vec![(1, 1), (2, 1), (3, 1)]).execute_with(|| {
+		initialize_block(1, Default::default());
+		assert_ok!(Grandpa::pause(RuntimeOrigin::root(), 0));
+		Grandpa::on_finalize(1);
+		let header = System::finalize();
+		assert_eq!(Grandpa::state(), StoredState::Paused);
+
+		initialize_block(2, header.hash());
+		assert_ok!(Grandpa::resume(RuntimeOrigin::root(), 5, Some(1)));
+
+		// a forced change to the current authorities is scheduled alongside the resume
+		assert_eq!(Grandpa::state(), StoredState::PendingResume { scheduled_at: 2, delay: 5 });
+		let pending_change = Grandpa::pending_change().unwrap();
+		assert_eq!(pending_change.forced, Some(1));
+		assert_eq!(pending_change.delay, 5);
+		assert_eq!(pending_change.next_authorities.to_vec(), Grandpa::grandpa_authorities());
+
+		Grandpa::on_finalize(2);
+		let header = System::finalize();
+		assert_eq!(
+			header.digest,
+			Digest {
+				logs: vec![
+					grandpa_log(ConsensusLog::ForcedChange(
+						1,
+						ScheduledChange {
+							delay: 5,
+							next_authorities: Grandpa::grandpa_authorities()
+						},
+					)),
+					grandpa_log(ConsensusLog::Resume(5)),
+				],
+			},
+		);
+	});
+}
+
+#[test]
+fn resume_fails_if_forced_change_cannot_be_scheduled() {
+	new_test_ext(vec![(1, 1), (2, 1), (3, 1)]).execute_with(|| {
+		initialize_block(1, Default::default());
+		assert_ok!(Grandpa::pause(RuntimeOrigin::root(), 0));
+		Grandpa::on_finalize(1);
+		let header = System::finalize();
+
+		initialize_block(2, header.hash());
+		Grandpa::schedule_change(to_authorities(vec![(4, 1)]), 1, None).unwrap();
+
+		// the whole call is reverted, the set stays paused
+		assert_noop!(
+			Grandpa::resume(RuntimeOrigin::root(), 1, Some(1)),
+			Error::<Test>::ChangePending,
+		);
+		assert_eq!(Grandpa::state(), StoredState::Paused);
+	});
+}
+
 #[test]
 fn time_slot_have_sane_ord() {
 	// Ensure that `Ord` implementation is sane.
@@ -132,7 +132,8 @@ mod benchmarks {
 		for i in 0..r {
 			let registrar: T::AccountId = account("registrar", i, SEED);
 			let _ = T::Lookup::unlookup(registrar.clone());
-			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
+			let balance_to_use =
+				<T::Currency as Currency<T::AccountId>>::minimum_balance() * 10u32.into();
 			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
 
 			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into())?;
@@ -215,7 +216,8 @@ mod benchmarks {
 		// User requests judgement from all the registrars, and they approve
 		for i in 0..r {
 			let registrar: T::AccountId = account("registrar", i, SEED);
-			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
+			let balance_to_use =
+				<T::Currency as Currency<T::AccountId>>::minimum_balance() * 10u32.into();
 			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
 
 			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into())?;
@@ -287,6 +289,46 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn refund_expired_request(
+		r: Linear<1, { T::MaxRegistrars::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
+
+		// Register the registrars
+		add_registrars::<T>(r)?;
+
+		// Create their main identity with x additional fields
+		let info = T::IdentityInformation::create_identity_info();
+		let target_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(target.clone()));
+		Identity::<T>::set_identity(target_origin.clone(), Box::new(info))?;
+
+		Identity::<T>::request_judgement(target_origin, r - 1, 10u32.into())?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::JudgementRequestExpiry::get()),
+		);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), target_lookup, r - 1);
+
+		assert_last_event::<T>(
+			Event::<T>::JudgementFeeRefunded {
+				who: target,
+				registrar_index: r - 1,
+				amount: 10u32.into(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_fee(r: Linear<1, { T::MaxRegistrars::get() - 1 }>) -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
@@ -423,23 +465,13 @@ mod benchmarks {
 		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
 
 		let info = T::IdentityInformation::create_identity_info();
-		Identity::<T>::set_identity(target_origin.clone(), Box::new(info.clone()))?;
+		Identity::<T>::set_identity(target_origin.clone(), Box::new(info))?;
 		let _ = add_sub_accounts::<T>(&target, s)?;
 
-		// User requests judgement from all the registrars, and they approve
+		// User requests judgement from all the registrars, and the fees held for the pending
+		// requests have to be slashed along with the deposit.
 		for i in 0..r {
-			let registrar: T::AccountId = account("registrar", i, SEED);
-			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
-			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
-
 			Identity::<T>::request_judgement(target_origin.clone(), i, 10u32.into())?;
-			Identity::<T>::provide_judgement(
-				RawOrigin::Signed(registrar).into(),
-				i,
-				target_lookup.clone(),
-				Judgement::Reasonable,
-				T::Hashing::hash_of(&info),
-			)?;
 		}
 
 		ensure!(IdentityOf::<T>::contains_key(&target), "Identity not set");
@@ -30,7 +30,11 @@
 //!
 //! Some judgements are identified as *sticky*, which means they cannot be removed except by
 //! complete removal of the identity, or by the registrar. Judgements are allowed to represent a
-//! portion of funds that have been reserved for the registrar.
+//! portion of funds that have been put on hold for the registrar.
+//!
+//! The fee of a judgement request is held in escrow on the account of the requester until the
+//! registrar provides its judgement. A request which the registrar did not answer within
+//! `JudgementRequestExpiry` blocks expires, after which anyone may refund its fee to the requester.
 //!
 //! A super-user can remove accounts and in doing so, slash the deposit.
 //!
@@ -50,6 +54,7 @@
 //! * `clear_identity` - Remove an account's associated identity; the deposit is returned.
 //! * `request_judgement` - Request a judgement from a registrar, paying a fee.
 //! * `cancel_request` - Cancel the previous request for a judgement.
+//! * `refund_expired_request` - Refund the fee of an expired judgement request.
 //!
 //! #### For general users with sub-identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -74,6 +79,7 @@
 
 mod benchmarking;
 pub mod legacy;
+pub mod migration;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -83,7 +89,11 @@ use codec::Encode;
 use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
-	traits::{BalanceStatus, Currency, Get, OnUnbalanced, ReservableCurrency},
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+		BalanceStatus, Currency, Get, OnUnbalanced, ReservableCurrency, StorageVersion,
+	},
 };
 use sp_runtime::traits::{AppendZerosInput, Hash, Saturating, StaticLookup, Zero};
 use sp_std::prelude::*;
@@ -107,13 +117,23 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The currency trait.
-		type Currency: ReservableCurrency<Self::AccountId>;
+		///
+		/// Identity deposits are reserved, while the fees of judgement requests are held in
+		/// escrow with [`HoldReason::JudgementFee`].
+		type Currency: ReservableCurrency<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = BalanceOf<Self>>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 
 		/// The amount held on deposit for a registered identity
 		#[pallet::constant]
@@ -141,6 +161,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRegistrars: Get<u32>;
 
+		/// The number of blocks after which a judgement request which was not answered by the
+		/// registrar expires. The fee of an expired request can be refunded by anyone.
+		#[pallet::constant]
+		type JudgementRequestExpiry: Get<BlockNumberFor<Self>>;
+
 		/// What to do with slashed funds.
 		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -155,8 +180,17 @@ pub mod pallet {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// A reason for the identity pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held in escrow as the fee of a pending judgement request.
+		#[codec(index = 0)]
+		JudgementFee,
+	}
+
 	/// Information that is pertinent to identify the entity behind an account.
 	///
 	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
@@ -213,6 +247,20 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The block number at which a pending judgement request expires, by the requesting account
+	/// and the index of the registrar.
+	#[pallet::storage]
+	#[pallet::getter(fn request_expiry)]
+	pub(super) type RequestExpiryOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -249,6 +297,8 @@ pub mod pallet {
 		JudgementForDifferentIdentity,
 		/// Error that occurs when there is an issue paying for judgement.
 		JudgementPaymentFailed,
+		/// The judgement request has not expired yet.
+		RequestNotExpired,
 	}
 
 	#[pallet::event]
@@ -264,6 +314,14 @@ pub mod pallet {
 		JudgementRequested { who: T::AccountId, registrar_index: RegistrarIndex },
 		/// A judgement request was retracted.
 		JudgementUnrequested { who: T::AccountId, registrar_index: RegistrarIndex },
+		/// A judgement request was not answered by the registrar in time and has expired.
+		JudgementRequestExpired { who: T::AccountId, registrar_index: RegistrarIndex },
+		/// The fee held for a judgement request was refunded to the requester.
+		JudgementFeeRefunded {
+			who: T::AccountId,
+			registrar_index: RegistrarIndex,
+			amount: BalanceOf<T>,
+		},
 		/// A judgement was given by a registrar.
 		JudgementGiven { target: T::AccountId, registrar_index: RegistrarIndex },
 		/// A registrar was added.
@@ -458,8 +516,10 @@ pub mod pallet {
 				<SuperOf<T>>::remove(sub);
 			}
 
-			let err_amount = T::Currency::unreserve(&sender, deposit);
+			let err_amount =
+				T::Currency::unreserve(&sender, id.deposit.saturating_add(subs_deposit));
 			debug_assert!(err_amount.is_zero());
+			Self::release_judgement_fees(&sender, &id.judgements);
 
 			Self::deposit_event(Event::IdentityCleared { who: sender, deposit });
 
@@ -473,8 +533,9 @@ pub mod pallet {
 
 		/// Request a judgement from a registrar.
 		///
-		/// Payment: At most `max_fee` will be reserved for payment to the registrar if judgement
-		/// given.
+		/// Payment: At most `max_fee` will be held in escrow for payment to the registrar if
+		/// judgement given. If the registrar does not provide a judgement within
+		/// `JudgementRequestExpiry` blocks, the fee can be refunded with `refund_expired_request`.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a
 		/// registered identity.
@@ -515,7 +576,10 @@ pub mod pallet {
 					id.judgements.try_insert(i, item).map_err(|_| Error::<T>::TooManyRegistrars)?,
 			}
 
-			T::Currency::reserve(&sender, registrar.fee)?;
+			T::Currency::hold(&HoldReason::JudgementFee.into(), &sender, registrar.fee)?;
+			let expiry = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::JudgementRequestExpiry::get());
+			<RequestExpiryOf<T>>::insert(&sender, reg_index, expiry);
 
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&sender, id);
@@ -530,7 +594,7 @@ pub mod pallet {
 
 		/// Cancel a previous request.
 		///
-		/// Payment: A previously reserved deposit is returned on success.
+		/// Payment: A previously held fee is returned on success.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a
 		/// registered identity.
@@ -557,8 +621,7 @@ pub mod pallet {
 				return Err(Error::<T>::JudgementGiven.into())
 			};
 
-			let err_amount = T::Currency::unreserve(&sender, fee);
-			debug_assert!(err_amount.is_zero());
+			Self::release_judgement_fee(&sender, reg_index, fee);
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&sender, id);
 
@@ -707,13 +770,17 @@ pub mod pallet {
 			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
 				Ok(position) => {
 					if let Judgement::FeePaid(fee) = id.judgements[position].1 {
-						T::Currency::repatriate_reserved(
+						T::Currency::transfer_on_hold(
+							&HoldReason::JudgementFee.into(),
 							&target,
 							&sender,
 							fee,
-							BalanceStatus::Free,
+							Precision::Exact,
+							Restriction::Free,
+							Fortitude::Polite,
 						)
 						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+						<RequestExpiryOf<T>>::remove(&target, reg_index);
 					}
 					id.judgements[position] = item
 				},
@@ -732,9 +799,8 @@ pub mod pallet {
 
 		/// Remove an account's identity and sub-account information and slash the deposits.
 		///
-		/// Payment: Reserved balances from `set_subs` and `set_identity` are slashed and handled by
-		/// `Slash`. Verification request deposits are not returned; they should be cancelled
-		/// manually using `cancel_request`.
+		/// Payment: Reserved balances from `set_subs` and `set_identity` as well as the fees held
+		/// for pending judgement requests are slashed and handled by `Slash`.
 		///
 		/// The dispatch origin for this call must match `T::ForceOrigin`.
 		///
@@ -758,12 +824,13 @@ pub mod pallet {
 			// Grab their deposit (and check that they have one).
 			let (subs_deposit, sub_ids) = <SubsOf<T>>::take(&target);
 			let id = <IdentityOf<T>>::take(&target).ok_or(Error::<T>::NotNamed)?;
-			let deposit = id.total_deposit().saturating_add(subs_deposit);
+			let deposit = id.deposit.saturating_add(subs_deposit);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
-			// Slash their deposit from them.
+			// Slash their deposit and any fees held for pending judgement requests from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
+			Self::slash_judgement_fees(&target, &id.judgements);
 
 			Self::deposit_event(Event::IdentityKilled { who: target, deposit });
 
@@ -886,6 +953,61 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Refund the fee of a judgement request which the registrar did not answer in time.
+		///
+		/// Payment: The fee held for the request is returned to `target`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: the account which requested the judgement.
+		/// - `reg_index`: the index of the registrar whose judgement was requested.
+		///
+		/// Emits `JudgementRequestExpired` and `JudgementFeeRefunded` if successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::refund_expired_request(T::MaxRegistrars::get()))]
+		pub fn refund_expired_request(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let mut id = <IdentityOf<T>>::get(&target).ok_or(Error::<T>::NoIdentity)?;
+
+			let pos = id
+				.judgements
+				.binary_search_by_key(&reg_index, |x| x.0)
+				.map_err(|_| Error::<T>::NotFound)?;
+			let fee = if let Judgement::FeePaid(fee) = id.judgements[pos].1 {
+				fee
+			} else {
+				return Err(Error::<T>::JudgementGiven.into())
+			};
+			let expiry =
+				<RequestExpiryOf<T>>::get(&target, reg_index).ok_or(Error::<T>::NotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= expiry,
+				Error::<T>::RequestNotExpired
+			);
+
+			id.judgements.remove(pos);
+			Self::release_judgement_fee(&target, reg_index, fee);
+			let judgements = id.judgements.len();
+			<IdentityOf<T>>::insert(&target, id);
+
+			Self::deposit_event(Event::JudgementRequestExpired {
+				who: target.clone(),
+				registrar_index: reg_index,
+			});
+			Self::deposit_event(Event::JudgementFeeRefunded {
+				who: target,
+				registrar_index: reg_index,
+				amount: fee,
+			});
+
+			Ok(Some(T::WeightInfo::refund_expired_request(judgements as u32)).into())
+		}
 	}
 }
 
@@ -904,6 +1026,43 @@ impl<T: Config> Pallet<T> {
 		T::SubAccountDeposit::get().saturating_mul(<BalanceOf<T>>::from(subs))
 	}
 
+	/// Release the `fee` held for the judgement request of `who` to the registrar `reg_index`.
+	fn release_judgement_fee(who: &T::AccountId, reg_index: RegistrarIndex, fee: BalanceOf<T>) {
+		let released =
+			T::Currency::release(&HoldReason::JudgementFee.into(), who, fee, Precision::BestEffort);
+		debug_assert!(released.map_or(false, |released| released == fee));
+		<RequestExpiryOf<T>>::remove(who, reg_index);
+	}
+
+	/// Release the fees held for all pending judgement requests of `who`.
+	fn release_judgement_fees(
+		who: &T::AccountId,
+		judgements: &[(RegistrarIndex, Judgement<BalanceOf<T>>)],
+	) {
+		for (reg_index, judgement) in judgements {
+			if let Judgement::FeePaid(fee) = judgement {
+				Self::release_judgement_fee(who, *reg_index, *fee);
+			}
+		}
+	}
+
+	/// Slash the fees held for all pending judgement requests of `who`, handing them to `Slashed`.
+	fn slash_judgement_fees(
+		who: &T::AccountId,
+		judgements: &[(RegistrarIndex, Judgement<BalanceOf<T>>)],
+	) {
+		let mut fees = BalanceOf::<T>::zero();
+		for (reg_index, judgement) in judgements {
+			if let Judgement::FeePaid(fee) = judgement {
+				Self::release_judgement_fee(who, *reg_index, *fee);
+				fees = fees.saturating_add(*fee);
+			}
+		}
+		if !fees.is_zero() {
+			T::Slashed::on_unbalanced(T::Currency::slash(who, fees).0);
+		}
+	}
+
 	/// Take the `current` deposit that `who` is holding, and update it to a `new` one.
 	fn rejig_deposit(
 		who: &T::AccountId,
@@ -954,10 +1113,10 @@ impl<T: Config> Pallet<T> {
 			<SuperOf<T>>::remove(sub);
 		}
 
-		// unreserve any deposits
-		let deposit = id.total_deposit().saturating_add(subs_deposit);
-		let err_amount = T::Currency::unreserve(&who, deposit);
+		// unreserve any deposits and release the fees of pending judgement requests
+		let err_amount = T::Currency::unreserve(&who, id.deposit.saturating_add(subs_deposit));
 		debug_assert!(err_amount.is_zero());
+		Self::release_judgement_fees(&who, &id.judgements);
 		Ok((registrars, encoded_byte_size, actual_subs))
 	}
 
@@ -0,0 +1,166 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the identity pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The log target.
+const TARGET: &'static str = "runtime::identity::migration::v1";
+
+pub mod v1 {
+	use super::*;
+
+	/// Migration moving the fees of pending judgement requests from reserves into holds.
+	///
+	/// Every pending request is given an expiry of `JudgementRequestExpiry` blocks, counted from
+	/// the block in which the migration is executed.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"can only upgrade from version 0"
+			);
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::warn!(
+					target: TARGET,
+					"skipping MigrateToV1: executed on wrong storage version. Expected version 0"
+				);
+				return weight
+			}
+
+			let expiry = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::JudgementRequestExpiry::get());
+			let mut requests = 0u32;
+			for (who, registration) in IdentityOf::<T>::iter() {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				for (reg_index, judgement) in registration.judgements.iter() {
+					let fee = match judgement {
+						Judgement::FeePaid(fee) => *fee,
+						_ => continue,
+					};
+
+					let missing = T::Currency::unreserve(&who, fee);
+					if !missing.is_zero() {
+						log::warn!(
+							target: TARGET,
+							"{:?} had {:?} less reserved than the fee for registrar {}",
+							who,
+							missing,
+							reg_index,
+						);
+					}
+					if let Err(e) = T::Currency::hold(
+						&HoldReason::JudgementFee.into(),
+						&who,
+						fee.saturating_sub(missing),
+					) {
+						log::error!(
+							target: TARGET,
+							"failed to hold the fee of {:?} for registrar {}: {:?}",
+							who,
+							reg_index,
+							e,
+						);
+					}
+					RequestExpiryOf::<T>::insert(&who, reg_index, expiry);
+
+					requests.saturating_inc();
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
+				}
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: TARGET, "Moved the fees of {} judgement requests into holds", requests);
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must upgrade to version 1");
+
+			for (who, registration) in IdentityOf::<T>::iter() {
+				for (reg_index, judgement) in registration.judgements.iter() {
+					if matches!(judgement, Judgement::FeePaid(_)) {
+						ensure!(
+							RequestExpiryOf::<T>::contains_key(&who, reg_index),
+							"pending judgement request without expiry"
+						);
+					}
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		legacy::IdentityInfo,
+		tests::{new_test_ext, Balances, System, Test as T},
+	};
+	use frame_support::{assert_ok, traits::fungible::InspectHold};
+
+	#[test]
+	fn migration_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<T>>();
+			System::set_block_number(5);
+
+			// A pending request with its fee reserved, and a judgement which was given.
+			assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&10, 15));
+			IdentityOf::<T>::insert(
+				10,
+				Registration {
+					judgements: vec![(0, Judgement::FeePaid(15)), (1, Judgement::Reasonable)]
+						.try_into()
+						.unwrap(),
+					deposit: 0,
+					info: IdentityInfo::default(),
+				},
+			);
+
+			v1::MigrateToV1::<T>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<T>::on_chain_storage_version(), 1);
+			assert_eq!(
+				<Balances as InspectHold<_>>::balance_on_hold(
+					&HoldReason::JudgementFee.into(),
+					&10
+				),
+				15
+			);
+			assert_eq!(Balances::reserved_balance(10), 15);
+			assert_eq!(RequestExpiryOf::<T>::get(10, 0), Some(15));
+			assert_eq!(RequestExpiryOf::<T>::get(10, 1), None);
+		});
+	}
+}
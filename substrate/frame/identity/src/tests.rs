@@ -25,8 +25,8 @@ use crate::{
 
 use codec::{Decode, Encode};
 use frame_support::{
-	assert_noop, assert_ok, ord_parameter_types, parameter_types,
-	traits::{ConstU32, ConstU64, EitherOfDiverse, Get},
+	assert_noop, assert_ok, derive_impl, ord_parameter_types, parameter_types,
+	traits::{fungible::InspectHold, ConstU32, ConstU64, EitherOfDiverse, Get},
 	BoundedVec,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
@@ -43,10 +43,11 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>},
+		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>, HoldReason},
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -85,9 +86,9 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
-	type MaxHolds = ();
+	type MaxHolds = ConstU32<1>;
 }
 
 parameter_types! {
@@ -111,6 +112,8 @@ impl pallet_identity::Config for Test {
 	type MaxSubAccounts = ConstU32<2>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type JudgementRequestExpiry = ConstU64<10>;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
 	type WeightInfo = ();
@@ -640,7 +643,12 @@ fn provide_judgement_should_return_judgement_payment_failed_error() {
 		assert_eq!(Balances::free_balance(10), 1000 - id_deposit - 10);
 
 		// This forces judgement payment failed error
-		Balances::make_free_balance_be(&3, 0);
+		assert_ok!(<Balances as MutateHold<_>>::release(
+			&HoldReason::JudgementFee.into(),
+			&10,
+			5,
+			Precision::Exact
+		));
 		assert_noop!(
 			Identity::provide_judgement(
 				RuntimeOrigin::signed(3),
@@ -654,6 +662,104 @@ fn provide_judgement_should_return_judgement_payment_failed_error() {
 	});
 }
 
+#[test]
+fn expired_judgement_request_can_be_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let ten = ten();
+		let id_deposit = id_deposit(&ten);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(3), 0, 10));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten.clone())));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 0, 10));
+		assert_eq!(Identity::request_expiry(10, 0), Some(11));
+		assert_eq!(Balances::free_balance(10), 1000 - id_deposit - 10);
+		assert_eq!(
+			<Balances as InspectHold<_>>::balance_on_hold(&HoldReason::JudgementFee.into(), &10),
+			10
+		);
+
+		assert_noop!(
+			Identity::refund_expired_request(RuntimeOrigin::signed(20), 10, 0),
+			Error::<Test>::RequestNotExpired
+		);
+		assert_noop!(
+			Identity::refund_expired_request(RuntimeOrigin::signed(20), 10, 1),
+			Error::<Test>::NotFound
+		);
+
+		// Anyone can refund the fee once the request expired.
+		System::set_block_number(11);
+		assert_ok!(Identity::refund_expired_request(RuntimeOrigin::signed(20), 10, 0));
+		assert_eq!(Balances::free_balance(10), 1000 - id_deposit);
+		assert_eq!(Balances::free_balance(20), 1000);
+		assert!(Identity::identity(10).unwrap().judgements.is_empty());
+		assert_eq!(Identity::request_expiry(10, 0), None);
+		System::assert_has_event(
+			Event::<Test>::JudgementRequestExpired { who: 10, registrar_index: 0 }.into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::JudgementFeeRefunded { who: 10, registrar_index: 0, amount: 10 }.into(),
+		);
+		assert_noop!(
+			Identity::refund_expired_request(RuntimeOrigin::signed(20), 10, 0),
+			Error::<Test>::NotFound
+		);
+
+		// A request which was answered cannot be refunded.
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 0, 10));
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(3),
+			0,
+			10,
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten)
+		));
+		assert_eq!(Identity::request_expiry(10, 0), None);
+		System::set_block_number(100);
+		assert_noop!(
+			Identity::refund_expired_request(RuntimeOrigin::signed(20), 10, 0),
+			Error::<Test>::JudgementGiven
+		);
+		assert_eq!(Balances::free_balance(3), 100 + 10);
+	});
+}
+
+#[test]
+fn clearing_identity_releases_held_judgement_fees() {
+	new_test_ext().execute_with(|| {
+		let ten = ten();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(3), 0, 10));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten.clone())));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 0, 10));
+
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(10)));
+		assert_eq!(Balances::free_balance(10), 1000);
+		assert_eq!(Balances::reserved_balance(10), 0);
+		assert_eq!(Identity::request_expiry(10, 0), None);
+	});
+}
+
+#[test]
+fn killing_identity_slashes_held_judgement_fees() {
+	new_test_ext().execute_with(|| {
+		let ten = ten();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(3), 0, 10));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten.clone())));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 0, 10));
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::signed(2), 10));
+		assert_eq!(Balances::free_balance(10), 1000 - id_deposit(&ten) - 10);
+		assert_eq!(Balances::reserved_balance(10), 0);
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(Balances::total_issuance(), issuance - id_deposit(&ten) - 10);
+		assert_eq!(Identity::request_expiry(10, 0), None);
+	});
+}
+
 #[test]
 fn field_deposit_should_work() {
 	new_test_ext().execute_with(|| {
@@ -38,6 +38,7 @@ use sp_staking::{
 
 use crate as imonline;
 use crate::Config;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -113,6 +114,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	result
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
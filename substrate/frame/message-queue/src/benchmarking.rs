@@ -27,6 +27,17 @@ use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
+/// Insert a dead letter of maximal length from `origin` at `index`.
+fn insert_dead_letter<T: Config>(origin: MessageOriginOf<T>, index: DeadLetterIndex) {
+	let message = BoundedVec::truncate_from(vec![0; MaxMessageLenOf::<T>::get() as usize]);
+	let hash = sp_io::hashing::blake2_256(&message);
+	DeadLetters::<T>::insert(
+		index,
+		DeadLetter { origin, hash, error: ProcessMessageError::Unsupported, message },
+	);
+	DeadLetterCount::<T>::mutate(|count| count.saturating_inc());
+}
+
 #[benchmarks(
 	where
 		<<T as Config>::MessageProcessor as ProcessMessage>::Origin: From<u32> + PartialEq,
@@ -274,6 +285,39 @@ mod benchmarks {
 		assert!(Pages::<T>::contains_key(&origin, 0), "Page must be updated");
 	}
 
+	// Worst case for `discard_dead_letter`, where the dead letter is of maximal length.
+	#[benchmark]
+	fn discard_dead_letter() -> Result<(), BenchmarkError> {
+		let origin =
+			T::DeadLetterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		insert_dead_letter::<T>(0.into(), 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		assert_last_event::<T>(Event::DeadLetterDiscarded { index: 0 }.into());
+		assert!(!DeadLetters::<T>::contains_key(0));
+		Ok(())
+	}
+
+	// Worst case for `requeue_dead_letter`, where the dead letter is of maximal length and its
+	// queue has to be knitted into the ready ring.
+	#[benchmark]
+	fn requeue_dead_letter() -> Result<(), BenchmarkError> {
+		let origin =
+			T::DeadLetterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		build_ring::<T>(&[1.into(), 2.into()]);
+		insert_dead_letter::<T>(0.into(), 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		assert_last_event::<T>(Event::DeadLetterRequeued { index: 0, origin: 0.into() }.into());
+		assert!(!DeadLetters::<T>::contains_key(0));
+		assert_ring::<T>(&[1.into(), 2.into(), 0.into()]);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		MessageQueue,
 		crate::mock::new_test_ext::<crate::integration_test::Test>(),
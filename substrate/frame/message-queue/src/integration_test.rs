@@ -38,7 +38,7 @@ use crate::{
 
 use crate as pallet_message_queue;
 use frame_support::{
-	parameter_types,
+	derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
@@ -57,6 +57,7 @@ frame_support::construct_runtime!(
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -99,6 +100,8 @@ impl Config for Test {
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
+	type MaxDeadLetters = ConstU32<0>;
+	type DeadLetterOrigin = frame_system::EnsureRoot<u64>;
 }
 
 /// Simulates heavy usage by enqueueing and processing large amounts of messages.
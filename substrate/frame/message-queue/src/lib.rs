@@ -80,9 +80,10 @@
 //! stay within the required limit. This implies that the message processor hook can calculate the
 //! weight of a message without executing it. This restricts the possible use-cases but is necessary
 //! since the pallet runs in `on_initialize` which has a hard weight limit. The weight meter is used
-//! in a way that `can_accrue` and `check_accrue` are always used to check the remaining weight of
+//! in a way that `can_consume` and `try_consume` are always used to check the remaining weight of
 //! an operation before committing to it. The process of exiting due to insufficient weight is
-//! termed "bailing".
+//! termed "bailing". Bailing because the next message does not fit into the remaining weight emits
+//! a [`Event::WeightExhausted`].
 //!
 //! # Scenario: Message enqueuing
 //!
@@ -432,6 +433,24 @@ impl<MessageOrigin> From<BookState<MessageOrigin>> for QueueFootprint {
 	}
 }
 
+/// Type for identifying a dead letter.
+pub type DeadLetterIndex = u32;
+
+/// A message whose processing failed permanently, kept for inspection by governance.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+#[scale_info(skip_type_params(MaxMessageLen))]
+#[codec(mel_bound(MessageOrigin: MaxEncodedLen))]
+pub struct DeadLetter<MessageOrigin, MaxMessageLen: Get<u32>> {
+	/// The origin from which the message arrived.
+	pub origin: MessageOrigin,
+	/// The `blake2_256` hash of the message.
+	pub hash: [u8; 32],
+	/// The error with which the processing of the message failed.
+	pub error: ProcessMessageError,
+	/// The message itself.
+	pub message: BoundedVec<u8, MaxMessageLen>,
+}
+
 /// Handler code for when the items in a queue change.
 pub trait OnQueueChanged<Id> {
 	/// Note that the queue `id` now has `item_count` items in it, taking up `items_size` bytes.
@@ -510,6 +529,16 @@ pub mod pallet {
 		/// `ServiceQueues::service_queues` manually.
 		#[pallet::constant]
 		type ServiceWeight: Get<Option<Weight>>;
+
+		/// The maximum number of permanently failing messages kept in [`DeadLetters`].
+		///
+		/// Such messages are dropped once the dead letters are full. Set this to `0` to always
+		/// drop them.
+		#[pallet::constant]
+		type MaxDeadLetters: Get<u32>;
+
+		/// The origin which may discard or re-enqueue dead letters.
+		type DeadLetterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	#[pallet::event]
@@ -528,6 +557,25 @@ pub mod pallet {
 		},
 		/// This page was reaped.
 		PageReaped { origin: MessageOriginOf<T>, index: PageIndex },
+		/// The weight limit was exhausted before this message could be processed. Processing
+		/// resumes with it in a later block.
+		WeightExhausted {
+			origin: MessageOriginOf<T>,
+			page_index: PageIndex,
+			message_index: T::Size,
+		},
+		/// Message failed permanently due to an error in the `MessageProcessor` and was moved to
+		/// the dead letters.
+		DeadLettered {
+			id: [u8; 32],
+			origin: MessageOriginOf<T>,
+			index: DeadLetterIndex,
+			error: ProcessMessageError,
+		},
+		/// A dead letter was discarded.
+		DeadLetterDiscarded { index: DeadLetterIndex },
+		/// A dead letter was enqueued again.
+		DeadLetterRequeued { index: DeadLetterIndex, origin: MessageOriginOf<T> },
 	}
 
 	#[pallet::error]
@@ -554,6 +602,8 @@ pub mod pallet {
 		///
 		/// This can change at any time and may resolve in the future by re-trying.
 		QueuePaused,
+		/// The referenced dead letter could not be found.
+		NoDeadLetter,
 	}
 
 	/// The index of the first and last (non-empty) pages.
@@ -577,6 +627,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Messages whose processing failed permanently, by their index.
+	#[pallet::storage]
+	pub(super) type DeadLetters<T: Config> =
+		StorageMap<_, Twox64Concat, DeadLetterIndex, DeadLetterOf<T>, OptionQuery>;
+
+	/// The number of messages in [`DeadLetters`].
+	#[pallet::storage]
+	pub(super) type DeadLetterCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The index of the next message moved to [`DeadLetters`].
+	#[pallet::storage]
+	pub(super) type NextDeadLetterIndex<T: Config> = StorageValue<_, DeadLetterIndex, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
@@ -643,6 +706,36 @@ pub mod pallet {
 				Self::do_execute_overweight(message_origin, page, index, weight_limit)?;
 			Ok(Some(actual_weight).into())
 		}
+
+		/// Discard a dead letter.
+		///
+		/// - `origin`: Must pass `T::DeadLetterOrigin`.
+		/// - `index`: The index of the dead letter to discard.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::discard_dead_letter())]
+		pub fn discard_dead_letter(origin: OriginFor<T>, index: DeadLetterIndex) -> DispatchResult {
+			T::DeadLetterOrigin::ensure_origin(origin)?;
+			Self::take_dead_letter(index)?;
+			Self::deposit_event(Event::<T>::DeadLetterDiscarded { index });
+			Ok(())
+		}
+
+		/// Enqueue a dead letter again, at the end of the queue of its origin.
+		///
+		/// - `origin`: Must pass `T::DeadLetterOrigin`.
+		/// - `index`: The index of the dead letter to enqueue again.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::requeue_dead_letter())]
+		pub fn requeue_dead_letter(origin: OriginFor<T>, index: DeadLetterIndex) -> DispatchResult {
+			T::DeadLetterOrigin::ensure_origin(origin)?;
+			let letter = Self::take_dead_letter(index)?;
+			<Self as EnqueueMessage<_>>::enqueue_message(
+				letter.message.as_bounded_slice(),
+				letter.origin.clone(),
+			);
+			Self::deposit_event(Event::<T>::DeadLetterRequeued { index, origin: letter.origin });
+			Ok(())
+		}
 	}
 }
 
@@ -1135,7 +1228,14 @@ impl<T: Config> Pallet<T> {
 			weight,
 			overweight_limit,
 		) {
-			InsufficientWeight => return ItemExecutionStatus::Bailed,
+			InsufficientWeight => {
+				Self::deposit_event(Event::<T>::WeightExhausted {
+					origin: origin.clone(),
+					page_index,
+					message_index: page.first_index,
+				});
+				return ItemExecutionStatus::Bailed
+			},
 			Unprocessable { permanent: false } => return ItemExecutionStatus::NoProgress,
 			Processed | Unprocessable { permanent: true } => true,
 			Overweight => false,
@@ -1338,8 +1438,12 @@ impl<T: Config> Pallet<T> {
 				MessageExecutionStatus::Unprocessable { permanent: false }
 			},
 			Err(error @ BadFormat | error @ Corrupt | error @ Unsupported) => {
-				// Permanent error - drop
-				Self::deposit_event(Event::<T>::ProcessingFailed { id, origin, error });
+				// Permanent error - move to the dead letters if possible, otherwise drop
+				match Self::dead_letter(origin.clone(), hash, error, message, meter) {
+					Some(index) =>
+						Self::deposit_event(Event::<T>::DeadLettered { id, origin, index, error }),
+					None => Self::deposit_event(Event::<T>::ProcessingFailed { id, origin, error }),
+				}
 				MessageExecutionStatus::Unprocessable { permanent: true }
 			},
 			Ok(success) => {
@@ -1352,6 +1456,40 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Move a permanently failing message to the dead letters.
+	///
+	/// Returns the index of the dead letter, or `None` if the dead letters are full or there is
+	/// not enough weight left in `meter` to store it.
+	fn dead_letter(
+		origin: MessageOriginOf<T>,
+		hash: [u8; 32],
+		error: ProcessMessageError,
+		message: &[u8],
+		meter: &mut WeightMeter,
+	) -> Option<DeadLetterIndex> {
+		let count = DeadLetterCount::<T>::get();
+		if count >= T::MaxDeadLetters::get() {
+			return None
+		}
+		let message = BoundedVec::try_from(message.to_vec()).ok()?;
+		meter.try_consume(T::DbWeight::get().reads_writes(2, 3)).ok()?;
+
+		let index = NextDeadLetterIndex::<T>::get();
+		DeadLetters::<T>::insert(index, DeadLetter { origin, hash, error, message });
+		DeadLetterCount::<T>::put(count.saturating_add(1));
+		NextDeadLetterIndex::<T>::put(index.wrapping_add(1));
+		Some(index)
+	}
+
+	/// Remove a dead letter from storage and return it.
+	fn take_dead_letter(index: DeadLetterIndex) -> Result<DeadLetterOf<T>, Error<T>> {
+		let letter = DeadLetters::<T>::take(index).ok_or(Error::<T>::NoDeadLetter)?;
+		DeadLetterCount::<T>::mutate(|count| count.saturating_dec());
+		Ok(letter)
+	}
+}
+
 /// Provides a [`sp_core::Get`] to access the `MEL` of a [`codec::MaxEncodedLen`] type.
 pub struct MaxEncodedLenOf<T>(sp_std::marker::PhantomData<T>);
 impl<T: MaxEncodedLen> Get<u32> for MaxEncodedLenOf<T> {
@@ -1383,6 +1521,8 @@ pub type MessageOriginOf<T> = <<T as Config>::MessageProcessor as ProcessMessage
 pub type HeapSizeU32Of<T> = IntoU32<<T as Config>::HeapSize, <T as Config>::Size>;
 /// The [`Page`] of this pallet.
 pub type PageOf<T> = Page<<T as Config>::Size, <T as Config>::HeapSize>;
+/// The [`DeadLetter`] of this pallet.
+pub type DeadLetterOf<T> = DeadLetter<MessageOriginOf<T>, MaxMessageLenOf<T>>;
 /// The [`BookState`] of this pallet.
 pub type BookStateOf<T> = BookState<MessageOriginOf<T>>;
 
@@ -24,7 +24,7 @@ use super::*;
 
 use crate as pallet_message_queue;
 use frame_support::{
-	parameter_types,
+	derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use sp_core::H256;
@@ -43,6 +43,7 @@ frame_support::construct_runtime!(
 		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>},
 	}
 );
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -72,6 +73,7 @@ parameter_types! {
 	pub const HeapSize: u32 = 24;
 	pub const MaxStale: u32 = 2;
 	pub const ServiceWeight: Option<Weight> = Some(Weight::from_parts(100, 100));
+	pub static MaxDeadLetters: u32 = 0;
 }
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -83,6 +85,8 @@ impl Config for Test {
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
+	type MaxDeadLetters = MaxDeadLetters;
+	type DeadLetterOrigin = frame_system::EnsureRoot<u64>;
 }
 
 /// Mocked `WeightInfo` impl with allows to set the weight per call.
@@ -108,6 +112,12 @@ impl crate::weights::WeightInfo for MockedWeightInfo {
 	fn reap_page() -> Weight {
 		WeightForCall::get().get("reap_page").copied().unwrap_or_default()
 	}
+	fn discard_dead_letter() -> Weight {
+		WeightForCall::get().get("discard_dead_letter").copied().unwrap_or_default()
+	}
+	fn requeue_dead_letter() -> Weight {
+		WeightForCall::get().get("requeue_dead_letter").copied().unwrap_or_default()
+	}
 	fn execute_overweight_page_updated() -> Weight {
 		WeightForCall::get()
 			.get("execute_overweight_page_updated")
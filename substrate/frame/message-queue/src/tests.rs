@@ -212,6 +212,137 @@ fn service_queues_failing_messages_works() {
 	});
 }
 
+#[test]
+fn service_queues_failing_messages_are_dead_lettered() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(2);
+		set_weight("service_page_item", 1.into_weight());
+		MessageQueue::enqueue_message(msg("badformat"), Here);
+		MessageQueue::enqueue_message(msg("corrupt"), Here);
+		MessageQueue::enqueue_message(msg("unsupported"), Here);
+
+		assert_eq!(MessageQueue::service_queues(1.into_weight()), 1.into_weight());
+		assert_last_event::<Test>(
+			Event::DeadLettered {
+				id: blake2_256(b"badformat"),
+				origin: Here,
+				index: 0,
+				error: ProcessMessageError::BadFormat,
+			}
+			.into(),
+		);
+		assert_eq!(MessageQueue::service_queues(1.into_weight()), 1.into_weight());
+		assert_last_event::<Test>(
+			Event::DeadLettered {
+				id: blake2_256(b"corrupt"),
+				origin: Here,
+				index: 1,
+				error: ProcessMessageError::Corrupt,
+			}
+			.into(),
+		);
+		// The dead letters are full, so the message is dropped.
+		assert_eq!(MessageQueue::service_queues(1.into_weight()), 1.into_weight());
+		assert_last_event::<Test>(
+			Event::ProcessingFailed {
+				id: blake2_256(b"unsupported"),
+				origin: Here,
+				error: ProcessMessageError::Unsupported,
+			}
+			.into(),
+		);
+		assert_eq!(DeadLetterCount::<Test>::get(), 2);
+		assert_eq!(
+			DeadLetters::<Test>::get(0),
+			Some(DeadLetter {
+				origin: Here,
+				hash: blake2_256(b"badformat"),
+				error: ProcessMessageError::BadFormat,
+				message: BoundedVec::truncate_from(vmsg("badformat")),
+			})
+		);
+	});
+}
+
+#[test]
+fn dead_letters_are_capped_by_max_dead_letters() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(1);
+		MessageQueue::enqueue_messages(vec![msg("badformat"), msg("corrupt")].into_iter(), Here);
+		MessageQueue::service_queues(Weight::MAX);
+		assert_eq!(DeadLetterCount::<Test>::get(), 1);
+		assert!(DeadLetters::<Test>::contains_key(0));
+		assert_last_event::<Test>(
+			Event::ProcessingFailed {
+				id: blake2_256(b"corrupt"),
+				origin: Here,
+				error: ProcessMessageError::Corrupt,
+			}
+			.into(),
+		);
+
+		// Discarding a dead letter makes room for the next one.
+		assert_ok!(MessageQueue::discard_dead_letter(RuntimeOrigin::root(), 0));
+		MessageQueue::enqueue_message(msg("unsupported"), There);
+		MessageQueue::service_queues(Weight::MAX);
+		assert_last_event::<Test>(
+			Event::DeadLettered {
+				id: blake2_256(b"unsupported"),
+				origin: There,
+				index: 1,
+				error: ProcessMessageError::Unsupported,
+			}
+			.into(),
+		);
+		assert_eq!(DeadLetterCount::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn dead_letters_can_be_discarded_and_requeued() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(2);
+		set_weight("service_page_item", 1.into_weight());
+		MessageQueue::enqueue_messages(vec![msg("badformat"), msg("corrupt")].into_iter(), Here);
+		MessageQueue::service_queues(Weight::MAX);
+		assert_eq!(DeadLetterCount::<Test>::get(), 2);
+
+		assert_noop!(
+			MessageQueue::discard_dead_letter(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(MessageQueue::discard_dead_letter(RuntimeOrigin::root(), 1));
+		assert_last_event::<Test>(Event::DeadLetterDiscarded { index: 1 }.into());
+		assert_noop!(
+			MessageQueue::discard_dead_letter(RuntimeOrigin::root(), 1),
+			Error::<Test>::NoDeadLetter
+		);
+		assert_eq!(DeadLetterCount::<Test>::get(), 1);
+
+		QueueChanges::take();
+		assert_ok!(MessageQueue::requeue_dead_letter(RuntimeOrigin::root(), 0));
+		assert_last_event::<Test>(Event::DeadLetterRequeued { index: 0, origin: Here }.into());
+		assert_eq!(QueueChanges::take(), vec![(Here, 1, 9)]);
+		assert_eq!(DeadLetterCount::<Test>::get(), 0);
+		assert!(!DeadLetters::<Test>::contains_key(0));
+
+		// Failing again, the message gets a new index.
+		MessageQueue::service_queues(Weight::MAX);
+		assert_last_event::<Test>(
+			Event::DeadLettered {
+				id: blake2_256(b"badformat"),
+				origin: Here,
+				index: 2,
+				error: ProcessMessageError::BadFormat,
+			}
+			.into(),
+		);
+	});
+}
+
 #[test]
 fn service_queues_suspension_works() {
 	use MessageOrigin::*;
@@ -305,6 +436,32 @@ fn service_queues_regression_1873() {
 	});
 }
 
+#[test]
+fn service_queues_weight_exhausted_works() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MessageQueue::enqueue_messages([msg("weight=2"), msg("weight=3")].into_iter(), Here);
+
+		// The second message does not fit into the remaining weight.
+		assert_eq!(MessageQueue::service_queues(4.into_weight()), 2.into_weight());
+		assert_last_event::<Test>(
+			Event::WeightExhausted { origin: Here, page_index: 1, message_index: 0 }.into(),
+		);
+
+		// It is processed once there is enough weight.
+		assert_eq!(MessageQueue::service_queues(4.into_weight()), 3.into_weight());
+		assert_last_event::<Test>(
+			Event::Processed {
+				id: blake2_256(b"weight=3"),
+				origin: Here,
+				weight_used: 3.into_weight(),
+				success: true,
+			}
+			.into(),
+		);
+	});
+}
+
 #[test]
 fn reap_page_permanent_overweight_works() {
 	use MessageOrigin::*;
@@ -21,7 +21,7 @@ use super::*;
 use crate as pallet_nft_fractionalization;
 
 use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime, derive_impl, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
 	BoundedVec, PalletId,
 };
@@ -49,6 +49,7 @@ construct_runtime!(
 		Nfts: pallet_nfts,
 	}
 );
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -96,6 +97,7 @@ impl pallet_assets::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<5>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
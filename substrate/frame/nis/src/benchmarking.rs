@@ -21,8 +21,9 @@
 
 use super::*;
 use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
-use frame_support::traits::{
-	fungible::Inspect as FunInspect, nonfungible::Inspect, EnsureOrigin, Get,
+use frame_support::{
+	traits::{fungible::Inspect as FunInspect, nonfungible::Inspect, EnsureOrigin, Get},
+	weights::WeightMeter,
 };
 use frame_system::RawOrigin;
 use sp_arithmetic::Perquintill;
@@ -113,7 +114,7 @@ benchmarks! {
 		let ed = T::Currency::minimum_balance();
 		T::Currency::set_balance(&caller, ed + bid);
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
-		Nis::<T>::process_queues(Perquintill::one(), 1, 1, &mut WeightCounter::unlimited());
+		Nis::<T>::process_queues(Perquintill::one(), 1, 1, &mut WeightMeter::new());
 		Nis::<T>::communify(RawOrigin::Signed(caller.clone()).into(), 0)?;
 		let original = T::Currency::balance(&Nis::<T>::account_id());
 		T::Currency::set_balance(&Nis::<T>::account_id(), BalanceOf::<T>::min_value());
@@ -132,10 +133,14 @@ benchmarks! {
 		T::Currency::set_balance(&caller, ed + bid + bid);
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
-		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightCounter::unlimited());
+		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightMeter::new());
 	}: _(RawOrigin::Signed(caller.clone()), 0)
 	verify {
 		assert_eq!(Nis::<T>::owner(&0), None);
+		assert_eq!(
+			Summary::<T>::get().proportion_communal,
+			Receipts::<T>::get(0).unwrap().proportion,
+		);
 	}
 
 	privatize {
@@ -145,11 +150,12 @@ benchmarks! {
 		T::Currency::set_balance(&caller, ed + bid + bid);
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
-		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightCounter::unlimited());
+		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightMeter::new());
 		Nis::<T>::communify(RawOrigin::Signed(caller.clone()).into(), 0)?;
 	}: _(RawOrigin::Signed(caller.clone()), 0)
 	verify {
 		assert_eq!(Nis::<T>::owner(&0), Some(caller));
+		assert!(Summary::<T>::get().proportion_communal.is_zero());
 	}
 
 	thaw_private {
@@ -162,7 +168,7 @@ benchmarks! {
 		T::Currency::set_balance(&whale, T::ThawThrottle::get().0.saturating_reciprocal_mul_ceil(T::Currency::balance(&caller)));
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
-		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightCounter::unlimited());
+		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightMeter::new());
 		frame_system::Pallet::<T>::set_block_number(Receipts::<T>::get(0).unwrap().expiry);
 	}: _(RawOrigin::Signed(caller.clone()), 0, None)
 	verify {
@@ -179,12 +185,13 @@ benchmarks! {
 		T::Currency::set_balance(&whale, T::ThawThrottle::get().0.saturating_reciprocal_mul_ceil(T::Currency::balance(&caller)));
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
-		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightCounter::unlimited());
+		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightMeter::new());
 		frame_system::Pallet::<T>::set_block_number(Receipts::<T>::get(0).unwrap().expiry);
 		Nis::<T>::communify(RawOrigin::Signed(caller.clone()).into(), 0)?;
 	}: _(RawOrigin::Signed(caller.clone()), 0)
 	verify {
 		assert!(Receipts::<T>::get(0).is_none());
+		assert!(Summary::<T>::get().proportion_communal.is_zero());
 	}
 
 	process_queues {
@@ -194,7 +201,7 @@ benchmarks! {
 			Perquintill::one(),
 			Zero::zero(),
 			u32::max_value(),
-			&mut WeightCounter::unlimited(),
+			&mut WeightMeter::new(),
 		)
 	}
 
@@ -212,7 +219,7 @@ benchmarks! {
 			&mut Bounded::max_value(),
 			&mut (T::MaxQueueLen::get(), Bounded::max_value()),
 			&mut summary,
-			&mut WeightCounter::unlimited(),
+			&mut WeightMeter::new(),
 		)
 	}
 
@@ -89,6 +89,7 @@ use sp_runtime::{
 };
 
 mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -173,6 +174,7 @@ pub mod pallet {
 			},
 			Defensive, DefensiveSaturating, OnUnbalanced,
 		},
+		weights::WeightMeter,
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
@@ -299,7 +301,11 @@ pub mod pallet {
 		type ThawThrottle: Get<(Perquintill, BlockNumberFor<Self>)>;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// A single bid, an item of a *queue* in `Queues`.
@@ -354,6 +360,9 @@ pub mod pallet {
 		/// The total amount of funds on hold for receipts. This doesn't include the pot or funds
 		/// on hold for bids.
 		pub receipts_on_hold: Balance,
+		/// The total proportion over all communal receipts, i.e. those for which fungible
+		/// counterparts have been issued.
+		pub proportion_communal: Perquintill,
 	}
 
 	pub struct OnEmptyQueueTotals<T>(sp_std::marker::PhantomData<T>);
@@ -428,6 +437,26 @@ pub mod pallet {
 		Funded { deficit: BalanceOf<T> },
 		/// A receipt was transfered.
 		Transferred { from: T::AccountId, to: T::AccountId, index: ReceiptIndex },
+		/// A receipt was made communal and fungible counterparts were issued for it.
+		Communified {
+			/// The identity of the receipt.
+			index: ReceiptIndex,
+			/// The former owner, who received the fungible counterparts.
+			who: T::AccountId,
+			/// The proportion of the effective total issuance which the receipt represents.
+			proportion: Perquintill,
+		},
+		/// A receipt was made private and its fungible counterparts were burned.
+		Privatized {
+			/// The identity of the receipt.
+			index: ReceiptIndex,
+			/// The new owner of the receipt.
+			who: T::AccountId,
+			/// The proportion of the effective total issuance which the receipt represents.
+			proportion: Perquintill,
+			/// The amount of funds which were put on hold for the new owner.
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -473,36 +502,12 @@ pub mod pallet {
 		NftReceipt,
 	}
 
-	pub(crate) struct WeightCounter {
-		pub(crate) used: Weight,
-		pub(crate) limit: Weight,
-	}
-	impl WeightCounter {
-		#[allow(dead_code)]
-		pub(crate) fn unlimited() -> Self {
-			WeightCounter { used: Weight::zero(), limit: Weight::max_value() }
-		}
-		fn check_accrue(&mut self, w: Weight) -> bool {
-			let test = self.used.saturating_add(w);
-			if test.any_gt(self.limit) {
-				false
-			} else {
-				self.used = test;
-				true
-			}
-		}
-		fn can_accrue(&mut self, w: Weight) -> bool {
-			self.used.saturating_add(w).all_lte(self.limit)
-		}
-	}
-
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-			let mut weight_counter =
-				WeightCounter { used: Weight::zero(), limit: T::MaxIntakeWeight::get() };
+			let mut weight_counter = WeightMeter::with_limit(T::MaxIntakeWeight::get());
 			if T::IntakePeriod::get().is_zero() || (n % T::IntakePeriod::get()).is_zero() {
-				if weight_counter.check_accrue(T::WeightInfo::process_queues()) {
+				if weight_counter.try_consume(T::WeightInfo::process_queues()).is_ok() {
 					Self::process_queues(
 						T::Target::get(),
 						T::QueueCount::get(),
@@ -511,7 +516,7 @@ pub mod pallet {
 					)
 				}
 			}
-			weight_counter.used
+			weight_counter.consumed()
 		}
 
 		fn integrity_test() {
@@ -800,6 +805,7 @@ pub mod pallet {
 			let amount = receipt.proportion * effective_issuance;
 
 			summary.proportion_owed.saturating_reduce(receipt.proportion);
+			summary.proportion_communal.saturating_reduce(receipt.proportion);
 
 			// Try to transfer amount owed from pot to receipt owner.
 			T::Currency::transfer(&our_account, &who, amount, Expendable)
@@ -840,9 +846,10 @@ pub mod pallet {
 			T::Currency::transfer_on_hold(&reason, &who, &us, on_hold, Exact, Free, Polite)
 				.map_err(|_| Error::<T>::Unfunded)?;
 
-			// Record that we've moved the amount reserved.
+			// Record that we've moved the amount reserved and issued the counterparts.
 			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
 			summary.receipts_on_hold.saturating_reduce(on_hold);
+			summary.proportion_communal.saturating_accrue(receipt.proportion);
 			Summary::<T>::put(&summary);
 			Receipts::<T>::insert(index, &receipt);
 
@@ -850,6 +857,8 @@ pub mod pallet {
 			let fung_eq = T::CounterpartAmount::convert(receipt.proportion);
 			let _ = T::Counterpart::mint_into(&who, fung_eq).defensive();
 
+			Self::deposit_event(Event::Communified { index, who, proportion: receipt.proportion });
+
 			Ok(())
 		}
 
@@ -890,14 +899,22 @@ pub mod pallet {
 			let us = Self::account_id();
 			T::Currency::transfer_and_hold(&reason, &us, &who, amount, Exact, Expendable, Polite)?;
 
-			// Record that we've moved the amount reserved.
+			// Record that we've moved the amount reserved and burned the counterparts.
 			summary.receipts_on_hold.saturating_accrue(amount);
+			summary.proportion_communal.saturating_reduce(receipt.proportion);
 
-			receipt.owner = Some((who, amount));
+			receipt.owner = Some((who.clone(), amount));
 
 			Summary::<T>::put(&summary);
 			Receipts::<T>::insert(index, &receipt);
 
+			Self::deposit_event(Event::Privatized {
+				index,
+				who,
+				proportion: receipt.proportion,
+				amount,
+			});
+
 			Ok(())
 		}
 	}
@@ -970,6 +987,13 @@ pub mod pallet {
 			Self::issuance_with(&Self::account_id(), &Summary::<T>::get())
 		}
 
+		/// Returns the amount of fungible counterparts which are outstanding for all communal
+		/// receipts.
+		pub fn communal_counterpart_issuance(
+		) -> <T::Counterpart as FunInspect<T::AccountId>>::Balance {
+			T::CounterpartAmount::convert(Summary::<T>::get().proportion_communal)
+		}
+
 		/// Returns information on the issuance within the system
 		///
 		/// This function is equivalent to `issuance`, except that it accepts arguments rather than
@@ -1002,7 +1026,7 @@ pub mod pallet {
 			target: Perquintill,
 			max_queues: u32,
 			max_bids: u32,
-			weight: &mut WeightCounter,
+			weight: &mut WeightMeter,
 		) {
 			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
 			if summary.proportion_owed >= target {
@@ -1024,9 +1048,9 @@ pub mod pallet {
 					continue
 				}
 				if remaining.is_zero() || queues_hit >= max_queues
-					|| !weight.check_accrue(T::WeightInfo::process_queue())
+					|| weight.try_consume(T::WeightInfo::process_queue()).is_err()
 					// No point trying to process a queue if we can't process a single bid.
-					|| !weight.can_accrue(T::WeightInfo::process_bid())
+					|| !weight.can_consume(T::WeightInfo::process_bid())
 				{
 					break
 				}
@@ -1059,7 +1083,7 @@ pub mod pallet {
 			remaining: &mut BalanceOf<T>,
 			queue_total: &mut (u32, BalanceOf<T>),
 			summary: &mut SummaryRecordOf<T>,
-			weight: &mut WeightCounter,
+			weight: &mut WeightMeter,
 		) -> u32 {
 			let mut queue: BoundedVec<BidOf<T>, _> = Queues::<T>::get(&duration);
 			let expiry = now.saturating_add(T::BasePeriod::get().saturating_mul(duration.into()));
@@ -1068,7 +1092,7 @@ pub mod pallet {
 			while count < max_bids &&
 				!queue.is_empty() &&
 				!remaining.is_zero() &&
-				weight.check_accrue(T::WeightInfo::process_bid())
+				weight.try_consume(T::WeightInfo::process_bid()).is_ok()
 			{
 				let bid = match queue.pop() {
 					Some(b) => b,
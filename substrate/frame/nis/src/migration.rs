@@ -0,0 +1,124 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::*,
+	traits::{Get, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::Saturating;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migrations to version [`1`], adding `proportion_communal` to the [`SummaryRecord`].
+pub mod v1 {
+	use super::*;
+
+	type BalanceOf<T> =
+		<<T as Config>::Currency as FunInspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The [`SummaryRecord`] as it was before version 1.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+	pub struct OldSummaryRecord<BlockNumber, Balance> {
+		pub proportion_owed: Perquintill,
+		pub index: ReceiptIndex,
+		pub thawed: Perquintill,
+		pub last_period: BlockNumber,
+		pub receipts_on_hold: Balance,
+	}
+
+	/// Actual implementation of [`MigrateToV1`].
+	///
+	/// Sums up the proportions of all communal receipts into the new
+	/// [`SummaryRecord::proportion_communal`] field.
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let old = frame_support::storage::unhashed::get::<
+				OldSummaryRecord<BlockNumberFor<T>, BalanceOf<T>>,
+			>(&Summary::<T>::hashed_key());
+			Ok(old.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 1u64;
+			let mut proportion_communal = Perquintill::zero();
+			for receipt in Receipts::<T>::iter_values() {
+				reads.saturating_inc();
+				if receipt.owner.is_none() {
+					proportion_communal.saturating_accrue(receipt.proportion);
+				}
+			}
+
+			let _ = Summary::<T>::translate::<OldSummaryRecord<BlockNumberFor<T>, BalanceOf<T>>, _>(
+				|maybe_old| {
+					let old = maybe_old.unwrap_or_default();
+					Some(SummaryRecord {
+						proportion_owed: old.proportion_owed,
+						index: old.index,
+						thawed: old.thawed,
+						last_period: old.last_period,
+						receipts_on_hold: old.receipts_on_hold,
+						proportion_communal,
+					})
+				},
+			);
+
+			T::DbWeight::get().reads_writes(reads, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let old = Option::<OldSummaryRecord<BlockNumberFor<T>, BalanceOf<T>>>::decode(
+				&mut &state[..],
+			)
+			.map_err(|_| "Nis: failed to decode the pre-upgrade state")?;
+			let new = Summary::<T>::get();
+
+			if let Some(old) = old {
+				ensure!(
+					old.proportion_owed == new.proportion_owed &&
+						old.index == new.index &&
+						old.thawed == new.thawed &&
+						old.last_period == new.last_period &&
+						old.receipts_on_hold == new.receipts_on_hold,
+					"Nis: the summary changed during the migration"
+				);
+			}
+			ensure!(
+				new.proportion_communal <= new.proportion_owed,
+				"Nis: communal proportion exceeds the total proportion owed"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// Migrate the storage from version 0 to version 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		VersionUncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -26,6 +26,7 @@ use frame_support::{
 		nonfungible::{Inspect, Transfer},
 		tokens::{Fortitude::Force, Precision::Exact},
 	},
+	weights::WeightMeter,
 };
 use sp_arithmetic::Perquintill;
 use sp_runtime::{
@@ -49,7 +50,7 @@ fn enlarge(amount: Balance, max_bids: u32) {
 	let summary: SummaryRecord<u64, Balance> = Summary::<Test>::get();
 	let increase_in_proportion_owed = Perquintill::from_rational(amount, Nis::issuance().effective);
 	let target = summary.proportion_owed.saturating_add(increase_in_proportion_owed);
-	Nis::process_queues(target, u32::max_value(), max_bids, &mut WeightCounter::unlimited());
+	Nis::process_queues(target, u32::max_value(), max_bids, &mut WeightMeter::new());
 }
 
 #[test]
@@ -68,6 +69,7 @@ fn basic_setup_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 0,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 	});
@@ -224,6 +226,7 @@ fn basic_enlarge_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 40,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 		assert_eq!(
@@ -277,6 +280,7 @@ fn enlarge_respects_bids_limit() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 80,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 	});
@@ -309,6 +313,7 @@ fn enlarge_respects_amount_limit_and_will_split() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 40,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 	});
@@ -350,6 +355,7 @@ fn basic_thaw_works() {
 				last_period: 0,
 				thawed: Perquintill::from_percent(10),
 				receipts_on_hold: 0,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 		assert_eq!(Receipts::<Test>::get(0), None);
@@ -393,6 +399,7 @@ fn partial_thaw_works() {
 				last_period: 0,
 				thawed: Perquintill::from_percent(20),
 				receipts_on_hold: 0,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 		assert_eq!(Receipts::<Test>::get(0), None);
@@ -532,6 +539,92 @@ fn privatize_and_thaw_with_another_receipt_works() {
 	});
 }
 
+#[test]
+fn communal_proportion_is_tracked() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Nis::place_bid(signed(1), 40, 1));
+		assert_ok!(Nis::place_bid(signed(2), 40, 1));
+		enlarge(80, 2);
+		run_to_block(4);
+		assert_eq!(Summary::<Test>::get().proportion_communal, Perquintill::zero());
+
+		assert_ok!(Nis::communify(signed(1), 0));
+		System::assert_last_event(
+			Event::<Test>::Communified {
+				index: 0,
+				who: 1,
+				proportion: Perquintill::from_percent(10),
+			}
+			.into(),
+		);
+		assert_ok!(Nis::communify(signed(2), 1));
+		assert_eq!(Summary::<Test>::get().proportion_communal, Perquintill::from_percent(20));
+		assert_eq!(Nis::communal_counterpart_issuance(), 4_200_000);
+		assert_eq!(NisBalances::total_issuance(), 4_200_000);
+
+		assert_ok!(Nis::privatize(signed(2), 1));
+		System::assert_last_event(
+			Event::<Test>::Privatized {
+				index: 1,
+				who: 2,
+				proportion: Perquintill::from_percent(10),
+				amount: 40,
+			}
+			.into(),
+		);
+		assert_eq!(Summary::<Test>::get().proportion_communal, Perquintill::from_percent(10));
+		assert_eq!(Nis::communal_counterpart_issuance(), 2_100_000);
+
+		assert_ok!(Nis::thaw_communal(signed(1), 0));
+		assert_eq!(Summary::<Test>::get().proportion_communal, Perquintill::zero());
+		assert_eq!(Nis::communal_counterpart_issuance(), 0);
+		assert_eq!(NisBalances::total_issuance(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v1_works() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+	use migration::v1::{MigrateToV1, OldSummaryRecord};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Nis>();
+		let old = OldSummaryRecord::<u64, Balance> {
+			proportion_owed: Perquintill::from_percent(30),
+			index: 3,
+			thawed: Perquintill::from_percent(1),
+			last_period: 2,
+			receipts_on_hold: 40,
+		};
+		frame_support::storage::unhashed::put(&Summary::<Test>::hashed_key(), &old);
+
+		let receipt = |percent, owner| ReceiptRecord {
+			proportion: Perquintill::from_percent(percent),
+			owner,
+			expiry: 10,
+		};
+		Receipts::<Test>::insert(0, receipt(10, Some((1, 40))));
+		Receipts::<Test>::insert(1, receipt(5, None));
+		Receipts::<Test>::insert(2, receipt(15, None));
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Nis::on_chain_storage_version(), 1);
+		assert_eq!(
+			Summary::<Test>::get(),
+			SummaryRecord {
+				proportion_owed: Perquintill::from_percent(30),
+				index: 3,
+				last_period: 2,
+				thawed: Perquintill::from_percent(1),
+				receipts_on_hold: 40,
+				proportion_communal: Perquintill::from_percent(20),
+			}
+		);
+	});
+}
+
 #[test]
 fn communal_thaw_when_issuance_higher_works() {
 	new_test_ext().execute_with(|| {
@@ -775,6 +868,7 @@ fn enlargement_to_target_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 80,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 
@@ -788,6 +882,7 @@ fn enlargement_to_target_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 80,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 
@@ -817,6 +912,7 @@ fn enlargement_to_target_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 160,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 
@@ -830,6 +926,7 @@ fn enlargement_to_target_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 160,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 
@@ -855,6 +952,7 @@ fn enlargement_to_target_works() {
 				last_period: 0,
 				thawed: Perquintill::zero(),
 				receipts_on_hold: 200,
+				proportion_communal: Perquintill::zero(),
 			}
 		);
 	});
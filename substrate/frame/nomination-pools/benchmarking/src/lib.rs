@@ -23,26 +23,28 @@
 #[cfg(test)]
 mod mock;
 
-use frame_benchmarking::v1::{account, whitelist_account};
+use frame_benchmarking::v1::{account, whitelist_account, BenchmarkError};
 use frame_election_provider_support::SortedListProvider;
 use frame_support::{
 	assert_ok, ensure,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		fungible::{Inspect, Mutate, Unbalanced},
-		Get,
+		Get, Imbalance,
 	},
 };
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_nomination_pools::{
 	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
-	Commission, CommissionChangeRate, ConfigOp, GlobalMaxCommission, MaxPoolMembers,
-	MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond, Pallet as Pools,
-	PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	Commission, CommissionChangeRate, ConfigOp, DelegatedPools, DelegatingMembers,
+	GlobalMaxCommission, MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond,
+	MinJoinBond, Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools,
+	SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
 	traits::{Bounded, StaticLookup, Zero},
-	Perbill,
+	DispatchError, Perbill, SaturatedConversion,
 };
 use sp_staking::{EraIndex, StakingInterface};
 use sp_std::{vec, vec::Vec};
@@ -113,6 +115,61 @@ fn create_pool_account<T: pallet_nomination_pools::Config>(
 	(pool_creator, pool_account)
 }
 
+// The migration to delegated stake is only benchmarked if `T::Delegation` supports it: otherwise
+// the migration calls fail right away, and their benchmarks are weightless.
+fn ensure_delegation_supported<T: pallet_nomination_pools::Config>(
+	pool_id: PoolId,
+) -> Result<(), BenchmarkError> {
+	let supported = with_transaction(|| {
+		let migrated = Pools::<T>::migrate_pool_to_delegate_stake(
+			RuntimeOrigin::Signed(account("prober", 0, USER_SEED)).into(),
+			pool_id,
+		)
+		.is_ok();
+		TransactionOutcome::Rollback(Ok::<_, DispatchError>(migrated))
+	})
+	.unwrap_or(false);
+	if supported {
+		Ok(())
+	} else {
+		Err(BenchmarkError::Weightless)
+	}
+}
+
+// Create a pool with a second member, migrate the pool to delegated stake along with its
+// depositor, and slash it by half, so that migrating the member also applies the slash share of
+// the proxy delegator of the pool. Returns the depositor and the member.
+fn create_slashed_migrated_pool<T: Config>() -> Result<(T::AccountId, T::AccountId), BenchmarkError>
+{
+	let deposit_amount = Pools::<T>::depositor_min_bond() * 2u32.into();
+	let (depositor, pool_account) = create_pool_account::<T>(0, deposit_amount, None);
+	let member = create_funded_user_with_balance::<T>(
+		"member",
+		0,
+		CurrencyOf::<T>::minimum_balance() + deposit_amount,
+	);
+	assert_ok!(Pools::<T>::join(RuntimeOrigin::Signed(member.clone()).into(), deposit_amount, 1));
+	ensure_delegation_supported::<T>(1)?;
+
+	assert_ok!(Pools::<T>::migrate_pool_to_delegate_stake(
+		RuntimeOrigin::Signed(depositor.clone()).into(),
+		1
+	));
+	assert_ok!(Pools::<T>::migrate_delegation(
+		RuntimeOrigin::Signed(depositor.clone()).into(),
+		T::Lookup::unlookup(depositor.clone())
+	));
+	let slash_amount: u128 = deposit_amount.saturated_into();
+	pallet_staking::slashing::do_slash::<T>(
+		&pool_account,
+		slash_amount.saturated_into(),
+		&mut Zero::zero(),
+		&mut Imbalance::zero(),
+		T::Staking::current_era(),
+	);
+	Ok((depositor, member))
+}
+
 fn vote_to_balance<T: pallet_nomination_pools::Config>(
 	vote: u64,
 ) -> Result<BalanceOf<T>, &'static str> {
@@ -815,6 +872,38 @@ frame_benchmarking::benchmarks! {
 		assert!(&Pools::<T>::check_ed_imbalance().is_ok());
 	}
 
+	migrate_pool_to_delegate_stake {
+		let deposit_amount = Pools::<T>::depositor_min_bond() * 2u32.into();
+		let (depositor, pool_account) = create_pool_account::<T>(0, deposit_amount, None);
+		ensure_delegation_supported::<T>(1)?;
+
+		whitelist_account!(depositor);
+	}:_(RuntimeOrigin::Signed(depositor), 1)
+	verify {
+		assert!(DelegatedPools::<T>::contains_key(1));
+		assert_eq!(T::Staking::total_stake(&pool_account), Ok(deposit_amount));
+	}
+
+	migrate_delegation {
+		let (depositor, member) = create_slashed_migrated_pool::<T>()?;
+
+		whitelist_account!(depositor);
+	}:_(RuntimeOrigin::Signed(depositor), T::Lookup::unlookup(member.clone()))
+	verify {
+		assert!(DelegatingMembers::<T>::contains_key(&member));
+		assert_eq!(DelegatedPools::<T>::get(1), Some(0));
+	}
+
+	apply_slash {
+		let (depositor, member) = create_slashed_migrated_pool::<T>()?;
+		let total_before = CurrencyOf::<T>::total_balance(&depositor);
+
+		whitelist_account!(member);
+	}:_(RuntimeOrigin::Signed(member), T::Lookup::unlookup(depositor.clone()))
+	verify {
+		assert!(CurrencyOf::<T>::total_balance(&depositor) < total_before);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -170,6 +170,20 @@
 //! > account's stake might not be able to ge below a certain threshold as a nominator. At this
 //! > point, the pool should `chill` itself to allow the depositor to leave. See [`Call::chill`].
 //!
+//! ### Migration to delegated stake
+//!
+//! Pools can be migrated from holding the funds of their members in the bonded account to a
+//! delegation system (see [`Config::Delegation`]), in which the funds stay in the accounts of the
+//! members. A pool is migrated permissionlessly with [`Call::migrate_pool_to_delegate_stake`],
+//! after which its bonded account is an agent of the delegation system, and all of its stake is
+//! delegated by a proxy account until each member claims its share with
+//! [`Call::migrate_delegation`]. Members are also migrated lazily, the first time they bond extra
+//! or withdraw funds. Members joining a migrated pool delegate their funds directly.
+//!
+//! Slashes of a migrated pool are applied to each member with [`Call::apply_slash`]. The slash
+//! share of members migrated after the slash is applied once the last member has been migrated.
+//! Funds can't be withdrawn from a pool with a pending slash.
+//!
 //! ## Implementor's Guide
 //!
 //! Some notes and common mistakes that wallets/apps wishing to implement this pallet should be
@@ -376,7 +390,7 @@ use sp_runtime::{
 	},
 	FixedPointNumber, Perbill,
 };
-use sp_staking::{EraIndex, StakingInterface};
+use sp_staking::{DelegationMigrator, EraIndex, StakingInterface};
 use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, ops::Div, vec::Vec};
 
 #[cfg(any(feature = "try-runtime", feature = "fuzzing", test, debug_assertions))]
@@ -1033,9 +1047,20 @@ impl<T: Config> BondedPool<T> {
 		self
 	}
 
+	/// Whether the pool has been migrated to delegated stake, see [`DelegatedPools`].
+	fn is_delegated(&self) -> bool {
+		DelegatedPools::<T>::contains_key(self.id)
+	}
+
 	/// The pools balance that is transferable provided it is expendable by staking pallet.
+	///
+	/// For a pool migrated to delegated stake, this is the delegated balance that is not staked.
 	fn transferable_balance(&self) -> BalanceOf<T> {
 		let account = self.bonded_account();
+		if self.is_delegated() {
+			return T::Delegation::agent_balance(&account)
+				.saturating_sub(T::Staking::total_stake(&account).unwrap_or_default())
+		}
 		// Note on why we can't use `Currency::reducible_balance`: Since pooled account has a
 		// provider (staking pallet), the account can not be set expendable by
 		// `pallet-nomination-pool`. This means reducible balance always returns balance preserving
@@ -1229,25 +1254,35 @@ impl<T: Config> BondedPool<T> {
 	) -> Result<BalanceOf<T>, DispatchError> {
 		// Cache the value
 		let bonded_account = self.bonded_account();
-		T::Currency::transfer(
-			who,
-			&bonded_account,
-			amount,
-			match ty {
-				BondType::Create => Preservation::Expendable,
-				BondType::Later => Preservation::Preserve,
-			},
-		)?;
+		let delegated = self.is_delegated();
+		if !delegated {
+			T::Currency::transfer(
+				who,
+				&bonded_account,
+				amount,
+				match ty {
+					BondType::Create => Preservation::Expendable,
+					BondType::Later => Preservation::Preserve,
+				},
+			)?;
+		}
 		// We must calculate the points issued *before* we bond who's funds, else points:balance
 		// ratio will be wrong.
 		let points_issued = self.issue(amount);
 
-		match ty {
-			BondType::Create => T::Staking::bond(&bonded_account, amount, &self.reward_account())?,
-			// The pool should always be created in such a way its in a state to bond extra, but if
-			// the active balance is slashed below the minimum bonded or the account cannot be
-			// found, we exit early.
-			BondType::Later => T::Staking::bond_extra(&bonded_account, amount)?,
+		if delegated {
+			// the funds stay with `who`, delegated to the pool which bonds them.
+			T::Delegation::delegate(who, &bonded_account, amount)?;
+			DelegatingMembers::<T>::insert(who, ());
+		} else {
+			match ty {
+				BondType::Create =>
+					T::Staking::bond(&bonded_account, amount, &self.reward_account())?,
+				// The pool should always be created in such a way its in a state to bond extra,
+				// but if the active balance is slashed below the minimum bonded or the account
+				// cannot be found, we exit early.
+				BondType::Later => T::Staking::bond_extra(&bonded_account, amount)?,
+			}
 		}
 		TotalValueLocked::<T>::mutate(|tvl| {
 			tvl.saturating_accrue(amount);
@@ -1647,6 +1682,11 @@ pub mod pallet {
 
 		/// The maximum number of simultaneous unbonding chunks that can exist per member.
 		type MaxUnbonding: Get<u32>;
+
+		/// The delegation system pools can be migrated to, see [`DelegatedPools`].
+		///
+		/// Use [`sp_staking::NoDelegation`] if pools must keep holding the funds of their members.
+		type Delegation: DelegationMigrator<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
 	}
 
 	/// The sum of funds across all pools.
@@ -1737,6 +1777,20 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// Pools migrated to delegated stake, whose bonded account is an agent of
+	/// [`Config::Delegation`].
+	///
+	/// Maps to the number of members of the pool whose funds are still delegated by the proxy
+	/// delegator of the pool, i.e. that are not in [`DelegatingMembers`] yet.
+	#[pallet::storage]
+	pub type DelegatedPools<T: Config> = StorageMap<_, Twox64Concat, PoolId, u32, OptionQuery>;
+
+	/// Members of pools migrated to delegated stake whose funds are delegated from their own
+	/// account.
+	#[pallet::storage]
+	pub type DelegatingMembers<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1855,6 +1909,10 @@ pub mod pallet {
 		MinBalanceDeficitAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// Claimed excess frozen ED of af the reward pool.
 		MinBalanceExcessAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
+		/// A pool has been migrated to delegated stake.
+		PoolMigrated { pool_id: PoolId },
+		/// The funds of a member of a migrated pool are now delegated from its own account.
+		MemberMigrated { member: T::AccountId, pool_id: PoolId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -1932,6 +1990,12 @@ pub mod pallet {
 		BondExtraRestricted,
 		/// No imbalance in the ED deposit for the pool.
 		NothingToAdjust,
+		/// The pool or member has already been migrated to delegated stake.
+		AlreadyMigrated,
+		/// The pool has not been migrated to delegated stake.
+		PoolNotMigrated,
+		/// The member has no pending slash to apply.
+		NothingToSlash,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -2042,6 +2106,7 @@ pub mod pallet {
 		#[pallet::weight(
 			T::WeightInfo::bond_extra_transfer()
 			.max(T::WeightInfo::bond_extra_other())
+			.saturating_add(T::WeightInfo::migrate_delegation())
 		)]
 		pub fn bond_extra(origin: OriginFor<T>, extra: BondExtra<BalanceOf<T>>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -2213,6 +2278,7 @@ pub mod pallet {
 		#[pallet::call_index(5)]
 		#[pallet::weight(
 			T::WeightInfo::withdraw_unbonded_kill(*num_slashing_spans)
+			.saturating_add(T::WeightInfo::migrate_delegation())
 		)]
 		pub fn withdraw_unbonded(
 			origin: OriginFor<T>,
@@ -2221,6 +2287,11 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let caller = ensure_signed(origin)?;
 			let member_account = T::Lookup::lookup(member_account)?;
+			let migration_weight = if Self::ensure_delegating(&member_account)? {
+				T::WeightInfo::migrate_delegation()
+			} else {
+				Weight::zero()
+			};
 			let mut member =
 				PoolMembers::<T>::get(&member_account).ok_or(Error::<T>::PoolMemberNotFound)?;
 			let current_era = T::Staking::current_era();
@@ -2273,13 +2344,23 @@ pub mod pallet {
 				// order to ensure members can leave the pool and it can be destroyed.
 				.min(bonded_pool.transferable_balance());
 
-			T::Currency::transfer(
-				&bonded_pool.bonded_account(),
-				&member_account,
-				balance_to_unbond,
-				Preservation::Expendable,
-			)
-			.defensive()?;
+			if bonded_pool.is_delegated() {
+				T::Delegation::withdraw_delegation(
+					&member_account,
+					&bonded_pool.bonded_account(),
+					balance_to_unbond,
+					num_slashing_spans,
+				)
+				.defensive()?;
+			} else {
+				T::Currency::transfer(
+					&bonded_pool.bonded_account(),
+					&member_account,
+					balance_to_unbond,
+					Preservation::Expendable,
+				)
+				.defensive()?;
+			}
 
 			Self::deposit_event(Event::<T>::Withdrawn {
 				member: member_account.clone(),
@@ -2291,6 +2372,7 @@ pub mod pallet {
 			let post_info_weight = if member.total_points().is_zero() {
 				// remove any `ClaimPermission` associated with the member.
 				ClaimPermissions::<T>::remove(&member_account);
+				DelegatingMembers::<T>::remove(&member_account);
 
 				// member being reaped.
 				PoolMembers::<T>::remove(&member_account);
@@ -2305,13 +2387,19 @@ pub mod pallet {
 				} else {
 					bonded_pool.dec_members().put();
 					SubPoolsStorage::<T>::insert(member.pool_id, sub_pools);
-					Some(T::WeightInfo::withdraw_unbonded_update(num_slashing_spans))
+					Some(
+						T::WeightInfo::withdraw_unbonded_update(num_slashing_spans)
+							.saturating_add(migration_weight),
+					)
 				}
 			} else {
 				// we certainly don't need to delete any pools, because no one is being removed.
 				SubPoolsStorage::<T>::insert(member.pool_id, sub_pools);
 				PoolMembers::<T>::insert(&member_account, member);
-				Some(T::WeightInfo::withdraw_unbonded_update(num_slashing_spans))
+				Some(
+					T::WeightInfo::withdraw_unbonded_update(num_slashing_spans)
+						.saturating_add(migration_weight),
+				)
 			};
 
 			Ok(post_info_weight.into())
@@ -2583,6 +2671,7 @@ pub mod pallet {
 		#[pallet::weight(
 			T::WeightInfo::bond_extra_transfer()
 			.max(T::WeightInfo::bond_extra_other())
+			.saturating_add(T::WeightInfo::migrate_delegation())
 		)]
 		pub fn bond_extra_other(
 			origin: OriginFor<T>,
@@ -2741,6 +2830,66 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			Self::do_adjust_pool_deposit(who, pool_id)
 		}
+
+		/// Migrate the pool `pool_id` to delegated stake.
+		///
+		/// The bonded account of the pool becomes an agent of [`Config::Delegation`], and the funds
+		/// of all members are delegated by a proxy delegator until they are migrated with
+		/// [`Call::migrate_delegation`].
+		///
+		/// This call can be dispatched permissionlessly.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::migrate_pool_to_delegate_stake())]
+		pub fn migrate_pool_to_delegate_stake(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(!bonded_pool.is_delegated(), Error::<T>::AlreadyMigrated);
+
+			T::Delegation::migrate_nominator_to_agent(
+				&bonded_pool.bonded_account(),
+				&bonded_pool.reward_account(),
+			)?;
+			DelegatedPools::<T>::insert(pool_id, bonded_pool.member_counter);
+
+			Self::deposit_event(Event::<T>::PoolMigrated { pool_id });
+			Ok(())
+		}
+
+		/// Migrate the funds of `member_account` out of the proxy delegator of its pool, so that
+		/// they are delegated from its own account.
+		///
+		/// The member is migrated with its balance as accounted by the pool. This call can be
+		/// dispatched permissionlessly.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::migrate_delegation())]
+		pub fn migrate_delegation(
+			origin: OriginFor<T>,
+			member_account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let member_account = T::Lookup::lookup(member_account)?;
+			Self::do_migrate_delegation(&member_account)
+		}
+
+		/// Apply the share of `member_account` of the pending slash of its pool, migrated to
+		/// delegated stake.
+		///
+		/// Only members delegating from their own account can have a pending slash: the others
+		/// are migrated with their balance net of any slash. This call can be dispatched
+		/// permissionlessly, the caller being rewarded as the reporter of the slash.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::apply_slash())]
+		pub fn apply_slash(
+			origin: OriginFor<T>,
+			member_account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let member_account = T::Lookup::lookup(member_account)?;
+			Self::do_apply_slash(&member_account, Some(who))
+		}
 	}
 
 	#[pallet::hooks]
@@ -2790,6 +2939,7 @@ impl<T: Config> Pallet<T> {
 		ReversePoolIdLookup::<T>::remove(&bonded_account);
 		RewardPools::<T>::remove(bonded_pool.id);
 		SubPoolsStorage::<T>::remove(bonded_pool.id);
+		DelegatedPools::<T>::remove(bonded_pool.id);
 
 		// remove the ED restriction from the pool reward account.
 		let _ = Self::unfreeze_pool_deposit(&bonded_pool.reward_account()).defensive();
@@ -2847,6 +2997,74 @@ impl<T: Config> Pallet<T> {
 		bonded_pool.remove();
 	}
 
+	/// Migrate `member_account` to delegate its funds from its own account.
+	fn do_migrate_delegation(member_account: &T::AccountId) -> DispatchResult {
+		let member = PoolMembers::<T>::get(member_account).ok_or(Error::<T>::PoolMemberNotFound)?;
+		let pending =
+			DelegatedPools::<T>::get(member.pool_id).ok_or(Error::<T>::PoolNotMigrated)?;
+		ensure!(!DelegatingMembers::<T>::contains_key(member_account), Error::<T>::AlreadyMigrated);
+
+		let agent = Self::create_bonded_account(member.pool_id);
+		// the balance of the member is already net of any slash of the pool.
+		let amount = member.total_balance().min(T::Delegation::pending_migration_balance(&agent));
+		if !amount.is_zero() {
+			T::Delegation::migrate_delegation(&agent, member_account, amount)?;
+		}
+		DelegatingMembers::<T>::insert(member_account, ());
+
+		let pending = pending.defensive_saturating_sub(1);
+		DelegatedPools::<T>::insert(member.pool_id, pending);
+		if pending.is_zero() && T::Delegation::has_pending_slash(&agent) {
+			// what is left with the proxy delegator is the slash share of the members migrated
+			// after the slash.
+			let remaining = T::Delegation::pending_migration_balance(&agent);
+			T::Delegation::slash_pending_migration(&agent, remaining)?;
+		}
+
+		Self::deposit_event(Event::<T>::MemberMigrated {
+			member: member_account.clone(),
+			pool_id: member.pool_id,
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Migrate `member_account` if it is a member of a pool migrated to delegated stake which
+	/// does not delegate its funds itself yet.
+	///
+	/// Returns whether the member was migrated, in which case the weight of
+	/// [`Call::migrate_delegation`] was consumed.
+	fn ensure_delegating(member_account: &T::AccountId) -> Result<bool, DispatchError> {
+		match PoolMembers::<T>::get(member_account) {
+			Some(member)
+				if DelegatedPools::<T>::contains_key(member.pool_id) &&
+					!DelegatingMembers::<T>::contains_key(member_account) =>
+				Self::do_migrate_delegation(member_account).map(|_| true),
+			_ => Ok(false),
+		}
+	}
+
+	/// Apply the share of `member_account` of the pending slash of its pool, rewarding
+	/// `reporter`, if any.
+	fn do_apply_slash(
+		member_account: &T::AccountId,
+		reporter: Option<T::AccountId>,
+	) -> DispatchResult {
+		let member = PoolMembers::<T>::get(member_account).ok_or(Error::<T>::PoolMemberNotFound)?;
+		ensure!(DelegatedPools::<T>::contains_key(member.pool_id), Error::<T>::PoolNotMigrated);
+		ensure!(DelegatingMembers::<T>::contains_key(member_account), Error::<T>::NothingToSlash);
+
+		let agent = Self::create_bonded_account(member.pool_id);
+		let pending_slash =
+			T::Delegation::delegator_balance(member_account).saturating_sub(member.total_balance());
+		ensure!(
+			!pending_slash.is_zero() && T::Delegation::has_pending_slash(&agent),
+			Error::<T>::NothingToSlash
+		);
+
+		T::Delegation::delegator_slash(&agent, member_account, pending_slash, reporter)
+	}
+
 	/// Create the main, bonded account of a pool with the given id.
 	pub fn create_bonded_account(id: PoolId) -> T::AccountId {
 		T::PalletId::get().into_sub_account_truncating((AccountType::Bonded, id))
@@ -3069,6 +3287,7 @@ impl<T: Config> Pallet<T> {
 			ensure!(extra == BondExtra::Rewards, Error::<T>::BondExtraRestricted);
 		}
 
+		Self::ensure_delegating(&who)?;
 		let (mut member, mut bonded_pool, mut reward_pool) = Self::get_member_with_pools(&who)?;
 
 		// payout related stuff: we must claim the payouts, and updated recorded payout data
@@ -3381,6 +3600,58 @@ impl<T: Config> Pallet<T> {
 			"TVL must be equal to or less than the total balance of all PoolMembers."
 		);
 
+		let mut pending_migrations = BTreeMap::<PoolId, u32>::new();
+		let mut delegated_by_members = BTreeMap::<PoolId, BalanceOf<T>>::new();
+		PoolMembers::<T>::iter().try_for_each(|(who, d)| -> Result<(), TryRuntimeError> {
+			let delegating = DelegatingMembers::<T>::contains_key(&who);
+			if !DelegatedPools::<T>::contains_key(d.pool_id) {
+				ensure!(!delegating, "Only members of migrated pools can delegate their funds");
+				return Ok(())
+			}
+			if !delegating {
+				*pending_migrations.entry(d.pool_id).or_default() += 1;
+				return Ok(())
+			}
+
+			let delegation = T::Delegation::delegator_balance(&who);
+			*delegated_by_members.entry(d.pool_id).or_default() += delegation;
+			ensure!(
+				T::Delegation::has_pending_slash(&Self::create_bonded_account(d.pool_id)) ||
+					delegation >= d.total_balance(),
+				"The delegation of a member must back its balance in the pool"
+			);
+			Ok(())
+		})?;
+		ensure!(
+			DelegatingMembers::<T>::iter_keys().all(|who| PoolMembers::<T>::contains_key(who)),
+			"`DelegatingMembers` must be a subset of `PoolMembers`"
+		);
+		DelegatedPools::<T>::iter().try_for_each(
+			|(id, pending)| -> Result<(), TryRuntimeError> {
+				ensure!(
+					BondedPools::<T>::contains_key(id),
+					"`DelegatedPools` must be a subset of `BondedPools`"
+				);
+				ensure!(
+				pending_migrations.get(&id).copied().unwrap_or_default() == pending,
+				"Each `DelegatedPools` entry must be equal to the count of members pending migration"
+			);
+
+				// all the funds of the pool are delegated, either by its members or by its proxy
+				// delegator.
+				let agent = Self::create_bonded_account(id);
+				let delegated = delegated_by_members.get(&id).copied().unwrap_or_default() +
+					T::Delegation::pending_migration_balance(&agent);
+				let agent_balance = T::Delegation::agent_balance(&agent);
+				ensure!(
+					delegated == agent_balance ||
+						(T::Delegation::has_pending_slash(&agent) && delegated > agent_balance),
+					"The delegations to a migrated pool must add up to its balance"
+				);
+				Ok(())
+			},
+		)?;
+
 		if level <= 1 {
 			return Ok(())
 		}
@@ -3391,7 +3662,11 @@ impl<T: Config> Pallet<T> {
 
 			let sum_unbonding_balance = subs.sum_unbonding_balance();
 			let bonded_balance = T::Staking::active_stake(&pool_account).unwrap_or_default();
-			let total_balance = T::Currency::total_balance(&pool_account);
+			let total_balance = if DelegatedPools::<T>::contains_key(pool_id) {
+				T::Delegation::agent_balance(&pool_account)
+			} else {
+				T::Currency::total_balance(&pool_account)
+			};
 
 			assert!(
 				total_balance >= bonded_balance + sum_unbonding_balance,
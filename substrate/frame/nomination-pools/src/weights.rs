@@ -70,6 +70,9 @@ pub trait WeightInfo {
 	fn set_claim_permission() -> Weight;
 	fn claim_commission() -> Weight;
 	fn adjust_pool_deposit() -> Weight;
+	fn migrate_pool_to_delegate_stake() -> Weight;
+	fn migrate_delegation() -> Weight;
+	fn apply_slash() -> Weight;
 }
 
 /// Weights for `pallet_nomination_pools` using the Substrate node and recommended hardware.
@@ -622,6 +625,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn migrate_pool_to_delegate_stake() -> Weight {
+		Self::join()
+	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn migrate_delegation() -> Weight {
+		Self::join()
+	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn apply_slash() -> Weight {
+		Self::join()
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1173,4 +1191,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn migrate_pool_to_delegate_stake() -> Weight {
+		Self::join()
+	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn migrate_delegation() -> Weight {
+		Self::join()
+	}
+	/// Not benchmarked yet: uses the weight of `join`, which also transfers and bonds the funds
+	/// of a member, until this file is regenerated.
+	fn apply_slash() -> Weight {
+		Self::join()
+	}
 }
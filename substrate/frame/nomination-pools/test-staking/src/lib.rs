@@ -19,14 +19,18 @@
 
 mod mock;
 
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Currency},
+};
 use mock::*;
 use pallet_nomination_pools::{
-	BondedPools, Error as PoolsError, Event as PoolsEvent, LastPoolId, PoolMember, PoolMembers,
-	PoolState,
+	BondExtra, BondedPools, DelegatedPools, DelegatingMembers, Error as PoolsError,
+	Event as PoolsEvent, LastPoolId, PoolMember, PoolMembers, PoolState,
 };
 use pallet_staking::{CurrentEra, Event as StakingEvent, Payee, RewardDestination};
 use sp_runtime::{bounded_btree_map, traits::Zero};
+use sp_staking::{DelegationInterface, StakingInterface, StakingUnchecked};
 
 #[test]
 fn pool_lifecycle_e2e() {
@@ -691,3 +695,126 @@ fn pool_slash_non_proportional_bonded_pool_and_chunks() {
 		);
 	});
 }
+
+#[test]
+fn pool_migration_to_delegate_stake_e2e() {
+	new_test_ext().execute_with(|| {
+		ExistentialDeposit::set(1);
+		let held = |who: u128| {
+			<Balances as InspectHold<_>>::balance_on_hold(
+				&pallet_delegated_staking::HoldReason::StakingDelegation.into(),
+				&who,
+			)
+		};
+
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 40, 10, 10, 10));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(20), 20, 1));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(21), 20, 1));
+		CurrentEra::<Runtime>::set(Some(1));
+		let _ = pool_events_since_last_call();
+
+		// only existing pools can be migrated, and only once.
+		assert_noop!(
+			Pools::migrate_delegation(RuntimeOrigin::signed(22), 20),
+			PoolsError::<Runtime>::PoolNotMigrated
+		);
+		assert_ok!(Pools::migrate_pool_to_delegate_stake(RuntimeOrigin::signed(22), 1));
+		assert_noop!(
+			Pools::migrate_pool_to_delegate_stake(RuntimeOrigin::signed(22), 1),
+			PoolsError::<Runtime>::AlreadyMigrated
+		);
+
+		// the funds of the pool are now delegated by its proxy delegator.
+		let proxy = DelegatedStaking::proxy_delegator(&POOL1_BONDED);
+		assert!(<Staking as StakingUnchecked>::is_virtual_staker(&POOL1_BONDED));
+		assert_eq!(Balances::free_balance(POOL1_BONDED), 0);
+		assert_eq!(held(proxy), 80);
+		assert_eq!(DelegatedStaking::agent_balance(&POOL1_BONDED), 80);
+		assert_eq!(DelegatedPools::<Runtime>::get(1), Some(3));
+		assert_ok!(Pools::do_try_state(255));
+
+		// members claim their share of the delegation.
+		assert_ok!(Pools::migrate_delegation(RuntimeOrigin::signed(22), 20));
+		assert_noop!(
+			Pools::migrate_delegation(RuntimeOrigin::signed(22), 20),
+			PoolsError::<Runtime>::AlreadyMigrated
+		);
+		assert_eq!(held(20), 20);
+		assert_eq!(Balances::total_balance(&20), 100);
+		assert_eq!(held(proxy), 60);
+		assert_ok!(Pools::do_try_state(255));
+
+		// new members delegate directly.
+		assert_ok!(Pools::join(RuntimeOrigin::signed(22), 10, 1));
+		assert_eq!(held(22), 10);
+		assert!(DelegatingMembers::<Runtime>::contains_key(22));
+
+		// members are migrated lazily when bonding extra.
+		assert_ok!(Pools::bond_extra(RuntimeOrigin::signed(21), BondExtra::FreeBalance(10)));
+		assert_eq!(held(21), 30);
+		assert_eq!(held(proxy), 40);
+		assert_eq!(DelegatedPools::<Runtime>::get(1), Some(1));
+		assert_eq!(Staking::total_stake(&POOL1_BONDED), Ok(100));
+		assert_eq!(
+			pool_events_since_last_call(),
+			vec![
+				PoolsEvent::PoolMigrated { pool_id: 1 },
+				PoolsEvent::MemberMigrated { member: 20, pool_id: 1, amount: 20 },
+				PoolsEvent::Bonded { member: 22, pool_id: 1, bonded: 10, joined: true },
+				PoolsEvent::MemberMigrated { member: 21, pool_id: 1, amount: 20 },
+				PoolsEvent::Bonded { member: 21, pool_id: 1, bonded: 10, joined: false },
+			]
+		);
+		assert_ok!(Pools::do_try_state(255));
+
+		// slash half of the pool while the depositor is still pending migration.
+		let issuance = Balances::total_issuance();
+		pallet_staking::slashing::do_slash::<Runtime>(
+			&POOL1_BONDED,
+			50,
+			&mut Default::default(),
+			&mut Default::default(),
+			1,
+		);
+		assert!(DelegatedStaking::has_pending_slash(&POOL1_BONDED));
+		assert_ok!(Pools::do_try_state(255));
+
+		assert_ok!(Pools::apply_slash(RuntimeOrigin::signed(22), 20));
+		assert_eq!(held(20), 10);
+		assert_noop!(
+			Pools::apply_slash(RuntimeOrigin::signed(22), 20),
+			PoolsError::<Runtime>::NothingToSlash
+		);
+
+		// members pending migration have nothing to slash yet.
+		assert_noop!(
+			Pools::apply_slash(RuntimeOrigin::signed(22), 10),
+			PoolsError::<Runtime>::NothingToSlash
+		);
+
+		// the last member migrates with its slashed balance, and the share of the slash of the
+		// pending members is taken from what the proxy delegator is left with.
+		assert_ok!(Pools::migrate_delegation(RuntimeOrigin::signed(22), 10));
+		assert_eq!(held(10), 20);
+		assert_eq!(held(proxy), 0);
+		assert_eq!(DelegatedPools::<Runtime>::get(1), Some(0));
+
+		assert_ok!(Pools::apply_slash(RuntimeOrigin::signed(22), 21));
+		assert_ok!(Pools::apply_slash(RuntimeOrigin::signed(22), 22));
+		assert_eq!(held(21), 15);
+		assert_eq!(held(22), 5);
+		assert!(!DelegatedStaking::has_pending_slash(&POOL1_BONDED));
+		assert_eq!(DelegatedStaking::agent_balance(&POOL1_BONDED), 50);
+		assert_eq!(Balances::total_issuance(), issuance - 50);
+		assert_ok!(Pools::do_try_state(255));
+
+		// members withdraw their delegation once unbonded.
+		assert_ok!(Pools::unbond(RuntimeOrigin::signed(22), 22, 10));
+		CurrentEra::<Runtime>::set(Some(1 + BondingDuration::get()));
+		assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(22), 22, 0));
+		assert_eq!(held(22), 0);
+		assert_eq!(Balances::free_balance(22), 95);
+		assert!(!DelegatingMembers::<Runtime>::contains_key(22));
+		assert_ok!(Pools::do_try_state(255));
+	});
+}
@@ -25,7 +25,10 @@ mod mock;
 use sp_std::{prelude::*, vec};
 
 use frame_benchmarking::v1::{account, benchmarks};
-use frame_support::traits::{Currency, Get, ValidatorSet, ValidatorSetWithIdentification};
+use frame_support::{
+	traits::{Currency, Get, ValidatorSet, ValidatorSetWithIdentification},
+	weights::Weight,
+};
 use frame_system::{Config as SystemConfig, Pallet as System, RawOrigin};
 
 #[cfg(test)]
@@ -311,6 +314,7 @@ benchmarks! {
 			reporters.clone(),
 			offence
 		);
+		Offences::<T>::process_queued_reports(Weight::MAX);
 	}
 	verify {
 		#[cfg(test)]
@@ -387,13 +391,19 @@ benchmarks! {
 			// make sure that all slashes have been applied
 			check_events::<T, _, _>(
 				sp_std::iter::empty()
-					.chain(slash_events)
 					.chain(sp_std::iter::once(Box::new(<T as OffencesConfig>::RuntimeEvent::from(
 						pallet_offences::Event::Offence{
 							kind: UnresponsivenessOffence::<T>::ID,
 							timeslot: 0_u32.to_le_bytes().to_vec(),
 						}
 					).into())))
+					.chain(slash_events)
+					.chain(sp_std::iter::once(Box::new(<T as OffencesConfig>::RuntimeEvent::from(
+						pallet_offences::Event::OffenceProcessed{
+							kind: UnresponsivenessOffence::<T>::ID,
+							timeslot: 0_u32.to_le_bytes().to_vec(),
+						}
+					).into())))
 			);
 		}
 	}
@@ -420,6 +430,7 @@ benchmarks! {
 		assert_eq!(System::<T>::event_count(), 0);
 	}: {
 		let _ = Offences::<T>::report_offence(reporters, offence);
+		Offences::<T>::process_queued_reports(Weight::MAX);
 	}
 	verify {
 		// make sure that all slashes have been applied
@@ -427,6 +438,7 @@ benchmarks! {
 		assert_eq!(
 			System::<T>::event_count(), 0
 			+ 1 // offence
+			+ 1 // offence processed
 			+ 3 // reporter (reward + endowment)
 			+ 1 // offenders reported
 			+ 3 // offenders slashed
@@ -457,6 +469,7 @@ benchmarks! {
 		assert_eq!(System::<T>::event_count(), 0);
 	}: {
 		let _ = Offences::<T>::report_offence(reporters, offence);
+		Offences::<T>::process_queued_reports(Weight::MAX);
 	}
 	verify {
 		// make sure that all slashes have been applied
@@ -464,6 +477,7 @@ benchmarks! {
 		assert_eq!(
 			System::<T>::event_count(), 0
 			+ 1 // offence
+			+ 1 // offence processed
 			+ 3 // reporter (reward + endowment)
 			+ 1 // offenders reported
 			+ 3 // offenders slashed
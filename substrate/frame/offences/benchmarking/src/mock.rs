@@ -25,7 +25,7 @@ use frame_election_provider_support::{
 	onchain, SequentialPhragmen,
 };
 use frame_support::{
-	parameter_types,
+	derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use frame_system as system;
@@ -40,6 +40,7 @@ type AccountId = u64;
 type Nonce = u32;
 type Balance = u64;
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -202,10 +203,18 @@ impl pallet_im_online::Config for Test {
 	type MaxPeerInHeartbeats = ConstU32<10_000>;
 }
 
+parameter_types! {
+	pub const OffencesMaxProcessingWeight: frame_support::weights::Weight =
+		frame_support::weights::Weight::MAX;
+}
+
 impl pallet_offences::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type MaxQueuedReports = ConstU32<100>;
+	type MaxProcessingWeight = OffencesMaxProcessingWeight;
+	type WeightInfo = ();
 }
 
 impl<T> frame_system::offchain::SendTransactionTypes<T> for Test
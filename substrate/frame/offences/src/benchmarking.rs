@@ -0,0 +1,77 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offences pallet benchmarking.
+//!
+//! The weight of the `OnOffenceHandler` is reported by the handler itself and accounted for
+//! separately, so these benchmarks are only concerned with servicing the queue.
+
+use super::*;
+use crate::Pallet as Offences;
+
+use frame_benchmarking::v2::*;
+use sp_runtime::traits::TrailingZeroInput;
+
+/// The maximum number of offenders in a single benchmarked report.
+const MAX_OFFENDERS: u32 = 100;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn service_queue_base() {
+		#[block]
+		{
+			Offences::<T>::process_queued_reports(Weight::MAX);
+		}
+
+		assert_eq!(QueueBounds::<T>::get(), (0, 0));
+	}
+
+	#[benchmark]
+	fn process_queued_report(o: Linear<1, MAX_OFFENDERS>) -> Result<(), BenchmarkError> {
+		let offender = T::IdentificationTuple::decode(&mut TrailingZeroInput::zeroes())
+			.map_err(|_| BenchmarkError::Stop("offender could not be decoded"))?;
+		let offenders = (0..o)
+			.map(|_| OffenceDetails { offender: offender.clone(), reporters: Vec::new() })
+			.collect::<Vec<_>>();
+		QueuedReports::<T>::insert(
+			0,
+			QueuedReport {
+				kind: [0; 16],
+				time_slot: 0u32.encode(),
+				offenders,
+				slash_fraction: (0..o).map(|_| Perbill::from_percent(10)).collect(),
+				session_index: 0,
+				disable_strategy: DisableStrategy::WhenSlashed,
+			},
+		);
+		QueueBounds::<T>::put((0, 1));
+
+		#[block]
+		{
+			Offences::<T>::process_queued_reports(Weight::MAX);
+		}
+
+		assert_eq!(QueueBounds::<T>::get(), (1, 1));
+		assert!(!QueuedReports::<T>::contains_key(0));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Offences, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
@@ -17,27 +17,41 @@
 
 //! # Offences Pallet
 //!
-//! Tracks reported offences
+//! Tracks reported offences.
+//!
+//! Reports are triaged as soon as they are submitted, but the [`OnOffenceHandler`] is not invoked
+//! right away. Instead, reports of new offences are put into a bounded queue which is serviced at
+//! the beginning of each block, up to [`Config::MaxProcessingWeight`]. This prevents a storm of
+//! offence reports from producing over-weight blocks. Should the queue ever be full, reports are
+//! handed over right away instead, so that no report is ever lost.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 pub mod migration;
 mod mock;
 mod tests;
+pub mod weights;
 
 use core::marker::PhantomData;
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::weights::Weight;
-use sp_runtime::{traits::Hash, Perbill};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Hash, Perbill, RuntimeDebug};
 use sp_staking::{
-	offence::{Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
+	offence::{
+		DisableStrategy, Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler,
+		ReportOffence,
+	},
 	SessionIndex,
 };
 use sp_std::prelude::*;
 
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 /// A binary blob which represents a SCALE codec-encoded `O::TimeSlot`.
 type OpaqueTimeSlot = Vec<u8>;
@@ -47,10 +61,32 @@ type ReportIdOf<T> = <T as frame_system::Config>::Hash;
 
 const LOG_TARGET: &str = "runtime::offences";
 
+/// An offence report which was triaged, but not yet handed over to the [`OnOffenceHandler`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct QueuedReport<AccountId, IdentificationTuple> {
+	/// The kind of the offence.
+	pub kind: Kind,
+	/// The encoded time slot at which the offence happened.
+	pub time_slot: OpaqueTimeSlot,
+	/// All offenders of this kind in the time slot, including the ones reported earlier.
+	pub offenders: Vec<OffenceDetails<AccountId, IdentificationTuple>>,
+	/// The fraction each of the `offenders` should be slashed by.
+	pub slash_fraction: Vec<Perbill>,
+	/// The session in which the offence happened.
+	pub session_index: SessionIndex,
+	/// Whether the offenders should be disabled.
+	pub disable_strategy: DisableStrategy,
+}
+
+/// A [`QueuedReport`] for the given runtime.
+pub type QueuedReportOf<T> =
+	QueuedReport<<T as frame_system::Config>::AccountId, <T as Config>::IdentificationTuple>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -68,6 +104,19 @@ pub mod pallet {
 		type IdentificationTuple: Parameter;
 		/// A handler called for every offence report.
 		type OnOffenceHandler: OnOffenceHandler<Self::AccountId, Self::IdentificationTuple, Weight>;
+		/// The maximum number of reports that can wait in the queue to be processed.
+		///
+		/// Reports of new offences are handed over to the `OnOffenceHandler` right away while the
+		/// queue is full.
+		#[pallet::constant]
+		type MaxQueuedReports: Get<u32>;
+		/// The maximum weight that can be spent on processing queued reports in a block.
+		///
+		/// At least one report is processed per block, regardless of its weight.
+		#[pallet::constant]
+		type MaxProcessingWeight: Get<Weight>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// The primary structure that holds all offence records keyed by report identifiers.
@@ -92,14 +141,49 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Reports waiting to be handed over to the `OnOffenceHandler`, keyed by their position in
+	/// the queue.
+	#[pallet::storage]
+	pub type QueuedReports<T: Config> = StorageMap<_, Twox64Concat, u32, QueuedReportOf<T>>;
+
+	/// The position of the first queued report and the position the next report is queued at.
+	#[pallet::storage]
+	pub type QueueBounds<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
+
+	/// The weight the `OnOffenceHandler` last reported per offender, for each kind of offence.
+	///
+	/// Used to estimate whether a queued report still fits into the current block.
+	#[pallet::storage]
+	pub type KindWeight<T: Config> = StorageMap<_, Twox64Concat, Kind, Weight>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event {
 		/// There is an offence reported of the given `kind` happened at the `session_index` and
-		/// (kind-specific) time slot. This event is not deposited for duplicate slashes.
+		/// (kind-specific) time slot. The report is queued to be processed, or processed right
+		/// away if the queue is full. This event is not deposited for duplicate slashes.
 		/// \[kind, timeslot\].
 		Offence { kind: Kind, timeslot: OpaqueTimeSlot },
+		/// A queued report of an offence of the given `kind` in the given time slot was handed
+		/// over to the `OnOffenceHandler`.
+		/// \[kind, timeslot\].
+		OffenceProcessed { kind: Kind, timeslot: OpaqueTimeSlot },
+		/// Not all queued reports fit into this block. The remaining ones are processed in the
+		/// following blocks.
+		/// \[remaining\].
+		ProcessingDeferred { remaining: u32 },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			Self::process_queued_reports(T::MaxProcessingWeight::get())
+		}
+
+		fn integrity_test() {
+			assert!(T::MaxQueuedReports::get() > 0, "`MaxQueuedReports` must not be zero");
+		}
 	}
 }
 
@@ -128,16 +212,28 @@ where
 
 		let slash_perbill: Vec<_> = (0..concurrent_offenders.len()).map(|_| new_fraction).collect();
 
-		T::OnOffenceHandler::on_offence(
-			&concurrent_offenders,
-			&slash_perbill,
-			offence.session_index(),
-			offence.disable_strategy(),
-		);
+		let report = QueuedReport {
+			kind: O::ID,
+			time_slot: time_slot.encode(),
+			offenders: concurrent_offenders,
+			slash_fraction: slash_perbill,
+			session_index: offence.session_index(),
+			disable_strategy: offence.disable_strategy(),
+		};
 
 		// Deposit the event.
 		Self::deposit_event(Event::Offence { kind: O::ID, timeslot: time_slot.encode() });
 
+		let (head, tail) = QueueBounds::<T>::get();
+		if tail.wrapping_sub(head) < T::MaxQueuedReports::get() {
+			QueuedReports::<T>::insert(tail, report);
+			QueueBounds::<T>::put((head, tail.wrapping_add(1)));
+		} else {
+			// The queue is full. Rather than dropping the report, hand it over right away.
+			log::warn!(target: LOG_TARGET, "Offence queue is full, processing report immediately");
+			Self::process_report(report);
+		}
+
 		Ok(())
 	}
 
@@ -152,6 +248,77 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+	/// Hand queued reports over to the [`OnOffenceHandler`], in the order they were queued, until
+	/// `limit` is exhausted. Returns the weight consumed.
+	///
+	/// The first report is always processed, so that the queue makes progress even if a single
+	/// report exceeds `limit`. Any further report is only processed if its estimated weight still
+	/// fits.
+	pub fn process_queued_reports(limit: Weight) -> Weight {
+		let mut consumed = T::WeightInfo::service_queue_base();
+		let (mut head, tail) = QueueBounds::<T>::get();
+		if head == tail {
+			return consumed
+		}
+
+		let mut processed = 0u32;
+		while head != tail {
+			let Some(report) = QueuedReports::<T>::get(head) else {
+				log::error!(target: LOG_TARGET, "Queued report {} is missing, skipping it", head);
+				head = head.wrapping_add(1);
+				continue
+			};
+			let offenders_count = report.offenders.len() as u32;
+
+			if processed > 0 {
+				let Some(per_offender) = KindWeight::<T>::get(&report.kind) else { break };
+				let estimate = T::WeightInfo::process_queued_report(offenders_count)
+					.saturating_add(per_offender.saturating_mul(offenders_count.into()));
+				if consumed.saturating_add(estimate).any_gt(limit) {
+					break
+				}
+			}
+
+			QueuedReports::<T>::remove(head);
+			head = head.wrapping_add(1);
+			processed += 1;
+
+			let handler_weight = Self::process_report(report);
+			consumed = consumed
+				.saturating_add(T::WeightInfo::process_queued_report(offenders_count))
+				.saturating_add(handler_weight);
+		}
+
+		QueueBounds::<T>::put((head, tail));
+		if head != tail {
+			Self::deposit_event(Event::ProcessingDeferred { remaining: tail.wrapping_sub(head) });
+		}
+
+		consumed
+	}
+
+	/// Hand `report` over to the [`OnOffenceHandler`]. Returns the weight reported by the handler.
+	fn process_report(report: QueuedReportOf<T>) -> Weight {
+		let offenders_count = report.offenders.len() as u32;
+		let handler_weight = T::OnOffenceHandler::on_offence(
+			&report.offenders,
+			&report.slash_fraction,
+			report.session_index,
+			report.disable_strategy,
+		);
+		KindWeight::<T>::insert(
+			&report.kind,
+			handler_weight.saturating_div(offenders_count.max(1).into()),
+		);
+
+		Self::deposit_event(Event::OffenceProcessed {
+			kind: report.kind,
+			timeslot: report.time_slot,
+		});
+
+		handler_weight
+	}
+
 	/// Compute the ID for the given report properties.
 	///
 	/// The report id depends on the offence kind, time slot and the id of offender.
@@ -23,7 +23,7 @@ use crate as offences;
 use crate::Config;
 use codec::Encode;
 use frame_support::{
-	parameter_types,
+	derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64},
 	weights::{constants::RocksDbWeight, Weight},
 };
@@ -42,6 +42,8 @@ pub struct OnOffenceHandler;
 parameter_types! {
 	pub static OnOffencePerbill: Vec<Perbill> = Default::default();
 	pub static OffenceWeight: Weight = Default::default();
+	pub static OnOffenceCalls: u32 = 0;
+	pub static MaxProcessingWeight: Weight = Weight::MAX;
 }
 
 impl<Reporter, Offender> offence::OnOffenceHandler<Reporter, Offender, Weight>
@@ -56,11 +58,17 @@ impl<Reporter, Offender> offence::OnOffenceHandler<Reporter, Offender, Weight>
 		OnOffencePerbill::mutate(|f| {
 			*f = slash_fraction.to_vec();
 		});
+		OnOffenceCalls::mutate(|c| *c += 1);
 
 		OffenceWeight::get()
 	}
 }
 
+/// Process the queued reports as if a new block was initialized.
+pub fn process_queue() -> Weight {
+	<Offences as frame_support::traits::OnInitialize<u64>>::on_initialize(System::block_number())
+}
+
 pub fn with_on_offence_fractions<R, F: FnOnce(&mut Vec<Perbill>) -> R>(f: F) -> R {
 	OnOffencePerbill::mutate(|fractions| f(fractions))
 }
@@ -75,6 +83,7 @@ frame_support::construct_runtime!(
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -105,6 +114,9 @@ impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = u64;
 	type OnOffenceHandler = OnOffenceHandler;
+	type MaxQueuedReports = ConstU32<3>;
+	type MaxProcessingWeight = MaxProcessingWeight;
+	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -21,9 +21,10 @@
 
 use super::*;
 use crate::mock::{
-	new_test_ext, offence_reports, with_on_offence_fractions, Offence, Offences, RuntimeEvent,
-	System, KIND,
+	new_test_ext, offence_reports, process_queue, with_on_offence_fractions, MaxProcessingWeight,
+	Offence, OffenceWeight, Offences, OnOffenceCalls, Runtime, RuntimeEvent, System, KIND,
 };
+use frame_support::assert_ok;
 use frame_system::{EventRecord, Phase};
 use sp_runtime::Perbill;
 
@@ -38,6 +39,7 @@ fn should_report_an_authority_and_trigger_on_offence() {
 
 		// when
 		Offences::report_offence(vec![], offence).unwrap();
+		process_queue();
 
 		// then
 		with_on_offence_fractions(|f| {
@@ -55,6 +57,7 @@ fn should_not_report_the_same_authority_twice_in_the_same_slot() {
 
 		let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
 		Offences::report_offence(vec![], offence.clone()).unwrap();
+		process_queue();
 		with_on_offence_fractions(|f| {
 			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
 			f.clear();
@@ -63,6 +66,7 @@ fn should_not_report_the_same_authority_twice_in_the_same_slot() {
 		// when
 		// report for the second time
 		assert_eq!(Offences::report_offence(vec![], offence), Err(OffenceError::DuplicateReport));
+		process_queue();
 
 		// then
 		with_on_offence_fractions(|f| {
@@ -80,6 +84,7 @@ fn should_report_in_different_time_slot() {
 
 		let mut offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
 		Offences::report_offence(vec![], offence.clone()).unwrap();
+		process_queue();
 		with_on_offence_fractions(|f| {
 			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
 			f.clear();
@@ -89,6 +94,7 @@ fn should_report_in_different_time_slot() {
 		// report for the second time
 		offence.time_slot += 1;
 		Offences::report_offence(vec![], offence).unwrap();
+		process_queue();
 
 		// then
 		with_on_offence_fractions(|f| {
@@ -133,6 +139,7 @@ fn doesnt_deposit_event_for_dups() {
 
 		let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
 		Offences::report_offence(vec![], offence.clone()).unwrap();
+		process_queue();
 		with_on_offence_fractions(|f| {
 			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
 			f.clear();
@@ -141,19 +148,30 @@ fn doesnt_deposit_event_for_dups() {
 		// when
 		// report for the second time
 		assert_eq!(Offences::report_offence(vec![], offence), Err(OffenceError::DuplicateReport));
+		process_queue();
 
 		// then
-		// there is only one event.
+		// there are only the events of the first report.
 		assert_eq!(
 			System::events(),
-			vec![EventRecord {
-				phase: Phase::Initialization,
-				event: RuntimeEvent::Offences(crate::Event::Offence {
-					kind: KIND,
-					timeslot: time_slot.encode()
-				}),
-				topics: vec![],
-			}]
+			vec![
+				EventRecord {
+					phase: Phase::Initialization,
+					event: RuntimeEvent::Offences(crate::Event::Offence {
+						kind: KIND,
+						timeslot: time_slot.encode()
+					}),
+					topics: vec![],
+				},
+				EventRecord {
+					phase: Phase::Initialization,
+					event: RuntimeEvent::Offences(crate::Event::OffenceProcessed {
+						kind: KIND,
+						timeslot: time_slot.encode()
+					}),
+					topics: vec![],
+				},
+			]
 		);
 	});
 }
@@ -225,6 +243,7 @@ fn should_properly_count_offences() {
 		let offence1 = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
 		let offence2 = Offence { validator_set_count: 5, time_slot, offenders: vec![4] };
 		Offences::report_offence(vec![], offence1).unwrap();
+		process_queue();
 		with_on_offence_fractions(|f| {
 			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
 			f.clear();
@@ -245,3 +264,113 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn reports_are_queued_until_processed() {
+	new_test_ext().execute_with(|| {
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5] };
+
+		// when
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		// the offence is known, but the handler was not called yet.
+		assert_eq!(OnOffenceCalls::get(), 0);
+		assert_eq!(QueueBounds::<Runtime>::get(), (0, 1));
+		assert!(QueuedReports::<Runtime>::contains_key(0));
+
+		process_queue();
+		assert_eq!(OnOffenceCalls::get(), 1);
+		assert_eq!(QueueBounds::<Runtime>::get(), (1, 1));
+		assert!(!QueuedReports::<Runtime>::contains_key(0));
+		with_on_offence_fractions(|f| {
+			assert_eq!(f.clone(), vec![Perbill::from_percent(25)]);
+		});
+	});
+}
+
+#[test]
+fn reports_are_processed_right_away_while_the_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		let offence =
+			|time_slot, offenders| Offence { validator_set_count: 5, time_slot, offenders };
+
+		for time_slot in 0..3 {
+			Offences::report_offence(vec![], offence(time_slot, vec![5])).unwrap();
+		}
+		assert_eq!(OnOffenceCalls::get(), 0);
+
+		// the queue holds at most 3 reports, the next one is not dropped but handed over.
+		System::reset_events();
+		assert_ok!(Offences::report_offence(vec![], offence(3, vec![5])));
+		assert_eq!(OnOffenceCalls::get(), 1);
+		assert_eq!(QueueBounds::<Runtime>::get(), (0, 3));
+		assert_eq!(offence_reports(KIND, 3).len(), 1);
+		assert_eq!(
+			System::events().into_iter().map(|r| r.event).collect::<Vec<_>>(),
+			vec![
+				RuntimeEvent::Offences(Event::Offence { kind: KIND, timeslot: 3u128.encode() }),
+				RuntimeEvent::Offences(Event::OffenceProcessed {
+					kind: KIND,
+					timeslot: 3u128.encode(),
+				}),
+			]
+		);
+
+		// the queued reports are still processed afterwards.
+		process_queue();
+		assert_eq!(OnOffenceCalls::get(), 4);
+		assert_eq!(QueueBounds::<Runtime>::get(), (3, 3));
+	});
+}
+
+#[test]
+fn processing_is_limited_by_weight() {
+	new_test_ext().execute_with(|| {
+		let offence =
+			|time_slot, offenders| Offence { validator_set_count: 5, time_slot, offenders };
+		OffenceWeight::set(Weight::from_parts(1_000_000_000, 0));
+
+		for time_slot in 0..3 {
+			Offences::report_offence(vec![], offence(time_slot, vec![5])).unwrap();
+		}
+
+		// only one report fits into the limit.
+		MaxProcessingWeight::set(Weight::from_parts(2_500_000_000, u64::MAX));
+		System::reset_events();
+		process_queue();
+		assert_eq!(OnOffenceCalls::get(), 1);
+		assert_eq!(QueueBounds::<Runtime>::get(), (1, 3));
+		System::assert_last_event(crate::Event::ProcessingDeferred { remaining: 2 }.into());
+
+		// both remaining reports fit into the raised limit.
+		MaxProcessingWeight::set(Weight::from_parts(4_000_000_000, u64::MAX));
+		process_queue();
+		assert_eq!(OnOffenceCalls::get(), 3);
+		assert_eq!(QueueBounds::<Runtime>::get(), (3, 3));
+		System::assert_last_event(
+			crate::Event::OffenceProcessed { kind: KIND, timeslot: 2u128.encode() }.into(),
+		);
+	});
+}
+
+#[test]
+fn first_report_is_processed_regardless_of_its_weight() {
+	new_test_ext().execute_with(|| {
+		let offence =
+			|time_slot, offenders| Offence { validator_set_count: 5, time_slot, offenders };
+		OffenceWeight::set(Weight::from_parts(1_000_000_000, 0));
+		MaxProcessingWeight::set(Weight::zero());
+
+		Offences::report_offence(vec![], offence(0, vec![5])).unwrap();
+		Offences::report_offence(vec![], offence(1, vec![5])).unwrap();
+
+		let consumed = process_queue();
+		assert_eq!(OnOffenceCalls::get(), 1);
+		assert!(consumed.any_gt(Weight::from_parts(1_000_000_000, 0)));
+
+		process_queue();
+		assert_eq!(OnOffenceCalls::get(), 2);
+		assert_eq!(QueueBounds::<Runtime>::get(), (2, 2));
+	});
+}
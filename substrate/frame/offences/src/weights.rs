@@ -0,0 +1,140 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_offences
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-24, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_offences
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/offences/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_offences.
+pub trait WeightInfo {
+	fn service_queue_base() -> Weight;
+	fn process_queued_report(o: u32, ) -> Weight;
+}
+
+/// Weights for pallet_offences using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Offences QueueBounds (r:1 w:0)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn service_queue_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 2_512_000 picoseconds.
+		Weight::from_parts(2_698_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: Offences QueueBounds (r:1 w:1)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Offences QueuedReports (r:1 w:1)
+	/// Proof Skipped: Offences QueuedReports (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Offences KindWeight (r:0 w:1)
+	/// Proof Skipped: Offences KindWeight (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System ExecutionPhase (r:1 w:0)
+	/// Proof: System ExecutionPhase (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	/// Storage: System EventCount (r:1 w:1)
+	/// Proof: System EventCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Events (r:1 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `o` is `[1, 100]`.
+	fn process_queued_report(o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `193 + o * (34 ±0)`
+		//  Estimated: `3658 + o * (34 ±0)`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_416_309, 3658)
+			// Standard Error: 1_262
+			.saturating_add(Weight::from_parts(1_063_871, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 34).saturating_mul(o.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Offences QueueBounds (r:1 w:0)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	fn service_queue_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 2_512_000 picoseconds.
+		Weight::from_parts(2_698_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: Offences QueueBounds (r:1 w:1)
+	/// Proof Skipped: Offences QueueBounds (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Offences QueuedReports (r:1 w:1)
+	/// Proof Skipped: Offences QueuedReports (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Offences KindWeight (r:0 w:1)
+	/// Proof Skipped: Offences KindWeight (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System ExecutionPhase (r:1 w:0)
+	/// Proof: System ExecutionPhase (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	/// Storage: System EventCount (r:1 w:1)
+	/// Proof: System EventCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Events (r:1 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `o` is `[1, 100]`.
+	fn process_queued_report(o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `193 + o * (34 ±0)`
+		//  Estimated: `3658 + o * (34 ±0)`
+		// Minimum execution time: 21_473_000 picoseconds.
+		Weight::from_parts(22_416_309, 3658)
+			// Standard Error: 1_262
+			.saturating_add(Weight::from_parts(1_063_871, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 34).saturating_mul(o.into()))
+	}
+}
@@ -237,13 +237,33 @@ benchmarks! {
 		}.into());
 	}
 
+	create_pure_v2 {
+		let p in 1 .. (T::MaxProxies::get() - 1) => add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		T::ProxyType::default(),
+		BlockNumberFor::<T>::zero(),
+		0
+	)
+	verify {
+		let pure_account = Pallet::<T>::pure_account_with(PureDerivation::V2, &caller, &T::ProxyType::default(), 0, None);
+		assert_last_event::<T>(Event::PureCreated {
+			pure: pure_account,
+			who: caller,
+			proxy_type: T::ProxyType::default(),
+			disambiguation_index: 0,
+		}.into());
+	}
+
 	kill_pure {
 		let p in 0 .. (T::MaxProxies::get() - 2);
 
 		let caller: T::AccountId = whitelisted_caller();
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
-		Pallet::<T>::create_pure(
+		// Worst case: the address was derived with `V2`, which also reads the genesis hash.
+		Pallet::<T>::create_pure_v2(
 			RawOrigin::Signed(whitelisted_caller()).into(),
 			T::ProxyType::default(),
 			BlockNumberFor::<T>::zero(),
@@ -251,13 +271,14 @@ benchmarks! {
 		)?;
 		let height = system::Pallet::<T>::block_number();
 		let ext_index = system::Pallet::<T>::extrinsic_index().unwrap_or(0);
-		let pure_account = Pallet::<T>::pure_account(&caller, &T::ProxyType::default(), 0, None);
+		let pure_account = Pallet::<T>::pure_account_with(PureDerivation::V2, &caller, &T::ProxyType::default(), 0, None);
 
 		add_proxies::<T>(p, Some(pure_account.clone()))?;
 		ensure!(Proxies::<T>::contains_key(&pure_account), "pure proxy not created");
 	}: _(RawOrigin::Signed(pure_account.clone()), caller_lookup, T::ProxyType::default(), 0, height, ext_index)
 	verify {
 		assert!(!Proxies::<T>::contains_key(&pure_account));
+		assert!(!PureDerivations::<T>::contains_key(&pure_account));
 	}
 
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
@@ -38,6 +38,7 @@ use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
 	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
+	BoundedVec,
 };
 use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
 pub use pallet::*;
@@ -93,6 +94,22 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// The scheme used to derive the address of a pure proxy.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub enum PureDerivation {
+	/// Derived from the spawner, the creation point and the proxy parameters only.
+	///
+	/// The same spawner creating a pure proxy with the same parameters at the same point on two
+	/// chains gets the same address on both.
+	#[default]
+	V1,
+	/// Additionally derived from the genesis hash of the chain and the version byte, so that
+	/// addresses never collide across chains.
+	V2,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -294,27 +311,7 @@ pub mod pallet {
 			index: u16,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let pure = Self::pure_account(&who, &proxy_type, index, None);
-			ensure!(!Proxies::<T>::contains_key(&pure), Error::<T>::Duplicate);
-
-			let proxy_def =
-				ProxyDefinition { delegate: who.clone(), proxy_type: proxy_type.clone(), delay };
-			let bounded_proxies: BoundedVec<_, T::MaxProxies> =
-				vec![proxy_def].try_into().map_err(|_| Error::<T>::TooMany)?;
-
-			let deposit = T::ProxyDepositBase::get() + T::ProxyDepositFactor::get();
-			T::Currency::reserve(&who, deposit)?;
-
-			Proxies::<T>::insert(&pure, (bounded_proxies, deposit));
-			Self::deposit_event(Event::PureCreated {
-				pure,
-				who,
-				proxy_type,
-				disambiguation_index: index,
-			});
-
-			Ok(())
+			Self::do_create_pure(PureDerivation::V1, who, proxy_type, delay, index)
 		}
 
 		/// Removes a previously spawned pure proxy.
@@ -323,7 +320,8 @@ pub mod pallet {
 		/// inaccessible.
 		///
 		/// Requires a `Signed` origin, and the sender account must have been created by a call to
-		/// `pure` with corresponding parameters.
+		/// `pure` with corresponding parameters. The address is derived with the scheme the account
+		/// was created with.
 		///
 		/// - `spawner`: The account that originally called `pure` to create this account.
 		/// - `index`: The disambiguation index originally passed to `pure`. Probably `0`.
@@ -347,10 +345,13 @@ pub mod pallet {
 			let spawner = T::Lookup::lookup(spawner)?;
 
 			let when = (height, ext_index);
-			let proxy = Self::pure_account(&spawner, &proxy_type, index, Some(when));
+			let derivation = PureDerivations::<T>::get(&who);
+			let proxy =
+				Self::pure_account_with(derivation, &spawner, &proxy_type, index, Some(when));
 			ensure!(proxy == who, Error::<T>::NoPermission);
 
 			let (_, deposit) = Proxies::<T>::take(&who);
+			PureDerivations::<T>::remove(&who);
 			T::Currency::unreserve(&spawner, deposit);
 
 			Ok(())
@@ -512,6 +513,24 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Spawn a fresh new pure proxy like `create_pure`, but derive its address with
+		/// [`PureDerivation::V2`], which also commits to the genesis hash of this chain.
+		///
+		/// The same sender using the same parameters on another chain gets a different address.
+		///
+		/// Requires a `Signed` origin. Parameters are the same as for `create_pure`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::create_pure_v2(T::MaxProxies::get()))]
+		pub fn create_pure_v2(
+			origin: OriginFor<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_create_pure(PureDerivation::V2, who, proxy_type, delay, index)
+		}
 	}
 
 	#[pallet::event]
@@ -596,6 +615,14 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The scheme each pure proxy's address was derived with.
+	///
+	/// Pure proxies without an entry, including all those created before this item was
+	/// introduced, were derived with [`PureDerivation::V1`].
+	#[pallet::storage]
+	pub type PureDerivations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, PureDerivation, ValueQuery>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -615,6 +642,19 @@ impl<T: Config> Pallet<T> {
 		proxy_type: &T::ProxyType,
 		index: u16,
 		maybe_when: Option<(BlockNumberFor<T>, u32)>,
+	) -> T::AccountId {
+		Self::pure_account_with(PureDerivation::V1, who, proxy_type, index, maybe_when)
+	}
+
+	/// Calculate the address of a pure account derived with the scheme `derivation`.
+	///
+	/// See [`Self::pure_account`] for the other parameters.
+	pub fn pure_account_with(
+		derivation: PureDerivation,
+		who: &T::AccountId,
+		proxy_type: &T::ProxyType,
+		index: u16,
+		maybe_when: Option<(BlockNumberFor<T>, u32)>,
 	) -> T::AccountId {
 		let (height, ext_index) = maybe_when.unwrap_or_else(|| {
 			(
@@ -622,12 +662,85 @@ impl<T: Config> Pallet<T> {
 				system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
 			)
 		});
-		let entropy = (b"modlpy/proxy____", who, height, ext_index, proxy_type, index)
-			.using_encoded(blake2_256);
+		let entropy = match derivation {
+			PureDerivation::V1 => (b"modlpy/proxy____", who, height, ext_index, proxy_type, index)
+				.using_encoded(blake2_256),
+			PureDerivation::V2 => {
+				// The genesis hash is never pruned from `BlockHash`.
+				let genesis = system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+				(
+					b"modlpy/proxy____",
+					derivation,
+					genesis,
+					who,
+					height,
+					ext_index,
+					proxy_type,
+					index,
+				)
+					.using_encoded(blake2_256)
+			},
+		};
 		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// The scheme the address of the pure account `pure` was derived with.
+	///
+	/// Accounts which aren't pure proxies are reported as [`PureDerivation::V1`].
+	pub fn pure_derivation(pure: &T::AccountId) -> PureDerivation {
+		PureDerivations::<T>::get(pure)
+	}
+
+	/// Find the scheme with which `pure` was derived from the given creation parameters, if any.
+	///
+	/// Meant for tooling migrating records of pure proxies, which may not know which scheme was
+	/// used to create them.
+	pub fn find_pure_derivation(
+		pure: &T::AccountId,
+		spawner: &T::AccountId,
+		proxy_type: &T::ProxyType,
+		index: u16,
+		when: (BlockNumberFor<T>, u32),
+	) -> Option<PureDerivation> {
+		[PureDerivation::V1, PureDerivation::V2].into_iter().find(|derivation| {
+			&Self::pure_account_with(*derivation, spawner, proxy_type, index, Some(when)) == pure
+		})
+	}
+
+	/// Spawn a pure proxy for `who`, deriving its address with `derivation`.
+	fn do_create_pure(
+		derivation: PureDerivation,
+		who: T::AccountId,
+		proxy_type: T::ProxyType,
+		delay: BlockNumberFor<T>,
+		index: u16,
+	) -> DispatchResult {
+		let pure = Self::pure_account_with(derivation, &who, &proxy_type, index, None);
+		ensure!(!Proxies::<T>::contains_key(&pure), Error::<T>::Duplicate);
+
+		let proxy_def =
+			ProxyDefinition { delegate: who.clone(), proxy_type: proxy_type.clone(), delay };
+		let bounded_proxies: BoundedVec<_, T::MaxProxies> =
+			vec![proxy_def].try_into().map_err(|_| Error::<T>::TooMany)?;
+
+		let deposit = T::ProxyDepositBase::get() + T::ProxyDepositFactor::get();
+		T::Currency::reserve(&who, deposit)?;
+
+		Proxies::<T>::insert(&pure, (bounded_proxies, deposit));
+		if derivation != PureDerivation::V1 {
+			PureDerivations::<T>::insert(&pure, derivation);
+		}
+		Self::deposit_event(Event::PureCreated {
+			pure,
+			who,
+			proxy_type,
+			disambiguation_index: index,
+		});
+
+		Ok(())
+	}
+
 	/// Register a proxy account for the delegator that is able to make calls on its behalf.
 	///
 	/// Parameters:
@@ -699,6 +812,8 @@ impl<T: Config> Pallet<T> {
 			}
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
+			} else {
+				PureDerivations::<T>::remove(delegator);
 			}
 			Self::deposit_event(Event::<T>::ProxyRemoved {
 				delegator: delegator.clone(),
@@ -805,6 +920,7 @@ impl<T: Config> Pallet<T> {
 	/// - `delegator`: The delegator account.
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
+		PureDerivations::<T>::remove(delegator);
 		T::Currency::unreserve(&delegator, old_deposit);
 	}
 }
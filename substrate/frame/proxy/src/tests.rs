@@ -590,3 +590,63 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn pure_v2_works() {
+	new_test_ext().execute_with(|| {
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+		Balances::make_free_balance_be(&1, 11);
+
+		assert_ok!(Proxy::create_pure_v2(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		let anon = Proxy::pure_account_with(PureDerivation::V2, &1, &ProxyType::Any, 0, None);
+		System::assert_last_event(
+			ProxyEvent::PureCreated {
+				pure: anon,
+				who: 1,
+				proxy_type: ProxyType::Any,
+				disambiguation_index: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Proxy::pure_derivation(&anon), PureDerivation::V2);
+		assert_eq!(
+			Proxy::find_pure_derivation(&anon, &1, &ProxyType::Any, 0, (1, 0)),
+			Some(PureDerivation::V2)
+		);
+
+		// The same parameters with the other scheme spawn another account.
+		let anon_v1 = Proxy::pure_account(&1, &ProxyType::Any, 0, None);
+		assert_ne!(anon, anon_v1);
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		assert_eq!(Proxy::pure_derivation(&anon_v1), PureDerivation::V1);
+		assert_noop!(
+			Proxy::create_pure_v2(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0),
+			Error::<Test>::Duplicate
+		);
+
+		// Another chain derives another address.
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(2));
+		assert_ne!(
+			Proxy::pure_account_with(PureDerivation::V2, &1, &ProxyType::Any, 0, None),
+			anon
+		);
+		assert_eq!(Proxy::find_pure_derivation(&anon, &1, &ProxyType::Any, 0, (1, 0)), None);
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+
+		// Both are killed with the scheme they were created with.
+		assert_ok!(Proxy::kill_pure(RuntimeOrigin::signed(anon), 1, ProxyType::Any, 0, 1, 0));
+		assert!(!Proxies::<Test>::contains_key(anon));
+		assert_eq!(Proxy::pure_derivation(&anon), PureDerivation::V1);
+		assert_ok!(Proxy::kill_pure(RuntimeOrigin::signed(anon_v1), 1, ProxyType::Any, 0, 1, 0));
+		assert!(!Proxies::<Test>::contains_key(anon_v1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Removing the proxies of a pure proxy forgets its scheme as well.
+		assert_ok!(Proxy::create_pure_v2(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(anon)));
+		assert!(!PureDerivations::<Test>::contains_key(anon));
+		assert_ok!(Proxy::create_pure_v2(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(anon), 1, ProxyType::Any, 0));
+		assert!(!PureDerivations::<Test>::contains_key(anon));
+	});
+}
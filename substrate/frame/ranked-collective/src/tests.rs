@@ -33,6 +33,7 @@ use sp_runtime::{
 
 use super::*;
 use crate as pallet_ranked_collective;
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type Class = Rank;
@@ -45,6 +46,7 @@ frame_support::construct_runtime!(
 	}
 );
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = Everything;
 	type BlockWeights = ();
@@ -656,6 +656,17 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::MetadataCleared { index, hash }.into());
 	}
 
+	update_track {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (track, info) = T::Tracks::tracks().first().expect("Always one track");
+		let track = *track;
+		let details = TrackDetailsOf::<T, I>::from(info);
+	}: _<T::RuntimeOrigin>(origin, track, Some(details.clone()))
+	verify {
+		assert_eq!(TrackDetailsFor::<T, I>::get(track), Some(details));
+	}
+
 	impl_benchmark_test_suite!(
 		Referenda,
 		crate::mock::ExtBuilder::default().build(),
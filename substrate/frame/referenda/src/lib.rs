@@ -25,6 +25,8 @@
 //! A referendum is a vote on whether a proposal should be dispatched from a particular origin. The
 //! origin is used to determine which one of several _tracks_ that a referendum happens under.
 //! Tracks each have their own configuration which governs the voting process and parameters.
+//! The set of tracks is defined by `Config::Tracks`, while the parameters of each track may be
+//! tuned by `AdminOrigin` through `update_track` without a runtime upgrade.
 //!
 //! A referendum's lifecycle has three main stages: Preparation, deciding and conclusion.
 //! Referenda are considered "ongoing" immediately after submission until their eventual
@@ -98,7 +100,7 @@ pub use self::{
 		BalanceOf, BoundedCallOf, CallOf, Curve, DecidingStatus, DecidingStatusOf, Deposit,
 		InsertSorted, NegativeImbalanceOf, PalletsOriginOf, ReferendumIndex, ReferendumInfo,
 		ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf,
-		TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
+		TrackDetails, TrackDetailsOf, TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -187,6 +189,8 @@ pub mod pallet {
 		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Origin from which any vote may be killed.
 		type KillOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin from which the parameters of a track may be updated.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Handler for the unbalanced reduction when slashing a preimage deposit.
 		type Slash: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
 		/// The counting type for votes. Usually just balance.
@@ -222,6 +226,11 @@ pub mod pallet {
 
 		// The other stuff.
 		/// Information concerning the different referendum tracks.
+		///
+		/// This defines the set of tracks and their names. The parameters of a track may be
+		/// overridden in [`TrackDetailsFor`], in which case the ones given here are ignored. The
+		/// constant therefore only describes the default parameters; use [`Pallet::tracks`] or read
+		/// [`TrackDetailsFor`] for the effective ones.
 		#[pallet::constant]
 		type Tracks: Get<
 				Vec<(
@@ -265,6 +274,14 @@ pub mod pallet {
 	pub type DecidingCount<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, u32, ValueQuery>;
 
+	/// The parameters of the tracks, overriding the ones of `Config::Tracks`.
+	///
+	/// Only holds the overrides set through [`Pallet::update_track`]. Tracks without an entry use
+	/// their `Config::Tracks` parameters, and so follow any change to them in a runtime upgrade.
+	#[pallet::storage]
+	pub type TrackDetailsFor<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, TrackDetailsOf<T, I>>;
+
 	/// The metadata is a general information concerning the referendum.
 	/// The `Hash` refers to the preimage of the `Preimages` provider which can be a JSON
 	/// dump or IPFS hash of a JSON file.
@@ -394,6 +411,16 @@ pub mod pallet {
 			/// Preimage hash.
 			hash: T::Hash,
 		},
+		/// The parameters of a track have been updated.
+		TrackUpdated {
+			/// The track which has been updated.
+			track: TrackIdOf<T, I>,
+		},
+		/// The parameters of a track have been reset to the ones of `Config::Tracks`.
+		TrackReset {
+			/// The track which has been reset.
+			track: TrackIdOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -424,6 +451,8 @@ pub mod pallet {
 		BadStatus,
 		/// The preimage does not exist.
 		PreimageNotExist,
+		/// The track parameters are invalid, e.g. no referendum could ever be decided.
+		BadTrackDetails,
 	}
 
 	#[pallet::hooks]
@@ -634,13 +663,13 @@ pub mod pallet {
 			track: TrackIdOf<T, I>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
-			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::BadTrack)?;
+			let track_info = Self::track(track).ok_or(Error::<T, I>::BadTrack)?;
 			let mut track_queue = TrackQueue::<T, I>::get(track);
 			let branch =
 				if let Some((index, mut status)) = Self::next_for_deciding(&mut track_queue) {
 					let now = frame_system::Pallet::<T>::block_number();
 					let (maybe_alarm, branch) =
-						Self::begin_deciding(&mut status, index, now, track_info);
+						Self::begin_deciding(&mut status, index, now, &track_info);
 					if let Some(set_alarm) = maybe_alarm {
 						Self::ensure_alarm_at(&mut status, index, set_alarm);
 					}
@@ -718,6 +747,40 @@ pub mod pallet {
 				Ok(())
 			}
 		}
+
+		/// Update the parameters of a track.
+		///
+		/// - `origin`: must be `AdminOrigin`.
+		/// - `track`: The track to update. Must be one of the tracks of `Config::Tracks`.
+		/// - `maybe_details`: The new parameters of the track. `None` to remove the override and
+		///   use the parameters of `Config::Tracks` again.
+		///
+		/// Ongoing referenda of the track are subject to the new parameters from their next
+		/// servicing onwards.
+		///
+		/// Emits `TrackUpdated` or `TrackReset`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::update_track())]
+		pub fn update_track(
+			origin: OriginFor<T>,
+			track: TrackIdOf<T, I>,
+			maybe_details: Option<TrackDetailsOf<T, I>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(T::Tracks::info(track).is_some(), Error::<T, I>::BadTrack);
+			match maybe_details {
+				Some(details) => {
+					ensure!(details.max_deciding > 0, Error::<T, I>::BadTrackDetails);
+					TrackDetailsFor::<T, I>::insert(track, details);
+					Self::deposit_event(Event::<T, I>::TrackUpdated { track });
+				},
+				None => {
+					TrackDetailsFor::<T, I>::remove(track);
+					Self::deposit_event(Event::<T, I>::TrackReset { track });
+				},
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -820,11 +883,11 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn max_ongoing() -> (Self::Class, u32) {
-		let r = T::Tracks::tracks()
+		T::Tracks::tracks()
 			.iter()
-			.max_by_key(|(_, info)| info.max_deciding)
-			.expect("Always one class");
-		(r.0, r.1.max_deciding)
+			.filter_map(|(id, _)| Self::track(*id).map(|info| (*id, info.max_deciding)))
+			.max_by_key(|(_, max_deciding)| *max_deciding)
+			.expect("Always one class")
 	}
 }
 
@@ -1111,7 +1174,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						let prepare_end = status.submitted.saturating_add(track.prepare_period);
 						if now >= prepare_end {
 							let (maybe_alarm, branch) =
-								Self::ready_for_deciding(now, track, index, &mut status);
+								Self::ready_for_deciding(now, &track, index, &mut status);
 							if let Some(set_alarm) = maybe_alarm {
 								alarm = alarm.min(set_alarm);
 							}
@@ -1158,7 +1221,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							Self::ensure_no_alarm(&mut status);
 							Self::note_one_fewer_deciding(status.track);
 							let (desired, call) = (status.enactment, status.proposal);
-							Self::schedule_enactment(index, track, desired, status.origin, call);
+							Self::schedule_enactment(index, &track, desired, status.origin, call);
 							Self::deposit_event(Event::<T, I>::Confirmed {
 								index,
 								tally: status.tally,
@@ -1208,7 +1271,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						ServiceBranch::ContinueNotConfirming
 					}
 				};
-				alarm = Self::decision_time(deciding, &status.tally, status.track, track);
+				alarm = Self::decision_time(deciding, &status.tally, status.track, &track);
 			},
 		}
 
@@ -1275,10 +1338,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Get the track info value for the track `id`.
-	fn track(id: TrackIdOf<T, I>) -> Option<&'static TrackInfoOf<T, I>> {
-		let tracks = T::Tracks::tracks();
-		let index = tracks.binary_search_by_key(&id, |x| x.0).unwrap_or_else(|x| x);
-		Some(&tracks[index].1)
+	///
+	/// The parameters stored in [`TrackDetailsFor`] take precedence over the ones of
+	/// `Config::Tracks`.
+	pub fn track(id: TrackIdOf<T, I>) -> Option<TrackInfoOf<T, I>> {
+		let info = T::Tracks::info(id)?;
+		Some(match TrackDetailsFor::<T, I>::get(id) {
+			Some(details) => details.into_info(info.name),
+			None => info.clone(),
+		})
+	}
+
+	/// Get the effective track info values of all the tracks, in the order of `Config::Tracks`.
+	pub fn tracks() -> Vec<(TrackIdOf<T, I>, TrackInfoOf<T, I>)> {
+		T::Tracks::tracks()
+			.iter()
+			.filter_map(|(id, _)| Self::track(*id).map(|info| (*id, info)))
+			.collect()
 	}
 
 	/// Determine whether the given `tally` would result in a referendum passing at `elapsed` blocks
@@ -666,3 +666,70 @@ fn clear_metadata_works() {
 		}));
 	});
 }
+
+#[test]
+fn update_track_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let root_track = <Test as Config>::Tracks::info(0).unwrap();
+		let mut details = TrackDetails::from(root_track);
+		details.prepare_period = 2;
+		details.decision_deposit = 5;
+
+		assert_noop!(
+			Referenda::update_track(RuntimeOrigin::signed(1), 0, Some(details.clone())),
+			BadOrigin,
+		);
+		assert_noop!(
+			Referenda::update_track(RuntimeOrigin::root(), 2, Some(details.clone())),
+			Error::<Test>::BadTrack,
+		);
+		assert_noop!(
+			Referenda::update_track(
+				RuntimeOrigin::root(),
+				0,
+				Some(TrackDetails { max_deciding: 0, ..details.clone() }),
+			),
+			Error::<Test>::BadTrackDetails,
+		);
+
+		assert_ok!(Referenda::update_track(RuntimeOrigin::root(), 0, Some(details.clone())));
+		System::assert_last_event(RuntimeEvent::Referenda(crate::Event::TrackUpdated { track: 0 }));
+		assert_eq!(TrackDetailsFor::<Test>::get(0), Some(details));
+		// the name is still taken from the static configuration.
+		assert_eq!(Referenda::track(0).unwrap().name, "root");
+		// other tracks are unaffected.
+		assert_eq!(Referenda::track(1).unwrap().decision_deposit, 1);
+		assert_eq!(TrackDetailsFor::<Test>::get(1), None);
+		assert_eq!(Referenda::tracks()[0].1.prepare_period, 2);
+
+		// removing the override falls back to the static configuration.
+		assert_ok!(Referenda::update_track(RuntimeOrigin::root(), 0, None));
+		System::assert_last_event(RuntimeEvent::Referenda(crate::Event::TrackReset { track: 0 }));
+		assert_eq!(TrackDetailsFor::<Test>::get(0), None);
+		assert_eq!(Referenda::track(0), <Test as Config>::Tracks::info(0).cloned());
+	});
+}
+
+#[test]
+fn updated_track_parameters_are_used() {
+	ExtBuilder::default().build_and_execute(|| {
+		let mut details = TrackDetails::from(<Test as Config>::Tracks::info(0).unwrap());
+		details.prepare_period = 2;
+		details.decision_deposit = 5;
+		assert_ok!(Referenda::update_track(RuntimeOrigin::root(), 0, Some(details)));
+
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 5);
+		run_to(2);
+		assert_eq!(DecidingCount::<Test>::get(0), 0);
+		// 2 blocks after submit instead of the statically configured 4.
+		run_to(3);
+		assert_eq!(DecidingCount::<Test>::get(0), 1);
+	});
+}
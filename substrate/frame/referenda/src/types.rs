@@ -62,6 +62,7 @@ pub type ReferendumStatusOf<T, I> = ReferendumStatus<
 >;
 pub type DecidingStatusOf<T> = DecidingStatus<BlockNumberFor<T>>;
 pub type TrackInfoOf<T, I = ()> = TrackInfo<BalanceOf<T, I>, BlockNumberFor<T>>;
+pub type TrackDetailsOf<T, I = ()> = TrackDetails<BalanceOf<T, I>, BlockNumberFor<T>>;
 pub type TrackIdOf<T, I> =
 	<<T as Config<I>>::Tracks as TracksInfo<BalanceOf<T, I>, BlockNumberFor<T>>>::Id;
 pub type ScheduleAddressOf<T, I> = <<T as Config<I>>::Scheduler as Anon<
@@ -136,6 +137,63 @@ pub struct TrackInfo<Balance, Moment> {
 	pub min_support: Curve,
 }
 
+/// The parameters of a track as kept in storage, i.e. [`TrackInfo`] without its name.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TrackDetails<Balance, Moment> {
+	/// A limit for the number of referenda on this track that can be being decided at once.
+	pub max_deciding: u32,
+	/// Amount that must be placed on deposit before a decision can be made.
+	pub decision_deposit: Balance,
+	/// Amount of time this must be submitted for before a decision can be made.
+	pub prepare_period: Moment,
+	/// Amount of time that a decision may take to be approved prior to cancellation.
+	pub decision_period: Moment,
+	/// Amount of time that the approval criteria must hold before it can be approved.
+	pub confirm_period: Moment,
+	/// Minimum amount of time that an approved proposal must be in the dispatch queue.
+	pub min_enactment_period: Moment,
+	/// Minimum aye votes as percentage of overall conviction-weighted votes needed for
+	/// approval as a function of time into decision period.
+	pub min_approval: Curve,
+	/// Minimum pre-conviction aye-votes ("support") as percentage of overall population that is
+	/// needed for approval as a function of time into decision period.
+	pub min_support: Curve,
+}
+
+impl<Balance, Moment> TrackDetails<Balance, Moment> {
+	/// Combine these parameters with the given `name` into a [`TrackInfo`].
+	pub fn into_info(self, name: &'static str) -> TrackInfo<Balance, Moment> {
+		TrackInfo {
+			name,
+			max_deciding: self.max_deciding,
+			decision_deposit: self.decision_deposit,
+			prepare_period: self.prepare_period,
+			decision_period: self.decision_period,
+			confirm_period: self.confirm_period,
+			min_enactment_period: self.min_enactment_period,
+			min_approval: self.min_approval,
+			min_support: self.min_support,
+		}
+	}
+}
+
+impl<Balance: Clone, Moment: Clone> From<&TrackInfo<Balance, Moment>>
+	for TrackDetails<Balance, Moment>
+{
+	fn from(info: &TrackInfo<Balance, Moment>) -> Self {
+		Self {
+			max_deciding: info.max_deciding,
+			decision_deposit: info.decision_deposit.clone(),
+			prepare_period: info.prepare_period.clone(),
+			decision_period: info.decision_period.clone(),
+			confirm_period: info.confirm_period.clone(),
+			min_enactment_period: info.min_enactment_period.clone(),
+			min_approval: info.min_approval.clone(),
+			min_support: info.min_support.clone(),
+		}
+	}
+}
+
 /// Information on the voting tracks.
 pub trait TracksInfo<Balance, Moment> {
 	/// The identifier for a track.
@@ -82,6 +82,7 @@ pub trait WeightInfo {
 	fn nudge_referendum_rejected() -> Weight;
 	fn set_some_metadata() -> Weight;
 	fn clear_metadata() -> Weight;
+	fn update_track() -> Weight;
 }
 
 /// Weights for pallet_referenda using the Substrate node and recommended hardware.
@@ -503,6 +504,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Referenda TrackDetailsFor (r:0 w:1)
+	/// Proof: Referenda TrackDetailsFor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_540_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -923,4 +934,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Referenda TrackDetailsFor (r:0 w:1)
+	/// Proof: Referenda TrackDetailsFor (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_540_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -23,7 +23,7 @@ use super::*;
 use crate as pallet_safe_mode;
 
 use frame_support::{
-	parameter_types,
+	derive_impl, parameter_types,
 	traits::{ConstU64, Everything, InsideBoth, InstanceFilter, IsInVec, SafeModeNotify},
 };
 use frame_system::EnsureSignedBy;
@@ -33,6 +33,7 @@ use sp_runtime::{
 	BuildStorage,
 };
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = InsideBoth<Everything, SafeMode>;
 	type BlockWeights = ();
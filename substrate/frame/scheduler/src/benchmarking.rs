@@ -306,5 +306,24 @@ benchmarks! {
 		);
 	}
 
+	reschedule_named {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		let after = BLOCK_NUMBER.into();
+		let new_when = frame_system::Pallet::<T>::block_number()
+			.saturating_add(after)
+			.saturating_add(One::one());
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, u32_to_name(0), DispatchTime::After(after))
+	verify {
+		ensure!(
+			Lookup::<T>::get(u32_to_name(0)) == Some((new_when, 0)),
+			"didn't move task to new block"
+		);
+		ensure!(Agenda::<T>::get(new_when).len() == 1, "didn't add to new schedule");
+	}
+
 	impl_benchmark_test_suite!(Scheduler, crate::mock::new_test_ext(), crate::mock::Test);
 }
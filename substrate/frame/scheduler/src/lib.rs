@@ -85,7 +85,7 @@ mod mock;
 mod tests;
 pub mod weights;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchResult, GetDispatchInfo, Parameter, RawOrigin},
 	ensure,
@@ -148,6 +148,29 @@ pub struct Scheduled<Name, Call, BlockNumber, PalletsOrigin, AccountId> {
 	_phantom: PhantomData<AccountId>,
 }
 
+/// Information about a task to be executed in the future, as exposed by [`SchedulerApi`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct ScheduledTask<BlockNumber, PalletsOrigin, Hash> {
+	/// The unique identity of the task, if it is named.
+	pub id: Option<TaskName>,
+	/// The block in which the task is executed.
+	pub when: BlockNumber,
+	/// The index of the task in the agenda of `when`.
+	pub index: u32,
+	/// If the task is periodic, then this points to the information concerning that.
+	pub maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	/// The origin with which the call is dispatched.
+	pub origin: PalletsOrigin,
+	/// The hash of the call to be dispatched.
+	pub call_hash: Hash,
+}
+
+pub type ScheduledTaskOf<T> = ScheduledTask<
+	BlockNumberFor<T>,
+	<T as Config>::PalletsOrigin,
+	<T as frame_system::Config>::Hash,
+>;
+
 use crate::{Scheduled as ScheduledV3, Scheduled as ScheduledV2};
 
 pub type ScheduledV2Of<T> = ScheduledV2<
@@ -301,6 +324,9 @@ pub mod pallet {
 		PeriodicFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The weight limit was exhausted before all due agendas could be serviced. Servicing
+		/// resumes at the agenda of `incomplete_since` in the next block.
+		WeightExhausted { incomplete_since: BlockNumberFor<T> },
 	}
 
 	#[pallet::error]
@@ -440,6 +466,24 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Move a named scheduled task to another block.
+		///
+		/// The origin must have greater or equal privileges than the origin of the task.
+		/// `DispatchTime::After` is relative to the current block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule_named(T::MaxScheduledPerBlock::get()))]
+		pub fn reschedule_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: DispatchTime<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::ensure_privilege_named(origin.caller(), &id)?;
+			Self::do_reschedule_named(id, when)?;
+			Ok(())
+		}
 	}
 }
 
@@ -952,6 +996,23 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Ensure that `origin` has greater or equal privileges than the origin of the named task.
+	fn ensure_privilege_named(origin: &T::PalletsOrigin, id: &TaskName) -> DispatchResult {
+		let (when, index) = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		if matches!(
+			T::OriginPrivilegeCmp::cmp_privilege(origin, &task.origin),
+			Some(Ordering::Less) | None
+		) {
+			return Err(BadOrigin.into())
+		}
+		Ok(())
+	}
+
 	fn do_reschedule_named(
 		id: TaskName,
 		new_time: DispatchTime<BlockNumberFor<T>>,
@@ -975,6 +1036,43 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// All tasks which are yet to be executed, ordered by the block in which they are executed.
+	pub fn scheduled_tasks() -> Vec<ScheduledTaskOf<T>> {
+		let mut tasks = Agenda::<T>::iter()
+			.flat_map(|(when, agenda)| {
+				agenda.into_iter().enumerate().filter_map(move |(index, task)| {
+					task.map(|task| Self::scheduled_task_info(when, index as u32, task))
+				})
+			})
+			.collect::<Vec<_>>();
+		tasks.sort_by_key(|task| (task.when, task.index));
+		tasks
+	}
+
+	/// The named task with the given `id`, if it is yet to be executed.
+	pub fn scheduled_task(id: TaskName) -> Option<ScheduledTaskOf<T>> {
+		let (when, index) = Lookup::<T>::get(id)?;
+		let task = Agenda::<T>::get(when).into_iter().nth(index as usize).flatten()?;
+		Some(Self::scheduled_task_info(when, index, task))
+	}
+
+	fn scheduled_task_info(
+		when: BlockNumberFor<T>,
+		index: u32,
+		task: ScheduledOf<T>,
+	) -> ScheduledTaskOf<T> {
+		ScheduledTask {
+			id: task.maybe_id,
+			when,
+			index,
+			maybe_periodic: task.maybe_periodic,
+			origin: task.origin,
+			call_hash: task.call.hash(),
+		}
+	}
+}
+
 enum ServiceTaskError {
 	/// Could not be executed due to missing preimage.
 	Unavailable,
@@ -1007,6 +1105,7 @@ impl<T: Config> Pallet<T> {
 		incomplete_since = incomplete_since.min(when);
 		if incomplete_since <= now {
 			IncompleteSince::<T>::put(incomplete_since);
+			Self::deposit_event(Event::WeightExhausted { incomplete_since });
 		}
 	}
 
@@ -1107,7 +1206,7 @@ impl<T: Config> Pallet<T> {
 			},
 		};
 
-		let _ = weight.try_consume(T::WeightInfo::service_task(
+		weight.consume(T::WeightInfo::service_task(
 			lookup_len.map(|x| x as usize),
 			task.maybe_id.is_some(),
 			task.maybe_periodic.is_some(),
@@ -1187,9 +1286,10 @@ impl<T: Config> Pallet<T> {
 			Err(error_and_info) =>
 				(error_and_info.post_info.actual_weight, Err(error_and_info.error)),
 		};
-		let call_weight = maybe_actual_call_weight.unwrap_or(call_weight);
-		let _ = weight.try_consume(base_weight);
-		let _ = weight.try_consume(call_weight);
+		// The actual weight can't exceed the reserved one, which the meter was checked against.
+		let call_weight = maybe_actual_call_weight.map_or(call_weight, |w| w.min(call_weight));
+		weight.consume(base_weight);
+		weight.consume(call_weight);
 		Ok(result)
 	}
 }
@@ -1351,3 +1451,19 @@ fn map_err_to_v3_err<T: Config>(err: DispatchError) -> DispatchError {
 		err
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// API to inspect the agenda of the scheduler.
+	pub trait SchedulerApi<BlockNumber, PalletsOrigin, Hash> where
+		BlockNumber: Codec,
+		PalletsOrigin: Codec,
+		Hash: Codec,
+	{
+		/// All tasks which are yet to be executed, ordered by the block in which they are
+		/// executed.
+		fn scheduled_tasks() -> Vec<ScheduledTask<BlockNumber, PalletsOrigin, Hash>>;
+
+		/// The named task with the given `id`, if it is yet to be executed.
+		fn scheduled_task(id: TaskName) -> Option<ScheduledTask<BlockNumber, PalletsOrigin, Hash>>;
+	}
+}
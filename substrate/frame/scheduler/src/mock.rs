@@ -21,7 +21,7 @@ use super::*;
 
 use crate as scheduler;
 use frame_support::{
-	ord_parameter_types, parameter_types,
+	derive_impl, ord_parameter_types, parameter_types,
 	traits::{
 		ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly, OnFinalize, OnInitialize,
 	},
@@ -89,6 +89,21 @@ pub mod logger {
 			});
 			Ok(())
 		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(*weight)]
+		pub fn log_with_actual_weight(
+			origin: OriginFor<T>,
+			i: u32,
+			weight: Weight,
+			actual_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			Self::deposit_event(Event::Logged(i, weight));
+			Log::mutate(|log| {
+				log.push((origin.caller().clone(), i));
+			});
+			Ok(Some(actual_weight).into())
+		}
 	}
 }
 
@@ -118,6 +133,7 @@ parameter_types! {
 			Weight::from_parts(2_000_000_000_000, u64::MAX),
 		);
 }
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl system::Config for Test {
 	type BaseCallFilter = BaseFilter;
 	type BlockWeights = BlockWeights;
@@ -196,6 +212,9 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_named(_s: u32) -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn reschedule_named(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
 }
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
@@ -258,6 +258,127 @@ fn reschedule_named_works() {
 	});
 }
 
+#[test]
+fn reschedule_named_call_works() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_named(
+			system::RawOrigin::Signed(1).into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call,
+		));
+		run_to_block(3);
+
+		// Only origins with at least the same privileges may reschedule.
+		assert_noop!(
+			Scheduler::reschedule_named(
+				system::RawOrigin::Signed(2).into(),
+				[1u8; 32],
+				DispatchTime::At(6)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::reschedule_named(
+				system::RawOrigin::Root.into(),
+				[1u8; 32],
+				DispatchTime::At(6)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::reschedule_named(
+				system::RawOrigin::Signed(1).into(),
+				[2u8; 32],
+				DispatchTime::At(6)
+			),
+			Error::<Test>::NotFound
+		);
+
+		// `After` is relative to the current block.
+		assert_ok!(Scheduler::reschedule_named(
+			system::RawOrigin::Signed(1).into(),
+			[1u8; 32],
+			DispatchTime::After(2)
+		));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((6, 0)));
+		assert_noop!(
+			Scheduler::reschedule_named(
+				system::RawOrigin::Signed(1).into(),
+				[1u8; 32],
+				DispatchTime::At(6)
+			),
+			Error::<Test>::RescheduleNoChange
+		);
+
+		run_to_block(5);
+		assert!(logger::log().is_empty());
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(system::RawOrigin::Signed(1).into(), 42u32)]);
+	});
+}
+
+#[test]
+fn scheduled_tasks_are_listed() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let call_hash = Preimage::bound(call.clone()).unwrap().hash();
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(6),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		assert_ok!(Scheduler::do_cancel(None, (4, 0)));
+
+		let named = ScheduledTask {
+			id: Some([1u8; 32]),
+			when: 6,
+			index: 0,
+			maybe_periodic: Some((3, 2)),
+			origin: root(),
+			call_hash,
+		};
+		let anonymous = ScheduledTask {
+			id: None,
+			when: 4,
+			index: 1,
+			maybe_periodic: None,
+			origin: root(),
+			call_hash,
+		};
+		assert_eq!(Scheduler::scheduled_tasks(), vec![anonymous, named.clone()]);
+		assert_eq!(Scheduler::scheduled_task([1u8; 32]), Some(named));
+		assert_eq!(Scheduler::scheduled_task([2u8; 32]), None);
+
+		run_to_block(4);
+		assert_eq!(Scheduler::scheduled_tasks().len(), 1);
+	});
+}
+
 #[test]
 fn reschedule_named_perodic_works() {
 	new_test_ext().execute_with(|| {
@@ -425,11 +546,34 @@ fn scheduler_respects_weight_limits() {
 		// 69 and 42 do not fit together
 		run_to_block(4);
 		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		System::assert_last_event(crate::Event::WeightExhausted { incomplete_since: 4 }.into());
 		run_to_block(5);
 		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 69u32)]);
 	});
 }
 
+/// A call reporting more weight than it declared only consumes its declared weight.
+#[test]
+fn actual_weight_above_declared_weight_is_capped() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_with_actual_weight {
+			i: 42,
+			weight: max_weight / 3 * 2,
+			actual_weight: Weight::MAX,
+		});
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
 /// Permanently overweight calls are not deleted but also not executed.
 #[test]
 fn scheduler_does_not_delete_permanently_overweight_call() {
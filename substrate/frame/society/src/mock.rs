@@ -32,6 +32,7 @@ use sp_runtime::{
 	BuildStorage,
 };
 
+use frame_support::derive_impl;
 use RuntimeOrigin as Origin;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -58,6 +59,7 @@ ord_parameter_types! {
 	pub const MaxBids: u32 = 10;
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
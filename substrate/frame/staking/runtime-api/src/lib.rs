@@ -20,8 +20,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use sp_staking::{EraIndex, Page};
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
@@ -32,5 +35,39 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the page count of exposures for a validator in a given era.
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page;
+
+		/// Returns the exposures of `account` in a given era, either as a validator or as a
+		/// nominator.
+		///
+		/// Each entry contains the backed validator, the page of the validator's exposure the
+		/// stake is part of and the exposed amount.
+		///
+		/// At most `max_validators` validators of the era are scanned, starting after
+		/// `start_after`. If more validators are left, the last scanned one is returned as well
+		/// and should be passed as `start_after` to fetch the next batch.
+		#[api_version(2)]
+		fn exposures(
+			era: EraIndex,
+			account: AccountId,
+			start_after: Option<AccountId>,
+			max_validators: u32,
+		) -> (Vec<(AccountId, Page, Balance)>, Option<AccountId>);
+
+		/// Returns the rewards of `account` in a given era which can still be claimed, as the
+		/// validator and the page of the validator's exposure to pay out.
+		///
+		/// The validators of the era are scanned in batches, like by `exposures`.
+		#[api_version(2)]
+		fn pending_rewards(
+			era: EraIndex,
+			account: AccountId,
+			start_after: Option<AccountId>,
+			max_validators: u32,
+		) -> (Vec<(AccountId, Page)>, Option<AccountId>);
+
+		/// Returns the slashes of `account` in the eras which are still bonded, as the era and the
+		/// slashed amount.
+		#[api_version(2)]
+		fn slashes(account: AccountId) -> Vec<(EraIndex, Balance)>;
 	}
 }
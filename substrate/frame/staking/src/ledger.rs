@@ -39,7 +39,8 @@ use sp_staking::StakingAccount;
 use sp_std::prelude::*;
 
 use crate::{
-	BalanceOf, Bonded, Config, Error, Ledger, Payee, RewardDestination, StakingLedger, STAKING_ID,
+	BalanceOf, Bonded, Config, Error, Ledger, Pallet, Payee, RewardDestination, StakingLedger,
+	VirtualStakers, STAKING_ID,
 };
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -166,7 +167,10 @@ impl<T: Config> StakingLedger<T> {
 			return Err(Error::<T>::NotStash)
 		}
 
-		T::Currency::set_lock(STAKING_ID, &self.stash, self.total, WithdrawReasons::all());
+		// the funds of virtual stakers are locked by the pallet managing them.
+		if !Pallet::<T>::is_virtual_staker(&self.stash) {
+			T::Currency::set_lock(STAKING_ID, &self.stash, self.total, WithdrawReasons::all());
+		}
 		Ledger::<T>::insert(
 			&self.controller().ok_or_else(|| {
 				defensive!("update called on a ledger that is not bonded.");
@@ -207,7 +211,9 @@ impl<T: Config> StakingLedger<T> {
 		let controller = <Bonded<T>>::get(stash).ok_or(Error::<T>::NotStash)?;
 
 		<Ledger<T>>::get(&controller).ok_or(Error::<T>::NotController).map(|ledger| {
-			T::Currency::remove_lock(STAKING_ID, &ledger.stash);
+			if VirtualStakers::<T>::take(&ledger.stash).is_none() {
+				T::Currency::remove_lock(STAKING_ID, &ledger.stash);
+			}
 			Ledger::<T>::remove(controller);
 
 			<Bonded<T>>::remove(&stash);
@@ -27,8 +27,8 @@ use frame_support::{
 	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
 	traits::{
-		Currency, Defensive, EstimateNextNewSession, Get, Imbalance, Len, OnUnbalanced, TryCollect,
-		UnixTime,
+		Currency, Defensive, EstimateNextNewSession, Get, Imbalance, Len, LockableCurrency,
+		OnUnbalanced, TryCollect, UnixTime,
 	},
 	weights::Weight,
 };
@@ -43,7 +43,7 @@ use sp_staking::{
 	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
 	EraIndex, Page, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, StakingUnchecked,
 };
 use sp_std::prelude::*;
 
@@ -117,6 +117,44 @@ impl<T: Config> Pallet<T> {
 		Self::slashable_balance_of_vote_weight(who, issuance)
 	}
 
+	/// Whether `who` is a virtual staker, whose bonded funds are held by another pallet.
+	pub fn is_virtual_staker(who: &T::AccountId) -> bool {
+		VirtualStakers::<T>::contains_key(who)
+	}
+
+	/// Bond up to `max_additional` more of the funds of `stash`.
+	///
+	/// The funds of virtual stakers are not in their stash, so all of `max_additional` is bonded.
+	pub(super) fn do_bond_extra(
+		stash: &T::AccountId,
+		max_additional: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut ledger = Self::ledger(StakingAccount::Stash(stash.clone()))?;
+
+		let extra = if Self::is_virtual_staker(stash) {
+			Some(max_additional)
+		} else {
+			let stash_balance = T::Currency::free_balance(stash);
+			stash_balance.checked_sub(&ledger.total).map(|extra| extra.min(max_additional))
+		};
+		if let Some(extra) = extra {
+			ledger.total += extra;
+			ledger.active += extra;
+			// Last check: the new active amount of ledger must be more than ED.
+			ensure!(ledger.active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
+
+			// NOTE: ledger must be updated prior to calling `Self::weight_of`.
+			ledger.update()?;
+			// update this staker in the sorted list, if they exist in it.
+			if T::VoterList::contains(stash) {
+				let _ = T::VoterList::on_update(stash, Self::weight_of(stash)).defensive();
+			}
+
+			Self::deposit_event(Event::<T>::Bonded { stash: stash.clone(), amount: extra });
+		}
+		Ok(())
+	}
+
 	pub(super) fn do_withdraw_unbonded(
 		controller: &T::AccountId,
 		num_slashing_spans: u32,
@@ -1100,6 +1138,139 @@ impl<T: Config> Pallet<T> {
 	pub fn api_eras_stakers_page_count(era: EraIndex, account: T::AccountId) -> Page {
 		EraInfo::<T>::get_page_count(era, &account)
 	}
+
+	/// Returns the exposures of `account` in the given era, as the backed validator, the page of
+	/// its exposure and the exposed amount.
+	///
+	/// At most `max_validators` validators of the era are scanned, starting after `start_after`.
+	/// The last scanned validator is returned alongside the exposures if more validators are left
+	/// to scan, to be passed as `start_after` of the next call.
+	///
+	/// The own stake of a validator is reported as part of page 0. Older non-paged exposures are
+	/// reported as a single page.
+	///
+	/// Used by the runtime API.
+	pub fn api_exposures(
+		era: EraIndex,
+		account: T::AccountId,
+		start_after: Option<T::AccountId>,
+		max_validators: u32,
+	) -> (Vec<(T::AccountId, Page, BalanceOf<T>)>, Option<T::AccountId>) {
+		let mut exposures = Vec::new();
+		let mut last = None;
+		let more;
+
+		if ErasStakersOverview::<T>::iter_key_prefix(era).next().is_some() {
+			let mut validators = match start_after {
+				Some(ref validator) => ErasStakersOverview::<T>::iter_prefix_from(
+					era,
+					ErasStakersOverview::<T>::hashed_key_for(era, validator),
+				),
+				None => ErasStakersOverview::<T>::iter_prefix(era),
+			};
+			for (validator, overview) in validators.by_ref().take(max_validators as usize) {
+				if validator == account {
+					exposures.push((validator.clone(), 0, overview.own));
+				}
+				for page in 0..overview.page_count {
+					let others = ErasStakersPaged::<T>::get((era, &validator, page))
+						.map(|exposure_page| exposure_page.others)
+						.unwrap_or_default();
+					exposures.extend(
+						others
+							.into_iter()
+							.filter(|individual| individual.who == account)
+							.map(|individual| (validator.clone(), page, individual.value)),
+					);
+				}
+				last = Some(validator);
+			}
+			more = validators.next().is_some();
+		} else {
+			// FIXME: Can be cleaned up with issue #13034.
+			let mut validators = match start_after {
+				Some(ref validator) => ErasStakers::<T>::iter_prefix_from(
+					era,
+					ErasStakers::<T>::hashed_key_for(era, validator),
+				),
+				None => ErasStakers::<T>::iter_prefix(era),
+			};
+			for (validator, exposure) in validators.by_ref().take(max_validators as usize) {
+				if validator == account {
+					exposures.push((validator.clone(), 0, exposure.own));
+				}
+				exposures.extend(
+					exposure
+						.others
+						.into_iter()
+						.filter(|individual| individual.who == account)
+						.map(|individual| (validator.clone(), 0, individual.value)),
+				);
+				last = Some(validator);
+			}
+			more = validators.next().is_some();
+		}
+
+		(exposures, last.filter(|_| more))
+	}
+
+	/// Returns the rewards of `account` in the given era which can still be paid out, as the
+	/// validator and the page of its exposure.
+	///
+	/// The validators of the era are scanned in the same way as by [`Self::api_exposures`].
+	///
+	/// Used by the runtime API.
+	pub fn api_pending_rewards(
+		era: EraIndex,
+		account: T::AccountId,
+		start_after: Option<T::AccountId>,
+		max_validators: u32,
+	) -> (Vec<(T::AccountId, Page)>, Option<T::AccountId>) {
+		let current_era = CurrentEra::<T>::get().unwrap_or_default();
+		if era > current_era ||
+			era < current_era.saturating_sub(T::HistoryDepth::get()) ||
+			!ErasValidatorReward::<T>::contains_key(era)
+		{
+			return (Vec::new(), None)
+		}
+
+		let (exposures, next) = Self::api_exposures(era, account, start_after, max_validators);
+		let pending = exposures
+			.into_iter()
+			.filter_map(|(validator, page, _)| {
+				// Rewards of validators which are no longer bonded can't be paid out.
+				let ledger = Self::ledger(Stash(validator.clone())).ok()?;
+				(!EraInfo::<T>::is_rewards_claimed_with_legacy_fallback(
+					era, &ledger, &validator, page,
+				))
+				.then_some((validator, page))
+			})
+			.collect();
+
+		(pending, next)
+	}
+
+	/// Returns the slashes of `account` in the eras which are still bonded, as the era and the
+	/// slashed amount.
+	///
+	/// Used by the runtime API.
+	pub fn api_slashes(account: T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		BondedEras::<T>::get()
+			.into_iter()
+			.filter_map(|(era, _)| {
+				let as_validator =
+					ValidatorSlashInEra::<T>::get(era, &account).map(|(_, slash)| slash);
+				let as_nominator = NominatorSlashInEra::<T>::get(era, &account);
+				match (as_validator, as_nominator) {
+					(None, None) => None,
+					(validator, nominator) => Some((
+						era,
+						validator.unwrap_or_default().saturating_add(nominator.unwrap_or_default()),
+					)),
+				}
+			})
+			.collect()
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1686,7 +1857,7 @@ impl<T: Config> StakingInterface for Pallet<T> {
 	}
 
 	fn bond_extra(who: &Self::AccountId, extra: Self::Balance) -> DispatchResult {
-		Self::bond_extra(RawOrigin::Signed(who.clone()).into(), extra)
+		Self::do_bond_extra(who, extra)
 	}
 
 	fn unbond(who: &Self::AccountId, value: Self::Balance) -> DispatchResult {
@@ -1808,6 +1979,55 @@ impl<T: Config> StakingInterface for Pallet<T> {
 	}
 }
 
+impl<T: Config> StakingUnchecked for Pallet<T> {
+	fn virtual_bond(
+		keyless_who: &Self::AccountId,
+		value: Self::Balance,
+		payee: &Self::AccountId,
+	) -> DispatchResult {
+		if StakingLedger::<T>::is_bonded(StakingAccount::Stash(keyless_who.clone())) {
+			return Err(Error::<T>::AlreadyBonded.into())
+		}
+
+		// Reject a bond which is considered to be _dust_.
+		if value < T::Currency::minimum_balance() {
+			return Err(Error::<T>::InsufficientBond.into())
+		}
+
+		// rewards can't be restaked, as nothing backs them.
+		ensure!(keyless_who != payee, Error::<T>::RewardDestinationRestricted);
+
+		frame_system::Pallet::<T>::inc_consumers(keyless_who).map_err(|_| Error::<T>::BadState)?;
+
+		VirtualStakers::<T>::insert(keyless_who, ());
+		Self::deposit_event(Event::<T>::Bonded { stash: keyless_who.clone(), amount: value });
+		let ledger = StakingLedger::<T>::new(keyless_who.clone(), value);
+		ledger.bond(RewardDestination::Account(payee.clone()))?;
+
+		Ok(())
+	}
+
+	fn is_virtual_staker(who: &Self::AccountId) -> bool {
+		Self::is_virtual_staker(who)
+	}
+
+	fn migrate_to_virtual_staker(who: &Self::AccountId, payee: &Self::AccountId) -> DispatchResult {
+		let ledger = Self::ledger(StakingAccount::Stash(who.clone()))?;
+		ensure!(!Self::is_virtual_staker(who), Error::<T>::AlreadyBonded);
+		ensure!(who != payee, Error::<T>::RewardDestinationRestricted);
+
+		T::Currency::remove_lock(crate::STAKING_ID, who);
+		VirtualStakers::<T>::insert(who, ());
+		ledger.set_payee(RewardDestination::Account(payee.clone()))?;
+
+		Ok(())
+	}
+
+	fn slash_reward_fraction() -> Perbill {
+		SlashRewardFraction::<T>::get()
+	}
+}
+
 #[cfg(any(test, feature = "try-runtime"))]
 impl<T: Config> Pallet<T> {
 	pub(crate) fn do_try_state(_: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
@@ -606,12 +606,16 @@ pub fn do_slash<T: Config>(
 	let value = ledger.slash(value, T::Currency::minimum_balance(), slash_era);
 
 	if !value.is_zero() {
-		let (imbalance, missing) = T::Currency::slash(stash, value);
-		slashed_imbalance.subsume(imbalance);
-
-		if !missing.is_zero() {
-			// deduct overslash from the reward payout
-			*reward_payout = reward_payout.saturating_sub(missing);
+		// the funds of virtual stakers are held by another pallet, which applies the slash once
+		// notified through `Config::EventListeners`.
+		if !Pallet::<T>::is_virtual_staker(stash) {
+			let (imbalance, missing) = T::Currency::slash(stash, value);
+			slashed_imbalance.subsume(imbalance);
+
+			if !missing.is_zero() {
+				// deduct overslash from the reward payout
+				*reward_payout = reward_payout.saturating_sub(missing);
+			}
 		}
 
 		let _ = ledger
@@ -5942,6 +5942,102 @@ fn api_nominations_quota_works() {
 	})
 }
 
+#[test]
+fn api_exposures_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		assert_eq!(Staking::api_exposures(1, 11, None, 100), (vec![(11, 0, 1000)], None));
+
+		let mut expected = [11, 21]
+			.into_iter()
+			.filter_map(|validator| {
+				Staking::eras_stakers(1, &validator)
+					.others
+					.into_iter()
+					.find(|individual| individual.who == 101)
+					.map(|individual| (validator, 0, individual.value))
+			})
+			.collect::<Vec<_>>();
+		expected.sort();
+		let (mut exposures, next) = Staking::api_exposures(1, 101, None, 100);
+		exposures.sort();
+		assert!(!exposures.is_empty());
+		assert_eq!(exposures, expected);
+		assert_eq!(next, None);
+
+		// not exposed at all.
+		assert_eq!(Staking::api_exposures(1, 1337, None, 100), (vec![], None));
+	})
+}
+
+#[test]
+fn api_exposures_pagination_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		mock::start_active_era(1);
+		let validators = ErasStakersOverview::<Test>::iter_key_prefix(1).count();
+		assert!(validators > 1);
+
+		let (all, _) = Staking::api_exposures(1, 101, None, validators as u32);
+
+		// scan the validators one by one.
+		let mut exposures = Vec::new();
+		let mut start_after = None;
+		let mut calls = 0;
+		loop {
+			let (page, next) = Staking::api_exposures(1, 101, start_after, 1);
+			exposures.extend(page);
+			calls += 1;
+			match next {
+				Some(validator) => start_after = Some(validator),
+				None => break,
+			}
+		}
+
+		assert_eq!(calls, validators);
+		assert_eq!(exposures, all);
+	})
+}
+
+#[test]
+fn api_pending_rewards_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+
+		let pending = |who| Staking::api_pending_rewards(1, who, None, 100).0;
+
+		assert!(pending(101).contains(&(11, 0)));
+		assert!(pending(11).contains(&(11, 0)));
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1));
+
+		assert!(!pending(101).contains(&(11, 0)));
+		assert!(!pending(11).contains(&(11, 0)));
+		assert!(pending(101).contains(&(21, 0)));
+
+		// no rewards in the current era yet.
+		assert_eq!(Staking::api_pending_rewards(2, 101, None, 100), (vec![], None));
+	})
+}
+
+#[test]
+fn api_slashes_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		mock::start_active_era(1);
+		assert!(Staking::api_slashes(11).is_empty());
+
+		add_slash(&11);
+
+		let validator_slash = ValidatorSlashInEra::<Test>::get(1, &11).unwrap().1;
+		assert_eq!(Staking::api_slashes(11), vec![(1, validator_slash)]);
+		let nominator_slash = NominatorSlashInEra::<Test>::get(1, &101).unwrap();
+		assert_eq!(Staking::api_slashes(101), vec![(1, nominator_slash)]);
+		assert!(Staking::api_slashes(21).is_empty());
+	})
+}
+
 mod sorted_list_provider {
 	use super::*;
 	use frame_election_provider_support::SortedListProvider;
@@ -6704,6 +6800,111 @@ fn test_validator_exposure_is_backward_compatible_with_non_paged_rewards_payout(
 	});
 }
 
+mod staking_unchecked {
+	use sp_staking::{Stake, StakingInterface, StakingUnchecked};
+
+	use super::*;
+
+	#[test]
+	fn virtual_bond_does_not_lock() {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::start_active_era(1);
+			// 200 holds no funds, a provider is normally added by the pallet managing them.
+			frame_system::Pallet::<Test>::inc_providers(&200);
+			assert_eq!(Balances::free_balance(200), 0);
+
+			// rewards can't be restaked.
+			assert_noop!(
+				<Staking as StakingUnchecked>::virtual_bond(&200, 100, &200),
+				Error::<Test>::RewardDestinationRestricted
+			);
+
+			// 200 can bond more than its balance since nothing is locked.
+			assert_ok!(<Staking as StakingUnchecked>::virtual_bond(&200, 100, &15));
+			assert!(<Staking as StakingUnchecked>::is_virtual_staker(&200));
+			assert!(Balances::locks(&200).is_empty());
+			assert_eq!(Staking::payee(200.into()), RewardDestination::Account(15));
+
+			// bonding extra doesn't lock anything either, but updates the ledger.
+			assert_ok!(<Staking as StakingInterface>::bond_extra(&200, 1000));
+			assert!(Balances::locks(&200).is_empty());
+			assert_eq!(
+				<Staking as StakingInterface>::stake(&200),
+				Ok(Stake { total: 1100, active: 1100 })
+			);
+
+			// a virtual staker is not reaped because its stash is empty.
+			assert_noop!(
+				Staking::reap_stash(RuntimeOrigin::signed(1), 200, 0),
+				Error::<Test>::FundedTarget
+			);
+
+			// once unbonded and withdrawn, the virtual staker is gone.
+			assert_ok!(<Staking as StakingInterface>::unbond(&200, 1100));
+			mock::start_active_era(1 + BondingDuration::get());
+			assert_eq!(<Staking as StakingInterface>::withdraw_unbonded(200, 0), Ok(true));
+			assert!(!<Staking as StakingUnchecked>::is_virtual_staker(&200));
+		});
+	}
+
+	#[test]
+	fn migrate_to_virtual_staker_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::start_active_era(1);
+			assert!(!Balances::locks(&101).is_empty());
+			let stake = <Staking as StakingInterface>::stake(&101).unwrap();
+
+			// rewards can't be restaked.
+			assert_noop!(
+				<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101, &101),
+				Error::<Test>::RewardDestinationRestricted
+			);
+
+			assert_ok!(<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101, &15));
+			assert!(<Staking as StakingUnchecked>::is_virtual_staker(&101));
+			assert!(Balances::locks(&101).is_empty());
+			assert_eq!(Staking::payee(101.into()), RewardDestination::Account(15));
+			// the stake itself is untouched.
+			assert_eq!(<Staking as StakingInterface>::stake(&101), Ok(stake));
+
+			// can't be migrated twice.
+			assert_noop!(
+				<Staking as StakingUnchecked>::migrate_to_virtual_staker(&101, &15),
+				Error::<Test>::AlreadyBonded
+			);
+		});
+	}
+
+	#[test]
+	fn virtual_nominator_slash_is_left_to_the_managing_pallet() {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::start_active_era(1);
+			frame_system::Pallet::<Test>::inc_providers(&200);
+			assert_ok!(<Staking as StakingUnchecked>::virtual_bond(&200, 500, &15));
+			assert_ok!(<Staking as StakingInterface>::nominate(&200, vec![11]));
+
+			mock::start_active_era(2);
+			let exposure = Staking::eras_stakers(active_era(), &11);
+			assert!(exposure.others.iter().any(|e| e.who == 200));
+
+			on_offence_now(
+				&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+				&[Perbill::from_percent(10)],
+			);
+
+			// the ledger of 200 is slashed and the slash reported to the listeners, but its
+			// balance is left untouched.
+			let slashed = 500 - Staking::ledger(200.into()).unwrap().active;
+			assert!(slashed > 0);
+			assert_eq!(LedgerSlashPerEra::get().0, 500 - slashed);
+			assert_eq!(Balances::free_balance(200), 0);
+			System::assert_has_event(
+				Event::<Test>::Slashed { staker: 200, amount: slashed }.into(),
+			);
+		});
+	}
+}
+
 mod staking_interface {
 	use frame_support::storage::with_storage_layer;
 	use sp_staking::StakingInterface;
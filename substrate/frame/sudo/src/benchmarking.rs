@@ -20,6 +20,7 @@
 use super::*;
 use crate::Pallet;
 use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
 use frame_system::RawOrigin;
 
 fn assert_last_event<T: Config>(generic_event: crate::Event<T>) {
@@ -42,7 +43,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), new_sudoer_lookup);
 
-		assert_last_event::<T>(Event::KeyChanged { old: Some(caller), new: new_sudoer });
+		assert_last_event::<T>(Event::KeyProposed { current: Some(caller), proposed: new_sudoer });
 	}
 
 	#[benchmark]
@@ -85,5 +86,56 @@ mod benchmarks {
 		assert_last_event::<T>(Event::KeyRemoved {});
 	}
 
+	#[benchmark]
+	fn accept_key() {
+		let caller: T::AccountId = whitelisted_caller();
+		let old: T::AccountId = account("old", 0, 0);
+		Key::<T>::put(&old);
+		PendingKey::<T>::put(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::KeyChanged { old: Some(old), new: caller });
+	}
+
+	#[benchmark]
+	fn schedule_sunset() {
+		let caller: T::AccountId = whitelisted_caller();
+		Key::<T>::put(&caller);
+		let at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), at);
+
+		assert_last_event::<T>(Event::SunsetScheduled { at });
+	}
+
+	#[benchmark]
+	fn check_sunset() {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(now);
+		}
+	}
+
+	#[benchmark]
+	fn sunset() {
+		let caller: T::AccountId = whitelisted_caller();
+		Key::<T>::put(&caller);
+		PendingKey::<T>::put(&caller);
+		let now = frame_system::Pallet::<T>::block_number();
+		Sunset::<T>::put(now);
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(now);
+		}
+
+		assert_last_event::<T>(Event::SunsetReached);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_bench_ext(), crate::mock::Test);
 }
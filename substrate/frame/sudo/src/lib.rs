@@ -73,12 +73,17 @@
 //! function by constructing a call using the [`sudo`](Pallet::sudo) dispatchable.
 //!
 //! To use this pallet in your runtime, a sudo key must be specified in the [`GenesisConfig`] of
-//! the pallet. You can change this key at anytime once your chain is live using the
-//! [`set_key`](Pallet::set_key) dispatchable, however <strong>only one sudo key can be set at a
-//! time</strong>. The pallet also allows you to make a call using
+//! the pallet. You can change this key at anytime once your chain is live by proposing a new key
+//! with the [`set_key`](Pallet::set_key) dispatchable, which takes effect once the proposed
+//! account confirms it using [`accept_key`](Pallet::accept_key). <strong>Only one sudo key can be
+//! set at a time</strong>. The pallet also allows you to make a call using
 //! [`sudo_unchecked_weight`](Pallet::sudo_unchecked_weight), which allows the sudo account to
 //! execute a call with a custom weight.
 //!
+//! To hand the chain over to on-chain governance, the sudo key can
+//! [`schedule_sunset`](Pallet::schedule_sunset) at a future block. Once that block is reached the
+//! sudo key is removed and all sudo calls fail permanently.
+//!
 //! <div class="example-wrap" style="display:inline-block"><pre class="compile_fail"
 //! style="white-space:normal;font:inherit;">
 //! <strong>Note:</strong> this pallet is not meant to be used inside other pallets. It is only
@@ -100,7 +105,7 @@
 //!    key.
 #![doc = docify::embed!("src/tests.rs", sudo_basics)]
 //!
-//! 2. Only an existing sudo key can set a new one.
+//! 2. Only an existing sudo key can propose a new one, which must accept it.
 #![doc = docify::embed!("src/tests.rs", set_key_basics)]
 //!
 //! 3. You can also make non-privileged calls using `sudo_as`.
@@ -233,8 +238,11 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
-		/// Authenticates the current sudo key and sets the given AccountId (`new`) as the new sudo
-		/// key.
+		/// Authenticates the current sudo key and proposes the given AccountId (`new`) as the new
+		/// sudo key.
+		///
+		/// The key only changes once `new` calls [`accept_key`](Pallet::accept_key). Proposing
+		/// another key replaces the pending proposal.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::set_key())]
 		pub fn set_key(
@@ -244,8 +252,11 @@ pub mod pallet {
 			Self::ensure_sudo(origin)?;
 
 			let new = T::Lookup::lookup(new)?;
-			Self::deposit_event(Event::KeyChanged { old: Key::<T>::get(), new: new.clone() });
-			Key::<T>::put(new);
+			Self::deposit_event(Event::KeyProposed {
+				current: Key::<T>::get(),
+				proposed: new.clone(),
+			});
+			PendingKey::<T>::put(new);
 
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
@@ -290,12 +301,67 @@ pub mod pallet {
 
 			Self::deposit_event(Event::KeyRemoved {});
 			Key::<T>::kill();
+			PendingKey::<T>::kill();
+			Sunset::<T>::kill();
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
+
+		/// Accepts the sudo key proposed by [`set_key`](Pallet::set_key).
+		///
+		/// The dispatch origin for this call must be _Signed_ by the proposed key.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::accept_key())]
+		pub fn accept_key(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(PendingKey::<T>::get().as_ref() == Some(&who), Error::<T>::NotPendingKey);
+
+			PendingKey::<T>::kill();
+			Self::deposit_event(Event::KeyChanged { old: Key::<T>::get(), new: who.clone() });
+			Key::<T>::put(who);
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
+
+		/// Authenticates the sudo key and schedules the removal of the sudo key at block `at`.
+		///
+		/// From block `at` on, all sudo calls fail permanently. Calling this again while the
+		/// sudo key is still set moves the sunset to the new block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::schedule_sunset())]
+		pub fn schedule_sunset(
+			origin: OriginFor<T>,
+			at: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sudo(origin)?;
+			ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::SunsetInPast);
+
+			Sunset::<T>::put(at);
+			Self::deposit_event(Event::SunsetScheduled { at });
 
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			match Sunset::<T>::get() {
+				Some(at) if at <= now => {
+					Key::<T>::kill();
+					PendingKey::<T>::kill();
+					Sunset::<T>::kill();
+					Self::deposit_event(Event::SunsetReached);
+					T::WeightInfo::sunset()
+				},
+				_ => T::WeightInfo::check_sunset(),
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -318,6 +384,20 @@ pub mod pallet {
 			/// The result of the call made by the sudo user.
 			sudo_result: DispatchResult,
 		},
+		/// A new sudo key has been proposed and awaits acceptance.
+		KeyProposed {
+			/// The current sudo key (if one is set).
+			current: Option<T::AccountId>,
+			/// The proposed sudo key.
+			proposed: T::AccountId,
+		},
+		/// The sudo key will be removed at the given block.
+		SunsetScheduled {
+			/// The block at which the sudo key is removed.
+			at: BlockNumberFor<T>,
+		},
+		/// The scheduled sunset was reached and the sudo key was permanently removed.
+		SunsetReached,
 	}
 
 	#[pallet::error]
@@ -325,6 +405,10 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Sender must be the Sudo account.
 		RequireSudo,
+		/// Sender is not the proposed sudo key.
+		NotPendingKey,
+		/// The sunset must be scheduled at a future block.
+		SunsetInPast,
 	}
 
 	/// The `AccountId` of the sudo key.
@@ -332,6 +416,14 @@ pub mod pallet {
 	#[pallet::getter(fn key)]
 	pub(super) type Key<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The `AccountId` proposed as the new sudo key, awaiting its acceptance.
+	#[pallet::storage]
+	pub(super) type PendingKey<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// The block at which the sudo key is permanently removed, if scheduled.
+	#[pallet::storage]
+	pub(super) type Sunset<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
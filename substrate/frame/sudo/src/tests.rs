@@ -18,7 +18,7 @@
 //! Tests for the module.
 
 use super::*;
-use frame_support::{assert_noop, assert_ok, weights::Weight};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use mock::{
 	new_test_ext, Logger, LoggerCall, RuntimeCall, RuntimeEvent as TestEvent, RuntimeOrigin, Sudo,
 	SudoCall, System, Test,
@@ -133,8 +133,10 @@ fn sudo_unchecked_weight_emits_events_correctly() {
 #[test]
 fn set_key_basics() {
 	new_test_ext(1).execute_with(|| {
-		// A root `key` can change the root `key`
+		// A root `key` can propose a new root `key`, which only takes effect once accepted.
 		assert_ok!(Sudo::set_key(RuntimeOrigin::signed(1), 2));
+		assert_eq!(Sudo::key(), Some(1u64));
+		assert_ok!(Sudo::accept_key(RuntimeOrigin::signed(2)));
 		assert_eq!(Sudo::key(), Some(2u64));
 	});
 
@@ -150,9 +152,15 @@ fn set_key_emits_events_correctly() {
 	new_test_ext(1).execute_with(|| {
 		// A root `key` can change the root `key`.
 		assert_ok!(Sudo::set_key(RuntimeOrigin::signed(1), 2));
+		System::assert_has_event(TestEvent::Sudo(Event::KeyProposed {
+			current: Some(1),
+			proposed: 2,
+		}));
+		assert_ok!(Sudo::accept_key(RuntimeOrigin::signed(2)));
 		System::assert_has_event(TestEvent::Sudo(Event::KeyChanged { old: Some(1), new: 2 }));
 		// Double check.
 		assert_ok!(Sudo::set_key(RuntimeOrigin::signed(2), 4));
+		assert_ok!(Sudo::accept_key(RuntimeOrigin::signed(4)));
 		System::assert_has_event(TestEvent::Sudo(Event::KeyChanged { old: Some(2), new: 4 }));
 	});
 }
@@ -169,6 +177,54 @@ fn remove_key_works() {
 	});
 }
 
+#[test]
+fn accept_key_requires_pending_key() {
+	new_test_ext(1).execute_with(|| {
+		assert_noop!(Sudo::accept_key(RuntimeOrigin::signed(2)), Error::<Test>::NotPendingKey);
+
+		assert_ok!(Sudo::set_key(RuntimeOrigin::signed(1), 2));
+		// A newer proposal replaces the pending one.
+		assert_ok!(Sudo::set_key(RuntimeOrigin::signed(1), 3));
+		assert_noop!(Sudo::accept_key(RuntimeOrigin::signed(2)), Error::<Test>::NotPendingKey);
+
+		// Removing the key also drops the proposal.
+		assert_ok!(Sudo::remove_key(RuntimeOrigin::signed(1)));
+		assert_noop!(Sudo::accept_key(RuntimeOrigin::signed(3)), Error::<Test>::NotPendingKey);
+	});
+}
+
+#[test]
+fn sunset_removes_key_permanently() {
+	new_test_ext(1).execute_with(|| {
+		assert_noop!(
+			Sudo::schedule_sunset(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::RequireSudo
+		);
+		assert_noop!(
+			Sudo::schedule_sunset(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::SunsetInPast
+		);
+
+		assert_ok!(Sudo::schedule_sunset(RuntimeOrigin::signed(1), 5));
+		System::assert_has_event(TestEvent::Sudo(Event::SunsetScheduled { at: 5 }));
+		assert_ok!(Sudo::set_key(RuntimeOrigin::signed(1), 2));
+
+		Sudo::on_initialize(4);
+		assert_eq!(Sudo::key(), Some(1u64));
+
+		Sudo::on_initialize(5);
+		System::assert_has_event(TestEvent::Sudo(Event::SunsetReached));
+		assert!(Sudo::key().is_none());
+
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+			i: 42,
+			weight: Weight::from_parts(1_000, 0),
+		}));
+		assert_noop!(Sudo::sudo(RuntimeOrigin::signed(1), call), Error::<Test>::RequireSudo);
+		assert_noop!(Sudo::accept_key(RuntimeOrigin::signed(2)), Error::<Test>::NotPendingKey);
+	});
+}
+
 #[test]
 fn sudo_as_basics() {
 	new_test_ext(1).execute_with(|| {
@@ -53,6 +53,10 @@ pub trait WeightInfo {
 	fn sudo() -> Weight;
 	fn sudo_as() -> Weight;
 	fn remove_key() -> Weight;
+	fn accept_key() -> Weight;
+	fn schedule_sunset() -> Weight;
+	fn check_sunset() -> Weight;
+	fn sunset() -> Weight;
 }
 
 /// Weights for `pallet_sudo` using the Substrate node and recommended hardware.
@@ -91,15 +95,70 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Sudo::Key` (r:1 w:1)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::PendingKey` (r:0 w:1)
+	/// Proof: `Sudo::PendingKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Sunset` (r:0 w:1)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn remove_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `165`
 		//  Estimated: `1517`
 		// Minimum execution time: 8_555_000 picoseconds.
 		Weight::from_parts(8_846_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:1)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::PendingKey` (r:1 w:1)
+	/// Proof: `Sudo::PendingKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn accept_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `1517`
+		// Minimum execution time: 11_100_000 picoseconds.
+		Weight::from_parts(11_576_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Sunset` (r:0 w:1)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn schedule_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_900_000 picoseconds.
+		Weight::from_parts(10_376_000, 1517)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Sudo::Sunset` (r:1 w:0)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn check_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1489`
+		// Minimum execution time: 2_006_000 picoseconds.
+		Weight::from_parts(2_131_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Sudo::Sunset` (r:1 w:1)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Key` (r:0 w:1)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::PendingKey` (r:0 w:1)
+	/// Proof: `Sudo::PendingKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1489`
+		// Minimum execution time: 10_600_000 picoseconds.
+		Weight::from_parts(11_076_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -137,13 +196,68 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Sudo::Key` (r:1 w:1)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::PendingKey` (r:0 w:1)
+	/// Proof: `Sudo::PendingKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Sunset` (r:0 w:1)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn remove_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `165`
 		//  Estimated: `1517`
 		// Minimum execution time: 8_555_000 picoseconds.
 		Weight::from_parts(8_846_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:1)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::PendingKey` (r:1 w:1)
+	/// Proof: `Sudo::PendingKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn accept_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `1517`
+		// Minimum execution time: 11_100_000 picoseconds.
+		Weight::from_parts(11_576_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Sunset` (r:0 w:1)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn schedule_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_900_000 picoseconds.
+		Weight::from_parts(10_376_000, 1517)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Sudo::Sunset` (r:1 w:0)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn check_sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1489`
+		// Minimum execution time: 2_006_000 picoseconds.
+		Weight::from_parts(2_131_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Sudo::Sunset` (r:1 w:1)
+	/// Proof: `Sudo::Sunset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Key` (r:0 w:1)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::PendingKey` (r:0 w:1)
+	/// Proof: `Sudo::PendingKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn sunset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1489`
+		// Minimum execution time: 10_600_000 picoseconds.
+		Weight::from_parts(11_076_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
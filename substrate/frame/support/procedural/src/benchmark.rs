@@ -781,16 +781,26 @@ fn expand_benchmark(
 				syn::Error::new(expr_span, "Extrinsic call must be a function call or `_`")
 					.to_compile_error()
 			};
-			let call_name = match *expr_call.func {
+			let (call_name, call_ty) = match *expr_call.func {
 				Expr::Path(expr_path) => {
 					// normal function call
-					let Some(segment) = expr_path.path.segments.last() else { return call_err() };
-					segment.ident.to_string()
+					let mut segments = expr_path.path.segments;
+					let Some(segment) = segments.pop() else { return call_err() };
+					let call_ty = if segments.is_empty() {
+						quote!(Call::<#type_use_generics>)
+					} else {
+						// call of another pallet, e.g. `pallet_balances::Call::<T>::transfer`:
+						// everything before the call name is the path of its `Call` enum
+						let leading_colon = expr_path.path.leading_colon;
+						let segments = segments.pairs().map(|pair| pair.value().clone());
+						quote!(#leading_colon #(#segments)::*)
+					};
+					(segment.into_value().ident.to_string(), call_ty)
 				},
 				Expr::Infer(_) => {
 					// `_` style
 					// replace `_` with fn name
-					name.to_string()
+					(name.to_string(), quote!(Call::<#type_use_generics>))
 				},
 				_ => return call_err(),
 			};
@@ -808,15 +818,15 @@ fn expand_benchmark(
 				path: Path { leading_colon: None, segments: punct },
 			});
 			let pre_call = quote! {
-				let __call = Call::<#type_use_generics>::#expr_call;
+				let __call = #call_ty::#expr_call;
 				let __benchmarked_call_encoded = #codec::Encode::encode(&__call);
 			};
 			let post_call = quote! {
-				let __call_decoded = <Call<#type_use_generics> as #codec::Decode>
+				let __call_decoded = <#call_ty as #codec::Decode>
 					::decode(&mut &__benchmarked_call_encoded[..])
 					.expect("call is encoded above, encoding must be correct");
 				let __origin = #origin;
-				<Call<#type_use_generics> as #traits::UnfilteredDispatchable>::dispatch_bypass_filter(
+				<#call_ty as #traits::UnfilteredDispatchable>::dispatch_bypass_filter(
 					__call_decoded,
 					__origin,
 				)
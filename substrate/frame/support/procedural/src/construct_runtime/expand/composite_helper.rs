@@ -15,14 +15,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License
 
-use crate::construct_runtime::parse::PalletPath;
+use crate::construct_runtime::parse::{PalletInstance, PalletPath};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 pub(crate) fn expand_conversion_fn(
 	composite_name: &str,
 	path: &PalletPath,
-	instance: Option<&Ident>,
+	instance: Option<&PalletInstance>,
 	variant_name: &Ident,
 ) -> TokenStream {
 	let composite_name = quote::format_ident!("{}", composite_name);
@@ -51,7 +51,7 @@ pub(crate) fn expand_variant(
 	composite_name: &str,
 	index: u8,
 	path: &PalletPath,
-	instance: Option<&Ident>,
+	instance: Option<&PalletInstance>,
 	variant_name: &Ident,
 ) -> TokenStream {
 	let composite_name = quote::format_ident!("{}", composite_name);
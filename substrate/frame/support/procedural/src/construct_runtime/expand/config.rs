@@ -30,6 +30,7 @@ pub fn expand_outer_config(
 	let mut types = TokenStream::new();
 	let mut fields = TokenStream::new();
 	let mut genesis_build_calls = TokenStream::new();
+	let mut genesis_check_calls = TokenStream::new();
 	let mut query_genesis_config_part_macros = Vec::new();
 
 	for decl in pallet_decls {
@@ -54,6 +55,13 @@ pub fn expand_outer_config(
 			fields.extend(quote!(#attr pub #field_name: #config,));
 			genesis_build_calls
 				.extend(expand_config_build_storage_call(scrate, &config, attr, field_name));
+			genesis_check_calls.extend(expand_config_check_call(
+				scrate,
+				&config,
+				attr,
+				field_name,
+				&pallet_name.to_string(),
+			));
 			query_genesis_config_part_macros.push(quote! {
 				#path::__substrate_genesis_config_check::is_genesis_config_defined!(#pallet_name);
 				#[cfg(feature = "std")]
@@ -86,6 +94,9 @@ pub fn expand_outer_config(
 				&self,
 				storage: &mut #scrate::sp_runtime::Storage,
 			) -> std::result::Result<(), String> {
+				<Self as #scrate::traits::CheckGenesisConfig>::check(&self).map_err(|errors| {
+					#scrate::traits::genesis_config_errors_message(&errors).to_string()
+				})?;
 				#scrate::__private::BasicExternalities::execute_with_storage(storage, || {
 					<Self as #scrate::traits::BuildGenesisConfig>::build(&self);
 					Ok(())
@@ -93,6 +104,23 @@ pub fn expand_outer_config(
 			}
 		}
 
+		impl #scrate::traits::CheckGenesisConfig for RuntimeGenesisConfig {
+			fn check(
+				&self,
+			) -> core::result::Result<
+				(),
+				#scrate::__private::sp_std::vec::Vec<#scrate::sp_runtime::RuntimeString>,
+			> {
+				let mut errors = #scrate::__private::sp_std::vec::Vec::new();
+				#genesis_check_calls
+				if errors.is_empty() {
+					Ok(())
+				} else {
+					Err(errors)
+				}
+			}
+		}
+
 		impl #scrate::traits::BuildGenesisConfig for RuntimeGenesisConfig {
 			fn build(&self) {
 				#genesis_build_calls
@@ -138,3 +166,18 @@ fn expand_config_build_storage_call(
 		<#pallet_genesis_config as #scrate::traits::BuildGenesisConfig>::build(&self.#field_name);
 	}
 }
+
+fn expand_config_check_call(
+	scrate: &TokenStream,
+	pallet_genesis_config: &Ident,
+	attr: &TokenStream,
+	field_name: &Ident,
+	pallet_name: &str,
+) -> TokenStream {
+	quote! {
+		#attr
+		if let Err(e) = <#pallet_genesis_config as #scrate::traits::CheckGenesisConfig>::check(&self.#field_name) {
+			errors.extend(e.iter().map(|e| #scrate::traits::prefix_genesis_config_error(#pallet_name, e)));
+		}
+	}
+}
@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License
 
-use crate::construct_runtime::{Pallet, SYSTEM_PALLET_NAME};
+use crate::construct_runtime::{parse::PalletInstance, Pallet, SYSTEM_PALLET_NAME};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::str::FromStr;
@@ -309,7 +309,7 @@ fn expand_origin_caller_variant(
 	runtime: &Ident,
 	pallet: &Pallet,
 	index: u8,
-	instance: Option<&Ident>,
+	instance: Option<&PalletInstance>,
 	generics: &Generics,
 ) -> TokenStream {
 	let part_is_generic = !generics.params.is_empty();
@@ -352,7 +352,7 @@ fn expand_origin_pallet_conversions(
 	scrate: &TokenStream,
 	runtime: &Ident,
 	pallet: &Pallet,
-	instance: Option<&Ident>,
+	instance: Option<&PalletInstance>,
 	generics: &Generics,
 ) -> TokenStream {
 	let path = &pallet.path;
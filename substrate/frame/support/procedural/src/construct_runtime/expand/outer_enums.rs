@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License
 
-use crate::construct_runtime::Pallet;
+use crate::construct_runtime::{parse::PalletInstance, Pallet};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use std::str::FromStr;
@@ -179,7 +179,7 @@ fn expand_enum_variant(
 	runtime: &Ident,
 	pallet: &Pallet,
 	index: u8,
-	instance: Option<&Ident>,
+	instance: Option<&PalletInstance>,
 	generics: &Generics,
 	enum_ty: OuterEnumType,
 ) -> TokenStream {
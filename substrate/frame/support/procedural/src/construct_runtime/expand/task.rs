@@ -0,0 +1,147 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License
+
+use crate::construct_runtime::Pallet;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use std::str::FromStr;
+
+/// Expands aggregate `RuntimeTask` enum.
+pub fn expand_outer_task(
+	runtime_name: &Ident,
+	pallet_decls: &[Pallet],
+	scrate: &TokenStream2,
+) -> TokenStream2 {
+	let mut from_impls = Vec::new();
+	let mut task_variants = Vec::new();
+	let mut variant_names = Vec::new();
+	let mut task_paths = Vec::new();
+	let mut pallet_attrs = Vec::new();
+	for decl in pallet_decls {
+		if decl.find_part("Task").is_none() {
+			continue
+		}
+
+		let variant_name = &decl.name;
+		let path = &decl.path;
+		let index = decl.index;
+		let attr = decl.cfg_pattern.iter().fold(TokenStream2::new(), |acc, pattern| {
+			let attr = TokenStream2::from_str(&format!("#[cfg({})]", pattern.original()))
+				.expect("was successfully parsed before; qed");
+			quote! {
+				#acc
+				#attr
+			}
+		});
+		let task_path = match decl.instance.as_ref() {
+			Some(inst) => quote!(#path::Task<#runtime_name, #path::#inst>),
+			None => quote!(#path::Task<#runtime_name>),
+		};
+
+		from_impls.push(quote! {
+			#attr
+			impl From<#task_path> for RuntimeTask {
+				fn from(hr: #task_path) -> Self {
+					RuntimeTask::#variant_name(hr)
+				}
+			}
+		});
+
+		task_variants.push(quote! {
+			#attr
+			#[codec(index = #index)]
+			#variant_name(#task_path),
+		});
+
+		variant_names.push(variant_name);
+		task_paths.push(task_path);
+		pallet_attrs.push(attr);
+	}
+
+	let prelude = quote!(#scrate::traits::tasks::__private);
+
+	quote! {
+		/// An aggregation of all `Task` enums across all pallets included in the current runtime.
+		#[derive(
+			Clone, Eq, PartialEq,
+			#scrate::__private::codec::Encode,
+			#scrate::__private::codec::Decode,
+			#scrate::__private::scale_info::TypeInfo,
+			#scrate::__private::RuntimeDebug,
+		)]
+		pub enum RuntimeTask {
+			#( #task_variants )*
+		}
+
+		impl #scrate::traits::Task for RuntimeTask {
+			type Enumeration = #prelude::IntoIter<RuntimeTask>;
+
+			fn iter() -> Self::Enumeration {
+				#[allow(unused_mut)]
+				let mut all_tasks = #prelude::vec![];
+				#(
+					#pallet_attrs
+					all_tasks.extend(
+						<#task_paths as #scrate::traits::Task>::iter().map(RuntimeTask::from)
+					);
+				)*
+				all_tasks.into_iter()
+			}
+
+			fn is_valid(&self) -> bool {
+				match *self {
+					#(
+						#pallet_attrs
+						RuntimeTask::#variant_names(ref task) => task.is_valid(),
+					)*
+				}
+			}
+
+			fn run(&self) -> Result<(), #prelude::DispatchError> {
+				match *self {
+					#(
+						#pallet_attrs
+						RuntimeTask::#variant_names(ref task) => task.run(),
+					)*
+				}
+			}
+
+			fn weight(&self) -> #prelude::Weight {
+				match *self {
+					#(
+						#pallet_attrs
+						RuntimeTask::#variant_names(ref task) => task.weight(),
+					)*
+				}
+			}
+
+			fn task_index(&self) -> u32 {
+				match *self {
+					#(
+						#pallet_attrs
+						RuntimeTask::#variant_names(ref task) => task.task_index(),
+					)*
+				}
+			}
+		}
+
+		impl #scrate::traits::tasks::RuntimeTasks for #runtime_name {
+		}
+
+		#( #from_impls )*
+	}
+}
@@ -18,7 +18,10 @@
 use frame_support_procedural_tools::syn_ext as ext;
 use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
-use std::collections::{HashMap, HashSet};
+use std::{
+	collections::{HashMap, HashSet},
+	ops::RangeInclusive,
+};
 use syn::{
 	ext::IdentExt,
 	parse::{Parse, ParseStream},
@@ -44,11 +47,16 @@ mod keyword {
 	syn::custom_keyword!(HoldReason);
 	syn::custom_keyword!(LockId);
 	syn::custom_keyword!(SlashReason);
+	syn::custom_keyword!(Task);
 	syn::custom_keyword!(exclude_parts);
 	syn::custom_keyword!(use_parts);
 	syn::custom_keyword!(expanded);
+	syn::custom_keyword!(reserve_indices);
 }
 
+/// The attribute allowing a pallet to be placed at an index reserved by `reserve_indices`.
+const UNRESERVE_INDEX_ATTR: &str = "unreserve_index";
+
 /// Declaration of a runtime.
 ///
 /// Pallet declare their part either explicitly or implicitly (using no part declaration)
@@ -91,11 +99,19 @@ impl Parse for RuntimeDeclaration {
 
 		let name = input.parse::<syn::Ident>()?;
 		let where_section = if input.peek(token::Where) { Some(input.parse()?) } else { None };
-		let pallets =
-			input.parse::<ext::Braces<ext::Punctuated<PalletDeclaration, Token![,]>>>()?;
-		let pallets_token = pallets.token;
+		let entries = input.parse::<ext::Braces<ext::Punctuated<RuntimeEntry, Token![,]>>>()?;
+		let pallets_token = entries.token;
+
+		let mut pallets = Vec::new();
+		let mut reserved_indices = Vec::new();
+		for entry in entries.content.inner {
+			match entry {
+				RuntimeEntry::Pallet(pallet) => pallets.push(pallet),
+				RuntimeEntry::ReservedIndices(reserved) => reserved_indices.push(reserved),
+			}
+		}
 
-		match convert_pallets(pallets.content.inner.into_iter().collect())? {
+		match convert_pallets(pallets, &reserved_indices)? {
 			PalletsConversion::Implicit(pallets) =>
 				Ok(RuntimeDeclaration::Implicit(ImplicitRuntimeDeclaration {
 					name,
@@ -197,6 +213,71 @@ impl Parse for WhereDefinition {
 	}
 }
 
+/// An entry of the runtime declaration, either a pallet or a range of reserved indices.
+enum RuntimeEntry {
+	Pallet(PalletDeclaration),
+	ReservedIndices(ReservedIndices),
+}
+
+impl Parse for RuntimeEntry {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if input.peek(keyword::reserve_indices) && !input.peek2(Token![:]) {
+			input.parse().map(Self::ReservedIndices)
+		} else {
+			input.parse().map(Self::Pallet)
+		}
+	}
+}
+
+/// A range of pallet indices reserved for future use, e.g. `reserve_indices 50..60,`.
+///
+/// No pallet can be assigned an index within a reserved range, neither explicitly nor implicitly,
+/// unless its index is explicitly unreserved with `#[unreserve_index]`.
+#[derive(Debug, Clone)]
+pub struct ReservedIndices {
+	/// The span of the `reserve_indices` keyword.
+	pub span: Span,
+	/// The reserved indices.
+	pub range: RangeInclusive<u8>,
+}
+
+impl Parse for ReservedIndices {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let keyword = input.parse::<keyword::reserve_indices>()?;
+		let start = input.parse::<syn::LitInt>()?;
+		let inclusive = if input.peek(Token![..=]) {
+			input.parse::<Token![..=]>()?;
+			true
+		} else {
+			input.parse::<Token![..]>()?;
+			false
+		};
+		let end = input.parse::<syn::LitInt>()?;
+		let span = keyword.span;
+
+		let start_index = start.base10_parse::<u8>()?;
+		let range = if inclusive {
+			start_index..=end.base10_parse::<u8>()?
+		} else {
+			// The exclusive end may be 256, so that index 255 can be reserved as well.
+			let end_index = end.base10_parse::<u16>()?;
+			if end_index > u8::MAX as u16 + 1 {
+				return Err(Error::new(end.span(), "Reserved index doesn't fit into u8"))
+			}
+			match end_index.checked_sub(1) {
+				Some(last) => start_index..=last as u8,
+				None => return Err(Error::new(span, "Reserved index range is empty")),
+			}
+		};
+
+		if range.is_empty() {
+			return Err(Error::new(span, "Reserved index range is empty"))
+		}
+
+		Ok(Self { span, range })
+	}
+}
+
 /// The declaration of a pallet.
 #[derive(Debug, Clone)]
 pub struct PalletDeclaration {
@@ -211,7 +292,7 @@ pub struct PalletDeclaration {
 	/// The path of the pallet, e.g. `frame_system` in `System: frame_system`.
 	pub path: PalletPath,
 	/// The instance of the pallet, e.g. `Instance1` in `Council: pallet_collective::<Instance1>`.
-	pub instance: Option<Ident>,
+	pub instance: Option<PalletInstance>,
 	/// The declared pallet parts,
 	/// e.g. `Some([Pallet, Call])` for `System: system::{Pallet, Call}`
 	/// or `None` for `System: system`.
@@ -406,6 +487,7 @@ pub enum PalletPartKeyword {
 	HoldReason(keyword::HoldReason),
 	LockId(keyword::LockId),
 	SlashReason(keyword::SlashReason),
+	Task(keyword::Task),
 }
 
 impl Parse for PalletPartKeyword {
@@ -438,6 +520,8 @@ impl Parse for PalletPartKeyword {
 			Ok(Self::LockId(input.parse()?))
 		} else if lookahead.peek(keyword::SlashReason) {
 			Ok(Self::SlashReason(input.parse()?))
+		} else if lookahead.peek(keyword::Task) {
+			Ok(Self::Task(input.parse()?))
 		} else {
 			Err(lookahead.error())
 		}
@@ -461,6 +545,7 @@ impl PalletPartKeyword {
 			Self::HoldReason(_) => "HoldReason",
 			Self::LockId(_) => "LockId",
 			Self::SlashReason(_) => "SlashReason",
+			Self::Task(_) => "Task",
 		}
 	}
 
@@ -491,6 +576,7 @@ impl ToTokens for PalletPartKeyword {
 			Self::HoldReason(inner) => inner.to_tokens(tokens),
 			Self::LockId(inner) => inner.to_tokens(tokens),
 			Self::SlashReason(inner) => inner.to_tokens(tokens),
+			Self::Task(inner) => inner.to_tokens(tokens),
 		}
 	}
 }
@@ -582,6 +668,45 @@ fn parse_pallet_parts_no_generic(input: ParseStream) -> Result<Vec<PalletPartNoG
 	Ok(pallet_parts.content.inner.into_iter().collect())
 }
 
+/// The instance of a pallet, as declared, e.g. `Instance1` in
+/// `Council: pallet_collective::<Instance1>` or `IndexedInstance<17>` in
+/// `Council17: pallet_collective::<IndexedInstance<17>>`.
+///
+/// The instance is expanded as declared, relative to the path of the pallet.
+#[derive(Debug, Clone)]
+pub struct PalletInstance {
+	/// The name of the instance, e.g. `Instance1` or `IndexedInstance`.
+	pub name: Ident,
+	/// The const-generic index of the instance, e.g. `17` in `IndexedInstance<17>`.
+	pub index: Option<syn::LitInt>,
+}
+
+impl Parse for PalletInstance {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let name = input.parse()?;
+		let index = if input.peek(Token![<]) {
+			let _: Token![<] = input.parse()?;
+			let index = input.parse()?;
+			let _: Token![>] = input.parse()?;
+			Some(index)
+		} else {
+			None
+		};
+
+		Ok(Self { name, index })
+	}
+}
+
+impl ToTokens for PalletInstance {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let name = &self.name;
+		match &self.index {
+			Some(index) => tokens.extend(quote::quote!(#name<#index>)),
+			None => name.to_tokens(tokens),
+		}
+	}
+}
+
 /// The final definition of a pallet with the resulting fixed index and explicit parts.
 #[derive(Debug, Clone)]
 pub struct Pallet {
@@ -594,7 +719,7 @@ pub struct Pallet {
 	/// The path of the pallet, e.g. `frame_system` in `System: frame_system`.
 	pub path: PalletPath,
 	/// The instance of the pallet, e.g. `Instance1` in `Council: pallet_collective::<Instance1>`.
-	pub instance: Option<Ident>,
+	pub instance: Option<PalletInstance>,
 	/// The pallet parts to use for the pallet.
 	pub pallet_parts: Vec<PalletPart>,
 	/// Expressions specified inside of a #[cfg] attribute.
@@ -655,7 +780,13 @@ enum PalletsConversion {
 /// Check if all pallet have explicit declaration of their parts, if so then assign index to each
 /// pallet using same rules as rust for fieldless enum. I.e. implicit are assigned number
 /// incrementedly from last explicit or 0.
-fn convert_pallets(pallets: Vec<PalletDeclaration>) -> syn::Result<PalletsConversion> {
+///
+/// Fails if any pallet is assigned an index within one of the `reserved_indices`, unless the pallet
+/// is annotated with `#[unreserve_index]`.
+fn convert_pallets(
+	pallets: Vec<PalletDeclaration>,
+	reserved_indices: &[ReservedIndices],
+) -> syn::Result<PalletsConversion> {
 	if pallets.iter().any(|pallet| pallet.pallet_parts.is_none()) {
 		return Ok(PalletsConversion::Implicit(pallets))
 	}
@@ -678,6 +809,38 @@ fn convert_pallets(pallets: Vec<PalletDeclaration>) -> syn::Result<PalletsConver
 
 			last_index = Some(final_index);
 
+			let unreserve_index =
+				pallet.attrs.iter().find(|attr| attr.path().is_ident(UNRESERVE_INDEX_ATTR));
+			if let Some(attr) = unreserve_index {
+				attr.meta.require_path_only()?;
+				if pallet.index.is_none() {
+					let msg = "`#[unreserve_index]` requires an explicit pallet index";
+					return Err(syn::Error::new(attr.span(), msg))
+				}
+			}
+
+			let reserved =
+				reserved_indices.iter().find(|reserved| reserved.range.contains(&final_index));
+			match (reserved, unreserve_index) {
+				(Some(reserved), None) => {
+					let msg = format!(
+						"Pallet {} is at index {}, which is reserved by `reserve_indices`",
+						pallet.name, final_index,
+					);
+					let mut err = syn::Error::new(pallet.name.span(), &msg);
+					err.combine(syn::Error::new(reserved.span, msg));
+					return Err(err)
+				},
+				(None, Some(attr)) => {
+					let msg = format!(
+						"Pallet {} is marked `#[unreserve_index]`, but its index {} is not reserved",
+						pallet.name, final_index,
+					);
+					return Err(syn::Error::new(attr.span(), msg))
+				},
+				_ => (),
+			}
+
 			if let Some(used_pallet) = indices.insert(final_index, pallet.name.clone()) {
 				let msg = format!(
 					"Pallet indices are conflicting: Both pallets {} and {} are at index {}",
@@ -741,10 +904,11 @@ fn convert_pallets(pallets: Vec<PalletDeclaration>) -> syn::Result<PalletsConver
 			let cfg_pattern = pallet
 				.attrs
 				.iter()
+				.filter(|attr| !attr.path().is_ident(UNRESERVE_INDEX_ATTR))
 				.map(|attr| {
 					if attr.path().segments.first().map_or(false, |s| s.ident != "cfg") {
-						let msg = "Unsupported attribute, only #[cfg] is supported on pallet \
-						declarations in `construct_runtime`";
+						let msg = "Unsupported attribute, only #[cfg] and #[unreserve_index] are \
+						supported on pallet declarations in `construct_runtime`";
 						return Err(syn::Error::new(attr.span(), msg))
 					}
 
@@ -29,6 +29,7 @@ mod match_and_insert;
 mod no_bound;
 mod pallet;
 mod pallet_error;
+mod parameter_preset;
 mod storage_alias;
 mod transactional;
 mod tt_macro;
@@ -171,6 +172,25 @@ fn counter_prefix(prefix: &str) -> String {
 ///   pallet4 .., // Here pallet4 is given index 1
 ///   ```
 ///
+/// Besides pallets, ranges of indices can be reserved for future use with
+/// `reserve_indices $start..$end,` or `reserve_indices $start..=$end,`. A pallet which is
+/// assigned an index within a reserved range, either explicitly or implicitly, results in a
+/// compile error:
+/// ```nocompile
+/// pallet1 .. = 49,
+/// reserve_indices 50..60,
+/// pallet2 .., // Error: pallet2 would be given the reserved index 50
+/// pallet3 .. = 60,
+/// ```
+///
+/// A pallet can still be placed within a reserved range by explicitly unreserving its index with
+/// the `#[unreserve_index]` attribute. This requires the pallet to have an explicit index:
+/// ```nocompile
+/// reserve_indices 50..60,
+/// #[unreserve_index]
+/// pallet2 .. = 50,
+/// ```
+///
 /// # Note
 ///
 /// The population of the genesis storage depends on the order of pallets. So, if one of your
@@ -492,8 +512,8 @@ pub fn crate_to_crate_version(input: TokenStream) -> TokenStream {
 		.into()
 }
 
-/// The number of module instances supported by the runtime, starting at index 1,
-/// and up to `NUMBER_OF_INSTANCE`.
+/// The number of named module instances supported by the runtime, starting at index 1,
+/// and up to `NUMBER_OF_INSTANCE`. Higher instances are declared as `IndexedInstance<N>`.
 pub(crate) const NUMBER_OF_INSTANCE: u8 = 16;
 
 /// This macro is meant to be used by frame-support only.
@@ -754,10 +774,7 @@ pub fn storage_alias(attributes: TokenStream, input: TokenStream) -> TokenStream
 #[import_tokens_attr_verbatim {
     format!(
         "{}::macro_magic",
-        match generate_access_from_frame_or_crate("frame-support") {
-            Ok(path) => Ok(path),
-            Err(_) => generate_access_from_frame_or_crate("frame"),
-        }
+        generate_access_from_frame_or_crate("frame-support")
         .expect("Failed to find either `frame-support` or `frame` in `Cargo.toml` dependencies.")
         .to_token_stream()
         .to_string()
@@ -875,6 +892,54 @@ pub fn register_default_impl(attrs: TokenStream, tokens: TokenStream) -> TokenSt
 	}
 }
 
+/// Declares a named bag of [`TypedGet`](frame_support::traits::TypedGet) parameters that can be
+/// injected into a trait impl via [`#[derive_impl(..)]`](`macro@derive_impl`).
+///
+/// This is meant for parameter sets that are shared by many mock runtimes, e.g. the balances
+/// parameters of a test network, so that they don't have to be redeclared with
+/// `parameter_types!` in each of them:
+///
+/// ```ignore
+/// frame_support::parameter_preset! {
+///     /// Balances parameters used by test networks.
+///     pub struct TestnetBalancesParams: TestnetBalancesPreset {
+///         ExistentialDeposit: u64 = 1;
+///         MaxLocks: u32 = 50;
+///     }
+/// }
+/// ```
+///
+/// The macro generates:
+///
+/// * the unit struct `TestnetBalancesParams`;
+/// * the trait `TestnetBalancesPreset` with one associated type per parameter, each bounded by
+///   `TypedGet` and `Get` of the declared type;
+/// * one parameter type per entry, declared through `parameter_types!` in a hidden module;
+/// * an impl of `TestnetBalancesPreset` for `TestnetBalancesParams`, registered the same way as
+///   [`#[register_default_impl]`](`macro@register_default_impl`) does.
+///
+/// The preset can then be used with `derive_impl`, qualified by the generated trait:
+///
+/// ```ignore
+/// #[derive_impl(presets::TestnetBalancesParams as presets::TestnetBalancesPreset)]
+/// impl pallet_balances::Config for Test {
+///     type Balance = u64;
+///     // ...
+/// }
+/// ```
+///
+/// Every parameter of the preset is copied into the impl, so the preset must only contain items
+/// of the implemented trait. Items declared in the impl itself take precedence. A preset can be
+/// stacked on top of a `DefaultConfig` by placing its `derive_impl` attribute first, in which case
+/// the preset's parameters take precedence over the ones of the `DefaultConfig`.
+#[proc_macro]
+pub fn parameter_preset(input: TokenStream) -> TokenStream {
+	let def = parse_macro_input!(input as parameter_preset::ParameterPresetDef);
+	parameter_preset::expand_parameter_preset(def)
+		.unwrap_or_else(|e| e.into_compile_error())
+		.into()
+}
+
 #[proc_macro_attribute]
 pub fn inject_runtime_type(_: TokenStream, tokens: TokenStream) -> TokenStream {
 	let item = tokens.clone();
@@ -1098,7 +1163,7 @@ pub fn weight(_: TokenStream, _: TokenStream) -> TokenStream {
 }
 
 /// Compact encoding for arguments can be achieved via `#[pallet::compact]`. The function must
-/// return a `DispatchResultWithPostInfo` or `DispatchResult`.
+/// return a `DispatchResultWithPostInfo`, `DispatchResult` or `RefundIfSuccessful`.
 #[proc_macro_attribute]
 pub fn compact(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
@@ -1150,8 +1215,9 @@ pub fn call(_: TokenStream, _: TokenStream) -> TokenStream {
 /// `Encode`, `Decode`, `GetDispatchInfo`, `GetCallName`, `GetCallIndex` and
 /// `UnfilteredDispatchable`.
 ///
-/// The macro implements the `Callable` trait on `Pallet` and a function `call_functions`
-/// which returns the dispatchable metadata.
+/// The macro implements the `Callable` trait on `Pallet`, a function `call_functions` which
+/// returns the dispatchable metadata and a function `feature_gated_calls` which returns the
+/// dispatchables gated behind a feature flag with the current state of their flag.
 #[proc_macro_attribute]
 pub fn call_index(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
@@ -1187,6 +1253,35 @@ pub fn feeless_if(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
 
+/// Each dispatchable may be annotated with the `#[pallet::feature_gated($type)]` attribute,
+/// which makes the dispatchable only callable while the given feature flag is enabled.
+///
+/// The type must implement `Get<bool>`. Using a storage-backed parameter (for example one
+/// declared with `parameter_types! { pub storage ... }`) lets governance enable dormant
+/// functionality without a runtime upgrade.
+///
+/// ### Example
+/// ```ignore
+/// #[pallet::feature_gated(T::ExperimentalEnabled)]
+/// pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
+///     ....
+/// }
+/// ```
+///
+/// ### Macro expansion
+///
+/// While the flag returns `false`, the dispatchable function fails with the `FeatureDisabled`
+/// variant, which is added to the `Error` of the pallet, before executing its body. This holds
+/// whether the function is dispatched or called directly, e.g. by another pallet.
+///
+/// The documentation of the call, which is part of the metadata, names the flag. The current
+/// state of the flags is returned by `Pallet::feature_gated_calls`, as the flags may change
+/// without the metadata being regenerated.
+#[proc_macro_attribute]
+pub fn feature_gated(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 /// Allows you to define some extra constants to be added into constant metadata.
 ///
 /// Item must be defined as:
@@ -1440,6 +1535,16 @@ pub fn genesis_build(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
 
+///
+/// ---
+///
+/// **Rust-Analyzer users**: See the documentation of the Rust item in
+/// `frame_support::pallet_macros::genesis_check`.
+#[proc_macro_attribute]
+pub fn genesis_check(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 /// The `#[pallet::inherent]` attribute allows the pallet to provide some
 /// [inherent](https://docs.substrate.io/fundamentals/transaction-types/#inherent-transactions).
 /// An inherent is some piece of data that is inserted by a block authoring node at block
@@ -1546,6 +1651,76 @@ pub fn composite_enum(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
 
+/// The `#[pallet::tasks_experimental]` attribute allows you to declare the tasks of a pallet, i.e.
+/// conditional and idempotent pieces of work which anyone can request to be run through
+/// `frame_system::Call::do_task`, if the `experimental` feature of `frame-system` is enabled.
+/// The tasks of all pallets are aggregated into the `RuntimeTask` enum by `construct_runtime`.
+///
+/// The attribute must be attached to an `impl<T: Config> Pallet<T>` block. Every function of
+/// the block is a task and must be public, return `DispatchResult` and be annotated with
+/// [`#[pallet::task_list]`](`macro@task_list`),
+/// [`#[pallet::task_condition]`](`macro@task_condition`), [`#[pallet::task_weight]`](`macro@
+/// task_weight`) and [`#[pallet::task_index]`](`macro@task_index`).
+///
+/// ```ignore
+/// #[pallet::tasks_experimental]
+/// impl<T: Config> Pallet<T> {
+///     #[pallet::task_list(Numbers::<T>::iter_keys())]
+///     #[pallet::task_condition(|i| Numbers::<T>::contains_key(i))]
+///     #[pallet::task_weight(T::WeightInfo::add_number_into_total())]
+///     #[pallet::task_index(0)]
+///     pub fn add_number_into_total(i: u32) -> DispatchResult {
+///         let v = Numbers::<T>::take(i).ok_or(Error::<T>::NotFound)?;
+///         Total::<T>::mutate(|total| *total += v);
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// The macro generates a `Task` enum with a variant per task, named after the function in
+/// `UpperCamelCase`, which implements [`frame_support::traits::Task`].
+///
+/// NOTE: This feature is experimental and its API may change.
+#[proc_macro_attribute]
+pub fn tasks_experimental(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
+/// Allows defining an iterator over the arguments of all potential tasks of a task function in
+/// a [`#[pallet::tasks_experimental]`](`macro@tasks_experimental`) block.
+///
+/// For tasks taking more than one argument, the items are expected to be tuples.
+#[proc_macro_attribute]
+pub fn task_list(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
+/// Allows defining the condition under which a task of a
+/// [`#[pallet::tasks_experimental]`](`macro@tasks_experimental`) block is valid, i.e. has work
+/// pending.
+///
+/// The condition is a closure which takes references to the arguments of the task and returns a
+/// `bool`.
+#[proc_macro_attribute]
+pub fn task_condition(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
+/// Allows defining the weight of a task of a
+/// [`#[pallet::tasks_experimental]`](`macro@tasks_experimental`) block.
+#[proc_macro_attribute]
+pub fn task_weight(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
+/// Allows defining the index of a task of a
+/// [`#[pallet::tasks_experimental]`](`macro@tasks_experimental`) block. The index must be unique
+/// within the pallet and is used to encode the task, similar to `#[pallet::call_index]`.
+#[proc_macro_attribute]
+pub fn task_index(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 /// Can be attached to a module. Doing so will declare that module as importable into a pallet
 /// via [`#[import_section]`](`macro@import_section`).
 ///
@@ -1614,10 +1789,7 @@ pub fn pallet_section(attr: TokenStream, tokens: TokenStream) -> TokenStream {
 #[import_tokens_attr {
     format!(
         "{}::macro_magic",
-        match generate_access_from_frame_or_crate("frame-support") {
-            Ok(path) => Ok(path),
-            Err(_) => generate_access_from_frame_or_crate("frame"),
-        }
+        generate_access_from_frame_or_crate("frame-support")
         .expect("Failed to find either `frame-support` or `frame` in `Cargo.toml` dependencies.")
         .to_token_stream()
         .to_string()
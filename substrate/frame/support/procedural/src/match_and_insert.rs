@@ -40,8 +40,8 @@ pub fn match_and_insert(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 struct MatchAndInsertDef {
 	// Token stream to search and insert tokens into.
 	target: TokenStream,
-	// Pattern to match against, this is ensured to have no TokenTree::Group nor TokenTree::Literal
-	// (i.e. contains only Punct or Ident), and not being empty.
+	// Pattern to match against, this is ensured to have no TokenTree::Group (i.e. contains only
+	// Punct, Ident or Literal), and not being empty.
 	pattern: Vec<TokenTree>,
 	// Token stream to insert after the match pattern.
 	tokens: TokenStream,
@@ -66,9 +66,6 @@ impl syn::parse::Parse for MatchAndInsertDef {
 		if let Some(t) = pattern.iter().find(|t| matches!(t, TokenTree::Group(_))) {
 			return Err(syn::Error::new(t.span(), "Unexpected group token tree"))
 		}
-		if let Some(t) = pattern.iter().find(|t| matches!(t, TokenTree::Literal(_))) {
-			return Err(syn::Error::new(t.span(), "Unexpected literal token tree"))
-		}
 
 		if pattern.is_empty() {
 			return Err(syn::Error::new(Span::call_site(), "empty match pattern is invalid"))
@@ -87,7 +84,7 @@ impl syn::parse::Parse for MatchAndInsertDef {
 
 // Insert `tokens` after the first matching `pattern`.
 // `tokens` must be some (Option is used for internal simplification).
-// `pattern` must not be empty and should only contain Ident or Punct.
+// `pattern` must not be empty and should only contain Ident, Punct or Literal.
 fn expand_in_stream(
 	pattern: &[TokenTree],
 	tokens: &mut Option<TokenStream>,
@@ -143,11 +140,14 @@ fn expand_in_stream(
 }
 
 fn advance_match_cursor(other: &TokenTree, pattern: &[TokenTree], match_cursor: &mut usize) {
-	use TokenTree::{Ident, Punct};
+	use TokenTree::{Ident, Literal, Punct};
 
 	let does_match_other_pattern = match (other, &pattern[*match_cursor]) {
 		(Ident(i1), Ident(i2)) => i1 == i2,
 		(Punct(p1), Punct(p2)) => p1.as_char() == p2.as_char(),
+		// Literals in patterns are the const-generic indices of instances, e.g. `17` in
+		// `IndexedInstance<17>`.
+		(Literal(l1), Literal(l2)) => l1.to_string() == l2.to_string(),
 		_ => false,
 	};
 
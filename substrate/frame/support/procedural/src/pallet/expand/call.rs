@@ -52,6 +52,13 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 	let pallet_ident = &def.pallet_struct.pallet;
 
 	let fn_name = methods.iter().map(|method| &method.name).collect::<Vec<_>>();
+	let cfg_attrs = methods
+		.iter()
+		.map(|method| {
+			let attrs = &method.cfg_attrs;
+			quote!( #( #attrs )* )
+		})
+		.collect::<Vec<_>>();
 	let call_index = methods.iter().map(|method| method.call_index).collect::<Vec<_>>();
 	let new_call_variant_fn_name = fn_name
 		.iter()
@@ -219,8 +226,22 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 	{
 		item_impl.items.iter_mut().for_each(|i| {
 			if let syn::ImplItem::Fn(method) = i {
+				// Dispatchables gated behind a feature flag bail out while the flag is disabled,
+				// whether they are dispatched or called directly.
+				let feature_gate_check = methods
+					.iter()
+					.find(|m| m.name == method.sig.ident)
+					.and_then(|m| m.feature_gate.as_ref())
+					.map(|flag| {
+						quote::quote!(
+							if !<#flag as #frame_support::traits::Get<bool>>::get() {
+								return Err(Error::<#type_use_gen>::FeatureDisabled.into())
+							}
+						)
+					});
 				let block = &method.block;
 				method.block = syn::parse_quote! {{
+					#feature_gate_check
 					// We execute all dispatchable in a new storage layer, allowing them
 					// to return an error at any point, and undoing any storage changes.
 					#frame_support::storage::with_storage_layer(|| #block)
@@ -251,6 +272,33 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		});
 
+	// Dispatchables gated behind a feature flag name the flag in their documentation, which is
+	// part of the metadata, while its current state is queried at runtime.
+	let feature_gate_doc = methods.iter().map(|method| match &method.feature_gate {
+		Some(flag) => {
+			let doc = format!(
+				" Only callable while the feature flag `{}` is enabled.",
+				flag.to_token_stream().to_string().replace(' ', ""),
+			);
+			quote::quote!(#[doc = #doc])
+		},
+		None => quote::quote!(),
+	});
+	let feature_gated_fn_name = methods
+		.iter()
+		.filter(|method| method.feature_gate.is_some())
+		.map(|method| &method.name)
+		.collect::<Vec<_>>();
+	let feature_gated_cfg_attrs = methods
+		.iter()
+		.filter(|method| method.feature_gate.is_some())
+		.map(|method| {
+			let attrs = &method.cfg_attrs;
+			quote!( #( #attrs )* )
+		})
+		.collect::<Vec<_>>();
+	let feature_gate = methods.iter().filter_map(|method| method.feature_gate.as_ref());
+
 	quote::quote_spanned!(span =>
 		mod warnings {
 			#(
@@ -297,7 +345,9 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 				#frame_support::Never,
 			),
 			#(
+				#cfg_attrs
 				#[doc = #fn_doc]
+				#feature_gate_doc
 				#[codec(index = #call_index)]
 				#fn_name {
 					#(
@@ -310,6 +360,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 
 		impl<#type_impl_gen> #call_ident<#type_use_gen> #where_clause {
 			#(
+				#cfg_attrs
 				#[doc = #new_call_variant_doc]
 				pub fn #new_call_variant_fn_name(
 					#( #args_name_stripped: #args_type ),*
@@ -328,6 +379,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			fn get_dispatch_info(&self) -> #frame_support::dispatch::DispatchInfo {
 				match *self {
 					#(
+						#cfg_attrs
 						Self::#fn_name { #( #args_name_pattern_ref, )* } => {
 							let __pallet_base_weight = #fn_weight;
 
@@ -365,6 +417,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			fn is_feeless(&self, origin: &Self::Origin) -> bool {
 				match *self {
 					#(
+						#cfg_attrs
 						Self::#fn_name { #( #args_name_pattern_ref, )* } => {
 							#feeless_check_result
 						},
@@ -379,13 +432,13 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		{
 			fn get_call_name(&self) -> &'static str {
 				match *self {
-					#( Self::#fn_name { .. } => stringify!(#fn_name), )*
+					#( #cfg_attrs Self::#fn_name { .. } => stringify!(#fn_name), )*
 					Self::__Ignore(_, _) => unreachable!("__PhantomItem cannot be used."),
 				}
 			}
 
 			fn get_call_names() -> &'static [&'static str] {
-				&[ #( stringify!(#fn_name), )* ]
+				&[ #( #cfg_attrs stringify!(#fn_name), )* ]
 			}
 		}
 
@@ -394,13 +447,13 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		{
 			fn get_call_index(&self) -> u8 {
 				match *self {
-					#( Self::#fn_name { .. } => #call_index, )*
+					#( #cfg_attrs Self::#fn_name { .. } => #call_index, )*
 					Self::__Ignore(_, _) => unreachable!("__PhantomItem cannot be used."),
 				}
 			}
 
 			fn get_call_indices() -> &'static [u8] {
-				&[ #( #call_index, )* ]
+				&[ #( #cfg_attrs #call_index, )* ]
 			}
 		}
 
@@ -416,6 +469,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 				#frame_support::dispatch_context::run_in_context(|| {
 					match self {
 						#(
+							#cfg_attrs
 							Self::#fn_name { #( #args_name_pattern, )* } => {
 								#frame_support::__private::sp_tracing::enter_span!(
 									#frame_support::__private::sp_tracing::trace_span!(stringify!(#fn_name))
@@ -445,6 +499,21 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			pub fn call_functions() -> #frame_support::__private::metadata_ir::PalletCallMetadataIR {
 				#frame_support::__private::scale_info::meta_type::<#call_ident<#type_use_gen>>().into()
 			}
+
+			/// The dispatchables gated behind a feature flag, and whether their flag is currently
+			/// enabled.
+			pub fn feature_gated_calls() -> #frame_support::__private::sp_std::vec::Vec<(&'static str, bool)> {
+				#[allow(unused_mut)]
+				let mut calls = #frame_support::__private::sp_std::vec::Vec::new();
+				#(
+					#feature_gated_cfg_attrs
+					calls.push((
+						stringify!(#feature_gated_fn_name),
+						<#feature_gate as #frame_support::traits::Get<bool>>::get(),
+					));
+				)*
+				calls
+			}
 		}
 	)
 }
@@ -33,9 +33,11 @@ pub fn expand_doc_only(def: &mut Def) -> proc_macro2::TokenStream {
 					.map(|(_, arg_name, arg_type)| quote::quote!( #arg_name: #arg_type, ))
 					.collect::<proc_macro2::TokenStream>();
 				let docs = &method.docs;
+				let cfg_attrs = &method.cfg_attrs;
 
 				let real = format!(" [`Pallet::{}`].", name);
 				quote::quote!(
+					#( #cfg_attrs )*
 					#( #[doc = #docs] )*
 					///
 					/// # Warning: Doc-Only
@@ -67,6 +67,18 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 		)
 	);
 
+	// Dispatchables gated behind a feature flag fail with this error while it is disabled.
+	let feature_disabled = syn::Ident::new("FeatureDisabled", error.attr_span);
+	let add_feature_disabled = def
+		.call
+		.as_ref()
+		.map_or(false, |call| call.methods.iter().any(|method| method.feature_gate.is_some())) &&
+		!error.variants.iter().any(|(variant, ..)| *variant == feature_disabled);
+	let feature_disabled_match = add_feature_disabled.then(|| {
+		let variant_str = feature_disabled.to_string();
+		quote::quote_spanned!(error.attr_span => Self::#feature_disabled => #variant_str,)
+	});
+
 	let as_str_matches = error.variants.iter().map(|(variant, field_ty, _)| {
 		let variant_str = variant.to_string();
 		match field_ty {
@@ -92,6 +104,12 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 	};
 
 	error_item.variants.insert(0, phantom_variant);
+	if add_feature_disabled {
+		error_item.variants.push(syn::parse_quote!(
+			/// The dispatchable is gated behind a feature flag which is currently disabled.
+			#feature_disabled
+		));
+	}
 
 	let capture_docs = if cfg!(feature = "no-metadata-docs") { "never" } else { "always" };
 
@@ -131,6 +149,7 @@ pub fn expand_error(def: &mut Def) -> proc_macro2::TokenStream {
 				match &self {
 					Self::__Ignore(_, _) => unreachable!("`__Ignore` can never be constructed"),
 					#( #as_str_matches )*
+					#feature_disabled_match
 				}
 			}
 		}
@@ -18,7 +18,8 @@
 use crate::pallet::Def;
 
 ///
-/// * implement the trait `sp_runtime::BuildStorage`
+/// * implement the trait `sp_runtime::BuildStorage`, checking the config first
+/// * implement the trait `CheckGenesisConfig` with no checks if `pallet::genesis_check` is unused
 pub fn expand_genesis_build(def: &mut Def) -> proc_macro2::TokenStream {
 	let genesis_config = if let Some(genesis_config) = &def.genesis_config {
 		genesis_config
@@ -34,11 +35,25 @@ pub fn expand_genesis_build(def: &mut Def) -> proc_macro2::TokenStream {
 
 	let where_clause = &genesis_build.where_clause;
 
+	let default_genesis_check = if def.genesis_check.is_none() {
+		quote::quote_spanned!(genesis_build.attr_span =>
+			impl<#type_impl_gen> #frame_support::traits::CheckGenesisConfig
+				for #gen_cfg_ident<#gen_cfg_use_gen> #where_clause {}
+		)
+	} else {
+		Default::default()
+	};
+
 	quote::quote_spanned!(genesis_build.attr_span =>
+		#default_genesis_check
+
 		#[cfg(feature = "std")]
 			impl<#type_impl_gen> #frame_support::sp_runtime::BuildStorage for #gen_cfg_ident<#gen_cfg_use_gen> #where_clause
 			{
 				fn assimilate_storage(&self, storage: &mut #frame_support::sp_runtime::Storage) -> std::result::Result<(), std::string::String> {
+					<Self as #frame_support::traits::CheckGenesisConfig>::check(self).map_err(|errors| {
+						#frame_support::traits::genesis_config_errors_message(&errors).to_string()
+					})?;
 					#frame_support::__private::BasicExternalities::execute_with_storage(storage, || {
 						self.build();
 						Ok(())
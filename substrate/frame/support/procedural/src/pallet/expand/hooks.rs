@@ -90,6 +90,39 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	};
 
+	// The accesses to deprecated storage items noted in a block are folded into their counters
+	// in `on_finalize`, and in the next `on_initialize` for those which happened afterwards.
+	let (fold_deprecated_accesses, fold_deprecated_accesses_weight): (Vec<_>, Vec<_>) = def
+		.storages
+		.iter()
+		.filter(|storage| storage.deprecated)
+		.map(|storage| {
+			let cfg_attrs = &storage.cfg_attrs;
+			let storage_name = storage.prefix();
+			let fold = quote::quote! {
+				#( #cfg_attrs )*
+				#frame_support::storage::deprecation::fold_block_accesses(
+					#pallet_name,
+					#storage_name,
+				);
+			};
+			let weight = quote::quote! {
+				#( #cfg_attrs )*
+				{
+					weight = weight.saturating_add(
+						#frame_support::storage::deprecation::fold_block_accesses_weight(
+							<
+								<T as #frame_system::Config>::DbWeight
+								as #frame_support::traits::Get<_>
+							>::get()
+						).saturating_mul(2)
+					);
+				}
+			};
+			(fold, weight)
+		})
+		.unzip();
+
 	let hooks_impl = if def.hooks.is_none() {
 		let frame_system = &def.frame_system;
 		quote::quote! {
@@ -155,7 +188,8 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 					Self as #frame_support::traits::Hooks<
 						#frame_system::pallet_prelude::BlockNumberFor::<T>
 					>
-				>::on_finalize(n)
+				>::on_finalize(n);
+				#( #fold_deprecated_accesses )*
 			}
 		}
 
@@ -185,11 +219,15 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 				#frame_support::__private::sp_tracing::enter_span!(
 					#frame_support::__private::sp_tracing::trace_span!("on_initialize")
 				);
-				<
+				#( #fold_deprecated_accesses )*
+				#[allow(unused_mut)]
+				let mut weight = <
 					Self as #frame_support::traits::Hooks<
 						#frame_system::pallet_prelude::BlockNumberFor::<T>
 					>
-				>::on_initialize(n)
+				>::on_initialize(n);
+				#( #fold_deprecated_accesses_weight )*
+				weight
 			}
 		}
 
@@ -20,7 +20,7 @@ use proc_macro2::Span;
 
 ///
 /// * Provide inherent instance to be used by construct_runtime
-/// * Provide Instance1 ..= Instance16 for instantiable pallet
+/// * Provide Instance1 ..= Instance16 and IndexedInstance for instantiable pallet
 pub fn expand_instances(def: &mut Def) -> proc_macro2::TokenStream {
 	let frame_support = &def.frame_support;
 	let inherent_ident = syn::Ident::new(crate::INHERENT_INSTANCE_NAME, Span::call_site());
@@ -31,6 +31,10 @@ pub fn expand_instances(def: &mut Def) -> proc_macro2::TokenStream {
 	} else {
 		vec![]
 	};
+	let indexed_instance = def
+		.config
+		.has_instance
+		.then(|| syn::Ident::new("IndexedInstance", Span::call_site()));
 
 	quote::quote!(
 		/// Hidden instance generated to be internally used when module is used without
@@ -39,5 +43,6 @@ pub fn expand_instances(def: &mut Def) -> proc_macro2::TokenStream {
 		pub type #inherent_ident = ();
 
 		#( pub use #frame_support::instances::#instances; )*
+		#( pub use #frame_support::instances::#indexed_instance; )*
 	)
 }
@@ -133,10 +133,30 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 		})
 		.collect::<Vec<_>>();
 
+	// The keys and values of bounded storages are checked to implement `MaxEncodedLen` at their
+	// declaration, see `expand_max_encoded_len_checks`. Requiring `StorageInfoTrait` of those
+	// storages in the where clause keeps the compiler from reporting the same missing bound a
+	// second time here. Storages with `cfg` attributes can't be named in a where clause.
+	let mut storage_info_where_clause = storages_where_clauses.clone().unwrap_or_else(|| {
+		syn::WhereClause { where_token: Default::default(), predicates: Default::default() }
+	});
+	if def.pallet_struct.without_storage_info.is_none() {
+		let bounded_storages = def
+			.storages
+			.iter()
+			.filter(|storage| !storage.unbounded && storage.cfg_attrs.is_empty())
+			.map(|storage| &storage.ident);
+		for storage in bounded_storages {
+			storage_info_where_clause.predicates.push(syn::parse_quote!(
+				#storage<#type_use_gen>: #frame_support::traits::StorageInfoTrait
+			));
+		}
+	}
+
 	let storage_info = quote::quote_spanned!(storage_info_span =>
 		impl<#type_impl_gen> #frame_support::traits::StorageInfoTrait
 			for #pallet_ident<#type_use_gen>
-			#storages_where_clauses
+			#storage_info_where_clause
 		{
 			fn storage_info()
 				-> #frame_support::__private::sp_std::vec::Vec<#frame_support::traits::StorageInfo>
@@ -160,13 +180,18 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	);
 
-	let (storage_version, current_storage_version_ty) =
+	let (storage_version, current_storage_version_ty, in_code_storage_version) =
 		if let Some(v) = def.pallet_struct.storage_version.as_ref() {
-			(quote::quote! { #v }, quote::quote! { #frame_support::traits::StorageVersion })
+			(
+				quote::quote! { #v },
+				quote::quote! { #frame_support::traits::StorageVersion },
+				quote::quote! { core::option::Option::Some(#v) },
+			)
 		} else {
 			(
 				quote::quote! { core::default::Default::default() },
 				quote::quote! { #frame_support::traits::NoStorageVersionSet },
+				quote::quote! { core::option::Option::None },
 			)
 		};
 
@@ -214,6 +239,23 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		// Implement `GetPalletsStorageVersions` for `Pallet`
+		impl<#type_impl_gen> #frame_support::traits::GetPalletsStorageVersions
+			for #pallet_ident<#type_use_gen>
+			#config_where_clause
+		{
+			fn pallets_storage_versions()
+				-> #frame_support::__private::sp_std::vec::Vec<#frame_support::traits::PalletStorageVersions>
+			{
+				use #frame_support::traits::{GetStorageVersion, PalletInfoAccess};
+				#frame_support::__private::sp_std::vec![#frame_support::traits::PalletStorageVersions {
+					name: <Self as PalletInfoAccess>::name().as_bytes().to_vec(),
+					on_chain: <Self as GetStorageVersion>::on_chain_storage_version(),
+					in_code: #in_code_storage_version,
+				}]
+			}
+		}
+
 		// Implement `OnGenesis` for `Pallet`
 		impl<#type_impl_gen> #frame_support::traits::OnGenesis
 			for #pallet_ident<#type_use_gen>
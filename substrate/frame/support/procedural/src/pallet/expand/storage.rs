@@ -309,6 +309,10 @@ pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMet
 	Ok(on_empty_struct_metadata)
 }
 
+/// The documentation added to the metadata and the Rust docs of deprecated storage items.
+const DEPRECATED_STORAGE_DOC: &str = "DEPRECATED: this storage item is scheduled for removal, \
+	accesses to it are logged and counted.";
+
 fn augment_final_docs(def: &mut Def) {
 	// expand the docs with a new line showing the storage type (value, map, double map, etc), and
 	// the key/value type(s).
@@ -377,6 +381,70 @@ fn augment_final_docs(def: &mut Def) {
 			push_string_literal(&doc_line, storage);
 		},
 	});
+
+	def.storages
+		.iter_mut()
+		.filter(|storage| storage.deprecated)
+		.for_each(|storage| {
+			push_string_literal(DEPRECATED_STORAGE_DOC, storage);
+		});
+}
+
+/// Generate a check that the keys and the value of every bounded storage implement
+/// `MaxEncodedLen`.
+///
+/// `StorageInfoTrait` requires the same bounds, but its errors point at `#[pallet::pallet]` and are
+/// reported for the storage type as a whole. Asserting the bound for each type on its own makes
+/// the compiler point at the offending key or value type of the storage declaration instead. The
+/// `StorageInfoTrait` implementation of the pallet defers its bounds to its where clause, so that
+/// missing implementations are only reported here.
+fn expand_max_encoded_len_checks(def: &Def) -> proc_macro2::TokenStream {
+	if def.pallet_struct.without_storage_info.is_some() {
+		return Default::default()
+	}
+
+	let frame_support = &def.frame_support;
+	let checks = def.storages.iter().filter(|storage| !storage.unbounded).map(|storage| {
+		let types = match &storage.metadata {
+			Metadata::Value { value } => vec![value],
+			Metadata::Map { key, value } | Metadata::CountedMap { key, value } => vec![key, value],
+			Metadata::DoubleMap { key1, key2, value } => vec![key1, key2, value],
+			Metadata::NMap { keys, value, .. } | Metadata::CountedNMap { keys, value, .. } =>
+				keys.iter().chain(Some(value)).collect(),
+		};
+		let cfg_attrs = &storage.cfg_attrs;
+		let asserts = types.into_iter().map(|ty| {
+			quote::quote_spanned!(ty.span() =>
+				__ensure_max_encoded_len::<#ty>();
+			)
+		});
+
+		quote::quote_spanned!(storage.attr_span =>
+			#(#cfg_attrs)*
+			{
+				#( #asserts )*
+			}
+		)
+	});
+
+	let pallet_ident = &def.pallet_struct.pallet;
+	let mut where_clauses = vec![&def.config.where_clause];
+	where_clauses.extend(def.storages.iter().map(|storage| &storage.where_clause));
+	let completed_where_clause = super::merge_where_clauses(&where_clauses);
+	let type_impl_gen = &def.type_impl_generics(proc_macro2::Span::call_site());
+	let type_use_gen = &def.type_use_generics(proc_macro2::Span::call_site());
+
+	quote::quote!(
+		impl<#type_impl_gen> #pallet_ident<#type_use_gen> #completed_where_clause {
+			#[doc(hidden)]
+			#[allow(dead_code)]
+			fn __ensure_storage_max_encoded_len() {
+				fn __ensure_max_encoded_len<V: #frame_support::__private::codec::MaxEncodedLen>() {}
+
+				#( #checks )*
+			}
+		}
+	)
 }
 
 ///
@@ -412,6 +480,10 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 	let entries_builder = def.storages.iter().map(|storage| {
 		let no_docs = vec![];
 		let docs = if cfg!(feature = "no-metadata-docs") { &no_docs } else { &storage.docs };
+		// Deprecation is surfaced in the metadata even without docs.
+		let deprecation_doc = storage
+			.deprecated
+			.then(|| quote::quote_spanned!(storage.attr_span => #DEPRECATED_STORAGE_DOC, ));
 
 		let ident = &storage.ident;
 		let gen = &def.type_use_generics(storage.attr_span);
@@ -424,6 +496,7 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 			{
 				<#full_ident as #frame_support::storage::StorageEntryMetadataBuilder>::build_metadata(
 					#frame_support::__private::sp_std::vec![
+						#deprecation_doc
 						#( #docs, )*
 					],
 					&mut entries,
@@ -730,6 +803,19 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		};
 
 		let storage_prefix_hash = two128_str(&prefix_struct_const);
+		let maybe_on_access = storage_def.deprecated.then(|| {
+			quote::quote_spanned!(storage_def.attr_span =>
+				fn on_access() {
+					#frame_support::storage::deprecation::note_access(
+						<
+							<T as #frame_system::Config>::PalletInfo
+							as #frame_support::traits::PalletInfo
+						>::name::<Pallet<#type_use_gen>>().unwrap_or_default(),
+						#prefix_struct_const,
+					);
+				}
+			)
+		});
 		quote::quote_spanned!(storage_def.attr_span =>
 			#maybe_counter
 
@@ -763,6 +849,8 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 				fn storage_prefix_hash() -> [u8; 16] {
 					#storage_prefix_hash
 				}
+
+				#maybe_on_access
 			}
 		)
 	});
@@ -829,6 +917,8 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 	let type_impl_gen = &def.type_impl_generics(proc_macro2::Span::call_site());
 	let type_use_gen = &def.type_use_generics(proc_macro2::Span::call_site());
 
+	let max_encoded_len_checks = expand_max_encoded_len_checks(def);
+
 	let try_decode_entire_state = {
 		let mut storage_names = def
 			.storages
@@ -907,6 +997,7 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		#max_encoded_len_checks
 		#( #getters )*
 		#( #prefix_structs )*
 		#( #on_empty_structs )*
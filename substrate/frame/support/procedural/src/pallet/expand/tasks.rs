@@ -0,0 +1,178 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::pallet::Def;
+use inflector::Inflector;
+use syn::spanned::Spanned;
+
+/// * Generate enum `Task` with a variant per task declared in `#[pallet::tasks_experimental]`.
+/// * Implement `frame_support::traits::Task` on it.
+pub fn expand_tasks(def: &mut Def) -> proc_macro2::TokenStream {
+	let Some(tasks) = def.tasks.as_ref() else { return Default::default() };
+
+	let span = tasks.attr_span;
+	let frame_support = &def.frame_support;
+	let type_impl_gen = &def.type_impl_generics(span);
+	let type_decl_bounded_gen = &def.type_decl_bounded_generics(span);
+	let type_use_gen = &def.type_use_generics(span);
+	let pallet_ident = &def.pallet_struct.pallet;
+	let where_clause = &tasks.where_clause;
+	let task_ident = syn::Ident::new("Task", span);
+
+	let capture_docs = if cfg!(feature = "no-metadata-docs") { "never" } else { "always" };
+
+	let fn_name = tasks.tasks.iter().map(|task| &task.name).collect::<Vec<_>>();
+	let variant_name = tasks
+		.tasks
+		.iter()
+		.map(|task| syn::Ident::new(&task.name.to_string().to_pascal_case(), task.name.span()))
+		.collect::<Vec<_>>();
+	let variant_doc = tasks
+		.tasks
+		.iter()
+		.map(|task| {
+			if def.dev_mode {
+				format!("See `Pallet::{}`.", task.name)
+			} else {
+				format!("See [`Pallet::{}`].", task.name)
+			}
+		})
+		.collect::<Vec<_>>();
+	let cfg_attrs = tasks.tasks.iter().map(|task| &task.cfg_attrs).collect::<Vec<_>>();
+	let task_index = tasks.tasks.iter().map(|task| task.task_index).collect::<Vec<_>>();
+	let list = tasks.tasks.iter().map(|task| &task.list).collect::<Vec<_>>();
+	let condition = tasks.tasks.iter().map(|task| &task.condition).collect::<Vec<_>>();
+	let weight = tasks.tasks.iter().map(|task| &task.weight).collect::<Vec<_>>();
+	let args_name = tasks
+		.tasks
+		.iter()
+		.map(|task| task.args.iter().map(|(name, _)| name).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let args_type = tasks
+		.tasks
+		.iter()
+		.map(|task| task.args.iter().map(|(_, type_)| type_).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	// The pattern the items of a task list are destructured with.
+	let list_item_pattern = args_name
+		.iter()
+		.map(|args_name| match &args_name[..] {
+			[arg_name] => quote::quote!(#arg_name),
+			_ => quote::quote!(( #( #args_name ),* )),
+		})
+		.collect::<Vec<_>>();
+
+	quote::quote_spanned!(span =>
+		/// Contains a variant per task declared in `#[pallet::tasks_experimental]`.
+		#[derive(
+			#frame_support::RuntimeDebugNoBound,
+			#frame_support::CloneNoBound,
+			#frame_support::EqNoBound,
+			#frame_support::PartialEqNoBound,
+			#frame_support::__private::codec::Encode,
+			#frame_support::__private::codec::Decode,
+			#frame_support::__private::scale_info::TypeInfo,
+		)]
+		#[codec(encode_bound())]
+		#[codec(decode_bound())]
+		#[scale_info(skip_type_params(#type_use_gen), capture_docs = #capture_docs)]
+		pub enum #task_ident<#type_decl_bounded_gen> #where_clause {
+			#[doc(hidden)]
+			#[codec(skip)]
+			__Ignore(
+				#frame_support::__private::sp_std::marker::PhantomData<(#type_use_gen,)>,
+				#frame_support::Never,
+			),
+			#(
+				#( #cfg_attrs )*
+				#[doc = #variant_doc]
+				#[codec(index = #task_index)]
+				#variant_name {
+					#(
+						#[allow(missing_docs)]
+						#args_name: #args_type
+					),*
+				},
+			)*
+		}
+
+		impl<#type_impl_gen> #frame_support::traits::Task for #task_ident<#type_use_gen>
+			#where_clause
+		{
+			type Enumeration = #frame_support::traits::tasks::__private::IntoIter<Self>;
+
+			fn iter() -> Self::Enumeration {
+				#[allow(unused_mut)]
+				let mut tasks = #frame_support::traits::tasks::__private::vec![];
+				#(
+					#( #cfg_attrs )*
+					tasks.extend(
+						#frame_support::__private::sp_std::iter::IntoIterator::into_iter(#list)
+							.map(|#list_item_pattern| Self::#variant_name { #( #args_name ),* })
+					);
+				)*
+				tasks.into_iter()
+			}
+
+			fn is_valid(&self) -> bool {
+				match *self {
+					#(
+						#( #cfg_attrs )*
+						Self::#variant_name { #( ref #args_name ),* } => {
+							let condition: &dyn Fn( #( &#args_type ),* ) -> bool = &(#condition);
+							condition( #( #args_name ),* )
+						},
+					)*
+					Self::__Ignore(_, _) => unreachable!("__Ignore cannot be used"),
+				}
+			}
+
+			fn run(&self) -> Result<(), #frame_support::traits::tasks::__private::DispatchError> {
+				match *self {
+					#(
+						#( #cfg_attrs )*
+						Self::#variant_name { #( ref #args_name ),* } =>
+							<#pallet_ident<#type_use_gen>>::#fn_name(
+								#( ::core::clone::Clone::clone(#args_name) ),*
+							),
+					)*
+					Self::__Ignore(_, _) => unreachable!("__Ignore cannot be used"),
+				}
+			}
+
+			fn weight(&self) -> #frame_support::traits::tasks::__private::Weight {
+				match *self {
+					#(
+						#( #cfg_attrs )*
+						Self::#variant_name { .. } => #weight,
+					)*
+					Self::__Ignore(_, _) => unreachable!("__Ignore cannot be used"),
+				}
+			}
+
+			fn task_index(&self) -> u32 {
+				match *self {
+					#(
+						#( #cfg_attrs )*
+						Self::#variant_name { .. } => #task_index,
+					)*
+					Self::__Ignore(_, _) => unreachable!("__Ignore cannot be used"),
+				}
+			}
+		}
+	)
+}
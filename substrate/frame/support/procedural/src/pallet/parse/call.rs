@@ -32,6 +32,7 @@ mod keyword {
 	syn::custom_keyword!(T);
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(feeless_if);
+	syn::custom_keyword!(feature_gated);
 }
 
 /// Definition of dispatchables typically `impl<T: Config> Pallet<T> { ... }`
@@ -84,8 +85,14 @@ pub struct CallVariantDef {
 	pub docs: Vec<syn::Expr>,
 	/// Attributes annotated at the top of the dispatchable function.
 	pub attrs: Vec<syn::Attribute>,
+	/// The `cfg` attributes of the dispatchable function, which also apply to its variant in the
+	/// `Call` enum.
+	pub cfg_attrs: Vec<syn::Attribute>,
 	/// The optional `feeless_if` attribute on the `pallet::call`.
 	pub feeless_check: Option<syn::ExprClosure>,
+	/// The optional `feature_gated` attribute on the `pallet::call`, i.e. the `Get<bool>` flag
+	/// which must be enabled for the dispatchable to be callable.
+	pub feature_gate: Option<syn::Type>,
 }
 
 /// Attributes for functions in call impl block.
@@ -96,6 +103,8 @@ pub enum FunctionAttr {
 	Weight(syn::Expr),
 	/// Parse for `#[pallet::feeless_if(expr)]`
 	FeelessIf(Span, syn::ExprClosure),
+	/// Parse for `#[pallet::feature_gated(type)]`
+	FeatureGated(Span, syn::Type),
 }
 
 impl syn::parse::Parse for FunctionAttr {
@@ -135,6 +144,11 @@ impl syn::parse::Parse for FunctionAttr {
 					err
 				})?,
 			))
+		} else if lookahead.peek(keyword::feature_gated) {
+			content.parse::<keyword::feature_gated>()?;
+			let flag_content;
+			syn::parenthesized!(flag_content in content);
+			Ok(FunctionAttr::FeatureGated(flag_content.span(), flag_content.parse::<syn::Type>()?))
 		} else {
 			Err(lookahead.error())
 		}
@@ -248,13 +262,14 @@ impl CallDef {
 					helper::check_pallet_call_return_type(type_)?;
 				} else {
 					let msg = "Invalid pallet::call, require return type \
-						DispatchResultWithPostInfo";
+						DispatchResultWithPostInfo, DispatchResult or RefundIfSuccessful";
 					return Err(syn::Error::new(method.sig.span(), msg))
 				}
 
 				let mut call_idx_attrs = vec![];
 				let mut weight_attrs = vec![];
 				let mut feeless_attrs = vec![];
+				let mut feature_gate_attrs = vec![];
 				for attr in helper::take_item_pallet_attrs(&mut method.attrs)?.into_iter() {
 					match attr {
 						FunctionAttr::CallIndex(_) => {
@@ -266,6 +281,9 @@ impl CallDef {
 						FunctionAttr::FeelessIf(span, _) => {
 							feeless_attrs.push((span, attr));
 						},
+						FunctionAttr::FeatureGated(span, flag) => {
+							feature_gate_attrs.push((span, flag));
+						},
 					}
 				}
 
@@ -421,6 +439,12 @@ impl CallDef {
 					}
 				}
 
+				if feature_gate_attrs.len() > 1 {
+					let msg = "Invalid pallet::call, there can only be one feature_gated attribute";
+					return Err(syn::Error::new(feature_gate_attrs[1].0, msg))
+				}
+				let feature_gate = feature_gate_attrs.pop().map(|(_, flag)| flag);
+
 				methods.push(CallVariantDef {
 					name: method.sig.ident.clone(),
 					weight,
@@ -429,7 +453,14 @@ impl CallDef {
 					args,
 					docs,
 					attrs: method.attrs.clone(),
+					cfg_attrs: method
+						.attrs
+						.iter()
+						.filter(|attr| attr.path().is_ident("cfg"))
+						.cloned()
+						.collect(),
 					feeless_check,
+					feature_gate,
 				});
 			} else {
 				let msg = "Invalid pallet::call, only method accepted";
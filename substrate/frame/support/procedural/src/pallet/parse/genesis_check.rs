@@ -0,0 +1,55 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use syn::spanned::Spanned;
+
+/// Definition for pallet genesis check implementation.
+pub struct GenesisCheckDef {
+	/// The index of item in pallet module.
+	pub index: usize,
+	/// The span of the pallet::genesis_check attribute.
+	pub attr_span: proc_macro2::Span,
+}
+
+impl GenesisCheckDef {
+	pub fn try_from(
+		attr_span: proc_macro2::Span,
+		index: usize,
+		item: &mut syn::Item,
+	) -> syn::Result<Self> {
+		let item = if let syn::Item::Impl(item) = item {
+			item
+		} else {
+			let msg = "Invalid pallet::genesis_check, expected item impl";
+			return Err(syn::Error::new(item.span(), msg))
+		};
+
+		match item.trait_.as_ref().and_then(|(_, path, _)| path.segments.last()) {
+			Some(last) if last.ident == "CheckGenesisConfig" => (),
+			Some(last) => {
+				let msg = "Invalid pallet::genesis_check, expected trait CheckGenesisConfig";
+				return Err(syn::Error::new(last.span(), msg))
+			},
+			None => {
+				let msg = "Invalid pallet::genesis_check, expected impl<..> CheckGenesisConfig \
+					for GenesisConfig<..>";
+				return Err(syn::Error::new(item.span(), msg))
+			},
+		}
+
+		Ok(Self { attr_span, index })
+	}
+}
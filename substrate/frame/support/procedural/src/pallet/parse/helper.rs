@@ -31,6 +31,7 @@ mod keyword {
 	syn::custom_keyword!(origin);
 	syn::custom_keyword!(DispatchResult);
 	syn::custom_keyword!(DispatchResultWithPostInfo);
+	syn::custom_keyword!(RefundIfSuccessful);
 }
 
 /// A usage of instance, either the trait `Config` has been used with instance or without instance.
@@ -603,6 +604,9 @@ pub fn check_pallet_call_return_type(type_: &syn::Type) -> syn::Result<()> {
 			} else if lookahead.peek(keyword::DispatchResult) {
 				input.parse::<keyword::DispatchResult>()?;
 				Ok(Self)
+			} else if lookahead.peek(keyword::RefundIfSuccessful) {
+				input.parse::<keyword::RefundIfSuccessful>()?;
+				Ok(Self)
 			} else {
 				Err(lookahead.error())
 			}
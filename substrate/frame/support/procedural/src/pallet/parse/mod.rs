@@ -26,6 +26,7 @@ pub mod error;
 pub mod event;
 pub mod extra_constants;
 pub mod genesis_build;
+pub mod genesis_check;
 pub mod genesis_config;
 pub mod helper;
 pub mod hooks;
@@ -33,6 +34,7 @@ pub mod inherent;
 pub mod origin;
 pub mod pallet_struct;
 pub mod storage;
+pub mod tasks;
 pub mod type_value;
 pub mod validate_unsigned;
 
@@ -49,6 +51,7 @@ pub struct Def {
 	pub pallet_struct: pallet_struct::PalletStructDef,
 	pub hooks: Option<hooks::HooksDef>,
 	pub call: Option<call::CallDef>,
+	pub tasks: Option<tasks::TasksDef>,
 	pub storages: Vec<storage::StorageDef>,
 	pub error: Option<error::ErrorDef>,
 	pub event: Option<event::EventDef>,
@@ -56,6 +59,7 @@ pub struct Def {
 	pub inherent: Option<inherent::InherentDef>,
 	pub genesis_config: Option<genesis_config::GenesisConfigDef>,
 	pub genesis_build: Option<genesis_build::GenesisBuildDef>,
+	pub genesis_check: Option<genesis_check::GenesisCheckDef>,
 	pub validate_unsigned: Option<validate_unsigned::ValidateUnsignedDef>,
 	pub extra_constants: Option<extra_constants::ExtraConstantsDef>,
 	pub composites: Vec<composite::CompositeDef>,
@@ -84,12 +88,14 @@ impl Def {
 		let mut pallet_struct = None;
 		let mut hooks = None;
 		let mut call = None;
+		let mut tasks = None;
 		let mut error = None;
 		let mut event = None;
 		let mut origin = None;
 		let mut inherent = None;
 		let mut genesis_config = None;
 		let mut genesis_build = None;
+		let mut genesis_check = None;
 		let mut validate_unsigned = None;
 		let mut extra_constants = None;
 		let mut storages = vec![];
@@ -118,6 +124,8 @@ impl Def {
 				},
 				Some(PalletAttr::RuntimeCall(cw, span)) if call.is_none() =>
 					call = Some(call::CallDef::try_from(span, index, item, dev_mode, cw)?),
+				Some(PalletAttr::Tasks(span)) if tasks.is_none() =>
+					tasks = Some(tasks::TasksDef::try_from(span, index, item)?),
 				Some(PalletAttr::Error(span)) if error.is_none() =>
 					error = Some(error::ErrorDef::try_from(span, index, item)?),
 				Some(PalletAttr::RuntimeEvent(span)) if event.is_none() =>
@@ -130,12 +138,16 @@ impl Def {
 					let g = genesis_build::GenesisBuildDef::try_from(span, index, item)?;
 					genesis_build = Some(g);
 				},
+				Some(PalletAttr::GenesisCheck(span)) if genesis_check.is_none() => {
+					let g = genesis_check::GenesisCheckDef::try_from(span, index, item)?;
+					genesis_check = Some(g);
+				},
 				Some(PalletAttr::RuntimeOrigin(_)) if origin.is_none() =>
 					origin = Some(origin::OriginDef::try_from(index, item)?),
 				Some(PalletAttr::Inherent(_)) if inherent.is_none() =>
 					inherent = Some(inherent::InherentDef::try_from(index, item)?),
-				Some(PalletAttr::Storage(span)) =>
-					storages.push(storage::StorageDef::try_from(span, index, item, dev_mode)?),
+				Some(PalletAttr::Storage(span, deprecated)) => storages
+					.push(storage::StorageDef::try_from(span, index, item, dev_mode, deprecated)?),
 				Some(PalletAttr::ValidateUnsigned(_)) if validate_unsigned.is_none() => {
 					let v = validate_unsigned::ValidateUnsignedDef::try_from(index, item)?;
 					validate_unsigned = Some(v);
@@ -190,6 +202,11 @@ impl Def {
 			return Err(syn::Error::new(item_span, msg))
 		}
 
+		if let (Some(genesis_check), None) = (&genesis_check, &genesis_config) {
+			let msg = "`#[pallet::genesis_check]` requires `#[pallet::genesis_config]` to be used";
+			return Err(syn::Error::new(genesis_check.attr_span, msg))
+		}
+
 		let def = Def {
 			item,
 			config: config
@@ -198,9 +215,11 @@ impl Def {
 				.ok_or_else(|| syn::Error::new(item_span, "Missing `#[pallet::pallet]`"))?,
 			hooks,
 			call,
+			tasks,
 			extra_constants,
 			genesis_config,
 			genesis_build,
+			genesis_check,
 			validate_unsigned,
 			error,
 			event,
@@ -216,10 +235,31 @@ impl Def {
 
 		def.check_instance_usage()?;
 		def.check_event_usage()?;
+		def.check_feature_gate_usage()?;
 
 		Ok(def)
 	}
 
+	/// Check that a pallet gating dispatchables behind a feature flag declares an `Error`, to which
+	/// the `FeatureDisabled` variant is added.
+	fn check_feature_gate_usage(&self) -> syn::Result<()> {
+		let gate = self
+			.call
+			.iter()
+			.flat_map(|call| &call.methods)
+			.find_map(|method| method.feature_gate.as_ref());
+		match (gate, &self.error) {
+			(Some(gate), None) => {
+				let msg =
+					"Invalid usage of `#[pallet::feature_gated]`, the pallet must declare an \
+					`Error` enum (i.e. use `#[pallet::error]`), to which the `FeatureDisabled` \
+					variant is added.";
+				Err(syn::Error::new(gate.span(), msg))
+			},
+			_ => Ok(()),
+		}
+	}
+
 	/// Check that usage of trait `Event` is consistent with the definition, i.e. it is declared
 	/// and trait defines type RuntimeEvent, or not declared and no trait associated type.
 	fn check_event_usage(&self) -> syn::Result<()> {
@@ -249,6 +289,9 @@ impl Def {
 		if let Some(call) = &self.call {
 			instances.extend_from_slice(&call.instances[..]);
 		}
+		if let Some(tasks) = &self.tasks {
+			instances.extend_from_slice(&tasks.instances[..]);
+		}
 		if let Some(hooks) = &self.hooks {
 			instances.extend_from_slice(&hooks.instances[..]);
 		}
@@ -416,7 +459,9 @@ mod keyword {
 	syn::custom_keyword!(inherent);
 	syn::custom_keyword!(error);
 	syn::custom_keyword!(storage);
+	syn::custom_keyword!(deprecated);
 	syn::custom_keyword!(genesis_build);
+	syn::custom_keyword!(genesis_check);
 	syn::custom_keyword!(genesis_config);
 	syn::custom_keyword!(validate_unsigned);
 	syn::custom_keyword!(type_value);
@@ -425,6 +470,7 @@ mod keyword {
 	syn::custom_keyword!(Store);
 	syn::custom_keyword!(extra_constants);
 	syn::custom_keyword!(composite_enum);
+	syn::custom_keyword!(tasks_experimental);
 }
 
 /// Parse attributes for item in pallet module
@@ -471,13 +517,25 @@ enum PalletAttr {
 	/// to zero. Now when there is a `weight` attribute on the `#[pallet::call]`, then that is used
 	/// instead of the zero weight. So to say: it works together with `dev_mode`.
 	RuntimeCall(Option<InheritedCallWeightAttr>, proc_macro2::Span),
+	/// A `#[pallet::tasks_experimental]` impl block declaring the tasks of the pallet.
+	///
+	/// Each public function of the block is a task and must be annotated with:
+	/// * `#[pallet::task_list(..)]`: an expression enumerating the arguments of all the tasks,
+	/// * `#[pallet::task_condition(|..| ..)]`: a closure taking references to the arguments and
+	///   returning whether the task is still to be done,
+	/// * `#[pallet::task_weight(..)]`: the weight of the task,
+	/// * `#[pallet::task_index(..)]`: the index of the task within the pallet.
+	Tasks(proc_macro2::Span),
 	Error(proc_macro2::Span),
 	RuntimeEvent(proc_macro2::Span),
 	RuntimeOrigin(proc_macro2::Span),
 	Inherent(proc_macro2::Span),
-	Storage(proc_macro2::Span),
+	/// A `#[pallet::storage]`, or a `#[pallet::storage(deprecated)]` for storage items whose
+	/// accesses are logged and counted until they can be removed.
+	Storage(proc_macro2::Span, bool),
 	GenesisConfig(proc_macro2::Span),
 	GenesisBuild(proc_macro2::Span),
+	GenesisCheck(proc_macro2::Span),
 	ValidateUnsigned(proc_macro2::Span),
 	TypeValue(proc_macro2::Span),
 	ExtraConstants(proc_macro2::Span),
@@ -491,13 +549,15 @@ impl PalletAttr {
 			Self::Pallet(span) => *span,
 			Self::Hooks(span) => *span,
 			Self::RuntimeCall(_, span) => *span,
+			Self::Tasks(span) => *span,
 			Self::Error(span) => *span,
 			Self::RuntimeEvent(span) => *span,
 			Self::RuntimeOrigin(span) => *span,
 			Self::Inherent(span) => *span,
-			Self::Storage(span) => *span,
+			Self::Storage(span, _) => *span,
 			Self::GenesisConfig(span) => *span,
 			Self::GenesisBuild(span) => *span,
+			Self::GenesisCheck(span) => *span,
 			Self::ValidateUnsigned(span) => *span,
 			Self::TypeValue(span) => *span,
 			Self::ExtraConstants(span) => *span,
@@ -535,6 +595,8 @@ impl syn::parse::Parse for PalletAttr {
 				false => Some(InheritedCallWeightAttr::parse(&content)?),
 			};
 			Ok(PalletAttr::RuntimeCall(attr, span))
+		} else if lookahead.peek(keyword::tasks_experimental) {
+			Ok(PalletAttr::Tasks(content.parse::<keyword::tasks_experimental>()?.span()))
 		} else if lookahead.peek(keyword::error) {
 			Ok(PalletAttr::Error(content.parse::<keyword::error>()?.span()))
 		} else if lookahead.peek(keyword::event) {
@@ -544,11 +606,20 @@ impl syn::parse::Parse for PalletAttr {
 		} else if lookahead.peek(keyword::inherent) {
 			Ok(PalletAttr::Inherent(content.parse::<keyword::inherent>()?.span()))
 		} else if lookahead.peek(keyword::storage) {
-			Ok(PalletAttr::Storage(content.parse::<keyword::storage>()?.span()))
+			let span = content.parse::<keyword::storage>()?.span();
+			let deprecated = content.peek(syn::token::Paren);
+			if deprecated {
+				let inside_storage;
+				let _paren = syn::parenthesized!(inside_storage in content);
+				inside_storage.parse::<keyword::deprecated>()?;
+			}
+			Ok(PalletAttr::Storage(span, deprecated))
 		} else if lookahead.peek(keyword::genesis_config) {
 			Ok(PalletAttr::GenesisConfig(content.parse::<keyword::genesis_config>()?.span()))
 		} else if lookahead.peek(keyword::genesis_build) {
 			Ok(PalletAttr::GenesisBuild(content.parse::<keyword::genesis_build>()?.span()))
+		} else if lookahead.peek(keyword::genesis_check) {
+			Ok(PalletAttr::GenesisCheck(content.parse::<keyword::genesis_check>()?.span()))
 		} else if lookahead.peek(keyword::validate_unsigned) {
 			Ok(PalletAttr::ValidateUnsigned(content.parse::<keyword::validate_unsigned>()?.span()))
 		} else if lookahead.peek(keyword::type_value) {
@@ -188,6 +188,9 @@ pub struct StorageDef {
 	pub whitelisted: bool,
 	/// Whether or not a default hasher is allowed to replace `_`
 	pub use_default_hasher: bool,
+	/// Whether the storage is declared with `#[pallet::storage(deprecated)]`, in which case
+	/// accesses to it are logged and counted.
+	pub deprecated: bool,
 }
 
 /// The parsed generic from the
@@ -767,6 +770,7 @@ impl StorageDef {
 		index: usize,
 		item: &mut syn::Item,
 		dev_mode: bool,
+		deprecated: bool,
 	) -> syn::Result<Self> {
 		let item = if let syn::Item::Type(item) = item {
 			item
@@ -922,6 +926,7 @@ impl StorageDef {
 			unbounded,
 			whitelisted,
 			use_default_hasher,
+			deprecated,
 		})
 	}
 }
@@ -0,0 +1,258 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::helper;
+use frame_support_procedural_tools::get_doc_literals;
+use std::collections::HashMap;
+use syn::spanned::Spanned;
+
+/// List of additional token to be used for parsing.
+mod keyword {
+	syn::custom_keyword!(pallet);
+	syn::custom_keyword!(task_list);
+	syn::custom_keyword!(task_condition);
+	syn::custom_keyword!(task_weight);
+	syn::custom_keyword!(task_index);
+}
+
+/// Definition of tasks typically `impl<T: Config> Pallet<T> { ... }`
+pub struct TasksDef {
+	/// The where_clause used.
+	pub where_clause: Option<syn::WhereClause>,
+	/// A set of usage of instance, must be check for consistency with trait.
+	pub instances: Vec<helper::InstanceUsage>,
+	/// The index of tasks item in pallet module.
+	pub index: usize,
+	/// Information on the declared tasks (used for expansion).
+	pub tasks: Vec<TaskDef>,
+	/// The span of the pallet::tasks_experimental attribute.
+	pub attr_span: proc_macro2::Span,
+}
+
+/// Definition of a task typically:
+/// `#[pallet::task_list(..)] .. #[pallet::task_index(..)] pub fn foo(param1: ...) -> ..`
+pub struct TaskDef {
+	/// Function name.
+	pub name: syn::Ident,
+	/// Information on args: `(name, type)`
+	pub args: Vec<(syn::Ident, Box<syn::Type>)>,
+	/// Expression enumerating the arguments of all potential tasks.
+	pub list: syn::Expr,
+	/// Closure checking whether a task is valid, given references to its arguments.
+	pub condition: syn::ExprClosure,
+	/// Weight of the task.
+	pub weight: syn::Expr,
+	/// Index of the task within the pallet.
+	pub task_index: u32,
+	/// Docs, used for metadata.
+	pub docs: Vec<syn::Expr>,
+	/// The cfg attributes annotated at the top of the task function.
+	pub cfg_attrs: Vec<syn::Attribute>,
+}
+
+/// Attributes for functions in tasks impl block.
+pub enum TaskAttr {
+	/// Parse for `#[pallet::task_list(expr)]`
+	List(syn::Expr),
+	/// Parse for `#[pallet::task_condition(closure)]`
+	Condition(syn::ExprClosure),
+	/// Parse for `#[pallet::task_weight(expr)]`
+	Weight(syn::Expr),
+	/// Parse for `#[pallet::task_index(literal)]`
+	Index(u32),
+}
+
+impl syn::parse::Parse for TaskAttr {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		input.parse::<syn::Token![#]>()?;
+		let content;
+		syn::bracketed!(content in input);
+		content.parse::<keyword::pallet>()?;
+		content.parse::<syn::Token![::]>()?;
+
+		let lookahead = content.lookahead1();
+		if lookahead.peek(keyword::task_list) {
+			content.parse::<keyword::task_list>()?;
+			let list_content;
+			syn::parenthesized!(list_content in content);
+			Ok(TaskAttr::List(list_content.parse::<syn::Expr>()?))
+		} else if lookahead.peek(keyword::task_condition) {
+			content.parse::<keyword::task_condition>()?;
+			let condition_content;
+			syn::parenthesized!(condition_content in content);
+			Ok(TaskAttr::Condition(condition_content.parse::<syn::ExprClosure>().map_err(|e| {
+				let msg = "Invalid task_condition attribute: expected a closure";
+				let mut err = syn::Error::new(condition_content.span(), msg);
+				err.combine(e);
+				err
+			})?))
+		} else if lookahead.peek(keyword::task_weight) {
+			content.parse::<keyword::task_weight>()?;
+			let weight_content;
+			syn::parenthesized!(weight_content in content);
+			Ok(TaskAttr::Weight(weight_content.parse::<syn::Expr>()?))
+		} else if lookahead.peek(keyword::task_index) {
+			content.parse::<keyword::task_index>()?;
+			let index_content;
+			syn::parenthesized!(index_content in content);
+			let index = index_content.parse::<syn::LitInt>()?;
+			if !index.suffix().is_empty() {
+				let msg = "Number literal must not have a suffix";
+				return Err(syn::Error::new(index.span(), msg))
+			}
+			Ok(TaskAttr::Index(index.base10_parse()?))
+		} else {
+			Err(lookahead.error())
+		}
+	}
+}
+
+impl TasksDef {
+	pub fn try_from(
+		attr_span: proc_macro2::Span,
+		index: usize,
+		item: &mut syn::Item,
+	) -> syn::Result<Self> {
+		let item_impl = if let syn::Item::Impl(item) = item {
+			item
+		} else {
+			let msg = "Invalid pallet::tasks_experimental, expected item impl";
+			return Err(syn::Error::new(item.span(), msg))
+		};
+
+		let instances = vec![
+			helper::check_impl_gen(&item_impl.generics, item_impl.impl_token.span())?,
+			helper::check_pallet_struct_usage(&item_impl.self_ty)?,
+		];
+
+		if let Some((_, _, for_)) = item_impl.trait_ {
+			let msg = "Invalid pallet::tasks_experimental, expected no trait ident as in \
+				`impl<..> Pallet<..> { .. }`";
+			return Err(syn::Error::new(for_.span(), msg))
+		}
+
+		let mut tasks = vec![];
+		let mut indices = HashMap::new();
+		for item in &mut item_impl.items {
+			let syn::ImplItem::Fn(method) = item else { continue };
+
+			if !matches!(method.vis, syn::Visibility::Public(_)) {
+				let msg = "Invalid pallet::tasks_experimental, task function must be public: \
+					`pub fn`";
+				return Err(syn::Error::new(method.sig.span(), msg))
+			}
+
+			let (mut list, mut condition, mut weight, mut task_index) = (None, None, None, None);
+			for attr in helper::take_item_pallet_attrs(&mut method.attrs)?.into_iter() {
+				let duplicated = match attr {
+					TaskAttr::List(expr) => list.replace(expr).is_some(),
+					TaskAttr::Condition(closure) => condition.replace(closure).is_some(),
+					TaskAttr::Weight(expr) => weight.replace(expr).is_some(),
+					TaskAttr::Index(idx) => task_index.replace(idx).is_some(),
+				};
+				if duplicated {
+					let msg = "Invalid pallet::tasks_experimental, duplicated task attribute";
+					return Err(syn::Error::new(method.sig.span(), msg))
+				}
+			}
+
+			let missing = |attr: &str| {
+				let msg = format!(
+					"Invalid pallet::tasks_experimental, missing `#[pallet::{}(..)]` attribute",
+					attr
+				);
+				syn::Error::new(method.sig.span(), msg)
+			};
+			let list = list.ok_or_else(|| missing("task_list"))?;
+			let condition = condition.ok_or_else(|| missing("task_condition"))?;
+			let weight = weight.ok_or_else(|| missing("task_weight"))?;
+			let task_index = task_index.ok_or_else(|| missing("task_index"))?;
+
+			if let Some(used_fn) = indices.insert(task_index, method.sig.ident.clone()) {
+				let msg = format!(
+					"Task indices are conflicting: Both functions {} and {} are at index {}",
+					used_fn, method.sig.ident, task_index,
+				);
+				let mut err = syn::Error::new(used_fn.span(), &msg);
+				err.combine(syn::Error::new(method.sig.ident.span(), msg));
+				return Err(err)
+			}
+
+			let mut args = vec![];
+			for arg in method.sig.inputs.iter() {
+				let arg = if let syn::FnArg::Typed(arg) = arg {
+					arg
+				} else {
+					let msg = "Invalid pallet::tasks_experimental, task function must not take \
+						`self`";
+					return Err(syn::Error::new(arg.span(), msg))
+				};
+
+				let arg_ident = if let syn::Pat::Ident(pat) = &*arg.pat {
+					pat.ident.clone()
+				} else {
+					let msg = "Invalid pallet::tasks_experimental, argument must be ident";
+					return Err(syn::Error::new(arg.pat.span(), msg))
+				};
+
+				args.push((arg_ident, arg.ty.clone()));
+			}
+
+			if condition.inputs.len() != args.len() {
+				let msg = "Invalid pallet::tasks_experimental, task_condition closure must have \
+					same number of arguments as the task function";
+				return Err(syn::Error::new(condition.span(), msg))
+			}
+
+			let returns_dispatch_result = match &method.sig.output {
+				syn::ReturnType::Type(_, type_) => match &**type_ {
+					syn::Type::Path(path) => path
+						.path
+						.segments
+						.last()
+						.map_or(false, |segment| segment.ident == "DispatchResult"),
+					_ => false,
+				},
+				syn::ReturnType::Default => false,
+			};
+			if !returns_dispatch_result {
+				let msg = "Invalid pallet::tasks_experimental, require return type \
+					DispatchResult";
+				return Err(syn::Error::new(method.sig.span(), msg))
+			}
+
+			tasks.push(TaskDef {
+				name: method.sig.ident.clone(),
+				args,
+				list,
+				condition,
+				weight,
+				task_index,
+				docs: get_doc_literals(&method.attrs),
+				cfg_attrs: helper::get_item_cfg_attrs(&method.attrs),
+			});
+		}
+
+		Ok(Self {
+			index,
+			attr_span,
+			instances,
+			tasks,
+			where_clause: item_impl.generics.where_clause.clone(),
+		})
+	}
+}
@@ -0,0 +1,162 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `parameter_preset!` macro.
+
+use frame_support_procedural_tools::generate_access_from_frame_or_crate;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use std::collections::HashSet;
+use syn::{
+	braced,
+	parse::{Parse, ParseStream},
+	punctuated::Punctuated,
+	Attribute, Expr, Ident, Result, Token, Type, Visibility,
+};
+
+/// A single parameter of a preset, e.g. `ExistentialDeposit: u64 = 1`.
+struct PresetParameter {
+	attrs: Vec<Attribute>,
+	name: Ident,
+	ty: Type,
+	value: Expr,
+}
+
+impl Parse for PresetParameter {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let name = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty = input.parse()?;
+		input.parse::<Token![=]>()?;
+		let value = input.parse()?;
+
+		Ok(Self { attrs, name, ty, value })
+	}
+}
+
+/// The input of `parameter_preset!`:
+///
+/// ```ignore
+/// pub struct Preset: PresetTrait {
+///     Name: Type = value;
+///     ...
+/// }
+/// ```
+pub struct ParameterPresetDef {
+	attrs: Vec<Attribute>,
+	vis: Visibility,
+	name: Ident,
+	preset_trait: Ident,
+	params: Vec<PresetParameter>,
+}
+
+impl Parse for ParameterPresetDef {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let vis = input.parse()?;
+		input.parse::<Token![struct]>()?;
+		let name = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let preset_trait = input.parse()?;
+
+		let content;
+		braced!(content in input);
+		let params = Punctuated::<PresetParameter, Token![;]>::parse_terminated(&content)?
+			.into_iter()
+			.collect::<Vec<_>>();
+
+		if params.is_empty() {
+			return Err(syn::Error::new(name.span(), "A parameter preset must not be empty"))
+		}
+
+		let mut names = HashSet::new();
+		for param in &params {
+			if !names.insert(param.name.clone()) {
+				return Err(syn::Error::new(
+					param.name.span(),
+					format!("Parameter `{}` is declared more than once", param.name),
+				))
+			}
+		}
+
+		Ok(Self { attrs, vis, name, preset_trait, params })
+	}
+}
+
+/// Expands a [`ParameterPresetDef`] into the preset struct, its trait, one parameter type per
+/// entry and the registered impl of the trait that `derive_impl` imports from.
+pub fn expand_parameter_preset(def: ParameterPresetDef) -> Result<TokenStream> {
+	let frame_support = generate_access_from_frame_or_crate("frame-support")?;
+	let ParameterPresetDef { attrs, vis, name, preset_trait, params } = def;
+
+	// The parameter types are named after the trait items, so they are kept in their own module
+	// to not collide with other presets declared next to this one.
+	let values_mod = format_ident!("__parameter_preset_{}", name);
+
+	let trait_items = params.iter().map(|PresetParameter { attrs, name, ty, .. }| {
+		quote! {
+			#( #attrs )*
+			type #name: #frame_support::traits::TypedGet<Type = #ty>
+				+ #frame_support::traits::Get<#ty>;
+		}
+	});
+	let values = params.iter().map(|PresetParameter { attrs, name, ty, value }| {
+		quote! {
+			#( #attrs )*
+			pub #name: #ty = #value;
+		}
+	});
+	let names = params.iter().map(|param| &param.name).collect::<Vec<_>>();
+
+	let registered_impl = quote! {
+		impl #preset_trait for #name {
+			#( type #names = #values_mod::#names; )*
+		}
+	};
+	let registered_impl = macro_magic::mm_core::export_tokens_internal(
+		name.to_token_stream(),
+		registered_impl,
+		true,
+		false,
+	)?;
+
+	let preset_doc = format!(" The parameters provided by [`{}`].", name);
+
+	Ok(quote! {
+		#( #attrs )*
+		#vis struct #name;
+
+		#[doc = #preset_doc]
+		#vis trait #preset_trait {
+			#( #trait_items )*
+		}
+
+		#[doc(hidden)]
+		#[allow(non_snake_case)]
+		#vis mod #values_mod {
+			#[allow(unused_imports)]
+			use super::*;
+
+			#frame_support::parameter_types! {
+				#( #values )*
+			}
+		}
+
+		#registered_impl
+	})
+}
@@ -549,7 +549,9 @@ fn generate_storage_instance(
 		})
 		.unwrap_or_default();
 
-	let (pallet_prefix, impl_generics, type_generics) = match prefix_type {
+	// The hash of the pallet prefix is either known at compile time or provided by the pallet, so
+	// that it doesn't need to be computed on every access.
+	let (pallet_prefix, pallet_prefix_hash, impl_generics, type_generics) = match prefix_type {
 		PrefixType::Compatibility =>
 			if !impl_generics_used_by_prefix.is_empty() {
 				let type_generics = impl_generics_used_by_prefix.iter().map(|g| &g.ident);
@@ -559,13 +561,17 @@ fn generate_storage_instance(
 					quote! {
 						< #prefix as #crate_::traits::PalletInfoAccess>::name()
 					},
+					Some(quote! {
+						< #prefix as #crate_::traits::PalletInfoAccess>::name_hash()
+					}),
 					quote!( #( #impl_generics ),* ),
 					quote!( #( #type_generics ),* ),
 				)
 			} else if let Some(prefix) = prefix.get_ident() {
 				let prefix_str = prefix.to_string();
+				let prefix_hash = helper::two128_str(&prefix_str);
 
-				(quote!(#prefix_str), quote!(), quote!())
+				(quote!(#prefix_str), Some(prefix_hash), quote!(), quote!())
 			} else {
 				return Err(Error::new_spanned(
 					prefix,
@@ -581,8 +587,9 @@ fn generate_storage_instance(
 						"Prefix type `verbatim` requires that the prefix is an ident.",
 					)),
 			};
+			let prefix_hash = helper::two128_str(&prefix_str);
 
-			(quote!(#prefix_str), quote!(), quote!())
+			(quote!(#prefix_str), Some(prefix_hash), quote!(), quote!())
 		},
 		PrefixType::PalletName => {
 			let type_generics = impl_generics_used_by_prefix.iter().map(|g| &g.ident);
@@ -592,6 +599,9 @@ fn generate_storage_instance(
 				quote! {
 					<#prefix as #crate_::traits::PalletInfoAccess>::name()
 				},
+				Some(quote! {
+					<#prefix as #crate_::traits::PalletInfoAccess>::name_hash()
+				}),
 				quote!( #( #impl_generics ),* ),
 				quote!( #( #type_generics ),* ),
 			)
@@ -604,6 +614,7 @@ fn generate_storage_instance(
 				quote! {
 					<#prefix as #crate_::traits::Get<_>>::get()
 				},
+				None,
 				quote!( #( #impl_generics ),* ),
 				quote!( #( #type_generics ),* ),
 			)
@@ -611,6 +622,13 @@ fn generate_storage_instance(
 	};
 
 	let where_clause = storage_where_clause.map(|w| quote!(#w)).unwrap_or_default();
+	let pallet_prefix_hash = pallet_prefix_hash.map(|hash| {
+		quote! {
+			fn pallet_prefix_hash() -> [u8; 16] {
+				#hash
+			}
+		}
+	});
 
 	let name_str = format!("{}_Storage_Instance", storage_name);
 	let name = Ident::new(&name_str, Span::call_site());
@@ -633,6 +651,8 @@ fn generate_storage_instance(
 					#pallet_prefix
 				}
 
+				#pallet_prefix_hash
+
 				const STORAGE_PREFIX: &'static str = #counter_storage_name_str;
 				fn storage_prefix_hash() -> [u8; 16] {
 					#storage_prefix_hash
@@ -663,6 +683,8 @@ fn generate_storage_instance(
 				#pallet_prefix
 			}
 
+			#pallet_prefix_hash
+
 			const STORAGE_PREFIX: &'static str = #storage_name_str;
 			fn storage_prefix_hash() -> [u8; 16] {
 				#storage_prefix_hash
@@ -54,30 +54,41 @@ pub fn generate_crate_access(unique_id: &str, def_crate: &str) -> TokenStream {
 ///
 /// This will usually check the output of [`generate_access_from_frame_or_crate`].
 /// We want to know if whatever the `path` takes us to, is exported from `frame` or not. In that
-/// case `path` would start with `frame`, something like `frame::x::y:z`.
+/// case `path` would start with `frame`, or the name `frame` is renamed to, something like
+/// `frame::x::y:z`.
 pub fn is_using_frame_crate(path: &syn::Path) -> bool {
-	path.segments.first().map(|s| s.ident == "frame").unwrap_or(false)
+	path.segments
+		.first()
+		.map(|s| s.ident == "frame" || frame_crate_ident().map_or(false, |frame| s.ident == frame))
+		.unwrap_or(false)
 }
 
 /// Generate the crate access for the crate using 2018 syntax.
 ///
-/// If `frame` is in scope, it will use `frame::deps::<def_crate>`. Else, it will try and find
-/// `<def_crate>` directly.
+/// If `frame` is in scope and re-exports `<def_crate>`, it will use `frame::deps::<def_crate>`.
+/// Else, it will try and find `<def_crate>` directly. Both `frame` and `<def_crate>` may be
+/// renamed in the `Cargo.toml` of the crate being compiled.
 pub fn generate_access_from_frame_or_crate(def_crate: &str) -> Result<syn::Path, Error> {
 	if let Some(path) = get_frame_crate_path(def_crate) {
-		Ok(path)
-	} else {
-		let ident = match crate_name(def_crate) {
-			Ok(FoundCrate::Itself) => {
-				let name = def_crate.to_string().replace("-", "_");
-				Ok(syn::Ident::new(&name, Span::call_site()))
-			},
-			Ok(FoundCrate::Name(name)) => Ok(Ident::new(&name, Span::call_site())),
-			Err(e) => Err(Error::new(Span::call_site(), e)),
-		}?;
-
-		Ok(syn::Path::from(ident))
+		return Ok(path)
 	}
+
+	let ident = match crate_name(def_crate) {
+		Ok(FoundCrate::Itself) => {
+			let name = def_crate.to_string().replace("-", "_");
+			Ok(syn::Ident::new(&name, Span::call_site()))
+		},
+		Ok(FoundCrate::Name(name)) => Ok(Ident::new(&name, Span::call_site())),
+		Err(e) => Err(Error::new(
+			Span::call_site(),
+			format!(
+				"{}. Add either `{}` or `frame` to the dependencies of your crate.",
+				e, def_crate
+			),
+		)),
+	}?;
+
+	Ok(syn::Path::from(ident))
 }
 
 /// Generates the hidden includes that are required to make the macro independent from its scope.
@@ -111,15 +122,132 @@ pub fn generate_hidden_includes(unique_id: &str, def_crate: &str) -> TokenStream
 	}
 }
 
-/// Generates the path to the frame crate deps.
-fn get_frame_crate_path(def_crate: &str) -> Option<syn::Path> {
-	// This does not work if the frame crate is renamed.
-	if let Ok(FoundCrate::Name(name)) = crate_name(&"frame") {
-		let path = format!("{}::deps::{}", name, def_crate.to_string().replace("-", "_"));
-		Some(syn::parse_str::<syn::Path>(&path).expect("is a valid path; qed"))
-	} else {
-		None
+/// Returns the name under which `def_crate` is re-exported in `frame::deps`, if it is.
+///
+/// Some crates are only re-exported with the `runtime` feature of `frame`, `runtime` tells whether
+/// that feature is enabled.
+fn frame_deps_name(def_crate: &str, runtime: bool) -> Option<&'static str> {
+	if let Some(name) = frame_runtime_deps_name(def_crate) {
+		return runtime.then_some(name)
 	}
+
+	Some(match def_crate {
+		"frame-support" => "frame_support",
+		"frame-system" => "frame_system",
+		"sp-arithmetic" => "sp_arithmetic",
+		"sp-core" => "sp_core",
+		"sp-io" => "sp_io",
+		"sp-runtime" => "sp_runtime",
+		"sp-std" => "sp_std",
+		"parity-scale-codec" => "codec",
+		"scale-info" => "scale_info",
+		_ => return None,
+	})
+}
+
+/// Returns the name under which `def_crate` is re-exported in `frame::deps` if it is only
+/// re-exported with the `runtime` feature of `frame`.
+fn frame_runtime_deps_name(def_crate: &str) -> Option<&'static str> {
+	Some(match def_crate {
+		"frame-executive" => "frame_executive",
+		"sp-api" => "sp_api",
+		"sp-block-builder" => "sp_block_builder",
+		"sp-consensus-aura" => "sp_consensus_aura",
+		"sp-consensus-grandpa" => "sp_consensus_grandpa",
+		"sp-inherents" => "sp_inherents",
+		"sp-offchain" => "sp_offchain",
+		"sp-version" => "sp_version",
+		_ => return None,
+	})
+}
+
+/// Returns the identifier to access the `frame` crate with, if it is a dependency.
+///
+/// Within the `frame` crate itself, this is `crate`.
+fn frame_crate_ident() -> Option<Ident> {
+	match crate_name("frame") {
+		Ok(FoundCrate::Itself) => Some(Ident::new("crate", Span::call_site())),
+		Ok(FoundCrate::Name(name)) => Some(Ident::new(&name, Span::call_site())),
+		Err(_) => None,
+	}
+}
+
+/// Returns whether the `runtime` feature of `frame` is enabled for the crate being compiled.
+///
+/// Proc macros can't query the features of dependencies, so this is derived from the manifest of
+/// the crate being compiled. If the manifest can't be read, the feature is assumed to be disabled
+/// so that the crates are accessed directly.
+fn frame_runtime_enabled() -> bool {
+	let Ok(dir) = std::env::var("CARGO_MANIFEST_DIR") else { return false };
+	std::fs::read_to_string(std::path::Path::new(&dir).join("Cargo.toml"))
+		.ok()
+		.and_then(|manifest| manifest.parse::<toml_edit::Document>().ok())
+		.map_or(false, |manifest| manifest_enables_frame_runtime(&manifest))
+}
+
+/// Returns whether `manifest` enables the `runtime` feature of `frame`.
+///
+/// This is the case if `frame` is declared with its default features, if `runtime` is one of the
+/// features it is declared with or if one of the features of the crate enables `frame/runtime`.
+/// Within `frame` itself, the feature has to be enabled by default.
+fn manifest_enables_frame_runtime(manifest: &toml_edit::Document) -> bool {
+	let contains_feature = |features: Option<&toml_edit::Item>, feature: &str| {
+		features
+			.and_then(|f| f.as_array())
+			.map_or(false, |f| f.iter().any(|f| f.as_str() == Some(feature)))
+	};
+	let features = manifest.get("features").and_then(|f| f.as_table_like());
+
+	let package = manifest.get("package").and_then(|p| p.get("name")).and_then(|n| n.as_str());
+	if package == Some("frame") {
+		return contains_feature(features.and_then(|f| f.get("default")), "runtime")
+	}
+
+	let mut dependency_tables = vec![manifest.get("dependencies")];
+	if let Some(targets) = manifest.get("target").and_then(|t| t.as_table_like()) {
+		dependency_tables.extend(targets.iter().map(|(_, target)| target.get("dependencies")));
+	}
+	let Some((name, dependency)) = dependency_tables
+		.into_iter()
+		.flatten()
+		.filter_map(|deps| deps.as_table_like())
+		.flat_map(|deps| deps.iter())
+		.find(|(name, dep)| {
+			dep.get("package").and_then(|p| p.as_str()).unwrap_or(*name) == "frame"
+		})
+	else {
+		return false
+	};
+
+	let Some(dependency) = dependency.as_table_like() else {
+		// Only a version, the default features are enabled.
+		return true
+	};
+	let default_features = dependency
+		.get("default-features")
+		.or_else(|| dependency.get("default_features"))
+		.and_then(|d| d.as_bool())
+		.unwrap_or(true);
+
+	default_features ||
+		contains_feature(dependency.get("features"), "runtime") ||
+		features.map_or(false, |features| {
+			features.iter().any(|(_, enabled)| {
+				contains_feature(Some(enabled), &format!("{}/runtime", name)) ||
+					contains_feature(Some(enabled), &format!("{}?/runtime", name))
+			})
+		})
+}
+
+/// Generates the path to `def_crate` through the frame crate deps.
+///
+/// Returns `None` if `frame` is not a dependency, or if it does not re-export `def_crate`.
+fn get_frame_crate_path(def_crate: &str) -> Option<syn::Path> {
+	let frame = frame_crate_ident()?;
+	let runtime = frame_runtime_deps_name(def_crate).is_some() && frame_runtime_enabled();
+	let deps_name = frame_deps_name(def_crate, runtime)?;
+	let path = format!("{}::deps::{}", frame, deps_name);
+	Some(syn::parse_str::<syn::Path>(&path).expect("is a valid path; qed"))
 }
 
 // fn to remove white spaces around string types
@@ -157,3 +285,62 @@ pub fn get_doc_literals(attrs: &[syn::Attribute]) -> Vec<syn::Expr> {
 		})
 		.collect()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn enables_runtime(manifest: &str) -> bool {
+		manifest_enables_frame_runtime(&manifest.parse().unwrap())
+	}
+
+	#[test]
+	fn frame_deps_name_maps_runtime_deps_only_with_runtime_feature() {
+		assert_eq!(frame_deps_name("frame-support", false), Some("frame_support"));
+		assert_eq!(frame_deps_name("frame-support", true), Some("frame_support"));
+		assert_eq!(frame_deps_name("parity-scale-codec", false), Some("codec"));
+		assert_eq!(frame_deps_name("sp-api", false), None);
+		assert_eq!(frame_deps_name("sp-api", true), Some("sp_api"));
+		assert_eq!(frame_deps_name("frame-executive", false), None);
+		assert_eq!(frame_deps_name("frame-executive", true), Some("frame_executive"));
+		assert_eq!(frame_deps_name("pallet-balances", true), None);
+	}
+
+	#[test]
+	fn manifest_enables_frame_runtime_works() {
+		// default features
+		assert!(enables_runtime("[dependencies]\nframe = \"0.1\""));
+		assert!(enables_runtime("[dependencies]\nframe = { path = \"../frame\" }"));
+		// no default features
+		assert!(!enables_runtime("[dependencies]\nframe = { default-features = false }"));
+		assert!(enables_runtime(
+			"[dependencies]\nframe = { default-features = false, features = [\"runtime\"] }"
+		));
+		assert!(enables_runtime(
+			"[dependencies.frame]\ndefault-features = false\nfeatures = [\"runtime\"]"
+		));
+		// enabled by a feature of the crate
+		assert!(enables_runtime(
+			"[dependencies]\nframe = { default-features = false, optional = true }\n\
+			[features]\nruntime = [\"frame?/runtime\"]"
+		));
+		// renamed
+		assert!(enables_runtime(
+			"[dependencies]\nfrm = { package = \"frame\", default-features = false }\n\
+			[features]\nstd = [\"frm/runtime\"]"
+		));
+		assert!(!enables_runtime(
+			"[dependencies]\nfrm = { package = \"frame\", default-features = false }\n\
+			[features]\nstd = [\"frame/runtime\"]"
+		));
+		// target specific
+		assert!(enables_runtime("[target.'cfg(unix)'.dependencies]\nframe = \"0.1\""));
+		// not a dependency
+		assert!(!enables_runtime("[dependencies]\nsp-api = \"4.0.0\""));
+		// `frame` itself
+		assert!(enables_runtime(
+			"[package]\nname = \"frame\"\n[features]\ndefault = [\"runtime\"]"
+		));
+		assert!(!enables_runtime("[package]\nname = \"frame\"\n[features]\ndefault = [\"std\"]"));
+	}
+}
@@ -45,6 +45,45 @@ pub type DispatchResult = Result<(), sp_runtime::DispatchError>;
 /// The error type contained in a `DispatchResultWithPostInfo`.
 pub type DispatchErrorWithPostInfo = sp_runtime::DispatchErrorWithPostInfo<PostDispatchInfo>;
 
+/// The return type of a dispatchable function which does not pay a fee if it succeeds.
+///
+/// If the dispatchable fails, the fee is paid as usual. Returned from a dispatchable function
+/// instead of hand-rolling the corresponding `PostDispatchInfo`:
+///
+/// ```ignore
+/// pub fn claim(origin: OriginFor<T>) -> RefundIfSuccessful {
+/// 	let who = ensure_signed(origin)?;
+/// 	Self::do_claim(&who)?;
+/// 	Ok(FeeRefund::new())
+/// }
+/// ```
+pub type RefundIfSuccessful = Result<FeeRefund, DispatchErrorWithPostInfo>;
+
+/// The refund of the fee of a successful dispatchable, see [`RefundIfSuccessful`].
+#[derive(Clone, Copy, Eq, PartialEq, Default, RuntimeDebug)]
+pub struct FeeRefund {
+	/// Actual weight consumed by the call or `None` which stands for the worst case static weight.
+	pub actual_weight: Option<Weight>,
+}
+
+impl FeeRefund {
+	/// Refund the fee, keeping the worst case static weight.
+	pub const fn new() -> Self {
+		Self { actual_weight: None }
+	}
+
+	/// Refund the fee and report the `actual_weight` consumed by the call.
+	pub const fn with_actual_weight(actual_weight: Weight) -> Self {
+		Self { actual_weight: Some(actual_weight) }
+	}
+}
+
+impl From<FeeRefund> for PostDispatchInfo {
+	fn from(refund: FeeRefund) -> Self {
+		Self { actual_weight: refund.actual_weight, pays_fee: Pays::No }
+	}
+}
+
 /// Serializable version of pallet dispatchable.
 pub trait Callable<T> {
 	type RuntimeCall: UnfilteredDispatchable + Codec + Clone + PartialEq + Eq;
@@ -19,9 +19,16 @@
 //!
 //! Provides common logic. For more info refer to [`sp_genesis_builder::GenesisBuilder`].
 
-use frame_support::traits::BuildGenesisConfig;
+use frame_support::traits::{
+	genesis_config_errors_message, BuildGenesisConfig, CheckGenesisConfig,
+};
+use serde::{
+	de::{DeserializeSeed, Deserializer, Error, MapAccess, SeqAccess, Visitor},
+	Deserialize,
+};
 use sp_genesis_builder::Result as BuildResult;
 use sp_runtime::format_runtime_string;
+use sp_std::{cell::RefCell, vec::Vec};
 
 /// Get the default `GenesisConfig` as a JSON blob. For more info refer to
 /// [`sp_genesis_builder::GenesisBuilder::create_default_config`]
@@ -36,9 +43,243 @@ where
 
 /// Build `GenesisConfig` from a JSON blob not using any defaults and store it in the storage. For
 /// more info refer to [`sp_genesis_builder::GenesisBuilder::build_config`].
-pub fn build_config<GC: BuildGenesisConfig>(json: sp_std::vec::Vec<u8>) -> BuildResult {
-	let gc = serde_json::from_slice::<GC>(&json)
-		.map_err(|e| format_runtime_string!("Invalid JSON blob: {}", e))?;
+///
+/// If the blob can't be deserialized, the returned error contains the path of the field at which
+/// deserialization failed, e.g. because a bounded collection exceeds its bound. The deserialized
+/// config is checked before it is built, see [`CheckGenesisConfig`].
+pub fn build_config<GC: BuildGenesisConfig + CheckGenesisConfig>(
+	json: sp_std::vec::Vec<u8>,
+) -> BuildResult {
+	let gc = serde_json::from_slice::<GC>(&json).map_err(|e| {
+		let path = json_path_at(&json, e.line(), e.column());
+		match sp_std::str::from_utf8(&path) {
+			Ok(path) if !path.is_empty() =>
+				format_runtime_string!("Invalid JSON blob: {} (at field `{}`)", e, path),
+			_ => format_runtime_string!("Invalid JSON blob: {}", e),
+		}
+	})?;
+	<GC as CheckGenesisConfig>::check(&gc)
+		.map_err(|errors| genesis_config_errors_message(&errors))?;
 	<GC as BuildGenesisConfig>::build(&gc);
 	Ok(())
 }
+
+/// A segment of the path to a JSON value.
+enum Segment {
+	/// The value of the given key of an object.
+	Key(Vec<u8>),
+	/// The element with the given index of an array.
+	Index(usize),
+}
+
+/// Returns the path of the field at the given position in `json`, like `balances.balances[2]`.
+///
+/// `line` and `column` are 1-based, as reported by `serde_json`. The JSON is only parsed as far
+/// as the position, so it doesn't need to be valid beyond it.
+fn json_path_at(json: &[u8], line: usize, column: usize) -> Vec<u8> {
+	let line_start = if line <= 1 {
+		0
+	} else {
+		json.iter()
+			.enumerate()
+			.filter(|(_, byte)| **byte == b'\n')
+			.nth(line - 2)
+			.map_or(json.len(), |(position, _)| position + 1)
+	};
+	let end = json.len().min(line_start + column);
+
+	// Parsing stops with an error at the end of the truncated blob, leaving the path to the
+	// value being parsed there on the stack.
+	let stack = RefCell::new(Vec::new());
+	let mut deserializer = serde_json::Deserializer::from_slice(&json[..end]);
+	let _ = PathTracker(&stack).deserialize(&mut deserializer);
+
+	let mut path = Vec::new();
+	for segment in stack.into_inner() {
+		match segment {
+			Segment::Key(key) => {
+				if !path.is_empty() {
+					path.push(b'.');
+				}
+				path.extend(key);
+			},
+			Segment::Index(index) => {
+				let mut segment = sp_std::Writer::default();
+				let _ = sp_std::fmt::Write::write_fmt(&mut segment, format_args!("[{}]", index));
+				path.extend(segment.into_inner());
+			},
+		}
+	}
+	path
+}
+
+/// Deserializes any JSON value, keeping track of the path to the value being deserialized.
+///
+/// The segment of the last key or element read is kept on the stack until the next one is read,
+/// so that errors reported right after a value are attributed to it.
+struct PathTracker<'a>(&'a RefCell<Vec<Segment>>);
+
+impl<'de> DeserializeSeed<'de> for PathTracker<'_> {
+	type Value = ();
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+		deserializer.deserialize_any(self)
+	}
+}
+
+impl<'de> Visitor<'de> for PathTracker<'_> {
+	type Value = ();
+
+	fn expecting(&self, formatter: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		formatter.write_str("any JSON value")
+	}
+
+	fn visit_bool<E: Error>(self, _: bool) -> Result<(), E> {
+		Ok(())
+	}
+
+	fn visit_i64<E: Error>(self, _: i64) -> Result<(), E> {
+		Ok(())
+	}
+
+	fn visit_u64<E: Error>(self, _: u64) -> Result<(), E> {
+		Ok(())
+	}
+
+	fn visit_f64<E: Error>(self, _: f64) -> Result<(), E> {
+		Ok(())
+	}
+
+	fn visit_str<E: Error>(self, _: &str) -> Result<(), E> {
+		Ok(())
+	}
+
+	fn visit_unit<E: Error>(self) -> Result<(), E> {
+		Ok(())
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+		let mut index = 0;
+		while let Some(()) = seq.next_element_seed(Element { index, stack: self.0 })? {
+			index += 1;
+		}
+		if index > 0 {
+			self.0.borrow_mut().pop();
+		}
+		Ok(())
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+		let mut read_any = false;
+		while let Some(Key(key)) = map.next_key::<Key>()? {
+			if read_any {
+				self.0.borrow_mut().pop();
+			}
+			read_any = true;
+			self.0.borrow_mut().push(Segment::Key(key));
+			map.next_value_seed(PathTracker(self.0))?;
+		}
+		if read_any {
+			self.0.borrow_mut().pop();
+		}
+		Ok(())
+	}
+}
+
+/// An element of an array, replacing the segment of the previous element once it is known to
+/// exist.
+struct Element<'a> {
+	index: usize,
+	stack: &'a RefCell<Vec<Segment>>,
+}
+
+impl<'de> DeserializeSeed<'de> for Element<'_> {
+	type Value = ();
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+		{
+			let mut stack = self.stack.borrow_mut();
+			if self.index > 0 {
+				stack.pop();
+			}
+			stack.push(Segment::Index(self.index));
+		}
+		PathTracker(self.stack).deserialize(deserializer)
+	}
+}
+
+/// An object key.
+struct Key(Vec<u8>);
+
+impl<'de> Deserialize<'de> for Key {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct KeyVisitor;
+
+		impl<'de> Visitor<'de> for KeyVisitor {
+			type Value = Key;
+
+			fn expecting(&self, formatter: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+				formatter.write_str("an object key")
+			}
+
+			fn visit_str<E: Error>(self, key: &str) -> Result<Key, E> {
+				Ok(Key(key.as_bytes().to_vec()))
+			}
+		}
+
+		deserializer.deserialize_str(KeyVisitor)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{traits::ConstU32, BoundedVec};
+
+	#[derive(serde::Serialize, serde::Deserialize)]
+	struct Inner {
+		values: BoundedVec<u32, ConstU32<2>>,
+	}
+
+	#[derive(serde::Serialize, serde::Deserialize)]
+	struct TestGenesisConfig {
+		first: u32,
+		inner: Vec<Inner>,
+	}
+
+	impl BuildGenesisConfig for TestGenesisConfig {
+		fn build(&self) {}
+	}
+
+	impl CheckGenesisConfig for TestGenesisConfig {}
+
+	#[test]
+	fn json_path_at_works() {
+		let json = b"{\"a\": {\"b\": [1, {\"c\": 2}]},\n \"d\": [[\"x\", \"]\"], 3]}";
+		let path_at = |needle: &[u8], line| {
+			let line_start =
+				if line == 1 { 0 } else { json.iter().position(|b| *b == b'\n').unwrap() + 1 };
+			let position = json.windows(needle.len()).position(|window| window == needle).unwrap();
+			String::from_utf8(json_path_at(json, line, position - line_start + 1)).unwrap()
+		};
+
+		assert_eq!(path_at(b"1,", 1), "a.b[0]");
+		assert_eq!(path_at(b"2}", 1), "a.b[1].c");
+		assert_eq!(path_at(b"3]", 2), "d[1]");
+		assert_eq!(path_at(b"\"x\"", 2), "d[0][0]");
+	}
+
+	#[test]
+	fn build_config_reports_field_of_exceeded_bound() {
+		let json = br#"{"first": 1, "inner": [{"values": [1]}, {"values": [1, 2, 3]}]}"#.to_vec();
+		let error = build_config::<TestGenesisConfig>(json).unwrap_err();
+		assert!(error.contains("(at field `inner[1].values[2]`)"), "{}", error);
+
+		let json = br#"{"first": "1", "inner": []}"#.to_vec();
+		let error = build_config::<TestGenesisConfig>(json).unwrap_err();
+		assert!(error.contains("(at field `first`)"), "{}", error);
+
+		let json = br#"{"first": 1, "inner": [{"values": [1, 2]}]}"#.to_vec();
+		assert!(build_config::<TestGenesisConfig>(json).is_ok());
+	}
+}
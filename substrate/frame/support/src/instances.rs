@@ -28,6 +28,10 @@
 //! pub trait Config<I: 'static = ()>: another_pallet::Config<I> {}
 //! ```
 //!
+//! Runtimes needing more than 16 instances of a pallet can use [`IndexedInstance`], which is
+//! declared as is in [`frame_support::construct_runtime`], e.g.
+//! `Council17: pallet_collective::<IndexedInstance<17>>`.
+//!
 //! NOTE: [`frame_support::pallet`] will reexport them inside the module, in order to make them
 //! accessible to [`frame_support::construct_runtime`].
 
@@ -111,3 +115,11 @@ pub struct Instance15;
 #[doc(hidden)]
 #[derive(Clone, Copy, PartialEq, Eq, crate::RuntimeDebugNoBound)]
 pub struct Instance16;
+
+/// Instance with index `N` to be used for instantiable pallets defined with the `#[pallet]` macro,
+/// when more than the 16 named instances are needed.
+///
+/// Instances are distinct types for each `N`, `IndexedInstance<N>` is not the same instance as
+/// `InstanceN` for `N <= 16`.
+#[derive(Clone, Copy, PartialEq, Eq, crate::RuntimeDebugNoBound)]
+pub struct IndexedInstance<const N: u16>;
@@ -173,6 +173,13 @@ pub use frame_support_procedural::storage_alias;
 
 pub use frame_support_procedural::derive_impl;
 
+/// Declare a named bag of parameters that can be injected into a trait impl via
+/// [`derive_impl`].
+///
+/// # Example
+#[doc = docify::embed!("src/tests/parameter_preset.rs", parameter_preset_works_with_derive_impl)]
+pub use frame_support_procedural::parameter_preset;
+
 /// Create new implementations of the [`Get`](crate::traits::Get) trait.
 ///
 /// The so-called parameter type can be created in four different ways:
@@ -830,7 +837,10 @@ pub mod testing_prelude {
 pub mod pallet_prelude {
 	pub use crate::{
 		defensive, defensive_assert,
-		dispatch::{DispatchClass, DispatchResult, DispatchResultWithPostInfo, Parameter, Pays},
+		dispatch::{
+			DispatchClass, DispatchResult, DispatchResultWithPostInfo, FeeRefund, Parameter, Pays,
+			RefundIfSuccessful,
+		},
 		ensure,
 		inherent::{InherentData, InherentIdentifier, ProvideInherent},
 		storage,
@@ -846,8 +856,9 @@ pub mod pallet_prelude {
 			StorageList,
 		},
 		traits::{
-			BuildGenesisConfig, ConstU32, EnsureOrigin, Get, GetDefault, GetStorageVersion, Hooks,
-			IsType, PalletInfoAccess, StorageInfoTrait, StorageVersion, TypedGet,
+			BuildGenesisConfig, CheckGenesisConfig, ConstU32, EnsureOrigin, Get, GetDefault,
+			GetStorageVersion, Hooks, IsType, PalletInfoAccess, StorageInfoTrait, StorageVersion,
+			TypedGet,
 		},
 		Blake2_128, Blake2_128Concat, Blake2_256, CloneNoBound, DebugNoBound, EqNoBound, Identity,
 		PartialEqNoBound, RuntimeDebugNoBound, Twox128, Twox256, Twox64Concat,
@@ -936,14 +947,17 @@ pub mod pallet_prelude {
 /// * [`pallet::storage_prefix = "SomeName"`](#palletstorage_prefix--somename-optional)
 /// * [`pallet::unbounded`](#palletunbounded-optional)
 /// * [`pallet::whitelist_storage`](#palletwhitelist_storage-optional)
+/// * [`pallet::storage(deprecated)`](#palletstoragedeprecated-optional)
 /// * [`cfg(..)`](#cfg-for-storage) (on storage items)
 /// * [`pallet::type_value`](#type-value-pallettype_value-optional)
 /// * [`pallet::genesis_config`](#genesis-config-palletgenesis_config-optional)
 /// * [`pallet::genesis_build`](#genesis-build-palletgenesis_build-optional)
+/// * [`pallet::genesis_check`](#genesis-check-palletgenesis_check-optional)
 /// * [`pallet::inherent`](#inherent-palletinherent-optional)
 /// * [`pallet::validate_unsigned`](#validate-unsigned-palletvalidate_unsigned-optional)
 /// * [`pallet::origin`](#origin-palletorigin-optional)
 /// * [`pallet::composite_enum`](#composite-enum-palletcomposite_enum-optional)
+/// * [`pallet::tasks_experimental`](#tasks-pallettasks_experimental-optional)
 ///
 /// Note that at compile-time, the `#[pallet]` macro will analyze and expand all of these
 /// attributes, ultimately removing their AST nodes before they can be parsed as real
@@ -1187,7 +1201,7 @@ pub mod pallet_prelude {
 /// 		$some_arg: $some_type,
 /// 		// or with compact attribute: #[pallet::compact] $some_arg: $some_type,
 /// 		...
-/// 	) -> DispatchResultWithPostInfo { // or `-> DispatchResult`
+/// 	) -> DispatchResultWithPostInfo { // or `-> DispatchResult` or `-> RefundIfSuccessful`
 /// 		...
 /// 	}
 /// 	...
@@ -1196,6 +1210,10 @@ pub mod pallet_prelude {
 /// I.e. a regular type implementation, with generic `T: Config`, on type `Pallet<T>`, with
 /// an optional where clause.
 ///
+/// A dispatchable which should not pay a fee if it succeeds can return
+/// [`RefundIfSuccessful`](dispatch::RefundIfSuccessful) with a
+/// [`FeeRefund`](dispatch::FeeRefund), instead of building the `PostDispatchInfo` itself.
+///
 /// ## `#[pallet::weight($expr)]`
 ///
 /// Each dispatchable needs to define a weight with `#[pallet::weight($expr)]` attribute, the
@@ -1441,6 +1459,10 @@ pub mod pallet_prelude {
 /// the pallet struct placeholder), the size of the storage will be declared as unbounded. This
 /// can be useful for storage which can never go into PoV (Proof of Validity).
 ///
+/// The keys and the value of every storage which is not unbounded must implement
+/// `MaxEncodedLen`. The macro checks this for each of them on its own, so a missing
+/// implementation is reported at the offending type in the storage declaration.
+///
 /// Also see [`pallet::unbounded`](`frame_support::pallet_macros::unbounded`)
 ///
 /// ## `#[pallet::whitelist_storage]` (optional)
@@ -1452,6 +1474,18 @@ pub mod pallet_prelude {
 /// [`pallet::whitelist_storage`](frame_support::pallet_macros::whitelist_storage)
 /// for more info.
 ///
+/// ## `#[pallet::storage(deprecated)]` (optional)
+///
+/// Declares a storage item which is scheduled for removal. The item keeps working as before,
+/// but every read or write of it is logged and counted, and its metadata documentation is
+/// prefixed with a deprecation notice. The accesses of a block are folded into an on chain
+/// counter once per block by the hooks of the pallet, which account for the weight of doing
+/// so. Once the item is no longer accessed, it can be removed
+/// with [`migrations::RemoveDeprecatedStorage`], which waits for a number of runtime upgrades
+/// without any access before removing it.
+///
+/// See [`storage::deprecation`] for more info.
+///
 ///	## `#[cfg(..)]` (for storage)
 /// The optional attributes `#[cfg(..)]` allow conditional compilation for the storage.
 ///
@@ -1586,6 +1620,26 @@ pub mod pallet_prelude {
 ///
 /// Also see [`pallet::genesis_build`](`frame_support::pallet_macros::genesis_build`)
 ///
+/// # Genesis check: `#[pallet::genesis_check]` (optional)
+///
+/// The `#[pallet::genesis_check]` attribute allows you to validate the `GenesisConfig` before
+/// any pallet is built. It requires `#[pallet::genesis_config]`.
+///
+/// The impl must be defined as:
+///
+/// ```ignore
+/// #[pallet::genesis_check]
+/// impl<T: Config> CheckGenesisConfig for GenesisConfig<$maybe_generics> {
+/// 	fn check(&self) -> Result<(), Vec<RuntimeString>> { $expr }
+/// }
+/// ```
+///
+/// `construct_runtime!` runs the checks of all pallets when building the genesis storage, both
+/// through `BuildStorage` and through the `GenesisBuilder` runtime API, and reports every
+/// error, prefixed with the name of its pallet.
+///
+/// Also see [`pallet::genesis_check`](`frame_support::pallet_macros::genesis_check`)
+///
 /// # Inherent: `#[pallet::inherent]` (optional)
 ///
 /// The `#[pallet::inherent]` attribute allows the pallet to provide some
@@ -1679,6 +1733,39 @@ pub mod pallet_prelude {
 /// The inverse is also true: if there are any #[derive] attributes present for the enum, then
 /// the attribute will not automatically derive any of the traits described above.
 ///
+/// # Tasks `#[pallet::tasks_experimental]` (optional)
+///
+/// The `#[pallet::tasks_experimental]` attribute allows you to declare conditional and
+/// idempotent pieces of work, so called tasks, on an `impl<T: Config> Pallet<T>` block:
+///
+/// ```ignore
+/// #[pallet::tasks_experimental]
+/// impl<T: Config> Pallet<T> {
+/// 	#[pallet::task_list(Numbers::<T>::iter_keys())]
+/// 	#[pallet::task_condition(|i| Numbers::<T>::contains_key(i))]
+/// 	#[pallet::task_weight(Weight::default())]
+/// 	#[pallet::task_index(0)]
+/// 	pub fn add_number_into_total(i: u32) -> DispatchResult { .. }
+/// }
+/// ```
+///
+/// Every function of the block is a task and is annotated with:
+/// * `#[pallet::task_list($expr)]`: an expression enumerating the arguments of all the
+///   potential tasks, as tuples if the task takes several arguments,
+/// * `#[pallet::task_condition($closure)]`: a closure taking references to the arguments and
+///   returning whether the task is valid, i.e. has work pending,
+/// * `#[pallet::task_weight($expr)]`: the weight of running the task,
+/// * `#[pallet::task_index($lit)]`: the index of the task, unique within the pallet.
+///
+/// The macro generates an enum `Task<T>` with a variant per task, which implements
+/// [`traits::Task`]. `construct_runtime` aggregates the `Task` enums of all pallets into the
+/// `RuntimeTask` enum, which is provided to `frame_system` through
+/// [`traits::tasks::RuntimeTasks`]. A valid task can be run by anyone through
+/// `frame_system::Call::do_task`, which is only available with the `experimental` feature of
+/// `frame-system`.
+///
+/// NOTE: This feature is experimental and its API may change.
+///
 /// # General notes on instantiable pallets
 ///
 /// An instantiable pallet is one where Config is generic, i.e. `Config<I>`. This allows
@@ -2227,9 +2314,10 @@ pub use frame_support_procedural::pallet;
 pub mod pallet_macros {
 	pub use frame_support_procedural::{
 		call_index, compact, composite_enum, config, disable_frame_system_supertrait_check, error,
-		event, extra_constants, feeless_if, generate_deposit, generate_store, getter, hooks,
-		import_section, inherent, no_default, no_default_bounds, origin, pallet_section,
-		storage_prefix, storage_version, type_value, unbounded, validate_unsigned, weight,
+		event, extra_constants, feature_gated, feeless_if, generate_deposit, generate_store,
+		getter, hooks, import_section, inherent, no_default, no_default_bounds, origin,
+		pallet_section, storage_prefix, storage_version, task_condition, task_index, task_list,
+		task_weight, tasks_experimental, type_value, unbounded, validate_unsigned, weight,
 		whitelist_storage,
 	};
 
@@ -2303,6 +2391,48 @@ pub mod pallet_macros {
 	/// ```
 	pub use frame_support_procedural::genesis_build;
 
+	/// Allows you to check the genesis configuration of the pallet before the genesis state of
+	/// the runtime is built.
+	///
+	/// The checks of all pallets are run first and their errors are aggregated, so a malformed
+	/// chain spec fails with the complete list of problems. Pallets without this attribute
+	/// accept any genesis configuration.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// #[frame_support::pallet]
+	/// pub mod pallet {
+	/// # 	#[pallet::config]
+	/// # 	pub trait Config: frame_system::Config {}
+	/// # 	#[pallet::pallet]
+	/// # 	pub struct Pallet<T>(_);
+	/// # 	use frame_support::traits::{BuildGenesisConfig, CheckGenesisConfig};
+	/// # 	use sp_runtime::RuntimeString;
+	///     #[pallet::genesis_config]
+	///     #[derive(frame_support::DefaultNoBound)]
+	///     pub struct GenesisConfig<T: Config> {
+	///         foo: Vec<T::AccountId>
+	///     }
+	///
+	///     #[pallet::genesis_build]
+	///     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+	///         fn build(&self) {}
+	///     }
+	///
+	///     #[pallet::genesis_check]
+	///     impl<T: Config> CheckGenesisConfig for GenesisConfig<T> {
+	///         fn check(&self) -> Result<(), Vec<RuntimeString>> {
+	///             if self.foo.is_empty() {
+	///                 return Err(vec!["foo must not be empty".into()])
+	///             }
+	///             Ok(())
+	///         }
+	///     }
+	/// }
+	/// ```
+	pub use frame_support_procedural::genesis_check;
+
 	/// The `#[pallet::constant]` attribute can be used to add an associated type trait bounded
 	/// by [`Get`](frame_support::pallet_prelude::Get) from [`pallet::config`](`macro@config`)
 	/// into metadata.
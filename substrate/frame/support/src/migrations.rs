@@ -345,3 +345,343 @@ impl<P: Get<&'static str>, DbWeight: Get<RuntimeDbWeight>> frame_support::traits
 		Ok(())
 	}
 }
+
+/// `RemoveDeprecatedStorage` removes a storage item declared with
+/// `#[pallet::storage(deprecated)]`, once it was not accessed for `N` consecutive runtime upgrades.
+///
+/// On every runtime upgrade, the accesses counted since the previous upgrade are inspected. If the
+/// item was accessed, the count of unaccessed upgrades starts over. Otherwise it is incremented
+/// and, once it reaches `N`, all keys of the item are removed together with its access counter.
+/// Keep the migration in the runtime until it logs that the item was removed, then remove the item
+/// from the pallet.
+///
+/// `P` is the name of the pallet in `construct_runtime!` and `S` the name of the storage item, i.e.
+/// its `storage_prefix`.
+///
+/// WARNING: like [`RemovePallet`], this migration has no guard rails preventing it from exceeding
+/// the block weight limit if the item has too many keys.
+pub struct RemoveDeprecatedStorage<
+	P: Get<&'static str>,
+	S: Get<&'static str>,
+	N: Get<u32>,
+	DbWeight: Get<RuntimeDbWeight>,
+>(PhantomData<(P, S, N, DbWeight)>);
+impl<P: Get<&'static str>, S: Get<&'static str>, N: Get<u32>, DbWeight: Get<RuntimeDbWeight>>
+	frame_support::traits::OnRuntimeUpgrade for RemoveDeprecatedStorage<P, S, N, DbWeight>
+{
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		use crate::storage::{deprecation, storage_prefix};
+
+		let (pallet, storage) = (P::get(), S::get());
+		// Include the accesses noted since the last block was finalized.
+		deprecation::fold_block_accesses(pallet, storage);
+		let fold_weight = deprecation::fold_block_accesses_weight(DbWeight::get());
+		let mut access = deprecation::access(pallet.as_bytes(), storage.as_bytes());
+		if access.accesses > 0 {
+			log::info!(
+				"{}::{} was accessed {} times since the last upgrade, keeping it 👀",
+				pallet,
+				storage,
+				access.accesses,
+			);
+			access = deprecation::DeprecatedStorageAccess::default();
+			deprecation::set_access(pallet.as_bytes(), storage.as_bytes(), access);
+			return fold_weight.saturating_add(DbWeight::get().reads_writes(1, 1))
+		}
+
+		access.unaccessed_upgrades = access.unaccessed_upgrades.saturating_add(1);
+		if access.unaccessed_upgrades < N::get() {
+			log::info!(
+				"{}::{} was not accessed for {} of {} upgrades",
+				pallet,
+				storage,
+				access.unaccessed_upgrades,
+				N::get(),
+			);
+			deprecation::set_access(pallet.as_bytes(), storage.as_bytes(), access);
+			return fold_weight.saturating_add(DbWeight::get().reads_writes(1, 1))
+		}
+
+		let hashed_prefix = storage_prefix(pallet.as_bytes(), storage.as_bytes());
+		let keys_removed = match clear_prefix(&hashed_prefix, None) {
+			KillStorageResult::AllRemoved(value) => value,
+			KillStorageResult::SomeRemaining(value) => {
+				log::error!(
+					"`clear_prefix` failed to remove all keys for {}::{}. THIS SHOULD NEVER HAPPEN! 🚨",
+					pallet,
+					storage,
+				);
+				value
+			},
+		} as u64;
+		deprecation::set_access(pallet.as_bytes(), storage.as_bytes(), Default::default());
+
+		log::info!("Removed {} {}::{} keys 🧹", keys_removed, pallet, storage);
+
+		fold_weight.saturating_add(DbWeight::get().reads_writes(keys_removed + 1, keys_removed + 1))
+	}
+}
+
+/// A migration with a declared upper bound of the weight which its execution may consume.
+///
+/// The bound is used by [`DeferrableMigrations`] to decide whether the migration still fits into
+/// the current block. A migration which exceeds its bound is still executed to completion, but a
+/// warning is logged.
+pub struct CappedMigration<Inner, MaxWeight>(PhantomData<(Inner, MaxWeight)>);
+
+/// An ordered sequence of migrations with declared maximum weights.
+///
+/// Implemented for [`CappedMigration`] and tuples thereof, so that sequences can be nested.
+pub trait CappedMigrations {
+	/// The number of migrations in the sequence.
+	fn len() -> u32;
+
+	/// The maximum weight of the migration at `index`, or `None` if there is no such migration.
+	fn max_weight(index: u32) -> Option<Weight>;
+
+	/// Execute the migration at `index` and return the weight it consumed.
+	fn execute(index: u32) -> Weight;
+
+	/// Execute the migration at `index` with the try-runtime checks, see
+	/// [`crate::traits::OnRuntimeUpgrade::try_on_runtime_upgrade`].
+	#[cfg(feature = "try-runtime")]
+	fn try_execute(index: u32, checks: bool) -> Result<Weight, sp_runtime::TryRuntimeError>;
+}
+
+impl<Inner: crate::traits::OnRuntimeUpgrade, MaxWeight: Get<Weight>> CappedMigrations
+	for CappedMigration<Inner, MaxWeight>
+{
+	fn len() -> u32 {
+		1
+	}
+
+	fn max_weight(index: u32) -> Option<Weight> {
+		(index == 0).then(MaxWeight::get)
+	}
+
+	fn execute(index: u32) -> Weight {
+		debug_assert_eq!(index, 0, "a single migration has only index 0");
+		let weight = Inner::on_runtime_upgrade();
+		if weight.any_gt(MaxWeight::get()) {
+			log::warn!(
+				"Migration consumed {:?}, exceeding its declared maximum weight of {:?}",
+				weight,
+				MaxWeight::get(),
+			);
+		}
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn try_execute(_index: u32, checks: bool) -> Result<Weight, sp_runtime::TryRuntimeError> {
+		Inner::try_on_runtime_upgrade(checks)
+	}
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+impl CappedMigrations for Tuple {
+	fn len() -> u32 {
+		let mut len = 0u32;
+		for_tuples!( #( len = len.saturating_add(Tuple::len()); )* );
+		len
+	}
+
+	#[allow(unused_assignments)]
+	fn max_weight(mut index: u32) -> Option<Weight> {
+		for_tuples!( #(
+			if index < Tuple::len() {
+				return Tuple::max_weight(index)
+			}
+			index -= Tuple::len();
+		)* );
+		None
+	}
+
+	#[allow(unused_assignments)]
+	fn execute(mut index: u32) -> Weight {
+		for_tuples!( #(
+			if index < Tuple::len() {
+				return Tuple::execute(index)
+			}
+			index -= Tuple::len();
+		)* );
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[allow(unused_assignments)]
+	fn try_execute(mut index: u32, checks: bool) -> Result<Weight, sp_runtime::TryRuntimeError> {
+		for_tuples!( #(
+			if index < Tuple::len() {
+				return Tuple::try_execute(index, checks)
+			}
+			index -= Tuple::len();
+		)* );
+		Ok(Weight::zero())
+	}
+}
+
+/// Executes a sequence of [`CappedMigrations`] in order, deferring the remaining migrations to the
+/// next block once executing another one could exceed `BlockLimit`.
+///
+/// It takes 4 type parameters:
+/// - `Id`: A unique name of the sequence, used to derive the storage key of its cursor.
+/// - `Migrations`: The [`CappedMigrations`] to execute.
+/// - `BlockLimit`: The maximum weight to spend on the migrations per block.
+/// - `DbWeight`: The runtime's RuntimeDbWeight implementation.
+///
+/// On a runtime upgrade, the migrations are executed from the start for as long as the sum of their
+/// declared maximum weights fits into `BlockLimit`. The index of the first migration which did not
+/// fit is persisted as a cursor and execution resumes from there in the following blocks through
+/// [`OnRuntimeUpgrade::continue_runtime_upgrade`](crate::traits::OnRuntimeUpgrade). The first
+/// migration of each block is always executed, so a migration whose maximum weight exceeds
+/// `BlockLimit` on its own ends up in a block of its own. A runtime upgrade happening while
+/// migrations are still pending resumes them from the cursor instead of starting over, so that no
+/// migration is executed twice. With `try-runtime`, the migrations are checked in the same steps.
+///
+/// WARNING: Deferred migrations run at the beginning of the following blocks, before any
+/// `on_initialize` hook. Only defer migrations which the runtime tolerates to be pending for a few
+/// blocks, i.e. which do not change storage that is accessed before they completed.
+///
+/// ### Examples
+/// ```ignore
+/// parameter_types! {
+/// 	pub const MigrationsId: &'static str = "MyMigrations";
+/// 	pub const MaxMigrationWeight: Weight = Weight::from_parts(500_000_000_000, 0);
+/// 	pub MaxMigrationsPerBlock: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
+/// }
+///
+/// // Migrations tuple to pass to the Executive pallet:
+/// pub type Migrations = DeferrableMigrations<
+/// 	MigrationsId,
+/// 	(
+/// 		CappedMigration<MigrateFoo, MaxMigrationWeight>,
+/// 		CappedMigration<MigrateBar, MaxMigrationWeight>,
+/// 	),
+/// 	MaxMigrationsPerBlock,
+/// 	<Runtime as frame_system::Config>::DbWeight,
+/// >;
+/// ```
+pub struct DeferrableMigrations<Id, Migrations, BlockLimit, DbWeight>(
+	PhantomData<(Id, Migrations, BlockLimit, DbWeight)>,
+);
+
+impl<
+		Id: Get<&'static str>,
+		Migrations: CappedMigrations,
+		BlockLimit: Get<Weight>,
+		DbWeight: Get<RuntimeDbWeight>,
+	> DeferrableMigrations<Id, Migrations, BlockLimit, DbWeight>
+{
+	/// The storage key of the cursor, i.e. the index of the next migration to execute.
+	pub fn cursor_key() -> [u8; 32] {
+		crate::storage::storage_prefix(b"DeferrableMigrations", Id::get().as_bytes())
+	}
+
+	/// The index of the next migration to execute, if any migrations are pending.
+	pub fn cursor() -> Option<u32> {
+		crate::storage::unhashed::get(&Self::cursor_key())
+	}
+
+	/// Execute migrations starting from `cursor` until the `BlockLimit` is reached and persist the
+	/// index of the next pending migration, if any.
+	///
+	/// Returns the consumed weight and the index of the next pending migration.
+	fn step<E>(
+		mut cursor: u32,
+		mut execute: impl FnMut(u32) -> Result<Weight, E>,
+	) -> Result<(Weight, Option<u32>), E> {
+		let start = cursor;
+		let limit = BlockLimit::get();
+		// Writing or removing the cursor.
+		let mut weight = DbWeight::get().writes(1);
+
+		while let Some(max_weight) = Migrations::max_weight(cursor) {
+			if cursor != start && weight.saturating_add(max_weight).any_gt(limit) {
+				break
+			}
+			weight.saturating_accrue(execute(cursor)?);
+			cursor += 1;
+		}
+
+		if cursor < Migrations::len() {
+			log::info!(
+				"{}: executed migrations {}..{}, deferring the remaining {} to the next block",
+				Id::get(),
+				start,
+				cursor,
+				Migrations::len() - cursor,
+			);
+			crate::storage::unhashed::put(&Self::cursor_key(), &cursor);
+			Ok((weight, Some(cursor)))
+		} else {
+			log::info!("{}: executed all {} migrations", Id::get(), Migrations::len());
+			crate::storage::unhashed::kill(&Self::cursor_key());
+			Ok((weight, None))
+		}
+	}
+
+	/// Execute the migrations of a block starting from `cursor`, see [`Self::step`].
+	fn execute_step(cursor: u32) -> Weight {
+		let result = Self::step(cursor, |index| {
+			Ok::<_, sp_std::convert::Infallible>(Migrations::execute(index))
+		});
+		match result {
+			Ok((weight, _)) => weight,
+			Err(never) => match never {},
+		}
+	}
+}
+
+impl<
+		Id: Get<&'static str>,
+		Migrations: CappedMigrations,
+		BlockLimit: Get<Weight>,
+		DbWeight: Get<RuntimeDbWeight>,
+	> crate::traits::OnRuntimeUpgrade for DeferrableMigrations<Id, Migrations, BlockLimit, DbWeight>
+{
+	/// Executes the migrations from the start, or resumes the pending ones if a previous upgrade
+	/// did not complete them yet, so that no migration is executed twice.
+	fn on_runtime_upgrade() -> Weight {
+		let cursor = Self::cursor();
+		if let Some(cursor) = cursor {
+			log::warn!(
+				"{}: runtime upgraded while {} migrations were still pending, resuming them",
+				Id::get(),
+				Migrations::len().saturating_sub(cursor),
+			);
+		}
+		DbWeight::get().reads(1).saturating_add(Self::execute_step(cursor.unwrap_or(0)))
+	}
+
+	fn continue_runtime_upgrade() -> Weight {
+		let weight = DbWeight::get().reads(1);
+		match Self::cursor() {
+			Some(cursor) => weight.saturating_add(Self::execute_step(cursor)),
+			None => weight,
+		}
+	}
+
+	fn runtime_upgrade_pending() -> bool {
+		Self::cursor().is_some()
+	}
+
+	/// Executes the migrations in the same steps as [`Self::on_runtime_upgrade`] and the following
+	/// blocks would, starting from the stored cursor, so that each of them is checked.
+	#[cfg(feature = "try-runtime")]
+	fn try_on_runtime_upgrade(checks: bool) -> Result<Weight, sp_runtime::TryRuntimeError> {
+		let mut weight = DbWeight::get().reads(1);
+		let mut cursor = Self::cursor().unwrap_or(0);
+		loop {
+			let (step_weight, next) =
+				Self::step(cursor, |index| Migrations::try_execute(index, checks))?;
+			weight.saturating_accrue(step_weight);
+			match next {
+				Some(next) => cursor = next,
+				None => return Ok(weight),
+			}
+		}
+	}
+}
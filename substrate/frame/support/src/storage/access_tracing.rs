@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracing of the storage keys accessed by some code, meant to be used in tests.
+//!
+//! With the `storage-access-tracing` feature enabled, `trace_storage_access` records the keys
+//! which are read and written through the storage abstractions of this crate while executing a
+//! closure, e.g. a dispatchable. Tests can then assert on the returned `StorageAccess` to catch
+//! accidental coupling between pallets, or to check that the storage items touched by a call
+//! match what its benchmark assumes.
+//!
+//! Accesses done directly through `sp_io`, bypassing this crate, are not recorded. Without the
+//! feature, the tracing hooks compile to nothing, except in the unit tests of this crate which
+//! always trace.
+//!
+//! ```ignore
+//! let (result, access) = trace_storage_access(|| Balances::transfer_allow_death(origin, 2, 10));
+//! assert!(result.is_ok());
+//! assert_eq!(
+//! 	access.written_items(),
+//! 	[storage_prefix(b"System", b"Account")].into_iter().collect(),
+//! );
+//! ```
+
+#[cfg(any(test, feature = "storage-access-tracing"))]
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// The storage keys accessed while executing some code.
+#[cfg(any(test, feature = "storage-access-tracing"))]
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct StorageAccess {
+	/// The keys which were read.
+	pub reads: BTreeSet<Vec<u8>>,
+	/// The keys which were written, including removed keys. Removals by prefix are recorded
+	/// with the prefix which was removed.
+	pub writes: BTreeSet<Vec<u8>>,
+}
+
+#[cfg(any(test, feature = "storage-access-tracing"))]
+impl StorageAccess {
+	/// The storage items which were read, identified by the 32 byte prefix of their pallet and
+	/// storage name.
+	///
+	/// Keys which are shorter than such a prefix, like well-known keys, are skipped.
+	pub fn read_items(&self) -> BTreeSet<[u8; 32]> {
+		Self::items(&self.reads)
+	}
+
+	/// The storage items which were written, identified by the 32 byte prefix of their pallet and
+	/// storage name.
+	///
+	/// Keys which are shorter than such a prefix, like well-known keys, are skipped.
+	pub fn written_items(&self) -> BTreeSet<[u8; 32]> {
+		Self::items(&self.writes)
+	}
+
+	fn items(keys: &BTreeSet<Vec<u8>>) -> BTreeSet<[u8; 32]> {
+		keys.iter().filter_map(|key| key.get(..32)?.try_into().ok()).collect()
+	}
+}
+
+#[cfg(any(test, feature = "storage-access-tracing"))]
+environmental::environmental!(STORAGE_ACCESS: StorageAccess);
+
+/// Execute `f`, recording the storage keys it accesses.
+///
+/// Calls can be nested, in which case the accesses of the inner call are recorded by the outer
+/// one as well.
+#[cfg(any(test, feature = "storage-access-tracing"))]
+pub fn trace_storage_access<R>(f: impl FnOnce() -> R) -> (R, StorageAccess) {
+	let mut access = StorageAccess::default();
+	let result = STORAGE_ACCESS::using(&mut access, f);
+
+	STORAGE_ACCESS::with(|outer| {
+		outer.reads.extend(access.reads.iter().cloned());
+		outer.writes.extend(access.writes.iter().cloned());
+	});
+
+	(result, access)
+}
+
+/// Note that `key` was read.
+#[inline]
+pub(crate) fn note_read(_key: &[u8]) {
+	#[cfg(any(test, feature = "storage-access-tracing"))]
+	STORAGE_ACCESS::with(|access| access.reads.insert(_key.to_vec()));
+}
+
+/// Note that `key` was written.
+#[inline]
+pub(crate) fn note_write(_key: &[u8]) {
+	#[cfg(any(test, feature = "storage-access-tracing"))]
+	STORAGE_ACCESS::with(|access| access.writes.insert(_key.to_vec()));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::storage::{storage_prefix, unhashed, StorageMap, StorageValue};
+	use sp_io::TestExternalities;
+
+	#[crate::storage_alias]
+	type Value = StorageValue<Prefix, u32>;
+
+	#[crate::storage_alias]
+	type Map = StorageMap<Prefix, crate::Twox64Concat, u32, u32>;
+
+	#[test]
+	fn accesses_are_recorded() {
+		TestExternalities::default().execute_with(|| {
+			Value::put(1);
+
+			let (value, access) = trace_storage_access(|| {
+				Map::insert(1, 2);
+				Value::get()
+			});
+			assert_eq!(value, Some(1));
+			assert_eq!(access.reads, [Value::hashed_key().to_vec()].into_iter().collect());
+			assert_eq!(access.writes, [Map::hashed_key_for(1)].into_iter().collect());
+			assert_eq!(
+				access.written_items(),
+				[storage_prefix(b"Prefix", b"Map")].into_iter().collect(),
+			);
+
+			let (_, access) = trace_storage_access(|| unhashed::get_raw(b":code"));
+			assert_eq!(access.reads.len(), 1);
+			assert!(access.read_items().is_empty());
+		});
+	}
+
+	#[test]
+	fn nested_accesses_are_recorded_by_outer_trace() {
+		TestExternalities::default().execute_with(|| {
+			let (inner, outer) = trace_storage_access(|| {
+				Value::put(1);
+				trace_storage_access(|| Map::take(1)).1
+			});
+
+			assert_eq!(inner.reads, [Map::hashed_key_for(1)].into_iter().collect());
+			assert!(inner.writes.is_empty());
+			assert_eq!(outer.reads, inner.reads);
+			assert_eq!(outer.writes, [Value::hashed_key().to_vec()].into_iter().collect());
+		});
+	}
+}
@@ -0,0 +1,122 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Instrumentation of deprecated storage items.
+//!
+//! Storage items declared with `#[pallet::storage(deprecated)]` keep working as before, but every
+//! read or write of them is logged and counted. Computing their metadata or storage info is not an
+//! access.
+//!
+//! Within a block, accesses are counted in a key which is removed before the end of the block, so
+//! that they never reach the state and don't need to be weighed. The pallet folds them into the
+//! [`DeprecatedStorageAccess`] of the item in its `on_finalize`, and again in its `on_initialize`
+//! for the accesses which happened after it, both weighed by `on_initialize`. This lets
+//! [`RemoveDeprecatedStorage`](crate::migrations::RemoveDeprecatedStorage) remove the item once it
+//! has not been accessed for a number of runtime upgrades.
+
+use crate::{
+	storage::{storage_prefix, unhashed},
+	weights::{RuntimeDbWeight, Weight},
+};
+use codec::{Decode, Encode};
+use sp_std::prelude::*;
+
+const LOG_TARGET: &str = "runtime::storage::deprecation";
+
+/// The suffix appended to the name of a deprecated storage item to derive the key of its
+/// [`DeprecatedStorageAccess`].
+///
+/// Storage names are Rust identifiers, so this can't collide with the name of another item.
+const ACCESS_SUFFIX: &[u8] = b":deprecated_access";
+
+/// The suffix appended to the name of a deprecated storage item to derive the key of the number
+/// of accesses in the current block.
+const BLOCK_ACCESS_SUFFIX: &[u8] = b":deprecated_block_access";
+
+/// The accesses of a deprecated storage item.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug)]
+pub struct DeprecatedStorageAccess {
+	/// The number of accesses since the last runtime upgrade.
+	pub accesses: u32,
+	/// The number of consecutive runtime upgrades before which the item was not accessed.
+	pub unaccessed_upgrades: u32,
+}
+
+/// The key of the [`DeprecatedStorageAccess`] of the item `storage` of `pallet`.
+pub fn access_key(pallet: &[u8], storage: &[u8]) -> [u8; 32] {
+	storage_prefix(pallet, &[storage, ACCESS_SUFFIX].concat())
+}
+
+/// The key of the number of accesses to the item `storage` of `pallet` in the current block.
+fn block_access_key(pallet: &[u8], storage: &[u8]) -> [u8; 32] {
+	storage_prefix(pallet, &[storage, BLOCK_ACCESS_SUFFIX].concat())
+}
+
+/// The accesses of the item `storage` of `pallet`.
+///
+/// The accesses of the current block are only included once they are folded in by
+/// [`fold_block_accesses`].
+pub fn access(pallet: &[u8], storage: &[u8]) -> DeprecatedStorageAccess {
+	unhashed::get_or_default(&access_key(pallet, storage))
+}
+
+/// Set the accesses of the item `storage` of `pallet`, removing them if they are the default.
+pub fn set_access(pallet: &[u8], storage: &[u8], access: DeprecatedStorageAccess) {
+	let key = access_key(pallet, storage);
+	if access == DeprecatedStorageAccess::default() {
+		unhashed::kill(&key);
+	} else {
+		unhashed::put(&key, &access);
+	}
+}
+
+/// Note an access to the deprecated item `storage` of `pallet`.
+///
+/// Called by the code generated for `#[pallet::storage(deprecated)]` whenever the item is read or
+/// written.
+pub fn note_access(pallet: &str, storage: &str) {
+	log::warn!(
+		target: LOG_TARGET,
+		"🚨 deprecated storage item {}::{} was accessed",
+		pallet,
+		storage,
+	);
+
+	let key = block_access_key(pallet.as_bytes(), storage.as_bytes());
+	unhashed::put(&key, &unhashed::get_or_default::<u32>(&key).saturating_add(1));
+}
+
+/// Fold the accesses to the item `storage` of `pallet` noted in the current block into its
+/// [`DeprecatedStorageAccess`].
+///
+/// Called by the hooks of the pallet declaring the item.
+pub fn fold_block_accesses(pallet: &str, storage: &str) {
+	let Some(accesses) =
+		unhashed::take::<u32>(&block_access_key(pallet.as_bytes(), storage.as_bytes()))
+	else {
+		return
+	};
+
+	let mut current = access(pallet.as_bytes(), storage.as_bytes());
+	current.accesses = current.accesses.saturating_add(accesses);
+	set_access(pallet.as_bytes(), storage.as_bytes(), current);
+}
+
+/// The worst case weight of a call to [`fold_block_accesses`].
+pub fn fold_block_accesses_weight(db_weight: RuntimeDbWeight) -> Weight {
+	db_weight.reads_writes(2, 2)
+}
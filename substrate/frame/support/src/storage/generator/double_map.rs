@@ -63,6 +63,10 @@ pub trait StorageDoubleMap<K1: FullEncode, K2: FullEncode, V: FullCodec> {
 	/// `storage_prefix`.
 	fn prefix_hash() -> [u8; 32];
 
+	/// Called whenever the storage is read or written, see
+	/// [`StorageInstance::on_access`](crate::traits::StorageInstance::on_access).
+	fn on_access() {}
+
 	/// Convert an optional value retrieved from storage to the type queried.
 	fn from_optional_value_to_query(v: Option<V>) -> Self::Query;
 
@@ -74,6 +78,7 @@ pub trait StorageDoubleMap<K1: FullEncode, K2: FullEncode, V: FullCodec> {
 	where
 		KArg1: EncodeLike<K1>,
 	{
+		Self::on_access();
 		let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 		let key_hashed = k1.using_encoded(Self::Hasher1::hash);
 
@@ -91,6 +96,7 @@ pub trait StorageDoubleMap<K1: FullEncode, K2: FullEncode, V: FullCodec> {
 		KArg1: EncodeLike<K1>,
 		KArg2: EncodeLike<K2>,
 	{
+		Self::on_access();
 		let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 		let key1_hashed = k1.using_encoded(Self::Hasher1::hash);
 		let key2_hashed = k2.using_encoded(Self::Hasher2::hash);
@@ -318,6 +324,7 @@ where
 		V: StorageAppend<Item>,
 	{
 		let final_key = Self::storage_double_map_final_key(k1, k2);
+		crate::storage::access_tracing::note_write(&final_key);
 		sp_io::storage::append(&final_key, item.encode());
 	}
 
@@ -331,6 +338,7 @@ where
 		key2: KeyArg2,
 	) -> Option<V> {
 		let old_key = {
+			Self::on_access();
 			let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 
 			let key1_hashed = key1.using_encoded(OldHasher1::hash);
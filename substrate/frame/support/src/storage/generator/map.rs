@@ -52,6 +52,10 @@ pub trait StorageMap<K: FullEncode, V: FullCodec> {
 	/// `storage_prefix`.
 	fn prefix_hash() -> [u8; 32];
 
+	/// Called whenever the storage is read or written, see
+	/// [`StorageInstance::on_access`](crate::traits::StorageInstance::on_access).
+	fn on_access() {}
+
 	/// Convert an optional value retrieved from storage to the type queried.
 	fn from_optional_value_to_query(v: Option<V>) -> Self::Query;
 
@@ -63,6 +67,7 @@ pub trait StorageMap<K: FullEncode, V: FullCodec> {
 	where
 		KeyArg: EncodeLike<K>,
 	{
+		Self::on_access();
 		let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 		let key_hashed = key.using_encoded(Self::Hasher::hash);
 
@@ -331,11 +336,13 @@ impl<K: FullEncode, V: FullCodec, G: StorageMap<K, V>> storage::StorageMap<K, V>
 		V: StorageAppend<Item>,
 	{
 		let key = Self::storage_map_final_key(key);
+		crate::storage::access_tracing::note_write(&key);
 		sp_io::storage::append(&key, item.encode());
 	}
 
 	fn migrate_key<OldHasher: StorageHasher, KeyArg: EncodeLike<K>>(key: KeyArg) -> Option<V> {
 		let old_key = {
+			Self::on_access();
 			let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 			let key_hashed = key.using_encoded(OldHasher::hash);
 
@@ -71,6 +71,10 @@ pub trait StorageNMap<K: KeyGenerator, V: FullCodec> {
 	/// `storage_prefix`.
 	fn prefix_hash() -> [u8; 32];
 
+	/// Called whenever the storage is read or written, see
+	/// [`StorageInstance::on_access`](crate::traits::StorageInstance::on_access).
+	fn on_access() {}
+
 	/// Convert an optional value retrieved from storage to the type queried.
 	fn from_optional_value_to_query(v: Option<V>) -> Self::Query;
 
@@ -82,6 +86,7 @@ pub trait StorageNMap<K: KeyGenerator, V: FullCodec> {
 	where
 		K: HasKeyPrefix<KP>,
 	{
+		Self::on_access();
 		let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 		let key_hashed = <K as HasKeyPrefix<KP>>::partial_key(key);
 
@@ -99,6 +104,7 @@ pub trait StorageNMap<K: KeyGenerator, V: FullCodec> {
 		KG: KeyGenerator,
 		KArg: EncodeLikeTuple<KG::KArg> + TupleToEncodedIter,
 	{
+		Self::on_access();
 		let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 		let key_hashed = KG::final_key(key);
 
@@ -288,6 +294,7 @@ where
 		V: StorageAppend<Item>,
 	{
 		let final_key = Self::storage_n_map_final_key::<K, _>(key);
+		crate::storage::access_tracing::note_write(&final_key);
 		sp_io::storage::append(&final_key, item.encode());
 	}
 
@@ -296,6 +303,7 @@ where
 		KArg: EncodeLikeTuple<K::KArg> + TupleToEncodedIter,
 	{
 		let old_key = {
+			Self::on_access();
 			let storage_prefix = storage_prefix(Self::pallet_prefix(), Self::storage_prefix());
 			let key_hashed = K::migrate_key(&key, hash_fns);
 
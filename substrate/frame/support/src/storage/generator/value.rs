@@ -156,6 +156,7 @@ impl<T: FullCodec, G: StorageValue<T>> storage::StorageValue<T> for G {
 		T: StorageAppend<Item>,
 	{
 		let key = Self::storage_value_final_key();
+		crate::storage::access_tracing::note_write(&key);
 		sp_io::storage::append(&key, item.encode());
 	}
 }
@@ -0,0 +1,167 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Computation of storage keys from metadata-level information.
+//!
+//! The final key of a storage item is
+//! `twox_128(pallet_prefix) ++ twox_128(storage_name) ++ hasher_1(key_1) ++ ... ++
+//! hasher_n(key_n)`, where the hashers are the ones declared for the item and listed in the
+//! metadata. The functions of this module derive keys from exactly this information, so that
+//! tooling which only knows the metadata of a runtime can derive the same keys as the runtime
+//! itself.
+
+use crate::{
+	__private::metadata_ir::{PalletMetadataIR, StorageEntryTypeIR, StorageHasherIR},
+	hash::{
+		Blake2_128, Blake2_128Concat, Blake2_256, Identity, StorageHasher, Twox128, Twox256,
+		Twox64Concat,
+	},
+};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// Reasons why a storage key can not be computed from the metadata.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum StorageKeyError {
+	/// There is no pallet with the given name.
+	UnknownPallet,
+	/// The pallet has no storage item with the given name.
+	UnknownStorage,
+	/// More keys were given than the storage item has hashers.
+	TooManyKeys,
+}
+
+/// Hash the encoded `key` with `hasher`, the way a storage map does with its keys.
+pub fn hash_key(hasher: &StorageHasherIR, key: &[u8]) -> Vec<u8> {
+	match hasher {
+		StorageHasherIR::Blake2_128 => Blake2_128::hash(key).to_vec(),
+		StorageHasherIR::Blake2_256 => Blake2_256::hash(key).to_vec(),
+		StorageHasherIR::Blake2_128Concat => Blake2_128Concat::hash(key),
+		StorageHasherIR::Twox128 => Twox128::hash(key).to_vec(),
+		StorageHasherIR::Twox256 => Twox256::hash(key).to_vec(),
+		StorageHasherIR::Twox64Concat => Twox64Concat::hash(key),
+		StorageHasherIR::Identity => Identity::hash(key),
+	}
+}
+
+/// Compute the key of a storage item from its pallet prefix, its name and its encoded keys,
+/// each paired with the hasher to use for it.
+///
+/// Passing fewer keys than the item has hashers gives the prefix of all the entries sharing the
+/// given keys. Without any keys, this is the key of a storage value or the prefix of a map.
+pub fn storage_key(
+	pallet_prefix: &[u8],
+	storage_name: &[u8],
+	keys: &[(StorageHasherIR, &[u8])],
+) -> Vec<u8> {
+	let mut final_key = super::storage_prefix(pallet_prefix, storage_name).to_vec();
+	for (hasher, key) in keys {
+		final_key.extend(hash_key(hasher, key));
+	}
+	final_key
+}
+
+/// Compute the key of the storage item `storage_name` of the pallet `pallet_name` from the
+/// metadata of the runtime.
+///
+/// The hashers to use for the encoded `keys` are looked up in the metadata. Like with
+/// [`storage_key`], fewer keys than hashers give a prefix.
+pub fn storage_key_from_metadata(
+	pallets: &[PalletMetadataIR],
+	pallet_name: &[u8],
+	storage_name: &[u8],
+	keys: &[Vec<u8>],
+) -> Result<Vec<u8>, StorageKeyError> {
+	let pallet = pallets
+		.iter()
+		.find(|pallet| pallet.name.as_bytes() == pallet_name)
+		.ok_or(StorageKeyError::UnknownPallet)?;
+	let storage = pallet.storage.as_ref().ok_or(StorageKeyError::UnknownStorage)?;
+	let entry = storage
+		.entries
+		.iter()
+		.find(|entry| entry.name.as_bytes() == storage_name)
+		.ok_or(StorageKeyError::UnknownStorage)?;
+
+	let hashers = match &entry.ty {
+		StorageEntryTypeIR::Plain(_) => &[][..],
+		StorageEntryTypeIR::Map { hashers, .. } => &hashers[..],
+	};
+	if keys.len() > hashers.len() {
+		return Err(StorageKeyError::TooManyKeys)
+	}
+
+	let keys = hashers
+		.iter()
+		.zip(keys)
+		.map(|(hasher, key)| (hasher.clone(), &key[..]))
+		.collect::<Vec<_>>();
+	Ok(storage_key(storage.prefix.as_bytes(), storage_name, &keys))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{storage::StoragePrefixedMap, storage_alias};
+
+	#[storage_alias]
+	type Value = StorageValue<Test, u32>;
+
+	#[storage_alias]
+	type Map = StorageMap<Test, Blake2_128Concat, u32, u32>;
+
+	#[storage_alias]
+	type DoubleMap = StorageDoubleMap<Test, Twox64Concat, u32, Identity, u64, u32>;
+
+	#[test]
+	fn storage_key_matches_storage_types() {
+		assert_eq!(storage_key(b"Test", b"Value", &[]), Value::hashed_key().to_vec());
+		assert_eq!(
+			storage_key(b"Test", b"Map", &[(StorageHasherIR::Blake2_128Concat, &7u32.encode())]),
+			Map::hashed_key_for(7),
+		);
+		assert_eq!(
+			storage_key(
+				b"Test",
+				b"DoubleMap",
+				&[
+					(StorageHasherIR::Twox64Concat, &7u32.encode()),
+					(StorageHasherIR::Identity, &9u64.encode())
+				]
+			),
+			DoubleMap::hashed_key_for(7, 9),
+		);
+		assert_eq!(
+			storage_key(b"Test", b"DoubleMap", &[(StorageHasherIR::Twox64Concat, &7u32.encode())]),
+			[DoubleMap::final_prefix().to_vec(), Twox64Concat::hash(&7u32.encode())].concat(),
+		);
+	}
+
+	#[test]
+	fn hash_key_matches_hashers() {
+		let key = 42u64.encode();
+
+		assert_eq!(hash_key(&StorageHasherIR::Blake2_128, &key), Blake2_128::hash(&key));
+		assert_eq!(hash_key(&StorageHasherIR::Blake2_256, &key), Blake2_256::hash(&key));
+		assert_eq!(hash_key(&StorageHasherIR::Twox128, &key), Twox128::hash(&key));
+		assert_eq!(hash_key(&StorageHasherIR::Twox256, &key), Twox256::hash(&key));
+		assert_eq!(hash_key(&StorageHasherIR::Twox64Concat, &key), Twox64Concat::hash(&key));
+		assert_eq!(hash_key(&StorageHasherIR::Identity, &key), key);
+	}
+}
@@ -32,20 +32,24 @@ use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, prelude::*};
 pub use self::{
 	stream_iter::StorageStreamIter,
 	transactional::{
-		in_storage_layer, with_storage_layer, with_transaction, with_transaction_unchecked,
+		in_storage_layer, with_storage_layer, with_transaction, with_transaction_multi,
+		with_transaction_unchecked,
 	},
 	types::StorageEntryMetadataBuilder,
 };
 pub use sp_runtime::TransactionOutcome;
 pub use types::Key;
 
+pub mod access_tracing;
 pub mod bounded_btree_map;
 pub mod bounded_btree_set;
 pub mod bounded_vec;
 pub mod child;
+pub mod deprecation;
 #[doc(hidden)]
 pub mod generator;
 pub mod hashed;
+pub mod keys;
 pub mod migration;
 pub mod storage_noop_guard;
 mod stream_iter;
@@ -1187,6 +1191,7 @@ impl<T> Iterator for KeyPrefixIterator<T> {
 				.filter(|n| n.starts_with(&self.prefix));
 
 			if let Some(next) = maybe_next {
+				access_tracing::note_read(&next);
 				self.previous_key = next;
 				if self.drain {
 					unhashed::kill(&self.previous_key);
@@ -1482,6 +1487,7 @@ pub trait StorageDecodeLength: private::Sealed + codec::DecodeLength {
 	fn decode_len(key: &[u8]) -> Option<usize> {
 		// `Compact<u32>` is 5 bytes in maximum.
 		let mut data = [0u8; 5];
+		access_tracing::note_read(key);
 		let len = sp_io::storage::read(key, &mut data, 0)?;
 		let len = data.len().min(len as usize);
 		<Self as codec::DecodeLength>::len(&data[..len]).ok()
@@ -1505,6 +1511,7 @@ pub trait StorageDecodeNonDedupLength: private::Sealed + codec::DecodeLength {
 	/// Returns `None` if the storage value does not exist or the decoding failed.
 	fn decode_non_dedup_len(key: &[u8]) -> Option<usize> {
 		let mut data = [0u8; 5];
+		access_tracing::note_read(key);
 		let len = sp_io::storage::read(key, &mut data, 0)?;
 		let len = data.len().min(len as usize);
 		<Self as codec::DecodeLength>::len(&data[..len]).ok()
@@ -1604,6 +1611,7 @@ where
 			// NOTE: we cannot reuse the implementation for `Vec<T>` here because we never want to
 			// mark `BoundedVec<T, S>` as `StorageAppend`.
 			let key = Self::storage_value_final_key();
+			access_tracing::note_write(&key);
 			sp_io::storage::append(&key, item.encode());
 			Ok(())
 		} else {
@@ -1638,6 +1646,7 @@ where
 		let current = Self::decode_len(key.clone()).unwrap_or_default();
 		if current < bound {
 			let key = Self::storage_map_final_key(key);
+			access_tracing::note_write(&key);
 			sp_io::storage::append(&key, item.encode());
 			Ok(())
 		} else {
@@ -1683,6 +1692,7 @@ where
 		let current = Self::decode_len(key1.clone(), key2.clone()).unwrap_or_default();
 		if current < bound {
 			let double_map_key = Self::storage_double_map_final_key(key1, key2);
+			access_tracing::note_write(&double_map_key);
 			sp_io::storage::append(&double_map_key, item.encode());
 			Ok(())
 		} else {
@@ -240,6 +240,7 @@ impl StorageInput {
 			buffer.set_len(buffer.capacity());
 		}
 
+		crate::storage::access_tracing::note_read(&key);
 		let (total_length, exists) =
 			if let Some(total_length) = sp_io::storage::read(&key, &mut buffer, 0) {
 				(total_length, true)
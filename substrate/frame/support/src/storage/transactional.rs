@@ -27,7 +27,8 @@
 //! [`with_transaction`] provides a way to run a given closure in a transactional context.
 
 use sp_io::storage::{commit_transaction, rollback_transaction, start_transaction};
-use sp_runtime::{DispatchError, TransactionOutcome, TransactionalError};
+use sp_runtime::{DispatchError, RuntimeDebug, TransactionOutcome, TransactionalError};
+use sp_std::marker::PhantomData;
 
 /// The type that is being used to store the current number of active layers.
 pub type Layer = u32;
@@ -200,6 +201,93 @@ where
 	}
 }
 
+/// The steps of an atomic operation run by [`with_transaction_multi`].
+///
+/// Each step is typically a call into the interface of another pallet, returning its own error
+/// type. Steps convert their errors into the error union `E` of the operation, and record which
+/// step failed.
+pub struct Steps<E> {
+	next: u32,
+	failed: Option<(u32, &'static str)>,
+	_phantom: PhantomData<E>,
+}
+
+impl<E> Steps<E> {
+	fn new() -> Self {
+		Self { next: 0, failed: None, _phantom: PhantomData }
+	}
+
+	/// Run the step `label`, converting its error into the error union `E`.
+	pub fn run<R, StepError: Into<E>>(
+		&mut self,
+		label: &'static str,
+		f: impl FnOnce() -> Result<R, StepError>,
+	) -> Result<R, E> {
+		let step = self.next;
+		self.next = self.next.saturating_add(1);
+		self.failed = None;
+		f().map_err(|err| {
+			self.failed = Some((step, label));
+			err.into()
+		})
+	}
+}
+
+/// Why an atomic operation run by [`with_transaction_multi`] was rolled back.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RolledBack<E> {
+	/// The index of the failed step, in the order the steps were run.
+	///
+	/// `None` if the operation failed outside of any step.
+	pub step: Option<u32>,
+	/// The label of the failed step, if any.
+	pub label: Option<&'static str>,
+	/// The error the operation failed with.
+	pub error: E,
+}
+
+impl<E: Into<DispatchError>> From<RolledBack<E>> for DispatchError {
+	fn from(rolled_back: RolledBack<E>) -> Self {
+		rolled_back.error.into()
+	}
+}
+
+/// Execute the supplied function, made of several [`Steps`], atomically in a new storage
+/// transaction.
+///
+/// This composes state changes across the interfaces of multiple pallets, e.g. a swap followed by
+/// a transfer and a lock, without each of them reimplementing its own rollback logic. The errors of
+/// all steps are converted into the error union `E`, usually an enum with a variant, and a `From`
+/// implementation, for the error type of each interface used.
+///
+/// All changes to storage are committed if the function returns `Ok`, and discarded otherwise, in
+/// which case the error is returned along with the step it happened in.
+pub fn with_transaction_multi<T, E, F>(f: F) -> Result<T, RolledBack<E>>
+where
+	E: From<DispatchError>,
+	F: FnOnce(&mut Steps<E>) -> Result<T, E>,
+{
+	let mut steps = Steps::new();
+	with_transaction(|| {
+		let r = f(&mut steps);
+		if r.is_ok() {
+			TransactionOutcome::Commit(r)
+		} else {
+			TransactionOutcome::Rollback(r)
+		}
+	})
+	.map_err(|error| {
+		let (step, label) = steps.failed.unzip();
+		log::debug!(
+			target: "runtime::storage",
+			"atomic operation rolled back at step {:?} ({:?})",
+			step,
+			label,
+		);
+		RolledBack { step, label, error }
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -300,4 +388,69 @@ mod tests {
 			assert_noop!(res, "epic fail");
 		});
 	}
+
+	#[derive(Debug, PartialEq)]
+	enum MultiError {
+		Swap(&'static str),
+		Transfer(u32),
+		Dispatch(DispatchError),
+	}
+
+	impl From<&'static str> for MultiError {
+		fn from(err: &'static str) -> Self {
+			Self::Swap(err)
+		}
+	}
+
+	impl From<DispatchError> for MultiError {
+		fn from(err: DispatchError) -> Self {
+			Self::Dispatch(err)
+		}
+	}
+
+	#[test]
+	fn with_transaction_multi_works() {
+		TestExternalities::default().execute_with(|| {
+			let swap = || -> Result<(), &'static str> {
+				crate::storage::unhashed::put(b"swapped", &true);
+				Ok(())
+			};
+
+			// all steps succeed, the changes are committed.
+			let res = with_transaction_multi(|steps: &mut Steps<MultiError>| {
+				steps.run("swap", swap)?;
+				steps.run("transfer", || -> Result<u32, DispatchError> { Ok(5) })
+			});
+			assert_eq!(res, Ok(5));
+			assert_eq!(crate::storage::unhashed::get(b"swapped"), Some(true));
+			crate::storage::unhashed::kill(b"swapped");
+
+			// the second step fails, the changes of the first are rolled back.
+			let res = with_transaction_multi(|steps: &mut Steps<MultiError>| {
+				steps.run("swap", swap)?;
+				steps.run("transfer", || Err(MultiError::Transfer(42)))
+			});
+			assert_eq!(
+				res,
+				Err::<(), _>(RolledBack {
+					step: Some(1),
+					label: Some("transfer"),
+					error: MultiError::Transfer(42),
+				})
+			);
+			assert_eq!(crate::storage::unhashed::get::<bool>(b"swapped"), None);
+			assert_eq!(get_transaction_level(), 0);
+
+			// failing outside of any step.
+			let res = with_transaction_multi(|steps: &mut Steps<MultiError>| {
+				steps.run("swap", swap)?;
+				Err::<(), _>(MultiError::Swap("slippage"))
+			});
+			assert_eq!(
+				res,
+				Err(RolledBack { step: None, label: None, error: MultiError::Swap("slippage") })
+			);
+			assert_eq!(crate::storage::unhashed::get::<bool>(b"swapped"), None);
+		});
+	}
 }
@@ -414,6 +414,7 @@ where
 		if current < bound {
 			CounterFor::<Prefix>::mutate(|value| value.saturating_inc());
 			let key = <Self as MapWrapper>::Map::hashed_key_for(key);
+			crate::storage::access_tracing::note_write(&key);
 			sp_io::storage::append(&key, item.encode());
 			Ok(())
 		} else {
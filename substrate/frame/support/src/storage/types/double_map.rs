@@ -170,8 +170,12 @@ where
 		Prefix::STORAGE_PREFIX.as_bytes()
 	}
 	fn prefix_hash() -> [u8; 32] {
+		Prefix::on_access();
 		Prefix::prefix_hash()
 	}
+	fn on_access() {
+		Prefix::on_access()
+	}
 
 	fn from_optional_value_to_query(v: Option<Value>) -> Self::Query {
 		QueryKind::from_optional_value_to_query(v)
@@ -515,6 +519,7 @@ where
 	/// overlay are not taken into account when deleting keys in the backend.
 	#[deprecated = "Use `clear` instead"]
 	pub fn remove_all(limit: Option<u32>) -> sp_io::KillStorageResult {
+		Prefix::on_access();
 		#[allow(deprecated)]
 		<Self as crate::storage::StoragePrefixedMap<Value>>::remove_all(limit)
 	}
@@ -543,6 +548,7 @@ where
 	/// operating on the same map should always pass `Some`, and this should be equal to the
 	/// previous call result's `maybe_cursor` field.
 	pub fn clear(limit: u32, maybe_cursor: Option<&[u8]>) -> sp_io::MultiRemovalResults {
+		Prefix::on_access();
 		<Self as crate::storage::StoragePrefixedMap<Value>>::clear(limit, maybe_cursor)
 	}
 
@@ -550,6 +556,7 @@ where
 	///
 	/// NOTE: If a value failed to decode because storage is corrupted then it is skipped.
 	pub fn iter_values() -> crate::storage::PrefixIterator<Value> {
+		Prefix::on_access();
 		<Self as crate::storage::StoragePrefixedMap<Value>>::iter_values()
 	}
 
@@ -567,6 +574,7 @@ where
 	///
 	/// This would typically be called inside the module implementation of on_runtime_upgrade.
 	pub fn translate_values<OldValue: Decode, F: FnMut(OldValue) -> Option<Value>>(f: F) {
+		Prefix::on_access();
 		<Self as crate::storage::StoragePrefixedMap<Value>>::translate_values(f)
 	}
 
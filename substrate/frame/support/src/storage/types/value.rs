@@ -88,6 +88,7 @@ where
 		QueryKind::from_query_to_optional_value(v)
 	}
 	fn storage_value_final_key() -> [u8; 32] {
+		Prefix::on_access();
 		Prefix::prefix_hash()
 	}
 }
@@ -303,7 +304,7 @@ where
 		vec![StorageInfo {
 			pallet_name: Self::pallet_prefix().to_vec(),
 			storage_name: Self::storage_prefix().to_vec(),
-			prefix: Self::hashed_key().to_vec(),
+			prefix: Prefix::prefix_hash().to_vec(),
 			max_values: Some(1),
 			max_size: Some(Value::max_encoded_len().saturated_into()),
 		}]
@@ -323,7 +324,7 @@ where
 		vec![StorageInfo {
 			pallet_name: Self::pallet_prefix().to_vec(),
 			storage_name: Self::storage_prefix().to_vec(),
-			prefix: Self::hashed_key().to_vec(),
+			prefix: Prefix::prefix_hash().to_vec(),
 			max_values: Some(1),
 			max_size: None,
 		}]
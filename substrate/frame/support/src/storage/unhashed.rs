@@ -17,11 +17,13 @@
 
 //! Operation on unhashed runtime storage.
 
+use super::access_tracing::{note_read, note_write};
 use codec::{Decode, Encode};
 use sp_std::prelude::*;
 
 /// Return the value of the item in storage under `key`, or `None` if there is no explicit entry.
 pub fn get<T: Decode + Sized>(key: &[u8]) -> Option<T> {
+	note_read(key);
 	sp_io::storage::get(key).and_then(|val| {
 		Decode::decode(&mut &val[..]).map(Some).unwrap_or_else(|e| {
 			// TODO #3700: error should be handleable.
@@ -56,6 +58,7 @@ pub fn get_or_else<T: Decode + Sized, F: FnOnce() -> T>(key: &[u8], default_valu
 
 /// Put `value` in storage under `key`.
 pub fn put<T: Encode + ?Sized>(key: &[u8], value: &T) {
+	note_write(key);
 	value.using_encoded(|slice| sp_io::storage::set(key, slice));
 }
 
@@ -88,17 +91,20 @@ pub fn take_or_else<T: Decode + Sized, F: FnOnce() -> T>(key: &[u8], default_val
 
 /// Check to see if `key` has an explicit entry in storage.
 pub fn exists(key: &[u8]) -> bool {
+	note_read(key);
 	sp_io::storage::exists(key)
 }
 
 /// Ensure `key` has no explicit entry in storage.
 pub fn kill(key: &[u8]) {
+	note_write(key);
 	sp_io::storage::clear(key);
 }
 
 /// Ensure keys with the given `prefix` have no entries in storage.
 #[deprecated = "Use `clear_prefix` instead"]
 pub fn kill_prefix(prefix: &[u8], limit: Option<u32>) -> sp_io::KillStorageResult {
+	note_write(prefix);
 	// TODO: Once the network has upgraded to include the new host functions, this code can be
 	// enabled.
 	// clear_prefix(prefix, limit).into()
@@ -158,6 +164,7 @@ pub fn clear_prefix(
 /// and is longer than said prefix.
 /// This means that a key which equals the prefix will not be counted.
 pub fn contains_prefixed_key(prefix: &[u8]) -> bool {
+	note_read(prefix);
 	match sp_io::storage::next_key(prefix) {
 		Some(key) => key.starts_with(prefix),
 		None => false,
@@ -166,6 +173,7 @@ pub fn contains_prefixed_key(prefix: &[u8]) -> bool {
 
 /// Get a Vec of bytes from storage.
 pub fn get_raw(key: &[u8]) -> Option<Vec<u8>> {
+	note_read(key);
 	sp_io::storage::get(key).map(|value| value.to_vec())
 }
 
@@ -175,5 +183,6 @@ pub fn get_raw(key: &[u8]) -> Option<Vec<u8>> {
 /// you should also call `frame_system::RuntimeUpgraded::put(true)` to trigger the
 /// `on_runtime_upgrade` logic.
 pub fn put_raw(key: &[u8], value: &[u8]) {
+	note_write(key);
 	sp_io::storage::set(key, value)
 }
@@ -26,6 +26,7 @@ use sp_runtime::{generic, traits::BlakeTwo256, BuildStorage};
 pub use self::frame_system::{pallet_prelude::*, Config, Pallet};
 
 mod inject_runtime_type;
+mod parameter_preset;
 mod storage_alias;
 
 #[pallet]
@@ -632,6 +633,37 @@ fn store_metadata() {
 	pretty_assertions::assert_eq!(expected_metadata(), metadata);
 }
 
+#[test]
+fn storage_key_from_metadata_matches_storage_types() {
+	use crate::storage::{
+		keys::{storage_key_from_metadata, StorageKeyError},
+		StoragePrefixedMap,
+	};
+	use codec::Encode;
+
+	let pallets = Runtime::metadata_ir().pallets;
+	let key = |storage: &[u8], keys: &[Vec<u8>]| {
+		storage_key_from_metadata(&pallets, b"System", storage, keys)
+	};
+
+	assert_eq!(
+		key(b"Data", &[7u32.encode()]),
+		Ok(frame_system::Data::<Runtime>::hashed_key_for(7))
+	);
+	assert_eq!(
+		key(b"DataDM", &[7u32.encode(), 9u32.encode()]),
+		Ok(frame_system::DataDM::<Runtime>::hashed_key_for(7, 9))
+	);
+	assert_eq!(key(b"DataDM", &[]), Ok(frame_system::DataDM::<Runtime>::final_prefix().to_vec()));
+
+	assert_eq!(key(b"Data", &[7u32.encode(), 9u32.encode()]), Err(StorageKeyError::TooManyKeys));
+	assert_eq!(key(b"Unknown", &[]), Err(StorageKeyError::UnknownStorage));
+	assert_eq!(
+		storage_key_from_metadata(&pallets, b"Unknown", b"Data", &[]),
+		Err(StorageKeyError::UnknownPallet)
+	);
+}
+
 parameter_types! {
 	storage StorageParameter: u64 = 10;
 }
@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{derive_impl, traits::Get};
+use static_assertions::assert_type_eq_all;
+
+trait BalancesLikeConfig {
+	type ExistentialDeposit: Get<u64>;
+	type MaxLocks: Get<u32>;
+	type MaxReserves: Get<u32>;
+}
+
+mod presets {
+	crate::parameter_preset! {
+		/// Parameters shared by the test runtimes below.
+		pub struct TestnetParams: TestnetPreset {
+			/// The minimum balance of an account.
+			ExistentialDeposit: u64 = 1;
+			MaxLocks: u32 = 50;
+			MaxReserves: u32 = 25 * 2;
+		}
+	}
+}
+
+#[docify::export]
+#[test]
+fn parameter_preset_works_with_derive_impl() {
+	struct Runtime;
+
+	#[derive_impl(presets::TestnetParams as presets::TestnetPreset)]
+	impl BalancesLikeConfig for Runtime {
+		// We decide to override this one.
+		type MaxLocks = crate::traits::ConstU32<10>;
+	}
+
+	assert_eq!(<Runtime as BalancesLikeConfig>::ExistentialDeposit::get(), 1);
+	assert_eq!(<Runtime as BalancesLikeConfig>::MaxLocks::get(), 10);
+	assert_eq!(<Runtime as BalancesLikeConfig>::MaxReserves::get(), 50);
+}
+
+#[test]
+fn parameter_preset_types_are_shared() {
+	use presets::TestnetPreset;
+
+	struct Runtime;
+
+	#[derive_impl(presets::TestnetParams as presets::TestnetPreset)]
+	impl BalancesLikeConfig for Runtime {}
+
+	assert_type_eq_all!(
+		<Runtime as BalancesLikeConfig>::ExistentialDeposit,
+		<presets::TestnetParams as TestnetPreset>::ExistentialDeposit,
+	);
+	assert_eq!(
+		<<presets::TestnetParams as TestnetPreset>::MaxLocks as crate::traits::TypedGet>::get(),
+		50,
+	);
+}
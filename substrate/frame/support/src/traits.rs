@@ -75,24 +75,28 @@ pub use randomness::Randomness;
 
 mod metadata;
 pub use metadata::{
-	CallMetadata, CrateVersion, GetCallIndex, GetCallMetadata, GetCallName, GetStorageVersion,
-	NoStorageVersionSet, PalletInfo, PalletInfoAccess, PalletInfoData, PalletsInfoAccess,
-	StorageVersion, STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+	CallMetadata, CrateVersion, GetCallIndex, GetCallMetadata, GetCallName,
+	GetPalletsStorageVersions, GetStorageVersion, NoStorageVersionSet, PalletInfo,
+	PalletInfoAccess, PalletInfoData, PalletStorageVersions, PalletsInfoAccess, StorageVersion,
+	STORAGE_VERSION_STORAGE_KEY_POSTFIX,
 };
 
 mod hooks;
 #[allow(deprecated)]
 pub use hooks::GenesisBuild;
 pub use hooks::{
-	BeforeAllRuntimeMigrations, BuildGenesisConfig, Hooks, IntegrityTest, OnFinalize, OnGenesis,
-	OnIdle, OnInitialize, OnRuntimeUpgrade, OnTimestampSet,
+	genesis_config_errors_message, note_on_initialize_failure, prefix_genesis_config_error,
+	BeforeAllRuntimeMigrations, BuildGenesisConfig, CheckGenesisConfig, Hooks, IntegrityTest,
+	OnFinalize, OnGenesis, OnIdle, OnInitialize, OnInitializeIsolated, OnRuntimeUpgrade,
+	OnTimestampSet,
 };
 
 pub mod schedule;
 mod storage;
 pub use storage::{
-	Consideration, Footprint, Incrementable, Instance, LinearStoragePrice, PartialStorageInfoTrait,
-	StorageInfo, StorageInfoTrait, StorageInstance, TrackedStorageKey, WhitelistedStorageKeys,
+	Consideration, ExponentialStoragePrice, Footprint, Incrementable, Instance, LinearStoragePrice,
+	PartialStorageInfoTrait, PerItemAndByteStoragePrice, SteppedStoragePrice, StorageInfo,
+	StorageInfoTrait, StorageInstance, TrackedStorageKey, WhitelistedStorageKeys,
 };
 
 mod dispatch;
@@ -123,6 +127,9 @@ pub use safe_mode::{SafeMode, SafeModeError, SafeModeNotify};
 mod tx_pause;
 pub use tx_pause::{TransactionPause, TransactionPauseError};
 
+pub mod tasks;
+pub use tasks::{RuntimeTasks, Task};
+
 #[cfg(feature = "try-runtime")]
 mod try_runtime;
 #[cfg(feature = "try-runtime")]
@@ -48,6 +48,86 @@ impl<BlockNumber: Clone> OnInitialize<BlockNumber> for Tuple {
 	}
 }
 
+environmental::environmental!(ON_INITIALIZE_FAILURE: Option<&'static str>);
+
+/// Note that the `on_initialize` hook which is currently executing failed.
+///
+/// Only has an effect while the hook is executed through [`OnInitializeIsolated`], in which case
+/// all storage changes made by the hook are rolled back once it returns. Otherwise this is a
+/// no-op. [`defensive!`](crate::defensive) calls this function, so defensive failures inside of
+/// an isolated `on_initialize` are handled the same way.
+pub fn note_on_initialize_failure(reason: &'static str) {
+	ON_INITIALIZE_FAILURE::with(|failure| {
+		failure.get_or_insert(reason);
+	});
+}
+
+/// Execute the `on_initialize` hook of every pallet in its own storage transaction.
+///
+/// A pallet which reports a failure through [`note_on_initialize_failure`] (or hits a
+/// [`defensive!`](crate::defensive) failure) has all of the storage changes made by its hook
+/// rolled back, while the hooks of all other pallets still execute normally. The weight returned
+/// by a failed hook is still accounted for, since the work has already been done.
+///
+/// # Invariants
+///
+/// Rolling back a single hook means that the storage of the failing pallet stays exactly as it
+/// was at the end of the previous block. Pallets which rely on `on_initialize` being executed in
+/// every block, e.g. to rotate per-block storage items, must be able to cope with a skipped
+/// invocation. Likewise, pallets executed later in the same block will not observe any of the
+/// changes of the failed hook.
+///
+/// Panics can not be caught inside of the runtime and are therefore *not* covered by this: a
+/// panicking hook still renders the block invalid.
+pub trait OnInitializeIsolated<BlockNumber> {
+	/// Execute all `on_initialize` hooks, each of them isolated from the others.
+	///
+	/// `on_failure` is called with the index of every pallet whose changes were rolled back and
+	/// the reported reason.
+	fn on_initialize_isolated(
+		n: BlockNumber,
+		on_failure: &mut dyn FnMut(usize, &'static str),
+	) -> Weight;
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+#[tuple_types_custom_trait_bound(OnInitialize<BlockNumber> + crate::traits::PalletInfoAccess)]
+impl<BlockNumber: Clone> OnInitializeIsolated<BlockNumber> for Tuple {
+	fn on_initialize_isolated(
+		n: BlockNumber,
+		on_failure: &mut dyn FnMut(usize, &'static str),
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			let (hook_weight, failure) = execute_isolated(|| Tuple::on_initialize(n.clone()));
+			weight = weight.saturating_add(hook_weight);
+			if let Some(reason) = failure {
+				on_failure(Tuple::index(), reason);
+			}
+		)* );
+		weight
+	}
+}
+
+/// Execute `hook` in a storage transaction, rolling it back if it noted a failure.
+fn execute_isolated(hook: impl FnOnce() -> Weight) -> (Weight, Option<&'static str>) {
+	use crate::storage::{with_transaction_unchecked, TransactionOutcome};
+
+	let mut failure = None;
+	let weight = with_transaction_unchecked(|| {
+		let weight = ON_INITIALIZE_FAILURE::using(&mut failure, hook);
+		if failure.is_some() {
+			TransactionOutcome::Rollback(weight)
+		} else {
+			TransactionOutcome::Commit(weight)
+		}
+	});
+
+	(weight, failure)
+}
+
 /// See [`Hooks::on_finalize`].
 #[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
 #[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
@@ -119,6 +199,22 @@ pub trait OnRuntimeUpgrade {
 		Weight::zero()
 	}
 
+	/// Continue a runtime upgrade which deferred some of its work to the blocks following the
+	/// upgrade.
+	///
+	/// Called by the executive at the beginning of every block which does not upgrade the
+	/// runtime, before any `on_initialize` hook. Implementations must therefore be cheap when there
+	/// is no pending work. See [`crate::migrations::DeferrableMigrations`].
+	fn continue_runtime_upgrade() -> Weight {
+		Weight::zero()
+	}
+
+	/// Whether a runtime upgrade deferred some work which is still pending, i.e. whether
+	/// [`Self::continue_runtime_upgrade`] has anything left to do.
+	fn runtime_upgrade_pending() -> bool {
+		false
+	}
+
 	/// The expected and default behavior of this method is to handle executing `pre_upgrade` ->
 	/// `on_runtime_upgrade` -> `post_upgrade` hooks for a migration.
 	///
@@ -187,6 +283,21 @@ impl OnRuntimeUpgrade for Tuple {
 		weight
 	}
 
+	/// Implements the default behavior of [`OnRuntimeUpgrade::continue_runtime_upgrade`] for
+	/// tuples.
+	fn continue_runtime_upgrade() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::continue_runtime_upgrade()); )* );
+		weight
+	}
+
+	/// Implements the default behavior of [`OnRuntimeUpgrade::runtime_upgrade_pending`] for
+	/// tuples.
+	fn runtime_upgrade_pending() -> bool {
+		for_tuples!( #( if Tuple::runtime_upgrade_pending() { return true } )* );
+		false
+	}
+
 	/// Implements the default behavior of `try_on_runtime_upgrade` for tuples, logging any errors
 	/// that occur.
 	#[cfg(feature = "try-runtime")]
@@ -474,6 +585,40 @@ pub trait BuildGenesisConfig: sp_runtime::traits::MaybeSerializeDeserialize {
 	fn build(&self);
 }
 
+/// A trait to check a genesis config before it is built.
+///
+/// Pallets implement it through `#[pallet::genesis_check]`. The checks of all pallets are run
+/// before any of them is built, so that a malformed genesis config is rejected with the complete
+/// list of its problems rather than a panic on the first one.
+pub trait CheckGenesisConfig {
+	/// Check the config, returning a description of each problem found.
+	fn check(&self) -> Result<(), Vec<sp_runtime::RuntimeString>> {
+		Ok(())
+	}
+}
+
+/// Prefix a problem found by the [`CheckGenesisConfig`] of `pallet` with the name of the pallet.
+#[doc(hidden)]
+pub fn prefix_genesis_config_error(
+	pallet: &str,
+	error: &sp_runtime::RuntimeString,
+) -> sp_runtime::RuntimeString {
+	let error = sp_std::str::from_utf8(error.as_ref()).unwrap_or_default();
+	sp_runtime::format_runtime_string!("{}: {}", pallet, error)
+}
+
+/// Join the problems found by a [`CheckGenesisConfig`] into a single error message.
+pub fn genesis_config_errors_message(
+	errors: &[sp_runtime::RuntimeString],
+) -> sp_runtime::RuntimeString {
+	let mut message = b"Invalid genesis config:".to_vec();
+	for error in errors {
+		message.push(b'\n');
+		message.extend_from_slice(error.as_ref());
+	}
+	sp_runtime::format_runtime_string!("{}", sp_std::str::from_utf8(&message).unwrap_or_default())
+}
+
 /// A trait to define the build function of a genesis config, T and I are placeholder for pallet
 /// trait and pallet instance.
 #[deprecated(
@@ -19,6 +19,7 @@
 
 use codec::{Decode, Encode};
 use impl_trait_for_tuples::impl_for_tuples;
+use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::{ops::Add, prelude::*};
 
@@ -62,6 +63,9 @@ pub trait PalletInfoAccess {
 	/// Name of the pallet as configured in the runtime.
 	fn name() -> &'static str;
 	/// Two128 hash of name.
+	///
+	/// This is the first part of the key of every storage item of the pallet, see
+	/// [`crate::storage::keys`].
 	fn name_hash() -> [u8; 16];
 	/// Name of the Rust module containing the pallet.
 	fn module_name() -> &'static str;
@@ -170,7 +174,7 @@ pub const STORAGE_VERSION_STORAGE_KEY_POSTFIX: &[u8] = b":__STORAGE_VERSION__:";
 ///
 /// Each storage version of a pallet is stored in the state under a fixed key. See
 /// [`STORAGE_VERSION_STORAGE_KEY_POSTFIX`] for how this key is built.
-#[derive(Debug, Eq, PartialEq, Encode, Decode, Ord, Clone, Copy, PartialOrd, Default)]
+#[derive(Debug, Eq, PartialEq, Encode, Decode, TypeInfo, Ord, Clone, Copy, PartialOrd, Default)]
 pub struct StorageVersion(u16);
 
 impl StorageVersion {
@@ -299,6 +303,46 @@ pub trait GetStorageVersion {
 	fn on_chain_storage_version() -> StorageVersion;
 }
 
+/// The storage versions of a single pallet.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct PalletStorageVersions {
+	/// Name of the pallet as configured in the runtime.
+	pub name: Vec<u8>,
+	/// The storage version of the pallet as stored in the storage.
+	pub on_chain: StorageVersion,
+	/// The storage version as supported by the pallet, if it declares one.
+	pub in_code: Option<StorageVersion>,
+}
+
+impl PalletStorageVersions {
+	/// Whether the on-chain storage version is behind the version supported by the pallet.
+	///
+	/// This is the case when the pallet still has to run a migration.
+	pub fn is_behind(&self) -> bool {
+		self.in_code.map_or(false, |in_code| self.on_chain < in_code)
+	}
+}
+
+/// Provides the storage versions of a bunch of pallets.
+///
+/// This will be implemented by the [`pallet`](crate::pallet) macro for every pallet and for
+/// tuples of pallets, so it can be queried for `AllPalletsWithSystem`.
+pub trait GetPalletsStorageVersions {
+	/// The storage versions of all the pallets that this type represents.
+	fn pallets_storage_versions() -> Vec<PalletStorageVersions>;
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+impl GetPalletsStorageVersions for Tuple {
+	fn pallets_storage_versions() -> Vec<PalletStorageVersions> {
+		let mut res = vec![];
+		for_tuples!( #( res.extend(Tuple::pallets_storage_versions()); )* );
+		res
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -59,6 +59,7 @@ macro_rules! defensive {
 			"{}",
 			$crate::traits::DEFENSIVE_OP_PUBLIC_ERROR
 		);
+		$crate::traits::note_on_initialize_failure("defensive failure");
 		debug_assert!(false, "{}", $crate::traits::DEFENSIVE_OP_INTERNAL_ERROR);
 	};
 	($error:expr $(,)?) => {
@@ -68,6 +69,7 @@ macro_rules! defensive {
 			$crate::traits::DEFENSIVE_OP_PUBLIC_ERROR,
 			$error
 		);
+		$crate::traits::note_on_initialize_failure("defensive failure");
 		debug_assert!(false, "{}: {:?}", $crate::traits::DEFENSIVE_OP_INTERNAL_ERROR, $error);
 	};
 	($error:expr, $proof:expr $(,)?) => {
@@ -78,6 +80,7 @@ macro_rules! defensive {
 			$error,
 			$proof,
 		);
+		$crate::traits::note_on_initialize_failure("defensive failure");
 		debug_assert!(false, "{}: {:?}: {:?}", $crate::traits::DEFENSIVE_OP_INTERNAL_ERROR, $error, $proof);
 	}
 }
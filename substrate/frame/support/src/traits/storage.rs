@@ -90,6 +90,12 @@ pub trait StorageInstance {
 
 		final_key
 	}
+
+	/// Called whenever the storage is read or written.
+	///
+	/// Does nothing by default. Only overridden by the macro generated impls of deprecated
+	/// storage items, see [`crate::storage::deprecation`].
+	fn on_access() {}
 }
 
 /// Metadata about storage from the runtime.
@@ -193,6 +199,77 @@ where
 	}
 }
 
+/// A storage price which is a fixed base plus separate linear costs for each item and each byte.
+///
+/// Unlike [`LinearStoragePrice`], the number of items and the size are priced independently,
+/// i.e. `Base + count * PerItem + size * PerByte`.
+pub struct PerItemAndByteStoragePrice<Base, PerItem, PerByte, Balance>(
+	PhantomData<(Base, PerItem, PerByte, Balance)>,
+);
+impl<Base, PerItem, PerByte, Balance> Convert<Footprint, Balance>
+	for PerItemAndByteStoragePrice<Base, PerItem, PerByte, Balance>
+where
+	Base: Get<Balance>,
+	PerItem: Get<Balance>,
+	PerByte: Get<Balance>,
+	Balance: From<u64> + Saturating,
+{
+	fn convert(a: Footprint) -> Balance {
+		let items = Balance::from(a.count).saturating_mul(PerItem::get());
+		let bytes = Balance::from(a.size).saturating_mul(PerByte::get());
+		Base::get().saturating_add(items).saturating_add(bytes)
+	}
+}
+
+/// A storage price which increases in tiers: every started `Step` bytes of the total size cost
+/// `PerStep` on top of `Base`.
+///
+/// A `Step` of zero is treated as one byte.
+pub struct SteppedStoragePrice<Base, Step, PerStep, Balance>(
+	PhantomData<(Base, Step, PerStep, Balance)>,
+);
+impl<Base, Step, PerStep, Balance> Convert<Footprint, Balance>
+	for SteppedStoragePrice<Base, Step, PerStep, Balance>
+where
+	Base: Get<Balance>,
+	Step: Get<u64>,
+	PerStep: Get<Balance>,
+	Balance: From<u64> + Saturating,
+{
+	fn convert(a: Footprint) -> Balance {
+		let step = Step::get().max(1);
+		let steps = a.size / step + if a.size % step == 0 { 0 } else { 1 };
+		Balance::from(steps).saturating_mul(PerStep::get()).saturating_add(Base::get())
+	}
+}
+
+/// A storage price which is `Base` up to `Threshold` bytes of total size and is multiplied by
+/// `Factor` for every started `Step` bytes beyond it.
+///
+/// Useful to make holding large amounts of data prohibitively expensive. A `Step` of zero is
+/// treated as one byte.
+pub struct ExponentialStoragePrice<Base, Threshold, Step, Factor, Balance>(
+	PhantomData<(Base, Threshold, Step, Factor, Balance)>,
+);
+impl<Base, Threshold, Step, Factor, Balance> Convert<Footprint, Balance>
+	for ExponentialStoragePrice<Base, Threshold, Step, Factor, Balance>
+where
+	Base: Get<Balance>,
+	Threshold: Get<u64>,
+	Step: Get<u64>,
+	Factor: Get<Balance>,
+	Balance: From<u64> + Saturating,
+{
+	fn convert(a: Footprint) -> Balance {
+		let excess = a.size.saturating_sub(Threshold::get());
+		let step = Step::get().max(1);
+		let steps = excess / step + if excess % step == 0 { 0 } else { 1 };
+		// Clamp so the exponent fits a `usize` on 32-bit targets; it saturates long before.
+		let steps = steps.min(u32::MAX as u64) as usize;
+		Base::get().saturating_mul(Factor::get().saturating_pow(steps))
+	}
+}
+
 /// Some sort of cost taken from account temporarily in order to offset the cost to the chain of
 /// holding some data [`Footprint`] in state.
 ///
@@ -301,4 +378,55 @@ mod tests {
 
 		assert_eq!(p(u64::MAX, u64::MAX), u64::MAX);
 	}
+
+	#[test]
+	fn per_item_and_byte_storage_price_works() {
+		type Price = PerItemAndByteStoragePrice<ConstU64<7>, ConstU64<5>, ConstU64<2>, u64>;
+		let p = |count, size| Price::convert(Footprint { count, size });
+
+		assert_eq!(p(0, 0), 7);
+		assert_eq!(p(1, 0), 12);
+		assert_eq!(p(0, 1), 9);
+		assert_eq!(p(2, 10), 37);
+
+		assert_eq!(p(u64::MAX, 0), u64::MAX);
+		assert_eq!(p(0, u64::MAX), u64::MAX);
+	}
+
+	#[test]
+	fn stepped_storage_price_works() {
+		type Stepped = SteppedStoragePrice<ConstU64<7>, ConstU64<10>, ConstU64<3>, u64>;
+		let p = |count, size| Stepped::convert(Footprint { count, size });
+
+		assert_eq!(p(0, 0), 7);
+		assert_eq!(p(1, 1), 10);
+		assert_eq!(p(1, 10), 10);
+		assert_eq!(p(1, 11), 13);
+		assert_eq!(p(5, 20), 13);
+		assert_eq!(p(5, 21), 16);
+
+		assert_eq!(p(u64::MAX, u64::MAX), u64::MAX);
+
+		// A zero step prices every byte.
+		type PerByte = SteppedStoragePrice<ConstU64<0>, ConstU64<0>, ConstU64<1>, u64>;
+		assert_eq!(PerByte::convert(Footprint { count: 1, size: 42 }), 42);
+	}
+
+	#[test]
+	fn exponential_storage_price_works() {
+		type Exp =
+			ExponentialStoragePrice<ConstU64<5>, ConstU64<100>, ConstU64<10>, ConstU64<2>, u64>;
+		let p = |count, size| Exp::convert(Footprint { count, size });
+
+		assert_eq!(p(0, 0), 5);
+		assert_eq!(p(1, 100), 5);
+		assert_eq!(p(1, 101), 10);
+		assert_eq!(p(1, 110), 10);
+		assert_eq!(p(1, 111), 20);
+		assert_eq!(p(3, 150), 160);
+
+		assert_eq!(p(1, 100 + 10 * 61), 5 << 61);
+		assert_eq!(p(1, 100 + 10 * 62), u64::MAX);
+		assert_eq!(p(u64::MAX, u64::MAX), u64::MAX);
+	}
 }
@@ -0,0 +1,99 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`Task`] trait, which defines a general-purpose way for defining and executing
+//! service work, and supporting types.
+
+use codec::FullCodec;
+use scale_info::TypeInfo;
+use sp_runtime::DispatchError;
+use sp_std::{fmt::Debug, vec, vec::IntoIter};
+use sp_weights::Weight;
+
+/// Contains re-exports of all the supporting types for the [`Task`] trait. Used in the macro
+/// expansion of `RuntimeTask`.
+#[doc(hidden)]
+pub mod __private {
+	pub use codec::FullCodec;
+	pub use scale_info::TypeInfo;
+	pub use sp_runtime::DispatchError;
+	pub use sp_std::{fmt::Debug, iter::Iterator, vec, vec::IntoIter};
+	pub use sp_weights::Weight;
+}
+
+/// A general-purpose trait which defines a type of service work (i.e., work to performed by an
+/// off-chain worker) including methods for enumerating, validating, indexing, and running
+/// tasks of this type.
+///
+/// Tasks are declared in a pallet using `#[pallet::tasks_experimental]` and are aggregated into
+/// the `RuntimeTask` enum by `construct_runtime`.
+pub trait Task: Sized + FullCodec + TypeInfo + Clone + Debug + PartialEq + Eq {
+	/// An [`Iterator`] over tasks of this type used as the return type for `iter`.
+	type Enumeration: Iterator<Item = Self>;
+
+	/// Inspects the pallet's state and enumerates the potential tasks of this type.
+	///
+	/// The returned tasks are not necessarily valid, see [`Task::is_valid`].
+	fn iter() -> Self::Enumeration;
+
+	/// Checks if a particular instance of this `Task` variant is a valid piece of work.
+	fn is_valid(&self) -> bool;
+
+	/// Performs the work for this particular `Task` variant.
+	fn run(&self) -> Result<(), DispatchError>;
+
+	/// Returns the weight of executing this `Task`.
+	fn weight(&self) -> Weight;
+
+	/// A unique value representing this `Task` within the current pallet. Analogous to
+	/// `call_index`, but for tasks.
+	fn task_index(&self) -> u32;
+}
+
+/// Implementation of [`Task`] for the unit type, which contains no tasks.
+impl Task for () {
+	type Enumeration = IntoIter<Self>;
+
+	fn iter() -> Self::Enumeration {
+		vec![].into_iter()
+	}
+
+	fn is_valid(&self) -> bool {
+		true
+	}
+
+	fn run(&self) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn weight(&self) -> Weight {
+		Weight::default()
+	}
+
+	fn task_index(&self) -> u32 {
+		0
+	}
+}
+
+/// Provides the aggregated `RuntimeTask` type of a runtime.
+///
+/// `construct_runtime` implements this trait for the runtime with the `RuntimeTask` enum it
+/// generates, so that the configuration of a runtime does not need to name it.
+pub trait RuntimeTasks {
+	/// The aggregated `RuntimeTask` type.
+	type RuntimeTask: Task;
+}
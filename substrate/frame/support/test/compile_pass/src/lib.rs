@@ -73,6 +73,7 @@ impl renamed_frame_system::Config for Runtime {
 	type OnKilledAccount = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<0>;
 }
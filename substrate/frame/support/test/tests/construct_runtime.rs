@@ -259,6 +259,7 @@ pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 frame_support::construct_runtime!(
 	pub struct Runtime
 	{
+		#[unreserve_index]
 		System: frame_system::{Pallet, Call, Event<T>, Origin<T>} = 30,
 		Module1_1: module1::<Instance1>::{Pallet, Call, Storage, Event<T>, Origin<T>},
 		Module2: module2::{Pallet, Call, Storage, Event<T>, Origin},
@@ -272,6 +273,7 @@ frame_support::construct_runtime!(
 		Module1_7: module1::<Instance7>::{Pallet, Call, Storage, Event<T>, Origin<T>},
 		Module1_8: module1::<Instance8>::{Pallet, Call, Storage, Event<T>, Origin<T>} = 12,
 		Module1_9: module1::<Instance9>::{Pallet, Call, Storage, Event<T>, Origin<T>},
+		reserve_indices 20..=30,
 	}
 );
 
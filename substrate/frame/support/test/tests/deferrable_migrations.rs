@@ -0,0 +1,128 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for [`DeferrableMigrations`]
+
+use frame_support::{
+	migrations::{CappedMigration, DeferrableMigrations},
+	parameter_types,
+	traits::OnRuntimeUpgrade,
+	weights::{RuntimeDbWeight, Weight},
+};
+
+parameter_types! {
+	static Executed: Vec<u32> = Vec::new();
+	const Id: &'static str = "TestMigrations";
+	const NoDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
+	const MaxWeight: Weight = Weight::from_parts(10, 0);
+	const HugeWeight: Weight = Weight::from_parts(100, 0);
+	const BlockLimit: Weight = Weight::from_parts(25, 0);
+}
+
+/// A migration recording its execution and consuming `10 * N` weight.
+struct Migration<const N: u32>;
+
+impl<const N: u32> OnRuntimeUpgrade for Migration<N> {
+	fn on_runtime_upgrade() -> Weight {
+		Executed::mutate(|executed| executed.push(N));
+		Weight::from_parts(10 * N as u64, 0)
+	}
+}
+
+type Migrations = DeferrableMigrations<
+	Id,
+	(
+		CappedMigration<Migration<1>, MaxWeight>,
+		(CappedMigration<Migration<1>, MaxWeight>, CappedMigration<Migration<1>, MaxWeight>),
+		CappedMigration<Migration<10>, HugeWeight>,
+		CappedMigration<Migration<1>, MaxWeight>,
+	),
+	BlockLimit,
+	NoDbWeight,
+>;
+
+fn take_executed() -> Vec<u32> {
+	Executed::take()
+}
+
+#[test]
+fn migrations_are_deferred_to_following_blocks() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		// Nothing is pending before the upgrade.
+		assert_eq!(Migrations::continue_runtime_upgrade(), Weight::zero());
+		assert_eq!(Migrations::cursor(), None);
+		assert!(!Migrations::runtime_upgrade_pending());
+
+		// Only two migrations fit into the block of the upgrade.
+		assert_eq!(Migrations::on_runtime_upgrade(), Weight::from_parts(20, 0));
+		assert_eq!(take_executed(), vec![1, 1]);
+		assert_eq!(Migrations::cursor(), Some(2));
+		assert!(Migrations::runtime_upgrade_pending());
+
+		// The nested migration is executed in the next block, the huge one does not fit anymore.
+		assert_eq!(Migrations::continue_runtime_upgrade(), Weight::from_parts(10, 0));
+		assert_eq!(take_executed(), vec![1]);
+		assert_eq!(Migrations::cursor(), Some(3));
+
+		// The huge migration exceeds the block limit on its own and gets a block of its own.
+		assert_eq!(Migrations::continue_runtime_upgrade(), Weight::from_parts(100, 0));
+		assert_eq!(take_executed(), vec![10]);
+		assert_eq!(Migrations::cursor(), Some(4));
+
+		assert_eq!(Migrations::continue_runtime_upgrade(), Weight::from_parts(10, 0));
+		assert_eq!(take_executed(), vec![1]);
+		assert_eq!(Migrations::cursor(), None);
+		assert!(!Migrations::runtime_upgrade_pending());
+
+		// Nothing is left to do afterwards.
+		assert_eq!(Migrations::continue_runtime_upgrade(), Weight::zero());
+		assert!(take_executed().is_empty());
+	});
+}
+
+#[test]
+fn runtime_upgrade_resumes_pending_migrations() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		Migrations::on_runtime_upgrade();
+		Migrations::continue_runtime_upgrade();
+		assert_eq!(take_executed(), vec![1, 1, 1]);
+		assert_eq!(Migrations::cursor(), Some(3));
+
+		// The already executed migrations are not executed again.
+		assert_eq!(Migrations::on_runtime_upgrade(), Weight::from_parts(100, 0));
+		assert_eq!(take_executed(), vec![10]);
+		assert_eq!(Migrations::cursor(), Some(4));
+
+		Migrations::continue_runtime_upgrade();
+		assert_eq!(take_executed(), vec![1]);
+		assert_eq!(Migrations::cursor(), None);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_runtime_upgrade_resumes_pending_migrations() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		Migrations::on_runtime_upgrade();
+		assert_eq!(take_executed(), vec![1, 1]);
+
+		// All pending migrations are executed in the same steps, but none of them twice.
+		assert_eq!(Migrations::try_on_runtime_upgrade(true).unwrap(), Weight::from_parts(120, 0));
+		assert_eq!(take_executed(), vec![1, 10, 1]);
+		assert_eq!(Migrations::cursor(), None);
+	});
+}
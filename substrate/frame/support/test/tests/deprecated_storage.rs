@@ -0,0 +1,209 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `#[pallet::storage(deprecated)]` and [`RemoveDeprecatedStorage`].
+
+use frame_support::{
+	derive_impl,
+	migrations::RemoveDeprecatedStorage,
+	parameter_types,
+	storage::deprecation::{self, DeprecatedStorageAccess},
+	traits::{OnFinalize, OnInitialize, OnRuntimeUpgrade, StorageInfoTrait},
+	weights::{RuntimeDbWeight, Weight},
+};
+use sp_core::{sr25519, ConstU32};
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Verify},
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	/// Superseded by `Current`.
+	#[pallet::storage(deprecated)]
+	pub type Old<T> = StorageValue<_, u32>;
+
+	/// Superseded by `Current`.
+	#[pallet::storage(deprecated)]
+	pub type OldMap<T> = StorageMap<_, Twox64Concat, u32, u32>;
+
+	#[pallet::storage]
+	pub type Current<T> = StorageValue<_, u32>;
+}
+
+pub type BlockNumber = u32;
+pub type Signature = sr25519::Signature;
+pub type AccountId = <Signature as Verify>::Signer;
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<u32, RuntimeCall, Signature, ()>;
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		MyPallet: pallet,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockHashCount = ConstU32<10>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletInfo = PalletInfo;
+	type OnSetCode = ();
+	type DbWeight = DbWeight;
+}
+
+impl pallet::Config for Test {}
+
+parameter_types! {
+	const PalletName: &'static str = "MyPallet";
+	const OldMapName: &'static str = "OldMap";
+	const NoDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
+	const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1, write: 10 };
+}
+
+type RemoveOldMap = RemoveDeprecatedStorage<PalletName, OldMapName, ConstU32<2>, NoDbWeight>;
+
+fn accesses(storage: &str) -> u32 {
+	deprecation::access(b"MyPallet", storage.as_bytes()).accesses
+}
+
+#[test]
+fn accesses_to_deprecated_storage_are_counted() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		pallet::Old::<Test>::put(1);
+		assert_eq!(pallet::Old::<Test>::get(), Some(1));
+		pallet::OldMap::<Test>::insert(1, 2);
+		assert_eq!(pallet::OldMap::<Test>::iter_values().collect::<Vec<_>>(), vec![2]);
+
+		// Other storage items are not instrumented.
+		pallet::Current::<Test>::put(1);
+
+		// The accesses are counted once the block is finalized.
+		assert_eq!(accesses("Old"), 0);
+		MyPallet::on_finalize(1);
+		assert_eq!(accesses("Old"), 2);
+		assert_eq!(accesses("OldMap"), 2);
+		assert_eq!(accesses("Current"), 0);
+	});
+}
+
+#[test]
+fn block_accesses_are_folded_and_weighed_by_the_hooks() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		// Two deprecated items, each folded twice with up to two reads and two writes.
+		let weight = MyPallet::on_initialize(1);
+		assert_eq!(weight, Weight::from_parts(2 * 2 * (2 * 1 + 2 * 10), 0));
+
+		MyPallet::on_finalize(1);
+
+		// An access after the pallet was finalized is only counted in the next block.
+		assert_eq!(pallet::Old::<Test>::get(), None);
+		assert_eq!(accesses("Old"), 0);
+		MyPallet::on_initialize(2);
+		assert_eq!(accesses("Old"), 1);
+	});
+}
+
+#[test]
+fn block_accesses_do_not_reach_the_state() {
+	let accessed = sp_io::TestExternalities::default().execute_with(|| {
+		assert_eq!(pallet::Old::<Test>::get(), None);
+		MyPallet::on_finalize(1);
+		sp_io::storage::root(sp_runtime::StateVersion::V1)
+	});
+
+	// Only the counter of the item is stored.
+	let expected = sp_io::TestExternalities::default().execute_with(|| {
+		deprecation::set_access(
+			b"MyPallet",
+			b"Old",
+			DeprecatedStorageAccess { accesses: 1, unaccessed_upgrades: 0 },
+		);
+		sp_io::storage::root(sp_runtime::StateVersion::V1)
+	});
+
+	assert_eq!(accessed, expected);
+}
+
+#[test]
+fn metadata_and_storage_info_are_not_accesses() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let _ = pallet::Pallet::<Test>::storage_metadata();
+		let _ = pallet::Pallet::<Test>::storage_info();
+		MyPallet::on_finalize(1);
+
+		assert_eq!(accesses("Old"), 0);
+		assert_eq!(accesses("OldMap"), 0);
+	});
+}
+
+#[test]
+fn deprecation_is_surfaced_in_metadata() {
+	let metadata = pallet::Pallet::<Test>::storage_metadata();
+	let docs = |name: &str| metadata.entries.iter().find(|e| e.name == name).unwrap().docs.clone();
+
+	assert!(docs("Old")[0].starts_with("DEPRECATED"));
+	assert!(docs("OldMap")[0].starts_with("DEPRECATED"));
+	assert!(docs("Current").is_empty());
+}
+
+#[test]
+fn deprecated_storage_is_removed_once_unaccessed() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		pallet::OldMap::<Test>::insert(1, 2);
+		pallet::OldMap::<Test>::insert(2, 3);
+
+		// The item was accessed since the last upgrade.
+		RemoveOldMap::on_runtime_upgrade();
+		assert_eq!(deprecation::access(b"MyPallet", b"OldMap"), DeprecatedStorageAccess::default());
+
+		// Not accessed for one upgrade.
+		RemoveOldMap::on_runtime_upgrade();
+		assert_eq!(
+			deprecation::access(b"MyPallet", b"OldMap"),
+			DeprecatedStorageAccess { accesses: 0, unaccessed_upgrades: 1 },
+		);
+
+		// An access starts the count over.
+		assert_eq!(pallet::OldMap::<Test>::get(1), Some(2));
+		RemoveOldMap::on_runtime_upgrade();
+		RemoveOldMap::on_runtime_upgrade();
+		assert_eq!(deprecation::access(b"MyPallet", b"OldMap").unaccessed_upgrades, 1);
+
+		// Not accessed for two upgrades, the item is removed.
+		RemoveOldMap::on_runtime_upgrade();
+		assert!(!frame_support::storage::unhashed::contains_prefixed_key(
+			&frame_support::storage::storage_prefix(b"MyPallet", b"OldMap")
+		));
+		assert_eq!(deprecation::access(b"MyPallet", b"OldMap"), DeprecatedStorageAccess::default());
+	});
+}
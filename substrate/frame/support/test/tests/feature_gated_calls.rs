@@ -0,0 +1,170 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for dispatchables gated behind a feature flag with `#[pallet::feature_gated]`.
+
+use frame_support::{
+	assert_noop, assert_ok, derive_impl, parameter_types, traits::UnfilteredDispatchable,
+};
+use scale_info::{TypeDef, TypeInfo};
+use sp_io::TestExternalities;
+
+parameter_types! {
+	pub storage ExperimentalEnabled: bool = false;
+}
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type ExperimentalEnabled: Get<bool>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {}
+
+	#[pallet::storage]
+	pub type Value<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		pub fn set_value(_origin: OriginFor<T>, value: u32) -> DispatchResult {
+			Value::<T>::put(value);
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::feature_gated(T::ExperimentalEnabled)]
+		pub fn double_value(_origin: OriginFor<T>) -> DispatchResult {
+			Value::<T>::mutate(|v| *v *= 2);
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::feature_gated(T::ExperimentalEnabled)]
+		pub fn reset_value(_origin: OriginFor<T>) -> DispatchResult {
+			Value::<T>::kill();
+			Ok(())
+		}
+	}
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletInfo = PalletInfo;
+	type OnSetCode = ();
+	type Block = Block;
+}
+
+impl pallet::Config for Runtime {
+	type ExperimentalEnabled = ExperimentalEnabled;
+}
+
+frame_support::construct_runtime!(
+	pub struct Runtime {
+		System: frame_system,
+		Gated: pallet,
+	}
+);
+
+#[test]
+fn feature_gated_calls_are_unavailable_while_disabled() {
+	TestExternalities::default().execute_with(|| {
+		let origin = RuntimeOrigin::signed(1);
+		assert_ok!(
+			pallet::Call::<Runtime>::set_value { value: 2 }.dispatch_bypass_filter(origin.clone())
+		);
+
+		assert_noop!(
+			pallet::Call::<Runtime>::double_value {}.dispatch_bypass_filter(origin.clone()),
+			pallet::Error::<Runtime>::FeatureDisabled,
+		);
+		assert_noop!(
+			pallet::Call::<Runtime>::reset_value {}.dispatch_bypass_filter(origin.clone()),
+			pallet::Error::<Runtime>::FeatureDisabled,
+		);
+
+		// Enabling the flag makes the calls available without any code change.
+		ExperimentalEnabled::set(&true);
+		assert_ok!(pallet::Call::<Runtime>::double_value {}.dispatch_bypass_filter(origin));
+		assert_eq!(pallet::Value::<Runtime>::get(), 4);
+	});
+}
+
+#[test]
+fn feature_gated_calls_cannot_be_called_directly_while_disabled() {
+	TestExternalities::default().execute_with(|| {
+		pallet::Value::<Runtime>::put(2);
+
+		assert_noop!(
+			pallet::Pallet::<Runtime>::double_value(RuntimeOrigin::signed(1)),
+			pallet::Error::<Runtime>::FeatureDisabled,
+		);
+
+		ExperimentalEnabled::set(&true);
+		assert_ok!(pallet::Pallet::<Runtime>::double_value(RuntimeOrigin::signed(1)));
+		assert_eq!(pallet::Value::<Runtime>::get(), 4);
+	});
+}
+
+#[test]
+fn feature_gated_calls_name_their_flag_in_the_metadata() {
+	let TypeDef::Variant(calls) = pallet::Call::<Runtime>::type_info().type_def else {
+		panic!("`Call` is an enum")
+	};
+	let names_flag = |name: &str| {
+		let variant = calls.variants.iter().find(|variant| variant.name == name).unwrap();
+		variant.docs.iter().any(|doc| {
+			doc.contains(
+				"Only callable while the feature flag `T::ExperimentalEnabled` is enabled.",
+			)
+		})
+	};
+
+	assert!(names_flag("double_value"));
+	assert!(names_flag("reset_value"));
+	assert!(!names_flag("set_value"));
+}
+
+#[test]
+fn feature_gate_state_can_be_queried() {
+	TestExternalities::default().execute_with(|| {
+		assert_eq!(
+			pallet::Pallet::<Runtime>::feature_gated_calls(),
+			vec![("double_value", false), ("reset_value", false)]
+		);
+
+		ExperimentalEnabled::set(&true);
+		assert_eq!(
+			pallet::Pallet::<Runtime>::feature_gated_calls(),
+			vec![("double_value", true), ("reset_value", true)]
+		);
+	});
+}
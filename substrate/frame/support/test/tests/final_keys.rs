@@ -207,6 +207,7 @@ frame_support::construct_runtime!(
 		FinalKeysNone: no_instance,
 		FinalKeysSome: instance,
 		Instance2FinalKeysSome: instance::<Instance2>,
+		Instance17FinalKeysSome: instance::<IndexedInstance<17>>,
 	}
 );
 
@@ -226,6 +227,7 @@ impl no_instance::Config for Runtime {}
 
 impl instance::Config for Runtime {}
 impl instance::Config<instance::Instance2> for Runtime {}
+impl instance::Config<instance::IndexedInstance<17>> for Runtime {}
 
 #[test]
 fn final_keys_no_instance() {
@@ -331,3 +333,22 @@ fn final_keys_instance_2() {
 		assert_eq!(&k[..32], &<instance::DoubleMap2<Runtime, instance::Instance2>>::final_prefix());
 	});
 }
+
+#[test]
+fn final_keys_indexed_instance() {
+	TestExternalities::default().execute_with(|| {
+		<instance::Value<Runtime, instance::IndexedInstance<17>>>::put(1);
+		let k = [twox_128(b"Instance17FinalKeysSome"), twox_128(b"Value")].concat();
+		assert_eq!(unhashed::get::<u32>(&k), Some(1u32));
+		assert_eq!(<instance::Value<Runtime, instance::Instance2>>::get(), 0);
+
+		<instance::Map<Runtime, instance::IndexedInstance<17>>>::insert(1, 2);
+		let mut k = [twox_128(b"Instance17FinalKeysSome"), twox_128(b"Map")].concat();
+		k.extend(1u32.using_encoded(blake2_128_concat));
+		assert_eq!(unhashed::get::<u32>(&k), Some(2u32));
+		assert_eq!(
+			&k[..32],
+			&<instance::Map<Runtime, instance::IndexedInstance<17>>>::final_prefix()
+		);
+	});
+}
@@ -21,6 +21,7 @@ use sp_core::{sr25519, ConstU32};
 use sp_runtime::{
 	generic,
 	traits::{BlakeTwo256, Verify},
+	BuildStorage,
 };
 
 #[frame_support::pallet]
@@ -61,6 +62,25 @@ pub mod pallet {
 			}
 		}
 	}
+
+	#[pallet::genesis_check]
+	impl<T: Config> CheckGenesisConfig for GenesisConfig<T> {
+		fn check(&self) -> Result<(), Vec<sp_runtime::RuntimeString>> {
+			let errors = self
+				.t
+				.iter()
+				.filter(|(_, _, v)| v.is_empty())
+				.map(|(k1, k2, _)| {
+					sp_runtime::format_runtime_string!("empty value at ({}, {:?})", k1, k2)
+				})
+				.collect::<Vec<_>>();
+			if errors.is_empty() {
+				Ok(())
+			} else {
+				Err(errors)
+			}
+		}
+	}
 }
 
 pub type BlockNumber = u32;
@@ -97,3 +117,45 @@ impl pallet::Config for Test {}
 fn init_genesis_config() {
 	pallet::GenesisConfig::<Test>::default();
 }
+
+#[test]
+fn genesis_check_accepts_valid_config() {
+	let config = RuntimeGenesisConfig {
+		my_pallet: pallet::GenesisConfig { t: vec![(1, 2, vec![3])] },
+		..Default::default()
+	};
+	assert!(config.build_storage().is_ok());
+}
+
+#[test]
+fn genesis_check_reports_all_errors_with_pallet_name() {
+	let config = RuntimeGenesisConfig {
+		my_pallet: pallet::GenesisConfig {
+			t: vec![(1, 2, vec![]), (3, 4, vec![5]), (6, 7, vec![])],
+		},
+		..Default::default()
+	};
+	assert_eq!(
+		config.build_storage().unwrap_err(),
+		"Invalid genesis config:\nMyPallet: empty value at (1, 2)\nMyPallet: empty value at (6, 7)",
+	);
+	assert_eq!(
+		config.my_pallet.build_storage().unwrap_err(),
+		"Invalid genesis config:\nempty value at (1, 2)\nempty value at (6, 7)",
+	);
+}
+
+#[test]
+fn genesis_builder_checks_config() {
+	use frame_support::genesis_builder_helper::{build_config, create_default_config};
+
+	let json = String::from_utf8(create_default_config::<RuntimeGenesisConfig>()).unwrap();
+	assert!(json.contains(r#""t":[]"#));
+	let json = json.replace(r#""t":[]"#, r#""t":[[1,2,[]]]"#);
+	sp_io::TestExternalities::default().execute_with(|| {
+		assert_eq!(
+			build_config::<RuntimeGenesisConfig>(json.into_bytes()),
+			Err("Invalid genesis config:\nMyPallet: empty value at (1, 2)".into()),
+		);
+	});
+}
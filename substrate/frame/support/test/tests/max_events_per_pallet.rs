@@ -0,0 +1,116 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `frame_system::Config::MaxEventsPerPallet`.
+
+use frame_support::{derive_impl, traits::ConstU32};
+use sp_core::sr25519;
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Verify},
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub fn deposit_event)]
+	pub enum Event<T: Config> {
+		Something(u32),
+	}
+}
+
+pub type BlockNumber = u32;
+pub type Signature = sr25519::Signature;
+pub type AccountId = <Signature as Verify>::Signer;
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<u32, RuntimeCall, Signature, ()>;
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		MyPallet: pallet,
+	}
+);
+
+frame_support::parameter_types! {
+	pub const MaxEventsPerPallet: Option<u32> = Some(2);
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockHashCount = ConstU32<10>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletInfo = PalletInfo;
+	type OnSetCode = ();
+	type MaxEventsPerPallet = MaxEventsPerPallet;
+}
+
+impl pallet::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+fn events() -> Vec<RuntimeEvent> {
+	frame_system::Pallet::<Test>::events().into_iter().map(|r| r.event).collect()
+}
+
+#[test]
+fn events_beyond_the_limit_are_truncated() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+
+		for i in 0..4 {
+			pallet::Pallet::<Test>::deposit_event(pallet::Event::Something(i));
+		}
+		// The events of the system pallet are not limited.
+		for _ in 0..3 {
+			frame_system::Pallet::<Test>::deposit_event(frame_system::Event::CodeUpdated);
+		}
+
+		assert_eq!(
+			events(),
+			vec![
+				RuntimeEvent::MyPallet(pallet::Event::Something(0)),
+				RuntimeEvent::MyPallet(pallet::Event::Something(1)),
+				RuntimeEvent::System(frame_system::Event::EventsTruncated { pallet: 1 }),
+				RuntimeEvent::System(frame_system::Event::CodeUpdated),
+				RuntimeEvent::System(frame_system::Event::CodeUpdated),
+				RuntimeEvent::System(frame_system::Event::CodeUpdated),
+			]
+		);
+
+		// The limit applies per block.
+		frame_system::Pallet::<Test>::reset_events();
+		pallet::Pallet::<Test>::deposit_event(pallet::Event::Something(4));
+		assert_eq!(events(), vec![RuntimeEvent::MyPallet(pallet::Event::Something(4))]);
+	});
+}
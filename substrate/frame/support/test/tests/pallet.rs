@@ -23,9 +23,9 @@ use frame_support::{
 	parameter_types,
 	storage::{unhashed, unhashed::contains_prefixed_key},
 	traits::{
-		ConstU32, GetCallIndex, GetCallName, GetStorageVersion, OnFinalize, OnGenesis,
-		OnInitialize, OnRuntimeUpgrade, PalletError, PalletInfoAccess, StorageVersion,
-		UnfilteredDispatchable,
+		ConstU32, GetCallIndex, GetCallName, GetPalletsStorageVersions, GetStorageVersion,
+		OnFinalize, OnGenesis, OnInitialize, OnRuntimeUpgrade, PalletError, PalletInfoAccess,
+		StorageVersion, UnfilteredDispatchable,
 	},
 	weights::{RuntimeDbWeight, Weight},
 };
@@ -257,6 +257,17 @@ pub mod pallet {
 		pub fn check_for_dispatch_context(_origin: OriginFor<T>) -> DispatchResult {
 			with_context::<(), _>(|_| ()).ok_or_else(|| DispatchError::Unavailable)
 		}
+
+		// Test for RefundIfSuccessful return type
+		#[pallet::call_index(5)]
+		#[pallet::weight({1})]
+		pub fn foo_refund_if_successful(_origin: OriginFor<T>, fail: bool) -> RefundIfSuccessful {
+			if fail {
+				Err(Error::<T>::InsufficientProposersBalance)?;
+			}
+
+			Ok(FeeRefund::with_actual_weight(Weight::from_parts(1, 0)))
+		}
 	}
 
 	#[pallet::error]
@@ -706,6 +717,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 impl pallet::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -802,12 +814,13 @@ fn call_expand() {
 			"foo_storage_layer",
 			"foo_index_out_of_order",
 			"foo_no_post_info",
-			"check_for_dispatch_context"
+			"check_for_dispatch_context",
+			"foo_refund_if_successful"
 		],
 	);
 
 	assert_eq!(call_foo.get_call_index(), 0u8);
-	assert_eq!(pallet::Call::<Runtime>::get_call_indices(), &[0u8, 1u8, 4u8, 2u8, 3u8])
+	assert_eq!(pallet::Call::<Runtime>::get_call_indices(), &[0u8, 1u8, 4u8, 2u8, 3u8, 5u8])
 }
 
 #[test]
@@ -815,7 +828,7 @@ fn call_expand_index() {
 	let call_foo = pallet::Call::<Runtime>::foo_index_out_of_order {};
 
 	assert_eq!(call_foo.get_call_index(), 4u8);
-	assert_eq!(pallet::Call::<Runtime>::get_call_indices(), &[0u8, 1u8, 4u8, 2u8, 3u8])
+	assert_eq!(pallet::Call::<Runtime>::get_call_indices(), &[0u8, 1u8, 4u8, 2u8, 3u8, 5u8])
 }
 
 #[test]
@@ -2382,6 +2395,29 @@ fn pallet_on_chain_storage_version_initializes_correctly() {
 	});
 }
 
+#[test]
+fn pallets_storage_versions_are_reported() {
+	TestExternalities::default().execute_with(|| {
+		let versions = AllPalletsWithSystem::pallets_storage_versions();
+
+		// Example has a current storage version, which is not yet set on-chain.
+		let example = versions.iter().find(|v| v.name == b"Example").unwrap();
+		assert_eq!(example.on_chain, StorageVersion::new(0));
+		assert_eq!(example.in_code, Some(Example::current_storage_version()));
+		assert!(example.is_behind());
+
+		// Example4 has no current storage version set.
+		let example4 = versions.iter().find(|v| v.name == b"Example4").unwrap();
+		assert_eq!(example4.in_code, None);
+		assert!(!example4.is_behind());
+
+		Example::current_storage_version().put::<Example>();
+		let example = Example::pallets_storage_versions().pop().unwrap();
+		assert_eq!(example.on_chain, Example::current_storage_version());
+		assert!(!example.is_behind());
+	});
+}
+
 #[cfg(feature = "try-runtime")]
 #[test]
 fn post_runtime_upgrade_detects_storage_version_issues() {
@@ -2495,3 +2531,22 @@ fn test_dispatch_context() {
 			.dispatch(RuntimeOrigin::root()));
 	});
 }
+
+#[test]
+fn refund_if_successful_refunds_fee_only_on_success() {
+	TestExternalities::default().execute_with(|| {
+		let post_info =
+			RuntimeCall::from(pallet::Call::<Runtime>::foo_refund_if_successful { fail: false })
+				.dispatch(RuntimeOrigin::root())
+				.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert_eq!(post_info.actual_weight, Some(Weight::from_parts(1, 0)));
+
+		let err =
+			RuntimeCall::from(pallet::Call::<Runtime>::foo_refund_if_successful { fail: true })
+				.dispatch(RuntimeOrigin::root())
+				.unwrap_err();
+		assert_eq!(err.post_info.pays_fee, Pays::Yes);
+		assert_eq!(err.error, pallet::Error::<Runtime>::InsufficientProposersBalance.into());
+	});
+}
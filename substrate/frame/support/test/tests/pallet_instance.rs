@@ -316,6 +316,7 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 impl pallet::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
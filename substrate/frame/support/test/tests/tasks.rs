@@ -0,0 +1,197 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::Encode;
+use frame_support::{pallet_prelude::ConstU32, traits::Task as _, weights::Weight};
+use pallet::*;
+use sp_io::TestExternalities;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NotFound,
+	}
+
+	#[pallet::storage]
+	pub type Numbers<T> = StorageMap<_, Twox64Concat, u32, u32, OptionQuery>;
+
+	#[pallet::storage]
+	pub type Total<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::tasks_experimental]
+	impl<T: Config> Pallet<T> {
+		/// Add the number stored at `i` into the total.
+		#[pallet::task_list(Numbers::<T>::iter_keys())]
+		#[pallet::task_condition(|i| Numbers::<T>::contains_key(i))]
+		#[pallet::task_weight(Weight::from_parts(10, 0))]
+		#[pallet::task_index(0)]
+		pub fn add_number_into_total(i: u32) -> DispatchResult {
+			let v = Numbers::<T>::take(i).ok_or(Error::<T>::NotFound)?;
+			Total::<T>::mutate(|total| *total += v);
+			Ok(())
+		}
+
+		/// Move the number stored at `from` to `to`.
+		#[pallet::task_list(Numbers::<T>::iter_keys().map(|from| (from, from + 1)))]
+		#[pallet::task_condition(|from, to| {
+			Numbers::<T>::contains_key(from) && !Numbers::<T>::contains_key(to)
+		})]
+		#[pallet::task_weight(Weight::from_parts(20, 0))]
+		#[pallet::task_index(1)]
+		pub fn move_number(from: u32, to: u32) -> DispatchResult {
+			let v = Numbers::<T>::take(from).ok_or(Error::<T>::NotFound)?;
+			Numbers::<T>::insert(to, v);
+			Ok(())
+		}
+	}
+}
+
+pub type BlockNumber = u32;
+pub type Nonce = u64;
+pub type AccountId = u64;
+pub type Header = sp_runtime::generic::Header<BlockNumber, sp_runtime::traits::BlakeTwo256>;
+pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, RuntimeCall, (), ()>;
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = Nonce;
+	type Hash = sp_runtime::testing::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU32<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
+}
+
+impl Config for Runtime {}
+
+frame_support::construct_runtime!(
+	pub struct Runtime {
+		System: frame_system,
+		Example: pallet = 5,
+	}
+);
+
+fn add_number_into_total(i: u32) -> RuntimeTask {
+	pallet::Task::<Runtime>::AddNumberIntoTotal { i }.into()
+}
+
+fn move_number(from: u32, to: u32) -> RuntimeTask {
+	pallet::Task::<Runtime>::MoveNumber { from, to }.into()
+}
+
+#[test]
+fn tasks_are_enumerated() {
+	TestExternalities::default().execute_with(|| {
+		assert!(RuntimeTask::iter().next().is_none());
+
+		Numbers::<Runtime>::insert(1, 10);
+		Numbers::<Runtime>::insert(2, 20);
+
+		let mut tasks = RuntimeTask::iter().collect::<Vec<_>>();
+		tasks.sort_by_key(|task| task.encode());
+		assert_eq!(
+			tasks,
+			vec![
+				add_number_into_total(1),
+				add_number_into_total(2),
+				move_number(1, 2),
+				move_number(2, 3),
+			]
+		);
+
+		// Moving `1` to `2` would overwrite a number.
+		assert_eq!(
+			tasks.iter().map(|task| task.is_valid()).collect::<Vec<_>>(),
+			vec![true, true, false, true],
+		);
+	});
+}
+
+#[test]
+fn task_properties_are_forwarded() {
+	let task = move_number(1, 2);
+	assert_eq!(task.weight(), Weight::from_parts(20, 0));
+	assert_eq!(task.task_index(), 1);
+	assert_eq!(add_number_into_total(1).task_index(), 0);
+
+	// Encoded as pallet index, task index and arguments.
+	assert_eq!(task.encode(), (5u8, 1u8, 1u32, 2u32).encode());
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn do_task_runs_valid_tasks() {
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::DispatchError;
+
+	TestExternalities::default().execute_with(|| {
+		System::set_block_number(1);
+		Numbers::<Runtime>::insert(1, 10);
+
+		let task = add_number_into_total(1);
+		assert!(task.is_valid());
+		assert_ok!(System::do_task(RuntimeOrigin::signed(1), task.clone()));
+		assert_eq!(Total::<Runtime>::get(), 10);
+		System::assert_last_event(frame_system::Event::TaskCompleted { task: task.clone() }.into());
+
+		// There is no more work pending for the task.
+		assert!(!task.is_valid());
+		assert_noop!(
+			System::do_task(RuntimeOrigin::signed(1), task),
+			frame_system::Error::<Runtime>::InvalidTask,
+		);
+		assert_noop!(
+			System::do_task(RuntimeOrigin::none(), add_number_into_total(1)),
+			DispatchError::BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn failing_task_is_reported() {
+	TestExternalities::default().execute_with(|| {
+		// The task is not valid, but running it directly reports the pallet error.
+		assert_eq!(add_number_into_total(1).run(), Err(pallet::Error::<Runtime>::NotFound.into()));
+	});
+}
@@ -23,6 +23,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Block as BlockT;
+
+/// The window in which an extrinsic is valid, as determined by its mortality.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct MortalityWindow<BlockNumber, Moment> {
+	/// The block the mortality period of the extrinsic started at.
+	pub birth: BlockNumber,
+	/// The first block the extrinsic is no longer valid in, `None` if it is immortal.
+	pub death: Option<BlockNumber>,
+	/// An estimate of the timestamp of block `death`, `None` if it is immortal.
+	pub expires_at: Option<Moment>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// The API to query account nonce.
 	pub trait AccountNonceApi<AccountId, Nonce> where
@@ -32,4 +47,26 @@ sp_api::decl_runtime_apis! {
 		/// Get current account nonce of given `AccountId`.
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
+
+	/// The API to query the author of the current block.
+	pub trait BlockAuthorApi<AccountId> where
+		AccountId: codec::Codec,
+	{
+		/// Get the author of the current block, if any was recorded.
+		fn block_author() -> Option<AccountId>;
+	}
+
+	/// The API to introspect the mortality of extrinsics.
+	pub trait MortalityApi<BlockNumber, Moment> where
+		BlockNumber: codec::Codec,
+		Moment: codec::Codec,
+	{
+		/// Get the validity window of `extrinsic` relative to the current block.
+		///
+		/// Returns `None` if it is not signed or the signature doesn't check out against the
+		/// current block, e.g. because it expired and was signed in an earlier period of its era.
+		fn mortality_window(
+			extrinsic: <Block as BlockT>::Extrinsic,
+		) -> Option<MortalityWindow<BlockNumber, Moment>>;
+	}
 }
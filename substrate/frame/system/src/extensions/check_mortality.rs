@@ -17,6 +17,10 @@
 
 use crate::{pallet_prelude::BlockNumberFor, BlockHash, Config, Pallet};
 use codec::{Decode, Encode};
+use frame_support::{
+	traits::{ConstU64, Get},
+	CloneNoBound, EqNoBound, PartialEqNoBound,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	generic::Era,
@@ -31,18 +35,49 @@ use sp_runtime::{
 /// # Transaction Validity
 ///
 /// The extension affects `longevity` of the transaction according to the [`Era`] definition.
-#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
-#[scale_info(skip_type_params(T))]
-pub struct CheckMortality<T: Config + Send + Sync>(pub Era, sp_std::marker::PhantomData<T>);
-
-impl<T: Config + Send + Sync> CheckMortality<T> {
+///
+/// Transactions whose era is longer than `MaxPeriod` blocks, including immortal ones, are invalid
+/// and rejected with [`InvalidTransaction::EraTooLong`]. With `MaxPeriod` being
+/// `u64::MAX`, which is the default, any era is accepted.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T, MaxPeriod))]
+pub struct CheckMortality<T: Config + Send + Sync, MaxPeriod = ConstU64<{ u64::MAX }>>(
+	pub Era,
+	sp_std::marker::PhantomData<(T, MaxPeriod)>,
+);
+
+impl<T: Config + Send + Sync, MaxPeriod> CheckMortality<T, MaxPeriod> {
 	/// utility constructor. Used only in client/factory code.
 	pub fn from(era: Era) -> Self {
 		Self(era, sp_std::marker::PhantomData)
 	}
+
+	/// The block the era of the transaction started at and the first block at which it has ended,
+	/// relative to the current block. The latter is `None` for immortal transactions.
+	///
+	/// Returns `None` if the hash of the birth block is not known anymore. The phase of an era
+	/// repeats every period, so this is only the window the transaction was signed for if its
+	/// signature was verified against the birth block hash, i.e. [`Self::additional_signed`].
+	pub fn validity_window(&self) -> Option<(BlockNumberFor<T>, Option<BlockNumberFor<T>>)> {
+		let current_u64 = <Pallet<T>>::block_number().saturated_into::<u64>();
+		let birth = self.0.birth(current_u64).saturated_into();
+		if !<BlockHash<T>>::contains_key(birth) {
+			return None
+		}
+		let death = (!self.0.is_immortal()).then(|| self.0.death(current_u64).saturated_into());
+		Some((birth, death))
+	}
+}
+
+/// The number of blocks an era lasts, `u64::MAX` for the immortal one.
+fn period(era: &Era) -> u64 {
+	match era {
+		Era::Immortal => u64::MAX,
+		Era::Mortal(period, _) => *period,
+	}
 }
 
-impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckMortality<T> {
+impl<T: Config + Send + Sync, MaxPeriod> sp_std::fmt::Debug for CheckMortality<T, MaxPeriod> {
 	#[cfg(feature = "std")]
 	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
 		write!(f, "CheckMortality({:?})", self.0)
@@ -54,7 +89,9 @@ impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckMortality<T> {
 	}
 }
 
-impl<T: Config + Send + Sync> SignedExtension for CheckMortality<T> {
+impl<T: Config + Send + Sync, MaxPeriod: Get<u64> + Send + Sync + 'static> SignedExtension
+	for CheckMortality<T, MaxPeriod>
+{
 	type AccountId = T::AccountId;
 	type Call = T::RuntimeCall;
 	type AdditionalSigned = T::Hash;
@@ -68,6 +105,9 @@ impl<T: Config + Send + Sync> SignedExtension for CheckMortality<T> {
 		_info: &DispatchInfoOf<Self::Call>,
 		_len: usize,
 	) -> TransactionValidity {
+		if period(&self.0) > MaxPeriod::get() {
+			return Err(InvalidTransaction::EraTooLong.into())
+		}
 		let current_u64 = <Pallet<T>>::block_number().saturated_into::<u64>();
 		let valid_till = self.0.death(current_u64);
 		Ok(ValidTransaction {
@@ -145,4 +185,48 @@ mod tests {
 			assert_eq!(ext.validate(&1, CALL, &normal, len).unwrap().longevity, 15);
 		})
 	}
+
+	#[test]
+	fn signed_ext_check_era_should_respect_max_period() {
+		new_test_ext().execute_with(|| {
+			let info = DispatchInfo::default();
+			type Bounded = CheckMortality<Test, ConstU64<64>>;
+			System::set_block_number(17);
+
+			assert!(Bounded::from(Era::mortal(64, 16)).validate(&1, CALL, &info, 0).is_ok());
+			assert_eq!(
+				Bounded::from(Era::mortal(128, 16)).validate(&1, CALL, &info, 0),
+				Err(InvalidTransaction::EraTooLong.into()),
+			);
+			assert_eq!(
+				Bounded::from(Era::immortal()).validate(&1, CALL, &info, 0),
+				Err(InvalidTransaction::EraTooLong.into()),
+			);
+
+			// Unbounded by default.
+			assert!(CheckMortality::<Test>::from(Era::immortal())
+				.validate(&1, CALL, &info, 0)
+				.is_ok());
+		})
+	}
+
+	#[test]
+	fn validity_window_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(17);
+			// The birth block is not known.
+			assert_eq!(CheckMortality::<Test>::from(Era::mortal(16, 16)).validity_window(), None);
+
+			<BlockHash<Test>>::insert(16, H256::repeat_byte(1));
+			<BlockHash<Test>>::insert(0, H256::repeat_byte(2));
+			assert_eq!(
+				CheckMortality::<Test>::from(Era::mortal(16, 16)).validity_window(),
+				Some((16, Some(32)))
+			);
+			assert_eq!(
+				CheckMortality::<Test>::from(Era::immortal()).validity_window(),
+				Some((0, None))
+			);
+		})
+	}
 }
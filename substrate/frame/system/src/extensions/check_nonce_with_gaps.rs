@@ -0,0 +1,371 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Config;
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchInfo,
+	traits::{ConstU32, Get},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, One, SignedExtension, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionLongevity, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+};
+use sp_std::{marker::PhantomData, vec};
+
+/// The maximum gap supported by [`CheckNonceWithGaps`], bounding the nonces kept in
+/// [`UsedNonces`](crate::UsedNonces) per account.
+pub const MAX_NONCE_GAP: u32 = 64;
+
+/// Nonce check and increment to give replay protection for transactions, tolerating gaps.
+///
+/// This is an alternative to [`CheckNonce`](crate::CheckNonce), with the same encoding. Instead of
+/// requiring the nonce of a transaction to be exactly the current account nonce, any unused nonce
+/// within `account.nonce..=account.nonce + MaxGap` is accepted. The nonces used above the account
+/// nonce are kept in [`UsedNonces`](crate::UsedNonces), and the account nonce advances past them
+/// once the gap below them is filled. Skipped nonces therefore stay usable, while every nonce can
+/// still be used only once.
+///
+/// This is useful for accounts which are used by several independent submitters at once, e.g.
+/// relayers batching transactions of an abstracted account, which cannot coordinate on a strict
+/// nonce sequence. Nonces beyond the window are still accepted by the transaction pool, but are
+/// held back as future transactions, until the window catches up with them.
+///
+/// `MaxGap` is capped at [`MAX_NONCE_GAP`]. With `MaxGap` being zero, which is the default, this
+/// behaves exactly like `CheckNonce`.
+///
+/// # Transaction Validity
+///
+/// This extension affects `requires` and `provides` tags of validity, but DOES NOT
+/// set the `priority` field. Make sure that AT LEAST one of the signed extension sets
+/// some kind of priority upon validating transactions.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T, MaxGap))]
+pub struct CheckNonceWithGaps<T: Config, MaxGap = ConstU32<0>>(
+	#[codec(compact)] pub T::Nonce,
+	PhantomData<MaxGap>,
+);
+
+impl<T: Config, MaxGap: Get<u32>> CheckNonceWithGaps<T, MaxGap> {
+	/// utility constructor. Used only in client/factory code.
+	pub fn from(nonce: T::Nonce) -> Self {
+		Self(nonce, PhantomData)
+	}
+
+	/// The maximum gap between the account nonce and the nonce of a transaction.
+	fn max_gap() -> u32 {
+		MaxGap::get().min(MAX_NONCE_GAP)
+	}
+
+	/// The highest nonce which is acceptable for inclusion, given the current account nonce.
+	fn max_acceptable(account_nonce: T::Nonce) -> T::Nonce {
+		account_nonce.saturating_add(Self::max_gap().into())
+	}
+
+	/// The nonces above the account nonce of `who` which were already used.
+	fn used_nonces(who: &T::AccountId) -> UsedNoncesOf<T> {
+		// Nothing is ever used above the account nonce without a gap.
+		if Self::max_gap().is_zero() {
+			Default::default()
+		} else {
+			crate::UsedNonces::<T>::get(who)
+		}
+	}
+}
+
+type UsedNoncesOf<T> = BoundedVec<<T as Config>::Nonce, ConstU32<MAX_NONCE_GAP>>;
+
+impl<T: Config, MaxGap> sp_std::fmt::Debug for CheckNonceWithGaps<T, MaxGap> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckNonceWithGaps({})", self.0)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config, MaxGap: Get<u32> + Send + Sync + 'static> SignedExtension
+	for CheckNonceWithGaps<T, MaxGap>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+{
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "CheckNonceWithGaps";
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		let mut account = crate::Account::<T>::get(who);
+		if account.providers.is_zero() && account.sufficients.is_zero() {
+			// Nonce storage not paid for
+			return Err(InvalidTransaction::Payment.into())
+		}
+		if self.0 < account.nonce {
+			return Err(InvalidTransaction::Stale.into())
+		}
+		let mut used = Self::used_nonces(who);
+		let position = match used.binary_search(&self.0) {
+			Ok(_) => return Err(InvalidTransaction::Stale.into()),
+			Err(position) => position,
+		};
+		if self.0 > Self::max_acceptable(account.nonce) {
+			return Err(InvalidTransaction::Future.into())
+		}
+
+		if self.0 == account.nonce {
+			// Advance past all nonces which were used out of order right above this one.
+			account.nonce += T::Nonce::one();
+			while used.first() == Some(&account.nonce) {
+				used.remove(0);
+				account.nonce += T::Nonce::one();
+			}
+		} else {
+			// Can not fail, all used nonces are within the window.
+			used.try_insert(position, self.0).map_err(|_| InvalidTransaction::Future)?;
+		}
+
+		crate::Account::<T>::insert(who, account);
+		if used.is_empty() {
+			crate::UsedNonces::<T>::remove(who);
+		} else {
+			crate::UsedNonces::<T>::insert(who, used);
+		}
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let account = crate::Account::<T>::get(who);
+		if account.providers.is_zero() && account.sufficients.is_zero() {
+			// Nonce storage not paid for
+			return InvalidTransaction::Payment.into()
+		}
+		if self.0 < account.nonce || Self::used_nonces(who).binary_search(&self.0).is_ok() {
+			return InvalidTransaction::Stale.into()
+		}
+
+		// Within the window the transaction is ready right away, beyond it the pool holds it back
+		// until its predecessor is known.
+		let provides = vec![Encode::encode(&(who, self.0))];
+		let requires = if self.0 > Self::max_acceptable(account.nonce) {
+			vec![Encode::encode(&(who, self.0 - One::one()))]
+		} else {
+			vec![]
+		};
+
+		Ok(ValidTransaction {
+			priority: 0,
+			requires,
+			provides,
+			longevity: TransactionLongevity::max_value(),
+			propagate: true,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{new_test_ext, Test, CALL},
+		CheckNonce,
+	};
+	use frame_support::{assert_noop, assert_ok};
+
+	type Strict = CheckNonceWithGaps<Test>;
+	type Gapped = CheckNonceWithGaps<Test, ConstU32<3>>;
+
+	fn set_nonce(who: u64, nonce: u32) {
+		crate::Account::<Test>::insert(
+			who,
+			crate::AccountInfo { nonce, consumers: 0, providers: 1, sufficients: 0, data: 0 },
+		);
+	}
+
+	#[test]
+	fn encoding_matches_check_nonce() {
+		assert_eq!(Gapped::from(42).encode(), CheckNonce::<Test>(42).encode());
+		assert_eq!(
+			Gapped::decode(&mut &CheckNonce::<Test>(42).encode()[..]).unwrap(),
+			Gapped::from(42)
+		);
+	}
+
+	#[test]
+	fn strict_default_behaves_like_check_nonce() {
+		new_test_ext().execute_with(|| {
+			set_nonce(1, 1);
+			let info = DispatchInfo::default();
+			let len = 0_usize;
+			for nonce in 0..5 {
+				assert_eq!(
+					Strict::from(nonce).validate(&1, CALL, &info, len),
+					CheckNonce::<Test>(nonce).validate(&1, CALL, &info, len),
+				);
+			}
+			// stale
+			assert_noop!(
+				Strict::from(0).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Stale
+			);
+			// future
+			assert_noop!(
+				Strict::from(2).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Future
+			);
+			// correct
+			assert_ok!(Strict::from(1).pre_dispatch(&1, CALL, &info, len));
+			assert_eq!(crate::Account::<Test>::get(1).nonce, 2);
+		})
+	}
+
+	#[test]
+	fn nonces_within_window_are_ready() {
+		new_test_ext().execute_with(|| {
+			set_nonce(1, 5);
+			let info = DispatchInfo::default();
+			let len = 0_usize;
+			// stale
+			assert_noop!(Gapped::from(4).validate(&1, CALL, &info, len), InvalidTransaction::Stale);
+			// anything from the current nonce up to the gap is ready.
+			for nonce in 5..=8 {
+				let valid = Gapped::from(nonce).validate(&1, CALL, &info, len).unwrap();
+				assert!(valid.requires.is_empty());
+				assert_eq!(valid.provides, vec![(1u64, nonce).encode()]);
+			}
+			// beyond the gap the transaction is held back as future.
+			let valid = Gapped::from(9).validate(&1, CALL, &info, len).unwrap();
+			assert_eq!(valid.requires, vec![(1u64, 8u32).encode()]);
+			assert_eq!(valid.provides, vec![(1u64, 9u32).encode()]);
+		})
+	}
+
+	#[test]
+	fn skipped_nonces_remain_usable() {
+		new_test_ext().execute_with(|| {
+			set_nonce(1, 5);
+			let info = DispatchInfo::default();
+			let len = 0_usize;
+			// beyond the gap
+			assert_noop!(
+				Gapped::from(9).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Future
+			);
+			// at the end of the window
+			assert_ok!(Gapped::from(8).pre_dispatch(&1, CALL, &info, len));
+			assert_eq!(crate::Account::<Test>::get(1).nonce, 5);
+			assert_eq!(crate::UsedNonces::<Test>::get(1).into_inner(), vec![8]);
+			// the window does not move until the gap is filled.
+			assert_noop!(
+				Gapped::from(9).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Future
+			);
+			assert_ok!(Gapped::from(6).pre_dispatch(&1, CALL, &info, len));
+			assert_eq!(crate::UsedNonces::<Test>::get(1).into_inner(), vec![6, 8]);
+			// filling the first gap advances the account nonce past the used nonces.
+			assert_ok!(Gapped::from(5).pre_dispatch(&1, CALL, &info, len));
+			assert_eq!(crate::Account::<Test>::get(1).nonce, 7);
+			assert_eq!(crate::UsedNonces::<Test>::get(1).into_inner(), vec![8]);
+			assert_ok!(Gapped::from(7).pre_dispatch(&1, CALL, &info, len));
+			assert_eq!(crate::Account::<Test>::get(1).nonce, 9);
+			assert!(!crate::UsedNonces::<Test>::contains_key(1));
+			// the previously future transaction is ready now.
+			assert!(Gapped::from(9).validate(&1, CALL, &info, len).unwrap().requires.is_empty());
+		})
+	}
+
+	#[test]
+	fn used_nonces_can_not_be_replayed() {
+		new_test_ext().execute_with(|| {
+			set_nonce(1, 5);
+			let info = DispatchInfo::default();
+			let len = 0_usize;
+			assert_ok!(Gapped::from(7).pre_dispatch(&1, CALL, &info, len));
+			assert_noop!(Gapped::from(7).validate(&1, CALL, &info, len), InvalidTransaction::Stale);
+			assert_noop!(
+				Gapped::from(7).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Stale
+			);
+			// the other nonces of the window are still usable.
+			for nonce in [5, 6, 8] {
+				assert_ok!(Gapped::from(nonce).validate(&1, CALL, &info, len));
+			}
+		})
+	}
+
+	#[test]
+	fn gap_is_capped() {
+		type Huge = CheckNonceWithGaps<Test, ConstU32<{ u32::MAX }>>;
+		new_test_ext().execute_with(|| {
+			set_nonce(1, 0);
+			let info = DispatchInfo::default();
+			let len = 0_usize;
+			assert_noop!(
+				Huge::from(MAX_NONCE_GAP + 1).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Future
+			);
+			for nonce in (1..=MAX_NONCE_GAP).rev() {
+				assert_ok!(Huge::from(nonce).pre_dispatch(&1, CALL, &info, len));
+			}
+			assert_eq!(crate::UsedNonces::<Test>::get(1).len() as u32, MAX_NONCE_GAP);
+			assert_ok!(Huge::from(0).pre_dispatch(&1, CALL, &info, len));
+			assert_eq!(crate::Account::<Test>::get(1).nonce, MAX_NONCE_GAP + 1);
+			assert!(!crate::UsedNonces::<Test>::contains_key(1));
+		})
+	}
+
+	#[test]
+	fn check_nonce_with_gaps_requires_provider() {
+		new_test_ext().execute_with(|| {
+			let info = DispatchInfo::default();
+			let len = 0_usize;
+			// Both providers and sufficients zero
+			assert_noop!(
+				Gapped::from(1).validate(&1, CALL, &info, len),
+				InvalidTransaction::Payment
+			);
+			assert_noop!(
+				Gapped::from(1).pre_dispatch(&1, CALL, &info, len),
+				InvalidTransaction::Payment
+			);
+		})
+	}
+}
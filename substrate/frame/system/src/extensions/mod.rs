@@ -19,6 +19,7 @@ pub mod check_genesis;
 pub mod check_mortality;
 pub mod check_non_zero_sender;
 pub mod check_nonce;
+pub mod check_nonce_with_gaps;
 pub mod check_spec_version;
 pub mod check_tx_version;
 pub mod check_weight;
@@ -51,6 +51,8 @@
 //!     exceed the limits.
 //!   - [`CheckNonce`]: Checks the nonce of the transaction. Contains a single payload of type
 //!     `T::Nonce`.
+//!   - [`CheckNonceWithGaps`]: Alternative to [`CheckNonce`], which tolerates a bounded gap between
+//!     the account nonce and the nonce of the transaction.
 //!   - [`CheckEra`]: Checks the era of the transaction. Contains a single payload of type `Era`.
 //!   - [`CheckGenesis`]: Checks the provided genesis hash of the transaction. Must be a part of the
 //!     signed payload of the transaction.
@@ -87,16 +89,23 @@ use sp_version::RuntimeVersion;
 use codec::{Decode, Encode, EncodeLike, FullCodec, MaxEncodedLen};
 #[cfg(feature = "std")]
 use frame_support::traits::BuildGenesisConfig;
+#[cfg(feature = "experimental")]
+use frame_support::traits::Task;
 use frame_support::{
 	dispatch::{
 		extract_actual_pays_fee, extract_actual_weight, DispatchClass, DispatchInfo,
 		DispatchResult, DispatchResultWithPostInfo, PerDispatchClass,
 	},
 	impl_ensure_origin_with_arg_ignoring_arg,
-	storage::{self, StorageStreamIter},
+	storage::{
+		self,
+		transactional::{RolledBack, Steps},
+		StorageStreamIter,
+	},
 	traits::{
 		ConstU32, Contains, EnsureOrigin, EnsureOriginWithArg, Get, HandleLifetime,
-		OnKilledAccount, OnNewAccount, OriginTrait, PalletInfo, SortedMembers, StoredMap, TypedGet,
+		OnKilledAccount, OnNewAccount, OriginTrait, PalletInfo, PalletInfoAccess, RuntimeTasks,
+		SortedMembers, StoredMap, TypedGet,
 	},
 	Parameter,
 };
@@ -111,6 +120,8 @@ pub mod limits;
 #[cfg(test)]
 pub(crate) mod mock;
 pub mod offchain;
+pub mod runtime_upgrade;
+pub mod storage_keys;
 
 mod extensions;
 #[cfg(feature = "std")]
@@ -124,8 +135,8 @@ pub mod migrations;
 pub use extensions::{
 	check_genesis::CheckGenesis, check_mortality::CheckMortality,
 	check_non_zero_sender::CheckNonZeroSender, check_nonce::CheckNonce,
-	check_spec_version::CheckSpecVersion, check_tx_version::CheckTxVersion,
-	check_weight::CheckWeight,
+	check_nonce_with_gaps::CheckNonceWithGaps, check_spec_version::CheckSpecVersion,
+	check_tx_version::CheckTxVersion, check_weight::CheckWeight,
 };
 // Backward compatible re-export.
 pub use extensions::check_mortality::CheckMortality as CheckEra;
@@ -243,6 +254,7 @@ pub mod pallet {
 			type BaseCallFilter = frame_support::traits::Everything;
 			type BlockHashCount = frame_support::traits::ConstU64<10>;
 			type OnSetCode = ();
+			type MaxEventsPerPallet = ();
 		}
 
 		/// Default configurations of this pallet in a solo-chain environment.
@@ -290,13 +302,14 @@ pub mod pallet {
 			type BaseCallFilter = frame_support::traits::Everything;
 			type BlockHashCount = frame_support::traits::ConstU32<256>;
 			type OnSetCode = ();
+			type MaxEventsPerPallet = ();
 		}
 	}
 
 	/// System configuration trait. Implemented by runtime.
 	#[pallet::config(with_default)]
 	#[pallet::disable_frame_system_supertrait_check]
-	pub trait Config: 'static + Eq + Clone {
+	pub trait Config: 'static + Eq + Clone + RuntimeTasks {
 		/// The aggregated event type of the runtime.
 		#[pallet::no_default_bounds]
 		type RuntimeEvent: Parameter
@@ -448,6 +461,14 @@ pub mod pallet {
 
 		/// The maximum number of consumers allowed on a single account.
 		type MaxConsumers: ConsumerLimits;
+
+		/// The maximum number of events a single pallet may deposit per block, if any.
+		///
+		/// Further events of the pallet in the same block are dropped and replaced by a single
+		/// [`Event::EventsTruncated`]. The events of this pallet are never truncated. Use `()` to
+		/// not limit the number of events.
+		#[pallet::constant]
+		type MaxEventsPerPallet: Get<Option<u32>>;
 	}
 
 	#[pallet::pallet]
@@ -568,6 +589,24 @@ pub mod pallet {
 			Self::deposit_event(Event::Remarked { sender: who, hash });
 			Ok(().into())
 		}
+
+		/// Run a task declared by one of the pallets of the runtime.
+		///
+		/// Anyone can submit a task, but it must currently be valid, i.e. there must be work
+		/// pending for it.
+		///
+		/// Only available with the `experimental` feature.
+		#[cfg(feature = "experimental")]
+		#[pallet::call_index(8)]
+		#[pallet::weight(task.weight())]
+		pub fn do_task(origin: OriginFor<T>, task: T::RuntimeTask) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(task.is_valid(), Error::<T>::InvalidTask);
+
+			task.run()?;
+			Self::deposit_event(Event::TaskCompleted { task });
+			Ok(().into())
+		}
 	}
 
 	/// Event for the System pallet.
@@ -585,6 +624,18 @@ pub mod pallet {
 		KilledAccount { account: T::AccountId },
 		/// On on-chain remark happened.
 		Remarked { sender: T::AccountId, hash: T::Hash },
+		/// A task was completed successfully.
+		TaskCompleted { task: T::RuntimeTask },
+		/// The `on_initialize` hook of a pallet failed and its changes were rolled back.
+		///
+		/// Only emitted if the executive runs the hooks in isolation.
+		OnInitializeRolledBack { pallet_index: u32 },
+		/// A pallet reached [`Config::MaxEventsPerPallet`], its further events in this block were
+		/// dropped.
+		EventsTruncated { pallet: u32 },
+		/// An atomic operation of a pallet, run with [`Pallet::with_transaction_multi`], was
+		/// rolled back because of `error`, which happened in its step of index `step`, if any.
+		AtomicOperationRolledBack { pallet: u32, step: Option<u32>, error: DispatchError },
 	}
 
 	/// Error for the System pallet
@@ -606,6 +657,8 @@ pub mod pallet {
 		NonZeroRefCount,
 		/// The origin filter prevent the call to be dispatched.
 		CallFiltered,
+		/// The specified task is not valid, i.e. there is no work pending for it.
+		InvalidTask,
 	}
 
 	/// Exposed trait-generic origin type.
@@ -623,6 +676,19 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The nonces above the account nonce which were already used by [`CheckNonceWithGaps`],
+	/// sorted in ascending order.
+	///
+	/// Only written for accounts using nonces out of order, see [`CheckNonceWithGaps`].
+	#[pallet::storage]
+	pub type UsedNonces<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::Nonce, ConstU32<{ crate::extensions::check_nonce_with_gaps::MAX_NONCE_GAP }>>,
+		ValueQuery,
+	>;
+
 	/// Total extrinsics count for the current block.
 	#[pallet::storage]
 	pub(super) type ExtrinsicCount<T: Config> = StorageValue<_, u32>;
@@ -631,7 +697,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::whitelist_storage]
 	#[pallet::getter(fn block_weight)]
-	pub(super) type BlockWeight<T: Config> = StorageValue<_, ConsumedWeight, ValueQuery>;
+	pub type BlockWeight<T: Config> = StorageValue<_, ConsumedWeight, ValueQuery>;
 
 	/// Total length (in bytes) for all extrinsics put together, for the current block.
 	#[pallet::storage]
@@ -667,6 +733,14 @@ pub mod pallet {
 	#[pallet::getter(fn digest)]
 	pub(super) type Digest<T: Config> = StorageValue<_, generic::Digest, ValueQuery>;
 
+	/// Author of the current block, if noted by the consensus pallet through
+	/// [`Pallet::note_block_author`], e.g. by `pallet_authorship`.
+	///
+	/// Reset when the next block is initialized.
+	#[pallet::storage]
+	#[pallet::getter(fn block_author)]
+	pub type BlockAuthor<T: Config> = StorageValue<_, T::AccountId>;
+
 	/// Events deposited for the current block.
 	///
 	/// NOTE: The item is unbound and should therefore never be read on chain.
@@ -702,6 +776,17 @@ pub mod pallet {
 	pub(super) type EventTopics<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::Hash, Vec<(BlockNumberFor<T>, EventIndex)>, ValueQuery>;
 
+	/// The number of events deposited in the current block by each pallet which deposited any,
+	/// sorted by pallet index.
+	///
+	/// Only tracked if [`Config::MaxEventsPerPallet`] is set. Like [`ExecutionPhase`], it is
+	/// removed when the block is finalized.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	#[pallet::unbounded]
+	pub(super) type PalletEventCounts<T: Config> =
+		StorageValue<_, Vec<(u8, EventIndex)>, ValueQuery>;
+
 	/// Stores the `spec_version` and `spec_name` of when the last runtime upgrade happened.
 	#[pallet::storage]
 	#[pallet::unbounded]
@@ -1358,6 +1443,10 @@ impl<T: Config> Pallet<T> {
 			return
 		}
 
+		if Self::pallet_events_truncated(&event) {
+			return
+		}
+
 		let phase = ExecutionPhase::<T>::get().unwrap_or_default();
 		let event = EventRecord { phase, event, topics: topics.to_vec() };
 
@@ -1381,6 +1470,64 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Count `event` against the [`Config::MaxEventsPerPallet`] of the pallet depositing it.
+	///
+	/// Returns whether the pallet already reached its limit and `event` must be dropped. Deposits
+	/// [`Event::EventsTruncated`] for the first dropped event of the pallet in the block.
+	fn pallet_events_truncated(event: &T::RuntimeEvent) -> bool {
+		let Some(max_events) = T::MaxEventsPerPallet::get() else { return false };
+
+		// The variant index of the aggregated event is the index of the pallet depositing it.
+		let pallet = event.using_encoded(|encoded| encoded.first().copied().unwrap_or_default());
+		if Some(pallet as usize) == T::PalletInfo::index::<Pallet<T>>() {
+			return false
+		}
+
+		let count = PalletEventCounts::<T>::mutate(|counts| {
+			let position = counts.binary_search_by_key(&pallet, |(index, _)| *index);
+			let position = position.unwrap_or_else(|position| {
+				counts.insert(position, (pallet, 0));
+				position
+			});
+			let count = counts[position].1;
+			if count <= max_events {
+				counts[position].1 = count + 1;
+			}
+			count
+		});
+
+		if count == max_events {
+			Self::deposit_event(Event::EventsTruncated { pallet: pallet as u32 });
+		}
+		count >= max_events
+	}
+
+	/// Run an atomic operation of the pallet `P` made of several [`Steps`], see
+	/// [`storage::with_transaction_multi`].
+	///
+	/// If the operation is rolled back, [`Event::AtomicOperationRolledBack`] is deposited with the
+	/// reason, after the rollback of the operation's own storage transaction.
+	///
+	/// Like any other event, it is discarded together with the other changes of the dispatchable
+	/// calling this if that dispatchable fails, since every extrinsic runs in a storage layer of
+	/// its own. The event is therefore only kept if the caller handles the rollback, e.g. by
+	/// falling back to another route, and succeeds.
+	pub fn with_transaction_multi<P, R, E, F>(f: F) -> Result<R, RolledBack<E>>
+	where
+		P: PalletInfoAccess,
+		E: From<DispatchError> + Into<DispatchError> + Clone,
+		F: FnOnce(&mut Steps<E>) -> Result<R, E>,
+	{
+		storage::with_transaction_multi(f).map_err(|rolled_back| {
+			Self::deposit_event(Event::AtomicOperationRolledBack {
+				pallet: P::index() as u32,
+				step: rolled_back.step,
+				error: rolled_back.error.clone().into(),
+			});
+			rolled_back
+		})
+	}
+
 	/// Gets the index of extrinsic that is currently executing.
 	pub fn extrinsic_index() -> Option<u32> {
 		storage::unhashed::get(well_known_keys::EXTRINSIC_INDEX)
@@ -1416,6 +1563,18 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Inform the system pallet that some weight already accounted for in the current block was
+	/// not consumed after all, e.g. because the work it was reserved for was skipped.
+	///
+	/// This is the counterpart of [`Self::register_extra_weight_unchecked`] and saturates at zero.
+	pub fn reclaim_weight(weight: Weight, class: DispatchClass) {
+		if weight.any_gt(Weight::zero()) {
+			BlockWeight::<T>::mutate(|current_weight| {
+				current_weight.reduce(weight, class);
+			});
+		}
+	}
+
 	/// Start the execution of a particular block.
 	pub fn initialize(number: &BlockNumberFor<T>, parent_hash: &T::Hash, digest: &generic::Digest) {
 		// populate environment
@@ -1430,6 +1589,15 @@ impl<T: Config> Pallet<T> {
 
 		// Remove previous block data from storage
 		BlockWeight::<T>::kill();
+		<BlockAuthor<T>>::kill();
+	}
+
+	/// Note the author of the current block, e.g. as found from the pre-runtime digests by the
+	/// consensus pallet.
+	///
+	/// The author can then be read from [`BlockAuthor`] until the next block is initialized.
+	pub fn note_block_author(author: T::AccountId) {
+		<BlockAuthor<T>>::put(author);
 	}
 
 	/// Remove temporary "environment" entries in storage, compute the storage root and return the
@@ -1471,6 +1639,7 @@ impl<T: Config> Pallet<T> {
 			).deconstruct(),
 		);
 		ExecutionPhase::<T>::kill();
+		PalletEventCounts::<T>::kill();
 		AllExtrinsicsLen::<T>::kill();
 		storage::unhashed::kill(well_known_keys::INTRABLOCK_ENTROPY);
 
@@ -1599,6 +1768,7 @@ impl<T: Config> Pallet<T> {
 		<Events<T>>::kill();
 		EventCount::<T>::kill();
 		let _ = <EventTopics<T>>::clear(u32::max_value(), None);
+		PalletEventCounts::<T>::kill();
 	}
 
 	/// Assert the given `event` exists.
@@ -1700,6 +1870,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Do anything that needs to be done after an account has been killed.
 	fn on_killed_account(who: T::AccountId) {
+		UsedNonces::<T>::remove(&who);
 		T::OnKilledAccount::on_killed_account(&who);
 		Self::deposit_event(Event::KilledAccount { account: who });
 	}
@@ -109,6 +109,7 @@ impl Config for Test {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 pub type SysEvent = frame_system::Event<Test>;
@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dry-running a runtime upgrade.
+//!
+//! The [`RuntimeUpgradeApi`] allows to check, before enacting `set_code`, whether a runtime is
+//! ready to be upgraded to on a live chain. It is meant to be called with the code of the new
+//! runtime against the current state of the chain, e.g. by overriding the runtime code of a
+//! `state_call`. Nothing that is done while dry-running the upgrade is persisted.
+
+use codec::{Decode, Encode};
+use frame_support::traits::PalletStorageVersions;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+use sp_weights::Weight;
+
+/// The outcome of dry-running a runtime upgrade.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct RuntimeUpgradeReport {
+	/// The pallets whose on-chain storage version is behind the version supported by their code.
+	///
+	/// These are the pallets that still need to be migrated.
+	pub pending_migrations: Vec<PalletStorageVersions>,
+	/// The weight reported by executing all `OnRuntimeUpgrade` hooks of the runtime in the block
+	/// enacting the upgrade.
+	pub weight: Weight,
+	/// The weight of the work deferred by the migrations to each of the blocks following the
+	/// upgrade, e.g. by [`DeferrableMigrations`](frame_support::migrations::DeferrableMigrations).
+	///
+	/// Empty if the migrations complete in the block enacting the upgrade.
+	pub deferred_weights: Vec<Weight>,
+	/// Whether `weight` or any of `deferred_weights` exceeds the maximum weight of a block.
+	///
+	/// The migrations would then not fit into the blocks executing them.
+	pub exceeds_max_block: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to dry-run a runtime upgrade.
+	pub trait RuntimeUpgradeApi {
+		/// Report the migrations pending in the current state and the weight of executing them,
+		/// including the work they defer to the blocks following the upgrade.
+		///
+		/// The migrations are executed, but all changes they make to the state are discarded.
+		fn dry_run_runtime_upgrade() -> RuntimeUpgradeReport;
+	}
+}
@@ -0,0 +1,42 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Computing storage keys from the metadata of the runtime.
+//!
+//! The [`StorageKeyApi`] derives the key of any storage item declared in the runtime from the
+//! names found in its metadata, the same way tooling does it off-chain. See
+//! [`frame_support::storage::keys`] for how keys are derived.
+
+use sp_std::prelude::*;
+
+pub use frame_support::storage::keys::StorageKeyError;
+
+sp_api::decl_runtime_apis! {
+	/// API to compute the storage keys of the runtime.
+	pub trait StorageKeyApi {
+		/// Compute the key of the storage item `storage_name` of the pallet `pallet_name`.
+		///
+		/// `keys` are the encoded keys of the item, hashed with the hashers declared for it.
+		/// Passing fewer keys than the item has hashers gives the prefix of all the matching
+		/// entries.
+		fn storage_key(
+			pallet_name: Vec<u8>,
+			storage_name: Vec<u8>,
+			keys: Vec<Vec<u8>>,
+		) -> Result<Vec<u8>, StorageKeyError>;
+	}
+}
@@ -763,6 +763,66 @@ fn ensure_signed_stuff_works() {
 	}
 }
 
+#[test]
+fn block_author_is_reset_on_initialize() {
+	new_test_ext().execute_with(|| {
+		System::initialize(&1, &[0u8; 32].into(), &Default::default());
+		assert_eq!(System::block_author(), None);
+		System::note_block_author(42);
+		assert_eq!(System::block_author(), Some(42));
+		System::finalize();
+
+		// the author of a previous block is not kept around
+		System::initialize(&2, &[0u8; 32].into(), &Default::default());
+		assert_eq!(System::block_author(), None);
+	});
+}
+
+#[test]
+fn atomic_operation_rollback_is_reported() {
+	new_test_ext().execute_with(|| {
+		System::initialize(&1, &[0u8; 32].into(), &Default::default());
+
+		let res = System::with_transaction_multi::<System, _, DispatchError, _>(|steps| {
+			steps.run("remark", || -> DispatchResult {
+				storage::unhashed::put(b"remarked", &true);
+				Ok(())
+			})?;
+			steps.run("fail", || -> DispatchResult { Err("nope".into()) })
+		});
+
+		assert_eq!(res.map_err(|rolled_back| rolled_back.step), Err(Some(1)));
+		assert_eq!(storage::unhashed::get::<bool>(b"remarked"), None);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			SysEvent::AtomicOperationRolledBack {
+				pallet: 0,
+				step: Some(1),
+				error: DispatchError::Other("nope"),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn atomic_operation_rollback_event_is_discarded_with_failing_caller() {
+	new_test_ext().execute_with(|| {
+		System::initialize(&1, &[0u8; 32].into(), &Default::default());
+
+		// The storage layer every extrinsic is dispatched in.
+		let res = storage::with_storage_layer(|| -> DispatchResult {
+			System::with_transaction_multi::<System, _, DispatchError, _>(|steps| {
+				steps.run("fail", || -> DispatchResult { Err("nope".into()) })
+			})?;
+			Ok(())
+		});
+
+		assert_eq!(res, Err(DispatchError::Other("nope")));
+		assert!(System::events().is_empty());
+	});
+}
+
 pub fn from_actual_ref_time(ref_time: Option<u64>) -> PostDispatchInfo {
 	PostDispatchInfo {
 		actual_weight: ref_time.map(|t| Weight::from_all(t)),
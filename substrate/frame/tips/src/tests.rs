@@ -38,6 +38,7 @@ use frame_support::{
 
 use super::*;
 use crate::{self as pallet_tips, Event as TipEvent};
+use frame_support::derive_impl;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -56,6 +57,7 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const AvailableBlockRatio: Perbill = Perbill::one();
 }
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
@@ -18,6 +18,7 @@ use crate as pallet_asset_conversion_tx_payment;
 
 use codec;
 use frame_support::{
+	derive_impl,
 	dispatch::DispatchClass,
 	instances::Instance2,
 	ord_parameter_types,
@@ -78,6 +79,7 @@ parameter_types! {
 	pub static TransactionByteFee: u64 = 1;
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = BlockWeights;
@@ -192,6 +194,7 @@ impl pallet_assets::Config for Runtime {
 	type CallbackHandle = ();
 	type WeightInfo = ();
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<5>;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
 	}
@@ -201,6 +204,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxRoleAccounts = ConstU32<5>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -26,6 +26,12 @@
 //! wrapped extension. If the dispatchable is indeed feeless, the extension is skipped and a custom
 //! event is emitted instead. Otherwise, the extension is applied as usual.
 //!
+//! The wrapped extension may also be a tuple of extensions, e.g. when a runtime bundles multiple
+//! payment-related extensions. They are then applied or skipped together.
+//!
+//! The weight of the wrapped extension(s) is accounted for as part of the extrinsic base weight.
+//! When they are skipped, the weight given by the [`ExtensionWeight`] implementation of
+//! [`Config::SkippedExtensionWeight`] is refunded from the block weight.
 //!
 //! ## Integration
 //!
@@ -37,12 +43,13 @@
 
 use codec::{Decode, Encode};
 use frame_support::{
-	dispatch::{CheckIfFeeless, DispatchResult},
+	dispatch::{CheckIfFeeless, DispatchInfo, DispatchResult},
 	traits::{IsType, OriginTrait},
+	weights::Weight,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension},
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
 	transaction_validity::TransactionValidityError,
 };
 
@@ -61,6 +68,12 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The weight of the wrapped extension(s), as accounted for in the extrinsic base weight.
+		///
+		/// It must implement [`ExtensionWeight`] for the wrapped extension(s). The weight is
+		/// refunded from the block weight whenever they are skipped. Use `()` to refund nothing.
+		type SkippedExtensionWeight;
 	}
 
 	#[pallet::pallet]
@@ -69,12 +82,30 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A transaction fee was skipped.
-		FeeSkipped { who: T::AccountId },
+		/// A transaction fee was skipped and the weight of the skipped extension(s) refunded.
+		FeeSkipped { who: T::AccountId, weight_refund: Weight },
+	}
+}
+
+/// The weight of a [`SignedExtension`] wrapped by [`SkipCheckIfFeeless`], as accounted for in the
+/// extrinsic base weight.
+///
+/// `S` may be a single extension or a tuple of extensions, in which case the weight is the one of
+/// all of them.
+pub trait ExtensionWeight<S: SignedExtension> {
+	/// The weight of `S` when applied for the dispatchable described by `info`.
+	fn weight(info: &DispatchInfoOf<S::Call>) -> Weight;
+}
+
+impl<S: SignedExtension> ExtensionWeight<S> for () {
+	fn weight(_: &DispatchInfoOf<S::Call>) -> Weight {
+		Weight::zero()
 	}
 }
 
 /// A [`SignedExtension`] that skips the wrapped extension if the dispatchable is feeless.
+///
+/// `S` may be a single extension or a tuple of extensions.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct SkipCheckIfFeeless<T: Config, S: SignedExtension>(pub S, sp_std::marker::PhantomData<T>);
@@ -100,7 +131,9 @@ impl<T: Config + Send + Sync, S: SignedExtension> SkipCheckIfFeeless<T, S> {
 impl<T: Config + Send + Sync, S: SignedExtension<AccountId = T::AccountId>> SignedExtension
 	for SkipCheckIfFeeless<T, S>
 where
-	S::Call: CheckIfFeeless<Origin = frame_system::pallet_prelude::OriginFor<T>>,
+	S::Call: CheckIfFeeless<Origin = frame_system::pallet_prelude::OriginFor<T>>
+		+ Dispatchable<Info = DispatchInfo>,
+	T::SkippedExtensionWeight: ExtensionWeight<S>,
 {
 	type AccountId = T::AccountId;
 	type Call = S::Call;
@@ -137,7 +170,9 @@ where
 			if let Some(pre) = pre.1 {
 				S::post_dispatch(Some(pre), info, post_info, len, result)?;
 			} else {
-				Pallet::<T>::deposit_event(Event::<T>::FeeSkipped { who: pre.0 });
+				let weight_refund = <T::SkippedExtensionWeight as ExtensionWeight<S>>::weight(info);
+				frame_system::Pallet::<T>::reclaim_weight(weight_refund, info.class);
+				Pallet::<T>::deposit_event(Event::<T>::FeeSkipped { who: pre.0, weight_refund });
 			}
 		}
 		Ok(())
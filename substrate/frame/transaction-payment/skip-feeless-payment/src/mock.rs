@@ -18,6 +18,7 @@ use crate as pallet_skip_feeless_payment;
 
 use frame_support::{derive_impl, parameter_types};
 use frame_system as system;
+use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 type AccountId = u64;
@@ -27,8 +28,23 @@ impl frame_system::Config for Runtime {
 	type Block = Block;
 }
 
+pub struct DummyExtensionWeight;
+
+impl ExtensionWeight<DummyExtension> for DummyExtensionWeight {
+	fn weight(_: &DispatchInfo) -> Weight {
+		Weight::from_parts(100, 0)
+	}
+}
+
+impl ExtensionWeight<(DummyExtension, DummyExtension)> for DummyExtensionWeight {
+	fn weight(info: &DispatchInfo) -> Weight {
+		<Self as ExtensionWeight<DummyExtension>>::weight(info).saturating_mul(2)
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type SkippedExtensionWeight = DummyExtensionWeight;
 }
 
 parameter_types! {
@@ -90,3 +106,10 @@ frame_support::construct_runtime!(
 		DummyPallet: pallet_dummy,
 	}
 );
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
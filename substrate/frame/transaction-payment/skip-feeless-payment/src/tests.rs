@@ -14,8 +14,11 @@
 // limitations under the License.
 
 use super::*;
-use crate::mock::{pallet_dummy::Call, DummyExtension, PreDispatchCount, Runtime, RuntimeCall};
-use frame_support::dispatch::DispatchInfo;
+use crate::mock::{
+	new_test_ext, pallet_dummy::Call, DummyExtension, PreDispatchCount, Runtime, RuntimeCall,
+	System,
+};
+use frame_support::dispatch::{DispatchClass, DispatchInfo, PostDispatchInfo};
 
 #[test]
 fn skip_feeless_payment_works() {
@@ -31,3 +34,71 @@ fn skip_feeless_payment_works() {
 		.unwrap();
 	assert_eq!(PreDispatchCount::get(), 1);
 }
+
+#[test]
+fn skipped_extension_weight_is_refunded() {
+	new_test_ext().execute_with(|| {
+		let info = DispatchInfo::default();
+		System::register_extra_weight_unchecked(Weight::from_parts(1000, 0), info.class);
+
+		let call = RuntimeCall::DummyPallet(Call::<Runtime>::aux { data: 0 });
+		let pre = SkipCheckIfFeeless::<Runtime, DummyExtension>::from(DummyExtension)
+			.pre_dispatch(&0, &call, &info, 0)
+			.unwrap();
+		SkipCheckIfFeeless::<Runtime, DummyExtension>::post_dispatch(
+			Some(pre),
+			&info,
+			&PostDispatchInfo::default(),
+			0,
+			&Ok(()),
+		)
+		.unwrap();
+
+		assert_eq!(*System::block_weight().get(DispatchClass::Normal), Weight::from_parts(900, 0));
+		System::assert_last_event(
+			Event::<Runtime>::FeeSkipped { who: 0, weight_refund: Weight::from_parts(100, 0) }
+				.into(),
+		);
+
+		// Nothing is refunded if the wrapped extension is applied.
+		let call = RuntimeCall::DummyPallet(Call::<Runtime>::aux { data: 1 });
+		let pre = SkipCheckIfFeeless::<Runtime, DummyExtension>::from(DummyExtension)
+			.pre_dispatch(&0, &call, &info, 0)
+			.unwrap();
+		SkipCheckIfFeeless::<Runtime, DummyExtension>::post_dispatch(
+			Some(pre),
+			&info,
+			&PostDispatchInfo::default(),
+			0,
+			&Ok(()),
+		)
+		.unwrap();
+		assert_eq!(*System::block_weight().get(DispatchClass::Normal), Weight::from_parts(900, 0));
+	});
+}
+
+#[test]
+fn skip_feeless_payment_works_for_wrapped_tuples() {
+	type Wrapped = SkipCheckIfFeeless<Runtime, (DummyExtension, DummyExtension)>;
+
+	let call = RuntimeCall::DummyPallet(Call::<Runtime>::aux { data: 1 });
+	Wrapped::from((DummyExtension, DummyExtension))
+		.pre_dispatch(&0, &call, &DispatchInfo::default(), 0)
+		.unwrap();
+	assert_eq!(PreDispatchCount::get(), 2);
+
+	new_test_ext().execute_with(|| {
+		let info = DispatchInfo::default();
+		System::register_extra_weight_unchecked(Weight::from_parts(1000, 0), info.class);
+
+		let call = RuntimeCall::DummyPallet(Call::<Runtime>::aux { data: 0 });
+		let pre = Wrapped::from((DummyExtension, DummyExtension))
+			.pre_dispatch(&0, &call, &info, 0)
+			.unwrap();
+		assert_eq!(PreDispatchCount::get(), 2);
+
+		// The weight of both skipped extensions is refunded.
+		Wrapped::post_dispatch(Some(pre), &info, &PostDispatchInfo::default(), 0, &Ok(())).unwrap();
+		assert_eq!(*System::block_weight().get(DispatchClass::Normal), Weight::from_parts(800, 0));
+	});
+}
@@ -44,6 +44,11 @@
 //!     final state of the chain at the end of the previous block. This can be configured via
 //!     [`Config::FeeMultiplierUpdate`]
 //!   - How the fees are paid via [`Config::OnChargeTransaction`].
+//!
+//! Where the tips end up is up to the [`OnUnbalanced`](frame_support::traits::OnUnbalanced)
+//! handler of [`CurrencyAdapter`]. [`SplitTips`] routes them according to a [`TipSplit`] between
+//! the block author, the treasury and burning. The split can be changed on chain with
+//! [`Pallet::set_tip_split`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -402,6 +407,20 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
 
+	/// The [`TipSplit`] set with [`Pallet::set_tip_split`], which [`SplitTips`] uses instead of
+	/// its default.
+	#[pallet::storage]
+	pub type TipSplitOverride<T: Config> = StorageValue<_, TipSplit, OptionQuery>;
+
+	/// The tips routed by [`SplitTips`] in the current block: to the block author, to the treasury
+	/// and burned.
+	///
+	/// Summarized by [`Event::TipsSplit`] and removed when the block is finalized.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub(super) type BlockTipSplit<T: Config> =
+		StorageValue<_, (BalanceOf<T>, BalanceOf<T>, BalanceOf<T>), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub multiplier: Multiplier,
@@ -429,14 +448,29 @@ pub mod pallet {
 		/// A transaction fee `actual_fee`, of which `tip` was added to the minimum inclusion fee,
 		/// has been paid by `who`.
 		TransactionFeePaid { who: T::AccountId, actual_fee: BalanceOf<T>, tip: BalanceOf<T> },
+		/// The tips of the block were routed by [`SplitTips`]: `to_author` went to the block
+		/// author, `to_treasury` to the treasury and `burnt` was burned.
+		TipsSplit { to_author: BalanceOf<T>, to_treasury: BalanceOf<T>, burnt: BalanceOf<T> },
+		/// The split used by [`SplitTips`] was set to `split`, or reset to its default if `None`.
+		TipSplitSet { split: Option<TipSplit> },
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			// Not benchmarked yet: only the storage accesses of summarizing the tips in
+			// `on_finalize` are accounted for.
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
 		fn on_finalize(_: frame_system::pallet_prelude::BlockNumberFor<T>) {
 			<NextFeeMultiplier<T>>::mutate(|fm| {
 				*fm = T::FeeMultiplierUpdate::convert(*fm);
 			});
+
+			if let Some((to_author, to_treasury, burnt)) = BlockTipSplit::<T>::take() {
+				Self::deposit_event(Event::TipsSplit { to_author, to_treasury, burnt });
+			}
 		}
 
 		#[cfg(feature = "std")]
@@ -482,6 +516,25 @@ pub mod pallet {
 			);
 		}
 	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set how [`SplitTips`] routes the tips, or reset it to the default split of the runtime
+		/// if `split` is `None`.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// Emits [`Event::TipSplitSet`].
+		#[pallet::call_index(0)]
+		// Not benchmarked yet: only the storage write is accounted for.
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_tip_split(origin: OriginFor<T>, split: Option<TipSplit>) -> DispatchResult {
+			ensure_root(origin)?;
+			TipSplitOverride::<T>::set(split);
+			Self::deposit_event(Event::TipSplitSet { split });
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -664,6 +717,20 @@ impl<T: Config> Pallet<T> {
 		let capped_weight = weight.min(T::BlockWeights::get().max_block);
 		T::WeightToFee::weight_to_fee(&capped_weight)
 	}
+
+	/// Note a tip routed by [`SplitTips`], to be summarized at the end of the block.
+	pub(crate) fn note_tip_split(
+		to_author: BalanceOf<T>,
+		to_treasury: BalanceOf<T>,
+		burnt: BalanceOf<T>,
+	) {
+		BlockTipSplit::<T>::mutate(|split| {
+			let (author, treasury, burn) = split.get_or_insert_with(Default::default);
+			*author = author.saturating_add(to_author);
+			*treasury = treasury.saturating_add(to_treasury);
+			*burn = burn.saturating_add(burnt);
+		});
+	}
 }
 
 impl<T> Convert<Weight, BalanceOf<T>> for Pallet<T>
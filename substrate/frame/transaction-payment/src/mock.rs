@@ -22,6 +22,7 @@ use sp_core::H256;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 
 use frame_support::{
+	derive_impl,
 	dispatch::DispatchClass,
 	parameter_types,
 	traits::{ConstU32, ConstU64, Imbalance, OnUnbalanced},
@@ -69,6 +70,7 @@ parameter_types! {
 	pub static OperationalFeeMultiplier: u8 = 5;
 }
 
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = BlockWeights;
@@ -133,6 +135,9 @@ impl WeightToFeeT for TransactionByteFee {
 parameter_types! {
 	pub(crate) static TipUnbalancedAmount: u64 = 0;
 	pub(crate) static FeeUnbalancedAmount: u64 = 0;
+	pub(crate) static MockTipSplit: TipSplit = TipSplit::default();
+	pub(crate) static AuthorTipAmount: u64 = 0;
+	pub(crate) static TreasuryTipAmount: u64 = 0;
 }
 
 pub struct DealWithFees;
@@ -149,6 +154,20 @@ impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithFees
 	}
 }
 
+pub struct ToAuthor;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for ToAuthor {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		AuthorTipAmount::mutate(|a| *a += amount.peek());
+	}
+}
+
+pub struct ToTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for ToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		TreasuryTipAmount::mutate(|a| *a += amount.peek());
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
@@ -16,16 +16,20 @@
 // limitations under the License.
 
 /// ! Traits and default implementation for paying transaction fees.
-use crate::Config;
+use crate::{BalanceOf, Config, Pallet, TipSplitOverride};
 
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
 	transaction_validity::InvalidTransaction,
+	Percent, RuntimeDebug,
 };
 use sp_std::marker::PhantomData;
 
 use frame_support::{
-	traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced, WithdrawReasons},
+	dispatch::DispatchClass,
+	traits::{Currency, ExistenceRequirement, Get, Imbalance, OnUnbalanced, WithdrawReasons},
 	unsigned::TransactionValidityError,
 };
 
@@ -154,3 +158,82 @@ where
 		Ok(())
 	}
 }
+
+/// How the tip of a transaction is routed by [`SplitTips`].
+///
+/// The `author` share goes to the block author and the `treasury` share to the treasury. The
+/// remainder, if any, is burned.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TipSplit {
+	/// The share of the tip going to the block author.
+	pub author: Percent,
+	/// The share of the tip going to the treasury.
+	pub treasury: Percent,
+}
+
+impl TipSplit {
+	/// The share of the tip which is burned.
+	pub fn burn(&self) -> Percent {
+		Percent::one().saturating_sub(self.author).saturating_sub(self.treasury)
+	}
+}
+
+impl Default for TipSplit {
+	/// The whole tip goes to the block author.
+	fn default() -> Self {
+		Self { author: Percent::one(), treasury: Percent::zero() }
+	}
+}
+
+/// An [`OnUnbalanced`] handler for the fee and the tip handed over by [`CurrencyAdapter`], which
+/// routes the tip according to a [`TipSplit`].
+///
+/// The fee is handed over to `OnFee`. The tip is split between `ToAuthor` and `ToTreasury`, and
+/// the remainder is burned. If the shares exceed 100% in total, the author is served first.
+///
+/// The split is the one set with [`crate::Pallet::set_tip_split`], or `DefaultSplit` if none was
+/// set. The tips routed within a block are summarized by a [`crate::Event::TipsSplit`] event when
+/// the block is finalized.
+pub struct SplitTips<T, DefaultSplit, OnFee, ToAuthor, ToTreasury>(
+	PhantomData<(T, DefaultSplit, OnFee, ToAuthor, ToTreasury)>,
+);
+
+impl<T, I, DefaultSplit, OnFee, ToAuthor, ToTreasury> OnUnbalanced<I>
+	for SplitTips<T, DefaultSplit, OnFee, ToAuthor, ToTreasury>
+where
+	T: Config,
+	I: Imbalance<BalanceOf<T>>,
+	DefaultSplit: Get<TipSplit>,
+	OnFee: OnUnbalanced<I>,
+	ToAuthor: OnUnbalanced<I>,
+	ToTreasury: OnUnbalanced<I>,
+{
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = I>) {
+		if let Some(fee) = fees_then_tips.next() {
+			OnFee::on_unbalanced(fee);
+		}
+		if let Some(tip) = fees_then_tips.next() {
+			// Not benchmarked yet: only the storage accesses of routing the tip are accounted for.
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::DbWeight::get().reads_writes(2, 1),
+				DispatchClass::Mandatory,
+			);
+
+			let split = TipSplitOverride::<T>::get().unwrap_or_else(DefaultSplit::get);
+			let amount = tip.peek();
+			let (to_author, rest) = tip.split(split.author.mul_floor(amount));
+			let (to_treasury, burnt) = rest.split(split.treasury.mul_floor(amount));
+
+			Pallet::<T>::note_tip_split(to_author.peek(), to_treasury.peek(), burnt.peek());
+			ToAuthor::on_unbalanced(to_author);
+			ToTreasury::on_unbalanced(to_treasury);
+			// Dropping the remainder burns it.
+			drop(burnt);
+		}
+	}
+
+	/// A single imbalance is treated as a fee.
+	fn on_nonzero_unbalanced(amount: I) {
+		OnFee::on_unbalanced(amount);
+	}
+}
@@ -22,12 +22,13 @@ use codec::Encode;
 
 use sp_runtime::{
 	testing::TestXt, traits::One, transaction_validity::InvalidTransaction, BuildStorage,
+	DispatchError, Percent,
 };
 
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
-	traits::Currency,
+	traits::{Currency, Hooks, OnUnbalanced},
 	weights::Weight,
 };
 use frame_system as system;
@@ -841,3 +842,77 @@ fn genesis_default_works() {
 		assert_eq!(<NextFeeMultiplier<Runtime>>::get(), Multiplier::saturating_from_integer(1));
 	});
 }
+
+#[test]
+fn split_tips_routes_tips_and_summarizes_them_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		type Handler = SplitTips<Runtime, MockTipSplit, DealWithFees, ToAuthor, ToTreasury>;
+		MockTipSplit::set(TipSplit {
+			author: Percent::from_percent(50),
+			treasury: Percent::from_percent(30),
+		});
+		assert_eq!(MockTipSplit::get().burn(), Percent::from_percent(20));
+
+		let issuance = Balances::total_issuance();
+		let fee = <Balances as Currency<_>>::issue(40);
+		let tip = <Balances as Currency<_>>::issue(100);
+		Handler::on_unbalanceds(vec![fee, tip].into_iter());
+		let tip = <Balances as Currency<_>>::issue(10);
+		Handler::on_unbalanceds(vec![<Balances as Currency<_>>::issue(0), tip].into_iter());
+
+		assert_eq!(FeeUnbalancedAmount::get(), 40);
+		assert_eq!(AuthorTipAmount::get(), 55);
+		assert_eq!(TreasuryTipAmount::get(), 33);
+		// The mock handlers drop what they are given, so everything is burned in the end.
+		assert_eq!(Balances::total_issuance(), issuance);
+
+		TransactionPayment::on_finalize(1);
+		System::assert_last_event(RuntimeEvent::TransactionPayment(Event::TipsSplit {
+			to_author: 55,
+			to_treasury: 33,
+			burnt: 22,
+		}));
+
+		// The summary is only emitted for blocks with routed tips.
+		System::reset_events();
+		TransactionPayment::on_finalize(2);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn set_tip_split_overrides_the_default_split() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		type Handler = SplitTips<Runtime, MockTipSplit, DealWithFees, ToAuthor, ToTreasury>;
+		let split =
+			TipSplit { author: Percent::from_percent(10), treasury: Percent::from_percent(90) };
+
+		assert_noop!(
+			TransactionPayment::set_tip_split(RuntimeOrigin::signed(1), Some(split)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TransactionPayment::set_tip_split(RuntimeOrigin::root(), Some(split)));
+		System::assert_last_event(RuntimeEvent::TransactionPayment(Event::TipSplitSet {
+			split: Some(split),
+		}));
+
+		// The default split would give the whole tip to the author.
+		Handler::on_unbalanceds(
+			vec![<Balances as Currency<_>>::issue(0), <Balances as Currency<_>>::issue(100)]
+				.into_iter(),
+		);
+		assert_eq!(AuthorTipAmount::get(), 10);
+		assert_eq!(TreasuryTipAmount::get(), 90);
+
+		// Resetting the split falls back to the default.
+		assert_ok!(TransactionPayment::set_tip_split(RuntimeOrigin::root(), None));
+		Handler::on_unbalanceds(
+			vec![<Balances as Currency<_>>::issue(0), <Balances as Currency<_>>::issue(100)]
+				.into_iter(),
+		);
+		assert_eq!(AuthorTipAmount::get(), 110);
+		assert_eq!(TreasuryTipAmount::get(), 90);
+	});
+}
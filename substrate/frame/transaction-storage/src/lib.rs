@@ -84,6 +84,26 @@ pub struct TransactionInfo {
 	block_chunks: u32,
 }
 
+/// Latest `store` or `renew` of some content, tracked by content hash.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	sp_runtime::RuntimeDebug,
+	PartialEq,
+	Eq,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct ContentInfo<BlockNumber> {
+	/// Block containing the latest `store` or `renew` of the content.
+	pub block: BlockNumber,
+	/// Index of the transaction within `block`.
+	pub index: u32,
+	/// Last block the content is kept in, unless renewed.
+	pub expires_at: BlockNumber,
+}
+
 fn num_chunks(bytes: u32) -> u32 {
 	((bytes as u64 + CHUNK_SIZE as u64 - 1) / CHUNK_SIZE as u64) as u32
 }
@@ -164,12 +184,23 @@ pub mod pallet {
 			// in this block, so we drop `obsolete` - 1.
 			let period = <StoragePeriod<T>>::get();
 			let obsolete = n.saturating_sub(period.saturating_add(One::one()));
+			let mut dropped = 0;
 			if obsolete > Zero::zero() {
-				<Transactions<T>>::remove(obsolete);
+				let transactions = <Transactions<T>>::take(obsolete).unwrap_or_default();
 				<ChunkCount<T>>::remove(obsolete);
+				// Stop tracking content which was not renewed since.
+				for info in &transactions {
+					<Contents<T>>::mutate_exists(info.content_hash, |content| {
+						if content.as_ref().map_or(false, |content| content.block == obsolete) {
+							*content = None;
+						}
+					});
+				}
+				dropped = transactions.len() as u64;
 			}
-			// 2 writes in `on_initialize` and 2 writes + 2 reads in `on_finalize`
-			T::DbWeight::get().reads_writes(2, 4)
+			// 1 read + 2 writes and a read + write per dropped transaction in `on_initialize`,
+			// 2 writes + 2 reads in `on_finalize`
+			T::DbWeight::get().reads_writes(3 + dropped, 4 + dropped)
 		}
 
 		fn on_finalize(n: BlockNumberFor<T>) {
@@ -238,6 +269,7 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::TooManyTransactions)?;
 				Ok(())
 			})?;
+			Self::note_content(content_hash.into(), index);
 			Self::deposit_event(Event::Stored { index });
 			Ok(())
 		}
@@ -256,33 +288,7 @@ pub mod pallet {
 			index: u32,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let transactions = <Transactions<T>>::get(block).ok_or(Error::<T>::RenewedNotFound)?;
-			let info = transactions.get(index as usize).ok_or(Error::<T>::RenewedNotFound)?;
-			let extrinsic_index =
-				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
-
-			Self::apply_fee(sender, info.size)?;
-
-			sp_io::transaction_index::renew(extrinsic_index, info.content_hash.into());
-
-			let mut index = 0;
-			<BlockTransactions<T>>::mutate(|transactions| {
-				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
-					return Err(Error::<T>::TooManyTransactions)
-				}
-				let chunks = num_chunks(info.size);
-				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunks;
-				index = transactions.len() as u32;
-				transactions
-					.try_push(TransactionInfo {
-						chunk_root: info.chunk_root,
-						size: info.size,
-						content_hash: info.content_hash,
-						block_chunks: total_chunks,
-					})
-					.map_err(|_| Error::<T>::TooManyTransactions)
-			})?;
-			Self::deposit_event(Event::Renewed { index });
+			Self::do_renew(sender, block, index)?;
 			Ok(().into())
 		}
 
@@ -337,6 +343,22 @@ pub mod pallet {
 			Self::deposit_event(Event::ProofChecked);
 			Ok(().into())
 		}
+
+		/// Renew previously stored data by its content hash, i.e. the blake2-256 hash of the data.
+		/// Renews the latest `store` or `renew` of the data, which must still be kept.
+		/// Applies same fees as `store`.
+		/// ## Complexity
+		/// - O(1).
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::renew().saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn renew_content(
+			origin: OriginFor<T>,
+			content_hash: <BlakeTwo256 as Hash>::Output,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let content = <Contents<T>>::get(content_hash).ok_or(Error::<T>::RenewedNotFound)?;
+			Self::do_renew(sender, content.block, content.index)
+		}
 	}
 
 	#[pallet::event]
@@ -361,6 +383,16 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Latest `store` or `renew` of the content which is currently kept, by content hash.
+	#[pallet::storage]
+	pub(super) type Contents<T: Config> = StorageMap<
+		_,
+		Identity,
+		<BlakeTwo256 as Hash>::Output,
+		ContentInfo<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	/// Count indexed chunks for each block.
 	#[pallet::storage]
 	pub(super) type ChunkCount<T: Config> =
@@ -442,6 +474,53 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The last block the content with the given blake2-256 hash is kept in, unless renewed.
+		///
+		/// Returns `None` if the content is not currently stored.
+		pub fn stored_until(content_hash: [u8; 32]) -> Option<BlockNumberFor<T>> {
+			<Contents<T>>::get(<BlakeTwo256 as Hash>::Output::from(content_hash))
+				.map(|content| content.expires_at)
+		}
+
+		fn do_renew(sender: T::AccountId, block: BlockNumberFor<T>, index: u32) -> DispatchResult {
+			let transactions = <Transactions<T>>::get(block).ok_or(Error::<T>::RenewedNotFound)?;
+			let info = transactions.get(index as usize).ok_or(Error::<T>::RenewedNotFound)?;
+			let extrinsic_index =
+				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
+
+			Self::apply_fee(sender, info.size)?;
+
+			sp_io::transaction_index::renew(extrinsic_index, info.content_hash.into());
+
+			let mut index = 0;
+			<BlockTransactions<T>>::mutate(|transactions| {
+				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
+					return Err(Error::<T>::TooManyTransactions)
+				}
+				let chunks = num_chunks(info.size);
+				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunks;
+				index = transactions.len() as u32;
+				transactions
+					.try_push(TransactionInfo {
+						chunk_root: info.chunk_root,
+						size: info.size,
+						content_hash: info.content_hash,
+						block_chunks: total_chunks,
+					})
+					.map_err(|_| Error::<T>::TooManyTransactions)
+			})?;
+			Self::note_content(info.content_hash, index);
+			Self::deposit_event(Event::Renewed { index });
+			Ok(())
+		}
+
+		/// Track the content stored or renewed by transaction `index` in the current block.
+		fn note_content(content_hash: <BlakeTwo256 as Hash>::Output, index: u32) {
+			let block = <frame_system::Pallet<T>>::block_number();
+			let expires_at = block.saturating_add(<StoragePeriod<T>>::get());
+			<Contents<T>>::insert(content_hash, ContentInfo { block, index, expires_at });
+		}
+
 		fn apply_fee(sender: T::AccountId, size: u32) -> DispatchResult {
 			let byte_fee = ByteFee::<T>::get().ok_or(Error::<T>::NotConfigured)?;
 			let entry_fee = EntryFee::<T>::get().ok_or(Error::<T>::NotConfigured)?;
@@ -147,3 +147,81 @@ fn renews_data() {
 		assert!(Transactions::<Test>::get(6).is_none());
 	});
 }
+
+#[test]
+fn tracks_content_expiry() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1, || None);
+		let caller = 1;
+		let data = vec![0u8; 2000];
+		let content_hash = sp_io::hashing::blake2_256(&data);
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), None);
+		assert_ok!(TransactionStorage::<Test>::store(RawOrigin::Signed(caller).into(), data));
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), Some(11));
+		let proof_provider = || {
+			let block_num = <frame_system::Pallet<Test>>::block_number();
+			if block_num == 11 {
+				let parent_hash = <frame_system::Pallet<Test>>::parent_hash();
+				Some(build_proof(parent_hash.as_ref(), vec![vec![0u8; 2000]]).unwrap())
+			} else {
+				None
+			}
+		};
+		run_to_block(11, proof_provider);
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), Some(11));
+		run_to_block(12, proof_provider);
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), None);
+		assert_noop!(
+			TransactionStorage::<Test>::renew_content(
+				RawOrigin::Signed(caller).into(),
+				content_hash.into(),
+			),
+			Error::<Test>::RenewedNotFound,
+		);
+	});
+}
+
+#[test]
+fn renews_content_by_hash() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1, || None);
+		let caller = 1;
+		let content_hash = sp_io::hashing::blake2_256(&[0u8; 2000]);
+		assert_noop!(
+			TransactionStorage::<Test>::renew_content(
+				RawOrigin::Signed(caller).into(),
+				content_hash.into(),
+			),
+			Error::<Test>::RenewedNotFound,
+		);
+		assert_ok!(TransactionStorage::<Test>::store(
+			RawOrigin::Signed(caller).into(),
+			vec![0u8; 2000]
+		));
+		let info = BlockTransactions::<Test>::get().last().unwrap().clone();
+		run_to_block(6, || None);
+		assert_ok!(TransactionStorage::<Test>::renew_content(
+			RawOrigin::Signed(caller).into(),
+			content_hash.into(),
+		));
+		assert_eq!(Balances::free_balance(1), 1_000_000_000 - 4000 * 2 - 200 * 2);
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), Some(16));
+		let proof_provider = || {
+			let block_num = <frame_system::Pallet<Test>>::block_number();
+			if block_num == 11 || block_num == 16 {
+				let parent_hash = <frame_system::Pallet<Test>>::parent_hash();
+				Some(build_proof(parent_hash.as_ref(), vec![vec![0u8; 2000]]).unwrap())
+			} else {
+				None
+			}
+		};
+		run_to_block(16, proof_provider);
+		// Dropping the original transaction keeps tracking the renewed one.
+		assert!(Transactions::<Test>::get(1).is_none());
+		assert_eq!(Transactions::<Test>::get(6).unwrap().get(0), Some(info).as_ref());
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), Some(16));
+		run_to_block(17, proof_provider);
+		assert!(Transactions::<Test>::get(6).is_none());
+		assert_eq!(TransactionStorage::<Test>::stored_until(content_hash), None);
+	});
+}
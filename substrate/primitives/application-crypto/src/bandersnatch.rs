@@ -32,23 +32,20 @@ pub use app::{Public as AppPublic, Signature as AppSignature};
 impl RuntimePublic for Public {
 	type Signature = Signature;
 
-	/// Dummy implementation. Returns an empty vector.
-	fn all(_key_type: KeyTypeId) -> Vec<Self> {
-		Vec::new()
+	fn all(key_type: KeyTypeId) -> Vec<Self> {
+		sp_io::crypto::bandersnatch_public_keys(key_type)
 	}
 
 	fn generate_pair(key_type: KeyTypeId, seed: Option<Vec<u8>>) -> Self {
 		sp_io::crypto::bandersnatch_generate(key_type, seed)
 	}
 
-	/// Dummy implementation. Returns `None`.
-	fn sign<M: AsRef<[u8]>>(&self, _key_type: KeyTypeId, _msg: &M) -> Option<Self::Signature> {
-		None
+	fn sign<M: AsRef<[u8]>>(&self, key_type: KeyTypeId, msg: &M) -> Option<Self::Signature> {
+		sp_io::crypto::bandersnatch_sign(key_type, self, msg.as_ref())
 	}
 
-	/// Dummy implementation. Returns `false`.
-	fn verify<M: AsRef<[u8]>>(&self, _msg: &M, _signature: &Self::Signature) -> bool {
-		false
+	fn verify<M: AsRef<[u8]>>(&self, msg: &M, signature: &Self::Signature) -> bool {
+		sp_io::crypto::bandersnatch_verify(signature, msg.as_ref(), self)
 	}
 
 	fn to_raw_vec(&self) -> Vec<u8> {
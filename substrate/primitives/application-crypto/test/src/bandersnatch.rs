@@ -0,0 +1,71 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for bandersnatch
+
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_application_crypto::bandersnatch::AppPair;
+use sp_core::{
+	crypto::{ByteArray, Pair},
+	testing::BANDERSNATCH,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_session::SessionKeys;
+use std::sync::Arc;
+use substrate_test_runtime_client::{
+	runtime::TestAPI, DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
+};
+
+#[test]
+fn bandersnatch_works_in_runtime() {
+	let keystore = Arc::new(MemoryKeystore::new());
+	let test_client = TestClientBuilder::new().build();
+
+	let mut runtime_api = test_client.runtime_api();
+	runtime_api.register_extension(KeystoreExt::new(keystore.clone()));
+
+	let (signature, public) = runtime_api
+		.test_bandersnatch_crypto(test_client.chain_info().genesis_hash)
+		.expect("Tests `bandersnatch` crypto.");
+
+	let supported_keys = keystore.keys(BANDERSNATCH).unwrap();
+	assert!(supported_keys.contains(&public.to_raw_vec()));
+	assert!(AppPair::verify(&signature, "bandersnatch", &public));
+}
+
+#[test]
+fn bandersnatch_session_keys_are_generated_in_keystore() {
+	let keystore = Arc::new(MemoryKeystore::new());
+	let test_client = TestClientBuilder::new().build();
+	let genesis_hash = test_client.chain_info().genesis_hash;
+
+	let mut runtime_api = test_client.runtime_api();
+	runtime_api.register_extension(KeystoreExt::new(keystore.clone()));
+
+	let session_keys = runtime_api
+		.generate_session_keys(genesis_hash, None)
+		.expect("Generates the session keys.");
+	let keys = runtime_api
+		.decode_session_keys(genesis_hash, session_keys)
+		.expect("Decodes the session keys.")
+		.expect("Session keys are valid.");
+
+	let bandersnatch = keys
+		.into_iter()
+		.find(|(_, key_type)| *key_type == BANDERSNATCH)
+		.expect("Session keys contain a `bandersnatch` key.");
+	assert!(keystore.has_keys(&[bandersnatch]));
+}
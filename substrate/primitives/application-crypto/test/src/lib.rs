@@ -17,6 +17,8 @@
 
 //! Integration tests for application crypto
 
+#[cfg(all(test, feature = "bandersnatch-experimental"))]
+mod bandersnatch;
 #[cfg(test)]
 mod ecdsa;
 #[cfg(test)]
@@ -223,6 +223,34 @@ impl<N: Codec> ConsensusLog<N> {
 	}
 }
 
+/// The state of the current GRANDPA authority set as tracked by the runtime.
+///
+/// Transitions always happen in the order the variants are declared, e.g. an
+/// authority set can only become `Paused` after having been `PendingPause`.
+#[derive(Clone, Decode, Encode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AuthoritySetState<N> {
+	/// The current authority set is live, and GRANDPA is enabled.
+	Live,
+	/// A pause has been scheduled and will be enacted `delay` blocks after
+	/// `scheduled_at`.
+	PendingPause {
+		/// Block at which the intention to pause was scheduled.
+		scheduled_at: N,
+		/// Number of blocks after which the pause will be enacted.
+		delay: N,
+	},
+	/// The current authority set is paused and is not finalizing blocks.
+	Paused,
+	/// A resume has been scheduled and will be enacted `delay` blocks after
+	/// `scheduled_at`.
+	PendingResume {
+		/// Block at which the intention to resume was scheduled.
+		scheduled_at: N,
+		/// Number of blocks after which the resume will be enacted.
+		delay: N,
+	},
+}
+
 /// Proof of voter misbehavior on a given set id. Misbehavior/equivocation in
 /// GRANDPA happens when a voter votes on the same round (either at prevote or
 /// precommit stage) for different blocks. Proving is achieved by collecting the
@@ -531,5 +559,9 @@ sp_api::decl_runtime_apis! {
 
 		/// Get current GRANDPA authority set id.
 		fn current_set_id() -> SetId;
+
+		/// Get the pause state of the current GRANDPA authority set.
+		#[api_version(4)]
+		fn authority_set_state() -> AuthoritySetState<NumberFor<Block>>;
 	}
 }
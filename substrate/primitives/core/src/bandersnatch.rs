@@ -610,6 +610,11 @@ pub mod ring_vrf {
 	use super::{vrf::*, *};
 	pub use bandersnatch_vrfs::ring::{RingProof, RingProver, RingVerifier, KZG};
 	use bandersnatch_vrfs::{CanonicalDeserialize, PublicKey};
+	use sp_runtime_interface::pass_by::PassByCodec;
+
+	/// Transcript label of the data signed with [`Pair::ring_vrf_sign`] when the signature is
+	/// meant to be verified by the runtime via the `bandersnatch_ring_vrf_verify` host function.
+	pub const RING_SIGNING_CTX: &[u8] = b"BandersnatchRingSigningContext";
 
 	/// Context used to produce ring signatures.
 	#[derive(Clone)]
@@ -689,7 +694,7 @@ pub mod ring_vrf {
 	}
 
 	/// Ring VRF signature.
-	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, PassByCodec)]
 	pub struct RingVrfSignature {
 		/// VRF (pre)outputs.
 		pub outputs: VrfIosVec<VrfOutput>,
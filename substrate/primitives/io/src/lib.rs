@@ -772,6 +772,48 @@ impl Default for UseDalekExt {
 	}
 }
 
+/// Number of decoded ring contexts kept by [`decode_ring_context`].
+#[cfg(all(feature = "std", feature = "bandersnatch-experimental"))]
+const RING_CONTEXT_CACHE_SIZE: usize = 4;
+
+/// Decode a SCALE encoded `bandersnatch` ring context.
+///
+/// Decoding a ring context deserializes all of its KZG points, which is far more expensive than
+/// hashing its encoding. So the last [`RING_CONTEXT_CACHE_SIZE`] decoded contexts are kept on the
+/// host, keyed by the hash of their encoding, and shared by all the executions.
+#[cfg(all(feature = "std", feature = "bandersnatch-experimental"))]
+fn decode_ring_context(
+	encoded: &[u8],
+) -> Option<std::sync::Arc<bandersnatch::ring_vrf::RingContext>> {
+	use codec::DecodeAll;
+	use std::sync::{Arc, Mutex, OnceLock};
+
+	type Cache = Vec<([u8; 32], Arc<bandersnatch::ring_vrf::RingContext>)>;
+	static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+	let hash = sp_core::hashing::blake2_256(encoded);
+	let mut cache = CACHE
+		.get_or_init(Default::default)
+		.lock()
+		.unwrap_or_else(|poisoned| poisoned.into_inner());
+
+	// Most recently used entries are kept at the end.
+	if let Some(pos) = cache.iter().position(|(cached, _)| *cached == hash) {
+		let entry = cache.remove(pos);
+		let ring_context = entry.1.clone();
+		cache.push(entry);
+		return Some(ring_context)
+	}
+
+	let ring_context =
+		Arc::new(bandersnatch::ring_vrf::RingContext::decode_all(&mut &encoded[..]).ok()?);
+	if cache.len() >= RING_CONTEXT_CACHE_SIZE {
+		cache.remove(0);
+	}
+	cache.push((hash, ring_context.clone()));
+	Some(ring_context)
+}
+
 /// Interfaces for working with crypto related types from within the runtime.
 #[runtime_interface]
 pub trait Crypto {
@@ -1244,6 +1286,80 @@ pub trait Crypto {
 			.bandersnatch_generate_new(id, seed)
 			.expect("`bandernatch_generate` failed")
 	}
+
+	/// Returns all `bandersnatch` public keys for the given key id from the keystore.
+	#[cfg(feature = "bandersnatch-experimental")]
+	fn bandersnatch_public_keys(&mut self, id: KeyTypeId) -> Vec<bandersnatch::Public> {
+		self.extension::<KeystoreExt>()
+			.expect("No `keystore` associated for the current context!")
+			.bandersnatch_public_keys(id)
+	}
+
+	/// Sign the given `msg` with the `bandersnatch` key that corresponds to the given public key
+	/// and key type in the keystore.
+	///
+	/// Returns the signature.
+	#[cfg(feature = "bandersnatch-experimental")]
+	fn bandersnatch_sign(
+		&mut self,
+		id: KeyTypeId,
+		pub_key: &bandersnatch::Public,
+		msg: &[u8],
+	) -> Option<bandersnatch::Signature> {
+		self.extension::<KeystoreExt>()
+			.expect("No `keystore` associated for the current context!")
+			.bandersnatch_sign(id, pub_key, msg)
+			.ok()
+			.flatten()
+	}
+
+	/// Verify a `bandersnatch` signature.
+	///
+	/// Returns `true` when the verification was successful.
+	#[cfg(feature = "bandersnatch-experimental")]
+	fn bandersnatch_verify(
+		sig: &bandersnatch::Signature,
+		msg: &[u8],
+		pub_key: &bandersnatch::Public,
+	) -> bool {
+		bandersnatch::Pair::verify(sig, msg, pub_key)
+	}
+
+	/// Verify a `bandersnatch` ring-VRF signature.
+	///
+	/// The signed data is expected to be built with
+	/// [`bandersnatch::ring_vrf::RING_SIGNING_CTX`] as transcript label, `msg` as the only
+	/// transcript data and one VRF input for each `(domain, data)` pair of `inputs`.
+	///
+	/// `ring_context` is the SCALE encoded [`bandersnatch::ring_vrf::RingContext`]. It is passed
+	/// as raw bytes, so the runtime doesn't need to decode it, and the host only decodes it when
+	/// it isn't cached already. Building the ring verifier from the context and the `ring` public
+	/// keys is the most expensive part of the verification, which is why it is done on the host.
+	///
+	/// Returns `true` when the signature was produced by a member of the `ring`.
+	#[cfg(feature = "bandersnatch-experimental")]
+	fn bandersnatch_ring_vrf_verify(
+		signature: &bandersnatch::ring_vrf::RingVrfSignature,
+		msg: &[u8],
+		inputs: Vec<(Vec<u8>, Vec<u8>)>,
+		ring_context: &[u8],
+		ring: &[bandersnatch::Public],
+	) -> bool {
+		let inputs = inputs
+			.iter()
+			.map(|(domain, data)| bandersnatch::vrf::VrfInput::new(domain, data));
+		let Ok(data) = bandersnatch::vrf::VrfSignData::new(
+			bandersnatch::ring_vrf::RING_SIGNING_CTX,
+			[msg],
+			inputs,
+		) else {
+			return false
+		};
+		let Some(ring_context) = decode_ring_context(ring_context) else { return false };
+		let Some(verifier) = ring_context.verifier(ring) else { return false };
+
+		signature.ring_vrf_verify(&data, &verifier)
+	}
 }
 
 /// Interface that provides functions for hashing with different algorithms.
@@ -1954,4 +2070,67 @@ mod tests {
 			));
 		});
 	}
+
+	#[cfg(feature = "bandersnatch-experimental")]
+	#[test]
+	fn bandersnatch_ring_vrf_verify_works() {
+		use sp_core::bandersnatch::{ring_vrf::*, vrf::*};
+
+		let ring_context = RingContext::new_testing();
+		let pairs: Vec<_> = (0..4u8).map(|i| bandersnatch::Pair::from_seed(&[i; 32])).collect();
+		let ring: Vec<_> = pairs.iter().map(|p| p.public()).collect();
+
+		let msg = b"ticket".as_slice();
+		let inputs = vec![(b"domain".to_vec(), b"data".to_vec())];
+		let data = VrfSignData::new(
+			RING_SIGNING_CTX,
+			[msg],
+			inputs.iter().map(|(domain, data)| VrfInput::new(domain, data)),
+		)
+		.unwrap();
+
+		let prover = ring_context.prover(&ring, 2).unwrap();
+		let signature = pairs[2].ring_vrf_sign(&data, &prover);
+		let ring_context = ring_context.encode();
+
+		BasicExternalities::default().execute_with(|| {
+			assert!(crypto::bandersnatch_ring_vrf_verify(
+				&signature,
+				msg,
+				inputs.clone(),
+				&ring_context,
+				&ring,
+			));
+			// A different message doesn't verify.
+			assert!(!crypto::bandersnatch_ring_vrf_verify(
+				&signature,
+				b"other",
+				inputs.clone(),
+				&ring_context,
+				&ring,
+			));
+			// Neither does a ring the signer is not part of.
+			assert!(!crypto::bandersnatch_ring_vrf_verify(
+				&signature,
+				msg,
+				inputs.clone(),
+				&ring_context,
+				&ring[..2],
+			));
+			// Nor does a context that can't be decoded.
+			assert!(!crypto::bandersnatch_ring_vrf_verify(
+				&signature,
+				msg,
+				inputs,
+				&ring_context[1..],
+				&ring,
+			));
+		});
+
+		// The context was decoded once and then reused.
+		assert!(std::sync::Arc::ptr_eq(
+			&decode_ring_context(&ring_context).unwrap(),
+			&decode_ring_context(&ring_context).unwrap(),
+		));
+	}
 }
@@ -80,6 +80,139 @@ pub fn decompress(blob: &[u8], bomb_limit: usize) -> Result<Cow<[u8]>, Error> {
 	}
 }
 
+/// Incremental counterpart of [`decompress`], for blobs which are received in parts.
+///
+/// Parts are decompressed as they are pushed, so that the work overlaps with receiving the rest of
+/// the blob and a possible bomb is refused without waiting for the blob in full.
+pub struct StreamingDecompressor {
+	state: StreamingState,
+	bomb_limit: usize,
+	keep_output: bool,
+}
+
+enum StreamingState {
+	/// Not enough of the blob was pushed to tell whether it is compressed.
+	Prefix(Vec<u8>),
+	/// The blob is compressed.
+	Compressed(zstd::stream::write::Decoder<'static, BoundedBuffer>),
+	/// The blob is not compressed.
+	Plain(Vec<u8>),
+}
+
+/// Decompressed output of a [`StreamingDecompressor`], refusing to grow over the bomb limit.
+struct BoundedBuffer {
+	buf: Vec<u8>,
+	/// Whether to keep the output in `buf`, or only count it in `len`.
+	keep: bool,
+	len: usize,
+	limit: usize,
+	exceeded: bool,
+}
+
+impl Write for BoundedBuffer {
+	fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+		if self.len + data.len() > self.limit {
+			self.exceeded = true;
+			return Err(std::io::ErrorKind::Other.into())
+		}
+
+		self.len += data.len();
+		if self.keep {
+			self.buf.extend_from_slice(data);
+		}
+		Ok(data.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+impl StreamingDecompressor {
+	/// Create a new decompressor, which refuses to decompress more than `bomb_limit` bytes.
+	pub fn new(bomb_limit: usize) -> Self {
+		Self {
+			state: StreamingState::Prefix(Vec::with_capacity(ZSTD_PREFIX.len())),
+			bomb_limit,
+			keep_output: true,
+		}
+	}
+
+	/// Create a new decompressor which only checks that the blob can be decompressed without
+	/// exceeding `bomb_limit`.
+	///
+	/// The decompressed output is not kept, so [`Self::finish`] returns an empty blob. This is
+	/// meant for receivers which have to pass the blob on in its original form, but want to
+	/// refuse an invalid blob or a bomb before receiving it in full.
+	pub fn validating(bomb_limit: usize) -> Self {
+		Self { keep_output: false, ..Self::new(bomb_limit) }
+	}
+
+	/// Push the next part of the blob.
+	pub fn push(&mut self, mut data: &[u8]) -> Result<(), Error> {
+		if let StreamingState::Prefix(prefix) = &mut self.state {
+			let (head, tail) = data.split_at(data.len().min(ZSTD_PREFIX.len() - prefix.len()));
+			prefix.extend_from_slice(head);
+			data = tail;
+
+			if prefix.len() < ZSTD_PREFIX.len() {
+				return Ok(())
+			}
+
+			let prefix = std::mem::take(prefix);
+			self.state = if prefix[..] == ZSTD_PREFIX[..] {
+				let output = BoundedBuffer {
+					buf: Vec::new(),
+					keep: self.keep_output,
+					len: 0,
+					limit: self.bomb_limit,
+					exceeded: false,
+				};
+				zstd::stream::write::Decoder::new(output)
+					.map(StreamingState::Compressed)
+					.map_err(|_| Error::Invalid)?
+			} else {
+				StreamingState::Plain(if self.keep_output { prefix } else { Vec::new() })
+			};
+		}
+
+		match &mut self.state {
+			StreamingState::Prefix(_) => Ok(()),
+			StreamingState::Compressed(decoder) => {
+				if decoder.write_all(data).and_then(|_| decoder.flush()).is_err() {
+					return Err(if decoder.get_ref().exceeded {
+						Error::PossibleBomb
+					} else {
+						Error::Invalid
+					})
+				}
+
+				Ok(())
+			},
+			StreamingState::Plain(blob) => {
+				if self.keep_output {
+					blob.extend_from_slice(data);
+				}
+				Ok(())
+			},
+		}
+	}
+
+	/// Finish decompressing, returning the decompressed blob.
+	///
+	/// Unlike [`decompress`], this doesn't detect a compressed blob being truncated.
+	pub fn finish(self) -> Vec<u8> {
+		if !self.keep_output {
+			return Vec::new()
+		}
+
+		match self.state {
+			StreamingState::Prefix(blob) | StreamingState::Plain(blob) => blob,
+			StreamingState::Compressed(decoder) => decoder.into_inner().buf,
+		}
+	}
+}
+
 /// Encode a blob as compressed. If the blob's size is over the bomb limit,
 /// this will not compress the blob, as the decoder will not be able to be
 /// able to differentiate it from a compression bomb.
@@ -142,4 +275,52 @@ mod tests {
 
 		assert_eq!(decompress(&buf[..], BOMB_LIMIT).err(), Some(Error::PossibleBomb));
 	}
+
+	#[test]
+	fn streaming_decompress_matches_decompress() {
+		let v = (0..BOMB_LIMIT as u8).collect::<Vec<_>>();
+		let compressed = compress(&v, BOMB_LIMIT).unwrap();
+
+		for blob in [&compressed[..], &v[..], &v[..3]] {
+			let mut decompressor = StreamingDecompressor::new(BOMB_LIMIT);
+			for part in blob.chunks(3) {
+				decompressor.push(part).unwrap();
+			}
+
+			assert_eq!(&decompressor.finish()[..], &decompress(blob, BOMB_LIMIT).unwrap()[..]);
+		}
+	}
+
+	#[test]
+	fn streaming_possible_bomb_fails() {
+		let mut buf = ZSTD_PREFIX.to_vec();
+
+		{
+			let mut v = zstd::Encoder::new(&mut buf, 3).unwrap().auto_finish();
+			v.write_all(&vec![0; BOMB_LIMIT + 1][..]).unwrap();
+		}
+
+		let mut decompressor = StreamingDecompressor::new(BOMB_LIMIT);
+		let result = buf.chunks(3).try_for_each(|part| decompressor.push(part));
+		assert_eq!(result, Err(Error::PossibleBomb));
+
+		let mut decompressor = StreamingDecompressor::validating(BOMB_LIMIT);
+		let result = buf.chunks(3).try_for_each(|part| decompressor.push(part));
+		assert_eq!(result, Err(Error::PossibleBomb));
+	}
+
+	#[test]
+	fn streaming_validation_does_not_keep_output() {
+		let v = (0..BOMB_LIMIT as u8).collect::<Vec<_>>();
+		let compressed = compress(&v, BOMB_LIMIT).unwrap();
+
+		for blob in [&compressed[..], &v[..]] {
+			let mut decompressor = StreamingDecompressor::validating(BOMB_LIMIT);
+			for part in blob.chunks(3) {
+				decompressor.push(part).unwrap();
+			}
+
+			assert!(decompressor.finish().is_empty());
+		}
+	}
 }
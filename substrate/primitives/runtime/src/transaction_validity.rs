@@ -82,6 +82,13 @@ pub enum InvalidTransaction {
 	MandatoryValidation,
 	/// The sending address is disabled or known to be invalid.
 	BadSigner,
+	/// The era of the transaction is longer than the runtime allows.
+	///
+	/// # Possible causes
+	///
+	/// For `FRAME`-based runtimes this would be caused by an immortal transaction or a mortal
+	/// one with a period above the `MaxPeriod` of `CheckMortality`.
+	EraTooLong,
 }
 
 impl InvalidTransaction {
@@ -113,6 +120,7 @@ impl From<InvalidTransaction> for &'static str {
 				"Transaction dispatch is mandatory; transactions must not be validated.",
 			InvalidTransaction::Custom(_) => "InvalidTransaction custom error",
 			InvalidTransaction::BadSigner => "Invalid signing address",
+			InvalidTransaction::EraTooLong => "Transaction era is longer than allowed",
 		}
 	}
 }
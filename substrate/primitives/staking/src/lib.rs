@@ -25,7 +25,7 @@ use codec::{Decode, Encode, FullCodec, HasCompact, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Zero},
-	DispatchError, DispatchResult, RuntimeDebug, Saturating,
+	DispatchError, DispatchResult, Perbill, RuntimeDebug, Saturating,
 };
 use sp_std::{collections::btree_map::BTreeMap, ops::Sub, vec, vec::Vec};
 
@@ -298,6 +298,212 @@ pub trait StakingInterface {
 	fn set_current_era(era: EraIndex);
 }
 
+/// Low level extension of [`StakingInterface`] for stakers whose funds are not held by the staking
+/// system itself.
+///
+/// These bypass the balance checks of the staking system, and must only be used by a system that
+/// keeps the bonded funds of the staker locked elsewhere, e.g. a delegation system holding the
+/// funds of delegators in their own accounts.
+pub trait StakingUnchecked: StakingInterface {
+	/// Bond `value` for `keyless_who` without locking any of its balance, hence virtually, while
+	/// forwarding any rewards to `payee`.
+	///
+	/// `keyless_who` must be an account nobody holds the key of, so that it can't interact with
+	/// the staking system directly. The caller is responsible for keeping `value` locked and for
+	/// applying any slash of the staker to the funds backing it. `payee` must not be
+	/// `keyless_who`, since rewards can't be restaked without backing funds.
+	fn virtual_bond(
+		keyless_who: &Self::AccountId,
+		value: Self::Balance,
+		payee: &Self::AccountId,
+	) -> DispatchResult;
+
+	/// Whether `who` is a virtual staker, bonded through [`Self::virtual_bond`].
+	fn is_virtual_staker(who: &Self::AccountId) -> bool;
+
+	/// Turn `who`, a staker bonding its own funds, into a virtual staker forwarding any rewards
+	/// to `payee`.
+	///
+	/// The staking lock of `who` is removed, so the caller is responsible for keeping the funds
+	/// backing its stake locked from then on. The same restrictions as for [`Self::virtual_bond`]
+	/// apply to `payee`.
+	fn migrate_to_virtual_staker(who: &Self::AccountId, payee: &Self::AccountId) -> DispatchResult;
+
+	/// The fraction of a slash paid out to whoever reports, or applies, it.
+	///
+	/// The system applying the slashes of virtual stakers uses it to reward the reporters itself.
+	fn slash_reward_fraction() -> Perbill;
+}
+
+/// Interface of a system holding funds delegated to agents which stake them.
+///
+/// Delegated funds stay in the account of the delegator, held on behalf of the agent. The agent
+/// is a virtual staker (see [`StakingUnchecked`]) whose stake is backed by all its delegations.
+pub trait DelegationInterface {
+	/// Balance type used by the delegation system.
+	type Balance: Sub<Output = Self::Balance>
+		+ Ord
+		+ PartialEq
+		+ Default
+		+ Copy
+		+ MaxEncodedLen
+		+ FullCodec
+		+ TypeInfo
+		+ Saturating;
+
+	/// AccountId type used by the delegation system.
+	type AccountId: Clone + sp_std::fmt::Debug;
+
+	/// The total amount delegated to `agent`, net of any slash not yet applied to its delegators.
+	fn agent_balance(agent: &Self::AccountId) -> Self::Balance;
+
+	/// The amount `delegator` has delegated.
+	fn delegator_balance(delegator: &Self::AccountId) -> Self::Balance;
+
+	/// Delegate `amount` of the funds of `delegator` to `agent` and stake it.
+	fn delegate(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Release `amount` of the funds `delegator` delegated to `agent`.
+	///
+	/// The funds must have been unbonded by `agent` before. `num_slashing_spans` is used to
+	/// withdraw the unbonded funds of `agent` from the staking system.
+	fn withdraw_delegation(
+		delegator: &Self::AccountId,
+		agent: &Self::AccountId,
+		amount: Self::Balance,
+		num_slashing_spans: u32,
+	) -> DispatchResult;
+
+	/// Whether `agent` has been slashed by an amount not yet applied to its delegators.
+	fn has_pending_slash(agent: &Self::AccountId) -> bool;
+
+	/// Apply up to `value` of the pending slash of `agent` to the funds of `delegator`.
+	///
+	/// If given, `maybe_reporter` is rewarded with a share of the slashed funds.
+	fn delegator_slash(
+		agent: &Self::AccountId,
+		delegator: &Self::AccountId,
+		value: Self::Balance,
+		maybe_reporter: Option<Self::AccountId>,
+	) -> DispatchResult;
+}
+
+/// Extension of [`DelegationInterface`] to migrate existing stakers to agents.
+///
+/// A staker bonding its own funds is migrated in two steps. First, it is turned into an agent
+/// with [`Self::migrate_nominator_to_agent`], after which all of its stake is delegated by a proxy
+/// delegator. This delegation is then split among the actual delegators, at their own pace, with
+/// [`Self::migrate_delegation`].
+pub trait DelegationMigrator: DelegationInterface {
+	/// Turn `agent`, a staker bonding its own funds, into an agent forwarding its staking rewards
+	/// to `payee`.
+	///
+	/// The total stake of `agent` is moved to, and delegated by, its proxy delegator.
+	fn migrate_nominator_to_agent(
+		agent: &Self::AccountId,
+		payee: &Self::AccountId,
+	) -> DispatchResult;
+
+	/// Move `amount` of the delegation of the proxy delegator of `agent` to `delegator`.
+	///
+	/// The funds are transferred to `delegator` and held there, as if it had delegated them.
+	fn migrate_delegation(
+		agent: &Self::AccountId,
+		delegator: &Self::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// The amount of the funds of `agent` still delegated by its proxy delegator.
+	fn pending_migration_balance(agent: &Self::AccountId) -> Self::Balance;
+
+	/// Apply up to `value` of the pending slash of `agent` to the funds still delegated by its
+	/// proxy delegator.
+	fn slash_pending_migration(agent: &Self::AccountId, value: Self::Balance) -> DispatchResult;
+}
+
+/// A [`DelegationMigrator`] for systems without delegation support, failing all operations.
+pub struct NoDelegation<AccountId, Balance>(sp_std::marker::PhantomData<(AccountId, Balance)>);
+
+impl<AccountId, Balance> DelegationInterface for NoDelegation<AccountId, Balance>
+where
+	AccountId: Clone + sp_std::fmt::Debug,
+	Balance: Sub<Output = Balance>
+		+ Ord
+		+ PartialEq
+		+ Default
+		+ Copy
+		+ MaxEncodedLen
+		+ FullCodec
+		+ TypeInfo
+		+ Saturating,
+{
+	type Balance = Balance;
+	type AccountId = AccountId;
+
+	fn agent_balance(_: &Self::AccountId) -> Self::Balance {
+		Default::default()
+	}
+
+	fn delegator_balance(_: &Self::AccountId) -> Self::Balance {
+		Default::default()
+	}
+
+	fn delegate(_: &Self::AccountId, _: &Self::AccountId, _: Self::Balance) -> DispatchResult {
+		Err(DispatchError::Other("delegation is not supported"))
+	}
+
+	fn withdraw_delegation(
+		_: &Self::AccountId,
+		_: &Self::AccountId,
+		_: Self::Balance,
+		_: u32,
+	) -> DispatchResult {
+		Err(DispatchError::Other("delegation is not supported"))
+	}
+
+	fn has_pending_slash(_: &Self::AccountId) -> bool {
+		false
+	}
+
+	fn delegator_slash(
+		_: &Self::AccountId,
+		_: &Self::AccountId,
+		_: Self::Balance,
+		_: Option<Self::AccountId>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("delegation is not supported"))
+	}
+}
+
+impl<AccountId, Balance> DelegationMigrator for NoDelegation<AccountId, Balance>
+where
+	Self: DelegationInterface,
+{
+	fn migrate_nominator_to_agent(_: &Self::AccountId, _: &Self::AccountId) -> DispatchResult {
+		Err(DispatchError::Other("delegation is not supported"))
+	}
+
+	fn migrate_delegation(
+		_: &Self::AccountId,
+		_: &Self::AccountId,
+		_: Self::Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("delegation is not supported"))
+	}
+
+	fn pending_migration_balance(_: &Self::AccountId) -> Self::Balance {
+		Default::default()
+	}
+
+	fn slash_pending_migration(_: &Self::AccountId, _: Self::Balance) -> DispatchResult {
+		Err(DispatchError::Other("delegation is not supported"))
+	}
+}
+
 /// The amount of exposure for an era that an individual nominator has (susceptible to slashing).
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct IndividualExposure<AccountId, Balance: HasCompact> {
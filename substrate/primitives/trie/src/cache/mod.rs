@@ -52,12 +52,16 @@ use trie_db::{node::NodeOwned, CachedValue};
 
 mod shared_cache;
 
-pub use shared_cache::SharedTrieCache;
+pub use shared_cache::{SharedTrieCache, SharedTrieCacheStats, TrieCacheConsumerStats};
 
 use self::shared_cache::ValueCacheKeyHash;
 
 const LOG_TARGET: &str = "trie-cache";
 
+/// The consumer to which the usage of a [`LocalTrieCache`] created by
+/// [`SharedTrieCache::local_cache`] is accounted.
+pub const DEFAULT_CONSUMER: &str = "default";
+
 /// The maximum amount of time we'll wait trying to acquire the shared cache lock
 /// when the local cache is dropped and synchronized with the share cache.
 ///
@@ -333,6 +337,9 @@ pub struct LocalTrieCache<H: Hasher> {
 	/// The shared trie cache that created this instance.
 	shared: SharedTrieCache<H>,
 
+	/// The consumer to which the usage of the shared cache is accounted.
+	consumer: &'static str,
+
 	/// The local cache for the trie nodes.
 	node_cache: Mutex<NodeCacheMap<H::Out>>,
 
@@ -414,12 +421,24 @@ impl<H: Hasher> Drop for LocalTrieCache<H> {
 			},
 		};
 
-		shared_inner.node_cache_mut().update(self.node_cache.get_mut().drain());
+		let nodes_added = shared_inner.node_cache_mut().update(self.node_cache.get_mut().drain());
 
-		shared_inner.value_cache_mut().update(
+		let values_added = shared_inner.value_cache_mut().update(
 			self.value_cache.get_mut().drain(),
 			self.shared_value_cache_access.get_mut().drain().map(|(key, ())| key),
 		);
+
+		let consumer_stats = shared_inner.consumer_stats_mut(self.consumer);
+		let node_stats = &self.stats.node_cache;
+		let value_stats = &self.stats.value_cache;
+		consumer_stats.node_hits += node_stats.shared_hits.load(Ordering::Relaxed);
+		consumer_stats.node_fetch_attempts +=
+			node_stats.shared_fetch_attempts.load(Ordering::Relaxed);
+		consumer_stats.value_hits += value_stats.shared_hits.load(Ordering::Relaxed);
+		consumer_stats.value_fetch_attempts +=
+			value_stats.shared_fetch_attempts.load(Ordering::Relaxed);
+		consumer_stats.nodes_added += nodes_added as u64;
+		consumer_stats.values_added += values_added as u64;
 	}
 }
 
@@ -981,4 +1000,60 @@ mod tests {
 
 		assert!(shared_cache.used_memory_size() < CACHE_SIZE_RAW);
 	}
+
+	#[test]
+	fn cache_stats_are_accounted_per_consumer() {
+		let (mut db, root) = create_trie();
+
+		let shared_cache = Cache::new(CACHE_SIZE);
+		let read_data = |consumer| {
+			let local_cache = shared_cache.local_cache_for(consumer);
+			let mut cache = local_cache.as_trie_db_cache(root);
+			let trie = TrieDBBuilder::<Layout>::new(&db, &root).with_cache(&mut cache).build();
+			for (key, value) in TEST_DATA {
+				assert_eq!(value.to_vec(), trie.get(key).unwrap().unwrap());
+			}
+		};
+
+		// The first consumer fills the cache, the second one profits from it.
+		read_data("parachain");
+		read_data("relay-chain");
+
+		let stats = shared_cache.stats();
+		assert_eq!(stats.used_memory_size, shared_cache.used_memory_size());
+		assert_eq!(stats.node_evictions, 0);
+		assert_eq!(stats.value_evictions, 0);
+		let [(parachain, parachain_stats), (relay_chain, relay_chain_stats)] = &stats.consumers[..]
+		else {
+			panic!("Expected two consumers, got {:?}", stats.consumers)
+		};
+		assert_eq!((*parachain, *relay_chain), ("parachain", "relay-chain"));
+		assert_eq!(parachain_stats.value_hits, 0);
+		assert!(parachain_stats.nodes_added > 0);
+		assert_eq!(parachain_stats.values_added, TEST_DATA.len() as u64);
+		assert_eq!(relay_chain_stats.value_hits, TEST_DATA.len() as u64);
+		assert_eq!(relay_chain_stats.values_added, 0);
+
+		// Overflowing the cache evicts nodes.
+		{
+			let local_cache = shared_cache.local_cache();
+			let mut new_root = root;
+			let mut cache = local_cache.as_trie_db_cache(root);
+			{
+				let mut trie = TrieDBMutBuilder::<Layout>::from_existing(&mut db, &mut new_root)
+					.with_cache(&mut cache)
+					.build();
+
+				let value = vec![10u8; 100];
+				for i in 0..CACHE_SIZE_RAW / 100 * 2 {
+					trie.insert(format!("key{}", i).as_bytes(), &value).unwrap();
+				}
+			}
+			cache.merge_into(&local_cache, new_root);
+		}
+
+		let stats = shared_cache.stats();
+		assert!(stats.node_evictions > 0);
+		assert_eq!(stats.consumers[0].0, DEFAULT_CONSUMER);
+	}
 }
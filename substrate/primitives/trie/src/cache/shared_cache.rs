@@ -24,6 +24,7 @@ use nohash_hasher::BuildNoHashHasher;
 use parking_lot::{Mutex, RwLock, RwLockWriteGuard};
 use schnellru::LruMap;
 use std::{
+	collections::BTreeMap,
 	hash::{BuildHasher, Hasher as _},
 	sync::Arc,
 };
@@ -60,6 +61,9 @@ pub struct SharedNodeCacheLimiter {
 	///
 	/// Reset on every update.
 	max_items_evicted: usize,
+
+	/// The total number of elements that got evicted from the cache.
+	total_items_evicted: u64,
 }
 
 impl<H> schnellru::Limiter<H, NodeOwned<H>> for SharedNodeCacheLimiter
@@ -125,6 +129,7 @@ where
 	fn on_removed(&mut self, _: &mut H, node: &mut NodeOwned<H>) {
 		self.heap_size -= node.size_in_bytes() - std::mem::size_of::<NodeOwned<H>>();
 		self.items_evicted += 1;
+		self.total_items_evicted += 1;
 	}
 
 	#[inline]
@@ -159,6 +164,9 @@ pub struct SharedValueCacheLimiter {
 	///
 	/// Reset on every update.
 	max_items_evicted: usize,
+
+	/// The total number of elements that got evicted from the cache.
+	total_items_evicted: u64,
 }
 
 impl<H> schnellru::Limiter<ValueCacheKey<H>, CachedValue<H>> for SharedValueCacheLimiter
@@ -224,6 +232,7 @@ where
 			self.known_storage_keys.remove(&key.storage_key);
 		}
 		self.items_evicted += 1;
+		self.total_items_evicted += 1;
 	}
 
 	#[inline]
@@ -263,12 +272,15 @@ impl<H: AsRef<[u8]> + Eq + std::hash::Hash> SharedNodeCache<H> {
 				heap_size: 0,
 				items_evicted: 0,
 				max_items_evicted: 0, // Will be set during `update`.
+				total_items_evicted: 0,
 			}),
 		}
 	}
 
 	/// Update the cache with the `list` of nodes which were either newly added or accessed.
-	pub fn update(&mut self, list: impl IntoIterator<Item = (H, NodeCached<H>)>) {
+	///
+	/// Returns the number of nodes added to the cache.
+	pub fn update(&mut self, list: impl IntoIterator<Item = (H, NodeCached<H>)>) -> usize {
 		let mut access_count = 0;
 		let mut add_count = 0;
 
@@ -312,6 +324,8 @@ impl<H: AsRef<[u8]> + Eq + std::hash::Hash> SharedNodeCache<H> {
 			self.lru.limiter().heap_size,
 			self.lru.limiter().max_heap_size,
 		);
+
+		add_count
 	}
 
 	/// Reset the cache.
@@ -494,6 +508,7 @@ impl<H: Eq + std::hash::Hash + Clone + Copy + AsRef<[u8]>> SharedValueCache<H> {
 					known_storage_keys: Default::default(),
 					items_evicted: 0,
 					max_items_evicted: 0, // Will be set during `update`.
+					total_items_evicted: 0,
 				},
 				Default::default(),
 			),
@@ -507,11 +522,13 @@ impl<H: Eq + std::hash::Hash + Clone + Copy + AsRef<[u8]>> SharedValueCache<H> {
 	/// [`ValueCacheKeyHash`] and represent the values that were retrieved from this shared cache.
 	/// These `accessed` values are being put to the front of the internal [`LruMap`] like the
 	/// `added` ones.
+	///
+	/// Returns the number of values added to the cache.
 	pub fn update(
 		&mut self,
 		added: impl IntoIterator<Item = (ValueCacheKey<H>, CachedValue<H>)>,
 		accessed: impl IntoIterator<Item = ValueCacheKeyHash>,
-	) {
+	) -> usize {
 		let mut access_count = 0;
 		let mut add_count = 0;
 
@@ -559,6 +576,8 @@ impl<H: Eq + std::hash::Hash + Clone + Copy + AsRef<[u8]>> SharedValueCache<H> {
 			self.lru.limiter().heap_size,
 			self.lru.limiter().max_heap_size
 		);
+
+		add_count
 	}
 
 	/// Reset the cache.
@@ -567,13 +586,54 @@ impl<H: Eq + std::hash::Hash + Clone + Copy + AsRef<[u8]>> SharedValueCache<H> {
 	}
 }
 
+/// The statistics of one consumer of a [`SharedTrieCache`].
+///
+/// A consumer is identified by the name given to [`SharedTrieCache::local_cache_for`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrieCacheConsumerStats {
+	/// The number of nodes found in the shared cache.
+	pub node_hits: u64,
+	/// The number of nodes looked up in the shared cache.
+	pub node_fetch_attempts: u64,
+	/// The number of values found in the shared cache.
+	pub value_hits: u64,
+	/// The number of values looked up in the shared cache.
+	pub value_fetch_attempts: u64,
+	/// The number of nodes added to the shared cache.
+	pub nodes_added: u64,
+	/// The number of values added to the shared cache.
+	pub values_added: u64,
+}
+
+/// A snapshot of the statistics of a [`SharedTrieCache`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SharedTrieCacheStats {
+	/// The used memory size of the cache in bytes.
+	pub used_memory_size: usize,
+	/// The total number of nodes evicted from the cache.
+	pub node_evictions: u64,
+	/// The total number of values evicted from the cache.
+	pub value_evictions: u64,
+	/// The statistics of each consumer of the cache, ordered by name.
+	pub consumers: Vec<(&'static str, TrieCacheConsumerStats)>,
+}
+
 /// The inner of [`SharedTrieCache`].
 pub(super) struct SharedTrieCacheInner<H: Hasher> {
 	node_cache: SharedNodeCache<H::Out>,
 	value_cache: SharedValueCache<H::Out>,
+	consumers: BTreeMap<&'static str, TrieCacheConsumerStats>,
 }
 
 impl<H: Hasher> SharedTrieCacheInner<H> {
+	/// Returns a mutable reference to the statistics of `consumer`.
+	pub(super) fn consumer_stats_mut(
+		&mut self,
+		consumer: &'static str,
+	) -> &mut TrieCacheConsumerStats {
+		self.consumers.entry(consumer).or_default()
+	}
+
 	/// Returns a reference to the [`SharedValueCache`].
 	#[cfg(test)]
 	pub(super) fn value_cache(&self) -> &SharedValueCache<H::Out> {
@@ -603,7 +663,10 @@ impl<H: Hasher> SharedTrieCacheInner<H> {
 /// operations to the state. To not use all available memory it will ensure to stay in the
 /// bounds given via the [`CacheSize`] at startup.
 ///
-/// The instance of this object can be shared between multiple threads.
+/// The instance of this object can be shared between multiple threads. It can also be shared
+/// between multiple backends running in the same process, e.g. a parachain node and its embedded
+/// relay chain node, which then compete for the same memory budget. Their usage of the cache is
+/// accounted separately by creating their local caches with [`Self::local_cache_for`].
 pub struct SharedTrieCache<H: Hasher> {
 	inner: Arc<RwLock<SharedTrieCacheInner<H>>>,
 }
@@ -661,14 +724,24 @@ impl<H: Hasher> SharedTrieCache<H> {
 					value_cache_max_inline_size,
 					value_cache_max_heap_size,
 				),
+				consumers: Default::default(),
 			})),
 		}
 	}
 
 	/// Create a new [`LocalTrieCache`](super::LocalTrieCache) instance from this shared cache.
+	///
+	/// Its usage of the shared cache is accounted to [`DEFAULT_CONSUMER`](super::DEFAULT_CONSUMER).
 	pub fn local_cache(&self) -> super::LocalTrieCache<H> {
+		self.local_cache_for(super::DEFAULT_CONSUMER)
+	}
+
+	/// Create a new [`LocalTrieCache`](super::LocalTrieCache) instance from this shared cache,
+	/// accounting its usage of the shared cache to `consumer`.
+	pub fn local_cache_for(&self, consumer: &'static str) -> super::LocalTrieCache<H> {
 		super::LocalTrieCache {
 			shared: self.clone(),
+			consumer,
 			node_cache: Default::default(),
 			value_cache: Default::default(),
 			shared_value_cache_access: Mutex::new(super::ValueAccessSet::with_hasher(
@@ -719,6 +792,19 @@ impl<H: Hasher> SharedTrieCache<H> {
 		node_cache_size + value_cache_size
 	}
 
+	/// Returns a snapshot of the statistics of this cache.
+	pub fn stats(&self) -> SharedTrieCacheStats {
+		let used_memory_size = self.used_memory_size();
+		let inner = self.inner.read();
+
+		SharedTrieCacheStats {
+			used_memory_size,
+			node_evictions: inner.node_cache.lru.limiter().total_items_evicted,
+			value_evictions: inner.value_cache.lru.limiter().total_items_evicted,
+			consumers: inner.consumers.iter().map(|(name, stats)| (*name, *stats)).collect(),
+		}
+	}
+
 	/// Reset the node cache.
 	pub fn reset_node_cache(&self) {
 		self.inner.write().node_cache.reset();
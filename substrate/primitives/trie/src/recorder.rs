@@ -20,7 +20,7 @@
 //! Provides an implementation of the [`TrieRecorder`](trie_db::TrieRecorder) trait. It can be used
 //! to record storage accesses to the state to generate a [`StorageProof`].
 
-use crate::{NodeCodec, StorageProof};
+use crate::{NodeCodec, ProofSizeProvider, StorageProof};
 use codec::Encode;
 use hash_db::Hasher;
 use parking_lot::Mutex;
@@ -231,6 +231,12 @@ impl<H: Hasher> Recorder<H> {
 	}
 }
 
+impl<H: Hasher> ProofSizeProvider for Recorder<H> {
+	fn estimate_encoded_size(&self) -> usize {
+		Recorder::estimate_encoded_size(self)
+	}
+}
+
 /// The [`TrieRecorder`](trie_db::TrieRecorder) implementation.
 struct TrieRecorder<H: Hasher, I> {
 	inner: I,
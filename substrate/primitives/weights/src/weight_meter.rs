@@ -19,7 +19,7 @@
 
 use super::Weight;
 
-use sp_arithmetic::Perbill;
+use sp_arithmetic::{PerThing, Perbill};
 
 /// Meters consumed weight and a hard limit for the maximal consumable weight.
 ///
@@ -106,6 +106,28 @@ impl WeightMeter {
 		time.max(pov)
 	}
 
+	/// The ratio of remaining weight to the limit.
+	///
+	/// This is the complement of [`Self::consumed_ratio`] and therefore the smaller ratio of both
+	/// components.
+	///
+	/// # Example
+	/// ```rust
+	/// use sp_weights::{Weight, WeightMeter};
+	/// use sp_arithmetic::Perbill;
+	///
+	/// let mut meter = WeightMeter::with_limit(Weight::from_parts(10, 20));
+	/// assert_eq!(meter.remaining_ratio(), Perbill::from_percent(100));
+	/// meter.consume(Weight::from_parts(5, 5));
+	/// // The ref-time is the smaller ratio:
+	/// assert_eq!(meter.remaining_ratio(), Perbill::from_percent(50));
+	/// meter.consume(Weight::from_parts(5, 0));
+	/// assert_eq!(meter.remaining_ratio(), Perbill::from_percent(0));
+	/// ```
+	pub fn remaining_ratio(&self) -> Perbill {
+		self.consumed_ratio().left_from_one()
+	}
+
 	/// Consume some weight and defensively fail if it is over the limit. Saturate in any case.
 	#[deprecated(note = "Use `consume` instead. Will be removed after December 2023.")]
 	pub fn defensive_saturating_accrue(&mut self, w: Weight) {
@@ -237,6 +259,22 @@ mod tests {
 		assert_eq!(meter.consumed_ratio(), Perbill::from_percent(100));
 	}
 
+	#[test]
+	fn remaining_ratio_works() {
+		let mut meter = WeightMeter::with_limit(Weight::from_parts(10, 20));
+
+		assert_eq!(meter.remaining_ratio(), Perbill::from_percent(100));
+		assert!(meter.try_consume(Weight::from_parts(0, 5)).is_ok());
+		assert_eq!(meter.remaining_ratio(), Perbill::from_percent(75));
+		assert!(meter.try_consume(Weight::from_parts(4, 0)).is_ok());
+		assert_eq!(meter.remaining_ratio(), Perbill::from_percent(60));
+
+		assert!(meter.try_consume(Weight::from_parts(6, 0)).is_ok());
+		assert_eq!(meter.remaining_ratio(), Perbill::from_percent(0));
+		assert!(meter.try_consume(Weight::from_parts(0, 15)).is_ok());
+		assert_eq!(meter.remaining_ratio(), Perbill::from_percent(0));
+	}
+
 	#[test]
 	fn try_consume_works() {
 		let mut meter = WeightMeter::with_limit(Weight::from_parts(10, 0));
@@ -46,6 +46,8 @@ use sp_std::prelude::*;
 #[cfg(not(feature = "std"))]
 use sp_std::vec;
 
+#[cfg(feature = "bandersnatch-experimental")]
+use sp_application_crypto::bandersnatch;
 use sp_application_crypto::{ecdsa, ed25519, sr25519, RuntimeAppPublic};
 use sp_core::{OpaqueMetadata, RuntimeDebug};
 use sp_trie::{
@@ -209,6 +211,11 @@ decl_runtime_apis! {
 		///
 		/// Returns the signature generated for the message `ecdsa`.
 		fn test_ecdsa_crypto() -> (ecdsa::AppSignature, ecdsa::AppPublic);
+		/// Test that `bandersnatch` crypto works in the runtime.
+		///
+		/// Returns the signature generated for the message `bandersnatch`.
+		#[cfg(feature = "bandersnatch-experimental")]
+		fn test_bandersnatch_crypto() -> (bandersnatch::AppSignature, bandersnatch::AppPublic);
 		/// Run various tests against storage.
 		fn test_storage();
 		/// Check a witness.
@@ -366,6 +373,7 @@ impl frame_system::pallet::Config for Runtime {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxEventsPerPallet = ();
 }
 
 pub mod currency {
@@ -456,11 +464,22 @@ fn code_using_trie() -> u64 {
 	res
 }
 
+#[cfg(not(feature = "bandersnatch-experimental"))]
+impl_opaque_keys! {
+	pub struct SessionKeys {
+		pub ed25519: ed25519::AppPublic,
+		pub sr25519: sr25519::AppPublic,
+		pub ecdsa: ecdsa::AppPublic,
+	}
+}
+
+#[cfg(feature = "bandersnatch-experimental")]
 impl_opaque_keys! {
 	pub struct SessionKeys {
 		pub ed25519: ed25519::AppPublic,
 		pub sr25519: sr25519::AppPublic,
 		pub ecdsa: ecdsa::AppPublic,
+		pub bandersnatch: bandersnatch::AppPublic,
 	}
 }
 
@@ -587,6 +606,11 @@ impl_runtime_apis! {
 			test_ecdsa_crypto()
 		}
 
+		#[cfg(feature = "bandersnatch-experimental")]
+		fn test_bandersnatch_crypto() -> (bandersnatch::AppSignature, bandersnatch::AppPublic) {
+			test_bandersnatch_crypto()
+		}
+
 		fn test_storage() {
 			test_read_storage();
 			test_read_child_storage();
@@ -778,6 +802,24 @@ fn test_ecdsa_crypto() -> (ecdsa::AppSignature, ecdsa::AppPublic) {
 	(signature, public0)
 }
 
+#[cfg(feature = "bandersnatch-experimental")]
+fn test_bandersnatch_crypto() -> (bandersnatch::AppSignature, bandersnatch::AppPublic) {
+	let public0 = bandersnatch::AppPublic::generate_pair(None);
+	let public1 = bandersnatch::AppPublic::generate_pair(None);
+	let public2 = bandersnatch::AppPublic::generate_pair(None);
+
+	let all = bandersnatch::AppPublic::all();
+	assert!(all.contains(&public0));
+	assert!(all.contains(&public1));
+	assert!(all.contains(&public2));
+
+	let signature = public0
+		.sign(&"bandersnatch")
+		.expect("Generates a valid `bandersnatch` signature.");
+	assert!(public0.verify(&"bandersnatch", &signature));
+	(signature, public0)
+}
+
 fn test_read_storage() {
 	const KEY: &[u8] = b":read_storage";
 	sp_io::storage::set(KEY, b"test");
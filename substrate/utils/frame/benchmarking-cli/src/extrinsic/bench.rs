@@ -25,6 +25,7 @@ use sp_blockchain::{
 	ApplyExtrinsicFailed::Validity,
 	Error::{ApplyExtrinsicFailed, RuntimeApiError},
 };
+use sp_inherents::{CreateInherentDataProviders, InherentData, InherentDataProvider};
 use sp_runtime::{
 	traits::Block as BlockT,
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
@@ -57,6 +58,33 @@ pub struct BenchmarkParams {
 	pub max_ext_per_block: Option<u32>,
 }
 
+/// Create the inherent data for a block on top of the best block of `client` with the given
+/// `inherent_data_providers`.
+///
+/// All blocks of the benchmarks are built on top of the best block, so the inherent data only needs
+/// to be created once.
+pub(crate) fn inherent_data_at_best_block<Block, C, IDP>(
+	client: &C,
+	inherent_data_providers: &IDP,
+) -> Result<InherentData>
+where
+	Block: BlockT,
+	C: UsageProvider<Block>,
+	IDP: CreateInherentDataProviders<Block, ()>,
+{
+	let best_hash = client.usage_info().chain.best_hash;
+	futures::executor::block_on(async {
+		let providers = inherent_data_providers
+			.create_inherent_data_providers(best_hash, ())
+			.await
+			.map_err(|e| Error::Input(format!("creating inherent data providers: {:?}", e)))?;
+		providers
+			.create_inherent_data()
+			.await
+			.map_err(|e| Error::Input(format!("creating inherent data: {:?}", e)))
+	})
+}
+
 /// The results of multiple runs in nano seconds.
 pub(crate) type BenchRecord = Vec<u64>;
 
@@ -64,7 +92,7 @@ pub(crate) type BenchRecord = Vec<u64>;
 pub(crate) struct Benchmark<Block, C> {
 	client: Arc<C>,
 	params: BenchmarkParams,
-	inherent_data: sp_inherents::InherentData,
+	inherent_data: InherentData,
 	digest_items: Vec<DigestItem>,
 	_p: PhantomData<Block>,
 }
@@ -82,7 +110,7 @@ where
 	pub fn new(
 		client: Arc<C>,
 		params: BenchmarkParams,
-		inherent_data: sp_inherents::InherentData,
+		inherent_data: InherentData,
 		digest_items: Vec<DigestItem>,
 	) -> Self {
 		Self { client, params, inherent_data, digest_items, _p: PhantomData }
@@ -19,6 +19,7 @@ use sc_block_builder::BlockBuilderApi;
 use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
 use sc_client_api::UsageProvider;
 use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
+use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::{traits::Block as BlockT, DigestItem, OpaqueExtrinsic};
 
 use clap::{Args, Parser};
@@ -27,7 +28,7 @@ use serde::Serialize;
 use std::{fmt::Debug, sync::Arc};
 
 use super::{
-	bench::{Benchmark, BenchmarkParams},
+	bench::{inherent_data_at_best_block, Benchmark, BenchmarkParams},
 	extrinsic_factory::ExtrinsicFactory,
 };
 
@@ -129,6 +130,32 @@ impl ExtrinsicCmd {
 
 		Ok(())
 	}
+
+	/// Benchmark the execution time of a specific type of extrinsic, with the inherents created
+	/// by `inherent_data_providers`.
+	///
+	/// Same as [`Self::run`], but the inherent data is created for the block on top of the best
+	/// block. This allows to benchmark runtimes whose inherents depend on the parent block, like
+	/// the `set_validation_data` inherent of parachains.
+	pub fn run_with_inherent_data_providers<Block, C, IDP>(
+		&self,
+		client: Arc<C>,
+		inherent_data_providers: IDP,
+		digest_items: Vec<DigestItem>,
+		ext_factory: &ExtrinsicFactory,
+	) -> Result<()>
+	where
+		Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+		C: ProvideRuntimeApi<Block>
+			+ CallApiAt<Block>
+			+ UsageProvider<Block>
+			+ sp_blockchain::HeaderBackend<Block>,
+		C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
+		IDP: CreateInherentDataProviders<Block, ()>,
+	{
+		let inherent_data = inherent_data_at_best_block(&*client, &inherent_data_providers)?;
+		self.run(client, inherent_data, digest_items, ext_factory)
+	}
 }
 
 // Boilerplate
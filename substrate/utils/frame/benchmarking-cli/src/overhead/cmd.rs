@@ -23,6 +23,7 @@ use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
 use sc_client_api::UsageProvider;
 use sc_service::Configuration;
 use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
+use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::{traits::Block as BlockT, DigestItem, OpaqueExtrinsic};
 
 use clap::{Args, Parser};
@@ -32,7 +33,9 @@ use std::{fmt::Debug, path::PathBuf, sync::Arc};
 
 use crate::{
 	extrinsic::{
-		bench::{Benchmark, BenchmarkParams as ExtrinsicBenchmarkParams},
+		bench::{
+			inherent_data_at_best_block, Benchmark, BenchmarkParams as ExtrinsicBenchmarkParams,
+		},
 		ExtrinsicBuilder,
 	},
 	overhead::template::TemplateData,
@@ -135,6 +138,33 @@ impl OverheadCmd {
 
 		Ok(())
 	}
+
+	/// Measure the per-block and per-extrinsic execution overhead, with the inherents created by
+	/// `inherent_data_providers`.
+	///
+	/// Same as [`Self::run`], but the inherent data is created for the block on top of the best
+	/// block. This allows to benchmark runtimes whose inherents depend on the parent block, like
+	/// the `set_validation_data` inherent of parachains.
+	pub fn run_with_inherent_data_providers<Block, C, IDP>(
+		&self,
+		cfg: Configuration,
+		client: Arc<C>,
+		inherent_data_providers: IDP,
+		digest_items: Vec<DigestItem>,
+		ext_builder: &dyn ExtrinsicBuilder,
+	) -> Result<()>
+	where
+		Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+		C: ProvideRuntimeApi<Block>
+			+ CallApiAt<Block>
+			+ UsageProvider<Block>
+			+ sp_blockchain::HeaderBackend<Block>,
+		C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
+		IDP: CreateInherentDataProviders<Block, ()>,
+	{
+		let inherent_data = inherent_data_at_best_block(&*client, &inherent_data_providers)?;
+		self.run(cfg, client, inherent_data, digest_items, ext_builder)
+	}
 }
 
 impl BenchmarkType {
@@ -525,6 +525,8 @@ impl PalletCmd {
 			self.print_summary(&batches, &storage_info, pov_modes.clone())
 		}
 
+		self.check_reliability(&batches, &component_ranges)?;
+
 		// Create the weights.rs file.
 		if let Some(output_path) = &self.output {
 			writer::write_results(
@@ -577,6 +579,74 @@ impl PalletCmd {
 		self.output(batches, &[], &component_ranges, Default::default())
 	}
 
+	/// Checks that every component was sampled at enough points and that the extrinsic time fits
+	/// the linear model well enough.
+	///
+	/// Emits a warning for every problem found, and fails if `--fail-on-unreliable-weights` is set.
+	fn check_reliability(
+		&self,
+		batches: &[BenchmarkBatchSplitResults],
+		component_ranges: &HashMap<(Vec<u8>, Vec<u8>), Vec<ComponentRange>>,
+	) -> Result<()> {
+		let mut problems = Vec::new();
+
+		for batch in batches.iter().filter(|batch| !batch.time_results.is_empty()) {
+			let name = format!(
+				"{}::{}",
+				String::from_utf8_lossy(&batch.pallet),
+				String::from_utf8_lossy(&batch.benchmark)
+			);
+			let ranges = component_ranges
+				.get(&(batch.pallet.clone(), batch.benchmark.clone()))
+				.cloned()
+				.unwrap_or_default();
+
+			for (i, (param, _)) in batch.time_results[0].components.iter().enumerate() {
+				let param = param.to_string();
+				let sampled = batch
+					.time_results
+					.iter()
+					.map(|result| result.components[i].1)
+					.collect::<std::collections::BTreeSet<_>>()
+					.len() as u32;
+				let required = ranges
+					.iter()
+					.find(|range| range.name == param)
+					.map_or(self.min_component_samples, |range| {
+						self.min_component_samples.min(range.max - range.min + 1)
+					});
+				if sampled < required {
+					problems.push(format!(
+						"{}: component `{}` was sampled at {} distinct values, expected at least {}",
+						name, param, sampled, required
+					));
+				}
+			}
+
+			let Some(analysis) =
+				Analysis::min_squares_iqr(&batch.time_results, BenchmarkSelector::ExtrinsicTime)
+			else {
+				continue
+			};
+			let Some(r_squared) = analysis.r_squared else { continue };
+			if analysis.slopes.iter().any(|slope| *slope > 0) && r_squared < self.min_r_squared {
+				problems.push(format!(
+					"{}: the extrinsic time fit has an R² of {:.3}, expected at least {}",
+					name, r_squared, self.min_r_squared
+				));
+			}
+		}
+
+		for problem in &problems {
+			log::warn!(target: LOG_TARGET, "Unreliable weight: {}", problem);
+		}
+		if self.fail_on_unreliable_weights && !problems.is_empty() {
+			return Err(format!("Found {} unreliable weight(s)", problems.len()).into())
+		}
+
+		Ok(())
+	}
+
 	/// Jsonifies the passed batches and writes them to stdout or into a file.
 	/// Can be configured via `--json` and `--json-file`.
 	/// Returns whether it wrote to stdout.
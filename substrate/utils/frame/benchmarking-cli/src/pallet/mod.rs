@@ -103,6 +103,25 @@ pub struct PalletCmd {
 	#[arg(long)]
 	pub output_analysis: Option<String>,
 
+	/// The minimum number of distinct values a component needs to be sampled at.
+	///
+	/// Components whose range contains fewer values are only required to be sampled at all of
+	/// them. A warning is emitted for every component sampled at fewer points.
+	#[arg(long, default_value_t = 3)]
+	pub min_component_samples: u32,
+
+	/// The minimum coefficient of determination (R²) of the min-squares fit of the extrinsic time.
+	///
+	/// A warning is emitted for every benchmark whose weight function depends on a component and
+	/// fits the measurements worse than this.
+	#[arg(long, default_value_t = 0.5)]
+	pub min_r_squared: f64,
+
+	/// Fail instead of only emitting warnings if a component is sampled at too few points or a
+	/// weight function fits the measurements badly.
+	#[arg(long)]
+	pub fail_on_unreliable_weights: bool,
+
 	/// Which analysis function to use when analyzing measured proof sizes.
 	#[arg(long, default_value("median-slopes"))]
 	pub output_pov_analysis: Option<String>,
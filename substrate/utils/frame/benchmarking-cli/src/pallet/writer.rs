@@ -81,6 +81,7 @@ struct BenchmarkData {
 	comments: Vec<String>,
 	#[serde(serialize_with = "string_serialize")]
 	min_execution_time: u128,
+	r_squared: Option<String>,
 }
 
 // This forwards some specific metadata from the `PalletCmd`
@@ -371,6 +372,7 @@ fn get_benchmark_data(
 		component_ranges,
 		comments,
 		min_execution_time: extrinsic_time.minimum,
+		r_squared: extrinsic_time.r_squared.map(|r_squared| format!("{:.3}", r_squared)),
 	}
 }
 